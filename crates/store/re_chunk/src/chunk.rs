@@ -285,6 +285,34 @@ impl Chunk {
         self
     }
 
+    /// Returns a version of us that lives at a different [`EntityPath`].
+    ///
+    /// Reminder:
+    /// * The returned [`Chunk`] will re-use the exact same [`ChunkId`] and [`RowId`]s as `self`.
+    #[must_use]
+    #[inline]
+    pub fn with_entity_path(mut self, entity_path: EntityPath) -> Self {
+        self.entity_path = entity_path;
+        self
+    }
+
+    /// Returns a version of us where all the times of the given `timeline` are offset by `offset`.
+    ///
+    /// Times saturate rather than overflow, and never become [`TimeInt::STATIC`].
+    /// This is a no-op if the timeline doesn't exist in this chunk.
+    #[must_use]
+    pub fn time_shifted(mut self, timeline: &TimelineName, offset: i64) -> Self {
+        if offset == 0 {
+            return self;
+        }
+
+        if let Some(time_column) = self.timelines.get_mut(timeline) {
+            *time_column = time_column.shifted(offset);
+        }
+
+        self
+    }
+
     /// Returns `Ok` if two [`Chunk`]s are _similar_, although not byte-for-byte equal.
     ///
     /// In particular, this ignores chunks and row IDs, as well as `log_time` timestamps.
@@ -1293,6 +1321,27 @@ impl TimeColumn {
         self.time_range
     }
 
+    /// Returns a copy of this column with all times offset by `offset`.
+    ///
+    /// Times saturate rather than overflow, and never become [`TimeInt::STATIC`].
+    /// Sortedness is preserved.
+    #[must_use]
+    pub fn shifted(&self, offset: i64) -> Self {
+        let shift = |time: i64| time.saturating_add(offset).max(TimeInt::MIN.as_i64());
+
+        let times: ArrowScalarBuffer<i64> = self.times.iter().copied().map(shift).collect();
+
+        Self {
+            timeline: self.timeline,
+            times,
+            is_sorted: self.is_sorted,
+            time_range: AbsoluteTimeRange::new(
+                TimeInt::new_temporal(shift(self.time_range.min().as_i64())),
+                TimeInt::new_temporal(shift(self.time_range.max().as_i64())),
+            ),
+        }
+    }
+
     #[inline]
     pub fn times_buffer(&self) -> &ArrowScalarBuffer<i64> {
         &self.times
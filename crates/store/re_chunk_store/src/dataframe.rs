@@ -34,6 +34,15 @@ pub enum SparseFillStrategy {
     /// The latest-at semantics are applied on the entire dataset as opposed to just the current
     /// view contents: it is possible to end up with values from outside the view!
     LatestAtGlobal,
+
+    /// Like [`Self::LatestAtGlobal`], but only fill null values with data that was logged at
+    /// most `tolerance` before the current row.
+    ///
+    /// The tolerance is expressed in the units of the filtered index, i.e. nanoseconds or
+    /// sequence steps. Static data is always used, regardless of tolerance.
+    ///
+    /// See [`QueryExpression::as_of_join`].
+    LatestAtGlobalWithin { tolerance: u64 },
     //
     // TODO(cmc): `LatestAtView`?
 }
@@ -43,6 +52,9 @@ impl std::fmt::Display for SparseFillStrategy {
         match self {
             Self::None => f.write_str("none"),
             Self::LatestAtGlobal => f.write_str("latest-at (global)"),
+            Self::LatestAtGlobalWithin { tolerance } => {
+                write!(f, "latest-at (global, within {tolerance})")
+            }
         }
     }
 }
@@ -250,6 +262,45 @@ pub struct QueryExpression {
 }
 
 impl QueryExpression {
+    /// Builds a query that as-of joins the rest of `view_contents` onto the rows of `on`.
+    ///
+    /// The resulting table has exactly one row per index value at which `on` was logged, and
+    /// every other column holds the latest value logged at or before that row, e.g. to align a
+    /// 1 kHz IMU onto the frames of a 30 Hz camera.
+    ///
+    /// If `tolerance` is set, values logged more than `tolerance` before a row are left null
+    /// rather than carried over.
+    ///
+    /// `on`'s entity is added to `view_contents` if it isn't already part of it.
+    pub fn as_of_join(
+        index: Index,
+        on: ComponentColumnSelector,
+        view_contents: Option<ViewContentsSelector>,
+        tolerance: Option<u64>,
+    ) -> Self {
+        let view_contents = view_contents.map(|mut view_contents| {
+            // `None` means that all components of that entity are already part of the view.
+            if let Some(components) = view_contents
+                .entry(on.entity_path.clone())
+                .or_insert_with(|| Some(Default::default()))
+            {
+                components.insert(ComponentIdentifier::from(on.component.as_str()));
+            }
+            view_contents
+        });
+
+        Self {
+            view_contents,
+            filtered_index: Some(index),
+            filtered_is_not_null: Some(on),
+            sparse_fill_strategy: match tolerance {
+                Some(tolerance) => SparseFillStrategy::LatestAtGlobalWithin { tolerance },
+                None => SparseFillStrategy::LatestAtGlobal,
+            },
+            ..Default::default()
+        }
+    }
+
     pub fn is_static(&self) -> bool {
         self.filtered_index.is_none()
     }
@@ -0,0 +1,207 @@
+use std::collections::BTreeSet;
+
+use arrow::array::{Array as _, ArrayRef, BooleanArray, Float64Array, StringArray};
+use arrow::datatypes::DataType;
+
+use re_arrow_util::ArrowArrayDowncastRef as _;
+use re_chunk::{ComponentIdentifier, LatestAtQuery};
+use re_log_types::{ComparisonOp, EntityPath, EntityPathPredicate, EntityPathQuery};
+
+use crate::ChunkStore;
+
+impl ChunkStore {
+    /// All entities matching the given [`EntityPathQuery`], e.g. `/world/**[has:Points3D]`.
+    ///
+    /// Value predicates are evaluated against the latest value as of `latest_at`.
+    /// Only the entities under the literal prefix of the pattern are considered, and
+    /// `has:` predicates only look at the per-entity component indices, never at the data itself.
+    pub fn entities_matching(
+        &self,
+        query: &EntityPathQuery,
+        latest_at: &LatestAtQuery,
+    ) -> BTreeSet<EntityPath> {
+        re_tracing::profile_function!();
+
+        let prefix = query.pattern.literal_prefix();
+
+        self.all_entities_sorted()
+            .into_iter()
+            .filter(|entity_path| entity_path.starts_with(&prefix))
+            .filter(|entity_path| query.matches_path(entity_path))
+            .filter(|entity_path| {
+                query.predicates.iter().all(|predicate| {
+                    self.entity_matches_predicate(entity_path, predicate, latest_at)
+                })
+            })
+            .collect()
+    }
+
+    fn entity_matches_predicate(
+        &self,
+        entity_path: &EntityPath,
+        predicate: &EntityPathPredicate,
+        latest_at: &LatestAtQuery,
+    ) -> bool {
+        match predicate {
+            EntityPathPredicate::Has(name) => {
+                let Some(components) = self.all_components_for_entity(entity_path) else {
+                    return false;
+                };
+                let name = name.as_str();
+
+                components.into_iter().any(|component| {
+                    component.as_str() == name
+                        || self
+                            .entity_component_descriptor(entity_path, component)
+                            .is_some_and(|descr| {
+                                descr.archetype.is_some_and(|a| a.short_name() == name)
+                                    || descr.component_type.is_some_and(|c| c.short_name() == name)
+                            })
+                })
+            }
+
+            EntityPathPredicate::Compare {
+                component,
+                op,
+                value,
+            } => self
+                .latest_component_batch(entity_path, *component, latest_at)
+                .is_some_and(|batch| any_instance_compares(&batch, *op, value)),
+        }
+    }
+
+    fn latest_component_batch(
+        &self,
+        entity_path: &EntityPath,
+        component: ComponentIdentifier,
+        query: &LatestAtQuery,
+    ) -> Option<ArrayRef> {
+        let (_index, unit) = self
+            .latest_at_relevant_chunks(query, entity_path, component)
+            .into_iter()
+            .filter_map(|chunk| {
+                let unit = chunk.latest_at(query, component).into_unit()?;
+                unit.index(&query.timeline()).map(|index| (index, unit))
+            })
+            .max_by_key(|(index, _unit)| *index)?;
+
+        unit.component_batch_raw(component)
+    }
+}
+
+/// Numbers are compared numerically, booleans and strings as text.
+///
+/// Other datatypes, e.g. structs and fixed-size lists, never match.
+fn any_instance_compares(batch: &ArrayRef, op: ComparisonOp, value: &str) -> bool {
+    if let Some(strings) = batch.downcast_array_ref::<StringArray>() {
+        return strings.iter().flatten().any(|s| op.compare(s, value));
+    }
+
+    if let Some(bools) = batch.downcast_array_ref::<BooleanArray>() {
+        return value
+            .parse::<bool>()
+            .is_ok_and(|rhs| bools.iter().flatten().any(|b| op.compare(&b, &rhs)));
+    }
+
+    let Ok(rhs) = value.parse::<f64>() else {
+        return false;
+    };
+    if !batch.data_type().is_numeric() {
+        return false;
+    }
+    let Ok(numbers) = arrow::compute::cast(batch, &DataType::Float64) else {
+        return false;
+    };
+    numbers
+        .downcast_array_ref::<Float64Array>()
+        .is_some_and(|numbers| numbers.iter().flatten().any(|n| op.compare(&n, &rhs)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::array::Float32Array;
+    use re_chunk::{Chunk, RowId, TimePoint, Timeline};
+    use re_types_core::{ComponentDescriptor, SerializedComponentBatch};
+
+    use super::*;
+    use crate::ChunkStoreConfig;
+
+    fn log(store: &mut ChunkStore, entity_path: &str, batches: Vec<SerializedComponentBatch>) {
+        let chunk = Chunk::builder(entity_path)
+            .with_serialized_batches(
+                RowId::new(),
+                TimePoint::from_iter([(Timeline::new_sequence("frame"), 1)]),
+                batches,
+            )
+            .build()
+            .unwrap();
+        store.insert_chunk(&Arc::new(chunk)).unwrap();
+    }
+
+    fn batch(
+        archetype: &str,
+        component: &str,
+        array: impl arrow::array::Array + 'static,
+    ) -> SerializedComponentBatch {
+        SerializedComponentBatch::new(
+            Arc::new(array),
+            ComponentDescriptor::partial(format!("{archetype}:{component}"))
+                .with_archetype(format!("rerun.archetypes.{archetype}").into()),
+        )
+    }
+
+    #[test]
+    fn entities_matching() {
+        let mut store = ChunkStore::new(
+            re_log_types::StoreId::random(re_log_types::StoreKind::Recording, "test"),
+            ChunkStoreConfig::COMPACTION_DISABLED,
+        );
+
+        log(
+            &mut store,
+            "/world/car/points",
+            vec![batch(
+                "Points3D",
+                "radii",
+                Float32Array::from(vec![0.1, 1.0]),
+            )],
+        );
+        log(
+            &mut store,
+            "/world/bike/points",
+            vec![batch("Points3D", "radii", Float32Array::from(vec![0.2]))],
+        );
+        log(
+            &mut store,
+            "/world/car/label",
+            vec![batch("TextLog", "text", StringArray::from(vec!["car"]))],
+        );
+
+        let latest_at = LatestAtQuery::latest(re_chunk::TimelineName::new("frame"));
+        let matching = |query: &str| -> Vec<String> {
+            store
+                .entities_matching(&query.parse().unwrap(), &latest_at)
+                .iter()
+                .map(ToString::to_string)
+                .collect()
+        };
+
+        assert_eq!(
+            matching("/world/**[has:Points3D]"),
+            ["/world/bike/points", "/world/car/points"]
+        );
+        assert_eq!(
+            matching("/world/*/label[has:TextLog:text]"),
+            ["/world/car/label"]
+        );
+        assert_eq!(matching("/**[Points3D:radii>0.5]"), ["/world/car/points"]);
+        assert_eq!(
+            matching("/world/**[TextLog:text=car]"),
+            ["/world/car/label"]
+        );
+        assert!(matching("/world/**[has:Mesh3D]").is_empty());
+        assert!(matching("/world/car[has:Points3D]").is_empty());
+    }
+}
@@ -0,0 +1,218 @@
+use std::{
+    sync::mpsc::{self, Receiver},
+    time::Duration,
+};
+
+use crate::{ChunkStore, ChunkStoreEvent, ChunkStoreSubscriber, ChunkStoreSubscriberHandle};
+
+// ---
+
+/// Receives the [`ChunkStoreEvent`]s of all [`ChunkStore`]s, on whichever thread it lives on.
+///
+/// This is the recommended way of building derived-data pipelines outside of the viewer: unlike a
+/// [`ChunkStoreSubscriber`], processing the events does not hold up ingestion.
+///
+/// Events are delivered in batches, exactly as the store emitted them: one batch per write
+/// (insertion, garbage collection, …). Within a store, events are always received in
+/// [`ChunkStoreEvent::event_id`] order.
+///
+/// Dropping the stream unsubscribes it.
+///
+/// See [`ChunkStore::subscribe_events`].
+pub struct ChunkStoreEventStream {
+    handle: ChunkStoreSubscriberHandle,
+    rx: Receiver<Vec<ChunkStoreEvent>>,
+}
+
+impl ChunkStore {
+    /// Subscribes to the [`ChunkStoreEvent`]s of all [`ChunkStore`]s through a channel.
+    ///
+    /// The channel is unbounded: the stores never wait on the stream, it is up to the receiving
+    /// end to keep up.
+    /// See [`ChunkStore::subscribe_events_bounded`] for a back-pressured alternative.
+    ///
+    /// The same rules as for [`ChunkStore::register_subscriber`] apply regarding scope and late
+    /// registration.
+    pub fn subscribe_events() -> ChunkStoreEventStream {
+        let (tx, rx) = mpsc::channel();
+        ChunkStoreEventStream::new(EventSender::Unbounded(tx), rx)
+    }
+
+    /// Like [`ChunkStore::subscribe_events`], but at most `capacity` batches of events can be
+    /// pending at any time.
+    ///
+    /// Once full, writes to any [`ChunkStore`] will block until the stream catches up.
+    /// Only use this if losing track of memory is worse than slowing down ingestion.
+    pub fn subscribe_events_bounded(capacity: usize) -> ChunkStoreEventStream {
+        let (tx, rx) = mpsc::sync_channel(capacity);
+        ChunkStoreEventStream::new(EventSender::Bounded(tx), rx)
+    }
+}
+
+impl ChunkStoreEventStream {
+    fn new(tx: EventSender, rx: Receiver<Vec<ChunkStoreEvent>>) -> Self {
+        let handle = ChunkStore::register_subscriber(Box::new(EventForwarder { tx: Some(tx) }));
+        Self { handle, rx }
+    }
+
+    /// The handle of the underlying [`ChunkStoreSubscriber`].
+    #[inline]
+    pub fn handle(&self) -> ChunkStoreSubscriberHandle {
+        self.handle
+    }
+
+    /// The underlying channel.
+    #[inline]
+    pub fn receiver(&self) -> &Receiver<Vec<ChunkStoreEvent>> {
+        &self.rx
+    }
+
+    /// Blocks until the next batch of events comes in.
+    #[inline]
+    pub fn recv(&self) -> Vec<ChunkStoreEvent> {
+        // The sender lives in the global subscriber list, and is never dropped first.
+        self.rx.recv().unwrap_or_default()
+    }
+
+    /// Blocks until the next batch of events comes in, or `timeout` elapses.
+    #[inline]
+    pub fn recv_timeout(&self, timeout: Duration) -> Option<Vec<ChunkStoreEvent>> {
+        self.rx.recv_timeout(timeout).ok()
+    }
+
+    /// Returns all the events that are pending right now, merged into a single batch, without
+    /// blocking.
+    ///
+    /// The result is empty if nothing happened since the last call.
+    pub fn drain(&self) -> Vec<ChunkStoreEvent> {
+        self.rx.try_iter().flatten().collect()
+    }
+
+    /// Waits for events for up to `timeout`, then merges everything that is pending into a single
+    /// batch.
+    ///
+    /// Handy to amortize per-batch costs (e.g. recomputing derived data) when ingesting lots of
+    /// small chunks.
+    pub fn recv_batch(&self, timeout: Duration) -> Vec<ChunkStoreEvent> {
+        let Some(mut events) = self.recv_timeout(timeout) else {
+            return Vec::new();
+        };
+        events.extend(self.rx.try_iter().flatten());
+        events
+    }
+}
+
+impl Drop for ChunkStoreEventStream {
+    fn drop(&mut self) {
+        // Disconnect first: a store could be blocked on a full bounded channel, while holding the
+        // very subscriber we're about to lock.
+        drop(std::mem::replace(&mut self.rx, mpsc::channel().1));
+
+        // Stop queuing events nobody will ever read.
+        ChunkStore::with_subscriber_mut::<EventForwarder, _, _>(self.handle, |forwarder| {
+            forwarder.tx = None;
+        });
+    }
+}
+
+// ---
+
+/// The [`ChunkStoreSubscriber`] backing a [`ChunkStoreEventStream`].
+struct EventForwarder {
+    /// `None` once the stream is gone.
+    tx: Option<EventSender>,
+}
+
+enum EventSender {
+    Unbounded(mpsc::Sender<Vec<ChunkStoreEvent>>),
+    Bounded(mpsc::SyncSender<Vec<ChunkStoreEvent>>),
+}
+
+impl EventSender {
+    /// Returns `false` if the receiving end is gone.
+    fn send(&self, events: Vec<ChunkStoreEvent>) -> bool {
+        match self {
+            Self::Unbounded(tx) => tx.send(events).is_ok(),
+            Self::Bounded(tx) => tx.send(events).is_ok(),
+        }
+    }
+}
+
+impl ChunkStoreSubscriber for EventForwarder {
+    fn name(&self) -> String {
+        "rerun.store_subscribers.EventForwarder".into()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn on_events(&mut self, events: &[ChunkStoreEvent]) {
+        if events.is_empty() {
+            return;
+        }
+
+        if let Some(tx) = &self.tx
+            && !tx.send(events.to_vec())
+        {
+            self.tx = None;
+        }
+    }
+}
+
+// ---
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use re_chunk::{Chunk, RowId};
+    use re_log_types::{StoreId, StoreKind, TimePoint, Timeline, example_components::MyIndex};
+
+    use super::*;
+    use crate::GarbageCollectionOptions;
+
+    #[test]
+    fn event_stream() -> anyhow::Result<()> {
+        let stream = ChunkStore::subscribe_events();
+
+        let mut store = ChunkStore::new(
+            StoreId::random(StoreKind::Recording, "test_app"),
+            Default::default(),
+        );
+
+        let mut expected_events = Vec::new();
+        for frame in 0..3 {
+            let chunk = Chunk::builder("entity")
+                .with_component_batch(
+                    RowId::new(),
+                    TimePoint::from_iter([(Timeline::new_sequence("frame"), frame)]),
+                    (MyIndex::partial_descriptor(), &MyIndex::from_iter(0..3)),
+                )
+                .build()?;
+            expected_events.extend(store.insert_chunk(&Arc::new(chunk))?);
+        }
+        expected_events.extend(store.gc(&GarbageCollectionOptions::gc_everything()).0);
+
+        // NOTE: `cargo` implicitly runs tests in parallel!
+        let got: Vec<_> = stream
+            .drain()
+            .into_iter()
+            .filter(|event| event.store_id == *store.id())
+            .collect();
+        similar_asserts::assert_eq!(expected_events, got);
+
+        assert!(
+            stream
+                .recv_batch(Duration::from_millis(1))
+                .iter()
+                .all(|event| event.store_id != *store.id())
+        );
+
+        Ok(())
+    }
+}
@@ -133,6 +133,19 @@ impl ChunkStore {
         let total_num_chunks_before = stats_before.total().num_chunks;
         let total_num_rows_before = stats_before.total().num_rows;
 
+        // Archived static versions are by far the cheapest thing to get rid of, and count towards
+        // the bytes to drop.
+        let num_static_versions_dropped = self.drop_unpinned_static_versions();
+        if num_static_versions_dropped > 0 {
+            re_log::trace!(
+                num_static_versions_dropped,
+                "dropped archived static versions"
+            );
+        }
+        let num_archived_bytes_dropped = (stats_before.archived_static_chunks.total_size_bytes
+            - self.archived_static_chunks_stats.total_size_bytes)
+            as f64;
+
         let protected_chunk_ids = self.find_all_protected_chunk_ids(options.protect_latest);
 
         let diffs = match options.target {
@@ -141,6 +154,7 @@ impl ChunkStore {
 
                 let num_bytes_to_drop = total_size_bytes_before * p;
                 let target_size_bytes = total_size_bytes_before - num_bytes_to_drop;
+                let num_bytes_to_drop = (num_bytes_to_drop - num_archived_bytes_dropped).max(0.0);
 
                 re_log::trace!(
                     kind = "gc",
@@ -349,6 +363,9 @@ impl ChunkStore {
                 temporal_chunks_stats: _,
                 static_chunk_ids_per_entity: _, // we don't GC static data
                 static_chunks_stats: _,         // we don't GC static data
+                static_versions_per_entity: _,  // handled above
+                archived_static_chunks_stats: _, // handled above
+                pinned_static_versions: _,
                 insert_id: _,
                 gc_id: _,
                 event_id: _,
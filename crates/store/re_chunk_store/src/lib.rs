@@ -16,10 +16,13 @@
 
 mod dataframe;
 mod drop_time_range;
+mod entity_query;
+mod event_stream;
 mod events;
 mod gc;
 mod properties;
 mod query;
+mod static_versions;
 mod stats;
 mod store;
 mod subscribers;
@@ -30,6 +33,7 @@ pub use self::{
         Index, IndexRange, IndexValue, QueryExpression, SparseFillStrategy, StaticColumnSelection,
         ViewContentsSelector,
     },
+    event_stream::ChunkStoreEventStream,
     events::{ChunkCompactionReport, ChunkStoreDiff, ChunkStoreDiffKind, ChunkStoreEvent},
     gc::{GarbageCollectionOptions, GarbageCollectionTarget},
     properties::ExtractPropertiesError,
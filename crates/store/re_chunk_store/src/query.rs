@@ -566,12 +566,7 @@ impl ChunkStore {
         // Reminder: if a chunk has been indexed for a given component, then it must contain at
         // least one non-null value for that column.
 
-        if let Some(static_chunk) = self
-            .static_chunk_ids_per_entity
-            .get(entity_path)
-            .and_then(|static_chunks_per_component| static_chunks_per_component.get(&component))
-            .and_then(|chunk_id| self.chunks_per_chunk_id.get(chunk_id))
-        {
+        if let Some(static_chunk) = self.static_chunk_for_component(entity_path, component) {
             return vec![Arc::clone(static_chunk)];
         }
 
@@ -754,12 +749,7 @@ impl ChunkStore {
     ) -> Vec<Arc<Chunk>> {
         re_tracing::profile_function!(format!("{query:?}"));
 
-        if let Some(static_chunk) = self
-            .static_chunk_ids_per_entity
-            .get(entity_path)
-            .and_then(|static_chunks_per_component| static_chunks_per_component.get(&component))
-            .and_then(|chunk_id| self.chunks_per_chunk_id.get(chunk_id))
-        {
+        if let Some(static_chunk) = self.static_chunk_for_component(entity_path, component) {
             return vec![Arc::clone(static_chunk)];
         }
 
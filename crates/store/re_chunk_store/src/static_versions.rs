@@ -0,0 +1,421 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use re_chunk::{Chunk, ChunkId, ComponentIdentifier, RowId};
+use re_log_types::EntityPath;
+
+use crate::{ChunkStore, ChunkStoreChunkStats, ChunkStoreDiff, ChunkStoreEvent};
+
+impl ChunkStore {
+    /// All known versions of the static data for the given component, oldest first.
+    ///
+    /// Each version is identified by the most recent [`RowId`] of that component within it.
+    /// The last entry is the version currently in use, unless another one was pinned
+    /// (see [`Self::pin_static_version`]).
+    ///
+    /// Returns an empty list if the component has no static data.
+    /// At most [`crate::ChunkStoreConfig::max_static_versions`] previous versions are kept around.
+    pub fn static_versions(
+        &self,
+        entity_path: &EntityPath,
+        component: ComponentIdentifier,
+    ) -> Vec<RowId> {
+        let archived = self
+            .static_versions_per_entity
+            .get(entity_path)
+            .and_then(|per_component| per_component.get(&component))
+            .into_iter()
+            .flat_map(|versions| versions.keys().copied());
+
+        archived
+            .chain(self.latest_static_version(entity_path, component))
+            .collect()
+    }
+
+    /// The chunk holding the given version of the static data for the given component.
+    ///
+    /// See [`Self::static_versions`].
+    pub fn static_version_chunk(
+        &self,
+        entity_path: &EntityPath,
+        component: ComponentIdentifier,
+        version: RowId,
+    ) -> Option<Arc<Chunk>> {
+        if self.latest_static_version(entity_path, component) == Some(version) {
+            return self
+                .latest_static_chunk(entity_path, component)
+                .map(Arc::clone);
+        }
+
+        self.static_versions_per_entity
+            .get(entity_path)?
+            .get(&component)?
+            .get(&version)
+            .map(Arc::clone)
+    }
+
+    /// The version of the static data that was pinned for the given component, if any.
+    ///
+    /// See [`Self::pin_static_version`].
+    #[inline]
+    pub fn pinned_static_version(
+        &self,
+        entity_path: &EntityPath,
+        component: ComponentIdentifier,
+    ) -> Option<RowId> {
+        self.pinned_static_versions
+            .get(entity_path)?
+            .get(&component)
+            .copied()
+    }
+
+    /// Pins a specific version of the static data for the given component, or unpins it if
+    /// `version` is `None`.
+    ///
+    /// While pinned, per-component queries ([`Self::latest_at_relevant_chunks`] and
+    /// [`Self::range_relevant_chunks`]) return that version instead of the latest one, even as
+    /// newer static data keeps coming in. Pinned versions are never evicted from the archive.
+    ///
+    /// Returns `None` if the version isn't known to the store, in which case nothing changes.
+    ///
+    /// Otherwise, returns the resulting [`ChunkStoreEvent`]s: if this changes the chunk that
+    /// queries use for this component, the deletion of the previous chunk followed by the addition
+    /// of the new one. Just like for regular writes, these are also forwarded to all subscribers.
+    pub fn pin_static_version(
+        &mut self,
+        entity_path: &EntityPath,
+        component: ComponentIdentifier,
+        version: Option<RowId>,
+    ) -> Option<Vec<ChunkStoreEvent>> {
+        if let Some(version) = version
+            && self
+                .static_version_chunk(entity_path, component, version)
+                .is_none()
+        {
+            return None;
+        }
+
+        let chunk_before = self
+            .static_chunk_for_component(entity_path, component)
+            .map(Arc::clone);
+
+        if let Some(version) = version {
+            self.pinned_static_versions
+                .entry(entity_path.clone())
+                .or_default()
+                .insert(component, version);
+        } else if let Some(per_component) = self.pinned_static_versions.get_mut(entity_path) {
+            per_component.remove(&component);
+            if per_component.is_empty() {
+                self.pinned_static_versions.remove(entity_path);
+            }
+        }
+
+        let chunk_after = self
+            .static_chunk_for_component(entity_path, component)
+            .map(Arc::clone);
+
+        let diffs = match (chunk_before, chunk_after) {
+            (Some(chunk_before), Some(chunk_after)) if chunk_before.id() != chunk_after.id() => {
+                vec![
+                    ChunkStoreDiff::deletion(chunk_before),
+                    ChunkStoreDiff::addition(chunk_after, None),
+                ]
+            }
+            _ => Vec::new(),
+        };
+
+        let events = if self.config.enable_changelog {
+            let events: Vec<_> = diffs
+                .into_iter()
+                .map(|diff| ChunkStoreEvent {
+                    store_id: self.id.clone(),
+                    store_generation: self.generation(),
+                    event_id: self
+                        .event_id
+                        .fetch_add(1, std::sync::atomic::Ordering::Relaxed),
+                    diff,
+                })
+                .collect();
+
+            Self::on_events(&events);
+
+            events
+        } else {
+            Vec::new()
+        };
+
+        Some(events)
+    }
+
+    /// The static chunk that queries should use for the given component, taking pinned versions
+    /// into account.
+    pub(crate) fn static_chunk_for_component(
+        &self,
+        entity_path: &EntityPath,
+        component: ComponentIdentifier,
+    ) -> Option<&Arc<Chunk>> {
+        if let Some(version) = self.pinned_static_version(entity_path, component)
+            && self.latest_static_version(entity_path, component) != Some(version)
+            && let Some(chunk) = self
+                .static_versions_per_entity
+                .get(entity_path)
+                .and_then(|per_component| per_component.get(&component))
+                .and_then(|versions| versions.get(&version))
+        {
+            return Some(chunk);
+        }
+
+        self.latest_static_chunk(entity_path, component)
+    }
+
+    /// Moves the given overwritten static chunks into the archive, evicting the oldest unpinned
+    /// versions beyond [`crate::ChunkStoreConfig::max_static_versions`].
+    ///
+    /// Must be called before the overwritten chunks are removed from the store.
+    pub(crate) fn archive_static_versions(
+        &mut self,
+        entity_path: &EntityPath,
+        overwritten: Vec<(ComponentIdentifier, ChunkId, RowId)>,
+    ) {
+        let max_versions = self.config.max_static_versions as usize;
+        if max_versions == 0 || overwritten.is_empty() {
+            return;
+        }
+
+        let pinned = self.pinned_static_versions.get(entity_path);
+        let per_component = self
+            .static_versions_per_entity
+            .entry(entity_path.clone())
+            .or_default();
+
+        for (component, chunk_id, version) in overwritten {
+            let Some(chunk) = self.chunks_per_chunk_id.get(&chunk_id) else {
+                continue;
+            };
+
+            let versions = per_component.entry(component).or_default();
+            versions.insert(version, Arc::clone(chunk));
+
+            let pinned = pinned.and_then(|pinned| pinned.get(&component));
+            while versions.len() > max_versions {
+                let Some(oldest_unpinned) = versions
+                    .keys()
+                    .copied()
+                    .find(|version| Some(version) != pinned)
+                else {
+                    break;
+                };
+                versions.remove(&oldest_unpinned);
+            }
+        }
+    }
+
+    /// Size statistics of the archived static versions of an entity that aren't also present in
+    /// the store proper.
+    pub(crate) fn entity_archived_static_chunks_stats(
+        &self,
+        entity_path: &EntityPath,
+    ) -> ChunkStoreChunkStats {
+        let Some(per_component) = self.static_versions_per_entity.get(entity_path) else {
+            return ChunkStoreChunkStats::default();
+        };
+
+        // The same chunk holds a version for each of the components it overwrote.
+        let archived_chunks: BTreeMap<ChunkId, &Arc<Chunk>> = per_component
+            .values()
+            .flat_map(|versions| versions.values())
+            .filter(|chunk| !self.chunks_per_chunk_id.contains_key(&chunk.id()))
+            .map(|chunk| (chunk.id(), chunk))
+            .collect();
+
+        archived_chunks
+            .into_values()
+            .map(ChunkStoreChunkStats::from_chunk)
+            .sum()
+    }
+
+    /// Drops all archived static versions that aren't pinned.
+    ///
+    /// Returns the number of versions that were dropped.
+    pub(crate) fn drop_unpinned_static_versions(&mut self) -> usize {
+        let Self {
+            static_versions_per_entity,
+            pinned_static_versions,
+            ..
+        } = self;
+
+        let mut num_dropped = 0;
+        static_versions_per_entity.retain(|entity_path, per_component| {
+            let pinned = pinned_static_versions.get(entity_path);
+            per_component.retain(|component, versions| {
+                let pinned = pinned.and_then(|pinned| pinned.get(component));
+                let len_before = versions.len();
+                versions.retain(|version, _| Some(version) == pinned);
+                num_dropped += len_before - versions.len();
+                !versions.is_empty()
+            });
+            !per_component.is_empty()
+        });
+
+        if num_dropped > 0 {
+            self.archived_static_chunks_stats = self
+                .static_versions_per_entity
+                .keys()
+                .map(|entity_path| self.entity_archived_static_chunks_stats(entity_path))
+                .sum();
+        }
+
+        num_dropped
+    }
+
+    fn latest_static_chunk(
+        &self,
+        entity_path: &EntityPath,
+        component: ComponentIdentifier,
+    ) -> Option<&Arc<Chunk>> {
+        self.static_chunk_ids_per_entity
+            .get(entity_path)
+            .and_then(|static_chunks_per_component| static_chunks_per_component.get(&component))
+            .and_then(|chunk_id| self.chunks_per_chunk_id.get(chunk_id))
+    }
+
+    fn latest_static_version(
+        &self,
+        entity_path: &EntityPath,
+        component: ComponentIdentifier,
+    ) -> Option<RowId> {
+        self.latest_static_chunk(entity_path, component)?
+            .row_id_range_per_component()
+            .get(&component)
+            .map(|(_row_id_min, row_id_max)| *row_id_max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::StringArray;
+    use re_chunk::{LatestAtQuery, TimePoint};
+    use re_log_types::TimelineName;
+    use re_types_core::{ComponentDescriptor, SerializedComponentBatch};
+
+    use super::*;
+    use crate::{ChunkStoreConfig, ChunkStoreDiffKind};
+
+    fn log_static(store: &mut ChunkStore, entity_path: &EntityPath, value: &str) -> RowId {
+        let row_id = RowId::new();
+        let chunk = Chunk::builder(entity_path.clone())
+            .with_serialized_batches(
+                row_id,
+                TimePoint::default(),
+                vec![SerializedComponentBatch::new(
+                    Arc::new(StringArray::from(vec![value])),
+                    ComponentDescriptor::partial("mesh:name"),
+                )],
+            )
+            .build()
+            .unwrap();
+        store.insert_chunk(&Arc::new(chunk)).unwrap();
+        row_id
+    }
+
+    fn num_archived_chunks(store: &ChunkStore) -> u64 {
+        store.stats().archived_static_chunks.num_chunks
+    }
+
+    fn event_kinds_and_row_ids(events: &[ChunkStoreEvent]) -> Vec<(ChunkStoreDiffKind, RowId)> {
+        events
+            .iter()
+            .filter_map(|event| Some((event.kind, event.chunk.row_id_range()?.1)))
+            .collect()
+    }
+
+    fn queried_row_id(store: &ChunkStore, entity_path: &EntityPath) -> Option<RowId> {
+        let component = ComponentIdentifier::from("mesh:name");
+        let query = LatestAtQuery::latest(TimelineName::log_tick());
+        store
+            .latest_at_relevant_chunks(&query, entity_path, component)
+            .into_iter()
+            .filter_map(|chunk| chunk.latest_at(&query, component).into_unit())
+            .filter_map(|unit| unit.row_id())
+            .max()
+    }
+
+    #[test]
+    fn static_versions() {
+        let mut store = ChunkStore::new(
+            re_log_types::StoreId::random(re_log_types::StoreKind::Recording, "test"),
+            ChunkStoreConfig {
+                max_static_versions: 2,
+                ..ChunkStoreConfig::COMPACTION_DISABLED
+            },
+        );
+
+        let entity_path = EntityPath::from("mesh");
+        let component = ComponentIdentifier::from("mesh:name");
+
+        let v1 = log_static(&mut store, &entity_path, "v1");
+        let v2 = log_static(&mut store, &entity_path, "v2");
+        assert_eq!(store.static_versions(&entity_path, component), [v1, v2]);
+        assert_eq!(queried_row_id(&store, &entity_path), Some(v2));
+
+        // The overwritten chunk left the store proper, but is still accounted for.
+        assert_eq!(store.stats().static_chunks.num_chunks, 1);
+        assert_eq!(num_archived_chunks(&store), 1);
+        assert_eq!(store.stats().total().num_chunks, 2);
+
+        // Pinned versions shadow newer data…
+        let events = store
+            .pin_static_version(&entity_path, component, Some(v1))
+            .unwrap();
+        assert_eq!(
+            event_kinds_and_row_ids(&events),
+            [
+                (ChunkStoreDiffKind::Deletion, v2),
+                (ChunkStoreDiffKind::Addition, v1)
+            ]
+        );
+        let v3 = log_static(&mut store, &entity_path, "v3");
+        assert_eq!(queried_row_id(&store, &entity_path), Some(v1));
+
+        // …and are never evicted.
+        let v4 = log_static(&mut store, &entity_path, "v4");
+        assert_eq!(store.static_versions(&entity_path, component), [v1, v3, v4]);
+        assert_eq!(num_archived_chunks(&store), 2);
+        assert_eq!(store.drop_unpinned_static_versions(), 1);
+        assert_eq!(store.static_versions(&entity_path, component), [v1, v4]);
+        assert_eq!(num_archived_chunks(&store), 1);
+
+        assert!(
+            store
+                .pin_static_version(&entity_path, component, Some(v2))
+                .is_none()
+        );
+        assert_eq!(
+            store.pinned_static_version(&entity_path, component),
+            Some(v1)
+        );
+
+        // Pinning the version that is already in use changes nothing.
+        let events = store
+            .pin_static_version(&entity_path, component, Some(v1))
+            .unwrap();
+        assert!(events.is_empty());
+
+        let events = store
+            .pin_static_version(&entity_path, component, None)
+            .unwrap();
+        assert_eq!(
+            event_kinds_and_row_ids(&events),
+            [
+                (ChunkStoreDiffKind::Deletion, v1),
+                (ChunkStoreDiffKind::Addition, v4)
+            ]
+        );
+        assert_eq!(queried_row_id(&store, &entity_path), Some(v4));
+
+        // Dropping the entity drops its archive too.
+        store.drop_entity_path(&entity_path);
+        assert_eq!(num_archived_chunks(&store), 0);
+    }
+}
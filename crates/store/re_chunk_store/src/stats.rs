@@ -11,6 +11,11 @@ use crate::ChunkStore;
 pub struct ChunkStoreStats {
     pub static_chunks: ChunkStoreChunkStats,
     pub temporal_chunks: ChunkStoreChunkStats,
+
+    /// Previous versions of overwritten static data, see [`ChunkStore::static_versions`].
+    ///
+    /// Only counts the chunks that aren't also part of [`Self::static_chunks`].
+    pub archived_static_chunks: ChunkStoreChunkStats,
 }
 
 impl ChunkStoreStats {
@@ -19,8 +24,9 @@ impl ChunkStoreStats {
         let Self {
             static_chunks,
             temporal_chunks,
+            archived_static_chunks,
         } = *self;
-        static_chunks + temporal_chunks
+        static_chunks + temporal_chunks + archived_static_chunks
     }
 }
 
@@ -32,14 +38,17 @@ impl std::ops::Add for ChunkStoreStats {
         let Self {
             static_chunks,
             temporal_chunks,
+            archived_static_chunks,
         } = self;
 
         let static_chunks = static_chunks + rhs.static_chunks;
         let temporal_chunks = temporal_chunks + rhs.temporal_chunks;
+        let archived_static_chunks = archived_static_chunks + rhs.archived_static_chunks;
 
         Self {
             static_chunks,
             temporal_chunks,
+            archived_static_chunks,
         }
     }
 }
@@ -52,14 +61,17 @@ impl std::ops::Sub for ChunkStoreStats {
         let Self {
             static_chunks,
             temporal_chunks,
+            archived_static_chunks,
         } = self;
 
         let static_chunks = static_chunks - rhs.static_chunks;
         let temporal_chunks = temporal_chunks - rhs.temporal_chunks;
+        let archived_static_chunks = archived_static_chunks - rhs.archived_static_chunks;
 
         Self {
             static_chunks,
             temporal_chunks,
+            archived_static_chunks,
         }
     }
 }
@@ -70,6 +82,7 @@ impl ChunkStore {
         ChunkStoreStats {
             static_chunks: self.static_chunks_stats,
             temporal_chunks: self.temporal_chunks_stats,
+            archived_static_chunks: self.archived_static_chunks_stats,
         }
     }
 }
@@ -72,6 +72,15 @@ pub struct ChunkStoreConfig {
     /// The default byte threshold is set to 8MiB, which is a reasonable unit of work when e.g.
     /// sending chunks over the network.
     pub chunk_max_rows_if_unsorted: u64,
+
+    /// How many overwritten versions of each static component should be kept around?
+    ///
+    /// Re-logging static data (e.g. an updated mesh) overwrites the previous value at query time.
+    /// The overwritten versions are kept in a bounded archive so that they remain addressable, see
+    /// [`ChunkStore::static_versions`] and [`ChunkStore::pin_static_version`].
+    ///
+    /// `0` disables the archive: overwritten static data is dropped right away.
+    pub max_static_versions: u64,
     //
     // TODO(cmc): It could make sense to have time-range-based thresholds in here, since the time
     // range covered by a chunk has direct effects on A) the complexity of backward walks and
@@ -109,6 +118,10 @@ impl ChunkStoreConfig {
         chunk_max_rows: 4096,
 
         chunk_max_rows_if_unsorted: 1024,
+
+        // Static data is rarely re-logged more than a handful of times, and each version can be
+        // arbitrarily large (meshes, images…), so keep this small.
+        max_static_versions: 4,
     };
 
     /// [`Self::DEFAULT`], but with compaction entirely disabled.
@@ -131,6 +144,7 @@ impl ChunkStoreConfig {
         chunk_max_bytes: 0,
         chunk_max_rows: 0,
         chunk_max_rows_if_unsorted: 0,
+        max_static_versions: 0,
     };
 
     /// Environment variable to configure [`Self::enable_changelog`].
@@ -147,6 +161,9 @@ impl ChunkStoreConfig {
     // NOTE: Shared with the same env-var on the batcher side, for consistency.
     pub const ENV_CHUNK_MAX_ROWS_IF_UNSORTED: &'static str = "RERUN_CHUNK_MAX_ROWS_IF_UNSORTED";
 
+    /// Environment variable to configure [`Self::max_static_versions`].
+    pub const ENV_STORE_MAX_STATIC_VERSIONS: &'static str = "RERUN_STORE_MAX_STATIC_VERSIONS";
+
     /// Creates a new `ChunkStoreConfig` using the default values, optionally overridden
     /// through the environment.
     ///
@@ -159,8 +176,8 @@ impl ChunkStoreConfig {
     /// Returns a copy of `self`, overriding existing fields with values from the environment if
     /// they are present.
     ///
-    /// See [`Self::ENV_STORE_ENABLE_CHANGELOG`], [`Self::ENV_CHUNK_MAX_BYTES`], [`Self::ENV_CHUNK_MAX_ROWS`]
-    /// and [`Self::ENV_CHUNK_MAX_ROWS_IF_UNSORTED`].
+    /// See [`Self::ENV_STORE_ENABLE_CHANGELOG`], [`Self::ENV_CHUNK_MAX_BYTES`], [`Self::ENV_CHUNK_MAX_ROWS`],
+    /// [`Self::ENV_CHUNK_MAX_ROWS_IF_UNSORTED`] and [`Self::ENV_STORE_MAX_STATIC_VERSIONS`].
     pub fn apply_env(&self) -> ChunkStoreResult<Self> {
         let mut new = self.clone();
 
@@ -197,6 +214,14 @@ impl ChunkStoreConfig {
                 })?;
         }
 
+        if let Ok(s) = std::env::var(Self::ENV_STORE_MAX_STATIC_VERSIONS) {
+            new.max_static_versions = s.parse().map_err(|err| ChunkStoreError::ParseConfig {
+                name: Self::ENV_STORE_MAX_STATIC_VERSIONS,
+                value: s.clone(),
+                err: Box::new(err),
+            })?;
+        }
+
         Ok(new)
     }
 }
@@ -212,6 +237,7 @@ fn chunk_store_config() {
         std::env::set_var("RERUN_CHUNK_MAX_BYTES", "42");
         std::env::set_var("RERUN_CHUNK_MAX_ROWS", "666");
         std::env::set_var("RERUN_CHUNK_MAX_ROWS_IF_UNSORTED", "999");
+        std::env::set_var("RERUN_STORE_MAX_STATIC_VERSIONS", "3");
     };
 
     let config = ChunkStoreConfig::from_env().unwrap();
@@ -221,6 +247,7 @@ fn chunk_store_config() {
         chunk_max_bytes: 42,
         chunk_max_rows: 666,
         chunk_max_rows_if_unsorted: 999,
+        max_static_versions: 3,
     };
 
     assert_eq!(expected, config);
@@ -275,6 +302,10 @@ pub type ChunkIdPerComponent = IntMap<ComponentIdentifier, ChunkId>;
 
 pub type ChunkIdPerComponentPerEntity = IntMap<EntityPath, ChunkIdPerComponent>;
 
+pub type StaticVersionsPerComponent = IntMap<ComponentIdentifier, BTreeMap<RowId, Arc<Chunk>>>;
+
+pub type StaticVersionsPerComponentPerEntity = IntMap<EntityPath, StaticVersionsPerComponent>;
+
 pub type ChunkIdSetPerTimePerTimeline = IntMap<TimelineName, ChunkIdSetPerTime>;
 
 pub type ChunkIdSetPerTimePerTimelinePerEntity = IntMap<EntityPath, ChunkIdSetPerTimePerTimeline>;
@@ -460,6 +491,23 @@ pub struct ChunkStore {
     /// This is too costly to be computed from scratch every frame, and is required by e.g. the GC.
     pub(crate) static_chunks_stats: ChunkStoreChunkStats,
 
+    /// Previous versions of overwritten static data, indexed by the most recent [`RowId`] of that
+    /// component in each version.
+    ///
+    /// Bounded by [`ChunkStoreConfig::max_static_versions`]. These chunks are not part of any other
+    /// index: they only exist so that [`Self::pinned_static_versions`] can refer to them.
+    pub(crate) static_versions_per_entity: StaticVersionsPerComponentPerEntity,
+
+    /// Accumulated size statistics for the archived static versions that aren't also present in
+    /// the store proper (a partially overwritten chunk is both).
+    pub(crate) archived_static_chunks_stats: ChunkStoreChunkStats,
+
+    /// Static versions that were explicitly pinned, and which shadow the latest static data at
+    /// query time.
+    ///
+    /// See [`Self::pin_static_version`].
+    pub(crate) pinned_static_versions: IntMap<EntityPath, IntMap<ComponentIdentifier, RowId>>,
+
     /// Monotonically increasing ID for insertions.
     pub(crate) insert_id: u64,
 
@@ -503,6 +551,9 @@ impl Clone for ChunkStore {
             temporal_chunks_stats: self.temporal_chunks_stats,
             static_chunk_ids_per_entity: self.static_chunk_ids_per_entity.clone(),
             static_chunks_stats: self.static_chunks_stats,
+            static_versions_per_entity: self.static_versions_per_entity.clone(),
+            archived_static_chunks_stats: self.archived_static_chunks_stats,
+            pinned_static_versions: self.pinned_static_versions.clone(),
             insert_id: Default::default(),
             gc_id: Default::default(),
             event_id: Default::default(),
@@ -525,6 +576,9 @@ impl std::fmt::Display for ChunkStore {
             temporal_chunks_stats,
             static_chunk_ids_per_entity: _,
             static_chunks_stats,
+            static_versions_per_entity: _,
+            archived_static_chunks_stats,
+            pinned_static_versions: _,
             insert_id: _,
             gc_id: _,
             event_id: _,
@@ -538,7 +592,10 @@ impl std::fmt::Display for ChunkStore {
         f.write_str(&indent::indent_all_by(4, "stats: {\n"))?;
         f.write_str(&indent::indent_all_by(
             8,
-            format!("{}", *static_chunks_stats + *temporal_chunks_stats),
+            format!(
+                "{}",
+                *static_chunks_stats + *temporal_chunks_stats + *archived_static_chunks_stats
+            ),
         ))?;
         f.write_str(&indent::indent_all_by(4, "}\n"))?;
 
@@ -586,6 +643,9 @@ impl ChunkStore {
             temporal_chunks_stats: Default::default(),
             static_chunk_ids_per_entity: Default::default(),
             static_chunks_stats: Default::default(),
+            static_versions_per_entity: Default::default(),
+            archived_static_chunks_stats: Default::default(),
+            pinned_static_versions: Default::default(),
             insert_id: 0,
             gc_id: 0,
             event_id: AtomicU64::new(0),
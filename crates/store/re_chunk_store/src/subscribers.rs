@@ -44,6 +44,8 @@ pub trait ChunkStoreSubscriber: std::any::Any + Send + Sync {
     /// registered: [`ChunkStore::register_subscriber`].
     /// Or you might want to feed it [`ChunkStoreEvent`]s manually, depending on your use case.
     ///
+    /// Refer to [`ChunkStore::register_subscriber`] for the threading guarantees.
+    ///
     /// ## Example
     ///
     /// ```ignore
@@ -113,6 +115,21 @@ impl ChunkStore {
     ///
     /// If you need a specific order across multiple subscribers, embed them into an orchestrating
     /// subscriber.
+    ///
+    /// ## Threading
+    ///
+    /// [`ChunkStoreSubscriber::on_events`] is called synchronously, on the thread that wrote to the
+    /// store, before the write returns. A given subscriber is never called concurrently.
+    ///
+    /// Each call carries all the events of a single write (e.g. an insertion and the compaction it
+    /// triggered, or everything a garbage collection pass removed). Events of a given store are
+    /// always delivered in [`ChunkStoreEvent::event_id`] order.
+    ///
+    /// Subscribers hold up ingestion for as long as they run, and must not register new
+    /// subscribers, access other subscribers, or write to a store from within `on_events`: that
+    /// would deadlock.
+    /// For anything more involved, use [`ChunkStore::subscribe_events`] and process events on
+    /// your own thread instead.
     //
     // TODO(cmc): send a compacted snapshot to late registerers for bootstrapping
     pub fn register_subscriber(
@@ -68,6 +68,7 @@ impl ChunkStore {
             let row_id_range_per_component = chunk.row_id_range_per_component();
 
             let mut overwritten_chunk_ids = HashMap::default();
+            let mut overwritten_versions = Vec::new();
 
             for (component, column) in chunk.components().iter() {
                 let is_empty = column
@@ -126,6 +127,11 @@ impl ChunkStore {
                                 overwritten_chunk_ids
                                     .insert(*cur_chunk_id, cur_row_id_min_for_chunk);
                             }
+                            overwritten_versions.push((
+                                *component,
+                                *cur_chunk_id,
+                                cur_row_id_max_for_component,
+                            ));
 
                             *cur_chunk_id = chunk.id();
                         }
@@ -133,6 +139,10 @@ impl ChunkStore {
                     .or_insert_with(|| chunk.id());
             }
 
+            let archived_stats_before =
+                self.entity_archived_static_chunks_stats(chunk.entity_path());
+            self.archive_static_versions(chunk.entity_path(), overwritten_versions);
+
             self.static_chunks_stats += ChunkStoreChunkStats::from_chunk(chunk);
 
             let mut diffs = vec![ChunkStoreDiff::addition(
@@ -187,6 +197,13 @@ impl ChunkStore {
                 }
             }
 
+            // Archiving adds versions and may evict others, and chunks that were just removed
+            // from the store might live on in the archive.
+            let archived_stats_after =
+                self.entity_archived_static_chunks_stats(chunk.entity_path());
+            self.archived_static_chunks_stats -= archived_stats_before;
+            self.archived_static_chunks_stats += archived_stats_after;
+
             (Arc::clone(chunk), diffs)
         } else {
             // Temporal data: just index the chunk on every dimension of interest.
@@ -469,6 +486,7 @@ impl ChunkStore {
                 chunk_max_bytes,
                 chunk_max_rows,
                 chunk_max_rows_if_unsorted,
+                max_static_versions: _,
             } = self.config;
 
             let total_bytes = <Chunk as SizeBytes>::total_size_bytes(chunk);
@@ -494,6 +512,7 @@ impl ChunkStore {
                     chunk_max_bytes,
                     chunk_max_rows,
                     chunk_max_rows_if_unsorted,
+                    max_static_versions: _,
                 } = store.config;
 
                 *candidates_below_threshold
@@ -611,6 +630,8 @@ impl ChunkStore {
 
         let generation = self.generation();
 
+        self.archived_static_chunks_stats -= self.entity_archived_static_chunks_stats(entity_path);
+
         let Self {
             id,
             config: _,
@@ -624,12 +645,17 @@ impl ChunkStore {
             temporal_chunks_stats,
             static_chunk_ids_per_entity,
             static_chunks_stats,
+            static_versions_per_entity,
+            archived_static_chunks_stats: _, // handled above
+            pinned_static_versions,
             insert_id: _,
             gc_id: _,
             event_id,
         } = self;
 
         per_column_metadata.remove(entity_path);
+        static_versions_per_entity.remove(entity_path);
+        pinned_static_versions.remove(entity_path);
 
         let dropped_static_chunks = {
             let dropped_static_chunk_ids: BTreeSet<_> = static_chunk_ids_per_entity
@@ -1181,6 +1207,7 @@ mod tests {
                     chunk_max_bytes: u64::MAX,
                     chunk_max_rows: u64::MAX,
                     chunk_max_rows_if_unsorted: u64::MAX,
+                    max_static_versions: 0,
                 },
             );
 
@@ -1210,6 +1237,7 @@ mod tests {
                     chunk_max_bytes: u64::MAX,
                     chunk_max_rows: u64::MAX,
                     chunk_max_rows_if_unsorted: u64::MAX,
+                    max_static_versions: 0,
                 },
             );
 
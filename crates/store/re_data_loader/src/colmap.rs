@@ -0,0 +1,595 @@
+//! A module for reading [COLMAP](https://colmap.github.io/) sparse reconstructions.
+//!
+//! A reconstruction is a directory with three files, either all in text (`.txt`) or all in
+//! binary (`.bin`) form, see <https://colmap.github.io/format.html>:
+//! - `cameras`: the intrinsics of every camera.
+//! - `images`: the pose of every registered image, and its 2D keypoints.
+//! - `points3D`: the triangulated 3D points.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Errors that might happen when reading a COLMAP reconstruction.
+#[derive(thiserror::Error, Debug)]
+pub enum ColmapError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error("Invalid line {line} in {file}")]
+    InvalidLine { file: &'static str, line: usize },
+
+    #[error("Unexpected end of file while reading {0}")]
+    UnexpectedEof(&'static str),
+
+    #[error("Unknown camera model {0}")]
+    UnknownCameraModel(String),
+}
+
+/// The camera models supported by COLMAP, with the number of parameters of each.
+///
+/// The index in this list is the model id used by the binary format.
+const CAMERA_MODELS: &[(&str, usize)] = &[
+    ("SIMPLE_PINHOLE", 3),
+    ("PINHOLE", 4),
+    ("SIMPLE_RADIAL", 4),
+    ("RADIAL", 5),
+    ("OPENCV", 8),
+    ("OPENCV_FISHEYE", 8),
+    ("FULL_OPENCV", 12),
+    ("FOV", 5),
+    ("SIMPLE_RADIAL_FISHEYE", 4),
+    ("RADIAL_FISHEYE", 5),
+    ("THIN_PRISM_FISHEYE", 12),
+];
+
+/// Intrinsics of a camera.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Camera {
+    pub id: u32,
+    pub model: String,
+    pub width: u64,
+    pub height: u64,
+    pub params: Vec<f64>,
+}
+
+impl Camera {
+    /// Returns the focal length and principal point, in pixels.
+    ///
+    /// Lens distortion is ignored.
+    pub fn pinhole(&self) -> Option<([f64; 2], [f64; 2])> {
+        let single_focal_length = matches!(
+            self.model.as_str(),
+            "SIMPLE_PINHOLE"
+                | "SIMPLE_RADIAL"
+                | "RADIAL"
+                | "SIMPLE_RADIAL_FISHEYE"
+                | "RADIAL_FISHEYE"
+        );
+
+        if single_focal_length {
+            let [f, cx, cy] = self.params.get(..3)?.try_into().ok()?;
+            Some(([f, f], [cx, cy]))
+        } else {
+            let [fx, fy, cx, cy] = self.params.get(..4)?.try_into().ok()?;
+            Some(([fx, fy], [cx, cy]))
+        }
+    }
+}
+
+/// A registered image.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Image {
+    pub id: u32,
+
+    /// Rotation from world to camera space, as `[w, x, y, z]`.
+    pub rotation_wxyz: [f64; 4],
+
+    /// Translation from world to camera space.
+    pub translation: [f64; 3],
+
+    pub camera_id: u32,
+
+    /// Path of the image, relative to the image directory.
+    pub name: String,
+
+    /// 2D keypoints in pixels, with the id of the 3D point they were triangulated into, if any.
+    pub keypoints: Vec<([f64; 2], Option<u64>)>,
+}
+
+/// A triangulated point.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Point3D {
+    pub id: u64,
+    pub position: [f64; 3],
+    pub color: [u8; 3],
+
+    /// Mean reprojection error, in pixels.
+    pub error: f64,
+
+    /// Ids of the images in which this point was observed.
+    pub image_ids: Vec<u32>,
+}
+
+/// A complete sparse reconstruction.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Reconstruction {
+    pub cameras: BTreeMap<u32, Camera>,
+    pub images: BTreeMap<u32, Image>,
+    pub points: Vec<Point3D>,
+}
+
+/// Returns the directory containing the reconstruction, if `path` is one.
+///
+/// Besides the reconstruction directory itself, this also accepts the project directory created
+/// by COLMAP's automatic reconstruction, which stores the first model in `sparse/0`.
+pub fn find_model_dir(path: impl AsRef<Path>) -> Option<PathBuf> {
+    let path = path.as_ref();
+    if !path.is_dir() {
+        return None;
+    }
+
+    [
+        path.to_path_buf(),
+        path.join("sparse").join("0"),
+        path.join("sparse"),
+    ]
+    .into_iter()
+    .find(|dir| {
+        ["txt", "bin"].iter().any(|ext| {
+            dir.join(format!("cameras.{ext}")).is_file()
+                && dir.join(format!("images.{ext}")).is_file()
+        })
+    })
+}
+
+/// Check whether the provided path contains a COLMAP reconstruction.
+pub fn is_colmap_dataset(path: impl AsRef<Path>) -> bool {
+    find_model_dir(path).is_some()
+}
+
+impl Reconstruction {
+    /// Reads a reconstruction from `dir`, preferring the binary files if both forms exist.
+    ///
+    /// `points3D` is optional, since it's not needed to look at the cameras.
+    pub fn load_from_directory(dir: &Path) -> Result<Self, ColmapError> {
+        re_tracing::profile_function!();
+
+        if dir.join("cameras.bin").is_file() {
+            let points = match std::fs::read(dir.join("points3D.bin")) {
+                Ok(contents) => read_points3d_binary(&contents)?,
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+                Err(err) => return Err(err.into()),
+            };
+            Ok(Self {
+                cameras: read_cameras_binary(&std::fs::read(dir.join("cameras.bin"))?)?,
+                images: read_images_binary(&std::fs::read(dir.join("images.bin"))?)?,
+                points,
+            })
+        } else {
+            let points = match std::fs::read_to_string(dir.join("points3D.txt")) {
+                Ok(contents) => read_points3d_text(&contents)?,
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+                Err(err) => return Err(err.into()),
+            };
+            Ok(Self {
+                cameras: read_cameras_text(&std::fs::read_to_string(dir.join("cameras.txt"))?)?,
+                images: read_images_text(&std::fs::read_to_string(dir.join("images.txt"))?)?,
+                points,
+            })
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Text format
+
+/// Returns all lines that are not comments, with their (1-based) line number.
+fn data_lines(contents: &str) -> impl Iterator<Item = (usize, &str)> {
+    contents
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.starts_with('#'))
+}
+
+fn parse_values<T: std::str::FromStr>(
+    tokens: &[&str],
+    file: &'static str,
+    line: usize,
+) -> Result<Vec<T>, ColmapError> {
+    tokens
+        .iter()
+        .map(|token| token.parse())
+        .collect::<Result<_, _>>()
+        .map_err(|_err| ColmapError::InvalidLine { file, line })
+}
+
+pub fn read_cameras_text(contents: &str) -> Result<BTreeMap<u32, Camera>, ColmapError> {
+    const FILE: &str = "cameras.txt";
+
+    let mut cameras = BTreeMap::new();
+    for (line_number, line) in data_lines(contents).filter(|(_, line)| !line.is_empty()) {
+        let invalid = || ColmapError::InvalidLine {
+            file: FILE,
+            line: line_number,
+        };
+
+        let tokens = line.split_whitespace().collect::<Vec<_>>();
+        let [id, model, width, height, params @ ..] = tokens.as_slice() else {
+            return Err(invalid());
+        };
+
+        let camera = Camera {
+            id: id.parse().map_err(|_err| invalid())?,
+            model: (*model).to_owned(),
+            width: width.parse().map_err(|_err| invalid())?,
+            height: height.parse().map_err(|_err| invalid())?,
+            params: parse_values(params, FILE, line_number)?,
+        };
+        cameras.insert(camera.id, camera);
+    }
+
+    Ok(cameras)
+}
+
+pub fn read_images_text(contents: &str) -> Result<BTreeMap<u32, Image>, ColmapError> {
+    const FILE: &str = "images.txt";
+
+    let mut images = BTreeMap::new();
+
+    // Every image takes up two lines, the second of which is empty if there are no keypoints.
+    let mut lines = data_lines(contents);
+    while let Some((line_number, line)) = lines.next() {
+        if line.is_empty() {
+            continue;
+        }
+        let invalid = || ColmapError::InvalidLine {
+            file: FILE,
+            line: line_number,
+        };
+
+        let tokens = line.split_whitespace().collect::<Vec<_>>();
+        let [id, qw, qx, qy, qz, tx, ty, tz, camera_id, name @ ..] = tokens.as_slice() else {
+            return Err(invalid());
+        };
+        if name.is_empty() {
+            return Err(invalid());
+        }
+
+        let pose = parse_values::<f64>(&[*qw, *qx, *qy, *qz, *tx, *ty, *tz], FILE, line_number)?;
+
+        let mut keypoints = Vec::new();
+        if let Some((line_number, line)) = lines.next() {
+            let values = line.split_whitespace().collect::<Vec<_>>();
+            for keypoint in values.chunks(3) {
+                let [x, y, point3d_id] = keypoint else {
+                    return Err(ColmapError::InvalidLine {
+                        file: FILE,
+                        line: line_number,
+                    });
+                };
+                let [x, y] = [x, y].map(|v| v.parse::<f64>());
+                let point3d_id = point3d_id.parse::<i64>();
+                let (Ok(x), Ok(y), Ok(point3d_id)) = (x, y, point3d_id) else {
+                    return Err(ColmapError::InvalidLine {
+                        file: FILE,
+                        line: line_number,
+                    });
+                };
+                keypoints.push(([x, y], u64::try_from(point3d_id).ok()));
+            }
+        }
+
+        let image = Image {
+            id: id.parse().map_err(|_err| invalid())?,
+            rotation_wxyz: [pose[0], pose[1], pose[2], pose[3]],
+            translation: [pose[4], pose[5], pose[6]],
+            camera_id: camera_id.parse().map_err(|_err| invalid())?,
+            // Names may contain spaces.
+            name: name.join(" "),
+            keypoints,
+        };
+        images.insert(image.id, image);
+    }
+
+    Ok(images)
+}
+
+pub fn read_points3d_text(contents: &str) -> Result<Vec<Point3D>, ColmapError> {
+    const FILE: &str = "points3D.txt";
+
+    let mut points = Vec::new();
+    for (line_number, line) in data_lines(contents).filter(|(_, line)| !line.is_empty()) {
+        let invalid = || ColmapError::InvalidLine {
+            file: FILE,
+            line: line_number,
+        };
+
+        let tokens = line.split_whitespace().collect::<Vec<_>>();
+        let [id, x, y, z, r, g, b, error, track @ ..] = tokens.as_slice() else {
+            return Err(invalid());
+        };
+
+        let position = parse_values::<f64>(&[*x, *y, *z], FILE, line_number)?;
+        let color = parse_values::<u8>(&[*r, *g, *b], FILE, line_number)?;
+        let track = parse_values::<u32>(track, FILE, line_number)?;
+
+        points.push(Point3D {
+            id: id.parse().map_err(|_err| invalid())?,
+            position: [position[0], position[1], position[2]],
+            color: [color[0], color[1], color[2]],
+            error: error.parse().map_err(|_err| invalid())?,
+            // The track consists of (image id, keypoint index) pairs.
+            image_ids: track.chunks_exact(2).map(|pair| pair[0]).collect(),
+        });
+    }
+
+    Ok(points)
+}
+
+// ----------------------------------------------------------------------------
+// Binary format
+
+/// A little-endian cursor over a byte slice.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    file: &'static str,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], ColmapError> {
+        if self.bytes.len() < len {
+            return Err(ColmapError::UnexpectedEof(self.file));
+        }
+        let (head, tail) = self.bytes.split_at(len);
+        self.bytes = tail;
+        Ok(head)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], ColmapError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.take(N)?);
+        Ok(array)
+    }
+
+    fn u8(&mut self) -> Result<u8, ColmapError> {
+        Ok(self.array::<1>()?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, ColmapError> {
+        Ok(u32::from_le_bytes(self.array()?))
+    }
+
+    fn i32(&mut self) -> Result<i32, ColmapError> {
+        Ok(i32::from_le_bytes(self.array()?))
+    }
+
+    fn u64(&mut self) -> Result<u64, ColmapError> {
+        Ok(u64::from_le_bytes(self.array()?))
+    }
+
+    fn i64(&mut self) -> Result<i64, ColmapError> {
+        Ok(i64::from_le_bytes(self.array()?))
+    }
+
+    fn f64(&mut self) -> Result<f64, ColmapError> {
+        Ok(f64::from_le_bytes(self.array()?))
+    }
+
+    fn f64s<const N: usize>(&mut self) -> Result<[f64; N], ColmapError> {
+        let mut values = [0.0; N];
+        for value in &mut values {
+            *value = self.f64()?;
+        }
+        Ok(values)
+    }
+
+    /// Reads a count, guarding against absurd values in corrupt files.
+    fn count(&mut self, min_item_size: usize) -> Result<usize, ColmapError> {
+        let count = self.u64()? as usize;
+        if count.saturating_mul(min_item_size) > self.bytes.len() {
+            return Err(ColmapError::UnexpectedEof(self.file));
+        }
+        Ok(count)
+    }
+
+    fn null_terminated_string(&mut self) -> Result<String, ColmapError> {
+        let end = self
+            .bytes
+            .iter()
+            .position(|&b| b == 0)
+            .ok_or(ColmapError::UnexpectedEof(self.file))?;
+        let string = String::from_utf8_lossy(&self.bytes[..end]).into_owned();
+        self.bytes = &self.bytes[end + 1..];
+        Ok(string)
+    }
+}
+
+pub fn read_cameras_binary(contents: &[u8]) -> Result<BTreeMap<u32, Camera>, ColmapError> {
+    let mut reader = Reader {
+        bytes: contents,
+        file: "cameras.bin",
+    };
+
+    let num_cameras = reader.count(4 + 4 + 8 + 8)?;
+    let mut cameras = BTreeMap::new();
+    for _ in 0..num_cameras {
+        let id = reader.u32()?;
+        let model_id = reader.i32()?;
+        let (model, num_params) = usize::try_from(model_id)
+            .ok()
+            .and_then(|model_id| CAMERA_MODELS.get(model_id))
+            .ok_or_else(|| ColmapError::UnknownCameraModel(model_id.to_string()))?;
+        let width = reader.u64()?;
+        let height = reader.u64()?;
+        let params = (0..*num_params)
+            .map(|_| reader.f64())
+            .collect::<Result<_, _>>()?;
+
+        cameras.insert(
+            id,
+            Camera {
+                id,
+                model: (*model).to_owned(),
+                width,
+                height,
+                params,
+            },
+        );
+    }
+
+    Ok(cameras)
+}
+
+pub fn read_images_binary(contents: &[u8]) -> Result<BTreeMap<u32, Image>, ColmapError> {
+    let mut reader = Reader {
+        bytes: contents,
+        file: "images.bin",
+    };
+
+    let num_images = reader.count(4 + 7 * 8 + 4 + 1 + 8)?;
+    let mut images = BTreeMap::new();
+    for _ in 0..num_images {
+        let id = reader.u32()?;
+        let rotation_wxyz = reader.f64s()?;
+        let translation = reader.f64s()?;
+        let camera_id = reader.u32()?;
+        let name = reader.null_terminated_string()?;
+
+        let num_keypoints = reader.count(8 + 8 + 8)?;
+        let mut keypoints = Vec::with_capacity(num_keypoints);
+        for _ in 0..num_keypoints {
+            let position = reader.f64s()?;
+            let point3d_id = reader.i64()?;
+            keypoints.push((position, u64::try_from(point3d_id).ok()));
+        }
+
+        images.insert(
+            id,
+            Image {
+                id,
+                rotation_wxyz,
+                translation,
+                camera_id,
+                name,
+                keypoints,
+            },
+        );
+    }
+
+    Ok(images)
+}
+
+pub fn read_points3d_binary(contents: &[u8]) -> Result<Vec<Point3D>, ColmapError> {
+    let mut reader = Reader {
+        bytes: contents,
+        file: "points3D.bin",
+    };
+
+    let num_points = reader.count(8 + 3 * 8 + 3 + 8 + 8)?;
+    let mut points = Vec::with_capacity(num_points);
+    for _ in 0..num_points {
+        let id = reader.u64()?;
+        let position = reader.f64s()?;
+        let color = [reader.u8()?, reader.u8()?, reader.u8()?];
+        let error = reader.f64()?;
+
+        let track_length = reader.count(4 + 4)?;
+        let mut image_ids = Vec::with_capacity(track_length);
+        for _ in 0..track_length {
+            image_ids.push(reader.u32()?);
+            let _keypoint_index = reader.u32()?;
+        }
+
+        points.push(Point3D {
+            id,
+            position,
+            color,
+            error,
+            image_ids,
+        });
+    }
+
+    Ok(points)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_text() {
+        let cameras = read_cameras_text(
+            "# Camera list with one line of data per camera:
+1 SIMPLE_RADIAL 640 480 500 320 240 0.01
+2 PINHOLE 640 480 500 510 320 240
+",
+        )
+        .unwrap();
+        assert_eq!(cameras[&1].pinhole(), Some(([500.0; 2], [320.0, 240.0])));
+        assert_eq!(
+            cameras[&2].pinhole(),
+            Some(([500.0, 510.0], [320.0, 240.0]))
+        );
+
+        let images = read_images_text(
+            "# Image list with two lines of data per image:
+1 1 0 0 0 0.5 0 0 1 frame 0.png
+10 20 -1 30 40 7
+
+2 0 1 0 0 0 0 0 2 frame_1.png
+
+",
+        )
+        .unwrap();
+        assert_eq!(images.len(), 2);
+        assert_eq!(images[&1].name, "frame 0.png");
+        assert_eq!(images[&1].translation, [0.5, 0.0, 0.0]);
+        assert_eq!(
+            images[&1].keypoints,
+            vec![([10.0, 20.0], None), ([30.0, 40.0], Some(7))]
+        );
+        assert_eq!(images[&2].camera_id, 2);
+        assert!(images[&2].keypoints.is_empty());
+
+        let points = read_points3d_text("7 1 2 3 255 128 0 0.5 1 0 2 1\n").unwrap();
+        assert_eq!(points[0].color, [255, 128, 0]);
+        assert_eq!(points[0].image_ids, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_read_binary() {
+        let mut cameras = Vec::new();
+        cameras.extend_from_slice(&1u64.to_le_bytes());
+        cameras.extend_from_slice(&3u32.to_le_bytes());
+        cameras.extend_from_slice(&1i32.to_le_bytes()); // PINHOLE
+        cameras.extend_from_slice(&640u64.to_le_bytes());
+        cameras.extend_from_slice(&480u64.to_le_bytes());
+        for param in [500.0f64, 510.0, 320.0, 240.0] {
+            cameras.extend_from_slice(&param.to_le_bytes());
+        }
+        let cameras = read_cameras_binary(&cameras).unwrap();
+        assert_eq!(cameras[&3].model, "PINHOLE");
+        assert_eq!(cameras[&3].params, vec![500.0, 510.0, 320.0, 240.0]);
+
+        let mut images = Vec::new();
+        images.extend_from_slice(&1u64.to_le_bytes());
+        images.extend_from_slice(&5u32.to_le_bytes());
+        for value in [1.0f64, 0.0, 0.0, 0.0, 1.0, 2.0, 3.0] {
+            images.extend_from_slice(&value.to_le_bytes());
+        }
+        images.extend_from_slice(&3u32.to_le_bytes());
+        images.extend_from_slice(b"image.jpg\0");
+        images.extend_from_slice(&1u64.to_le_bytes());
+        images.extend_from_slice(&4.0f64.to_le_bytes());
+        images.extend_from_slice(&5.0f64.to_le_bytes());
+        images.extend_from_slice(&(-1i64).to_le_bytes());
+        let images = read_images_binary(&images).unwrap();
+        assert_eq!(images[&5].name, "image.jpg");
+        assert_eq!(images[&5].translation, [1.0, 2.0, 3.0]);
+        assert_eq!(images[&5].keypoints, vec![([4.0, 5.0], None)]);
+
+        // Counts that can't possibly fit in the file are rejected, rather than allocated.
+        assert!(read_points3d_binary(&u64::MAX.to_le_bytes()).is_err());
+    }
+}
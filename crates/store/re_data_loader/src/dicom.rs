@@ -0,0 +1,709 @@
+//! A minimal reader for [DICOM](https://www.dicomstandard.org/) files, such as the ones written by
+//! CT and MR scanners, and the assembly of their slices into volumes.
+//!
+//! Only the uncompressed little-endian transfer syntaxes are supported, which covers most exports
+//! of grayscale modalities. Compressed (encapsulated) pixel data is reported as unsupported.
+
+use std::collections::HashMap;
+
+/// Errors that might happen when reading a DICOM file.
+#[derive(thiserror::Error, Debug)]
+pub enum DicomError {
+    #[error("Unexpected end of file")]
+    UnexpectedEof,
+
+    #[error("Unsupported transfer syntax {0:?}")]
+    UnsupportedTransferSyntax(String),
+
+    #[error("Missing element {0}")]
+    MissingElement(&'static str),
+
+    #[error("Invalid value of {0}")]
+    InvalidValue(&'static str),
+
+    #[error("Unsupported image: {0}")]
+    Unsupported(String),
+
+    #[error("The slices of the series have different sizes")]
+    InconsistentSlices,
+}
+
+const IMPLICIT_VR_LITTLE_ENDIAN: &str = "1.2.840.10008.1.2";
+const EXPLICIT_VR_LITTLE_ENDIAN: &str = "1.2.840.10008.1.2.1";
+
+/// A data element tag, as `(group, element)`.
+type Tag = (u16, u16);
+
+const TRANSFER_SYNTAX_UID: Tag = (0x0002, 0x0010);
+const MODALITY: Tag = (0x0008, 0x0060);
+const SERIES_DESCRIPTION: Tag = (0x0008, 0x103E);
+const SLICE_THICKNESS: Tag = (0x0018, 0x0050);
+const SPACING_BETWEEN_SLICES: Tag = (0x0018, 0x0088);
+const SERIES_INSTANCE_UID: Tag = (0x0020, 0x000E);
+const INSTANCE_NUMBER: Tag = (0x0020, 0x0013);
+const IMAGE_POSITION_PATIENT: Tag = (0x0020, 0x0032);
+const IMAGE_ORIENTATION_PATIENT: Tag = (0x0020, 0x0037);
+const SAMPLES_PER_PIXEL: Tag = (0x0028, 0x0002);
+const PHOTOMETRIC_INTERPRETATION: Tag = (0x0028, 0x0004);
+const NUMBER_OF_FRAMES: Tag = (0x0028, 0x0008);
+const ROWS: Tag = (0x0028, 0x0010);
+const COLUMNS: Tag = (0x0028, 0x0011);
+const PIXEL_SPACING: Tag = (0x0028, 0x0030);
+const BITS_ALLOCATED: Tag = (0x0028, 0x0100);
+const BITS_STORED: Tag = (0x0028, 0x0101);
+const PIXEL_REPRESENTATION: Tag = (0x0028, 0x0103);
+const WINDOW_CENTER: Tag = (0x0028, 0x1050);
+const WINDOW_WIDTH: Tag = (0x0028, 0x1051);
+const RESCALE_INTERCEPT: Tag = (0x0028, 0x1052);
+const RESCALE_SLOPE: Tag = (0x0028, 0x1053);
+const PIXEL_DATA: Tag = (0x7FE0, 0x0010);
+
+const ITEM: Tag = (0xFFFE, 0xE000);
+const ITEM_DELIMITATION: Tag = (0xFFFE, 0xE00D);
+const SEQUENCE_DELIMITATION: Tag = (0xFFFE, 0xE0DD);
+
+const UNDEFINED_LENGTH: u32 = u32::MAX;
+
+/// Checks whether `contents` start like a DICOM file.
+pub fn has_dicom_magic(contents: &[u8]) -> bool {
+    contents.get(128..132) == Some(b"DICM")
+}
+
+/// A single image of a series, possibly made of several frames.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DicomImage {
+    pub series_instance_uid: String,
+    pub series_description: Option<String>,
+    pub modality: Option<String>,
+    pub instance_number: Option<i64>,
+
+    /// Position of the center of the first voxel, in millimeters.
+    pub position: Option<[f64; 3]>,
+
+    /// Direction cosines of the rows and of the columns.
+    pub orientation: Option<[f64; 6]>,
+
+    /// Distance between rows and between columns, in millimeters.
+    pub pixel_spacing: Option<[f64; 2]>,
+
+    /// Distance between frames, in millimeters.
+    pub slice_spacing: Option<f64>,
+
+    pub rows: usize,
+    pub columns: usize,
+    pub frames: usize,
+
+    /// Window center and width, in rescaled units.
+    pub window: Option<[f64; 2]>,
+
+    /// Slope and intercept, to go from stored values to e.g. Hounsfield units.
+    pub rescale: [f64; 2],
+
+    /// `MONOCHROME1` images display low values as white.
+    pub inverted: bool,
+
+    /// The stored values, frame by frame, row by row.
+    pub pixels: Vec<i32>,
+}
+
+/// Parses a DICOM file, see [`DicomImage`].
+///
+/// Files without the DICOM preamble are read as implicit VR little endian data sets.
+pub fn parse_dicom(contents: &[u8]) -> Result<DicomImage, DicomError> {
+    let elements = if has_dicom_magic(contents) {
+        let mut reader = Reader::new(&contents[132..], true);
+        let meta = reader.read_elements(|tag| tag.0 == 0x0002)?;
+        let transfer_syntax = meta
+            .get(&TRANSFER_SYNTAX_UID)
+            .map(|value| string(value))
+            .ok_or(DicomError::MissingElement("TransferSyntaxUID"))?;
+        let explicit = match transfer_syntax.as_str() {
+            IMPLICIT_VR_LITTLE_ENDIAN => false,
+            EXPLICIT_VR_LITTLE_ENDIAN => true,
+            _ => return Err(DicomError::UnsupportedTransferSyntax(transfer_syntax)),
+        };
+        reader.explicit = explicit;
+        reader.read_elements(|_| true)?
+    } else {
+        Reader::new(contents, false).read_elements(|_| true)?
+    };
+
+    let text = |tag: Tag| elements.get(&tag).map(|value| string(value));
+    let numbers = |tag: Tag| -> Vec<f64> {
+        text(tag)
+            .map(|text| {
+                text.split('\\')
+                    .filter_map(|value| value.trim().parse().ok())
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    let number = |tag: Tag| numbers(tag).first().copied();
+    let uint = |tag: Tag, name: &'static str| -> Result<u16, DicomError> {
+        let value = elements.get(&tag).ok_or(DicomError::MissingElement(name))?;
+        Ok(u16::from_le_bytes(
+            value
+                .get(..2)
+                .ok_or(DicomError::InvalidValue(name))?
+                .try_into()
+                .map_err(|_err| DicomError::InvalidValue(name))?,
+        ))
+    };
+
+    let samples_per_pixel = uint(SAMPLES_PER_PIXEL, "SamplesPerPixel").unwrap_or(1);
+    if samples_per_pixel != 1 {
+        return Err(DicomError::Unsupported(format!(
+            "{samples_per_pixel} samples per pixel"
+        )));
+    }
+
+    let rows = uint(ROWS, "Rows")? as usize;
+    let columns = uint(COLUMNS, "Columns")? as usize;
+    let frames = number(NUMBER_OF_FRAMES).map_or(1, |frames| frames.max(1.0) as usize);
+    let bits_allocated = uint(BITS_ALLOCATED, "BitsAllocated")?;
+    let bits_stored = uint(BITS_STORED, "BitsStored").unwrap_or(bits_allocated);
+    let signed = uint(PIXEL_REPRESENTATION, "PixelRepresentation").unwrap_or(0) == 1;
+
+    let pixel_data = elements
+        .get(&PIXEL_DATA)
+        .ok_or(DicomError::MissingElement("PixelData"))?;
+    let pixels = decode_pixels(
+        pixel_data,
+        rows * columns * frames,
+        bits_allocated,
+        bits_stored,
+        signed,
+    )?;
+
+    let window = match (number(WINDOW_CENTER), number(WINDOW_WIDTH)) {
+        (Some(center), Some(width)) if width > 0.0 => Some([center, width]),
+        _ => None,
+    };
+
+    Ok(DicomImage {
+        series_instance_uid: text(SERIES_INSTANCE_UID).unwrap_or_default(),
+        series_description: text(SERIES_DESCRIPTION).filter(|text| !text.is_empty()),
+        modality: text(MODALITY).filter(|text| !text.is_empty()),
+        instance_number: number(INSTANCE_NUMBER).map(|number| number as i64),
+        position: numbers(IMAGE_POSITION_PATIENT).try_into().ok(),
+        orientation: numbers(IMAGE_ORIENTATION_PATIENT).try_into().ok(),
+        pixel_spacing: numbers(PIXEL_SPACING).try_into().ok(),
+        slice_spacing: number(SPACING_BETWEEN_SLICES).or_else(|| number(SLICE_THICKNESS)),
+        rows,
+        columns,
+        frames,
+        window,
+        rescale: [
+            number(RESCALE_SLOPE).unwrap_or(1.0),
+            number(RESCALE_INTERCEPT).unwrap_or(0.0),
+        ],
+        inverted: text(PHOTOMETRIC_INTERPRETATION).as_deref() == Some("MONOCHROME1"),
+        pixels,
+    })
+}
+
+/// Decodes `count` stored values, taking care of the sign of values using fewer bits than allocated.
+fn decode_pixels(
+    data: &[u8],
+    count: usize,
+    bits_allocated: u16,
+    bits_stored: u16,
+    signed: bool,
+) -> Result<Vec<i32>, DicomError> {
+    let bytes_per_value = match bits_allocated {
+        8 => 1,
+        16 => 2,
+        32 => 4,
+        _ => {
+            return Err(DicomError::Unsupported(format!(
+                "{bits_allocated} bits per pixel"
+            )));
+        }
+    };
+    let data = data
+        .get(..count * bytes_per_value)
+        .ok_or(DicomError::UnexpectedEof)?;
+
+    let bits_stored = bits_stored.clamp(1, bits_allocated) as u32;
+    let mask = (u64::MAX >> (64 - bits_stored)) as u32;
+    Ok(data
+        .chunks_exact(bytes_per_value)
+        .map(|bytes| {
+            let mut value = [0; 4];
+            value[..bytes_per_value].copy_from_slice(bytes);
+            let value = u32::from_le_bytes(value) & mask;
+            if signed && value >> (bits_stored - 1) & 1 == 1 {
+                (value as i64 - (1_i64 << bits_stored)) as i32
+            } else {
+                value as i32
+            }
+        })
+        .collect())
+}
+
+/// Decodes a string value, dropping the padding.
+fn string(value: &[u8]) -> String {
+    String::from_utf8_lossy(value)
+        .trim_end_matches(['\0', ' '])
+        .trim_start()
+        .to_owned()
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+    explicit: bool,
+}
+
+impl<'a> Reader<'a> {
+    fn new(data: &'a [u8], explicit: bool) -> Self {
+        Self {
+            data,
+            pos: 0,
+            explicit,
+        }
+    }
+
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8], DicomError> {
+        let bytes = self
+            .data
+            .get(self.pos..self.pos.saturating_add(len))
+            .ok_or(DicomError::UnexpectedEof)?;
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn u16(&mut self) -> Result<u16, DicomError> {
+        let bytes = self.bytes(2)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u32(&mut self) -> Result<u32, DicomError> {
+        let bytes = self.bytes(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn peek_tag(&self) -> Option<Tag> {
+        let bytes = self.data.get(self.pos..self.pos + 4)?;
+        Some((
+            u16::from_le_bytes([bytes[0], bytes[1]]),
+            u16::from_le_bytes([bytes[2], bytes[3]]),
+        ))
+    }
+
+    /// Reads the top-level elements for which `filter` returns true, stopping at the first one
+    /// for which it doesn't. Sequences are skipped.
+    fn read_elements(
+        &mut self,
+        filter: impl Fn(Tag) -> bool,
+    ) -> Result<HashMap<Tag, &'a [u8]>, DicomError> {
+        let mut elements = HashMap::new();
+        while let Some(tag) = self.peek_tag() {
+            if !filter(tag) {
+                break;
+            }
+            let tag = (self.u16()?, self.u16()?);
+            if let Some(value) = self.read_value(tag)? {
+                elements.insert(tag, value);
+            }
+        }
+        Ok(elements)
+    }
+
+    /// Reads the value of the element with the given tag, or skips it if it's a sequence.
+    fn read_value(&mut self, tag: Tag) -> Result<Option<&'a [u8]>, DicomError> {
+        let (is_sequence, len) = if self.explicit {
+            let vr = self.bytes(2)?;
+            let has_long_length = matches!(
+                vr,
+                b"OB"
+                    | b"OD"
+                    | b"OF"
+                    | b"OL"
+                    | b"OV"
+                    | b"OW"
+                    | b"SQ"
+                    | b"SV"
+                    | b"UC"
+                    | b"UN"
+                    | b"UR"
+                    | b"UT"
+                    | b"UV"
+            );
+            let len = if has_long_length {
+                self.bytes(2)?;
+                self.u32()?
+            } else {
+                self.u16()? as u32
+            };
+            (vr == b"SQ", len)
+        } else {
+            (false, self.u32()?)
+        };
+
+        if len == UNDEFINED_LENGTH {
+            if tag == PIXEL_DATA {
+                return Err(DicomError::Unsupported("compressed pixel data".to_owned()));
+            }
+            // Only sequences can have an undefined length.
+            self.skip_sequence()?;
+            return Ok(None);
+        }
+
+        let value = self.bytes(len as usize)?;
+        Ok((!is_sequence).then_some(value))
+    }
+
+    /// Skips the items of a sequence of undefined length, up to its delimiter.
+    fn skip_sequence(&mut self) -> Result<(), DicomError> {
+        loop {
+            let tag = (self.u16()?, self.u16()?);
+            let len = self.u32()?;
+            match tag {
+                SEQUENCE_DELIMITATION => return Ok(()),
+                ITEM if len == UNDEFINED_LENGTH => self.skip_item()?,
+                ITEM => {
+                    self.bytes(len as usize)?;
+                }
+                _ => return Err(DicomError::InvalidValue("sequence item")),
+            }
+        }
+    }
+
+    /// Skips the elements of an item of undefined length, up to its delimiter.
+    fn skip_item(&mut self) -> Result<(), DicomError> {
+        loop {
+            let tag = (self.u16()?, self.u16()?);
+            if tag == ITEM_DELIMITATION {
+                self.u32()?;
+                return Ok(());
+            }
+            self.read_value(tag)?;
+        }
+    }
+}
+
+/// The voxels of a [`Volume`], with rescaling applied.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Voxels {
+    U16(Vec<u16>),
+    I16(Vec<i16>),
+    I32(Vec<i32>),
+    F32(Vec<f32>),
+}
+
+impl Voxels {
+    /// Returns the values in `range` as floats, if in bounds.
+    fn values(&self, range: std::ops::Range<usize>) -> Option<Vec<f64>> {
+        Some(match self {
+            Self::U16(voxels) => voxels.get(range)?.iter().map(|v| *v as f64).collect(),
+            Self::I16(voxels) => voxels.get(range)?.iter().map(|v| *v as f64).collect(),
+            Self::I32(voxels) => voxels.get(range)?.iter().map(|v| *v as f64).collect(),
+            Self::F32(voxels) => voxels.get(range)?.iter().map(|v| *v as f64).collect(),
+        })
+    }
+}
+
+/// The images of a series, stacked along their normal.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Volume {
+    /// Number of slices, rows, and columns.
+    pub shape: [usize; 3],
+
+    pub voxels: Voxels,
+
+    /// Distance between slices, rows, and columns, in millimeters.
+    pub spacing: [f64; 3],
+
+    /// Position of the center of the first voxel, in millimeters.
+    pub origin: [f64; 3],
+
+    /// Direction cosines of the rows and of the columns.
+    pub orientation: [f64; 6],
+
+    /// Window center and width, in rescaled units.
+    pub window: [f64; 2],
+
+    /// `MONOCHROME1` volumes display low values as white.
+    pub inverted: bool,
+}
+
+impl Volume {
+    /// Stacks the images of a series.
+    ///
+    /// Images are sorted along the normal of their orientation if they have a position, or by
+    /// instance number otherwise.
+    pub fn from_images(mut images: Vec<DicomImage>) -> Result<Self, DicomError> {
+        let first = images
+            .first()
+            .ok_or(DicomError::MissingElement("PixelData"))?;
+        let (rows, columns) = (first.rows, first.columns);
+        if images
+            .iter()
+            .any(|image| image.rows != rows || image.columns != columns)
+        {
+            return Err(DicomError::InconsistentSlices);
+        }
+
+        let orientation = first.orientation.unwrap_or([1.0, 0.0, 0.0, 0.0, 1.0, 0.0]);
+        let normal = cross(
+            [orientation[0], orientation[1], orientation[2]],
+            [orientation[3], orientation[4], orientation[5]],
+        );
+        let distance = |image: &DicomImage| image.position.map(|position| dot(position, normal));
+
+        if images.iter().all(|image| image.position.is_some()) {
+            images.sort_by(|a, b| {
+                let [a, b] = [a, b].map(|image| distance(image).unwrap_or_default());
+                a.total_cmp(&b)
+            });
+        } else {
+            images.sort_by_key(|image| image.instance_number);
+        }
+
+        // Prefer the actual distance between slices, since the thickness can differ from it.
+        let distances = images.iter().filter_map(distance).collect::<Vec<_>>();
+        let slice_spacing = distances
+            .windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .find(|spacing| *spacing > 0.0)
+            .or(images[0].slice_spacing)
+            .unwrap_or(1.0);
+        let [row_spacing, column_spacing] = images[0].pixel_spacing.unwrap_or([1.0, 1.0]);
+
+        let values = || {
+            images.iter().flat_map(|image| {
+                let [slope, intercept] = image.rescale;
+                image
+                    .pixels
+                    .iter()
+                    .map(move |value| *value as f64 * slope + intercept)
+            })
+        };
+
+        // Use the smallest type that can hold the rescaled values.
+        let is_integral = values().all(|value| value.fract() == 0.0);
+        let (min, max) = values().fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+            (min.min(value), max.max(value))
+        });
+        let voxels = if is_integral && min >= 0.0 && max <= u16::MAX as f64 {
+            Voxels::U16(values().map(|value| value as u16).collect())
+        } else if is_integral && min >= i16::MIN as f64 && max <= i16::MAX as f64 {
+            Voxels::I16(values().map(|value| value as i16).collect())
+        } else if is_integral && min >= i32::MIN as f64 && max <= i32::MAX as f64 {
+            Voxels::I32(values().map(|value| value as i32).collect())
+        } else {
+            Voxels::F32(values().map(|value| value as f32).collect())
+        };
+
+        let window = images[0].window.unwrap_or_else(|| {
+            if min < max {
+                [0.5 * (min + max), max - min]
+            } else {
+                [min, 1.0]
+            }
+        });
+
+        Ok(Self {
+            shape: [images.iter().map(|image| image.frames).sum(), rows, columns],
+            voxels,
+            spacing: [slice_spacing, row_spacing, column_spacing],
+            origin: images[0].position.unwrap_or_default(),
+            orientation,
+            window,
+            inverted: images[0].inverted,
+        })
+    }
+
+    /// Returns a slice as 8-bit grayscale pixels, using the window of the volume.
+    pub fn windowed_slice(&self, index: usize) -> Option<Vec<u8>> {
+        let [_, rows, columns] = self.shape;
+        let values = self
+            .voxels
+            .values(index * rows * columns..(index + 1) * rows * columns)?;
+
+        let [center, width] = self.window;
+        let low = center - 0.5 * width;
+        Some(
+            values
+                .iter()
+                .map(|value| {
+                    let value = ((value - low) / width).clamp(0.0, 1.0);
+                    let value = if self.inverted { 1.0 - value } else { value };
+                    (value * 255.0).round() as u8
+                })
+                .collect(),
+        )
+    }
+}
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Writes an explicit VR little endian element.
+    fn element(out: &mut Vec<u8>, (group, element): Tag, vr: &[u8; 2], value: &[u8]) {
+        out.extend(group.to_le_bytes());
+        out.extend(element.to_le_bytes());
+        out.extend(vr);
+        let mut value = value.to_vec();
+        if value.len() % 2 == 1 {
+            value.push(if vr == b"UI" { 0 } else { b' ' });
+        }
+        if matches!(vr, b"OB" | b"OW" | b"SQ") {
+            out.extend([0, 0]);
+            out.extend((value.len() as u32).to_le_bytes());
+        } else {
+            out.extend((value.len() as u16).to_le_bytes());
+        }
+        out.extend(value);
+    }
+
+    fn dicom_file(z: f64, instance_number: i64, pixels: &[i16]) -> Vec<u8> {
+        let mut out = vec![0; 128];
+        out.extend(b"DICM");
+        element(
+            &mut out,
+            TRANSFER_SYNTAX_UID,
+            b"UI",
+            EXPLICIT_VR_LITTLE_ENDIAN.as_bytes(),
+        );
+        element(&mut out, MODALITY, b"CS", b"CT");
+
+        // A sequence of undefined length, with an item of undefined length, to be skipped.
+        out.extend([0x08, 0x00, 0x15, 0x11]);
+        out.extend(b"SQ");
+        out.extend([0, 0]);
+        out.extend(UNDEFINED_LENGTH.to_le_bytes());
+        out.extend([0xFE, 0xFF, 0x00, 0xE0]);
+        out.extend(UNDEFINED_LENGTH.to_le_bytes());
+        element(&mut out, (0x0008, 0x1150), b"UI", b"1.2.3");
+        out.extend([0xFE, 0xFF, 0x0D, 0xE0, 0, 0, 0, 0]);
+        out.extend([0xFE, 0xFF, 0xDD, 0xE0, 0, 0, 0, 0]);
+
+        element(&mut out, SERIES_INSTANCE_UID, b"UI", b"1.2.840.1");
+        element(
+            &mut out,
+            INSTANCE_NUMBER,
+            b"IS",
+            instance_number.to_string().as_bytes(),
+        );
+        element(
+            &mut out,
+            IMAGE_POSITION_PATIENT,
+            b"DS",
+            format!("-10\\-20\\{z}").as_bytes(),
+        );
+        element(
+            &mut out,
+            IMAGE_ORIENTATION_PATIENT,
+            b"DS",
+            b"1\\0\\0\\0\\1\\0",
+        );
+        element(&mut out, SAMPLES_PER_PIXEL, b"US", &1_u16.to_le_bytes());
+        element(&mut out, PHOTOMETRIC_INTERPRETATION, b"CS", b"MONOCHROME2");
+        element(&mut out, ROWS, b"US", &2_u16.to_le_bytes());
+        element(&mut out, COLUMNS, b"US", &3_u16.to_le_bytes());
+        element(&mut out, PIXEL_SPACING, b"DS", b"0.5\\0.25");
+        element(&mut out, BITS_ALLOCATED, b"US", &16_u16.to_le_bytes());
+        element(&mut out, BITS_STORED, b"US", &12_u16.to_le_bytes());
+        element(&mut out, PIXEL_REPRESENTATION, b"US", &1_u16.to_le_bytes());
+        element(&mut out, WINDOW_CENTER, b"DS", b"40\\400");
+        element(&mut out, WINDOW_WIDTH, b"DS", b"400\\2000");
+        element(&mut out, RESCALE_INTERCEPT, b"DS", b"-1024");
+        element(&mut out, RESCALE_SLOPE, b"DS", b"1");
+        let pixels = pixels
+            .iter()
+            // Only the lower 12 bits are meaningful.
+            .flat_map(|value| ((*value as u16 & 0x0FFF) | 0xF000).to_le_bytes())
+            .collect::<Vec<_>>();
+        element(&mut out, PIXEL_DATA, b"OW", &pixels);
+        out
+    }
+
+    #[test]
+    fn test_parse_dicom() {
+        let image = parse_dicom(&dicom_file(2.5, 7, &[0, 1, -1, 2047, -2048, 1024])).unwrap();
+        assert_eq!(
+            image,
+            DicomImage {
+                series_instance_uid: "1.2.840.1".to_owned(),
+                series_description: None,
+                modality: Some("CT".to_owned()),
+                instance_number: Some(7),
+                position: Some([-10.0, -20.0, 2.5]),
+                orientation: Some([1.0, 0.0, 0.0, 0.0, 1.0, 0.0]),
+                pixel_spacing: Some([0.5, 0.25]),
+                slice_spacing: None,
+                rows: 2,
+                columns: 3,
+                frames: 1,
+                window: Some([40.0, 400.0]),
+                rescale: [1.0, -1024.0],
+                inverted: false,
+                pixels: vec![0, 1, -1, 2047, -2048, 1024],
+            }
+        );
+
+        assert!(matches!(
+            parse_dicom(&dicom_file(0.0, 1, &[0; 5])),
+            Err(DicomError::UnexpectedEof)
+        ));
+    }
+
+    #[test]
+    fn test_volume() {
+        // Out of order, with decreasing instance numbers.
+        let images = [(5.0, 1, [4; 6]), (2.0, 3, [0; 6]), (3.5, 2, [2; 6])]
+            .into_iter()
+            .map(|(z, instance_number, pixels)| {
+                parse_dicom(&dicom_file(z, instance_number, &pixels)).unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        let volume = Volume::from_images(images).unwrap();
+        assert_eq!(volume.shape, [3, 2, 3]);
+        assert_eq!(volume.spacing, [1.5, 0.5, 0.25]);
+        assert_eq!(volume.origin, [-10.0, -20.0, 2.0]);
+        assert_eq!(volume.window, [40.0, 400.0]);
+
+        let expected = [-1024, -1022, -1020]
+            .into_iter()
+            .flat_map(|value| [value; 6])
+            .collect::<Vec<i16>>();
+        assert_eq!(volume.voxels, Voxels::I16(expected));
+
+        let mut image = parse_dicom(&dicom_file(0.0, 1, &[0; 6])).unwrap();
+        image.columns = 2;
+        image.rows = 3;
+        assert!(matches!(
+            Volume::from_images(vec![
+                parse_dicom(&dicom_file(1.0, 2, &[0; 6])).unwrap(),
+                image
+            ]),
+            Err(DicomError::InconsistentSlices)
+        ));
+    }
+
+    #[test]
+    fn test_windowed_slice() {
+        let mut image = parse_dicom(&dicom_file(0.0, 1, &[0; 6])).unwrap();
+        image.pixels = vec![1024 - 200, 1024 - 160, 1024 + 40, 1024 + 240, 0, 2047];
+
+        let volume = Volume::from_images(vec![image]).unwrap();
+        assert_eq!(volume.windowed_slice(0), Some(vec![0, 0, 128, 255, 0, 255]));
+        assert_eq!(volume.windowed_slice(1), None);
+    }
+}
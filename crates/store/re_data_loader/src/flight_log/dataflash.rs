@@ -0,0 +1,331 @@
+//! Parser for the `ArduPilot` DataFlash (`.bin`) log format.
+
+use ahash::HashMap;
+
+use super::{FlightLog, FlightLogError, FlightLogKind, LogLevel, LogMessage, fixed_str};
+
+/// Every message starts with these two bytes, followed by the message type.
+pub(super) const HEADER: [u8; 2] = [0xA3, 0x95];
+
+/// The message type of format definitions, which is the only one that is known up front.
+const FMT_TYPE: u8 = 0x80;
+
+/// Total length of a format definition, including the three header bytes.
+const FMT_LENGTH: usize = 89;
+
+/// Labels that identify the instance of a message, e.g. which of several GPS receivers it came from.
+const INSTANCE_LABELS: &[&str] = &["I", "Instance", "C"];
+
+/// Messages that describe the log itself, and don't contain any interesting data.
+const METADATA_MESSAGES: &[&str] = &["FMT", "FMTU", "MULT", "UNIT"];
+
+#[derive(Clone, Debug, PartialEq)]
+enum Value {
+    Number(f64),
+    Text(String),
+}
+
+/// Decodes a single field, returns the value and its size in bytes.
+///
+/// Returns `None` if the type is unknown or there are not enough bytes left.
+fn decode_field(ty: u8, bytes: &[u8]) -> Option<(Option<Value>, usize)> {
+    fn le<const N: usize>(bytes: &[u8]) -> Option<[u8; N]> {
+        bytes.get(..N)?.try_into().ok()
+    }
+
+    let number = |value: f64, size: usize| Some((Some(Value::Number(value)), size));
+    let text = |size: usize| {
+        let value = fixed_str(bytes.get(..size)?);
+        Some((Some(Value::Text(value)), size))
+    };
+
+    match ty {
+        b'b' => number(*bytes.first()? as i8 as f64, 1),
+        b'B' | b'M' => number(*bytes.first()? as f64, 1),
+        b'h' => number(i16::from_le_bytes(le(bytes)?) as f64, 2),
+        b'H' => number(u16::from_le_bytes(le(bytes)?) as f64, 2),
+        b'i' => number(i32::from_le_bytes(le(bytes)?) as f64, 4),
+        b'I' => number(u32::from_le_bytes(le(bytes)?) as f64, 4),
+        b'q' => number(i64::from_le_bytes(le(bytes)?) as f64, 8),
+        b'Q' => number(u64::from_le_bytes(le(bytes)?) as f64, 8),
+        b'f' => number(f32::from_le_bytes(le(bytes)?) as f64, 4),
+        b'd' => number(f64::from_le_bytes(le(bytes)?), 8),
+
+        // Fixed point values, scaled by 100.
+        b'c' => number(i16::from_le_bytes(le(bytes)?) as f64 / 100.0, 2),
+        b'C' => number(u16::from_le_bytes(le(bytes)?) as f64 / 100.0, 2),
+        b'e' => number(i32::from_le_bytes(le(bytes)?) as f64 / 100.0, 4),
+        b'E' => number(u32::from_le_bytes(le(bytes)?) as f64 / 100.0, 4),
+
+        // Latitude or longitude in degrees, scaled by 1e7.
+        b'L' => number(i32::from_le_bytes(le(bytes)?) as f64 * 1e-7, 4),
+
+        b'n' => text(4),
+        b'N' => text(16),
+        b'Z' => text(64),
+
+        // Arrays of 32 `i16`, which are rarely used and not worth plotting.
+        b'a' => (bytes.len() >= 64).then_some((None, 64)),
+
+        _ => None,
+    }
+}
+
+/// The layout of one message type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Format {
+    name: String,
+    length: usize,
+    types: Vec<u8>,
+    labels: Vec<String>,
+}
+
+impl Format {
+    fn parse(payload: &[u8]) -> Option<(u8, Self)> {
+        let msg_type = *payload.first()?;
+        let length = *payload.get(1)? as usize;
+        let name = fixed_str(payload.get(2..6)?);
+        let types = fixed_str(payload.get(6..22)?).into_bytes();
+        let labels = fixed_str(payload.get(22..86)?)
+            .split(',')
+            .map(|label| label.to_owned())
+            .collect();
+
+        Some((
+            msg_type,
+            Self {
+                name,
+                length,
+                types,
+                labels,
+            },
+        ))
+    }
+
+    fn decode(&self, payload: &[u8]) -> Option<Vec<(&str, Value)>> {
+        let mut values = Vec::with_capacity(self.types.len());
+        let mut offset = 0;
+
+        for (ty, label) in self.types.iter().zip(&self.labels) {
+            let (value, size) = decode_field(*ty, payload.get(offset..)?)?;
+            offset += size;
+            if let Some(value) = value {
+                values.push((label.as_str(), value));
+            }
+        }
+
+        Some(values)
+    }
+}
+
+/// Parses a complete DataFlash log.
+pub fn parse_dataflash(contents: &[u8]) -> Result<FlightLog, FlightLogError> {
+    re_tracing::profile_function!();
+
+    if !contents.starts_with(&HEADER) {
+        return Err(FlightLogError::InvalidMagic("DataFlash"));
+    }
+
+    let mut log = FlightLog::new(FlightLogKind::DataFlash);
+    let mut formats: HashMap<u8, Format> = HashMap::default();
+    let mut num_skipped_bytes = 0;
+
+    let mut offset = 0;
+    while offset + 3 <= contents.len() {
+        if contents[offset..offset + 2] != HEADER {
+            // Corrupt data, e.g. after a power loss: scan for the next message.
+            offset += 1;
+            num_skipped_bytes += 1;
+            continue;
+        }
+
+        let msg_type = contents[offset + 2];
+        let length = if msg_type == FMT_TYPE {
+            FMT_LENGTH
+        } else if let Some(format) = formats.get(&msg_type) {
+            format.length
+        } else {
+            offset += 1;
+            num_skipped_bytes += 1;
+            continue;
+        };
+
+        let Some(payload) = contents.get(offset + 3..offset + length) else {
+            re_log::warn!("DataFlash log is truncated, ignoring the last message");
+            break;
+        };
+        offset += length;
+
+        if msg_type == FMT_TYPE {
+            if let Some((msg_type, format)) = Format::parse(payload) {
+                if format.length < 3 {
+                    re_log::warn!(
+                        "Ignoring DataFlash format {:?} with invalid length",
+                        format.name
+                    );
+                } else {
+                    formats.insert(msg_type, format);
+                }
+            }
+            continue;
+        }
+
+        let format = &formats[&msg_type];
+        let Some(values) = format.decode(payload) else {
+            re_log::warn_once!("Failed to decode DataFlash message {:?}", format.name);
+            continue;
+        };
+
+        let number = |label: &str| {
+            values.iter().find_map(|(l, value)| match value {
+                Value::Number(number) if *l == label => Some(*number),
+                _ => None,
+            })
+        };
+        let text = |label: &str| {
+            values.iter().find_map(|(l, value)| match value {
+                Value::Text(text) if *l == label => Some(text.clone()),
+                _ => None,
+            })
+        };
+
+        let timestamp_us = number("TimeUS").or_else(|| number("TimeMS").map(|ms| ms * 1000.0));
+
+        match format.name.as_str() {
+            "PARM" => {
+                if let (Some(name), Some(value)) = (text("Name"), number("Value")) {
+                    log.parameters.insert(name, value);
+                }
+            }
+
+            "MSG" => {
+                if let Some(text) = text("Message") {
+                    log.messages.push(LogMessage {
+                        timestamp_us: timestamp_us.unwrap_or_default() as u64,
+                        level: LogLevel::Info,
+                        text,
+                    });
+                }
+            }
+
+            name if METADATA_MESSAGES.contains(&name) => {}
+
+            name => {
+                let Some(timestamp_us) = timestamp_us else {
+                    continue;
+                };
+
+                let instance_label = INSTANCE_LABELS.iter().copied().find(|label| {
+                    format
+                        .labels
+                        .iter()
+                        .zip(&format.types)
+                        .any(|(l, ty)| l == label && matches!(ty, b'B' | b'b'))
+                });
+
+                let topic = match instance_label.and_then(number) {
+                    Some(instance) if instance > 0.0 => format!("{name}_{instance}"),
+                    _ => name.to_owned(),
+                };
+
+                log.topics.entry(topic).or_default().push_row(
+                    timestamp_us as u64,
+                    values.iter().filter_map(|(label, value)| match value {
+                        Value::Number(number)
+                            if !matches!(*label, "TimeUS" | "TimeMS")
+                                && Some(*label) != instance_label =>
+                        {
+                            Some((label.to_string(), *number))
+                        }
+                        _ => None,
+                    }),
+                );
+            }
+        }
+    }
+
+    if num_skipped_bytes > 0 {
+        re_log::warn!("Skipped {num_skipped_bytes} bytes of corrupt data in DataFlash log");
+    }
+
+    Ok(log)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fmt_message(msg_type: u8, length: u8, name: &str, types: &str, labels: &str) -> Vec<u8> {
+        fn padded(s: &str, len: usize) -> Vec<u8> {
+            let mut bytes = s.as_bytes().to_vec();
+            bytes.resize(len, 0);
+            bytes
+        }
+
+        let mut bytes = HEADER.to_vec();
+        bytes.extend([FMT_TYPE, msg_type, length]);
+        bytes.extend(padded(name, 4));
+        bytes.extend(padded(types, 16));
+        bytes.extend(padded(labels, 64));
+        assert_eq!(bytes.len(), FMT_LENGTH);
+        bytes
+    }
+
+    #[test]
+    fn test_parse_dataflash() {
+        let mut file = fmt_message(
+            FMT_TYPE,
+            FMT_LENGTH as u8,
+            "FMT",
+            "BBnNZ",
+            "Type,Length,Name,Format,Columns",
+        );
+        file.extend(fmt_message(
+            0x81,
+            3 + 8 + 1 + 4 + 4 + 2,
+            "GPS",
+            "QBLLc",
+            "TimeUS,I,Lat,Lng,Spd",
+        ));
+        file.extend(fmt_message(0x82, 3 + 16 + 4, "PARM", "Nf", "Name,Value"));
+
+        let gps = |instance: u8, lat: i32| {
+            let mut bytes = HEADER.to_vec();
+            bytes.push(0x81);
+            bytes.extend_from_slice(&5u64.to_le_bytes());
+            bytes.push(instance);
+            bytes.extend_from_slice(&lat.to_le_bytes());
+            bytes.extend_from_slice(&85_000_000i32.to_le_bytes());
+            bytes.extend_from_slice(&250i16.to_le_bytes());
+            bytes
+        };
+        file.extend(gps(0, 473_000_000));
+        // Garbage in between messages is skipped.
+        file.extend([0xA3, 0x00, 0x12]);
+        file.extend(gps(1, 474_000_000));
+
+        let mut parm = HEADER.to_vec();
+        parm.push(0x82);
+        let mut name = b"ARMING_CHECK".to_vec();
+        name.resize(16, 0);
+        parm.extend(name);
+        parm.extend_from_slice(&1.0f32.to_le_bytes());
+        file.extend(parm);
+
+        let log = parse_dataflash(&file).unwrap();
+
+        assert_eq!(log.kind, FlightLogKind::DataFlash);
+        assert_eq!(log.parameters.get("ARMING_CHECK"), Some(&1.0));
+        assert_eq!(log.topics.keys().collect::<Vec<_>>(), vec!["GPS", "GPS_1"]);
+
+        let gps = &log.topics["GPS"];
+        assert_eq!(gps.timestamps_us, vec![5]);
+        assert_eq!(
+            gps.fields.keys().collect::<Vec<_>>(),
+            vec!["Lat", "Lng", "Spd"]
+        );
+        assert!((gps.field("Lat").unwrap()[0] - 47.3).abs() < 1e-9);
+        assert!((gps.field("Spd").unwrap()[0] - 2.5).abs() < 1e-9);
+        assert!((log.topics["GPS_1"].field("Lat").unwrap()[0] - 47.4).abs() < 1e-9);
+    }
+}
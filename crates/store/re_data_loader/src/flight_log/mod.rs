@@ -0,0 +1,212 @@
+//! A module for parsing drone autopilot flight logs.
+//!
+//! Two formats are supported:
+//! - [ULog](https://docs.px4.io/main/en/dev_log/ulog_file_format.html), written by PX4 (`.ulg`).
+//! - [DataFlash](https://ardupilot.org/dev/docs/code-overview-adding-a-new-log-message.html),
+//!   written by `ArduPilot` (`.bin`).
+//!
+//! Both formats are self-describing: they first define the layout of every message type, followed
+//! by a stream of (timestamped) messages. Both are parsed into the same [`FlightLog`] representation,
+//! where all numeric fields are converted to `f64`.
+
+mod dataflash;
+mod ulog;
+
+use std::collections::BTreeMap;
+
+pub use self::{dataflash::parse_dataflash, ulog::parse_ulog};
+
+/// Errors that might happen when parsing a flight log.
+#[derive(thiserror::Error, Debug)]
+pub enum FlightLogError {
+    #[error("Not a {0} file")]
+    InvalidMagic(&'static str),
+
+    #[error("Unexpected end of file while reading {0}")]
+    UnexpectedEof(&'static str),
+
+    #[error("Invalid format definition {definition:?}: {reason}")]
+    InvalidFormat { definition: String, reason: String },
+}
+
+/// The kind of flight log, based on its contents.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FlightLogKind {
+    /// PX4 `ULog`.
+    ULog,
+
+    /// `ArduPilot` DataFlash log.
+    DataFlash,
+}
+
+impl FlightLogKind {
+    /// Guesses the kind of flight log from the first bytes of a file.
+    pub fn from_contents(contents: &[u8]) -> Option<Self> {
+        if contents.starts_with(ulog::MAGIC) {
+            Some(Self::ULog)
+        } else if contents.starts_with(&dataflash::HEADER) {
+            Some(Self::DataFlash)
+        } else {
+            None
+        }
+    }
+}
+
+/// All numeric fields of a single message type, stored column-wise.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Topic {
+    /// Time since boot in microseconds, one per row.
+    pub timestamps_us: Vec<u64>,
+
+    /// The values of each field, all of them have the same length as `timestamps_us`.
+    pub fields: BTreeMap<String, Vec<f64>>,
+}
+
+impl Topic {
+    /// Returns the values of the given field, if present.
+    pub fn field(&self, name: &str) -> Option<&[f64]> {
+        self.fields.get(name).map(|values| values.as_slice())
+    }
+
+    fn push_row(&mut self, timestamp_us: u64, values: impl IntoIterator<Item = (String, f64)>) {
+        let row = self.timestamps_us.len();
+        self.timestamps_us.push(timestamp_us);
+
+        for (name, value) in values {
+            let column = self.fields.entry(name).or_default();
+            // Fields that appear late are padded, so that all columns stay aligned.
+            column.resize(row, f64::NAN);
+            column.push(value);
+        }
+
+        for column in self.fields.values_mut() {
+            column.resize(row + 1, f64::NAN);
+        }
+    }
+}
+
+/// Severity of a [`LogMessage`], following the syslog levels used by both autopilots.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Emergency,
+    Alert,
+    Critical,
+    Error,
+    Warning,
+    Notice,
+    Info,
+    Debug,
+}
+
+impl LogLevel {
+    fn from_syslog(level: u8) -> Self {
+        match level {
+            0 => Self::Emergency,
+            1 => Self::Alert,
+            2 => Self::Critical,
+            3 => Self::Error,
+            4 => Self::Warning,
+            5 => Self::Notice,
+            6 => Self::Info,
+            _ => Self::Debug,
+        }
+    }
+}
+
+/// A text message emitted by the autopilot.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LogMessage {
+    pub timestamp_us: u64,
+    pub level: LogLevel,
+    pub text: String,
+}
+
+/// The contents of a flight log.
+#[derive(Clone, Debug)]
+pub struct FlightLog {
+    pub kind: FlightLogKind,
+
+    /// All message types that carry a timestamp, keyed by name.
+    ///
+    /// Multiple instances of the same message type (e.g. two GPS receivers) get a `_<instance>` suffix,
+    /// except for the first one.
+    pub topics: BTreeMap<String, Topic>,
+
+    /// The final value of each parameter.
+    pub parameters: BTreeMap<String, f64>,
+
+    /// Free-form key-value information about the vehicle and the log.
+    pub info: BTreeMap<String, String>,
+
+    /// Text messages, in the order they were logged.
+    pub messages: Vec<LogMessage>,
+}
+
+impl FlightLog {
+    fn new(kind: FlightLogKind) -> Self {
+        Self {
+            kind,
+            topics: Default::default(),
+            parameters: Default::default(),
+            info: Default::default(),
+            messages: Default::default(),
+        }
+    }
+
+    /// Parses a flight log, detecting its kind from the contents.
+    pub fn parse(contents: &[u8]) -> Result<Self, FlightLogError> {
+        match FlightLogKind::from_contents(contents) {
+            Some(FlightLogKind::ULog) => parse_ulog(contents),
+            Some(FlightLogKind::DataFlash) => parse_dataflash(contents),
+            None => Err(FlightLogError::InvalidMagic("flight log")),
+        }
+    }
+}
+
+/// A little-endian cursor over a byte slice.
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    fn take(&mut self, len: usize, what: &'static str) -> Result<&'a [u8], FlightLogError> {
+        if self.bytes.len() < len {
+            return Err(FlightLogError::UnexpectedEof(what));
+        }
+        let (head, tail) = self.bytes.split_at(len);
+        self.bytes = tail;
+        Ok(head)
+    }
+
+    fn array<const N: usize>(&mut self, what: &'static str) -> Result<[u8; N], FlightLogError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.take(N, what)?);
+        Ok(array)
+    }
+
+    fn u8(&mut self, what: &'static str) -> Result<u8, FlightLogError> {
+        Ok(self.array::<1>(what)?[0])
+    }
+
+    fn u16(&mut self, what: &'static str) -> Result<u16, FlightLogError> {
+        Ok(u16::from_le_bytes(self.array(what)?))
+    }
+
+    fn u64(&mut self, what: &'static str) -> Result<u64, FlightLogError> {
+        Ok(u64::from_le_bytes(self.array(what)?))
+    }
+}
+
+/// Decodes a fixed-size, zero-padded string.
+fn fixed_str(bytes: &[u8]) -> String {
+    let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..end]).trim().to_owned()
+}
@@ -0,0 +1,468 @@
+//! Parser for the PX4 [ULog](https://docs.px4.io/main/en/dev_log/ulog_file_format.html) format.
+
+use ahash::HashMap;
+
+use super::{FlightLog, FlightLogError, FlightLogKind, LogLevel, LogMessage, Reader, fixed_str};
+
+/// The first bytes of every `ULog` file, followed by a version byte and a timestamp.
+pub(super) const MAGIC: &[u8] = b"ULog\x01\x12\x35";
+
+/// Nested message formats can't be arbitrarily deep, this guards against cyclic definitions.
+const MAX_NESTING_DEPTH: usize = 16;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Primitive {
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    I64,
+    U64,
+    F32,
+    F64,
+    Bool,
+    Char,
+}
+
+impl Primitive {
+    fn parse(type_name: &str) -> Option<Self> {
+        Some(match type_name {
+            "int8_t" => Self::I8,
+            "uint8_t" => Self::U8,
+            "int16_t" => Self::I16,
+            "uint16_t" => Self::U16,
+            "int32_t" => Self::I32,
+            "uint32_t" => Self::U32,
+            "int64_t" => Self::I64,
+            "uint64_t" => Self::U64,
+            "float" => Self::F32,
+            "double" => Self::F64,
+            "bool" => Self::Bool,
+            "char" => Self::Char,
+            _ => return None,
+        })
+    }
+
+    fn size(self) -> usize {
+        match self {
+            Self::I8 | Self::U8 | Self::Bool | Self::Char => 1,
+            Self::I16 | Self::U16 => 2,
+            Self::I32 | Self::U32 | Self::F32 => 4,
+            Self::I64 | Self::U64 | Self::F64 => 8,
+        }
+    }
+
+    /// Reads a value, `bytes` must be at least [`Self::size`] long.
+    fn read(self, bytes: &[u8]) -> f64 {
+        fn le<const N: usize>(bytes: &[u8]) -> [u8; N] {
+            let mut array = [0; N];
+            array.copy_from_slice(&bytes[..N]);
+            array
+        }
+
+        match self {
+            Self::I8 => bytes[0] as i8 as f64,
+            Self::U8 | Self::Bool | Self::Char => bytes[0] as f64,
+            Self::I16 => i16::from_le_bytes(le(bytes)) as f64,
+            Self::U16 => u16::from_le_bytes(le(bytes)) as f64,
+            Self::I32 => i32::from_le_bytes(le(bytes)) as f64,
+            Self::U32 => u32::from_le_bytes(le(bytes)) as f64,
+            Self::I64 => i64::from_le_bytes(le(bytes)) as f64,
+            Self::U64 => u64::from_le_bytes(le(bytes)) as f64,
+            Self::F32 => f32::from_le_bytes(le(bytes)) as f64,
+            Self::F64 => f64::from_le_bytes(le(bytes)),
+        }
+    }
+}
+
+/// A single field as written in a format definition, e.g. `float[4] q`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct FieldDefinition {
+    type_name: String,
+    array_len: Option<usize>,
+    name: String,
+}
+
+/// Parses a format definition of the form `message_name:type0 field0;type1[2] field1;`.
+fn parse_format(definition: &str) -> Result<(String, Vec<FieldDefinition>), FlightLogError> {
+    let invalid = |reason: &str| FlightLogError::InvalidFormat {
+        definition: definition.to_owned(),
+        reason: reason.to_owned(),
+    };
+
+    let (name, fields) = definition
+        .split_once(':')
+        .ok_or_else(|| invalid("missing ':'"))?;
+
+    let fields = fields
+        .split(';')
+        .filter(|field| !field.trim().is_empty())
+        .map(|field| {
+            let (ty, name) = field
+                .trim()
+                .rsplit_once(' ')
+                .ok_or_else(|| invalid("field without a type"))?;
+
+            let (type_name, array_len) = if let Some((ty, len)) = ty.split_once('[') {
+                let len = len
+                    .trim_end_matches(']')
+                    .parse()
+                    .map_err(|_err| invalid("invalid array length"))?;
+                (ty, Some(len))
+            } else {
+                (ty, None)
+            };
+
+            Ok(FieldDefinition {
+                type_name: type_name.to_owned(),
+                array_len,
+                name: name.to_owned(),
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((name.to_owned(), fields))
+}
+
+/// A primitive field at a fixed offset, with nested formats and arrays flattened out.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct FlatField {
+    name: String,
+    primitive: Primitive,
+    offset: usize,
+}
+
+/// Flattens the format called `format_name`, returning its total size in bytes.
+fn flatten(
+    formats: &HashMap<String, Vec<FieldDefinition>>,
+    format_name: &str,
+    prefix: &str,
+    base_offset: usize,
+    depth: usize,
+    out: &mut Vec<FlatField>,
+) -> Result<usize, FlightLogError> {
+    let invalid = |reason: String| FlightLogError::InvalidFormat {
+        definition: format_name.to_owned(),
+        reason,
+    };
+
+    if depth > MAX_NESTING_DEPTH {
+        return Err(invalid("nested too deeply".to_owned()));
+    }
+
+    let fields = formats
+        .get(format_name)
+        .ok_or_else(|| invalid("unknown format".to_owned()))?;
+
+    let mut offset = base_offset;
+    for field in fields {
+        for index in 0..field.array_len.unwrap_or(1) {
+            let name = if field.array_len.is_some() {
+                format!("{prefix}{}[{index}]", field.name)
+            } else {
+                format!("{prefix}{}", field.name)
+            };
+
+            if let Some(primitive) = Primitive::parse(&field.type_name) {
+                // Padding and strings can't be plotted.
+                if !field.name.starts_with("_padding") && primitive != Primitive::Char {
+                    out.push(FlatField {
+                        name,
+                        primitive,
+                        offset,
+                    });
+                }
+                offset += primitive.size();
+            } else {
+                offset += flatten(
+                    formats,
+                    &field.type_name,
+                    &format!("{name}."),
+                    offset,
+                    depth + 1,
+                    out,
+                )?;
+            }
+        }
+    }
+
+    Ok(offset - base_offset)
+}
+
+struct Subscription {
+    topic: String,
+    timestamp: FlatField,
+    fields: Vec<FlatField>,
+}
+
+/// Parses a complete `ULog` file.
+pub fn parse_ulog(contents: &[u8]) -> Result<FlightLog, FlightLogError> {
+    re_tracing::profile_function!();
+
+    let mut reader = Reader::new(contents);
+    if reader.take(MAGIC.len(), "header")? != MAGIC {
+        return Err(FlightLogError::InvalidMagic("ULog"));
+    }
+    let _version = reader.u8("header")?;
+    let _start_timestamp_us = reader.u64("header")?;
+
+    let mut log = FlightLog::new(FlightLogKind::ULog);
+    let mut formats = HashMap::default();
+    let mut subscriptions: HashMap<u16, Subscription> = HashMap::default();
+
+    while !reader.is_empty() {
+        let (Ok(msg_size), Ok(msg_type)) =
+            (reader.u16("message header"), reader.u8("message header"))
+        else {
+            re_log::warn!("ULog file is truncated, ignoring the last message");
+            break;
+        };
+
+        let Ok(payload) = reader.take(msg_size as usize, "message") else {
+            // Logs of crashed vehicles are often truncated, keep everything we got so far.
+            re_log::warn!("ULog file is truncated, ignoring the last message");
+            break;
+        };
+        let mut msg = Reader::new(payload);
+
+        match msg_type {
+            // Format definition.
+            b'F' => match parse_format(&String::from_utf8_lossy(payload)) {
+                Ok((name, fields)) => {
+                    formats.insert(name, fields);
+                }
+                Err(err) => re_log::warn!("Ignoring ULog format: {err}"),
+            },
+
+            // Subscription to a message type.
+            b'A' => {
+                let multi_id = msg.u8("subscription")?;
+                let msg_id = msg.u16("subscription")?;
+                let format_name = String::from_utf8_lossy(msg.bytes).into_owned();
+
+                let mut fields = Vec::new();
+                if let Err(err) = flatten(&formats, &format_name, "", 0, 0, &mut fields) {
+                    re_log::warn!("Ignoring ULog subscription: {err}");
+                    continue;
+                }
+
+                let Some(timestamp_index) = fields.iter().position(|f| f.name == "timestamp")
+                else {
+                    re_log::warn_once!("Ignoring ULog message {format_name:?} without timestamp");
+                    continue;
+                };
+                let timestamp = fields.remove(timestamp_index);
+
+                let topic = if multi_id == 0 {
+                    format_name
+                } else {
+                    format!("{format_name}_{multi_id}")
+                };
+
+                subscriptions.insert(
+                    msg_id,
+                    Subscription {
+                        topic,
+                        timestamp,
+                        fields,
+                    },
+                );
+            }
+
+            // Logged data.
+            b'D' => {
+                let msg_id = msg.u16("data")?;
+                let Some(subscription) = subscriptions.get(&msg_id) else {
+                    continue;
+                };
+
+                let data = msg.bytes;
+                let read = |field: &FlatField| {
+                    data.get(field.offset..field.offset + field.primitive.size())
+                        .map(|bytes| field.primitive.read(bytes))
+                };
+
+                let Some(timestamp_us) = read(&subscription.timestamp) else {
+                    continue;
+                };
+
+                log.topics
+                    .entry(subscription.topic.clone())
+                    .or_default()
+                    .push_row(
+                        timestamp_us as u64,
+                        subscription
+                            .fields
+                            .iter()
+                            .filter_map(|field| Some((field.name.clone(), read(field)?))),
+                    );
+            }
+
+            // Parameter.
+            b'P' => {
+                let key_len = msg.u8("parameter")? as usize;
+                let key = String::from_utf8_lossy(msg.take(key_len, "parameter")?).into_owned();
+                let Some((ty, name)) = key.split_once(' ') else {
+                    continue;
+                };
+                if let Some(primitive) = Primitive::parse(ty)
+                    && let Ok(value) = msg.take(primitive.size(), "parameter")
+                {
+                    log.parameters
+                        .insert(name.to_owned(), primitive.read(value));
+                }
+            }
+
+            // Information message.
+            b'I' => {
+                let key_len = msg.u8("info")? as usize;
+                let key = String::from_utf8_lossy(msg.take(key_len, "info")?).into_owned();
+                let Some((ty, name)) = key.split_once(' ') else {
+                    continue;
+                };
+
+                let value = if ty.starts_with("char") {
+                    fixed_str(msg.bytes)
+                } else if let Some(primitive) = Primitive::parse(ty)
+                    && msg.bytes.len() >= primitive.size()
+                {
+                    primitive.read(msg.bytes).to_string()
+                } else {
+                    continue;
+                };
+                log.info.insert(name.to_owned(), value);
+            }
+
+            // Logged string, optionally tagged.
+            b'L' | b'C' => {
+                let level = msg.u8("logged string")?;
+                if msg_type == b'C' {
+                    let _tag = msg.u16("logged string")?;
+                }
+                let timestamp_us = msg.u64("logged string")?;
+
+                log.messages.push(LogMessage {
+                    timestamp_us,
+                    // Levels are stored as ASCII digits.
+                    level: LogLevel::from_syslog(level.wrapping_sub(b'0')),
+                    text: fixed_str(msg.bytes),
+                });
+            }
+
+            // Flag bits, multi-info, default parameters, sync, dropouts, unsubscriptions, …
+            _ => {}
+        }
+    }
+
+    Ok(log)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(msg_type: u8, payload: &[u8]) -> Vec<u8> {
+        let mut bytes = (payload.len() as u16).to_le_bytes().to_vec();
+        bytes.push(msg_type);
+        bytes.extend_from_slice(payload);
+        bytes
+    }
+
+    #[test]
+    fn test_parse_format() {
+        let (name, fields) =
+            parse_format("vehicle_attitude:uint64_t timestamp;float[4] q;").unwrap();
+        assert_eq!(name, "vehicle_attitude");
+        assert_eq!(
+            fields,
+            vec![
+                FieldDefinition {
+                    type_name: "uint64_t".to_owned(),
+                    array_len: None,
+                    name: "timestamp".to_owned(),
+                },
+                FieldDefinition {
+                    type_name: "float".to_owned(),
+                    array_len: Some(4),
+                    name: "q".to_owned(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_ulog() {
+        let mut file = MAGIC.to_vec();
+        file.push(1);
+        file.extend_from_slice(&0u64.to_le_bytes());
+
+        file.extend(message(b'F', b"vec:float x;uint8_t[2] _padding0;"));
+        file.extend(message(
+            b'F',
+            b"sensor:uint64_t timestamp;vec v;int16_t[2] raw;",
+        ));
+
+        let mut param = vec![b"float GAIN".len() as u8];
+        param.extend_from_slice(b"float GAIN");
+        param.extend_from_slice(&1.5f32.to_le_bytes());
+        file.extend(message(b'P', &param));
+
+        let mut subscribe = vec![1];
+        subscribe.extend_from_slice(&7u16.to_le_bytes());
+        subscribe.extend_from_slice(b"sensor");
+        file.extend(message(b'A', &subscribe));
+
+        let mut data = 7u16.to_le_bytes().to_vec();
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        data.extend_from_slice(&2.0f32.to_le_bytes());
+        data.extend_from_slice(&[0, 0]);
+        data.extend_from_slice(&(-3i16).to_le_bytes());
+        data.extend_from_slice(&4i16.to_le_bytes());
+        file.extend(message(b'D', &data));
+
+        let mut logged = vec![b'4'];
+        logged.extend_from_slice(&2_000u64.to_le_bytes());
+        logged.extend_from_slice(b"low battery");
+        file.extend(message(b'L', &logged));
+
+        let log = parse_ulog(&file).unwrap();
+
+        assert_eq!(log.kind, FlightLogKind::ULog);
+        assert_eq!(log.parameters.get("GAIN"), Some(&1.5));
+
+        let topic = &log.topics["sensor_1"];
+        assert_eq!(topic.timestamps_us, vec![1_000]);
+        assert_eq!(
+            topic.fields.keys().collect::<Vec<_>>(),
+            vec!["raw[0]", "raw[1]", "v.x"]
+        );
+        assert_eq!(topic.field("v.x"), Some([2.0].as_slice()));
+        assert_eq!(topic.field("raw[0]"), Some([-3.0].as_slice()));
+        assert_eq!(topic.field("raw[1]"), Some([4.0].as_slice()));
+
+        assert_eq!(
+            log.messages,
+            vec![LogMessage {
+                timestamp_us: 2_000,
+                level: LogLevel::Warning,
+                text: "low battery".to_owned(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_cyclic_format() {
+        let mut formats = HashMap::default();
+        formats.insert(
+            "a".to_owned(),
+            vec![FieldDefinition {
+                type_name: "a".to_owned(),
+                array_len: None,
+                name: "inner".to_owned(),
+            }],
+        );
+        assert!(flatten(&formats, "a", "", 0, 0, &mut Vec::new()).is_err());
+    }
+}
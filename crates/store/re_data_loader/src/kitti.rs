@@ -0,0 +1,313 @@
+//! A module for reading drives of the [KITTI raw](https://www.cvlibs.net/datasets/kitti/raw_data.php)
+//! dataset.
+//!
+//! A drive is a directory such as `2011_09_26/2011_09_26_drive_0001_sync`, containing:
+//! - `image_00` … `image_03`: the grayscale and color cameras, as `data/*.png`.
+//! - `velodyne_points`: the LiDAR sweeps, as `data/*.bin`.
+//! - `oxts`: the GPS/IMU measurements.
+//! - `tracklet_labels.xml`: the annotated objects, if any.
+//!
+//! Every sensor directory also contains a `timestamps.txt`, with one line per frame.
+//! The calibration files (`calib_cam_to_cam.txt`, `calib_velo_to_cam.txt`) live next to the drive.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Errors that might happen when reading a KITTI drive.
+#[derive(thiserror::Error, Debug)]
+pub enum KittiError {
+    #[error(transparent)]
+    Xml(#[from] roxmltree::Error),
+
+    #[error("Missing element <{0}> in tracklets")]
+    MissingElement(&'static str),
+
+    #[error("Invalid value {0:?} in tracklets")]
+    InvalidValue(String),
+}
+
+/// The camera directories of a drive.
+pub const CAMERAS: &[&str] = &["image_00", "image_01", "image_02", "image_03"];
+
+/// The LiDAR directory of a drive.
+pub const VELODYNE: &str = "velodyne_points";
+
+/// Checks whether `path` is the directory of a KITTI raw drive.
+pub fn is_kitti_drive(path: impl AsRef<Path>) -> bool {
+    let path = path.as_ref();
+    path.join("oxts").is_dir()
+        && std::iter::once(VELODYNE)
+            .chain(CAMERAS.iter().copied())
+            .any(|sensor| path.join(sensor).join("data").is_dir())
+}
+
+/// Parses a calibration file, made of `key: values…` lines.
+///
+/// Entries whose values aren't numbers (e.g. `calib_time`) are skipped.
+pub fn parse_calibration(text: &str) -> BTreeMap<String, Vec<f64>> {
+    text.lines()
+        .filter_map(|line| {
+            let (key, values) = line.split_once(':')?;
+            let values = values
+                .split_whitespace()
+                .map(|value| value.parse().ok())
+                .collect::<Option<Vec<f64>>>()?;
+            Some((key.trim().to_owned(), values))
+        })
+        .collect()
+}
+
+/// Parses a `timestamps.txt` file, returning one timestamp in nanoseconds since epoch per line.
+pub fn parse_timestamps(text: &str) -> Vec<Option<i64>> {
+    text.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(parse_timestamp)
+        .collect()
+}
+
+/// Parses a timestamp such as `2011-09-26 13:02:25.964389445`, which is in UTC.
+pub fn parse_timestamp(text: &str) -> Option<i64> {
+    let (date, time) = text.trim().split_once(' ')?;
+
+    let mut date = date.splitn(3, '-').map(|value| value.parse::<i64>().ok());
+    let (year, month, day) = (date.next()??, date.next()??, date.next()??);
+
+    let (time, fraction) = time.split_once('.').unwrap_or((time, ""));
+    let mut time = time.splitn(3, ':').map(|value| value.parse::<i64>().ok());
+    let (hours, minutes, seconds) = (time.next()??, time.next()??, time.next()??);
+
+    // Right-pad the fraction to nanoseconds.
+    let nanos = if fraction.is_empty() {
+        0
+    } else {
+        let digits = fraction.get(..fraction.len().min(9))?;
+        digits.parse::<i64>().ok()? * 10_i64.pow(9 - digits.len() as u32)
+    };
+
+    let days = days_from_civil(year, month, day);
+    let seconds = ((days * 24 + hours) * 60 + minutes) * 60 + seconds;
+    Some(seconds * 1_000_000_000 + nanos)
+}
+
+/// Number of days since 1970-01-01 of a date in the proleptic Gregorian calendar.
+///
+/// See <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Parses a LiDAR sweep, made of little-endian `x, y, z, reflectance` floats.
+pub fn parse_velodyne(bytes: &[u8]) -> Vec<[f32; 4]> {
+    bytes
+        .chunks_exact(16)
+        .map(|point| {
+            std::array::from_fn(|i| {
+                f32::from_le_bytes([
+                    point[i * 4],
+                    point[i * 4 + 1],
+                    point[i * 4 + 2],
+                    point[i * 4 + 3],
+                ])
+            })
+        })
+        .collect()
+}
+
+/// The pose of a tracked object in a given frame, in the LiDAR frame.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TrackletPose {
+    /// Center of the bottom face of the box.
+    pub translation: [f64; 3],
+
+    /// Roll, pitch, and yaw, in radians.
+    pub rotation: [f64; 3],
+}
+
+/// An object tracked over consecutive frames.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tracklet {
+    /// E.g. `Car`, `Pedestrian`, or `Cyclist`.
+    pub object_type: String,
+
+    /// Height, width, and length of the box, in meters.
+    pub size: [f64; 3],
+
+    /// The frame of the first pose.
+    pub first_frame: usize,
+
+    pub poses: Vec<TrackletPose>,
+}
+
+/// Parses a `tracklet_labels.xml` file, as written by boost serialization.
+pub fn parse_tracklets(xml: &str) -> Result<Vec<Tracklet>, KittiError> {
+    // The files start with a `<!DOCTYPE boost_serialization>` declaration.
+    let options = roxmltree::ParsingOptions {
+        allow_dtd: true,
+        ..Default::default()
+    };
+    let document = roxmltree::Document::parse_with_options(xml, options)?;
+    let tracklets = document
+        .descendants()
+        .find(|node| node.has_tag_name("tracklets"))
+        .ok_or(KittiError::MissingElement("tracklets"))?;
+
+    tracklets
+        .children()
+        .filter(|node| node.has_tag_name("item"))
+        .map(|item| {
+            let poses = child(item, "poses")?
+                .children()
+                .filter(|node| node.has_tag_name("item"))
+                .map(|pose| {
+                    Ok(TrackletPose {
+                        translation: [
+                            child_value(pose, "tx")?,
+                            child_value(pose, "ty")?,
+                            child_value(pose, "tz")?,
+                        ],
+                        rotation: [
+                            child_value(pose, "rx")?,
+                            child_value(pose, "ry")?,
+                            child_value(pose, "rz")?,
+                        ],
+                    })
+                })
+                .collect::<Result<_, KittiError>>()?;
+
+            Ok(Tracklet {
+                object_type: child(item, "objectType")?
+                    .text()
+                    .unwrap_or_default()
+                    .trim()
+                    .to_owned(),
+                size: [
+                    child_value(item, "h")?,
+                    child_value(item, "w")?,
+                    child_value(item, "l")?,
+                ],
+                first_frame: child_value(item, "first_frame")?,
+                poses,
+            })
+        })
+        .collect()
+}
+
+fn child<'a, 'input>(
+    node: roxmltree::Node<'a, 'input>,
+    name: &'static str,
+) -> Result<roxmltree::Node<'a, 'input>, KittiError> {
+    node.children()
+        .find(|child| child.has_tag_name(name))
+        .ok_or(KittiError::MissingElement(name))
+}
+
+fn child_value<T: std::str::FromStr>(
+    node: roxmltree::Node<'_, '_>,
+    name: &'static str,
+) -> Result<T, KittiError> {
+    let text = child(node, name)?.text().unwrap_or_default().trim();
+    text.parse()
+        .map_err(|_err| KittiError::InvalidValue(text.to_owned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_timestamp() {
+        assert_eq!(parse_timestamp("1970-01-01 00:00:00.000000000"), Some(0));
+        assert_eq!(
+            parse_timestamp("2011-09-26 13:02:25.964389445"),
+            Some(1_317_042_145_964_389_445)
+        );
+        assert_eq!(
+            parse_timestamp("2011-09-26 13:02:25.5"),
+            Some(1_317_042_145_500_000_000)
+        );
+        assert_eq!(parse_timestamp("not a timestamp"), None);
+    }
+
+    #[test]
+    fn test_parse_calibration() {
+        let calibration = parse_calibration(
+            "calib_time: 15-Mar-2012 11:37:16\nR: 1 0 0 0 1 0 0 0 1\nT: 0.5 -1 2e-1\n",
+        );
+        assert_eq!(calibration.len(), 2);
+        assert_eq!(calibration["T"], vec![0.5, -1.0, 0.2]);
+        assert_eq!(calibration["R"].len(), 9);
+    }
+
+    #[test]
+    fn test_parse_velodyne() {
+        let bytes = [1.0_f32, 2.0, 3.0, 0.5, -1.0, -2.0, -3.0, 0.25]
+            .iter()
+            .flat_map(|value| value.to_le_bytes())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            parse_velodyne(&bytes),
+            vec![[1.0, 2.0, 3.0, 0.5], [-1.0, -2.0, -3.0, 0.25]]
+        );
+    }
+
+    #[test]
+    fn test_parse_tracklets() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes" ?>
+<!DOCTYPE boost_serialization>
+<boost_serialization signature="serialization::archive" version="9">
+<tracklets class_id="0" tracking_level="0" version="0">
+	<count>1</count>
+	<item_version>1</item_version>
+	<item class_id="1" tracking_level="0" version="1">
+		<objectType>Car</objectType>
+		<h>1.5</h>
+		<w>1.6</w>
+		<l>4.0</l>
+		<first_frame>3</first_frame>
+		<poses class_id="2" tracking_level="0" version="0">
+			<count>2</count>
+			<item_version>2</item_version>
+			<item class_id="3" tracking_level="0" version="2">
+				<tx>10.0</tx><ty>1.0</ty><tz>-1.7</tz>
+				<rx>0</rx><ry>0</ry><rz>0.5</rz>
+				<state>1</state>
+			</item>
+			<item>
+				<tx>11.0</tx><ty>1.0</ty><tz>-1.7</tz>
+				<rx>0</rx><ry>0</ry><rz>0.6</rz>
+				<state>1</state>
+			</item>
+		</poses>
+		<finished>1</finished>
+	</item>
+</tracklets>
+</boost_serialization>"#;
+
+        let tracklets = parse_tracklets(xml).unwrap();
+        assert_eq!(tracklets.len(), 1);
+        let tracklet = &tracklets[0];
+        assert_eq!(tracklet.object_type, "Car");
+        assert_eq!(tracklet.size, [1.5, 1.6, 4.0]);
+        assert_eq!(tracklet.first_frame, 3);
+        assert_eq!(
+            tracklet.poses,
+            vec![
+                TrackletPose {
+                    translation: [10.0, 1.0, -1.7],
+                    rotation: [0.0, 0.0, 0.5],
+                },
+                TrackletPose {
+                    translation: [11.0, 1.0, -1.7],
+                    rotation: [0.0, 0.0, 0.6],
+                },
+            ]
+        );
+
+        assert!(parse_tracklets("<tracklets><item><h>1</h></item></tracklets>").is_err());
+    }
+}
@@ -9,13 +9,34 @@ use re_log_types::{ArrowMsg, EntityPath, LogMsg, RecordingId, StoreId, TimePoint
 
 mod load_file;
 mod loader_archetype;
+mod loader_dicom;
 mod loader_directory;
+mod loader_e57;
+mod loader_flight_log;
+mod loader_las;
+mod loader_pcd;
 mod loader_rrd;
+mod loader_tabular;
 mod loader_urdf;
+mod loader_usd;
+mod pcd;
+
+pub mod dicom;
+pub mod flight_log;
+pub mod usd;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod colmap;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod kitti;
 
 #[cfg(not(target_arch = "wasm32"))]
 pub mod lerobot;
 
+#[cfg(not(target_arch = "wasm32"))]
+pub mod nuscenes;
+
 // This loader currently only works when loading the entire dataset directory, and we cannot do that on web yet.
 #[cfg(not(target_arch = "wasm32"))]
 pub mod loader_lerobot;
@@ -26,25 +47,43 @@ pub mod loader_mcap;
 #[cfg(not(target_arch = "wasm32"))]
 mod loader_external;
 
+// These loaders need to read images next to the dataset description, which we cannot do on web yet.
+#[cfg(not(target_arch = "wasm32"))]
+mod loader_colmap;
+#[cfg(not(target_arch = "wasm32"))]
+mod loader_kitti;
+#[cfg(not(target_arch = "wasm32"))]
+mod loader_nerfstudio;
+#[cfg(not(target_arch = "wasm32"))]
+mod loader_nuscenes;
+
 pub use self::loader_mcap::McapLoader;
 
 pub use self::{
     load_file::load_from_file_contents, loader_archetype::ArchetypeLoader,
-    loader_directory::DirectoryLoader, loader_rrd::RrdLoader, loader_urdf::UrdfDataLoader,
-    loader_urdf::UrdfTree,
+    loader_dicom::DicomLoader, loader_directory::DirectoryLoader, loader_e57::E57Loader,
+    loader_flight_log::FlightLogLoader, loader_las::LasLoader, loader_pcd::PcdLoader,
+    loader_rrd::RrdLoader, loader_tabular::TabularLoader, loader_urdf::UrdfDataLoader,
+    loader_urdf::UrdfTree, loader_usd::UsdLoader,
 };
 
 #[cfg(not(target_arch = "wasm32"))]
 pub use self::{
     load_file::load_from_path,
+    loader_colmap::ColmapLoader,
     loader_external::{
         EXTERNAL_DATA_LOADER_INCOMPATIBLE_EXIT_CODE, EXTERNAL_DATA_LOADER_PREFIX, ExternalLoader,
         iter_external_loaders,
     },
+    loader_kitti::KittiLoader,
     loader_lerobot::LeRobotDatasetLoader,
+    loader_nerfstudio::NerfstudioLoader,
+    loader_nuscenes::NuScenesLoader,
 };
 
 pub mod external {
+    #[cfg(not(target_arch = "wasm32"))]
+    pub use parquet;
     pub use urdf_rs;
 }
 
@@ -426,6 +465,21 @@ static BUILTIN_LOADERS: LazyLock<Vec<Arc<dyn DataLoader>>> = LazyLock::new(|| {
         #[cfg(not(target_arch = "wasm32"))]
         Arc::new(ExternalLoader),
         Arc::new(UrdfDataLoader),
+        Arc::new(FlightLogLoader),
+        Arc::new(LasLoader),
+        Arc::new(PcdLoader),
+        Arc::new(E57Loader),
+        Arc::new(DicomLoader),
+        Arc::new(TabularLoader),
+        Arc::new(UsdLoader),
+        #[cfg(not(target_arch = "wasm32"))]
+        Arc::new(ColmapLoader),
+        #[cfg(not(target_arch = "wasm32"))]
+        Arc::new(NerfstudioLoader),
+        #[cfg(not(target_arch = "wasm32"))]
+        Arc::new(KittiLoader),
+        #[cfg(not(target_arch = "wasm32"))]
+        Arc::new(NuScenesLoader),
     ]
 });
 
@@ -483,7 +537,10 @@ pub const SUPPORTED_POINT_CLOUD_EXTENSIONS: &[&str] = &["ply"];
 pub const SUPPORTED_RERUN_EXTENSIONS: &[&str] = &["rbl", "rrd"];
 
 /// 3rd party formats with built-in support.
-pub const SUPPORTED_THIRD_PARTY_FORMATS: &[&str] = &["mcap"];
+pub const SUPPORTED_THIRD_PARTY_FORMATS: &[&str] = &[
+    "mcap", "ulg", "bin", "las", "laz", "pcd", "e57", "dcm", "csv", "tsv", "parquet", "usd",
+    "usda", "usdc", "usdz",
+];
 
 // TODO(#4555): Add catch-all builtin `DataLoader` for text files
 pub const SUPPORTED_TEXT_EXTENSIONS: &[&str] = &["txt", "md"];
@@ -515,5 +572,12 @@ pub fn is_supported_file_extension(extension: &str) -> bool {
 fn test_supported_extensions() {
     assert!(is_supported_file_extension("rrd"));
     assert!(is_supported_file_extension("mcap"));
+    assert!(is_supported_file_extension("ulg"));
+    assert!(is_supported_file_extension("laz"));
+    assert!(is_supported_file_extension("pcd"));
+    assert!(is_supported_file_extension("dcm"));
+    assert!(is_supported_file_extension("csv"));
+    assert!(is_supported_file_extension("parquet"));
+    assert!(is_supported_file_extension("usdz"));
     assert!(is_supported_file_extension("png"));
 }
@@ -277,6 +277,13 @@ fn load_mesh(
 ) -> Result<impl ExactSizeIterator<Item = Chunk>, DataLoaderError> {
     re_tracing::profile_function!();
 
+    if requires_draco_compression(&contents) {
+        return Err(anyhow::anyhow!(
+            "{filepath:?} uses Draco compressed meshes (KHR_draco_mesh_compression), which are not supported. Export the asset with EXT_meshopt_compression or without compression instead."
+        )
+        .into());
+    }
+
     let rows = [
         {
             let arch = re_types::archetypes::Asset3D::from_file_contents(
@@ -293,6 +300,41 @@ fn load_mesh(
     Ok(rows.into_iter())
 }
 
+/// Whether `contents` is a glTF or GLB asset that can't be read without decoding Draco compressed meshes.
+///
+/// The viewer would only fail once it tries to draw the asset, so we'd rather fail early.
+fn requires_draco_compression(contents: &[u8]) -> bool {
+    #[derive(serde::Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Extensions {
+        #[serde(default)]
+        extensions_required: Vec<String>,
+    }
+
+    // A GLB file is a 12 byte header followed by chunks, the first of which holds the json.
+    let json = if contents.starts_with(b"glTF") {
+        let Some(len) = contents
+            .get(12..16)
+            .and_then(|len| len.try_into().ok())
+            .map(|len| u32::from_le_bytes(len) as usize)
+        else {
+            return false;
+        };
+        contents
+            .get(20..20_usize.saturating_add(len))
+            .unwrap_or(&[])
+    } else {
+        contents
+    };
+
+    serde_json::from_slice::<Extensions>(json).is_ok_and(|extensions| {
+        extensions
+            .extensions_required
+            .iter()
+            .any(|extension| extension == "KHR_draco_mesh_compression")
+    })
+}
+
 fn load_point_cloud(
     timepoint: TimePoint,
     entity_path: EntityPath,
@@ -337,3 +379,36 @@ fn load_text_document(
 
     Ok(rows.into_iter())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_requires_draco_compression() {
+        let gltf = br#"{
+            "asset": { "version": "2.0" },
+            "extensionsUsed": ["KHR_draco_mesh_compression"],
+            "extensionsRequired": ["KHR_draco_mesh_compression"]
+        }"#;
+        assert!(requires_draco_compression(gltf));
+
+        let mut glb = b"glTF".to_vec();
+        glb.extend(2_u32.to_le_bytes());
+        glb.extend((20 + gltf.len() as u32).to_le_bytes());
+        glb.extend((gltf.len() as u32).to_le_bytes());
+        glb.extend(b"JSON");
+        glb.extend(gltf);
+        assert!(requires_draco_compression(&glb));
+
+        // With uncompressed fallbacks, the asset can still be read.
+        let optional = br#"{
+            "asset": { "version": "2.0" },
+            "extensionsUsed": ["KHR_draco_mesh_compression"]
+        }"#;
+        assert!(!requires_draco_compression(optional));
+
+        assert!(!requires_draco_compression(b"solid cube"));
+        assert!(!requires_draco_compression(b"glTF"));
+    }
+}
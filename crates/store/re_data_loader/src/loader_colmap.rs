@@ -0,0 +1,225 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::{Arc, mpsc::Sender},
+};
+
+use anyhow::Context as _;
+
+use re_chunk::{Chunk, ChunkBuilder, ChunkId, RowId, TimePoint};
+use re_log_types::{EntityPath, EntityPathPart};
+use re_types::{
+    AsComponents, ComponentDescriptor, SerializedComponentBatch,
+    archetypes::{EncodedImage, Pinhole, Points2D, Points3D, Transform3D, ViewCoordinates},
+    components::TransformRelation,
+    datatypes::{Quaternion, Rgba32},
+};
+
+use crate::colmap::{Reconstruction, find_model_dir};
+use crate::{DataLoader, DataLoaderError, DataLoaderSettings, LoadedData};
+
+const COLMAP_LOADER_NAME: &str = "ColmapLoader";
+
+/// A [`DataLoader`] for [COLMAP](https://colmap.github.io/) sparse reconstructions.
+///
+/// Open either the directory containing `cameras`, `images`, and `points3D`, or the project
+/// directory containing `sparse/0`. Every registered image is logged as a camera with its
+/// [`Pinhole`] intrinsics and its pose, together with the image itself if it can be found in a
+/// sibling `images` directory. The triangulated points are logged as [`Points3D`], with their
+/// reprojection error as an additional `error` component.
+pub struct ColmapLoader;
+
+impl DataLoader for ColmapLoader {
+    fn name(&self) -> crate::DataLoaderName {
+        COLMAP_LOADER_NAME.into()
+    }
+
+    fn load_from_path(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: PathBuf,
+        tx: Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        let Some(model_dir) = find_model_dir(&filepath) else {
+            return Err(DataLoaderError::Incompatible(filepath));
+        };
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        let reconstruction = Reconstruction::load_from_directory(&model_dir)
+            .with_context(|| format!("Failed to load COLMAP reconstruction {model_dir:?}"))?;
+
+        let image_dir = find_image_dir(&filepath, &model_dir);
+        if image_dir.is_none() {
+            re_log::warn!("Couldn't find the images of COLMAP reconstruction {filepath:?}");
+        }
+
+        let settings = settings.clone();
+
+        // NOTE(1): `spawn` is fine, this whole function is native-only.
+        // NOTE(2): this must spawned on a dedicated thread to avoid a deadlock!
+        // `load` will spawn a bunch of loaders on the common rayon thread pool and wait for
+        // their response via channels: we cannot be waiting for these responses on the
+        // common rayon thread pool.
+        std::thread::Builder::new()
+            .name(format!("load_colmap({filepath:?})"))
+            .spawn(move || {
+                if let Err(err) =
+                    load_reconstruction(&reconstruction, image_dir.as_deref(), &settings, &tx)
+                {
+                    re_log::error!("Failed to load COLMAP reconstruction {filepath:?}: {err}");
+                }
+            })
+            .with_context(|| "Failed to spawn IO thread to load COLMAP reconstruction")?;
+
+        Ok(())
+    }
+
+    fn load_from_file_contents(
+        &self,
+        _settings: &crate::DataLoaderSettings,
+        filepath: PathBuf,
+        _contents: std::borrow::Cow<'_, [u8]>,
+        _tx: Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        // A reconstruction is spread over several files, which we can't get at from here.
+        Err(DataLoaderError::Incompatible(filepath))
+    }
+}
+
+/// Looks for the directory containing the images, relative to both the opened path and the model.
+fn find_image_dir(path: &Path, model_dir: &Path) -> Option<PathBuf> {
+    [
+        Some(path),
+        Some(model_dir),
+        model_dir.parent(),
+        model_dir.parent().and_then(Path::parent),
+    ]
+    .into_iter()
+    .flatten()
+    .map(|dir| dir.join("images"))
+    .find(|dir| dir.is_dir())
+}
+
+fn load_reconstruction(
+    reconstruction: &Reconstruction,
+    image_dir: Option<&Path>,
+    settings: &DataLoaderSettings,
+    tx: &Sender<LoadedData>,
+) -> anyhow::Result<()> {
+    re_tracing::profile_function!();
+
+    let store_id = settings.recommended_store_id();
+    let root = settings
+        .entity_path_prefix
+        .clone()
+        .unwrap_or_else(EntityPath::root);
+
+    let send_chunk = |chunk: Chunk| -> anyhow::Result<()> {
+        tx.send(LoadedData::Chunk(
+            COLMAP_LOADER_NAME.to_owned(),
+            store_id.clone(),
+            chunk,
+        ))?;
+        Ok(())
+    };
+
+    // COLMAP uses the same conventions as OpenCV.
+    send_chunk(static_chunk(
+        root.clone(),
+        &ViewCoordinates::RIGHT_HAND_Y_DOWN(),
+    )?)?;
+
+    if !reconstruction.points.is_empty() {
+        let points = &reconstruction.points;
+        let points3d = Points3D::new(points.iter().map(|point| point.position.map(|v| v as f32)))
+            .with_colors(points.iter().map(|point| {
+                let [r, g, b] = point.color;
+                Rgba32::from_rgb(r, g, b)
+            }));
+
+        let mut batches = points3d.as_serialized_batches();
+        batches.push(SerializedComponentBatch {
+            descriptor: ComponentDescriptor::partial("error"),
+            array: Arc::new(arrow::array::Float64Array::from_iter_values(
+                points.iter().map(|point| point.error),
+            )),
+        });
+
+        send_chunk(
+            ChunkBuilder::new(ChunkId::new(), &root / "points")
+                .with_serialized_batches(RowId::new(), TimePoint::default(), batches)
+                .build()?,
+        )?;
+    }
+
+    for image in reconstruction.images.values() {
+        let camera_path = root.join(&EntityPath::from(vec![
+            EntityPathPart::new("cameras"),
+            EntityPathPart::new(&image.name),
+        ]));
+        let image_path = &camera_path / "image";
+
+        // COLMAP stores the transform from world to camera.
+        let [qw, qx, qy, qz] = image.rotation_wxyz.map(|v| v as f32);
+        send_chunk(static_chunk(
+            camera_path,
+            &Transform3D::from_translation_rotation(
+                image.translation.map(|v| v as f32),
+                Quaternion::from_xyzw([qx, qy, qz, qw]),
+            )
+            .with_relation(TransformRelation::ChildFromParent),
+        )?)?;
+
+        let camera = reconstruction.cameras.get(&image.camera_id);
+        if let Some((camera, (focal_length, principal_point))) =
+            camera.and_then(|camera| Some((camera, camera.pinhole()?)))
+        {
+            send_chunk(static_chunk(
+                image_path.clone(),
+                &Pinhole::from_focal_length_and_resolution(
+                    focal_length.map(|v| v as f32),
+                    [camera.width as f32, camera.height as f32],
+                )
+                .with_principal_point(principal_point.map(|v| v as f32)),
+            )?)?;
+        } else {
+            re_log::warn_once!("Unsupported camera for COLMAP image {:?}", image.name);
+        }
+
+        if let Some(image_dir) = image_dir {
+            let filepath = image_dir.join(&image.name);
+            match std::fs::read(&filepath) {
+                Ok(contents) => {
+                    let mut encoded_image = EncodedImage::from_file_contents(contents);
+                    if let Ok(format) = image::ImageFormat::from_path(&filepath) {
+                        encoded_image = encoded_image.with_media_type(format.to_mime_type());
+                    }
+                    send_chunk(static_chunk(image_path.clone(), &encoded_image)?)?;
+                }
+                Err(err) => re_log::warn_once!("Failed to read image {filepath:?}: {err}"),
+            }
+        }
+
+        // Only keypoints that were triangulated are interesting to look at.
+        let keypoints = image
+            .keypoints
+            .iter()
+            .filter(|(_, point3d_id)| point3d_id.is_some())
+            .map(|(position, _)| position.map(|v| v as f32))
+            .collect::<Vec<_>>();
+        if !keypoints.is_empty() {
+            send_chunk(static_chunk(
+                &image_path / "keypoints",
+                &Points2D::new(keypoints),
+            )?)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn static_chunk(entity_path: EntityPath, archetype: &impl AsComponents) -> anyhow::Result<Chunk> {
+    Ok(ChunkBuilder::new(ChunkId::new(), entity_path)
+        .with_archetype(RowId::new(), TimePoint::default(), archetype)
+        .build()?)
+}
@@ -0,0 +1,287 @@
+//! Rerun data loader for DICOM series, see [`crate::dicom`].
+
+use std::{path::Path, sync::Arc, sync::mpsc::Sender};
+
+use anyhow::Context as _;
+
+use re_chunk::{Chunk, ChunkBuilder, ChunkId, RowId, TimePoint};
+use re_log_types::{EntityPath, Timeline};
+use re_types::{
+    AsComponents as _, ComponentDescriptor, SerializedComponentBatch,
+    archetypes::{Image, Tensor},
+    datatypes::{TensorBuffer, TensorData},
+};
+
+use crate::{
+    DataLoader, DataLoaderError, DataLoaderSettings, LoadedData,
+    dicom::{Volume, Voxels, parse_dicom},
+};
+
+const DICOM_LOADER_NAME: &str = "DicomLoader";
+
+fn is_dicom_file(path: impl AsRef<Path>) -> bool {
+    path.as_ref()
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("dcm"))
+}
+
+/// Checks whether `path` is a directory containing DICOM files.
+///
+/// Files exported by scanners often have no extension, so a few of them are checked for the
+/// DICOM magic as well.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn is_dicom_directory(path: &Path) -> bool {
+    use std::io::Read as _;
+
+    let Ok(entries) = std::fs::read_dir(path) else {
+        return false;
+    };
+
+    entries
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .filter(|path| path.is_file())
+        .take(16)
+        .any(|path| {
+            if is_dicom_file(&path) || path.file_name().is_some_and(|name| name == "DICOMDIR") {
+                return true;
+            }
+            let mut header = [0; 132];
+            std::fs::File::open(&path)
+                .and_then(|mut file| file.read_exact(&mut header))
+                .is_ok_and(|()| crate::dicom::has_dicom_magic(&header))
+        })
+}
+
+/// A [`DataLoader`] for [DICOM](https://www.dicomstandard.org/) files and series.
+///
+/// Open either a single `.dcm` file, or a directory of them. The images of every series are
+/// stacked along their normal into a volume, logged as a [`Tensor`] with `slice`, `row`, and
+/// `column` dimensions, and whose value range is the window of the series. The spacing between
+/// slices, rows, and columns (in millimeters), the position of the first voxel, and the
+/// orientation of the rows and columns are logged as the additional `spacing`, `origin`, and
+/// `orientation` components.
+///
+/// Every slice is also logged as a windowed grayscale [`Image`] on a `slice` timeline.
+pub struct DicomLoader;
+
+impl DataLoader for DicomLoader {
+    fn name(&self) -> crate::DataLoaderName {
+        DICOM_LOADER_NAME.into()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load_from_path(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        tx: Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        if is_dicom_file(&filepath) && filepath.is_file() {
+            let contents = std::fs::read(&filepath)?;
+            return self.load_from_file_contents(settings, filepath, contents.into(), tx);
+        }
+
+        if !filepath.is_dir() || !is_dicom_directory(&filepath) {
+            return Err(DataLoaderError::Incompatible(filepath)); // simply not interested
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        let settings = settings.clone();
+
+        // NOTE: this must be spawned on a dedicated thread, since reading a whole study takes a
+        // long time and we don't want to block the common rayon thread pool in the meantime.
+        std::thread::Builder::new()
+            .name(format!("load_dicom({filepath:?})"))
+            .spawn(move || {
+                if let Err(err) = load_directory(&settings, &filepath, &tx) {
+                    re_log::error!("Failed to load DICOM directory {filepath:?}: {err}");
+                }
+            })
+            .with_context(|| "Failed to spawn IO thread to load DICOM directory")?;
+
+        Ok(())
+    }
+
+    fn load_from_file_contents(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        contents: std::borrow::Cow<'_, [u8]>,
+        tx: Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        if !is_dicom_file(&filepath) {
+            return Err(DataLoaderError::Incompatible(filepath)); // simply not interested
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        let image = parse_dicom(&contents)
+            .with_context(|| format!("Failed to read DICOM file {filepath:?}"))?;
+        let volume = Volume::from_images(vec![image])
+            .with_context(|| format!("Failed to read DICOM file {filepath:?}"))?;
+
+        let store_id = settings.recommended_store_id();
+        for chunk in volume_chunks(&volume, entity_path(settings, &filepath))? {
+            if tx
+                .send(LoadedData::Chunk(
+                    DICOM_LOADER_NAME.to_owned(),
+                    store_id.clone(),
+                    chunk,
+                ))
+                .is_err()
+            {
+                break; // The other end has decided to hang up, not our problem.
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn load_directory(
+    settings: &DataLoaderSettings,
+    dirpath: &Path,
+    tx: &Sender<LoadedData>,
+) -> anyhow::Result<()> {
+    re_tracing::profile_function!();
+
+    let mut series = std::collections::BTreeMap::<String, Vec<crate::dicom::DicomImage>>::new();
+    for entry in walkdir::WalkDir::new(dirpath).sort_by_file_name() {
+        let entry = entry?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+
+        let contents = std::fs::read(entry.path())?;
+        if !is_dicom_file(entry.path()) && !crate::dicom::has_dicom_magic(&contents) {
+            continue;
+        }
+
+        // `DICOMDIR`s, reports, and the like don't have any pixel data.
+        match parse_dicom(&contents) {
+            Ok(image) => series
+                .entry(image.series_instance_uid.clone())
+                .or_default()
+                .push(image),
+            Err(err) => re_log::debug!("Skipping DICOM file {:?}: {err}", entry.path()),
+        }
+    }
+
+    // Series are named after their description, unless it's ambiguous.
+    let descriptions = series
+        .values()
+        .filter_map(|images| images[0].series_description.clone())
+        .collect::<Vec<_>>();
+    let is_unique = |description: &String| {
+        descriptions
+            .iter()
+            .filter(|other| *other == description)
+            .count()
+            == 1
+    };
+
+    let root = entity_path(settings, dirpath);
+    let store_id = settings.recommended_store_id();
+    let is_single_series = series.len() == 1;
+    for (uid, images) in series {
+        let series_path = if is_single_series {
+            root.clone()
+        } else {
+            let name = images[0]
+                .series_description
+                .clone()
+                .filter(is_unique)
+                .unwrap_or(uid);
+            root.join(&EntityPath::from(vec![re_log_types::EntityPathPart::new(
+                name,
+            )]))
+        };
+
+        let volume = match Volume::from_images(images) {
+            Ok(volume) => volume,
+            Err(err) => {
+                re_log::warn!("Skipping DICOM series {series_path}: {err}");
+                continue;
+            }
+        };
+
+        for chunk in volume_chunks(&volume, series_path)? {
+            if tx
+                .send(LoadedData::Chunk(
+                    DICOM_LOADER_NAME.to_owned(),
+                    store_id.clone(),
+                    chunk,
+                ))
+                .is_err()
+            {
+                return Ok(()); // The other end has decided to hang up, not our problem.
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn entity_path(settings: &DataLoaderSettings, path: &Path) -> EntityPath {
+    settings
+        .entity_path_prefix
+        .clone()
+        .map(|prefix| prefix / EntityPath::from_file_path(path))
+        .unwrap_or_else(|| EntityPath::from_file_path(path))
+}
+
+fn volume_chunks(volume: &Volume, entity_path: EntityPath) -> anyhow::Result<Vec<Chunk>> {
+    re_tracing::profile_function!();
+
+    let [slices, rows, columns] = volume.shape;
+    let buffer = match &volume.voxels {
+        Voxels::U16(voxels) => TensorBuffer::U16(voxels.clone().into()),
+        Voxels::I16(voxels) => TensorBuffer::I16(voxels.clone().into()),
+        Voxels::I32(voxels) => TensorBuffer::I32(voxels.clone().into()),
+        Voxels::F32(voxels) => TensorBuffer::F32(voxels.clone().into()),
+    };
+    let tensor_data = TensorData::new(vec![slices as u64, rows as u64, columns as u64], buffer)
+        .with_dim_names(["slice", "row", "column"]);
+
+    let [center, width] = volume.window;
+    let mut batches = Tensor::new(tensor_data)
+        .with_value_range([center - 0.5 * width, center + 0.5 * width])
+        .as_serialized_batches();
+    for (name, values) in [
+        ("spacing", volume.spacing.as_slice()),
+        ("origin", volume.origin.as_slice()),
+        ("orientation", volume.orientation.as_slice()),
+    ] {
+        batches.push(SerializedComponentBatch {
+            descriptor: ComponentDescriptor::partial(name),
+            array: Arc::new(arrow::array::Float64Array::from(values.to_vec())),
+        });
+    }
+
+    let mut chunks = vec![
+        ChunkBuilder::new(ChunkId::new(), &entity_path / "volume")
+            .with_serialized_batches(RowId::new(), TimePoint::default(), batches)
+            .build()?,
+    ];
+
+    let slice_path = &entity_path / "slice";
+    let timeline = Timeline::new_sequence("slice");
+    for index in 0..slices {
+        let Some(pixels) = volume.windowed_slice(index) else {
+            break;
+        };
+        chunks.push(
+            ChunkBuilder::new(ChunkId::new(), slice_path.clone())
+                .with_archetype(
+                    RowId::new(),
+                    TimePoint::default().with(timeline, index as i64),
+                    &Image::from_l8(pixels, [columns as u32, rows as u32]),
+                )
+                .build()?,
+        );
+    }
+
+    Ok(chunks)
+}
@@ -32,6 +32,24 @@ impl crate::DataLoader for DirectoryLoader {
             return Err(crate::DataLoaderError::Incompatible(dirpath.clone()));
         }
 
+        if crate::colmap::is_colmap_dataset(&dirpath)
+            || crate::loader_nerfstudio::find_transforms_file(&dirpath).is_some()
+        {
+            // COLMAP and nerfstudio datasets are loaded by ColmapLoader and NerfstudioLoader
+            return Err(crate::DataLoaderError::Incompatible(dirpath.clone()));
+        }
+
+        if crate::loader_dicom::is_dicom_directory(&dirpath) {
+            // DICOM series are loaded by DicomLoader
+            return Err(crate::DataLoaderError::Incompatible(dirpath.clone()));
+        }
+
+        if crate::kitti::is_kitti_drive(&dirpath) || crate::nuscenes::is_nuscenes_dataset(&dirpath)
+        {
+            // KITTI drives and nuScenes datasets are loaded by KittiLoader and NuScenesLoader
+            return Err(crate::DataLoaderError::Incompatible(dirpath.clone()));
+        }
+
         re_tracing::profile_function!(dirpath.display().to_string());
 
         re_log::debug!(?dirpath, loader = self.name(), "Loading directory…",);
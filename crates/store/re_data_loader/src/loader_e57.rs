@@ -0,0 +1,267 @@
+//! Rerun data loader for E57 point clouds, the standard interchange format for terrestrial laser scanners.
+
+use std::{
+    io::{Read, Seek},
+    path::Path,
+    sync::{Arc, mpsc::Sender},
+};
+
+use re_chunk::{Chunk, ChunkBuilder, ChunkId, RowId, TimePoint};
+use re_log_types::{EntityPath, EntityPathPart};
+use re_types::{
+    AsComponents as _, ComponentDescriptor, SerializedComponentBatch,
+    archetypes::{Points3D, Transform3D},
+    datatypes::{Quaternion, Rgba32},
+};
+
+use crate::{DataLoader, DataLoaderError, DataLoaderSettings, LoadedData};
+
+const E57_LOADER_NAME: &str = "E57Loader";
+
+/// How many points are read and sent at once, see [`crate::LasLoader`].
+const POINTS_PER_BATCH: usize = 1 << 20;
+
+fn is_e57_file(path: impl AsRef<Path>) -> bool {
+    path.as_ref()
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("e57"))
+}
+
+/// A [`DataLoader`] for [E57](http://www.libe57.org/) files.
+///
+/// Every scan in the file is logged as its own entity, with the scanner pose as [`Transform3D`].
+/// Points are logged as [`Points3D`] in batches, so that large scans show up progressively.
+/// Colors are preserved, and intensities are logged as an additional `intensity` component.
+pub struct E57Loader;
+
+impl DataLoader for E57Loader {
+    fn name(&self) -> crate::DataLoaderName {
+        E57_LOADER_NAME.into()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load_from_path(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        tx: Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        if !is_e57_file(&filepath) {
+            return Err(DataLoaderError::Incompatible(filepath)); // simply not interested
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        let file = std::io::BufReader::new(std::fs::File::open(&filepath)?);
+        spawn_load_e57(settings, filepath, file, tx)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load_from_file_contents(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        contents: std::borrow::Cow<'_, [u8]>,
+        tx: Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        if !is_e57_file(&filepath) {
+            return Err(DataLoaderError::Incompatible(filepath)); // simply not interested
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        spawn_load_e57(
+            settings,
+            filepath,
+            std::io::Cursor::new(contents.into_owned()),
+            tx,
+        )
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn load_from_file_contents(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        contents: std::borrow::Cow<'_, [u8]>,
+        tx: Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        if !is_e57_file(&filepath) {
+            return Err(DataLoaderError::Incompatible(filepath)); // simply not interested
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        load_e57(
+            settings,
+            &filepath,
+            std::io::Cursor::new(contents.into_owned()),
+            &tx,
+        )
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn spawn_load_e57(
+    settings: &DataLoaderSettings,
+    filepath: std::path::PathBuf,
+    reader: impl Read + Seek + Send + 'static,
+    tx: Sender<LoadedData>,
+) -> Result<(), DataLoaderError> {
+    // NOTE: this must be spawned on a dedicated thread, since reading a big scan takes a long time
+    // and we don't want to block the common rayon thread pool in the meantime.
+    let settings = settings.clone();
+    std::thread::Builder::new()
+        .name(format!("load_e57({filepath:?})"))
+        .spawn(move || {
+            if let Err(err) = load_e57(&settings, &filepath, reader, &tx) {
+                re_log::error!("Failed to load E57 file {filepath:?}: {err}");
+            }
+        })
+        .map_err(|err| DataLoaderError::Other(err.into()))?;
+
+    Ok(())
+}
+
+fn load_e57(
+    settings: &DataLoaderSettings,
+    filepath: &Path,
+    reader: impl Read + Seek,
+    tx: &Sender<LoadedData>,
+) -> Result<(), DataLoaderError> {
+    re_tracing::profile_function!();
+
+    let mut e57 = e57::E57Reader::new(reader).map_err(|err| DataLoaderError::Other(err.into()))?;
+
+    let store_id = settings.recommended_store_id();
+    let entity_path = settings
+        .entity_path_prefix
+        .clone()
+        .map(|prefix| prefix / EntityPath::from_file_path(filepath))
+        .unwrap_or_else(|| EntityPath::from_file_path(filepath));
+
+    let send_chunk = |chunk: Chunk| {
+        tx.send(LoadedData::Chunk(
+            E57_LOADER_NAME.to_owned(),
+            store_id.clone(),
+            chunk,
+        ))
+        .is_ok()
+    };
+
+    for (scan_index, pointcloud) in e57.pointclouds().into_iter().enumerate() {
+        let scan_name = pointcloud
+            .name
+            .clone()
+            .unwrap_or_else(|| format!("scan_{scan_index}"));
+        let scan_path = entity_path.join(&EntityPath::from(vec![EntityPathPart::new(scan_name)]));
+
+        if let Some(transform) = &pointcloud.transform {
+            let (translation, rotation) = (&transform.translation, &transform.rotation);
+            let scanner_pose = Transform3D::from_translation([
+                translation.x as f32,
+                translation.y as f32,
+                translation.z as f32,
+            ])
+            .with_quaternion(Quaternion::from_xyzw([
+                rotation.x as f32,
+                rotation.y as f32,
+                rotation.z as f32,
+                rotation.w as f32,
+            ]));
+            if !send_chunk(static_chunk(
+                scan_path.clone(),
+                scanner_pose.as_serialized_batches(),
+            )?) {
+                return Ok(()); // The other end has decided to hang up, not our problem.
+            }
+        }
+
+        let mut points = e57
+            .pointcloud_simple(&pointcloud)
+            .map_err(|err| DataLoaderError::Other(err.into()))?;
+        // We log the pose ourselves, so that the points stay close to the origin.
+        points.apply_pose(false);
+        points.spherical_to_cartesian(true);
+
+        let mut batch = Vec::with_capacity(POINTS_PER_BATCH);
+        let mut batch_index = 0;
+        loop {
+            batch.clear();
+            for point in points.by_ref().take(POINTS_PER_BATCH) {
+                batch.push(point.map_err(|err| DataLoaderError::Other(err.into()))?);
+            }
+            if batch.is_empty() {
+                break;
+            }
+
+            let batch_path = scan_path.join(&EntityPath::from_single_string(format!(
+                "batch_{batch_index}"
+            )));
+            batch_index += 1;
+
+            if !send_chunk(static_chunk(batch_path, points_batch(&batch))?) {
+                return Ok(()); // The other end has decided to hang up, not our problem.
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn static_chunk(
+    entity_path: EntityPath,
+    batches: Vec<SerializedComponentBatch>,
+) -> Result<Chunk, DataLoaderError> {
+    Ok(ChunkBuilder::new(ChunkId::new(), entity_path)
+        .with_serialized_batches(RowId::new(), TimePoint::default(), batches)
+        .build()?)
+}
+
+/// Converts a batch of points, skipping the ones without a valid position.
+fn points_batch(points: &[e57::Point]) -> Vec<SerializedComponentBatch> {
+    re_tracing::profile_function!();
+
+    let points = points
+        .iter()
+        .filter_map(|point| match point.cartesian {
+            e57::CartesianCoordinate::Valid { x, y, z } => Some(([x, y, z], point)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    let mut points3d = Points3D::new(
+        points
+            .iter()
+            .map(|([x, y, z], _)| [*x as f32, *y as f32, *z as f32]),
+    );
+
+    if points.iter().any(|(_, point)| point.color.is_some()) {
+        // Colors are normalized to `[0, 1]` by the reader.
+        let channel = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+        points3d = points3d.with_colors(points.iter().map(|(_, point)| {
+            point.color.as_ref().map_or(Rgba32::BLACK, |color| {
+                Rgba32::from_rgb(
+                    channel(color.red),
+                    channel(color.green),
+                    channel(color.blue),
+                )
+            })
+        }));
+    }
+
+    let mut batches = points3d.as_serialized_batches();
+
+    if points.iter().any(|(_, point)| point.intensity.is_some()) {
+        let intensities = points
+            .iter()
+            .map(|(_, point)| point.intensity.unwrap_or(f32::NAN))
+            .collect::<Vec<_>>();
+        batches.push(SerializedComponentBatch {
+            descriptor: ComponentDescriptor::partial("intensity"),
+            array: Arc::new(arrow::array::Float32Array::from(intensities)),
+        });
+    }
+
+    batches
+}
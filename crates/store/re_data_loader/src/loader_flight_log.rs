@@ -0,0 +1,412 @@
+//! Rerun data loader for drone autopilot flight logs, see [`crate::flight_log`].
+
+use std::{path::Path, sync::mpsc::Sender};
+
+use re_chunk::{
+    Chunk, ChunkBuilder, ChunkId, RowId, TimeColumn, TimePoint, TimelineName,
+    external::nohash_hasher::IntMap,
+};
+use re_log_types::{EntityPath, EntityPathPart};
+use re_types::{
+    AsComponents, SerializedComponentColumn,
+    archetypes::{
+        GeoLineStrings, GeoPoints, LineStrips3D, Scalars, TextDocument, TextLog, Transform3D,
+        ViewCoordinates,
+    },
+    components::{MediaType, TextLogLevel},
+    datatypes::Quaternion,
+    external::glam,
+};
+
+use crate::{
+    DataLoader, DataLoaderError, DataLoaderSettings, LoadedData,
+    flight_log::{FlightLog, FlightLogKind, LogLevel, Topic},
+};
+
+const FLIGHT_LOG_LOADER_NAME: &str = "FlightLogLoader";
+
+/// All data is logged on this timeline, which counts from the moment the autopilot booted.
+const TIMELINE_NAME: &str = "time_since_boot";
+
+fn is_flight_log_file(path: impl AsRef<Path>) -> bool {
+    path.as_ref()
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("ulg") || ext.eq_ignore_ascii_case("bin"))
+}
+
+/// A [`DataLoader`] for flight logs of the two most common open-source drone autopilots:
+/// [PX4](https://px4.io/) `ULog` files (`.ulg`) and [`ArduPilot`](https://ardupilot.org/)
+/// DataFlash logs (`.bin`).
+///
+/// The vehicle pose is logged as a [`Transform3D`] in a north-east-down `world`, together with its
+/// trajectory. The GPS track is logged as [`GeoPoints`] and [`GeoLineStrings`] on a `map`.
+/// Every numeric field of every message, which includes actuator outputs, is logged as a
+/// [`Scalars`] plot under `topics`. Parameters and text messages are logged as well.
+pub struct FlightLogLoader;
+
+impl DataLoader for FlightLogLoader {
+    fn name(&self) -> crate::DataLoaderName {
+        FLIGHT_LOG_LOADER_NAME.into()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load_from_path(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        tx: Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        if !is_flight_log_file(&filepath) {
+            return Err(DataLoaderError::Incompatible(filepath)); // simply not interested
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        // Check the header first, there's no point in reading some random multi-gigabyte `.bin` file.
+        let mut header = Vec::new();
+        std::io::Read::read_to_end(
+            &mut std::io::Read::take(std::fs::File::open(&filepath)?, 16),
+            &mut header,
+        )?;
+        if FlightLogKind::from_contents(&header).is_none() {
+            return Err(DataLoaderError::Incompatible(filepath)); // simply not interested
+        }
+
+        let contents = std::fs::read(&filepath)?;
+        self.load_from_file_contents(settings, filepath, contents.into(), tx)
+    }
+
+    fn load_from_file_contents(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        contents: std::borrow::Cow<'_, [u8]>,
+        tx: Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        // `.bin` is a very generic extension, so we also need to look at the contents.
+        if !is_flight_log_file(&filepath) || FlightLogKind::from_contents(&contents).is_none() {
+            return Err(DataLoaderError::Incompatible(filepath)); // simply not interested
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        let log = FlightLog::parse(&contents).map_err(|err| DataLoaderError::Other(err.into()))?;
+
+        let store_id = settings.recommended_store_id();
+        for chunk in flight_log_chunks(&log, settings)? {
+            if tx
+                .send(LoadedData::Chunk(
+                    FLIGHT_LOG_LOADER_NAME.to_owned(),
+                    store_id.clone(),
+                    chunk,
+                ))
+                .is_err()
+            {
+                break; // The other end has decided to hang up, not our problem.
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Converts a parsed flight log into chunks.
+fn flight_log_chunks(log: &FlightLog, settings: &DataLoaderSettings) -> anyhow::Result<Vec<Chunk>> {
+    re_tracing::profile_function!();
+
+    let root = settings
+        .entity_path_prefix
+        .clone()
+        .unwrap_or_else(EntityPath::root);
+
+    let mut chunks = Vec::new();
+
+    // Both autopilots use a local north-east-down frame.
+    chunks.push(static_chunk(&root / "world", &ViewCoordinates::FRD())?);
+
+    if let Some((timestamps_us, positions)) = local_positions(log) {
+        chunks.push(temporal_chunk(
+            &root / "world/vehicle",
+            timestamps_us,
+            Transform3D::update_fields()
+                .with_many_translation(positions.iter().copied())
+                .columns_of_unit_batches()?,
+        )?);
+        chunks.push(static_chunk(
+            &root / "world/trajectory",
+            &LineStrips3D::new([positions.iter().copied()]),
+        )?);
+    }
+
+    if let Some((timestamps_us, quaternions)) = attitudes(log) {
+        chunks.push(temporal_chunk(
+            &root / "world/vehicle",
+            timestamps_us,
+            Transform3D::update_fields()
+                .with_many_quaternion(quaternions)
+                .columns_of_unit_batches()?,
+        )?);
+        chunks.push(static_chunk(
+            &root / "world/vehicle",
+            &Transform3D::update_fields().with_axis_length(1.0),
+        )?);
+    }
+
+    if let Some((timestamps_us, lat_lon)) = global_positions(log) {
+        chunks.push(temporal_chunk(
+            &root / "map/vehicle",
+            timestamps_us,
+            GeoPoints::update_fields()
+                .with_positions(lat_lon.iter().copied())
+                .columns_of_unit_batches()?,
+        )?);
+        chunks.push(static_chunk(
+            &root / "map/track",
+            &GeoLineStrings::from_lat_lon([lat_lon]),
+        )?);
+    }
+
+    if !log.parameters.is_empty() || !log.info.is_empty() {
+        chunks.push(static_chunk(
+            &root / "parameters",
+            &TextDocument::new(parameters_markdown(log)).with_media_type(MediaType::markdown()),
+        )?);
+    }
+
+    if !log.messages.is_empty() {
+        chunks.push(temporal_chunk(
+            &root / "messages",
+            log.messages.iter().map(|msg| msg.timestamp_us).collect(),
+            TextLog::update_fields()
+                .with_many_text(log.messages.iter().map(|msg| msg.text.clone()))
+                .with_many_level(log.messages.iter().map(|msg| text_log_level(msg.level)))
+                .columns_of_unit_batches()?,
+        )?);
+    }
+
+    for (topic_name, topic) in &log.topics {
+        for (field_name, values) in &topic.fields {
+            // Fields are NaN for rows in which they were missing, those are not worth logging.
+            let (timestamps_us, values): (Vec<_>, Vec<_>) = topic
+                .timestamps_us
+                .iter()
+                .copied()
+                .zip(values.iter().copied())
+                .filter(|(_, value)| !value.is_nan())
+                .unzip();
+            if values.is_empty() {
+                continue;
+            }
+
+            let entity_path = root.join(&EntityPath::from(vec![
+                EntityPathPart::new("topics"),
+                EntityPathPart::new(topic_name),
+                EntityPathPart::new(field_name),
+            ]));
+
+            chunks.push(temporal_chunk(
+                entity_path,
+                timestamps_us,
+                Scalars::update_fields()
+                    .with_scalars(values)
+                    .columns_of_unit_batches()?,
+            )?);
+        }
+    }
+
+    Ok(chunks)
+}
+
+fn static_chunk(entity_path: EntityPath, archetype: &impl AsComponents) -> anyhow::Result<Chunk> {
+    Ok(ChunkBuilder::new(ChunkId::new(), entity_path)
+        .with_archetype(RowId::new(), TimePoint::default(), archetype)
+        .build()?)
+}
+
+fn temporal_chunk(
+    entity_path: EntityPath,
+    timestamps_us: Vec<u64>,
+    components: impl IntoIterator<Item = SerializedComponentColumn>,
+) -> anyhow::Result<Chunk> {
+    let time_column = TimeColumn::new_duration_nanos(
+        TIMELINE_NAME,
+        timestamps_us
+            .into_iter()
+            .map(|us| (us as i64).saturating_mul(1000)),
+    );
+    let timelines =
+        std::iter::once((TimelineName::from(TIMELINE_NAME), time_column)).collect::<IntMap<_, _>>();
+
+    Ok(Chunk::from_auto_row_ids(
+        ChunkId::new(),
+        entity_path,
+        timelines,
+        components.into_iter().collect(),
+    )?)
+}
+
+/// Returns the rows of `topic` for which all of `fields` are present.
+fn rows<const N: usize>(topic: &Topic, fields: [&str; N]) -> Option<(Vec<u64>, Vec<[f64; N]>)> {
+    let columns = fields
+        .iter()
+        .map(|field| topic.field(field))
+        .collect::<Option<Vec<_>>>()?;
+
+    let (timestamps_us, values): (Vec<_>, Vec<_>) = topic
+        .timestamps_us
+        .iter()
+        .enumerate()
+        .map(|(row, timestamp_us)| {
+            let values: [f64; N] = std::array::from_fn(|i| columns[i][row]);
+            (*timestamp_us, values)
+        })
+        .filter(|(_, values)| values.iter().all(|value| value.is_finite()))
+        .unzip();
+
+    (!values.is_empty()).then_some((timestamps_us, values))
+}
+
+/// Returns the first of `topics` that is present in the log and has all of `fields`.
+fn first_rows<const N: usize>(
+    log: &FlightLog,
+    topics: &[&str],
+    fields: [&str; N],
+) -> Option<(Vec<u64>, Vec<[f64; N]>)> {
+    topics
+        .iter()
+        .filter_map(|topic| log.topics.get(*topic))
+        .find_map(|topic| rows(topic, fields))
+}
+
+/// Position of the vehicle in the local north-east-down frame, in meters.
+fn local_positions(log: &FlightLog) -> Option<(Vec<u64>, Vec<[f32; 3]>)> {
+    let (timestamps_us, positions) = match log.kind {
+        FlightLogKind::ULog => first_rows(log, &["vehicle_local_position"], ["x", "y", "z"]),
+        FlightLogKind::DataFlash => first_rows(log, &["XKF1", "NKF1"], ["PN", "PE", "PD"]),
+    }?;
+
+    let positions = positions
+        .into_iter()
+        .map(|position| position.map(|v| v as f32))
+        .collect();
+    Some((timestamps_us, positions))
+}
+
+/// Orientation of the vehicle relative to the local north-east-down frame.
+fn attitudes(log: &FlightLog) -> Option<(Vec<u64>, Vec<Quaternion>)> {
+    match log.kind {
+        FlightLogKind::ULog => {
+            let (timestamps_us, quaternions) =
+                first_rows(log, &["vehicle_attitude"], ["q[0]", "q[1]", "q[2]", "q[3]"])?;
+            let quaternions = quaternions
+                .into_iter()
+                .map(|[w, x, y, z]| Quaternion::from_xyzw([x as f32, y as f32, z as f32, w as f32]))
+                .collect();
+            Some((timestamps_us, quaternions))
+        }
+
+        FlightLogKind::DataFlash => {
+            let (timestamps_us, angles) = first_rows(log, &["ATT"], ["Roll", "Pitch", "Yaw"])?;
+            let quaternions = angles
+                .into_iter()
+                .map(|[roll, pitch, yaw]| {
+                    let quat = glam::Quat::from_euler(
+                        glam::EulerRot::ZYX,
+                        yaw.to_radians() as f32,
+                        pitch.to_radians() as f32,
+                        roll.to_radians() as f32,
+                    );
+                    Quaternion::from_xyzw(quat.to_array())
+                })
+                .collect();
+            Some((timestamps_us, quaternions))
+        }
+    }
+}
+
+/// Latitude and longitude of the vehicle, in degrees.
+fn global_positions(log: &FlightLog) -> Option<(Vec<u64>, Vec<[f64; 2]>)> {
+    let (timestamps_us, lat_lon) = match log.kind {
+        FlightLogKind::ULog => first_rows(log, &["vehicle_global_position"], ["lat", "lon"]),
+        FlightLogKind::DataFlash => first_rows(log, &["GPS"], ["Lat", "Lng"]),
+    }?;
+
+    // Receivers report exactly zero as long as they don't have a fix.
+    let (timestamps_us, lat_lon): (Vec<_>, Vec<_>) = timestamps_us
+        .into_iter()
+        .zip(lat_lon)
+        .filter(|(_, lat_lon)| *lat_lon != [0.0, 0.0])
+        .unzip();
+
+    (!lat_lon.is_empty()).then_some((timestamps_us, lat_lon))
+}
+
+fn text_log_level(level: LogLevel) -> TextLogLevel {
+    match level {
+        LogLevel::Emergency | LogLevel::Alert | LogLevel::Critical => TextLogLevel::CRITICAL,
+        LogLevel::Error => TextLogLevel::ERROR,
+        LogLevel::Warning => TextLogLevel::WARN,
+        LogLevel::Notice | LogLevel::Info => TextLogLevel::INFO,
+        LogLevel::Debug => TextLogLevel::DEBUG,
+    }
+    .into()
+}
+
+fn parameters_markdown(log: &FlightLog) -> String {
+    use std::fmt::Write as _;
+
+    let mut markdown = String::new();
+
+    if !log.info.is_empty() {
+        markdown.push_str("## Info\n\n| Key | Value |\n| --- | --- |\n");
+        for (key, value) in &log.info {
+            writeln!(markdown, "| {key} | {value} |").ok();
+        }
+        markdown.push('\n');
+    }
+
+    if !log.parameters.is_empty() {
+        markdown.push_str("## Parameters\n\n| Name | Value |\n| --- | --- |\n");
+        for (name, value) in &log.parameters {
+            writeln!(markdown, "| {name} | {value} |").ok();
+        }
+    }
+
+    markdown
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_log_level() {
+        assert_eq!(
+            text_log_level(LogLevel::Alert),
+            TextLogLevel::from(TextLogLevel::CRITICAL)
+        );
+        assert_eq!(
+            text_log_level(LogLevel::Notice),
+            TextLogLevel::from(TextLogLevel::INFO)
+        );
+    }
+
+    #[test]
+    fn test_rows_skips_missing_values() {
+        let topic = Topic {
+            timestamps_us: vec![1, 2, 3],
+            fields: [
+                ("lat".to_owned(), vec![47.0, f64::NAN, 47.2]),
+                ("lon".to_owned(), vec![8.0, 8.1, 8.2]),
+            ]
+            .into_iter()
+            .collect(),
+        };
+
+        let (timestamps_us, values) = rows(&topic, ["lat", "lon"]).unwrap();
+        assert_eq!(timestamps_us, vec![1, 3]);
+        assert_eq!(values, vec![[47.0, 8.0], [47.2, 8.2]]);
+
+        assert!(rows(&topic, ["lat", "alt"]).is_none());
+    }
+}
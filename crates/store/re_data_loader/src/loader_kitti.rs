@@ -0,0 +1,452 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    sync::{Arc, mpsc::Sender},
+};
+
+use anyhow::Context as _;
+
+use re_chunk::{Chunk, ChunkBuilder, ChunkId, RowId, TimePoint};
+use re_log_types::{EntityPath, TimeCell, Timeline};
+use re_types::{
+    AsComponents, ComponentDescriptor, SerializedComponentBatch,
+    archetypes::{
+        AnnotationContext, Boxes3D, EncodedImage, Pinhole, Points3D, Transform3D, ViewCoordinates,
+    },
+    components::{FillMode, TransformRelation},
+    datatypes::{ClassDescription, Quaternion, Rgba32},
+    external::glam,
+};
+
+use crate::kitti::{self, Tracklet};
+use crate::{DataLoader, DataLoaderError, DataLoaderSettings, LoadedData};
+
+const KITTI_LOADER_NAME: &str = "KittiLoader";
+
+/// The object types of the tracklets, and their colors.
+const OBJECT_TYPES: &[(&str, Rgba32)] = &[
+    ("Car", Rgba32::from_rgb(0, 142, 255)),
+    ("Van", Rgba32::from_rgb(0, 200, 200)),
+    ("Truck", Rgba32::from_rgb(150, 100, 255)),
+    ("Pedestrian", Rgba32::from_rgb(255, 60, 60)),
+    ("Person_sitting", Rgba32::from_rgb(255, 140, 60)),
+    ("Cyclist", Rgba32::from_rgb(255, 220, 0)),
+    ("Tram", Rgba32::from_rgb(60, 200, 60)),
+    ("Misc", Rgba32::from_rgb(160, 160, 160)),
+];
+
+/// A [`DataLoader`] for drives of the [KITTI raw](https://www.cvlibs.net/datasets/kitti/raw_data.php)
+/// dataset, see [`crate::kitti`].
+///
+/// Open the directory of a drive, e.g. `2011_09_26/2011_09_26_drive_0001_sync`.
+/// The LiDAR sweeps are logged as [`Points3D`], and the cameras as [`Pinhole`]s with their
+/// calibrated pose relative to the LiDAR, together with their images. Tracklets are logged as
+/// [`Boxes3D`], with their object type as class.
+///
+/// Everything is logged on a `frame` timeline, as well as on a `time` timeline using the
+/// timestamps of each sensor.
+pub struct KittiLoader;
+
+impl DataLoader for KittiLoader {
+    fn name(&self) -> crate::DataLoaderName {
+        KITTI_LOADER_NAME.into()
+    }
+
+    fn load_from_path(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: PathBuf,
+        tx: Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        if !kitti::is_kitti_drive(&filepath) {
+            return Err(DataLoaderError::Incompatible(filepath));
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        let settings = settings.clone();
+
+        // NOTE: this must be spawned on a dedicated thread, since reading all the sweeps and images
+        // takes a long time and we don't want to block the common rayon thread pool in the meantime.
+        std::thread::Builder::new()
+            .name(format!("load_kitti({filepath:?})"))
+            .spawn(move || {
+                if let Err(err) = load_drive(&filepath, &settings, &tx) {
+                    re_log::error!("Failed to load KITTI drive {filepath:?}: {err}");
+                }
+            })
+            .with_context(|| "Failed to spawn IO thread to load KITTI drive")?;
+
+        Ok(())
+    }
+
+    fn load_from_file_contents(
+        &self,
+        _settings: &crate::DataLoaderSettings,
+        filepath: PathBuf,
+        _contents: std::borrow::Cow<'_, [u8]>,
+        _tx: Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        // A drive is spread over many files, which we can't get at from here.
+        Err(DataLoaderError::Incompatible(filepath))
+    }
+}
+
+fn load_drive(
+    drive_dir: &Path,
+    settings: &DataLoaderSettings,
+    tx: &Sender<LoadedData>,
+) -> anyhow::Result<()> {
+    re_tracing::profile_function!();
+
+    let store_id = settings.recommended_store_id();
+    let root = settings
+        .entity_path_prefix
+        .clone()
+        .unwrap_or_else(EntityPath::root);
+
+    let send_chunk = |chunk: Chunk| -> anyhow::Result<()> {
+        tx.send(LoadedData::Chunk(
+            KITTI_LOADER_NAME.to_owned(),
+            store_id.clone(),
+            chunk,
+        ))?;
+        Ok(())
+    };
+
+    // Everything is expressed relative to the LiDAR, which is x forward, y left, z up.
+    send_chunk(static_chunk(root.clone(), &ViewCoordinates::FLU())?)?;
+
+    // The calibration files live next to the drives of the same day.
+    let calibration_dir = drive_dir.parent().unwrap_or(drive_dir);
+    let read_calibration = |name: &str| {
+        std::fs::read_to_string(calibration_dir.join(name))
+            .map(|text| kitti::parse_calibration(&text))
+            .map_err(|err| re_log::warn!("Failed to read KITTI calibration {name:?}: {err}"))
+            .ok()
+    };
+    let cam_to_cam = read_calibration("calib_cam_to_cam.txt");
+    let velo_to_cam = read_calibration("calib_velo_to_cam.txt");
+
+    if let (Some(cam_to_cam), Some(velo_to_cam)) = (&cam_to_cam, &velo_to_cam) {
+        for camera in kitti::CAMERAS {
+            if let Some(calibration) = camera_calibration(cam_to_cam, velo_to_cam, camera) {
+                let camera_path = &root / "cameras" / *camera;
+                send_chunk(static_chunk(camera_path.clone(), &calibration.transform())?)?;
+                send_chunk(static_chunk(
+                    &camera_path / "image",
+                    &calibration.pinhole(),
+                )?)?;
+            }
+        }
+    }
+
+    let tracklets_path = drive_dir.join("tracklet_labels.xml");
+    if tracklets_path.is_file() {
+        let xml = std::fs::read_to_string(&tracklets_path)?;
+        let tracklets = kitti::parse_tracklets(&xml)
+            .with_context(|| format!("Failed to parse tracklets {tracklets_path:?}"))?;
+        let timestamps = read_timestamps(&drive_dir.join(kitti::VELODYNE));
+
+        let annotations_path = &root / "annotations";
+        send_chunk(static_chunk(
+            annotations_path.clone(),
+            &AnnotationContext::new(
+                OBJECT_TYPES.iter().enumerate().map(|(id, (label, color))| {
+                    ClassDescription::from((id as u16, *label, *color))
+                }),
+            ),
+        )?)?;
+        for (frame, boxes) in tracklet_boxes(&tracklets) {
+            send_chunk(temporal_chunk(
+                annotations_path.clone(),
+                timepoint(frame, &timestamps),
+                &boxes,
+            )?)?;
+        }
+    }
+
+    // The sweeps and the images are by far the largest, so they come last.
+    let velodyne_dir = drive_dir.join(kitti::VELODYNE);
+    if velodyne_dir.is_dir() {
+        let timestamps = read_timestamps(&velodyne_dir);
+        for (frame, path) in frame_files(&velodyne_dir.join("data"))? {
+            let points = kitti::parse_velodyne(&std::fs::read(&path)?);
+
+            let mut batches = Points3D::new(points.iter().map(|[x, y, z, _]| [*x, *y, *z]))
+                .as_serialized_batches();
+            batches.push(SerializedComponentBatch {
+                descriptor: ComponentDescriptor::partial("reflectance"),
+                array: Arc::new(arrow::array::Float32Array::from_iter_values(
+                    points.iter().map(|[_, _, _, reflectance]| *reflectance),
+                )),
+            });
+
+            send_chunk(
+                ChunkBuilder::new(ChunkId::new(), &root / "velodyne")
+                    .with_serialized_batches(RowId::new(), timepoint(frame, &timestamps), batches)
+                    .build()?,
+            )?;
+        }
+    }
+
+    for camera in kitti::CAMERAS {
+        let camera_dir = drive_dir.join(camera);
+        if !camera_dir.is_dir() {
+            continue;
+        }
+
+        let image_path = &root / "cameras" / *camera / "image";
+        let timestamps = read_timestamps(&camera_dir);
+        for (frame, path) in frame_files(&camera_dir.join("data"))? {
+            let mut encoded_image = EncodedImage::from_file_contents(std::fs::read(&path)?);
+            if let Ok(format) = image::ImageFormat::from_path(&path) {
+                encoded_image = encoded_image.with_media_type(format.to_mime_type());
+            }
+            send_chunk(temporal_chunk(
+                image_path.clone(),
+                timepoint(frame, &timestamps),
+                &encoded_image,
+            )?)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// The calibration of a rectified camera.
+#[derive(Debug)]
+struct CameraCalibration {
+    /// Transforms points from the LiDAR frame to the camera frame.
+    camera_from_velodyne: glam::DAffine3,
+    focal_length: [f64; 2],
+    principal_point: [f64; 2],
+    resolution: [f64; 2],
+}
+
+impl CameraCalibration {
+    fn transform(&self) -> Transform3D {
+        Transform3D::from_translation_mat3x3(
+            self.camera_from_velodyne.translation.as_vec3(),
+            self.camera_from_velodyne.matrix3.as_mat3(),
+        )
+        .with_relation(TransformRelation::ChildFromParent)
+    }
+
+    fn pinhole(&self) -> Pinhole {
+        Pinhole::from_focal_length_and_resolution(
+            self.focal_length.map(|v| v as f32),
+            self.resolution.map(|v| v as f32),
+        )
+        .with_principal_point(self.principal_point.map(|v| v as f32))
+    }
+}
+
+/// Computes the pose of a camera relative to the LiDAR, and its intrinsics.
+///
+/// Images of the `_sync` drives are rectified, so the pose and intrinsics are the ones of the
+/// rectified cameras.
+fn camera_calibration(
+    cam_to_cam: &BTreeMap<String, Vec<f64>>,
+    velo_to_cam: &BTreeMap<String, Vec<f64>>,
+    camera: &str,
+) -> Option<CameraCalibration> {
+    let index = camera.strip_prefix("image_")?;
+    let mat3 = |values: &[f64]| -> Option<glam::DMat3> {
+        Some(glam::DMat3::from_cols_array(values.try_into().ok()?).transpose())
+    };
+    let vec3 = |values: &[f64]| -> Option<glam::DVec3> {
+        Some(glam::DVec3::from_array(values.try_into().ok()?))
+    };
+
+    let velo_rotation = mat3(velo_to_cam.get("R")?)?;
+    let velo_translation = vec3(velo_to_cam.get("T")?)?;
+    let rectification = mat3(cam_to_cam.get("R_rect_00")?)?;
+
+    // The projection matrix is `K [I | b]`, with `b` the offset from the first camera.
+    let projection = cam_to_cam.get(&format!("P_rect_{index}"))?;
+    let resolution = cam_to_cam
+        .get(&format!("S_rect_{index}"))?
+        .as_slice()
+        .try_into()
+        .ok()?;
+    let intrinsics = mat3(
+        &[
+            projection.get(0..3)?,
+            projection.get(4..7)?,
+            projection.get(8..11)?,
+        ]
+        .concat(),
+    )?;
+    let offset = intrinsics.inverse()
+        * glam::DVec3::new(
+            *projection.get(3)?,
+            *projection.get(7)?,
+            *projection.get(11)?,
+        );
+
+    Some(CameraCalibration {
+        camera_from_velodyne: glam::DAffine3::from_mat3_translation(
+            rectification * velo_rotation,
+            rectification * velo_translation + offset,
+        ),
+        focal_length: [intrinsics.x_axis.x, intrinsics.y_axis.y],
+        principal_point: [intrinsics.z_axis.x, intrinsics.z_axis.y],
+        resolution,
+    })
+}
+
+/// Groups the tracklets per frame.
+fn tracklet_boxes(tracklets: &[Tracklet]) -> BTreeMap<usize, Boxes3D> {
+    let mut frames = BTreeMap::<usize, Vec<_>>::new();
+    for tracklet in tracklets {
+        let class_id = OBJECT_TYPES
+            .iter()
+            .position(|(object_type, _)| *object_type == tracklet.object_type)
+            .unwrap_or(OBJECT_TYPES.len() - 1) as u16;
+        let [h, w, l] = tracklet.size.map(|v| v as f32);
+
+        for (i, pose) in tracklet.poses.iter().enumerate() {
+            let [x, y, z] = pose.translation.map(|v| v as f32);
+            let [roll, pitch, yaw] = pose.rotation.map(|v| v as f32);
+            let rotation = glam::Quat::from_euler(glam::EulerRot::ZYX, yaw, pitch, roll);
+
+            frames.entry(tracklet.first_frame + i).or_default().push((
+                // The pose is the center of the bottom face.
+                [x, y, z + 0.5 * h],
+                [0.5 * l, 0.5 * w, 0.5 * h],
+                Quaternion::from_xyzw(rotation.to_array()),
+                class_id,
+            ));
+        }
+    }
+
+    frames
+        .into_iter()
+        .map(|(frame, boxes)| {
+            let boxes3d = Boxes3D::from_centers_and_half_sizes(
+                boxes.iter().map(|(center, _, _, _)| *center),
+                boxes.iter().map(|(_, half_size, _, _)| *half_size),
+            )
+            .with_quaternions(boxes.iter().map(|(_, _, rotation, _)| *rotation))
+            .with_class_ids(boxes.iter().map(|(_, _, _, class_id)| *class_id))
+            .with_fill_mode(FillMode::MajorWireframe);
+            (frame, boxes3d)
+        })
+        .collect()
+}
+
+/// Reads the `timestamps.txt` of a sensor, if any.
+fn read_timestamps(sensor_dir: &Path) -> Vec<Option<i64>> {
+    std::fs::read_to_string(sensor_dir.join("timestamps.txt"))
+        .map(|text| kitti::parse_timestamps(&text))
+        .unwrap_or_default()
+}
+
+/// Lists the files of a `data` directory, which are named after their frame number.
+fn frame_files(data_dir: &Path) -> anyhow::Result<Vec<(usize, PathBuf)>> {
+    let mut files = std::fs::read_dir(data_dir)
+        .with_context(|| format!("Failed to read {data_dir:?}"))?
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let frame = path.file_stem()?.to_str()?.parse().ok()?;
+            Some((frame, path))
+        })
+        .collect::<Vec<_>>();
+    files.sort();
+    Ok(files)
+}
+
+fn timepoint(frame: usize, timestamps: &[Option<i64>]) -> TimePoint {
+    let mut timepoint = TimePoint::default().with(Timeline::new_sequence("frame"), frame as i64);
+    if let Some(Some(timestamp)) = timestamps.get(frame) {
+        timepoint.insert_cell(
+            "time",
+            TimeCell::from_timestamp_nanos_since_epoch(*timestamp),
+        );
+    }
+    timepoint
+}
+
+fn static_chunk(entity_path: EntityPath, archetype: &impl AsComponents) -> anyhow::Result<Chunk> {
+    temporal_chunk(entity_path, TimePoint::default(), archetype)
+}
+
+fn temporal_chunk(
+    entity_path: EntityPath,
+    timepoint: TimePoint,
+    archetype: &impl AsComponents,
+) -> anyhow::Result<Chunk> {
+    Ok(ChunkBuilder::new(ChunkId::new(), entity_path)
+        .with_archetype(RowId::new(), timepoint, archetype)
+        .build()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_camera_calibration() {
+        let cam_to_cam = kitti::parse_calibration(
+            "R_rect_00: 1 0 0 0 1 0 0 0 1\n\
+             P_rect_02: 700 0 600 -70 0 700 180 0 0 0 1 0\n\
+             S_rect_02: 1242 375\n",
+        );
+        // LiDAR x forward, y left, z up to camera x right, y down, z forward.
+        let velo_to_cam = kitti::parse_calibration("R: 0 -1 0 0 0 -1 1 0 0\nT: 0 -0.1 -0.3\n");
+
+        assert!(camera_calibration(&cam_to_cam, &velo_to_cam, "image_03").is_none());
+        let calibration = camera_calibration(&cam_to_cam, &velo_to_cam, "image_02").unwrap();
+
+        // A point 10m ahead of the LiDAR.
+        let point = calibration
+            .camera_from_velodyne
+            .transform_point3(glam::DVec3::new(10.0, 0.0, 0.0));
+        assert!(point.abs_diff_eq(glam::DVec3::new(-0.1, -0.1, 9.7), 1e-9));
+
+        assert_eq!(calibration.focal_length, [700.0, 700.0]);
+        assert_eq!(calibration.principal_point, [600.0, 180.0]);
+        assert_eq!(calibration.resolution, [1242.0, 375.0]);
+    }
+
+    #[test]
+    fn test_tracklet_boxes() {
+        let tracklets = [
+            Tracklet {
+                object_type: "Pedestrian".to_owned(),
+                size: [2.0, 1.0, 1.0],
+                first_frame: 1,
+                poses: vec![
+                    kitti::TrackletPose {
+                        translation: [1.0, 2.0, 0.0],
+                        rotation: [0.0; 3],
+                    };
+                    2
+                ],
+            },
+            Tracklet {
+                object_type: "Unknown".to_owned(),
+                size: [1.0; 3],
+                first_frame: 2,
+                poses: vec![kitti::TrackletPose {
+                    translation: [0.0; 3],
+                    rotation: [0.0; 3],
+                }],
+            },
+        ];
+
+        let frames = tracklet_boxes(&tracklets);
+        assert_eq!(frames.keys().copied().collect::<Vec<_>>(), vec![1, 2]);
+
+        let expected = Boxes3D::from_centers_and_half_sizes([[1.0, 2.0, 1.0]], [[0.5, 0.5, 1.0]])
+            .with_quaternions([Quaternion::IDENTITY])
+            .with_class_ids([3])
+            .with_fill_mode(FillMode::MajorWireframe);
+        assert_eq!(frames[&1], expected);
+        assert_eq!(
+            frames[&2].class_ids,
+            Boxes3D::update_fields().with_class_ids([3, 7]).class_ids
+        );
+    }
+}
@@ -0,0 +1,349 @@
+//! Rerun data loader for LAS/LAZ point clouds, the standard format for aerial lidar scans.
+
+use std::{
+    io::{Read, Seek},
+    path::Path,
+    sync::mpsc::Sender,
+};
+
+use re_chunk::{Chunk, ChunkBuilder, ChunkId, RowId, TimePoint};
+use re_log_types::EntityPath;
+use re_types::{
+    AsComponents,
+    archetypes::{AnnotationContext, Points3D, Transform3D},
+    datatypes::{ClassDescription, Rgba32},
+};
+
+use crate::{DataLoader, DataLoaderError, DataLoaderSettings, LoadedData};
+
+const LAS_LOADER_NAME: &str = "LasLoader";
+
+/// How many points are read and sent at once.
+///
+/// Aerial scans easily contain billions of points, so we send them in batches that the viewer can
+/// show as they come in, rather than waiting for the whole file.
+const POINTS_PER_BATCH: u64 = 1 << 20;
+
+/// How many points are read at once.
+///
+/// Reading space is reserved upfront, so this is kept small: that way, what we allocate follows
+/// the points that are actually in the file, rather than how many its header claims.
+const POINTS_PER_READ: u64 = 1 << 16;
+
+/// The standard ASPRS classes, see table 17 of the LAS 1.4 specification.
+const ASPRS_CLASSES: &[(u16, &str, Rgba32)] = &[
+    (0, "Never classified", Rgba32::from_rgb(160, 160, 160)),
+    (1, "Unclassified", Rgba32::from_rgb(200, 200, 200)),
+    (2, "Ground", Rgba32::from_rgb(170, 120, 70)),
+    (3, "Low vegetation", Rgba32::from_rgb(160, 220, 110)),
+    (4, "Medium vegetation", Rgba32::from_rgb(80, 180, 60)),
+    (5, "High vegetation", Rgba32::from_rgb(20, 110, 30)),
+    (6, "Building", Rgba32::from_rgb(220, 60, 50)),
+    (7, "Low point (noise)", Rgba32::from_rgb(255, 0, 255)),
+    (8, "Model key-point", Rgba32::from_rgb(255, 255, 0)),
+    (9, "Water", Rgba32::from_rgb(40, 110, 220)),
+    (10, "Rail", Rgba32::from_rgb(110, 70, 40)),
+    (11, "Road surface", Rgba32::from_rgb(90, 90, 90)),
+    (12, "Overlap", Rgba32::from_rgb(240, 200, 80)),
+    (13, "Wire guard", Rgba32::from_rgb(250, 170, 0)),
+    (14, "Wire conductor", Rgba32::from_rgb(250, 130, 0)),
+    (15, "Transmission tower", Rgba32::from_rgb(130, 40, 160)),
+    (16, "Wire connector", Rgba32::from_rgb(200, 100, 220)),
+    (17, "Bridge deck", Rgba32::from_rgb(120, 120, 180)),
+    (18, "High noise", Rgba32::from_rgb(255, 60, 180)),
+];
+
+fn is_las_file(path: impl AsRef<Path>) -> bool {
+    path.as_ref()
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("las") || ext.eq_ignore_ascii_case("laz"))
+}
+
+/// A [`DataLoader`] for [LAS](https://www.asprs.org/divisions-committees/lidar-division/laser-las-file-format-exchange-activities)
+/// point clouds, and their compressed LAZ variant.
+///
+/// Points are logged as [`Points3D`] in batches, so that large scans show up progressively.
+/// Every point gets a class id from its ASPRS classification, which is described by an
+/// [`AnnotationContext`]. Points are colored by their RGB values if the file has them, or else
+/// by intensity if the file has no classification.
+///
+/// Coordinates are usually projected (e.g. UTM), and therefore way too large to be stored as `f32`
+/// without losing precision. The points are stored relative to the center of the scan instead,
+/// with that center as the [`Transform3D`] of the entity.
+pub struct LasLoader;
+
+impl DataLoader for LasLoader {
+    fn name(&self) -> crate::DataLoaderName {
+        LAS_LOADER_NAME.into()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load_from_path(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        tx: Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        if !is_las_file(&filepath) {
+            return Err(DataLoaderError::Incompatible(filepath)); // simply not interested
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        let file = std::io::BufReader::new(std::fs::File::open(&filepath)?);
+        spawn_load_las(settings, filepath, file, tx)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load_from_file_contents(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        contents: std::borrow::Cow<'_, [u8]>,
+        tx: Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        if !is_las_file(&filepath) {
+            return Err(DataLoaderError::Incompatible(filepath)); // simply not interested
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        spawn_load_las(
+            settings,
+            filepath,
+            std::io::Cursor::new(contents.into_owned()),
+            tx,
+        )
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn load_from_file_contents(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        contents: std::borrow::Cow<'_, [u8]>,
+        tx: Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        if !is_las_file(&filepath) {
+            return Err(DataLoaderError::Incompatible(filepath)); // simply not interested
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        load_las(
+            settings,
+            &filepath,
+            std::io::Cursor::new(contents.into_owned()),
+            &tx,
+        )
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn spawn_load_las(
+    settings: &DataLoaderSettings,
+    filepath: std::path::PathBuf,
+    reader: impl Read + Seek + Send + std::fmt::Debug + 'static,
+    tx: Sender<LoadedData>,
+) -> Result<(), DataLoaderError> {
+    // NOTE: this must be spawned on a dedicated thread, since reading a big scan takes a long time
+    // and we don't want to block the common rayon thread pool in the meantime.
+    let settings = settings.clone();
+    std::thread::Builder::new()
+        .name(format!("load_las({filepath:?})"))
+        .spawn(move || {
+            if let Err(err) = load_las(&settings, &filepath, reader, &tx) {
+                re_log::error!("Failed to load LAS file {filepath:?}: {err}");
+            }
+        })
+        .map_err(|err| DataLoaderError::Other(err.into()))?;
+
+    Ok(())
+}
+
+fn load_las(
+    settings: &DataLoaderSettings,
+    filepath: &Path,
+    reader: impl Read + Seek + Send + std::fmt::Debug + 'static,
+    tx: &Sender<LoadedData>,
+) -> Result<(), DataLoaderError> {
+    re_tracing::profile_function!();
+
+    let mut reader = las::Reader::new(reader).map_err(|err| DataLoaderError::Other(err.into()))?;
+
+    let header = reader.header();
+    let num_points = header.number_of_points();
+    let bounds = header.bounds();
+    let origin = [
+        (bounds.min.x + bounds.max.x) / 2.0,
+        (bounds.min.y + bounds.max.y) / 2.0,
+        (bounds.min.z + bounds.max.z) / 2.0,
+    ];
+
+    re_log::debug!("Loading {num_points} points from {filepath:?}");
+
+    let store_id = settings.recommended_store_id();
+    let entity_path = settings
+        .entity_path_prefix
+        .clone()
+        .map(|prefix| prefix / EntityPath::from_file_path(filepath))
+        .unwrap_or_else(|| EntityPath::from_file_path(filepath));
+
+    let send_chunk = |chunk: Chunk| {
+        tx.send(LoadedData::Chunk(
+            LAS_LOADER_NAME.to_owned(),
+            store_id.clone(),
+            chunk,
+        ))
+        .is_ok()
+    };
+
+    let scan = Transform3D::from_translation(origin.map(|v| v as f32));
+    let classes = AnnotationContext::new(
+        ASPRS_CLASSES
+            .iter()
+            .map(|&(id, label, color)| ClassDescription::from((id, label, color))),
+    );
+    if !send_chunk(static_chunk(entity_path.clone(), &scan)?)
+        || !send_chunk(static_chunk(entity_path.clone(), &classes)?)
+    {
+        return Ok(()); // The other end has decided to hang up, not our problem.
+    }
+
+    let mut points = Vec::new();
+    for batch_index in 0.. {
+        points.clear();
+        while (points.len() as u64) < POINTS_PER_BATCH {
+            let num_read = reader
+                .read_points_into(POINTS_PER_READ, &mut points)
+                .map_err(|err| DataLoaderError::Other(err.into()))?;
+            if num_read == 0 {
+                break;
+            }
+        }
+        if points.is_empty() {
+            break;
+        }
+
+        let batch = points_batch(&points, origin);
+        let batch_path = entity_path.join(&EntityPath::from_single_string(format!(
+            "batch_{batch_index}"
+        )));
+        if !send_chunk(static_chunk(batch_path, &batch)?) {
+            break; // The other end has decided to hang up, not our problem.
+        }
+    }
+
+    Ok(())
+}
+
+fn static_chunk(
+    entity_path: EntityPath,
+    archetype: &impl AsComponents,
+) -> Result<Chunk, DataLoaderError> {
+    Ok(ChunkBuilder::new(ChunkId::new(), entity_path)
+        .with_archetype(RowId::new(), TimePoint::default(), archetype)
+        .build()?)
+}
+
+/// Converts a batch of points, relative to `origin`.
+fn points_batch(points: &[las::Point], origin: [f64; 3]) -> Points3D {
+    re_tracing::profile_function!();
+
+    let positions = points.iter().map(|point| {
+        [
+            (point.x - origin[0]) as f32,
+            (point.y - origin[1]) as f32,
+            (point.z - origin[2]) as f32,
+        ]
+    });
+
+    let class_ids = points
+        .iter()
+        .map(|point| u8::from(point.classification) as u16)
+        .collect::<Vec<_>>();
+
+    let mut batch = Points3D::new(positions);
+
+    if points.iter().any(|point| point.color.is_some()) {
+        // Colors are supposed to be 16 bit, but some writers store 8 bit values instead.
+        let is_8_bit = points.iter().all(|point| {
+            point.color.is_none_or(|color| {
+                color.red <= u8::MAX as u16
+                    && color.green <= u8::MAX as u16
+                    && color.blue <= u8::MAX as u16
+            })
+        });
+        let channel = |value: u16| {
+            if is_8_bit {
+                value as u8
+            } else {
+                (value >> 8) as u8
+            }
+        };
+
+        batch = batch.with_colors(points.iter().map(|point| {
+            let color = point.color.unwrap_or_default();
+            Rgba32::from_rgb(
+                channel(color.red),
+                channel(color.green),
+                channel(color.blue),
+            )
+        }));
+    } else if class_ids.iter().all(|&class_id| class_id <= 1) {
+        // Without a classification, the class colors don't tell anything, so use intensity instead.
+        let max_intensity = points
+            .iter()
+            .map(|point| point.intensity)
+            .max()
+            .unwrap_or(0);
+        let scale = if max_intensity <= u8::MAX as u16 {
+            1.0
+        } else {
+            u8::MAX as f32 / u16::MAX as f32
+        };
+
+        batch = batch.with_colors(points.iter().map(|point| {
+            let gray = (point.intensity as f32 * scale) as u8;
+            Rgba32::from_rgb(gray, gray, gray)
+        }));
+    }
+
+    batch.with_class_ids(class_ids)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(x: f64, classification: u8, intensity: u16) -> las::Point {
+        las::Point {
+            x,
+            y: 2.0,
+            z: 3.0,
+            intensity,
+            classification: las::point::Classification::new(classification).unwrap(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_points_batch() {
+        let points = [point(101.0, 2, 10), point(102.0, 6, 20)];
+        let batch = points_batch(&points, [100.0, 2.0, 3.0]);
+
+        let expected = Points3D::new([[1.0, 0.0, 0.0], [2.0, 0.0, 0.0]]).with_class_ids([2, 6]);
+        assert_eq!(batch, expected);
+    }
+
+    #[test]
+    fn test_points_batch_intensity() {
+        let points = [point(0.0, 1, 0), point(0.0, 1, 255)];
+        let batch = points_batch(&points, [0.0; 3]);
+
+        let expected = Points3D::new([[0.0, -2.0, -3.0], [0.0, -2.0, -3.0]])
+            .with_colors([Rgba32::from_rgb(0, 0, 0), Rgba32::from_rgb(255, 255, 255)])
+            .with_class_ids([1, 1]);
+        assert_eq!(batch, expected);
+    }
+}
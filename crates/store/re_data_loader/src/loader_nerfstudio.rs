@@ -0,0 +1,279 @@
+//! Rerun data loader for [nerfstudio](https://docs.nerf.studio/quickstart/data_conventions.html)
+//! and instant-ngp style `transforms.json` datasets.
+
+use std::{
+    path::{Path, PathBuf},
+    sync::mpsc::Sender,
+};
+
+use anyhow::Context as _;
+
+use re_chunk::{Chunk, ChunkBuilder, ChunkId, RowId, TimePoint};
+use re_log_types::{EntityPath, EntityPathPart};
+use re_types::{
+    AsComponents,
+    archetypes::{EncodedImage, Pinhole, Points3D, Transform3D},
+    components,
+};
+
+use crate::{DataLoader, DataLoaderError, DataLoaderSettings, LoadedData};
+
+const NERFSTUDIO_LOADER_NAME: &str = "NerfstudioLoader";
+
+/// The file describing a dataset, when opening a whole directory.
+const TRANSFORMS_FILE_NAME: &str = "transforms.json";
+
+/// Returns the `transforms.json` describing the dataset at `path`, if any.
+///
+/// `path` can either be the dataset directory, or one of its `transforms*.json` files
+/// (e.g. the `transforms_train.json` of the original NeRF synthetic datasets).
+pub fn find_transforms_file(path: &Path) -> Option<PathBuf> {
+    if path.is_dir() {
+        let path = path.join(TRANSFORMS_FILE_NAME);
+        return path.is_file().then_some(path);
+    }
+
+    let file_name = path.file_name()?.to_str()?;
+    (file_name.starts_with("transforms") && file_name.ends_with(".json") && path.is_file())
+        .then(|| path.to_owned())
+}
+
+/// Intrinsics can be specified either for the whole dataset, or for each frame.
+#[derive(Debug, Default, Clone, serde::Deserialize)]
+struct Intrinsics {
+    fl_x: Option<f64>,
+    fl_y: Option<f64>,
+    cx: Option<f64>,
+    cy: Option<f64>,
+    w: Option<f64>,
+    h: Option<f64>,
+
+    /// Horizontal field of view in radians, used by instant-ngp instead of focal lengths.
+    camera_angle_x: Option<f64>,
+}
+
+impl Intrinsics {
+    /// Fills in the values missing from `self` with the ones from `other`.
+    fn or(self, other: &Self) -> Self {
+        Self {
+            fl_x: self.fl_x.or(other.fl_x),
+            fl_y: self.fl_y.or(other.fl_y),
+            cx: self.cx.or(other.cx),
+            cy: self.cy.or(other.cy),
+            w: self.w.or(other.w),
+            h: self.h.or(other.h),
+            camera_angle_x: self.camera_angle_x.or(other.camera_angle_x),
+        }
+    }
+
+    fn pinhole(&self, resolution: Option<[f64; 2]>) -> Option<Pinhole> {
+        let [w, h] = match (self.w, self.h) {
+            (Some(w), Some(h)) => [w, h],
+            _ => resolution?,
+        };
+        let fl_x = self
+            .fl_x
+            .or_else(|| Some(0.5 * w / (0.5 * self.camera_angle_x?).tan()))?;
+        let fl_y = self.fl_y.unwrap_or(fl_x);
+
+        Some(
+            Pinhole::from_focal_length_and_resolution(
+                [fl_x as f32, fl_y as f32],
+                [w as f32, h as f32],
+            )
+            .with_principal_point([
+                self.cx.unwrap_or(0.5 * w) as f32,
+                self.cy.unwrap_or(0.5 * h) as f32,
+            ]),
+        )
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct Frame {
+    file_path: String,
+
+    /// Camera-to-world, row-major.
+    transform_matrix: [[f64; 4]; 4],
+
+    #[serde(flatten)]
+    intrinsics: Intrinsics,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct Transforms {
+    #[serde(flatten)]
+    intrinsics: Intrinsics,
+
+    frames: Vec<Frame>,
+
+    /// Sparse points, as exported by `ns-process-data`.
+    ply_file_path: Option<String>,
+}
+
+/// A [`DataLoader`] for [nerfstudio](https://docs.nerf.studio/) datasets.
+///
+/// Open either the dataset directory or its `transforms.json`. Every frame is logged as a camera
+/// with its [`Pinhole`] intrinsics, its pose, and its image. If the dataset references a sparse
+/// point cloud, it is logged as [`Points3D`].
+pub struct NerfstudioLoader;
+
+impl DataLoader for NerfstudioLoader {
+    fn name(&self) -> crate::DataLoaderName {
+        NERFSTUDIO_LOADER_NAME.into()
+    }
+
+    fn load_from_path(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: PathBuf,
+        tx: Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        let Some(transforms_path) = find_transforms_file(&filepath) else {
+            return Err(DataLoaderError::Incompatible(filepath));
+        };
+
+        // Plenty of unrelated JSON files are named like this, so only claim the ones with frames.
+        let Ok(transforms) = std::fs::read(&transforms_path)
+            .map_err(anyhow::Error::from)
+            .and_then(|contents| Ok(serde_json::from_slice::<Transforms>(&contents)?))
+        else {
+            return Err(DataLoaderError::Incompatible(filepath));
+        };
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        let Some(dataset_dir) = transforms_path.parent().map(Path::to_path_buf) else {
+            return Err(DataLoaderError::Incompatible(filepath));
+        };
+        let settings = settings.clone();
+
+        // NOTE: this must be spawned on a dedicated thread, since reading all the images takes a
+        // long time and we don't want to block the common rayon thread pool in the meantime.
+        std::thread::Builder::new()
+            .name(format!("load_nerfstudio({filepath:?})"))
+            .spawn(move || {
+                if let Err(err) = load_transforms(&transforms, &dataset_dir, &settings, &tx) {
+                    re_log::error!("Failed to load nerfstudio dataset {filepath:?}: {err}");
+                }
+            })
+            .with_context(|| "Failed to spawn IO thread to load nerfstudio dataset")?;
+
+        Ok(())
+    }
+
+    fn load_from_file_contents(
+        &self,
+        _settings: &crate::DataLoaderSettings,
+        filepath: PathBuf,
+        _contents: std::borrow::Cow<'_, [u8]>,
+        _tx: Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        // The images live in separate files, which we can't get at from here.
+        Err(DataLoaderError::Incompatible(filepath))
+    }
+}
+
+fn load_transforms(
+    transforms: &Transforms,
+    dataset_dir: &Path,
+    settings: &DataLoaderSettings,
+    tx: &Sender<LoadedData>,
+) -> anyhow::Result<()> {
+    re_tracing::profile_function!();
+
+    let store_id = settings.recommended_store_id();
+    let root = settings
+        .entity_path_prefix
+        .clone()
+        .unwrap_or_else(EntityPath::root);
+
+    let send_chunk = |chunk: Chunk| -> anyhow::Result<()> {
+        tx.send(LoadedData::Chunk(
+            NERFSTUDIO_LOADER_NAME.to_owned(),
+            store_id.clone(),
+            chunk,
+        ))?;
+        Ok(())
+    };
+
+    if let Some(ply_file_path) = &transforms.ply_file_path {
+        let ply_path = dataset_dir.join(ply_file_path);
+        let points = Points3D::from_file_path(&ply_path)
+            .with_context(|| format!("Failed to load sparse points {ply_path:?}"))?;
+        send_chunk(static_chunk(&root / "points", &points)?)?;
+    }
+
+    for frame in &transforms.frames {
+        let Some(image_path) = resolve_image_path(dataset_dir, &frame.file_path) else {
+            re_log::warn_once!("Couldn't find image {:?}", frame.file_path);
+            continue;
+        };
+
+        let camera_path = root.join(&EntityPath::from(vec![
+            EntityPathPart::new("cameras"),
+            EntityPathPart::new(frame.file_path.trim_start_matches("./")),
+        ]));
+        let image_entity_path = &camera_path / "image";
+
+        let m = frame.transform_matrix.map(|row| row.map(|v| v as f32));
+        send_chunk(static_chunk(
+            camera_path,
+            &Transform3D::from_translation_mat3x3(
+                [m[0][3], m[1][3], m[2][3]],
+                [
+                    [m[0][0], m[1][0], m[2][0]],
+                    [m[0][1], m[1][1], m[2][1]],
+                    [m[0][2], m[1][2], m[2][2]],
+                ],
+            ),
+        )?)?;
+
+        let intrinsics = frame.intrinsics.clone().or(&transforms.intrinsics);
+        let resolution = || {
+            image::image_dimensions(&image_path)
+                .ok()
+                .map(|(w, h)| [w as f64, h as f64])
+        };
+        match intrinsics.pinhole(resolution()) {
+            // nerfstudio uses the same conventions as OpenGL.
+            Some(pinhole) => send_chunk(static_chunk(
+                image_entity_path.clone(),
+                &pinhole.with_camera_xyz(components::ViewCoordinates::RUB),
+            )?)?,
+            None => re_log::warn_once!("Missing intrinsics for frame {:?}", frame.file_path),
+        }
+
+        match std::fs::read(&image_path) {
+            Ok(contents) => {
+                let mut encoded_image = EncodedImage::from_file_contents(contents);
+                if let Ok(format) = image::ImageFormat::from_path(&image_path) {
+                    encoded_image = encoded_image.with_media_type(format.to_mime_type());
+                }
+                send_chunk(static_chunk(image_entity_path, &encoded_image)?)?;
+            }
+            Err(err) => re_log::warn_once!("Failed to read image {image_path:?}: {err}"),
+        }
+    }
+
+    Ok(())
+}
+
+/// instant-ngp datasets commonly leave out the extension of the images.
+fn resolve_image_path(dataset_dir: &Path, file_path: &str) -> Option<PathBuf> {
+    let path = dataset_dir.join(file_path);
+    if path.is_file() {
+        return Some(path);
+    }
+
+    ["png", "jpg", "jpeg"]
+        .into_iter()
+        .map(|ext| path.with_extension(ext))
+        .find(|path| path.is_file())
+}
+
+fn static_chunk(entity_path: EntityPath, archetype: &impl AsComponents) -> anyhow::Result<Chunk> {
+    Ok(ChunkBuilder::new(ChunkId::new(), entity_path)
+        .with_archetype(RowId::new(), TimePoint::default(), archetype)
+        .build()?)
+}
@@ -0,0 +1,429 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::{Path, PathBuf},
+    sync::{Arc, mpsc::Sender},
+};
+
+use anyhow::Context as _;
+
+use re_chunk::{Chunk, ChunkBuilder, ChunkId, RowId, TimeColumn, TimePoint};
+use re_log_types::{EntityPath, EntityPathPart, TimeCell, TimelineName};
+use re_types::{
+    AsComponents, ComponentDescriptor, SerializedComponentBatch,
+    archetypes::{
+        AnnotationContext, Boxes3D, EncodedImage, Pinhole, Points3D, TextLog, Transform3D,
+        ViewCoordinates,
+    },
+    components::FillMode,
+    datatypes::{ClassDescription, Quaternion},
+};
+
+use crate::nuscenes::{self, CalibratedSensor, NuScenes, SampleData};
+use crate::{DataLoader, DataLoaderError, DataLoaderSettings, LoadedData};
+
+const NUSCENES_LOADER_NAME: &str = "NuScenesLoader";
+
+/// The timeline all the data is logged on.
+const TIMELINE_NAME: &str = "timestamp";
+
+/// A [`DataLoader`] for the [nuScenes](https://www.nuscenes.org/nuscenes) dataset, see
+/// [`crate::nuscenes`].
+///
+/// Open the dataset directory, or its metadata directory (e.g. `v1.0-mini`).
+/// Everything is logged in the global frame: the ego vehicle with its pose over time, and every
+/// sensor relative to it. Camera images come with their [`Pinhole`] intrinsics, LiDAR sweeps are
+/// logged as [`Points3D`] with an additional `intensity` component, and radar returns as
+/// [`Points3D`]. Annotations are logged as [`Boxes3D`], with their category as class.
+pub struct NuScenesLoader;
+
+impl DataLoader for NuScenesLoader {
+    fn name(&self) -> crate::DataLoaderName {
+        NUSCENES_LOADER_NAME.into()
+    }
+
+    fn load_from_path(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: PathBuf,
+        tx: Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        let Some(metadata_dir) = nuscenes::find_metadata_dir(&filepath) else {
+            return Err(DataLoaderError::Incompatible(filepath));
+        };
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        // The sensor data is next to the metadata directory.
+        let dataset_dir = metadata_dir
+            .parent()
+            .map_or_else(|| metadata_dir.clone(), Path::to_path_buf);
+        let settings = settings.clone();
+
+        // NOTE: this must be spawned on a dedicated thread, since reading the tables and all the
+        // sensor data takes a long time and we don't want to block the common rayon thread pool
+        // in the meantime.
+        std::thread::Builder::new()
+            .name(format!("load_nuscenes({filepath:?})"))
+            .spawn(move || {
+                let result = NuScenes::load_from_directory(&metadata_dir)
+                    .map_err(anyhow::Error::from)
+                    .and_then(|nuscenes| load_dataset(&nuscenes, &dataset_dir, &settings, &tx));
+                if let Err(err) = result {
+                    re_log::error!("Failed to load nuScenes dataset {filepath:?}: {err}");
+                }
+            })
+            .with_context(|| "Failed to spawn IO thread to load nuScenes dataset")?;
+
+        Ok(())
+    }
+
+    fn load_from_file_contents(
+        &self,
+        _settings: &crate::DataLoaderSettings,
+        filepath: PathBuf,
+        _contents: std::borrow::Cow<'_, [u8]>,
+        _tx: Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        // The dataset is spread over many files, which we can't get at from here.
+        Err(DataLoaderError::Incompatible(filepath))
+    }
+}
+
+fn load_dataset(
+    nuscenes: &NuScenes,
+    dataset_dir: &Path,
+    settings: &DataLoaderSettings,
+    tx: &Sender<LoadedData>,
+) -> anyhow::Result<()> {
+    re_tracing::profile_function!();
+
+    let store_id = settings.recommended_store_id();
+    let root = settings
+        .entity_path_prefix
+        .clone()
+        .unwrap_or_else(EntityPath::root);
+
+    let send_chunk = |chunk: Chunk| -> anyhow::Result<()> {
+        tx.send(LoadedData::Chunk(
+            NUSCENES_LOADER_NAME.to_owned(),
+            store_id.clone(),
+            chunk,
+        ))?;
+        Ok(())
+    };
+
+    send_chunk(static_chunk(
+        root.clone(),
+        &ViewCoordinates::RIGHT_HAND_Z_UP(),
+    )?)?;
+
+    for scene in &nuscenes.scenes {
+        if let Some(sample) = nuscenes.samples.get(&scene.first_sample_token) {
+            let text = if scene.description.is_empty() {
+                scene.name.clone()
+            } else {
+                format!("{}: {}", scene.name, scene.description)
+            };
+            send_chunk(temporal_chunk(
+                &root / "scene",
+                timepoint(sample.timestamp),
+                &TextLog::new(text),
+            )?)?;
+        }
+    }
+
+    send_chunk(ego_poses_chunk(nuscenes, &root / "ego")?)?;
+
+    if !nuscenes.sample_annotations.is_empty() {
+        let annotations_path = &root / "annotations";
+        send_chunk(static_chunk(
+            annotations_path.clone(),
+            &AnnotationContext::new(
+                nuscenes
+                    .categories
+                    .iter()
+                    .enumerate()
+                    .map(|(id, category)| {
+                        ClassDescription::from((id as u16, category.name.as_str()))
+                    }),
+            ),
+        )?)?;
+        for (timestamp, boxes) in annotation_boxes(nuscenes) {
+            send_chunk(temporal_chunk(
+                annotations_path.clone(),
+                timepoint(timestamp),
+                &boxes,
+            )?)?;
+        }
+    }
+
+    // The sensor data is by far the largest, so it comes last.
+    let mut calibrations = HashMap::<&str, &str>::new();
+    for sample_data in &nuscenes.sample_data {
+        let Some((sensor, calibrated_sensor)) = nuscenes.sensor(sample_data) else {
+            re_log::warn_once!(
+                "Missing sensor for nuScenes sample data {}",
+                sample_data.token
+            );
+            continue;
+        };
+
+        let sensor_path = root.join(&EntityPath::from(vec![
+            EntityPathPart::new("ego"),
+            EntityPathPart::new(&sensor.channel),
+        ]));
+        let time = timepoint(sample_data.timestamp);
+
+        // The calibration of a sensor changes between logs, so it has to be logged over time.
+        if calibrations.insert(sensor.channel.as_str(), calibrated_sensor.token.as_str())
+            != Some(calibrated_sensor.token.as_str())
+        {
+            send_chunk(temporal_chunk(
+                sensor_path.clone(),
+                time.clone(),
+                &sensor_transform(calibrated_sensor),
+            )?)?;
+            if let Some(pinhole) = sensor_pinhole(calibrated_sensor, sample_data) {
+                send_chunk(temporal_chunk(sensor_path.clone(), time.clone(), &pinhole)?)?;
+            }
+        }
+
+        let filepath = dataset_dir.join(&sample_data.filename);
+        let contents = match std::fs::read(&filepath) {
+            Ok(contents) => contents,
+            Err(err) => {
+                // The sweeps are a separate download, so they are often missing.
+                re_log::debug_once!("Failed to read nuScenes sensor data {filepath:?}: {err}");
+                continue;
+            }
+        };
+
+        let batches = match sensor.modality.as_str() {
+            "camera" => {
+                let mut encoded_image = EncodedImage::from_file_contents(contents);
+                if let Ok(format) = image::ImageFormat::from_path(&filepath) {
+                    encoded_image = encoded_image.with_media_type(format.to_mime_type());
+                }
+                encoded_image.as_serialized_batches()
+            }
+
+            "lidar" => {
+                let points = nuscenes::parse_lidar(&contents);
+                let mut batches = Points3D::new(points.iter().map(|[x, y, z, _, _]| [*x, *y, *z]))
+                    .as_serialized_batches();
+                batches.push(SerializedComponentBatch {
+                    descriptor: ComponentDescriptor::partial("intensity"),
+                    array: Arc::new(arrow::array::Float32Array::from_iter_values(
+                        points.iter().map(|[_, _, _, intensity, _]| *intensity),
+                    )),
+                });
+                batches
+            }
+
+            "radar" => {
+                let cloud = match crate::pcd::parse_pcd(&contents) {
+                    Ok(cloud) => cloud,
+                    Err(err) => {
+                        re_log::warn_once!("Failed to parse radar returns {filepath:?}: {err}");
+                        continue;
+                    }
+                };
+                let (Some(xs), Some(ys), Some(zs)) =
+                    (cloud.field("x"), cloud.field("y"), cloud.field("z"))
+                else {
+                    continue;
+                };
+                Points3D::new(
+                    itertools::izip!(xs, ys, zs).map(|(x, y, z)| [*x as f32, *y as f32, *z as f32]),
+                )
+                .as_serialized_batches()
+            }
+
+            modality => {
+                re_log::warn_once!("Unsupported nuScenes sensor modality {modality:?}");
+                continue;
+            }
+        };
+
+        send_chunk(
+            ChunkBuilder::new(ChunkId::new(), sensor_path)
+                .with_serialized_batches(RowId::new(), time, batches)
+                .build()?,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Logs the pose of the ego vehicle at the time of every sensor reading.
+fn ego_poses_chunk(nuscenes: &NuScenes, entity_path: EntityPath) -> anyhow::Result<Chunk> {
+    let ego_poses = nuscenes
+        .sample_data
+        .iter()
+        .filter_map(|sample_data| nuscenes.ego_poses.get(&sample_data.ego_pose_token))
+        .map(|ego_pose| (ego_pose.timestamp, ego_pose))
+        .collect::<BTreeMap<_, _>>();
+
+    let time_column = TimeColumn::new_timestamp_nanos_since_epoch(
+        TIMELINE_NAME,
+        ego_poses
+            .keys()
+            .map(|timestamp_us| timestamp_us.saturating_mul(1000)),
+    );
+    let components = Transform3D::update_fields()
+        .with_many_translation(
+            ego_poses
+                .values()
+                .map(|ego_pose| ego_pose.translation.map(|v| v as f32)),
+        )
+        .with_many_quaternion(
+            ego_poses
+                .values()
+                .map(|ego_pose| quaternion(ego_pose.rotation)),
+        )
+        .columns_of_unit_batches()?;
+
+    Ok(Chunk::from_auto_row_ids(
+        ChunkId::new(),
+        entity_path,
+        std::iter::once((TimelineName::from(TIMELINE_NAME), time_column)).collect(),
+        components.collect(),
+    )?)
+}
+
+/// Groups the annotations per sample.
+///
+/// Every sample gets a batch of boxes, even an empty one, so that annotations don't linger.
+fn annotation_boxes(nuscenes: &NuScenes) -> BTreeMap<i64, Boxes3D> {
+    let mut samples = nuscenes
+        .samples
+        .values()
+        .map(|sample| (sample.token.as_str(), (sample.timestamp, Vec::new())))
+        .collect::<HashMap<_, _>>();
+
+    for annotation in &nuscenes.sample_annotations {
+        let Some((_, boxes)) = samples.get_mut(annotation.sample_token.as_str()) else {
+            continue;
+        };
+        let [w, l, h] = annotation.size.map(|v| v as f32);
+        boxes.push((
+            annotation.translation.map(|v| v as f32),
+            [0.5 * l, 0.5 * w, 0.5 * h],
+            quaternion(annotation.rotation),
+            nuscenes.category_index(annotation).map(|id| id as u16),
+        ));
+    }
+
+    samples
+        .into_values()
+        .map(|(timestamp, boxes)| {
+            let boxes3d = Boxes3D::from_centers_and_half_sizes(
+                boxes.iter().map(|(center, _, _, _)| *center),
+                boxes.iter().map(|(_, half_size, _, _)| *half_size),
+            )
+            .with_quaternions(boxes.iter().map(|(_, _, rotation, _)| *rotation))
+            .with_class_ids(
+                boxes
+                    .iter()
+                    .map(|(_, _, _, class_id)| class_id.unwrap_or(u16::MAX)),
+            )
+            .with_fill_mode(FillMode::MajorWireframe);
+            (timestamp, boxes3d)
+        })
+        .collect()
+}
+
+fn sensor_transform(calibrated_sensor: &CalibratedSensor) -> Transform3D {
+    Transform3D::from_translation(calibrated_sensor.translation.map(|v| v as f32))
+        .with_quaternion(quaternion(calibrated_sensor.rotation))
+}
+
+fn sensor_pinhole(
+    calibrated_sensor: &CalibratedSensor,
+    sample_data: &SampleData,
+) -> Option<Pinhole> {
+    let [[fx, _, cx], [_, fy, cy], _] = calibrated_sensor.camera_intrinsic.as_slice() else {
+        return None;
+    };
+    (sample_data.width > 0 && sample_data.height > 0).then(|| {
+        Pinhole::from_focal_length_and_resolution(
+            [*fx as f32, *fy as f32],
+            [sample_data.width as f32, sample_data.height as f32],
+        )
+        .with_principal_point([*cx as f32, *cy as f32])
+    })
+}
+
+/// Converts a nuScenes `[w, x, y, z]` quaternion.
+fn quaternion([w, x, y, z]: [f64; 4]) -> Quaternion {
+    Quaternion::from_xyzw([x as f32, y as f32, z as f32, w as f32])
+}
+
+fn timepoint(timestamp_us: i64) -> TimePoint {
+    let mut timepoint = TimePoint::default();
+    timepoint.insert_cell(
+        TIMELINE_NAME,
+        TimeCell::from_timestamp_nanos_since_epoch(timestamp_us.saturating_mul(1000)),
+    );
+    timepoint
+}
+
+fn static_chunk(entity_path: EntityPath, archetype: &impl AsComponents) -> anyhow::Result<Chunk> {
+    temporal_chunk(entity_path, TimePoint::default(), archetype)
+}
+
+fn temporal_chunk(
+    entity_path: EntityPath,
+    timepoint: TimePoint,
+    archetype: &impl AsComponents,
+) -> anyhow::Result<Chunk> {
+    Ok(ChunkBuilder::new(ChunkId::new(), entity_path)
+        .with_archetype(RowId::new(), timepoint, archetype)
+        .build()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quaternion() {
+        assert_eq!(quaternion([1.0, 0.0, 0.0, 0.0]), Quaternion::IDENTITY);
+        assert_eq!(
+            quaternion([0.5, 0.1, 0.2, 0.3]),
+            Quaternion::from_xyzw([0.1, 0.2, 0.3, 0.5])
+        );
+    }
+
+    #[test]
+    fn test_sensor_pinhole() {
+        let mut calibrated_sensor = CalibratedSensor {
+            token: "c".to_owned(),
+            sensor_token: "s".to_owned(),
+            translation: [0.0; 3],
+            rotation: [1.0, 0.0, 0.0, 0.0],
+            camera_intrinsic: vec![],
+        };
+        let sample_data = SampleData {
+            token: "d".to_owned(),
+            sample_token: "x".to_owned(),
+            ego_pose_token: "e".to_owned(),
+            calibrated_sensor_token: "c".to_owned(),
+            timestamp: 0,
+            filename: "samples/CAM_FRONT/d.jpg".to_owned(),
+            width: 1600,
+            height: 900,
+        };
+        assert!(sensor_pinhole(&calibrated_sensor, &sample_data).is_none());
+
+        calibrated_sensor.camera_intrinsic =
+            vec![[1266.0, 0.0, 816.0], [0.0, 1266.0, 491.0], [0.0, 0.0, 1.0]];
+        assert_eq!(
+            sensor_pinhole(&calibrated_sensor, &sample_data),
+            Some(
+                Pinhole::from_focal_length_and_resolution([1266.0, 1266.0], [1600.0, 900.0])
+                    .with_principal_point([816.0, 491.0])
+            )
+        );
+    }
+}
@@ -0,0 +1,177 @@
+//! Rerun data loader for PCD point clouds, see [`crate::pcd`].
+
+use std::{path::Path, sync::Arc, sync::mpsc::Sender};
+
+use anyhow::Context as _;
+
+use re_chunk::{Chunk, ChunkBuilder, ChunkId, RowId, TimePoint};
+use re_log_types::EntityPath;
+use re_types::{
+    AsComponents as _, ComponentDescriptor, SerializedComponentBatch,
+    archetypes::{Points3D, Transform3D},
+    datatypes::{Quaternion, Rgba32},
+};
+
+use crate::{
+    DataLoader, DataLoaderError, LoadedData,
+    pcd::{PointCloud, parse_pcd},
+};
+
+const PCD_LOADER_NAME: &str = "PcdLoader";
+
+/// Fields that are logged as [`Points3D`] components, everything else is logged as is.
+const BUILTIN_FIELDS: &[&str] = &["x", "y", "z", "rgb", "rgba", "label"];
+
+fn is_pcd_file(path: impl AsRef<Path>) -> bool {
+    path.as_ref()
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("pcd"))
+}
+
+/// A [`DataLoader`] for [PCD](https://pointclouds.org/documentation/tutorials/pcd_file_format.html)
+/// files, as written by the Point Cloud Library.
+///
+/// Positions, colors, and labels (as class ids) are logged as [`Points3D`].
+/// All other per-point fields, such as `intensity` or `normal_x`, are logged as additional
+/// components with the same name. Invalid points of organized clouds are skipped.
+pub struct PcdLoader;
+
+impl DataLoader for PcdLoader {
+    fn name(&self) -> crate::DataLoaderName {
+        PCD_LOADER_NAME.into()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load_from_path(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        tx: Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        if !is_pcd_file(&filepath) {
+            return Err(DataLoaderError::Incompatible(filepath)); // simply not interested
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        let contents = std::fs::read(&filepath)?;
+        self.load_from_file_contents(settings, filepath, contents.into(), tx)
+    }
+
+    fn load_from_file_contents(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        contents: std::borrow::Cow<'_, [u8]>,
+        tx: Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        if !is_pcd_file(&filepath) {
+            return Err(DataLoaderError::Incompatible(filepath)); // simply not interested
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        let cloud = parse_pcd(&contents)
+            .with_context(|| format!("Failed to parse PCD file {filepath:?}"))?;
+
+        let entity_path = settings
+            .entity_path_prefix
+            .clone()
+            .map(|prefix| prefix / EntityPath::from_file_path(&filepath))
+            .unwrap_or_else(|| EntityPath::from_file_path(&filepath));
+
+        let store_id = settings.recommended_store_id();
+        for chunk in point_cloud_chunks(&cloud, entity_path)? {
+            if tx
+                .send(LoadedData::Chunk(
+                    PCD_LOADER_NAME.to_owned(),
+                    store_id.clone(),
+                    chunk,
+                ))
+                .is_err()
+            {
+                break; // The other end has decided to hang up, not our problem.
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn point_cloud_chunks(cloud: &PointCloud, entity_path: EntityPath) -> anyhow::Result<Vec<Chunk>> {
+    re_tracing::profile_function!();
+
+    let (Some(xs), Some(ys), Some(zs)) = (cloud.field("x"), cloud.field("y"), cloud.field("z"))
+    else {
+        anyhow::bail!("PCD file has no x, y, and z fields");
+    };
+
+    // Organized clouds mark missing measurements with NaN positions.
+    let valid = itertools::izip!(xs, ys, zs)
+        .map(|(x, y, z)| x.is_finite() && y.is_finite() && z.is_finite())
+        .collect::<Vec<_>>();
+    let valid_values = |values: &[f64]| {
+        values
+            .iter()
+            .zip(&valid)
+            .filter(|(_, is_valid)| **is_valid)
+            .map(|(value, _)| *value)
+            .collect::<Vec<_>>()
+    };
+
+    let positions = itertools::izip!(valid_values(xs), valid_values(ys), valid_values(zs))
+        .map(|(x, y, z)| [x as f32, y as f32, z as f32]);
+    let mut points = Points3D::new(positions);
+
+    if let Some(colors) = cloud.packed_colors() {
+        let colors = colors
+            .iter()
+            .zip(&valid)
+            .filter(|(_, is_valid)| **is_valid)
+            .map(|(&argb, _)| {
+                let [a, r, g, b] = argb.to_be_bytes();
+                Rgba32::from_unmultiplied_rgba(r, g, b, a)
+            });
+        points = points.with_colors(colors);
+    }
+
+    if let Some(labels) = cloud.field("label") {
+        let labels = valid_values(labels);
+        if labels.iter().all(|&label| label <= u16::MAX as f64) {
+            points = points.with_class_ids(labels.into_iter().map(|label| label as u16));
+        }
+    }
+
+    let mut batches = points.as_serialized_batches();
+    batches.extend(
+        cloud
+            .fields
+            .iter()
+            .filter(|(name, _)| !BUILTIN_FIELDS.contains(&name.as_str()))
+            .map(|(name, values)| SerializedComponentBatch {
+                descriptor: ComponentDescriptor::partial(name.as_str()),
+                array: Arc::new(arrow::array::Float64Array::from(valid_values(values))),
+            }),
+    );
+
+    let mut chunks = vec![
+        ChunkBuilder::new(ChunkId::new(), entity_path.clone())
+            .with_serialized_batches(RowId::new(), TimePoint::default(), batches)
+            .build()?,
+    ];
+
+    let [tx, ty, tz, qw, qx, qy, qz] = cloud.viewpoint;
+    if cloud.viewpoint != [0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0] {
+        let sensor_pose = Transform3D::from_translation([tx as f32, ty as f32, tz as f32])
+            .with_quaternion(Quaternion::from_xyzw([
+                qx as f32, qy as f32, qz as f32, qw as f32,
+            ]));
+        chunks.push(
+            ChunkBuilder::new(ChunkId::new(), entity_path)
+                .with_archetype(RowId::new(), TimePoint::default(), &sensor_pose)
+                .build()?,
+        );
+    }
+
+    Ok(chunks)
+}
@@ -2,6 +2,8 @@ use re_log_encoding::Decoder;
 
 #[cfg(not(target_arch = "wasm32"))]
 use crossbeam::channel::Receiver;
+#[cfg(not(target_arch = "wasm32"))]
+use re_log_encoding::PendingLogMsg;
 use re_log_types::ApplicationId;
 
 use crate::{DataLoader as _, LoadedData};
@@ -60,7 +62,8 @@ impl crate::DataLoader for RrdLoader {
                     .with_context(|| format!("Failed to open file {filepath:?}"))?;
                 let file = std::io::BufReader::new(file);
 
-                let messages = Decoder::decode_eager(file)?;
+                let messages = Decoder::<PendingLogMsg>::decode_eager(file)?;
+                let messages = decode_in_parallel(&filepath, messages)?;
 
                 // NOTE: This is IO bound, it must run on a dedicated thread, not the shared rayon thread pool.
                 std::thread::Builder::new()
@@ -92,7 +95,11 @@ impl crate::DataLoader for RrdLoader {
                     format!("failed to create retryable file reader for {filepath:?}")
                 })?;
                 let wait_for_eos = true;
-                let messages = Decoder::decode_eager_with_opts(retryable_reader, wait_for_eos)?;
+                let messages = Decoder::<PendingLogMsg>::decode_eager_with_opts(
+                    retryable_reader,
+                    wait_for_eos,
+                )?;
+                let messages = decode_in_parallel(&filepath, messages)?;
 
                 // NOTE: This is IO bound, it must run on a dedicated thread, not the shared rayon thread pool.
                 std::thread::Builder::new()
@@ -167,6 +174,55 @@ impl crate::DataLoader for RrdLoader {
     }
 }
 
+/// Finishes decoding the messages on the shared rayon thread pool, while preserving their order.
+///
+/// The messages are pulled from `msgs` on a dedicated thread, so that the ones that are ready can
+/// be handed off even while the reader is blocked waiting for more data (e.g. live `.rrd` files).
+#[cfg(not(target_arch = "wasm32"))]
+fn decode_in_parallel(
+    filepath: &std::path::Path,
+    msgs: impl Iterator<Item = Result<PendingLogMsg, re_log_encoding::DecodeError>> + Send + 'static,
+) -> Result<
+    impl Iterator<Item = Result<re_log_types::LogMsg, re_log_encoding::DecodeError>> + use<>,
+    crate::DataLoaderError,
+> {
+    use anyhow::Context as _;
+
+    // Bounds how many messages can be decoded ahead of the one we're waiting on.
+    let max_in_flight = 2 * rayon::current_num_threads();
+    let (tx_pending, rx_pending) = crossbeam::channel::bounded(max_in_flight);
+
+    // NOTE: This is IO bound, it must run on a dedicated thread, not the shared rayon thread pool.
+    std::thread::Builder::new()
+        .name(format!("decode_in_parallel({filepath:?})"))
+        .spawn(move || {
+            for msg in msgs {
+                let (tx_msg, rx_msg) = crossbeam::channel::bounded(1);
+
+                match msg {
+                    Ok(PendingLogMsg::Decoded(msg)) => {
+                        tx_msg.send(Ok(msg)).ok();
+                    }
+                    Ok(msg) => rayon::spawn(move || {
+                        tx_msg.send(msg.finish().map_err(Into::into)).ok();
+                    }),
+                    Err(err) => {
+                        tx_msg.send(Err(err)).ok();
+                    }
+                }
+
+                if tx_pending.send(rx_msg).is_err() {
+                    break; // The other end has decided to hang up, not our problem.
+                }
+            }
+        })
+        .with_context(|| format!("Failed to spawn IO thread for {filepath:?}"))?;
+
+    Ok(rx_pending
+        .into_iter()
+        .filter_map(|rx_msg| rx_msg.recv().ok()))
+}
+
 fn decode_and_stream(
     filepath: &std::path::Path,
     tx: &std::sync::mpsc::Sender<crate::LoadedData>,
@@ -0,0 +1,644 @@
+//! Rerun data loader for tabular data, i.e. CSV and Parquet files.
+
+use std::{collections::BTreeMap, path::Path, sync::Arc, sync::mpsc::Sender};
+
+use anyhow::Context as _;
+use arrow::{
+    array::{Array, ArrayRef, AsArray as _, RecordBatch},
+    compute::cast,
+    datatypes::{DataType, DurationNanosecondType, Float64Type, TimestampNanosecondType},
+};
+
+use re_chunk::{Chunk, ChunkId, TimeColumn, TimelineName, external::nohash_hasher::IntMap};
+use re_log_types::{EntityPath, EntityPathPart};
+use re_types::archetypes::Scalars;
+
+use crate::{DataLoader, DataLoaderError, LoadedData};
+
+const TABULAR_LOADER_NAME: &str = "TabularLoader";
+
+/// The timeline used when no column of the table looks like a time.
+const ROW_TIMELINE_NAME: &str = "row";
+
+/// Columns with these names (ignoring case and a unit suffix) are used as time.
+const TIME_COLUMN_NAMES: &[&str] = &["t", "time", "timestamp", "elapsed", "log_time", "wall_time"];
+
+/// Integer columns with these names (ignoring case) are used as a sequence.
+const SEQUENCE_COLUMN_NAMES: &[&str] = &[
+    "step",
+    "frame",
+    "frame_index",
+    "frame_nr",
+    "epoch",
+    "iteration",
+    "iter",
+    "tick",
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum FileFormat {
+    Csv { delimiter: u8 },
+    Parquet,
+}
+
+impl FileFormat {
+    fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_lowercase();
+        match extension.as_str() {
+            "csv" => Some(Self::Csv { delimiter: b',' }),
+            "tsv" => Some(Self::Csv { delimiter: b'\t' }),
+            "parquet" => Some(Self::Parquet),
+            _ => None,
+        }
+    }
+}
+
+/// Describes how the columns of a table are logged.
+///
+/// Read from a sidecar file next to the table, named after it with an additional `.rerun.json`
+/// extension, e.g. `metrics.csv.rerun.json`:
+/// ```json
+/// {
+///     "timelines": [{ "column": "t", "type": "duration", "unit": "ms" }],
+///     "entity_paths": { "loss": "train/loss" },
+///     "ignore": ["comment"]
+/// }
+/// ```
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct TabularConfig {
+    /// The columns to use as timelines, instead of guessing them from their names.
+    timelines: Vec<TimelineConfig>,
+
+    /// The entity path of columns, relative to the entity of the file.
+    ///
+    /// Defaults to the name of the column.
+    entity_paths: BTreeMap<String, String>,
+
+    /// Columns that aren't logged at all.
+    ignore: Vec<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TimelineConfig {
+    column: String,
+
+    /// Name of the timeline, defaults to the name of the column.
+    #[serde(default)]
+    name: Option<String>,
+
+    /// Guessed from the type of the column if missing.
+    #[serde(default, rename = "type")]
+    kind: Option<TimelineKind>,
+
+    /// Unit of numeric durations and timestamps, defaults to seconds.
+    #[serde(default)]
+    unit: Option<TimeUnit>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum TimelineKind {
+    Sequence,
+    Duration,
+    Timestamp,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum TimeUnit {
+    S,
+    Ms,
+    Us,
+    Ns,
+}
+
+impl TimeUnit {
+    fn nanos(self) -> f64 {
+        match self {
+            Self::S => 1e9,
+            Self::Ms => 1e6,
+            Self::Us => 1e3,
+            Self::Ns => 1.0,
+        }
+    }
+
+    /// Splits a unit suffix such as `_ms` off a column name.
+    fn split_suffix(name: &str) -> (&str, Option<Self>) {
+        for (suffix, unit) in [
+            ("_s", Self::S),
+            ("_sec", Self::S),
+            ("_ms", Self::Ms),
+            ("_us", Self::Us),
+            ("_ns", Self::Ns),
+        ] {
+            if let Some(stem) = name.strip_suffix(suffix) {
+                return (stem, Some(unit));
+            }
+        }
+        (name, None)
+    }
+
+    /// Guesses the unit of timestamps since the Unix epoch from their magnitude.
+    ///
+    /// Returns `None` if the values are too small to be timestamps, i.e. before 1973.
+    fn guess_epoch_unit(value: f64) -> Option<Self> {
+        let value = value.abs();
+        if value > 1e17 {
+            Some(Self::Ns)
+        } else if value > 1e14 {
+            Some(Self::Us)
+        } else if value > 1e11 {
+            Some(Self::Ms)
+        } else if value > 1e8 {
+            Some(Self::S)
+        } else {
+            None
+        }
+    }
+}
+
+/// A column of the table that is used as a timeline.
+struct TimeSource {
+    column: String,
+    name: TimelineName,
+    kind: TimelineKind,
+
+    /// One value per row of the table, in nanoseconds for durations and timestamps.
+    values: Vec<Option<i64>>,
+}
+
+/// A [`DataLoader`] for tabular data: `.csv`, `.tsv`, and `.parquet` files.
+///
+/// Every numeric or boolean column is logged as a [`Scalars`] time series, at an entity named
+/// after the column under the entity of the file.
+///
+/// Columns that look like a time are used as timelines: timestamp and duration columns, integer
+/// columns named e.g. `step`, `frame`, or `epoch`, and numeric columns named e.g. `t`, `time`, or
+/// `timestamp_ms`. Numeric times are durations in seconds unless the column name says otherwise,
+/// or their magnitude gives them away as timestamps since the Unix epoch. Tables without any such
+/// column are logged on a `row` timeline.
+///
+/// The timelines, the entity paths of columns, and columns to ignore can be specified in a
+/// `<file>.rerun.json` sidecar file instead, which is only read on native.
+pub struct TabularLoader;
+
+impl DataLoader for TabularLoader {
+    fn name(&self) -> crate::DataLoaderName {
+        TABULAR_LOADER_NAME.into()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load_from_path(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        tx: Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        if FileFormat::from_path(&filepath).is_none() || !filepath.is_file() {
+            return Err(DataLoaderError::Incompatible(filepath)); // simply not interested
+        }
+
+        let contents = std::fs::read(&filepath)?;
+        self.load_from_file_contents(settings, filepath, contents.into(), tx)
+    }
+
+    fn load_from_file_contents(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        contents: std::borrow::Cow<'_, [u8]>,
+        tx: Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        let Some(format) = FileFormat::from_path(&filepath) else {
+            return Err(DataLoaderError::Incompatible(filepath)); // simply not interested
+        };
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        let config = read_config(&filepath)?;
+        let batch = read_table(format, contents)
+            .with_context(|| format!("Failed to read table {filepath:?}"))?;
+
+        let entity_path = settings
+            .entity_path_prefix
+            .clone()
+            .map(|prefix| prefix / EntityPath::from_file_path(&filepath))
+            .unwrap_or_else(|| EntityPath::from_file_path(&filepath));
+
+        let store_id = settings.recommended_store_id();
+        for chunk in table_chunks(&batch, &config, &entity_path)? {
+            if tx
+                .send(LoadedData::Chunk(
+                    TABULAR_LOADER_NAME.to_owned(),
+                    store_id.clone(),
+                    chunk,
+                ))
+                .is_err()
+            {
+                break; // The other end has decided to hang up, not our problem.
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn read_config(filepath: &Path) -> anyhow::Result<TabularConfig> {
+    let mut config_path = filepath.as_os_str().to_owned();
+    config_path.push(".rerun.json");
+
+    match std::fs::read(&config_path) {
+        Ok(contents) => serde_json::from_slice(&contents)
+            .with_context(|| format!("Failed to parse table config {config_path:?}")),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(TabularConfig::default()),
+        Err(err) => {
+            Err(err).with_context(|| format!("Failed to read table config {config_path:?}"))
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+#[expect(clippy::unnecessary_wraps)]
+fn read_config(_filepath: &Path) -> anyhow::Result<TabularConfig> {
+    Ok(TabularConfig::default())
+}
+
+fn read_table(
+    format: FileFormat,
+    contents: std::borrow::Cow<'_, [u8]>,
+) -> anyhow::Result<RecordBatch> {
+    re_tracing::profile_function!();
+
+    match format {
+        FileFormat::Csv { delimiter } => {
+            let format = arrow::csv::reader::Format::default()
+                .with_header(true)
+                .with_delimiter(delimiter);
+
+            // Looking at all records is slower, but a column that only turns out to contain text
+            // at the very end of the file would fail the whole load otherwise.
+            let (schema, _) = format.infer_schema(std::io::Cursor::new(&*contents), None)?;
+            let schema = Arc::new(schema);
+            let batches = arrow::csv::ReaderBuilder::new(schema.clone())
+                .with_format(format)
+                .build(std::io::Cursor::new(&*contents))?
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok(arrow::compute::concat_batches(&schema, &batches)?)
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        FileFormat::Parquet => {
+            let builder = parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder::try_new(
+                bytes::Bytes::from(contents.into_owned()),
+            )?;
+            let schema = builder.schema().clone();
+            let batches = builder.build()?.collect::<Result<Vec<_>, _>>()?;
+
+            Ok(arrow::compute::concat_batches(&schema, &batches)?)
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        FileFormat::Parquet => {
+            anyhow::bail!("Parquet files are not supported on the web")
+        }
+    }
+}
+
+/// Converts a table into one chunk of [`Scalars`] per column.
+fn table_chunks(
+    batch: &RecordBatch,
+    config: &TabularConfig,
+    entity_path: &EntityPath,
+) -> anyhow::Result<Vec<Chunk>> {
+    re_tracing::profile_function!();
+
+    let time_sources = time_sources(batch, config)?;
+
+    // Time columns don't support nulls, so rows without a time can't be logged at all.
+    let timed_rows = (0..batch.num_rows())
+        .filter(|&row| {
+            time_sources
+                .iter()
+                .all(|source| source.values[row].is_some())
+        })
+        .collect::<Vec<_>>();
+
+    let mut chunks = Vec::new();
+    for (field, array) in batch.schema().fields().iter().zip(batch.columns()) {
+        let column = field.name();
+        if config.ignore.contains(column)
+            || time_sources.iter().any(|source| &source.column == column)
+        {
+            continue;
+        }
+
+        let Some(values) = scalar_values(array)? else {
+            re_log::debug!("Skipping column {column:?} of type {}", field.data_type());
+            continue;
+        };
+
+        let rows = timed_rows
+            .iter()
+            .copied()
+            .filter(|&row| values.is_valid(row))
+            .collect::<Vec<_>>();
+        if rows.is_empty() {
+            continue;
+        }
+
+        let timelines = if time_sources.is_empty() {
+            std::iter::once((
+                TimelineName::from(ROW_TIMELINE_NAME),
+                TimeColumn::new_sequence(ROW_TIMELINE_NAME, rows.iter().map(|&row| row as i64)),
+            ))
+            .collect::<IntMap<_, _>>()
+        } else {
+            time_sources
+                .iter()
+                .map(|source| (source.name, source.time_column(&rows)))
+                .collect()
+        };
+
+        let column_path = match config.entity_paths.get(column) {
+            Some(path) => entity_path.join(&EntityPath::parse_forgiving(path)),
+            None => entity_path.join(&EntityPath::from(vec![EntityPathPart::new(column)])),
+        };
+
+        chunks.push(Chunk::from_auto_row_ids(
+            ChunkId::new(),
+            column_path,
+            timelines,
+            Scalars::update_fields()
+                .with_scalars(rows.iter().map(|&row| values.value(row)))
+                .columns_of_unit_batches()?
+                .collect(),
+        )?);
+    }
+
+    Ok(chunks)
+}
+
+/// Finds the columns to use as timelines, either from the config or by guessing.
+fn time_sources(batch: &RecordBatch, config: &TabularConfig) -> anyhow::Result<Vec<TimeSource>> {
+    let schema = batch.schema();
+
+    if !config.timelines.is_empty() {
+        return config
+            .timelines
+            .iter()
+            .map(|timeline| {
+                let array = batch
+                    .column_by_name(&timeline.column)
+                    .with_context(|| format!("Missing time column {:?}", timeline.column))?;
+                let kind = timeline
+                    .kind
+                    .unwrap_or_else(|| guess_kind(array.data_type()));
+                TimeSource::new(
+                    &timeline.column,
+                    timeline.name.as_deref().unwrap_or(&timeline.column),
+                    kind,
+                    array,
+                    timeline.unit,
+                )
+            })
+            .collect();
+    }
+
+    let mut sources = Vec::new();
+    for (field, array) in schema.fields().iter().zip(batch.columns()) {
+        let column = field.name();
+        if config.ignore.contains(column) {
+            continue;
+        }
+
+        let (stem, unit) = TimeUnit::split_suffix(column);
+        let stem = stem.to_lowercase();
+        let data_type = array.data_type();
+
+        let (kind, unit) = match data_type {
+            DataType::Timestamp(..) | DataType::Date32 | DataType::Date64 => {
+                (TimelineKind::Timestamp, unit)
+            }
+            DataType::Duration(_) => (TimelineKind::Duration, unit),
+            _ if data_type.is_integer() && SEQUENCE_COLUMN_NAMES.contains(&stem.as_str()) => {
+                (TimelineKind::Sequence, unit)
+            }
+            _ if data_type.is_numeric() && TIME_COLUMN_NAMES.contains(&stem.as_str()) => {
+                let first = cast(array, &DataType::Float64)?
+                    .as_primitive::<Float64Type>()
+                    .iter()
+                    .flatten()
+                    .next();
+                match first.and_then(TimeUnit::guess_epoch_unit) {
+                    Some(epoch_unit) => (TimelineKind::Timestamp, unit.or(Some(epoch_unit))),
+                    None => (TimelineKind::Duration, unit),
+                }
+            }
+            _ => continue,
+        };
+
+        sources.push(TimeSource::new(column, column, kind, array, unit)?);
+    }
+
+    Ok(sources)
+}
+
+fn guess_kind(data_type: &DataType) -> TimelineKind {
+    match data_type {
+        DataType::Duration(_) => TimelineKind::Duration,
+        _ if data_type.is_integer() => TimelineKind::Sequence,
+        _ if data_type.is_floating() => TimelineKind::Duration,
+        _ => TimelineKind::Timestamp,
+    }
+}
+
+impl TimeSource {
+    fn new(
+        column: &str,
+        name: &str,
+        kind: TimelineKind,
+        array: &ArrayRef,
+        unit: Option<TimeUnit>,
+    ) -> anyhow::Result<Self> {
+        let values = match (kind, array.data_type()) {
+            (
+                TimelineKind::Timestamp,
+                DataType::Timestamp(..) | DataType::Date32 | DataType::Date64 | DataType::Utf8,
+            ) => {
+                // Dropping the timezone would shift the values to local time.
+                let timezone = match array.data_type() {
+                    DataType::Timestamp(_, timezone) => timezone.clone(),
+                    _ => None,
+                };
+                cast(
+                    array,
+                    &DataType::Timestamp(arrow::datatypes::TimeUnit::Nanosecond, timezone),
+                )?
+                .as_primitive::<TimestampNanosecondType>()
+                .iter()
+                .collect()
+            }
+
+            (TimelineKind::Duration, DataType::Duration(_)) => cast(
+                array,
+                &DataType::Duration(arrow::datatypes::TimeUnit::Nanosecond),
+            )?
+            .as_primitive::<DurationNanosecondType>()
+            .iter()
+            .collect(),
+
+            (_, data_type) if data_type.is_numeric() => {
+                let scale = match kind {
+                    TimelineKind::Sequence => 1.0,
+                    TimelineKind::Duration | TimelineKind::Timestamp => {
+                        unit.unwrap_or(TimeUnit::S).nanos()
+                    }
+                };
+                cast(array, &DataType::Float64)?
+                    .as_primitive::<Float64Type>()
+                    .iter()
+                    .map(|value| value.map(|value| (value * scale).round() as i64))
+                    .collect()
+            }
+
+            (kind, data_type) => {
+                anyhow::bail!(
+                    "Can't use column {column:?} of type {data_type} as a {kind:?} timeline"
+                )
+            }
+        };
+
+        Ok(Self {
+            column: column.to_owned(),
+            name: name.into(),
+            kind,
+            values,
+        })
+    }
+
+    /// The time column of the given rows, which must all have a value.
+    fn time_column(&self, rows: &[usize]) -> TimeColumn {
+        let times = rows.iter().map(|&row| self.values[row].unwrap_or_default());
+        match self.kind {
+            TimelineKind::Sequence => TimeColumn::new_sequence(self.name, times),
+            TimelineKind::Duration => TimeColumn::new_duration_nanos(self.name, times),
+            TimelineKind::Timestamp => {
+                TimeColumn::new_timestamp_nanos_since_epoch(self.name, times)
+            }
+        }
+    }
+}
+
+/// Returns the values of numeric and boolean columns as floats, or `None` for other columns.
+fn scalar_values(array: &ArrayRef) -> anyhow::Result<Option<arrow::array::Float64Array>> {
+    let data_type = array.data_type();
+    if !data_type.is_numeric() && data_type != &DataType::Boolean {
+        return Ok(None);
+    }
+
+    Ok(Some(
+        cast(array, &DataType::Float64)?
+            .as_primitive::<Float64Type>()
+            .clone(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load_csv(csv: &str, config: &TabularConfig) -> Vec<Chunk> {
+        let batch = read_table(FileFormat::Csv { delimiter: b',' }, csv.as_bytes().into()).unwrap();
+        table_chunks(&batch, config, &EntityPath::from("metrics")).unwrap()
+    }
+
+    fn timeline_names(chunk: &Chunk) -> Vec<String> {
+        let mut names = chunk
+            .timelines()
+            .keys()
+            .map(|name| name.to_string())
+            .collect::<Vec<_>>();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn test_guessed_timelines() {
+        let chunks = load_csv(
+            "step,time_ms,loss,accuracy,comment\n\
+             0,0,1.5,0.1,start\n\
+             1,500,1.25,,\n\
+             2,1000,1.0,0.3,end\n",
+            &TabularConfig::default(),
+        );
+
+        // The text column is skipped.
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].entity_path(), &EntityPath::from("metrics/loss"));
+        assert_eq!(chunks[0].num_rows(), 3);
+        assert_eq!(timeline_names(&chunks[0]), ["step", "time_ms"]);
+
+        let time = &chunks[0].timelines()[&TimelineName::from("time_ms")];
+        assert_eq!(time.times_raw(), [0, 500_000_000, 1_000_000_000]);
+
+        // Missing values are skipped.
+        assert_eq!(
+            chunks[1].entity_path(),
+            &EntityPath::from("metrics/accuracy")
+        );
+        assert_eq!(chunks[1].num_rows(), 2);
+    }
+
+    #[test]
+    fn test_epoch_timestamps() {
+        let chunks = load_csv(
+            "timestamp,value\n1700000000.5,1\n1700000001.5,2\n",
+            &TabularConfig::default(),
+        );
+
+        assert_eq!(chunks.len(), 1);
+        let time = &chunks[0].timelines()[&TimelineName::from("timestamp")];
+        assert_eq!(time.timeline().typ(), re_log_types::TimeType::TimestampNs);
+        assert_eq!(
+            time.times_raw(),
+            [1_700_000_000_500_000_000, 1_700_000_001_500_000_000]
+        );
+    }
+
+    #[test]
+    fn test_row_timeline() {
+        let chunks = load_csv("a,b\n1,2\n3,4\n", &TabularConfig::default());
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(timeline_names(&chunks[0]), [ROW_TIMELINE_NAME]);
+    }
+
+    #[test]
+    fn test_config() {
+        let config: TabularConfig = serde_json::from_str(
+            r#"{
+                "timelines": [{ "column": "t", "name": "sim_time", "type": "duration", "unit": "ms" }],
+                "entity_paths": { "x": "robot/position/x" },
+                "ignore": ["y"]
+            }"#,
+        )
+        .unwrap();
+
+        let chunks = load_csv("t,x,y,step\n0,1,2,0\n10,3,4,1\n", &config);
+
+        // `step` is not a timeline when the timelines are configured.
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(
+            chunks[0].entity_path(),
+            &EntityPath::from("metrics/robot/position/x")
+        );
+        assert_eq!(timeline_names(&chunks[0]), ["sim_time"]);
+        let time = &chunks[0].timelines()[&TimelineName::from("sim_time")];
+        assert_eq!(time.times_raw(), [0, 10_000_000]);
+        assert_eq!(chunks[1].entity_path(), &EntityPath::from("metrics/step"));
+    }
+}
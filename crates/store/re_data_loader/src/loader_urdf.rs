@@ -231,7 +231,8 @@ impl UrdfTree {
         self.joints.iter().find(|j| j.name == joint_name)
     }
 
-    fn get_joint_path(&self, joint: &Joint) -> EntityPath {
+    /// The entity path of a joint, where its fixed origin pose is logged.
+    pub fn get_joint_path(&self, joint: &Joint) -> EntityPath {
         let parent_path = self.get_link_path_by_name(&joint.parent.link);
         parent_path / EntityPathPart::new(&joint.name)
     }
@@ -256,6 +257,68 @@ impl UrdfTree {
     pub fn get_joint_child(&self, joint: &Joint) -> &Link {
         &self.links[&joint.child.link] // Safe because we checked that the joint's child link exists in `new()`
     }
+
+    /// Compute the transform that moves the child link of `joint` to the given joint position.
+    ///
+    /// The position is an angle in radians for revolute and continuous joints,
+    /// and a distance in meters for prismatic joints.
+    /// Positions of revolute and prismatic joints are clamped to the joint limits.
+    ///
+    /// Returns the entity path of the child link together with the transform to log there.
+    /// The fixed origin pose of the joint is already logged to the joint entity by the [`UrdfDataLoader`],
+    /// so logging the returned transform is all that is needed to animate the robot.
+    ///
+    /// Returns `None` for joints that can't be described by a single position, e.g. fixed or floating joints.
+    pub fn joint_transform(
+        &self,
+        joint: &Joint,
+        position: f64,
+    ) -> Option<(EntityPath, Transform3D)> {
+        let transform = transform_from_joint_position(joint, position)?;
+        let link_path = self.get_link_path(self.get_joint_child(joint));
+        Some((link_path, transform))
+    }
+
+    /// Compute the transforms for a set of joint positions, e.g. a ROS `JointState` message.
+    ///
+    /// Joints are identified by name. Joints that mimic one of the given joints are moved along with it.
+    /// See [`Self::joint_transform`] for details.
+    pub fn joint_transforms<'a>(
+        &self,
+        joint_positions: impl IntoIterator<Item = (&'a str, f64)>,
+    ) -> anyhow::Result<Vec<(EntityPath, Transform3D)>> {
+        let mut positions = HashMap::<&str, f64>::new();
+        for (joint_name, position) in joint_positions {
+            if self.get_joint_by_name(joint_name).is_none() {
+                bail!("Unknown joint {joint_name:?} in URDF {:?}", self.name);
+            }
+            positions.insert(joint_name, position);
+        }
+
+        let mimic_positions = self
+            .joints
+            .iter()
+            .filter(|joint| !positions.contains_key(joint.name.as_str()))
+            .filter_map(|joint| {
+                let mimic = joint.mimic.as_ref()?;
+                let position = positions.get(mimic.joint.as_str())?;
+                Some((
+                    joint.name.as_str(),
+                    position * mimic.multiplier.unwrap_or(1.0) + mimic.offset.unwrap_or(0.0),
+                ))
+            })
+            .collect_vec();
+        positions.extend(mimic_positions);
+
+        Ok(self
+            .joints
+            .iter()
+            .filter_map(|joint| {
+                let position = positions.get(joint.name.as_str())?;
+                self.joint_transform(joint, *position)
+            })
+            .collect())
+    }
 }
 
 fn log_robot(
@@ -366,6 +429,37 @@ fn transform_from_pose(origin: &urdf_rs::Pose) -> Transform3D {
         .with_quaternion(quaternion)
 }
 
+fn transform_from_joint_position(joint: &Joint, position: f64) -> Option<Transform3D> {
+    let clamped_position = || {
+        let urdf_rs::JointLimit { lower, upper, .. } = joint.limit;
+        if lower < upper {
+            position.clamp(lower, upper)
+        } else {
+            position // No (valid) limits specified.
+        }
+    };
+
+    let axis = glam::DVec3::from(joint.axis.xyz.0)
+        .normalize_or_zero()
+        .as_vec3();
+    if axis == glam::Vec3::ZERO {
+        return None;
+    }
+
+    match joint.joint_type {
+        urdf_rs::JointType::Revolute => Some(transform_from_rotation(axis, clamped_position())),
+        urdf_rs::JointType::Continuous => Some(transform_from_rotation(axis, position)),
+        urdf_rs::JointType::Prismatic => {
+            Some(Transform3D::update_fields().with_translation(axis * clamped_position() as f32))
+        }
+        _ => None,
+    }
+}
+
+fn transform_from_rotation(axis: glam::Vec3, angle: f64) -> Transform3D {
+    Transform3D::update_fields().with_quaternion(glam::Quat::from_axis_angle(axis, angle as f32))
+}
+
 fn send_transform(
     tx: &Sender<LoadedData>,
     store_id: &StoreId,
@@ -704,3 +798,79 @@ fn resolve_package_uri(uri: &str) -> anyhow::Result<PathBuf> {
         "Failed to resolve package URI: {uri}, tried `ROS_PACKAGE_PATH` and `AMENT_PREFIX_PATH`, but no matching package found"
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const URDF: &str = r#"
+        <robot name="arm">
+            <link name="base"/>
+            <link name="upper"/>
+            <link name="lower"/>
+            <link name="finger"/>
+            <joint name="shoulder" type="revolute">
+                <parent link="base"/>
+                <child link="upper"/>
+                <axis xyz="0 0 2"/>
+                <limit lower="-1" upper="1" effort="1" velocity="1"/>
+            </joint>
+            <joint name="elbow" type="prismatic">
+                <parent link="upper"/>
+                <child link="lower"/>
+                <axis xyz="1 0 0"/>
+                <limit lower="0" upper="0.5" effort="1" velocity="1"/>
+                <mimic joint="shoulder" multiplier="0.5" offset="0.1"/>
+            </joint>
+            <joint name="wrist" type="fixed">
+                <parent link="lower"/>
+                <child link="finger"/>
+            </joint>
+        </robot>
+    "#;
+
+    fn urdf_tree() -> UrdfTree {
+        UrdfTree::new(urdf_rs::read_from_string(URDF).unwrap(), None).unwrap()
+    }
+
+    #[test]
+    fn test_joint_transform() {
+        let urdf = urdf_tree();
+
+        let shoulder = urdf.get_joint_by_name("shoulder").unwrap();
+        let (path, transform) = urdf.joint_transform(shoulder, 2.0).unwrap();
+        assert_eq!(path, EntityPath::from("arm/base/shoulder/upper"));
+        assert_eq!(
+            transform,
+            Transform3D::update_fields()
+                .with_quaternion(glam::Quat::from_axis_angle(glam::Vec3::Z, 1.0)),
+            "position should be clamped to the joint limits"
+        );
+
+        let wrist = urdf.get_joint_by_name("wrist").unwrap();
+        assert!(urdf.joint_transform(wrist, 1.0).is_none());
+    }
+
+    #[test]
+    fn test_joint_transforms_with_mimic() {
+        let urdf = urdf_tree();
+
+        let transforms = urdf.joint_transforms([("shoulder", 0.5)]).unwrap();
+        assert_eq!(
+            transforms,
+            vec![
+                (
+                    EntityPath::from("arm/base/shoulder/upper"),
+                    Transform3D::update_fields()
+                        .with_quaternion(glam::Quat::from_axis_angle(glam::Vec3::Z, 0.5)),
+                ),
+                (
+                    EntityPath::from("arm/base/shoulder/upper/elbow/lower"),
+                    Transform3D::update_fields().with_translation(glam::vec3(0.35, 0.0, 0.0)),
+                ),
+            ]
+        );
+
+        assert!(urdf.joint_transforms([("knee", 0.0)]).is_err());
+    }
+}
@@ -0,0 +1,712 @@
+//! Rerun data loader for USD scenes, see [`crate::usd`].
+
+use std::{path::Path, sync::mpsc::Sender};
+
+use anyhow::Context as _;
+
+use re_chunk::{Chunk, ChunkBuilder, ChunkId, RowId, TimePoint};
+use re_log_types::{EntityPath, EntityPathPart};
+use re_types::{
+    AsComponents,
+    archetypes::{
+        Boxes3D, Capsules3D, Cylinders3D, Ellipsoids3D, Mesh3D, Points3D, Transform3D,
+        ViewCoordinates,
+    },
+    components::FillMode,
+    datatypes::Rgba32,
+    external::glam,
+};
+
+use crate::{
+    DataLoader, DataLoaderError, DataLoaderSettings, LoadedData,
+    usd::{Prim, Stage, Value},
+};
+
+const USD_LOADER_NAME: &str = "UsdLoader";
+
+/// The extensions of USD layers and archives.
+const USD_EXTENSIONS: &[&str] = &["usd", "usda", "usdc", "usdz"];
+
+fn is_usd_file(path: impl AsRef<Path>) -> bool {
+    path.as_ref().extension().is_some_and(|ext| {
+        USD_EXTENSIONS
+            .iter()
+            .any(|usd| ext.eq_ignore_ascii_case(usd))
+    })
+}
+
+/// A [`DataLoader`] for [USD](https://openusd.org/) scenes: `.usd`, `.usda`, `.usdc`, and `.usdz`.
+///
+/// Every prim is logged to the entity of the same path below the file's entity, with its local
+/// transform as a [`Transform3D`]. The up axis and units of the stage are applied at the root.
+///
+/// `Mesh` prims are logged as [`Mesh3D`]s, `Points` as [`Points3D`], and the `Cube`, `Sphere`,
+/// `Cylinder`, and `Capsule` shapes as the matching archetypes. Colors come from the diffuse
+/// color of the bound `UsdPreviewSurface` material, or from the `displayColor` primvar.
+///
+/// Only the root layer is loaded: references, payloads, sublayers, and variants are ignored.
+pub struct UsdLoader;
+
+impl DataLoader for UsdLoader {
+    fn name(&self) -> crate::DataLoaderName {
+        USD_LOADER_NAME.into()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load_from_path(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        tx: Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        if !is_usd_file(&filepath) || !filepath.is_file() {
+            return Err(DataLoaderError::Incompatible(filepath)); // simply not interested
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        let settings = settings.clone();
+
+        // NOTE: this must be spawned on a dedicated thread, since large scenes take a while to
+        // parse and we don't want to block the common rayon thread pool in the meantime.
+        std::thread::Builder::new()
+            .name(format!("load_usd({filepath:?})"))
+            .spawn(move || {
+                let result = std::fs::read(&filepath)
+                    .map_err(anyhow::Error::from)
+                    .and_then(|contents| load_scene(&settings, &filepath, &contents, &tx));
+                if let Err(err) = result {
+                    re_log::error!("Failed to load USD scene {filepath:?}: {err}");
+                }
+            })
+            .with_context(|| "Failed to spawn IO thread to load USD scene")?;
+
+        Ok(())
+    }
+
+    fn load_from_file_contents(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        contents: std::borrow::Cow<'_, [u8]>,
+        tx: Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        if !is_usd_file(&filepath) {
+            return Err(DataLoaderError::Incompatible(filepath)); // simply not interested
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        load_scene(settings, &filepath, &contents, &tx)?;
+
+        Ok(())
+    }
+}
+
+fn load_scene(
+    settings: &DataLoaderSettings,
+    filepath: &Path,
+    contents: &[u8],
+    tx: &Sender<LoadedData>,
+) -> anyhow::Result<()> {
+    let stage =
+        Stage::parse(contents).with_context(|| format!("Failed to read USD scene {filepath:?}"))?;
+
+    let root = settings
+        .entity_path_prefix
+        .clone()
+        .map(|prefix| prefix / EntityPath::from_file_path(filepath))
+        .unwrap_or_else(|| EntityPath::from_file_path(filepath));
+
+    let store_id = settings.recommended_store_id();
+    for chunk in scene_chunks(&stage, &root)? {
+        if tx
+            .send(LoadedData::Chunk(
+                USD_LOADER_NAME.to_owned(),
+                store_id.clone(),
+                chunk,
+            ))
+            .is_err()
+        {
+            break; // The other end has decided to hang up, not our problem.
+        }
+    }
+
+    Ok(())
+}
+
+fn chunk(entity_path: EntityPath, archetype: &dyn AsComponents) -> anyhow::Result<Chunk> {
+    Ok(ChunkBuilder::new(ChunkId::new(), entity_path)
+        .with_archetype(RowId::new(), TimePoint::default(), archetype)
+        .build()?)
+}
+
+/// Converts a whole stage into chunks, with the prims below `root`.
+fn scene_chunks(stage: &Stage, root: &EntityPath) -> anyhow::Result<Vec<Chunk>> {
+    re_tracing::profile_function!();
+
+    let mut chunks = Vec::new();
+
+    let view_coordinates = match stage.up_axis.as_deref() {
+        Some("Z") => ViewCoordinates::RIGHT_HAND_Z_UP(),
+        _ => ViewCoordinates::RIGHT_HAND_Y_UP(), // The default of USD.
+    };
+    chunks.push(chunk(root.clone(), &view_coordinates)?);
+
+    if let Some(meters_per_unit) = stage.meters_per_unit
+        && meters_per_unit > 0.0
+        && (meters_per_unit - 1.0).abs() > f64::EPSILON
+    {
+        chunks.push(chunk(
+            root.clone(),
+            &Transform3D::update_fields().with_scale(meters_per_unit as f32),
+        )?);
+    }
+
+    // Invisible prims hide their whole subtree.
+    let mut hidden: Vec<&str> = Vec::new();
+    for (path, prim) in stage.walk() {
+        if hidden.iter().any(|hidden| is_descendant(path, hidden)) {
+            continue;
+        }
+        let is_invisible = prim
+            .attribute("visibility")
+            .and_then(Value::as_text)
+            .is_some_and(|visibility| visibility == "invisible");
+        let is_guide = prim
+            .attribute("purpose")
+            .and_then(Value::as_text)
+            .is_some_and(|purpose| purpose == "guide");
+        if is_invisible || is_guide {
+            hidden.push(path);
+            continue;
+        }
+
+        let entity_path = root.join(&prim_entity_path(path));
+
+        if let Some(transform) = local_transform(prim)
+            && !transform.abs_diff_eq(glam::DMat4::IDENTITY, 1e-9)
+        {
+            chunks.push(chunk(entity_path.clone(), &transform3d(&transform))?);
+        }
+
+        let color = prim_color(stage, prim);
+        let geometry_chunk = match prim.type_name.as_str() {
+            "Mesh" => mesh(prim, color).map(|mesh| chunk(entity_path, &mesh)),
+            "Points" => points(prim, color).map(|points| chunk(entity_path, &points)),
+            "Cube" => {
+                let half_size = attribute_f32(prim, "size").unwrap_or(2.0) * 0.5;
+                let boxes = Boxes3D::from_half_sizes([[half_size; 3]])
+                    .with_fill_mode(FillMode::Solid)
+                    .with_colors(color);
+                Some(chunk(entity_path, &boxes))
+            }
+            "Sphere" => {
+                let radius = attribute_f32(prim, "radius").unwrap_or(1.0);
+                let ellipsoids = Ellipsoids3D::from_radii([radius])
+                    .with_fill_mode(FillMode::Solid)
+                    .with_colors(color);
+                Some(chunk(entity_path, &ellipsoids))
+            }
+            "Cylinder" => {
+                let height = attribute_f32(prim, "height").unwrap_or(2.0);
+                let radius = attribute_f32(prim, "radius").unwrap_or(1.0);
+                let cylinders = Cylinders3D::from_lengths_and_radii([height], [radius])
+                    .with_quaternions([axis_rotation(prim)])
+                    .with_fill_mode(FillMode::Solid)
+                    .with_colors(color);
+                Some(chunk(entity_path, &cylinders))
+            }
+            "Capsule" => {
+                let height = attribute_f32(prim, "height").unwrap_or(1.0);
+                let radius = attribute_f32(prim, "radius").unwrap_or(0.5);
+                // USD capsules are centered, ours start at the origin.
+                let rotation = axis_rotation(prim);
+                let capsules = Capsules3D::from_lengths_and_radii([height], [radius])
+                    .with_translations([rotation * glam::vec3(0.0, 0.0, -0.5 * height)])
+                    .with_quaternions([rotation])
+                    .with_colors(color);
+                Some(chunk(entity_path, &capsules))
+            }
+            _ => None,
+        };
+        if let Some(geometry_chunk) = geometry_chunk {
+            chunks.push(geometry_chunk?);
+        }
+    }
+
+    Ok(chunks)
+}
+
+fn is_descendant(path: &str, ancestor: &str) -> bool {
+    path.strip_prefix(ancestor)
+        .is_some_and(|rest| rest.starts_with('/'))
+}
+
+/// The entity path of a prim, relative to the root of the scene.
+fn prim_entity_path(path: &str) -> EntityPath {
+    EntityPath::from(
+        path.split('/')
+            .filter(|name| !name.is_empty())
+            .map(EntityPathPart::from)
+            .collect::<Vec<_>>(),
+    )
+}
+
+fn attribute_f32(prim: &Prim, name: &str) -> Option<f32> {
+    prim.attribute(name)?.as_f64().map(|value| value as f32)
+}
+
+/// Rotates the +Z axis of our shapes onto the `axis` of a USD shape.
+fn axis_rotation(prim: &Prim) -> glam::Quat {
+    match prim.attribute("axis").and_then(Value::as_text) {
+        Some("X") => glam::Quat::from_rotation_y(std::f32::consts::FRAC_PI_2),
+        Some("Y") => glam::Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2),
+        _ => glam::Quat::IDENTITY,
+    }
+}
+
+/// Computes the local transform of a prim from its `xformOpOrder`.
+///
+/// Operations are applied right to left, i.e. the last operation of the order is applied first.
+fn local_transform(prim: &Prim) -> Option<glam::DMat4> {
+    let order = prim.attribute("xformOpOrder")?.as_texts()?;
+
+    let mut transform = glam::DMat4::IDENTITY;
+    for op in order {
+        if op == "!resetXformStack!" {
+            continue; // The hierarchy of entities can't be broken.
+        }
+        let (is_inverse, name) = match op.strip_prefix("!invert!") {
+            Some(name) => (true, name),
+            None => (false, op.as_str()),
+        };
+        let Some(value) = prim.attribute(name) else {
+            continue;
+        };
+        let op_type = name
+            .strip_prefix("xformOp:")
+            .and_then(|op_type| op_type.split(':').next())
+            .unwrap_or_default();
+        let Some(matrix) = xform_op_matrix(op_type, value) else {
+            re_log::warn_once!("Unsupported USD transform operation {name:?}");
+            continue;
+        };
+        transform *= if is_inverse { matrix.inverse() } else { matrix };
+    }
+
+    Some(transform)
+}
+
+fn xform_op_matrix(op_type: &str, value: &Value) -> Option<glam::DMat4> {
+    let vec3 = || -> Option<glam::DVec3> {
+        Some(glam::DVec3::from_slice(
+            value.as_tuple().filter(|v| v.len() == 3)?,
+        ))
+    };
+    let rotation = |axis: u8, degrees: f64| {
+        let angle = degrees.to_radians();
+        match axis {
+            b'X' => glam::DMat4::from_rotation_x(angle),
+            b'Y' => glam::DMat4::from_rotation_y(angle),
+            _ => glam::DMat4::from_rotation_z(angle),
+        }
+    };
+
+    Some(match op_type {
+        "translate" => glam::DMat4::from_translation(vec3()?),
+        "scale" => glam::DMat4::from_scale(vec3()?),
+        "rotateX" | "rotateY" | "rotateZ" => rotation(op_type.as_bytes()[6], value.as_f64()?),
+        "orient" => {
+            let [w, x, y, z]: [f64; 4] = value.as_tuple()?.try_into().ok()?;
+            glam::DMat4::from_quat(glam::DQuat::from_xyzw(x, y, z, w).normalize())
+        }
+        // Row-major with row vectors, i.e. column-major with column vectors.
+        "transform" => glam::DMat4::from_cols_array(&value.as_tuple()?.try_into().ok()?),
+        _ => {
+            // E.g. `rotateXYZ` rotates around X first, then Y, then Z.
+            let axes = op_type
+                .strip_prefix("rotate")
+                .filter(|axes| axes.len() == 3)?;
+            let angles = vec3()?;
+            axes.bytes().fold(glam::DMat4::IDENTITY, |matrix, axis| {
+                let degrees = match axis {
+                    b'X' => angles.x,
+                    b'Y' => angles.y,
+                    _ => angles.z,
+                };
+                rotation(axis, degrees) * matrix
+            })
+        }
+    })
+}
+
+fn transform3d(transform: &glam::DMat4) -> Transform3D {
+    let (scale, rotation, translation) = transform.to_scale_rotation_translation();
+    let is_decomposable =
+        glam::DMat4::from_scale_rotation_translation(scale, rotation, translation)
+            .abs_diff_eq(*transform, 1e-6);
+
+    if is_decomposable {
+        let mut transform = Transform3D::update_fields();
+        if translation != glam::DVec3::ZERO {
+            transform = transform.with_translation(translation.as_vec3());
+        }
+        if !rotation.abs_diff_eq(glam::DQuat::IDENTITY, 1e-9) {
+            transform = transform.with_quaternion(rotation.as_quat());
+        }
+        if !scale.abs_diff_eq(glam::DVec3::ONE, 1e-9) {
+            transform = transform.with_scale(scale.as_vec3());
+        }
+        transform
+    } else {
+        // Sheared transforms.
+        Transform3D::update_fields()
+            .with_translation(translation.as_vec3())
+            .with_mat3x3(glam::DMat3::from_mat4(*transform).as_mat3())
+    }
+}
+
+/// The diffuse color of the material bound to a prim.
+fn material_color(stage: &Stage, prim: &Prim) -> Option<Rgba32> {
+    let material_path = prim.target("material:binding")?;
+    let material = stage.prim(material_path)?;
+
+    // The surface output is connected to a shader output, e.g. `/Material/Shader.outputs:surface`.
+    let shader = material
+        .target("outputs:surface")
+        .and_then(|output| stage.prim(output.split_once('.').map_or(output, |(path, _)| path)))
+        .or_else(|| {
+            material.children.iter().find_map(|child| {
+                let shader = stage.prim(&format!("{material_path}/{child}"))?;
+                let id = shader.attribute("info:id").and_then(Value::as_text)?;
+                (id == "UsdPreviewSurface").then_some(shader)
+            })
+        })?;
+
+    let [r, g, b]: [f64; 3] = shader
+        .attribute("inputs:diffuseColor")?
+        .as_tuple()?
+        .try_into()
+        .ok()?;
+    let opacity = shader
+        .attribute("inputs:opacity")
+        .and_then(Value::as_f64)
+        .unwrap_or(1.0);
+
+    Some(Rgba32::from_linear_unmultiplied_rgba_f32(
+        r as f32,
+        g as f32,
+        b as f32,
+        opacity as f32,
+    ))
+}
+
+/// The single color of a prim: either from its material, or from a constant `displayColor`.
+fn prim_color(stage: &Stage, prim: &Prim) -> Option<Rgba32> {
+    if let Some(color) = material_color(stage, prim) {
+        return Some(color);
+    }
+
+    let colors = Primvar::new(prim, "primvars:displayColor", 3)?;
+    if colors.interpolation != "constant" && colors.values.len() != 3 {
+        return None;
+    }
+    let opacity = Primvar::new(prim, "primvars:displayOpacity", 1)
+        .and_then(|opacity| opacity.values.first().copied())
+        .unwrap_or(1.0);
+    let [r, g, b]: [f64; 3] = colors.values[..3].try_into().ok()?;
+
+    Some(Rgba32::from_linear_unmultiplied_rgba_f32(
+        r as f32,
+        g as f32,
+        b as f32,
+        opacity as f32,
+    ))
+}
+
+/// The values of a primvar, with its indices resolved.
+struct Primvar {
+    /// Flattened tuples of `width` numbers.
+    values: Vec<f64>,
+    width: usize,
+
+    /// `constant`, `uniform`, `vertex`, `varying`, or `faceVarying`.
+    interpolation: String,
+}
+
+impl Primvar {
+    fn new(prim: &Prim, name: &str, width: usize) -> Option<Self> {
+        let mut values = prim.attribute(name)?.as_array(width)?.to_vec();
+        if values.is_empty() {
+            return None;
+        }
+
+        if let Some(indices) = prim
+            .attribute(&format!("{name}:indices"))
+            .and_then(|indices| indices.as_array(1))
+        {
+            let resolved = indices
+                .iter()
+                .map(|index| {
+                    let start = *index as usize * width;
+                    values.get(start..start + width)
+                })
+                .collect::<Option<Vec<_>>>()?;
+            values = resolved.concat();
+        }
+
+        Some(Self {
+            values,
+            width,
+            interpolation: prim
+                .interpolation(name)
+                .unwrap_or(if name.starts_with("primvars:") {
+                    "constant"
+                } else {
+                    "vertex"
+                })
+                .to_owned(),
+        })
+    }
+
+    /// Whether the values are per face or per face corner, rather than per point.
+    fn is_per_corner(&self) -> bool {
+        matches!(self.interpolation.as_str(), "uniform" | "faceVarying")
+    }
+
+    fn get(&self, face: usize, point: usize, corner: usize) -> Option<&[f64]> {
+        let index = match self.interpolation.as_str() {
+            "constant" => 0,
+            "uniform" => face,
+            "faceVarying" => corner,
+            _ => point,
+        };
+        self.values
+            .get(index * self.width..(index + 1) * self.width)
+    }
+}
+
+fn mesh(prim: &Prim, color: Option<Rgba32>) -> Option<Mesh3D> {
+    let points = prim.attribute("points")?.as_array(3)?;
+    let counts = prim.attribute("faceVertexCounts")?.as_array(1)?;
+    let indices = prim.attribute("faceVertexIndices")?.as_array(1)?;
+    let is_left_handed = prim
+        .attribute("orientation")
+        .and_then(Value::as_text)
+        .is_some_and(|orientation| orientation == "leftHanded");
+
+    let normals =
+        Primvar::new(prim, "primvars:normals", 3).or_else(|| Primvar::new(prim, "normals", 3));
+    let colors = if color.is_none() {
+        Primvar::new(prim, "primvars:displayColor", 3)
+            .filter(|colors| colors.interpolation != "constant")
+    } else {
+        None
+    };
+    let opacities = colors
+        .as_ref()
+        .and_then(|_| Primvar::new(prim, "primvars:displayOpacity", 1));
+
+    // `(face, corner)` of every triangle corner, triangulated as fans.
+    let mut triangles = Vec::new();
+    let mut corner = 0;
+    for (face, count) in counts.iter().enumerate() {
+        let count = *count as usize;
+        for i in 1..count.saturating_sub(1) {
+            let mut triangle = [corner, corner + i, corner + i + 1];
+            if is_left_handed {
+                triangle.swap(1, 2);
+            }
+            triangles.extend(triangle.map(|corner| (face, corner)));
+        }
+        corner += count;
+    }
+    if corner > indices.len() {
+        re_log::warn!("Skipping USD mesh with fewer face vertex indices than its face counts");
+        return None;
+    }
+    let point_index = |corner: usize| indices[corner] as usize;
+    if triangles
+        .iter()
+        .any(|(_, corner)| point_index(*corner) >= points.len() / 3)
+    {
+        re_log::warn!("Skipping USD mesh with out of bounds face vertex indices");
+        return None;
+    }
+
+    let vec3 = |values: &[f64]| glam::vec3(values[0] as f32, values[1] as f32, values[2] as f32);
+    let vertex_color = |face: usize, point: usize, corner: usize| -> Option<Rgba32> {
+        let [r, g, b]: [f64; 3] = colors.as_ref()?.get(face, point, corner)?.try_into().ok()?;
+        let opacity = opacities
+            .as_ref()
+            .and_then(|opacities| opacities.get(face, point, corner))
+            .map_or(1.0, |opacity| opacity[0]);
+        Some(Rgba32::from_linear_unmultiplied_rgba_f32(
+            r as f32,
+            g as f32,
+            b as f32,
+            opacity as f32,
+        ))
+    };
+
+    let is_per_corner = normals.as_ref().is_some_and(Primvar::is_per_corner)
+        || colors.as_ref().is_some_and(Primvar::is_per_corner);
+
+    let mut mesh = if is_per_corner {
+        // Every triangle corner gets its own vertex.
+        let positions = triangles
+            .iter()
+            .map(|(_, corner)| {
+                let point = point_index(*corner);
+                vec3(&points[point * 3..point * 3 + 3])
+            })
+            .collect::<Vec<_>>();
+        let mut mesh = Mesh3D::new(positions);
+        if let Some(normals) = &normals {
+            let normals = triangles
+                .iter()
+                .map(|(face, corner)| normals.get(*face, point_index(*corner), *corner).map(vec3))
+                .collect::<Option<Vec<_>>>();
+            if let Some(normals) = normals {
+                mesh = mesh.with_vertex_normals(normals);
+            }
+        }
+        if colors.is_some() {
+            let colors = triangles
+                .iter()
+                .map(|(face, corner)| vertex_color(*face, point_index(*corner), *corner))
+                .collect::<Option<Vec<_>>>();
+            if let Some(colors) = colors {
+                mesh = mesh.with_vertex_colors(colors);
+            }
+        }
+        mesh
+    } else {
+        let mut mesh = Mesh3D::new(points.chunks_exact(3).map(vec3)).with_triangle_indices(
+            triangles.chunks_exact(3).map(|triangle| {
+                glam::uvec3(
+                    point_index(triangle[0].1) as u32,
+                    point_index(triangle[1].1) as u32,
+                    point_index(triangle[2].1) as u32,
+                )
+            }),
+        );
+        let num_points = points.len() / 3;
+        if let Some(normals) = &normals {
+            let normals = (0..num_points)
+                .map(|point| normals.get(0, point, 0).map(vec3))
+                .collect::<Option<Vec<_>>>();
+            if let Some(normals) = normals {
+                mesh = mesh.with_vertex_normals(normals);
+            }
+        }
+        if colors.is_some() {
+            let colors = (0..num_points)
+                .map(|point| vertex_color(0, point, 0))
+                .collect::<Option<Vec<_>>>();
+            if let Some(colors) = colors {
+                mesh = mesh.with_vertex_colors(colors);
+            }
+        }
+        mesh
+    };
+
+    if let Some(color) = color {
+        mesh = mesh.with_albedo_factor(color);
+    }
+
+    Some(mesh)
+}
+
+fn points(prim: &Prim, color: Option<Rgba32>) -> Option<Points3D> {
+    let positions = prim.attribute("points")?.as_array(3)?;
+    let mut points = Points3D::new(
+        positions
+            .chunks_exact(3)
+            .map(|p| [p[0] as f32, p[1] as f32, p[2] as f32]),
+    );
+
+    if let Some(widths) = prim
+        .attribute("widths")
+        .and_then(|widths| widths.as_array(1))
+    {
+        points = points.with_radii(widths.iter().map(|width| 0.5 * *width as f32));
+    }
+
+    if let Some(color) = color {
+        points = points.with_colors([color]);
+    } else if let Some(colors) = Primvar::new(prim, "primvars:displayColor", 3) {
+        points = points.with_colors(colors.values.chunks_exact(3).map(|rgb| {
+            Rgba32::from_linear_unmultiplied_rgba_f32(
+                rgb[0] as f32,
+                rgb[1] as f32,
+                rgb[2] as f32,
+                1.0,
+            )
+        }));
+    }
+
+    Some(points)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn xform_ops() {
+        let prim = Prim {
+            attributes: [
+                (
+                    "xformOp:translate".to_owned(),
+                    Value::Tuple(vec![1.0, 2.0, 3.0]),
+                ),
+                ("xformOp:rotateZ".to_owned(), Value::Number(90.0)),
+                (
+                    "xformOp:scale".to_owned(),
+                    Value::Tuple(vec![2.0, 2.0, 2.0]),
+                ),
+                (
+                    "xformOpOrder".to_owned(),
+                    Value::Texts(vec![
+                        "xformOp:translate".to_owned(),
+                        "xformOp:rotateZ".to_owned(),
+                        "xformOp:scale".to_owned(),
+                    ]),
+                ),
+            ]
+            .into(),
+            ..Default::default()
+        };
+
+        let transform = local_transform(&prim).unwrap();
+        let point = transform.transform_point3(glam::DVec3::X);
+        assert!(point.abs_diff_eq(glam::dvec3(1.0, 4.0, 3.0), 1e-9));
+    }
+
+    #[test]
+    fn mesh_from_usda() {
+        let stage = crate::usd::parse_usda(
+            br#"#usda 1.0
+def Mesh "Quad"
+{
+    int[] faceVertexCounts = [4]
+    int[] faceVertexIndices = [0, 1, 2, 3]
+    point3f[] points = [(0, 0, 0), (1, 0, 0), (1, 1, 0), (0, 1, 0)]
+    color3f[] primvars:displayColor = [(1, 0, 0), (0, 1, 0)] (
+        interpolation = "uniform"
+    )
+}
+"#,
+        )
+        .unwrap();
+
+        let chunks = scene_chunks(&stage, &EntityPath::from("scene")).unwrap();
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[1].entity_path(), &EntityPath::from("scene/Quad"));
+
+        let mesh = mesh(stage.prim("/Quad").unwrap(), None).unwrap();
+        assert_eq!(mesh.num_vertices(), 6);
+    }
+}
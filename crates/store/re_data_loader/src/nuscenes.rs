@@ -0,0 +1,355 @@
+//! A module for reading the [nuScenes](https://www.nuscenes.org/nuscenes) dataset.
+//!
+//! The dataset is a directory containing the sensor data (`samples/`, `sweeps/`), and a
+//! metadata directory (e.g. `v1.0-mini/`) made of JSON tables referencing each other by token,
+//! see <https://www.nuscenes.org/nuscenes#data-format>.
+//!
+//! All poses are given as a translation and a `[w, x, y, z]` quaternion, and timestamps are in
+//! microseconds since epoch.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Errors that might happen when reading a nuScenes dataset.
+#[derive(thiserror::Error, Debug)]
+pub enum NuScenesError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error("Failed to parse {file}: {err}")]
+    Json {
+        file: &'static str,
+        err: serde_json::Error,
+    },
+}
+
+/// The tables that must be present in a metadata directory.
+const REQUIRED_TABLES: &[&str] = &["scene.json", "sample_data.json", "calibrated_sensor.json"];
+
+/// Returns the metadata directory of the dataset at `path`, if any.
+///
+/// `path` can either be the dataset directory, or the metadata directory itself.
+pub fn find_metadata_dir(path: &Path) -> Option<PathBuf> {
+    let is_metadata_dir = |dir: &Path| {
+        REQUIRED_TABLES
+            .iter()
+            .all(|table| dir.join(table).is_file())
+    };
+
+    if !path.is_dir() {
+        return None;
+    }
+    if is_metadata_dir(path) {
+        return Some(path.to_owned());
+    }
+
+    let mut candidates = std::fs::read_dir(path)
+        .ok()?
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .filter(|dir| {
+            dir.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("v1.0-"))
+                && is_metadata_dir(dir)
+        })
+        .collect::<Vec<_>>();
+    // Prefer `v1.0-mini` over `v1.0-trainval` if both are there, as it's much faster to load.
+    candidates.sort();
+    candidates.into_iter().next()
+}
+
+/// Checks whether `path` is a nuScenes dataset directory.
+pub fn is_nuscenes_dataset(path: impl AsRef<Path>) -> bool {
+    find_metadata_dir(path.as_ref()).is_some()
+}
+
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct Scene {
+    pub token: String,
+    pub name: String,
+
+    #[serde(default)]
+    pub description: String,
+
+    pub first_sample_token: String,
+}
+
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct Sensor {
+    pub token: String,
+
+    /// E.g. `CAM_FRONT` or `LIDAR_TOP`.
+    pub channel: String,
+
+    /// `camera`, `lidar`, or `radar`.
+    pub modality: String,
+}
+
+/// The pose and intrinsics of a sensor, relative to the ego vehicle.
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct CalibratedSensor {
+    pub token: String,
+    pub sensor_token: String,
+    pub translation: [f64; 3],
+    pub rotation: [f64; 4],
+
+    /// Row-major, empty for sensors other than cameras.
+    #[serde(default)]
+    pub camera_intrinsic: Vec<[f64; 3]>,
+}
+
+/// The pose of the ego vehicle, in the global frame.
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct EgoPose {
+    pub token: String,
+    pub timestamp: i64,
+    pub translation: [f64; 3],
+    pub rotation: [f64; 4],
+}
+
+/// A single sensor reading, either part of an annotated sample (key frame) or a sweep.
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct SampleData {
+    pub token: String,
+    pub sample_token: String,
+    pub ego_pose_token: String,
+    pub calibrated_sensor_token: String,
+    pub timestamp: i64,
+
+    /// Relative to the dataset directory.
+    pub filename: String,
+
+    #[serde(default)]
+    pub width: u32,
+
+    #[serde(default)]
+    pub height: u32,
+}
+
+/// An annotated point in time, common to all sensors.
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct Sample {
+    pub token: String,
+    pub timestamp: i64,
+    pub scene_token: String,
+}
+
+/// A bounding box, in the global frame.
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct SampleAnnotation {
+    pub token: String,
+    pub sample_token: String,
+    pub instance_token: String,
+    pub translation: [f64; 3],
+
+    /// Width, length, and height, in meters.
+    pub size: [f64; 3],
+    pub rotation: [f64; 4],
+}
+
+/// An object, annotated over several samples.
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct Instance {
+    pub token: String,
+    pub category_token: String,
+}
+
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct Category {
+    pub token: String,
+
+    /// E.g. `vehicle.car` or `human.pedestrian.adult`.
+    pub name: String,
+}
+
+/// The tables of a nuScenes dataset that are needed to visualize it.
+#[derive(Clone, Debug, Default)]
+pub struct NuScenes {
+    pub scenes: Vec<Scene>,
+    pub sensors: HashMap<String, Sensor>,
+    pub calibrated_sensors: HashMap<String, CalibratedSensor>,
+    pub ego_poses: HashMap<String, EgoPose>,
+    pub samples: HashMap<String, Sample>,
+    pub sample_data: Vec<SampleData>,
+    pub sample_annotations: Vec<SampleAnnotation>,
+    pub instances: HashMap<String, Instance>,
+    pub categories: Vec<Category>,
+}
+
+impl NuScenes {
+    /// Loads the tables of a metadata directory, see [`find_metadata_dir`].
+    ///
+    /// Annotation tables are optional, as they are missing from the test split.
+    pub fn load_from_directory(metadata_dir: &Path) -> Result<Self, NuScenesError> {
+        re_tracing::profile_function!();
+
+        let mut sample_data: Vec<SampleData> = read_table(metadata_dir, "sample_data.json")?;
+        sample_data.sort_by_key(|sample_data| sample_data.timestamp);
+
+        Ok(Self {
+            scenes: read_table(metadata_dir, "scene.json")?,
+            sensors: by_token(read_table(metadata_dir, "sensor.json")?, |s: &Sensor| {
+                &s.token
+            }),
+            calibrated_sensors: by_token(
+                read_table(metadata_dir, "calibrated_sensor.json")?,
+                |s: &CalibratedSensor| &s.token,
+            ),
+            ego_poses: by_token(read_table(metadata_dir, "ego_pose.json")?, |p: &EgoPose| {
+                &p.token
+            }),
+            samples: by_token(read_table(metadata_dir, "sample.json")?, |s: &Sample| {
+                &s.token
+            }),
+            sample_data,
+            sample_annotations: read_optional_table(metadata_dir, "sample_annotation.json")?,
+            instances: by_token(
+                read_optional_table(metadata_dir, "instance.json")?,
+                |i: &Instance| &i.token,
+            ),
+            categories: read_optional_table(metadata_dir, "category.json")?,
+        })
+    }
+
+    /// The sensor a reading was made with, together with its calibration.
+    pub fn sensor(&self, sample_data: &SampleData) -> Option<(&Sensor, &CalibratedSensor)> {
+        let calibrated_sensor = self
+            .calibrated_sensors
+            .get(&sample_data.calibrated_sensor_token)?;
+        let sensor = self.sensors.get(&calibrated_sensor.sensor_token)?;
+        Some((sensor, calibrated_sensor))
+    }
+
+    /// The index of the category of an annotation in [`Self::categories`].
+    pub fn category_index(&self, annotation: &SampleAnnotation) -> Option<usize> {
+        let instance = self.instances.get(&annotation.instance_token)?;
+        self.categories
+            .iter()
+            .position(|category| category.token == instance.category_token)
+    }
+}
+
+fn read_table<T: serde::de::DeserializeOwned>(
+    metadata_dir: &Path,
+    file: &'static str,
+) -> Result<Vec<T>, NuScenesError> {
+    re_tracing::profile_function!(file);
+    let reader = std::io::BufReader::new(std::fs::File::open(metadata_dir.join(file))?);
+    serde_json::from_reader(reader).map_err(|err| NuScenesError::Json { file, err })
+}
+
+fn read_optional_table<T: serde::de::DeserializeOwned>(
+    metadata_dir: &Path,
+    file: &'static str,
+) -> Result<Vec<T>, NuScenesError> {
+    if metadata_dir.join(file).is_file() {
+        read_table(metadata_dir, file)
+    } else {
+        Ok(Vec::new())
+    }
+}
+
+fn by_token<T>(rows: Vec<T>, token: impl Fn(&T) -> &String) -> HashMap<String, T> {
+    rows.into_iter()
+        .map(|row| (token(&row).clone(), row))
+        .collect()
+}
+
+/// Parses a LiDAR sweep (`.pcd.bin`), made of little-endian `x, y, z, intensity, ring` floats.
+pub fn parse_lidar(bytes: &[u8]) -> Vec<[f32; 5]> {
+    bytes
+        .chunks_exact(20)
+        .map(|point| {
+            std::array::from_fn(|i| {
+                f32::from_le_bytes([
+                    point[i * 4],
+                    point[i * 4 + 1],
+                    point[i * 4 + 2],
+                    point[i * 4 + 3],
+                ])
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_lidar() {
+        let bytes = [1.0_f32, 2.0, 3.0, 10.0, 0.0, -1.0, -2.0, -3.0, 20.0, 31.0]
+            .iter()
+            .flat_map(|value| value.to_le_bytes())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            parse_lidar(&bytes),
+            vec![[1.0, 2.0, 3.0, 10.0, 0.0], [-1.0, -2.0, -3.0, 20.0, 31.0]]
+        );
+    }
+
+    #[test]
+    fn test_tables() {
+        let dir =
+            std::env::temp_dir().join(format!("re_data_loader_nuscenes_{}", std::process::id()));
+        let metadata_dir = dir.join("v1.0-mini");
+        std::fs::create_dir_all(&metadata_dir).unwrap();
+
+        let tables = [
+            (
+                "scene.json",
+                r#"[{"token": "s", "name": "scene-0061", "log_token": "l", "first_sample_token": "x"}]"#,
+            ),
+            (
+                "sensor.json",
+                r#"[{"token": "lidar", "channel": "LIDAR_TOP", "modality": "lidar"}]"#,
+            ),
+            (
+                "calibrated_sensor.json",
+                r#"[{"token": "c", "sensor_token": "lidar", "translation": [1, 0, 2],
+                     "rotation": [1, 0, 0, 0], "camera_intrinsic": []}]"#,
+            ),
+            (
+                "ego_pose.json",
+                r#"[{"token": "e", "timestamp": 5, "translation": [0, 0, 0], "rotation": [1, 0, 0, 0]}]"#,
+            ),
+            (
+                "sample.json",
+                r#"[{"token": "x", "timestamp": 5, "scene_token": "s", "prev": "", "next": ""}]"#,
+            ),
+            (
+                "sample_data.json",
+                r#"[
+                    {"token": "b", "sample_token": "x", "ego_pose_token": "e", "calibrated_sensor_token": "c",
+                     "timestamp": 7, "filename": "sweeps/b.pcd.bin", "width": 0, "height": 0, "is_key_frame": false},
+                    {"token": "a", "sample_token": "x", "ego_pose_token": "e", "calibrated_sensor_token": "c",
+                     "timestamp": 5, "filename": "samples/a.pcd.bin", "width": 0, "height": 0, "is_key_frame": true}
+                ]"#,
+            ),
+        ];
+        for (file, contents) in tables {
+            std::fs::write(metadata_dir.join(file), contents).unwrap();
+        }
+
+        assert_eq!(find_metadata_dir(&dir), Some(metadata_dir.clone()));
+        assert_eq!(find_metadata_dir(&metadata_dir), Some(metadata_dir.clone()));
+
+        let nuscenes = NuScenes::load_from_directory(&metadata_dir).unwrap();
+        assert_eq!(nuscenes.scenes[0].name, "scene-0061");
+        assert!(nuscenes.sample_annotations.is_empty());
+
+        // Readings are sorted by time.
+        let tokens = nuscenes
+            .sample_data
+            .iter()
+            .map(|sample_data| sample_data.token.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(tokens, vec!["a", "b"]);
+
+        let (sensor, calibrated_sensor) = nuscenes.sensor(&nuscenes.sample_data[0]).unwrap();
+        assert_eq!(sensor.channel, "LIDAR_TOP");
+        assert_eq!(calibrated_sensor.translation, [1.0, 0.0, 2.0]);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,501 @@
+//! A parser for the [PCD](https://pointclouds.org/documentation/tutorials/pcd_file_format.html)
+//! point cloud format of the Point Cloud Library.
+//!
+//! All three data encodings are supported: `ascii`, `binary`, and `binary_compressed`.
+//! Every field is decoded to `f64`, which can represent all of PCD's numeric types without loss,
+//! except for 64-bit integers.
+
+/// Errors that might happen when parsing a PCD file.
+#[derive(thiserror::Error, Debug)]
+pub enum PcdError {
+    #[error("Invalid PCD header: {0}")]
+    InvalidHeader(String),
+
+    #[error("Unexpected end of PCD data")]
+    UnexpectedEof,
+
+    #[error("Invalid ASCII value {0:?}")]
+    InvalidValue(String),
+
+    #[error("Failed to decompress PCD data")]
+    Decompression,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DataEncoding {
+    Ascii,
+    Binary,
+    BinaryCompressed,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Field {
+    name: String,
+    size: usize,
+
+    /// `I` for signed integers, `U` for unsigned integers, `F` for floats.
+    ty: u8,
+    count: usize,
+}
+
+impl Field {
+    fn decode(&self, bytes: &[u8]) -> Option<f64> {
+        fn le<const N: usize>(bytes: &[u8]) -> Option<[u8; N]> {
+            bytes.get(..N)?.try_into().ok()
+        }
+
+        Some(match (self.ty, self.size) {
+            (b'I', 1) => *bytes.first()? as i8 as f64,
+            (b'I', 2) => i16::from_le_bytes(le(bytes)?) as f64,
+            (b'I', 4) => i32::from_le_bytes(le(bytes)?) as f64,
+            (b'I', 8) => i64::from_le_bytes(le(bytes)?) as f64,
+            (b'U', 1) => *bytes.first()? as f64,
+            (b'U', 2) => u16::from_le_bytes(le(bytes)?) as f64,
+            (b'U', 4) => u32::from_le_bytes(le(bytes)?) as f64,
+            (b'U', 8) => u64::from_le_bytes(le(bytes)?) as f64,
+            (b'F', 4) => f32::from_le_bytes(le(bytes)?) as f64,
+            (b'F', 8) => f64::from_le_bytes(le(bytes)?),
+            _ => return None,
+        })
+    }
+
+    /// Fields with a count other than one are histograms and descriptors, which we don't keep.
+    fn is_scalar(&self) -> bool {
+        self.count == 1 && self.name != "_"
+    }
+}
+
+/// A decoded PCD file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PointCloud {
+    /// Number of points per row.
+    pub width: usize,
+
+    /// Number of rows, `1` for unorganized clouds.
+    ///
+    /// Organized clouds (e.g. from a depth camera) store invalid points as `NaN`.
+    pub height: usize,
+
+    /// Sensor pose as `[tx, ty, tz, qw, qx, qy, qz]`.
+    pub viewpoint: [f64; 7],
+
+    /// The values of every scalar field, in the order they appear in the file.
+    pub fields: Vec<(String, Vec<f64>)>,
+
+    /// The type of every entry in [`Self::fields`], needed to interpret packed colors.
+    field_types: Vec<u8>,
+}
+
+impl PointCloud {
+    /// Returns the values of the given field, if present.
+    pub fn field(&self, name: &str) -> Option<&[f64]> {
+        self.fields
+            .iter()
+            .find(|(field_name, _)| field_name == name)
+            .map(|(_, values)| values.as_slice())
+    }
+
+    /// Returns the packed `0xAARRGGBB` colors of the `rgb` or `rgba` field, if present.
+    ///
+    /// PCL usually stores these as the bits of a `float`, but unsigned integers are used as well.
+    pub fn packed_colors(&self) -> Option<Vec<u32>> {
+        let (index, (name, values)) = self
+            .fields
+            .iter()
+            .enumerate()
+            .find(|(_, (name, _))| name == "rgb" || name == "rgba")?;
+
+        let has_alpha = name == "rgba";
+        let is_float = self.field_types[index] == b'F';
+
+        Some(
+            values
+                .iter()
+                .map(|&value| {
+                    let packed = if is_float {
+                        (value as f32).to_bits()
+                    } else {
+                        value as u32
+                    };
+                    if has_alpha {
+                        packed
+                    } else {
+                        packed | 0xFF00_0000
+                    }
+                })
+                .collect(),
+        )
+    }
+}
+
+struct Header {
+    fields: Vec<Field>,
+    width: usize,
+    height: usize,
+    viewpoint: [f64; 7],
+    num_points: usize,
+    encoding: DataEncoding,
+}
+
+/// Parses the header, returns it together with the remaining data.
+fn parse_header(contents: &[u8]) -> Result<(Header, &[u8]), PcdError> {
+    let invalid = |reason: &str| PcdError::InvalidHeader(reason.to_owned());
+
+    let mut names = Vec::new();
+    let mut sizes = Vec::new();
+    let mut types = Vec::new();
+    let mut counts = None;
+    let mut width = None;
+    let mut height = None;
+    let mut viewpoint = [0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0];
+    let mut num_points = None;
+
+    let mut rest = contents;
+    loop {
+        let Some(end) = rest.iter().position(|&b| b == b'\n') else {
+            return Err(invalid("missing DATA line"));
+        };
+        let line = String::from_utf8_lossy(&rest[..end]);
+        rest = &rest[end + 1..];
+
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let mut tokens = line.split_whitespace();
+        let key = tokens.next().unwrap_or_default().to_ascii_uppercase();
+        let values = tokens.collect::<Vec<_>>();
+        let usize_value = || -> Result<usize, PcdError> {
+            values
+                .first()
+                .and_then(|value| value.parse().ok())
+                .ok_or_else(|| PcdError::InvalidHeader(format!("invalid {key}")))
+        };
+
+        match key.as_str() {
+            "VERSION" => {}
+            "FIELDS" | "COLUMNS" => names = values.iter().map(|v| (*v).to_owned()).collect(),
+            "SIZE" => {
+                sizes = values
+                    .iter()
+                    .map(|v| v.parse::<usize>())
+                    .collect::<Result<_, _>>()
+                    .map_err(|_err| invalid("invalid SIZE"))?;
+            }
+            "TYPE" => {
+                types = values
+                    .iter()
+                    .map(|v| v.bytes().next().unwrap_or_default().to_ascii_uppercase())
+                    .collect();
+            }
+            "COUNT" => {
+                counts = Some(
+                    values
+                        .iter()
+                        .map(|v| v.parse::<usize>())
+                        .collect::<Result<Vec<_>, _>>()
+                        .map_err(|_err| invalid("invalid COUNT"))?,
+                );
+            }
+            "WIDTH" => width = Some(usize_value()?),
+            "HEIGHT" => height = Some(usize_value()?),
+            "POINTS" => num_points = Some(usize_value()?),
+            "VIEWPOINT" => {
+                for (target, value) in viewpoint.iter_mut().zip(&values) {
+                    *target = value.parse().map_err(|_err| invalid("invalid VIEWPOINT"))?;
+                }
+            }
+            "DATA" => {
+                let encoding = match values.first().copied() {
+                    Some("ascii") => DataEncoding::Ascii,
+                    Some("binary") => DataEncoding::Binary,
+                    Some("binary_compressed") => DataEncoding::BinaryCompressed,
+                    _ => return Err(invalid("unknown DATA encoding")),
+                };
+
+                let counts = counts.unwrap_or_else(|| vec![1; names.len()]);
+                if sizes.len() != names.len()
+                    || types.len() != names.len()
+                    || counts.len() != names.len()
+                {
+                    return Err(invalid(
+                        "FIELDS, SIZE, TYPE and COUNT have different lengths",
+                    ));
+                }
+
+                let fields = itertools::izip!(names, sizes, types, counts)
+                    .map(|(name, size, ty, count)| Field {
+                        name,
+                        size,
+                        ty,
+                        count,
+                    })
+                    .collect();
+
+                let width = width.ok_or_else(|| invalid("missing WIDTH"))?;
+                let height = height.unwrap_or(1);
+                let num_points = num_points.unwrap_or(width * height);
+
+                return Ok((
+                    Header {
+                        fields,
+                        width,
+                        height,
+                        viewpoint,
+                        num_points,
+                        encoding,
+                    },
+                    rest,
+                ));
+            }
+            _ => {
+                re_log::debug_once!("Ignoring unknown PCD header line {key:?}");
+            }
+        }
+    }
+}
+
+/// Parses a complete PCD file.
+pub fn parse_pcd(contents: &[u8]) -> Result<PointCloud, PcdError> {
+    re_tracing::profile_function!();
+
+    let (header, data) = parse_header(contents)?;
+    let Header {
+        fields,
+        width,
+        height,
+        viewpoint,
+        num_points,
+        encoding,
+    } = header;
+
+    let mut columns: Vec<Vec<f64>> = fields
+        .iter()
+        .filter(|field| field.is_scalar())
+        .map(|_| Vec::with_capacity(num_points))
+        .collect();
+
+    match encoding {
+        DataEncoding::Ascii => {
+            let text = String::from_utf8_lossy(data);
+            for line in text.lines().filter(|line| !line.trim().is_empty()) {
+                let mut tokens = line.split_whitespace();
+                let mut column = 0;
+                for field in &fields {
+                    for _ in 0..field.count {
+                        let token = tokens.next().ok_or(PcdError::UnexpectedEof)?;
+                        if !field.is_scalar() {
+                            continue;
+                        }
+                        let value = if field.ty == b'F' && field.size == 4 {
+                            // Packed colors are written as floats, which must round-trip exactly.
+                            token.parse::<f32>().map(|v| v as f64)
+                        } else {
+                            token.parse::<f64>()
+                        };
+                        columns[column]
+                            .push(value.map_err(|_err| PcdError::InvalidValue(token.to_owned()))?);
+                    }
+                    if field.is_scalar() {
+                        column += 1;
+                    }
+                }
+            }
+        }
+
+        DataEncoding::Binary => {
+            let point_size: usize = fields.iter().map(|field| field.size * field.count).sum();
+            let data = data
+                .get(..point_size * num_points)
+                .ok_or(PcdError::UnexpectedEof)?;
+
+            for point in data.chunks_exact(point_size.max(1)) {
+                let mut offset = 0;
+                let mut column = 0;
+                for field in &fields {
+                    if field.is_scalar() {
+                        columns[column].push(
+                            field
+                                .decode(&point[offset..])
+                                .ok_or_else(|| invalid_type(field))?,
+                        );
+                        column += 1;
+                    }
+                    offset += field.size * field.count;
+                }
+            }
+        }
+
+        DataEncoding::BinaryCompressed => {
+            let read_u32 = |offset: usize| -> Result<usize, PcdError> {
+                let bytes = data
+                    .get(offset..offset + 4)
+                    .ok_or(PcdError::UnexpectedEof)?;
+                Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize)
+            };
+            let compressed_size = read_u32(0)?;
+            let uncompressed_size = read_u32(4)?;
+            let compressed = data
+                .get(8..8 + compressed_size)
+                .ok_or(PcdError::UnexpectedEof)?;
+            let data = lzf_decompress(compressed, uncompressed_size)?;
+
+            // The decompressed data is stored field by field, rather than point by point.
+            let mut offset = 0;
+            let mut column = 0;
+            for field in &fields {
+                let field_size = field.size * field.count;
+                let field_data = data
+                    .get(offset..offset + field_size * num_points)
+                    .ok_or(PcdError::UnexpectedEof)?;
+                offset += field_size * num_points;
+
+                if !field.is_scalar() {
+                    continue;
+                }
+                for value in field_data.chunks_exact(field_size.max(1)) {
+                    columns[column].push(field.decode(value).ok_or_else(|| invalid_type(field))?);
+                }
+                column += 1;
+            }
+        }
+    }
+
+    let scalar_fields = fields.iter().filter(|field| field.is_scalar());
+    Ok(PointCloud {
+        width,
+        height,
+        viewpoint,
+        field_types: scalar_fields.clone().map(|field| field.ty).collect(),
+        fields: scalar_fields
+            .map(|field| field.name.clone())
+            .zip(columns)
+            .collect(),
+    })
+}
+
+fn invalid_type(field: &Field) -> PcdError {
+    PcdError::InvalidHeader(format!(
+        "unsupported type {}{} of field {:?}",
+        field.ty as char, field.size, field.name
+    ))
+}
+
+/// Decompresses [LZF](http://oldhome.schmorp.de/marc/liblzf.html) data, as used by `binary_compressed`.
+fn lzf_decompress(input: &[u8], uncompressed_size: usize) -> Result<Vec<u8>, PcdError> {
+    let mut output = Vec::with_capacity(uncompressed_size);
+    let mut i = 0;
+
+    while i < input.len() {
+        let ctrl = input[i] as usize;
+        i += 1;
+
+        if ctrl < 32 {
+            // A run of literal bytes.
+            let len = ctrl + 1;
+            let literal = input.get(i..i + len).ok_or(PcdError::Decompression)?;
+            output.extend_from_slice(literal);
+            i += len;
+        } else {
+            // A back-reference into the output so far.
+            let mut len = ctrl >> 5;
+            if len == 7 {
+                len += *input.get(i).ok_or(PcdError::Decompression)? as usize;
+                i += 1;
+            }
+            len += 2;
+
+            let low = *input.get(i).ok_or(PcdError::Decompression)? as usize;
+            i += 1;
+            let distance = ((ctrl & 0x1F) << 8) + low + 1;
+
+            let start = output
+                .len()
+                .checked_sub(distance)
+                .ok_or(PcdError::Decompression)?;
+            for index in start..start + len {
+                output.push(output[index]);
+            }
+        }
+    }
+
+    if output.len() == uncompressed_size {
+        Ok(output)
+    } else {
+        Err(PcdError::Decompression)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEADER: &str = "# .PCD v0.7 - Point Cloud Data file format
+VERSION 0.7
+FIELDS x y z rgb
+SIZE 4 4 4 4
+TYPE F F F F
+COUNT 1 1 1 1
+WIDTH 2
+HEIGHT 1
+VIEWPOINT 0 0 0 1 0 0 0
+POINTS 2
+";
+
+    #[test]
+    fn test_parse_ascii() {
+        let rgb = f32::from_bits(0x00FF_8000);
+        let contents = format!("{HEADER}DATA ascii\n1 2 3 {rgb:e}\n4 5 6 {rgb:e}\n");
+
+        let cloud = parse_pcd(contents.as_bytes()).unwrap();
+        assert_eq!(cloud.width, 2);
+        assert_eq!(cloud.height, 1);
+        assert_eq!(cloud.field("x"), Some([1.0, 4.0].as_slice()));
+        assert_eq!(cloud.field("z"), Some([3.0, 6.0].as_slice()));
+        assert_eq!(cloud.packed_colors(), Some(vec![0xFFFF_8000, 0xFFFF_8000]));
+    }
+
+    #[test]
+    fn test_parse_binary() {
+        let mut contents = HEADER.replace("rgb", "label").replace("F F F F", "F F F U");
+        contents.push_str("DATA binary\n");
+        let mut contents = contents.into_bytes();
+        for (xyz, label) in [([1.0f32, 2.0, 3.0], 7u32), ([f32::NAN, 0.0, 0.0], 8)] {
+            for v in xyz {
+                contents.extend_from_slice(&v.to_le_bytes());
+            }
+            contents.extend_from_slice(&label.to_le_bytes());
+        }
+
+        let cloud = parse_pcd(&contents).unwrap();
+        assert_eq!(cloud.field("label"), Some([7.0, 8.0].as_slice()));
+        assert!(cloud.field("x").unwrap()[1].is_nan());
+        assert_eq!(cloud.packed_colors(), None);
+    }
+
+    #[test]
+    fn test_parse_binary_compressed() {
+        // Field by field: x = [1, 1], y = [2, 2], z = [3, 3], rgb = [0, 0].
+        let mut uncompressed = Vec::new();
+        for v in [1.0f32, 1.0, 2.0, 2.0, 3.0, 3.0, 0.0, 0.0] {
+            uncompressed.extend_from_slice(&v.to_le_bytes());
+        }
+
+        // The second `x` is a back-reference to the first one, everything else is stored as is.
+        let mut compressed = vec![3];
+        compressed.extend_from_slice(&uncompressed[..4]);
+        compressed.extend_from_slice(&[(4 - 2) << 5, 4 - 1]);
+        compressed.push(23);
+        compressed.extend_from_slice(&uncompressed[8..]);
+
+        let mut contents = format!("{HEADER}DATA binary_compressed\n").into_bytes();
+        contents.extend_from_slice(&(compressed.len() as u32).to_le_bytes());
+        contents.extend_from_slice(&(uncompressed.len() as u32).to_le_bytes());
+        contents.extend_from_slice(&compressed);
+
+        let cloud = parse_pcd(&contents).unwrap();
+        assert_eq!(cloud.field("y"), Some([2.0, 2.0].as_slice()));
+        assert_eq!(cloud.field("z"), Some([3.0, 3.0].as_slice()));
+    }
+}
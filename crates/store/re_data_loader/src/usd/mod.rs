@@ -0,0 +1,258 @@
+//! A module for reading [USD](https://openusd.org/) scenes.
+//!
+//! Three containers are supported:
+//! - `.usda`: the human readable text format.
+//! - `.usdc`: the binary "crate" format.
+//! - `.usdz`: an uncompressed zip archive, whose first layer is the root of the scene.
+//!
+//! `.usd` files can be either text or binary, which is detected from their contents.
+//!
+//! Only a single layer is read: composition arcs (sublayers, references, payloads, variants,
+//! inherits) are not resolved. Attributes are read at their default value, falling back to their
+//! first time sample in text layers.
+
+mod usda;
+mod usdc;
+
+use std::collections::BTreeMap;
+
+pub use self::{usda::parse_usda, usdc::parse_usdc};
+
+/// Errors that might happen when reading a USD scene.
+#[derive(thiserror::Error, Debug)]
+pub enum UsdError {
+    #[error("Not a {0} file")]
+    InvalidMagic(&'static str),
+
+    #[error("Unexpected end of file while reading {0}")]
+    UnexpectedEof(&'static str),
+
+    #[error("Syntax error on line {line}: {reason}")]
+    Syntax { line: usize, reason: String },
+
+    #[error("Unsupported crate file version {0}.{1}.{2}")]
+    UnsupportedVersion(u8, u8, u8),
+
+    #[error("Invalid crate file: {0}")]
+    InvalidCrate(String),
+
+    #[error("USDZ archive entry {0:?} is compressed, which the USDZ specification forbids")]
+    CompressedUsdzEntry(String),
+
+    #[error("USDZ archive contains no USD layer")]
+    EmptyUsdz,
+}
+
+/// The value of an attribute, or of a piece of metadata.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Bool(bool),
+
+    /// Any scalar number.
+    Number(f64),
+
+    /// A string, token, or asset path.
+    Text(String),
+
+    /// An array of strings or tokens.
+    Texts(Vec<String>),
+
+    /// A single vector, quaternion, or matrix, flattened in row-major order.
+    ///
+    /// Quaternions are stored as `(w, x, y, z)`, like in the text format.
+    Tuple(Vec<f64>),
+
+    /// An array of scalars (`width == 1`), or of tuples of `width` numbers each.
+    Array {
+        values: Vec<f64>,
+        width: usize,
+    },
+}
+
+impl Value {
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Number(value) => Some(*value),
+            Self::Bool(value) => Some(f64::from(u8::from(*value))),
+            _ => None,
+        }
+    }
+
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Self::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    pub fn as_texts(&self) -> Option<&[String]> {
+        match self {
+            Self::Texts(texts) => Some(texts),
+            _ => None,
+        }
+    }
+
+    pub fn as_tuple(&self) -> Option<&[f64]> {
+        match self {
+            Self::Tuple(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Returns the flattened values of an array of tuples with `width` numbers each.
+    ///
+    /// Empty arrays match any width.
+    pub fn as_array(&self, width: usize) -> Option<&[f64]> {
+        match self {
+            Self::Array { values, .. } if values.is_empty() => Some(values),
+            Self::Array {
+                values,
+                width: actual,
+            } if *actual == width => Some(values),
+            _ => None,
+        }
+    }
+}
+
+/// A single prim of a [`Stage`], without its children.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Prim {
+    /// The schema of the prim, e.g. `Xform` or `Mesh`. Empty for typeless prims.
+    pub type_name: String,
+
+    /// The names of the children, in order.
+    pub children: Vec<String>,
+
+    /// The values of all attributes that have one, keyed by their namespaced name.
+    pub attributes: BTreeMap<String, Value>,
+
+    /// The `interpolation` of attributes that specify one, e.g. `vertex` for primvars.
+    pub interpolations: BTreeMap<String, String>,
+
+    /// The targets of relationships and attribute connections, keyed by the property name.
+    pub targets: BTreeMap<String, Vec<String>>,
+}
+
+impl Prim {
+    pub fn attribute(&self, name: &str) -> Option<&Value> {
+        self.attributes.get(name)
+    }
+
+    pub fn interpolation(&self, name: &str) -> Option<&str> {
+        self.interpolations.get(name).map(String::as_str)
+    }
+
+    /// The first target of a relationship or connection.
+    pub fn target(&self, name: &str) -> Option<&str> {
+        self.targets.get(name)?.first().map(String::as_str)
+    }
+}
+
+/// A single USD layer.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Stage {
+    /// Either `Y` or `Z`.
+    pub up_axis: Option<String>,
+
+    pub meters_per_unit: Option<f64>,
+
+    /// The names of the root prims, in order.
+    pub root_prims: Vec<String>,
+
+    /// All prims that are defined (not just overridden) in the layer, keyed by their absolute
+    /// path, e.g. `/World/Robot`.
+    pub prims: BTreeMap<String, Prim>,
+}
+
+impl Stage {
+    /// Reads a `.usda`, `.usdc`, `.usd`, or `.usdz` file, detecting the format from its contents.
+    pub fn parse(contents: &[u8]) -> Result<Self, UsdError> {
+        if contents.starts_with(usdc::MAGIC) {
+            parse_usdc(contents)
+        } else if contents.starts_with(USDZ_MAGIC) {
+            parse_usdz(contents)
+        } else if contents.starts_with(b"#usda") {
+            parse_usda(contents)
+        } else {
+            Err(UsdError::InvalidMagic("USD"))
+        }
+    }
+
+    pub fn prim(&self, path: &str) -> Option<&Prim> {
+        self.prims.get(path)
+    }
+
+    /// Walks the prim hierarchy depth first, in the order of the children.
+    pub fn walk(&self) -> impl Iterator<Item = (&str, &Prim)> {
+        let mut stack = self
+            .root_prims
+            .iter()
+            .rev()
+            .map(|name| format!("/{name}"))
+            .collect::<Vec<_>>();
+
+        std::iter::from_fn(move || {
+            loop {
+                let path = stack.pop()?;
+                let Some((path, prim)) = self.prims.get_key_value(&path) else {
+                    continue; // Children that are only overridden.
+                };
+                stack.extend(
+                    prim.children
+                        .iter()
+                        .rev()
+                        .map(|child| format!("{path}/{child}")),
+                );
+                return Some((path.as_str(), prim));
+            }
+        })
+    }
+}
+
+/// Magic of the zip local file header.
+const USDZ_MAGIC: &[u8] = b"PK\x03\x04";
+
+/// Reads the first USD layer in a `.usdz` archive.
+///
+/// USDZ archives are zip files without compression, so the entries can be read in place.
+pub fn parse_usdz(contents: &[u8]) -> Result<Stage, UsdError> {
+    let mut offset = 0;
+    while contents.get(offset..offset + 4) == Some(USDZ_MAGIC) {
+        let header = contents
+            .get(offset..offset + 30)
+            .ok_or(UsdError::UnexpectedEof("USDZ entry header"))?;
+        let u16_at = |i: usize| usize::from(u16::from_le_bytes([header[i], header[i + 1]]));
+        let u32_at = |i: usize| {
+            u32::from_le_bytes([header[i], header[i + 1], header[i + 2], header[i + 3]]) as usize
+        };
+        let compression = u16_at(8);
+        let size = u32_at(18);
+        let name_length = u16_at(26);
+        let extra_length = u16_at(28);
+
+        let name_start = offset + 30;
+        let data_start = name_start + name_length + extra_length;
+        let name = contents
+            .get(name_start..name_start + name_length)
+            .ok_or(UsdError::UnexpectedEof("USDZ entry name"))?;
+        let name = String::from_utf8_lossy(name).into_owned();
+        let data = contents
+            .get(data_start..data_start + size)
+            .ok_or(UsdError::UnexpectedEof("USDZ entry"))?;
+
+        let extension = name.rsplit('.').next().unwrap_or_default();
+        if ["usd", "usda", "usdc"]
+            .iter()
+            .any(|usd| extension.eq_ignore_ascii_case(usd))
+        {
+            if compression != 0 {
+                return Err(UsdError::CompressedUsdzEntry(name));
+            }
+            return Stage::parse(data);
+        }
+
+        offset = data_start + size;
+    }
+
+    Err(UsdError::EmptyUsdz)
+}
@@ -0,0 +1,650 @@
+//! Parser for the USD text format, see
+//! <https://openusd.org/release/api/sdf_page_front.html#sdf_usda_format>.
+
+use super::{Prim, Stage, UsdError, Value};
+
+/// Parses a `.usda` layer.
+pub fn parse_usda(contents: &[u8]) -> Result<Stage, UsdError> {
+    re_tracing::profile_function!();
+
+    let text = std::str::from_utf8(contents).map_err(|err| UsdError::Syntax {
+        line: 1,
+        reason: err.to_string(),
+    })?;
+    let mut parser = Parser {
+        tokens: tokenize(text)?,
+        pos: 0,
+    };
+
+    let mut stage = Stage::default();
+    if parser.peek() == Some(&Token::Punct('(')) {
+        for (key, value) in parser.metadata()? {
+            match (key.as_str(), value) {
+                ("upAxis", Raw::Str(axis)) => stage.up_axis = Some(axis),
+                ("metersPerUnit", Raw::Number(meters)) => stage.meters_per_unit = Some(meters),
+                _ => {}
+            }
+        }
+    }
+
+    while parser.peek().is_some() {
+        if let Some(name) = parser.prim("", &mut stage)? {
+            stage.root_prims.push(name);
+        }
+    }
+
+    Ok(stage)
+}
+
+// ----------------------------------------------------------------------------
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    /// Identifiers and keywords, including namespaced names like `xformOp:translate` and
+    /// property suffixes like `.connect`.
+    Ident(String),
+    Str(String),
+    Number(f64),
+    Path(String),
+    Asset(String),
+    Punct(char),
+}
+
+fn tokenize(text: &str) -> Result<Vec<(Token, usize)>, UsdError> {
+    let mut tokens = Vec::new();
+    let mut chars = text.char_indices().peekable();
+    let mut line = 1;
+
+    let syntax_error = |line: usize, reason: &str| UsdError::Syntax {
+        line,
+        reason: reason.to_owned(),
+    };
+
+    while let Some((start, c)) = chars.next() {
+        match c {
+            '\n' => line += 1,
+            c if c.is_whitespace() => {}
+            '#' => {
+                // Comments, including the `#usda 1.0` header.
+                while chars.next_if(|(_, c)| *c != '\n').is_some() {}
+            }
+            '"' | '\'' => {
+                let is_triple = text[start..].starts_with(&c.to_string().repeat(3));
+                if is_triple {
+                    chars.next();
+                    chars.next();
+                }
+                let mut value = String::new();
+                loop {
+                    let (i, next) = chars
+                        .next()
+                        .ok_or_else(|| syntax_error(line, "unterminated string"))?;
+                    if next == '\n' {
+                        line += 1;
+                    }
+                    if next == '\\' {
+                        if let Some((_, escaped)) = chars.next() {
+                            value.push(match escaped {
+                                'n' => '\n',
+                                't' => '\t',
+                                other => other,
+                            });
+                        }
+                    } else if next == c
+                        && (!is_triple || text[i..].starts_with(&c.to_string().repeat(3)))
+                    {
+                        if is_triple {
+                            chars.next();
+                            chars.next();
+                        }
+                        break;
+                    } else {
+                        value.push(next);
+                    }
+                }
+                tokens.push((Token::Str(value), line));
+            }
+            '<' => {
+                let mut path = String::new();
+                loop {
+                    match chars.next() {
+                        Some((_, '>')) => break,
+                        Some((_, c)) => path.push(c),
+                        None => return Err(syntax_error(line, "unterminated path")),
+                    }
+                }
+                tokens.push((Token::Path(path), line));
+            }
+            '@' => {
+                let is_triple = text[start..].starts_with("@@@");
+                let delimiter = if is_triple { "@@@" } else { "@" };
+                let body_start = start + delimiter.len();
+                let length = text[body_start..]
+                    .find(delimiter)
+                    .ok_or_else(|| syntax_error(line, "unterminated asset path"))?;
+                tokens.push((
+                    Token::Asset(text[body_start..body_start + length].to_owned()),
+                    line,
+                ));
+                let end = body_start + length + delimiter.len();
+                while chars.next_if(|(i, _)| *i < end).is_some() {}
+            }
+            c if c.is_ascii_digit() || c == '-' || c == '+' || c == '.' => {
+                let mut end = start + c.len_utf8();
+                while let Some((i, next)) = chars.peek().copied() {
+                    let is_exponent_sign =
+                        (next == '-' || next == '+') && text[..i].ends_with(['e', 'E']);
+                    if next.is_ascii_alphanumeric() || next == '.' || is_exponent_sign {
+                        end = i + next.len_utf8();
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                let literal = &text[start..end];
+                let number = match literal {
+                    "-inf" => f64::NEG_INFINITY,
+                    "+inf" => f64::INFINITY,
+                    _ => literal.parse().map_err(|_err| {
+                        syntax_error(line, &format!("invalid number {literal:?}"))
+                    })?,
+                };
+                tokens.push((Token::Number(number), line));
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let mut end = start + c.len_utf8();
+                while let Some((i, next)) = chars.peek().copied() {
+                    if next.is_alphanumeric() || matches!(next, '_' | ':' | '.') {
+                        end = i + next.len_utf8();
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                tokens.push((Token::Ident(text[start..end].to_owned()), line));
+            }
+            c => tokens.push((Token::Punct(c), line)),
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// A parsed value, before it's interpreted based on the type of its attribute.
+#[derive(Clone, Debug, PartialEq)]
+enum Raw {
+    None,
+    Number(f64),
+    Str(String),
+    Path(String),
+    Tuple(Vec<Raw>),
+    List(Vec<Raw>),
+    Dictionary,
+}
+
+impl Raw {
+    fn flatten_numbers(&self, numbers: &mut Vec<f64>) -> bool {
+        match self {
+            Self::Number(number) => {
+                numbers.push(*number);
+                true
+            }
+            Self::Tuple(items) => items.iter().all(|item| item.flatten_numbers(numbers)),
+            _ => false,
+        }
+    }
+
+    fn paths(&self) -> Vec<String> {
+        match self {
+            Self::Path(path) => vec![path.clone()],
+            Self::List(items) => items.iter().flat_map(Self::paths).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Interprets the value as an attribute of the given type, e.g. `point3f[]`.
+    fn into_value(self, type_name: &str) -> Option<Value> {
+        let is_array = type_name.ends_with("[]");
+        match self {
+            Self::None | Self::Path(_) | Self::Dictionary => None,
+            Self::Number(number) if type_name == "bool" => Some(Value::Bool(number != 0.0)),
+            Self::Number(number) => Some(Value::Number(number)),
+            Self::Str(text) if type_name == "bool" => Some(Value::Bool(text == "true")),
+            Self::Str(text) => Some(Value::Text(text)),
+            Self::Tuple(_) => {
+                let mut values = Vec::new();
+                self.flatten_numbers(&mut values)
+                    .then_some(Value::Tuple(values))
+            }
+            Self::List(items) if items.iter().all(|item| matches!(item, Self::Str(_))) => {
+                let is_text = ["token", "string", "asset"]
+                    .iter()
+                    .any(|prefix| type_name.starts_with(prefix));
+                if items.is_empty() && !is_text {
+                    return Some(Value::Array {
+                        values: Vec::new(),
+                        width: 1,
+                    });
+                }
+                Some(Value::Texts(
+                    items
+                        .into_iter()
+                        .filter_map(|item| match item {
+                            Self::Str(text) => Some(text),
+                            _ => None,
+                        })
+                        .collect(),
+                ))
+            }
+            Self::List(items) if is_array || type_name.is_empty() => {
+                let mut first = Vec::new();
+                let width = match items.first() {
+                    Some(item @ Self::Tuple(_)) if item.flatten_numbers(&mut first) => first.len(),
+                    _ => 1,
+                };
+                let mut values = Vec::with_capacity(items.len() * width);
+                for item in &items {
+                    if !item.flatten_numbers(&mut values) {
+                        return None;
+                    }
+                }
+                Some(Value::Array { values, width })
+            }
+            Self::List(_) => None,
+        }
+    }
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(token, _)| token)
+    }
+
+    fn peek_ident(&self) -> Option<&str> {
+        match self.peek() {
+            Some(Token::Ident(ident)) => Some(ident),
+            _ => None,
+        }
+    }
+
+    fn line(&self) -> usize {
+        self.tokens
+            .get(self.pos.min(self.tokens.len().saturating_sub(1)))
+            .map_or(1, |(_, line)| *line)
+    }
+
+    fn error(&self, reason: impl Into<String>) -> UsdError {
+        UsdError::Syntax {
+            line: self.line(),
+            reason: reason.into(),
+        }
+    }
+
+    fn next(&mut self) -> Result<Token, UsdError> {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .map(|(token, _)| token.clone())
+            .ok_or_else(|| self.error("unexpected end of file"))?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn eat(&mut self, punct: char) -> bool {
+        if self.peek() == Some(&Token::Punct(punct)) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, punct: char) -> Result<(), UsdError> {
+        if self.eat(punct) {
+            Ok(())
+        } else {
+            Err(self.error(format!("expected {punct:?}, found {:?}", self.peek())))
+        }
+    }
+
+    fn ident(&mut self) -> Result<String, UsdError> {
+        match self.next()? {
+            Token::Ident(ident) => Ok(ident),
+            token => Err(self.error(format!("expected a name, found {token:?}"))),
+        }
+    }
+
+    fn value(&mut self) -> Result<Raw, UsdError> {
+        Ok(match self.next()? {
+            Token::Number(number) => Raw::Number(number),
+            Token::Str(text) => Raw::Str(text),
+            Token::Path(path) => Raw::Path(path),
+            Token::Asset(asset) => {
+                // References and payloads can target a prim within the asset.
+                if let Some(Token::Path(_)) = self.peek() {
+                    self.pos += 1;
+                }
+                if self.peek() == Some(&Token::Punct('(')) {
+                    self.skip_balanced()?;
+                }
+                Raw::Str(asset)
+            }
+            Token::Ident(ident) => match ident.as_str() {
+                "None" => Raw::None,
+                "true" => Raw::Number(1.0),
+                "false" => Raw::Number(0.0),
+                "inf" => Raw::Number(f64::INFINITY),
+                "nan" => Raw::Number(f64::NAN),
+                _ => Raw::Str(ident),
+            },
+            Token::Punct('(') => Raw::Tuple(self.items(')')?),
+            Token::Punct('[') => Raw::List(self.items(']')?),
+            Token::Punct('{') => {
+                self.pos -= 1;
+                self.skip_balanced()?;
+                Raw::Dictionary
+            }
+            token => return Err(self.error(format!("expected a value, found {token:?}"))),
+        })
+    }
+
+    /// Comma separated values, until the closing delimiter.
+    fn items(&mut self, close: char) -> Result<Vec<Raw>, UsdError> {
+        let mut items = Vec::new();
+        while !self.eat(close) {
+            items.push(self.value()?);
+            if !self.eat(',') {
+                self.expect(close)?;
+                break;
+            }
+        }
+        Ok(items)
+    }
+
+    /// Skips a parenthesized, bracketed, or braced group, including nested groups.
+    fn skip_balanced(&mut self) -> Result<(), UsdError> {
+        let mut depth = 0usize;
+        loop {
+            match self.next()? {
+                Token::Punct('(' | '[' | '{') => depth += 1,
+                Token::Punct(')' | ']' | '}') => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+            if depth == 0 {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Parses `( key = value ... )` metadata.
+    ///
+    /// Only entries with a plain `key = value` form are returned, doc strings and list edits
+    /// like `prepend apiSchemas = [...]` are skipped.
+    fn metadata(&mut self) -> Result<Vec<(String, Raw)>, UsdError> {
+        self.expect('(')?;
+        let mut entries = Vec::new();
+        while !self.eat(')') {
+            match self.next()? {
+                Token::Str(_doc) => {}
+                Token::Punct(';') => {}
+                Token::Ident(ident) => {
+                    let is_list_edit = matches!(
+                        ident.as_str(),
+                        "add" | "append" | "prepend" | "delete" | "reorder"
+                    );
+                    let key = if is_list_edit { self.ident()? } else { ident };
+                    if self.eat('=') {
+                        let value = self.value()?;
+                        if !is_list_edit {
+                            entries.push((key, value));
+                        }
+                    }
+                }
+                token => return Err(self.error(format!("unexpected {token:?} in metadata"))),
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Parses a prim and all of its children, and adds the defined ones to the stage.
+    ///
+    /// Returns the name of the prim if it's defined.
+    fn prim(&mut self, parent: &str, stage: &mut Stage) -> Result<Option<String>, UsdError> {
+        let specifier = self.ident()?;
+        if !matches!(specifier.as_str(), "def" | "over" | "class") {
+            return Err(self.error(format!("expected a prim, found {specifier:?}")));
+        }
+
+        let mut prim = Prim::default();
+        if let Some(type_name) = self.peek_ident() {
+            prim.type_name = type_name.to_owned();
+            self.pos += 1;
+        }
+        let Token::Str(name) = self.next()? else {
+            return Err(self.error("expected the name of the prim"));
+        };
+        let path = format!("{parent}/{name}");
+
+        let mut is_active = true;
+        if self.peek() == Some(&Token::Punct('(')) {
+            for (key, value) in self.metadata()? {
+                if key == "active" && value == Raw::Number(0.0) {
+                    is_active = false;
+                }
+            }
+        }
+
+        self.expect('{')?;
+        while !self.eat('}') {
+            match self.peek_ident() {
+                Some("def" | "over" | "class") => {
+                    if let Some(child) = self.prim(&path, stage)? {
+                        prim.children.push(child);
+                    }
+                }
+                Some("variantSet") => {
+                    // Variants are not resolved.
+                    self.pos += 1;
+                    self.next()?;
+                    self.expect('=')?;
+                    self.skip_balanced()?;
+                }
+                Some("reorder") => {
+                    self.pos += 1;
+                    self.ident()?;
+                    self.expect('=')?;
+                    self.value()?;
+                }
+                Some(_) => self.property(&mut prim)?,
+                None => {
+                    if !self.eat(';') {
+                        return Err(self.error(format!("unexpected {:?} in prim", self.peek())));
+                    }
+                }
+            }
+        }
+
+        let is_defined = specifier == "def" && is_active;
+        if is_defined {
+            stage.prims.insert(path, prim);
+        }
+        Ok(is_defined.then_some(name))
+    }
+
+    /// Parses an attribute or relationship declaration.
+    fn property(&mut self, prim: &mut Prim) -> Result<(), UsdError> {
+        let mut type_name = self.ident()?;
+        while matches!(
+            type_name.as_str(),
+            "custom" | "uniform" | "varying" | "config" | "add" | "append" | "prepend" | "delete"
+        ) {
+            type_name = self.ident()?;
+        }
+        if self.eat('[') {
+            self.expect(']')?;
+            type_name.push_str("[]");
+        }
+        let is_relationship = type_name == "rel";
+        let name = self.ident()?;
+
+        if self.eat('=') {
+            if let Some(name) = name.strip_suffix(".timeSamples") {
+                // Attributes without a default value use their first sample.
+                self.expect('{')?;
+                let mut first = None;
+                while !self.eat('}') {
+                    let _time = self.value()?;
+                    self.expect(':')?;
+                    let value = self.value()?;
+                    first.get_or_insert(value);
+                    self.eat(',');
+                }
+                if let Some(value) = first.and_then(|value| value.into_value(&type_name)) {
+                    prim.attributes.entry(name.to_owned()).or_insert(value);
+                }
+            } else if let Some(name) = name.strip_suffix(".connect") {
+                let targets = self.value()?.paths();
+                prim.targets.insert(name.to_owned(), targets);
+            } else {
+                let value = self.value()?;
+                if is_relationship {
+                    prim.targets.insert(name.clone(), value.paths());
+                } else if let Some(value) = value.into_value(&type_name) {
+                    prim.attributes.insert(name.clone(), value);
+                }
+            }
+        }
+
+        if self.peek() == Some(&Token::Punct('(')) {
+            for (key, value) in self.metadata()? {
+                if let ("interpolation", Raw::Str(interpolation)) = (key.as_str(), value) {
+                    prim.interpolations.insert(name.clone(), interpolation);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CUBE: &str = r#"#usda 1.0
+(
+    defaultPrim = "World"
+    metersPerUnit = 0.01
+    upAxis = "Z"
+    doc = """A test
+scene"""
+)
+
+def Xform "World" (
+    kind = "component"
+)
+{
+    def Mesh "Cube" (
+        prepend apiSchemas = ["MaterialBindingAPI"]
+    )
+    {
+        int[] faceVertexCounts = [4]
+        int[] faceVertexIndices = [0, 1, 2, 3]
+        point3f[] points = [(-1, -1, 0), (1, -1, 0), (1, 1, 0), (-1, 1, 0)]
+        color3f[] primvars:displayColor = [(1, 0.5, 0)] (
+            interpolation = "constant"
+        )
+        rel material:binding = </World/Looks/Red>
+        double3 xformOp:translate = (1, 2, 3e-1)
+        float xformOp:rotateZ.timeSamples = {
+            0: 90,
+            10: 180,
+        }
+        uniform token[] xformOpOrder = ["xformOp:translate", "xformOp:rotateZ"]
+    }
+
+    over "Hidden"
+    {
+        def Sphere "Ignored" {}
+    }
+
+    def Scope "Looks"
+    {
+        def Material "Red"
+        {
+            token outputs:surface.connect = </World/Looks/Red/Shader.outputs:surface>
+            def Shader "Shader"
+            {
+                uniform token info:id = "UsdPreviewSurface"
+                color3f inputs:diffuseColor = (1, 0, 0)
+            }
+        }
+    }
+}
+"#;
+
+    #[test]
+    fn parse_scene() {
+        let stage = parse_usda(CUBE.as_bytes()).unwrap();
+        assert_eq!(stage.up_axis.as_deref(), Some("Z"));
+        assert_eq!(stage.meters_per_unit, Some(0.01));
+        assert_eq!(stage.root_prims, ["World"]);
+
+        let paths = stage.walk().map(|(path, _)| path).collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            [
+                "/World",
+                "/World/Cube",
+                "/World/Looks",
+                "/World/Looks/Red",
+                "/World/Looks/Red/Shader",
+            ]
+        );
+
+        let cube = stage.prim("/World/Cube").unwrap();
+        assert_eq!(cube.type_name, "Mesh");
+        assert_eq!(
+            cube.attribute("points")
+                .and_then(|v| v.as_array(3))
+                .unwrap(),
+            [
+                -1.0, -1.0, 0.0, 1.0, -1.0, 0.0, 1.0, 1.0, 0.0, -1.0, 1.0, 0.0
+            ]
+        );
+        assert_eq!(
+            cube.attribute("faceVertexIndices")
+                .and_then(|v| v.as_array(1)),
+            Some(&[0.0, 1.0, 2.0, 3.0][..])
+        );
+        assert_eq!(
+            cube.interpolation("primvars:displayColor"),
+            Some("constant")
+        );
+        assert_eq!(cube.target("material:binding"), Some("/World/Looks/Red"));
+        assert_eq!(
+            cube.attribute("xformOp:translate")
+                .and_then(|v| v.as_tuple()),
+            Some(&[1.0, 2.0, 0.3][..])
+        );
+        assert_eq!(
+            cube.attribute("xformOp:rotateZ"),
+            Some(&Value::Number(90.0))
+        );
+        assert_eq!(
+            cube.attribute("xformOpOrder").and_then(|v| v.as_texts()),
+            Some(&["xformOp:translate".to_owned(), "xformOp:rotateZ".to_owned()][..])
+        );
+
+        let material = stage.prim("/World/Looks/Red").unwrap();
+        assert_eq!(
+            material.target("outputs:surface"),
+            Some("/World/Looks/Red/Shader.outputs:surface")
+        );
+    }
+}
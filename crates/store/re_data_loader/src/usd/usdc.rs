@@ -0,0 +1,1006 @@
+//! Reader for the binary USD "crate" format (`.usdc`).
+//!
+//! A crate file starts with a bootstrap header pointing to a table of contents, whose sections
+//! hold deduplicated tokens, strings, fields (a name and a value), field sets, paths, and specs.
+//! Every spec (a prim, attribute, or relationship) is a path and a set of fields.
+//!
+//! Structural sections and large integer and float arrays are compressed with LZ4 on top of a
+//! delta encoding, see `crateFile.cpp` and `integerCoding.cpp` in the USD sources.
+
+#![expect(clippy::cast_possible_wrap)] // The file format reinterprets unsigned integers as signed.
+
+use std::collections::BTreeMap;
+
+use super::{Prim, Stage, UsdError, Value};
+
+pub(super) const MAGIC: &[u8] = b"PXR-USDC";
+
+/// Parses a `.usdc` layer.
+pub fn parse_usdc(contents: &[u8]) -> Result<Stage, UsdError> {
+    re_tracing::profile_function!();
+
+    let crate_file = CrateFile::new(contents)?;
+    crate_file.stage()
+}
+
+// ----------------------------------------------------------------------------
+
+/// Spec types, see `SdfSpecType`.
+const SPEC_TYPE_ATTRIBUTE: u32 = 1;
+const SPEC_TYPE_PRIM: u32 = 6;
+const SPEC_TYPE_PSEUDO_ROOT: u32 = 7;
+const SPEC_TYPE_RELATIONSHIP: u32 = 8;
+
+/// `SdfSpecifier::Def`.
+const SPECIFIER_DEF: u32 = 0;
+
+/// Terminates the fields of a field set.
+const FIELD_SET_END: u32 = u32::MAX;
+
+/// The types of values, see `crateDataTypes.h`.
+mod value_type {
+    pub const BOOL: u8 = 1;
+    pub const UCHAR: u8 = 2;
+    pub const INT: u8 = 3;
+    pub const UINT: u8 = 4;
+    pub const INT64: u8 = 5;
+    pub const UINT64: u8 = 6;
+    pub const HALF: u8 = 7;
+    pub const FLOAT: u8 = 8;
+    pub const DOUBLE: u8 = 9;
+    pub const STRING: u8 = 10;
+    pub const TOKEN: u8 = 11;
+    pub const ASSET_PATH: u8 = 12;
+    pub const MATRIX2D: u8 = 13;
+    pub const MATRIX3D: u8 = 14;
+    pub const MATRIX4D: u8 = 15;
+    pub const QUATD: u8 = 16;
+    pub const QUATF: u8 = 17;
+    pub const QUATH: u8 = 18;
+    pub const VEC2D: u8 = 19;
+    pub const VEC4I: u8 = 30;
+    pub const PATH_LIST_OP: u8 = 34;
+    pub const TOKEN_VECTOR: u8 = 41;
+    pub const SPECIFIER: u8 = 42;
+    pub const VARIABILITY: u8 = 44;
+    pub const TIME_SAMPLES: u8 = 46;
+    pub const DOUBLE_VECTOR: u8 = 48;
+    pub const STRING_VECTOR: u8 = 50;
+    pub const TIME_CODE: u8 = 56;
+}
+
+/// The scalar type of the components of a vector, quaternion, or matrix.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Scalar {
+    F64,
+    F32,
+    F16,
+    I32,
+}
+
+impl Scalar {
+    fn size(self) -> usize {
+        match self {
+            Self::F64 => 8,
+            Self::F32 | Self::I32 => 4,
+            Self::F16 => 2,
+        }
+    }
+
+    fn read(self, bytes: &[u8]) -> f64 {
+        match self {
+            Self::F64 => f64::from_le_bytes(bytes[..8].try_into().unwrap_or_default()),
+            Self::F32 => f64::from(f32::from_le_bytes(
+                bytes[..4].try_into().unwrap_or_default(),
+            )),
+            Self::F16 => f64::from(f16_to_f32(u16::from_le_bytes([bytes[0], bytes[1]]))),
+            Self::I32 => f64::from(i32::from_le_bytes(
+                bytes[..4].try_into().unwrap_or_default(),
+            )),
+        }
+    }
+}
+
+/// The layout of a vector, quaternion, or matrix value type.
+fn tuple_layout(value_type: u8) -> Option<(Scalar, usize)> {
+    use value_type::{MATRIX2D, MATRIX3D, MATRIX4D, QUATD, QUATF, QUATH, VEC2D, VEC4I};
+
+    Some(match value_type {
+        MATRIX2D => (Scalar::F64, 4),
+        MATRIX3D => (Scalar::F64, 9),
+        MATRIX4D => (Scalar::F64, 16),
+        QUATD => (Scalar::F64, 4),
+        QUATF => (Scalar::F32, 4),
+        QUATH => (Scalar::F16, 4),
+        VEC2D..=VEC4I => {
+            let index = value_type - VEC2D;
+            let scalar =
+                [Scalar::F64, Scalar::F32, Scalar::F16, Scalar::I32][usize::from(index % 4)];
+            (scalar, 2 + usize::from(index / 4))
+        }
+        _ => return None,
+    })
+}
+
+fn is_quaternion(value_type: u8) -> bool {
+    (value_type::QUATD..=value_type::QUATH).contains(&value_type)
+}
+
+fn is_matrix(value_type: u8) -> bool {
+    (value_type::MATRIX2D..=value_type::MATRIX4D).contains(&value_type)
+}
+
+/// Quaternions are stored as `(x, y, z, w)`, but exposed as `(w, x, y, z)` like in text layers.
+fn quaternion_from_xyzw(values: &mut [f64]) {
+    for quaternion in values.chunks_exact_mut(4) {
+        quaternion.rotate_right(1);
+    }
+}
+
+fn f16_to_f32(bits: u16) -> f32 {
+    let sign = if bits & 0x8000 == 0 { 1.0 } else { -1.0 };
+    let exponent = i32::from((bits >> 10) & 0x1f);
+    let mantissa = f32::from(bits & 0x3ff);
+    match exponent {
+        0 => sign * mantissa * 2f32.powi(-24),
+        31 if mantissa == 0.0 => sign * f32::INFINITY,
+        31 => f32::NAN,
+        _ => sign * (1.0 + mantissa / 1024.0) * 2f32.powi(exponent - 15),
+    }
+}
+
+/// A reference to a value in the file.
+#[derive(Clone, Copy, Debug)]
+struct ValueRep(u64);
+
+impl ValueRep {
+    fn is_array(self) -> bool {
+        self.0 & (1 << 63) != 0
+    }
+
+    fn is_inlined(self) -> bool {
+        self.0 & (1 << 62) != 0
+    }
+
+    fn is_compressed(self) -> bool {
+        self.0 & (1 << 61) != 0
+    }
+
+    fn value_type(self) -> u8 {
+        (self.0 >> 48) as u8
+    }
+
+    /// Either the inlined value, or the offset of the value in the file.
+    fn payload(self) -> u64 {
+        self.0 & ((1 << 48) - 1)
+    }
+}
+
+/// A little-endian cursor over a byte slice.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn at(bytes: &'a [u8], pos: u64) -> Self {
+        Self {
+            bytes,
+            pos: pos as usize,
+        }
+    }
+
+    fn take(&mut self, len: usize, what: &'static str) -> Result<&'a [u8], UsdError> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or(UsdError::UnexpectedEof(what))?;
+        let bytes = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn array<const N: usize>(&mut self, what: &'static str) -> Result<[u8; N], UsdError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.take(N, what)?);
+        Ok(array)
+    }
+
+    fn u8(&mut self, what: &'static str) -> Result<u8, UsdError> {
+        Ok(self.array::<1>(what)?[0])
+    }
+
+    fn u32(&mut self, what: &'static str) -> Result<u32, UsdError> {
+        Ok(u32::from_le_bytes(self.array(what)?))
+    }
+
+    fn i64(&mut self, what: &'static str) -> Result<i64, UsdError> {
+        Ok(i64::from_le_bytes(self.array(what)?))
+    }
+
+    fn u64(&mut self, what: &'static str) -> Result<u64, UsdError> {
+        Ok(u64::from_le_bytes(self.array(what)?))
+    }
+
+    /// Reads a `u64` that is used as a length or count.
+    fn len(&mut self, what: &'static str) -> Result<usize, UsdError> {
+        let len = self.u64(what)?;
+        // Every element takes at least one byte, which guards against huge allocations.
+        if len > self.bytes.len() as u64 {
+            return Err(UsdError::UnexpectedEof(what));
+        }
+        Ok(len as usize)
+    }
+
+    fn u32_vec(&mut self, count: usize, what: &'static str) -> Result<Vec<u32>, UsdError> {
+        Ok(self
+            .take(count * 4, what)?
+            .chunks_exact(4)
+            .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .collect())
+    }
+
+    /// Reads a compressed array of 32 bit (or `is_64_bit`) integers, preceded by its compressed size.
+    fn compressed_ints(
+        &mut self,
+        count: usize,
+        is_64_bit: bool,
+        what: &'static str,
+    ) -> Result<Vec<i64>, UsdError> {
+        let compressed_size = self.len(what)?;
+        let compressed = self.take(compressed_size, what)?;
+        let int_size = if is_64_bit { 8 } else { 4 };
+        let max_size = int_size + (count * 2).div_ceil(8) + count * int_size;
+        let encoded = decompress(compressed, max_size)?;
+        decode_integers(&encoded, count, is_64_bit)
+    }
+
+    fn compressed_u32(&mut self, count: usize, what: &'static str) -> Result<Vec<u32>, UsdError> {
+        Ok(self
+            .compressed_ints(count, false, what)?
+            .into_iter()
+            .map(|value| value as u32)
+            .collect())
+    }
+}
+
+/// Decompresses data written by `TfFastCompression`: LZ4 blocks, preceded by their number.
+fn decompress(compressed: &[u8], max_size: usize) -> Result<Vec<u8>, UsdError> {
+    let invalid = |err: lz4_flex::block::DecompressError| UsdError::InvalidCrate(err.to_string());
+
+    let Some((&num_chunks, mut compressed)) = compressed.split_first() else {
+        return Err(UsdError::UnexpectedEof("compressed data"));
+    };
+
+    let mut output = vec![0; max_size];
+    let mut size = 0;
+    if num_chunks == 0 {
+        size = lz4_flex::block::decompress_into(compressed, &mut output).map_err(invalid)?;
+    } else {
+        for _ in 0..num_chunks {
+            let mut reader = Reader::at(compressed, 0);
+            let chunk_size = reader.u32("compressed chunk")? as usize;
+            let chunk = reader.take(chunk_size, "compressed chunk")?;
+            size +=
+                lz4_flex::block::decompress_into(chunk, &mut output[size..]).map_err(invalid)?;
+            compressed = &compressed[reader.pos..];
+        }
+    }
+    output.truncate(size);
+
+    Ok(output)
+}
+
+/// Decodes integers written by `Usd_IntegerCompression`.
+///
+/// The encoding starts with the most common delta between consecutive values, followed by a
+/// 2-bit code per value that selects between that common delta, or a small, medium, or large
+/// delta stored after the codes.
+fn decode_integers(encoded: &[u8], count: usize, is_64_bit: bool) -> Result<Vec<i64>, UsdError> {
+    let mut reader = Reader::at(encoded, 0);
+    let common = if is_64_bit {
+        reader.i64("integer encoding")?
+    } else {
+        i64::from(reader.u32("integer encoding")? as i32)
+    };
+    let codes = reader.take((count * 2).div_ceil(8), "integer codes")?;
+
+    let mut values = Vec::with_capacity(count);
+    let mut previous = 0i64;
+    for i in 0..count {
+        let code = (codes[i / 4] >> ((i % 4) * 2)) & 3;
+        let delta = match (code, is_64_bit) {
+            (0, _) => common,
+            (1, false) => i64::from(reader.u8("integer delta")? as i8),
+            (2, false) | (1, true) => i64::from(i16::from_le_bytes(reader.array("integer delta")?)),
+            (3, false) | (2, true) => i64::from(i32::from_le_bytes(reader.array("integer delta")?)),
+            _ => reader.i64("integer delta")?,
+        };
+        previous = previous.wrapping_add(delta);
+        values.push(if is_64_bit {
+            previous
+        } else {
+            i64::from(previous as i32)
+        });
+    }
+
+    Ok(values)
+}
+
+struct CrateFile<'a> {
+    contents: &'a [u8],
+
+    /// `(major, minor, patch)`.
+    version: (u8, u8, u8),
+
+    tokens: Vec<String>,
+    strings: Vec<u32>,
+    fields: Vec<(u32, ValueRep)>,
+    field_sets: Vec<u32>,
+    paths: Vec<String>,
+
+    /// `(path index, field set index, spec type)`.
+    specs: Vec<(u32, u32, u32)>,
+}
+
+impl<'a> CrateFile<'a> {
+    fn new(contents: &'a [u8]) -> Result<Self, UsdError> {
+        if !contents.starts_with(MAGIC) {
+            return Err(UsdError::InvalidMagic("USD crate"));
+        }
+        let mut reader = Reader::at(contents, 8);
+        let [major, minor, patch, ..] = reader.array::<8>("version")?;
+        if major != 0 || minor < 4 {
+            return Err(UsdError::UnsupportedVersion(major, minor, patch));
+        }
+        let toc_offset = reader.u64("table of contents offset")?;
+
+        let mut sections = BTreeMap::new();
+        let mut reader = Reader::at(contents, toc_offset);
+        for _ in 0..reader.len("table of contents")? {
+            let name = reader.array::<16>("section name")?;
+            let name = String::from_utf8_lossy(&name)
+                .trim_end_matches('\0')
+                .to_owned();
+            let start = reader.u64("section start")?;
+            let _size = reader.u64("section size")?;
+            sections.insert(name, start);
+        }
+        let section = |name: &str| {
+            sections
+                .get(name)
+                .map(|start| Reader::at(contents, *start))
+                .ok_or_else(|| UsdError::InvalidCrate(format!("missing {name} section")))
+        };
+
+        let mut crate_file = Self {
+            contents,
+            version: (major, minor, patch),
+            tokens: Vec::new(),
+            strings: Vec::new(),
+            fields: Vec::new(),
+            field_sets: Vec::new(),
+            paths: Vec::new(),
+            specs: Vec::new(),
+        };
+
+        let mut reader = section("TOKENS")?;
+        let num_tokens = reader.len("tokens")?;
+        let uncompressed_size = reader.len("tokens")?;
+        let compressed_size = reader.len("tokens")?;
+        let tokens = decompress(reader.take(compressed_size, "tokens")?, uncompressed_size)?;
+        crate_file.tokens = tokens
+            .split(|byte| *byte == 0)
+            .take(num_tokens)
+            .map(|token| String::from_utf8_lossy(token).into_owned())
+            .collect();
+
+        let mut reader = section("STRINGS")?;
+        let num_strings = reader.len("strings")?;
+        crate_file.strings = reader.u32_vec(num_strings, "strings")?;
+
+        let mut reader = section("FIELDS")?;
+        let num_fields = reader.len("fields")?;
+        let names = reader.compressed_u32(num_fields, "field names")?;
+        let reps_size = reader.len("field values")?;
+        let reps = decompress(reader.take(reps_size, "field values")?, num_fields * 8)?;
+        crate_file.fields = names
+            .into_iter()
+            .zip(reps.chunks_exact(8))
+            .map(|(name, rep)| {
+                let rep = u64::from_le_bytes(rep.try_into().unwrap_or_default());
+                (name, ValueRep(rep))
+            })
+            .collect();
+
+        let mut reader = section("FIELDSETS")?;
+        let num_field_sets = reader.len("field sets")?;
+        crate_file.field_sets = reader.compressed_u32(num_field_sets, "field sets")?;
+
+        let mut reader = section("PATHS")?;
+        let num_paths = reader.len("paths")?;
+        let num_encoded = reader.len("paths")?;
+        let path_indices = reader.compressed_u32(num_encoded, "path indices")?;
+        let element_tokens = reader.compressed_ints(num_encoded, false, "path elements")?;
+        let jumps = reader.compressed_ints(num_encoded, false, "path jumps")?;
+        crate_file.paths =
+            crate_file.build_paths(num_paths, &path_indices, &element_tokens, &jumps)?;
+
+        let mut reader = section("SPECS")?;
+        let num_specs = reader.len("specs")?;
+        let spec_paths = reader.compressed_u32(num_specs, "spec paths")?;
+        let spec_field_sets = reader.compressed_u32(num_specs, "spec field sets")?;
+        let spec_types = reader.compressed_u32(num_specs, "spec types")?;
+        crate_file.specs = itertools::izip!(spec_paths, spec_field_sets, spec_types).collect();
+
+        Ok(crate_file)
+    }
+
+    /// Rebuilds the paths from their tree encoding.
+    ///
+    /// Paths are stored depth first. Every entry appends one element to its parent, and its jump
+    /// tells whether it has children (that directly follow it) and where its next sibling is:
+    /// `-1` means only children, `0` only a sibling that directly follows, `-2` neither, and a
+    /// positive jump is the offset of the sibling after the children.
+    fn build_paths(
+        &self,
+        num_paths: usize,
+        path_indices: &[u32],
+        element_tokens: &[i64],
+        jumps: &[i64],
+    ) -> Result<Vec<String>, UsdError> {
+        let invalid = || UsdError::InvalidCrate("invalid path tree".to_owned());
+
+        let mut paths = vec![String::new(); num_paths];
+        // `(index of the entry, parent path)`
+        let mut stack = vec![(0usize, None::<String>)];
+        while let Some((mut index, mut parent)) = stack.pop() {
+            loop {
+                let Some(&path_index) = path_indices.get(index) else {
+                    break;
+                };
+                let path = match &parent {
+                    None => "/".to_owned(),
+                    Some(parent) => {
+                        let token = element_tokens[index];
+                        let element = self
+                            .tokens
+                            .get(token.unsigned_abs() as usize)
+                            .ok_or_else(invalid)?;
+                        if token < 0 {
+                            format!("{parent}.{element}")
+                        } else if element.starts_with('{') || parent == "/" {
+                            format!("{parent}{element}")
+                        } else {
+                            format!("{parent}/{element}")
+                        }
+                    }
+                };
+                *paths.get_mut(path_index as usize).ok_or_else(invalid)? = path.clone();
+
+                let jump = jumps[index];
+                let has_child = jump > 0 || jump == -1;
+                let has_sibling = jump >= 0;
+                if has_child {
+                    if has_sibling {
+                        let sibling =
+                            usize::try_from(index as i64 + jump).map_err(|_err| invalid())?;
+                        stack.push((sibling, parent.clone()));
+                    }
+                    parent = Some(path);
+                } else if !has_sibling {
+                    break;
+                }
+                index += 1;
+            }
+        }
+
+        Ok(paths)
+    }
+
+    fn token(&self, index: u64) -> Result<String, UsdError> {
+        self.tokens
+            .get(index as usize)
+            .cloned()
+            .ok_or_else(|| UsdError::InvalidCrate(format!("invalid token index {index}")))
+    }
+
+    fn string(&self, index: u64) -> Result<String, UsdError> {
+        let token = self
+            .strings
+            .get(index as usize)
+            .ok_or_else(|| UsdError::InvalidCrate(format!("invalid string index {index}")))?;
+        self.token(u64::from(*token))
+    }
+
+    fn path(&self, index: u32) -> Result<&str, UsdError> {
+        self.paths
+            .get(index as usize)
+            .map(String::as_str)
+            .ok_or_else(|| UsdError::InvalidCrate(format!("invalid path index {index}")))
+    }
+
+    /// The name and value of every field of a spec.
+    fn spec_fields(&self, field_set: u32) -> impl Iterator<Item = (&str, ValueRep)> {
+        self.field_sets
+            .get(field_set as usize..)
+            .unwrap_or_default()
+            .iter()
+            .take_while(|field| **field != FIELD_SET_END)
+            .filter_map(|field| {
+                let (name, rep) = self.fields.get(*field as usize)?;
+                Some((self.tokens.get(*name as usize)?.as_str(), *rep))
+            })
+    }
+
+    /// The number of elements of an array, which is a `u32` in older files.
+    fn array_len(&self, reader: &mut Reader<'_>) -> Result<usize, UsdError> {
+        if self.version < (0, 7, 0) {
+            Ok(reader.u32("array length")? as usize)
+        } else {
+            reader.len("array length")
+        }
+    }
+
+    /// Reads a value, returning `None` for types that aren't needed to build a [`Stage`].
+    fn value(&self, rep: ValueRep) -> Result<Option<Value>, UsdError> {
+        use value_type::{
+            ASSET_PATH, BOOL, DOUBLE, DOUBLE_VECTOR, FLOAT, HALF, INT, INT64, SPECIFIER, STRING,
+            STRING_VECTOR, TIME_CODE, TOKEN, TOKEN_VECTOR, UCHAR, UINT, UINT64, VARIABILITY,
+        };
+
+        if rep.is_array() {
+            return self.array(rep);
+        }
+
+        let value_type = rep.value_type();
+        let payload = rep.payload();
+        let mut reader = Reader::at(self.contents, payload);
+
+        if rep.is_inlined() {
+            let bytes = payload.to_le_bytes();
+            return Ok(Some(match value_type {
+                BOOL => Value::Bool(payload != 0),
+                UCHAR => Value::Number(f64::from(bytes[0])),
+                INT | SPECIFIER | VARIABILITY => Value::Number(f64::from(payload as u32 as i32)),
+                UINT => Value::Number(f64::from(payload as u32)),
+                // Doubles are inlined if they can be stored as floats without loss.
+                FLOAT | DOUBLE | TIME_CODE => {
+                    Value::Number(f64::from(f32::from_bits(payload as u32)))
+                }
+                HALF => Value::Number(f64::from(f16_to_f32(payload as u16))),
+                STRING => Value::Text(self.string(payload)?),
+                TOKEN | ASSET_PATH => Value::Text(self.token(payload)?),
+                _ => {
+                    // Vectors are inlined if all their components fit in an `i8`, and matrices
+                    // if they are diagonal with such components.
+                    let Some((_, len)) = tuple_layout(value_type) else {
+                        return Ok(None);
+                    };
+                    if is_matrix(value_type) {
+                        let n = (len as f64).sqrt() as usize;
+                        let mut matrix = vec![0.0; len];
+                        for i in 0..n {
+                            matrix[i * n + i] = f64::from(bytes[i] as i8);
+                        }
+                        Value::Tuple(matrix)
+                    } else {
+                        Value::Tuple(bytes[..len].iter().map(|b| f64::from(*b as i8)).collect())
+                    }
+                }
+            }));
+        }
+
+        Ok(Some(match value_type {
+            BOOL | UCHAR => Value::Number(f64::from(reader.u8("value")?)),
+            INT | UINT | INT64 | UINT64 | HALF | FLOAT | DOUBLE | TIME_CODE => {
+                let scalar = self.scalar_array(value_type, false, 1, &mut reader)?;
+                Value::Number(scalar.first().copied().unwrap_or_default())
+            }
+            TOKEN_VECTOR | STRING_VECTOR => {
+                let len = reader.len("token vector")?;
+                let indices = reader.u32_vec(len, "token vector")?;
+                Value::Texts(
+                    indices
+                        .into_iter()
+                        .map(|index| {
+                            if value_type == TOKEN_VECTOR {
+                                self.token(u64::from(index))
+                            } else {
+                                self.string(u64::from(index))
+                            }
+                        })
+                        .collect::<Result<_, _>>()?,
+                )
+            }
+            DOUBLE_VECTOR => {
+                let len = reader.len("double vector")?;
+                let values = reader
+                    .take(len * 8, "double vector")?
+                    .chunks_exact(8)
+                    .map(|b| Scalar::F64.read(b))
+                    .collect();
+                Value::Array { values, width: 1 }
+            }
+            _ => {
+                let Some((scalar, len)) = tuple_layout(value_type) else {
+                    return Ok(None);
+                };
+                let mut values = reader
+                    .take(scalar.size() * len, "value")?
+                    .chunks_exact(scalar.size())
+                    .map(|b| scalar.read(b))
+                    .collect::<Vec<_>>();
+                if is_quaternion(value_type) {
+                    quaternion_from_xyzw(&mut values);
+                }
+                Value::Tuple(values)
+            }
+        }))
+    }
+
+    fn array(&self, rep: ValueRep) -> Result<Option<Value>, UsdError> {
+        use value_type::{
+            ASSET_PATH, BOOL, DOUBLE, FLOAT, HALF, INT, INT64, STRING, TOKEN, UCHAR, UINT, UINT64,
+        };
+
+        let value_type = rep.value_type();
+        if rep.payload() == 0 {
+            // Empty arrays are not stored.
+            return Ok(Some(match value_type {
+                STRING | TOKEN | ASSET_PATH => Value::Texts(Vec::new()),
+                _ => Value::Array {
+                    values: Vec::new(),
+                    width: 1,
+                },
+            }));
+        }
+
+        let mut reader = Reader::at(self.contents, rep.payload());
+        let len = self.array_len(&mut reader)?;
+        Ok(Some(match value_type {
+            BOOL | UCHAR => Value::Array {
+                values: reader
+                    .take(len, "array")?
+                    .iter()
+                    .map(|b| f64::from(*b))
+                    .collect(),
+                width: 1,
+            },
+            INT | UINT | INT64 | UINT64 | HALF | FLOAT | DOUBLE => Value::Array {
+                values: self.scalar_array(value_type, rep.is_compressed(), len, &mut reader)?,
+                width: 1,
+            },
+            STRING | TOKEN | ASSET_PATH => Value::Texts(
+                reader
+                    .u32_vec(len, "array")?
+                    .into_iter()
+                    .map(|index| {
+                        if value_type == STRING {
+                            self.string(u64::from(index))
+                        } else {
+                            self.token(u64::from(index))
+                        }
+                    })
+                    .collect::<Result<_, _>>()?,
+            ),
+            _ => {
+                let Some((scalar, width)) = tuple_layout(value_type) else {
+                    return Ok(None);
+                };
+                let mut values = reader
+                    .take(len * width * scalar.size(), "array")?
+                    .chunks_exact(scalar.size())
+                    .map(|b| scalar.read(b))
+                    .collect::<Vec<_>>();
+                if is_quaternion(value_type) {
+                    quaternion_from_xyzw(&mut values);
+                }
+                Value::Array { values, width }
+            }
+        }))
+    }
+
+    /// Reads `len` scalars, which are compressed for arrays with enough elements.
+    fn scalar_array(
+        &self,
+        value_type: u8,
+        is_compressed: bool,
+        len: usize,
+        reader: &mut Reader<'_>,
+    ) -> Result<Vec<f64>, UsdError> {
+        use value_type::{DOUBLE, FLOAT, HALF, INT, INT64, UINT, UINT64};
+
+        let (size, read): (usize, fn(&[u8]) -> f64) = match value_type {
+            INT => (4, |b| Scalar::I32.read(b)),
+            UINT => (4, |b| {
+                f64::from(u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            }),
+            INT64 => (8, |b| {
+                i64::from_le_bytes(b[..8].try_into().unwrap_or_default()) as f64
+            }),
+            UINT64 => (8, |b| {
+                u64::from_le_bytes(b[..8].try_into().unwrap_or_default()) as f64
+            }),
+            HALF => (2, |b| Scalar::F16.read(b)),
+            FLOAT => (4, |b| Scalar::F32.read(b)),
+            DOUBLE => (8, |b| Scalar::F64.read(b)),
+            _ => return Ok(Vec::new()),
+        };
+
+        if !is_compressed {
+            return Ok(reader
+                .take(len * size, "array")?
+                .chunks_exact(size)
+                .map(read)
+                .collect());
+        }
+
+        match value_type {
+            INT | UINT => Ok(reader
+                .compressed_ints(len, false, "array")?
+                .into_iter()
+                .map(|value| {
+                    if value_type == UINT {
+                        f64::from(value as u32)
+                    } else {
+                        value as f64
+                    }
+                })
+                .collect()),
+            INT64 | UINT64 => Ok(reader
+                .compressed_ints(len, true, "array")?
+                .into_iter()
+                .map(|value| {
+                    if value_type == UINT64 {
+                        value as u64 as f64
+                    } else {
+                        value as f64
+                    }
+                })
+                .collect()),
+            _ => {
+                // Floats are either all integral, or drawn from a small lookup table.
+                match reader.u8("float array encoding")? {
+                    b'i' => Ok(reader
+                        .compressed_ints(len, false, "array")?
+                        .into_iter()
+                        .map(|value| value as f64)
+                        .collect()),
+                    b't' => {
+                        let table_len = reader.u32("float lookup table")? as usize;
+                        let table = reader
+                            .take(table_len * size, "float lookup table")?
+                            .chunks_exact(size)
+                            .map(read)
+                            .collect::<Vec<_>>();
+                        reader
+                            .compressed_u32(len, "array")?
+                            .into_iter()
+                            .map(|index| {
+                                table.get(index as usize).copied().ok_or_else(|| {
+                                    UsdError::InvalidCrate("invalid float lookup index".to_owned())
+                                })
+                            })
+                            .collect()
+                    }
+                    code => Err(UsdError::InvalidCrate(format!(
+                        "unknown float array encoding {code:#x}"
+                    ))),
+                }
+            }
+        }
+    }
+
+    /// The first value of a time sampled attribute.
+    fn first_time_sample(&self, rep: ValueRep) -> Result<Option<Value>, UsdError> {
+        // Two relative jumps lead to the times, and to the reps of the values.
+        let mut reader = Reader::at(self.contents, rep.payload());
+        let jump = reader.i64("time samples")?;
+        let mut reader = Reader::at(self.contents, (rep.payload() as i64 + jump) as u64);
+        let _times = reader.u64("time samples")?;
+        let start = reader.pos as i64;
+        let jump = reader.i64("time samples")?;
+        let mut reader = Reader::at(self.contents, (start + jump) as u64);
+        if reader.len("time samples")? == 0 {
+            return Ok(None);
+        }
+        self.value(ValueRep(reader.u64("time samples")?))
+    }
+
+    /// The targets of a relationship or connection.
+    fn path_list_op(&self, rep: ValueRep) -> Result<Vec<String>, UsdError> {
+        let mut reader = Reader::at(self.contents, rep.payload());
+        let header = reader.u8("list op")?;
+
+        // Explicit, added, prepended, appended, deleted, and ordered items, in that order.
+        let mut targets = Vec::new();
+        for (bit, is_kept) in [
+            (1 << 1, true),
+            (1 << 2, true),
+            (1 << 5, true),
+            (1 << 6, true),
+            (1 << 3, false),
+            (1 << 4, false),
+        ] {
+            if header & bit == 0 {
+                continue;
+            }
+            let len = reader.len("list op")?;
+            for index in reader.u32_vec(len, "list op")? {
+                if is_kept {
+                    targets.push(self.path(index)?.to_owned());
+                }
+            }
+        }
+
+        Ok(targets)
+    }
+
+    fn stage(&self) -> Result<Stage, UsdError> {
+        let mut stage = Stage::default();
+        let mut properties = BTreeMap::<&str, Prim>::new();
+
+        for (path_index, field_set, spec_type) in &self.specs {
+            let path = self.path(*path_index)?;
+            if path.contains('{') {
+                continue; // Variants are not resolved.
+            }
+            let fields = self.spec_fields(*field_set);
+
+            match *spec_type {
+                SPEC_TYPE_PSEUDO_ROOT => {
+                    for (name, rep) in fields {
+                        match name {
+                            "upAxis" => {
+                                stage.up_axis = self
+                                    .value(rep)?
+                                    .and_then(|value| value.as_text().map(ToOwned::to_owned));
+                            }
+                            "metersPerUnit" => {
+                                stage.meters_per_unit =
+                                    self.value(rep)?.and_then(|value| value.as_f64());
+                            }
+                            "primChildren" => {
+                                if let Some(Value::Texts(children)) = self.value(rep)? {
+                                    stage.root_prims = children;
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+
+                SPEC_TYPE_PRIM => {
+                    let mut prim = Prim::default();
+                    let mut is_defined = false;
+                    let mut is_active = true;
+                    for (name, rep) in fields {
+                        let value = match name {
+                            "specifier" | "typeName" | "primChildren" | "active" => {
+                                self.value(rep)?
+                            }
+                            _ => None,
+                        };
+                        match (name, value) {
+                            ("specifier", Some(Value::Number(specifier))) => {
+                                is_defined = specifier as u32 == SPECIFIER_DEF;
+                            }
+                            ("typeName", Some(Value::Text(type_name))) => {
+                                prim.type_name = type_name;
+                            }
+                            ("primChildren", Some(Value::Texts(children))) => {
+                                prim.children = children;
+                            }
+                            ("active", Some(Value::Bool(active))) => is_active = active,
+                            _ => {}
+                        }
+                    }
+                    if is_defined && is_active {
+                        stage.prims.insert(path.to_owned(), prim);
+                    }
+                }
+
+                SPEC_TYPE_ATTRIBUTE | SPEC_TYPE_RELATIONSHIP => {
+                    let Some((prim_path, name)) = path.rsplit_once('.') else {
+                        continue;
+                    };
+                    let prim = properties.entry(prim_path).or_default();
+                    let mut first_sample = None;
+                    for (field, rep) in fields {
+                        match field {
+                            "default" => {
+                                if let Some(value) = self.value(rep)? {
+                                    prim.attributes.insert(name.to_owned(), value);
+                                }
+                            }
+                            "timeSamples" if rep.value_type() == value_type::TIME_SAMPLES => {
+                                // Samples that can't be read are simply ignored.
+                                first_sample = self.first_time_sample(rep).ok().flatten();
+                            }
+                            "interpolation" => {
+                                if let Some(Value::Text(interpolation)) = self.value(rep)? {
+                                    prim.interpolations.insert(name.to_owned(), interpolation);
+                                }
+                            }
+                            "targetPaths" | "connectionPaths"
+                                if rep.value_type() == value_type::PATH_LIST_OP =>
+                            {
+                                prim.targets
+                                    .insert(name.to_owned(), self.path_list_op(rep)?);
+                            }
+                            _ => {}
+                        }
+                    }
+                    if let Some(sample) = first_sample {
+                        prim.attributes.entry(name.to_owned()).or_insert(sample);
+                    }
+                }
+
+                _ => {}
+            }
+        }
+
+        for (path, properties) in properties {
+            if let Some(prim) = stage.prims.get_mut(path) {
+                prim.attributes.extend(properties.attributes);
+                prim.interpolations.extend(properties.interpolations);
+                prim.targets.extend(properties.targets);
+            }
+        }
+
+        Ok(stage)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Compresses `data` as a single uncompressed LZ4 block, like `TfFastCompression` would.
+    fn compress(data: &[u8]) -> Vec<u8> {
+        let mut compressed = vec![0];
+        compressed.extend(lz4_flex::block::compress(data));
+        compressed
+    }
+
+    #[test]
+    fn integer_coding() {
+        // Values 5, 6, 7, 107, 106: the common delta is 1.
+        let mut encoded = 1i32.to_le_bytes().to_vec();
+        // Codes: large (5), common, common, small (100) | small (-1).
+        encoded.extend([0b01_00_00_11, 0b01]);
+        encoded.extend(5i32.to_le_bytes());
+        encoded.extend([100u8, (-1i8) as u8]);
+
+        assert_eq!(
+            decode_integers(&encoded, 5, false).unwrap(),
+            [5, 6, 7, 107, 106]
+        );
+
+        let compressed = compress(&encoded);
+        let mut file = (compressed.len() as u64).to_le_bytes().to_vec();
+        file.extend(compressed);
+        assert_eq!(
+            Reader::at(&file, 0).compressed_u32(5, "test").unwrap(),
+            [5, 6, 7, 107, 106]
+        );
+    }
+
+    #[test]
+    fn half_floats() {
+        assert_eq!(f16_to_f32(0x3c00), 1.0);
+        assert_eq!(f16_to_f32(0xc000), -2.0);
+        assert_eq!(f16_to_f32(0x3800), 0.5);
+    }
+
+    #[test]
+    fn tuple_layouts() {
+        assert_eq!(tuple_layout(24).map(|(s, n)| (s.size(), n)), Some((4, 3)));
+        assert_eq!(tuple_layout(27).map(|(s, n)| (s.size(), n)), Some((8, 4)));
+        assert_eq!(tuple_layout(21).map(|(s, n)| (s.size(), n)), Some((2, 2)));
+        assert_eq!(tuple_layout(30).map(|(s, n)| (s.size(), n)), Some((4, 4)));
+        assert_eq!(tuple_layout(15).map(|(s, n)| (s.size(), n)), Some((8, 16)));
+    }
+}
@@ -44,6 +44,12 @@ pub enum LogDataSource {
 
     /// A `rerun+http://` URI pointing to a proxy.
     RedapProxy(re_uri::ProxyUri),
+
+    /// A `ws://` or `wss://` URL pointing to a Foxglove WebSocket server, e.g. `foxglove_bridge`.
+    ///
+    /// Messages are converted on the fly, the same way as when loading an `.mcap` file.
+    #[cfg(not(target_arch = "wasm32"))]
+    FoxgloveWebSocket(url::Url),
 }
 
 impl LogDataSource {
@@ -106,6 +112,10 @@ impl LogDataSource {
                 return Some(Self::Stdin);
             }
 
+            if url.starts_with("ws://") || url.starts_with("wss://") {
+                return url::Url::parse(url).ok().map(Self::FoxgloveWebSocket);
+            }
+
             let path = std::path::Path::new(url).to_path_buf();
 
             if url.starts_with("file://") || path.exists() {
@@ -269,7 +279,24 @@ impl LogDataSource {
                 Ok(rx)
             }
 
-            Self::RedapProxy(uri) => Ok(re_grpc_client::stream(uri, on_msg)),
+            Self::RedapProxy(uri) => {
+                #[cfg(not(target_arch = "wasm32"))]
+                let mut options = re_grpc_client::read::Options::from_env();
+                #[cfg(target_arch = "wasm32")]
+                let mut options = re_grpc_client::read::Options::default();
+
+                if let Some(proxy_token) = connection_registry.proxy_token() {
+                    options.auth_token = Some(proxy_token);
+                }
+
+                Ok(re_grpc_client::stream(uri, options, on_msg))
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            Self::FoxgloveWebSocket(url) => {
+                crate::foxglove_websocket::stream(url.to_string(), on_msg)
+                    .with_context(|| url.to_string())
+            }
         }
     }
 }
@@ -332,6 +359,11 @@ mod tests {
             "rerun+https://127.0.0.1:9876/proxy",
             "rerun+http://example.com/proxy",
         ];
+        let websocket = [
+            "ws://localhost:8765",
+            "wss://example.com:8765/",
+            "ws://127.0.0.1:8765/foo.mcap",
+        ];
 
         let file_source = FileSource::DragAndDrop {
             recommended_store_id: None,
@@ -381,6 +413,16 @@ mod tests {
             }
         }
 
+        for uri in websocket {
+            let data_source = LogDataSource::from_uri(file_source.clone(), uri);
+            if !matches!(data_source, Some(LogDataSource::FoxgloveWebSocket { .. })) {
+                eprintln!(
+                    "Expected {uri:?} to be categorized as FoxgloveWebSocket. Instead it got parsed as {data_source:?}"
+                );
+                failed = true;
+            }
+        }
+
         assert!(!failed, "one or more test cases failed");
     }
 }
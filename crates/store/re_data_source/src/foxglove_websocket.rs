@@ -0,0 +1,384 @@
+//! Live MCAP-style data from a server speaking the [Foxglove WebSocket protocol],
+//! e.g. `foxglove_bridge` running next to a ROS 2 graph.
+//!
+//! Every advertised channel is subscribed to, and incoming messages are converted on the fly
+//! using the same layers that are used for loading `.mcap` files.
+//!
+//! [Foxglove WebSocket protocol]: https://github.com/foxglove/ws-protocol
+
+use std::{borrow::Cow, sync::Arc};
+
+use base64::Engine as _;
+use futures_util::{SinkExt as _, StreamExt as _};
+use re_log_types::{DataSourceMessage, LogMsg, RecordingId};
+use re_smart_channel::{Receiver, Sender, SmartChannelSource, SmartMessageSource};
+use tokio_tungstenite::tungstenite::{Message, client::IntoClientRequest as _, http::HeaderValue};
+
+/// The WebSocket subprotocol we need to negotiate with the server.
+const SUBPROTOCOL: &str = "foxglove.websocket.v1";
+
+/// Opcode of binary `Message Data` frames sent by the server.
+const OPCODE_MESSAGE_DATA: u8 = 0x01;
+
+/// JSON messages sent by the server.
+///
+/// We only care about a small subset of the protocol, everything else is ignored.
+#[derive(Debug, serde::Deserialize)]
+#[serde(tag = "op", rename_all = "camelCase")]
+enum ServerMessage {
+    ServerInfo {
+        name: String,
+    },
+
+    Status {
+        level: u8,
+        message: String,
+    },
+
+    Advertise {
+        channels: Vec<AdvertisedChannel>,
+    },
+
+    #[serde(rename_all = "camelCase")]
+    Unadvertise {
+        channel_ids: Vec<u32>,
+    },
+
+    #[serde(other)]
+    Unsupported,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AdvertisedChannel {
+    id: u32,
+    topic: String,
+    encoding: String,
+    schema_name: String,
+    schema: String,
+    schema_encoding: Option<String>,
+}
+
+impl AdvertisedChannel {
+    /// Converts the advertisement into the equivalent MCAP channel.
+    fn into_mcap_channel(self) -> anyhow::Result<Arc<mcap::Channel<'static>>> {
+        let Self {
+            id,
+            topic,
+            encoding,
+            schema_name,
+            schema,
+            schema_encoding,
+        } = self;
+
+        let id = u16::try_from(id)
+            .map_err(|_err| anyhow::anyhow!("channel id {id} of topic {topic:?} is too large"))?;
+
+        // Older servers don't send a schema encoding, in which case it follows from the message encoding.
+        let schema_encoding = schema_encoding.unwrap_or_else(|| {
+            match encoding.as_str() {
+                "cdr" => "ros2msg",
+                "ros1" => "ros1msg",
+                "json" => "jsonschema",
+                other => other,
+            }
+            .to_owned()
+        });
+
+        // Binary schemas are sent as base64, all others as plain text.
+        let schema_data = match schema_encoding.as_str() {
+            "protobuf" | "flatbuffer" => base64::engine::general_purpose::STANDARD
+                .decode(schema.as_bytes())
+                .map_err(|err| anyhow::anyhow!("invalid schema for topic {topic:?}: {err}"))?,
+            _ => schema.into_bytes(),
+        };
+
+        Ok(Arc::new(mcap::Channel {
+            id,
+            topic,
+            schema: Some(Arc::new(mcap::Schema {
+                id,
+                name: schema_name,
+                encoding: schema_encoding,
+                data: Cow::Owned(schema_data),
+            })),
+            message_encoding: encoding,
+            metadata: Default::default(),
+        }))
+    }
+}
+
+/// A binary `Message Data` frame.
+#[derive(Debug, PartialEq, Eq)]
+struct MessageData<'a> {
+    subscription_id: u32,
+    receive_time_nanos: u64,
+    payload: &'a [u8],
+}
+
+impl<'a> MessageData<'a> {
+    /// Parses a binary frame, returns `None` if it is not a `Message Data` frame.
+    fn parse(frame: &'a [u8]) -> Option<Self> {
+        let (&opcode, rest) = frame.split_first()?;
+        if opcode != OPCODE_MESSAGE_DATA || rest.len() < 12 {
+            return None;
+        }
+
+        let (subscription_id, rest) = rest.split_at(4);
+        let (receive_time_nanos, payload) = rest.split_at(8);
+
+        Some(Self {
+            subscription_id: u32::from_le_bytes(subscription_id.try_into().ok()?),
+            receive_time_nanos: u64::from_le_bytes(receive_time_nanos.try_into().ok()?),
+            payload,
+        })
+    }
+}
+
+/// Streams live data from a Foxglove WebSocket server at `url`.
+///
+/// The connection is handled on a background thread, errors are reported through the channel.
+pub fn stream(
+    url: String,
+    on_msg: Option<Box<dyn Fn() + Send + Sync>>,
+) -> anyhow::Result<Receiver<DataSourceMessage>> {
+    re_log::debug!("Connecting to {url} via WebSocket…");
+
+    let (tx, rx) = re_smart_channel::smart_channel(
+        SmartMessageSource::McapWebSocket { url: url.clone() },
+        SmartChannelSource::McapWebSocket { url: url.clone() },
+    );
+
+    // The message layers are not `Send`, so the decoding can't hop between worker threads.
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?;
+
+    std::thread::Builder::new()
+        .name(format!("foxglove_websocket({url})"))
+        .spawn(move || {
+            match runtime.block_on(stream_async(&url, &tx, on_msg)) {
+                Ok(()) => tx.quit(None),
+                Err(err) => tx.quit(Some(err.into())),
+            }
+            .ok(); // The other end has decided to hang up, not our problem.
+        })?;
+
+    Ok(rx)
+}
+
+/// Connects to `url` and forwards all advertised channels into `tx`, until either side hangs up.
+///
+/// We always use the channel id as subscription id, which keeps the bookkeeping trivial.
+async fn stream_async(
+    url: &str,
+    tx: &Sender<DataSourceMessage>,
+    on_msg: Option<Box<dyn Fn() + Send + Sync>>,
+) -> anyhow::Result<()> {
+    let mut request = url.into_client_request()?;
+    request.headers_mut().insert(
+        "Sec-WebSocket-Protocol",
+        HeaderValue::from_static(SUBPROTOCOL),
+    );
+
+    let (mut socket, _response) = tokio_tungstenite::connect_async(request).await?;
+    re_log::debug!("Connected to {url}");
+
+    let store_id = re_data_loader::DataLoaderSettings::recommended(RecordingId::random())
+        .recommended_store_id();
+    let send = |msg: LogMsg| {
+        let is_open = tx.send(msg.into()).is_ok();
+        if let Some(on_msg) = &on_msg {
+            on_msg();
+        }
+        is_open
+    };
+
+    if !send(LogMsg::SetStoreInfo(
+        re_data_loader::loader_mcap::store_info(store_id.clone()),
+    )) {
+        return Ok(()); // The other end has decided to hang up, not our problem.
+    }
+
+    let mut decoder = re_mcap::LiveDecoder::new(re_mcap::LayerRegistry::all_with_raw_fallback());
+
+    while let Some(frame) = socket.next().await {
+        match frame? {
+            Message::Text(text) => {
+                let msg = match serde_json::from_str::<ServerMessage>(text.as_str()) {
+                    Ok(msg) => msg,
+                    Err(err) => {
+                        re_log::warn_once!("Failed to parse message from {url}: {err}");
+                        continue;
+                    }
+                };
+
+                match msg {
+                    ServerMessage::ServerInfo { name } => {
+                        re_log::info!("Connected to server {name:?} at {url}");
+                    }
+
+                    ServerMessage::Status { level, message } => match level {
+                        0 => re_log::info!("{url}: {message}"),
+                        1 => re_log::warn!("{url}: {message}"),
+                        _ => re_log::error!("{url}: {message}"),
+                    },
+
+                    ServerMessage::Advertise { channels } => {
+                        let mut subscriptions = Vec::with_capacity(channels.len());
+
+                        for channel in channels {
+                            let topic = channel.topic.clone();
+                            let result = channel.into_mcap_channel().and_then(|channel| {
+                                let id = channel.id;
+                                decoder.add_channel(channel)?;
+                                Ok(id)
+                            });
+
+                            match result {
+                                Ok(id) => subscriptions.push(serde_json::json!({
+                                    "id": id,
+                                    "channelId": id,
+                                })),
+                                Err(err) => {
+                                    re_log::warn!("Skipping topic {topic:?} from {url}: {err}");
+                                }
+                            }
+                        }
+
+                        if !subscriptions.is_empty() {
+                            let subscribe = serde_json::json!({
+                                "op": "subscribe",
+                                "subscriptions": subscriptions,
+                            });
+                            socket.send(Message::text(subscribe.to_string())).await?;
+                        }
+                    }
+
+                    ServerMessage::Unadvertise { channel_ids } => {
+                        for id in channel_ids {
+                            if let Ok(id) = u16::try_from(id)
+                                && let Err(err) = decoder.remove_channel(id)
+                            {
+                                re_log::warn!("Failed to remove channel {id} from {url}: {err}");
+                            }
+                        }
+                    }
+
+                    ServerMessage::Unsupported => {}
+                }
+            }
+
+            Message::Binary(frame) => {
+                let Some(data) = MessageData::parse(&frame) else {
+                    continue;
+                };
+
+                let Some(channel) = u16::try_from(data.subscription_id)
+                    .ok()
+                    .and_then(|id| decoder.channel(id))
+                else {
+                    continue;
+                };
+
+                let msg = mcap::Message {
+                    channel: channel.clone(),
+                    sequence: 0,
+                    log_time: data.receive_time_nanos,
+                    publish_time: data.receive_time_nanos,
+                    data: Cow::Borrowed(data.payload),
+                };
+
+                let chunks = match decoder.decode(&msg) {
+                    Ok(chunks) => chunks,
+                    Err(err) => {
+                        re_log::warn_once!(
+                            "Failed to decode message on topic {:?}: {err}",
+                            channel.topic
+                        );
+                        continue;
+                    }
+                };
+
+                for chunk in chunks {
+                    let arrow_msg = match chunk.to_arrow_msg() {
+                        Ok(arrow_msg) => arrow_msg,
+                        Err(err) => {
+                            re_log::warn_once!("Failed to encode chunk: {err}");
+                            continue;
+                        }
+                    };
+
+                    if !send(LogMsg::ArrowMsg(store_id.clone(), arrow_msg)) {
+                        return Ok(()); // The other end has decided to hang up, not our problem.
+                    }
+                }
+            }
+
+            Message::Close(_) => break,
+
+            Message::Ping(_) | Message::Pong(_) | Message::Frame(_) => {}
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_message_data() {
+        let mut frame = vec![OPCODE_MESSAGE_DATA];
+        frame.extend_from_slice(&7u32.to_le_bytes());
+        frame.extend_from_slice(&42u64.to_le_bytes());
+        frame.extend_from_slice(b"payload");
+
+        assert_eq!(
+            MessageData::parse(&frame),
+            Some(MessageData {
+                subscription_id: 7,
+                receive_time_nanos: 42,
+                payload: b"payload",
+            })
+        );
+
+        // Time frames, and truncated frames are not message data.
+        assert_eq!(MessageData::parse(&[0x02, 0, 0, 0, 0, 0, 0, 0, 0]), None);
+        assert_eq!(MessageData::parse(&frame[..8]), None);
+    }
+
+    #[test]
+    fn test_parse_advertise() {
+        let text = r#"{
+            "op": "advertise",
+            "channels": [{
+                "id": 3,
+                "topic": "/fix",
+                "encoding": "cdr",
+                "schemaName": "sensor_msgs/msg/NavSatFix",
+                "schema": "float64 latitude"
+            }]
+        }"#;
+
+        let ServerMessage::Advertise { mut channels } = serde_json::from_str(text).unwrap() else {
+            panic!("expected an advertise message");
+        };
+
+        let channel = channels.remove(0).into_mcap_channel().unwrap();
+        assert_eq!(channel.id, 3);
+        assert_eq!(channel.topic, "/fix");
+        assert_eq!(channel.message_encoding, "cdr");
+
+        let schema = channel.schema.as_ref().unwrap();
+        assert_eq!(schema.name, "sensor_msgs/msg/NavSatFix");
+        assert_eq!(schema.encoding, "ros2msg");
+        assert_eq!(schema.data.as_ref(), b"float64 latitude");
+
+        assert!(matches!(
+            serde_json::from_str(r#"{"op": "parameterValues", "parameters": []}"#).unwrap(),
+            ServerMessage::Unsupported
+        ));
+    }
+}
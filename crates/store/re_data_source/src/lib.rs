@@ -2,17 +2,27 @@
 //!
 //! - Over HTTPS
 //! - Over gRPC
+//! - Over WebSocket, from a Foxglove WebSocket server (e.g. `foxglove_bridge`)
 //! - From disk
 //!
 //! Also handles different file types: rrd, images, text files, 3D models, point clouds…
 
 mod data_source;
 
+#[cfg(not(target_arch = "wasm32"))]
+mod foxglove_websocket;
+
 #[cfg(not(target_arch = "wasm32"))]
 mod load_stdin;
 
+#[cfg(not(target_arch = "wasm32"))]
+mod replay;
+
 pub use self::data_source::LogDataSource;
 
+#[cfg(not(target_arch = "wasm32"))]
+pub use self::replay::replay_at_recorded_speed;
+
 // ----------------------------------------------------------------------------
 
 /// The contents of a file.
@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use anyhow::Context as _;
+
+use re_log_types::{ArrowMsg, DataSourceMessage, LogMsg, StoreId, TimelineName};
+use re_smart_channel::{Receiver, SmartMessagePayload};
+
+/// Forwards the messages of `rx` at the wall-clock speed they were recorded at, times `speed`.
+///
+/// Useful to rehearse how a live recording behaves, e.g. in a dashboard, from a file.
+///
+/// Each recording is paced on its own, starting when its first chunk comes in.
+/// The time a chunk was recorded at is the start of its `log_time`, or else the time the chunk
+/// was created at, which the SDKs do shortly after logging.
+/// Blueprints and everything that isn't a chunk are forwarded right away.
+pub fn replay_at_recorded_speed(
+    rx: Receiver<DataSourceMessage>,
+    speed: f64,
+) -> anyhow::Result<Receiver<DataSourceMessage>> {
+    anyhow::ensure!(
+        speed.is_finite() && 0.0 < speed,
+        "The replay speed must be a positive number, got {speed}"
+    );
+
+    let (tx, new_rx) = rx.chained_channel();
+    std::thread::Builder::new()
+        .name(format!("replay({})", rx.source()))
+        .spawn(move || {
+            let mut clocks: HashMap<StoreId, RecordingClock> = HashMap::default();
+
+            while let Ok(msg) = rx.recv_with_send_time() {
+                let mut time = msg.time;
+
+                if let SmartMessagePayload::Msg(DataSourceMessage::LogMsg(LogMsg::ArrowMsg(
+                    store_id,
+                    arrow_msg,
+                ))) = &msg.payload
+                    && store_id.is_recording()
+                {
+                    let recorded_nanos = recorded_time_nanos(arrow_msg);
+                    let clock = clocks
+                        .entry(store_id.clone())
+                        .or_insert_with(|| RecordingClock::new(recorded_nanos));
+                    let delay = clock.delay(recorded_nanos, speed, Instant::now());
+                    if !delay.is_zero() {
+                        std::thread::sleep(delay);
+                    }
+
+                    // Time spent waiting on purpose isn't latency.
+                    time = Instant::now();
+                }
+
+                if tx.send_at(time, msg.source, msg.payload).is_err() {
+                    break;
+                }
+            }
+
+            re_log::trace!("Shutting down replay thread");
+        })
+        .context("Failed to spawn replay thread")?;
+
+    Ok(new_rx)
+}
+
+/// Maps the recorded times of a recording to the times they are replayed at.
+struct RecordingClock {
+    recorded_start_nanos: i64,
+    replay_start: Instant,
+}
+
+impl RecordingClock {
+    fn new(recorded_start_nanos: i64) -> Self {
+        Self {
+            recorded_start_nanos,
+            replay_start: Instant::now(),
+        }
+    }
+
+    /// How long to wait from `now` on until the data recorded at `recorded_nanos` is due.
+    fn delay(&self, recorded_nanos: i64, speed: f64, now: Instant) -> Duration {
+        let elapsed_nanos = recorded_nanos
+            .saturating_sub(self.recorded_start_nanos)
+            .max(0);
+        let due = self.replay_start + Duration::from_secs_f64(elapsed_nanos as f64 * 1e-9 / speed);
+        due.saturating_duration_since(now)
+    }
+}
+
+fn recorded_time_nanos(msg: &ArrowMsg) -> i64 {
+    use re_log_types::external::arrow::array::{Array as _, TimestampNanosecondArray};
+
+    msg.batch
+        .column_by_name(TimelineName::log_time().as_str())
+        .and_then(|column| column.as_any().downcast_ref::<TimestampNanosecondArray>())
+        .and_then(|times| times.iter().flatten().min())
+        .unwrap_or_else(|| msg.chunk_id.nanos_since_epoch() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recording_clock_delay() {
+        let clock = RecordingClock::new(1_000_000_000);
+        let start = clock.replay_start;
+
+        // The first chunk is due right away.
+        assert_eq!(clock.delay(1_000_000_000, 1.0, start), Duration::ZERO);
+
+        // A chunk recorded a second later is due a second later, or half a second at double speed.
+        assert_eq!(
+            clock.delay(2_000_000_000, 1.0, start),
+            Duration::from_secs(1)
+        );
+        assert_eq!(
+            clock.delay(2_000_000_000, 2.0, start),
+            Duration::from_millis(500)
+        );
+
+        // Chunks recorded before the first one, or that are overdue, aren't waited for.
+        assert_eq!(clock.delay(0, 1.0, start), Duration::ZERO);
+        assert_eq!(
+            clock.delay(2_000_000_000, 1.0, start + Duration::from_secs(3)),
+            Duration::ZERO
+        );
+    }
+}
@@ -1,6 +1,8 @@
-use re_chunk::EntityPath;
+use std::collections::BTreeSet;
+
+use re_chunk::{EntityPath, LatestAtQuery};
 use re_chunk_store::{ChunkStoreHandle, QueryExpression};
-use re_log_types::EntityPathFilter;
+use re_log_types::{EntityPathFilter, EntityPathQuery};
 use re_query::{QueryCache, QueryCacheHandle, StorageEngine, StorageEngineLike};
 use re_sorbet::ChunkColumnDescriptors;
 
@@ -103,4 +105,17 @@ impl<E: StorageEngineLike + Clone> QueryEngine<E> {
                 .filter(move |entity_path| filter.matches(entity_path))
         })
     }
+
+    /// Returns all the [`EntityPath`]s matching a query such as `/world/**[has:Points3D]`.
+    ///
+    /// Value predicates are evaluated as of `latest_at`.
+    #[inline]
+    pub fn entity_paths_matching(
+        &self,
+        query: &EntityPathQuery,
+        latest_at: &LatestAtQuery,
+    ) -> BTreeSet<EntityPath> {
+        self.engine
+            .with(|store, _cache| store.entities_matching(query, latest_at))
+    }
 }
@@ -1029,47 +1029,62 @@ impl<E: StorageEngineLike> QueryHandle<E> {
             }
         }
 
-        match self.query.sparse_fill_strategy {
-            SparseFillStrategy::None => {}
-
-            SparseFillStrategy::LatestAtGlobal => {
-                // Everything that yielded `null` for the current iteration.
-                let null_streaming_states = view_streaming_state
-                    .iter_mut()
-                    .enumerate()
-                    .filter(|(_view_idx, streaming_state)| streaming_state.is_none());
-
-                for (view_idx, streaming_state) in null_streaming_states {
-                    let Some(ColumnDescriptor::Component(descr)) =
-                        state.view_contents.get_index_or_component(view_idx)
-                    else {
-                        continue;
-                    };
+        // `None` means no filling at all, `Some(None)` means filling regardless of how old the data is.
+        let fill_tolerance = match self.query.sparse_fill_strategy {
+            SparseFillStrategy::None => None,
+            SparseFillStrategy::LatestAtGlobal => Some(None),
+            SparseFillStrategy::LatestAtGlobalWithin { tolerance } => Some(Some(tolerance)),
+        };
 
-                    // NOTE: While it would be very tempting to resolve the latest-at state
-                    // of the entire view contents at `filtered_index_range.start - 1` once
-                    // during `QueryHandle` initialization, and then bootstrap off of that, that
-                    // would effectively close the door to efficient pagination forever, since
-                    // we'd have to iterate over all the pages to compute the right latest-at
-                    // value at t+n (i.e. no more random access).
-                    // Therefore, it is better to simply do this the "dumb" way.
-                    //
-                    // TODO(cmc): Still, as always, this can be made faster and smarter at
-                    // the cost of some extra complexity (e.g. caching the result across
-                    // consecutive nulls etc). Later.
+        if let Some(tolerance) = fill_tolerance {
+            // Everything that yielded `null` for the current iteration.
+            let null_streaming_states = view_streaming_state
+                .iter_mut()
+                .enumerate()
+                .filter(|(_view_idx, streaming_state)| streaming_state.is_none());
 
-                    let query =
-                        re_chunk::LatestAtQuery::new(state.filtered_index, *cur_index_value);
+            for (view_idx, streaming_state) in null_streaming_states {
+                let Some(ColumnDescriptor::Component(descr)) =
+                    state.view_contents.get_index_or_component(view_idx)
+                else {
+                    continue;
+                };
 
-                    let results =
-                        cache.latest_at(&query, &descr.entity_path.clone(), [descr.component]);
+                // NOTE: While it would be very tempting to resolve the latest-at state
+                // of the entire view contents at `filtered_index_range.start - 1` once
+                // during `QueryHandle` initialization, and then bootstrap off of that, that
+                // would effectively close the door to efficient pagination forever, since
+                // we'd have to iterate over all the pages to compute the right latest-at
+                // value at t+n (i.e. no more random access).
+                // Therefore, it is better to simply do this the "dumb" way.
+                //
+                // TODO(cmc): Still, as always, this can be made faster and smarter at
+                // the cost of some extra complexity (e.g. caching the result across
+                // consecutive nulls etc). Later.
+
+                let query = re_chunk::LatestAtQuery::new(state.filtered_index, *cur_index_value);
+
+                let results =
+                    cache.latest_at(&query, &descr.entity_path.clone(), [descr.component]);
+
+                *streaming_state = results
+                    .components
+                    .into_values()
+                    .next()
+                    .filter(|unit| {
+                        let Some(tolerance) = tolerance else {
+                            return true;
+                        };
 
-                    *streaming_state = results
-                        .components
-                        .into_values()
-                        .next()
-                        .map(|unit| StreamingJoinState::Retrofilled(unit.clone()));
-                }
+                        // Static data is timeless: it is never too old.
+                        unit.index(&state.filtered_index)
+                            .is_none_or(|(data_time, _row_id)| {
+                                data_time.is_static()
+                                    || cur_index_value.as_i64().abs_diff(data_time.as_i64())
+                                        <= tolerance
+                            })
+                    })
+                    .map(|unit| StreamingJoinState::Retrofilled(unit.clone()));
             }
         }
 
@@ -1469,6 +1484,82 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn as_of_join() -> anyhow::Result<()> {
+        use arrow::array::{Array as _, Int64Array};
+
+        re_log::setup_logging();
+
+        let store = ChunkStoreHandle::new(create_nasty_store()?);
+        eprintln!("{store}");
+        let query_cache = QueryCache::new_handle(store.clone());
+        let query_engine = QueryEngine::new(store.clone(), query_cache.clone());
+
+        let filtered_index = TimelineName::new("frame_nr");
+        let entity_path: EntityPath = "this/that".into();
+        let points = ComponentColumnSelector {
+            entity_path: entity_path.clone(),
+            component: MyPoints::descriptor_points().component.to_string(),
+        };
+        let colors = ComponentColumnSelector {
+            entity_path: entity_path.clone(),
+            component: MyPoints::descriptor_colors().component.to_string(),
+        };
+
+        // Points are logged on every frame, colors only on frames 30, 40, 50 and 70.
+        let frames_without_colors = |tolerance: Option<u64>| -> anyhow::Result<Vec<i64>> {
+            let mut query = QueryExpression::as_of_join(
+                filtered_index,
+                points.clone(),
+                Some(
+                    std::iter::once((
+                        entity_path.clone(),
+                        Some(
+                            std::iter::once(ComponentIdentifier::from(colors.component.as_str()))
+                                .collect(),
+                        ),
+                    ))
+                    .collect(),
+                ),
+                tolerance,
+            );
+            query.selection = Some(vec![
+                ColumnSelector::Time(TimeColumnSelector::from(filtered_index)),
+                ColumnSelector::Component(colors.clone()),
+                ColumnSelector::Component(points.clone()),
+            ]);
+            eprintln!("{query:#?}:");
+
+            let query_handle = query_engine.query(query.clone());
+            let dataframe = concat_batches(
+                query_handle.schema(),
+                &query_handle.batch_iter().collect_vec(),
+            )?;
+            eprintln!("{}", format_record_batch(&dataframe.clone()));
+
+            // The joined-on component must have been added to the view contents.
+            assert_eq!(0, dataframe.column(2).null_count());
+
+            let frames = dataframe
+                .column(0)
+                .as_any()
+                .downcast_ref::<Int64Array>()
+                .ok_or_else(|| anyhow::anyhow!("frame_nr should be an i64 column"))?;
+
+            Ok((0..dataframe.num_rows())
+                .filter(|&row| dataframe.column(1).is_null(row))
+                .map(|row| frames.value(row))
+                .collect())
+        };
+
+        assert_eq!(vec![10, 20], frames_without_colors(None)?);
+        assert_eq!(vec![10, 20], frames_without_colors(Some(10))?);
+        assert_eq!(vec![10, 20, 60], frames_without_colors(Some(5))?);
+        assert_eq!(vec![10, 20, 60], frames_without_colors(Some(0))?);
+
+        Ok(())
+    }
+
     #[test]
     fn filtered_index_range() -> anyhow::Result<()> {
         re_log::setup_logging();
@@ -8,6 +8,7 @@ mod dataframe_query_provider;
 mod dataframe_query_provider_wasm;
 mod dataset_manifest;
 mod grpc_streaming_provider;
+mod local_query_table;
 mod partition_table;
 mod search_provider;
 mod table_entry_provider;
@@ -20,6 +21,7 @@ pub(crate) use dataframe_query_provider::PartitionStreamExec;
 #[cfg(target_arch = "wasm32")]
 pub(crate) use dataframe_query_provider_wasm::PartitionStreamExec;
 pub use dataset_manifest::DatasetManifestProvider;
+pub use local_query_table::local_query_table;
 pub use partition_table::PartitionTableProvider;
 pub use search_provider::SearchResultsTableProvider;
 pub use table_entry_provider::TableEntryTableProvider;
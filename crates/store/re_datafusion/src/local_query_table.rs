@@ -0,0 +1,29 @@
+use arrow::compute::concat_batches;
+use datafusion::{datasource::MemTable, error::Result as DataFusionResult};
+use tracing::instrument;
+
+use re_dataframe::QueryHandle;
+use re_dataframe::external::re_query::StorageEngineLike;
+
+/// Number of query rows merged into each record batch of the table.
+const BATCH_SIZE: usize = 4096;
+
+/// Snapshots the entire result of a local dataframe query into an in-memory table.
+///
+/// The table has the schema of the query, and does not reflect later changes to the store.
+#[instrument(skip_all, err)]
+pub fn local_query_table<E: StorageEngineLike>(
+    query_handle: &QueryHandle<E>,
+) -> DataFusionResult<MemTable> {
+    let schema = query_handle.schema().clone();
+
+    query_handle.seek_to_row(0);
+    let mut rows = query_handle.batch_iter().peekable();
+    let mut batches = Vec::new();
+    while rows.peek().is_some() {
+        let chunk = rows.by_ref().take(BATCH_SIZE).collect::<Vec<_>>();
+        batches.push(concat_batches(&schema, &chunk)?);
+    }
+
+    MemTable::try_new(schema, vec![batches])
+}
@@ -13,7 +13,7 @@ use re_chunk_store::{
 };
 use re_log_types::{
     AbsoluteTimeRange, AbsoluteTimeRangeF, ApplicationId, EntityPath, EntityPathHash, LogMsg,
-    RecordingId, SetStoreInfo, StoreId, StoreInfo, StoreKind, TimeType,
+    RecordingId, SetStoreInfo, StoreId, StoreInfo, StoreKind, TimeType, TimelineCategories,
 };
 use re_query::{
     QueryCache, QueryCacheHandle, StorageEngine, StorageEngineArcReadGuard, StorageEngineReadGuard,
@@ -310,6 +310,27 @@ impl EntityDb {
         .map(|(_, value)| value)
     }
 
+    /// The categories of the given timeline, if it is a categorical timeline.
+    ///
+    /// See [`TimelineCategories`].
+    pub fn timeline_categories(&self, timeline: &TimelineName) -> Option<TimelineCategories> {
+        use re_arrow_util::ArrowArrayDowncastRef as _;
+        use re_chunk::external::arrow::array::StringArray;
+
+        let component = TimelineCategories::CATEGORIES.into();
+        let array = self
+            .latest_at(
+                &LatestAtQuery::latest(TimelineName::log_tick()),
+                &TimelineCategories::property_path(timeline),
+                [component],
+            )
+            .component_batch_raw(component)?;
+        let array = array.as_ref().downcast_array_ref::<StringArray>()?;
+
+        let categories = TimelineCategories::new(array.iter().map(Option::unwrap_or_default));
+        (!categories.is_empty()).then_some(categories)
+    }
+
     /// Use can use this both for setting the built-in `RecordingInfo` components,
     /// and for setting custom properties on the recording.
     pub fn set_recording_property<Component: re_types_core::Component>(
@@ -519,6 +540,71 @@ impl EntityDb {
         self.entity_path_from_hash.values().sorted().collect()
     }
 
+    /// All entities matching a query such as `/world/**[has:Points3D]`, sorted.
+    ///
+    /// See [`re_log_types::EntityPathQuery`] for the syntax.
+    pub fn entities_matching(
+        &self,
+        query: &re_log_types::EntityPathQuery,
+        latest_at: &LatestAtQuery,
+    ) -> std::collections::BTreeSet<EntityPath> {
+        self.storage_engine
+            .read()
+            .store()
+            .entities_matching(query, latest_at)
+    }
+
+    /// All known versions of the static data for the given component, oldest first.
+    ///
+    /// See [`re_chunk_store::ChunkStore::static_versions`].
+    pub fn static_versions(
+        &self,
+        entity_path: &EntityPath,
+        component: ComponentIdentifier,
+    ) -> Vec<RowId> {
+        self.storage_engine
+            .read()
+            .store()
+            .static_versions(entity_path, component)
+    }
+
+    /// The version of the static data that was pinned for the given component, if any.
+    pub fn pinned_static_version(
+        &self,
+        entity_path: &EntityPath,
+        component: ComponentIdentifier,
+    ) -> Option<RowId> {
+        self.storage_engine
+            .read()
+            .store()
+            .pinned_static_version(entity_path, component)
+    }
+
+    /// Pins a specific version of the static data for the given component, or unpins it if
+    /// `version` is `None`.
+    ///
+    /// Returns `None` if the version is unknown, the resulting store events otherwise.
+    ///
+    /// See [`re_chunk_store::ChunkStore::pin_static_version`].
+    pub fn pin_static_version(
+        &mut self,
+        entity_path: &EntityPath,
+        component: ComponentIdentifier,
+        version: Option<RowId>,
+    ) -> Option<Vec<ChunkStoreEvent>> {
+        re_tracing::profile_function!();
+
+        let mut engine = self.storage_engine.write();
+        let store_events = engine
+            .store()
+            .pin_static_version(entity_path, component, version)?;
+        engine.cache().on_events(&store_events);
+
+        // The entity tree, time histograms & co are left alone: they track what is in the store
+        // proper, which pinning doesn't change.
+        Some(store_events)
+    }
+
     #[inline]
     pub fn ingestion_stats(&self) -> &IngestionStatistics {
         &self.stats
@@ -543,6 +629,17 @@ impl EntityDb {
     }
 
     pub fn add(&mut self, msg: &LogMsg) -> Result<Vec<ChunkStoreEvent>, Error> {
+        self.add_and_migrate(msg, |_chunk| {})
+    }
+
+    /// Like [`Self::add`], but lets `migrate` upgrade incoming chunks before they are inserted.
+    ///
+    /// Used by the viewer to bring data logged by other versions of Rerun up to date.
+    pub fn add_and_migrate(
+        &mut self,
+        msg: &LogMsg,
+        migrate: impl FnOnce(&mut Chunk),
+    ) -> Result<Vec<ChunkStoreEvent>, Error> {
         re_tracing::profile_function!();
 
         debug_assert_eq!(msg.store_id(), self.store_id());
@@ -559,6 +656,7 @@ impl EntityDb {
                 let chunk_batch = re_sorbet::ChunkBatch::try_from(&arrow_msg.batch)
                     .map_err(re_chunk::ChunkError::from)?;
                 let mut chunk = re_chunk::Chunk::from_chunk_batch(&chunk_batch)?;
+                migrate(&mut chunk);
                 chunk.sort_if_unsorted();
                 self.add_chunk_with_timestamp_metadata(
                     &Arc::new(chunk),
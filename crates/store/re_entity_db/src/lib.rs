@@ -8,6 +8,7 @@ pub mod entity_db;
 pub mod entity_tree;
 mod ingestion_statistics;
 mod instance_path;
+mod rebase;
 mod store_bundle;
 mod time_histogram_per_timeline;
 mod times_per_timeline;
@@ -18,6 +19,7 @@ pub use self::{
     entity_tree::EntityTree,
     ingestion_statistics::{IngestionStatistics, LatencySnapshot, LatencyStats},
     instance_path::{InstancePath, InstancePathHash},
+    rebase::TimelineRebase,
     store_bundle::{StoreBundle, StoreLoadError},
     time_histogram_per_timeline::{TimeHistogram, TimeHistogramPerTimeline},
     times_per_timeline::{TimeCounts, TimelineStats, TimesPerTimeline},
@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use re_chunk::{RowId, TimelineName};
+use re_log_types::{EntityPath, SetStoreInfo, StoreId};
+
+use crate::{EntityDb, Error};
+
+/// How to shift the times of a timeline, e.g. to correct for drifting clocks.
+///
+/// See [`EntityDb::rebased`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TimelineRebase {
+    /// Added to all times, in the native unit of the timeline.
+    pub offset: i64,
+
+    /// Added to the times of each source on top of [`Self::offset`].
+    ///
+    /// A source is an entity subtree. If sources are nested, the innermost one wins.
+    pub per_source: Vec<(EntityPath, i64)>,
+}
+
+impl TimelineRebase {
+    /// The offset to apply to the data of the given entity.
+    pub fn offset_for(&self, entity_path: &EntityPath) -> i64 {
+        let source_offset = self
+            .per_source
+            .iter()
+            .filter(|(source, _)| entity_path.starts_with(source))
+            .max_by_key(|(source, _)| source.len())
+            .map_or(0, |(_, offset)| *offset);
+
+        self.offset.saturating_add(source_offset)
+    }
+
+    pub fn is_noop(&self) -> bool {
+        self.offset == 0 && self.per_source.iter().all(|(_, offset)| *offset == 0)
+    }
+}
+
+impl EntityDb {
+    /// Make a copy of this [`EntityDb`] with a new [`StoreId`], where all times of `timeline`
+    /// have been shifted according to `rebase`.
+    ///
+    /// Recordings are immutable, so this is how we correct them.
+    pub fn rebased(
+        &self,
+        new_id: StoreId,
+        timeline: &TimelineName,
+        rebase: &TimelineRebase,
+    ) -> Result<Self, Error> {
+        re_tracing::profile_function!();
+
+        let mut new_db = Self::new(new_id.clone());
+
+        // Like for clones, this is NOT the same data as what came from the original data source.
+        if let Some(store_info) = self.store_info() {
+            let mut new_info = store_info.clone();
+            new_info.store_id = new_id;
+            new_info.cloned_from = Some(self.store_id().clone());
+
+            new_db.set_store_info(SetStoreInfo {
+                row_id: *RowId::new(),
+                info: new_info,
+            });
+        }
+
+        let engine = self.storage_engine();
+        for chunk in engine.store().iter_chunks() {
+            let offset = rebase.offset_for(chunk.entity_path());
+            let chunk = if offset != 0 && chunk.timelines().contains_key(timeline) {
+                Arc::new((**chunk).clone().time_shifted(timeline, offset))
+            } else {
+                Arc::clone(chunk)
+            };
+            new_db.add_chunk(&chunk)?;
+        }
+
+        Ok(new_db)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offset_for_innermost_source() {
+        let rebase = TimelineRebase {
+            offset: 100,
+            per_source: vec![
+                (EntityPath::from("/robot"), 10),
+                (EntityPath::from("/robot/arm"), 20),
+            ],
+        };
+
+        assert_eq!(rebase.offset_for(&EntityPath::from("/world")), 100);
+        assert_eq!(rebase.offset_for(&EntityPath::from("/robot")), 110);
+        assert_eq!(rebase.offset_for(&EntityPath::from("/robot/base")), 110);
+        assert_eq!(
+            rebase.offset_for(&EntityPath::from("/robot/arm/joint")),
+            120
+        );
+        assert_eq!(rebase.offset_for(&EntityPath::from("/robotic")), 100);
+    }
+}
@@ -80,6 +80,14 @@ pub enum StreamError {
     #[error("connection failed: {0}")]
     Transport(#[from] tonic::transport::Error),
 
+    /// Native TLS setup error, e.g. an unreadable root certificate.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[error("invalid TLS configuration: {0}")]
+    InvalidTlsConfig(Box<dyn std::error::Error + Send + Sync>),
+
+    #[error("invalid auth token: {0}")]
+    InvalidAuthToken(#[from] tonic::metadata::errors::InvalidMetadataValue),
+
     #[error(transparent)]
     TonicStatus(#[from] TonicStatusError),
 
@@ -98,6 +106,21 @@ impl From<tonic::Status> for StreamError {
     }
 }
 
+/// Enables TLS on `endpoint`, trusting `ca_cert` on top of the system's root certificates.
+#[cfg(not(target_arch = "wasm32"))]
+fn with_tls(
+    endpoint: tonic::transport::Endpoint,
+    ca_cert: Option<&std::path::Path>,
+) -> Result<tonic::transport::Endpoint, Box<dyn std::error::Error + Send + Sync>> {
+    use tonic::transport::{Certificate, ClientTlsConfig};
+
+    let mut tls_config = ClientTlsConfig::new().with_enabled_roots();
+    if let Some(path) = ca_cert {
+        tls_config = tls_config.ca_certificate(Certificate::from_pem(std::fs::read(path)?));
+    }
+    Ok(endpoint.tls_config(tls_config)?)
+}
+
 // TODO(ab, andreas): This should be replaced by the use of `AsyncRuntimeHandle`. However, this
 // requires:
 // - `AsyncRuntimeHandle` to be moved lower in the crate hierarchy to be available here (unsure
@@ -1,4 +1,5 @@
 use tokio_stream::StreamExt as _;
+use tonic::metadata::{Ascii, MetadataValue};
 
 use re_log_encoding::ToApplication as _;
 use re_log_types::DataSourceMessage;
@@ -10,11 +11,43 @@ use crate::MAX_DECODING_MESSAGE_SIZE;
 use crate::StreamError;
 use crate::TonicStatusError;
 
+/// Options for reading from a proxy server.
+#[derive(Clone, Default)]
+pub struct Options {
+    /// Token to authenticate with, if the server requires one.
+    pub auth_token: Option<String>,
+
+    /// An additional PEM-encoded root certificate to trust for `rerun+https://` connections.
+    ///
+    /// Not available on the web, where the browser decides which certificates to trust.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub tls_ca_cert: Option<std::path::PathBuf>,
+}
+
+impl Options {
+    /// Creates new `Options` from the same environment variables as the write side, see
+    /// [`crate::write::Options::apply_env`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_env() -> Self {
+        let crate::write::Options {
+            auth_token,
+            tls_ca_cert,
+            ..
+        } = crate::write::Options::from_env();
+
+        Self {
+            auth_token,
+            tls_ca_cert,
+        }
+    }
+}
+
 /// Read log messages from a proxy server.
 ///
 /// This is used by the viewer to _receive_ log messages.
 pub fn stream(
     uri: re_uri::ProxyUri,
+    options: Options,
     on_msg: Option<Box<dyn Fn() + Send + Sync>>,
 ) -> re_smart_channel::Receiver<DataSourceMessage> {
     re_log::debug!("Loading {uri} via gRPC…");
@@ -25,7 +58,7 @@ pub fn stream(
     );
 
     crate::spawn_future(async move {
-        if let Err(err) = stream_async(uri, &tx, on_msg).await {
+        if let Err(err) = stream_async(uri, options, &tx, on_msg).await {
             tx.quit(Some(Box::new(err))).ok();
         }
     });
@@ -35,9 +68,15 @@ pub fn stream(
 
 async fn stream_async(
     uri: re_uri::ProxyUri,
+    options: Options,
     tx: &re_smart_channel::Sender<DataSourceMessage>,
     on_msg: Option<Box<dyn Fn() + Send + Sync>>,
 ) -> Result<(), StreamError> {
+    let authorization = options
+        .auth_token
+        .map(|token| format!("Bearer {}", token.trim()).parse::<MetadataValue<Ascii>>())
+        .transpose()?;
+
     let mut client = {
         let url = uri.origin.as_url();
 
@@ -50,19 +89,60 @@ async fn stream_async(
         };
 
         #[cfg(not(target_arch = "wasm32"))]
-        let tonic_client = { tonic::transport::Endpoint::new(url)?.connect().await? };
+        let tonic_client = {
+            let mut endpoint = tonic::transport::Endpoint::new(url)?;
+            if uri.origin.scheme != re_uri::Scheme::RerunHttp {
+                endpoint = crate::with_tls(endpoint, options.tls_ca_cert.as_deref())
+                    .map_err(StreamError::InvalidTlsConfig)?;
+            } else if options.tls_ca_cert.is_some() {
+                re_log::warn_once!(
+                    "Ignoring the TLS root certificate for non-TLS connection to {uri}"
+                );
+            }
+            endpoint.connect().await?
+        };
 
-        MessageProxyServiceClient::new(tonic_client)
-            .max_decoding_message_size(MAX_DECODING_MESSAGE_SIZE)
+        let client = MessageProxyServiceClient::with_interceptor(
+            tonic_client,
+            move |mut req: tonic::Request<()>| -> Result<_, tonic::Status> {
+                if let Some(authorization) = &authorization {
+                    req.metadata_mut()
+                        .insert("authorization", authorization.clone());
+                }
+                Ok(req)
+            },
+        )
+        .max_decoding_message_size(MAX_DECODING_MESSAGE_SIZE);
+
+        // The server only compresses what it sends us if it supports it too.
+        #[cfg(not(target_arch = "wasm32"))]
+        let client = client.accept_compressed(tonic::codec::CompressionEncoding::Zstd);
+
+        client
     };
 
     re_log::debug!("Streaming messages from gRPC endpoint {uri}");
 
-    let mut stream = client
+    let response = client
         .read_messages(ReadMessagesRequest {})
         .await
-        .map_err(TonicStatusError::from)?
-        .into_inner();
+        .map_err(|status| {
+            if status.code() == tonic::Code::Unauthenticated {
+                re_log::error!("The server at {uri} requires a valid auth token");
+            }
+            TonicStatusError::from(status)
+        })?;
+
+    let connection = tx.add_connection(
+        uri.origin.to_string(),
+        response
+            .metadata()
+            .get("grpc-encoding")
+            .and_then(|value| value.to_str().ok())
+            .map(ToOwned::to_owned),
+    );
+
+    let mut stream = response.into_inner();
 
     let mut app_id_cache = re_log_encoding::CachingApplicationIdInjector::default();
     loop {
@@ -70,7 +150,16 @@ async fn stream_async(
             Ok(Some(ReadMessagesResponse {
                 log_msg: Some(log_msg_proto),
             })) => {
-                let mut log_msg = log_msg_proto.to_application((&mut app_id_cache, None))?;
+                let (payload_bytes, uncompressed_bytes) = log_msg_proto.encoded_sizes();
+                connection.on_message(payload_bytes, uncompressed_bytes);
+
+                let mut log_msg = match log_msg_proto.to_application((&mut app_id_cache, None)) {
+                    Ok(log_msg) => log_msg,
+                    Err(err) => {
+                        connection.on_disconnect();
+                        return Err(err.into());
+                    }
+                };
 
                 // Insert the timestamp metadata into the Arrow message for accurate e2e latency measurements:
                 log_msg.insert_arrow_record_batch_metadata(
@@ -104,5 +193,117 @@ async fn stream_async(
         }
     }
 
+    connection.on_disconnect();
+
     Ok(())
 }
+
+#[cfg(test)]
+#[cfg(not(target_arch = "wasm32"))]
+mod tests {
+    use std::time::Duration;
+
+    use re_log_types::{LogMsg, SetStoreInfo, StoreId, StoreInfo, StoreKind, StoreSource};
+    use re_smart_channel::SmartMessagePayload;
+
+    use super::*;
+
+    const TOKEN: &str = "secret";
+
+    fn set_store_info_msg() -> LogMsg {
+        LogMsg::SetStoreInfo(SetStoreInfo {
+            row_id: *re_chunk::RowId::new(),
+            info: StoreInfo::new(
+                StoreId::random(StoreKind::Recording, "test_app"),
+                StoreSource::RustSdk {
+                    rustc_version: String::new(),
+                    llvm_version: String::new(),
+                },
+            ),
+        })
+    }
+
+    /// Waits for the first message of a stream, without blocking the runtime.
+    async fn first_payload(
+        rx: re_smart_channel::Receiver<DataSourceMessage>,
+    ) -> SmartMessagePayload<DataSourceMessage> {
+        tokio::task::spawn_blocking(move || rx.recv_timeout(Duration::from_secs(10)).unwrap())
+            .await
+            .unwrap()
+            .payload
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn read_from_authenticated_server() {
+        let addr = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let (signal, shutdown) = re_grpc_server::shutdown::shutdown();
+        tokio::spawn(re_grpc_server::serve(
+            addr,
+            re_grpc_server::ServerOptions {
+                auth_token: Some(TOKEN.to_owned()),
+                ..Default::default()
+            },
+            shutdown,
+        ));
+
+        let uri = re_uri::ProxyUri::new(re_uri::Origin::from_scheme_and_socket_addr(
+            re_uri::Scheme::RerunHttp,
+            addr,
+        ));
+        let with_token = |token: Option<&str>| Options {
+            auth_token: token.map(ToOwned::to_owned),
+            ..Default::default()
+        };
+
+        let authenticated = stream(uri.clone(), with_token(Some(TOKEN)), None);
+
+        let msg = set_store_info_msg();
+        tokio::task::spawn_blocking({
+            let uri = uri.clone();
+            let msg = msg.clone();
+            move || {
+                let client = crate::write::Client::new(
+                    uri,
+                    crate::write::Options {
+                        auth_token: Some(TOKEN.to_owned()),
+                        ..Default::default()
+                    },
+                );
+                client.send(msg);
+                client.flush_blocking(Duration::from_secs(10)).unwrap();
+            }
+        })
+        .await
+        .unwrap();
+
+        match first_payload(authenticated).await {
+            SmartMessagePayload::Msg(DataSourceMessage::LogMsg(received)) => {
+                assert_eq!(received, msg);
+            }
+            payload => panic!("expected a log message, got {payload:?}"),
+        }
+
+        for token in [None, Some("wrong")] {
+            let unauthenticated = stream(uri.clone(), with_token(token), None);
+            match first_payload(unauthenticated).await {
+                SmartMessagePayload::Quit(Some(err)) => {
+                    let Some(StreamError::TonicStatus(status)) = err.downcast_ref::<StreamError>()
+                    else {
+                        panic!("expected a gRPC status, got {err}");
+                    };
+                    assert_eq!(
+                        status.as_ref().code(),
+                        tonic::Code::Unauthenticated,
+                        "{token:?}"
+                    );
+                }
+                payload => panic!("expected an authentication error, got {payload:?}"),
+            }
+        }
+
+        signal.stop();
+    }
+}
@@ -1,4 +1,5 @@
 use std::{
+    path::PathBuf,
     sync::Arc,
     thread::{self, JoinHandle},
     time::Duration,
@@ -8,7 +9,11 @@ use tokio::{
     runtime,
     sync::mpsc::{self, Receiver, Sender, UnboundedReceiver, UnboundedSender},
 };
-use tonic::transport::Endpoint;
+use tonic::{
+    codec::CompressionEncoding,
+    metadata::{Ascii, MetadataValue},
+    transport::Endpoint,
+};
 use web_time::Instant;
 
 use re_chunk::external::crossbeam::atomic::AtomicCell;
@@ -67,10 +72,42 @@ enum Cmd {
     },
 }
 
+/// Compression of the gRPC messages as a whole, on top of the payload [`Options::compression`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TransportCompression {
+    #[default]
+    Off,
+
+    /// Slower than LZ4, but much better compression ratio.
+    ///
+    /// Worth it on slow links. Requires a server that supports it.
+    Zstd,
+}
+
+impl std::str::FromStr for TransportCompression {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "off" | "none" => Ok(Self::Off),
+            "zstd" => Ok(Self::Zstd),
+            _ => Err(format!(
+                "unknown transport compression {s:?}, expected 'off' or 'zstd'"
+            )),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct Options {
+    /// Compression of the Arrow payloads.
     pub compression: Compression,
 
+    /// Compression of the gRPC messages as a whole.
+    ///
+    /// Data the server sends back is always compressed if the server supports it.
+    pub transport_compression: TransportCompression,
+
     /// If we have not yet connected to the client, then
     /// do not block [`Client::flush_blocking`] for longer than this.
     ///
@@ -78,23 +115,109 @@ pub struct Options {
     /// But blocking [`Client::flush_blocking`] forever when the
     /// server just isn't there is not a good idea.
     pub connect_timeout_on_flush: Duration,
+
+    /// Token to authenticate with, if the server requires one.
+    pub auth_token: Option<String>,
+
+    /// An additional PEM-encoded root certificate to trust for `rerun+https://` connections.
+    ///
+    /// Useful to connect to a server using a self-signed certificate.
+    pub tls_ca_cert: Option<PathBuf>,
+
+    /// How this client introduces itself to the server, e.g. `robot-A`.
+    ///
+    /// Servers that merge several clients into the same recording can use this to tell them
+    /// apart, e.g. to prefix their entity paths with it.
+    pub client_name: Option<String>,
 }
 
 impl Default for Options {
     fn default() -> Self {
         Self {
             compression: Compression::LZ4,
+            transport_compression: TransportCompression::Off,
             connect_timeout_on_flush: Duration::from_secs(5),
+            auth_token: None,
+            tls_ca_cert: None,
+            client_name: None,
         }
     }
 }
 
+impl Options {
+    /// Environment variable to configure [`Self::auth_token`].
+    ///
+    /// Shared with the server side, so that both ends can be configured at once.
+    pub const ENV_AUTH_TOKEN: &'static str = "RERUN_GRPC_TOKEN";
+
+    /// Environment variable to configure [`Self::tls_ca_cert`].
+    pub const ENV_TLS_CA_CERT: &'static str = "RERUN_GRPC_CA_CERT";
+
+    /// Environment variable to configure [`Self::transport_compression`].
+    pub const ENV_TRANSPORT_COMPRESSION: &'static str = "RERUN_GRPC_COMPRESSION";
+
+    /// Environment variable to configure [`Self::client_name`].
+    pub const ENV_CLIENT_NAME: &'static str = "RERUN_GRPC_CLIENT_NAME";
+
+    /// Creates new `Options` using the default values, optionally overridden through the
+    /// environment.
+    ///
+    /// See [`Self::apply_env`].
+    pub fn from_env() -> Self {
+        Self::default().apply_env()
+    }
+
+    /// Returns a copy of `self`, overriding existing fields with values from the environment if
+    /// they are present.
+    ///
+    /// See [`Self::ENV_AUTH_TOKEN`], [`Self::ENV_TLS_CA_CERT`], [`Self::ENV_TRANSPORT_COMPRESSION`],
+    /// [`Self::ENV_CLIENT_NAME`].
+    pub fn apply_env(&self) -> Self {
+        let mut new = self.clone();
+
+        if let Ok(token) = std::env::var(Self::ENV_AUTH_TOKEN)
+            && !token.is_empty()
+        {
+            new.auth_token = Some(token);
+        }
+
+        if let Some(path) = std::env::var_os(Self::ENV_TLS_CA_CERT)
+            && !path.is_empty()
+        {
+            new.tls_ca_cert = Some(path.into());
+        }
+
+        if let Ok(s) = std::env::var(Self::ENV_TRANSPORT_COMPRESSION) {
+            match s.parse() {
+                Ok(transport_compression) => new.transport_compression = transport_compression,
+                Err(err) => {
+                    re_log::warn!("Ignoring {}: {err}", Self::ENV_TRANSPORT_COMPRESSION);
+                }
+            }
+        }
+
+        if let Ok(name) = std::env::var(Self::ENV_CLIENT_NAME)
+            && !name.is_empty()
+        {
+            new.client_name = Some(name);
+        }
+
+        new
+    }
+}
+
 /// Why a client was unintentionally disconnected.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
 pub enum ClientConnectionFailure {
     #[error("Invalid message proxy server endpoint")]
     InvalidEndpoint,
 
+    #[error("Invalid TLS configuration")]
+    InvalidTlsConfig,
+
+    #[error("Invalid auth token")]
+    InvalidAuthToken,
+
     #[error("Failed to encode message")]
     FailedToEncodeMessage,
 
@@ -137,6 +260,7 @@ impl Client {
         }));
         let thread = {
             let uri = uri.clone();
+            let options = options.clone();
             let status = status.clone();
             thread::Builder::new()
                 .name("message_proxy_client".to_owned())
@@ -150,7 +274,7 @@ impl Client {
                             uri.clone(),
                             cmd_rx,
                             shutdown_rx,
-                            options.compression,
+                            options,
                             status,
                         ));
                 })
@@ -311,10 +435,19 @@ async fn message_proxy_client(
     uri: ProxyUri,
     mut cmd_rx: UnboundedReceiver<Cmd>,
     mut shutdown_rx: Receiver<()>,
-    compression: Compression,
+    options: Options,
     status: Arc<AtomicCell<ClientConnectionState>>,
 ) {
-    let endpoint = match Endpoint::from_shared(uri.origin.as_url()) {
+    let Options {
+        compression,
+        transport_compression,
+        connect_timeout_on_flush: _,
+        auth_token,
+        tls_ca_cert,
+        client_name,
+    } = options;
+
+    let mut endpoint = match Endpoint::from_shared(uri.origin.as_url()) {
         Ok(endpoint) => endpoint,
         Err(err) => {
             status.store(ClientConnectionState::Disconnected(Err(
@@ -325,6 +458,41 @@ async fn message_proxy_client(
         }
     };
 
+    if uri.origin.scheme != re_uri::Scheme::RerunHttp {
+        endpoint = match crate::with_tls(endpoint, tls_ca_cert.as_deref()) {
+            Ok(endpoint) => endpoint,
+            Err(err) => {
+                status.store(ClientConnectionState::Disconnected(Err(
+                    ClientConnectionFailure::InvalidTlsConfig,
+                )));
+                re_log::error!("Invalid TLS configuration for {uri}: {err}");
+                return;
+            }
+        };
+    } else if tls_ca_cert.is_some() {
+        re_log::warn_once!("Ignoring the TLS root certificate for non-TLS connection to {uri}");
+    }
+
+    let authorization = match auth_token
+        .map(|token| format!("Bearer {}", token.trim()).parse::<MetadataValue<Ascii>>())
+        .transpose()
+    {
+        Ok(authorization) => authorization,
+        Err(err) => {
+            status.store(ClientConnectionState::Disconnected(Err(
+                ClientConnectionFailure::InvalidAuthToken,
+            )));
+            re_log::error!("Invalid auth token: {err}");
+            return;
+        }
+    };
+
+    let client_name = client_name.and_then(|name| {
+        name.parse::<MetadataValue<Ascii>>()
+            .inspect_err(|err| re_log::warn!("Ignoring client name {name:?}: {err}"))
+            .ok()
+    });
+
     let mut last_connect_failure_log_time: Option<Instant> = None;
     let channel = loop {
         match endpoint.connect().await {
@@ -354,8 +522,27 @@ async fn message_proxy_client(
     re_log::debug!("Connected to {uri}");
     status.store(ClientConnectionState::Connected);
 
-    let mut client = MessageProxyServiceClient::new(channel)
-        .max_decoding_message_size(crate::MAX_DECODING_MESSAGE_SIZE);
+    let mut client = MessageProxyServiceClient::with_interceptor(
+        channel,
+        move |mut req: tonic::Request<()>| -> Result<_, tonic::Status> {
+            if let Some(authorization) = &authorization {
+                req.metadata_mut()
+                    .insert("authorization", authorization.clone());
+            }
+            if let Some(client_name) = &client_name {
+                req.metadata_mut().insert(
+                    re_protos::headers::RERUN_HTTP_HEADER_CLIENT_NAME,
+                    client_name.clone(),
+                );
+            }
+            Ok(req)
+        },
+    )
+    .max_decoding_message_size(crate::MAX_DECODING_MESSAGE_SIZE)
+    .accept_compressed(CompressionEncoding::Zstd);
+    if transport_compression == TransportCompression::Zstd {
+        client = client.send_compressed(CompressionEncoding::Zstd);
+    }
 
     let stream_status = status.clone();
     let stream = async_stream::stream! {
@@ -421,6 +608,20 @@ async fn message_proxy_client(
             TonicStatusError::from(status.clone())
         );
 
+        if status.code() == tonic::Code::Unimplemented
+            && transport_compression != TransportCompression::Off
+        {
+            re_log::error!(
+                "The server at {uri} may not support {transport_compression:?} compression, try disabling it through `{}`",
+                Options::ENV_TRANSPORT_COMPRESSION
+            );
+        } else if status.code() == tonic::Code::Unauthenticated {
+            re_log::error!(
+                "The server at {uri} requires a valid auth token, set it through `{}`",
+                Options::ENV_AUTH_TOKEN
+            );
+        }
+
         // Ignore status code "Unknown" since this was observed to happen on regular Viewer shutdowns.
         if status.code() != tonic::Code::Ok && status.code() != tonic::Code::Unknown {
             Err(ClientConnectionFailure::FailedToSendMessages(status.code()))
@@ -0,0 +1,86 @@
+//! Token-based authentication for the gRPC server.
+
+use std::sync::Arc;
+
+use tonic::{Request, Status, service::Interceptor};
+
+/// The metadata key clients put their token in.
+const AUTHORIZATION_KEY: &str = "authorization";
+
+/// The prefix of the token in the metadata value.
+const TOKEN_PREFIX: &str = "Bearer ";
+
+/// Rejects all requests that don't carry the expected token, if any.
+#[derive(Clone)]
+pub(crate) struct TokenAuthenticator {
+    expected: Option<Arc<str>>,
+}
+
+impl TokenAuthenticator {
+    pub fn new(expected: Option<&str>) -> Self {
+        Self {
+            expected: expected.map(Arc::from),
+        }
+    }
+}
+
+impl Interceptor for TokenAuthenticator {
+    fn call(&mut self, req: Request<()>) -> Result<Request<()>, Status> {
+        let Some(expected) = &self.expected else {
+            return Ok(req);
+        };
+
+        let token = req
+            .metadata()
+            .get(AUTHORIZATION_KEY)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix(TOKEN_PREFIX))
+            .map(str::trim);
+
+        match token {
+            Some(token) if constant_time_eq(token.as_bytes(), expected.as_bytes()) => Ok(req),
+            Some(_) => Err(Status::unauthenticated("invalid auth token")),
+            None => Err(Status::unauthenticated("missing auth token")),
+        }
+    }
+}
+
+/// Compares two byte strings without leaking where they first differ through timing.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (a, b)| acc | (a ^ b)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request_with(value: Option<&str>) -> Request<()> {
+        let mut req = Request::new(());
+        if let Some(value) = value {
+            req.metadata_mut()
+                .insert(AUTHORIZATION_KEY, value.parse().unwrap());
+        }
+        req
+    }
+
+    #[test]
+    fn token_authenticator() {
+        let mut open = TokenAuthenticator::new(None);
+        assert!(open.call(request_with(None)).is_ok());
+        assert!(open.call(request_with(Some("Bearer whatever"))).is_ok());
+
+        let mut auth = TokenAuthenticator::new(Some("secret"));
+        assert!(auth.call(request_with(Some("Bearer secret"))).is_ok());
+        assert!(auth.call(request_with(Some("Bearer  secret "))).is_ok());
+
+        for bad in [
+            None,
+            Some("secret"),
+            Some("Bearer secre"),
+            Some("Bearer secret2"),
+        ] {
+            let status = auth.call(request_with(bad)).unwrap_err();
+            assert_eq!(status.code(), tonic::Code::Unauthenticated, "{bad:?}");
+        }
+    }
+}
@@ -2,14 +2,29 @@
 
 pub mod shutdown;
 
-use std::{collections::VecDeque, net::SocketAddr, pin::Pin};
+mod auth;
+mod merge;
+mod tls;
+
+use std::{
+    collections::VecDeque,
+    net::SocketAddr,
+    pin::Pin,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+};
 
 use tokio::{
     net::TcpListener,
     sync::{broadcast, mpsc, oneshot},
 };
 use tokio_stream::{Stream, StreamExt as _, wrappers::BroadcastStream};
-use tonic::transport::{Server, server::TcpIncoming};
+use tonic::{
+    codec::CompressionEncoding,
+    transport::{Server, server::TcpIncoming},
+};
 use tower_http::cors::CorsLayer;
 
 use re_byte_size::SizeBytes;
@@ -37,19 +52,24 @@ mod priority_stream;
 
 pub use re_memory::MemoryLimit;
 
+pub use self::{merge::MergeOptions, tls::TlsOptions};
+
 /// Default port of the OSS /proxy server.
 pub const DEFAULT_SERVER_PORT: u16 = 9876;
 
 pub const MAX_DECODING_MESSAGE_SIZE: usize = u32::MAX as usize;
 pub const MAX_ENCODING_MESSAGE_SIZE: usize = MAX_DECODING_MESSAGE_SIZE;
 
+/// The gRPC metadata key naming the compression of the messages of a request.
+const GRPC_ENCODING_KEY: &str = "grpc-encoding";
+
 // Channel capacity is completely arbitrary, e just want something large enough
 // to handle bursts of messages. This is roughly 16 MiB of `Msg` (excluding their contents).
 const MESSAGE_QUEUE_CAPACITY: usize =
     (16 * 1024 * 1024 / std::mem::size_of::<LogOrTableMsgProto>()).next_power_of_two();
 
 /// Options for the gRPC Proxy Server
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct ServerOptions {
     /// When a client connect, should they be sent the oldest data first, or the newest?
     pub playback_behavior: PlaybackBehavior,
@@ -60,6 +80,17 @@ pub struct ServerOptions {
     /// any data is sent, it is highly recommended that you set the memory limit to `0B`,
     /// otherwise you're potentially doubling your memory usage!
     pub memory_limit: MemoryLimit,
+
+    /// If set, clients must present this token to read or write any data.
+    ///
+    /// It is sent as a `Bearer` token in the `authorization` metadata of each request.
+    pub auth_token: Option<String>,
+
+    /// If set, the server only accepts TLS connections (`rerun+https://`).
+    pub tls: Option<TlsOptions>,
+
+    /// How to merge the data of several clients writing into the same recording.
+    pub merge: MergeOptions,
 }
 
 impl Default for ServerOptions {
@@ -67,6 +98,39 @@ impl Default for ServerOptions {
         Self {
             playback_behavior: PlaybackBehavior::OldestFirst,
             memory_limit: MemoryLimit::UNLIMITED,
+            auth_token: None,
+            tls: None,
+            merge: MergeOptions::default(),
+        }
+    }
+}
+
+impl ServerOptions {
+    /// Environment variable to configure [`Self::auth_token`].
+    ///
+    /// Shared with the SDK-side client, so that both ends can be configured at once.
+    pub const ENV_AUTH_TOKEN: &'static str = "RERUN_GRPC_TOKEN";
+
+    /// Returns a copy of `self`, with [`Self::auth_token`] taken from the environment if it
+    /// isn't already set.
+    ///
+    /// See [`Self::ENV_AUTH_TOKEN`].
+    pub fn apply_env(&self) -> Self {
+        let mut new = self.clone();
+        if new.auth_token.is_none() {
+            new.auth_token = std::env::var(Self::ENV_AUTH_TOKEN)
+                .ok()
+                .filter(|token| !token.is_empty());
+        }
+        new
+    }
+
+    /// The scheme clients need to use to connect to a server with these options.
+    pub fn scheme(&self) -> re_uri::Scheme {
+        if self.tls.is_some() {
+            re_uri::Scheme::RerunHttps
+        } else {
+            re_uri::Scheme::RerunHttp
         }
     }
 }
@@ -158,34 +222,60 @@ async fn serve_impl(
     message_proxy: MessageProxy,
     shutdown: shutdown::Shutdown,
 ) -> anyhow::Result<()> {
+    let auth_token = message_proxy.options.auth_token.clone();
+
+    // Fail early on bad certificates, before we start listening.
+    let tls_config = message_proxy
+        .options
+        .tls
+        .as_ref()
+        .map(|tls| tls.server_tls_config(addr))
+        .transpose()?;
+
     let tcp_listener = TcpListener::bind(addr).await?;
     let incoming = TcpIncoming::from(tcp_listener).with_nodelay(Some(true));
 
+    let scheme = message_proxy.options.scheme();
     let connect_addr = if addr.ip().is_loopback() || addr.ip().is_unspecified() {
-        format!("rerun+http://127.0.0.1:{}/proxy", addr.port())
+        format!("{scheme}://127.0.0.1:{}/proxy", addr.port())
     } else {
-        format!("rerun+http://{addr}/proxy")
+        format!("{scheme}://{addr}/proxy")
     };
     re_log::info!(
         "Listening for gRPC connections on {addr}. Connect by running `rerun --connect {connect_addr}`"
     );
+    if auth_token.is_some() {
+        re_log::info!(
+            "Clients must authenticate with the configured token (e.g. through `{}`)",
+            ServerOptions::ENV_AUTH_TOKEN
+        );
+    }
 
     let cors = CorsLayer::very_permissive();
     let grpc_web = tonic_web::GrpcWebLayer::new();
 
     let routes = {
         let mut routes_builder = tonic::service::Routes::builder();
-        routes_builder.add_service(
+        routes_builder.add_service(tonic::service::interceptor::InterceptedService::new(
             re_protos::sdk_comms::v1alpha1::message_proxy_service_server::MessageProxyServiceServer::new(
                 message_proxy,
             )
             .max_decoding_message_size(MAX_DECODING_MESSAGE_SIZE)
-            .max_encoding_message_size(MAX_ENCODING_MESSAGE_SIZE),
-        );
+            .max_encoding_message_size(MAX_ENCODING_MESSAGE_SIZE)
+            // Responses are only compressed for clients that say they support it.
+            .accept_compressed(CompressionEncoding::Zstd)
+            .send_compressed(CompressionEncoding::Zstd),
+            auth::TokenAuthenticator::new(auth_token.as_deref()),
+        ));
         routes_builder.routes()
     };
 
-    Server::builder()
+    let mut server = Server::builder();
+    if let Some(tls_config) = tls_config {
+        server = server.tls_config(tls_config)?;
+    }
+
+    server
         .accept_http1(true) // Support `grpc-web` clients
         .layer(cors) // Allow CORS requests from web clients
         .layer(grpc_web) // Support `grpc-web` clients
@@ -361,7 +451,7 @@ pub fn spawn_with_recv(
     crossbeam::channel::Receiver<re_log_types::TableMsg>,
 ) {
     let uri = re_uri::ProxyUri::new(re_uri::Origin::from_scheme_and_socket_addr(
-        re_uri::Scheme::RerunHttp,
+        options.scheme(),
         addr,
     ));
     let (channel_log_tx, channel_log_rx) = re_smart_channel::smart_channel(
@@ -369,8 +459,14 @@ pub fn spawn_with_recv(
         re_smart_channel::SmartChannelSource::MessageProxy(uri),
     );
     let (channel_table_tx, channel_table_rx) = crossbeam::channel::unbounded();
-    let (message_proxy, mut broadcast_log_rx, mut broadcast_table_rx) =
+    let (mut message_proxy, mut broadcast_log_rx, mut broadcast_table_rx) =
         MessageProxy::new_with_recv(options);
+    message_proxy.on_new_connection = Some(Box::new({
+        let channel_log_tx = channel_log_tx.clone();
+        move |peer, transport_compression| {
+            channel_log_tx.add_connection(peer, transport_compression)
+        }
+    }));
     tokio::spawn(async move {
         if let Err(err) = serve_impl(addr, message_proxy, shutdown).await {
             re_log::error!("message proxy server crashed: {err}");
@@ -809,10 +905,19 @@ impl SizeBytes for TableMsgProto {
     }
 }
 
+/// Starts tracking the stats of a new client writing to the server, given its address and the
+/// gRPC compression it uses.
+type OnNewConnection =
+    Box<dyn Fn(String, Option<String>) -> Arc<re_smart_channel::ConnectionStats> + Send + Sync>;
+
 pub struct MessageProxy {
     options: ServerOptions,
     _queue_task_handle: tokio::task::JoinHandle<()>,
     event_tx: mpsc::Sender<Event>,
+    on_new_connection: Option<OnNewConnection>,
+
+    /// Used to name the clients that don't name themselves.
+    num_write_connections: AtomicU64,
 }
 
 impl MessageProxy {
@@ -831,17 +936,22 @@ impl MessageProxy {
         let (broadcast_log_tx, broadcast_log_rx) = broadcast::channel(MESSAGE_QUEUE_CAPACITY);
         let (broadcast_table_tx, broadcast_table_rx) = broadcast::channel(MESSAGE_QUEUE_CAPACITY);
 
-        let task_handle = tokio::spawn(async move {
-            EventLoop::new(options, event_rx, broadcast_log_tx, broadcast_table_tx)
-                .run_in_place()
-                .await;
-        });
+        let task_handle = {
+            let options = options.clone();
+            tokio::spawn(async move {
+                EventLoop::new(options, event_rx, broadcast_log_tx, broadcast_table_tx)
+                    .run_in_place()
+                    .await;
+            })
+        };
 
         (
             Self {
                 options,
                 _queue_task_handle: task_handle,
                 event_tx,
+                on_new_connection: None,
+                num_write_connections: AtomicU64::new(0),
             },
             broadcast_log_rx,
             broadcast_table_rx,
@@ -955,12 +1065,46 @@ impl message_proxy_service_server::MessageProxyService for MessageProxy {
         &self,
         request: tonic::Request<tonic::Streaming<WriteMessagesRequest>>,
     ) -> tonic::Result<tonic::Response<WriteMessagesResponse>> {
+        let connection_index = self.num_write_connections.fetch_add(1, Ordering::Relaxed);
+        let client_name = request
+            .metadata()
+            .get(re_protos::headers::RERUN_HTTP_HEADER_CLIENT_NAME)
+            .and_then(|value| value.to_str().ok())
+            .map(ToOwned::to_owned);
+
+        let connection = self.on_new_connection.as_ref().map(|on_new_connection| {
+            let mut peer = request
+                .remote_addr()
+                .map_or_else(|| "unknown".to_owned(), |addr| addr.to_string());
+            if let Some(client_name) = &client_name {
+                peer = format!("{client_name} ({peer})");
+            }
+            let transport_compression = request
+                .metadata()
+                .get(GRPC_ENCODING_KEY)
+                .and_then(|value| value.to_str().ok())
+                .map(ToOwned::to_owned);
+            on_new_connection(peer, transport_compression)
+        });
+
+        let mut merger = merge::ClientMerger::new(
+            self.options.merge,
+            client_name.unwrap_or_else(|| format!("client-{connection_index}")),
+        );
+
         let mut stream = request.into_inner();
         loop {
             match stream.message().await {
                 Ok(Some(WriteMessagesRequest {
-                    log_msg: Some(log_msg),
+                    log_msg: Some(mut log_msg),
                 })) => {
+                    if let Some(connection) = &connection {
+                        let (payload_bytes, uncompressed_bytes) = log_msg.encoded_sizes();
+                        connection.on_message(payload_bytes, uncompressed_bytes);
+                    }
+                    if let Some(merger) = &mut merger {
+                        log_msg = merger.process(log_msg);
+                    }
                     self.push_msg(log_msg).await;
                 }
 
@@ -980,6 +1124,10 @@ impl message_proxy_service_server::MessageProxyService for MessageProxy {
             }
         }
 
+        if let Some(connection) = &connection {
+            connection.on_disconnect();
+        }
+
         Ok(tonic::Response::new(WriteMessagesResponse {}))
     }
 
@@ -1170,6 +1318,7 @@ mod tests {
         setup_opt(ServerOptions {
             playback_behavior: PlaybackBehavior::OldestFirst,
             memory_limit: MemoryLimit::UNLIMITED,
+            ..Default::default()
         })
         .await
     }
@@ -1178,6 +1327,7 @@ mod tests {
         setup_opt(ServerOptions {
             playback_behavior: PlaybackBehavior::OldestFirst,
             memory_limit,
+            ..Default::default()
         })
         .await
     }
@@ -1499,6 +1649,7 @@ mod tests {
         let (completion, addr) = setup_opt(ServerOptions {
             playback_behavior: PlaybackBehavior::NewestFirst, // this is what we want to test
             memory_limit: MemoryLimit::UNLIMITED,
+            ..Default::default()
         })
         .await;
         let mut client = make_client(addr).await;
@@ -1531,4 +1682,54 @@ mod tests {
 
         completion.finish();
     }
+
+    #[tokio::test]
+    async fn merge_prefixes_client_entities() {
+        let (completion, addr) = setup_opt(ServerOptions {
+            merge: MergeOptions {
+                prefix_entities: true,
+                align_clocks: false,
+            },
+            ..Default::default()
+        })
+        .await;
+
+        let recording = fake_log_stream_recording(3);
+        let blueprint = fake_log_stream_blueprint(3);
+
+        // Two separate clients, neither of which names itself.
+        write_messages(&mut make_client(addr).await, recording.clone()).await;
+        write_messages(&mut make_client(addr).await, blueprint.clone()).await;
+
+        let mut client = make_client(addr).await;
+        let mut log_stream = client.read_messages(ReadMessagesRequest {}).await.unwrap();
+        let actual = read_log_stream(&mut log_stream, recording.len() + blueprint.len()).await;
+
+        let entity_paths = actual
+            .iter()
+            .filter_map(|msg| match msg {
+                LogMsg::ArrowMsg(store_id, arrow_msg) => Some((
+                    store_id.kind(),
+                    re_chunk::Chunk::from_arrow_msg(arrow_msg)
+                        .unwrap()
+                        .entity_path()
+                        .to_string(),
+                )),
+                _ => None,
+            })
+            .collect_vec();
+
+        // Only recordings get prefixed.
+        let expected = chain!(
+            std::iter::repeat_n(
+                (StoreKind::Recording, "/client-0/test_entity".to_owned()),
+                3
+            ),
+            std::iter::repeat_n((StoreKind::Blueprint, "/test_entity".to_owned()), 3),
+        )
+        .collect_vec();
+        assert_eq!(entity_paths, expected);
+
+        completion.finish();
+    }
 }
@@ -0,0 +1,210 @@
+//! Merging the data of several clients writing into the same recording.
+
+use re_chunk::{Chunk, TimelineName};
+use re_log_encoding::{
+    CachingApplicationIdInjector, ToApplication as _, ToTransport as _, rrd::Compression,
+};
+use re_log_types::{Duration, EntityPath, LogMsg, TimeType};
+use re_protos::log_msg::v1alpha1::LogMsg as LogMsgProto;
+use re_sorbet::timestamp_metadata::{KEY_TIMESTAMP_SDK_IPC_ENCODE, parse_timestamp};
+
+/// How the server merges the data of several clients writing into the same recording,
+/// e.g. one per robot.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MergeOptions {
+    /// Log the entities of each client under `/<client name>/…`.
+    ///
+    /// Clients name themselves with the `RERUN_GRPC_CLIENT_NAME` environment variable.
+    /// Unnamed clients are called `client-<n>`, in the order they connected.
+    ///
+    /// Reserved entities, such as the recording properties, are left as-is.
+    pub prefix_entities: bool,
+
+    /// Estimate how far the clock of each client is off from the one of the server, and shift
+    /// all the timestamp timelines of that client to compensate.
+    ///
+    /// Sequence and duration timelines are left as-is.
+    pub align_clocks: bool,
+}
+
+impl MergeOptions {
+    fn is_noop(&self) -> bool {
+        let Self {
+            prefix_entities,
+            align_clocks,
+        } = *self;
+        !prefix_entities && !align_clocks
+    }
+}
+
+/// Rewrites the messages of a single client, according to [`MergeOptions`].
+pub(crate) struct ClientMerger {
+    client_name: String,
+    prefix: Option<EntityPath>,
+    clock: Option<ClockOffsetEstimator>,
+    app_id_injector: CachingApplicationIdInjector,
+}
+
+impl ClientMerger {
+    /// Returns `None` if there is nothing to do.
+    pub fn new(options: MergeOptions, client_name: String) -> Option<Self> {
+        if options.is_noop() {
+            return None;
+        }
+
+        Some(Self {
+            prefix: options
+                .prefix_entities
+                .then(|| EntityPath::from_single_string(client_name.as_str())),
+            clock: options.align_clocks.then(ClockOffsetEstimator::default),
+            client_name,
+            app_id_injector: CachingApplicationIdInjector::default(),
+        })
+    }
+
+    /// Messages we can't make sense of are passed through as-is.
+    pub fn process(&mut self, msg: LogMsgProto) -> LogMsgProto {
+        match self.try_process(&msg) {
+            Ok(Some(new_msg)) => new_msg,
+            Ok(None) => msg,
+            Err(err) => {
+                re_log::warn_once!(
+                    "Failed to merge message from client {:?}, passing it through: {err}",
+                    self.client_name
+                );
+                msg
+            }
+        }
+    }
+
+    fn try_process(&mut self, msg: &LogMsgProto) -> anyhow::Result<Option<LogMsgProto>> {
+        // Always decode, so that the application id injector sees every `SetStoreInfo`.
+        let log_msg = msg.to_application((&mut self.app_id_injector, None))?;
+        let LogMsg::ArrowMsg(store_id, arrow_msg) = &log_msg else {
+            return Ok(None);
+        };
+        if !store_id.is_recording() {
+            // Blueprints are about the whole recording, not a single client.
+            return Ok(None);
+        }
+
+        // Keep it around for the end-to-end latency measurements.
+        let encoded_at = arrow_msg
+            .batch
+            .schema_ref()
+            .metadata()
+            .get(KEY_TIMESTAMP_SDK_IPC_ENCODE)
+            .cloned();
+
+        let clock_offset = match (&mut self.clock, &encoded_at) {
+            (Some(clock), Some(encoded_at)) => {
+                clock.on_message(&self.client_name, parse_timestamp(encoded_at))
+            }
+            _ => None,
+        };
+
+        let mut chunk = Chunk::from_arrow_msg(arrow_msg)?;
+
+        if let Some(prefix) = &self.prefix
+            && !chunk.entity_path().is_reserved()
+        {
+            let entity_path = prefix.join(chunk.entity_path());
+            chunk = chunk.with_entity_path(entity_path);
+        }
+
+        if let Some(clock_offset) = clock_offset {
+            let timestamp_timelines: Vec<TimelineName> = chunk
+                .timelines()
+                .iter()
+                .filter(|(_, time_column)| time_column.timeline().typ() == TimeType::TimestampNs)
+                .map(|(timeline, _)| *timeline)
+                .collect();
+            for timeline in &timestamp_timelines {
+                chunk = chunk.time_shifted(timeline, clock_offset);
+            }
+        }
+
+        let mut new_log_msg = LogMsg::ArrowMsg(store_id.clone(), chunk.to_arrow_msg()?);
+        if let Some(encoded_at) = encoded_at {
+            new_log_msg.insert_arrow_record_batch_metadata(
+                KEY_TIMESTAMP_SDK_IPC_ENCODE.to_owned(),
+                encoded_at,
+            );
+        }
+
+        Ok(Some(new_log_msg.to_transport(Compression::LZ4)?.into()))
+    }
+}
+
+/// Estimates how far the clock of a client is behind the one of the server.
+///
+/// Every message carries the time it was sent at, according to the client. The difference with
+/// the time it was received at, according to the server, is the clock offset plus the network
+/// latency. Since latency is never negative, the smallest difference seen so far is the best
+/// estimate of the offset, and it gets better as more messages come in.
+#[derive(Default)]
+struct ClockOffsetEstimator {
+    /// In nanoseconds, positive if the client is behind the server.
+    best_offset_ns: Option<i64>,
+}
+
+impl ClockOffsetEstimator {
+    /// Returns the current best estimate of the offset, in nanoseconds.
+    fn on_message(
+        &mut self,
+        client_name: &str,
+        sent_at: Option<web_time::SystemTime>,
+    ) -> Option<i64> {
+        if let Some(sent_at) = sent_at {
+            let offset_ns = nanos_since_epoch(web_time::SystemTime::now())
+                .saturating_sub(nanos_since_epoch(sent_at));
+
+            if self.best_offset_ns.is_none() {
+                re_log::info!(
+                    "Clock of client {client_name:?} is {:+.3}s off, compensating",
+                    Duration::from_nanos(offset_ns).as_secs_f64()
+                );
+            }
+
+            if self.best_offset_ns.is_none_or(|best| offset_ns < best) {
+                self.best_offset_ns = Some(offset_ns);
+            }
+        }
+
+        self.best_offset_ns
+    }
+}
+
+fn nanos_since_epoch(time: web_time::SystemTime) -> i64 {
+    match time.duration_since(web_time::UNIX_EPOCH) {
+        Ok(duration) => i64::try_from(duration.as_nanos()).unwrap_or(i64::MAX),
+        Err(err) => i64::try_from(err.duration().as_nanos()).map_or(i64::MIN, |nanos| -nanos),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clock_offset_estimator_keeps_smallest_offset() {
+        let now = web_time::SystemTime::now();
+        let second = std::time::Duration::from_secs(1);
+
+        let mut clock = ClockOffsetEstimator::default();
+        assert_eq!(clock.on_message("test", None), None);
+
+        // Client is ~10s behind, with varying latency.
+        let first = clock.on_message("test", Some(now - 10 * second)).unwrap();
+        assert!((10 * 1_000_000_000..11 * 1_000_000_000).contains(&first));
+
+        let second_estimate = clock.on_message("test", Some(now - 8 * second)).unwrap();
+        assert!(second_estimate < first);
+
+        // A slower message doesn't make the estimate worse.
+        assert_eq!(
+            clock.on_message("test", Some(now - 20 * second)),
+            Some(second_estimate)
+        );
+    }
+}
@@ -0,0 +1,81 @@
+//! TLS configuration of the gRPC server.
+
+use std::{net::SocketAddr, path::PathBuf};
+
+use tonic::transport::{Identity, ServerTlsConfig};
+
+/// Where the server gets its TLS certificate from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TlsOptions {
+    /// Load a PEM-encoded certificate chain and private key from disk.
+    Files {
+        cert_path: PathBuf,
+        key_path: PathBuf,
+    },
+
+    /// Generate a self-signed certificate when the server starts.
+    ///
+    /// The certificate is valid for `localhost`, the loopback addresses, the address the server
+    /// is bound to (unless unspecified), as well as any of the additional `subject_alt_names`.
+    ///
+    /// Clients won't trust this certificate unless they are told to.
+    /// If `save_cert_to` is set, the certificate is written there as PEM, so that it can be
+    /// handed out to clients (see `RERUN_GRPC_CA_CERT`).
+    SelfSigned {
+        subject_alt_names: Vec<String>,
+        save_cert_to: Option<PathBuf>,
+    },
+}
+
+impl TlsOptions {
+    pub(crate) fn server_tls_config(&self, addr: SocketAddr) -> anyhow::Result<ServerTlsConfig> {
+        let identity = match self {
+            Self::Files {
+                cert_path,
+                key_path,
+            } => {
+                let cert = std::fs::read(cert_path).map_err(|err| {
+                    anyhow::anyhow!("Failed to read TLS certificate {cert_path:?}: {err}")
+                })?;
+                let key = std::fs::read(key_path).map_err(|err| {
+                    anyhow::anyhow!("Failed to read TLS private key {key_path:?}: {err}")
+                })?;
+                Identity::from_pem(cert, key)
+            }
+
+            Self::SelfSigned {
+                subject_alt_names,
+                save_cert_to,
+            } => {
+                let mut names = vec![
+                    "localhost".to_owned(),
+                    "127.0.0.1".to_owned(),
+                    "::1".to_owned(),
+                ];
+                if !addr.ip().is_unspecified() && !addr.ip().is_loopback() {
+                    names.push(addr.ip().to_string());
+                }
+                names.extend(subject_alt_names.iter().cloned());
+
+                let rcgen::CertifiedKey { cert, signing_key } =
+                    rcgen::generate_simple_self_signed(names.clone())?;
+                let cert = cert.pem();
+
+                if let Some(path) = save_cert_to {
+                    std::fs::write(path, &cert).map_err(|err| {
+                        anyhow::anyhow!("Failed to save self-signed certificate to {path:?}: {err}")
+                    })?;
+                    re_log::info!(
+                        "Generated a self-signed TLS certificate for {names:?}, saved to {path:?}"
+                    );
+                } else {
+                    re_log::info!("Generated a self-signed TLS certificate for {names:?}");
+                }
+
+                Identity::from_pem(cert, signing_key.serialize_pem())
+            }
+        };
+
+        Ok(ServerTlsConfig::new().identity(identity))
+    }
+}
@@ -0,0 +1,379 @@
+//! An optional index of every message in an RRD stream.
+//!
+//! The index is stored as the payload of the end-of-stream marker, which decoders have always
+//! skipped over, so indexed streams remain readable by older versions of Rerun.
+//!
+//! It is immediately followed by a fixed-size trailer, which makes it possible to locate the
+//! index by only looking at the very end of a file (e.g. with an HTTP range request):
+//!
+//! ```text,ignore
+//! StreamHeader
+//! MessageHeader + Message
+//! …
+//! MessageHeader (End, len = N)
+//! ChunkIndex (N - 12 bytes)
+//! N: u64 | RRIX
+//! ```
+//!
+//! The index itself starts with `RRIX` followed by a version byte, see [`ChunkIndex::VERSION`].
+//! Indices written before versioning was introduced lack this header, and are still understood.
+
+use re_log_types::{AbsoluteTimeRange, EntityPath, TimeInt, TimelineName};
+
+use crate::rrd::{CodecError, Decodable, Encodable, MessageHeader, MessageKind};
+
+/// Where to find a single message of an RRD stream, and what time ranges it covers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChunkIndexEntry {
+    /// Where the message lives, relative to the start of the stream, [`MessageHeader`] included.
+    pub byte_span: re_chunk::Span<u64>,
+
+    pub kind: MessageKind,
+
+    /// Is this a chunk of static data?
+    pub is_static: bool,
+
+    /// The entity this chunk belongs to.
+    ///
+    /// `None` for everything but chunks, or if the encoder couldn't tell.
+    pub entity_path: Option<EntityPath>,
+
+    /// The time range covered by this chunk on each of its timelines.
+    ///
+    /// Empty for everything but temporal chunks, or if the encoder couldn't tell.
+    pub time_ranges: Vec<(TimelineName, AbsoluteTimeRange)>,
+}
+
+impl ChunkIndexEntry {
+    /// How far away this message is from `time` on `timeline`.
+    ///
+    /// Messages that aren't bound to `timeline` (store infos, static data, chunks on other
+    /// timelines…) are always considered to be as close as it gets.
+    pub fn distance_to(&self, timeline: &TimelineName, time: TimeInt) -> u64 {
+        let Some((_, range)) = self.time_ranges.iter().find(|(name, _)| name == timeline) else {
+            return 0;
+        };
+
+        let time = time.as_i64();
+        let (min, max) = (range.min().as_i64(), range.max().as_i64());
+        if time < min {
+            min.abs_diff(time)
+        } else if max < time {
+            time.abs_diff(max)
+        } else {
+            0
+        }
+    }
+
+    /// Might this message carry data within `range` on `timeline`?
+    ///
+    /// Just like for [`Self::distance_to`], messages that aren't bound to `timeline` always do.
+    pub fn intersects(&self, timeline: &TimelineName, range: AbsoluteTimeRange) -> bool {
+        self.time_ranges
+            .iter()
+            .find(|(name, _)| name == timeline)
+            .is_none_or(|(_, entry_range)| entry_range.intersects(range))
+    }
+}
+
+/// An index of every message in an RRD stream, see [module-level docs](self).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChunkIndex {
+    /// Offset of the end-of-stream [`MessageHeader`], relative to the start of the stream.
+    pub end_offset: u64,
+
+    /// All messages of the stream, in stream order.
+    pub entries: Vec<ChunkIndexEntry>,
+}
+
+impl ChunkIndex {
+    /// Marks the end of an indexed RRD stream.
+    pub const FOURCC: [u8; 4] = *b"RRIX";
+
+    /// The payload length, followed by [`Self::FOURCC`].
+    pub const TRAILER_SIZE_BYTES: usize = 12;
+
+    /// Version of the index layout, written right after [`Self::FOURCC`] at the start of the index.
+    ///
+    /// - 0: no header, no entity paths (never written anymore)
+    /// - 1: entity paths
+    pub const VERSION: u8 = 1;
+
+    /// Looks for a trailer in the last [`Self::TRAILER_SIZE_BYTES`] of a stream.
+    ///
+    /// Returns the size of the end-of-stream payload (i.e. the encoded index, trailer included),
+    /// or `None` if the stream isn't indexed.
+    pub fn payload_len_from_trailer(trailer: &[u8]) -> Option<u64> {
+        let trailer: &[u8; Self::TRAILER_SIZE_BYTES] = trailer.try_into().ok()?;
+        (trailer[8..] == Self::FOURCC)
+            .then(|| u64::from_le_bytes(trailer[..8].try_into().expect("cannot fail")))
+            .filter(|&len| Self::TRAILER_SIZE_BYTES as u64 <= len)
+    }
+
+    /// The size of the whole indexed stream, given the size of its end-of-stream payload.
+    pub fn stream_len(&self, payload_len: u64) -> u64 {
+        self.end_offset + MessageHeader::ENCODED_SIZE_BYTES as u64 + payload_len
+    }
+}
+
+impl Encodable for ChunkIndex {
+    /// Serializes the trailer too!
+    fn to_rrd_bytes(&self, out: &mut Vec<u8>) -> Result<u64, CodecError> {
+        let Self {
+            end_offset,
+            entries,
+        } = self;
+
+        let before = out.len() as u64;
+
+        out.extend_from_slice(&Self::FOURCC);
+        out.push(Self::VERSION);
+        out.extend_from_slice(&end_offset.to_le_bytes());
+        out.extend_from_slice(&(entries.len() as u64).to_le_bytes());
+        for entry in entries {
+            let ChunkIndexEntry {
+                byte_span,
+                kind,
+                is_static,
+                entity_path,
+                time_ranges,
+            } = entry;
+
+            out.extend_from_slice(&byte_span.start.to_le_bytes());
+            out.extend_from_slice(&byte_span.len.to_le_bytes());
+            out.extend_from_slice(&(*kind as u64).to_le_bytes());
+            out.push(*is_static as u8);
+
+            // An empty string stands for `None`: actual entity paths are at least `/`.
+            let entity_path = entity_path
+                .as_ref()
+                .map(|entity_path| entity_path.to_string())
+                .unwrap_or_default();
+            out.extend_from_slice(&(entity_path.len() as u64).to_le_bytes());
+            out.extend_from_slice(entity_path.as_bytes());
+
+            out.extend_from_slice(&(time_ranges.len() as u64).to_le_bytes());
+            for (timeline, range) in time_ranges {
+                let name = timeline.as_str().as_bytes();
+                out.extend_from_slice(&(name.len() as u64).to_le_bytes());
+                out.extend_from_slice(name);
+                out.extend_from_slice(&range.min().as_i64().to_le_bytes());
+                out.extend_from_slice(&range.max().as_i64().to_le_bytes());
+            }
+        }
+
+        let payload_len = out.len() as u64 - before + Self::TRAILER_SIZE_BYTES as u64;
+        out.extend_from_slice(&payload_len.to_le_bytes());
+        out.extend_from_slice(&Self::FOURCC);
+
+        Ok(payload_len)
+    }
+}
+
+impl Decodable for ChunkIndex {
+    /// This expects `data` to carry the trailer too!
+    fn from_rrd_bytes(data: &[u8]) -> Result<Self, CodecError> {
+        let payload_len = data
+            .len()
+            .checked_sub(Self::TRAILER_SIZE_BYTES)
+            .and_then(|start| Self::payload_len_from_trailer(&data[start..]));
+        if payload_len != Some(data.len() as u64) {
+            return Err(CodecError::ChunkIndexDecoding(
+                "missing or invalid trailer".to_owned(),
+            ));
+        }
+
+        let mut reader = Reader {
+            data: &data[..data.len() - Self::TRAILER_SIZE_BYTES],
+        };
+
+        // Unversioned indices start right away with the end offset, which would need to be
+        // at least 1.4 GiB, with very specific low bits, to be mistaken for the header.
+        let version = if reader.data.starts_with(&Self::FOURCC) {
+            reader.bytes(Self::FOURCC.len() as u64)?;
+            reader.bytes(1)?[0]
+        } else {
+            0
+        };
+        if Self::VERSION < version {
+            return Err(CodecError::ChunkIndexDecoding(format!(
+                "unsupported index version {version}, the newest supported one is {}",
+                Self::VERSION
+            )));
+        }
+
+        let end_offset = reader.u64()?;
+        let num_entries = reader.u64()?;
+        let mut entries = Vec::new();
+        for _ in 0..num_entries {
+            let start = reader.u64()?;
+            let len = reader.u64()?;
+            let kind = MessageKind::try_from(reader.u64()?)?;
+            let is_static = reader.bytes(1)?[0] != 0;
+
+            let entity_path = if 1 <= version {
+                let entity_path_len = reader.u64()?;
+                let entity_path = reader.str(entity_path_len, "entity path")?;
+                (!entity_path.is_empty()).then(|| EntityPath::parse_forgiving(entity_path))
+            } else {
+                None
+            };
+
+            let num_timelines = reader.u64()?;
+            let mut time_ranges = Vec::new();
+            for _ in 0..num_timelines {
+                let name_len = reader.u64()?;
+                let name = reader.str(name_len, "timeline name")?;
+                let min = i64::from_le_bytes(reader.array()?);
+                let max = i64::from_le_bytes(reader.array()?);
+                time_ranges.push((TimelineName::new(name), AbsoluteTimeRange::new(min, max)));
+            }
+
+            entries.push(ChunkIndexEntry {
+                byte_span: re_chunk::Span { start, len },
+                kind,
+                is_static,
+                entity_path,
+                time_ranges,
+            });
+        }
+
+        Ok(Self {
+            end_offset,
+            entries,
+        })
+    }
+}
+
+/// Reads little-endian values out of a byte slice, failing on truncated data.
+struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, n: u64) -> Result<&'a [u8], CodecError> {
+        let n = usize::try_from(n)
+            .ok()
+            .filter(|&n| n <= self.data.len())
+            .ok_or_else(|| CodecError::ChunkIndexDecoding("unexpected end of data".to_owned()))?;
+        let (head, tail) = self.data.split_at(n);
+        self.data = tail;
+        Ok(head)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], CodecError> {
+        Ok(self
+            .bytes(N as u64)?
+            .try_into()
+            .expect("cannot fail, checked above"))
+    }
+
+    fn u64(&mut self) -> Result<u64, CodecError> {
+        Ok(u64::from_le_bytes(self.array()?))
+    }
+
+    fn str(&mut self, n: u64, what: &str) -> Result<&'a str, CodecError> {
+        std::str::from_utf8(self.bytes(n)?)
+            .map_err(|err| CodecError::ChunkIndexDecoding(format!("invalid {what}: {err}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let index = ChunkIndex {
+            end_offset: 1234,
+            entries: vec![
+                ChunkIndexEntry {
+                    byte_span: re_chunk::Span {
+                        start: 12,
+                        len: 100,
+                    },
+                    kind: MessageKind::SetStoreInfo,
+                    is_static: false,
+                    entity_path: None,
+                    time_ranges: vec![],
+                },
+                ChunkIndexEntry {
+                    byte_span: re_chunk::Span {
+                        start: 112,
+                        len: 1122,
+                    },
+                    kind: MessageKind::ArrowMsg,
+                    is_static: false,
+                    entity_path: Some(EntityPath::from("world/points")),
+                    time_ranges: vec![
+                        (TimelineName::new("frame"), AbsoluteTimeRange::new(10, 20)),
+                        (TimelineName::log_time(), AbsoluteTimeRange::new(-5, 5)),
+                    ],
+                },
+            ],
+        };
+
+        let mut payload = Vec::new();
+        let payload_len = index.to_rrd_bytes(&mut payload).unwrap();
+        assert_eq!(payload_len, payload.len() as u64);
+
+        let trailer = &payload[payload.len() - ChunkIndex::TRAILER_SIZE_BYTES..];
+        assert_eq!(
+            ChunkIndex::payload_len_from_trailer(trailer),
+            Some(payload_len)
+        );
+        assert_eq!(ChunkIndex::from_rrd_bytes(&payload).unwrap(), index);
+        assert!(ChunkIndex::from_rrd_bytes(&payload[1..]).is_err());
+
+        let frame = TimelineName::new("frame");
+        let entry = &index.entries[1];
+        assert_eq!(entry.distance_to(&frame, TimeInt::new_temporal(15)), 0);
+        assert_eq!(entry.distance_to(&frame, TimeInt::new_temporal(7)), 3);
+        assert_eq!(entry.distance_to(&frame, TimeInt::new_temporal(25)), 5);
+        assert_eq!(index.entries[0].distance_to(&frame, TimeInt::MAX), 0);
+
+        assert!(entry.intersects(&frame, AbsoluteTimeRange::new(20, 30)));
+        assert!(!entry.intersects(&frame, AbsoluteTimeRange::new(21, 30)));
+        assert!(index.entries[0].intersects(&frame, AbsoluteTimeRange::new(21, 30)));
+    }
+
+    #[test]
+    fn versions() {
+        // An index written before versioning was introduced, without entity paths.
+        let mut legacy = Vec::new();
+        legacy.extend_from_slice(&1234_u64.to_le_bytes()); // end offset
+        legacy.extend_from_slice(&1_u64.to_le_bytes()); // number of entries
+        legacy.extend_from_slice(&12_u64.to_le_bytes()); // start
+        legacy.extend_from_slice(&100_u64.to_le_bytes()); // len
+        legacy.extend_from_slice(&(MessageKind::ArrowMsg as u64).to_le_bytes());
+        legacy.push(1); // static
+        legacy.extend_from_slice(&0_u64.to_le_bytes()); // number of timelines
+        let payload_len = (legacy.len() + ChunkIndex::TRAILER_SIZE_BYTES) as u64;
+        legacy.extend_from_slice(&payload_len.to_le_bytes());
+        legacy.extend_from_slice(&ChunkIndex::FOURCC);
+
+        let index = ChunkIndex {
+            end_offset: 1234,
+            entries: vec![ChunkIndexEntry {
+                byte_span: re_chunk::Span {
+                    start: 12,
+                    len: 100,
+                },
+                kind: MessageKind::ArrowMsg,
+                is_static: true,
+                entity_path: None,
+                time_ranges: vec![],
+            }],
+        };
+        assert_eq!(ChunkIndex::from_rrd_bytes(&legacy).unwrap(), index);
+
+        let mut payload = Vec::new();
+        index.to_rrd_bytes(&mut payload).unwrap();
+        assert_eq!(payload[..4], ChunkIndex::FOURCC);
+        assert_eq!(payload[4], ChunkIndex::VERSION);
+
+        // Indices written by newer versions of Rerun are rejected.
+        payload[4] = ChunkIndex::VERSION + 1;
+        assert!(ChunkIndex::from_rrd_bytes(&payload).is_err());
+    }
+}
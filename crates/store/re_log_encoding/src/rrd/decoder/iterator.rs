@@ -177,7 +177,7 @@ mod tests {
     use re_protos::log_msg::v1alpha1 as proto;
     use re_protos::log_msg::v1alpha1::log_msg::Msg as LogMsgProto;
 
-    use crate::rrd::{Compression, DecoderApp, EncodingOptions, Serializer};
+    use crate::rrd::{Compression, DecoderApp, EncodingOptions, PendingLogMsg, Serializer};
     use crate::{Encoder, ToTransport as _};
 
     fn fake_log_messages() -> Vec<LogMsg> {
@@ -309,10 +309,17 @@ mod tests {
             EncodingOptions {
                 compression: Compression::Off,
                 serializer: Serializer::Protobuf,
+                checksums: false,
             },
             EncodingOptions {
                 compression: Compression::LZ4,
                 serializer: Serializer::Protobuf,
+                checksums: false,
+            },
+            EncodingOptions {
+                compression: Compression::LZ4,
+                serializer: Serializer::Protobuf,
+                checksums: true,
             },
         ];
 
@@ -360,6 +367,156 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_encode_decode_with_chunk_index() {
+        use crate::rrd::{ChunkIndex, Decodable as _, MessageKind};
+
+        let messages = fake_log_messages();
+
+        let mut file = vec![];
+        let mut encoder = Encoder::new_eager(
+            CrateVersion::LOCAL,
+            EncodingOptions::PROTOBUF_COMPRESSED,
+            &mut file,
+        )
+        .unwrap()
+        .with_chunk_index();
+        for message in &messages {
+            encoder.append(message).unwrap();
+        }
+        drop(encoder);
+
+        // The index must be transparent to decoders…
+        let decoded_messages: Vec<_> = DecoderApp::decode_lazy(file.as_slice())
+            .map(Result::unwrap)
+            .collect();
+        similar_asserts::assert_eq!(decoded_messages, messages);
+
+        // …while being reachable from the tail of the file alone.
+        let trailer = &file[file.len() - ChunkIndex::TRAILER_SIZE_BYTES..];
+        let payload_len = ChunkIndex::payload_len_from_trailer(trailer).unwrap();
+        let index = ChunkIndex::from_rrd_bytes(&file[file.len() - payload_len as usize..]).unwrap();
+        assert_eq!(index.stream_len(payload_len), file.len() as u64);
+
+        let kinds: Vec<_> = index.entries.iter().map(|entry| entry.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                MessageKind::SetStoreInfo,
+                MessageKind::ArrowMsg,
+                MessageKind::BlueprintActivationCommand
+            ]
+        );
+        for (entry, next) in index.entries.iter().zip(index.entries.iter().skip(1)) {
+            assert_eq!(entry.byte_span.end(), next.byte_span.start);
+        }
+        assert_eq!(
+            index.entries.last().unwrap().byte_span.end(),
+            index.end_offset
+        );
+
+        let (timeline, _) = &index.entries[1].time_ranges[0];
+        assert_eq!(timeline.as_str(), "blueprint");
+    }
+
+    /// Test that streams with checksums can be read up to their last complete message, like
+    /// what a crashed writer would leave behind.
+    #[test]
+    fn test_decode_torn_write() {
+        let rrd_version = CrateVersion::LOCAL;
+
+        let messages = fake_log_messages();
+        let options = EncodingOptions::PROTOBUF_COMPRESSED_CHECKSUMMED;
+
+        let mut file = vec![];
+        crate::Encoder::encode_into_without_eos(
+            rrd_version,
+            options,
+            messages.iter().map(Ok),
+            &mut file,
+        )
+        .unwrap();
+
+        // The last message was only partially written…
+        let mut truncated = file.clone();
+        truncated.truncate(file.len() - 3);
+
+        // …or was, but got garbled.
+        let mut garbled = file.clone();
+        let len = garbled.len();
+        garbled[len - 3..].fill(0);
+
+        for file in [truncated, garbled] {
+            let decoded_messages: Vec<_> = DecoderApp::decode_lazy(file.as_slice())
+                .map(Result::unwrap)
+                .collect();
+            similar_asserts::assert_eq!(
+                decoded_messages.as_slice(),
+                &messages[..messages.len() - 1]
+            );
+        }
+    }
+
+    /// Test that streams using flags from the future are refused, rather than misread.
+    #[test]
+    fn test_decode_unknown_flags() {
+        let mut file = vec![];
+        crate::Encoder::encode_into(
+            CrateVersion::LOCAL,
+            EncodingOptions::PROTOBUF_COMPRESSED_CHECKSUMMED,
+            fake_log_messages().iter().map(Ok),
+            &mut file,
+        )
+        .unwrap();
+
+        // The flags byte of the options, right after the FourCC and the version.
+        file[10] |= 0b1000_0000;
+
+        let err = DecoderApp::decode_lazy(file.as_slice())
+            .find_map(Result::err)
+            .unwrap();
+        assert!(
+            matches!(
+                err,
+                DecodeError::Codec(crate::rrd::CodecError::InvalidOptions(
+                    crate::rrd::OptionsError::UnknownFlags(0b1000_0001)
+                ))
+            ),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_decode_pending() {
+        let rrd_version = CrateVersion::LOCAL;
+
+        let messages = fake_log_messages();
+
+        let mut file = vec![];
+        crate::Encoder::encode_into(
+            rrd_version,
+            EncodingOptions::PROTOBUF_COMPRESSED,
+            messages.iter().map(Ok),
+            &mut file,
+        )
+        .unwrap();
+
+        let pending_messages: Vec<_> =
+            crate::rrd::Decoder::<PendingLogMsg>::decode_lazy(file.as_slice())
+                .map(Result::unwrap)
+                .collect();
+        assert!(matches!(pending_messages[1], PendingLogMsg::ArrowMsg(..)));
+
+        // Finish decoding in reverse order, to make sure it doesn't depend on any stream state.
+        let mut decoded_messages: Vec<_> = pending_messages
+            .into_iter()
+            .rev()
+            .map(|msg| msg.finish().unwrap())
+            .collect();
+        decoded_messages.reverse();
+        similar_asserts::assert_eq!(decoded_messages, messages);
+    }
+
     /// Test that legacy messages (aka `StoreId` without an application id) are properly decoded.
     #[test]
     fn test_decode_legacy() {
@@ -448,10 +605,12 @@ mod tests {
             EncodingOptions {
                 compression: Compression::Off,
                 serializer: Serializer::Protobuf,
+                checksums: false,
             },
             EncodingOptions {
                 compression: Compression::LZ4,
                 serializer: Serializer::Protobuf,
+                checksums: false,
             },
         ];
 
@@ -482,10 +641,12 @@ mod tests {
             EncodingOptions {
                 compression: Compression::Off,
                 serializer: Serializer::Protobuf,
+                checksums: false,
             },
             EncodingOptions {
                 compression: Compression::LZ4,
                 serializer: Serializer::Protobuf,
+                checksums: false,
             },
         ];
 
@@ -3,10 +3,12 @@
 pub mod state_machine;
 
 mod iterator;
+mod seek;
 mod stream;
 
 pub use self::{
     iterator::DecoderIterator,
+    seek::DecoderSeekIterator,
     state_machine::{Decoder, DecoderApp, DecoderTransport},
     stream::DecoderStream,
 };
@@ -37,9 +39,10 @@ use crate::rrd::Decodable as _;
 
 /// Implemented for top-level types that can kickoff decoding.
 ///
-/// There are only two of them in this crate:
+/// There are only three of them in this crate:
 /// * [`re_log_types::LogMsg`]: application-level root message
 /// * [`re_protos::log_msg::v1alpha1::log_msg::Msg`]: transport-level root message
+/// * [`PendingLogMsg`]: application-level root message, minus the Arrow payload
 ///
 /// This can be used to generically instantiate transport- and/or application-level decoders.
 /// See also:
@@ -107,3 +110,70 @@ impl DecoderEntrypoint for re_protos::log_msg::v1alpha1::log_msg::Msg {
         }))
     }
 }
+
+/// An application-level [`re_log_types::LogMsg`] whose Arrow payload might not be decoded yet.
+///
+/// Decompressing and decoding Arrow payloads is by far the most expensive part of reading an RRD
+/// stream, but unlike the rest of the decoding process it doesn't depend on any state carried
+/// across messages.
+/// Decoding into a [`PendingLogMsg`] leaves that part to [`PendingLogMsg::finish`], which can then
+/// run on any thread, in any order.
+#[derive(Debug)]
+pub enum PendingLogMsg {
+    Decoded(re_log_types::LogMsg),
+    ArrowMsg(
+        re_log_types::StoreId,
+        re_protos::log_msg::v1alpha1::ArrowMsg,
+    ),
+}
+
+impl PendingLogMsg {
+    /// Finishes decoding the message, if needed.
+    pub fn finish(self) -> Result<re_log_types::LogMsg, crate::rrd::CodecError> {
+        match self {
+            Self::Decoded(msg) => Ok(msg),
+            Self::ArrowMsg(store_id, arrow_msg) => {
+                let arrow_msg = crate::transport_to_app::arrow_msg_to_app(&arrow_msg)?;
+                Ok(re_log_types::LogMsg::ArrowMsg(store_id, arrow_msg))
+            }
+        }
+    }
+}
+
+impl DecoderEntrypoint for PendingLogMsg {
+    fn decode(
+        data_excluding_headers: bytes::Bytes,
+        byte_span_excluding_headers: re_chunk::Span<u64>,
+        message_kind: crate::rrd::MessageKind,
+        app_id_injector: &mut impl ApplicationIdInjector,
+        patched_version: Option<CrateVersion>,
+    ) -> Result<Option<Self>, crate::rrd::CodecError> {
+        use re_protos::log_msg::v1alpha1::log_msg::Msg;
+
+        let Some(log_msg) = Msg::decode(
+            data_excluding_headers,
+            byte_span_excluding_headers,
+            message_kind,
+            app_id_injector,
+            patched_version,
+        )?
+        else {
+            return Ok(None);
+        };
+
+        match log_msg {
+            // The store ID must be resolved in stream order, but the Arrow payload can wait.
+            Msg::ArrowMsg(arrow_msg) => {
+                let store_id = crate::transport_to_app::arrow_msg_store_id_to_app(
+                    app_id_injector,
+                    &arrow_msg,
+                )?;
+                Ok(Some(Self::ArrowMsg(store_id, arrow_msg)))
+            }
+
+            log_msg => log_msg
+                .to_application((app_id_injector, patched_version))
+                .map(|msg| Some(Self::Decoded(msg))),
+        }
+    }
+}
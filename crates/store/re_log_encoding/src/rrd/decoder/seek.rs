@@ -0,0 +1,280 @@
+use std::io::{Read, Seek, SeekFrom};
+
+use crate::rrd::{
+    ChunkIndex, ChunkIndexEntry, Decodable as _, DecodeError, Decoder, DecoderEntrypoint,
+    StreamHeader,
+};
+
+/// Adjacent messages are read in one go, up to this many bytes at a time.
+const MAX_READ_BYTES: u64 = 8 * 1024 * 1024;
+
+impl ChunkIndex {
+    /// Reads the [`ChunkIndex`] at the end of a seekable RRD stream, if any.
+    ///
+    /// Returns `None` if the stream isn't indexed, or if the index doesn't cover the whole stream
+    /// (e.g. concatenated RRD streams, where only the last one is indexed).
+    ///
+    /// Only the tail of the stream is read.
+    pub fn read_from<R: Read + Seek>(reader: &mut R) -> Result<Option<Self>, DecodeError> {
+        re_tracing::profile_function!();
+
+        let stream_len = reader.seek(SeekFrom::End(0))?;
+        let Some(trailer_start) = stream_len.checked_sub(Self::TRAILER_SIZE_BYTES as u64) else {
+            return Ok(None);
+        };
+
+        let trailer = read_span(reader, trailer_start, Self::TRAILER_SIZE_BYTES as u64)?;
+        let Some(payload_len) = Self::payload_len_from_trailer(&trailer) else {
+            return Ok(None);
+        };
+        let Some(payload_start) = stream_len.checked_sub(payload_len) else {
+            return Ok(None);
+        };
+
+        let payload = read_span(reader, payload_start, payload_len)?;
+        let index = Self::from_rrd_bytes(&payload)?;
+
+        Ok((index.stream_len(payload_len) == stream_len).then_some(index))
+    }
+}
+
+impl<T: DecoderEntrypoint> Decoder<T> {
+    /// Decodes only the messages of an indexed RRD stream that `filter` selects, by seeking
+    /// directly to them rather than scanning the whole stream.
+    ///
+    /// `index` must have been read from that very same stream, see [`ChunkIndex::read_from`].
+    ///
+    /// Messages are decoded in stream order. Keep in mind that chunks can only be decoded once the
+    /// `SetStoreInfo` of their recording has been, for legacy streams.
+    pub fn decode_indexed<R: Read + Seek>(
+        reader: R,
+        index: &ChunkIndex,
+        filter: impl Fn(&ChunkIndexEntry) -> bool,
+    ) -> DecoderSeekIterator<T, R> {
+        let mut spans = vec![re_chunk::Span {
+            start: 0,
+            len: StreamHeader::ENCODED_SIZE_BYTES as u64,
+        }];
+
+        for entry in index.entries.iter().filter(|entry| filter(entry)) {
+            let span = entry.byte_span;
+            match spans.last_mut() {
+                Some(last) if last.end() == span.start && last.len + span.len <= MAX_READ_BYTES => {
+                    last.len += span.len;
+                }
+                _ => spans.push(span),
+            }
+        }
+
+        DecoderSeekIterator {
+            decoder: Self::new(),
+            reader,
+            spans: spans.into_iter(),
+        }
+    }
+}
+
+// ---
+
+/// Decodes the messages of a seekable reader that live within a given set of byte spans.
+///
+/// See [`Decoder::decode_indexed`].
+pub struct DecoderSeekIterator<T, R> {
+    decoder: Decoder<T>,
+    reader: R,
+
+    /// What's left to read, in stream order.
+    spans: std::vec::IntoIter<re_chunk::Span<u64>>,
+}
+
+impl<T, R> DecoderSeekIterator<T, R> {
+    pub fn num_bytes_processed(&self) -> u64 {
+        self.decoder.byte_chunks.num_read() as _
+    }
+}
+
+impl<T: DecoderEntrypoint, R: Read + Seek> std::iter::Iterator for DecoderSeekIterator<T, R> {
+    type Item = Result<T, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.decoder.try_read() {
+                Ok(Some(msg)) => return Some(Ok(msg)),
+                Ok(None) => {}
+                Err(err) => return Some(Err(err)),
+            }
+
+            let span = self.spans.next()?;
+            match read_span(&mut self.reader, span.start, span.len) {
+                Ok(bytes) => self.decoder.push_byte_chunk(bytes),
+                Err(err) => return Some(Err(err.into())),
+            }
+        }
+    }
+}
+
+fn read_span<R: Read + Seek>(reader: &mut R, start: u64, len: u64) -> std::io::Result<Vec<u8>> {
+    let len = usize::try_from(len).map_err(std::io::Error::other)?;
+    let mut bytes = vec![0; len];
+    reader.seek(SeekFrom::Start(start))?;
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+// ---
+
+#[cfg(all(test, feature = "encoder"))]
+mod tests {
+    #![expect(clippy::unwrap_used)] // tests
+
+    use re_build_info::CrateVersion;
+    use re_chunk::{Chunk, RowId};
+    use re_log_types::{
+        AbsoluteTimeRange, EntityPath, LogMsg, SetStoreInfo, StoreId, StoreInfo, StoreKind,
+        StoreSource, TimePoint, Timeline, TimelineName,
+    };
+
+    use crate::rrd::{ChunkIndex, DecoderApp, EncodingOptions};
+
+    fn points_at(entity_path: &str, frames: &[i64]) -> Chunk {
+        let mut builder = Chunk::builder(entity_path);
+        for &frame in frames {
+            builder = builder.with_archetype(
+                RowId::new(),
+                TimePoint::default().with(Timeline::new_sequence("frame"), frame),
+                &re_types::archetypes::Points3D::new([[0.0, 0.0, 0.0]]),
+            );
+        }
+        builder.build().unwrap()
+    }
+
+    #[test]
+    fn decode_time_range() {
+        let store_id = StoreId::random(StoreKind::Recording, "test_app");
+        let chunks = [
+            points_at("a", &[0, 5, 9]),
+            points_at("b", &[10, 15]),
+            points_at("a", &[20, 25]),
+            Chunk::builder("static")
+                .with_archetype(
+                    RowId::new(),
+                    TimePoint::default(),
+                    &re_types::archetypes::Points3D::new([[0.0, 0.0, 0.0]]),
+                )
+                .build()
+                .unwrap(),
+        ];
+
+        let mut messages = vec![LogMsg::SetStoreInfo(SetStoreInfo {
+            row_id: *RowId::new(),
+            info: StoreInfo::new(
+                store_id.clone(),
+                StoreSource::RustSdk {
+                    rustc_version: String::new(),
+                    llvm_version: String::new(),
+                },
+            ),
+        })];
+        messages.extend(
+            chunks
+                .iter()
+                .map(|chunk| LogMsg::ArrowMsg(store_id.clone(), chunk.to_arrow_msg().unwrap())),
+        );
+
+        let mut file = vec![];
+        {
+            let mut encoder = crate::Encoder::new_eager(
+                CrateVersion::LOCAL,
+                EncodingOptions::PROTOBUF_COMPRESSED,
+                &mut file,
+            )
+            .unwrap()
+            .with_chunk_index();
+            for message in &messages {
+                encoder.append(message).unwrap();
+            }
+        }
+
+        let mut reader = std::io::Cursor::new(file.as_slice());
+        let index = ChunkIndex::read_from(&mut reader).unwrap().unwrap();
+        assert_eq!(index.entries[1].entity_path, Some(EntityPath::from("a")));
+
+        let frame = TimelineName::new("frame");
+        let range = AbsoluteTimeRange::new(12, 20);
+        let decoded: Vec<_> =
+            DecoderApp::decode_indexed(reader, &index, |entry| entry.intersects(&frame, range))
+                .map(Result::unwrap)
+                .collect();
+
+        // Everything but the first chunk: it is entirely out of range.
+        assert_eq!(decoded.len(), messages.len() - 1);
+        assert_eq!(decoded[0], messages[0]);
+        similar_asserts::assert_eq!(decoded[1..], messages[2..]);
+
+        // Unindexed streams are reported as such.
+        let mut file = vec![];
+        crate::Encoder::encode_into(
+            CrateVersion::LOCAL,
+            EncodingOptions::PROTOBUF_COMPRESSED,
+            messages.iter().map(Ok),
+            &mut file,
+        )
+        .unwrap();
+        let mut reader = std::io::Cursor::new(file.as_slice());
+        assert!(ChunkIndex::read_from(&mut reader).unwrap().is_none());
+    }
+
+    #[test]
+    fn decode_with_checksums() {
+        let store_id = StoreId::random(StoreKind::Recording, "test_app");
+        let mut messages = vec![LogMsg::SetStoreInfo(SetStoreInfo {
+            row_id: *RowId::new(),
+            info: StoreInfo::new(
+                store_id.clone(),
+                StoreSource::RustSdk {
+                    rustc_version: String::new(),
+                    llvm_version: String::new(),
+                },
+            ),
+        })];
+        messages.extend(
+            [points_at("a", &[0, 1]), points_at("b", &[2, 3])]
+                .iter()
+                .map(|chunk| LogMsg::ArrowMsg(store_id.clone(), chunk.to_arrow_msg().unwrap())),
+        );
+
+        let mut file = vec![];
+        {
+            let mut encoder = crate::Encoder::new_eager(
+                CrateVersion::LOCAL,
+                EncodingOptions {
+                    checksums: true,
+                    ..EncodingOptions::PROTOBUF_COMPRESSED
+                },
+                &mut file,
+            )
+            .unwrap()
+            .with_chunk_index();
+            for message in &messages {
+                encoder.append(message).unwrap();
+            }
+        }
+
+        // The byte spans of the index include the checksums.
+        let mut reader = std::io::Cursor::new(file.as_slice());
+        let index = ChunkIndex::read_from(&mut reader).unwrap().unwrap();
+        let decoded: Vec<_> = DecoderApp::decode_indexed(reader, &index, |_| true)
+            .map(Result::unwrap)
+            .collect();
+        similar_asserts::assert_eq!(decoded, messages);
+
+        // Skip the first chunk.
+        let reader = std::io::Cursor::new(file.as_slice());
+        let decoded: Vec<_> = DecoderApp::decode_indexed(reader, &index, |entry| {
+            entry.entity_path != Some(EntityPath::from("a"))
+        })
+        .map(Result::unwrap)
+        .collect();
+        similar_asserts::assert_eq!(decoded, [messages[0].clone(), messages[2].clone()]);
+    }
+}
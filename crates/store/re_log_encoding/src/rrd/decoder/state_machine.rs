@@ -215,9 +215,37 @@ impl<T: DecoderEntrypoint> Decoder<T> {
             DecoderState::Message(header) => {
                 let start_offset = self.byte_chunks.num_read() as u64;
 
-                if let Some(bytes) = self.byte_chunks.try_read(header.len as usize) {
+                let has_checksum =
+                    self.options.checksums && header.kind != crate::rrd::MessageKind::End;
+                let checksum_len = if has_checksum {
+                    crate::rrd::MessageHeader::CHECKSUM_SIZE_BYTES
+                } else {
+                    0
+                };
+
+                if let Some(bytes) = self
+                    .byte_chunks
+                    .try_read(header.len as usize + checksum_len)
+                {
                     re_log::trace!(?header, "Read message");
 
+                    let bytes = if has_checksum {
+                        let Some(bytes) = verify_checksum(header, bytes) else {
+                            // This is what the torn write of a crashed writer looks like: there's
+                            // nothing trustworthy left past this point.
+                            re_log::warn!(
+                                position = start_offset,
+                                "Corrupt message in rrd stream, ignoring the rest of it (did the \
+                                writer crash?)"
+                            );
+                            self.state = DecoderState::Aborted;
+                            return Ok(None);
+                        };
+                        bytes
+                    } else {
+                        bytes
+                    };
+
                     let bytes_len = bytes.len() as u64;
                     let byte_span = re_chunk::Span {
                         start: start_offset,
@@ -265,6 +293,28 @@ impl<T: DecoderEntrypoint> Decoder<T> {
     }
 }
 
+/// Strips the checksum off of a message, as long as it matches.
+///
+/// See [`EncodingOptions::checksums`].
+fn verify_checksum(
+    header: crate::rrd::MessageHeader,
+    mut bytes: bytes::Bytes,
+) -> Option<bytes::Bytes> {
+    use crate::rrd::Encodable as _;
+
+    let checksum = bytes.split_off(header.len as usize);
+    let checksum = u32::from_le_bytes(checksum.as_ref().try_into().ok()?);
+
+    let mut header_bytes = Vec::with_capacity(crate::rrd::MessageHeader::ENCODED_SIZE_BYTES);
+    header.to_rrd_bytes(&mut header_bytes).ok()?;
+
+    let mut hasher = crc32fast::Hasher::new();
+    hasher.update(&header_bytes);
+    hasher.update(&bytes);
+
+    (hasher.finalize() == checksum).then_some(bytes)
+}
+
 // ---
 
 /// A bunch of contiguous bytes.
@@ -246,10 +246,12 @@ mod tests {
             EncodingOptions {
                 compression: Compression::Off,
                 serializer: Serializer::Protobuf,
+                checksums: false,
             },
             EncodingOptions {
                 compression: Compression::LZ4,
                 serializer: Serializer::Protobuf,
+                checksums: false,
             },
         ];
 
@@ -281,10 +283,12 @@ mod tests {
             EncodingOptions {
                 compression: Compression::Off,
                 serializer: Serializer::Protobuf,
+                checksums: false,
             },
             EncodingOptions {
                 compression: Compression::LZ4,
                 serializer: Serializer::Protobuf,
+                checksums: false,
             },
         ];
 
@@ -8,8 +8,8 @@ use re_log_types::LogMsg;
 
 use crate::ToTransport as _;
 use crate::rrd::{
-    CodecError, Compression, Encodable as _, EncodingOptions, MessageHeader, MessageKind,
-    Serializer, StreamHeader,
+    ChunkIndex, ChunkIndexEntry, CodecError, Compression, Encodable as _, EncodingOptions,
+    MessageHeader, MessageKind, Serializer, StreamHeader,
 };
 
 // ----------------------------------------------------------------------------
@@ -60,6 +60,7 @@ impl From<ChunkError> for EncodeError {
 pub struct Encoder<W: std::io::Write> {
     serializer: Serializer,
     compression: Compression,
+    checksums: bool,
 
     /// Optional so that we can `take()` it in `into_inner`, while still being allowed to implement `Drop`.
     write: Option<W>,
@@ -69,6 +70,14 @@ pub struct Encoder<W: std::io::Write> {
 
     /// Tracks whether the end-of-stream marker has been written out already.
     is_finished: bool,
+
+    /// How many bytes have been written so far, [`StreamHeader`] included.
+    num_written: u64,
+
+    /// If set, written out as part of the end-of-stream marker.
+    ///
+    /// See [`Self::with_chunk_index`].
+    chunk_index: Option<ChunkIndex>,
 }
 
 impl Encoder<Vec<u8>> {
@@ -125,12 +134,25 @@ impl<W: std::io::Write> Encoder<W> {
         Ok(Self {
             serializer: options.serializer,
             compression: options.compression,
+            checksums: options.checksums,
             write: Some(write),
             scratch: Vec::new(),
             is_finished: false,
+            num_written: out.len() as u64,
+            chunk_index: None,
         })
     }
 
+    /// Keep track of every appended message, and write a [`ChunkIndex`] as part of the
+    /// end-of-stream marker.
+    ///
+    /// This lets readers fetch only the parts of the stream they're interested in (e.g. the
+    /// viewer streaming a large file over HTTP), at the cost of a few bytes per message.
+    pub fn with_chunk_index(mut self) -> Self {
+        self.chunk_index = Some(ChunkIndex::default());
+        self
+    }
+
     /// Returns the size in bytes of the encoded data.
     pub fn append(&mut self, message: &re_log_types::LogMsg) -> Result<u64, EncodeError> {
         if self.is_finished {
@@ -143,12 +165,35 @@ impl<W: std::io::Write> Encoder<W> {
 
         re_tracing::profile_function!();
 
-        let message = message.to_transport(self.compression)?;
+        let transport = message.to_transport(self.compression)?;
         // Safety: the compression settings of this message are consistent with this stream.
         #[expect(unsafe_code)]
-        unsafe {
-            self.append_transport(&message)
+        let size_bytes = unsafe { self.append_transport(&transport)? };
+
+        // Only application-level messages tell us what time ranges a chunk covers.
+        if let re_log_types::LogMsg::ArrowMsg(_, arrow_msg) = message
+            && let Some(entry) = self
+                .chunk_index
+                .as_mut()
+                .and_then(|index| index.entries.last_mut())
+        {
+            match re_chunk::Chunk::from_arrow_msg(arrow_msg) {
+                Ok(chunk) => {
+                    entry.is_static = chunk.is_static();
+                    entry.entity_path = Some(chunk.entity_path().clone());
+                    entry.time_ranges = chunk
+                        .timelines()
+                        .iter()
+                        .map(|(timeline, time_column)| (*timeline, time_column.time_range()))
+                        .collect();
+                }
+                Err(err) => {
+                    re_log::debug_once!("Failed to index chunk {}: {err}", arrow_msg.chunk_id);
+                }
+            }
         }
+
+        Ok(size_bytes)
     }
 
     /// Returns the size in bytes of the encoded data.
@@ -176,11 +221,39 @@ impl<W: std::io::Write> Encoder<W> {
         match self.serializer {
             Serializer::Protobuf => {
                 message.to_rrd_bytes(&mut self.scratch)?;
-                w.write_all(&self.scratch)
-                    .map(|_| self.scratch.len() as _)
-                    .map_err(EncodeError::Write)
+                if self.checksums {
+                    let checksum = crc32fast::hash(&self.scratch);
+                    self.scratch.extend_from_slice(&checksum.to_le_bytes());
+                }
+                w.write_all(&self.scratch)?;
             }
         }
+
+        let len = self.scratch.len() as u64;
+        if let Some(index) = &mut self.chunk_index {
+            let kind = match message {
+                re_protos::log_msg::v1alpha1::log_msg::Msg::SetStoreInfo(_) => {
+                    MessageKind::SetStoreInfo
+                }
+                re_protos::log_msg::v1alpha1::log_msg::Msg::ArrowMsg(_) => MessageKind::ArrowMsg,
+                re_protos::log_msg::v1alpha1::log_msg::Msg::BlueprintActivationCommand(_) => {
+                    MessageKind::BlueprintActivationCommand
+                }
+            };
+            index.entries.push(ChunkIndexEntry {
+                byte_span: re_chunk::Span {
+                    start: self.num_written,
+                    len,
+                },
+                kind,
+                is_static: false,
+                entity_path: None,
+                time_ranges: Vec::new(),
+            });
+        }
+        self.num_written += len;
+
+        Ok(len)
     }
 
     /// Appends an end-of-stream marker to the encoded bytes. Does not flush.
@@ -190,6 +263,7 @@ impl<W: std::io::Write> Encoder<W> {
     /// This end-of-stream marker is currently (seemingly?) relied on for:
     /// * Tail mode (where the Viewer continuously poll reads from a file on disk).
     /// * Concatenated RRD file streams (e.g. `cat *.rrd | rerun -`).
+    /// * Locating the [`ChunkIndex`], if any (see [`Self::with_chunk_index`]).
     #[inline]
     pub fn finish(&mut self) -> Result<(), EncodeError> {
         if self.is_finished {
@@ -205,13 +279,20 @@ impl<W: std::io::Write> Encoder<W> {
                 // TODO(cmc): the extra heap-alloc and copy could be easily avoided with the
                 // introduction of an InMemoryWriter trait or similar. In practice it makes no
                 // difference and the cognitive overhead of this crate is already through the roof.
+                let mut payload = Vec::new();
+                if let Some(index) = &mut self.chunk_index {
+                    index.end_offset = self.num_written;
+                    index.to_rrd_bytes(&mut payload)?;
+                }
+
                 let mut header = Vec::new();
                 MessageHeader {
                     kind: MessageKind::End,
-                    len: 0,
+                    len: payload.len() as u64,
                 }
                 .to_rrd_bytes(&mut header)?;
                 w.write_all(&header)?;
+                w.write_all(&payload)?;
             }
         }
 
@@ -28,6 +28,9 @@ pub enum CodecError {
     #[error("Failed to decode message header {0}")]
     HeaderDecoding(String),
 
+    #[error("Failed to decode chunk index: {0}")]
+    ChunkIndexDecoding(String),
+
     #[error("Arrow IPC deserialization error: {0}")]
     ArrowDeserialization(::arrow::error::ArrowError),
 
@@ -122,6 +125,9 @@ pub enum OptionsError {
     #[error("Reserved bytes not zero")]
     UnknownReservedBytes,
 
+    #[error("Unknown flags {0:#010b}, the stream was likely written by a newer version of Rerun")]
+    UnknownFlags(u8),
+
     #[error("Unknown compression: {0}")]
     UnknownCompression(u8),
 
@@ -79,9 +79,12 @@ impl Drop for FileSink {
 
 impl FileSink {
     /// Start writing log messages to a file at the given path.
+    ///
+    /// Messages are only ever appended to the file, one whole checksummed message at a time, so
+    /// the file can be read up to its last complete message even if this process crashes.
     pub fn new(path: impl Into<std::path::PathBuf>) -> Result<Self, FileSinkError> {
-        // We always compress on disk
-        let encoding_options = crate::rrd::EncodingOptions::PROTOBUF_COMPRESSED;
+        // We always compress on disk, and make sure that torn writes can be told apart.
+        let encoding_options = crate::rrd::EncodingOptions::PROTOBUF_COMPRESSED_CHECKSUMMED;
 
         let (tx, rx) = std::sync::mpsc::channel();
 
@@ -96,7 +99,8 @@ impl FileSink {
         let file = std::fs::File::create(&path)
             .map_err(|err| FileSinkError::CreateFile(path.clone(), err))?;
         let encoder =
-            crate::Encoder::new_eager(re_build_info::CrateVersion::LOCAL, encoding_options, file)?;
+            crate::Encoder::new_eager(re_build_info::CrateVersion::LOCAL, encoding_options, file)?
+                .with_chunk_index();
         let join_handle = spawn_and_stream(Some(&path), encoder, rx)?;
 
         Ok(Self {
@@ -16,6 +16,13 @@ pub enum Serializer {
 pub struct EncodingOptions {
     pub compression: Compression,
     pub serializer: Serializer,
+
+    /// Follow every message with a checksum, so that decoders can tell complete messages apart
+    /// from the torn writes of a crashed writer.
+    ///
+    /// This is signaled by a flag of the stream header. Rerun versions that predate flags refuse
+    /// to open such streams, while later versions refuse streams with flags they don't know.
+    pub checksums: bool,
 }
 
 impl EncodingOptions {
@@ -26,11 +33,25 @@ impl EncodingOptions {
     pub const PROTOBUF_COMPRESSED: Self = Self {
         compression: Compression::LZ4,
         serializer: Serializer::Protobuf,
+        checksums: false,
     };
     pub const PROTOBUF_UNCOMPRESSED: Self = Self {
         compression: Compression::Off,
         serializer: Serializer::Protobuf,
+        checksums: false,
+    };
+
+    /// What files are written with, see [`Self::checksums`].
+    pub const PROTOBUF_COMPRESSED_CHECKSUMMED: Self = Self {
+        checksums: true,
+        ..Self::PROTOBUF_COMPRESSED
     };
+
+    /// See [`Self::checksums`].
+    const FLAG_CHECKSUMS: u8 = 0b0000_0001;
+
+    /// All flags known to this version of Rerun.
+    const KNOWN_FLAGS: u8 = Self::FLAG_CHECKSUMS;
 }
 
 impl Encodable for EncodingOptions {
@@ -38,14 +59,20 @@ impl Encodable for EncodingOptions {
         let Self {
             compression,
             serializer,
+            checksums,
         } = *self;
 
         let before = out.len() as u64;
 
+        let mut flags = 0;
+        if checksums {
+            flags |= Self::FLAG_CHECKSUMS;
+        }
+
         out.extend_from_slice(&[
             compression as u8,
             serializer as u8,
-            0, // reserved
+            flags,
             0, // reserved
         ]);
 
@@ -59,7 +86,11 @@ impl Encodable for EncodingOptions {
 impl Decodable for EncodingOptions {
     fn from_rrd_bytes(data: &[u8]) -> Result<Self, crate::rrd::CodecError> {
         match data {
-            &[compression, serializer, 0, 0] => {
+            &[_, _, flags, 0] if flags & !Self::KNOWN_FLAGS != 0 => {
+                Err(OptionsError::UnknownFlags(flags).into())
+            }
+
+            &[compression, serializer, flags, 0] => {
                 let compression = match compression {
                     0 => Compression::Off,
                     1 => Compression::LZ4,
@@ -73,6 +104,7 @@ impl Decodable for EncodingOptions {
                 Ok(Self {
                     compression,
                     serializer,
+                    checksums: flags & Self::FLAG_CHECKSUMS != 0,
                 })
             }
 
@@ -209,6 +241,22 @@ impl MessageKind {
     const BLUEPRINT_ACTIVATION_COMMAND: u64 = 3;
 }
 
+impl TryFrom<u64> for MessageKind {
+    type Error = crate::rrd::CodecError;
+
+    fn try_from(kind: u64) -> Result<Self, Self::Error> {
+        match kind {
+            Self::END => Ok(Self::End),
+            Self::SET_STORE_INFO => Ok(Self::SetStoreInfo),
+            Self::ARROW_MSG => Ok(Self::ArrowMsg),
+            Self::BLUEPRINT_ACTIVATION_COMMAND => Ok(Self::BlueprintActivationCommand),
+            _ => Err(crate::rrd::CodecError::HeaderDecoding(format!(
+                "unknown MessageHeader kind: {kind:?}"
+            ))),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct MessageHeader {
     pub kind: MessageKind,
@@ -217,6 +265,12 @@ pub struct MessageHeader {
 
 impl MessageHeader {
     pub const ENCODED_SIZE_BYTES: usize = 16;
+
+    /// The size of the checksum that follows every message but end-of-stream markers, if
+    /// [`EncodingOptions::checksums`] is set.
+    ///
+    /// It covers both the [`MessageHeader`] and the message itself.
+    pub const CHECKSUM_SIZE_BYTES: usize = 4;
 }
 
 impl Encodable for MessageHeader {
@@ -246,17 +300,7 @@ impl Decodable for MessageHeader {
         }
 
         let kind = u64::from_le_bytes(data[0..8].try_into().expect("cannot fail, checked above"));
-        let kind = match kind {
-            MessageKind::END => MessageKind::End,
-            MessageKind::SET_STORE_INFO => MessageKind::SetStoreInfo,
-            MessageKind::ARROW_MSG => MessageKind::ArrowMsg,
-            MessageKind::BLUEPRINT_ACTIVATION_COMMAND => MessageKind::BlueprintActivationCommand,
-            _ => {
-                return Err(crate::rrd::CodecError::HeaderDecoding(format!(
-                    "unknown MessageHeader kind: {kind:?}"
-                )));
-            }
-        };
+        let kind = MessageKind::try_from(kind)?;
 
         let len = u64::from_le_bytes(data[8..16].try_into().expect("cannot fail, checked above"));
 
@@ -15,6 +15,7 @@
 //! sort of state. That's the job of the `Encoder` and `Decoder`: they provide the IO and the
 //! state machines that turn collections of `Encodable`s and `Decodable`s into actual RRD streams.
 
+mod chunk_index;
 mod errors;
 mod headers;
 mod log_msg;
@@ -32,6 +33,7 @@ mod file_sink;
 #[cfg(feature = "stream_from_http")]
 pub mod stream_from_http;
 
+pub use self::chunk_index::{ChunkIndex, ChunkIndexEntry};
 pub use self::errors::{CodecError, NotAnRrdError, OptionsError};
 pub use self::headers::{
     Compression, CrateVersion, EncodingOptions, MessageHeader, MessageKind, Serializer,
@@ -40,8 +42,8 @@ pub use self::headers::{
 
 #[cfg(feature = "decoder")]
 pub use self::decoder::{
-    DecodeError, Decoder, DecoderApp, DecoderEntrypoint, DecoderIterator, DecoderStream,
-    DecoderTransport,
+    DecodeError, Decoder, DecoderApp, DecoderEntrypoint, DecoderIterator, DecoderSeekIterator,
+    DecoderStream, DecoderTransport, PendingLogMsg,
 };
 
 #[cfg(feature = "encoder")]
@@ -5,11 +5,18 @@ use std::sync::Arc;
 use re_log::ResultExt as _;
 use re_log_types::{DataSourceMessage, LogMsg};
 
+mod progressive;
+
+pub use self::progressive::{set_time_cursor, stream_from_http_progressive};
+
 /// Stream an rrd file from a HTTP server.
 ///
 /// If `follow_if_http` is `true`, and the url is an HTTP source, the viewer will open the stream
 /// in `Following` mode rather than `Playing` mode.
 ///
+/// Otherwise, the file is assumed to be complete, and is loaded progressively if it is indexed
+/// (see [`stream_from_http_progressive`]).
+///
 /// `on_msg` can be used to wake up the UI thread on Wasm.
 pub fn stream_from_http_to_channel(
     url: String,
@@ -23,7 +30,12 @@ pub fn stream_from_http_to_channel(
             follow,
         },
     );
-    stream_from_http(
+    let stream = if follow {
+        stream_from_http
+    } else {
+        stream_from_http_progressive
+    };
+    stream(
         url.clone(),
         Arc::new(move |msg| {
             if let Some(on_msg) = &on_msg {
@@ -0,0 +1,371 @@
+//! Progressive loading of indexed `.rrd` files, using HTTP range requests.
+//!
+//! See [`ChunkIndex`] for how such files are laid out.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ops::{ControlFlow, Range};
+use std::sync::{Arc, LazyLock};
+
+use re_log_types::{TimeInt, TimelineName};
+
+use super::{HttpMessage, HttpMessageCallback};
+use crate::rrd::{ChunkIndex, Decodable as _, MessageKind, StreamHeader};
+
+/// Upper bound for the size of a single range request, unless a single message is larger than that.
+const MAX_BATCH_BYTES: u64 = 8 * 1024 * 1024;
+
+/// The time cursor of every recording being viewed, keyed by url.
+static TIME_CURSORS: LazyLock<parking_lot::Mutex<HashMap<String, (TimelineName, TimeInt)>>> =
+    LazyLock::new(Default::default);
+
+/// Let the loader of the `.rrd` file at `url` know where the time cursor currently is.
+///
+/// The chunks closest to it will be fetched first.
+/// This has no effect if that file isn't being loaded progressively.
+pub fn set_time_cursor(url: &str, timeline: TimelineName, time: TimeInt) {
+    let mut cursors = TIME_CURSORS.lock();
+    if cursors.get(url) != Some(&(timeline, time)) {
+        cursors.insert(url.to_owned(), (timeline, time));
+    }
+}
+
+/// Stream an indexed `.rrd` file from an HTTP server, starting with the chunks closest to the
+/// time cursor (see [`set_time_cursor`]).
+///
+/// Falls back to [`super::stream_from_http`] if the file isn't indexed, or if the server doesn't
+/// support range requests.
+pub fn stream_from_http_progressive(url: String, on_msg: Arc<HttpMessageCallback>) {
+    re_log::debug!("Looking for a chunk index at the end of {url:?}…");
+
+    let mut request = ehttp::Request::get(&url);
+    request.headers.insert(
+        "Range",
+        format!("bytes=-{}", ChunkIndex::TRAILER_SIZE_BYTES),
+    );
+
+    // Streaming, so that we can bail out right away if the server sends the whole file instead.
+    ehttp::streaming::fetch(request, {
+        let file_len = RefCell::new(None);
+        let trailer = RefCell::new(Vec::new());
+        move |part| match part {
+            Ok(ehttp::streaming::Part::Response(response)) => {
+                let content_range = response.headers.get("content-range");
+                if let Some(len) = content_range
+                    .filter(|_| response.status == 206)
+                    .and_then(content_range_total)
+                {
+                    *file_len.borrow_mut() = Some(len);
+                    ControlFlow::Continue(())
+                } else {
+                    re_log::debug!(
+                        "{url:?} can't be fetched piecewise ({} {}), downloading all of it…",
+                        response.status,
+                        response.status_text
+                    );
+                    super::stream_from_http(url.clone(), Arc::clone(&on_msg));
+                    ControlFlow::Break(())
+                }
+            }
+
+            Ok(ehttp::streaming::Part::Chunk(chunk)) => {
+                if !chunk.is_empty() {
+                    trailer.borrow_mut().extend_from_slice(&chunk);
+                    return ControlFlow::Continue(());
+                }
+
+                let file_len = file_len.borrow().unwrap_or_default();
+                match ChunkIndex::payload_len_from_trailer(&trailer.borrow()) {
+                    Some(payload_len) if payload_len <= file_len => {
+                        fetch_index(url.clone(), file_len, payload_len, Arc::clone(&on_msg));
+                    }
+                    _ => {
+                        re_log::debug!("{url:?} isn't indexed, downloading all of it…");
+                        super::stream_from_http(url.clone(), Arc::clone(&on_msg));
+                    }
+                }
+                ControlFlow::Break(())
+            }
+
+            Err(err) => on_msg(HttpMessage::Failure(
+                format!("Failed to fetch .rrd file from {url}: {err}").into(),
+            )),
+        }
+    });
+}
+
+fn fetch_index(url: String, file_len: u64, payload_len: u64, on_msg: Arc<HttpMessageCallback>) {
+    fetch_range(
+        &url.clone(),
+        file_len - payload_len..file_len,
+        move |result| {
+            let index = result.and_then(|payload| {
+                ChunkIndex::from_rrd_bytes(&payload).map_err(|err| err.to_string())
+            });
+            match index {
+                Ok(index) if index.stream_len(payload_len) == file_len => {
+                    re_log::debug!(
+                        "Streaming {} messages from {url:?}, closest to the time cursor first…",
+                        index.entries.len()
+                    );
+                    ProgressiveLoader::start(url, index, on_msg);
+                }
+
+                Ok(_) => {
+                    // Only the last stream is indexed: we can't tell where the others are.
+                    re_log::debug!("{url:?} contains concatenated streams, downloading all of it…");
+                    super::stream_from_http(url, on_msg);
+                }
+
+                Err(err) => {
+                    let _ignored_control_flow = on_msg(HttpMessage::Failure(
+                        format!("Failed to fetch chunk index from {url}: {err}").into(),
+                    ));
+                }
+            }
+        },
+    );
+}
+
+struct ProgressiveLoader {
+    url: String,
+    index: ChunkIndex,
+
+    /// Which entries of the index have already been fetched.
+    fetched: Vec<bool>,
+
+    decoder: crate::DecoderApp,
+    on_msg: Arc<HttpMessageCallback>,
+}
+
+impl ProgressiveLoader {
+    fn new(url: String, index: ChunkIndex, on_msg: Arc<HttpMessageCallback>) -> Self {
+        Self {
+            url,
+            fetched: vec![false; index.entries.len()],
+            index,
+            decoder: crate::DecoderApp::new(),
+            on_msg,
+        }
+    }
+
+    fn start(url: String, index: ChunkIndex, on_msg: Arc<HttpMessageCallback>) {
+        let header_range = 0..StreamHeader::ENCODED_SIZE_BYTES as u64;
+        fetch_range(&url.clone(), header_range, move |result| match result {
+            Ok(header) => {
+                let mut loader = Box::new(Self::new(url, index, on_msg));
+                if loader.decode(header).is_continue() {
+                    loader.fetch_next();
+                }
+            }
+            Err(err) => {
+                let _ignored_control_flow = on_msg(HttpMessage::Failure(
+                    format!("Failed to fetch .rrd file from {url}: {err}").into(),
+                ));
+            }
+        });
+    }
+
+    /// Fetches the next batch of messages, and so on until everything has been fetched.
+    ///
+    /// Only one request is in flight at any time, so that every batch accounts for the latest
+    /// position of the time cursor.
+    fn fetch_next(mut self: Box<Self>) {
+        let cursor = TIME_CURSORS.lock().get(&self.url).copied();
+        let Some(batch) = self.next_batch(cursor) else {
+            re_log::debug!("Finished streaming .rrd file from {:?}", self.url);
+            let _ignored_control_flow = (self.on_msg)(HttpMessage::Success);
+            return;
+        };
+
+        let entries = &self.index.entries;
+        let range = entries[batch.start].byte_span.start..entries[batch.end - 1].byte_span.end();
+
+        let url = self.url.clone();
+        fetch_range(&url, range, move |result| match result {
+            Ok(bytes) => {
+                self.fetched[batch].fill(true);
+                if self.decode(bytes).is_continue() {
+                    self.fetch_next();
+                }
+            }
+            Err(err) => {
+                let _ignored_control_flow = (self.on_msg)(HttpMessage::Failure(
+                    format!("Failed to fetch .rrd file from {url}: {err}").into(),
+                ));
+            }
+        });
+    }
+
+    /// Picks the most relevant message that hasn't been fetched yet, along with as many of its
+    /// unfetched neighbours as fit in a single request.
+    fn next_batch(&self, cursor: Option<(TimelineName, TimeInt)>) -> Option<Range<usize>> {
+        let entries = &self.index.entries;
+
+        let best = (0..entries.len())
+            .filter(|&i| !self.fetched[i])
+            .min_by_key(|&i| {
+                let entry = &entries[i];
+                let distance =
+                    cursor.map_or(0, |(timeline, time)| entry.distance_to(&timeline, time));
+                // Store infos come first, so that the viewer knows what it's dealing with.
+                (entry.kind != MessageKind::SetStoreInfo, distance)
+            })?;
+
+        let mut batch = best..best + 1;
+        let mut num_bytes = entries[best].byte_span.len;
+
+        // Playback usually moves forward, so look ahead first.
+        while batch.end < entries.len()
+            && !self.fetched[batch.end]
+            && num_bytes + entries[batch.end].byte_span.len <= MAX_BATCH_BYTES
+        {
+            num_bytes += entries[batch.end].byte_span.len;
+            batch.end += 1;
+        }
+        while 0 < batch.start
+            && !self.fetched[batch.start - 1]
+            && num_bytes + entries[batch.start - 1].byte_span.len <= MAX_BATCH_BYTES
+        {
+            num_bytes += entries[batch.start - 1].byte_span.len;
+            batch.start -= 1;
+        }
+
+        Some(batch)
+    }
+
+    fn decode(&mut self, bytes: Vec<u8>) -> ControlFlow<()> {
+        re_tracing::profile_scope!("decoding_rrd_stream");
+
+        // Every batch is made of whole messages, so the decoder never ends up halfway through one.
+        self.decoder.push_byte_chunk(bytes);
+        loop {
+            match self.decoder.try_read() {
+                Ok(Some(message)) => {
+                    if (self.on_msg)(HttpMessage::LogMsg(message)).is_break() {
+                        return ControlFlow::Break(());
+                    }
+                }
+                Ok(None) => return ControlFlow::Continue(()),
+                Err(err) => {
+                    return (self.on_msg)(HttpMessage::Failure(
+                        format!("Failed to fetch .rrd file from {}: {err}", self.url).into(),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Fetches the given byte `range` of the file at `url`.
+fn fetch_range(
+    url: &str,
+    range: Range<u64>,
+    on_done: impl 'static + Send + FnOnce(Result<Vec<u8>, String>),
+) {
+    let mut request = ehttp::Request::get(url);
+    request
+        .headers
+        .insert("Range", format!("bytes={}-{}", range.start, range.end - 1));
+
+    ehttp::fetch(request, move |response| {
+        on_done(response.and_then(|response| {
+            let expected_len = range.end - range.start;
+            if response.status != 206 {
+                Err(format!("{} {}", response.status, response.status_text))
+            } else if response.bytes.len() as u64 != expected_len {
+                Err(format!(
+                    "expected {expected_len} bytes but got {}",
+                    response.bytes.len()
+                ))
+            } else {
+                Ok(response.bytes)
+            }
+        }));
+    });
+}
+
+/// Parses the total size of the file out of a `Content-Range: bytes 0-11/1234` header.
+fn content_range_total(content_range: &str) -> Option<u64> {
+    content_range.rsplit_once('/')?.1.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use re_log_types::AbsoluteTimeRange;
+
+    use super::*;
+    use crate::rrd::ChunkIndexEntry;
+
+    #[test]
+    fn batches_follow_time_cursor() {
+        let frame = TimelineName::new("frame");
+        let mut start = StreamHeader::ENCODED_SIZE_BYTES as u64;
+        let mut entry = |kind, len, time_ranges| {
+            let byte_span = re_chunk::Span { start, len };
+            start += len;
+            ChunkIndexEntry {
+                byte_span,
+                kind,
+                is_static: false,
+                entity_path: None,
+                time_ranges,
+            }
+        };
+
+        let half = MAX_BATCH_BYTES / 2;
+        let entries = vec![
+            entry(
+                MessageKind::ArrowMsg,
+                half,
+                vec![(frame, AbsoluteTimeRange::new(0, 9))],
+            ),
+            entry(MessageKind::SetStoreInfo, 100, vec![]),
+            entry(
+                MessageKind::ArrowMsg,
+                half,
+                vec![(frame, AbsoluteTimeRange::new(10, 19))],
+            ),
+            entry(
+                MessageKind::ArrowMsg,
+                half,
+                vec![(frame, AbsoluteTimeRange::new(20, 29))],
+            ),
+            entry(
+                MessageKind::ArrowMsg,
+                half,
+                vec![(frame, AbsoluteTimeRange::new(30, 39))],
+            ),
+        ];
+        let index = ChunkIndex {
+            end_offset: start,
+            entries,
+        };
+
+        let mut loader = ProgressiveLoader::new(
+            "http://localhost/test.rrd".to_owned(),
+            index,
+            Arc::new(|_: HttpMessage| ControlFlow::Continue(())),
+        );
+        let cursor = Some((frame, TimeInt::new_temporal(35)));
+
+        // Store info first, along with whatever follows it.
+        assert_eq!(loader.next_batch(cursor), Some(1..3));
+        loader.fetched[1..3].fill(true);
+
+        // Then whatever is closest to the cursor.
+        assert_eq!(loader.next_batch(cursor), Some(3..5));
+        loader.fetched[3..5].fill(true);
+
+        assert_eq!(loader.next_batch(cursor), Some(0..1));
+        loader.fetched[0..1].fill(true);
+
+        assert_eq!(loader.next_batch(cursor), None);
+    }
+
+    #[test]
+    fn content_range() {
+        assert_eq!(content_range_total("bytes 1222-1233/1234"), Some(1234));
+        assert_eq!(content_range_total("bytes 0-11/*"), None);
+    }
+}
@@ -135,24 +135,7 @@ fn log_msg_to_app<I: ApplicationIdInjector + ?Sized>(
 
         Msg::ArrowMsg(arrow_msg) => {
             let encoded = arrow_msg_to_app(arrow_msg)?;
-
-            //TODO(#10730): clean that up when removing 0.24 back compat
-            let store_id: re_log_types::StoreId = match arrow_msg
-                .store_id
-                .as_ref()
-                .ok_or_else(|| missing_field!(re_protos::log_msg::v1alpha1::ArrowMsg, "store_id"))?
-                .clone()
-                .try_into()
-            {
-                Ok(store_id) => store_id,
-                Err(err) => {
-                    let Some(store_id) = app_id_injector.recover_store_id(err.clone()) else {
-                        return Err(err.into());
-                    };
-
-                    store_id
-                }
-            };
+            let store_id = arrow_msg_store_id_to_app(app_id_injector, arrow_msg)?;
 
             Ok(re_log_types::LogMsg::ArrowMsg(store_id, encoded))
         }
@@ -192,9 +175,34 @@ fn log_msg_to_app<I: ApplicationIdInjector + ?Sized>(
     }
 }
 
+/// Extracts the application-level `StoreId` of a transport-level `ArrowMsg`.
+///
+/// This is cheap compared to [`arrow_msg_to_app`], but must be called in stream order since it
+/// relies on the [`ApplicationIdInjector`] to migrate legacy `StoreId`s.
+pub(crate) fn arrow_msg_store_id_to_app<I: ApplicationIdInjector + ?Sized>(
+    app_id_injector: &I,
+    arrow_msg: &re_protos::log_msg::v1alpha1::ArrowMsg,
+) -> Result<re_log_types::StoreId, CodecError> {
+    use re_protos::missing_field;
+
+    //TODO(#10730): clean that up when removing 0.24 back compat
+    match arrow_msg
+        .store_id
+        .as_ref()
+        .ok_or_else(|| missing_field!(re_protos::log_msg::v1alpha1::ArrowMsg, "store_id"))?
+        .clone()
+        .try_into()
+    {
+        Ok(store_id) => Ok(store_id),
+        Err(err) => app_id_injector
+            .recover_store_id(err.clone())
+            .ok_or_else(|| err.into()),
+    }
+}
+
 /// Converts a transport-level `ArrowMsg` to its application-level counterpart.
 #[tracing::instrument(level = "trace", skip_all)]
-fn arrow_msg_to_app(
+pub(crate) fn arrow_msg_to_app(
     arrow_msg: &re_protos::log_msg::v1alpha1::ArrowMsg,
 ) -> Result<re_log_types::ArrowMsg, CodecError> {
     re_tracing::profile_function!();
@@ -0,0 +1,299 @@
+use crate::{AbsoluteTimeRange, TimeInt};
+
+/// A set of times, stored as a sorted list of disjoint [`AbsoluteTimeRange`]s.
+///
+/// The ranges are always normalized: sorted, non-empty, and neither overlapping nor adjacent
+/// (`[1, 3]` and `[4, 6]` are stored as `[1, 6]`, since both ends are inclusive).
+/// This makes equality meaningful and lookups `O(log n)`.
+///
+/// Useful for e.g. looping over several segments, retention windows, or the times at which an
+/// entity was visible.
+///
+/// Never includes [`TimeInt::STATIC`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct AbsoluteTimeRangeSet {
+    ranges: Vec<AbsoluteTimeRange>,
+}
+
+impl AbsoluteTimeRangeSet {
+    /// Contains no time at all.
+    pub const EMPTY: Self = Self { ranges: Vec::new() };
+
+    /// Contains all time.
+    pub fn everything() -> Self {
+        Self {
+            ranges: vec![AbsoluteTimeRange::EVERYTHING],
+        }
+    }
+
+    /// Empty ranges are ignored, and overlapping or adjacent ones are merged.
+    pub fn new(ranges: impl IntoIterator<Item = AbsoluteTimeRange>) -> Self {
+        let mut ranges: Vec<AbsoluteTimeRange> = ranges
+            .into_iter()
+            .map(|range| AbsoluteTimeRange {
+                min: range.min.max(TimeInt::MIN),
+                max: range.max,
+            })
+            .filter(|range| range.min <= range.max)
+            .collect();
+        ranges.sort_by_key(|range| range.min);
+
+        let mut normalized: Vec<AbsoluteTimeRange> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match normalized.last_mut() {
+                Some(last) if range.min <= last.max.inc() => {
+                    last.max = last.max.max(range.max);
+                }
+                _ => normalized.push(range),
+            }
+        }
+
+        Self { ranges: normalized }
+    }
+
+    /// The disjoint ranges of this set, in order.
+    #[inline]
+    pub fn ranges(&self) -> &[AbsoluteTimeRange] {
+        &self.ranges
+    }
+
+    #[inline]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = AbsoluteTimeRange> + '_ {
+        self.ranges.iter().copied()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The number of disjoint ranges.
+    #[inline]
+    pub fn num_ranges(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// The smallest range containing the whole set, if it isn't empty.
+    #[inline]
+    pub fn bounding_range(&self) -> Option<AbsoluteTimeRange> {
+        let first = self.ranges.first()?;
+        let last = self.ranges.last()?;
+        Some(AbsoluteTimeRange {
+            min: first.min,
+            max: last.max,
+        })
+    }
+
+    /// The range containing `time`, if any.
+    pub fn range_containing(&self, time: TimeInt) -> Option<AbsoluteTimeRange> {
+        // Index of the first range starting after `time`:
+        let index = self.ranges.partition_point(|range| range.min <= time);
+        let range = self.ranges.get(index.checked_sub(1)?)?;
+        range.contains(time).then_some(*range)
+    }
+
+    #[inline]
+    pub fn contains(&self, time: TimeInt) -> bool {
+        self.range_containing(time).is_some()
+    }
+
+    /// Is all of `range` part of the set?
+    #[inline]
+    pub fn contains_range(&self, range: AbsoluteTimeRange) -> bool {
+        self.range_containing(range.min)
+            .is_some_and(|containing| containing.contains_range(range))
+    }
+
+    /// Is any of `range` part of the set?
+    pub fn intersects(&self, range: AbsoluteTimeRange) -> bool {
+        let index = self.ranges.partition_point(|r| r.max < range.min);
+        self.ranges.get(index).is_some_and(|r| r.intersects(range))
+    }
+
+    /// The first range starting after `time`, e.g. to jump to the next segment when looping.
+    pub fn next_range_after(&self, time: TimeInt) -> Option<AbsoluteTimeRange> {
+        let index = self.ranges.partition_point(|range| range.min <= time);
+        self.ranges.get(index).copied()
+    }
+
+    /// Adds all of `range` to the set.
+    pub fn insert(&mut self, range: AbsoluteTimeRange) {
+        *self = self.union(&Self::new([range]));
+    }
+
+    /// Removes all of `range` from the set.
+    pub fn remove(&mut self, range: AbsoluteTimeRange) {
+        *self = self.difference(&Self::new([range]));
+    }
+
+    /// All times that are in either set.
+    pub fn union(&self, other: &Self) -> Self {
+        Self::new(self.iter().chain(other.iter()))
+    }
+
+    /// All times that are in both sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            if let Some(intersection) = a.intersection(*b) {
+                ranges.push(intersection);
+            }
+
+            // Drop whichever ends first: it can't intersect anything else in the other set.
+            if a.max < b.max {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        // The intersections of normalized sets are already sorted and disjoint, but they may be
+        // adjacent to each other.
+        Self::new(ranges)
+    }
+
+    /// All times that are in `self` but not in `other`.
+    #[inline]
+    pub fn difference(&self, other: &Self) -> Self {
+        self.intersection(&other.complement())
+    }
+
+    /// All times that are not in the set.
+    pub fn complement(&self) -> Self {
+        let mut ranges = Vec::with_capacity(self.ranges.len() + 1);
+
+        let mut next_min = Some(TimeInt::MIN);
+        for range in &self.ranges {
+            if let Some(min) = next_min
+                && min < range.min
+            {
+                ranges.push(AbsoluteTimeRange {
+                    min,
+                    max: range.min.dec(),
+                });
+            }
+            next_min = (range.max < TimeInt::MAX).then(|| range.max.inc());
+        }
+
+        if let Some(min) = next_min {
+            ranges.push(AbsoluteTimeRange {
+                min,
+                max: TimeInt::MAX,
+            });
+        }
+
+        Self { ranges }
+    }
+}
+
+impl From<AbsoluteTimeRange> for AbsoluteTimeRangeSet {
+    #[inline]
+    fn from(range: AbsoluteTimeRange) -> Self {
+        Self::new([range])
+    }
+}
+
+impl FromIterator<AbsoluteTimeRange> for AbsoluteTimeRangeSet {
+    #[inline]
+    fn from_iter<T: IntoIterator<Item = AbsoluteTimeRange>>(iter: T) -> Self {
+        Self::new(iter)
+    }
+}
+
+impl re_byte_size::SizeBytes for AbsoluteTimeRangeSet {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.ranges.heap_size_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[(i64, i64)]) -> AbsoluteTimeRangeSet {
+        ranges
+            .iter()
+            .map(|&(min, max)| AbsoluteTimeRange::new(min, max))
+            .collect()
+    }
+
+    #[test]
+    fn normalization() {
+        assert_eq!(
+            set(&[(10, 20), (1, 3), (4, 6), (15, 30), (40, 35)]).ranges(),
+            &[AbsoluteTimeRange::new(1, 6), AbsoluteTimeRange::new(10, 30)]
+        );
+        assert_eq!(set(&[(1, 3), (5, 6)]).num_ranges(), 2);
+        assert!(set(&[(5, 1)]).is_empty());
+        assert_eq!(
+            AbsoluteTimeRangeSet::new([AbsoluteTimeRange::EMPTY]),
+            AbsoluteTimeRangeSet::EMPTY
+        );
+    }
+
+    #[test]
+    fn lookups() {
+        let s = set(&[(1, 6), (10, 30)]);
+
+        assert!(s.contains(TimeInt::new_temporal(1)));
+        assert!(s.contains(TimeInt::new_temporal(6)));
+        assert!(!s.contains(TimeInt::new_temporal(7)));
+        assert!(!s.contains(TimeInt::new_temporal(0)));
+        assert!(!s.contains(TimeInt::STATIC));
+
+        assert!(s.contains_range(AbsoluteTimeRange::new(12, 20)));
+        assert!(!s.contains_range(AbsoluteTimeRange::new(5, 12)));
+        assert!(s.intersects(AbsoluteTimeRange::new(5, 12)));
+        assert!(!s.intersects(AbsoluteTimeRange::new(7, 9)));
+
+        assert_eq!(
+            s.next_range_after(TimeInt::new_temporal(3)),
+            Some(AbsoluteTimeRange::new(10, 30))
+        );
+        assert_eq!(s.next_range_after(TimeInt::new_temporal(10)), None);
+        assert_eq!(s.bounding_range(), Some(AbsoluteTimeRange::new(1, 30)));
+    }
+
+    #[test]
+    fn set_algebra() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25), (40, 50)]);
+
+        assert_eq!(a.union(&b), set(&[(0, 30), (40, 50)]));
+        assert_eq!(a.intersection(&b), set(&[(5, 10), (20, 25)]));
+        assert_eq!(a.difference(&b), set(&[(0, 4), (26, 30)]));
+        assert_eq!(b.difference(&a), set(&[(11, 19), (40, 50)]));
+
+        assert_eq!(
+            a.intersection(&AbsoluteTimeRangeSet::EMPTY),
+            AbsoluteTimeRangeSet::EMPTY
+        );
+        assert_eq!(a.intersection(&AbsoluteTimeRangeSet::everything()), a);
+        assert_eq!(a.union(&a.complement()), AbsoluteTimeRangeSet::everything());
+        assert!(a.intersection(&a.complement()).is_empty());
+        assert_eq!(a.complement().complement(), a);
+
+        let mut c = a.clone();
+        c.insert(AbsoluteTimeRange::new(11, 19));
+        assert_eq!(c, set(&[(0, 30)]));
+        c.remove(AbsoluteTimeRange::new(10, 20));
+        assert_eq!(c, set(&[(0, 9), (21, 30)]));
+    }
+
+    #[test]
+    fn extremes() {
+        let everything = AbsoluteTimeRangeSet::everything();
+        assert!(everything.complement().is_empty());
+        assert_eq!(AbsoluteTimeRangeSet::EMPTY.complement(), everything);
+
+        let edges = AbsoluteTimeRangeSet::new([
+            AbsoluteTimeRange::new(TimeInt::MIN, TimeInt::new_temporal(0)),
+            AbsoluteTimeRange::new(TimeInt::new_temporal(10), TimeInt::MAX),
+        ]);
+        assert_eq!(edges.complement(), set(&[(1, 9)]));
+    }
+}
@@ -1,11 +1,15 @@
 use std::ops::RangeInclusive;
 
+use super::TimeUnit;
+
 /// A signed duration represented as nanoseconds since unix epoch
 #[derive(Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Duration(i64);
 
 impl Duration {
+    pub const MIN: Self = Self(i64::MIN);
+    pub const ZERO: Self = Self(0);
     pub const MAX: Self = Self(i64::MAX);
     const NANOS_PER_SEC: i64 = 1_000_000_000;
 
@@ -14,27 +18,74 @@ impl Duration {
         Self(nanos)
     }
 
+    /// Saturates on overflow.
     #[inline]
     pub const fn from_micros(micros: i64) -> Self {
-        Self::from_nanos(1_000 * micros)
+        Self::from_nanos(micros.saturating_mul(1_000))
     }
 
+    /// Saturates on overflow.
     #[inline]
     pub const fn from_millis(millis: i64) -> Self {
-        Self::from_nanos(1_000_000 * millis)
+        Self::from_nanos(millis.saturating_mul(1_000_000))
     }
 
+    /// Saturates on overflow.
     #[inline]
     pub fn from_secs(secs: impl Into<f64>) -> Self {
         let secs = secs.into();
         Self::from_nanos((secs * Self::NANOS_PER_SEC as f64).round() as _)
     }
 
+    /// A number of the given temporal unit, rounded to the closest nanosecond.
+    ///
+    /// Returns `None` for [`TimeUnit::Sequence`], if `value` isn't finite, or on overflow.
+    #[inline]
+    pub fn checked_from_unit(value: f64, unit: TimeUnit) -> Option<Self> {
+        if unit == TimeUnit::Sequence {
+            return None;
+        }
+        unit.checked_to_native_f64(value).map(Self::from_nanos)
+    }
+
     #[inline]
     pub fn as_nanos(&self) -> i64 {
         self.0
     }
 
+    /// Rounds towards zero.
+    #[inline]
+    pub fn as_micros(&self) -> i64 {
+        self.0 / 1_000
+    }
+
+    /// Rounds towards zero.
+    #[inline]
+    pub fn as_millis(&self) -> i64 {
+        self.0 / 1_000_000
+    }
+
+    /// This duration in the given unit, e.g. `duration.as_unit_f64(TimeUnit::Millis)`.
+    #[inline]
+    pub fn as_unit_f64(&self, unit: TimeUnit) -> f64 {
+        unit.native_to_f64(self.0)
+    }
+
+    #[inline]
+    pub fn abs(self) -> Self {
+        Self(self.0.saturating_abs())
+    }
+
+    #[inline]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.0.checked_add(rhs.0).map(Self)
+    }
+
+    #[inline]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.0.checked_sub(rhs.0).map(Self)
+    }
+
     #[inline]
     pub fn as_secs_f32(&self) -> f32 {
         self.0 as f32 * 1e-9
@@ -108,6 +159,46 @@ impl From<std::time::Duration> for Duration {
     }
 }
 
+impl TryFrom<Duration> for std::time::Duration {
+    type Error = std::num::TryFromIntError;
+
+    /// Fails for negative durations.
+    #[inline]
+    fn try_from(duration: Duration) -> Result<Self, Self::Error> {
+        u64::try_from(duration.as_nanos()).map(Self::from_nanos)
+    }
+}
+
+impl std::ops::Add for Duration {
+    type Output = Self;
+
+    /// Saturates on overflow.
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0.saturating_add(rhs.0))
+    }
+}
+
+impl std::ops::Sub for Duration {
+    type Output = Self;
+
+    /// Saturates on overflow.
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+}
+
+impl std::ops::Mul<i64> for Duration {
+    type Output = Self;
+
+    /// Saturates on overflow.
+    #[inline]
+    fn mul(self, rhs: i64) -> Self {
+        Self(self.0.saturating_mul(rhs))
+    }
+}
+
 impl std::ops::Neg for Duration {
     type Output = Self;
 
@@ -159,7 +250,7 @@ impl std::fmt::Display for Duration {
 mod tests {
     use std::str::FromStr as _;
 
-    use crate::Duration;
+    use crate::{Duration, TimeUnit};
 
     #[test]
     fn test_formatting_duration() {
@@ -226,4 +317,31 @@ mod tests {
         assert!(Duration::from_str("invalid").is_err());
         assert!(Duration::from_str("123").is_err());
     }
+
+    #[test]
+    fn duration_units() {
+        assert_eq!(
+            Duration::checked_from_unit(1.5, TimeUnit::Millis),
+            Some(Duration::from_micros(1_500))
+        );
+        assert_eq!(Duration::checked_from_unit(1.0, TimeUnit::Sequence), None);
+        assert_eq!(Duration::checked_from_unit(1e12, TimeUnit::Secs), None);
+
+        assert_eq!(Duration::from_millis(i64::MAX), Duration::MAX);
+        assert_eq!(Duration::from_nanos(1_999_999).as_millis(), 1);
+        assert_eq!(
+            Duration::from_millis(-1_500).as_unit_f64(TimeUnit::Secs),
+            -1.5
+        );
+
+        assert_eq!(Duration::MAX + Duration::from_nanos(1), Duration::MAX);
+        assert_eq!(Duration::MAX.checked_add(Duration::from_nanos(1)), None);
+        assert_eq!(Duration::from_secs(2.0) * 3, Duration::from_secs(6.0));
+
+        assert_eq!(
+            std::time::Duration::try_from(Duration::from_millis(42)),
+            Ok(std::time::Duration::from_millis(42))
+        );
+        assert!(std::time::Duration::try_from(Duration::from_millis(-42)).is_err());
+    }
 }
@@ -1,6 +1,7 @@
 //! Related to indices, i.e. timelines.
 
 mod absolute_time_range;
+mod absolute_time_range_set;
 mod duration;
 mod non_min_i64;
 mod time_cell;
@@ -8,12 +9,15 @@ mod time_int;
 mod time_point;
 mod time_real;
 mod time_type;
+mod time_unit;
 mod timeline;
+mod timeline_categories;
 mod timestamp;
 mod timestamp_format;
 
 pub use self::{
     absolute_time_range::{AbsoluteTimeRange, AbsoluteTimeRangeF},
+    absolute_time_range_set::AbsoluteTimeRangeSet,
     duration::Duration,
     non_min_i64::{NonMinI64, TryFromIntError},
     time_cell::TimeCell,
@@ -21,7 +25,9 @@ pub use self::{
     time_point::TimePoint,
     time_real::TimeReal,
     time_type::TimeType,
+    time_unit::TimeUnit,
     timeline::{Timeline, TimelineName},
+    timeline_categories::TimelineCategories,
     timestamp::Timestamp,
     timestamp_format::{TimestampFormat, TimestampFormatKind},
 };
@@ -1,4 +1,4 @@
-use crate::{Duration, NonMinI64, TryFromIntError};
+use crate::{Duration, NonMinI64, TimeUnit, TryFromIntError};
 
 /// A 64-bit number describing either nanoseconds, sequence numbers or fully static data.
 ///
@@ -98,6 +98,34 @@ impl TimeInt {
         Self(Some(sequence))
     }
 
+    /// A whole number of the given unit, e.g. `TimeInt::checked_from_unit(42, TimeUnit::Millis)`.
+    ///
+    /// Returns `None` if it can't be represented in the native unit of the timeline.
+    #[inline]
+    pub fn checked_from_unit(value: i64, unit: TimeUnit) -> Option<Self> {
+        unit.checked_to_native(value)
+            .and_then(NonMinI64::new)
+            .map(Self::from)
+    }
+
+    /// A fractional number of the given unit, rounded to the closest native unit.
+    ///
+    /// Returns `None` if `value` isn't finite, or can't be represented in the native unit.
+    #[inline]
+    pub fn checked_from_unit_f64(value: f64, unit: TimeUnit) -> Option<Self> {
+        unit.checked_to_native_f64(value)
+            .and_then(NonMinI64::new)
+            .map(Self::from)
+    }
+
+    /// This time in the given unit, e.g. `time.as_unit_f64(TimeUnit::Secs)`.
+    ///
+    /// Returns `None` for [`Self::STATIC`].
+    #[inline]
+    pub fn as_unit_f64(self, unit: TimeUnit) -> Option<f64> {
+        self.0.map(|t| unit.native_to_f64(t.get()))
+    }
+
     /// Clamp to valid non-static range.
     #[inline]
     pub fn saturated_temporal_i64(value: impl Into<i64>) -> Self {
@@ -289,4 +317,27 @@ mod tests {
             TimeInt::new_temporal(i64::MAX - 1)
         );
     }
+
+    #[test]
+    fn units() {
+        assert_eq!(
+            TimeInt::checked_from_unit(42, TimeUnit::Millis),
+            Some(TimeInt::new_temporal(42_000_000))
+        );
+        assert_eq!(TimeInt::checked_from_unit(i64::MAX, TimeUnit::Micros), None);
+        assert_eq!(
+            TimeInt::checked_from_unit_f64(0.25, TimeUnit::Secs),
+            Some(TimeInt::new_temporal(250_000_000))
+        );
+        assert_eq!(
+            TimeInt::checked_from_unit_f64(f64::NAN, TimeUnit::Secs),
+            None
+        );
+
+        assert_eq!(
+            TimeInt::new_temporal(1_500).as_unit_f64(TimeUnit::Micros),
+            Some(1.5)
+        );
+        assert_eq!(TimeInt::STATIC.as_unit_f64(TimeUnit::Secs), None);
+    }
 }
@@ -1,6 +1,6 @@
 use fixed::{FixedI128, traits::LossyInto as _};
 
-use crate::TimeInt;
+use crate::{TimeInt, TimeUnit};
 
 use super::NonMinI64;
 
@@ -69,6 +69,20 @@ impl TimeReal {
         self.as_f64() / 1_000_000_000f64
     }
 
+    /// A number of the given unit, e.g. `TimeReal::from_unit(1.5, TimeUnit::Millis)`.
+    ///
+    /// Saturates on overflow.
+    #[inline]
+    pub fn from_unit(value: f64, unit: TimeUnit) -> Self {
+        Self::from(value * unit.native_per_unit() as f64)
+    }
+
+    /// This time in the given unit.
+    #[inline]
+    pub fn as_unit_f64(self, unit: TimeUnit) -> f64 {
+        self.as_f64() / unit.native_per_unit() as f64
+    }
+
     /// Returns the value half-way to `other`.
     pub fn midpoint(self, other: Self) -> Self {
         Self((self.0 + other.0) / FixedI128::from_num(2))
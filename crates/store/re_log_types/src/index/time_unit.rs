@@ -0,0 +1,151 @@
+use super::TimeType;
+
+/// The unit of a time value, e.g. as entered by a user or read from a sensor.
+///
+/// Temporal timelines ([`TimeType::DurationNs`] and [`TimeType::TimestampNs`]) store
+/// nanoseconds, and sequence timelines store plain steps: their _native_ unit.
+/// Use this to convert to and from the native unit instead of multiplying by `1e9` by hand.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum TimeUnit {
+    /// Steps of a sequence timeline.
+    Sequence,
+
+    Nanos,
+    Micros,
+    Millis,
+    Secs,
+}
+
+impl TimeUnit {
+    pub const ALL: [Self; 5] = [
+        Self::Sequence,
+        Self::Nanos,
+        Self::Micros,
+        Self::Millis,
+        Self::Secs,
+    ];
+
+    /// The native unit of timelines of the given type.
+    #[inline]
+    pub fn native(typ: TimeType) -> Self {
+        match typ {
+            TimeType::Sequence => Self::Sequence,
+            TimeType::DurationNs | TimeType::TimestampNs => Self::Nanos,
+        }
+    }
+
+    /// Can values of this unit be stored on timelines of the given type?
+    #[inline]
+    pub fn is_compatible_with(self, typ: TimeType) -> bool {
+        (self == Self::Sequence) == (typ == TimeType::Sequence)
+    }
+
+    /// How many native units there are in one of this unit.
+    #[inline]
+    pub const fn native_per_unit(self) -> i64 {
+        match self {
+            Self::Sequence | Self::Nanos => 1,
+            Self::Micros => 1_000,
+            Self::Millis => 1_000_000,
+            Self::Secs => 1_000_000_000,
+        }
+    }
+
+    /// Converts a whole number of this unit to the native unit.
+    ///
+    /// Returns `None` on overflow.
+    #[inline]
+    pub fn checked_to_native(self, value: i64) -> Option<i64> {
+        value.checked_mul(self.native_per_unit())
+    }
+
+    /// Converts a number of this unit to the native unit, rounding to the closest one.
+    ///
+    /// Returns `None` if `value` isn't finite, or on overflow.
+    pub fn checked_to_native_f64(self, value: f64) -> Option<i64> {
+        let native = (value * self.native_per_unit() as f64).round();
+
+        // `i64::MAX as f64` rounds up to 2^63, which is out of range.
+        let in_range = (i64::MIN as f64) <= native && native < (i64::MAX as f64);
+        in_range.then_some(native as i64)
+    }
+
+    /// Converts a value in the native unit to this unit.
+    #[inline]
+    pub fn native_to_f64(self, native: i64) -> f64 {
+        native as f64 / self.native_per_unit() as f64
+    }
+
+    /// What to show after a value of this unit, e.g. `ms`.
+    ///
+    /// Empty for [`Self::Sequence`].
+    #[inline]
+    pub fn suffix(self) -> &'static str {
+        match self {
+            Self::Sequence => "",
+            Self::Nanos => "ns",
+            Self::Micros => "µs",
+            Self::Millis => "ms",
+            Self::Secs => "s",
+        }
+    }
+}
+
+impl std::fmt::Display for TimeUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Sequence => f.write_str("sequence"),
+            _ => f.write_str(self.suffix()),
+        }
+    }
+}
+
+impl std::str::FromStr for TimeUnit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "seq" | "sequence" => Ok(Self::Sequence),
+            "ns" => Ok(Self::Nanos),
+            "us" | "µs" => Ok(Self::Micros),
+            "ms" => Ok(Self::Millis),
+            "s" => Ok(Self::Secs),
+            _ => Err(format!(
+                "Unknown time unit {s:?}, expected one of: seq, ns, us, ms, s"
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn time_unit_conversions() {
+        assert_eq!(TimeUnit::Millis.checked_to_native(42), Some(42_000_000));
+        assert_eq!(TimeUnit::Secs.checked_to_native(i64::MAX / 10), None);
+        assert_eq!(TimeUnit::Sequence.checked_to_native(42), Some(42));
+
+        assert_eq!(
+            TimeUnit::Secs.checked_to_native_f64(1.5),
+            Some(1_500_000_000)
+        );
+        assert_eq!(TimeUnit::Micros.checked_to_native_f64(-0.0004), Some(0));
+        assert_eq!(TimeUnit::Secs.checked_to_native_f64(1e12), None);
+        assert_eq!(TimeUnit::Secs.checked_to_native_f64(f64::NAN), None);
+        assert_eq!(TimeUnit::Nanos.checked_to_native_f64(f64::INFINITY), None);
+
+        assert_eq!(TimeUnit::Millis.native_to_f64(1_500_000), 1.5);
+
+        for unit in TimeUnit::ALL {
+            assert_eq!(unit.to_string().parse::<TimeUnit>(), Ok(unit));
+        }
+        assert!("hours".parse::<TimeUnit>().is_err());
+
+        assert!(TimeUnit::Secs.is_compatible_with(TimeType::TimestampNs));
+        assert!(!TimeUnit::Secs.is_compatible_with(TimeType::Sequence));
+        assert!(!TimeUnit::Sequence.is_compatible_with(TimeType::DurationNs));
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{EntityPath, EntityPathPart, NonMinI64, TimeCell, TimeInt, TimelineName};
+
+/// The named values of a categorical timeline, e.g. the phases `calib`, `run` and `teardown`
+/// of an experiment.
+///
+/// A categorical timeline is a sequence timeline whose values are indices into its categories:
+/// the first category is `0`, the second `1`, etc.
+/// Its categories are logged as a recording property (see [`Self::property_path`]), which the
+/// viewer uses to label the timeline.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TimelineCategories {
+    categories: Vec<String>,
+}
+
+impl TimelineCategories {
+    /// The component holding the names of the categories, in order.
+    pub const CATEGORIES: &'static str = "categories";
+
+    pub fn new(categories: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            categories: categories.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    #[inline]
+    pub fn categories(&self) -> &[String] {
+        &self.categories
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.categories.is_empty()
+    }
+
+    /// The value of the given category on the timeline.
+    pub fn index_of(&self, category: &str) -> Option<i64> {
+        self.categories
+            .iter()
+            .position(|c| c == category)
+            .and_then(|index| i64::try_from(index).ok())
+    }
+
+    /// The name of the category at the given value of the timeline.
+    pub fn label(&self, value: i64) -> Option<&str> {
+        usize::try_from(value)
+            .ok()
+            .and_then(|index| self.categories.get(index))
+            .map(String::as_str)
+    }
+
+    /// The cell to pass to `set_time` to enter the given category.
+    pub fn time_cell(&self, category: &str) -> Option<TimeCell> {
+        self.index_of(category)
+            .and_then(NonMinI64::new)
+            .map(TimeCell::from_sequence)
+    }
+
+    /// Formats a value of the timeline, falling back to its number if it isn't a known category.
+    pub fn format(&self, time: TimeInt) -> String {
+        if time.is_static() {
+            return "<static>".to_owned();
+        }
+
+        match self.label(time.as_i64()) {
+            Some(label) => label.to_owned(),
+            None => format!("#{}", re_format::format_int(time.as_i64())),
+        }
+    }
+
+    /// Parses either the name of a category, or a plain number.
+    pub fn parse(&self, s: &str) -> Option<TimeInt> {
+        let s = s.trim();
+        self.index_of(s)
+            .or_else(|| s.trim_start_matches('#').parse().ok())
+            .map(TimeInt::new_temporal)
+    }
+
+    /// The entity all timeline categories of a recording are logged under.
+    pub fn properties_path() -> EntityPath {
+        EntityPath::properties() / "timeline_categories"
+    }
+
+    /// Where the categories of the given timeline are logged.
+    pub fn property_path(timeline: &TimelineName) -> EntityPath {
+        Self::properties_path() / EntityPathPart::from(timeline.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timeline_categories() {
+        let phases = TimelineCategories::new(["calib", "run", "teardown"]);
+
+        assert_eq!(phases.index_of("run"), Some(1));
+        assert_eq!(phases.index_of("lunch"), None);
+        assert_eq!(phases.label(2), Some("teardown"));
+        assert_eq!(phases.label(3), None);
+        assert_eq!(phases.label(-1), None);
+        assert_eq!(
+            phases.time_cell("teardown"),
+            Some(TimeCell::from_sequence(2))
+        );
+
+        assert_eq!(phases.format(TimeInt::new_temporal(0)), "calib");
+        assert_eq!(phases.format(TimeInt::new_temporal(7)), "#7");
+
+        assert_eq!(phases.parse(" run "), Some(TimeInt::new_temporal(1)));
+        assert_eq!(phases.parse("#7"), Some(TimeInt::new_temporal(7)));
+        assert_eq!(phases.parse("lunch"), None);
+    }
+}
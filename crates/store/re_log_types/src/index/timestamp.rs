@@ -1,6 +1,6 @@
 use std::{ops::RangeInclusive, str::FromStr as _};
 
-use super::{Duration, TimestampFormat};
+use super::{Duration, TimeUnit, TimestampFormat};
 
 use crate::{TimestampFormatKind, external::re_types_core};
 
@@ -25,21 +25,43 @@ impl Timestamp {
         Self(nanos_since_epoch)
     }
 
+    /// Saturates on overflow.
     #[inline]
     pub fn from_us_since_epoch(us_since_epoch: i64) -> Self {
-        Self(us_since_epoch * 1_000)
+        Self(us_since_epoch.saturating_mul(1_000))
     }
 
+    /// Saturates on overflow.
+    #[inline]
+    pub fn from_millis_since_epoch(millis_since_epoch: i64) -> Self {
+        Self(millis_since_epoch.saturating_mul(1_000_000))
+    }
+
+    /// Saturates on overflow.
     #[inline]
     pub fn from_secs_since_epoch(secs: f64) -> Self {
         Self::from_nanos_since_epoch((secs * 1e9).round() as _)
     }
 
+    /// A number of the given temporal unit since the unix epoch, rounded to the closest nanosecond.
+    ///
+    /// Returns `None` for [`TimeUnit::Sequence`], if `value` isn't finite, or on overflow.
+    #[inline]
+    pub fn checked_from_unit_since_epoch(value: f64, unit: TimeUnit) -> Option<Self> {
+        Duration::checked_from_unit(value, unit).map(|since_epoch| Self(since_epoch.as_nanos()))
+    }
+
     #[inline]
     pub fn nanos_since_epoch(self) -> i64 {
         self.0
     }
 
+    /// Time since the unix epoch in the given unit, e.g. `TimeUnit::Secs`.
+    #[inline]
+    pub fn as_unit_since_epoch_f64(self, unit: TimeUnit) -> f64 {
+        unit.native_to_f64(self.0)
+    }
+
     #[inline]
     pub fn elapsed(self) -> Duration {
         Self::now() - self
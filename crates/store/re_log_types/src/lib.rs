@@ -43,9 +43,9 @@ pub use self::{
     data_source_message::{DataSourceMessage, DataSourceUiCommand},
     entry_id::{EntryId, EntryIdOrName},
     index::{
-        AbsoluteTimeRange, AbsoluteTimeRangeF, Duration, NonMinI64, TimeCell, TimeInt, TimePoint,
-        TimeReal, TimeType, Timeline, TimelineName, Timestamp, TimestampFormat,
-        TimestampFormatKind, TryFromIntError,
+        AbsoluteTimeRange, AbsoluteTimeRangeF, AbsoluteTimeRangeSet, Duration, NonMinI64, TimeCell,
+        TimeInt, TimePoint, TimeReal, TimeType, TimeUnit, Timeline, TimelineCategories,
+        TimelineName, Timestamp, TimestampFormat, TimestampFormatKind, TryFromIntError,
     },
     instance::Instance,
     path::*,
@@ -0,0 +1,419 @@
+use std::str::FromStr;
+
+use re_types_core::ComponentIdentifier;
+
+use crate::{EntityPath, EntityPathPart, PathParseError, tokenize_by};
+
+/// Error returned when parsing an [`EntityPathQuery`].
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum EntityPathQueryError {
+    #[error("Path parse error: {0}")]
+    PathParseError(#[from] PathParseError),
+
+    #[error("Missing closing bracket (])")]
+    UnclosedPredicate,
+
+    #[error("Expected a predicate in brackets, found: {0:?}")]
+    UnexpectedTrailing(String),
+
+    #[error("Invalid predicate {0:?}, expected e.g. `[has:Points3D]` or `[Points3D:radii>0.5]`")]
+    InvalidPredicate(String),
+}
+
+/// A part of an [`EntityPathPattern`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EntityPathPatternPart {
+    /// Matches exactly this part.
+    Part(EntityPathPart),
+
+    /// `*`: matches any single part.
+    AnyPart,
+
+    /// `**`: matches any number of parts, including none.
+    AnyParts,
+}
+
+/// An entity path with wildcards, e.g. `/world/*/points` or `/world/**/camera`.
+///
+/// `*` matches any single part, and `**` matches any number of parts, including none.
+/// So `/world/**` matches `/world` itself and its whole subtree, like in an
+/// [`crate::EntityPathFilter`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EntityPathPattern {
+    parts: Vec<EntityPathPatternPart>,
+}
+
+impl EntityPathPattern {
+    #[inline]
+    pub fn parts(&self) -> &[EntityPathPatternPart] {
+        &self.parts
+    }
+
+    /// The path before the first wildcard.
+    ///
+    /// All matching paths are in its subtree.
+    pub fn literal_prefix(&self) -> EntityPath {
+        self.parts
+            .iter()
+            .map_while(|part| match part {
+                EntityPathPatternPart::Part(part) => Some(part.clone()),
+                EntityPathPatternPart::AnyPart | EntityPathPatternPart::AnyParts => None,
+            })
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    pub fn matches(&self, path: &EntityPath) -> bool {
+        matches_parts(&self.parts, path.as_slice())
+    }
+}
+
+fn matches_parts(pattern: &[EntityPathPatternPart], path: &[EntityPathPart]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+
+        Some((EntityPathPatternPart::AnyParts, rest)) => {
+            (0..=path.len()).any(|skipped| matches_parts(rest, &path[skipped..]))
+        }
+
+        Some((EntityPathPatternPart::AnyPart, rest)) => {
+            !path.is_empty() && matches_parts(rest, &path[1..])
+        }
+
+        Some((EntityPathPatternPart::Part(part), rest)) => {
+            path.first() == Some(part) && matches_parts(rest, &path[1..])
+        }
+    }
+}
+
+impl FromStr for EntityPathPattern {
+    type Err = PathParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(PathParseError::EmptyString);
+        }
+
+        let mut parts: Vec<EntityPathPatternPart> = vec![];
+        for token in tokenize_by(s, b"/") {
+            let part = match token {
+                "/" => continue,
+                "*" => EntityPathPatternPart::AnyPart,
+                "**" => EntityPathPatternPart::AnyParts,
+                _ => EntityPathPatternPart::Part(EntityPathPart::parse_strict(token)?),
+            };
+
+            // `/**/**` is the same as `/**`, but would make matching a lot slower.
+            if part == EntityPathPatternPart::AnyParts
+                && parts.last() == Some(&EntityPathPatternPart::AnyParts)
+            {
+                continue;
+            }
+            parts.push(part);
+        }
+
+        Ok(Self { parts })
+    }
+}
+
+impl std::fmt::Display for EntityPathPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.parts.is_empty() {
+            return f.write_str("/");
+        }
+
+        for part in &self.parts {
+            f.write_str("/")?;
+            match part {
+                EntityPathPatternPart::Part(part) => f.write_str(&part.escaped_string())?,
+                EntityPathPatternPart::AnyPart => f.write_str("*")?,
+                EntityPathPatternPart::AnyParts => f.write_str("**")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// How to compare the value of a component in an [`EntityPathPredicate::Compare`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ComparisonOp {
+    Eq,
+    NotEq,
+    Less,
+    LessOrEq,
+    Greater,
+    GreaterOrEq,
+}
+
+impl ComparisonOp {
+    /// Longest first, so that `<=` isn't parsed as `<`.
+    const ALL: [Self; 6] = [
+        Self::NotEq,
+        Self::LessOrEq,
+        Self::GreaterOrEq,
+        Self::Eq,
+        Self::Less,
+        Self::Greater,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Eq => "=",
+            Self::NotEq => "!=",
+            Self::Less => "<",
+            Self::LessOrEq => "<=",
+            Self::Greater => ">",
+            Self::GreaterOrEq => ">=",
+        }
+    }
+
+    #[inline]
+    pub fn compare<T: PartialOrd + ?Sized>(self, lhs: &T, rhs: &T) -> bool {
+        match self {
+            Self::Eq => lhs == rhs,
+            Self::NotEq => lhs != rhs,
+            Self::Less => lhs < rhs,
+            Self::LessOrEq => lhs <= rhs,
+            Self::Greater => lhs > rhs,
+            Self::GreaterOrEq => lhs >= rhs,
+        }
+    }
+}
+
+/// A condition on the data of an entity, e.g. `[has:Points3D]`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EntityPathPredicate {
+    /// `[has:<name>]`: the entity has a component with this identifier, archetype
+    /// or component type, e.g. `[has:Points3D]`, `[has:Color]` or `[has:Points3D:radii]`.
+    ///
+    /// Archetypes and component types are matched by their short name.
+    Has(String),
+
+    /// `[<component><op><value>]`: any instance of the latest value of the component compares
+    /// as requested with `value`, e.g. `[Points3D:radii>0.5]` or `[label=car]`.
+    ///
+    /// Numbers are compared numerically, everything else as text.
+    Compare {
+        component: ComponentIdentifier,
+        op: ComparisonOp,
+        value: String,
+    },
+}
+
+impl FromStr for EntityPathPredicate {
+    type Err = EntityPathQueryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || EntityPathQueryError::InvalidPredicate(s.to_owned());
+        let predicate = s.trim();
+
+        if let Some(name) = predicate.strip_prefix("has:") {
+            let name = name.trim();
+            return if name.is_empty() {
+                Err(invalid())
+            } else {
+                Ok(Self::Has(name.to_owned()))
+            };
+        }
+
+        let op_start = predicate.find(['!', '=', '<', '>']).ok_or_else(invalid)?;
+        let (component, rest) = predicate.split_at(op_start);
+        let op = ComparisonOp::ALL
+            .into_iter()
+            .find(|op| rest.starts_with(op.as_str()))
+            .ok_or_else(invalid)?;
+
+        let component = component.trim();
+        let value = rest[op.as_str().len()..].trim();
+        let value = value
+            .strip_prefix('"')
+            .and_then(|value| value.strip_suffix('"'))
+            .unwrap_or(value);
+        if component.is_empty() {
+            return Err(invalid());
+        }
+
+        Ok(Self::Compare {
+            component: component.into(),
+            op,
+            value: value.to_owned(),
+        })
+    }
+}
+
+impl std::fmt::Display for EntityPathPredicate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Has(name) => write!(f, "[has:{name}]"),
+            Self::Compare {
+                component,
+                op,
+                value,
+            } => {
+                if value.contains(char::is_whitespace) {
+                    write!(f, "[{component}{}\"{value}\"]", op.as_str())
+                } else {
+                    write!(f, "[{component}{}{value}]", op.as_str())
+                }
+            }
+        }
+    }
+}
+
+/// An [`EntityPathPattern`] followed by any number of [`EntityPathPredicate`]s,
+/// e.g. `/world/**[has:Points3D][Points3D:radii>0.5]`.
+///
+/// An entity matches if its path matches the pattern, and its data matches all predicates.
+/// This only checks the path: the predicates are evaluated against a store,
+/// see `ChunkStore::entities_matching`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EntityPathQuery {
+    pub pattern: EntityPathPattern,
+    pub predicates: Vec<EntityPathPredicate>,
+}
+
+impl EntityPathQuery {
+    /// Does the path of the entity match? Predicates aren't checked.
+    #[inline]
+    pub fn matches_path(&self, path: &EntityPath) -> bool {
+        self.pattern.matches(path)
+    }
+}
+
+impl FromStr for EntityPathQuery {
+    type Err = EntityPathQueryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        // The path ends at the first unescaped bracket.
+        let path_len = match tokenize_by(s, b"[").first() {
+            Some(&"[") | None => 0,
+            Some(path) => path.len(),
+        };
+        let (path, mut rest) = s.split_at(path_len);
+        let pattern = path.parse()?;
+
+        let mut predicates = vec![];
+        while !rest.is_empty() {
+            let Some(predicate) = rest.strip_prefix('[') else {
+                return Err(EntityPathQueryError::UnexpectedTrailing(rest.to_owned()));
+            };
+            let Some((predicate, after)) = predicate.split_once(']') else {
+                return Err(EntityPathQueryError::UnclosedPredicate);
+            };
+            predicates.push(predicate.parse()?);
+            rest = after.trim_start();
+        }
+
+        Ok(Self {
+            pattern,
+            predicates,
+        })
+    }
+}
+
+impl std::fmt::Display for EntityPathQuery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.pattern.fmt(f)?;
+        for predicate in &self.predicates {
+            predicate.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pattern_matching() {
+        let matches = |pattern: &str, path: &str| {
+            pattern
+                .parse::<EntityPathPattern>()
+                .unwrap()
+                .matches(&EntityPath::from(path))
+        };
+
+        assert!(matches("/world/points", "/world/points"));
+        assert!(!matches("/world/points", "/world/points/child"));
+
+        assert!(matches("/world/*/points", "/world/car/points"));
+        assert!(!matches("/world/*/points", "/world/points"));
+        assert!(!matches("/world/*/points", "/world/car/wheel/points"));
+
+        assert!(matches("/world/**", "/world"));
+        assert!(matches("/world/**", "/world/car/wheel"));
+        assert!(!matches("/world/**", "/worlds"));
+        assert!(matches("/**", "/"));
+
+        assert!(matches("/world/**/points", "/world/points"));
+        assert!(matches("/world/**/points", "/world/car/wheel/points"));
+        assert!(!matches(
+            "/world/**/points",
+            "/world/car/wheel/points/child"
+        ));
+        assert!(matches("/**/**/points", "/points"));
+    }
+
+    #[test]
+    fn literal_prefix() {
+        let pattern: EntityPathPattern = "/world/car/**/points".parse().unwrap();
+        assert_eq!(pattern.literal_prefix(), EntityPath::from("/world/car"));
+
+        let pattern: EntityPathPattern = "/*/car".parse().unwrap();
+        assert_eq!(pattern.literal_prefix(), EntityPath::root());
+    }
+
+    #[test]
+    fn parse_query() {
+        let query: EntityPathQuery =
+            "/world/**[has:Points3D] [Points3D:radii >= 0.5][label=\"a b\"]"
+                .parse()
+                .unwrap();
+
+        assert_eq!(query.pattern.to_string(), "/world/**");
+        assert_eq!(
+            query.predicates,
+            vec![
+                EntityPathPredicate::Has("Points3D".to_owned()),
+                EntityPathPredicate::Compare {
+                    component: "Points3D:radii".into(),
+                    op: ComparisonOp::GreaterOrEq,
+                    value: "0.5".to_owned(),
+                },
+                EntityPathPredicate::Compare {
+                    component: "label".into(),
+                    op: ComparisonOp::Eq,
+                    value: "a b".to_owned(),
+                },
+            ]
+        );
+
+        for roundtrip in [
+            "/",
+            "/world/*/points",
+            "/world/**[has:Color][Points3D:radii!=1]",
+            "/world[label=\"a b\"]",
+        ] {
+            assert_eq!(
+                roundtrip.parse::<EntityPathQuery>().unwrap().to_string(),
+                roundtrip
+            );
+        }
+
+        assert_eq!(
+            "/world[has:Points3D".parse::<EntityPathQuery>(),
+            Err(EntityPathQueryError::UnclosedPredicate)
+        );
+        assert_eq!(
+            "/world[has:Points3D]oops".parse::<EntityPathQuery>(),
+            Err(EntityPathQueryError::UnexpectedTrailing("oops".to_owned()))
+        );
+        assert!("/world[has:]".parse::<EntityPathQuery>().is_err());
+        assert!("/world[radii]".parse::<EntityPathQuery>().is_err());
+        assert!("[has:Points3D]".parse::<EntityPathQuery>().is_err());
+    }
+}
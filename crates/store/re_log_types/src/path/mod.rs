@@ -8,6 +8,7 @@ mod data_path;
 mod entity_path;
 mod entity_path_filter;
 mod entity_path_part;
+mod entity_path_query;
 pub mod natural_ordering;
 mod parse_path;
 
@@ -19,6 +20,10 @@ pub use entity_path_filter::{
     ResolvedEntityPathFilter, ResolvedEntityPathRule, RuleEffect,
 };
 pub use entity_path_part::EntityPathPart;
+pub use entity_path_query::{
+    ComparisonOp, EntityPathPattern, EntityPathPatternPart, EntityPathPredicate, EntityPathQuery,
+    EntityPathQueryError,
+};
 pub use parse_path::{PathParseError, tokenize_by};
 
 // ----------------------------------------------------------------------------
@@ -22,6 +22,11 @@ pub const RERUN_HTTP_HEADER_CLIENT_VERSION: &str = "x-rerun-client-version";
 /// propagating it into our gRPC metrics, traces and metrics.
 pub const RERUN_HTTP_HEADER_SERVER_VERSION: &str = "x-rerun-server-version";
 
+/// The HTTP header key SDK clients use to name themselves when writing to a gRPC proxy server.
+///
+/// Lets the server tell apart several clients writing to the same recording.
+pub const RERUN_HTTP_HEADER_CLIENT_NAME: &str = "x-rerun-client-name";
+
 /// Extension trait for [`tonic::Request`] to inject Rerun Data Protocol headers into gRPC requests.
 ///
 /// Example:
@@ -37,6 +37,23 @@ impl From<crate::log_msg::v1alpha1::log_msg::Msg> for crate::log_msg::v1alpha1::
     }
 }
 
+impl crate::log_msg::v1alpha1::LogMsg {
+    /// The encoded size of this message, as sent over the wire and once its payload is decompressed.
+    pub fn encoded_sizes(&self) -> (u64, u64) {
+        use crate::log_msg::v1alpha1::log_msg::Msg;
+        use prost::Message as _;
+
+        let encoded_len = self.encoded_len() as u64;
+        let uncompressed_len = match &self.msg {
+            Some(Msg::ArrowMsg(arrow_msg)) => {
+                encoded_len - arrow_msg.payload.len() as u64 + arrow_msg.uncompressed_size
+            }
+            Some(Msg::SetStoreInfo(_) | Msg::BlueprintActivationCommand(_)) | None => encoded_len,
+        };
+        (encoded_len, uncompressed_len)
+    }
+}
+
 impl From<re_log_types::StoreSource> for crate::log_msg::v1alpha1::StoreSource {
     #[inline]
     fn from(value: re_log_types::StoreSource) -> Self {
@@ -4,7 +4,7 @@ use std::{
 };
 
 use ahash::HashMap;
-use nohash_hasher::IntSet;
+use nohash_hasher::{IntMap, IntSet};
 use parking_lot::RwLock;
 
 use re_chunk::{ChunkId, ComponentIdentifier};
@@ -14,7 +14,7 @@ use re_chunk_store::{
 use re_log_types::{AbsoluteTimeRange, EntityPath, StoreId, TimeInt, TimelineName};
 use re_types_core::archetypes;
 
-use crate::{LatestAtCache, RangeCache};
+use crate::{DerivedComponent, LatestAtCache, RangeCache};
 
 // ---
 
@@ -159,6 +159,9 @@ pub struct QueryCache {
 
     // NOTE: `Arc` so we can cheaply free the top-level lock early when needed.
     pub(crate) range_per_cache_key: RwLock<HashMap<QueryCacheKey, Arc<RwLock<RangeCache>>>>,
+
+    /// All registered [`DerivedComponent`]s, indexed by the component they derive.
+    pub(crate) derived_components: RwLock<IntMap<ComponentIdentifier, Arc<dyn DerivedComponent>>>,
 }
 
 impl std::fmt::Debug for QueryCache {
@@ -169,6 +172,7 @@ impl std::fmt::Debug for QueryCache {
             might_require_clearing,
             latest_at_per_cache_key,
             range_per_cache_key,
+            derived_components: _,
         } = self;
 
         let mut strings = Vec::new();
@@ -238,6 +242,7 @@ impl QueryCache {
             might_require_clearing: Default::default(),
             latest_at_per_cache_key: Default::default(),
             range_per_cache_key: Default::default(),
+            derived_components: Default::default(),
         }
     }
 
@@ -254,6 +259,7 @@ impl QueryCache {
             might_require_clearing,
             latest_at_per_cache_key,
             range_per_cache_key,
+            derived_components: _, // Not a cache.
         } = self;
 
         might_require_clearing.write().clear();
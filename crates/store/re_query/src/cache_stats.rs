@@ -33,6 +33,19 @@ impl QueryCachesStats {
 
         latest_at_size_bytes + range_size_bytes
     }
+
+    /// Total number of cache hits and misses, across all caches.
+    #[inline]
+    pub fn total_hits_and_misses(&self) -> (u64, u64) {
+        let Self { latest_at, range } = self;
+
+        latest_at
+            .values()
+            .chain(range.values())
+            .fold((0, 0), |(num_hits, num_misses), stats| {
+                (num_hits + stats.num_hits, num_misses + stats.num_misses)
+            })
+    }
 }
 
 /// Stats for a single `crate::RangeCache`.
@@ -47,6 +60,12 @@ pub struct QueryCacheStats {
 
     /// What is the actual size of this cache after deduplication?
     pub total_actual_size_bytes: u64,
+
+    /// How many lookups were answered from this cache?
+    pub num_hits: u64,
+
+    /// How many lookups had to go to the store?
+    pub num_misses: u64,
 }
 
 impl QueryCache {
@@ -72,6 +91,8 @@ impl QueryCache {
                                 .map(|cached| cached.unit.total_size_bytes())
                                 .sum(),
                             total_actual_size_bytes: cache.per_query_time.total_size_bytes(),
+                            num_hits: cache.num_hits,
+                            num_misses: cache.num_misses,
                         },
                     )
                 })
@@ -97,6 +118,8 @@ impl QueryCache {
                                 .map(|cached| cached.chunk.total_size_bytes())
                                 .sum(),
                             total_actual_size_bytes: cache.chunks.total_size_bytes(),
+                            num_hits: cache.num_hits,
+                            num_misses: cache.num_misses,
                         },
                     )
                 })
@@ -0,0 +1,422 @@
+use std::sync::Arc;
+
+use arrow::{
+    array::{Array as _, ArrayRef, AsArray as _, FixedSizeListArray, Float64Array},
+    datatypes::{DataType, Float64Type},
+};
+
+use re_chunk::{Chunk, ChunkId, ComponentIdentifier, LatestAtQuery, RangeQuery, UnitChunkShared};
+use re_chunk_store::ChunkStore;
+use re_log_types::{EntityPath, TimeInt, TimeType, Timeline, TimelineName};
+use re_types_core::{ComponentDescriptor, SerializedComponentColumn};
+
+use crate::QueryCache;
+
+// --- Public API ---
+
+/// A component that isn't stored anywhere, but computed from another one at query time.
+///
+/// Once registered with [`QueryCache::register_derived_component`], derived components can be
+/// queried just like any other component, using both [`QueryCache::latest_at`] and
+/// [`QueryCache::range`]: as far as views are concerned, they are regular components.
+///
+/// Nothing is ever computed unless the derived component is actually queried.
+///
+/// See [`FiniteDifference`] and [`Norm`] for built-in implementations.
+pub trait DerivedComponent: Send + Sync {
+    /// The stored component this is derived from.
+    ///
+    /// Derived components cannot be derived from other derived components.
+    fn source(&self) -> ComponentIdentifier;
+
+    /// Describes the derived component.
+    ///
+    /// Its [`ComponentDescriptor::component`] is what should be queried.
+    fn descriptor(&self) -> &ComponentDescriptor;
+
+    /// How many of the preceding rows of source data are required to derive a row.
+    ///
+    /// E.g. `1` for a finite difference, `0` for anything that works on a single row.
+    fn num_preceding_rows(&self) -> usize {
+        0
+    }
+
+    /// Derives one batch of data for each of the given rows of source data.
+    ///
+    /// `rows` are sorted on `timeline`, and start with (up to) [`Self::num_preceding_rows`]
+    /// rows that precede the rows being queried, if any.
+    ///
+    /// Must return exactly as many items as there are `rows`. `None` means that there is no
+    /// derived data for that row.
+    fn derive(&self, timeline: &Timeline, rows: &[(TimeInt, ArrayRef)]) -> Vec<Option<ArrayRef>>;
+}
+
+impl QueryCache {
+    /// Registers a [`DerivedComponent`], which can then be queried like any stored component.
+    ///
+    /// Replaces any [`DerivedComponent`] previously registered with the same component identifier.
+    /// Components that are actually stored always take precedence over derived ones.
+    pub fn register_derived_component(&self, derived: impl DerivedComponent + 'static) {
+        let component = derived.descriptor().component;
+        self.derived_components
+            .write()
+            .insert(component, Arc::new(derived));
+    }
+
+    /// Returns the descriptors of all the derived components that can be queried for the given
+    /// entity on the given timeline.
+    pub fn derived_components_for_entity(
+        &self,
+        timeline: &TimelineName,
+        entity_path: &EntityPath,
+    ) -> Vec<ComponentDescriptor> {
+        let store = self.store.read();
+        self.derived_components
+            .read()
+            .values()
+            .filter(|derived| {
+                store.entity_has_component_on_timeline(timeline, entity_path, derived.source())
+            })
+            .map(|derived| derived.descriptor().clone())
+            .collect()
+    }
+
+    /// Returns the [`DerivedComponent`] to be used for `component`, if it isn't stored as-is.
+    pub(crate) fn derived_component(
+        &self,
+        store: &ChunkStore,
+        timeline: &TimelineName,
+        entity_path: &EntityPath,
+        component: ComponentIdentifier,
+    ) -> Option<Arc<dyn DerivedComponent>> {
+        let derived_components = self.derived_components.read();
+        if derived_components.is_empty() {
+            return None;
+        }
+
+        let derived = derived_components.get(&component)?;
+        (!store.entity_has_component_on_timeline(timeline, entity_path, component)
+            && store.entity_has_component_on_timeline(timeline, entity_path, derived.source()))
+        .then(|| Arc::clone(derived))
+    }
+
+    /// Derives the latest-at value of a [`DerivedComponent`].
+    pub(crate) fn latest_at_derived(
+        &self,
+        query: &LatestAtQuery,
+        entity_path: &EntityPath,
+        derived: &dyn DerivedComponent,
+    ) -> Option<UnitChunkShared> {
+        re_tracing::profile_function!();
+
+        let timeline = self.timeline(&query.timeline());
+        let source = derived.source();
+
+        let mut units = self.latest_units(
+            query.clone(),
+            entity_path,
+            source,
+            derived.num_preceding_rows() + 1,
+        );
+        let latest = units.pop()?;
+
+        let rows = units
+            .iter()
+            .chain(std::iter::once(&latest))
+            .filter_map(|unit| {
+                Some((
+                    unit.index(&query.timeline())?.0,
+                    unit.component_batch_raw(source)?,
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        let batch = derived.derive(&timeline, &rows).pop()??;
+        derived_chunk(&latest, derived, &[Some(batch)])?.into_unit()
+    }
+
+    /// Derives the values of a [`DerivedComponent`] over a range.
+    pub(crate) fn range_derived(
+        &self,
+        query: &RangeQuery,
+        entity_path: &EntityPath,
+        derived: &dyn DerivedComponent,
+    ) -> Vec<Chunk> {
+        re_tracing::profile_function!();
+
+        let timeline = self.timeline(query.timeline());
+        let source = derived.source();
+        let num_preceding_rows = derived.num_preceding_rows();
+
+        // The rows right before the range, so that the first rows within can be derived too.
+        let mut preceding: Vec<(TimeInt, ArrayRef)> =
+            if num_preceding_rows > 0 && query.range().min() > TimeInt::MIN {
+                let before = LatestAtQuery::new(*query.timeline(), query.range().min().dec());
+                self.latest_units(before, entity_path, source, num_preceding_rows)
+                    .iter()
+                    .filter_map(|unit| {
+                        Some((
+                            unit.index(query.timeline())?.0,
+                            unit.component_batch_raw(source)?,
+                        ))
+                    })
+                    .collect()
+            } else {
+                Vec::new()
+            };
+
+        let mut results = self.range(query, entity_path, [source]);
+        let chunks = results.components.remove(&source).unwrap_or_default();
+
+        chunks
+            .into_iter()
+            .filter_map(|chunk| {
+                let list_array = chunk.components().get_array(source)?;
+
+                // Range results are densified on the queried component.
+                let mut rows = std::mem::take(&mut preceding);
+                let num_preceding = rows.len();
+                rows.extend(
+                    chunk
+                        .iter_component_indices(*query.timeline(), source)
+                        .zip((0..list_array.len()).filter(|&i| list_array.is_valid(i)))
+                        .map(|((time, _row_id), i)| (time, list_array.value(i))),
+                );
+                let num_rows = rows.len();
+
+                let batches = derived.derive(&timeline, &rows);
+                preceding = rows.split_off(num_rows.saturating_sub(num_preceding_rows));
+
+                if num_rows - num_preceding != chunk.num_rows() || batches.len() != num_rows {
+                    re_log::warn_once!(
+                        "Derived component {} yielded the wrong number of rows",
+                        derived.descriptor()
+                    );
+                    return None;
+                }
+
+                derived_chunk(&chunk, derived, &batches[num_preceding..])
+            })
+            .collect()
+    }
+
+    /// Returns up to `n` latest-at results for `component`, walking back in time from `query`.
+    ///
+    /// Oldest first.
+    fn latest_units(
+        &self,
+        mut query: LatestAtQuery,
+        entity_path: &EntityPath,
+        component: ComponentIdentifier,
+        n: usize,
+    ) -> Vec<UnitChunkShared> {
+        let mut units = Vec::with_capacity(n);
+
+        while units.len() < n {
+            let mut results = self.latest_at(&query, entity_path, [component]);
+            let Some(unit) = results.components.remove(&component) else {
+                break;
+            };
+            let (time, _row_id) = results.compound_index;
+            units.push(unit);
+
+            if time == TimeInt::STATIC || time == TimeInt::MIN {
+                break;
+            }
+            query = LatestAtQuery::new(query.timeline(), time.dec());
+        }
+
+        units.reverse();
+        units
+    }
+
+    fn timeline(&self, timeline: &TimelineName) -> Timeline {
+        let typ = self
+            .store
+            .read()
+            .time_column_type(timeline)
+            .unwrap_or(TimeType::Sequence);
+        Timeline::new(*timeline, typ)
+    }
+}
+
+/// Builds the chunk holding the derived data of `source_chunk`, one batch per row.
+fn derived_chunk(
+    source_chunk: &Chunk,
+    derived: &dyn DerivedComponent,
+    batches: &[Option<ArrayRef>],
+) -> Option<Chunk> {
+    let arrays = batches
+        .iter()
+        .map(|batch| batch.as_deref())
+        .collect::<Vec<_>>();
+    let list_array = re_arrow_util::arrays_to_list_array_opt(&arrays)?;
+
+    let descriptor = derived.descriptor().clone();
+    let component = descriptor.component;
+
+    let mut chunk = source_chunk
+        .clone()
+        .components_removed()
+        .with_id(ChunkId::new());
+    if let Err(err) = chunk.add_component(SerializedComponentColumn::new(list_array, descriptor)) {
+        re_log::warn_once!("Failed to derive component {component}: {err}");
+        return None;
+    }
+
+    Some(chunk.densified(component))
+}
+
+// --- Built-in derived components ---
+
+/// Derives the rate of change of a scalar or vector component, e.g. velocity from positions.
+///
+/// The rate of change is expressed per second on temporal timelines, and per step on sequence
+/// timelines.
+///
+/// Each instance is derived from the instance at the same index in the preceding row.
+/// Rows whose number of instances differs from the preceding one yield nothing.
+pub struct FiniteDifference {
+    source: ComponentIdentifier,
+    descriptor: ComponentDescriptor,
+}
+
+impl FiniteDifference {
+    /// Derives `descriptor` from the `source` component.
+    ///
+    /// `source` must be a numeric component, or a fixed-size list thereof.
+    pub fn new(source: impl Into<ComponentIdentifier>, descriptor: ComponentDescriptor) -> Self {
+        Self {
+            source: source.into(),
+            descriptor,
+        }
+    }
+}
+
+impl DerivedComponent for FiniteDifference {
+    fn source(&self) -> ComponentIdentifier {
+        self.source
+    }
+
+    fn descriptor(&self) -> &ComponentDescriptor {
+        &self.descriptor
+    }
+
+    fn num_preceding_rows(&self) -> usize {
+        1
+    }
+
+    fn derive(&self, timeline: &Timeline, rows: &[(TimeInt, ArrayRef)]) -> Vec<Option<ArrayRef>> {
+        let seconds_per_step = match timeline.typ() {
+            TimeType::Sequence => 1.0,
+            TimeType::DurationNs | TimeType::TimestampNs => 1e-9,
+        };
+
+        let mut derived = vec![None];
+        derived.extend(rows.windows(2).map(|window| {
+            let [(prev_time, prev), (time, cur)] = window else {
+                return None;
+            };
+            if prev_time.is_static() || time.is_static() {
+                return None;
+            }
+
+            let dt = (time.as_i64() - prev_time.as_i64()) as f64 * seconds_per_step;
+            if dt <= 0.0 {
+                return None;
+            }
+
+            let (_, prev_values) = flattened_f64(prev)?;
+            let (_, values) = flattened_f64(cur)?;
+            if prev_values.len() != values.len() {
+                return None;
+            }
+
+            let rates = values
+                .iter()
+                .zip(&prev_values)
+                .map(|(value, prev_value)| (value - prev_value) / dt)
+                .collect();
+            unflattened_like(cur, rates)
+        }));
+        derived.truncate(rows.len());
+
+        derived
+    }
+}
+
+/// Derives the euclidean norm of each instance of a vector component, e.g. speed from velocity.
+///
+/// The derived component is always made of `f64`s, which can be plotted as-is.
+pub struct Norm {
+    source: ComponentIdentifier,
+    descriptor: ComponentDescriptor,
+}
+
+impl Norm {
+    /// Derives `descriptor` from the `source` component.
+    ///
+    /// `source` must be a numeric component, or a fixed-size list thereof.
+    pub fn new(source: impl Into<ComponentIdentifier>, descriptor: ComponentDescriptor) -> Self {
+        Self {
+            source: source.into(),
+            descriptor,
+        }
+    }
+}
+
+impl DerivedComponent for Norm {
+    fn source(&self) -> ComponentIdentifier {
+        self.source
+    }
+
+    fn descriptor(&self) -> &ComponentDescriptor {
+        &self.descriptor
+    }
+
+    fn derive(&self, _timeline: &Timeline, rows: &[(TimeInt, ArrayRef)]) -> Vec<Option<ArrayRef>> {
+        rows.iter()
+            .map(|(_time, batch)| {
+                let (num_dims, values) = flattened_f64(batch)?;
+                let norms: Float64Array = values
+                    .chunks_exact(num_dims)
+                    .map(|vector| vector.iter().map(|x| x * x).sum::<f64>().sqrt())
+                    .collect();
+                Some(Arc::new(norms) as ArrayRef)
+            })
+            .collect()
+    }
+}
+
+/// Flattens a batch of numbers, or of fixed-size lists of numbers, into `f64`s.
+///
+/// Also returns the number of values per instance.
+fn flattened_f64(batch: &ArrayRef) -> Option<(usize, Vec<f64>)> {
+    let (num_dims, values) = if let Some(list) = batch.as_fixed_size_list_opt() {
+        (list.value_length() as usize, Arc::clone(list.values()))
+    } else {
+        (1, Arc::clone(batch))
+    };
+
+    if num_dims == 0 {
+        return None;
+    }
+
+    let values = arrow::compute::cast(&values, &DataType::Float64).ok()?;
+    let values = values.as_primitive_opt::<Float64Type>()?;
+
+    Some((num_dims, values.values().to_vec()))
+}
+
+/// The inverse of [`flattened_f64`]: casts `values` back to the datatype of `like`.
+fn unflattened_like(like: &ArrayRef, values: Vec<f64>) -> Option<ArrayRef> {
+    let values: ArrayRef = Arc::new(Float64Array::from(values));
+
+    if let DataType::FixedSizeList(field, num_dims) = like.data_type() {
+        let values = arrow::compute::cast(&values, field.data_type()).ok()?;
+        let list = FixedSizeListArray::try_new(Arc::clone(field), *num_dims, values, None).ok()?;
+        Some(Arc::new(list))
+    } else {
+        arrow::compute::cast(&values, like.data_type()).ok()
+    }
+}
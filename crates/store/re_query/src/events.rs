@@ -0,0 +1,190 @@
+use arrow::{
+    array::{Array as _, AsArray as _},
+    datatypes::{DataType, Float64Type},
+};
+
+use re_chunk::{ComponentIdentifier, RangeQuery};
+use re_log_types::{AbsoluteTimeRange, AbsoluteTimeRangeSet, EntityPath, TimeInt};
+
+use crate::{QueryCache, QueryError};
+
+// --- Public API ---
+
+/// How a value is compared to the threshold of a [`ScalarPredicate`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ScalarComparison {
+    Less,
+    LessOrEqual,
+
+    #[default]
+    Greater,
+    GreaterOrEqual,
+    Equal,
+    NotEqual,
+}
+
+impl ScalarComparison {
+    pub const ALL: [Self; 6] = [
+        Self::Less,
+        Self::LessOrEqual,
+        Self::Greater,
+        Self::GreaterOrEqual,
+        Self::Equal,
+        Self::NotEqual,
+    ];
+
+    /// The operator this is written as, e.g. `>=`.
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::Less => "<",
+            Self::LessOrEqual => "<=",
+            Self::Greater => ">",
+            Self::GreaterOrEqual => ">=",
+            Self::Equal => "==",
+            Self::NotEqual => "!=",
+        }
+    }
+}
+
+impl std::fmt::Display for ScalarComparison {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.symbol())
+    }
+}
+
+/// A condition on the value of a scalar, e.g. `> 2.0`.
+///
+/// Can be parsed from, and displayed as, an operator followed by a number.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ScalarPredicate {
+    pub comparison: ScalarComparison,
+    pub threshold: f64,
+}
+
+impl ScalarPredicate {
+    pub fn new(comparison: ScalarComparison, threshold: f64) -> Self {
+        Self {
+            comparison,
+            threshold,
+        }
+    }
+
+    /// Whether `value` satisfies this predicate.
+    ///
+    /// `NaN` never does.
+    #[inline]
+    pub fn holds(&self, value: f64) -> bool {
+        let threshold = self.threshold;
+        match self.comparison {
+            ScalarComparison::Less => value < threshold,
+            ScalarComparison::LessOrEqual => value <= threshold,
+            ScalarComparison::Greater => value > threshold,
+            ScalarComparison::GreaterOrEqual => value >= threshold,
+            ScalarComparison::Equal => value == threshold,
+            ScalarComparison::NotEqual => !value.is_nan() && value != threshold,
+        }
+    }
+}
+
+impl std::fmt::Display for ScalarPredicate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.comparison, self.threshold)
+    }
+}
+
+impl std::str::FromStr for ScalarPredicate {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        // Longest operators first, so that `>=` isn't read as `>` followed by `=2`.
+        let mut comparisons = ScalarComparison::ALL;
+        comparisons.sort_by_key(|comparison| std::cmp::Reverse(comparison.symbol().len()));
+
+        let (comparison, threshold) = comparisons
+            .into_iter()
+            .find_map(|comparison| {
+                s.strip_prefix(comparison.symbol())
+                    .map(|rest| (comparison, rest))
+            })
+            .ok_or_else(|| format!("expected one of <, <=, >, >=, ==, != in {s:?}"))?;
+
+        let threshold = threshold
+            .trim()
+            .parse::<f64>()
+            .map_err(|err| format!("invalid threshold {threshold:?}: {err}"))?;
+
+        Ok(Self::new(comparison, threshold))
+    }
+}
+
+impl QueryCache {
+    /// Scans the values of `component` over the range of `query`, and returns the times at which
+    /// `predicate` holds.
+    ///
+    /// Each returned range goes from the first to the last of a run of consecutive rows that
+    /// satisfy the predicate, so an event that lasts a single row is a single point in time.
+    /// A row satisfies the predicate if any of its values does.
+    ///
+    /// The values are cast to `f64` beforehand, which fails for components that aren't numeric.
+    /// Static data is ignored.
+    pub fn find_time_ranges(
+        &self,
+        query: &RangeQuery,
+        entity_path: &EntityPath,
+        component: ComponentIdentifier,
+        predicate: impl Fn(f64) -> bool,
+    ) -> Result<AbsoluteTimeRangeSet, QueryError> {
+        re_tracing::profile_function!();
+
+        let mut results = self.range(query, entity_path, [component]);
+        let chunks = results.components.remove(&component).unwrap_or_default();
+
+        let mut rows: Vec<(TimeInt, bool)> = Vec::new();
+        for chunk in &chunks {
+            if chunk.is_static() {
+                continue;
+            }
+
+            let Some(list_array) = chunk.components().get_array(component) else {
+                continue;
+            };
+            let values = arrow::compute::cast(list_array.values(), &DataType::Float64)
+                .map_err(|err| QueryError::Other(err.into()))?;
+            let values = values.as_primitive::<Float64Type>();
+            let offsets = list_array.value_offsets();
+
+            // Range results are densified on the queried component.
+            rows.extend(
+                chunk
+                    .iter_component_indices(*query.timeline(), component)
+                    .zip((0..list_array.len()).filter(|&i| list_array.is_valid(i)))
+                    .map(|((time, _row_id), i)| {
+                        let (start, end) = (offsets[i] as usize, offsets[i + 1] as usize);
+                        let holds =
+                            (start..end).any(|j| values.is_valid(j) && predicate(values.value(j)));
+                        (time, holds)
+                    }),
+            );
+        }
+
+        // Chunks may overlap in time.
+        rows.sort_by_key(|(time, _)| *time);
+
+        let mut ranges = Vec::new();
+        let mut current: Option<AbsoluteTimeRange> = None;
+        for (time, holds) in rows {
+            if holds {
+                current = Some(current.map_or(AbsoluteTimeRange::point(time), |range| {
+                    AbsoluteTimeRange::new(range.min, time)
+                }));
+            } else if let Some(range) = current.take() {
+                ranges.push(range);
+            }
+        }
+        ranges.extend(current);
+
+        Ok(AbsoluteTimeRangeSet::new(ranges))
+    }
+}
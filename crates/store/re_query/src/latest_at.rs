@@ -54,11 +54,23 @@ impl QueryCache {
 
         let mut results = LatestAtResults::empty(entity_path.clone(), query.clone());
 
+        let mut derived_components = Vec::new();
+
         // NOTE: This pre-filtering is extremely important: going through all these query layers
         // has non-negligible overhead even if the final result ends up being nothing, and our
         // number of queries for a frame grows linearly with the number of entity paths.
         let components = components.into_iter().filter(|component| {
-            store.entity_has_component_on_timeline(&query.timeline(), entity_path, *component)
+            if store.entity_has_component_on_timeline(&query.timeline(), entity_path, *component) {
+                return true;
+            }
+
+            if let Some(derived) =
+                self.derived_component(&store, &query.timeline(), entity_path, *component)
+            {
+                derived_components.push((*component, derived));
+            }
+
+            false
         });
 
         // Query-time clears
@@ -162,6 +174,16 @@ impl QueryCache {
             }
         }
 
+        // Derived components are computed from the results of regular queries, which are
+        // themselves subject to clears already.
+        for (component, derived) in derived_components {
+            if let Some(unit) = self.latest_at_derived(query, entity_path, derived.as_ref())
+                && let Some(index) = unit.index(&query.timeline())
+            {
+                results.add(component, index, unit);
+            }
+        }
+
         results
     }
 
@@ -183,6 +205,7 @@ impl QueryCache {
                 // future result that in fact should have been.
                 // See `handle_pending_invalidation` for more information.
                 cache.per_query_time = cache.per_query_time.split_off(&split_time);
+                cache.per_data_time_validity = cache.per_data_time_validity.split_off(&split_time);
             }
         }
     }
@@ -525,6 +548,15 @@ pub struct LatestAtCache {
     /// out what to render, and this scales linearly with the number of entity.
     pub per_query_time: BTreeMap<TimeInt, LatestAtCachedChunk>,
 
+    /// For each cached _data time_, the most recent _query time_ known to still yield that data.
+    ///
+    /// Since latest-at results can only change when data is added or removed, any query time in
+    /// between the data time and this upper bound (inclusive) yields the exact same result: scrubbing
+    /// within that interval is a pure cache hit, without ever touching the store.
+    ///
+    /// Static data is valid for all query times.
+    pub per_data_time_validity: BTreeMap<TimeInt, TimeInt>,
+
     /// These timestamps have been invalidated asynchronously.
     ///
     /// The next time this cache gets queried, it must remove any invalidated entries accordingly.
@@ -532,6 +564,12 @@ pub struct LatestAtCache {
     /// Invalidation is deferred to query time because it is far more efficient that way: the frame
     /// time effectively behaves as a natural micro-batching mechanism.
     pub pending_invalidations: BTreeSet<TimeInt>,
+
+    /// How many queries were answered from the cache.
+    pub num_hits: u64,
+
+    /// How many queries had to go to the store.
+    pub num_misses: u64,
 }
 
 impl LatestAtCache {
@@ -540,7 +578,10 @@ impl LatestAtCache {
         Self {
             cache_key,
             per_query_time: Default::default(),
+            per_data_time_validity: Default::default(),
             pending_invalidations: Default::default(),
+            num_hits: 0,
+            num_misses: 0,
         }
     }
 }
@@ -551,7 +592,10 @@ impl std::fmt::Debug for LatestAtCache {
         let Self {
             cache_key: _,
             per_query_time,
+            per_data_time_validity: _,
             pending_invalidations: _,
+            num_hits: _,
+            num_misses: _,
         } = self;
 
         let mut strings = Vec::new();
@@ -603,13 +647,17 @@ impl SizeBytes for LatestAtCache {
         let Self {
             cache_key: _,
             per_query_time,
+            per_data_time_validity,
             pending_invalidations,
+            num_hits: _,
+            num_misses: _,
         } = self;
 
         let per_query_time = per_query_time.total_size_bytes();
+        let per_data_time_validity = per_data_time_validity.total_size_bytes();
         let pending_invalidations = pending_invalidations.total_size_bytes();
 
-        per_query_time + pending_invalidations
+        per_query_time + per_data_time_validity + pending_invalidations
     }
 }
 
@@ -630,13 +678,26 @@ impl LatestAtCache {
         let Self {
             cache_key: _,
             per_query_time,
+            per_data_time_validity,
             pending_invalidations: _,
+            num_hits,
+            num_misses,
         } = self;
 
         if let Some(cached) = per_query_time.get(&query.at()) {
+            *num_hits += 1;
             return Some(cached.unit.clone());
         }
 
+        if let Some(cached) =
+            cached_within_validity(per_query_time, per_data_time_validity, query.at())
+        {
+            *num_hits += 1;
+            return Some(cached.unit.clone());
+        }
+
+        *num_misses += 1;
+
         let ((data_time, _row_id), unit) = store
             .latest_at_relevant_chunks(query, entity_path, component)
             .into_iter()
@@ -654,6 +715,18 @@ impl LatestAtCache {
             })
             .clone();
 
+        // We just saw that querying at `query.at()` yields the data at `data_time`, so it must be
+        // the case for all query times in between as well.
+        let valid_until = if data_time.is_static() {
+            TimeInt::MAX
+        } else {
+            query.at()
+        };
+        let validity = per_data_time_validity
+            .entry(data_time)
+            .or_insert(valid_until);
+        *validity = (*validity).max(valid_until);
+
         // NOTE: Queries that return static data are much cheaper to run, and polluting the query-time cache
         // just to point to the static tables again and again is very wasteful.
         if query.at() != data_time && !data_time.is_static() {
@@ -672,10 +745,22 @@ impl LatestAtCache {
         let Self {
             cache_key: _,
             per_query_time,
+            per_data_time_validity,
             pending_invalidations,
+            num_hits: _,
+            num_misses: _,
         } = self;
 
         if let Some(oldest_data_time) = pending_invalidations.first() {
+            // Any data time that's at least as recent as the invalidated one is gone, and older data
+            // times are not valid anymore from the invalidated data time onwards.
+            per_data_time_validity.split_off(oldest_data_time);
+            for (data_time, valid_until) in per_data_time_validity.iter_mut() {
+                if !data_time.is_static() && *valid_until >= *oldest_data_time {
+                    *valid_until = oldest_data_time.dec();
+                }
+            }
+
             // Remove any data indexed by a _query time_ that's more recent than the oldest
             // _data time_ that's been invalidated.
             //
@@ -701,3 +786,21 @@ impl LatestAtCache {
         }
     }
 }
+
+/// Returns the cached data whose validity interval contains the given query time, if any.
+fn cached_within_validity<'a>(
+    per_query_time: &'a BTreeMap<TimeInt, LatestAtCachedChunk>,
+    per_data_time_validity: &BTreeMap<TimeInt, TimeInt>,
+    query_time: TimeInt,
+) -> Option<&'a LatestAtCachedChunk> {
+    // Static data always shadows temporal data.
+    let (data_time, valid_until) = per_data_time_validity
+        .get_key_value(&TimeInt::STATIC)
+        .or_else(|| per_data_time_validity.range(..=query_time).next_back())?;
+
+    if query_time <= *valid_until {
+        per_query_time.get(data_time)
+    } else {
+        None
+    }
+}
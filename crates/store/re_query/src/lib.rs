@@ -2,8 +2,11 @@
 
 mod cache;
 mod cache_stats;
+mod derived;
+mod events;
 mod latest_at;
 mod range;
+mod series;
 mod storage_engine;
 
 pub mod clamped_zip;
@@ -14,9 +17,12 @@ use re_chunk::ComponentIdentifier;
 pub use self::cache::{QueryCache, QueryCacheHandle, QueryCacheKey};
 pub use self::cache_stats::{QueryCacheStats, QueryCachesStats};
 pub use self::clamped_zip::*;
+pub use self::derived::{DerivedComponent, FiniteDifference, Norm};
+pub use self::events::{ScalarComparison, ScalarPredicate};
 pub use self::latest_at::LatestAtResults;
 pub use self::range::RangeResults;
 pub use self::range_zip::*;
+pub use self::series::{SeriesLag, estimate_lag};
 pub use self::storage_engine::{
     StorageEngine, StorageEngineArcReadGuard, StorageEngineLike, StorageEngineReadGuard,
     StorageEngineWriteGuard,
@@ -31,15 +31,27 @@ impl QueryCache {
 
         let mut results = RangeResults::new(query.clone());
 
+        let mut derived_components = Vec::new();
+
         // NOTE: This pre-filtering is extremely important: going through all these query layers
         // has non-negligible overhead even if the final result ends up being nothing, and our
         // number of queries for a frame grows linearly with the number of entity paths.
         let components = components.into_iter().filter(|component_identifier| {
-            store.entity_has_component_on_timeline(
+            if store.entity_has_component_on_timeline(
                 query.timeline(),
                 entity_path,
                 *component_identifier,
-            )
+            ) {
+                return true;
+            }
+
+            if let Some(derived) =
+                self.derived_component(&store, query.timeline(), entity_path, *component_identifier)
+            {
+                derived_components.push((*component_identifier, derived));
+            }
+
+            false
         });
 
         for component in components {
@@ -62,6 +74,13 @@ impl QueryCache {
             }
         }
 
+        for (component, derived) in derived_components {
+            let derived = self.range_derived(query, entity_path, derived.as_ref());
+            if !derived.is_empty() {
+                results.add(component, derived);
+            }
+        }
+
         results
     }
 }
@@ -139,6 +158,12 @@ pub struct RangeCache {
     /// Invalidation is deferred to query time because it is far more efficient that way: the frame
     /// time effectively behaves as a natural micro-batching mechanism.
     pub pending_invalidations: BTreeSet<ChunkId>,
+
+    /// How many relevant chunks were already cached when queried.
+    pub num_hits: u64,
+
+    /// How many relevant chunks had to be pre-processed and cached when queried.
+    pub num_misses: u64,
 }
 
 impl RangeCache {
@@ -148,6 +173,8 @@ impl RangeCache {
             cache_key,
             chunks: HashMap::default(),
             pending_invalidations: BTreeSet::default(),
+            num_hits: 0,
+            num_misses: 0,
         }
     }
 
@@ -179,6 +206,8 @@ impl std::fmt::Debug for RangeCache {
             cache_key: _,
             chunks,
             pending_invalidations: _,
+            num_hits: _,
+            num_misses: _,
         } = self;
 
         let mut strings: Vec<String> = Vec::new();
@@ -233,6 +262,8 @@ impl SizeBytes for RangeCache {
             cache_key,
             chunks,
             pending_invalidations,
+            num_hits: _,
+            num_misses: _,
         } = self;
 
         cache_key.heap_size_bytes()
@@ -264,6 +295,12 @@ impl RangeCache {
 
         let raw_chunks = store.range_relevant_chunks(query, entity_path, component);
         for raw_chunk in &raw_chunks {
+            if self.chunks.contains_key(&raw_chunk.id()) {
+                self.num_hits += 1;
+            } else {
+                self.num_misses += 1;
+            }
+
             self.chunks
                 .entry(raw_chunk.id())
                 .or_insert_with(|| RangeCachedChunk {
@@ -307,6 +344,8 @@ impl RangeCache {
             cache_key: _,
             chunks,
             pending_invalidations,
+            num_hits: _,
+            num_misses: _,
         } = self;
 
         chunks.retain(|chunk_id, _chunk| !pending_invalidations.contains(chunk_id));
@@ -0,0 +1,225 @@
+use arrow::{
+    array::{Array as _, AsArray as _},
+    datatypes::{DataType, Float64Type},
+};
+
+use re_chunk::{ComponentIdentifier, RangeQuery};
+use re_log_types::{EntityPath, TimeInt};
+
+use crate::{QueryCache, QueryError};
+
+// --- Public API ---
+
+/// Series are resampled to at most this many samples before being cross-correlated.
+const MAX_RESAMPLED_LEN: usize = 2_048;
+
+/// Lags at which the series overlap by less than this fraction of the resampled length are ignored,
+/// since a handful of overlapping samples correlate by chance.
+const MIN_OVERLAP_FRACTION: f64 = 0.25;
+
+/// The time offset between two scalar series, as estimated by [`estimate_lag`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SeriesLag {
+    /// How far the second series lags behind the first one, in the native unit of the timeline.
+    ///
+    /// Shifting the times of the second series by `-lag` aligns it with the first one.
+    pub lag: f64,
+
+    /// The correlation coefficient of the two series at [`Self::lag`], between -1 and 1.
+    pub correlation: f64,
+}
+
+/// Estimates the time offset between two scalar series by cross-correlating them.
+///
+/// Both series are linearly interpolated onto a common grid, spaced like the samples of the first
+/// series, and the lag with the highest correlation coefficient is refined to a fraction of a
+/// grid step. The series may be sampled at different times and rates, but must be sorted by time.
+///
+/// Returns `None` if either series has fewer than two samples, or is constant where they overlap.
+pub fn estimate_lag(first: &[(TimeInt, f64)], second: &[(TimeInt, f64)]) -> Option<SeriesLag> {
+    re_tracing::profile_function!();
+
+    let (first_min, first_max) = time_span(first)?;
+    let (second_min, second_max) = time_span(second)?;
+
+    let start = first_min.min(second_min);
+    let end = first_max.max(second_max);
+    let num_steps = ((end - start) / median_step(first)?).ceil() as usize;
+    let len = (num_steps + 1).clamp(2, MAX_RESAMPLED_LEN);
+    let step = (end - start) / (len - 1) as f64;
+
+    let grid = |i: usize| start + i as f64 * step;
+    let first = (0..len)
+        .map(|i| interpolate(first, grid(i)))
+        .collect::<Vec<_>>();
+    let second = (0..len)
+        .map(|i| interpolate(second, grid(i)))
+        .collect::<Vec<_>>();
+
+    let min_overlap = ((len as f64 * MIN_OVERLAP_FRACTION) as usize).max(2);
+    let max_shift = len as isize - 1;
+    let correlations = (-max_shift..=max_shift)
+        .map(|shift| correlation_at(&first, &second, shift, min_overlap))
+        .collect::<Vec<_>>();
+
+    let (best, correlation) = correlations
+        .iter()
+        .enumerate()
+        .filter_map(|(i, correlation)| Some((i, (*correlation)?)))
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))?;
+
+    // Fit a parabola through the peak and its neighbors for a lag between grid steps.
+    let neighbor = |offset: isize| {
+        correlations
+            .get(best.checked_add_signed(offset)?)
+            .copied()
+            .flatten()
+    };
+    let refinement = match (neighbor(-1), neighbor(1)) {
+        (Some(before), Some(after)) => {
+            let curvature = before - 2.0 * correlation + after;
+            if curvature < 0.0 {
+                (0.5 * (before - after) / curvature).clamp(-0.5, 0.5)
+            } else {
+                0.0
+            }
+        }
+        _ => 0.0,
+    };
+
+    let shift = best as f64 - max_shift as f64 + refinement;
+    Some(SeriesLag {
+        lag: shift * step,
+        correlation,
+    })
+}
+
+impl QueryCache {
+    /// The first value of `component` at every time in the range of `query`, sorted by time.
+    ///
+    /// The values are cast to `f64` beforehand, which fails for components that aren't numeric.
+    /// Rows without values and static data are ignored.
+    pub fn scalar_series(
+        &self,
+        query: &RangeQuery,
+        entity_path: &EntityPath,
+        component: ComponentIdentifier,
+    ) -> Result<Vec<(TimeInt, f64)>, QueryError> {
+        re_tracing::profile_function!();
+
+        let mut results = self.range(query, entity_path, [component]);
+        let chunks = results.components.remove(&component).unwrap_or_default();
+
+        let mut series = Vec::new();
+        for chunk in &chunks {
+            if chunk.is_static() {
+                continue;
+            }
+
+            let Some(list_array) = chunk.components().get_array(component) else {
+                continue;
+            };
+            let values = arrow::compute::cast(list_array.values(), &DataType::Float64)
+                .map_err(|err| QueryError::Other(err.into()))?;
+            let values = values.as_primitive::<Float64Type>();
+            let offsets = list_array.value_offsets();
+
+            // Range results are densified on the queried component.
+            series.extend(
+                chunk
+                    .iter_component_indices(*query.timeline(), component)
+                    .zip((0..list_array.len()).filter(|&i| list_array.is_valid(i)))
+                    .filter_map(|((time, _row_id), i)| {
+                        let (start, end) = (offsets[i] as usize, offsets[i + 1] as usize);
+                        (start..end)
+                            .find(|&j| values.is_valid(j))
+                            .map(|j| (time, values.value(j)))
+                    }),
+            );
+        }
+
+        // Chunks may overlap in time.
+        series.sort_by_key(|(time, _)| *time);
+
+        Ok(series)
+    }
+}
+
+// --- Internals ---
+
+fn time_span(series: &[(TimeInt, f64)]) -> Option<(f64, f64)> {
+    match series {
+        [(first, _), .., (last, _)] if first < last => {
+            Some((first.as_i64() as f64, last.as_i64() as f64))
+        }
+        _ => None,
+    }
+}
+
+/// The median time between consecutive samples, ignoring samples at the same time.
+fn median_step(series: &[(TimeInt, f64)]) -> Option<f64> {
+    let mut steps = series
+        .windows(2)
+        .map(|pair| (pair[1].0.as_i64() - pair[0].0.as_i64()) as f64)
+        .filter(|step| *step > 0.0)
+        .collect::<Vec<_>>();
+    steps.sort_by(f64::total_cmp);
+    steps.get(steps.len() / 2).copied()
+}
+
+/// The value of the series at `time`, linearly interpolated between its samples.
+///
+/// `None` outside of the time span of the series.
+fn interpolate(series: &[(TimeInt, f64)], time: f64) -> Option<f64> {
+    let after = series.partition_point(|(t, _)| (t.as_i64() as f64) < time);
+
+    let (t1, v1) = *series.get(after)?;
+    let t1 = t1.as_i64() as f64;
+    if t1 == time {
+        return Some(v1);
+    }
+
+    let (t0, v0) = *series.get(after.checked_sub(1)?)?;
+    let t0 = t0.as_i64() as f64;
+    Some(v0 + (v1 - v0) * (time - t0) / (t1 - t0))
+}
+
+/// The correlation coefficient of `first[i]` and `second[i + shift]`, where both are defined.
+fn correlation_at(
+    first: &[Option<f64>],
+    second: &[Option<f64>],
+    shift: isize,
+    min_overlap: usize,
+) -> Option<f64> {
+    let mut n = 0.0;
+    let (mut sum_a, mut sum_b, mut sum_ab, mut sum_aa, mut sum_bb) = (0.0, 0.0, 0.0, 0.0, 0.0);
+
+    for (i, a) in first.iter().enumerate() {
+        let Some(b) = i.checked_add_signed(shift).and_then(|j| second.get(j)) else {
+            continue;
+        };
+        let (Some(a), Some(b)) = (a, b) else {
+            continue;
+        };
+
+        n += 1.0;
+        sum_a += a;
+        sum_b += b;
+        sum_ab += a * b;
+        sum_aa += a * a;
+        sum_bb += b * b;
+    }
+
+    if n < min_overlap as f64 {
+        return None;
+    }
+
+    let covariance = sum_ab - sum_a * sum_b / n;
+    let variance_a = sum_aa - sum_a * sum_a / n;
+    let variance_b = sum_bb - sum_b * sum_b / n;
+    if variance_a <= 0.0 || variance_b <= 0.0 {
+        return None;
+    }
+
+    Some(covariance / (variance_a * variance_b).sqrt())
+}
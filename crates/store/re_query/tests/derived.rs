@@ -0,0 +1,96 @@
+// https://github.com/rust-lang/rust-clippy/issues/10011
+#![cfg(test)]
+
+use std::sync::Arc;
+
+use re_chunk::RowId;
+use re_chunk_store::{
+    AbsoluteTimeRange, ChunkStore, ChunkStoreSubscriber as _, LatestAtQuery, RangeQuery,
+    external::re_chunk::Chunk,
+};
+use re_log_types::build_frame_nr;
+use re_query::{FiniteDifference, Norm, QueryCache};
+use re_types::{ComponentDescriptor, archetypes::Transform3D};
+
+// ---
+
+#[test]
+fn derived_components() {
+    let store = ChunkStore::new_handle(
+        re_log_types::StoreId::random(re_log_types::StoreKind::Recording, "test_app"),
+        Default::default(),
+    );
+    let mut caches = QueryCache::new(store.clone());
+
+    let translation = Transform3D::descriptor_translation().component;
+    let velocity = ComponentDescriptor::partial("Transform3D:velocity");
+    let distance = ComponentDescriptor::partial("Transform3D:distance");
+    caches.register_derived_component(FiniteDifference::new(translation, velocity.clone()));
+    caches.register_derived_component(Norm::new(translation, distance.clone()));
+
+    // x = t²
+    let entity_path = "point";
+    let mut builder = Chunk::builder(entity_path);
+    for frame in 1..=4_i64 {
+        builder = builder.with_archetype(
+            RowId::new(),
+            [build_frame_nr(frame)],
+            &Transform3D::from_translation([(frame * frame) as f32, 0.0, 0.0]),
+        );
+    }
+    let chunk = builder.build().unwrap();
+    caches.on_events(&store.write().insert_chunk(&Arc::new(chunk)).unwrap());
+
+    let timeline = *build_frame_nr(0).0.name();
+
+    {
+        let query = LatestAtQuery::new(timeline, 3);
+        let results = caches.latest_at(
+            &query,
+            &entity_path.into(),
+            [velocity.component, distance.component],
+        );
+
+        let unit = results.get(velocity.component).unwrap();
+        assert_eq!(
+            unit.iter_slices::<[f32; 3]>(velocity.component).next(),
+            Some([[5.0, 0.0, 0.0]].as_slice())
+        );
+
+        let unit = results.get(distance.component).unwrap();
+        assert_eq!(
+            unit.iter_slices::<f64>(distance.component).next(),
+            Some([9.0].as_slice())
+        );
+    }
+
+    {
+        let query = RangeQuery::new(timeline, AbsoluteTimeRange::new(2, 4));
+        let results = caches.range(&query, &entity_path.into(), [velocity.component]);
+
+        let velocities: Vec<_> = results
+            .get_required(velocity.component)
+            .unwrap()
+            .iter()
+            .flat_map(|chunk| chunk.iter_slices::<[f32; 3]>(velocity.component))
+            .map(|velocities| velocities.to_vec())
+            .collect();
+
+        // The first velocity within the range is derived from the position right before it.
+        similar_asserts::assert_eq!(
+            vec![
+                vec![[3.0, 0.0, 0.0]],
+                vec![[5.0, 0.0, 0.0]],
+                vec![[7.0, 0.0, 0.0]],
+            ],
+            velocities
+        );
+    }
+
+    // Derived components are nowhere to be found in the store itself.
+    assert!(!store.read().entity_has_component_on_timeline(
+        &timeline,
+        &entity_path.into(),
+        velocity.component
+    ));
+}
@@ -0,0 +1,106 @@
+// https://github.com/rust-lang/rust-clippy/issues/10011
+#![cfg(test)]
+
+use std::sync::Arc;
+
+use re_chunk::RowId;
+use re_chunk_store::{
+    AbsoluteTimeRange, ChunkStore, ChunkStoreSubscriber as _, RangeQuery, external::re_chunk::Chunk,
+};
+use re_log_types::build_frame_nr;
+use re_query::{QueryCache, ScalarComparison, ScalarPredicate};
+use re_types::archetypes::Scalars;
+
+// ---
+
+#[test]
+fn find_time_ranges() {
+    let store = ChunkStore::new_handle(
+        re_log_types::StoreId::random(re_log_types::StoreKind::Recording, "test_app"),
+        Default::default(),
+    );
+    let mut caches = QueryCache::new(store.clone());
+
+    let entity_path = "speed";
+    let mut builder = Chunk::builder(entity_path);
+    for (frame, speed) in [
+        (1_i64, 0.0),
+        (2, 3.0),
+        (3, 4.0),
+        (4, 1.0),
+        (5, 5.0),
+        (6, 0.0),
+    ] {
+        builder = builder.with_archetype(
+            RowId::new(),
+            [build_frame_nr(frame)],
+            &Scalars::single(speed),
+        );
+    }
+    let chunk = builder.build().unwrap();
+    caches.on_events(&store.write().insert_chunk(&Arc::new(chunk)).unwrap());
+
+    let timeline = *build_frame_nr(0).0.name();
+    let component = Scalars::descriptor_scalars().component;
+
+    let find = |range: AbsoluteTimeRange, predicate: ScalarPredicate| {
+        caches
+            .find_time_ranges(
+                &RangeQuery::new(timeline, range),
+                &entity_path.into(),
+                component,
+                |value| predicate.holds(value),
+            )
+            .unwrap()
+            .iter()
+            .map(|range| (range.min().as_i64(), range.max().as_i64()))
+            .collect::<Vec<_>>()
+    };
+
+    let faster_than_2 = ScalarPredicate::new(ScalarComparison::Greater, 2.0);
+    assert_eq!(
+        find(AbsoluteTimeRange::EVERYTHING, faster_than_2),
+        vec![(2, 3), (5, 5)]
+    );
+
+    // Only the part of the events within the queried range is returned.
+    assert_eq!(
+        find(AbsoluteTimeRange::new(3, 4), faster_than_2),
+        vec![(3, 3)]
+    );
+
+    let stopped = ScalarPredicate::new(ScalarComparison::Equal, 0.0);
+    assert_eq!(
+        find(AbsoluteTimeRange::EVERYTHING, stopped),
+        vec![(1, 1), (6, 6)]
+    );
+
+    let never = ScalarPredicate::new(ScalarComparison::Less, -1.0);
+    assert!(find(AbsoluteTimeRange::EVERYTHING, never).is_empty());
+}
+
+#[test]
+fn parse_scalar_predicate() {
+    assert_eq!(
+        "> 2.0".parse::<ScalarPredicate>(),
+        Ok(ScalarPredicate::new(ScalarComparison::Greater, 2.0))
+    );
+    assert_eq!(
+        ">=-1.5".parse::<ScalarPredicate>(),
+        Ok(ScalarPredicate::new(ScalarComparison::GreaterOrEqual, -1.5))
+    );
+    assert_eq!(
+        " != 0 ".parse::<ScalarPredicate>(),
+        Ok(ScalarPredicate::new(ScalarComparison::NotEqual, 0.0))
+    );
+    assert!("2.0".parse::<ScalarPredicate>().is_err());
+    assert!("> fast".parse::<ScalarPredicate>().is_err());
+
+    let predicate = ScalarPredicate::new(ScalarComparison::LessOrEqual, 0.5);
+    assert_eq!(
+        predicate.to_string().parse::<ScalarPredicate>(),
+        Ok(predicate)
+    );
+
+    assert!(!ScalarPredicate::new(ScalarComparison::NotEqual, 0.0).holds(f64::NAN));
+}
@@ -643,6 +643,81 @@ fn static_invalidation() {
     );
 }
 
+#[test]
+fn scrubbing_within_validity_interval() {
+    let store = ChunkStore::new_handle(
+        re_log_types::StoreId::random(re_log_types::StoreKind::Recording, "test_app"),
+        Default::default(),
+    );
+    let mut caches = QueryCache::new(store.clone());
+
+    let entity_path: EntityPath = "point".into();
+    let insert_points = |caches: &mut QueryCache, t: i64, points: &[MyPoint]| {
+        let row_id = RowId::new();
+        let chunk = Chunk::builder(entity_path.clone())
+            .with_archetype(row_id, [build_frame_nr(t)], &MyPoints::new(points.to_vec()))
+            .build()
+            .unwrap();
+        insert_and_react(&mut store.write(), caches, &Arc::new(chunk));
+        row_id
+    };
+    let query_at = |t: i64| LatestAtQuery::new(*build_frame_nr(t).0.name(), t);
+
+    let points10 = vec![MyPoint::new(1.0, 1.0)];
+    let points20 = vec![MyPoint::new(2.0, 2.0)];
+    let row_id10 = insert_points(&mut caches, 10, &points10);
+    let row_id20 = insert_points(&mut caches, 20, &points20);
+
+    // Scrub back and forth within, and across, the validity intervals.
+    for (t, data_time, row_id, points) in [
+        (15, 10, row_id10, &points10),
+        (12, 10, row_id10, &points10),
+        (19, 10, row_id10, &points10),
+        (10, 10, row_id10, &points10),
+        (25, 20, row_id20, &points20),
+        (21, 20, row_id20, &points20),
+    ] {
+        query_and_compare(
+            &caches,
+            &store.read(),
+            &query_at(t),
+            &entity_path,
+            (TimeInt::new_temporal(data_time), row_id),
+            points,
+            &[],
+        );
+    }
+
+    // New data in the middle of a validity interval must split it.
+    let points14 = vec![MyPoint::new(3.0, 3.0)];
+    let row_id14 = insert_points(&mut caches, 14, &points14);
+
+    for (t, data_time, row_id, points) in [
+        (12, 10, row_id10, &points10),
+        (15, 14, row_id14, &points14),
+        (19, 14, row_id14, &points14),
+        (25, 20, row_id20, &points20),
+    ] {
+        query_and_compare(
+            &caches,
+            &store.read(),
+            &query_at(t),
+            &entity_path,
+            (TimeInt::new_temporal(data_time), row_id),
+            points,
+            &[],
+        );
+    }
+
+    // Nothing before the first data.
+    let cached = caches.latest_at(
+        &query_at(5),
+        &entity_path,
+        [MyPoints::descriptor_points().component],
+    );
+    assert!(cached.components.is_empty());
+}
+
 // ---
 
 fn insert_and_react(store: &mut ChunkStore, caches: &mut QueryCache, chunk: &Arc<Chunk>) {
@@ -0,0 +1,85 @@
+// https://github.com/rust-lang/rust-clippy/issues/10011
+#![cfg(test)]
+
+use std::sync::Arc;
+
+use re_chunk::RowId;
+use re_chunk_store::{
+    AbsoluteTimeRange, ChunkStore, ChunkStoreSubscriber as _, RangeQuery, external::re_chunk::Chunk,
+};
+use re_log_types::{TimeInt, build_frame_nr};
+use re_query::{QueryCache, estimate_lag};
+use re_types::archetypes::Scalars;
+
+// ---
+
+/// Not periodic, so that there's a single lag at which two copies of it line up.
+fn signal(t: f64) -> f64 {
+    (0.002 * t * t).sin() + 0.3 * (0.07 * t).sin()
+}
+
+fn sampled(times: impl Iterator<Item = i64>, delay: f64) -> Vec<(TimeInt, f64)> {
+    times
+        .map(|t| (TimeInt::new_temporal(t), signal(t as f64 - delay)))
+        .collect()
+}
+
+#[test]
+fn scalar_series() {
+    let store = ChunkStore::new_handle(
+        re_log_types::StoreId::random(re_log_types::StoreKind::Recording, "test_app"),
+        Default::default(),
+    );
+    let mut caches = QueryCache::new(store.clone());
+
+    let entity_path = "speed";
+
+    // Out of order, to check that the series gets sorted.
+    let mut builder = Chunk::builder(entity_path);
+    for (frame, speed) in [(3_i64, 4.0), (1, 0.0), (2, 3.0)] {
+        builder = builder.with_archetype(
+            RowId::new(),
+            [build_frame_nr(frame)],
+            &Scalars::single(speed),
+        );
+    }
+    let chunk = builder.build().unwrap();
+    caches.on_events(&store.write().insert_chunk(&Arc::new(chunk)).unwrap());
+
+    let timeline = *build_frame_nr(0).0.name();
+    let series = caches
+        .scalar_series(
+            &RangeQuery::new(timeline, AbsoluteTimeRange::new(2, 10)),
+            &entity_path.into(),
+            Scalars::descriptor_scalars().component,
+        )
+        .unwrap()
+        .into_iter()
+        .map(|(time, value)| (time.as_i64(), value))
+        .collect::<Vec<_>>();
+
+    assert_eq!(series, vec![(2, 3.0), (3, 4.0)]);
+}
+
+#[test]
+fn estimate_lag_of_delayed_series() {
+    let first = sampled(0..200, 0.0);
+
+    // Sampled at a different rate, and delayed.
+    let second = sampled((0..200).step_by(2), 7.0);
+    let lag = estimate_lag(&first, &second).unwrap();
+    assert!((lag.lag - 7.0).abs() < 0.1, "{lag:?}");
+    assert!(lag.correlation > 0.95, "{lag:?}");
+
+    // Ahead instead of behind.
+    let second = sampled((0..200).step_by(3), -12.0);
+    let lag = estimate_lag(&first, &second).unwrap();
+    assert!((lag.lag + 12.0).abs() < 0.1, "{lag:?}");
+
+    assert_eq!(estimate_lag(&first, &first[..1]), None);
+
+    let constant = (0..200)
+        .map(|t| (TimeInt::new_temporal(t), 1.0))
+        .collect::<Vec<_>>();
+    assert_eq!(estimate_lag(&first, &constant), None);
+}
@@ -32,6 +32,12 @@ pub struct ConnectionRegistry {
     /// If set, the fallback token is used when no specific token is registered for a given origin.
     fallback_token: Option<Jwt>,
 
+    /// Token to authenticate against message proxy servers (`/proxy` URLs) with.
+    ///
+    /// Unlike redap servers, these use plain tokens rather than JWTs. When not set, the
+    /// `RERUN_GRPC_TOKEN` envvar is used on native.
+    proxy_token: Option<String>,
+
     /// The cached clients.
     ///
     /// Clients are much cheaper to clone than create (since the latter involves establishing an
@@ -53,6 +59,7 @@ impl ConnectionRegistry {
             inner: Arc::new(RwLock::new(Self {
                 saved_credentials: HashMap::new(),
                 fallback_token: None,
+                proxy_token: None,
                 clients: HashMap::new(),
                 use_stored_credentials: true,
             })),
@@ -69,6 +76,7 @@ impl ConnectionRegistry {
             inner: Arc::new(RwLock::new(Self {
                 saved_credentials: HashMap::new(),
                 fallback_token: None,
+                proxy_token: None,
                 clients: HashMap::new(),
                 use_stored_credentials: false,
             })),
@@ -146,6 +154,20 @@ impl ConnectionRegistryHandle {
         });
     }
 
+    pub fn set_proxy_token(&self, token: String) {
+        wrap_blocking_lock(|| {
+            let mut inner = self.inner.blocking_write();
+            inner.proxy_token = Some(token);
+        });
+    }
+
+    pub fn proxy_token(&self) -> Option<String> {
+        wrap_blocking_lock(|| {
+            let inner = self.inner.blocking_read();
+            inner.proxy_token.clone()
+        })
+    }
+
     pub fn should_use_stored_credentials(&self) -> bool {
         wrap_blocking_lock(|| {
             let inner = self.inner.blocking_read();
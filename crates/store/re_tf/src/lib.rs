@@ -142,15 +142,18 @@ mod entity_to_frame_tracking;
 mod frame_id_registry;
 mod transform_aspect;
 mod transform_forest;
+mod transform_hierarchy;
 mod transform_queries;
 mod transform_resolution_cache;
 
 pub mod convert;
 
 pub use transform_forest::{PinholeTreeRoot, TransformForest, TransformFromToError, TransformInfo};
+pub use transform_hierarchy::{TransformFrameNode, TransformFrameProblem, TransformHierarchy};
 pub use transform_queries::{query_view_coordinates, query_view_coordinates_at_closest_ancestor};
 pub use transform_resolution_cache::{
-    CachedTransformsForTimeline, ResolvedPinholeProjection, TransformResolutionCache,
+    CachedTransformsForTimeline, ResolvedPinholeProjection, TransformInvalidationStats,
+    TransformResolutionCache, TransformsForChildFrame,
 };
 
 // Re-export the transform frame id types from re_types.
@@ -83,12 +83,31 @@ impl TransformInfo {
         &self.target_from_instances
     }
 
+    /// Returns the transform info for the source frame being moved by `source_from_moved_source`.
+    ///
+    /// This is useful to show the source at a different time than the rest of the tree,
+    /// e.g. by passing the inverse of the source's current parent transform multiplied by an earlier one.
+    /// Per instance poses move along with the source.
+    pub fn with_moved_source(&self, source_from_moved_source: glam::DAffine3) -> Self {
+        let target_from_moved_source = self.target_from_source * source_from_moved_source;
+        let moved_target_from_target = target_from_moved_source * self.target_from_source.inverse();
+
+        Self {
+            root: self.root,
+            target_from_source: target_from_moved_source,
+            target_from_instances: left_multiply_smallvec1_of_transforms(
+                moved_target_from_target,
+                &self.target_from_instances,
+            ),
+        }
+    }
+
     /// Multiplies all transforms from the left by `target_from_reference`
     ///
     /// Or in other words:
     /// `reference_from_source = self`
     /// `target_from_source = target_from_reference * reference_from_source`
-    fn left_multiply(&self, target_from_reference: glam::DAffine3) -> Self {
+    pub fn left_multiply(&self, target_from_reference: glam::DAffine3) -> Self {
         let Self {
             root,
             target_from_source: reference_from_source,
@@ -426,7 +445,7 @@ fn walk_towards_parent(
 }
 
 /// If `frame` is an implicit transform frame and has a parent, return said parent.
-fn implicit_transform_parent(
+pub(crate) fn implicit_transform_parent(
     frame: TransformFrameIdHash,
     id_registry: &FrameIdRegistry,
 ) -> Option<TransformFrameIdHash> {
@@ -737,14 +756,14 @@ fn pinhole3d_from_image_plane(
     // but the image plane distance is arbitrary and there might be other, better visualizations!
 }
 
-struct ParentChildTransforms {
-    child_frame: TransformFrameIdHash,
-    parent_from_child: Option<ParentFromChildTransform>,
-    child_from_instance_poses: Vec<glam::DAffine3>,
-    pinhole_projection: Option<ResolvedPinholeProjection>,
+pub(crate) struct ParentChildTransforms {
+    pub child_frame: TransformFrameIdHash,
+    pub parent_from_child: Option<ParentFromChildTransform>,
+    pub child_from_instance_poses: Vec<glam::DAffine3>,
+    pub pinhole_projection: Option<ResolvedPinholeProjection>,
 }
 
-fn transforms_at(
+pub(crate) fn transforms_at(
     child_frame: TransformFrameIdHash,
     entity_db: &EntityDb,
     query: &LatestAtQuery,
@@ -0,0 +1,527 @@
+//! A snapshot of the transform hierarchy, meant for inspection & debugging.
+
+use nohash_hasher::IntMap;
+
+use re_chunk_store::LatestAtQuery;
+use re_entity_db::{EntityDb, EntityPath};
+use re_types::archetypes;
+use re_types::components::TransformFrameId;
+
+use crate::transform_forest::{ParentChildTransforms, implicit_transform_parent, transforms_at};
+use crate::{TransformFrameIdHash, TransformResolutionCache};
+
+/// Why a frame can't be placed in a tree, or is suspiciously placed in one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransformFrameProblem {
+    /// Following the parents of this frame leads back to it.
+    Cycle,
+
+    /// One of the ancestors of this frame is part of a cycle, so the frame has no root.
+    AncestorInCycle,
+
+    /// The parent frame isn't connected to anything else, and no entity is in it.
+    ///
+    /// This is usually a misspelled `parent_frame`, or a transform for the parent frame that
+    /// wasn't logged (yet).
+    MissingParent,
+}
+
+impl TransformFrameProblem {
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Cycle => "Part of a cycle",
+            Self::AncestorInCycle => "An ancestor is part of a cycle",
+            Self::MissingParent => {
+                "The parent frame is not connected to anything, and no entity is in it"
+            }
+        }
+    }
+}
+
+/// A single frame of a [`TransformHierarchy`].
+#[derive(Clone, Debug)]
+pub struct TransformFrameNode {
+    pub frame: TransformFrameIdHash,
+
+    /// `None` if the frame was never mentioned by name, which shouldn't happen in practice.
+    pub frame_id: Option<TransformFrameId>,
+
+    pub parent: Option<TransformFrameIdHash>,
+
+    /// Whether the connection to the parent is implied by the entity hierarchy rather than logged.
+    pub is_implicit_parent: bool,
+
+    pub parent_from_frame: glam::DAffine3,
+
+    /// Everything below a pinhole is 2D.
+    pub is_pinhole: bool,
+
+    /// The root of the tree this frame belongs to, or the closest pinhole above it.
+    ///
+    /// `None` if there is no root, see [`TransformFrameProblem::AncestorInCycle`].
+    pub root: Option<TransformFrameIdHash>,
+
+    /// Transform from this frame into [`Self::root`].
+    pub root_from_frame: Option<glam::DAffine3>,
+
+    /// Entities whose coordinate frame this is.
+    pub entities: Vec<EntityPath>,
+
+    pub problem: Option<TransformFrameProblem>,
+}
+
+impl TransformFrameNode {
+    /// A human-readable name for the frame.
+    pub fn name(&self) -> String {
+        self.frame_id
+            .as_ref()
+            .map_or_else(|| format!("{:?}", self.frame), ToString::to_string)
+    }
+}
+
+/// The transform hierarchy of a recording at a given time, with any problems it has.
+///
+/// Unlike [`crate::TransformForest`], this keeps around the full parent/child structure and tolerates
+/// cycles, at the expense of being slower to build.
+#[derive(Default, Clone)]
+pub struct TransformHierarchy {
+    frames: IntMap<TransformFrameIdHash, TransformFrameNode>,
+    children: IntMap<TransformFrameIdHash, Vec<TransformFrameIdHash>>,
+
+    /// Frames without a parent, sorted by name.
+    roots: Vec<TransformFrameIdHash>,
+}
+
+impl TransformHierarchy {
+    pub fn new(
+        entity_db: &EntityDb,
+        transform_cache: &TransformResolutionCache,
+        query: &LatestAtQuery,
+    ) -> Self {
+        re_tracing::profile_function!();
+
+        let registry = transform_cache.frame_id_registry();
+        let transforms = transform_cache.transforms_for_timeline(query.timeline());
+
+        let mut entities_per_frame = entities_per_frame(entity_db, query);
+
+        let mut frames: IntMap<TransformFrameIdHash, TransformFrameNode> = registry
+            .iter_frame_id_hashes()
+            .map(|frame| {
+                let ParentChildTransforms {
+                    child_frame: _,
+                    parent_from_child,
+                    child_from_instance_poses: _,
+                    pinhole_projection,
+                } = transforms_at(frame, entity_db, query, transforms);
+
+                let (parent, parent_from_frame, is_implicit_parent) =
+                    if let Some(parent_from_child) = parent_from_child {
+                        (
+                            Some(parent_from_child.parent),
+                            parent_from_child.transform,
+                            false,
+                        )
+                    } else {
+                        let parent = implicit_transform_parent(frame, registry);
+                        (parent, glam::DAffine3::IDENTITY, parent.is_some())
+                    };
+
+                let node = TransformFrameNode {
+                    frame,
+                    frame_id: registry.lookup_frame_id(frame).cloned(),
+                    parent,
+                    is_implicit_parent,
+                    parent_from_frame,
+                    is_pinhole: pinhole_projection.is_some(),
+                    root: None,
+                    root_from_frame: None,
+                    entities: entities_per_frame.remove(&frame).unwrap_or_default(),
+                    problem: None,
+                };
+                (frame, node)
+            })
+            .collect();
+
+        // Parents should always be registered, but let's not make that a precondition.
+        let unknown_parents = frames
+            .values()
+            .filter_map(|node| node.parent)
+            .filter(|parent| !frames.contains_key(parent))
+            .collect::<Vec<_>>();
+        for parent in unknown_parents {
+            frames.insert(
+                parent,
+                TransformFrameNode {
+                    frame: parent,
+                    frame_id: None,
+                    parent: None,
+                    is_implicit_parent: false,
+                    parent_from_frame: glam::DAffine3::IDENTITY,
+                    is_pinhole: false,
+                    root: None,
+                    root_from_frame: None,
+                    entities: Vec::new(),
+                    problem: None,
+                },
+            );
+        }
+
+        mark_cycles(&mut frames);
+        resolve_roots(&mut frames);
+        mark_missing_parents(&mut frames);
+
+        let mut hierarchy = Self {
+            frames,
+            children: IntMap::default(),
+            roots: Vec::new(),
+        };
+
+        for node in hierarchy.frames.values() {
+            match node.parent {
+                Some(parent) => hierarchy
+                    .children
+                    .entry(parent)
+                    .or_default()
+                    .push(node.frame),
+                None => hierarchy.roots.push(node.frame),
+            }
+        }
+
+        let sort_key = |frames: &IntMap<TransformFrameIdHash, TransformFrameNode>,
+                        frame: &TransformFrameIdHash| {
+            frames.get(frame).map(TransformFrameNode::name)
+        };
+        hierarchy
+            .roots
+            .sort_by_cached_key(|frame| sort_key(&hierarchy.frames, frame));
+        for children in hierarchy.children.values_mut() {
+            children.sort_by_cached_key(|frame| sort_key(&hierarchy.frames, frame));
+        }
+
+        hierarchy
+    }
+
+    /// Frames without a parent, sorted by name.
+    ///
+    /// Frames that are part of a cycle have a parent, and thus never show up here,
+    /// see [`Self::problems`].
+    #[inline]
+    pub fn roots(&self) -> &[TransformFrameIdHash] {
+        &self.roots
+    }
+
+    /// The direct children of a frame, sorted by name.
+    #[inline]
+    pub fn children(&self, frame: TransformFrameIdHash) -> &[TransformFrameIdHash] {
+        self.children.get(&frame).map_or(&[], |children| children)
+    }
+
+    #[inline]
+    pub fn frame(&self, frame: TransformFrameIdHash) -> Option<&TransformFrameNode> {
+        self.frames.get(&frame)
+    }
+
+    /// All frames with a [`TransformFrameProblem`], sorted by name.
+    pub fn problems(&self) -> Vec<&TransformFrameNode> {
+        let mut problems = self
+            .frames
+            .values()
+            .filter(|node| node.problem.is_some())
+            .collect::<Vec<_>>();
+        problems.sort_by_cached_key(|node| node.name());
+        problems
+    }
+}
+
+/// The coordinate frame of every entity, see [`archetypes::CoordinateFrame`].
+fn entities_per_frame(
+    entity_db: &EntityDb,
+    query: &LatestAtQuery,
+) -> IntMap<TransformFrameIdHash, Vec<EntityPath>> {
+    re_tracing::profile_function!();
+
+    let frame_id_component = archetypes::CoordinateFrame::descriptor_frame_id().component;
+
+    let mut entities_per_frame: IntMap<TransformFrameIdHash, Vec<EntityPath>> = IntMap::default();
+    for entity_path in entity_db.entity_paths() {
+        let frame = entity_db
+            .latest_at(query, entity_path, [frame_id_component])
+            .component_mono_quiet::<TransformFrameId>(frame_id_component)
+            .map_or_else(
+                || TransformFrameIdHash::from_entity_path(entity_path),
+                |frame_id| TransformFrameIdHash::new(&frame_id),
+            );
+        entities_per_frame
+            .entry(frame)
+            .or_default()
+            .push(entity_path.clone());
+    }
+
+    for entities in entities_per_frame.values_mut() {
+        entities.sort();
+    }
+
+    entities_per_frame
+}
+
+/// Marks all frames that are part of a cycle with [`TransformFrameProblem::Cycle`].
+fn mark_cycles(frames: &mut IntMap<TransformFrameIdHash, TransformFrameNode>) {
+    #[derive(PartialEq, Eq)]
+    enum Visit {
+        OnPath,
+        Done,
+    }
+
+    let mut visits: IntMap<TransformFrameIdHash, Visit> = IntMap::default();
+    let mut in_cycle = Vec::new();
+
+    for &start in frames.keys() {
+        let mut path = Vec::new();
+        let mut current = Some(start);
+
+        while let Some(frame) = current {
+            match visits.get(&frame) {
+                Some(Visit::Done) => break,
+
+                Some(Visit::OnPath) => {
+                    // We came back to a frame we walked through on this very path.
+                    if let Some(cycle_start) = path.iter().position(|f| *f == frame) {
+                        in_cycle.extend_from_slice(&path[cycle_start..]);
+                    }
+                    break;
+                }
+
+                None => {
+                    visits.insert(frame, Visit::OnPath);
+                    path.push(frame);
+                    current = frames.get(&frame).and_then(|node| node.parent);
+                }
+            }
+        }
+
+        for frame in path {
+            visits.insert(frame, Visit::Done);
+        }
+    }
+
+    for frame in in_cycle {
+        if let Some(node) = frames.get_mut(&frame) {
+            node.problem = Some(TransformFrameProblem::Cycle);
+        }
+    }
+}
+
+/// Fills in [`TransformFrameNode::root`] and [`TransformFrameNode::root_from_frame`].
+///
+/// Must run after [`mark_cycles`].
+fn resolve_roots(frames: &mut IntMap<TransformFrameIdHash, TransformFrameNode>) {
+    type Resolved = Option<(TransformFrameIdHash, glam::DAffine3)>;
+
+    // What the children of a frame are relative to.
+    let base_for_children = |node: &TransformFrameNode, resolved: Resolved| -> Resolved {
+        if node.is_pinhole {
+            Some((node.frame, glam::DAffine3::IDENTITY))
+        } else {
+            resolved
+        }
+    };
+
+    let all_frames = frames.keys().copied().collect::<Vec<_>>();
+    let mut resolved: IntMap<TransformFrameIdHash, Resolved> = IntMap::default();
+
+    for frame in all_frames {
+        // Walk up until we find something we already know about, accumulating the path.
+        let mut path = Vec::new();
+        let mut current = frame;
+        let mut base = loop {
+            let node = &frames[&current];
+            if let Some(resolution) = resolved.get(&current) {
+                break base_for_children(node, *resolution);
+            }
+            if node.problem == Some(TransformFrameProblem::Cycle) {
+                break None;
+            }
+
+            path.push(current);
+            match node.parent {
+                Some(parent) => current = parent,
+                None => break Some((current, glam::DAffine3::IDENTITY)),
+            }
+        };
+
+        // Walk back down, resolving everything on the way.
+        while let Some(frame) = path.pop() {
+            let node = &frames[&frame];
+            let resolution = base
+                .map(|(root, root_from_parent)| (root, root_from_parent * node.parent_from_frame));
+            resolved.insert(frame, resolution);
+            base = base_for_children(node, resolution);
+        }
+    }
+
+    for (frame, resolution) in resolved {
+        let Some(node) = frames.get_mut(&frame) else {
+            continue;
+        };
+        match resolution {
+            Some((root, root_from_frame)) => {
+                node.root = Some(root);
+                node.root_from_frame = Some(root_from_frame);
+            }
+            None => {
+                if node.problem.is_none() {
+                    node.problem = Some(TransformFrameProblem::AncestorInCycle);
+                }
+            }
+        }
+    }
+}
+
+/// Marks all frames whose explicit parent is a dead end with [`TransformFrameProblem::MissingParent`].
+fn mark_missing_parents(frames: &mut IntMap<TransformFrameIdHash, TransformFrameNode>) {
+    let is_dead_end = |frame: &TransformFrameIdHash| {
+        frames.get(frame).is_none_or(|node| {
+            node.parent.is_none()
+                && node.entities.is_empty()
+                && !node
+                    .frame_id
+                    .as_ref()
+                    .is_some_and(|frame_id| frame_id.is_entity_path_derived())
+        })
+    };
+
+    let with_missing_parent = frames
+        .values()
+        .filter(|node| node.problem.is_none() && !node.is_implicit_parent)
+        .filter(|node| node.parent.as_ref().is_some_and(is_dead_end))
+        .map(|node| node.frame)
+        .collect::<Vec<_>>();
+
+    for frame in with_missing_parent {
+        if let Some(node) = frames.get_mut(&frame) {
+            node.problem = Some(TransformFrameProblem::MissingParent);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use re_chunk_store::Chunk;
+    use re_log_types::{StoreInfo, TimePoint, TimelineName};
+
+    use super::*;
+
+    fn log_transform(
+        entity_db: &mut EntityDb,
+        entity_path: &str,
+        transform: archetypes::Transform3D,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        entity_db.add_chunk(&Arc::new(
+            Chunk::builder(EntityPath::from(entity_path))
+                .with_archetype_auto_row(TimePoint::STATIC, &transform)
+                .build()?,
+        ))?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_transform_hierarchy() -> Result<(), Box<dyn std::error::Error>> {
+        let mut entity_db = EntityDb::new(StoreInfo::testing().store_id);
+
+        log_transform(
+            &mut entity_db,
+            "robot",
+            archetypes::Transform3D::from_translation([1.0, 0.0, 0.0]),
+        )?;
+        log_transform(
+            &mut entity_db,
+            "robot/arm",
+            archetypes::Transform3D::from_translation([0.0, 1.0, 0.0]),
+        )?;
+        log_transform(
+            &mut entity_db,
+            "cycle_a",
+            archetypes::Transform3D::from_translation([0.0, 0.0, 1.0])
+                .with_child_frame("a")
+                .with_parent_frame("b"),
+        )?;
+        log_transform(
+            &mut entity_db,
+            "cycle_b",
+            archetypes::Transform3D::from_translation([0.0, 0.0, 1.0])
+                .with_child_frame("b")
+                .with_parent_frame("a"),
+        )?;
+        log_transform(
+            &mut entity_db,
+            "below_cycle",
+            archetypes::Transform3D::from_translation([0.0, 0.0, 1.0])
+                .with_child_frame("c")
+                .with_parent_frame("a"),
+        )?;
+        log_transform(
+            &mut entity_db,
+            "sensor",
+            archetypes::Transform3D::from_translation([0.0, 0.0, 1.0])
+                .with_child_frame("sensor")
+                .with_parent_frame("wrold"),
+        )?;
+
+        let mut transform_cache = TransformResolutionCache::default();
+        transform_cache.add_chunks(entity_db.storage_engine().store().iter_chunks());
+
+        let query = LatestAtQuery::latest(TimelineName::log_tick());
+        let hierarchy = TransformHierarchy::new(&entity_db, &transform_cache, &query);
+
+        let frame = |name: &str| {
+            let frame = TransformFrameIdHash::from_str(name);
+            hierarchy.frame(frame).unwrap()
+        };
+
+        let arm = frame("tf#/robot/arm");
+        assert_eq!(arm.problem, None);
+        assert!(!arm.is_implicit_parent);
+        assert_eq!(arm.entities, [EntityPath::from("robot/arm")]);
+        assert_eq!(
+            arm.root,
+            Some(TransformFrameIdHash::entity_path_hierarchy_root())
+        );
+        assert_eq!(
+            arm.root_from_frame,
+            Some(glam::DAffine3::from_translation(glam::dvec3(1.0, 1.0, 0.0)))
+        );
+        assert_eq!(
+            hierarchy.children(TransformFrameIdHash::from_str("tf#/robot")),
+            [arm.frame]
+        );
+
+        assert_eq!(frame("a").problem, Some(TransformFrameProblem::Cycle));
+        assert_eq!(frame("b").problem, Some(TransformFrameProblem::Cycle));
+        assert_eq!(
+            frame("c").problem,
+            Some(TransformFrameProblem::AncestorInCycle)
+        );
+        assert_eq!(frame("c").root_from_frame, None);
+
+        assert_eq!(
+            frame("sensor").problem,
+            Some(TransformFrameProblem::MissingParent)
+        );
+        assert_eq!(
+            frame("sensor").root,
+            Some(TransformFrameIdHash::from_str("wrold"))
+        );
+
+        assert!(
+            hierarchy
+                .roots()
+                .contains(&TransformFrameIdHash::entity_path_hierarchy_root())
+        );
+        assert_eq!(hierarchy.problems().len(), 4);
+
+        Ok(())
+    }
+}
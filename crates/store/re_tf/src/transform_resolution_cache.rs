@@ -48,6 +48,8 @@ pub struct TransformResolutionCache {
 
     per_timeline: HashMap<TimelineName, CachedTransformsForTimeline>,
     static_timeline: CachedTransformsForTimeline,
+
+    invalidation_stats: TransformInvalidationStats,
 }
 
 impl Default for TransformResolutionCache {
@@ -62,10 +64,33 @@ impl Default for TransformResolutionCache {
                 per_child_frame_transforms: Default::default(),
                 recursive_clears: Default::default(), // Unused for static timeline.
             },
+            invalidation_stats: Default::default(),
         }
     }
 }
 
+/// Counts how many cached transforms were touched by invalidations since the cache was created.
+///
+/// Invalidations only affect the entity that received new data and only the times at which that data can
+/// change the outcome of a latest-at query, so these numbers should stay small compared to the
+/// total number of cached transforms even for long recordings.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TransformInvalidationStats {
+    /// Cached transforms that were reset and have to be resolved again.
+    pub num_invalidated_entries: u64,
+
+    /// Cached transforms on invalidated child frames that were kept since they were produced by a different entity.
+    pub num_retained_entries: u64,
+}
+
+impl std::ops::AddAssign for TransformInvalidationStats {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.num_invalidated_entries += rhs.num_invalidated_entries;
+        self.num_retained_entries += rhs.num_retained_entries;
+    }
+}
+
 /// A transform from a child frame to a parent frame.
 #[derive(Clone, Debug, PartialEq)]
 pub struct ParentFromChildTransform {
@@ -451,7 +476,7 @@ impl PartialEq for TransformsForChildFrame {
 }
 
 impl TransformsForChildFrame {
-    /// Invalidates all transforms for the given aspects starting at the given time `min_time` (inclusive) and adds new invalidated times.
+    /// Invalidates all transforms of `entity_path` for the given aspects starting at the given time `min_time` (inclusive) and adds new invalidated times.
     ///
     /// [`TransformAspect::Clear`] causes all types of transforms to be invalidated and being added to.
     pub fn insert_invalidated_transform_events<I: Iterator<Item = TimeInt>>(
@@ -460,7 +485,7 @@ impl TransformsForChildFrame {
         min_time: TimeInt,
         get_new_invalidated_times: impl Fn() -> I,
         entity_path: &EntityPath,
-    ) {
+    ) -> TransformInvalidationStats {
         let TransformsForChildFrameEvents {
             frame_transforms,
             pose_transforms,
@@ -475,14 +500,16 @@ impl TransformsForChildFrame {
         // but using the same for all is fine as it rarely matters.
         // (it may produce some false positive transform updates)
 
-        // TODO(andreas): this is clearly _too_ conservative for long recordings.
-        // We'd like to know all points in time when a transform is fully "shadowed", so we don't have to invalidate as aggressively.
+        // Transforms produced by other entities are resolved by querying those other entities,
+        // so new data on `entity_path` can't change them.
+
+        // TODO(andreas): We'd like to know all points in time when a transform is fully "shadowed", so we don't have to invalidate as aggressively.
+
+        let mut stats = TransformInvalidationStats::default();
 
         if aspects.intersects(TransformAspect::Frame | TransformAspect::Clear) {
             // Invalidate existing transforms after min_time (rationale see above).
-            for (_, transform) in frame_transforms.range_mut(min_time..) {
-                *transform = TransformEntry::new(transform.entity_path.clone());
-            }
+            invalidate_entity_transforms(frame_transforms, min_time, entity_path, &mut stats);
 
             // Add new invalidated transforms.
             frame_transforms.extend(
@@ -495,9 +522,7 @@ impl TransformsForChildFrame {
             let pose_transforms = pose_transforms.get_or_insert_with(Box::default);
 
             // Invalidate existing transforms after min_time (rationale see above).
-            for (_, transform) in pose_transforms.range_mut(min_time..) {
-                *transform = TransformEntry::new(transform.entity_path.clone());
-            }
+            invalidate_entity_transforms(pose_transforms, min_time, entity_path, &mut stats);
 
             // Add new invalidated transforms.
             pose_transforms.extend(
@@ -510,9 +535,7 @@ impl TransformsForChildFrame {
             let pinhole_projections = pinhole_projections.get_or_insert_with(Box::default);
 
             // Invalidate existing transforms after min_time (rationale see above).
-            for (_, transform) in pinhole_projections.range_mut(min_time..) {
-                *transform = TransformEntry::new(transform.entity_path.clone());
-            }
+            invalidate_entity_transforms(pinhole_projections, min_time, entity_path, &mut stats);
 
             // Add new invalidated transforms.
             pinhole_projections.extend(
@@ -520,6 +543,25 @@ impl TransformsForChildFrame {
                     .map(|time| (time, TransformEntry::new(entity_path.clone()))),
             );
         }
+
+        stats
+    }
+}
+
+/// Invalidates all transforms from `min_time` on (inclusive) that were produced by `entity_path`.
+fn invalidate_entity_transforms<T>(
+    transforms: &mut BTreeMap<TimeInt, TransformEntry<T>>,
+    min_time: TimeInt,
+    entity_path: &EntityPath,
+    stats: &mut TransformInvalidationStats,
+) {
+    for (_, transform) in transforms.range_mut(min_time..) {
+        if transform.entity_path != *entity_path {
+            stats.num_retained_entries += 1;
+        } else if !matches!(transform.value, CachedTransformValue::Invalidated) {
+            *transform = TransformEntry::new(entity_path.clone());
+            stats.num_invalidated_entries += 1;
+        }
     }
 }
 
@@ -637,6 +679,34 @@ impl TransformsForChildFrame {
         }
     }
 
+    /// Times within `range` at which the transform from this frame to its parent changed, in ascending order.
+    ///
+    /// Together with [`Self::latest_at_transform`], this allows to trace how a frame moved over time.
+    pub fn frame_transform_times_in_range(
+        &self,
+        range: std::ops::RangeInclusive<TimeInt>,
+    ) -> Vec<TimeInt> {
+        self.events
+            .lock()
+            .frame_transforms
+            .range(range)
+            .map(|(time, _)| *time)
+            .collect()
+    }
+
+    /// Returns the time of the last frame transform change at or before `time`, if any.
+    ///
+    /// Static frame transforms are reported as [`TimeInt::STATIC`].
+    #[inline]
+    pub fn latest_frame_transform_time_at(&self, time: TimeInt) -> Option<TimeInt> {
+        self.events
+            .lock()
+            .frame_transforms
+            .range(..=time)
+            .next_back()
+            .map(|(time, _)| *time)
+    }
+
     #[inline]
     pub fn latest_at_instance_poses(
         &self,
@@ -728,6 +798,12 @@ impl TransformResolutionCache {
             .unwrap_or(&self.static_timeline)
     }
 
+    /// Counters of how many cached transforms were invalidated so far.
+    #[inline]
+    pub fn invalidation_stats(&self) -> TransformInvalidationStats {
+        self.invalidation_stats
+    }
+
     /// Makes sure the internal transform index is up to date and outdated cache entries are discarded.
     ///
     /// This needs to be called once per frame prior to any transform propagation.
@@ -735,10 +811,12 @@ impl TransformResolutionCache {
     ///
     /// This will internally…
     /// * keep track of which child frames are influenced by which entity
-    /// * invalidate cache entries if needed (may happen conservatively - potentially invalidating more than needed)
+    /// * invalidate cache entries of the affected entities from the first changed time on (may happen conservatively - potentially invalidating more than needed)
     /// * create empty entries for where transforms may change over time (may happen conservatively - creating more entries than needed)
     /// * remove cached entries if chunks were GC'ed
     ///
+    /// See [`Self::invalidation_stats`] for how many cache entries got invalidated.
+    ///
     /// See also [`Self::add_chunks`].
     pub fn process_store_events<'a>(
         &mut self,
@@ -916,16 +994,21 @@ impl TransformResolutionCache {
                             )
                         });
 
-                    frame_transforms.insert_invalidated_transform_events(
-                        aspects,
-                        time_range.start,
-                        || {
-                            times_with_potential_update
-                                .iter()
-                                .map(|t| TimeInt::new_temporal(*t))
-                        },
-                        entity_path,
-                    );
+                    // Only transforms at or after the first update of this chunk can change,
+                    // not everything since the start of the range in which this child frame is active.
+                    if let Some(first_update) = times_with_potential_update.iter().min() {
+                        self.invalidation_stats += frame_transforms
+                            .insert_invalidated_transform_events(
+                                aspects,
+                                TimeInt::new_temporal(*first_update),
+                                || {
+                                    times_with_potential_update
+                                        .iter()
+                                        .map(|t| TimeInt::new_temporal(*t))
+                                },
+                                entity_path,
+                            );
+                    }
 
                     // If we've never seen this entity update these child frames,
                     // we have to make sure that we take recursive clears into account.
@@ -1028,12 +1111,13 @@ impl TransformResolutionCache {
             "There should be only information about the static child frame"
         );
 
-        // Adding a static transform invalidates affected child frames on ALL timelines, since the resulting transforms at all times may be different now.
-        // TODO(andreas): This is too conservative for long recordings - we should know when a static transform is fully "shadowed", so we don't have to invalidate as aggressively.
+        // Adding a static transform invalidates transforms of this entity on ALL timelines, since the resulting transforms at all times may be different now.
+        // TODO(andreas): We should know when a static transform is fully "shadowed", so we don't have to invalidate as aggressively.
         // Furthermore, since we want to incorporate the static transforms into all timelines, we have to add this event to all timelines.
         for child_frame in child_frames {
             // Note down the events/invalidations on the static timeline itself.
-            self.static_timeline
+            self.invalidation_stats += self
+                .static_timeline
                 .per_child_frame_transforms
                 .entry(child_frame)
                 .or_insert_with(|| TransformsForChildFrame::new_empty(child_frame))
@@ -1058,7 +1142,7 @@ impl TransformResolutionCache {
                         )
                     });
 
-                entity_transforms.insert_invalidated_transform_events(
+                self.invalidation_stats += entity_transforms.insert_invalidated_transform_events(
                     aspects,
                     TimeInt::STATIC,
                     || std::iter::once(TimeInt::STATIC),
@@ -1418,6 +1502,101 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_frame_transform_times_in_range() -> Result<(), Box<dyn std::error::Error>> {
+        let mut entity_db = new_entity_db_with_subscriber_registered();
+        let mut cache = TransformResolutionCache::default();
+
+        let timeline = Timeline::new_sequence("t");
+        let mut chunk = Chunk::builder(EntityPath::from("moving"));
+        for time in [1, 3, 5, 7] {
+            chunk = chunk.with_archetype_auto_row(
+                [(timeline, time)],
+                &archetypes::Transform3D::from_translation([time as f32, 0.0, 0.0]),
+            );
+        }
+        entity_db.add_chunk(&Arc::new(chunk.build()?))?;
+
+        apply_store_subscriber_events(&mut cache, &entity_db);
+        let transforms = cache
+            .transforms_for_timeline(*timeline.name())
+            .frame_transforms(TransformFrameIdHash::from_entity_path(&EntityPath::from(
+                "moving",
+            )))
+            .unwrap();
+
+        assert_eq!(
+            transforms.frame_transform_times_in_range(
+                TimeInt::new_temporal(2)..=TimeInt::new_temporal(7)
+            ),
+            vec![
+                TimeInt::new_temporal(3),
+                TimeInt::new_temporal(5),
+                TimeInt::new_temporal(7)
+            ]
+        );
+        assert!(
+            transforms
+                .frame_transform_times_in_range(TimeInt::new_temporal(8)..=TimeInt::MAX)
+                .is_empty()
+        );
+
+        let translation_at_5 = transforms
+            .latest_at_transform(
+                &entity_db,
+                &LatestAtQuery::new(*timeline.name(), TimeInt::new_temporal(5)),
+            )
+            .unwrap()
+            .transform
+            .translation;
+        assert_eq!(translation_at_5, glam::DVec3::new(5.0, 0.0, 0.0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_latest_frame_transform_time_at() -> Result<(), Box<dyn std::error::Error>> {
+        let mut entity_db = new_entity_db_with_subscriber_registered();
+        let mut cache = TransformResolutionCache::default();
+
+        let timeline = Timeline::new_sequence("t");
+        let mut chunk = Chunk::builder(EntityPath::from("moving"));
+        for time in [2, 4] {
+            chunk = chunk.with_archetype_auto_row(
+                [(timeline, time)],
+                &archetypes::Transform3D::from_translation([time as f32, 0.0, 0.0]),
+            );
+        }
+        entity_db.add_chunk(&Arc::new(chunk.build()?))?;
+
+        apply_store_subscriber_events(&mut cache, &entity_db);
+        let transforms = cache
+            .transforms_for_timeline(*timeline.name())
+            .frame_transforms(TransformFrameIdHash::from_entity_path(&EntityPath::from(
+                "moving",
+            )))
+            .unwrap();
+
+        assert_eq!(
+            transforms.latest_frame_transform_time_at(TimeInt::new_temporal(1)),
+            None
+        );
+        assert_eq!(
+            transforms.latest_frame_transform_time_at(TimeInt::new_temporal(2)),
+            Some(TimeInt::new_temporal(2))
+        );
+        assert_eq!(
+            transforms.latest_frame_transform_time_at(TimeInt::new_temporal(3)),
+            Some(TimeInt::new_temporal(2))
+        );
+        assert_eq!(
+            transforms.latest_frame_transform_time_at(TimeInt::MAX),
+            Some(TimeInt::new_temporal(4))
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_static_tree_transforms() -> Result<(), Box<dyn std::error::Error>> {
         for flavor in &ALL_STATIC_TEST_FLAVOURS {
@@ -2160,6 +2339,111 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_invalidation_is_limited_to_entity_and_time_range()
+    -> Result<(), Box<dyn std::error::Error>> {
+        let mut entity_db = new_entity_db_with_subscriber_registered();
+        let mut cache = TransformResolutionCache::default();
+
+        // Two entities take turns in providing the transform for the same child frame.
+        let timeline = Timeline::new_sequence("t");
+        let mut chunk_a = Chunk::builder(EntityPath::from("a"));
+        for t in 0..5_u8 {
+            chunk_a = chunk_a.with_archetype_auto_row(
+                [(timeline, i64::from(t))],
+                &archetypes::Transform3D::update_fields()
+                    .with_translation([f32::from(t), 0.0, 0.0])
+                    .with_child_frame("shared"),
+            );
+        }
+        let mut chunk_b = Chunk::builder(EntityPath::from("b"));
+        for t in 5..10_u8 {
+            chunk_b = chunk_b.with_archetype_auto_row(
+                [(timeline, i64::from(t))],
+                &archetypes::Transform3D::update_fields()
+                    .with_translation([f32::from(t), 0.0, 0.0])
+                    .with_child_frame("shared"),
+            );
+        }
+        entity_db.add_chunk(&Arc::new(chunk_a.build()?))?;
+        entity_db.add_chunk(&Arc::new(chunk_b.build()?))?;
+        apply_store_subscriber_events(&mut cache, &entity_db);
+
+        let timeline = *timeline.name();
+        let shared_frame = TransformFrameIdHash::from_str("shared");
+
+        // Resolve all transforms, so that there's something to invalidate.
+        {
+            let transforms = cache
+                .transforms_for_timeline(timeline)
+                .frame_transforms(shared_frame)
+                .unwrap();
+            for t in 0..10 {
+                assert!(
+                    transforms
+                        .latest_at_transform(&entity_db, &LatestAtQuery::new(timeline, t))
+                        .is_some()
+                );
+            }
+        }
+        let stats_before = cache.invalidation_stats();
+
+        // Update `a` in the middle of its time range.
+        entity_db.add_chunk(&Arc::new(
+            Chunk::builder(EntityPath::from("a"))
+                .with_archetype_auto_row(
+                    [(Timeline::new_sequence("t"), 2)],
+                    &archetypes::Transform3D::update_fields().with_scale([2.0, 2.0, 2.0]),
+                )
+                .build()?,
+        ))?;
+        apply_store_subscriber_events(&mut cache, &entity_db);
+
+        // Only the transforms of `a` at times 2, 3 & 4 have to be resolved again,
+        // the ones of `b` at times 5 to 9 are unaffected.
+        let stats_after = cache.invalidation_stats();
+        assert_eq!(
+            stats_after.num_invalidated_entries - stats_before.num_invalidated_entries,
+            3
+        );
+        assert_eq!(
+            stats_after.num_retained_entries - stats_before.num_retained_entries,
+            5
+        );
+
+        let transforms = cache
+            .transforms_for_timeline(timeline)
+            .frame_transforms(shared_frame)
+            .unwrap();
+        assert_eq!(
+            transforms.latest_at_transform(&entity_db, &LatestAtQuery::new(timeline, 1)),
+            Some(ParentFromChildTransform {
+                parent: TransformFrameIdHash::entity_path_hierarchy_root(),
+                transform: DAffine3::from_translation(glam::dvec3(1.0, 0.0, 0.0)),
+            })
+        );
+        assert_eq!(
+            transforms.latest_at_transform(&entity_db, &LatestAtQuery::new(timeline, 4)),
+            Some(ParentFromChildTransform {
+                parent: TransformFrameIdHash::entity_path_hierarchy_root(),
+                transform: DAffine3::from_scale_rotation_translation(
+                    glam::dvec3(2.0, 2.0, 2.0),
+                    glam::DQuat::IDENTITY,
+                    glam::dvec3(4.0, 0.0, 0.0),
+                ),
+            })
+        );
+        assert_eq!(
+            transforms.latest_at_transform(&entity_db, &LatestAtQuery::new(timeline, 6)),
+            Some(ParentFromChildTransform {
+                parent: TransformFrameIdHash::entity_path_hierarchy_root(),
+                transform: DAffine3::from_translation(glam::dvec3(6.0, 0.0, 0.0)),
+            })
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_clear_non_recursive() -> Result<(), Box<dyn std::error::Error>> {
         for clear_in_separate_chunk in [false, true] {
@@ -28,6 +28,9 @@ use ::re_types_core::{DeserializationError, DeserializationResult};
 /// If there are multiple [`archetypes::InstancePoses3D`][crate::archetypes::InstancePoses3D] instances logged to the same entity as a mesh,
 /// an instance of the mesh will be drawn for each transform.
 ///
+/// glTF meshes may be compressed with `EXT_meshopt_compression`.
+/// Meshes compressed with `KHR_draco_mesh_compression` are not supported, the viewer reports an error for them.
+///
 /// ## Example
 ///
 /// ### Simple 3D asset
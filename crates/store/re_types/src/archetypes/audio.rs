@@ -0,0 +1,521 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/audio.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::allow_attributes)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+#![allow(clippy::wildcard_imports)]
+
+use ::re_types_core::SerializationResult;
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: A chunk of audio, either as raw PCM samples or as an encoded audio file.
+///
+/// The audio starts at the time it is logged at.
+/// Longer recordings can be logged as a sequence of chunks, e.g. one per buffer of a microphone.
+///
+/// The audio view shows the waveform of all chunks on the timeline.
+/// While time is playing, the native viewer also plays the audio back, if it was built with the `audio_playback` feature.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+///
+/// ## Example
+///
+/// ### Chunked sine wave
+/// ```ignore
+/// const SAMPLE_RATE: u32 = 16_000;
+/// const CHUNK_SIZE: u32 = 1_600; // 100 ms per chunk
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let rec = rerun::RecordingStreamBuilder::new("rerun_example_audio_simple").spawn()?;
+///
+///     for i in 0..20 {
+///         let start = i * CHUNK_SIZE;
+///         rec.set_duration_secs("time", start as f64 / SAMPLE_RATE as f64);
+///
+///         // A 440 Hz tone that slowly fades out.
+///         let samples: Vec<f32> = (start..start + CHUNK_SIZE)
+///             .map(|n| {
+///                 let t = n as f32 / SAMPLE_RATE as f32;
+///                 (std::f32::consts::TAU * 440.0 * t).sin() * (-t).exp()
+///             })
+///             .collect();
+///
+///         rec.log(
+///             "audio",
+///             &rerun::Audio::from_pcm_f32(&samples, SAMPLE_RATE, 1),
+///         )?;
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Audio {
+    /// The audio data.
+    ///
+    /// Either raw PCM samples, described by [`components::AudioSampleFormat`][crate::components::AudioSampleFormat] and [`components::AudioChannelCount`][crate::components::AudioChannelCount],
+    /// or an encoded audio file, see [`components::MediaType`][crate::components::MediaType].
+    pub blob: Option<SerializedComponentBatch>,
+
+    /// The number of samples per second and channel.
+    ///
+    /// Required for raw PCM samples, encoded audio files specify their own sample rate.
+    pub sample_rate: Option<SerializedComponentBatch>,
+
+    /// The Media Type of an encoded audio file.
+    ///
+    /// Supported values:
+    /// * `audio/wav` (uncompressed PCM only)
+    ///
+    /// If omitted, the blob holds raw PCM samples, unless the viewer recognizes an encoded audio file.
+    pub media_type: Option<SerializedComponentBatch>,
+
+    /// The data type of the raw PCM samples.
+    ///
+    /// Defaults to 32-bit floats.
+    pub sample_format: Option<SerializedComponentBatch>,
+
+    /// The number of interleaved channels of the raw PCM samples.
+    ///
+    /// Defaults to a single channel.
+    pub channel_count: Option<SerializedComponentBatch>,
+}
+
+impl Audio {
+    /// Returns the [`ComponentDescriptor`] for [`Self::blob`].
+    ///
+    /// The corresponding component is [`crate::components::Blob`].
+    #[inline]
+    pub fn descriptor_blob() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Audio".into()),
+            component: "Audio:blob".into(),
+            component_type: Some("rerun.components.Blob".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::sample_rate`].
+    ///
+    /// The corresponding component is [`crate::components::SampleRate`].
+    #[inline]
+    pub fn descriptor_sample_rate() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Audio".into()),
+            component: "Audio:sample_rate".into(),
+            component_type: Some("rerun.components.SampleRate".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::media_type`].
+    ///
+    /// The corresponding component is [`crate::components::MediaType`].
+    #[inline]
+    pub fn descriptor_media_type() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Audio".into()),
+            component: "Audio:media_type".into(),
+            component_type: Some("rerun.components.MediaType".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::sample_format`].
+    ///
+    /// The corresponding component is [`crate::components::AudioSampleFormat`].
+    #[inline]
+    pub fn descriptor_sample_format() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Audio".into()),
+            component: "Audio:sample_format".into(),
+            component_type: Some("rerun.components.AudioSampleFormat".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::channel_count`].
+    ///
+    /// The corresponding component is [`crate::components::AudioChannelCount`].
+    #[inline]
+    pub fn descriptor_channel_count() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Audio".into()),
+            component: "Audio:channel_count".into(),
+            component_type: Some("rerun.components.AudioChannelCount".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
+    std::sync::LazyLock::new(|| [Audio::descriptor_blob()]);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
+    std::sync::LazyLock::new(|| [Audio::descriptor_sample_rate()]);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 3usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            Audio::descriptor_media_type(),
+            Audio::descriptor_sample_format(),
+            Audio::descriptor_channel_count(),
+        ]
+    });
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 5usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            Audio::descriptor_blob(),
+            Audio::descriptor_sample_rate(),
+            Audio::descriptor_media_type(),
+            Audio::descriptor_sample_format(),
+            Audio::descriptor_channel_count(),
+        ]
+    });
+
+impl Audio {
+    /// The total number of components in the archetype: 1 required, 1 recommended, 3 optional
+    pub const NUM_COMPONENTS: usize = 5usize;
+}
+
+impl ::re_types_core::Archetype for Audio {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.archetypes.Audio".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Audio"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let blob = arrays_by_descr
+            .get(&Self::descriptor_blob())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_blob()));
+        let sample_rate = arrays_by_descr
+            .get(&Self::descriptor_sample_rate())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_sample_rate())
+            });
+        let media_type = arrays_by_descr
+            .get(&Self::descriptor_media_type())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_media_type())
+            });
+        let sample_format = arrays_by_descr
+            .get(&Self::descriptor_sample_format())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_sample_format())
+            });
+        let channel_count = arrays_by_descr
+            .get(&Self::descriptor_channel_count())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_channel_count())
+            });
+        Ok(Self {
+            blob,
+            sample_rate,
+            media_type,
+            sample_format,
+            channel_count,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for Audio {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            self.blob.clone(),
+            self.sample_rate.clone(),
+            self.media_type.clone(),
+            self.sample_format.clone(),
+            self.channel_count.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for Audio {}
+
+impl Audio {
+    /// Create a new `Audio`.
+    #[inline]
+    pub fn new(blob: impl Into<crate::components::Blob>) -> Self {
+        Self {
+            blob: try_serialize_field(Self::descriptor_blob(), [blob]),
+            sample_rate: None,
+            media_type: None,
+            sample_format: None,
+            channel_count: None,
+        }
+    }
+
+    /// Update only some specific fields of a `Audio`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `Audio`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            blob: Some(SerializedComponentBatch::new(
+                crate::components::Blob::arrow_empty(),
+                Self::descriptor_blob(),
+            )),
+            sample_rate: Some(SerializedComponentBatch::new(
+                crate::components::SampleRate::arrow_empty(),
+                Self::descriptor_sample_rate(),
+            )),
+            media_type: Some(SerializedComponentBatch::new(
+                crate::components::MediaType::arrow_empty(),
+                Self::descriptor_media_type(),
+            )),
+            sample_format: Some(SerializedComponentBatch::new(
+                crate::components::AudioSampleFormat::arrow_empty(),
+                Self::descriptor_sample_format(),
+            )),
+            channel_count: Some(SerializedComponentBatch::new(
+                crate::components::AudioChannelCount::arrow_empty(),
+                Self::descriptor_channel_count(),
+            )),
+        }
+    }
+
+    /// Partitions the component data into multiple sub-batches.
+    ///
+    /// Specifically, this transforms the existing [`SerializedComponentBatch`]es data into [`SerializedComponentColumn`]s
+    /// instead, via [`SerializedComponentBatch::partitioned`].
+    ///
+    /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+    ///
+    /// The specified `lengths` must sum to the total length of the component batch.
+    ///
+    /// [`SerializedComponentColumn`]: [::re_types_core::SerializedComponentColumn]
+    #[inline]
+    pub fn columns<I>(
+        self,
+        _lengths: I,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>>
+    where
+        I: IntoIterator<Item = usize> + Clone,
+    {
+        let columns = [
+            self.blob
+                .map(|blob| blob.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.sample_rate
+                .map(|sample_rate| sample_rate.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.media_type
+                .map(|media_type| media_type.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.sample_format
+                .map(|sample_format| sample_format.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.channel_count
+                .map(|channel_count| channel_count.partitioned(_lengths.clone()))
+                .transpose()?,
+        ];
+        Ok(columns.into_iter().flatten())
+    }
+
+    /// Helper to partition the component data into unit-length sub-batches.
+    ///
+    /// This is semantically similar to calling [`Self::columns`] with `std::iter::take(1).repeat(n)`,
+    /// where `n` is automatically guessed.
+    #[inline]
+    pub fn columns_of_unit_batches(
+        self,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>> {
+        let len_blob = self.blob.as_ref().map(|b| b.array.len());
+        let len_sample_rate = self.sample_rate.as_ref().map(|b| b.array.len());
+        let len_media_type = self.media_type.as_ref().map(|b| b.array.len());
+        let len_sample_format = self.sample_format.as_ref().map(|b| b.array.len());
+        let len_channel_count = self.channel_count.as_ref().map(|b| b.array.len());
+        let len = None
+            .or(len_blob)
+            .or(len_sample_rate)
+            .or(len_media_type)
+            .or(len_sample_format)
+            .or(len_channel_count)
+            .unwrap_or(0);
+        self.columns(std::iter::repeat_n(1, len))
+    }
+
+    /// The audio data.
+    ///
+    /// Either raw PCM samples, described by [`components::AudioSampleFormat`][crate::components::AudioSampleFormat] and [`components::AudioChannelCount`][crate::components::AudioChannelCount],
+    /// or an encoded audio file, see [`components::MediaType`][crate::components::MediaType].
+    #[inline]
+    pub fn with_blob(mut self, blob: impl Into<crate::components::Blob>) -> Self {
+        self.blob = try_serialize_field(Self::descriptor_blob(), [blob]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::Blob`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_blob`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_blob(
+        mut self,
+        blob: impl IntoIterator<Item = impl Into<crate::components::Blob>>,
+    ) -> Self {
+        self.blob = try_serialize_field(Self::descriptor_blob(), blob);
+        self
+    }
+
+    /// The number of samples per second and channel.
+    ///
+    /// Required for raw PCM samples, encoded audio files specify their own sample rate.
+    #[inline]
+    pub fn with_sample_rate(
+        mut self,
+        sample_rate: impl Into<crate::components::SampleRate>,
+    ) -> Self {
+        self.sample_rate = try_serialize_field(Self::descriptor_sample_rate(), [sample_rate]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::SampleRate`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_sample_rate`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_sample_rate(
+        mut self,
+        sample_rate: impl IntoIterator<Item = impl Into<crate::components::SampleRate>>,
+    ) -> Self {
+        self.sample_rate = try_serialize_field(Self::descriptor_sample_rate(), sample_rate);
+        self
+    }
+
+    /// The Media Type of an encoded audio file.
+    ///
+    /// Supported values:
+    /// * `audio/wav` (uncompressed PCM only)
+    ///
+    /// If omitted, the blob holds raw PCM samples, unless the viewer recognizes an encoded audio file.
+    #[inline]
+    pub fn with_media_type(mut self, media_type: impl Into<crate::components::MediaType>) -> Self {
+        self.media_type = try_serialize_field(Self::descriptor_media_type(), [media_type]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::MediaType`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_media_type`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_media_type(
+        mut self,
+        media_type: impl IntoIterator<Item = impl Into<crate::components::MediaType>>,
+    ) -> Self {
+        self.media_type = try_serialize_field(Self::descriptor_media_type(), media_type);
+        self
+    }
+
+    /// The data type of the raw PCM samples.
+    ///
+    /// Defaults to 32-bit floats.
+    #[inline]
+    pub fn with_sample_format(
+        mut self,
+        sample_format: impl Into<crate::components::AudioSampleFormat>,
+    ) -> Self {
+        self.sample_format = try_serialize_field(Self::descriptor_sample_format(), [sample_format]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::AudioSampleFormat`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_sample_format`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_sample_format(
+        mut self,
+        sample_format: impl IntoIterator<Item = impl Into<crate::components::AudioSampleFormat>>,
+    ) -> Self {
+        self.sample_format = try_serialize_field(Self::descriptor_sample_format(), sample_format);
+        self
+    }
+
+    /// The number of interleaved channels of the raw PCM samples.
+    ///
+    /// Defaults to a single channel.
+    #[inline]
+    pub fn with_channel_count(
+        mut self,
+        channel_count: impl Into<crate::components::AudioChannelCount>,
+    ) -> Self {
+        self.channel_count = try_serialize_field(Self::descriptor_channel_count(), [channel_count]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::AudioChannelCount`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_channel_count`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_channel_count(
+        mut self,
+        channel_count: impl IntoIterator<Item = impl Into<crate::components::AudioChannelCount>>,
+    ) -> Self {
+        self.channel_count = try_serialize_field(Self::descriptor_channel_count(), channel_count);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for Audio {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.blob.heap_size_bytes()
+            + self.sample_rate.heap_size_bytes()
+            + self.media_type.heap_size_bytes()
+            + self.sample_format.heap_size_bytes()
+            + self.channel_count.heap_size_bytes()
+    }
+}
@@ -0,0 +1,59 @@
+use crate::components::{AudioSampleFormat, MediaType};
+
+use super::Audio;
+
+impl Audio {
+    /// Creates a new [`Audio`] from 32-bit float PCM samples in the range `[-1, 1]`.
+    ///
+    /// If there is more than one channel, the samples of all channels must be interleaved.
+    #[inline]
+    pub fn from_pcm_f32(samples: &[f32], sample_rate: u32, channel_count: u32) -> Self {
+        let bytes: Vec<u8> = samples.iter().flat_map(|s| s.to_le_bytes()).collect();
+        Self::new(bytes)
+            .with_sample_rate(sample_rate)
+            .with_sample_format(AudioSampleFormat::F32)
+            .with_channel_count(channel_count)
+    }
+
+    /// Creates a new [`Audio`] from signed 16-bit PCM samples.
+    ///
+    /// If there is more than one channel, the samples of all channels must be interleaved.
+    #[inline]
+    pub fn from_pcm_i16(samples: &[i16], sample_rate: u32, channel_count: u32) -> Self {
+        let bytes: Vec<u8> = samples.iter().flat_map(|s| s.to_le_bytes()).collect();
+        Self::new(bytes)
+            .with_sample_rate(sample_rate)
+            .with_sample_format(AudioSampleFormat::I16)
+            .with_channel_count(channel_count)
+    }
+
+    /// Creates a new [`Audio`] from the encoded audio file at `path`.
+    ///
+    /// The [`MediaType`] will first be guessed from the file extension, then from the file
+    /// contents if needed.
+    ///
+    /// Returns an error if the file cannot be read.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[inline]
+    pub fn from_file_path(filepath: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
+        let filepath = filepath.as_ref();
+        let contents = std::fs::read(filepath)?;
+        Ok(Self::from_file_contents(
+            contents,
+            MediaType::guess_from_path(filepath),
+        ))
+    }
+
+    /// Creates a new [`Audio`] from the contents of an encoded audio file.
+    ///
+    /// If no [`MediaType`] is given, it will be guessed from magic bytes in the data.
+    #[inline]
+    pub fn from_file_contents(contents: Vec<u8>, media_type: Option<impl Into<MediaType>>) -> Self {
+        let media_type = media_type.map(Into::into);
+        if let Some(media_type) = MediaType::or_guess_from_data(media_type, &contents) {
+            Self::new(contents).with_media_type(media_type)
+        } else {
+            Self::new(contents)
+        }
+    }
+}
@@ -0,0 +1,245 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/clock_control.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::allow_attributes)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+#![allow(clippy::wildcard_imports)]
+
+use ::re_types_core::SerializationResult;
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Makes the time cursor of the viewer follow the logging SDK, e.g. to let a simulator drive playback.
+///
+/// Whenever a `ClockControl` comes in, the viewer pauses its own playback, switches to the given
+/// timeline, and moves the time cursor to the time the `ClockControl` was logged at on that timeline.
+/// The cursor follows `ClockControl`s in the order they come in rather than in time order.
+/// A simulation can thus pause by not logging any, step by logging one at the next time, and
+/// jump by logging one at any other time, including back in time after a reset.
+///
+/// The entity path a `ClockControl` is logged to doesn't matter.
+/// The time cursor can still be moved by hand until the next `ClockControl` comes in.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+///
+/// ## Example
+///
+/// ### Driving the time cursor from a simulation
+/// ```ignore
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let rec = rerun::RecordingStreamBuilder::new("rerun_example_clock_control").spawn()?;
+///
+///     let mut position: f32 = 0.0;
+///     for step in 0..100 {
+///         position += 0.01;
+///
+///         rec.set_duration_secs("sim_time", step as f64 * 0.01);
+///         rec.log("ball", &rerun::Points3D::new([(position, 0.0, 0.0)]))?;
+///
+///         // Move the time cursor of the viewer to the current simulation time.
+///         rec.log("sim/clock", &rerun::ClockControl::new("sim_time"))?;
+///     }
+///
+///     // Jump back to the start, e.g. after a reset of the simulation.
+///     rec.set_duration_secs("sim_time", 0.0);
+///     rec.log("sim/clock", &rerun::ClockControl::new("sim_time"))?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ClockControl {
+    /// Name of the timeline whose time cursor is controlled.
+    pub timeline: Option<SerializedComponentBatch>,
+}
+
+impl ClockControl {
+    /// Returns the [`ComponentDescriptor`] for [`Self::timeline`].
+    ///
+    /// The corresponding component is [`crate::components::Name`].
+    #[inline]
+    pub fn descriptor_timeline() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.ClockControl".into()),
+            component: "ClockControl:timeline".into(),
+            component_type: Some("rerun.components.Name".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
+    std::sync::LazyLock::new(|| [ClockControl::descriptor_timeline()]);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
+    std::sync::LazyLock::new(|| [ClockControl::descriptor_timeline()]);
+
+impl ClockControl {
+    /// The total number of components in the archetype: 1 required, 0 recommended, 0 optional
+    pub const NUM_COMPONENTS: usize = 1usize;
+}
+
+impl ::re_types_core::Archetype for ClockControl {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.archetypes.ClockControl".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Clock control"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let timeline = arrays_by_descr
+            .get(&Self::descriptor_timeline())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_timeline()));
+        Ok(Self { timeline })
+    }
+}
+
+impl ::re_types_core::AsComponents for ClockControl {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        std::iter::once(self.timeline.clone()).flatten().collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for ClockControl {}
+
+impl ClockControl {
+    /// Create a new `ClockControl`.
+    #[inline]
+    pub fn new(timeline: impl Into<crate::components::Name>) -> Self {
+        Self {
+            timeline: try_serialize_field(Self::descriptor_timeline(), [timeline]),
+        }
+    }
+
+    /// Update only some specific fields of a `ClockControl`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `ClockControl`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            timeline: Some(SerializedComponentBatch::new(
+                crate::components::Name::arrow_empty(),
+                Self::descriptor_timeline(),
+            )),
+        }
+    }
+
+    /// Partitions the component data into multiple sub-batches.
+    ///
+    /// Specifically, this transforms the existing [`SerializedComponentBatch`]es data into [`SerializedComponentColumn`]s
+    /// instead, via [`SerializedComponentBatch::partitioned`].
+    ///
+    /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+    ///
+    /// The specified `lengths` must sum to the total length of the component batch.
+    ///
+    /// [`SerializedComponentColumn`]: [::re_types_core::SerializedComponentColumn]
+    #[inline]
+    pub fn columns<I>(
+        self,
+        _lengths: I,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>>
+    where
+        I: IntoIterator<Item = usize> + Clone,
+    {
+        let columns = [self
+            .timeline
+            .map(|timeline| timeline.partitioned(_lengths.clone()))
+            .transpose()?];
+        Ok(columns.into_iter().flatten())
+    }
+
+    /// Helper to partition the component data into unit-length sub-batches.
+    ///
+    /// This is semantically similar to calling [`Self::columns`] with `std::iter::take(1).repeat(n)`,
+    /// where `n` is automatically guessed.
+    #[inline]
+    pub fn columns_of_unit_batches(
+        self,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>> {
+        let len_timeline = self.timeline.as_ref().map(|b| b.array.len());
+        let len = None.or(len_timeline).unwrap_or(0);
+        self.columns(std::iter::repeat_n(1, len))
+    }
+
+    /// Name of the timeline whose time cursor is controlled.
+    #[inline]
+    pub fn with_timeline(mut self, timeline: impl Into<crate::components::Name>) -> Self {
+        self.timeline = try_serialize_field(Self::descriptor_timeline(), [timeline]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::Name`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_timeline`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_timeline(
+        mut self,
+        timeline: impl IntoIterator<Item = impl Into<crate::components::Name>>,
+    ) -> Self {
+        self.timeline = try_serialize_field(Self::descriptor_timeline(), timeline);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for ClockControl {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.timeline.heap_size_bytes()
+    }
+}
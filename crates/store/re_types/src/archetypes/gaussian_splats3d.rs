@@ -0,0 +1,552 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/gaussian_splats3d.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::allow_attributes)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+#![allow(clippy::wildcard_imports)]
+
+use ::re_types_core::SerializationResult;
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: A 3D Gaussian splatting scene.
+///
+/// Each splat is an anisotropic 3D gaussian, defined by its center, its orientation and its scale,
+/// i.e. the standard deviation along each of its local axes.
+/// Together, orientation and scale describe the covariance of the gaussian: `Σ = R S Sᵀ Rᵀ`.
+/// This is the same parameterization that is used by most Gaussian splatting reconstructions.
+///
+/// Splats are alpha blended in back to front order.
+/// The color of a splat is given by the zeroth degree of its spherical harmonics `f_dc` as `0.5 + 0.2820948 * f_dc`.
+/// Higher degrees, as produced by most reconstructions, are given by [`components::SphericalHarmonics`][crate::components::SphericalHarmonics]
+/// and make the color depend on the direction the splat is viewed from.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+///
+/// ## Example
+///
+/// ### Simple Gaussian splats
+/// ```ignore
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let rec = rerun::RecordingStreamBuilder::new("rerun_example_gaussian_splats3d_simple").spawn()?;
+///
+///     let angles = (0..32).map(|i| i as f32 / 32.0 * std::f32::consts::TAU);
+///
+///     rec.log(
+///         "splats",
+///         &rerun::GaussianSplats3D::new(angles.clone().map(|a| (a.cos(), a.sin(), 0.0)))
+///             // Rotate each splat around the z axis so that it is tangent to the ring.
+///             .with_quaternions(
+///                 angles.map(|a| rerun::Quaternion::from_xyzw([0.0, 0.0, (a / 2.0).sin(), (a / 2.0).cos()])),
+///             )
+///             .with_scales([(0.02, 0.1, 0.02)])
+///             .with_colors((0..32).map(|i| {
+///                 if i % 2 == 0 {
+///                     rerun::Color::from_rgb(255, 128, 0)
+///                 } else {
+///                     rerun::Color::from_rgb(0, 128, 255)
+///                 }
+///             }))
+///             .with_opacities([0.8]),
+///     )?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct GaussianSplats3D {
+    /// The center of each splat.
+    pub centers: Option<SerializedComponentBatch>,
+
+    /// The orientation of each splat.
+    ///
+    /// If no orientation is specified, the axes of the splats align with the axes of the local coordinate system.
+    pub quaternions: Option<SerializedComponentBatch>,
+
+    /// The standard deviation of each splat along its three local axes.
+    ///
+    /// If not specified, splats are isotropic with a standard deviation of 1% of the size of the bounding box of all centers.
+    pub scales: Option<SerializedComponentBatch>,
+
+    /// The color of each splat.
+    ///
+    /// The alpha channel is multiplied with [`components::Opacity`][crate::components::Opacity].
+    pub colors: Option<SerializedComponentBatch>,
+
+    /// The opacity at the center of each splat.
+    ///
+    /// Defaults to fully opaque.
+    pub opacities: Option<SerializedComponentBatch>,
+
+    /// The view dependent part of the color of each splat.
+    ///
+    /// All splats are expected to have the same number of coefficients.
+    /// If not specified, splats look the same from all directions.
+    pub spherical_harmonics: Option<SerializedComponentBatch>,
+
+    /// Optional class IDs for the splats.
+    ///
+    /// The class ID provides colors if not specified explicitly.
+    pub class_ids: Option<SerializedComponentBatch>,
+}
+
+impl GaussianSplats3D {
+    /// Returns the [`ComponentDescriptor`] for [`Self::centers`].
+    ///
+    /// The corresponding component is [`crate::components::Position3D`].
+    #[inline]
+    pub fn descriptor_centers() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.GaussianSplats3D".into()),
+            component: "GaussianSplats3D:centers".into(),
+            component_type: Some("rerun.components.Position3D".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::quaternions`].
+    ///
+    /// The corresponding component is [`crate::components::PoseRotationQuat`].
+    #[inline]
+    pub fn descriptor_quaternions() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.GaussianSplats3D".into()),
+            component: "GaussianSplats3D:quaternions".into(),
+            component_type: Some("rerun.components.PoseRotationQuat".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::scales`].
+    ///
+    /// The corresponding component is [`crate::components::PoseScale3D`].
+    #[inline]
+    pub fn descriptor_scales() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.GaussianSplats3D".into()),
+            component: "GaussianSplats3D:scales".into(),
+            component_type: Some("rerun.components.PoseScale3D".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::colors`].
+    ///
+    /// The corresponding component is [`crate::components::Color`].
+    #[inline]
+    pub fn descriptor_colors() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.GaussianSplats3D".into()),
+            component: "GaussianSplats3D:colors".into(),
+            component_type: Some("rerun.components.Color".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::opacities`].
+    ///
+    /// The corresponding component is [`crate::components::Opacity`].
+    #[inline]
+    pub fn descriptor_opacities() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.GaussianSplats3D".into()),
+            component: "GaussianSplats3D:opacities".into(),
+            component_type: Some("rerun.components.Opacity".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::spherical_harmonics`].
+    ///
+    /// The corresponding component is [`crate::components::SphericalHarmonics`].
+    #[inline]
+    pub fn descriptor_spherical_harmonics() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.GaussianSplats3D".into()),
+            component: "GaussianSplats3D:spherical_harmonics".into(),
+            component_type: Some("rerun.components.SphericalHarmonics".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::class_ids`].
+    ///
+    /// The corresponding component is [`crate::components::ClassId`].
+    #[inline]
+    pub fn descriptor_class_ids() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.GaussianSplats3D".into()),
+            component: "GaussianSplats3D:class_ids".into(),
+            component_type: Some("rerun.components.ClassId".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
+    std::sync::LazyLock::new(|| [GaussianSplats3D::descriptor_centers()]);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 5usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            GaussianSplats3D::descriptor_quaternions(),
+            GaussianSplats3D::descriptor_scales(),
+            GaussianSplats3D::descriptor_colors(),
+            GaussianSplats3D::descriptor_opacities(),
+            GaussianSplats3D::descriptor_spherical_harmonics(),
+        ]
+    });
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
+    std::sync::LazyLock::new(|| [GaussianSplats3D::descriptor_class_ids()]);
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 7usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            GaussianSplats3D::descriptor_centers(),
+            GaussianSplats3D::descriptor_quaternions(),
+            GaussianSplats3D::descriptor_scales(),
+            GaussianSplats3D::descriptor_colors(),
+            GaussianSplats3D::descriptor_opacities(),
+            GaussianSplats3D::descriptor_spherical_harmonics(),
+            GaussianSplats3D::descriptor_class_ids(),
+        ]
+    });
+
+impl GaussianSplats3D {
+    /// The total number of components in the archetype: 1 required, 5 recommended, 1 optional
+    pub const NUM_COMPONENTS: usize = 7usize;
+}
+
+impl ::re_types_core::Archetype for GaussianSplats3D {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.archetypes.GaussianSplats3D".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Gaussian splats 3D"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let centers = arrays_by_descr
+            .get(&Self::descriptor_centers())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_centers()));
+        let quaternions = arrays_by_descr
+            .get(&Self::descriptor_quaternions())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_quaternions())
+            });
+        let scales = arrays_by_descr
+            .get(&Self::descriptor_scales())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_scales()));
+        let colors = arrays_by_descr
+            .get(&Self::descriptor_colors())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_colors()));
+        let opacities = arrays_by_descr
+            .get(&Self::descriptor_opacities())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_opacities())
+            });
+        let spherical_harmonics = arrays_by_descr
+            .get(&Self::descriptor_spherical_harmonics())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_spherical_harmonics())
+            });
+        let class_ids = arrays_by_descr
+            .get(&Self::descriptor_class_ids())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_class_ids())
+            });
+        Ok(Self {
+            centers,
+            quaternions,
+            scales,
+            colors,
+            opacities,
+            spherical_harmonics,
+            class_ids,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for GaussianSplats3D {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            self.centers.clone(),
+            self.quaternions.clone(),
+            self.scales.clone(),
+            self.colors.clone(),
+            self.opacities.clone(),
+            self.spherical_harmonics.clone(),
+            self.class_ids.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for GaussianSplats3D {}
+
+impl GaussianSplats3D {
+    /// Create a new `GaussianSplats3D`.
+    #[inline]
+    pub fn new(
+        centers: impl IntoIterator<Item = impl Into<crate::components::Position3D>>,
+    ) -> Self {
+        Self {
+            centers: try_serialize_field(Self::descriptor_centers(), centers),
+            quaternions: None,
+            scales: None,
+            colors: None,
+            opacities: None,
+            spherical_harmonics: None,
+            class_ids: None,
+        }
+    }
+
+    /// Update only some specific fields of a `GaussianSplats3D`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `GaussianSplats3D`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            centers: Some(SerializedComponentBatch::new(
+                crate::components::Position3D::arrow_empty(),
+                Self::descriptor_centers(),
+            )),
+            quaternions: Some(SerializedComponentBatch::new(
+                crate::components::PoseRotationQuat::arrow_empty(),
+                Self::descriptor_quaternions(),
+            )),
+            scales: Some(SerializedComponentBatch::new(
+                crate::components::PoseScale3D::arrow_empty(),
+                Self::descriptor_scales(),
+            )),
+            colors: Some(SerializedComponentBatch::new(
+                crate::components::Color::arrow_empty(),
+                Self::descriptor_colors(),
+            )),
+            opacities: Some(SerializedComponentBatch::new(
+                crate::components::Opacity::arrow_empty(),
+                Self::descriptor_opacities(),
+            )),
+            spherical_harmonics: Some(SerializedComponentBatch::new(
+                crate::components::SphericalHarmonics::arrow_empty(),
+                Self::descriptor_spherical_harmonics(),
+            )),
+            class_ids: Some(SerializedComponentBatch::new(
+                crate::components::ClassId::arrow_empty(),
+                Self::descriptor_class_ids(),
+            )),
+        }
+    }
+
+    /// Partitions the component data into multiple sub-batches.
+    ///
+    /// Specifically, this transforms the existing [`SerializedComponentBatch`]es data into [`SerializedComponentColumn`]s
+    /// instead, via [`SerializedComponentBatch::partitioned`].
+    ///
+    /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+    ///
+    /// The specified `lengths` must sum to the total length of the component batch.
+    ///
+    /// [`SerializedComponentColumn`]: [::re_types_core::SerializedComponentColumn]
+    #[inline]
+    pub fn columns<I>(
+        self,
+        _lengths: I,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>>
+    where
+        I: IntoIterator<Item = usize> + Clone,
+    {
+        let columns = [
+            self.centers
+                .map(|centers| centers.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.quaternions
+                .map(|quaternions| quaternions.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.scales
+                .map(|scales| scales.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.colors
+                .map(|colors| colors.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.opacities
+                .map(|opacities| opacities.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.spherical_harmonics
+                .map(|spherical_harmonics| spherical_harmonics.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.class_ids
+                .map(|class_ids| class_ids.partitioned(_lengths.clone()))
+                .transpose()?,
+        ];
+        Ok(columns.into_iter().flatten())
+    }
+
+    /// Helper to partition the component data into unit-length sub-batches.
+    ///
+    /// This is semantically similar to calling [`Self::columns`] with `std::iter::take(1).repeat(n)`,
+    /// where `n` is automatically guessed.
+    #[inline]
+    pub fn columns_of_unit_batches(
+        self,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>> {
+        let len_centers = self.centers.as_ref().map(|b| b.array.len());
+        let len_quaternions = self.quaternions.as_ref().map(|b| b.array.len());
+        let len_scales = self.scales.as_ref().map(|b| b.array.len());
+        let len_colors = self.colors.as_ref().map(|b| b.array.len());
+        let len_opacities = self.opacities.as_ref().map(|b| b.array.len());
+        let len_spherical_harmonics = self.spherical_harmonics.as_ref().map(|b| b.array.len());
+        let len_class_ids = self.class_ids.as_ref().map(|b| b.array.len());
+        let len = None
+            .or(len_centers)
+            .or(len_quaternions)
+            .or(len_scales)
+            .or(len_colors)
+            .or(len_opacities)
+            .or(len_spherical_harmonics)
+            .or(len_class_ids)
+            .unwrap_or(0);
+        self.columns(std::iter::repeat_n(1, len))
+    }
+
+    /// The center of each splat.
+    #[inline]
+    pub fn with_centers(
+        mut self,
+        centers: impl IntoIterator<Item = impl Into<crate::components::Position3D>>,
+    ) -> Self {
+        self.centers = try_serialize_field(Self::descriptor_centers(), centers);
+        self
+    }
+
+    /// The orientation of each splat.
+    ///
+    /// If no orientation is specified, the axes of the splats align with the axes of the local coordinate system.
+    #[inline]
+    pub fn with_quaternions(
+        mut self,
+        quaternions: impl IntoIterator<Item = impl Into<crate::components::PoseRotationQuat>>,
+    ) -> Self {
+        self.quaternions = try_serialize_field(Self::descriptor_quaternions(), quaternions);
+        self
+    }
+
+    /// The standard deviation of each splat along its three local axes.
+    ///
+    /// If not specified, splats are isotropic with a standard deviation of 1% of the size of the bounding box of all centers.
+    #[inline]
+    pub fn with_scales(
+        mut self,
+        scales: impl IntoIterator<Item = impl Into<crate::components::PoseScale3D>>,
+    ) -> Self {
+        self.scales = try_serialize_field(Self::descriptor_scales(), scales);
+        self
+    }
+
+    /// The color of each splat.
+    ///
+    /// The alpha channel is multiplied with [`components::Opacity`][crate::components::Opacity].
+    #[inline]
+    pub fn with_colors(
+        mut self,
+        colors: impl IntoIterator<Item = impl Into<crate::components::Color>>,
+    ) -> Self {
+        self.colors = try_serialize_field(Self::descriptor_colors(), colors);
+        self
+    }
+
+    /// The opacity at the center of each splat.
+    ///
+    /// Defaults to fully opaque.
+    #[inline]
+    pub fn with_opacities(
+        mut self,
+        opacities: impl IntoIterator<Item = impl Into<crate::components::Opacity>>,
+    ) -> Self {
+        self.opacities = try_serialize_field(Self::descriptor_opacities(), opacities);
+        self
+    }
+
+    /// The view dependent part of the color of each splat.
+    ///
+    /// All splats are expected to have the same number of coefficients.
+    /// If not specified, splats look the same from all directions.
+    #[inline]
+    pub fn with_spherical_harmonics(
+        mut self,
+        spherical_harmonics: impl IntoIterator<Item = impl Into<crate::components::SphericalHarmonics>>,
+    ) -> Self {
+        self.spherical_harmonics =
+            try_serialize_field(Self::descriptor_spherical_harmonics(), spherical_harmonics);
+        self
+    }
+
+    /// Optional class IDs for the splats.
+    ///
+    /// The class ID provides colors if not specified explicitly.
+    #[inline]
+    pub fn with_class_ids(
+        mut self,
+        class_ids: impl IntoIterator<Item = impl Into<crate::components::ClassId>>,
+    ) -> Self {
+        self.class_ids = try_serialize_field(Self::descriptor_class_ids(), class_ids);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for GaussianSplats3D {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.centers.heap_size_bytes()
+            + self.quaternions.heap_size_bytes()
+            + self.scales.heap_size_bytes()
+            + self.colors.heap_size_bytes()
+            + self.opacities.heap_size_bytes()
+            + self.spherical_harmonics.heap_size_bytes()
+            + self.class_ids.heap_size_bytes()
+    }
+}
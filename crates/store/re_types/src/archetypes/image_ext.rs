@@ -1,9 +1,11 @@
+use std::{ptr::NonNull, sync::Arc};
+
 use crate::{
     components::ImageBuffer,
     datatypes::{ChannelDatatype, ColorModel, ImageFormat, PixelFormat, TensorData},
     image::{
-        ImageChannelType, ImageConstructionError, blob_and_datatype_from_tensor,
-        find_non_empty_dim_indices,
+        CameraPixelFormat, ImageChannelType, ImageConstructionError, RawImageConversion,
+        RawImageError, RawImageLayout, blob_and_datatype_from_tensor, find_non_empty_dim_indices,
     },
 };
 
@@ -134,6 +136,97 @@ impl Image {
         Self::from_color_model_and_bytes(bytes, resolution, ColorModel::RGBA, ChannelDatatype::U8)
     }
 
+    /// Construct an image from a capture buffer owned by someone else, e.g. a V4L2 or GenICam
+    /// driver, without copying it.
+    ///
+    /// `owner` is kept alive for as long as the image data is in use, and should give the
+    /// buffer back to the driver when dropped.
+    ///
+    /// The data is only copied if it is converted (see [`RawImageConversion`]) or if the rows
+    /// are padded, since images are always stored without row padding.
+    ///
+    /// See also [`Self::from_strided_buffer`].
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to `len` bytes that stay valid and unmodified for as long as `owner`
+    /// is alive.
+    pub unsafe fn from_raw_parts(
+        ptr: NonNull<u8>,
+        len: usize,
+        owner: Arc<dyn arrow::alloc::Allocation>,
+        layout: RawImageLayout,
+        conversion: RawImageConversion,
+    ) -> Result<Self, RawImageError> {
+        // SAFETY: upheld by the caller.
+        let buffer = unsafe { arrow::buffer::Buffer::from_custom_allocation(ptr, len, owner) };
+        Self::from_strided_buffer(buffer, layout, conversion)
+    }
+
+    /// Construct an image from a buffer with the given layout, which may have padded rows and
+    /// a pixel format commonly produced by cameras.
+    ///
+    /// The data is only copied if it is converted (see [`RawImageConversion`]) or if the rows
+    /// are padded.
+    ///
+    /// See also [`Self::from_raw_parts`].
+    pub fn from_strided_buffer(
+        buffer: impl Into<arrow::buffer::Buffer>,
+        layout: RawImageLayout,
+        conversion: RawImageConversion,
+    ) -> Result<Self, RawImageError> {
+        re_tracing::profile_function!();
+
+        let buffer = buffer.into();
+        layout.validate(buffer.len())?;
+
+        let RawImageLayout {
+            resolution,
+            row_stride,
+            pixel_format,
+        } = layout;
+
+        let packed = if layout.is_packed() {
+            buffer.slice_with_length(0, layout.min_num_bytes())
+        } else {
+            let packed_row_bytes = pixel_format.packed_row_bytes(resolution[0]);
+            let num_rows = pixel_format.num_rows(resolution[1]);
+
+            let mut packed = Vec::with_capacity(num_rows * packed_row_bytes);
+            for row in buffer.chunks(row_stride).take(num_rows) {
+                packed.extend_from_slice(&row[..packed_row_bytes]);
+            }
+            packed.into()
+        };
+
+        Ok(match (pixel_format, conversion) {
+            (CameraPixelFormat::Rgb8, _) => Self::from_rgb24(packed, resolution),
+
+            (_, RawImageConversion::ToRgb) => {
+                Self::from_rgb24(packed_to_rgb(&packed, resolution, pixel_format), resolution)
+            }
+
+            (
+                CameraPixelFormat::L8 | CameraPixelFormat::BayerRg8,
+                RawImageConversion::Passthrough,
+            ) => Self::from_l8(packed, resolution),
+            (CameraPixelFormat::Bgr8, RawImageConversion::Passthrough) => {
+                Self::from_color_model_and_bytes(
+                    packed,
+                    resolution,
+                    ColorModel::BGR,
+                    ChannelDatatype::U8,
+                )
+            }
+            (CameraPixelFormat::Yuyv, RawImageConversion::Passthrough) => {
+                Self::from_pixel_format(resolution, PixelFormat::YUY2, packed)
+            }
+            (CameraPixelFormat::Nv12, RawImageConversion::Passthrough) => {
+                Self::from_pixel_format(resolution, PixelFormat::NV12, packed)
+            }
+        })
+    }
+
     /// Creates a new [`Image`] from a file.
     ///
     /// The image format will be inferred from the path (extension), or the contents if that fails.
@@ -192,3 +285,64 @@ impl Image {
         Ok(Self::new(image_buffer, image_format))
     }
 }
+
+/// Converts an image without row padding to 8-bit RGB.
+fn packed_to_rgb(
+    bytes: &[u8],
+    [width, height]: [u32; 2],
+    pixel_format: CameraPixelFormat,
+) -> Vec<u8> {
+    re_tracing::profile_function!();
+
+    let (w, h) = (width as usize, height as usize);
+
+    match pixel_format {
+        CameraPixelFormat::L8 => bytes.iter().flat_map(|&l| [l, l, l]).collect(),
+
+        CameraPixelFormat::Rgb8 => bytes.to_vec(),
+
+        CameraPixelFormat::Bgr8 => bytes
+            .chunks_exact(3)
+            .flat_map(|bgr| [bgr[2], bgr[1], bgr[0]])
+            .collect(),
+
+        CameraPixelFormat::Yuyv | CameraPixelFormat::Nv12 => {
+            let yuv_format = if pixel_format == CameraPixelFormat::Yuyv {
+                PixelFormat::YUY2
+            } else {
+                PixelFormat::NV12
+            };
+
+            let mut rgb = Vec::with_capacity(3 * w * h);
+            for y in 0..height {
+                for x in 0..width {
+                    rgb.extend(
+                        yuv_format
+                            .decode_rgb_at(bytes, [width, height], [x, y])
+                            .unwrap_or_default(),
+                    );
+                }
+            }
+            rgb
+        }
+
+        CameraPixelFormat::BayerRg8 => {
+            // Every 2x2 block of the `RGGB` pattern becomes four pixels of the same color.
+            let mut rgb = vec![0; 3 * w * h];
+            for y in (0..h).step_by(2) {
+                for x in (0..w).step_by(2) {
+                    let r = bytes[y * w + x];
+                    let g = ((u16::from(bytes[y * w + x + 1]) + u16::from(bytes[(y + 1) * w + x]))
+                        / 2) as u8;
+                    let b = bytes[(y + 1) * w + x + 1];
+
+                    for [dx, dy] in [[0, 0], [1, 0], [0, 1], [1, 1]] {
+                        let index = 3 * ((y + dy) * w + x + dx);
+                        rgb[index..index + 3].copy_from_slice(&[r, g, b]);
+                    }
+                }
+            }
+            rgb
+        }
+    }
+}
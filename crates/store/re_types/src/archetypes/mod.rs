@@ -9,6 +9,8 @@ mod asset3d;
 mod asset3d_ext;
 mod asset_video;
 mod asset_video_ext;
+mod audio;
+mod audio_ext;
 mod bar_chart;
 mod boxes2d;
 mod boxes2d_ext;
@@ -16,6 +18,7 @@ mod boxes3d;
 mod boxes3d_ext;
 mod capsules3d;
 mod capsules3d_ext;
+mod clock_control;
 mod coordinate_frame;
 mod cylinders3d;
 mod cylinders3d_ext;
@@ -25,6 +28,7 @@ mod ellipsoids3d;
 mod ellipsoids3d_ext;
 mod encoded_image;
 mod encoded_image_ext;
+mod gaussian_splats3d;
 mod geo_line_strings;
 mod geo_line_strings_ext;
 mod geo_points;
@@ -53,8 +57,10 @@ mod scalars;
 mod scalars_ext;
 mod segmentation_image;
 mod segmentation_image_ext;
+mod sensitive;
 mod series_lines;
 mod series_points;
+mod skeleton;
 mod tensor;
 mod tensor_ext;
 mod text_document;
@@ -72,15 +78,18 @@ pub use self::arrows2d::Arrows2D;
 pub use self::arrows3d::Arrows3D;
 pub use self::asset_video::AssetVideo;
 pub use self::asset3d::Asset3D;
+pub use self::audio::Audio;
 pub use self::bar_chart::BarChart;
 pub use self::boxes2d::Boxes2D;
 pub use self::boxes3d::Boxes3D;
 pub use self::capsules3d::Capsules3D;
+pub use self::clock_control::ClockControl;
 pub use self::coordinate_frame::CoordinateFrame;
 pub use self::cylinders3d::Cylinders3D;
 pub use self::depth_image::DepthImage;
 pub use self::ellipsoids3d::Ellipsoids3D;
 pub use self::encoded_image::EncodedImage;
+pub use self::gaussian_splats3d::GaussianSplats3D;
 pub use self::geo_line_strings::GeoLineStrings;
 pub use self::geo_points::GeoPoints;
 pub use self::graph_edges::GraphEdges;
@@ -100,8 +109,10 @@ pub use self::points3d::Points3D;
 pub use self::recording_info::RecordingInfo;
 pub use self::scalars::Scalars;
 pub use self::segmentation_image::SegmentationImage;
+pub use self::sensitive::Sensitive;
 pub use self::series_lines::SeriesLines;
 pub use self::series_points::SeriesPoints;
+pub use self::skeleton::Skeleton;
 pub use self::tensor::Tensor;
 pub use self::text_document::TextDocument;
 pub use self::text_log::TextLog;
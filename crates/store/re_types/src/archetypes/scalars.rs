@@ -92,6 +92,11 @@ use ::re_types_core::{DeserializationError, DeserializationResult};
 pub struct Scalars {
     /// The scalar values to log.
     pub scalars: Option<SerializedComponentBatch>,
+
+    /// The unit the scalar values are logged in, e.g. `m/s` or `rad`.
+    ///
+    /// Shown on the axes and tooltips of plots. Expected to be unchanging over time.
+    pub unit: Option<SerializedComponentBatch>,
 }
 
 impl Scalars {
@@ -106,6 +111,18 @@ impl Scalars {
             component_type: Some("rerun.components.Scalar".into()),
         }
     }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::unit`].
+    ///
+    /// The corresponding component is [`crate::components::Unit`].
+    #[inline]
+    pub fn descriptor_unit() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Scalars".into()),
+            component: "Scalars:unit".into(),
+            component_type: Some("rerun.components.Unit".into()),
+        }
+    }
 }
 
 static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
@@ -114,15 +131,15 @@ static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
 static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
     std::sync::LazyLock::new(|| []);
 
-static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
-    std::sync::LazyLock::new(|| []);
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
+    std::sync::LazyLock::new(|| [Scalars::descriptor_unit()]);
 
-static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
-    std::sync::LazyLock::new(|| [Scalars::descriptor_scalars()]);
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 2usize]> =
+    std::sync::LazyLock::new(|| [Scalars::descriptor_scalars(), Scalars::descriptor_unit()]);
 
 impl Scalars {
-    /// The total number of components in the archetype: 1 required, 0 recommended, 0 optional
-    pub const NUM_COMPONENTS: usize = 1usize;
+    /// The total number of components in the archetype: 1 required, 0 recommended, 1 optional
+    pub const NUM_COMPONENTS: usize = 2usize;
 }
 
 impl ::re_types_core::Archetype for Scalars {
@@ -166,7 +183,10 @@ impl ::re_types_core::Archetype for Scalars {
         let scalars = arrays_by_descr
             .get(&Self::descriptor_scalars())
             .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_scalars()));
-        Ok(Self { scalars })
+        let unit = arrays_by_descr
+            .get(&Self::descriptor_unit())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_unit()));
+        Ok(Self { scalars, unit })
     }
 }
 
@@ -174,7 +194,10 @@ impl ::re_types_core::AsComponents for Scalars {
     #[inline]
     fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
         use ::re_types_core::Archetype as _;
-        std::iter::once(self.scalars.clone()).flatten().collect()
+        [self.scalars.clone(), self.unit.clone()]
+            .into_iter()
+            .flatten()
+            .collect()
     }
 }
 
@@ -186,6 +209,7 @@ impl Scalars {
     pub fn new(scalars: impl IntoIterator<Item = impl Into<crate::components::Scalar>>) -> Self {
         Self {
             scalars: try_serialize_field(Self::descriptor_scalars(), scalars),
+            unit: None,
         }
     }
 
@@ -204,6 +228,10 @@ impl Scalars {
                 crate::components::Scalar::arrow_empty(),
                 Self::descriptor_scalars(),
             )),
+            unit: Some(SerializedComponentBatch::new(
+                crate::components::Unit::arrow_empty(),
+                Self::descriptor_unit(),
+            )),
         }
     }
 
@@ -225,10 +253,14 @@ impl Scalars {
     where
         I: IntoIterator<Item = usize> + Clone,
     {
-        let columns = [self
-            .scalars
-            .map(|scalars| scalars.partitioned(_lengths.clone()))
-            .transpose()?];
+        let columns = [
+            self.scalars
+                .map(|scalars| scalars.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.unit
+                .map(|unit| unit.partitioned(_lengths.clone()))
+                .transpose()?,
+        ];
         Ok(columns.into_iter().flatten())
     }
 
@@ -241,7 +273,8 @@ impl Scalars {
         self,
     ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>> {
         let len_scalars = self.scalars.as_ref().map(|b| b.array.len());
-        let len = None.or(len_scalars).unwrap_or(0);
+        let len_unit = self.unit.as_ref().map(|b| b.array.len());
+        let len = None.or(len_scalars).or(len_unit).unwrap_or(0);
         self.columns(std::iter::repeat_n(1, len))
     }
 
@@ -254,11 +287,33 @@ impl Scalars {
         self.scalars = try_serialize_field(Self::descriptor_scalars(), scalars);
         self
     }
+
+    /// The unit the scalar values are logged in, e.g. `m/s` or `rad`.
+    ///
+    /// Shown on the axes and tooltips of plots. Expected to be unchanging over time.
+    #[inline]
+    pub fn with_unit(mut self, unit: impl Into<crate::components::Unit>) -> Self {
+        self.unit = try_serialize_field(Self::descriptor_unit(), [unit]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::Unit`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_unit`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_unit(
+        mut self,
+        unit: impl IntoIterator<Item = impl Into<crate::components::Unit>>,
+    ) -> Self {
+        self.unit = try_serialize_field(Self::descriptor_unit(), unit);
+        self
+    }
 }
 
 impl ::re_byte_size::SizeBytes for Scalars {
     #[inline]
     fn heap_size_bytes(&self) -> u64 {
-        self.scalars.heap_size_bytes()
+        self.scalars.heap_size_bytes() + self.unit.heap_size_bytes()
     }
 }
@@ -0,0 +1,231 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/sensitive.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::allow_attributes)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+#![allow(clippy::wildcard_imports)]
+
+use ::re_types_core::SerializationResult;
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Tags an entity, and everything below it, as holding sensitive data, e.g. camera images showing faces.
+///
+/// Tagged entities can be redacted or left out when sharing data, see `rerun rrd filter --sensitive`
+/// and `rerun rrd export --sensitive`.
+/// The tag applies to all data of the entity, no matter when it was logged, so it's best logged as static.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+///
+/// ## Example
+///
+/// ### Tagging camera images as sensitive
+/// ```ignore
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let rec = rerun::RecordingStreamBuilder::new("rerun_example_sensitive").spawn()?;
+///
+///     // Everything logged to `camera` and below is tagged, no matter when it's logged.
+///     rec.log_static("camera", &rerun::Sensitive::new(["faces"]))?;
+///
+///     for frame in 0..10 {
+///         rec.set_time_sequence("frame", frame);
+///         let image = vec![(frame * 25) as u8; 64 * 48 * 3];
+///         rec.log("camera/image", &rerun::Image::from_rgb24(image, [64, 48]))?;
+///     }
+///
+///     // Share the recording without the faces, e.g. with:
+///     // `rerun rrd filter recording.rrd --sensitive redact -o shareable.rrd`
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Sensitive {
+    /// What makes the data sensitive, e.g. `faces` or `license_plates`.
+    ///
+    /// Lets exports redact some kinds of sensitive data but not others.
+    pub tags: Option<SerializedComponentBatch>,
+}
+
+impl Sensitive {
+    /// Returns the [`ComponentDescriptor`] for [`Self::tags`].
+    ///
+    /// The corresponding component is [`crate::components::Text`].
+    #[inline]
+    pub fn descriptor_tags() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Sensitive".into()),
+            component: "Sensitive:tags".into(),
+            component_type: Some("rerun.components.Text".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
+    std::sync::LazyLock::new(|| [Sensitive::descriptor_tags()]);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
+    std::sync::LazyLock::new(|| [Sensitive::descriptor_tags()]);
+
+impl Sensitive {
+    /// The total number of components in the archetype: 1 required, 0 recommended, 0 optional
+    pub const NUM_COMPONENTS: usize = 1usize;
+}
+
+impl ::re_types_core::Archetype for Sensitive {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.archetypes.Sensitive".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Sensitive"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let tags = arrays_by_descr
+            .get(&Self::descriptor_tags())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_tags()));
+        Ok(Self { tags })
+    }
+}
+
+impl ::re_types_core::AsComponents for Sensitive {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        std::iter::once(self.tags.clone()).flatten().collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for Sensitive {}
+
+impl Sensitive {
+    /// Create a new `Sensitive`.
+    #[inline]
+    pub fn new(tags: impl IntoIterator<Item = impl Into<crate::components::Text>>) -> Self {
+        Self {
+            tags: try_serialize_field(Self::descriptor_tags(), tags),
+        }
+    }
+
+    /// Update only some specific fields of a `Sensitive`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `Sensitive`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            tags: Some(SerializedComponentBatch::new(
+                crate::components::Text::arrow_empty(),
+                Self::descriptor_tags(),
+            )),
+        }
+    }
+
+    /// Partitions the component data into multiple sub-batches.
+    ///
+    /// Specifically, this transforms the existing [`SerializedComponentBatch`]es data into [`SerializedComponentColumn`]s
+    /// instead, via [`SerializedComponentBatch::partitioned`].
+    ///
+    /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+    ///
+    /// The specified `lengths` must sum to the total length of the component batch.
+    ///
+    /// [`SerializedComponentColumn`]: [::re_types_core::SerializedComponentColumn]
+    #[inline]
+    pub fn columns<I>(
+        self,
+        _lengths: I,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>>
+    where
+        I: IntoIterator<Item = usize> + Clone,
+    {
+        let columns = [self
+            .tags
+            .map(|tags| tags.partitioned(_lengths.clone()))
+            .transpose()?];
+        Ok(columns.into_iter().flatten())
+    }
+
+    /// Helper to partition the component data into unit-length sub-batches.
+    ///
+    /// This is semantically similar to calling [`Self::columns`] with `std::iter::take(1).repeat(n)`,
+    /// where `n` is automatically guessed.
+    #[inline]
+    pub fn columns_of_unit_batches(
+        self,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>> {
+        let len_tags = self.tags.as_ref().map(|b| b.array.len());
+        let len = None.or(len_tags).unwrap_or(0);
+        self.columns(std::iter::repeat_n(1, len))
+    }
+
+    /// What makes the data sensitive, e.g. `faces` or `license_plates`.
+    ///
+    /// Lets exports redact some kinds of sensitive data but not others.
+    #[inline]
+    pub fn with_tags(
+        mut self,
+        tags: impl IntoIterator<Item = impl Into<crate::components::Text>>,
+    ) -> Self {
+        self.tags = try_serialize_field(Self::descriptor_tags(), tags);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for Sensitive {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.tags.heap_size_bytes()
+    }
+}
@@ -115,6 +115,14 @@ pub struct SeriesLines {
     ///
     /// Expected to be unchanging over time.
     pub aggregation_policy: Option<SerializedComponentBatch>,
+
+    /// The unit the series are shown in, e.g. `deg` or `km/h`.
+    ///
+    /// Values are converted from the unit of the [`archetypes::Scalars`][crate::archetypes::Scalars] if both are known units of the same quantity.
+    /// If not set, values are shown in the unit they were logged in.
+    ///
+    /// Expected to be unchanging over time.
+    pub display_unit: Option<SerializedComponentBatch>,
 }
 
 impl SeriesLines {
@@ -177,6 +185,18 @@ impl SeriesLines {
             component_type: Some("rerun.components.AggregationPolicy".into()),
         }
     }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::display_unit`].
+    ///
+    /// The corresponding component is [`crate::components::Unit`].
+    #[inline]
+    pub fn descriptor_display_unit() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.SeriesLines".into()),
+            component: "SeriesLines:display_unit".into(),
+            component_type: Some("rerun.components.Unit".into()),
+        }
+    }
 }
 
 static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
@@ -185,7 +205,7 @@ static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
 static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
     std::sync::LazyLock::new(|| []);
 
-static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 5usize]> =
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 6usize]> =
     std::sync::LazyLock::new(|| {
         [
             SeriesLines::descriptor_colors(),
@@ -193,10 +213,11 @@ static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 5usize]> =
             SeriesLines::descriptor_names(),
             SeriesLines::descriptor_visible_series(),
             SeriesLines::descriptor_aggregation_policy(),
+            SeriesLines::descriptor_display_unit(),
         ]
     });
 
-static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 5usize]> =
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 6usize]> =
     std::sync::LazyLock::new(|| {
         [
             SeriesLines::descriptor_colors(),
@@ -204,12 +225,13 @@ static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 5usize]> =
             SeriesLines::descriptor_names(),
             SeriesLines::descriptor_visible_series(),
             SeriesLines::descriptor_aggregation_policy(),
+            SeriesLines::descriptor_display_unit(),
         ]
     });
 
 impl SeriesLines {
-    /// The total number of components in the archetype: 0 required, 0 recommended, 5 optional
-    pub const NUM_COMPONENTS: usize = 5usize;
+    /// The total number of components in the archetype: 0 required, 0 recommended, 6 optional
+    pub const NUM_COMPONENTS: usize = 6usize;
 }
 
 impl ::re_types_core::Archetype for SeriesLines {
@@ -269,12 +291,18 @@ impl ::re_types_core::Archetype for SeriesLines {
             .map(|array| {
                 SerializedComponentBatch::new(array.clone(), Self::descriptor_aggregation_policy())
             });
+        let display_unit = arrays_by_descr
+            .get(&Self::descriptor_display_unit())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_display_unit())
+            });
         Ok(Self {
             colors,
             widths,
             names,
             visible_series,
             aggregation_policy,
+            display_unit,
         })
     }
 }
@@ -289,6 +317,7 @@ impl ::re_types_core::AsComponents for SeriesLines {
             self.names.clone(),
             self.visible_series.clone(),
             self.aggregation_policy.clone(),
+            self.display_unit.clone(),
         ]
         .into_iter()
         .flatten()
@@ -308,6 +337,7 @@ impl SeriesLines {
             names: None,
             visible_series: None,
             aggregation_policy: None,
+            display_unit: None,
         }
     }
 
@@ -342,6 +372,10 @@ impl SeriesLines {
                 crate::components::AggregationPolicy::arrow_empty(),
                 Self::descriptor_aggregation_policy(),
             )),
+            display_unit: Some(SerializedComponentBatch::new(
+                crate::components::Unit::arrow_empty(),
+                Self::descriptor_display_unit(),
+            )),
         }
     }
 
@@ -379,6 +413,9 @@ impl SeriesLines {
             self.aggregation_policy
                 .map(|aggregation_policy| aggregation_policy.partitioned(_lengths.clone()))
                 .transpose()?,
+            self.display_unit
+                .map(|display_unit| display_unit.partitioned(_lengths.clone()))
+                .transpose()?,
         ];
         Ok(columns.into_iter().flatten())
     }
@@ -396,12 +433,14 @@ impl SeriesLines {
         let len_names = self.names.as_ref().map(|b| b.array.len());
         let len_visible_series = self.visible_series.as_ref().map(|b| b.array.len());
         let len_aggregation_policy = self.aggregation_policy.as_ref().map(|b| b.array.len());
+        let len_display_unit = self.display_unit.as_ref().map(|b| b.array.len());
         let len = None
             .or(len_colors)
             .or(len_widths)
             .or(len_names)
             .or(len_visible_series)
             .or(len_aggregation_policy)
+            .or(len_display_unit)
             .unwrap_or(0);
         self.columns(std::iter::repeat_n(1, len))
     }
@@ -489,6 +528,31 @@ impl SeriesLines {
             try_serialize_field(Self::descriptor_aggregation_policy(), aggregation_policy);
         self
     }
+
+    /// The unit the series are shown in, e.g. `deg` or `km/h`.
+    ///
+    /// Values are converted from the unit of the [`archetypes::Scalars`][crate::archetypes::Scalars] if both are known units of the same quantity.
+    /// If not set, values are shown in the unit they were logged in.
+    ///
+    /// Expected to be unchanging over time.
+    #[inline]
+    pub fn with_display_unit(mut self, display_unit: impl Into<crate::components::Unit>) -> Self {
+        self.display_unit = try_serialize_field(Self::descriptor_display_unit(), [display_unit]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::Unit`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_display_unit`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_display_unit(
+        mut self,
+        display_unit: impl IntoIterator<Item = impl Into<crate::components::Unit>>,
+    ) -> Self {
+        self.display_unit = try_serialize_field(Self::descriptor_display_unit(), display_unit);
+        self
+    }
 }
 
 impl ::re_byte_size::SizeBytes for SeriesLines {
@@ -499,5 +563,6 @@ impl ::re_byte_size::SizeBytes for SeriesLines {
             + self.names.heap_size_bytes()
             + self.visible_series.heap_size_bytes()
             + self.aggregation_policy.heap_size_bytes()
+            + self.display_unit.heap_size_bytes()
     }
 }
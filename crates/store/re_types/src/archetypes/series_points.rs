@@ -107,6 +107,14 @@ pub struct SeriesPoints {
     ///
     /// May change over time.
     pub marker_sizes: Option<SerializedComponentBatch>,
+
+    /// The unit the series are shown in, e.g. `deg` or `km/h`.
+    ///
+    /// Values are converted from the unit of the [`archetypes::Scalars`][crate::archetypes::Scalars] if both are known units of the same quantity.
+    /// If not set, values are shown in the unit they were logged in.
+    ///
+    /// Expected to be unchanging over time.
+    pub display_unit: Option<SerializedComponentBatch>,
 }
 
 impl SeriesPoints {
@@ -169,6 +177,18 @@ impl SeriesPoints {
             component_type: Some("rerun.components.MarkerSize".into()),
         }
     }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::display_unit`].
+    ///
+    /// The corresponding component is [`crate::components::Unit`].
+    #[inline]
+    pub fn descriptor_display_unit() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.SeriesPoints".into()),
+            component: "SeriesPoints:display_unit".into(),
+            component_type: Some("rerun.components.Unit".into()),
+        }
+    }
 }
 
 static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
@@ -177,17 +197,18 @@ static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
 static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
     std::sync::LazyLock::new(|| []);
 
-static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 4usize]> =
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 5usize]> =
     std::sync::LazyLock::new(|| {
         [
             SeriesPoints::descriptor_colors(),
             SeriesPoints::descriptor_names(),
             SeriesPoints::descriptor_visible_series(),
             SeriesPoints::descriptor_marker_sizes(),
+            SeriesPoints::descriptor_display_unit(),
         ]
     });
 
-static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 5usize]> =
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 6usize]> =
     std::sync::LazyLock::new(|| {
         [
             SeriesPoints::descriptor_markers(),
@@ -195,12 +216,13 @@ static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 5usize]> =
             SeriesPoints::descriptor_names(),
             SeriesPoints::descriptor_visible_series(),
             SeriesPoints::descriptor_marker_sizes(),
+            SeriesPoints::descriptor_display_unit(),
         ]
     });
 
 impl SeriesPoints {
-    /// The total number of components in the archetype: 1 required, 0 recommended, 4 optional
-    pub const NUM_COMPONENTS: usize = 5usize;
+    /// The total number of components in the archetype: 1 required, 0 recommended, 5 optional
+    pub const NUM_COMPONENTS: usize = 6usize;
 }
 
 impl ::re_types_core::Archetype for SeriesPoints {
@@ -260,12 +282,18 @@ impl ::re_types_core::Archetype for SeriesPoints {
             .map(|array| {
                 SerializedComponentBatch::new(array.clone(), Self::descriptor_marker_sizes())
             });
+        let display_unit = arrays_by_descr
+            .get(&Self::descriptor_display_unit())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_display_unit())
+            });
         Ok(Self {
             colors,
             markers,
             names,
             visible_series,
             marker_sizes,
+            display_unit,
         })
     }
 }
@@ -280,6 +308,7 @@ impl ::re_types_core::AsComponents for SeriesPoints {
             self.names.clone(),
             self.visible_series.clone(),
             self.marker_sizes.clone(),
+            self.display_unit.clone(),
         ]
         .into_iter()
         .flatten()
@@ -299,6 +328,7 @@ impl SeriesPoints {
             names: None,
             visible_series: None,
             marker_sizes: None,
+            display_unit: None,
         }
     }
 
@@ -333,6 +363,10 @@ impl SeriesPoints {
                 crate::components::MarkerSize::arrow_empty(),
                 Self::descriptor_marker_sizes(),
             )),
+            display_unit: Some(SerializedComponentBatch::new(
+                crate::components::Unit::arrow_empty(),
+                Self::descriptor_display_unit(),
+            )),
         }
     }
 
@@ -370,6 +404,9 @@ impl SeriesPoints {
             self.marker_sizes
                 .map(|marker_sizes| marker_sizes.partitioned(_lengths.clone()))
                 .transpose()?,
+            self.display_unit
+                .map(|display_unit| display_unit.partitioned(_lengths.clone()))
+                .transpose()?,
         ];
         Ok(columns.into_iter().flatten())
     }
@@ -387,12 +424,14 @@ impl SeriesPoints {
         let len_names = self.names.as_ref().map(|b| b.array.len());
         let len_visible_series = self.visible_series.as_ref().map(|b| b.array.len());
         let len_marker_sizes = self.marker_sizes.as_ref().map(|b| b.array.len());
+        let len_display_unit = self.display_unit.as_ref().map(|b| b.array.len());
         let len = None
             .or(len_colors)
             .or(len_markers)
             .or(len_names)
             .or(len_visible_series)
             .or(len_marker_sizes)
+            .or(len_display_unit)
             .unwrap_or(0);
         self.columns(std::iter::repeat_n(1, len))
     }
@@ -461,6 +500,31 @@ impl SeriesPoints {
         self.marker_sizes = try_serialize_field(Self::descriptor_marker_sizes(), marker_sizes);
         self
     }
+
+    /// The unit the series are shown in, e.g. `deg` or `km/h`.
+    ///
+    /// Values are converted from the unit of the [`archetypes::Scalars`][crate::archetypes::Scalars] if both are known units of the same quantity.
+    /// If not set, values are shown in the unit they were logged in.
+    ///
+    /// Expected to be unchanging over time.
+    #[inline]
+    pub fn with_display_unit(mut self, display_unit: impl Into<crate::components::Unit>) -> Self {
+        self.display_unit = try_serialize_field(Self::descriptor_display_unit(), [display_unit]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::Unit`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_display_unit`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_display_unit(
+        mut self,
+        display_unit: impl IntoIterator<Item = impl Into<crate::components::Unit>>,
+    ) -> Self {
+        self.display_unit = try_serialize_field(Self::descriptor_display_unit(), display_unit);
+        self
+    }
 }
 
 impl ::re_byte_size::SizeBytes for SeriesPoints {
@@ -471,5 +535,6 @@ impl ::re_byte_size::SizeBytes for SeriesPoints {
             + self.names.heap_size_bytes()
             + self.visible_series.heap_size_bytes()
             + self.marker_sizes.heap_size_bytes()
+            + self.display_unit.heap_size_bytes()
     }
 }
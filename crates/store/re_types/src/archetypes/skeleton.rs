@@ -0,0 +1,686 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/skeleton.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::allow_attributes)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+#![allow(clippy::wildcard_imports)]
+
+use ::re_types_core::SerializationResult;
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: A hierarchy of joints connected by bones, e.g. a human skeleton or the kinematic chain of a robot.
+///
+/// Each joint is placed relative to its parent joint, or relative to the entity's origin for root joints.
+/// The hierarchy is typically logged once as static data, whereas the joint transforms change over time.
+///
+/// Joints are drawn as points and bones as lines between each joint and its parent.
+/// If [`components::AxisLength`][crate::components::AxisLength] is set, the coordinate frame of every joint is drawn as well.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+///
+/// ## Example
+///
+/// ### Simple kinematic chain
+/// ```ignore
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let rec = rerun::RecordingStreamBuilder::new("rerun_example_skeleton_simple").spawn()?;
+///
+///     // The hierarchy doesn't change, so it only needs to be logged once.
+///     rec.log_static(
+///         "arm",
+///         &rerun::Skeleton::update_fields()
+///             .with_parents([0, 0, 1, 2])
+///             .with_labels(["base", "shoulder", "elbow", "wrist"])
+///             .with_axis_length(0.1),
+///     )?;
+///
+///     for t in 0..100 {
+///         rec.set_time_sequence("step", t);
+///
+///         // Every joint is rotated by the same angle around the x axis relative to its parent.
+///         let angle = (t as f32 * 0.1).sin() * 0.5;
+///         let rotation =
+///             rerun::Quaternion::from_xyzw([(angle / 2.0).sin(), 0.0, 0.0, (angle / 2.0).cos()]);
+///
+///         rec.log(
+///             "arm",
+///             &rerun::Skeleton::new([
+///                 [0.0, 0.0, 0.0],
+///                 [0.0, 0.0, 0.5],
+///                 [0.0, 0.0, 0.4],
+///                 [0.0, 0.0, 0.3],
+///             ])
+///             .with_quaternions([rotation; 4]),
+///         )?;
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct Skeleton {
+    /// Translation of each joint relative to its parent joint.
+    pub translations: Option<SerializedComponentBatch>,
+
+    /// Index of the parent of each joint.
+    ///
+    /// Root joints reference themselves.
+    /// If not specified, all joints are treated as roots, i.e. they are placed relative to the entity's origin.
+    pub parents: Option<SerializedComponentBatch>,
+
+    /// Rotation of each joint relative to its parent joint.
+    ///
+    /// If not specified, joints aren't rotated relative to their parents.
+    pub quaternions: Option<SerializedComponentBatch>,
+
+    /// Optional radii for the joints.
+    ///
+    /// Bones are drawn with half the radius of the joint they lead to.
+    pub radii: Option<SerializedComponentBatch>,
+
+    /// Optional colors for the joints and bones.
+    ///
+    /// The bone leading to a joint uses the color of that joint.
+    pub colors: Option<SerializedComponentBatch>,
+
+    /// Optional text labels for the joints, e.g. their names.
+    ///
+    /// If there's a single label present, it will be placed at the center of the skeleton.
+    /// Otherwise, each label will be placed at its joint.
+    pub labels: Option<SerializedComponentBatch>,
+
+    /// Whether the text labels should be shown.
+    ///
+    /// If not set, labels will automatically appear when there is exactly one label for this entity
+    /// or the number of instances on this entity is under a certain threshold.
+    pub show_labels: Option<SerializedComponentBatch>,
+
+    /// Length of the coordinate axes drawn at each joint.
+    ///
+    /// If not set, no axes are drawn.
+    pub axis_length: Option<SerializedComponentBatch>,
+
+    /// Optional class IDs for the joints.
+    ///
+    /// The [`components::ClassId`][crate::components::ClassId] provides colors and labels if not specified explicitly.
+    pub class_ids: Option<SerializedComponentBatch>,
+}
+
+impl Skeleton {
+    /// Returns the [`ComponentDescriptor`] for [`Self::translations`].
+    ///
+    /// The corresponding component is [`crate::components::PoseTranslation3D`].
+    #[inline]
+    pub fn descriptor_translations() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Skeleton".into()),
+            component: "Skeleton:translations".into(),
+            component_type: Some("rerun.components.PoseTranslation3D".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::parents`].
+    ///
+    /// The corresponding component is [`crate::components::JointParent`].
+    #[inline]
+    pub fn descriptor_parents() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Skeleton".into()),
+            component: "Skeleton:parents".into(),
+            component_type: Some("rerun.components.JointParent".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::quaternions`].
+    ///
+    /// The corresponding component is [`crate::components::PoseRotationQuat`].
+    #[inline]
+    pub fn descriptor_quaternions() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Skeleton".into()),
+            component: "Skeleton:quaternions".into(),
+            component_type: Some("rerun.components.PoseRotationQuat".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::radii`].
+    ///
+    /// The corresponding component is [`crate::components::Radius`].
+    #[inline]
+    pub fn descriptor_radii() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Skeleton".into()),
+            component: "Skeleton:radii".into(),
+            component_type: Some("rerun.components.Radius".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::colors`].
+    ///
+    /// The corresponding component is [`crate::components::Color`].
+    #[inline]
+    pub fn descriptor_colors() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Skeleton".into()),
+            component: "Skeleton:colors".into(),
+            component_type: Some("rerun.components.Color".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::labels`].
+    ///
+    /// The corresponding component is [`crate::components::Text`].
+    #[inline]
+    pub fn descriptor_labels() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Skeleton".into()),
+            component: "Skeleton:labels".into(),
+            component_type: Some("rerun.components.Text".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::show_labels`].
+    ///
+    /// The corresponding component is [`crate::components::ShowLabels`].
+    #[inline]
+    pub fn descriptor_show_labels() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Skeleton".into()),
+            component: "Skeleton:show_labels".into(),
+            component_type: Some("rerun.components.ShowLabels".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::axis_length`].
+    ///
+    /// The corresponding component is [`crate::components::AxisLength`].
+    #[inline]
+    pub fn descriptor_axis_length() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Skeleton".into()),
+            component: "Skeleton:axis_length".into(),
+            component_type: Some("rerun.components.AxisLength".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::class_ids`].
+    ///
+    /// The corresponding component is [`crate::components::ClassId`].
+    #[inline]
+    pub fn descriptor_class_ids() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Skeleton".into()),
+            component: "Skeleton:class_ids".into(),
+            component_type: Some("rerun.components.ClassId".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
+    std::sync::LazyLock::new(|| [Skeleton::descriptor_translations()]);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 4usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            Skeleton::descriptor_parents(),
+            Skeleton::descriptor_quaternions(),
+            Skeleton::descriptor_radii(),
+            Skeleton::descriptor_colors(),
+        ]
+    });
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 4usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            Skeleton::descriptor_labels(),
+            Skeleton::descriptor_show_labels(),
+            Skeleton::descriptor_axis_length(),
+            Skeleton::descriptor_class_ids(),
+        ]
+    });
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 9usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            Skeleton::descriptor_translations(),
+            Skeleton::descriptor_parents(),
+            Skeleton::descriptor_quaternions(),
+            Skeleton::descriptor_radii(),
+            Skeleton::descriptor_colors(),
+            Skeleton::descriptor_labels(),
+            Skeleton::descriptor_show_labels(),
+            Skeleton::descriptor_axis_length(),
+            Skeleton::descriptor_class_ids(),
+        ]
+    });
+
+impl Skeleton {
+    /// The total number of components in the archetype: 1 required, 4 recommended, 4 optional
+    pub const NUM_COMPONENTS: usize = 9usize;
+}
+
+impl ::re_types_core::Archetype for Skeleton {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.archetypes.Skeleton".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Skeleton"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let translations = arrays_by_descr
+            .get(&Self::descriptor_translations())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_translations())
+            });
+        let parents = arrays_by_descr
+            .get(&Self::descriptor_parents())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_parents()));
+        let quaternions = arrays_by_descr
+            .get(&Self::descriptor_quaternions())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_quaternions())
+            });
+        let radii = arrays_by_descr
+            .get(&Self::descriptor_radii())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_radii()));
+        let colors = arrays_by_descr
+            .get(&Self::descriptor_colors())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_colors()));
+        let labels = arrays_by_descr
+            .get(&Self::descriptor_labels())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_labels()));
+        let show_labels = arrays_by_descr
+            .get(&Self::descriptor_show_labels())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_show_labels())
+            });
+        let axis_length = arrays_by_descr
+            .get(&Self::descriptor_axis_length())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_axis_length())
+            });
+        let class_ids = arrays_by_descr
+            .get(&Self::descriptor_class_ids())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_class_ids())
+            });
+        Ok(Self {
+            translations,
+            parents,
+            quaternions,
+            radii,
+            colors,
+            labels,
+            show_labels,
+            axis_length,
+            class_ids,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for Skeleton {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            self.translations.clone(),
+            self.parents.clone(),
+            self.quaternions.clone(),
+            self.radii.clone(),
+            self.colors.clone(),
+            self.labels.clone(),
+            self.show_labels.clone(),
+            self.axis_length.clone(),
+            self.class_ids.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for Skeleton {}
+
+impl Skeleton {
+    /// Create a new `Skeleton`.
+    #[inline]
+    pub fn new(
+        translations: impl IntoIterator<Item = impl Into<crate::components::PoseTranslation3D>>,
+    ) -> Self {
+        Self {
+            translations: try_serialize_field(Self::descriptor_translations(), translations),
+            parents: None,
+            quaternions: None,
+            radii: None,
+            colors: None,
+            labels: None,
+            show_labels: None,
+            axis_length: None,
+            class_ids: None,
+        }
+    }
+
+    /// Update only some specific fields of a `Skeleton`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `Skeleton`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            translations: Some(SerializedComponentBatch::new(
+                crate::components::PoseTranslation3D::arrow_empty(),
+                Self::descriptor_translations(),
+            )),
+            parents: Some(SerializedComponentBatch::new(
+                crate::components::JointParent::arrow_empty(),
+                Self::descriptor_parents(),
+            )),
+            quaternions: Some(SerializedComponentBatch::new(
+                crate::components::PoseRotationQuat::arrow_empty(),
+                Self::descriptor_quaternions(),
+            )),
+            radii: Some(SerializedComponentBatch::new(
+                crate::components::Radius::arrow_empty(),
+                Self::descriptor_radii(),
+            )),
+            colors: Some(SerializedComponentBatch::new(
+                crate::components::Color::arrow_empty(),
+                Self::descriptor_colors(),
+            )),
+            labels: Some(SerializedComponentBatch::new(
+                crate::components::Text::arrow_empty(),
+                Self::descriptor_labels(),
+            )),
+            show_labels: Some(SerializedComponentBatch::new(
+                crate::components::ShowLabels::arrow_empty(),
+                Self::descriptor_show_labels(),
+            )),
+            axis_length: Some(SerializedComponentBatch::new(
+                crate::components::AxisLength::arrow_empty(),
+                Self::descriptor_axis_length(),
+            )),
+            class_ids: Some(SerializedComponentBatch::new(
+                crate::components::ClassId::arrow_empty(),
+                Self::descriptor_class_ids(),
+            )),
+        }
+    }
+
+    /// Partitions the component data into multiple sub-batches.
+    ///
+    /// Specifically, this transforms the existing [`SerializedComponentBatch`]es data into [`SerializedComponentColumn`]s
+    /// instead, via [`SerializedComponentBatch::partitioned`].
+    ///
+    /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+    ///
+    /// The specified `lengths` must sum to the total length of the component batch.
+    ///
+    /// [`SerializedComponentColumn`]: [::re_types_core::SerializedComponentColumn]
+    #[inline]
+    pub fn columns<I>(
+        self,
+        _lengths: I,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>>
+    where
+        I: IntoIterator<Item = usize> + Clone,
+    {
+        let columns = [
+            self.translations
+                .map(|translations| translations.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.parents
+                .map(|parents| parents.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.quaternions
+                .map(|quaternions| quaternions.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.radii
+                .map(|radii| radii.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.colors
+                .map(|colors| colors.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.labels
+                .map(|labels| labels.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.show_labels
+                .map(|show_labels| show_labels.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.axis_length
+                .map(|axis_length| axis_length.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.class_ids
+                .map(|class_ids| class_ids.partitioned(_lengths.clone()))
+                .transpose()?,
+        ];
+        Ok(columns.into_iter().flatten())
+    }
+
+    /// Helper to partition the component data into unit-length sub-batches.
+    ///
+    /// This is semantically similar to calling [`Self::columns`] with `std::iter::take(1).repeat(n)`,
+    /// where `n` is automatically guessed.
+    #[inline]
+    pub fn columns_of_unit_batches(
+        self,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>> {
+        let len_translations = self.translations.as_ref().map(|b| b.array.len());
+        let len_parents = self.parents.as_ref().map(|b| b.array.len());
+        let len_quaternions = self.quaternions.as_ref().map(|b| b.array.len());
+        let len_radii = self.radii.as_ref().map(|b| b.array.len());
+        let len_colors = self.colors.as_ref().map(|b| b.array.len());
+        let len_labels = self.labels.as_ref().map(|b| b.array.len());
+        let len_show_labels = self.show_labels.as_ref().map(|b| b.array.len());
+        let len_axis_length = self.axis_length.as_ref().map(|b| b.array.len());
+        let len_class_ids = self.class_ids.as_ref().map(|b| b.array.len());
+        let len = None
+            .or(len_translations)
+            .or(len_parents)
+            .or(len_quaternions)
+            .or(len_radii)
+            .or(len_colors)
+            .or(len_labels)
+            .or(len_show_labels)
+            .or(len_axis_length)
+            .or(len_class_ids)
+            .unwrap_or(0);
+        self.columns(std::iter::repeat_n(1, len))
+    }
+
+    /// Translation of each joint relative to its parent joint.
+    #[inline]
+    pub fn with_translations(
+        mut self,
+        translations: impl IntoIterator<Item = impl Into<crate::components::PoseTranslation3D>>,
+    ) -> Self {
+        self.translations = try_serialize_field(Self::descriptor_translations(), translations);
+        self
+    }
+
+    /// Index of the parent of each joint.
+    ///
+    /// Root joints reference themselves.
+    /// If not specified, all joints are treated as roots, i.e. they are placed relative to the entity's origin.
+    #[inline]
+    pub fn with_parents(
+        mut self,
+        parents: impl IntoIterator<Item = impl Into<crate::components::JointParent>>,
+    ) -> Self {
+        self.parents = try_serialize_field(Self::descriptor_parents(), parents);
+        self
+    }
+
+    /// Rotation of each joint relative to its parent joint.
+    ///
+    /// If not specified, joints aren't rotated relative to their parents.
+    #[inline]
+    pub fn with_quaternions(
+        mut self,
+        quaternions: impl IntoIterator<Item = impl Into<crate::components::PoseRotationQuat>>,
+    ) -> Self {
+        self.quaternions = try_serialize_field(Self::descriptor_quaternions(), quaternions);
+        self
+    }
+
+    /// Optional radii for the joints.
+    ///
+    /// Bones are drawn with half the radius of the joint they lead to.
+    #[inline]
+    pub fn with_radii(
+        mut self,
+        radii: impl IntoIterator<Item = impl Into<crate::components::Radius>>,
+    ) -> Self {
+        self.radii = try_serialize_field(Self::descriptor_radii(), radii);
+        self
+    }
+
+    /// Optional colors for the joints and bones.
+    ///
+    /// The bone leading to a joint uses the color of that joint.
+    #[inline]
+    pub fn with_colors(
+        mut self,
+        colors: impl IntoIterator<Item = impl Into<crate::components::Color>>,
+    ) -> Self {
+        self.colors = try_serialize_field(Self::descriptor_colors(), colors);
+        self
+    }
+
+    /// Optional text labels for the joints, e.g. their names.
+    ///
+    /// If there's a single label present, it will be placed at the center of the skeleton.
+    /// Otherwise, each label will be placed at its joint.
+    #[inline]
+    pub fn with_labels(
+        mut self,
+        labels: impl IntoIterator<Item = impl Into<crate::components::Text>>,
+    ) -> Self {
+        self.labels = try_serialize_field(Self::descriptor_labels(), labels);
+        self
+    }
+
+    /// Whether the text labels should be shown.
+    ///
+    /// If not set, labels will automatically appear when there is exactly one label for this entity
+    /// or the number of instances on this entity is under a certain threshold.
+    #[inline]
+    pub fn with_show_labels(
+        mut self,
+        show_labels: impl Into<crate::components::ShowLabels>,
+    ) -> Self {
+        self.show_labels = try_serialize_field(Self::descriptor_show_labels(), [show_labels]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::ShowLabels`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_show_labels`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_show_labels(
+        mut self,
+        show_labels: impl IntoIterator<Item = impl Into<crate::components::ShowLabels>>,
+    ) -> Self {
+        self.show_labels = try_serialize_field(Self::descriptor_show_labels(), show_labels);
+        self
+    }
+
+    /// Length of the coordinate axes drawn at each joint.
+    ///
+    /// If not set, no axes are drawn.
+    #[inline]
+    pub fn with_axis_length(
+        mut self,
+        axis_length: impl Into<crate::components::AxisLength>,
+    ) -> Self {
+        self.axis_length = try_serialize_field(Self::descriptor_axis_length(), [axis_length]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::AxisLength`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_axis_length`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_axis_length(
+        mut self,
+        axis_length: impl IntoIterator<Item = impl Into<crate::components::AxisLength>>,
+    ) -> Self {
+        self.axis_length = try_serialize_field(Self::descriptor_axis_length(), axis_length);
+        self
+    }
+
+    /// Optional class IDs for the joints.
+    ///
+    /// The [`components::ClassId`][crate::components::ClassId] provides colors and labels if not specified explicitly.
+    #[inline]
+    pub fn with_class_ids(
+        mut self,
+        class_ids: impl IntoIterator<Item = impl Into<crate::components::ClassId>>,
+    ) -> Self {
+        self.class_ids = try_serialize_field(Self::descriptor_class_ids(), class_ids);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for Skeleton {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.translations.heap_size_bytes()
+            + self.parents.heap_size_bytes()
+            + self.quaternions.heap_size_bytes()
+            + self.radii.heap_size_bytes()
+            + self.colors.heap_size_bytes()
+            + self.labels.heap_size_bytes()
+            + self.show_labels.heap_size_bytes()
+            + self.axis_length.heap_size_bytes()
+            + self.class_ids.heap_size_bytes()
+    }
+}
@@ -0,0 +1,160 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/bar_chart_layout.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::allow_attributes)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+#![allow(clippy::wildcard_imports)]
+
+use ::re_types_core::SerializationResult;
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Configures how the bar charts of several entities are combined in a bar chart view.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct BarChartLayout {
+    /// How the bars of different entities at the same position are arranged.
+    pub mode: Option<SerializedComponentBatch>,
+}
+
+impl BarChartLayout {
+    /// Returns the [`ComponentDescriptor`] for [`Self::mode`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::BarChartMode`].
+    #[inline]
+    pub fn descriptor_mode() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.BarChartLayout".into()),
+            component: "BarChartLayout:mode".into(),
+            component_type: Some("rerun.blueprint.components.BarChartMode".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
+    std::sync::LazyLock::new(|| [BarChartLayout::descriptor_mode()]);
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
+    std::sync::LazyLock::new(|| [BarChartLayout::descriptor_mode()]);
+
+impl BarChartLayout {
+    /// The total number of components in the archetype: 0 required, 0 recommended, 1 optional
+    pub const NUM_COMPONENTS: usize = 1usize;
+}
+
+impl ::re_types_core::Archetype for BarChartLayout {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.BarChartLayout".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Bar chart layout"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let mode = arrays_by_descr
+            .get(&Self::descriptor_mode())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_mode()));
+        Ok(Self { mode })
+    }
+}
+
+impl ::re_types_core::AsComponents for BarChartLayout {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        std::iter::once(self.mode.clone()).flatten().collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for BarChartLayout {}
+
+impl BarChartLayout {
+    /// Create a new `BarChartLayout`.
+    #[inline]
+    pub fn new() -> Self {
+        Self { mode: None }
+    }
+
+    /// Update only some specific fields of a `BarChartLayout`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `BarChartLayout`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            mode: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::BarChartMode::arrow_empty(),
+                Self::descriptor_mode(),
+            )),
+        }
+    }
+
+    /// How the bars of different entities at the same position are arranged.
+    #[inline]
+    pub fn with_mode(
+        mut self,
+        mode: impl Into<crate::blueprint::components::BarChartMode>,
+    ) -> Self {
+        self.mode = try_serialize_field(Self::descriptor_mode(), [mode]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for BarChartLayout {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.mode.heap_size_bytes()
+    }
+}
@@ -0,0 +1,227 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/class_id_remap.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::allow_attributes)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+#![allow(clippy::wildcard_imports)]
+
+use ::re_types_core::SerializationResult;
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Remaps class ids of the data shown in a view, e.g. to merge the classes of a segmentation image.
+///
+/// Each entry of `source_class_ids` is shown as the class at the same index in `target_class_ids`,
+/// i.e. with its color and label from the [`archetypes::AnnotationContext`][crate::archetypes::AnnotationContext].
+/// Class ids that are not listed are shown as they are.
+///
+/// This applies to everything with class ids in the view, and doesn't change the logged data.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct ClassIdRemap {
+    /// The class ids that are remapped.
+    pub source_class_ids: Option<SerializedComponentBatch>,
+
+    /// The class id that each entry of `source_class_ids` is remapped to.
+    ///
+    /// Must have the same length as `source_class_ids`, extra entries of either are ignored.
+    pub target_class_ids: Option<SerializedComponentBatch>,
+}
+
+impl ClassIdRemap {
+    /// Returns the [`ComponentDescriptor`] for [`Self::source_class_ids`].
+    ///
+    /// The corresponding component is [`crate::components::ClassId`].
+    #[inline]
+    pub fn descriptor_source_class_ids() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.ClassIdRemap".into()),
+            component: "ClassIdRemap:source_class_ids".into(),
+            component_type: Some("rerun.components.ClassId".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::target_class_ids`].
+    ///
+    /// The corresponding component is [`crate::components::ClassId`].
+    #[inline]
+    pub fn descriptor_target_class_ids() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.ClassIdRemap".into()),
+            component: "ClassIdRemap:target_class_ids".into(),
+            component_type: Some("rerun.components.ClassId".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 2usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            ClassIdRemap::descriptor_source_class_ids(),
+            ClassIdRemap::descriptor_target_class_ids(),
+        ]
+    });
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 2usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            ClassIdRemap::descriptor_source_class_ids(),
+            ClassIdRemap::descriptor_target_class_ids(),
+        ]
+    });
+
+impl ClassIdRemap {
+    /// The total number of components in the archetype: 0 required, 0 recommended, 2 optional
+    pub const NUM_COMPONENTS: usize = 2usize;
+}
+
+impl ::re_types_core::Archetype for ClassIdRemap {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.ClassIdRemap".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Class id remap"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let source_class_ids = arrays_by_descr
+            .get(&Self::descriptor_source_class_ids())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_source_class_ids())
+            });
+        let target_class_ids = arrays_by_descr
+            .get(&Self::descriptor_target_class_ids())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_target_class_ids())
+            });
+        Ok(Self {
+            source_class_ids,
+            target_class_ids,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for ClassIdRemap {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [self.source_class_ids.clone(), self.target_class_ids.clone()]
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for ClassIdRemap {}
+
+impl ClassIdRemap {
+    /// Create a new `ClassIdRemap`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            source_class_ids: None,
+            target_class_ids: None,
+        }
+    }
+
+    /// Update only some specific fields of a `ClassIdRemap`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `ClassIdRemap`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            source_class_ids: Some(SerializedComponentBatch::new(
+                crate::components::ClassId::arrow_empty(),
+                Self::descriptor_source_class_ids(),
+            )),
+            target_class_ids: Some(SerializedComponentBatch::new(
+                crate::components::ClassId::arrow_empty(),
+                Self::descriptor_target_class_ids(),
+            )),
+        }
+    }
+
+    /// The class ids that are remapped.
+    #[inline]
+    pub fn with_source_class_ids(
+        mut self,
+        source_class_ids: impl IntoIterator<Item = impl Into<crate::components::ClassId>>,
+    ) -> Self {
+        self.source_class_ids =
+            try_serialize_field(Self::descriptor_source_class_ids(), source_class_ids);
+        self
+    }
+
+    /// The class id that each entry of `source_class_ids` is remapped to.
+    ///
+    /// Must have the same length as `source_class_ids`, extra entries of either are ignored.
+    #[inline]
+    pub fn with_target_class_ids(
+        mut self,
+        target_class_ids: impl IntoIterator<Item = impl Into<crate::components::ClassId>>,
+    ) -> Self {
+        self.target_class_ids =
+            try_serialize_field(Self::descriptor_target_class_ids(), target_class_ids);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for ClassIdRemap {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.source_class_ids.heap_size_bytes() + self.target_class_ids.heap_size_bytes()
+    }
+}
@@ -0,0 +1,269 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/color_by_component.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::allow_attributes)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+#![allow(clippy::wildcard_imports)]
+
+use ::re_types_core::SerializationResult;
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Colors the instances of an entity by the value of a scalar component logged alongside them.
+///
+/// Log this as an override of the entity in a 3D view, e.g. to color points by `intensity`
+/// or boxes by `confidence`.
+/// Applies to points, boxes, ellipsoids, capsules, cylinders, and meshes,
+/// and takes precedence over their logged colors.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct ColorByComponent {
+    /// The component whose values are mapped to colors, e.g. `intensity`.
+    ///
+    /// Either the full component identifier or just the part after the archetype prefix.
+    /// Any numeric component works.
+    /// If unset, or if the entity doesn't have this component, the instances keep their colors.
+    pub component: Option<SerializedComponentBatch>,
+
+    /// The colormap the values are mapped through.
+    ///
+    /// Defaults to viridis.
+    pub colormap: Option<SerializedComponentBatch>,
+
+    /// The values mapped to the start and end of the colormap.
+    ///
+    /// Values outside of this range are clamped.
+    /// If unset, the range of the entity's values at the current time is used.
+    pub range: Option<SerializedComponentBatch>,
+}
+
+impl ColorByComponent {
+    /// Returns the [`ComponentDescriptor`] for [`Self::component`].
+    ///
+    /// The corresponding component is [`crate::components::Name`].
+    #[inline]
+    pub fn descriptor_component() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.ColorByComponent".into()),
+            component: "ColorByComponent:component".into(),
+            component_type: Some("rerun.components.Name".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::colormap`].
+    ///
+    /// The corresponding component is [`crate::components::Colormap`].
+    #[inline]
+    pub fn descriptor_colormap() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.ColorByComponent".into()),
+            component: "ColorByComponent:colormap".into(),
+            component_type: Some("rerun.components.Colormap".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::range`].
+    ///
+    /// The corresponding component is [`crate::components::ValueRange`].
+    #[inline]
+    pub fn descriptor_range() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.ColorByComponent".into()),
+            component: "ColorByComponent:range".into(),
+            component_type: Some("rerun.components.ValueRange".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 3usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            ColorByComponent::descriptor_component(),
+            ColorByComponent::descriptor_colormap(),
+            ColorByComponent::descriptor_range(),
+        ]
+    });
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 3usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            ColorByComponent::descriptor_component(),
+            ColorByComponent::descriptor_colormap(),
+            ColorByComponent::descriptor_range(),
+        ]
+    });
+
+impl ColorByComponent {
+    /// The total number of components in the archetype: 0 required, 0 recommended, 3 optional
+    pub const NUM_COMPONENTS: usize = 3usize;
+}
+
+impl ::re_types_core::Archetype for ColorByComponent {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.ColorByComponent".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Color by component"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let component = arrays_by_descr
+            .get(&Self::descriptor_component())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_component())
+            });
+        let colormap = arrays_by_descr
+            .get(&Self::descriptor_colormap())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_colormap()));
+        let range = arrays_by_descr
+            .get(&Self::descriptor_range())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_range()));
+        Ok(Self {
+            component,
+            colormap,
+            range,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for ColorByComponent {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            self.component.clone(),
+            self.colormap.clone(),
+            self.range.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for ColorByComponent {}
+
+impl ColorByComponent {
+    /// Create a new `ColorByComponent`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            component: None,
+            colormap: None,
+            range: None,
+        }
+    }
+
+    /// Update only some specific fields of a `ColorByComponent`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `ColorByComponent`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            component: Some(SerializedComponentBatch::new(
+                crate::components::Name::arrow_empty(),
+                Self::descriptor_component(),
+            )),
+            colormap: Some(SerializedComponentBatch::new(
+                crate::components::Colormap::arrow_empty(),
+                Self::descriptor_colormap(),
+            )),
+            range: Some(SerializedComponentBatch::new(
+                crate::components::ValueRange::arrow_empty(),
+                Self::descriptor_range(),
+            )),
+        }
+    }
+
+    /// The component whose values are mapped to colors, e.g. `intensity`.
+    ///
+    /// Either the full component identifier or just the part after the archetype prefix.
+    /// Any numeric component works.
+    /// If unset, or if the entity doesn't have this component, the instances keep their colors.
+    #[inline]
+    pub fn with_component(mut self, component: impl Into<crate::components::Name>) -> Self {
+        self.component = try_serialize_field(Self::descriptor_component(), [component]);
+        self
+    }
+
+    /// The colormap the values are mapped through.
+    ///
+    /// Defaults to viridis.
+    #[inline]
+    pub fn with_colormap(mut self, colormap: impl Into<crate::components::Colormap>) -> Self {
+        self.colormap = try_serialize_field(Self::descriptor_colormap(), [colormap]);
+        self
+    }
+
+    /// The values mapped to the start and end of the colormap.
+    ///
+    /// Values outside of this range are clamped.
+    /// If unset, the range of the entity's values at the current time is used.
+    #[inline]
+    pub fn with_range(mut self, range: impl Into<crate::components::ValueRange>) -> Self {
+        self.range = try_serialize_field(Self::descriptor_range(), [range]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for ColorByComponent {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.component.heap_size_bytes()
+            + self.colormap.heap_size_bytes()
+            + self.range.heap_size_bytes()
+    }
+}
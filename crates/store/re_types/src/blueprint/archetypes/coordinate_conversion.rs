@@ -0,0 +1,332 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/coordinate_conversion.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::allow_attributes)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+#![allow(clippy::wildcard_imports)]
+
+use ::re_types_core::SerializationResult;
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Converts the data shown in a 3D view from the coordinate convention it was logged in to another one.
+///
+/// E.g. this makes it possible to show data logged in NED (`FRD`) in an ENU (`RFU`) world,
+/// or data logged in Earth-centered, Earth-fixed coordinates in the local tangent plane at `geo_origin`.
+///
+/// This only changes how the data is shown, not the logged data itself.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct CoordinateConversion {
+    /// The coordinate convention the data is logged in, e.g. `FRD` for NED.
+    ///
+    /// Defaults to the [`components::ViewCoordinates`][crate::components::ViewCoordinates] logged at the space origin, if any.
+    pub source_coordinates: Option<SerializedComponentBatch>,
+
+    /// The coordinate convention the data is shown in, e.g. `RFU` for ENU.
+    ///
+    /// Defaults to `source_coordinates`, i.e. no conversion.
+    pub target_coordinates: Option<SerializedComponentBatch>,
+
+    /// Whether the data is logged in Earth-centered, Earth-fixed (ECEF) coordinates, in meters.
+    ///
+    /// If enabled, the data is shown in the east-north-up tangent plane at `geo_origin` instead,
+    /// and `source_coordinates` is ignored.
+    ///
+    /// Defaults to false.
+    pub earth_centered: Option<SerializedComponentBatch>,
+
+    /// The origin of the local tangent plane, on the WGS84 ellipsoid.
+    ///
+    /// Used for Earth-centered data, and to place [`archetypes::GeoPoints`][crate::archetypes::GeoPoints] in the view,
+    /// which the `GeoPoints3D` visualizer does in an east-north-up frame.
+    ///
+    /// Defaults to 0° latitude, 0° longitude.
+    pub geo_origin: Option<SerializedComponentBatch>,
+}
+
+impl CoordinateConversion {
+    /// Returns the [`ComponentDescriptor`] for [`Self::source_coordinates`].
+    ///
+    /// The corresponding component is [`crate::components::ViewCoordinates`].
+    #[inline]
+    pub fn descriptor_source_coordinates() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.CoordinateConversion".into()),
+            component: "CoordinateConversion:source_coordinates".into(),
+            component_type: Some("rerun.components.ViewCoordinates".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::target_coordinates`].
+    ///
+    /// The corresponding component is [`crate::components::ViewCoordinates`].
+    #[inline]
+    pub fn descriptor_target_coordinates() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.CoordinateConversion".into()),
+            component: "CoordinateConversion:target_coordinates".into(),
+            component_type: Some("rerun.components.ViewCoordinates".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::earth_centered`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::Enabled`].
+    #[inline]
+    pub fn descriptor_earth_centered() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.CoordinateConversion".into()),
+            component: "CoordinateConversion:earth_centered".into(),
+            component_type: Some("rerun.blueprint.components.Enabled".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::geo_origin`].
+    ///
+    /// The corresponding component is [`crate::components::LatLon`].
+    #[inline]
+    pub fn descriptor_geo_origin() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.CoordinateConversion".into()),
+            component: "CoordinateConversion:geo_origin".into(),
+            component_type: Some("rerun.components.LatLon".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 4usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            CoordinateConversion::descriptor_source_coordinates(),
+            CoordinateConversion::descriptor_target_coordinates(),
+            CoordinateConversion::descriptor_earth_centered(),
+            CoordinateConversion::descriptor_geo_origin(),
+        ]
+    });
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 4usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            CoordinateConversion::descriptor_source_coordinates(),
+            CoordinateConversion::descriptor_target_coordinates(),
+            CoordinateConversion::descriptor_earth_centered(),
+            CoordinateConversion::descriptor_geo_origin(),
+        ]
+    });
+
+impl CoordinateConversion {
+    /// The total number of components in the archetype: 0 required, 0 recommended, 4 optional
+    pub const NUM_COMPONENTS: usize = 4usize;
+}
+
+impl ::re_types_core::Archetype for CoordinateConversion {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.CoordinateConversion".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Coordinate conversion"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let source_coordinates = arrays_by_descr
+            .get(&Self::descriptor_source_coordinates())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_source_coordinates())
+            });
+        let target_coordinates = arrays_by_descr
+            .get(&Self::descriptor_target_coordinates())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_target_coordinates())
+            });
+        let earth_centered = arrays_by_descr
+            .get(&Self::descriptor_earth_centered())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_earth_centered())
+            });
+        let geo_origin = arrays_by_descr
+            .get(&Self::descriptor_geo_origin())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_geo_origin())
+            });
+        Ok(Self {
+            source_coordinates,
+            target_coordinates,
+            earth_centered,
+            geo_origin,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for CoordinateConversion {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            self.source_coordinates.clone(),
+            self.target_coordinates.clone(),
+            self.earth_centered.clone(),
+            self.geo_origin.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for CoordinateConversion {}
+
+impl CoordinateConversion {
+    /// Create a new `CoordinateConversion`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            source_coordinates: None,
+            target_coordinates: None,
+            earth_centered: None,
+            geo_origin: None,
+        }
+    }
+
+    /// Update only some specific fields of a `CoordinateConversion`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `CoordinateConversion`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            source_coordinates: Some(SerializedComponentBatch::new(
+                crate::components::ViewCoordinates::arrow_empty(),
+                Self::descriptor_source_coordinates(),
+            )),
+            target_coordinates: Some(SerializedComponentBatch::new(
+                crate::components::ViewCoordinates::arrow_empty(),
+                Self::descriptor_target_coordinates(),
+            )),
+            earth_centered: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::Enabled::arrow_empty(),
+                Self::descriptor_earth_centered(),
+            )),
+            geo_origin: Some(SerializedComponentBatch::new(
+                crate::components::LatLon::arrow_empty(),
+                Self::descriptor_geo_origin(),
+            )),
+        }
+    }
+
+    /// The coordinate convention the data is logged in, e.g. `FRD` for NED.
+    ///
+    /// Defaults to the [`components::ViewCoordinates`][crate::components::ViewCoordinates] logged at the space origin, if any.
+    #[inline]
+    pub fn with_source_coordinates(
+        mut self,
+        source_coordinates: impl Into<crate::components::ViewCoordinates>,
+    ) -> Self {
+        self.source_coordinates =
+            try_serialize_field(Self::descriptor_source_coordinates(), [source_coordinates]);
+        self
+    }
+
+    /// The coordinate convention the data is shown in, e.g. `RFU` for ENU.
+    ///
+    /// Defaults to `source_coordinates`, i.e. no conversion.
+    #[inline]
+    pub fn with_target_coordinates(
+        mut self,
+        target_coordinates: impl Into<crate::components::ViewCoordinates>,
+    ) -> Self {
+        self.target_coordinates =
+            try_serialize_field(Self::descriptor_target_coordinates(), [target_coordinates]);
+        self
+    }
+
+    /// Whether the data is logged in Earth-centered, Earth-fixed (ECEF) coordinates, in meters.
+    ///
+    /// If enabled, the data is shown in the east-north-up tangent plane at `geo_origin` instead,
+    /// and `source_coordinates` is ignored.
+    ///
+    /// Defaults to false.
+    #[inline]
+    pub fn with_earth_centered(
+        mut self,
+        earth_centered: impl Into<crate::blueprint::components::Enabled>,
+    ) -> Self {
+        self.earth_centered =
+            try_serialize_field(Self::descriptor_earth_centered(), [earth_centered]);
+        self
+    }
+
+    /// The origin of the local tangent plane, on the WGS84 ellipsoid.
+    ///
+    /// Used for Earth-centered data, and to place [`archetypes::GeoPoints`][crate::archetypes::GeoPoints] in the view,
+    /// which the `GeoPoints3D` visualizer does in an east-north-up frame.
+    ///
+    /// Defaults to 0° latitude, 0° longitude.
+    #[inline]
+    pub fn with_geo_origin(mut self, geo_origin: impl Into<crate::components::LatLon>) -> Self {
+        self.geo_origin = try_serialize_field(Self::descriptor_geo_origin(), [geo_origin]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for CoordinateConversion {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.source_coordinates.heap_size_bytes()
+            + self.target_coordinates.heap_size_bytes()
+            + self.earth_centered.heap_size_bytes()
+            + self.geo_origin.heap_size_bytes()
+    }
+}
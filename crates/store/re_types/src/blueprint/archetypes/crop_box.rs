@@ -0,0 +1,310 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/crop_box.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::allow_attributes)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+#![allow(clippy::wildcard_imports)]
+
+use ::re_types_core::SerializationResult;
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Hides the points of point clouds inside or outside of an axis-aligned box in a 3D view.
+///
+/// The points are filtered on the GPU, so this stays fast even for very large point clouds.
+/// Depth images shown as point clouds are cropped the same way, Gaussian splats by their center.
+/// Hidden points can't be hovered or selected.
+/// The box can be moved and resized by dragging its faces in the view.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct CropBox {
+    /// Whether the crop box is applied.
+    ///
+    /// Defaults to false.
+    pub enabled: Option<SerializedComponentBatch>,
+
+    /// The center of the box, in the coordinates of the view's origin.
+    ///
+    /// Defaults to the origin.
+    pub center: Option<SerializedComponentBatch>,
+
+    /// Half the size of the box along each axis.
+    ///
+    /// Defaults to 1 along each axis.
+    pub half_size: Option<SerializedComponentBatch>,
+
+    /// If enabled, the points inside the box are hidden instead of those outside.
+    ///
+    /// Defaults to false.
+    pub keep_outside: Option<SerializedComponentBatch>,
+}
+
+impl CropBox {
+    /// Returns the [`ComponentDescriptor`] for [`Self::enabled`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::Enabled`].
+    #[inline]
+    pub fn descriptor_enabled() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.CropBox".into()),
+            component: "CropBox:enabled".into(),
+            component_type: Some("rerun.blueprint.components.Enabled".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::center`].
+    ///
+    /// The corresponding component is [`crate::components::Translation3D`].
+    #[inline]
+    pub fn descriptor_center() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.CropBox".into()),
+            component: "CropBox:center".into(),
+            component_type: Some("rerun.components.Translation3D".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::half_size`].
+    ///
+    /// The corresponding component is [`crate::components::HalfSize3D`].
+    #[inline]
+    pub fn descriptor_half_size() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.CropBox".into()),
+            component: "CropBox:half_size".into(),
+            component_type: Some("rerun.components.HalfSize3D".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::keep_outside`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::Enabled`].
+    #[inline]
+    pub fn descriptor_keep_outside() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.CropBox".into()),
+            component: "CropBox:keep_outside".into(),
+            component_type: Some("rerun.blueprint.components.Enabled".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 4usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            CropBox::descriptor_enabled(),
+            CropBox::descriptor_center(),
+            CropBox::descriptor_half_size(),
+            CropBox::descriptor_keep_outside(),
+        ]
+    });
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 4usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            CropBox::descriptor_enabled(),
+            CropBox::descriptor_center(),
+            CropBox::descriptor_half_size(),
+            CropBox::descriptor_keep_outside(),
+        ]
+    });
+
+impl CropBox {
+    /// The total number of components in the archetype: 0 required, 0 recommended, 4 optional
+    pub const NUM_COMPONENTS: usize = 4usize;
+}
+
+impl ::re_types_core::Archetype for CropBox {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.CropBox".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Crop box"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let enabled = arrays_by_descr
+            .get(&Self::descriptor_enabled())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_enabled()));
+        let center = arrays_by_descr
+            .get(&Self::descriptor_center())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_center()));
+        let half_size = arrays_by_descr
+            .get(&Self::descriptor_half_size())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_half_size())
+            });
+        let keep_outside = arrays_by_descr
+            .get(&Self::descriptor_keep_outside())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_keep_outside())
+            });
+        Ok(Self {
+            enabled,
+            center,
+            half_size,
+            keep_outside,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for CropBox {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            self.enabled.clone(),
+            self.center.clone(),
+            self.half_size.clone(),
+            self.keep_outside.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for CropBox {}
+
+impl CropBox {
+    /// Create a new `CropBox`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            enabled: None,
+            center: None,
+            half_size: None,
+            keep_outside: None,
+        }
+    }
+
+    /// Update only some specific fields of a `CropBox`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `CropBox`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            enabled: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::Enabled::arrow_empty(),
+                Self::descriptor_enabled(),
+            )),
+            center: Some(SerializedComponentBatch::new(
+                crate::components::Translation3D::arrow_empty(),
+                Self::descriptor_center(),
+            )),
+            half_size: Some(SerializedComponentBatch::new(
+                crate::components::HalfSize3D::arrow_empty(),
+                Self::descriptor_half_size(),
+            )),
+            keep_outside: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::Enabled::arrow_empty(),
+                Self::descriptor_keep_outside(),
+            )),
+        }
+    }
+
+    /// Whether the crop box is applied.
+    ///
+    /// Defaults to false.
+    #[inline]
+    pub fn with_enabled(
+        mut self,
+        enabled: impl Into<crate::blueprint::components::Enabled>,
+    ) -> Self {
+        self.enabled = try_serialize_field(Self::descriptor_enabled(), [enabled]);
+        self
+    }
+
+    /// The center of the box, in the coordinates of the view's origin.
+    ///
+    /// Defaults to the origin.
+    #[inline]
+    pub fn with_center(mut self, center: impl Into<crate::components::Translation3D>) -> Self {
+        self.center = try_serialize_field(Self::descriptor_center(), [center]);
+        self
+    }
+
+    /// Half the size of the box along each axis.
+    ///
+    /// Defaults to 1 along each axis.
+    #[inline]
+    pub fn with_half_size(mut self, half_size: impl Into<crate::components::HalfSize3D>) -> Self {
+        self.half_size = try_serialize_field(Self::descriptor_half_size(), [half_size]);
+        self
+    }
+
+    /// If enabled, the points inside the box are hidden instead of those outside.
+    ///
+    /// Defaults to false.
+    #[inline]
+    pub fn with_keep_outside(
+        mut self,
+        keep_outside: impl Into<crate::blueprint::components::Enabled>,
+    ) -> Self {
+        self.keep_outside = try_serialize_field(Self::descriptor_keep_outside(), [keep_outside]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for CropBox {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.enabled.heap_size_bytes()
+            + self.center.heap_size_bytes()
+            + self.half_size.heap_size_bytes()
+            + self.keep_outside.heap_size_bytes()
+    }
+}
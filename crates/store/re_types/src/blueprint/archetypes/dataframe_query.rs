@@ -42,6 +42,12 @@ pub struct DataframeQuery {
     /// Should empty cells be filled with latest-at queries?
     pub apply_latest_at: Option<SerializedComponentBatch>,
 
+    /// If set, empty cells are only filled with values logged at most this long before their row.
+    ///
+    /// This is in the units of the timeline, i.e. nanoseconds or sequence steps.
+    /// It has no effect unless `apply_latest_at` is enabled.
+    pub latest_at_tolerance: Option<SerializedComponentBatch>,
+
     /// Selected columns. If unset, all columns are selected.
     pub select: Option<SerializedComponentBatch>,
 }
@@ -95,6 +101,18 @@ impl DataframeQuery {
         }
     }
 
+    /// Returns the [`ComponentDescriptor`] for [`Self::latest_at_tolerance`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::TimeInt`].
+    #[inline]
+    pub fn descriptor_latest_at_tolerance() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.DataframeQuery".into()),
+            component: "DataframeQuery:latest_at_tolerance".into(),
+            component_type: Some("rerun.blueprint.components.TimeInt".into()),
+        }
+    }
+
     /// Returns the [`ComponentDescriptor`] for [`Self::select`].
     ///
     /// The corresponding component is [`crate::blueprint::components::SelectedColumns`].
@@ -114,31 +132,33 @@ static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
 static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
     std::sync::LazyLock::new(|| []);
 
-static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 5usize]> =
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 6usize]> =
     std::sync::LazyLock::new(|| {
         [
             DataframeQuery::descriptor_timeline(),
             DataframeQuery::descriptor_filter_by_range(),
             DataframeQuery::descriptor_filter_is_not_null(),
             DataframeQuery::descriptor_apply_latest_at(),
+            DataframeQuery::descriptor_latest_at_tolerance(),
             DataframeQuery::descriptor_select(),
         ]
     });
 
-static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 5usize]> =
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 6usize]> =
     std::sync::LazyLock::new(|| {
         [
             DataframeQuery::descriptor_timeline(),
             DataframeQuery::descriptor_filter_by_range(),
             DataframeQuery::descriptor_filter_is_not_null(),
             DataframeQuery::descriptor_apply_latest_at(),
+            DataframeQuery::descriptor_latest_at_tolerance(),
             DataframeQuery::descriptor_select(),
         ]
     });
 
 impl DataframeQuery {
-    /// The total number of components in the archetype: 0 required, 0 recommended, 5 optional
-    pub const NUM_COMPONENTS: usize = 5usize;
+    /// The total number of components in the archetype: 0 required, 0 recommended, 6 optional
+    pub const NUM_COMPONENTS: usize = 6usize;
 }
 
 impl ::re_types_core::Archetype for DataframeQuery {
@@ -197,6 +217,11 @@ impl ::re_types_core::Archetype for DataframeQuery {
             .map(|array| {
                 SerializedComponentBatch::new(array.clone(), Self::descriptor_apply_latest_at())
             });
+        let latest_at_tolerance = arrays_by_descr
+            .get(&Self::descriptor_latest_at_tolerance())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_latest_at_tolerance())
+            });
         let select = arrays_by_descr
             .get(&Self::descriptor_select())
             .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_select()));
@@ -205,6 +230,7 @@ impl ::re_types_core::Archetype for DataframeQuery {
             filter_by_range,
             filter_is_not_null,
             apply_latest_at,
+            latest_at_tolerance,
             select,
         })
     }
@@ -219,6 +245,7 @@ impl ::re_types_core::AsComponents for DataframeQuery {
             self.filter_by_range.clone(),
             self.filter_is_not_null.clone(),
             self.apply_latest_at.clone(),
+            self.latest_at_tolerance.clone(),
             self.select.clone(),
         ]
         .into_iter()
@@ -238,6 +265,7 @@ impl DataframeQuery {
             filter_by_range: None,
             filter_is_not_null: None,
             apply_latest_at: None,
+            latest_at_tolerance: None,
             select: None,
         }
     }
@@ -269,6 +297,10 @@ impl DataframeQuery {
                 crate::blueprint::components::ApplyLatestAt::arrow_empty(),
                 Self::descriptor_apply_latest_at(),
             )),
+            latest_at_tolerance: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::TimeInt::arrow_empty(),
+                Self::descriptor_latest_at_tolerance(),
+            )),
             select: Some(SerializedComponentBatch::new(
                 crate::blueprint::components::SelectedColumns::arrow_empty(),
                 Self::descriptor_select(),
@@ -323,6 +355,22 @@ impl DataframeQuery {
         self
     }
 
+    /// If set, empty cells are only filled with values logged at most this long before their row.
+    ///
+    /// This is in the units of the timeline, i.e. nanoseconds or sequence steps.
+    /// It has no effect unless `apply_latest_at` is enabled.
+    #[inline]
+    pub fn with_latest_at_tolerance(
+        mut self,
+        latest_at_tolerance: impl Into<crate::blueprint::components::TimeInt>,
+    ) -> Self {
+        self.latest_at_tolerance = try_serialize_field(
+            Self::descriptor_latest_at_tolerance(),
+            [latest_at_tolerance],
+        );
+        self
+    }
+
     /// Selected columns. If unset, all columns are selected.
     #[inline]
     pub fn with_select(
@@ -341,6 +389,7 @@ impl ::re_byte_size::SizeBytes for DataframeQuery {
             + self.filter_by_range.heap_size_bytes()
             + self.filter_is_not_null.heap_size_bytes()
             + self.apply_latest_at.heap_size_bytes()
+            + self.latest_at_tolerance.heap_size_bytes()
             + self.select.heap_size_bytes()
     }
 }
@@ -0,0 +1,278 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/depth_clouds3d.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::allow_attributes)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+#![allow(clippy::wildcard_imports)]
+
+use ::re_types_core::SerializationResult;
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Configuration for how depth images are backprojected into point clouds in the 3D view.
+///
+/// A [`archetypes::DepthImage`][crate::archetypes::DepthImage] logged under a [`archetypes::Pinhole`][crate::archetypes::Pinhole] is shown as a point cloud,
+/// using the camera intrinsics and [`components::DepthMeter`][crate::components::DepthMeter] of the depth image.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct DepthClouds3D {
+    /// Whether depth images are shown as point clouds.
+    ///
+    /// If disabled, depth images are shown as images on the image plane of their camera instead.
+    /// Defaults to true.
+    pub enabled: Option<SerializedComponentBatch>,
+
+    /// Only every n-th pixel of a depth image, in both directions, is turned into a point.
+    ///
+    /// Higher values speed up rendering of large depth images.
+    /// Defaults to 1, i.e. a point for every pixel.
+    pub subsampling: Option<SerializedComponentBatch>,
+
+    /// Only depths within this range, in meters, are shown as points.
+    ///
+    /// This does not affect the range used for the colormap, see [`archetypes::DepthImage`][crate::archetypes::DepthImage].
+    /// If unset, all valid depths are shown.
+    pub depth_range: Option<SerializedComponentBatch>,
+}
+
+impl DepthClouds3D {
+    /// Returns the [`ComponentDescriptor`] for [`Self::enabled`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::Enabled`].
+    #[inline]
+    pub fn descriptor_enabled() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.DepthClouds3D".into()),
+            component: "DepthClouds3D:enabled".into(),
+            component_type: Some("rerun.blueprint.components.Enabled".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::subsampling`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::DepthCloudSubsampling`].
+    #[inline]
+    pub fn descriptor_subsampling() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.DepthClouds3D".into()),
+            component: "DepthClouds3D:subsampling".into(),
+            component_type: Some("rerun.blueprint.components.DepthCloudSubsampling".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::depth_range`].
+    ///
+    /// The corresponding component is [`crate::components::ValueRange`].
+    #[inline]
+    pub fn descriptor_depth_range() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.DepthClouds3D".into()),
+            component: "DepthClouds3D:depth_range".into(),
+            component_type: Some("rerun.components.ValueRange".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 3usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            DepthClouds3D::descriptor_enabled(),
+            DepthClouds3D::descriptor_subsampling(),
+            DepthClouds3D::descriptor_depth_range(),
+        ]
+    });
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 3usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            DepthClouds3D::descriptor_enabled(),
+            DepthClouds3D::descriptor_subsampling(),
+            DepthClouds3D::descriptor_depth_range(),
+        ]
+    });
+
+impl DepthClouds3D {
+    /// The total number of components in the archetype: 0 required, 0 recommended, 3 optional
+    pub const NUM_COMPONENTS: usize = 3usize;
+}
+
+impl ::re_types_core::Archetype for DepthClouds3D {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.DepthClouds3D".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Depth clouds 3D"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let enabled = arrays_by_descr
+            .get(&Self::descriptor_enabled())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_enabled()));
+        let subsampling = arrays_by_descr
+            .get(&Self::descriptor_subsampling())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_subsampling())
+            });
+        let depth_range = arrays_by_descr
+            .get(&Self::descriptor_depth_range())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_depth_range())
+            });
+        Ok(Self {
+            enabled,
+            subsampling,
+            depth_range,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for DepthClouds3D {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            self.enabled.clone(),
+            self.subsampling.clone(),
+            self.depth_range.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for DepthClouds3D {}
+
+impl DepthClouds3D {
+    /// Create a new `DepthClouds3D`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            enabled: None,
+            subsampling: None,
+            depth_range: None,
+        }
+    }
+
+    /// Update only some specific fields of a `DepthClouds3D`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `DepthClouds3D`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            enabled: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::Enabled::arrow_empty(),
+                Self::descriptor_enabled(),
+            )),
+            subsampling: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::DepthCloudSubsampling::arrow_empty(),
+                Self::descriptor_subsampling(),
+            )),
+            depth_range: Some(SerializedComponentBatch::new(
+                crate::components::ValueRange::arrow_empty(),
+                Self::descriptor_depth_range(),
+            )),
+        }
+    }
+
+    /// Whether depth images are shown as point clouds.
+    ///
+    /// If disabled, depth images are shown as images on the image plane of their camera instead.
+    /// Defaults to true.
+    #[inline]
+    pub fn with_enabled(
+        mut self,
+        enabled: impl Into<crate::blueprint::components::Enabled>,
+    ) -> Self {
+        self.enabled = try_serialize_field(Self::descriptor_enabled(), [enabled]);
+        self
+    }
+
+    /// Only every n-th pixel of a depth image, in both directions, is turned into a point.
+    ///
+    /// Higher values speed up rendering of large depth images.
+    /// Defaults to 1, i.e. a point for every pixel.
+    #[inline]
+    pub fn with_subsampling(
+        mut self,
+        subsampling: impl Into<crate::blueprint::components::DepthCloudSubsampling>,
+    ) -> Self {
+        self.subsampling = try_serialize_field(Self::descriptor_subsampling(), [subsampling]);
+        self
+    }
+
+    /// Only depths within this range, in meters, are shown as points.
+    ///
+    /// This does not affect the range used for the colormap, see [`archetypes::DepthImage`][crate::archetypes::DepthImage].
+    /// If unset, all valid depths are shown.
+    #[inline]
+    pub fn with_depth_range(
+        mut self,
+        depth_range: impl Into<crate::components::ValueRange>,
+    ) -> Self {
+        self.depth_range = try_serialize_field(Self::descriptor_depth_range(), [depth_range]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for DepthClouds3D {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.enabled.heap_size_bytes()
+            + self.subsampling.heap_size_bytes()
+            + self.depth_range.heap_size_bytes()
+    }
+}
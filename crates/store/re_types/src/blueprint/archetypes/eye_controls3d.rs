@@ -66,6 +66,11 @@ pub struct EyeControls3D {
     ///
     /// Defaults to zero, meaning no spinning.
     pub spin_speed: Option<SerializedComponentBatch>,
+
+    /// The projection of the eye.
+    ///
+    /// Defaults to perspective.
+    pub projection: Option<SerializedComponentBatch>,
 }
 
 impl EyeControls3D {
@@ -152,6 +157,18 @@ impl EyeControls3D {
             component_type: Some("rerun.blueprint.components.AngularSpeed".into()),
         }
     }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::projection`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::Eye3DProjection`].
+    #[inline]
+    pub fn descriptor_projection() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.EyeControls3D".into()),
+            component: "EyeControls3D:projection".into(),
+            component_type: Some("rerun.blueprint.components.Eye3DProjection".into()),
+        }
+    }
 }
 
 static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
@@ -160,7 +177,7 @@ static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
 static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
     std::sync::LazyLock::new(|| []);
 
-static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 7usize]> =
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 8usize]> =
     std::sync::LazyLock::new(|| {
         [
             EyeControls3D::descriptor_kind(),
@@ -170,10 +187,11 @@ static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 7usize]> =
             EyeControls3D::descriptor_speed(),
             EyeControls3D::descriptor_tracking_entity(),
             EyeControls3D::descriptor_spin_speed(),
+            EyeControls3D::descriptor_projection(),
         ]
     });
 
-static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 7usize]> =
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 8usize]> =
     std::sync::LazyLock::new(|| {
         [
             EyeControls3D::descriptor_kind(),
@@ -183,12 +201,13 @@ static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 7usize]> =
             EyeControls3D::descriptor_speed(),
             EyeControls3D::descriptor_tracking_entity(),
             EyeControls3D::descriptor_spin_speed(),
+            EyeControls3D::descriptor_projection(),
         ]
     });
 
 impl EyeControls3D {
-    /// The total number of components in the archetype: 0 required, 0 recommended, 7 optional
-    pub const NUM_COMPONENTS: usize = 7usize;
+    /// The total number of components in the archetype: 0 required, 0 recommended, 8 optional
+    pub const NUM_COMPONENTS: usize = 8usize;
 }
 
 impl ::re_types_core::Archetype for EyeControls3D {
@@ -256,6 +275,11 @@ impl ::re_types_core::Archetype for EyeControls3D {
             .map(|array| {
                 SerializedComponentBatch::new(array.clone(), Self::descriptor_spin_speed())
             });
+        let projection = arrays_by_descr
+            .get(&Self::descriptor_projection())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_projection())
+            });
         Ok(Self {
             kind,
             position,
@@ -264,6 +288,7 @@ impl ::re_types_core::Archetype for EyeControls3D {
             speed,
             tracking_entity,
             spin_speed,
+            projection,
         })
     }
 }
@@ -280,6 +305,7 @@ impl ::re_types_core::AsComponents for EyeControls3D {
             self.speed.clone(),
             self.tracking_entity.clone(),
             self.spin_speed.clone(),
+            self.projection.clone(),
         ]
         .into_iter()
         .flatten()
@@ -301,6 +327,7 @@ impl EyeControls3D {
             speed: None,
             tracking_entity: None,
             spin_speed: None,
+            projection: None,
         }
     }
 
@@ -343,6 +370,10 @@ impl EyeControls3D {
                 crate::blueprint::components::AngularSpeed::arrow_empty(),
                 Self::descriptor_spin_speed(),
             )),
+            projection: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::Eye3DProjection::arrow_empty(),
+                Self::descriptor_projection(),
+            )),
         }
     }
 
@@ -422,6 +453,18 @@ impl EyeControls3D {
         self.spin_speed = try_serialize_field(Self::descriptor_spin_speed(), [spin_speed]);
         self
     }
+
+    /// The projection of the eye.
+    ///
+    /// Defaults to perspective.
+    #[inline]
+    pub fn with_projection(
+        mut self,
+        projection: impl Into<crate::blueprint::components::Eye3DProjection>,
+    ) -> Self {
+        self.projection = try_serialize_field(Self::descriptor_projection(), [projection]);
+        self
+    }
 }
 
 impl ::re_byte_size::SizeBytes for EyeControls3D {
@@ -434,5 +477,6 @@ impl ::re_byte_size::SizeBytes for EyeControls3D {
             + self.speed.heap_size_bytes()
             + self.tracking_entity.heap_size_bytes()
             + self.spin_speed.heap_size_bytes()
+            + self.projection.heap_size_bytes()
     }
 }
@@ -0,0 +1,224 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/fft_window.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::allow_attributes)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+#![allow(clippy::wildcard_imports)]
+
+use ::re_types_core::SerializationResult;
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Configures the windows of the rolling FFT of a spectrogram.
+///
+/// Each window is multiplied with a Hann window before its spectrum is computed.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct FftWindow {
+    /// The number of samples in each window.
+    ///
+    /// Rounded up to the next power of two.
+    /// Larger windows resolve frequencies more finely, but resolve events in time more coarsely.
+    /// Defaults to 256.
+    pub size: Option<SerializedComponentBatch>,
+
+    /// How much consecutive windows overlap, as a fraction of the window size.
+    ///
+    /// Clamped to `[0, 0.95]`.
+    /// Defaults to 0.5.
+    pub overlap: Option<SerializedComponentBatch>,
+}
+
+impl FftWindow {
+    /// Returns the [`ComponentDescriptor`] for [`Self::size`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::FftWindowSize`].
+    #[inline]
+    pub fn descriptor_size() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.FftWindow".into()),
+            component: "FftWindow:size".into(),
+            component_type: Some("rerun.blueprint.components.FftWindowSize".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::overlap`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::FftWindowOverlap`].
+    #[inline]
+    pub fn descriptor_overlap() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.FftWindow".into()),
+            component: "FftWindow:overlap".into(),
+            component_type: Some("rerun.blueprint.components.FftWindowOverlap".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 2usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            FftWindow::descriptor_size(),
+            FftWindow::descriptor_overlap(),
+        ]
+    });
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 2usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            FftWindow::descriptor_size(),
+            FftWindow::descriptor_overlap(),
+        ]
+    });
+
+impl FftWindow {
+    /// The total number of components in the archetype: 0 required, 0 recommended, 2 optional
+    pub const NUM_COMPONENTS: usize = 2usize;
+}
+
+impl ::re_types_core::Archetype for FftWindow {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.FftWindow".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Fft window"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let size = arrays_by_descr
+            .get(&Self::descriptor_size())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_size()));
+        let overlap = arrays_by_descr
+            .get(&Self::descriptor_overlap())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_overlap()));
+        Ok(Self { size, overlap })
+    }
+}
+
+impl ::re_types_core::AsComponents for FftWindow {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [self.size.clone(), self.overlap.clone()]
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for FftWindow {}
+
+impl FftWindow {
+    /// Create a new `FftWindow`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            size: None,
+            overlap: None,
+        }
+    }
+
+    /// Update only some specific fields of a `FftWindow`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `FftWindow`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            size: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::FftWindowSize::arrow_empty(),
+                Self::descriptor_size(),
+            )),
+            overlap: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::FftWindowOverlap::arrow_empty(),
+                Self::descriptor_overlap(),
+            )),
+        }
+    }
+
+    /// The number of samples in each window.
+    ///
+    /// Rounded up to the next power of two.
+    /// Larger windows resolve frequencies more finely, but resolve events in time more coarsely.
+    /// Defaults to 256.
+    #[inline]
+    pub fn with_size(
+        mut self,
+        size: impl Into<crate::blueprint::components::FftWindowSize>,
+    ) -> Self {
+        self.size = try_serialize_field(Self::descriptor_size(), [size]);
+        self
+    }
+
+    /// How much consecutive windows overlap, as a fraction of the window size.
+    ///
+    /// Clamped to `[0, 0.95]`.
+    /// Defaults to 0.5.
+    #[inline]
+    pub fn with_overlap(
+        mut self,
+        overlap: impl Into<crate::blueprint::components::FftWindowOverlap>,
+    ) -> Self {
+        self.overlap = try_serialize_field(Self::descriptor_overlap(), [overlap]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for FftWindow {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.size.heap_size_bytes() + self.overlap.heap_size_bytes()
+    }
+}
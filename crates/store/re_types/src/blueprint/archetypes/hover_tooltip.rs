@@ -0,0 +1,179 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/hover_tooltip.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::allow_attributes)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+#![allow(clippy::wildcard_imports)]
+
+use ::re_types_core::SerializationResult;
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Which components of an entity are shown when hovering it in a 2D or 3D view.
+///
+/// Log this as an override of the entity in a view, e.g. to only show `velocity` and `class`
+/// instead of the default summary of all components.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct HoverTooltip {
+    /// The components shown in the tooltip, in this order.
+    ///
+    /// Either the full component identifiers or just the part after the archetype prefix,
+    /// e.g. `velocity`. Components the entity doesn't have are skipped.
+    /// If unset, the tooltip shows a summary of all components.
+    pub shown_components: Option<SerializedComponentBatch>,
+}
+
+impl HoverTooltip {
+    /// Returns the [`ComponentDescriptor`] for [`Self::shown_components`].
+    ///
+    /// The corresponding component is [`crate::components::Name`].
+    #[inline]
+    pub fn descriptor_shown_components() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.HoverTooltip".into()),
+            component: "HoverTooltip:shown_components".into(),
+            component_type: Some("rerun.components.Name".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
+    std::sync::LazyLock::new(|| [HoverTooltip::descriptor_shown_components()]);
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
+    std::sync::LazyLock::new(|| [HoverTooltip::descriptor_shown_components()]);
+
+impl HoverTooltip {
+    /// The total number of components in the archetype: 0 required, 0 recommended, 1 optional
+    pub const NUM_COMPONENTS: usize = 1usize;
+}
+
+impl ::re_types_core::Archetype for HoverTooltip {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.HoverTooltip".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Hover tooltip"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let shown_components = arrays_by_descr
+            .get(&Self::descriptor_shown_components())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_shown_components())
+            });
+        Ok(Self { shown_components })
+    }
+}
+
+impl ::re_types_core::AsComponents for HoverTooltip {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [self.shown_components.clone()]
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for HoverTooltip {}
+
+impl HoverTooltip {
+    /// Create a new `HoverTooltip`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            shown_components: None,
+        }
+    }
+
+    /// Update only some specific fields of a `HoverTooltip`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `HoverTooltip`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            shown_components: Some(SerializedComponentBatch::new(
+                crate::components::Name::arrow_empty(),
+                Self::descriptor_shown_components(),
+            )),
+        }
+    }
+
+    /// The components shown in the tooltip, in this order.
+    ///
+    /// Either the full component identifiers or just the part after the archetype prefix,
+    /// e.g. `velocity`. Components the entity doesn't have are skipped.
+    /// If unset, the tooltip shows a summary of all components.
+    #[inline]
+    pub fn with_shown_components(
+        mut self,
+        shown_components: impl IntoIterator<Item = impl Into<crate::components::Name>>,
+    ) -> Self {
+        self.shown_components =
+            try_serialize_field(Self::descriptor_shown_components(), shown_components);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for HoverTooltip {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.shown_components.heap_size_bytes()
+    }
+}
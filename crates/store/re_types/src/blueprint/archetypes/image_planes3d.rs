@@ -0,0 +1,325 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/image_planes3d.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::allow_attributes)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+#![allow(clippy::wildcard_imports)]
+
+use ::re_types_core::SerializationResult;
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Configuration for the image planes of pinhole cameras in the 3D view.
+///
+/// 2D content logged under a [`archetypes::Pinhole`][crate::archetypes::Pinhole], e.g. images and 2D annotations,
+/// is shown on the image plane at the end of the camera frustum.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct ImagePlanes3D {
+    /// Distance of the image plane from the camera, used for all cameras in the view.
+    ///
+    /// If unset, each camera uses its own [`components::ImagePlaneDistance`][crate::components::ImagePlaneDistance].
+    pub image_plane_distance: Option<SerializedComponentBatch>,
+
+    /// Opacity of the images shown on the image planes.
+    ///
+    /// This is multiplied with the opacity of each image.
+    /// Defaults to 1.0.
+    pub opacity: Option<SerializedComponentBatch>,
+
+    /// Whether the images are visible when looking at the image plane from behind.
+    ///
+    /// Defaults to true.
+    pub show_back_faces: Option<SerializedComponentBatch>,
+
+    /// Whether 2D annotations, i.e. points, boxes, line strips and arrows, are shown on the image planes.
+    ///
+    /// Defaults to true.
+    pub project_annotations: Option<SerializedComponentBatch>,
+}
+
+impl ImagePlanes3D {
+    /// Returns the [`ComponentDescriptor`] for [`Self::image_plane_distance`].
+    ///
+    /// The corresponding component is [`crate::components::ImagePlaneDistance`].
+    #[inline]
+    pub fn descriptor_image_plane_distance() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.ImagePlanes3D".into()),
+            component: "ImagePlanes3D:image_plane_distance".into(),
+            component_type: Some("rerun.components.ImagePlaneDistance".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::opacity`].
+    ///
+    /// The corresponding component is [`crate::components::Opacity`].
+    #[inline]
+    pub fn descriptor_opacity() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.ImagePlanes3D".into()),
+            component: "ImagePlanes3D:opacity".into(),
+            component_type: Some("rerun.components.Opacity".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::show_back_faces`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::Enabled`].
+    #[inline]
+    pub fn descriptor_show_back_faces() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.ImagePlanes3D".into()),
+            component: "ImagePlanes3D:show_back_faces".into(),
+            component_type: Some("rerun.blueprint.components.Enabled".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::project_annotations`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::Enabled`].
+    #[inline]
+    pub fn descriptor_project_annotations() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.ImagePlanes3D".into()),
+            component: "ImagePlanes3D:project_annotations".into(),
+            component_type: Some("rerun.blueprint.components.Enabled".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 4usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            ImagePlanes3D::descriptor_image_plane_distance(),
+            ImagePlanes3D::descriptor_opacity(),
+            ImagePlanes3D::descriptor_show_back_faces(),
+            ImagePlanes3D::descriptor_project_annotations(),
+        ]
+    });
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 4usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            ImagePlanes3D::descriptor_image_plane_distance(),
+            ImagePlanes3D::descriptor_opacity(),
+            ImagePlanes3D::descriptor_show_back_faces(),
+            ImagePlanes3D::descriptor_project_annotations(),
+        ]
+    });
+
+impl ImagePlanes3D {
+    /// The total number of components in the archetype: 0 required, 0 recommended, 4 optional
+    pub const NUM_COMPONENTS: usize = 4usize;
+}
+
+impl ::re_types_core::Archetype for ImagePlanes3D {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.ImagePlanes3D".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Image planes 3D"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let image_plane_distance = arrays_by_descr
+            .get(&Self::descriptor_image_plane_distance())
+            .map(|array| {
+                SerializedComponentBatch::new(
+                    array.clone(),
+                    Self::descriptor_image_plane_distance(),
+                )
+            });
+        let opacity = arrays_by_descr
+            .get(&Self::descriptor_opacity())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_opacity()));
+        let show_back_faces = arrays_by_descr
+            .get(&Self::descriptor_show_back_faces())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_show_back_faces())
+            });
+        let project_annotations = arrays_by_descr
+            .get(&Self::descriptor_project_annotations())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_project_annotations())
+            });
+        Ok(Self {
+            image_plane_distance,
+            opacity,
+            show_back_faces,
+            project_annotations,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for ImagePlanes3D {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            self.image_plane_distance.clone(),
+            self.opacity.clone(),
+            self.show_back_faces.clone(),
+            self.project_annotations.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for ImagePlanes3D {}
+
+impl ImagePlanes3D {
+    /// Create a new `ImagePlanes3D`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            image_plane_distance: None,
+            opacity: None,
+            show_back_faces: None,
+            project_annotations: None,
+        }
+    }
+
+    /// Update only some specific fields of a `ImagePlanes3D`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `ImagePlanes3D`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            image_plane_distance: Some(SerializedComponentBatch::new(
+                crate::components::ImagePlaneDistance::arrow_empty(),
+                Self::descriptor_image_plane_distance(),
+            )),
+            opacity: Some(SerializedComponentBatch::new(
+                crate::components::Opacity::arrow_empty(),
+                Self::descriptor_opacity(),
+            )),
+            show_back_faces: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::Enabled::arrow_empty(),
+                Self::descriptor_show_back_faces(),
+            )),
+            project_annotations: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::Enabled::arrow_empty(),
+                Self::descriptor_project_annotations(),
+            )),
+        }
+    }
+
+    /// Distance of the image plane from the camera, used for all cameras in the view.
+    ///
+    /// If unset, each camera uses its own [`components::ImagePlaneDistance`][crate::components::ImagePlaneDistance].
+    #[inline]
+    pub fn with_image_plane_distance(
+        mut self,
+        image_plane_distance: impl Into<crate::components::ImagePlaneDistance>,
+    ) -> Self {
+        self.image_plane_distance = try_serialize_field(
+            Self::descriptor_image_plane_distance(),
+            [image_plane_distance],
+        );
+        self
+    }
+
+    /// Opacity of the images shown on the image planes.
+    ///
+    /// This is multiplied with the opacity of each image.
+    /// Defaults to 1.0.
+    #[inline]
+    pub fn with_opacity(mut self, opacity: impl Into<crate::components::Opacity>) -> Self {
+        self.opacity = try_serialize_field(Self::descriptor_opacity(), [opacity]);
+        self
+    }
+
+    /// Whether the images are visible when looking at the image plane from behind.
+    ///
+    /// Defaults to true.
+    #[inline]
+    pub fn with_show_back_faces(
+        mut self,
+        show_back_faces: impl Into<crate::blueprint::components::Enabled>,
+    ) -> Self {
+        self.show_back_faces =
+            try_serialize_field(Self::descriptor_show_back_faces(), [show_back_faces]);
+        self
+    }
+
+    /// Whether 2D annotations, i.e. points, boxes, line strips and arrows, are shown on the image planes.
+    ///
+    /// Defaults to true.
+    #[inline]
+    pub fn with_project_annotations(
+        mut self,
+        project_annotations: impl Into<crate::blueprint::components::Enabled>,
+    ) -> Self {
+        self.project_annotations = try_serialize_field(
+            Self::descriptor_project_annotations(),
+            [project_annotations],
+        );
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for ImagePlanes3D {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.image_plane_distance.heap_size_bytes()
+            + self.opacity.heap_size_bytes()
+            + self.show_back_faces.heap_size_bytes()
+            + self.project_annotations.heap_size_bytes()
+    }
+}
@@ -0,0 +1,225 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/instance_filter.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::allow_attributes)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+#![allow(clippy::wildcard_imports)]
+
+use ::re_types_core::SerializationResult;
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Hides instances based on the value of a scalar component logged alongside them.
+///
+/// This is useful to threshold the output of a detector, e.g. by a `confidence` component,
+/// without logging the data again.
+/// The value of each instance is compared against the threshold at the current time.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct InstanceFilter {
+    /// The component whose values are compared against the threshold, e.g. `confidence`.
+    ///
+    /// Either the full component identifier or just the part after the archetype prefix.
+    /// Entities that don't have this component are not filtered.
+    /// If unset, no instances are filtered.
+    pub component: Option<SerializedComponentBatch>,
+
+    /// Instances with a value below this threshold are hidden.
+    ///
+    /// Defaults to 0.5.
+    pub threshold: Option<SerializedComponentBatch>,
+}
+
+impl InstanceFilter {
+    /// Returns the [`ComponentDescriptor`] for [`Self::component`].
+    ///
+    /// The corresponding component is [`crate::components::Name`].
+    #[inline]
+    pub fn descriptor_component() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.InstanceFilter".into()),
+            component: "InstanceFilter:component".into(),
+            component_type: Some("rerun.components.Name".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::threshold`].
+    ///
+    /// The corresponding component is [`crate::components::Scalar`].
+    #[inline]
+    pub fn descriptor_threshold() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.InstanceFilter".into()),
+            component: "InstanceFilter:threshold".into(),
+            component_type: Some("rerun.components.Scalar".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 2usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            InstanceFilter::descriptor_component(),
+            InstanceFilter::descriptor_threshold(),
+        ]
+    });
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 2usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            InstanceFilter::descriptor_component(),
+            InstanceFilter::descriptor_threshold(),
+        ]
+    });
+
+impl InstanceFilter {
+    /// The total number of components in the archetype: 0 required, 0 recommended, 2 optional
+    pub const NUM_COMPONENTS: usize = 2usize;
+}
+
+impl ::re_types_core::Archetype for InstanceFilter {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.InstanceFilter".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Instance filter"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let component = arrays_by_descr
+            .get(&Self::descriptor_component())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_component())
+            });
+        let threshold = arrays_by_descr
+            .get(&Self::descriptor_threshold())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_threshold())
+            });
+        Ok(Self {
+            component,
+            threshold,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for InstanceFilter {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [self.component.clone(), self.threshold.clone()]
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for InstanceFilter {}
+
+impl InstanceFilter {
+    /// Create a new `InstanceFilter`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            component: None,
+            threshold: None,
+        }
+    }
+
+    /// Update only some specific fields of a `InstanceFilter`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `InstanceFilter`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            component: Some(SerializedComponentBatch::new(
+                crate::components::Name::arrow_empty(),
+                Self::descriptor_component(),
+            )),
+            threshold: Some(SerializedComponentBatch::new(
+                crate::components::Scalar::arrow_empty(),
+                Self::descriptor_threshold(),
+            )),
+        }
+    }
+
+    /// The component whose values are compared against the threshold, e.g. `confidence`.
+    ///
+    /// Either the full component identifier or just the part after the archetype prefix.
+    /// Entities that don't have this component are not filtered.
+    /// If unset, no instances are filtered.
+    #[inline]
+    pub fn with_component(mut self, component: impl Into<crate::components::Name>) -> Self {
+        self.component = try_serialize_field(Self::descriptor_component(), [component]);
+        self
+    }
+
+    /// Instances with a value below this threshold are hidden.
+    ///
+    /// Defaults to 0.5.
+    #[inline]
+    pub fn with_threshold(mut self, threshold: impl Into<crate::components::Scalar>) -> Self {
+        self.threshold = try_serialize_field(Self::descriptor_threshold(), [threshold]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for InstanceFilter {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.component.heap_size_bytes() + self.threshold.heap_size_bytes()
+    }
+}
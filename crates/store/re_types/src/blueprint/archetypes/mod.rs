@@ -1,23 +1,37 @@
 // DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
 
 mod background;
+mod bar_chart_layout;
+mod class_id_remap;
+mod color_by_component;
 mod container_blueprint;
+mod coordinate_conversion;
+mod crop_box;
 mod dataframe_query;
+mod depth_clouds3d;
 mod entity_behavior;
 mod eye_controls3d;
+mod fft_window;
 mod force_center;
 mod force_collision_radius;
 mod force_link;
 mod force_many_body;
 mod force_position;
 mod graph_background;
+mod hover_tooltip;
+mod image_planes3d;
+mod instance_filter;
 mod line_grid3d;
 mod map_background;
 mod map_zoom;
 mod near_clip_plane;
+mod onion_skin;
+mod outlines;
 mod panel_blueprint;
 mod plot_background;
 mod plot_legend;
+mod point_shading;
+mod rigid_alignment;
 mod scalar_axis;
 mod spatial_information;
 mod tensor_scalar_mapping;
@@ -25,31 +39,47 @@ mod tensor_slice_selection;
 mod tensor_view_fit;
 mod time_axis;
 mod time_panel_blueprint;
+mod trajectory_trails;
 mod view_blueprint;
 mod view_contents;
 mod viewport_blueprint;
+mod visibility_rules;
 mod visible_time_ranges;
 mod visual_bounds2d;
 mod visualizer_overrides;
 
 pub use self::background::Background;
+pub use self::bar_chart_layout::BarChartLayout;
+pub use self::class_id_remap::ClassIdRemap;
+pub use self::color_by_component::ColorByComponent;
 pub use self::container_blueprint::ContainerBlueprint;
+pub use self::coordinate_conversion::CoordinateConversion;
+pub use self::crop_box::CropBox;
 pub use self::dataframe_query::DataframeQuery;
+pub use self::depth_clouds3d::DepthClouds3D;
 pub use self::entity_behavior::EntityBehavior;
 pub use self::eye_controls3d::EyeControls3D;
+pub use self::fft_window::FftWindow;
 pub use self::force_center::ForceCenter;
 pub use self::force_collision_radius::ForceCollisionRadius;
 pub use self::force_link::ForceLink;
 pub use self::force_many_body::ForceManyBody;
 pub use self::force_position::ForcePosition;
 pub use self::graph_background::GraphBackground;
+pub use self::hover_tooltip::HoverTooltip;
+pub use self::image_planes3d::ImagePlanes3D;
+pub use self::instance_filter::InstanceFilter;
 pub use self::line_grid3d::LineGrid3D;
 pub use self::map_background::MapBackground;
 pub use self::map_zoom::MapZoom;
 pub use self::near_clip_plane::NearClipPlane;
+pub use self::onion_skin::OnionSkin;
+pub use self::outlines::Outlines;
 pub use self::panel_blueprint::PanelBlueprint;
 pub use self::plot_background::PlotBackground;
 pub use self::plot_legend::PlotLegend;
+pub use self::point_shading::PointShading;
+pub use self::rigid_alignment::RigidAlignment;
 pub use self::scalar_axis::ScalarAxis;
 pub use self::spatial_information::SpatialInformation;
 pub use self::tensor_scalar_mapping::TensorScalarMapping;
@@ -57,9 +87,11 @@ pub use self::tensor_slice_selection::TensorSliceSelection;
 pub use self::tensor_view_fit::TensorViewFit;
 pub use self::time_axis::TimeAxis;
 pub use self::time_panel_blueprint::TimePanelBlueprint;
+pub use self::trajectory_trails::TrajectoryTrails;
 pub use self::view_blueprint::ViewBlueprint;
 pub use self::view_contents::ViewContents;
 pub use self::viewport_blueprint::ViewportBlueprint;
+pub use self::visibility_rules::VisibilityRules;
 pub use self::visible_time_ranges::VisibleTimeRanges;
 pub use self::visual_bounds2d::VisualBounds2D;
 pub use self::visualizer_overrides::VisualizerOverrides;
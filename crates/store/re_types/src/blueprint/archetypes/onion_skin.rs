@@ -0,0 +1,221 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/onion_skin.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::allow_attributes)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+#![allow(clippy::wildcard_imports)]
+
+use ::re_types_core::SerializationResult;
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Configuration for the onion skin of a spatial view.
+///
+/// The onion skin shows the earlier states of entities as translucent ghosts,
+/// e.g. to review the jitter of a pose estimate or how a robot moved.
+/// It is supported for points, line strips, arrows and 2D boxes.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct OnionSkin {
+    /// How many earlier states of each entity are shown.
+    ///
+    /// Every time an entity's data or its transform changed counts as a step.
+    /// Defaults to 0, i.e. no onion skin.
+    pub steps: Option<SerializedComponentBatch>,
+
+    /// Opacity of the most recent earlier state.
+    ///
+    /// Older states fade out further.
+    /// Defaults to 0.5.
+    pub opacity: Option<SerializedComponentBatch>,
+}
+
+impl OnionSkin {
+    /// Returns the [`ComponentDescriptor`] for [`Self::steps`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::OnionSkinSteps`].
+    #[inline]
+    pub fn descriptor_steps() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.OnionSkin".into()),
+            component: "OnionSkin:steps".into(),
+            component_type: Some("rerun.blueprint.components.OnionSkinSteps".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::opacity`].
+    ///
+    /// The corresponding component is [`crate::components::Opacity`].
+    #[inline]
+    pub fn descriptor_opacity() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.OnionSkin".into()),
+            component: "OnionSkin:opacity".into(),
+            component_type: Some("rerun.components.Opacity".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 2usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            OnionSkin::descriptor_steps(),
+            OnionSkin::descriptor_opacity(),
+        ]
+    });
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 2usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            OnionSkin::descriptor_steps(),
+            OnionSkin::descriptor_opacity(),
+        ]
+    });
+
+impl OnionSkin {
+    /// The total number of components in the archetype: 0 required, 0 recommended, 2 optional
+    pub const NUM_COMPONENTS: usize = 2usize;
+}
+
+impl ::re_types_core::Archetype for OnionSkin {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.OnionSkin".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Onion skin"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let steps = arrays_by_descr
+            .get(&Self::descriptor_steps())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_steps()));
+        let opacity = arrays_by_descr
+            .get(&Self::descriptor_opacity())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_opacity()));
+        Ok(Self { steps, opacity })
+    }
+}
+
+impl ::re_types_core::AsComponents for OnionSkin {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [self.steps.clone(), self.opacity.clone()]
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for OnionSkin {}
+
+impl OnionSkin {
+    /// Create a new `OnionSkin`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            steps: None,
+            opacity: None,
+        }
+    }
+
+    /// Update only some specific fields of a `OnionSkin`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `OnionSkin`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            steps: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::OnionSkinSteps::arrow_empty(),
+                Self::descriptor_steps(),
+            )),
+            opacity: Some(SerializedComponentBatch::new(
+                crate::components::Opacity::arrow_empty(),
+                Self::descriptor_opacity(),
+            )),
+        }
+    }
+
+    /// How many earlier states of each entity are shown.
+    ///
+    /// Every time an entity's data or its transform changed counts as a step.
+    /// Defaults to 0, i.e. no onion skin.
+    #[inline]
+    pub fn with_steps(
+        mut self,
+        steps: impl Into<crate::blueprint::components::OnionSkinSteps>,
+    ) -> Self {
+        self.steps = try_serialize_field(Self::descriptor_steps(), [steps]);
+        self
+    }
+
+    /// Opacity of the most recent earlier state.
+    ///
+    /// Older states fade out further.
+    /// Defaults to 0.5.
+    #[inline]
+    pub fn with_opacity(mut self, opacity: impl Into<crate::components::Opacity>) -> Self {
+        self.opacity = try_serialize_field(Self::descriptor_opacity(), [opacity]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for OnionSkin {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.steps.heap_size_bytes() + self.opacity.heap_size_bytes()
+    }
+}
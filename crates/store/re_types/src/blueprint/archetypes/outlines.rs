@@ -0,0 +1,362 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/outlines.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::allow_attributes)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+#![allow(clippy::wildcard_imports)]
+
+use ::re_types_core::SerializationResult;
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Configuration for the outlines of hovered, selected and highlighted entities in a spatial view.
+///
+/// Highlighted entities are always outlined, e.g. to mark the ground truth in a scene.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct Outlines {
+    /// Color of the outline of hovered objects.
+    ///
+    /// Defaults to the hover color of the viewer.
+    pub hover_color: Option<SerializedComponentBatch>,
+
+    /// Color of the outline of selected objects.
+    ///
+    /// Defaults to the selection color of the viewer.
+    pub selection_color: Option<SerializedComponentBatch>,
+
+    /// How thick the outlines are in ui units.
+    ///
+    /// Defaults to the thickness of hover & selection strokes elsewhere in the viewer.
+    pub stroke_width: Option<SerializedComponentBatch>,
+
+    /// Query expressions of the entities that are always outlined, e.g. `+ /world/ground_truth/**`.
+    ///
+    /// Uses the same syntax as the contents of the view.
+    /// While any entity is highlighted, hovered objects are outlined with the selection color.
+    pub highlighted: Option<SerializedComponentBatch>,
+
+    /// Color of the outline of highlighted entities.
+    ///
+    /// Defaults to green.
+    pub highlight_color: Option<SerializedComponentBatch>,
+}
+
+impl Outlines {
+    /// Returns the [`ComponentDescriptor`] for [`Self::hover_color`].
+    ///
+    /// The corresponding component is [`crate::components::Color`].
+    #[inline]
+    pub fn descriptor_hover_color() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.Outlines".into()),
+            component: "Outlines:hover_color".into(),
+            component_type: Some("rerun.components.Color".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::selection_color`].
+    ///
+    /// The corresponding component is [`crate::components::Color`].
+    #[inline]
+    pub fn descriptor_selection_color() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.Outlines".into()),
+            component: "Outlines:selection_color".into(),
+            component_type: Some("rerun.components.Color".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::stroke_width`].
+    ///
+    /// The corresponding component is [`crate::components::StrokeWidth`].
+    #[inline]
+    pub fn descriptor_stroke_width() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.Outlines".into()),
+            component: "Outlines:stroke_width".into(),
+            component_type: Some("rerun.components.StrokeWidth".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::highlighted`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::QueryExpression`].
+    #[inline]
+    pub fn descriptor_highlighted() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.Outlines".into()),
+            component: "Outlines:highlighted".into(),
+            component_type: Some("rerun.blueprint.components.QueryExpression".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::highlight_color`].
+    ///
+    /// The corresponding component is [`crate::components::Color`].
+    #[inline]
+    pub fn descriptor_highlight_color() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.Outlines".into()),
+            component: "Outlines:highlight_color".into(),
+            component_type: Some("rerun.components.Color".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 5usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            Outlines::descriptor_hover_color(),
+            Outlines::descriptor_selection_color(),
+            Outlines::descriptor_stroke_width(),
+            Outlines::descriptor_highlighted(),
+            Outlines::descriptor_highlight_color(),
+        ]
+    });
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 5usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            Outlines::descriptor_hover_color(),
+            Outlines::descriptor_selection_color(),
+            Outlines::descriptor_stroke_width(),
+            Outlines::descriptor_highlighted(),
+            Outlines::descriptor_highlight_color(),
+        ]
+    });
+
+impl Outlines {
+    /// The total number of components in the archetype: 0 required, 0 recommended, 5 optional
+    pub const NUM_COMPONENTS: usize = 5usize;
+}
+
+impl ::re_types_core::Archetype for Outlines {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.Outlines".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Outlines"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let hover_color = arrays_by_descr
+            .get(&Self::descriptor_hover_color())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_hover_color())
+            });
+        let selection_color = arrays_by_descr
+            .get(&Self::descriptor_selection_color())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_selection_color())
+            });
+        let stroke_width = arrays_by_descr
+            .get(&Self::descriptor_stroke_width())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_stroke_width())
+            });
+        let highlighted = arrays_by_descr
+            .get(&Self::descriptor_highlighted())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_highlighted())
+            });
+        let highlight_color = arrays_by_descr
+            .get(&Self::descriptor_highlight_color())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_highlight_color())
+            });
+        Ok(Self {
+            hover_color,
+            selection_color,
+            stroke_width,
+            highlighted,
+            highlight_color,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for Outlines {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            self.hover_color.clone(),
+            self.selection_color.clone(),
+            self.stroke_width.clone(),
+            self.highlighted.clone(),
+            self.highlight_color.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for Outlines {}
+
+impl Outlines {
+    /// Create a new `Outlines`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            hover_color: None,
+            selection_color: None,
+            stroke_width: None,
+            highlighted: None,
+            highlight_color: None,
+        }
+    }
+
+    /// Update only some specific fields of a `Outlines`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `Outlines`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            hover_color: Some(SerializedComponentBatch::new(
+                crate::components::Color::arrow_empty(),
+                Self::descriptor_hover_color(),
+            )),
+            selection_color: Some(SerializedComponentBatch::new(
+                crate::components::Color::arrow_empty(),
+                Self::descriptor_selection_color(),
+            )),
+            stroke_width: Some(SerializedComponentBatch::new(
+                crate::components::StrokeWidth::arrow_empty(),
+                Self::descriptor_stroke_width(),
+            )),
+            highlighted: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::QueryExpression::arrow_empty(),
+                Self::descriptor_highlighted(),
+            )),
+            highlight_color: Some(SerializedComponentBatch::new(
+                crate::components::Color::arrow_empty(),
+                Self::descriptor_highlight_color(),
+            )),
+        }
+    }
+
+    /// Color of the outline of hovered objects.
+    ///
+    /// Defaults to the hover color of the viewer.
+    #[inline]
+    pub fn with_hover_color(mut self, hover_color: impl Into<crate::components::Color>) -> Self {
+        self.hover_color = try_serialize_field(Self::descriptor_hover_color(), [hover_color]);
+        self
+    }
+
+    /// Color of the outline of selected objects.
+    ///
+    /// Defaults to the selection color of the viewer.
+    #[inline]
+    pub fn with_selection_color(
+        mut self,
+        selection_color: impl Into<crate::components::Color>,
+    ) -> Self {
+        self.selection_color =
+            try_serialize_field(Self::descriptor_selection_color(), [selection_color]);
+        self
+    }
+
+    /// How thick the outlines are in ui units.
+    ///
+    /// Defaults to the thickness of hover & selection strokes elsewhere in the viewer.
+    #[inline]
+    pub fn with_stroke_width(
+        mut self,
+        stroke_width: impl Into<crate::components::StrokeWidth>,
+    ) -> Self {
+        self.stroke_width = try_serialize_field(Self::descriptor_stroke_width(), [stroke_width]);
+        self
+    }
+
+    /// Query expressions of the entities that are always outlined, e.g. `+ /world/ground_truth/**`.
+    ///
+    /// Uses the same syntax as the contents of the view.
+    /// While any entity is highlighted, hovered objects are outlined with the selection color.
+    #[inline]
+    pub fn with_highlighted(
+        mut self,
+        highlighted: impl IntoIterator<Item = impl Into<crate::blueprint::components::QueryExpression>>,
+    ) -> Self {
+        self.highlighted = try_serialize_field(Self::descriptor_highlighted(), highlighted);
+        self
+    }
+
+    /// Color of the outline of highlighted entities.
+    ///
+    /// Defaults to green.
+    #[inline]
+    pub fn with_highlight_color(
+        mut self,
+        highlight_color: impl Into<crate::components::Color>,
+    ) -> Self {
+        self.highlight_color =
+            try_serialize_field(Self::descriptor_highlight_color(), [highlight_color]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for Outlines {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.hover_color.heap_size_bytes()
+            + self.selection_color.heap_size_bytes()
+            + self.stroke_width.heap_size_bytes()
+            + self.highlighted.heap_size_bytes()
+            + self.highlight_color.heap_size_bytes()
+    }
+}
@@ -0,0 +1,177 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/point_shading.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::allow_attributes)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+#![allow(clippy::wildcard_imports)]
+
+use ::re_types_core::SerializationResult;
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Configures how point clouds are shaded in a 3D view.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct PointShading {
+    /// Whether normals are estimated for point clouds, and used to light them.
+    ///
+    /// The normal of each point is fitted to its nearest neighbors on the GPU, which brings out
+    /// the shape of scanned surfaces without having to compute normals offline.
+    /// This is redone every frame, so it can be slow for very large point clouds.
+    /// Defaults to false.
+    pub estimate_normals: Option<SerializedComponentBatch>,
+}
+
+impl PointShading {
+    /// Returns the [`ComponentDescriptor`] for [`Self::estimate_normals`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::Enabled`].
+    #[inline]
+    pub fn descriptor_estimate_normals() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.PointShading".into()),
+            component: "PointShading:estimate_normals".into(),
+            component_type: Some("rerun.blueprint.components.Enabled".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
+    std::sync::LazyLock::new(|| [PointShading::descriptor_estimate_normals()]);
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
+    std::sync::LazyLock::new(|| [PointShading::descriptor_estimate_normals()]);
+
+impl PointShading {
+    /// The total number of components in the archetype: 0 required, 0 recommended, 1 optional
+    pub const NUM_COMPONENTS: usize = 1usize;
+}
+
+impl ::re_types_core::Archetype for PointShading {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.PointShading".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Point shading"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let estimate_normals = arrays_by_descr
+            .get(&Self::descriptor_estimate_normals())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_estimate_normals())
+            });
+        Ok(Self { estimate_normals })
+    }
+}
+
+impl ::re_types_core::AsComponents for PointShading {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        std::iter::once(self.estimate_normals.clone())
+            .flatten()
+            .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for PointShading {}
+
+impl PointShading {
+    /// Create a new `PointShading`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            estimate_normals: None,
+        }
+    }
+
+    /// Update only some specific fields of a `PointShading`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `PointShading`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            estimate_normals: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::Enabled::arrow_empty(),
+                Self::descriptor_estimate_normals(),
+            )),
+        }
+    }
+
+    /// Whether normals are estimated for point clouds, and used to light them.
+    ///
+    /// The normal of each point is fitted to its nearest neighbors on the GPU, which brings out
+    /// the shape of scanned surfaces without having to compute normals offline.
+    /// This is redone every frame, so it can be slow for very large point clouds.
+    /// Defaults to false.
+    #[inline]
+    pub fn with_estimate_normals(
+        mut self,
+        estimate_normals: impl Into<crate::blueprint::components::Enabled>,
+    ) -> Self {
+        self.estimate_normals =
+            try_serialize_field(Self::descriptor_estimate_normals(), [estimate_normals]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for PointShading {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.estimate_normals.heap_size_bytes()
+    }
+}
@@ -0,0 +1,350 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/rigid_alignment.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::allow_attributes)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+#![allow(clippy::wildcard_imports)]
+
+use ::re_types_core::SerializationResult;
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Moves an entity onto another one with a rigid transform.
+///
+/// Log this as an override of the entity in a 3D view, e.g. to compare a reconstruction with its ground truth.
+/// The transform is only applied in that view, on top of the transforms logged for the entity.
+/// If neither translation nor rotation is set, the viewer fits the transform that best aligns
+/// the points or line strips of the entity with those of the reference, and fills them in.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct RigidAlignment {
+    /// The entity to align to, e.g. the ground truth.
+    ///
+    /// Has to be shown in the same view.
+    pub reference: Option<SerializedComponentBatch>,
+
+    /// Whether to match each point with the closest point of the reference (ICP).
+    ///
+    /// Otherwise points are matched by index, which requires both entities to have the same
+    /// number of points, e.g. two trajectories sampled at the same times.
+    pub match_closest: Option<SerializedComponentBatch>,
+
+    /// The translation of the fitted transform, in the space of the entity.
+    pub translation: Option<SerializedComponentBatch>,
+
+    /// The rotation of the fitted transform, in the space of the entity.
+    pub quaternion: Option<SerializedComponentBatch>,
+
+    /// The root mean square distance between the matched points after the fit.
+    ///
+    /// Set by the viewer when it fits the transform.
+    pub rmse: Option<SerializedComponentBatch>,
+}
+
+impl RigidAlignment {
+    /// Returns the [`ComponentDescriptor`] for [`Self::reference`].
+    ///
+    /// The corresponding component is [`crate::components::EntityPath`].
+    #[inline]
+    pub fn descriptor_reference() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.RigidAlignment".into()),
+            component: "RigidAlignment:reference".into(),
+            component_type: Some("rerun.components.EntityPath".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::match_closest`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::Enabled`].
+    #[inline]
+    pub fn descriptor_match_closest() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.RigidAlignment".into()),
+            component: "RigidAlignment:match_closest".into(),
+            component_type: Some("rerun.blueprint.components.Enabled".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::translation`].
+    ///
+    /// The corresponding component is [`crate::components::Translation3D`].
+    #[inline]
+    pub fn descriptor_translation() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.RigidAlignment".into()),
+            component: "RigidAlignment:translation".into(),
+            component_type: Some("rerun.components.Translation3D".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::quaternion`].
+    ///
+    /// The corresponding component is [`crate::components::RotationQuat`].
+    #[inline]
+    pub fn descriptor_quaternion() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.RigidAlignment".into()),
+            component: "RigidAlignment:quaternion".into(),
+            component_type: Some("rerun.components.RotationQuat".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::rmse`].
+    ///
+    /// The corresponding component is [`crate::components::Length`].
+    #[inline]
+    pub fn descriptor_rmse() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.RigidAlignment".into()),
+            component: "RigidAlignment:rmse".into(),
+            component_type: Some("rerun.components.Length".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 5usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            RigidAlignment::descriptor_reference(),
+            RigidAlignment::descriptor_match_closest(),
+            RigidAlignment::descriptor_translation(),
+            RigidAlignment::descriptor_quaternion(),
+            RigidAlignment::descriptor_rmse(),
+        ]
+    });
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 5usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            RigidAlignment::descriptor_reference(),
+            RigidAlignment::descriptor_match_closest(),
+            RigidAlignment::descriptor_translation(),
+            RigidAlignment::descriptor_quaternion(),
+            RigidAlignment::descriptor_rmse(),
+        ]
+    });
+
+impl RigidAlignment {
+    /// The total number of components in the archetype: 0 required, 0 recommended, 5 optional
+    pub const NUM_COMPONENTS: usize = 5usize;
+}
+
+impl ::re_types_core::Archetype for RigidAlignment {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.RigidAlignment".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Rigid alignment"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let reference = arrays_by_descr
+            .get(&Self::descriptor_reference())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_reference())
+            });
+        let match_closest = arrays_by_descr
+            .get(&Self::descriptor_match_closest())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_match_closest())
+            });
+        let translation = arrays_by_descr
+            .get(&Self::descriptor_translation())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_translation())
+            });
+        let quaternion = arrays_by_descr
+            .get(&Self::descriptor_quaternion())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_quaternion())
+            });
+        let rmse = arrays_by_descr
+            .get(&Self::descriptor_rmse())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_rmse()));
+        Ok(Self {
+            reference,
+            match_closest,
+            translation,
+            quaternion,
+            rmse,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for RigidAlignment {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            self.reference.clone(),
+            self.match_closest.clone(),
+            self.translation.clone(),
+            self.quaternion.clone(),
+            self.rmse.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for RigidAlignment {}
+
+impl RigidAlignment {
+    /// Create a new `RigidAlignment`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            reference: None,
+            match_closest: None,
+            translation: None,
+            quaternion: None,
+            rmse: None,
+        }
+    }
+
+    /// Update only some specific fields of a `RigidAlignment`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `RigidAlignment`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            reference: Some(SerializedComponentBatch::new(
+                crate::components::EntityPath::arrow_empty(),
+                Self::descriptor_reference(),
+            )),
+            match_closest: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::Enabled::arrow_empty(),
+                Self::descriptor_match_closest(),
+            )),
+            translation: Some(SerializedComponentBatch::new(
+                crate::components::Translation3D::arrow_empty(),
+                Self::descriptor_translation(),
+            )),
+            quaternion: Some(SerializedComponentBatch::new(
+                crate::components::RotationQuat::arrow_empty(),
+                Self::descriptor_quaternion(),
+            )),
+            rmse: Some(SerializedComponentBatch::new(
+                crate::components::Length::arrow_empty(),
+                Self::descriptor_rmse(),
+            )),
+        }
+    }
+
+    /// The entity to align to, e.g. the ground truth.
+    ///
+    /// Has to be shown in the same view.
+    #[inline]
+    pub fn with_reference(mut self, reference: impl Into<crate::components::EntityPath>) -> Self {
+        self.reference = try_serialize_field(Self::descriptor_reference(), [reference]);
+        self
+    }
+
+    /// Whether to match each point with the closest point of the reference (ICP).
+    ///
+    /// Otherwise points are matched by index, which requires both entities to have the same
+    /// number of points, e.g. two trajectories sampled at the same times.
+    #[inline]
+    pub fn with_match_closest(
+        mut self,
+        match_closest: impl Into<crate::blueprint::components::Enabled>,
+    ) -> Self {
+        self.match_closest = try_serialize_field(Self::descriptor_match_closest(), [match_closest]);
+        self
+    }
+
+    /// The translation of the fitted transform, in the space of the entity.
+    #[inline]
+    pub fn with_translation(
+        mut self,
+        translation: impl Into<crate::components::Translation3D>,
+    ) -> Self {
+        self.translation = try_serialize_field(Self::descriptor_translation(), [translation]);
+        self
+    }
+
+    /// The rotation of the fitted transform, in the space of the entity.
+    #[inline]
+    pub fn with_quaternion(
+        mut self,
+        quaternion: impl Into<crate::components::RotationQuat>,
+    ) -> Self {
+        self.quaternion = try_serialize_field(Self::descriptor_quaternion(), [quaternion]);
+        self
+    }
+
+    /// The root mean square distance between the matched points after the fit.
+    ///
+    /// Set by the viewer when it fits the transform.
+    #[inline]
+    pub fn with_rmse(mut self, rmse: impl Into<crate::components::Length>) -> Self {
+        self.rmse = try_serialize_field(Self::descriptor_rmse(), [rmse]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for RigidAlignment {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.reference.heap_size_bytes()
+            + self.match_closest.heap_size_bytes()
+            + self.translation.heap_size_bytes()
+            + self.quaternion.heap_size_bytes()
+            + self.rmse.heap_size_bytes()
+    }
+}
@@ -50,6 +50,12 @@ pub struct TimePanelBlueprint {
 
     /// Selects a range of time on the time panel.
     pub time_selection: Option<SerializedComponentBatch>,
+
+    /// The entities whose rows are expanded in the streams tree of the time panel.
+    ///
+    /// Collapsed rows show the combined data density of their whole subtree.
+    /// If not set, the viewer picks which rows to expand.
+    pub expanded_entities: Option<SerializedComponentBatch>,
 }
 
 impl TimePanelBlueprint {
@@ -136,6 +142,18 @@ impl TimePanelBlueprint {
             component_type: Some("rerun.blueprint.components.AbsoluteTimeRange".into()),
         }
     }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::expanded_entities`].
+    ///
+    /// The corresponding component is [`crate::components::EntityPath`].
+    #[inline]
+    pub fn descriptor_expanded_entities() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.TimePanelBlueprint".into()),
+            component: "TimePanelBlueprint:expanded_entities".into(),
+            component_type: Some("rerun.components.EntityPath".into()),
+        }
+    }
 }
 
 static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
@@ -144,7 +162,7 @@ static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
 static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
     std::sync::LazyLock::new(|| []);
 
-static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 7usize]> =
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 8usize]> =
     std::sync::LazyLock::new(|| {
         [
             TimePanelBlueprint::descriptor_state(),
@@ -154,10 +172,11 @@ static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 7usize]> =
             TimePanelBlueprint::descriptor_play_state(),
             TimePanelBlueprint::descriptor_loop_mode(),
             TimePanelBlueprint::descriptor_time_selection(),
+            TimePanelBlueprint::descriptor_expanded_entities(),
         ]
     });
 
-static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 7usize]> =
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 8usize]> =
     std::sync::LazyLock::new(|| {
         [
             TimePanelBlueprint::descriptor_state(),
@@ -167,12 +186,13 @@ static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 7usize]> =
             TimePanelBlueprint::descriptor_play_state(),
             TimePanelBlueprint::descriptor_loop_mode(),
             TimePanelBlueprint::descriptor_time_selection(),
+            TimePanelBlueprint::descriptor_expanded_entities(),
         ]
     });
 
 impl TimePanelBlueprint {
-    /// The total number of components in the archetype: 0 required, 0 recommended, 7 optional
-    pub const NUM_COMPONENTS: usize = 7usize;
+    /// The total number of components in the archetype: 0 required, 0 recommended, 8 optional
+    pub const NUM_COMPONENTS: usize = 8usize;
 }
 
 impl ::re_types_core::Archetype for TimePanelBlueprint {
@@ -242,6 +262,11 @@ impl ::re_types_core::Archetype for TimePanelBlueprint {
             .map(|array| {
                 SerializedComponentBatch::new(array.clone(), Self::descriptor_time_selection())
             });
+        let expanded_entities = arrays_by_descr
+            .get(&Self::descriptor_expanded_entities())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_expanded_entities())
+            });
         Ok(Self {
             state,
             timeline,
@@ -250,6 +275,7 @@ impl ::re_types_core::Archetype for TimePanelBlueprint {
             play_state,
             loop_mode,
             time_selection,
+            expanded_entities,
         })
     }
 }
@@ -266,6 +292,7 @@ impl ::re_types_core::AsComponents for TimePanelBlueprint {
             self.play_state.clone(),
             self.loop_mode.clone(),
             self.time_selection.clone(),
+            self.expanded_entities.clone(),
         ]
         .into_iter()
         .flatten()
@@ -287,6 +314,7 @@ impl TimePanelBlueprint {
             play_state: None,
             loop_mode: None,
             time_selection: None,
+            expanded_entities: None,
         }
     }
 
@@ -329,6 +357,10 @@ impl TimePanelBlueprint {
                 crate::blueprint::components::AbsoluteTimeRange::arrow_empty(),
                 Self::descriptor_time_selection(),
             )),
+            expanded_entities: Some(SerializedComponentBatch::new(
+                crate::components::EntityPath::arrow_empty(),
+                Self::descriptor_expanded_entities(),
+            )),
         }
     }
 
@@ -404,6 +436,20 @@ impl TimePanelBlueprint {
             try_serialize_field(Self::descriptor_time_selection(), [time_selection]);
         self
     }
+
+    /// The entities whose rows are expanded in the streams tree of the time panel.
+    ///
+    /// Collapsed rows show the combined data density of their whole subtree.
+    /// If not set, the viewer picks which rows to expand.
+    #[inline]
+    pub fn with_expanded_entities(
+        mut self,
+        expanded_entities: impl IntoIterator<Item = impl Into<crate::components::EntityPath>>,
+    ) -> Self {
+        self.expanded_entities =
+            try_serialize_field(Self::descriptor_expanded_entities(), expanded_entities);
+        self
+    }
 }
 
 impl ::re_byte_size::SizeBytes for TimePanelBlueprint {
@@ -416,5 +462,6 @@ impl ::re_byte_size::SizeBytes for TimePanelBlueprint {
             + self.play_state.heap_size_bytes()
             + self.loop_mode.heap_size_bytes()
             + self.time_selection.heap_size_bytes()
+            + self.expanded_entities.heap_size_bytes()
     }
 }
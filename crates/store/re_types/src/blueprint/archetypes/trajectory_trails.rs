@@ -0,0 +1,269 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/trajectory_trails.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::allow_attributes)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+#![allow(clippy::wildcard_imports)]
+
+use ::re_types_core::SerializationResult;
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Configuration for the trajectory trails in the 3D view.
+///
+/// Trails show the past positions of entities, and are enabled per entity
+/// by adding the `Trajectory` visualizer to it.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct TrajectoryTrails {
+    /// The time range of past positions that is drawn, usually relative to the time cursor.
+    ///
+    /// Defaults to the 10 seconds before the time cursor, or to the 100 steps before it on sequence timelines.
+    pub time_range: Option<SerializedComponentBatch>,
+
+    /// Whether older parts of the trails are drawn thinner.
+    ///
+    /// Defaults to true.
+    pub fade: Option<SerializedComponentBatch>,
+
+    /// Whether the trails are colored by time instead of with the color of their entity.
+    ///
+    /// Defaults to false.
+    pub color_by_time: Option<SerializedComponentBatch>,
+}
+
+impl TrajectoryTrails {
+    /// Returns the [`ComponentDescriptor`] for [`Self::time_range`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::TimeRange`].
+    #[inline]
+    pub fn descriptor_time_range() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.TrajectoryTrails".into()),
+            component: "TrajectoryTrails:time_range".into(),
+            component_type: Some("rerun.blueprint.components.TimeRange".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::fade`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::Enabled`].
+    #[inline]
+    pub fn descriptor_fade() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.TrajectoryTrails".into()),
+            component: "TrajectoryTrails:fade".into(),
+            component_type: Some("rerun.blueprint.components.Enabled".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::color_by_time`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::Enabled`].
+    #[inline]
+    pub fn descriptor_color_by_time() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.TrajectoryTrails".into()),
+            component: "TrajectoryTrails:color_by_time".into(),
+            component_type: Some("rerun.blueprint.components.Enabled".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 3usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            TrajectoryTrails::descriptor_time_range(),
+            TrajectoryTrails::descriptor_fade(),
+            TrajectoryTrails::descriptor_color_by_time(),
+        ]
+    });
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 3usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            TrajectoryTrails::descriptor_time_range(),
+            TrajectoryTrails::descriptor_fade(),
+            TrajectoryTrails::descriptor_color_by_time(),
+        ]
+    });
+
+impl TrajectoryTrails {
+    /// The total number of components in the archetype: 0 required, 0 recommended, 3 optional
+    pub const NUM_COMPONENTS: usize = 3usize;
+}
+
+impl ::re_types_core::Archetype for TrajectoryTrails {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.TrajectoryTrails".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Trajectory trails"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let time_range = arrays_by_descr
+            .get(&Self::descriptor_time_range())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_time_range())
+            });
+        let fade = arrays_by_descr
+            .get(&Self::descriptor_fade())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_fade()));
+        let color_by_time = arrays_by_descr
+            .get(&Self::descriptor_color_by_time())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_color_by_time())
+            });
+        Ok(Self {
+            time_range,
+            fade,
+            color_by_time,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for TrajectoryTrails {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            self.time_range.clone(),
+            self.fade.clone(),
+            self.color_by_time.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for TrajectoryTrails {}
+
+impl TrajectoryTrails {
+    /// Create a new `TrajectoryTrails`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            time_range: None,
+            fade: None,
+            color_by_time: None,
+        }
+    }
+
+    /// Update only some specific fields of a `TrajectoryTrails`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `TrajectoryTrails`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            time_range: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::TimeRange::arrow_empty(),
+                Self::descriptor_time_range(),
+            )),
+            fade: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::Enabled::arrow_empty(),
+                Self::descriptor_fade(),
+            )),
+            color_by_time: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::Enabled::arrow_empty(),
+                Self::descriptor_color_by_time(),
+            )),
+        }
+    }
+
+    /// The time range of past positions that is drawn, usually relative to the time cursor.
+    ///
+    /// Defaults to the 10 seconds before the time cursor, or to the 100 steps before it on sequence timelines.
+    #[inline]
+    pub fn with_time_range(
+        mut self,
+        time_range: impl Into<crate::blueprint::components::TimeRange>,
+    ) -> Self {
+        self.time_range = try_serialize_field(Self::descriptor_time_range(), [time_range]);
+        self
+    }
+
+    /// Whether older parts of the trails are drawn thinner.
+    ///
+    /// Defaults to true.
+    #[inline]
+    pub fn with_fade(mut self, fade: impl Into<crate::blueprint::components::Enabled>) -> Self {
+        self.fade = try_serialize_field(Self::descriptor_fade(), [fade]);
+        self
+    }
+
+    /// Whether the trails are colored by time instead of with the color of their entity.
+    ///
+    /// Defaults to false.
+    #[inline]
+    pub fn with_color_by_time(
+        mut self,
+        color_by_time: impl Into<crate::blueprint::components::Enabled>,
+    ) -> Self {
+        self.color_by_time = try_serialize_field(Self::descriptor_color_by_time(), [color_by_time]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for TrajectoryTrails {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.time_range.heap_size_bytes()
+            + self.fade.heap_size_bytes()
+            + self.color_by_time.heap_size_bytes()
+    }
+}
@@ -0,0 +1,363 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/visibility_rules.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::allow_attributes)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+#![allow(clippy::wildcard_imports)]
+
+use ::re_types_core::SerializationResult;
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Rules under which a view or an entity in a view is shown, evaluated every frame.
+///
+/// Logged as a property of a view, the rules show or hide the whole view.
+/// Logged as an override of an entity in a view, they show or hide that entity and its children.
+/// As soon as any of the rules isn't met, the view or entity is hidden.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct VisibilityRules {
+    /// The timeline that `time_range` refers to.
+    ///
+    /// If unset, `time_range` applies to whichever timeline is active.
+    pub timeline: Option<SerializedComponentBatch>,
+
+    /// Only shown while the time cursor is within this range.
+    ///
+    /// If unset, shown at all times.
+    pub time_range: Option<SerializedComponentBatch>,
+
+    /// The entity whose scalar value is compared against `threshold`.
+    ///
+    /// If unset, shown regardless of any scalar value.
+    pub scalar_entity: Option<SerializedComponentBatch>,
+
+    /// The component of `scalar_entity` that is compared against `threshold`.
+    ///
+    /// Either the full component identifier or just the part after the archetype prefix.
+    /// Defaults to the `scalars` of a `Scalars` archetype.
+    pub scalar_component: Option<SerializedComponentBatch>,
+
+    /// Only shown while the latest value of the scalar is at least this threshold.
+    ///
+    /// Shown if the scalar has no value at the current time.
+    /// Defaults to 0.0.
+    pub threshold: Option<SerializedComponentBatch>,
+}
+
+impl VisibilityRules {
+    /// Returns the [`ComponentDescriptor`] for [`Self::timeline`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::TimelineName`].
+    #[inline]
+    pub fn descriptor_timeline() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.VisibilityRules".into()),
+            component: "VisibilityRules:timeline".into(),
+            component_type: Some("rerun.blueprint.components.TimelineName".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::time_range`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::AbsoluteTimeRange`].
+    #[inline]
+    pub fn descriptor_time_range() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.VisibilityRules".into()),
+            component: "VisibilityRules:time_range".into(),
+            component_type: Some("rerun.blueprint.components.AbsoluteTimeRange".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::scalar_entity`].
+    ///
+    /// The corresponding component is [`crate::components::EntityPath`].
+    #[inline]
+    pub fn descriptor_scalar_entity() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.VisibilityRules".into()),
+            component: "VisibilityRules:scalar_entity".into(),
+            component_type: Some("rerun.components.EntityPath".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::scalar_component`].
+    ///
+    /// The corresponding component is [`crate::components::Name`].
+    #[inline]
+    pub fn descriptor_scalar_component() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.VisibilityRules".into()),
+            component: "VisibilityRules:scalar_component".into(),
+            component_type: Some("rerun.components.Name".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::threshold`].
+    ///
+    /// The corresponding component is [`crate::components::Scalar`].
+    #[inline]
+    pub fn descriptor_threshold() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.VisibilityRules".into()),
+            component: "VisibilityRules:threshold".into(),
+            component_type: Some("rerun.components.Scalar".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 5usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            VisibilityRules::descriptor_timeline(),
+            VisibilityRules::descriptor_time_range(),
+            VisibilityRules::descriptor_scalar_entity(),
+            VisibilityRules::descriptor_scalar_component(),
+            VisibilityRules::descriptor_threshold(),
+        ]
+    });
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 5usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            VisibilityRules::descriptor_timeline(),
+            VisibilityRules::descriptor_time_range(),
+            VisibilityRules::descriptor_scalar_entity(),
+            VisibilityRules::descriptor_scalar_component(),
+            VisibilityRules::descriptor_threshold(),
+        ]
+    });
+
+impl VisibilityRules {
+    /// The total number of components in the archetype: 0 required, 0 recommended, 5 optional
+    pub const NUM_COMPONENTS: usize = 5usize;
+}
+
+impl ::re_types_core::Archetype for VisibilityRules {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.VisibilityRules".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Visibility rules"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let timeline = arrays_by_descr
+            .get(&Self::descriptor_timeline())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_timeline()));
+        let time_range = arrays_by_descr
+            .get(&Self::descriptor_time_range())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_time_range())
+            });
+        let scalar_entity = arrays_by_descr
+            .get(&Self::descriptor_scalar_entity())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_scalar_entity())
+            });
+        let scalar_component = arrays_by_descr
+            .get(&Self::descriptor_scalar_component())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_scalar_component())
+            });
+        let threshold = arrays_by_descr
+            .get(&Self::descriptor_threshold())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_threshold())
+            });
+        Ok(Self {
+            timeline,
+            time_range,
+            scalar_entity,
+            scalar_component,
+            threshold,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for VisibilityRules {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            self.timeline.clone(),
+            self.time_range.clone(),
+            self.scalar_entity.clone(),
+            self.scalar_component.clone(),
+            self.threshold.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for VisibilityRules {}
+
+impl VisibilityRules {
+    /// Create a new `VisibilityRules`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            timeline: None,
+            time_range: None,
+            scalar_entity: None,
+            scalar_component: None,
+            threshold: None,
+        }
+    }
+
+    /// Update only some specific fields of a `VisibilityRules`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `VisibilityRules`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            timeline: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::TimelineName::arrow_empty(),
+                Self::descriptor_timeline(),
+            )),
+            time_range: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::AbsoluteTimeRange::arrow_empty(),
+                Self::descriptor_time_range(),
+            )),
+            scalar_entity: Some(SerializedComponentBatch::new(
+                crate::components::EntityPath::arrow_empty(),
+                Self::descriptor_scalar_entity(),
+            )),
+            scalar_component: Some(SerializedComponentBatch::new(
+                crate::components::Name::arrow_empty(),
+                Self::descriptor_scalar_component(),
+            )),
+            threshold: Some(SerializedComponentBatch::new(
+                crate::components::Scalar::arrow_empty(),
+                Self::descriptor_threshold(),
+            )),
+        }
+    }
+
+    /// The timeline that `time_range` refers to.
+    ///
+    /// If unset, `time_range` applies to whichever timeline is active.
+    #[inline]
+    pub fn with_timeline(
+        mut self,
+        timeline: impl Into<crate::blueprint::components::TimelineName>,
+    ) -> Self {
+        self.timeline = try_serialize_field(Self::descriptor_timeline(), [timeline]);
+        self
+    }
+
+    /// Only shown while the time cursor is within this range.
+    ///
+    /// If unset, shown at all times.
+    #[inline]
+    pub fn with_time_range(
+        mut self,
+        time_range: impl Into<crate::blueprint::components::AbsoluteTimeRange>,
+    ) -> Self {
+        self.time_range = try_serialize_field(Self::descriptor_time_range(), [time_range]);
+        self
+    }
+
+    /// The entity whose scalar value is compared against `threshold`.
+    ///
+    /// If unset, shown regardless of any scalar value.
+    #[inline]
+    pub fn with_scalar_entity(
+        mut self,
+        scalar_entity: impl Into<crate::components::EntityPath>,
+    ) -> Self {
+        self.scalar_entity = try_serialize_field(Self::descriptor_scalar_entity(), [scalar_entity]);
+        self
+    }
+
+    /// The component of `scalar_entity` that is compared against `threshold`.
+    ///
+    /// Either the full component identifier or just the part after the archetype prefix.
+    /// Defaults to the `scalars` of a `Scalars` archetype.
+    #[inline]
+    pub fn with_scalar_component(
+        mut self,
+        scalar_component: impl Into<crate::components::Name>,
+    ) -> Self {
+        self.scalar_component =
+            try_serialize_field(Self::descriptor_scalar_component(), [scalar_component]);
+        self
+    }
+
+    /// Only shown while the latest value of the scalar is at least this threshold.
+    ///
+    /// Shown if the scalar has no value at the current time.
+    /// Defaults to 0.0.
+    #[inline]
+    pub fn with_threshold(mut self, threshold: impl Into<crate::components::Scalar>) -> Self {
+        self.threshold = try_serialize_field(Self::descriptor_threshold(), [threshold]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for VisibilityRules {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.timeline.heap_size_bytes()
+            + self.time_range.heap_size_bytes()
+            + self.scalar_entity.heap_size_bytes()
+            + self.scalar_component.heap_size_bytes()
+            + self.threshold.heap_size_bytes()
+    }
+}
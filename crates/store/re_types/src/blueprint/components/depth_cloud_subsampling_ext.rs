@@ -0,0 +1,8 @@
+use super::DepthCloudSubsampling;
+
+impl Default for DepthCloudSubsampling {
+    #[inline]
+    fn default() -> Self {
+        Self(crate::datatypes::UInt64(1))
+    }
+}
@@ -0,0 +1,163 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/eye3d_projection.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::allow_attributes)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+#![allow(clippy::wildcard_imports)]
+#![allow(non_camel_case_types)]
+
+use ::re_types_core::SerializationResult;
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Component**: The projection of the 3D eye to view a scene in a [`views::Spatial3DView`][crate::blueprint::views::Spatial3DView].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Default)]
+#[repr(u8)]
+pub enum Eye3DProjection {
+    /// Perspective projection.
+    ///
+    /// Objects further away from the eye appear smaller, as with a real camera.
+    #[default]
+    Perspective = 1,
+
+    /// Orthographic projection.
+    ///
+    /// Parallel lines stay parallel and objects keep their size regardless of their distance to the eye,
+    /// which makes it possible to compare and measure lengths on screen.
+    Orthographic = 2,
+}
+
+impl ::re_types_core::Component for Eye3DProjection {
+    #[inline]
+    fn name() -> ComponentType {
+        "rerun.blueprint.components.Eye3DProjection".into()
+    }
+}
+
+::re_types_core::macros::impl_into_cow!(Eye3DProjection);
+
+impl ::re_types_core::Loggable for Eye3DProjection {
+    #[inline]
+    fn arrow_datatype() -> arrow::datatypes::DataType {
+        use arrow::datatypes::*;
+        DataType::UInt8
+    }
+
+    fn to_arrow_opt<'a>(
+        data: impl IntoIterator<Item = Option<impl Into<::std::borrow::Cow<'a, Self>>>>,
+    ) -> SerializationResult<arrow::array::ArrayRef>
+    where
+        Self: Clone + 'a,
+    {
+        #![allow(clippy::manual_is_variant_and)]
+        use ::re_types_core::{Loggable as _, ResultExt as _, arrow_helpers::as_array_ref};
+        use arrow::{array::*, buffer::*, datatypes::*};
+        Ok({
+            let (somes, data0): (Vec<_>, Vec<_>) = data
+                .into_iter()
+                .map(|datum| {
+                    let datum: Option<::std::borrow::Cow<'a, Self>> = datum.map(Into::into);
+                    let datum = datum.map(|datum| *datum as u8);
+                    (datum.is_some(), datum)
+                })
+                .unzip();
+            let data0_validity: Option<arrow::buffer::NullBuffer> = {
+                let any_nones = somes.iter().any(|some| !*some);
+                any_nones.then(|| somes.into())
+            };
+            as_array_ref(PrimitiveArray::<UInt8Type>::new(
+                ScalarBuffer::from(
+                    data0
+                        .into_iter()
+                        .map(|v| v.unwrap_or_default())
+                        .collect::<Vec<_>>(),
+                ),
+                data0_validity,
+            ))
+        })
+    }
+
+    fn from_arrow_opt(
+        arrow_data: &dyn arrow::array::Array,
+    ) -> DeserializationResult<Vec<Option<Self>>>
+    where
+        Self: Sized,
+    {
+        use ::re_types_core::{Loggable as _, ResultExt as _, arrow_zip_validity::ZipValidity};
+        use arrow::{array::*, buffer::*, datatypes::*};
+        Ok(arrow_data
+            .as_any()
+            .downcast_ref::<UInt8Array>()
+            .ok_or_else(|| {
+                let expected = Self::arrow_datatype();
+                let actual = arrow_data.data_type().clone();
+                DeserializationError::datatype_mismatch(expected, actual)
+            })
+            .with_context("rerun.blueprint.components.Eye3DProjection#enum")?
+            .into_iter()
+            .map(|typ| match typ {
+                Some(1) => Ok(Some(Self::Perspective)),
+                Some(2) => Ok(Some(Self::Orthographic)),
+                None => Ok(None),
+                Some(invalid) => Err(DeserializationError::missing_union_arm(
+                    Self::arrow_datatype(),
+                    "<invalid>",
+                    invalid as _,
+                )),
+            })
+            .collect::<DeserializationResult<Vec<Option<_>>>>()
+            .with_context("rerun.blueprint.components.Eye3DProjection")?)
+    }
+}
+
+impl std::fmt::Display for Eye3DProjection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Perspective => write!(f, "Perspective"),
+            Self::Orthographic => write!(f, "Orthographic"),
+        }
+    }
+}
+
+impl ::re_types_core::reflection::Enum for Eye3DProjection {
+    #[inline]
+    fn variants() -> &'static [Self] {
+        &[Self::Perspective, Self::Orthographic]
+    }
+
+    #[inline]
+    fn docstring_md(self) -> &'static str {
+        match self {
+            Self::Perspective => {
+                "Perspective projection.\n\nObjects further away from the eye appear smaller, as with a real camera."
+            }
+            Self::Orthographic => {
+                "Orthographic projection.\n\nParallel lines stay parallel and objects keep their size regardless of their distance to the eye,\nwhich makes it possible to compare and measure lengths on screen."
+            }
+        }
+    }
+}
+
+impl ::re_byte_size::SizeBytes for Eye3DProjection {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        0
+    }
+
+    #[inline]
+    fn is_pod() -> bool {
+        true
+    }
+}
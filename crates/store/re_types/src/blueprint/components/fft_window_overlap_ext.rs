@@ -0,0 +1,11 @@
+use re_types_core::datatypes::Float32;
+
+use super::FftWindowOverlap;
+
+impl Default for FftWindowOverlap {
+    #[inline]
+    fn default() -> Self {
+        // Half overlapping Hann windows weigh every sample equally.
+        Self(Float32(0.5))
+    }
+}
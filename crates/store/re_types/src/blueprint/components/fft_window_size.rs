@@ -0,0 +1,89 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/fft_window_size.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::allow_attributes)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+#![allow(clippy::wildcard_imports)]
+
+use ::re_types_core::SerializationResult;
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Component**: The number of samples in each window of a rolling FFT, e.g. of a `SpectrogramView`.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct FftWindowSize(
+    /// The number of samples per window.
+    pub crate::datatypes::UInt32,
+);
+
+impl ::re_types_core::WrapperComponent for FftWindowSize {
+    type Datatype = crate::datatypes::UInt32;
+
+    #[inline]
+    fn name() -> ComponentType {
+        "rerun.blueprint.components.FftWindowSize".into()
+    }
+
+    #[inline]
+    fn into_inner(self) -> Self::Datatype {
+        self.0
+    }
+}
+
+::re_types_core::macros::impl_into_cow!(FftWindowSize);
+
+impl<T: Into<crate::datatypes::UInt32>> From<T> for FftWindowSize {
+    fn from(v: T) -> Self {
+        Self(v.into())
+    }
+}
+
+impl std::borrow::Borrow<crate::datatypes::UInt32> for FftWindowSize {
+    #[inline]
+    fn borrow(&self) -> &crate::datatypes::UInt32 {
+        &self.0
+    }
+}
+
+impl std::ops::Deref for FftWindowSize {
+    type Target = crate::datatypes::UInt32;
+
+    #[inline]
+    fn deref(&self) -> &crate::datatypes::UInt32 {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for FftWindowSize {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut crate::datatypes::UInt32 {
+        &mut self.0
+    }
+}
+
+impl ::re_byte_size::SizeBytes for FftWindowSize {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.0.heap_size_bytes()
+    }
+
+    #[inline]
+    fn is_pod() -> bool {
+        <crate::datatypes::UInt32>::is_pod()
+    }
+}
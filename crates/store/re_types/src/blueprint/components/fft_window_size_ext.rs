@@ -0,0 +1,12 @@
+use re_types_core::datatypes::UInt32;
+
+use super::FftWindowSize;
+
+impl Default for FftWindowSize {
+    #[inline]
+    fn default() -> Self {
+        // Enough frequency resolution for most vibration and audio signals,
+        // while still resolving short events in time.
+        Self(UInt32(256))
+    }
+}
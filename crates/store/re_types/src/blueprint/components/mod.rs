@@ -8,15 +8,23 @@ mod auto_layout;
 mod auto_layout_ext;
 mod auto_views;
 mod background_kind;
+mod bar_chart_mode;
 mod column_share;
 mod component_column_selector;
 mod component_column_selector_ext;
 mod container_kind;
 mod corner2d;
 mod corner2d_ext;
+mod depth_cloud_subsampling;
+mod depth_cloud_subsampling_ext;
 mod enabled;
 mod enabled_ext;
 mod eye3d_kind;
+mod eye3d_projection;
+mod fft_window_overlap;
+mod fft_window_overlap_ext;
+mod fft_window_size;
+mod fft_window_size_ext;
 mod filter_by_range;
 mod filter_by_range_ext;
 mod filter_is_not_null;
@@ -35,6 +43,7 @@ mod loop_mode;
 mod map_provider;
 mod near_clip_plane;
 mod near_clip_plane_ext;
+mod onion_skin_steps;
 mod panel_state;
 mod panel_state_ext;
 mod play_state;
@@ -70,12 +79,17 @@ pub use self::apply_latest_at::ApplyLatestAt;
 pub use self::auto_layout::AutoLayout;
 pub use self::auto_views::AutoViews;
 pub use self::background_kind::BackgroundKind;
+pub use self::bar_chart_mode::BarChartMode;
 pub use self::column_share::ColumnShare;
 pub use self::component_column_selector::ComponentColumnSelector;
 pub use self::container_kind::ContainerKind;
 pub use self::corner2d::Corner2D;
+pub use self::depth_cloud_subsampling::DepthCloudSubsampling;
 pub use self::enabled::Enabled;
 pub use self::eye3d_kind::Eye3DKind;
+pub use self::eye3d_projection::Eye3DProjection;
+pub use self::fft_window_overlap::FftWindowOverlap;
+pub use self::fft_window_size::FftWindowSize;
 pub use self::filter_by_range::FilterByRange;
 pub use self::filter_is_not_null::FilterIsNotNull;
 pub use self::force_distance::ForceDistance;
@@ -90,6 +104,7 @@ pub use self::lock_range_during_zoom::LockRangeDuringZoom;
 pub use self::loop_mode::LoopMode;
 pub use self::map_provider::MapProvider;
 pub use self::near_clip_plane::NearClipPlane;
+pub use self::onion_skin_steps::OnionSkinSteps;
 pub use self::panel_state::PanelState;
 pub use self::play_state::PlayState;
 pub use self::playback_speed::PlaybackSpeed;
@@ -26,6 +26,9 @@ use ::re_types_core::{DeserializationError, DeserializationResult};
 /// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
 #[derive(Clone, Debug)]
 pub struct BarChartView {
+    /// Configures how the bar charts of several entities are combined.
+    pub layout: crate::blueprint::archetypes::BarChartLayout,
+
     /// Configures the legend of the plot.
     pub plot_legend: crate::blueprint::archetypes::PlotLegend,
 
@@ -43,12 +46,15 @@ impl ::re_types_core::View for BarChartView {
 impl ::re_byte_size::SizeBytes for BarChartView {
     #[inline]
     fn heap_size_bytes(&self) -> u64 {
-        self.plot_legend.heap_size_bytes() + self.background.heap_size_bytes()
+        self.layout.heap_size_bytes()
+            + self.plot_legend.heap_size_bytes()
+            + self.background.heap_size_bytes()
     }
 
     #[inline]
     fn is_pod() -> bool {
-        <crate::blueprint::archetypes::PlotLegend>::is_pod()
+        <crate::blueprint::archetypes::BarChartLayout>::is_pod()
+            && <crate::blueprint::archetypes::PlotLegend>::is_pod()
             && <crate::blueprint::archetypes::PlotBackground>::is_pod()
     }
 }
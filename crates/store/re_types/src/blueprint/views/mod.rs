@@ -1,22 +1,26 @@
 // DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
 
+mod audio_view;
 mod bar_chart_view;
 mod dataframe_view;
 mod graph_view;
 mod map_view;
 mod spatial2d_view;
 mod spatial3d_view;
+mod spectrogram_view;
 mod tensor_view;
 mod text_document_view;
 mod text_log_view;
 mod time_series_view;
 
+pub use self::audio_view::AudioView;
 pub use self::bar_chart_view::BarChartView;
 pub use self::dataframe_view::DataframeView;
 pub use self::graph_view::GraphView;
 pub use self::map_view::MapView;
 pub use self::spatial2d_view::Spatial2DView;
 pub use self::spatial3d_view::Spatial3DView;
+pub use self::spectrogram_view::SpectrogramView;
 pub use self::tensor_view::TensorView;
 pub use self::text_document_view::TextDocumentView;
 pub use self::text_log_view::TextLogView;
@@ -35,6 +35,18 @@ pub struct Spatial2DView {
     /// Somethings outside of these bounds may also be visible due to letterboxing.
     pub visual_bounds: crate::blueprint::archetypes::VisualBounds2D,
 
+    /// Hides instances based on the value of a scalar component, e.g. to threshold detections.
+    pub instance_filter: crate::blueprint::archetypes::InstanceFilter,
+
+    /// Shows the earlier states of entities as translucent ghosts.
+    pub onion_skin: crate::blueprint::archetypes::OnionSkin,
+
+    /// Remaps class ids, e.g. to merge classes of segmentation images without re-logging them.
+    pub class_id_remap: crate::blueprint::archetypes::ClassIdRemap,
+
+    /// Colors and thickness of outlines, and which entities are always outlined.
+    pub outlines: crate::blueprint::archetypes::Outlines,
+
     /// Configures which range on each timeline is shown by this view (unless specified differently per entity).
     ///
     /// If not specified, the default is to show the latest state of each component.
@@ -54,6 +66,10 @@ impl ::re_byte_size::SizeBytes for Spatial2DView {
     fn heap_size_bytes(&self) -> u64 {
         self.background.heap_size_bytes()
             + self.visual_bounds.heap_size_bytes()
+            + self.instance_filter.heap_size_bytes()
+            + self.onion_skin.heap_size_bytes()
+            + self.class_id_remap.heap_size_bytes()
+            + self.outlines.heap_size_bytes()
             + self.time_ranges.heap_size_bytes()
     }
 
@@ -61,6 +77,10 @@ impl ::re_byte_size::SizeBytes for Spatial2DView {
     fn is_pod() -> bool {
         <crate::blueprint::archetypes::Background>::is_pod()
             && <crate::blueprint::archetypes::VisualBounds2D>::is_pod()
+            && <crate::blueprint::archetypes::InstanceFilter>::is_pod()
+            && <crate::blueprint::archetypes::OnionSkin>::is_pod()
+            && <crate::blueprint::archetypes::ClassIdRemap>::is_pod()
+            && <crate::blueprint::archetypes::Outlines>::is_pod()
             && <crate::blueprint::archetypes::VisibleTimeRanges>::is_pod()
     }
 }
@@ -38,6 +38,36 @@ pub struct Spatial3DView {
     /// Configuration for the 3D eye
     pub eye_controls: crate::blueprint::archetypes::EyeControls3D,
 
+    /// Configuration for the image planes of pinhole cameras.
+    pub image_planes: crate::blueprint::archetypes::ImagePlanes3D,
+
+    /// Hides instances based on the value of a scalar component, e.g. to threshold detections.
+    pub instance_filter: crate::blueprint::archetypes::InstanceFilter,
+
+    /// Configuration for the trajectory trails of moving entities.
+    pub trajectory_trails: crate::blueprint::archetypes::TrajectoryTrails,
+
+    /// Shows the earlier states of entities as translucent ghosts.
+    pub onion_skin: crate::blueprint::archetypes::OnionSkin,
+
+    /// Configuration for the point clouds backprojected from depth images.
+    pub depth_clouds: crate::blueprint::archetypes::DepthClouds3D,
+
+    /// Remaps class ids, e.g. to merge classes of segmentation images without re-logging them.
+    pub class_id_remap: crate::blueprint::archetypes::ClassIdRemap,
+
+    /// Converts the data from the coordinate convention it was logged in, e.g. from NED to ENU.
+    pub coordinate_conversion: crate::blueprint::archetypes::CoordinateConversion,
+
+    /// Hides the points of point clouds inside or outside of a box.
+    pub crop_box: crate::blueprint::archetypes::CropBox,
+
+    /// Configures how point clouds are shaded.
+    pub point_shading: crate::blueprint::archetypes::PointShading,
+
+    /// Colors and thickness of outlines, and which entities are always outlined.
+    pub outlines: crate::blueprint::archetypes::Outlines,
+
     /// Configures which range on each timeline is shown by this view (unless specified differently per entity).
     ///
     /// If not specified, the default is to show the latest state of each component.
@@ -59,6 +89,16 @@ impl ::re_byte_size::SizeBytes for Spatial3DView {
             + self.line_grid.heap_size_bytes()
             + self.spatial_information.heap_size_bytes()
             + self.eye_controls.heap_size_bytes()
+            + self.image_planes.heap_size_bytes()
+            + self.instance_filter.heap_size_bytes()
+            + self.trajectory_trails.heap_size_bytes()
+            + self.onion_skin.heap_size_bytes()
+            + self.depth_clouds.heap_size_bytes()
+            + self.class_id_remap.heap_size_bytes()
+            + self.coordinate_conversion.heap_size_bytes()
+            + self.crop_box.heap_size_bytes()
+            + self.point_shading.heap_size_bytes()
+            + self.outlines.heap_size_bytes()
             + self.time_ranges.heap_size_bytes()
     }
 
@@ -68,6 +108,16 @@ impl ::re_byte_size::SizeBytes for Spatial3DView {
             && <crate::blueprint::archetypes::LineGrid3D>::is_pod()
             && <crate::blueprint::archetypes::SpatialInformation>::is_pod()
             && <crate::blueprint::archetypes::EyeControls3D>::is_pod()
+            && <crate::blueprint::archetypes::ImagePlanes3D>::is_pod()
+            && <crate::blueprint::archetypes::InstanceFilter>::is_pod()
+            && <crate::blueprint::archetypes::TrajectoryTrails>::is_pod()
+            && <crate::blueprint::archetypes::OnionSkin>::is_pod()
+            && <crate::blueprint::archetypes::DepthClouds3D>::is_pod()
+            && <crate::blueprint::archetypes::ClassIdRemap>::is_pod()
+            && <crate::blueprint::archetypes::CoordinateConversion>::is_pod()
+            && <crate::blueprint::archetypes::CropBox>::is_pod()
+            && <crate::blueprint::archetypes::PointShading>::is_pod()
+            && <crate::blueprint::archetypes::Outlines>::is_pod()
             && <crate::blueprint::archetypes::VisibleTimeRanges>::is_pod()
     }
 }
@@ -0,0 +1,66 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/views/spectrogram.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::allow_attributes)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+#![allow(clippy::wildcard_imports)]
+
+use ::re_types_core::SerializationResult;
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **View**: A spectrogram view for scalars over time, for use with [`archetypes::Scalars`][crate::archetypes::Scalars].
+///
+/// Shows the rolling FFT of each scalar series as a time-frequency heatmap,
+/// e.g. to analyze vibrations picked up by an IMU or audio.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug)]
+pub struct SpectrogramView {
+    /// Configures the windows of the rolling FFT.
+    pub fft_window: crate::blueprint::archetypes::FftWindow,
+
+    /// Configures how magnitudes are mapped to color.
+    pub scalar_mapping: crate::blueprint::archetypes::TensorScalarMapping,
+
+    /// Configures which range on each timeline is shown by this view (unless specified differently per entity).
+    ///
+    /// If not specified, the default is to show the entire timeline.
+    /// If a timeline is specified more than once, the first entry will be used.
+    pub time_ranges: crate::blueprint::archetypes::VisibleTimeRanges,
+}
+
+impl ::re_types_core::View for SpectrogramView {
+    #[inline]
+    fn identifier() -> ::re_types_core::ViewClassIdentifier {
+        "Spectrogram".into()
+    }
+}
+
+impl ::re_byte_size::SizeBytes for SpectrogramView {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.fft_window.heap_size_bytes()
+            + self.scalar_mapping.heap_size_bytes()
+            + self.time_ranges.heap_size_bytes()
+    }
+
+    #[inline]
+    fn is_pod() -> bool {
+        <crate::blueprint::archetypes::FftWindow>::is_pod()
+            && <crate::blueprint::archetypes::TensorScalarMapping>::is_pod()
+            && <crate::blueprint::archetypes::VisibleTimeRanges>::is_pod()
+    }
+}
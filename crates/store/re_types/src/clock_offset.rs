@@ -0,0 +1,117 @@
+//! Clock offsets between the data sources of a recording.
+
+use std::sync::Arc;
+
+use arrow::array::Int64Array;
+use re_log_types::EntityPath;
+use re_types_core::{AsComponents, SerializedComponentBatch};
+
+use crate::{AnyValues, components};
+
+/// How far the clock of a data source was off from the reference clock of the recording,
+/// e.g. as measured by PTP or NTP on the machine that produced the data.
+///
+/// A data source is an entity subtree, e.g. `/robot_a` for everything logged by a robot.
+/// Clock offsets are logged as recording properties, under `/__properties/clock_offsets/<source>`,
+/// and the viewer can use them to re-base the timestamp timelines of each source.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClockOffset {
+    /// Add this to the timestamps of the source to get the reference time, in nanoseconds.
+    ///
+    /// Positive if the clock of the source is behind.
+    pub offset_ns: i64,
+
+    /// How the offset was measured, e.g. `ptp` or `ntp`.
+    pub method: Option<String>,
+
+    /// How far off the offset itself may be, in nanoseconds.
+    pub uncertainty_ns: Option<i64>,
+}
+
+impl ClockOffset {
+    pub const OFFSET_NS: &'static str = "offset_ns";
+    pub const METHOD: &'static str = "method";
+    pub const UNCERTAINTY_NS: &'static str = "uncertainty_ns";
+
+    #[inline]
+    pub fn new(offset_ns: i64) -> Self {
+        Self {
+            offset_ns,
+            method: None,
+            uncertainty_ns: None,
+        }
+    }
+
+    #[inline]
+    pub fn with_method(mut self, method: impl Into<String>) -> Self {
+        self.method = Some(method.into());
+        self
+    }
+
+    #[inline]
+    pub fn with_uncertainty_ns(mut self, uncertainty_ns: i64) -> Self {
+        self.uncertainty_ns = Some(uncertainty_ns);
+        self
+    }
+
+    /// The entity all clock offsets of a recording are logged under.
+    pub fn properties_path() -> EntityPath {
+        EntityPath::properties() / "clock_offsets"
+    }
+
+    /// Where the clock offset of the given source is logged.
+    pub fn property_path(source: &EntityPath) -> EntityPath {
+        Self::properties_path().join(source)
+    }
+
+    /// The source whose clock offset is logged at `property_path`, if any.
+    pub fn source_of(property_path: &EntityPath) -> Option<EntityPath> {
+        let prefix = Self::properties_path();
+        property_path
+            .starts_with(&prefix)
+            .then(|| EntityPath::new(property_path.iter().skip(prefix.len()).cloned().collect()))
+    }
+}
+
+impl AsComponents for ClockOffset {
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        let Self {
+            offset_ns,
+            method,
+            uncertainty_ns,
+        } = self;
+
+        let mut values = AnyValues::default().with_component_from_data(
+            Self::OFFSET_NS,
+            Arc::new(Int64Array::from(vec![*offset_ns])),
+        );
+        if let Some(method) = method {
+            values = values.with_component::<components::Text>(Self::METHOD, [method.as_str()]);
+        }
+        if let Some(uncertainty_ns) = uncertainty_ns {
+            values = values.with_component_from_data(
+                Self::UNCERTAINTY_NS,
+                Arc::new(Int64Array::from(vec![*uncertainty_ns])),
+            );
+        }
+
+        values.as_serialized_batches()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn property_path_roundtrip() {
+        for source in ["/", "/robot_a", "/fleet/robot_b"] {
+            let source = EntityPath::from(source);
+            let property_path = ClockOffset::property_path(&source);
+            assert!(property_path.is_reserved());
+            assert_eq!(ClockOffset::source_of(&property_path), Some(source));
+        }
+
+        assert_eq!(ClockOffset::source_of(&EntityPath::from("/robot_a")), None);
+    }
+}
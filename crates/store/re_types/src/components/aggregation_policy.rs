@@ -50,6 +50,12 @@ pub enum AggregationPolicy {
 
     /// Find both the minimum and maximum values in the range, then use the average of those.
     MinMaxAverage = 6,
+
+    /// Draw a band from the minimum to the maximum values in the range, with a line through their average.
+    ///
+    /// The aggregates are kept up to date as the data arrives rather than computed from every point on every frame,
+    /// which keeps live plots of scalars logged at high rates smooth.
+    MinMeanMax = 7,
 }
 
 impl ::re_types_core::Component for AggregationPolicy {
@@ -127,6 +133,7 @@ impl ::re_types_core::Loggable for AggregationPolicy {
                 Some(4) => Ok(Some(Self::Min)),
                 Some(5) => Ok(Some(Self::MinMax)),
                 Some(6) => Ok(Some(Self::MinMaxAverage)),
+                Some(7) => Ok(Some(Self::MinMeanMax)),
                 None => Ok(None),
                 Some(invalid) => Err(DeserializationError::missing_union_arm(
                     Self::arrow_datatype(),
@@ -148,6 +155,7 @@ impl std::fmt::Display for AggregationPolicy {
             Self::Min => write!(f, "Min"),
             Self::MinMax => write!(f, "MinMax"),
             Self::MinMaxAverage => write!(f, "MinMaxAverage"),
+            Self::MinMeanMax => write!(f, "MinMeanMax"),
         }
     }
 }
@@ -162,6 +170,7 @@ impl ::re_types_core::reflection::Enum for AggregationPolicy {
             Self::Min,
             Self::MinMax,
             Self::MinMaxAverage,
+            Self::MinMeanMax,
         ]
     }
 
@@ -178,6 +187,9 @@ impl ::re_types_core::reflection::Enum for AggregationPolicy {
             Self::MinMaxAverage => {
                 "Find both the minimum and maximum values in the range, then use the average of those."
             }
+            Self::MinMeanMax => {
+                "Draw a band from the minimum to the maximum values in the range, with a line through their average.\n\nThe aggregates are kept up to date as the data arrives rather than computed from every point on every frame,\nwhich keeps live plots of scalars logged at high rates smooth."
+            }
         }
     }
 }
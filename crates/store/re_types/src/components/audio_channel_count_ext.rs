@@ -0,0 +1,8 @@
+use super::AudioChannelCount;
+
+impl Default for AudioChannelCount {
+    #[inline]
+    fn default() -> Self {
+        Self(crate::datatypes::UInt32(1))
+    }
+}
@@ -0,0 +1,12 @@
+use super::AudioSampleFormat;
+
+impl AudioSampleFormat {
+    /// The size of a single sample in bytes.
+    #[inline]
+    pub fn bytes_per_sample(self) -> usize {
+        match self {
+            Self::I16 => 2,
+            Self::F32 | Self::I32 => 4,
+        }
+    }
+}
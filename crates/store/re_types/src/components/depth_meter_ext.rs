@@ -1,8 +1,19 @@
 use super::DepthMeter;
 
+impl DepthMeter {
+    /// Depth values are in meters.
+    pub const METERS: Self = Self(crate::datatypes::Float32(1.0));
+
+    /// Depth values are in centimeters.
+    pub const CENTIMETERS: Self = Self(crate::datatypes::Float32(100.0));
+
+    /// Depth values are in millimeters, as produced by most depth cameras with `u16` depth images.
+    pub const MILLIMETERS: Self = Self(crate::datatypes::Float32(1000.0));
+}
+
 impl Default for DepthMeter {
     #[inline]
     fn default() -> Self {
-        Self(1.0.into()) // 1 unit == 1 meter.
+        Self::METERS // 1 unit == 1 meter.
     }
 }
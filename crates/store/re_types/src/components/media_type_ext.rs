@@ -53,6 +53,12 @@ impl MediaType {
     ///
     /// <https://www.iana.org/assignments/media-types/video/mp4>
     pub const MP4: &'static str = "video/mp4";
+
+    // -------------------------------------------------------
+    // Audio:
+
+    /// [WAV audio](https://en.wikipedia.org/wiki/WAV): `audio/wav`.
+    pub const WAV: &'static str = "audio/wav";
 }
 
 impl MediaType {
@@ -118,6 +124,15 @@ impl MediaType {
     pub fn mp4() -> Self {
         Self(Self::MP4.into())
     }
+
+    // -------------------------------------------------------
+    // Audio:
+
+    /// `audio/wav`
+    #[inline]
+    pub fn wav() -> Self {
+        Self(Self::WAV.into())
+    }
 }
 
 impl MediaType {
@@ -233,6 +248,11 @@ impl MediaType {
     pub fn is_video(&self) -> bool {
         self.as_str().starts_with("video/")
     }
+
+    /// Returns `true` if this is an audio media type.
+    pub fn is_audio(&self) -> bool {
+        self.as_str().starts_with("audio/")
+    }
 }
 
 impl std::fmt::Display for MediaType {
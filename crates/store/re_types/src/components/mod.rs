@@ -4,6 +4,10 @@ mod aggregation_policy;
 mod albedo_factor;
 mod albedo_factor_ext;
 mod annotation_context;
+mod audio_channel_count;
+mod audio_channel_count_ext;
+mod audio_sample_format;
+mod audio_sample_format_ext;
 mod axis_length;
 mod axis_length_ext;
 mod blob;
@@ -45,6 +49,7 @@ mod image_plane_distance;
 mod image_plane_distance_ext;
 mod interactive;
 mod interactive_ext;
+mod joint_parent;
 mod key_value_pairs;
 mod keypoint_id;
 mod keypoint_id_ext;
@@ -96,6 +101,8 @@ mod rotation_axis_angle;
 mod rotation_axis_angle_ext;
 mod rotation_quat;
 mod rotation_quat_ext;
+mod sample_rate;
+mod sample_rate_ext;
 mod scalar;
 mod scalar_ext;
 mod scale3d;
@@ -104,6 +111,7 @@ mod schema_id;
 mod series_visible;
 mod show_labels;
 mod show_labels_ext;
+mod spherical_harmonics;
 mod stroke_width;
 mod stroke_width_ext;
 mod tensor_data;
@@ -128,6 +136,8 @@ mod translation3d;
 mod translation3d_ext;
 mod triangle_indices;
 mod triangle_indices_ext;
+mod unit;
+mod unit_ext;
 mod value_range;
 mod value_range_ext;
 mod vector2d;
@@ -146,6 +156,8 @@ mod visible_ext;
 pub use self::aggregation_policy::AggregationPolicy;
 pub use self::albedo_factor::AlbedoFactor;
 pub use self::annotation_context::AnnotationContext;
+pub use self::audio_channel_count::AudioChannelCount;
+pub use self::audio_sample_format::AudioSampleFormat;
 pub use self::axis_length::AxisLength;
 pub use self::blob::Blob;
 pub use self::channel_id::ChannelId;
@@ -170,6 +182,7 @@ pub use self::image_buffer::ImageBuffer;
 pub use self::image_format::ImageFormat;
 pub use self::image_plane_distance::ImagePlaneDistance;
 pub use self::interactive::Interactive;
+pub use self::joint_parent::JointParent;
 pub use self::key_value_pairs::KeyValuePairs;
 pub use self::keypoint_id::KeypointId;
 pub use self::lat_lon::LatLon;
@@ -197,11 +210,13 @@ pub use self::range1d::Range1D;
 pub use self::resolution::Resolution;
 pub use self::rotation_axis_angle::RotationAxisAngle;
 pub use self::rotation_quat::RotationQuat;
+pub use self::sample_rate::SampleRate;
 pub use self::scalar::Scalar;
 pub use self::scale3d::Scale3D;
 pub use self::schema_id::SchemaId;
 pub use self::series_visible::SeriesVisible;
 pub use self::show_labels::ShowLabels;
+pub use self::spherical_harmonics::SphericalHarmonics;
 pub use self::stroke_width::StrokeWidth;
 pub use self::tensor_data::TensorData;
 pub use self::tensor_dimension_index_selection::TensorDimensionIndexSelection;
@@ -216,6 +231,7 @@ pub use self::transform_mat3x3::TransformMat3x3;
 pub use self::transform_relation::TransformRelation;
 pub use self::translation3d::Translation3D;
 pub use self::triangle_indices::TriangleIndices;
+pub use self::unit::Unit;
 pub use self::value_range::ValueRange;
 pub use self::vector2d::Vector2D;
 pub use self::vector3d::Vector3D;
@@ -0,0 +1,9 @@
+use super::SampleRate;
+
+impl SampleRate {
+    /// The sample rate in Hz.
+    #[inline]
+    pub fn hz(self) -> u32 {
+        self.0.0
+    }
+}
@@ -0,0 +1,264 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/components/spherical_harmonics.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::allow_attributes)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+#![allow(clippy::wildcard_imports)]
+
+use ::re_types_core::SerializationResult;
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Component**: The view dependent color of a Gaussian splat, as coefficients of real spherical harmonics.
+///
+/// Holds the RGB coefficients of all degrees above zero, i.e. 3, 8 or 15 coefficients for degree 1, 2 or 3.
+/// They are ordered by degree first and by order second, the same way as the `f_rest` properties of most
+/// Gaussian splatting reconstructions, after transposing those from channel major to coefficient major order.
+/// The zeroth degree is given by the color of the splat.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SphericalHarmonics(pub Vec<crate::datatypes::Vec3D>);
+
+impl ::re_types_core::Component for SphericalHarmonics {
+    #[inline]
+    fn name() -> ComponentType {
+        "rerun.components.SphericalHarmonics".into()
+    }
+}
+
+::re_types_core::macros::impl_into_cow!(SphericalHarmonics);
+
+impl ::re_types_core::Loggable for SphericalHarmonics {
+    #[inline]
+    fn arrow_datatype() -> arrow::datatypes::DataType {
+        use arrow::datatypes::*;
+        DataType::List(std::sync::Arc::new(Field::new(
+            "item",
+            <crate::datatypes::Vec3D>::arrow_datatype(),
+            false,
+        )))
+    }
+
+    fn to_arrow_opt<'a>(
+        data: impl IntoIterator<Item = Option<impl Into<::std::borrow::Cow<'a, Self>>>>,
+    ) -> SerializationResult<arrow::array::ArrayRef>
+    where
+        Self: Clone + 'a,
+    {
+        #![allow(clippy::manual_is_variant_and)]
+        use ::re_types_core::{Loggable as _, ResultExt as _, arrow_helpers::as_array_ref};
+        use arrow::{array::*, buffer::*, datatypes::*};
+        Ok({
+            let (somes, data0): (Vec<_>, Vec<_>) = data
+                .into_iter()
+                .map(|datum| {
+                    let datum: Option<::std::borrow::Cow<'a, Self>> = datum.map(Into::into);
+                    let datum = datum.map(|datum| datum.into_owned().0);
+                    (datum.is_some(), datum)
+                })
+                .unzip();
+            let data0_validity: Option<arrow::buffer::NullBuffer> = {
+                let any_nones = somes.iter().any(|some| !*some);
+                any_nones.then(|| somes.into())
+            };
+            {
+                let offsets = arrow::buffer::OffsetBuffer::<i32>::from_lengths(
+                    data0
+                        .iter()
+                        .map(|opt| opt.as_ref().map_or(0, |datum| datum.len())),
+                );
+                let data0_inner_data: Vec<_> = data0.into_iter().flatten().flatten().collect();
+                let data0_inner_validity: Option<arrow::buffer::NullBuffer> = None;
+                as_array_ref(ListArray::try_new(
+                    std::sync::Arc::new(Field::new(
+                        "item",
+                        <crate::datatypes::Vec3D>::arrow_datatype(),
+                        false,
+                    )),
+                    offsets,
+                    {
+                        let data0_inner_data_inner_data: Vec<_> = data0_inner_data
+                            .into_iter()
+                            .map(|datum| datum.0)
+                            .flatten()
+                            .collect();
+                        let data0_inner_data_inner_validity: Option<arrow::buffer::NullBuffer> =
+                            None;
+                        as_array_ref(FixedSizeListArray::new(
+                            std::sync::Arc::new(Field::new("item", DataType::Float32, false)),
+                            3,
+                            as_array_ref(PrimitiveArray::<Float32Type>::new(
+                                ScalarBuffer::from(
+                                    data0_inner_data_inner_data.into_iter().collect::<Vec<_>>(),
+                                ),
+                                data0_inner_data_inner_validity,
+                            )),
+                            data0_inner_validity,
+                        ))
+                    },
+                    data0_validity,
+                )?)
+            }
+        })
+    }
+
+    fn from_arrow_opt(
+        arrow_data: &dyn arrow::array::Array,
+    ) -> DeserializationResult<Vec<Option<Self>>>
+    where
+        Self: Sized,
+    {
+        use ::re_types_core::{Loggable as _, ResultExt as _, arrow_zip_validity::ZipValidity};
+        use arrow::{array::*, buffer::*, datatypes::*};
+        Ok({
+            let arrow_data = arrow_data
+                .as_any()
+                .downcast_ref::<arrow::array::ListArray>()
+                .ok_or_else(|| {
+                    let expected = Self::arrow_datatype();
+                    let actual = arrow_data.data_type().clone();
+                    DeserializationError::datatype_mismatch(expected, actual)
+                })
+                .with_context("rerun.components.SphericalHarmonics#coefficients")?;
+            if arrow_data.is_empty() {
+                Vec::new()
+            } else {
+                let arrow_data_inner = {
+                    let arrow_data_inner = &**arrow_data.values();
+                    {
+                        let arrow_data_inner = arrow_data_inner
+                            .as_any()
+                            .downcast_ref::<arrow::array::FixedSizeListArray>()
+                            .ok_or_else(|| {
+                                let expected = DataType::FixedSizeList(
+                                    std::sync::Arc::new(Field::new(
+                                        "item",
+                                        DataType::Float32,
+                                        false,
+                                    )),
+                                    3,
+                                );
+                                let actual = arrow_data_inner.data_type().clone();
+                                DeserializationError::datatype_mismatch(expected, actual)
+                            })
+                            .with_context("rerun.components.SphericalHarmonics#coefficients")?;
+                        if arrow_data_inner.is_empty() {
+                            Vec::new()
+                        } else {
+                            let offsets = (0..)
+                                .step_by(3usize)
+                                .zip((3usize..).step_by(3usize).take(arrow_data_inner.len()));
+                            let arrow_data_inner_inner = {
+                                let arrow_data_inner_inner = &**arrow_data_inner.values();
+                                arrow_data_inner_inner
+                                    .as_any()
+                                    .downcast_ref::<Float32Array>()
+                                    .ok_or_else(|| {
+                                        let expected = DataType::Float32;
+                                        let actual = arrow_data_inner_inner.data_type().clone();
+                                        DeserializationError::datatype_mismatch(expected, actual)
+                                    })
+                                    .with_context(
+                                        "rerun.components.SphericalHarmonics#coefficients",
+                                    )?
+                                    .into_iter()
+                                    .collect::<Vec<_>>()
+                            };
+                            ZipValidity::new_with_validity(offsets, arrow_data_inner.nulls())
+                                .map(|elem| {
+                                    elem.map(|(start, end): (usize, usize)| {
+                                        debug_assert!(end - start == 3usize);
+                                        if arrow_data_inner_inner.len() < end {
+                                            return Err(DeserializationError::offset_slice_oob(
+                                                (start, end),
+                                                arrow_data_inner_inner.len(),
+                                            ));
+                                        }
+
+                                        #[expect(unsafe_code, clippy::undocumented_unsafe_blocks)]
+                                        let data = unsafe {
+                                            arrow_data_inner_inner.get_unchecked(start..end)
+                                        };
+                                        let data =
+                                            data.iter().cloned().map(Option::unwrap_or_default);
+
+                                        // NOTE: Unwrapping cannot fail: the length must be correct.
+                                        #[expect(clippy::unwrap_used)]
+                                        Ok(array_init::from_iter(data).unwrap())
+                                    })
+                                    .transpose()
+                                })
+                                .map(|res_or_opt| {
+                                    res_or_opt
+                                        .map(|res_or_opt| res_or_opt.map(crate::datatypes::Vec3D))
+                                })
+                                .collect::<DeserializationResult<Vec<Option<_>>>>()?
+                        }
+                        .into_iter()
+                    }
+                    .collect::<Vec<_>>()
+                };
+                let offsets = arrow_data.offsets();
+                ZipValidity::new_with_validity(offsets.windows(2), arrow_data.nulls())
+                    .map(|elem| {
+                        elem.map(|window| {
+                            let start = window[0] as usize;
+                            let end = window[1] as usize;
+                            if arrow_data_inner.len() < end {
+                                return Err(DeserializationError::offset_slice_oob(
+                                    (start, end),
+                                    arrow_data_inner.len(),
+                                ));
+                            }
+
+                            #[expect(unsafe_code, clippy::undocumented_unsafe_blocks)]
+                            let data = unsafe { arrow_data_inner.get_unchecked(start..end) };
+                            let data = data
+                                .iter()
+                                .cloned()
+                                .map(Option::unwrap_or_default)
+                                .collect();
+                            Ok(data)
+                        })
+                        .transpose()
+                    })
+                    .collect::<DeserializationResult<Vec<Option<_>>>>()?
+            }
+            .into_iter()
+        }
+        .map(|v| v.ok_or_else(DeserializationError::missing_data))
+        .map(|res| res.map(|v| Some(Self(v))))
+        .collect::<DeserializationResult<Vec<Option<_>>>>()
+        .with_context("rerun.components.SphericalHarmonics#coefficients")
+        .with_context("rerun.components.SphericalHarmonics")?)
+    }
+}
+
+impl<I: Into<crate::datatypes::Vec3D>, T: IntoIterator<Item = I>> From<T> for SphericalHarmonics {
+    fn from(v: T) -> Self {
+        Self(v.into_iter().map(|v| v.into()).collect())
+    }
+}
+
+impl ::re_byte_size::SizeBytes for SphericalHarmonics {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.0.heap_size_bytes()
+    }
+
+    #[inline]
+    fn is_pod() -> bool {
+        <Vec<crate::datatypes::Vec3D>>::is_pod()
+    }
+}
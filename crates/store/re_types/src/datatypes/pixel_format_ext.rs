@@ -151,8 +151,10 @@ impl PixelFormat {
             Self::NV12 => {
                 let uv_offset = w * h;
                 let luma = *buf.get((y * w + x) as usize)?;
-                let u = *buf.get((uv_offset + (y / 2) * w + x) as usize)?;
-                let v = *buf.get((uv_offset + (y / 2) * w + x) as usize + 1)?;
+                // U and V are interleaved, and shared by two horizontally adjacent pixels.
+                let uv_index = (uv_offset + (y / 2) * w + (x & !1)) as usize;
+                let u = *buf.get(uv_index)?;
+                let v = *buf.get(uv_index + 1)?;
                 Some([luma, u, v])
             }
 
@@ -174,6 +174,203 @@ pub fn cast_to_u8<T: arrow::datatypes::ArrowNativeType>(
 
 // ----------------------------------------------------------------------------
 
+/// Pixel formats commonly produced by camera capture APIs such as V4L2 or GenICam.
+///
+/// See [`archetypes::Image::from_raw_parts`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CameraPixelFormat {
+    /// 8-bit grayscale.
+    L8,
+
+    /// 8-bit RGB, interleaved as `RGBRGBRGB`.
+    Rgb8,
+
+    /// 8-bit BGR, interleaved as `BGRBGRBGR`.
+    Bgr8,
+
+    /// 4:2:2 YUV, interleaved as `YUYV` (also known as `YUY2`).
+    ///
+    /// Requires an even width.
+    Yuyv,
+
+    /// 4:2:0 YUV, with a full-resolution Y plane followed by an interleaved `UV` plane.
+    ///
+    /// Both planes use the same row stride.
+    /// Requires an even width and height.
+    Nv12,
+
+    /// Raw 8-bit sensor data behind a Bayer filter with an `RGGB` pattern.
+    ///
+    /// Requires an even width and height.
+    BayerRg8,
+}
+
+impl CameraPixelFormat {
+    /// Number of bytes in a row of `width` pixels, without any padding.
+    pub fn packed_row_bytes(&self, width: u32) -> usize {
+        let width = width as usize;
+        match self {
+            Self::L8 | Self::Nv12 | Self::BayerRg8 => width,
+            Self::Yuyv => 2 * width,
+            Self::Rgb8 | Self::Bgr8 => 3 * width,
+        }
+    }
+
+    /// Number of rows in an image of the given height, including any chroma plane.
+    pub fn num_rows(&self, height: u32) -> usize {
+        let height = height as usize;
+        match self {
+            Self::Nv12 => height + height / 2,
+            Self::L8 | Self::Rgb8 | Self::Bgr8 | Self::Yuyv | Self::BayerRg8 => height,
+        }
+    }
+
+    fn needs_even_width(&self) -> bool {
+        matches!(self, Self::Yuyv | Self::Nv12 | Self::BayerRg8)
+    }
+
+    fn needs_even_height(&self) -> bool {
+        matches!(self, Self::Nv12 | Self::BayerRg8)
+    }
+}
+
+/// Memory layout of an image in a capture buffer.
+///
+/// See [`archetypes::Image::from_raw_parts`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RawImageLayout {
+    /// Width and height in pixels.
+    pub resolution: [u32; 2],
+
+    /// Number of bytes from the start of one row to the start of the next.
+    ///
+    /// Capture APIs often pad rows, so this may be larger than
+    /// [`CameraPixelFormat::packed_row_bytes`].
+    pub row_stride: usize,
+
+    /// How the pixels are encoded.
+    pub pixel_format: CameraPixelFormat,
+}
+
+impl RawImageLayout {
+    /// A layout without any row padding.
+    pub fn packed(resolution: [u32; 2], pixel_format: CameraPixelFormat) -> Self {
+        Self {
+            resolution,
+            row_stride: pixel_format.packed_row_bytes(resolution[0]),
+            pixel_format,
+        }
+    }
+
+    /// Whether there is no padding between rows.
+    pub fn is_packed(&self) -> bool {
+        self.row_stride == self.pixel_format.packed_row_bytes(self.resolution[0])
+    }
+
+    /// The minimum number of bytes a buffer with this layout must have.
+    ///
+    /// The last row does not need to be padded.
+    pub fn min_num_bytes(&self) -> usize {
+        let num_rows = self.pixel_format.num_rows(self.resolution[1]);
+        if num_rows == 0 {
+            0
+        } else {
+            (num_rows - 1) * self.row_stride
+                + self.pixel_format.packed_row_bytes(self.resolution[0])
+        }
+    }
+
+    /// Checks that a buffer of `num_bytes` bytes can hold an image with this layout.
+    pub fn validate(&self, num_bytes: usize) -> Result<(), RawImageError> {
+        let Self {
+            resolution: [width, height],
+            row_stride,
+            pixel_format,
+        } = *self;
+
+        if (pixel_format.needs_even_width() && width % 2 != 0)
+            || (pixel_format.needs_even_height() && height % 2 != 0)
+        {
+            return Err(RawImageError::OddResolution {
+                pixel_format,
+                resolution: self.resolution,
+            });
+        }
+
+        let packed_row_bytes = pixel_format.packed_row_bytes(width);
+        if row_stride < packed_row_bytes {
+            return Err(RawImageError::StrideTooSmall {
+                row_stride,
+                packed_row_bytes,
+            });
+        }
+
+        let expected = self.min_num_bytes();
+        if num_bytes < expected {
+            return Err(RawImageError::BufferTooSmall {
+                expected,
+                actual: num_bytes,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// What to do with pixel formats when creating an image from a capture buffer.
+///
+/// See [`archetypes::Image::from_raw_parts`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RawImageConversion {
+    /// Log the pixels as they are, without converting them.
+    ///
+    /// All formats except [`CameraPixelFormat::BayerRg8`] are supported by the viewer directly.
+    /// Bayer data is logged as a grayscale image of the raw sensor values.
+    #[default]
+    Passthrough,
+
+    /// Convert the pixels to 8-bit RGB on the CPU.
+    ///
+    /// Uses a simple and fast demosaicing for Bayer data, at the cost of some sharpness.
+    ToRgb,
+}
+
+/// Error returned when a capture buffer doesn't match its [`RawImageLayout`].
+#[derive(thiserror::Error, Clone, Debug, PartialEq, Eq)]
+pub enum RawImageError {
+    /// The pixel format needs an even resolution, e.g. due to chroma subsampling.
+    #[error("{pixel_format:?} images need an even resolution, got {resolution:?}")]
+    OddResolution {
+        /// The pixel format.
+        pixel_format: CameraPixelFormat,
+
+        /// The resolution of the image.
+        resolution: [u32; 2],
+    },
+
+    /// The row stride is smaller than a row of pixels.
+    #[error("Row stride of {row_stride} B is smaller than a row of pixels ({packed_row_bytes} B)")]
+    StrideTooSmall {
+        /// The given row stride.
+        row_stride: usize,
+
+        /// The number of bytes in a row of pixels.
+        packed_row_bytes: usize,
+    },
+
+    /// The buffer is too small for the layout.
+    #[error("Expected at least {expected} B of image data, but got {actual} B")]
+    BufferTooSmall {
+        /// The minimum number of bytes needed.
+        expected: usize,
+
+        /// The size of the buffer.
+        actual: usize,
+    },
+}
+
+// ----------------------------------------------------------------------------
+
 /// Types that implement this can be used as image channel types.
 ///
 /// Implemented for `u8, u16, u32, u64, i8, i16, i32, i64, f16, f32, f64`.
@@ -304,6 +304,9 @@ pub mod view_coordinates;
 pub mod any_values;
 pub use any_values::AnyValues;
 
+pub mod clock_offset;
+pub use clock_offset::ClockOffset;
+
 pub mod dynamic_archetype;
 pub use dynamic_archetype::DynamicArchetype;
 
@@ -107,6 +107,16 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: BackgroundKind::verify_arrow_array,
             },
         ),
+        (
+            <BarChartMode as Component>::name(),
+            ComponentReflection {
+                docstring_md: "How the bars of several bar charts in the same view are arranged.",
+                deprecation_summary: None,
+                custom_placeholder: Some(BarChartMode::default().to_arrow()?),
+                datatype: BarChartMode::arrow_datatype(),
+                verify_arrow_array: BarChartMode::verify_arrow_array,
+            },
+        ),
         (
             <ColumnShare as Component>::name(),
             ComponentReflection {
@@ -147,6 +157,16 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: Corner2D::verify_arrow_array,
             },
         ),
+        (
+            <DepthCloudSubsampling as Component>::name(),
+            ComponentReflection {
+                docstring_md: "Only every n-th pixel of a depth image, in both directions, is turned into a point of its point cloud.\n\n⚠\u{fe0f} **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**",
+                deprecation_summary: None,
+                custom_placeholder: Some(DepthCloudSubsampling::default().to_arrow()?),
+                datatype: DepthCloudSubsampling::arrow_datatype(),
+                verify_arrow_array: DepthCloudSubsampling::verify_arrow_array,
+            },
+        ),
         (
             <Enabled as Component>::name(),
             ComponentReflection {
@@ -167,6 +187,36 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: Eye3DKind::verify_arrow_array,
             },
         ),
+        (
+            <Eye3DProjection as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The projection of the 3D eye to view a scene in a [`views.Spatial3DView`](https://rerun.io/docs/reference/types/views/spatial3d_view).",
+                deprecation_summary: None,
+                custom_placeholder: Some(Eye3DProjection::default().to_arrow()?),
+                datatype: Eye3DProjection::arrow_datatype(),
+                verify_arrow_array: Eye3DProjection::verify_arrow_array,
+            },
+        ),
+        (
+            <FftWindowOverlap as Component>::name(),
+            ComponentReflection {
+                docstring_md: "How much consecutive windows of a rolling FFT overlap, e.g. of a `SpectrogramView`.\n\n⚠\u{fe0f} **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**",
+                deprecation_summary: None,
+                custom_placeholder: Some(FftWindowOverlap::default().to_arrow()?),
+                datatype: FftWindowOverlap::arrow_datatype(),
+                verify_arrow_array: FftWindowOverlap::verify_arrow_array,
+            },
+        ),
+        (
+            <FftWindowSize as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The number of samples in each window of a rolling FFT, e.g. of a `SpectrogramView`.\n\n⚠\u{fe0f} **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**",
+                deprecation_summary: None,
+                custom_placeholder: Some(FftWindowSize::default().to_arrow()?),
+                datatype: FftWindowSize::arrow_datatype(),
+                verify_arrow_array: FftWindowSize::verify_arrow_array,
+            },
+        ),
         (
             <FilterByRange as Component>::name(),
             ComponentReflection {
@@ -307,6 +357,16 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: NearClipPlane::verify_arrow_array,
             },
         ),
+        (
+            <OnionSkinSteps as Component>::name(),
+            ComponentReflection {
+                docstring_md: "How many earlier states of each entity are shown by the onion skin of a view.\n\n⚠\u{fe0f} **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**",
+                deprecation_summary: None,
+                custom_placeholder: Some(OnionSkinSteps::default().to_arrow()?),
+                datatype: OnionSkinSteps::arrow_datatype(),
+                verify_arrow_array: OnionSkinSteps::verify_arrow_array,
+            },
+        ),
         (
             <PanelState as Component>::name(),
             ComponentReflection {
@@ -537,6 +597,26 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: AnnotationContext::verify_arrow_array,
             },
         ),
+        (
+            <AudioChannelCount as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The number of interleaved channels of [`archetypes.Audio`](https://rerun.io/docs/reference/types/archetypes/audio), e.g. 2 for stereo.\n\n⚠\u{fe0f} **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**",
+                deprecation_summary: None,
+                custom_placeholder: Some(AudioChannelCount::default().to_arrow()?),
+                datatype: AudioChannelCount::arrow_datatype(),
+                verify_arrow_array: AudioChannelCount::verify_arrow_array,
+            },
+        ),
+        (
+            <AudioSampleFormat as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The data type of raw PCM samples of [`archetypes.Audio`](https://rerun.io/docs/reference/types/archetypes/audio).\n\nAll formats are little-endian.\n\n⚠\u{fe0f} **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**",
+                deprecation_summary: None,
+                custom_placeholder: Some(AudioSampleFormat::default().to_arrow()?),
+                datatype: AudioSampleFormat::arrow_datatype(),
+                verify_arrow_array: AudioSampleFormat::verify_arrow_array,
+            },
+        ),
         (
             <AxisLength as Component>::name(),
             ComponentReflection {
@@ -787,6 +867,16 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: Interactive::verify_arrow_array,
             },
         ),
+        (
+            <JointParent as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The index of the parent of a joint within a [`archetypes.Skeleton`](https://rerun.io/docs/reference/types/archetypes/skeleton).\n\nRoot joints, i.e. joints without a parent, reference themselves.\n\n⚠\u{fe0f} **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**",
+                deprecation_summary: None,
+                custom_placeholder: None,
+                datatype: JointParent::arrow_datatype(),
+                verify_arrow_array: JointParent::verify_arrow_array,
+            },
+        ),
         (
             <KeyValuePairs as Component>::name(),
             ComponentReflection {
@@ -1057,6 +1147,16 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: RotationQuat::verify_arrow_array,
             },
         ),
+        (
+            <SampleRate as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The number of samples per second of a signal, e.g. of [`archetypes.Audio`](https://rerun.io/docs/reference/types/archetypes/audio), in Hz.\n\n⚠\u{fe0f} **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**",
+                deprecation_summary: None,
+                custom_placeholder: None,
+                datatype: SampleRate::arrow_datatype(),
+                verify_arrow_array: SampleRate::verify_arrow_array,
+            },
+        ),
         (
             <Scalar as Component>::name(),
             ComponentReflection {
@@ -1107,6 +1207,16 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: ShowLabels::verify_arrow_array,
             },
         ),
+        (
+            <SphericalHarmonics as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The view dependent color of a Gaussian splat, as coefficients of real spherical harmonics.\n\nHolds the RGB coefficients of all degrees above zero, i.e. 3, 8 or 15 coefficients for degree 1, 2 or 3.\nThey are ordered by degree first and by order second, the same way as the `f_rest` properties of most\nGaussian splatting reconstructions, after transposing those from channel major to coefficient major order.\nThe zeroth degree is given by the color of the splat.",
+                deprecation_summary: None,
+                custom_placeholder: Some(SphericalHarmonics::default().to_arrow()?),
+                datatype: SphericalHarmonics::arrow_datatype(),
+                verify_arrow_array: SphericalHarmonics::verify_arrow_array,
+            },
+        ),
         (
             <StrokeWidth as Component>::name(),
             ComponentReflection {
@@ -1247,6 +1357,16 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: TriangleIndices::verify_arrow_array,
             },
         ),
+        (
+            <Unit as Component>::name(),
+            ComponentReflection {
+                docstring_md: "A unit of measurement, e.g. `m/s` or `rad`.\n\nUnits are free-form strings, but using SI symbols lets the viewer convert between them.\n\n⚠\u{fe0f} **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**",
+                deprecation_summary: None,
+                custom_placeholder: Some(Unit::default().to_arrow()?),
+                datatype: Unit::arrow_datatype(),
+                verify_arrow_array: Unit::verify_arrow_array,
+            },
+        ),
         (
             <ValueRange as Component>::name(),
             ComponentReflection {
@@ -1538,6 +1658,52 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.archetypes.Audio"),
+            ArchetypeReflection {
+                display_name: "Audio",
+                deprecation_summary: None,
+                scope: None,
+                view_types: &["AudioView"],
+                fields: vec![
+                    ArchetypeFieldReflection {
+                        name: "blob",
+                        display_name: "Blob",
+                        component_type: "rerun.components.Blob".into(),
+                        docstring_md: "The audio data.\n\nEither raw PCM samples, described by [`components.AudioSampleFormat`](https://rerun.io/docs/reference/types/components/audio_sample_format) and [`components.AudioChannelCount`](https://rerun.io/docs/reference/types/components/audio_channel_count),\nor an encoded audio file, see [`components.MediaType`](https://rerun.io/docs/reference/types/components/media_type).",
+                        is_required: true,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "sample_rate",
+                        display_name: "Sample rate",
+                        component_type: "rerun.components.SampleRate".into(),
+                        docstring_md: "The number of samples per second and channel.\n\nRequired for raw PCM samples, encoded audio files specify their own sample rate.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "media_type",
+                        display_name: "Media type",
+                        component_type: "rerun.components.MediaType".into(),
+                        docstring_md: "The Media Type of an encoded audio file.\n\nSupported values:\n* `audio/wav` (uncompressed PCM only)\n\nIf omitted, the blob holds raw PCM samples, unless the viewer recognizes an encoded audio file.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "sample_format",
+                        display_name: "Sample format",
+                        component_type: "rerun.components.AudioSampleFormat".into(),
+                        docstring_md: "The data type of the raw PCM samples.\n\nDefaults to 32-bit floats.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "channel_count",
+                        display_name: "Channel count",
+                        component_type: "rerun.components.AudioChannelCount".into(),
+                        docstring_md: "The number of interleaved channels of the raw PCM samples.\n\nDefaults to a single channel.",
+                        is_required: false,
+                    },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.archetypes.BarChart"),
             ArchetypeReflection {
@@ -1822,6 +1988,22 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 }],
             },
         ),
+        (
+            ArchetypeName::new("rerun.archetypes.ClockControl"),
+            ArchetypeReflection {
+                display_name: "Clock control",
+                deprecation_summary: None,
+                scope: None,
+                view_types: &[],
+                fields: vec![ArchetypeFieldReflection {
+                    name: "timeline",
+                    display_name: "Timeline",
+                    component_type: "rerun.components.Name".into(),
+                    docstring_md: "Name of the timeline whose time cursor is controlled.",
+                    is_required: true,
+                }],
+            },
+        ),
         (
             ArchetypeName::new("rerun.archetypes.CoordinateFrame"),
             ArchetypeReflection {
@@ -2106,6 +2288,66 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.archetypes.GaussianSplats3D"),
+            ArchetypeReflection {
+                display_name: "Gaussian splats 3D",
+                deprecation_summary: None,
+                scope: None,
+                view_types: &["Spatial3DView"],
+                fields: vec![
+                    ArchetypeFieldReflection {
+                        name: "centers",
+                        display_name: "Centers",
+                        component_type: "rerun.components.Position3D".into(),
+                        docstring_md: "The center of each splat.",
+                        is_required: true,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "quaternions",
+                        display_name: "Quaternions",
+                        component_type: "rerun.components.PoseRotationQuat".into(),
+                        docstring_md: "The orientation of each splat.\n\nIf no orientation is specified, the axes of the splats align with the axes of the local coordinate system.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "scales",
+                        display_name: "Scales",
+                        component_type: "rerun.components.PoseScale3D".into(),
+                        docstring_md: "The standard deviation of each splat along its three local axes.\n\nIf not specified, splats are isotropic with a standard deviation of 1% of the size of the bounding box of all centers.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "colors",
+                        display_name: "Colors",
+                        component_type: "rerun.components.Color".into(),
+                        docstring_md: "The color of each splat.\n\nThe alpha channel is multiplied with [`components.Opacity`](https://rerun.io/docs/reference/types/components/opacity).",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "opacities",
+                        display_name: "Opacities",
+                        component_type: "rerun.components.Opacity".into(),
+                        docstring_md: "The opacity at the center of each splat.\n\nDefaults to fully opaque.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "spherical_harmonics",
+                        display_name: "Spherical harmonics",
+                        component_type: "rerun.components.SphericalHarmonics".into(),
+                        docstring_md: "The view dependent part of the color of each splat.\n\nAll splats are expected to have the same number of coefficients.\nIf not specified, splats look the same from all directions.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "class_ids",
+                        display_name: "Class ids",
+                        component_type: "rerun.components.ClassId".into(),
+                        docstring_md: "Optional class IDs for the splats.\n\nThe class ID provides colors if not specified explicitly.",
+                        is_required: false,
+                    },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.archetypes.GeoLineStrings"),
             ArchetypeReflection {
@@ -2906,14 +3148,23 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 display_name: "Scalars",
                 deprecation_summary: None,
                 scope: None,
-                view_types: &["TimeSeriesView"],
-                fields: vec![ArchetypeFieldReflection {
-                    name: "scalars",
-                    display_name: "Scalars",
-                    component_type: "rerun.components.Scalar".into(),
-                    docstring_md: "The scalar values to log.",
-                    is_required: true,
-                }],
+                view_types: &["TimeSeriesView", "SpectrogramView"],
+                fields: vec![
+                    ArchetypeFieldReflection {
+                        name: "scalars",
+                        display_name: "Scalars",
+                        component_type: "rerun.components.Scalar".into(),
+                        docstring_md: "The scalar values to log.",
+                        is_required: true,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "unit",
+                        display_name: "Unit",
+                        component_type: "rerun.components.Unit".into(),
+                        docstring_md: "The unit the scalar values are logged in, e.g. `m/s` or `rad`.\n\nShown on the axes and tooltips of plots. Expected to be unchanging over time.",
+                        is_required: false,
+                    },
+                ],
             },
         ),
         (
@@ -2955,6 +3206,22 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.archetypes.Sensitive"),
+            ArchetypeReflection {
+                display_name: "Sensitive",
+                deprecation_summary: None,
+                scope: None,
+                view_types: &[],
+                fields: vec![ArchetypeFieldReflection {
+                    name: "tags",
+                    display_name: "Tags",
+                    component_type: "rerun.components.Text".into(),
+                    docstring_md: "What makes the data sensitive, e.g. `faces` or `license_plates`.\n\nLets exports redact some kinds of sensitive data but not others.",
+                    is_required: true,
+                }],
+            },
+        ),
         (
             ArchetypeName::new("rerun.archetypes.SeriesLines"),
             ArchetypeReflection {
@@ -2998,6 +3265,13 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                         docstring_md: "Configures the zoom-dependent scalar aggregation.\n\nThis is done only if steps on the X axis go below a single pixel,\ni.e. a single pixel covers more than one tick worth of data. It can greatly improve performance\n(and readability) in such situations as it prevents overdraw.\n\nExpected to be unchanging over time.",
                         is_required: false,
                     },
+                    ArchetypeFieldReflection {
+                        name: "display_unit",
+                        display_name: "Display unit",
+                        component_type: "rerun.components.Unit".into(),
+                        docstring_md: "The unit the series are shown in, e.g. `deg` or `km/h`.\n\nValues are converted from the unit of the [`archetypes.Scalars`](https://rerun.io/docs/reference/types/archetypes/scalars) if both are known units of the same quantity.\nIf not set, values are shown in the unit they were logged in.\n\nExpected to be unchanging over time.",
+                        is_required: false,
+                    },
                 ],
             },
         ),
@@ -3044,6 +3318,87 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                         docstring_md: "Sizes of the markers.\n\nMay change over time.",
                         is_required: false,
                     },
+                    ArchetypeFieldReflection {
+                        name: "display_unit",
+                        display_name: "Display unit",
+                        component_type: "rerun.components.Unit".into(),
+                        docstring_md: "The unit the series are shown in, e.g. `deg` or `km/h`.\n\nValues are converted from the unit of the [`archetypes.Scalars`](https://rerun.io/docs/reference/types/archetypes/scalars) if both are known units of the same quantity.\nIf not set, values are shown in the unit they were logged in.\n\nExpected to be unchanging over time.",
+                        is_required: false,
+                    },
+                ],
+            },
+        ),
+        (
+            ArchetypeName::new("rerun.archetypes.Skeleton"),
+            ArchetypeReflection {
+                display_name: "Skeleton",
+                deprecation_summary: None,
+                scope: None,
+                view_types: &["Spatial3DView"],
+                fields: vec![
+                    ArchetypeFieldReflection {
+                        name: "translations",
+                        display_name: "Translations",
+                        component_type: "rerun.components.PoseTranslation3D".into(),
+                        docstring_md: "Translation of each joint relative to its parent joint.",
+                        is_required: true,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "parents",
+                        display_name: "Parents",
+                        component_type: "rerun.components.JointParent".into(),
+                        docstring_md: "Index of the parent of each joint.\n\nRoot joints reference themselves.\nIf not specified, all joints are treated as roots, i.e. they are placed relative to the entity's origin.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "quaternions",
+                        display_name: "Quaternions",
+                        component_type: "rerun.components.PoseRotationQuat".into(),
+                        docstring_md: "Rotation of each joint relative to its parent joint.\n\nIf not specified, joints aren't rotated relative to their parents.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "radii",
+                        display_name: "Radii",
+                        component_type: "rerun.components.Radius".into(),
+                        docstring_md: "Optional radii for the joints.\n\nBones are drawn with half the radius of the joint they lead to.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "colors",
+                        display_name: "Colors",
+                        component_type: "rerun.components.Color".into(),
+                        docstring_md: "Optional colors for the joints and bones.\n\nThe bone leading to a joint uses the color of that joint.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "labels",
+                        display_name: "Labels",
+                        component_type: "rerun.components.Text".into(),
+                        docstring_md: "Optional text labels for the joints, e.g. their names.\n\nIf there's a single label present, it will be placed at the center of the skeleton.\nOtherwise, each label will be placed at its joint.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "show_labels",
+                        display_name: "Show labels",
+                        component_type: "rerun.components.ShowLabels".into(),
+                        docstring_md: "Whether the text labels should be shown.\n\nIf not set, labels will automatically appear when there is exactly one label for this entity\nor the number of instances on this entity is under a certain threshold.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "axis_length",
+                        display_name: "Axis length",
+                        component_type: "rerun.components.AxisLength".into(),
+                        docstring_md: "Length of the coordinate axes drawn at each joint.\n\nIf not set, no axes are drawn.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "class_ids",
+                        display_name: "Class ids",
+                        component_type: "rerun.components.ClassId".into(),
+                        docstring_md: "Optional class IDs for the joints.\n\nThe [`components.ClassId`](https://rerun.io/docs/reference/types/components/class_id) provides colors and labels if not specified explicitly.",
+                        is_required: false,
+                    },
                 ],
             },
         ),
@@ -3323,20 +3678,93 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
             },
         ),
         (
-            ArchetypeName::new("rerun.blueprint.archetypes.ContainerBlueprint"),
+            ArchetypeName::new("rerun.blueprint.archetypes.BarChartLayout"),
             ArchetypeReflection {
-                display_name: "Container blueprint",
+                display_name: "Bar chart layout",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![ArchetypeFieldReflection {
+                    name: "mode",
+                    display_name: "Mode",
+                    component_type: "rerun.blueprint.components.BarChartMode".into(),
+                    docstring_md: "How the bars of different entities at the same position are arranged.",
+                    is_required: false,
+                }],
+            },
+        ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.ClassIdRemap"),
+            ArchetypeReflection {
+                display_name: "Class id remap",
                 deprecation_summary: None,
                 scope: Some("blueprint"),
                 view_types: &[],
                 fields: vec![
                     ArchetypeFieldReflection {
-                        name: "container_kind",
-                        display_name: "Container kind",
-                        component_type: "rerun.blueprint.components.ContainerKind".into(),
-                        docstring_md: "The class of the view.",
-                        is_required: true,
-                    },
+                        name: "source_class_ids",
+                        display_name: "Source class ids",
+                        component_type: "rerun.components.ClassId".into(),
+                        docstring_md: "The class ids that are remapped.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "target_class_ids",
+                        display_name: "Target class ids",
+                        component_type: "rerun.components.ClassId".into(),
+                        docstring_md: "The class id that each entry of `source_class_ids` is remapped to.\n\nMust have the same length as `source_class_ids`, extra entries of either are ignored.",
+                        is_required: false,
+                    },
+                ],
+            },
+        ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.ColorByComponent"),
+            ArchetypeReflection {
+                display_name: "Color by component",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection {
+                        name: "component",
+                        display_name: "Component",
+                        component_type: "rerun.components.Name".into(),
+                        docstring_md: "The component whose values are mapped to colors, e.g. `intensity`.\n\nEither the full component identifier or just the part after the archetype prefix.\nAny numeric component works.\nIf unset, or if the entity doesn't have this component, the instances keep their colors.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "colormap",
+                        display_name: "Colormap",
+                        component_type: "rerun.components.Colormap".into(),
+                        docstring_md: "The colormap the values are mapped through.\n\nDefaults to viridis.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "range",
+                        display_name: "Range",
+                        component_type: "rerun.components.ValueRange".into(),
+                        docstring_md: "The values mapped to the start and end of the colormap.\n\nValues outside of this range are clamped.\nIf unset, the range of the entity's values at the current time is used.",
+                        is_required: false,
+                    },
+                ],
+            },
+        ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.ContainerBlueprint"),
+            ArchetypeReflection {
+                display_name: "Container blueprint",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection {
+                        name: "container_kind",
+                        display_name: "Container kind",
+                        component_type: "rerun.blueprint.components.ContainerKind".into(),
+                        docstring_md: "The class of the view.",
+                        is_required: true,
+                    },
                     ArchetypeFieldReflection {
                         name: "display_name",
                         display_name: "Display name",
@@ -3389,6 +3817,84 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.CoordinateConversion"),
+            ArchetypeReflection {
+                display_name: "Coordinate conversion",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection {
+                        name: "source_coordinates",
+                        display_name: "Source coordinates",
+                        component_type: "rerun.components.ViewCoordinates".into(),
+                        docstring_md: "The coordinate convention the data is logged in, e.g. `FRD` for NED.\n\nDefaults to the [`components.ViewCoordinates`](https://rerun.io/docs/reference/types/components/view_coordinates) logged at the space origin, if any.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "target_coordinates",
+                        display_name: "Target coordinates",
+                        component_type: "rerun.components.ViewCoordinates".into(),
+                        docstring_md: "The coordinate convention the data is shown in, e.g. `RFU` for ENU.\n\nDefaults to `source_coordinates`, i.e. no conversion.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "earth_centered",
+                        display_name: "Earth centered",
+                        component_type: "rerun.blueprint.components.Enabled".into(),
+                        docstring_md: "Whether the data is logged in Earth-centered, Earth-fixed (ECEF) coordinates, in meters.\n\nIf enabled, the data is shown in the east-north-up tangent plane at `geo_origin` instead,\nand `source_coordinates` is ignored.\n\nDefaults to false.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "geo_origin",
+                        display_name: "Geo origin",
+                        component_type: "rerun.components.LatLon".into(),
+                        docstring_md: "The origin of the local tangent plane, on the WGS84 ellipsoid.\n\nUsed for Earth-centered data, and to place [`archetypes.GeoPoints`](https://rerun.io/docs/reference/types/archetypes/geo_points) in the view,\nwhich the `GeoPoints3D` visualizer does in an east-north-up frame.\n\nDefaults to 0° latitude, 0° longitude.",
+                        is_required: false,
+                    },
+                ],
+            },
+        ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.CropBox"),
+            ArchetypeReflection {
+                display_name: "Crop box",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection {
+                        name: "enabled",
+                        display_name: "Enabled",
+                        component_type: "rerun.blueprint.components.Enabled".into(),
+                        docstring_md: "Whether the crop box is applied.\n\nDefaults to false.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "center",
+                        display_name: "Center",
+                        component_type: "rerun.components.Translation3D".into(),
+                        docstring_md: "The center of the box, in the coordinates of the view's origin.\n\nDefaults to the origin.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "half_size",
+                        display_name: "Half size",
+                        component_type: "rerun.components.HalfSize3D".into(),
+                        docstring_md: "Half the size of the box along each axis.\n\nDefaults to 1 along each axis.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "keep_outside",
+                        display_name: "Keep outside",
+                        component_type: "rerun.blueprint.components.Enabled".into(),
+                        docstring_md: "If enabled, the points inside the box are hidden instead of those outside.\n\nDefaults to false.",
+                        is_required: false,
+                    },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.blueprint.archetypes.DataframeQuery"),
             ArchetypeReflection {
@@ -3425,6 +3931,13 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                         docstring_md: "Should empty cells be filled with latest-at queries?",
                         is_required: false,
                     },
+                    ArchetypeFieldReflection {
+                        name: "latest_at_tolerance",
+                        display_name: "Latest at tolerance",
+                        component_type: "rerun.blueprint.components.TimeInt".into(),
+                        docstring_md: "If set, empty cells are only filled with values logged at most this long before their row.\n\nThis is in the units of the timeline, i.e. nanoseconds or sequence steps.\nIt has no effect unless `apply_latest_at` is enabled.",
+                        is_required: false,
+                    },
                     ArchetypeFieldReflection {
                         name: "select",
                         display_name: "Select",
@@ -3435,6 +3948,38 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.DepthClouds3D"),
+            ArchetypeReflection {
+                display_name: "Depth clouds 3D",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection {
+                        name: "enabled",
+                        display_name: "Enabled",
+                        component_type: "rerun.blueprint.components.Enabled".into(),
+                        docstring_md: "Whether depth images are shown as point clouds.\n\nIf disabled, depth images are shown as images on the image plane of their camera instead.\nDefaults to true.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "subsampling",
+                        display_name: "Subsampling",
+                        component_type: "rerun.blueprint.components.DepthCloudSubsampling".into(),
+                        docstring_md: "Only every n-th pixel of a depth image, in both directions, is turned into a point.\n\nHigher values speed up rendering of large depth images.\nDefaults to 1, i.e. a point for every pixel.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "depth_range",
+                        display_name: "Depth range",
+                        component_type: "rerun.components.ValueRange".into(),
+                        docstring_md: "Only depths within this range, in meters, are shown as points.\n\nThis does not affect the range used for the colormap, see [`archetypes.DepthImage`](https://rerun.io/docs/reference/types/archetypes/depth_image).\nIf unset, all valid depths are shown.",
+                        is_required: false,
+                    },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.blueprint.archetypes.EntityBehavior"),
             ArchetypeReflection {
@@ -3517,6 +4062,38 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                         docstring_md: "What speed, if any, the camera should spin around the eye-up axis.\n\nDefaults to zero, meaning no spinning.",
                         is_required: false,
                     },
+                    ArchetypeFieldReflection {
+                        name: "projection",
+                        display_name: "Projection",
+                        component_type: "rerun.blueprint.components.Eye3DProjection".into(),
+                        docstring_md: "The projection of the eye.\n\nDefaults to perspective.",
+                        is_required: false,
+                    },
+                ],
+            },
+        ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.FftWindow"),
+            ArchetypeReflection {
+                display_name: "Fft window",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection {
+                        name: "size",
+                        display_name: "Size",
+                        component_type: "rerun.blueprint.components.FftWindowSize".into(),
+                        docstring_md: "The number of samples in each window.\n\nRounded up to the next power of two.\nLarger windows resolve frequencies more finely, but resolve events in time more coarsely.\nDefaults to 256.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "overlap",
+                        display_name: "Overlap",
+                        component_type: "rerun.blueprint.components.FftWindowOverlap".into(),
+                        docstring_md: "How much consecutive windows overlap, as a fraction of the window size.\n\nClamped to `[0, 0.95]`.\nDefaults to 0.5.",
+                        is_required: false,
+                    },
                 ],
             },
         ),
@@ -3682,6 +4259,86 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 }],
             },
         ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.HoverTooltip"),
+            ArchetypeReflection {
+                display_name: "Hover tooltip",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![ArchetypeFieldReflection {
+                    name: "shown_components",
+                    display_name: "Shown components",
+                    component_type: "rerun.components.Name".into(),
+                    docstring_md: "The components shown in the tooltip, in this order.\n\nEither the full component identifiers or just the part after the archetype prefix,\ne.g. `velocity`. Components the entity doesn't have are skipped.\nIf unset, the tooltip shows a summary of all components.",
+                    is_required: false,
+                }],
+            },
+        ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.ImagePlanes3D"),
+            ArchetypeReflection {
+                display_name: "Image planes 3D",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection {
+                        name: "image_plane_distance",
+                        display_name: "Image plane distance",
+                        component_type: "rerun.components.ImagePlaneDistance".into(),
+                        docstring_md: "Distance of the image plane from the camera, used for all cameras in the view.\n\nIf unset, each camera uses its own [`components.ImagePlaneDistance`](https://rerun.io/docs/reference/types/components/image_plane_distance).",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "opacity",
+                        display_name: "Opacity",
+                        component_type: "rerun.components.Opacity".into(),
+                        docstring_md: "Opacity of the images shown on the image planes.\n\nThis is multiplied with the opacity of each image.\nDefaults to 1.0.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "show_back_faces",
+                        display_name: "Show back faces",
+                        component_type: "rerun.blueprint.components.Enabled".into(),
+                        docstring_md: "Whether the images are visible when looking at the image plane from behind.\n\nDefaults to true.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "project_annotations",
+                        display_name: "Project annotations",
+                        component_type: "rerun.blueprint.components.Enabled".into(),
+                        docstring_md: "Whether 2D annotations, i.e. points, boxes, line strips and arrows, are shown on the image planes.\n\nDefaults to true.",
+                        is_required: false,
+                    },
+                ],
+            },
+        ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.InstanceFilter"),
+            ArchetypeReflection {
+                display_name: "Instance filter",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection {
+                        name: "component",
+                        display_name: "Component",
+                        component_type: "rerun.components.Name".into(),
+                        docstring_md: "The component whose values are compared against the threshold, e.g. `confidence`.\n\nEither the full component identifier or just the part after the archetype prefix.\nEntities that don't have this component are not filtered.\nIf unset, no instances are filtered.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "threshold",
+                        display_name: "Threshold",
+                        component_type: "rerun.components.Scalar".into(),
+                        docstring_md: "Instances with a value below this threshold are hidden.\n\nDefaults to 0.5.",
+                        is_required: false,
+                    },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.blueprint.archetypes.LineGrid3D"),
             ArchetypeReflection {
@@ -3776,6 +4433,77 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 }],
             },
         ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.OnionSkin"),
+            ArchetypeReflection {
+                display_name: "Onion skin",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection {
+                        name: "steps",
+                        display_name: "Steps",
+                        component_type: "rerun.blueprint.components.OnionSkinSteps".into(),
+                        docstring_md: "How many earlier states of each entity are shown.\n\nEvery time an entity's data or its transform changed counts as a step.\nDefaults to 0, i.e. no onion skin.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "opacity",
+                        display_name: "Opacity",
+                        component_type: "rerun.components.Opacity".into(),
+                        docstring_md: "Opacity of the most recent earlier state.\n\nOlder states fade out further.\nDefaults to 0.5.",
+                        is_required: false,
+                    },
+                ],
+            },
+        ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.Outlines"),
+            ArchetypeReflection {
+                display_name: "Outlines",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection {
+                        name: "hover_color",
+                        display_name: "Hover color",
+                        component_type: "rerun.components.Color".into(),
+                        docstring_md: "Color of the outline of hovered objects.\n\nDefaults to the hover color of the viewer.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "selection_color",
+                        display_name: "Selection color",
+                        component_type: "rerun.components.Color".into(),
+                        docstring_md: "Color of the outline of selected objects.\n\nDefaults to the selection color of the viewer.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "stroke_width",
+                        display_name: "Stroke width",
+                        component_type: "rerun.components.StrokeWidth".into(),
+                        docstring_md: "How thick the outlines are in ui units.\n\nDefaults to the thickness of hover & selection strokes elsewhere in the viewer.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "highlighted",
+                        display_name: "Highlighted",
+                        component_type: "rerun.blueprint.components.QueryExpression".into(),
+                        docstring_md: "Query expressions of the entities that are always outlined, e.g. `+ /world/ground_truth/**`.\n\nUses the same syntax as the contents of the view.\nWhile any entity is highlighted, hovered objects are outlined with the selection color.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "highlight_color",
+                        display_name: "Highlight color",
+                        component_type: "rerun.components.Color".into(),
+                        docstring_md: "Color of the outline of highlighted entities.\n\nDefaults to green.",
+                        is_required: false,
+                    },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.blueprint.archetypes.PanelBlueprint"),
             ArchetypeReflection {
@@ -3842,6 +4570,68 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.PointShading"),
+            ArchetypeReflection {
+                display_name: "Point shading",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![ArchetypeFieldReflection {
+                    name: "estimate_normals",
+                    display_name: "Estimate normals",
+                    component_type: "rerun.blueprint.components.Enabled".into(),
+                    docstring_md: "Whether normals are estimated for point clouds, and used to light them.\n\nThe normal of each point is fitted to its nearest neighbors on the GPU, which brings out\nthe shape of scanned surfaces without having to compute normals offline.\nThis is redone every frame, so it can be slow for very large point clouds.\nDefaults to false.",
+                    is_required: false,
+                }],
+            },
+        ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.RigidAlignment"),
+            ArchetypeReflection {
+                display_name: "Rigid alignment",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection {
+                        name: "reference",
+                        display_name: "Reference",
+                        component_type: "rerun.components.EntityPath".into(),
+                        docstring_md: "The entity to align to, e.g. the ground truth.\n\nHas to be shown in the same view.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "match_closest",
+                        display_name: "Match closest",
+                        component_type: "rerun.blueprint.components.Enabled".into(),
+                        docstring_md: "Whether to match each point with the closest point of the reference (ICP).\n\nOtherwise points are matched by index, which requires both entities to have the same\nnumber of points, e.g. two trajectories sampled at the same times.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "translation",
+                        display_name: "Translation",
+                        component_type: "rerun.components.Translation3D".into(),
+                        docstring_md: "The translation of the fitted transform, in the space of the entity.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "quaternion",
+                        display_name: "Quaternion",
+                        component_type: "rerun.components.RotationQuat".into(),
+                        docstring_md: "The rotation of the fitted transform, in the space of the entity.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "rmse",
+                        display_name: "Rmse",
+                        component_type: "rerun.components.Length".into(),
+                        docstring_md: "The root mean square distance between the matched points after the fit.\n\nSet by the viewer when it fits the transform.",
+                        is_required: false,
+                    },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.blueprint.archetypes.ScalarAxis"),
             ArchetypeReflection {
@@ -4069,6 +4859,45 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                         docstring_md: "Selects a range of time on the time panel.",
                         is_required: false,
                     },
+                    ArchetypeFieldReflection {
+                        name: "expanded_entities",
+                        display_name: "Expanded entities",
+                        component_type: "rerun.components.EntityPath".into(),
+                        docstring_md: "The entities whose rows are expanded in the streams tree of the time panel.\n\nCollapsed rows show the combined data density of their whole subtree.\nIf not set, the viewer picks which rows to expand.",
+                        is_required: false,
+                    },
+                ],
+            },
+        ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.TrajectoryTrails"),
+            ArchetypeReflection {
+                display_name: "Trajectory trails",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection {
+                        name: "time_range",
+                        display_name: "Time range",
+                        component_type: "rerun.blueprint.components.TimeRange".into(),
+                        docstring_md: "The time range of past positions that is drawn, usually relative to the time cursor.\n\nDefaults to the 10 seconds before the time cursor, or to the 100 steps before it on sequence timelines.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "fade",
+                        display_name: "Fade",
+                        component_type: "rerun.blueprint.components.Enabled".into(),
+                        docstring_md: "Whether older parts of the trails are drawn thinner.\n\nDefaults to true.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "color_by_time",
+                        display_name: "Color by time",
+                        component_type: "rerun.blueprint.components.Enabled".into(),
+                        docstring_md: "Whether the trails are colored by time instead of with the color of their entity.\n\nDefaults to false.",
+                        is_required: false,
+                    },
                 ],
             },
         ),
@@ -4174,6 +5003,52 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.VisibilityRules"),
+            ArchetypeReflection {
+                display_name: "Visibility rules",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection {
+                        name: "timeline",
+                        display_name: "Timeline",
+                        component_type: "rerun.blueprint.components.TimelineName".into(),
+                        docstring_md: "The timeline that `time_range` refers to.\n\nIf unset, `time_range` applies to whichever timeline is active.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "time_range",
+                        display_name: "Time range",
+                        component_type: "rerun.blueprint.components.AbsoluteTimeRange".into(),
+                        docstring_md: "Only shown while the time cursor is within this range.\n\nIf unset, shown at all times.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "scalar_entity",
+                        display_name: "Scalar entity",
+                        component_type: "rerun.components.EntityPath".into(),
+                        docstring_md: "The entity whose scalar value is compared against `threshold`.\n\nIf unset, shown regardless of any scalar value.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "scalar_component",
+                        display_name: "Scalar component",
+                        component_type: "rerun.components.Name".into(),
+                        docstring_md: "The component of `scalar_entity` that is compared against `threshold`.\n\nEither the full component identifier or just the part after the archetype prefix.\nDefaults to the `scalars` of a `Scalars` archetype.",
+                        is_required: false,
+                    },
+                    ArchetypeFieldReflection {
+                        name: "threshold",
+                        display_name: "Threshold",
+                        component_type: "rerun.components.Scalar".into(),
+                        docstring_md: "Only shown while the latest value of the scalar is at least this threshold.\n\nShown if the scalar has no value at the current time.\nDefaults to 0.0.",
+                        is_required: false,
+                    },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.blueprint.archetypes.VisibleTimeRanges"),
             ArchetypeReflection {
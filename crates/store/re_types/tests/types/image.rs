@@ -60,3 +60,56 @@ fn dynamic_image_roundtrip() {
         similar_asserts::assert_eq!(expected, deserialized);
     }
 }
+
+#[test]
+fn image_from_strided_buffer() {
+    use re_types::image::{CameraPixelFormat, RawImageConversion, RawImageError, RawImageLayout};
+
+    // 2x2 grayscale image with two bytes of padding per row.
+    let layout = RawImageLayout {
+        resolution: [2, 2],
+        row_stride: 4,
+        pixel_format: CameraPixelFormat::L8,
+    };
+    let image = Image::from_strided_buffer(
+        vec![1u8, 2, 0, 0, 3, 4],
+        layout,
+        RawImageConversion::Passthrough,
+    )
+    .unwrap();
+    similar_asserts::assert_eq!(image, Image::from_l8(vec![1, 2, 3, 4], [2, 2]));
+
+    let image =
+        Image::from_strided_buffer(vec![1u8, 2, 0, 0, 3, 4], layout, RawImageConversion::ToRgb)
+            .unwrap();
+    similar_asserts::assert_eq!(
+        image,
+        Image::from_rgb24(vec![1, 1, 1, 2, 2, 2, 3, 3, 3, 4, 4, 4], [2, 2])
+    );
+
+    // A single RGGB block.
+    let image = Image::from_strided_buffer(
+        vec![10u8, 20, 40, 30],
+        RawImageLayout::packed([2, 2], CameraPixelFormat::BayerRg8),
+        RawImageConversion::ToRgb,
+    )
+    .unwrap();
+    similar_asserts::assert_eq!(image, Image::from_rgb24([10, 30, 30].repeat(4), [2, 2]));
+
+    assert_eq!(
+        Image::from_strided_buffer(vec![0u8; 5], layout, RawImageConversion::Passthrough)
+            .unwrap_err(),
+        RawImageError::BufferTooSmall {
+            expected: 6,
+            actual: 5
+        }
+    );
+    assert!(matches!(
+        Image::from_strided_buffer(
+            vec![0u8; 6],
+            RawImageLayout::packed([3, 1], CameraPixelFormat::Yuyv),
+            RawImageConversion::Passthrough,
+        ),
+        Err(RawImageError::OddResolution { .. })
+    ));
+}
@@ -206,6 +206,42 @@ impl SerializedComponentColumn {
         }
     }
 
+    /// Creates a column from an existing Arrow array, without copying its values.
+    ///
+    /// A `List` or `LargeList` array is used as is, with each list holding one row's worth of
+    /// instances.
+    /// Any other array is treated as one instance per row, and null values become null rows.
+    pub fn from_arrow_array(
+        array: arrow::array::ArrayRef,
+        descriptor: ComponentDescriptor,
+    ) -> SerializationResult<Self> {
+        use arrow::array::{Array as _, AsArray as _};
+        use arrow::datatypes::DataType;
+
+        let list_array = match array.data_type() {
+            DataType::List(_) => array.as_list::<i32>().clone(),
+
+            DataType::LargeList(field) => {
+                let list_type = DataType::List(field.clone());
+                arrow::compute::cast(&array, &list_type)?
+                    .as_list::<i32>()
+                    .clone()
+            }
+
+            _ => {
+                let offsets = OffsetBuffer::from_lengths(std::iter::repeat_n(1, array.len()));
+                let nulls = array.nulls().cloned();
+                let field = arrow::datatypes::Field::new("item", array.data_type().clone(), true);
+                ListArray::try_new(field.into(), offsets, array, nulls)?
+            }
+        };
+
+        Ok(Self {
+            list_array,
+            descriptor,
+        })
+    }
+
     /// Repartitions the component data into multiple sub-batches, ignoring the previous partitioning.
     ///
     /// The specified `lengths` must sum to the total length of the component batch.
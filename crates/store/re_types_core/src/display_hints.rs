@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+
+/// The key used to store [`DisplayHints::unit`] in field-level metadata.
+pub const FIELD_METADATA_KEY_DISPLAY_UNIT: &str = "rerun:display_unit";
+
+/// The key used to store [`DisplayHints::colormap`] in field-level metadata.
+pub const FIELD_METADATA_KEY_DISPLAY_COLORMAP: &str = "rerun:display_colormap";
+
+/// The key used to store [`DisplayHints::range`] in field-level metadata.
+pub const FIELD_METADATA_KEY_DISPLAY_RANGE: &str = "rerun:display_range";
+
+/// Hints for how the viewer should display a field of a custom component.
+///
+/// These are stored in the metadata of the fields of an Arrow struct, and thus apply to
+/// components whose datatype is a struct, such as the ones created by `#[derive(Component)]`.
+/// They are purely cosmetic and only used when the viewer has no dedicated UI for a component.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DisplayHints {
+    /// Unit shown after each value, e.g. `m` or `m/s`.
+    pub unit: Option<String>,
+
+    /// Name of a colormap (e.g. `viridis`) used to show a color next to each numeric value.
+    ///
+    /// Only used together with [`Self::range`].
+    pub colormap: Option<String>,
+
+    /// The values that are mapped to the start and end of [`Self::colormap`].
+    pub range: Option<[f64; 2]>,
+}
+
+impl DisplayHints {
+    /// Show the given unit after each value.
+    #[inline]
+    pub fn with_unit(mut self, unit: impl Into<String>) -> Self {
+        self.unit = Some(unit.into());
+        self
+    }
+
+    /// Show a color from the given colormap next to each value, with `range` mapped to the
+    /// start and end of the colormap.
+    #[inline]
+    pub fn with_colormap(mut self, colormap: impl Into<String>, range: [f64; 2]) -> Self {
+        self.colormap = Some(colormap.into());
+        self.range = Some(range);
+        self
+    }
+
+    /// Whether there are no hints at all.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// Reads the hints from the metadata of the given field.
+    ///
+    /// Malformed hints are ignored.
+    pub fn from_field(field: &arrow::datatypes::Field) -> Self {
+        let metadata = field.metadata();
+
+        let range = metadata
+            .get(FIELD_METADATA_KEY_DISPLAY_RANGE)
+            .and_then(|range| {
+                let (min, max) = range.split_once(',')?;
+                Some([min.trim().parse().ok()?, max.trim().parse().ok()?])
+            });
+
+        Self {
+            unit: metadata.get(FIELD_METADATA_KEY_DISPLAY_UNIT).cloned(),
+            colormap: metadata.get(FIELD_METADATA_KEY_DISPLAY_COLORMAP).cloned(),
+            range,
+        }
+    }
+
+    /// Adds the hints to the metadata of the given field.
+    pub fn apply(&self, field: arrow::datatypes::Field) -> arrow::datatypes::Field {
+        let Self {
+            unit,
+            colormap,
+            range,
+        } = self;
+
+        let mut metadata: HashMap<String, String> = field.metadata().clone();
+        if let Some(unit) = unit {
+            metadata.insert(FIELD_METADATA_KEY_DISPLAY_UNIT.to_owned(), unit.clone());
+        }
+        if let Some(colormap) = colormap {
+            metadata.insert(
+                FIELD_METADATA_KEY_DISPLAY_COLORMAP.to_owned(),
+                colormap.clone(),
+            );
+        }
+        if let Some([min, max]) = range {
+            metadata.insert(
+                FIELD_METADATA_KEY_DISPLAY_RANGE.to_owned(),
+                format!("{min},{max}"),
+            );
+        }
+
+        field.with_metadata(metadata)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let hints = DisplayHints::default()
+            .with_unit("m/s")
+            .with_colormap("viridis", [-1.5, 10.0]);
+
+        let field = hints.apply(arrow::datatypes::Field::new(
+            "speed",
+            arrow::datatypes::DataType::Float32,
+            true,
+        ));
+        assert_eq!(DisplayHints::from_field(&field), hints);
+
+        let field = arrow::datatypes::Field::new("speed", arrow::datatypes::DataType::Float32, true);
+        assert!(DisplayHints::from_field(&field).is_empty());
+    }
+}
@@ -27,6 +27,7 @@ pub mod arrow_zip_validity;
 mod as_components;
 mod component_batch;
 mod component_descriptor;
+mod display_hints;
 mod id;
 mod loggable;
 pub mod reflection;
@@ -44,6 +45,10 @@ pub use self::{
         ComponentDescriptor, FIELD_METADATA_KEY_ARCHETYPE, FIELD_METADATA_KEY_COMPONENT,
         FIELD_METADATA_KEY_COMPONENT_TYPE,
     },
+    display_hints::{
+        DisplayHints, FIELD_METADATA_KEY_DISPLAY_COLORMAP, FIELD_METADATA_KEY_DISPLAY_RANGE,
+        FIELD_METADATA_KEY_DISPLAY_UNIT,
+    },
     id::{ChunkId, RowId},
     loggable::{
         Component, ComponentSet, ComponentType, DatatypeName, Loggable, UnorderedComponentSet,
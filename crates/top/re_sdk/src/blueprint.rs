@@ -0,0 +1,757 @@
+use re_log_types::{
+    ApplicationId, BlueprintActivationCommand, EntityPath, LogMsg, RecordingId, StoreId,
+};
+use re_types::{
+    Archetype, AsComponents, SerializedComponentBatch, ViewClassIdentifier,
+    blueprint::{
+        archetypes::{
+            ContainerBlueprint, PanelBlueprint, TimePanelBlueprint, ViewBlueprint, ViewContents,
+            ViewportBlueprint,
+        },
+        components::{ContainerKind, PanelState},
+    },
+    external::uuid::Uuid,
+};
+
+use crate::{RecordingStream, RecordingStreamBuilder, RecordingStreamResult};
+
+pub use re_types::blueprint::{archetypes, components, datatypes, views};
+
+// ---
+
+/// The blueprint of a single view.
+///
+/// This is an ergonomic helper on top of [`ViewBlueprint`] and [`ViewContents`].
+///
+/// ```
+/// use re_sdk::blueprint::{View, archetypes::Background, components::BackgroundKind, views};
+///
+/// let view = View::new::<views::Spatial3DView>()
+///     .with_origin("/world")
+///     .with_name("World")
+///     .with_property(Background::new(BackgroundKind::SolidColor));
+/// ```
+#[derive(Clone, Debug)]
+pub struct View {
+    id: Uuid,
+    class_identifier: ViewClassIdentifier,
+    origin: EntityPath,
+    contents: Vec<String>,
+    name: Option<String>,
+    visible: Option<bool>,
+    properties: Vec<(&'static str, Vec<SerializedComponentBatch>)>,
+    defaults: Vec<SerializedComponentBatch>,
+    overrides: Vec<(EntityPath, Vec<SerializedComponentBatch>)>,
+}
+
+impl View {
+    /// Creates a new view of the given type, e.g. [`views::Spatial3DView`].
+    ///
+    /// The view shows everything under its origin, which is the root by default.
+    pub fn new<V: re_types::View>() -> Self {
+        Self::from_class_identifier(V::identifier())
+    }
+
+    /// Creates a new view of the given class, e.g. `"3D"`.
+    ///
+    /// Prefer [`Self::new`], which can't be misspelled.
+    pub fn from_class_identifier(class_identifier: impl Into<ViewClassIdentifier>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            class_identifier: class_identifier.into(),
+            origin: EntityPath::root(),
+            contents: vec!["$origin/**".to_owned()],
+            name: None,
+            visible: None,
+            properties: Vec::new(),
+            defaults: Vec::new(),
+            overrides: Vec::new(),
+        }
+    }
+
+    /// The entity path used as the origin of this view.
+    ///
+    /// All other entities are transformed to be displayed relative to it.
+    #[inline]
+    pub fn with_origin(mut self, origin: impl Into<EntityPath>) -> Self {
+        self.origin = origin.into();
+        self
+    }
+
+    /// The contents of the view as query expressions, see [`ViewContents`].
+    ///
+    /// Defaults to `$origin/**`.
+    #[inline]
+    pub fn with_contents(mut self, contents: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.contents = contents.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// The name of the view.
+    #[inline]
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Whether the view is visible.
+    ///
+    /// Defaults to true.
+    #[inline]
+    pub fn with_visible(mut self, visible: bool) -> Self {
+        self.visible = Some(visible);
+        self
+    }
+
+    /// Sets a property of the view, e.g. its [`archetypes::Background`].
+    ///
+    /// Use the archetype's `update_fields` to only set the fields you need.
+    #[inline]
+    pub fn with_property<A: Archetype + AsComponents>(mut self, property: A) -> Self {
+        self.properties
+            .push((A::name().short_name(), property.as_serialized_batches()));
+        self
+    }
+
+    /// Adds component defaults to the view.
+    ///
+    /// When an entity in the view is missing one of these components, the default is used
+    /// instead of the visualizer's usual fallback.
+    #[inline]
+    pub fn with_defaults(mut self, defaults: &dyn AsComponents) -> Self {
+        self.defaults.extend(defaults.as_serialized_batches());
+        self
+    }
+
+    /// Overrides components of the entity at `entity_path` in this view.
+    ///
+    /// The path has to be a full entity path, starting at the root.
+    #[inline]
+    pub fn with_overrides(
+        mut self,
+        entity_path: impl Into<EntityPath>,
+        overrides: &dyn AsComponents,
+    ) -> Self {
+        self.overrides
+            .push((entity_path.into(), overrides.as_serialized_batches()));
+        self
+    }
+
+    /// The blueprint path this view is logged to.
+    pub fn blueprint_path(&self) -> EntityPath {
+        EntityPath::from_iter(["view".into(), self.id.to_string().into()])
+    }
+
+    fn log_to_stream(&self, stream: &RecordingStream) -> RecordingStreamResult<()> {
+        let Self {
+            id: _,
+            class_identifier,
+            origin,
+            contents,
+            name,
+            visible,
+            properties,
+            defaults,
+            overrides,
+        } = self;
+
+        let blueprint_path = self.blueprint_path();
+        let contents_path = blueprint_path.join(&EntityPath::from_single_string(
+            ViewContents::name().short_name(),
+        ));
+
+        stream.log(contents_path.clone(), &ViewContents::new(contents.clone()))?;
+
+        let mut view =
+            ViewBlueprint::new(class_identifier.as_str()).with_space_origin(origin.to_string());
+        if let Some(name) = name {
+            view = view.with_display_name(name.clone());
+        }
+        if let Some(visible) = visible {
+            view = view.with_visible(*visible);
+        }
+        stream.log(blueprint_path.clone(), &view)?;
+
+        for (property_name, property) in properties {
+            stream.log(
+                blueprint_path.join(&EntityPath::from_single_string(*property_name)),
+                property,
+            )?;
+        }
+
+        if !defaults.is_empty() {
+            stream.log(
+                blueprint_path.join(&EntityPath::from_single_string("defaults")),
+                defaults,
+            )?;
+        }
+
+        for (entity_path, components) in overrides {
+            // Has to be kept in sync with `ViewContents::override_path_for_entity` in the viewer.
+            let override_path = contents_path
+                .join(&EntityPath::from_single_string("overrides"))
+                .join(entity_path);
+            stream.log(override_path, components)?;
+        }
+
+        Ok(())
+    }
+}
+
+// ---
+
+/// A container of views and other containers.
+///
+/// This is an ergonomic helper on top of [`ContainerBlueprint`].
+#[derive(Clone, Debug)]
+pub struct Container {
+    id: Uuid,
+    kind: ContainerKind,
+    contents: Vec<ContainerLike>,
+    column_shares: Option<Vec<f32>>,
+    row_shares: Option<Vec<f32>>,
+    grid_columns: Option<u32>,
+    active_tab: Option<usize>,
+    name: Option<String>,
+    visible: Option<bool>,
+}
+
+impl Container {
+    /// Creates a new container of the given kind.
+    ///
+    /// Prefer [`Self::horizontal`], [`Self::vertical`], [`Self::grid`] or [`Self::tabs`].
+    pub fn new(
+        kind: ContainerKind,
+        contents: impl IntoIterator<Item = impl Into<ContainerLike>>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            kind,
+            contents: contents.into_iter().map(Into::into).collect(),
+            column_shares: None,
+            row_shares: None,
+            grid_columns: None,
+            active_tab: None,
+            name: None,
+            visible: None,
+        }
+    }
+
+    /// Lays out its contents side by side.
+    #[inline]
+    pub fn horizontal(contents: impl IntoIterator<Item = impl Into<ContainerLike>>) -> Self {
+        Self::new(ContainerKind::Horizontal, contents)
+    }
+
+    /// Lays out its contents on top of each other.
+    #[inline]
+    pub fn vertical(contents: impl IntoIterator<Item = impl Into<ContainerLike>>) -> Self {
+        Self::new(ContainerKind::Vertical, contents)
+    }
+
+    /// Lays out its contents in a grid.
+    #[inline]
+    pub fn grid(contents: impl IntoIterator<Item = impl Into<ContainerLike>>) -> Self {
+        Self::new(ContainerKind::Grid, contents)
+    }
+
+    /// Shows one of its contents at a time, as tabs.
+    #[inline]
+    pub fn tabs(contents: impl IntoIterator<Item = impl Into<ContainerLike>>) -> Self {
+        Self::new(ContainerKind::Tabs, contents)
+    }
+
+    /// The layout shares of the columns.
+    ///
+    /// The column with index `i` takes up the fraction `shares[i] / total_shares` of the width.
+    /// Only applicable to horizontal and grid containers.
+    #[inline]
+    pub fn with_column_shares(mut self, shares: impl IntoIterator<Item = f32>) -> Self {
+        self.column_shares = Some(shares.into_iter().collect());
+        self
+    }
+
+    /// The layout shares of the rows.
+    ///
+    /// The row with index `i` takes up the fraction `shares[i] / total_shares` of the height.
+    /// Only applicable to vertical and grid containers.
+    #[inline]
+    pub fn with_row_shares(mut self, shares: impl IntoIterator<Item = f32>) -> Self {
+        self.row_shares = Some(shares.into_iter().collect());
+        self
+    }
+
+    /// The number of columns of a grid container.
+    #[inline]
+    pub fn with_grid_columns(mut self, grid_columns: u32) -> Self {
+        self.grid_columns = Some(grid_columns);
+        self
+    }
+
+    /// The index of the active tab of a tabs container.
+    #[inline]
+    pub fn with_active_tab(mut self, active_tab: usize) -> Self {
+        self.active_tab = Some(active_tab);
+        self
+    }
+
+    /// The name of the container.
+    #[inline]
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Whether the container is visible.
+    ///
+    /// Defaults to true.
+    #[inline]
+    pub fn with_visible(mut self, visible: bool) -> Self {
+        self.visible = Some(visible);
+        self
+    }
+
+    /// The blueprint path this container is logged to.
+    pub fn blueprint_path(&self) -> EntityPath {
+        EntityPath::from_iter(["container".into(), self.id.to_string().into()])
+    }
+
+    fn log_to_stream(&self, stream: &RecordingStream) -> RecordingStreamResult<()> {
+        let Self {
+            id: _,
+            kind,
+            contents,
+            column_shares,
+            row_shares,
+            grid_columns,
+            active_tab,
+            name,
+            visible,
+        } = self;
+
+        for content in contents {
+            content.log_to_stream(stream)?;
+        }
+
+        let mut container = ContainerBlueprint::new(*kind).with_contents(
+            contents
+                .iter()
+                .map(|content| content.blueprint_path().to_string()),
+        );
+        if let Some(column_shares) = column_shares {
+            container = container.with_col_shares(column_shares.iter().copied());
+        }
+        if let Some(row_shares) = row_shares {
+            container = container.with_row_shares(row_shares.iter().copied());
+        }
+        if let Some(grid_columns) = grid_columns {
+            container = container.with_grid_columns(*grid_columns);
+        }
+        if let Some(active_tab) = active_tab {
+            if let Some(content) = contents.get(*active_tab) {
+                container = container.with_active_tab(content.blueprint_path().to_string());
+            } else {
+                re_log::warn_once!(
+                    "Active tab {active_tab} is out of range, the container only has {} tabs",
+                    contents.len()
+                );
+            }
+        }
+        if let Some(name) = name {
+            container = container.with_display_name(name.clone());
+        }
+        if let Some(visible) = visible {
+            container = container.with_visible(*visible);
+        }
+
+        stream.log(self.blueprint_path(), &container)
+    }
+}
+
+// ---
+
+/// Either a [`Container`] or a [`View`].
+#[derive(Clone, Debug)]
+pub enum ContainerLike {
+    /// A container of views and other containers.
+    Container(Container),
+
+    /// A single view.
+    View(View),
+}
+
+impl From<Container> for ContainerLike {
+    #[inline]
+    fn from(container: Container) -> Self {
+        Self::Container(container)
+    }
+}
+
+impl From<View> for ContainerLike {
+    #[inline]
+    fn from(view: View) -> Self {
+        Self::View(view)
+    }
+}
+
+impl ContainerLike {
+    /// The blueprint path the container or view is logged to.
+    pub fn blueprint_path(&self) -> EntityPath {
+        match self {
+            Self::Container(container) => container.blueprint_path(),
+            Self::View(view) => view.blueprint_path(),
+        }
+    }
+
+    /// Wraps a view in a tabs container, and leaves containers as they are.
+    pub fn into_container(self) -> Container {
+        match self {
+            Self::Container(container) => container,
+            Self::View(view) => Container::tabs([view]),
+        }
+    }
+
+    fn log_to_stream(&self, stream: &RecordingStream) -> RecordingStreamResult<()> {
+        match self {
+            Self::Container(container) => container.log_to_stream(stream),
+            Self::View(view) => view.log_to_stream(stream),
+        }
+    }
+}
+
+// ---
+
+/// The top-level description of the viewer blueprint: the viewport layout and the panels.
+///
+/// Mirrors `rerun.blueprint.Blueprint` of the Python SDK.
+///
+/// ```no_run
+/// use re_sdk::blueprint::{Blueprint, Container, View, views};
+///
+/// let rec = re_sdk::RecordingStreamBuilder::new("rerun_example_blueprint").spawn()?;
+///
+/// Blueprint::new(Container::horizontal([
+///     View::new::<views::Spatial3DView>().with_origin("/world"),
+///     View::new::<views::TimeSeriesView>().with_origin("/metrics"),
+/// ]))
+/// .with_collapse_panels(true)
+/// .send(&rec)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Blueprint {
+    root_container: Option<Container>,
+    auto_layout: Option<bool>,
+    auto_views: Option<bool>,
+    collapse_panels: bool,
+    top_panel: Option<PanelState>,
+    blueprint_panel: Option<PanelState>,
+    selection_panel: Option<PanelState>,
+    time_panel: Option<TimePanelBlueprint>,
+}
+
+impl Blueprint {
+    /// Creates a blueprint with the given root container.
+    ///
+    /// A single view is wrapped in a tabs container.
+    pub fn new(root: impl Into<ContainerLike>) -> Self {
+        Self {
+            root_container: Some(root.into().into_container()),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a blueprint without any containers or views, which lets the viewer
+    /// create the views and their layout automatically.
+    pub fn auto() -> Self {
+        Self::default()
+    }
+
+    /// Whether the viewer resets the layout whenever a new view is added.
+    ///
+    /// Defaults to false, unless the blueprint has no root container.
+    #[inline]
+    pub fn with_auto_layout(mut self, auto_layout: bool) -> Self {
+        self.auto_layout = Some(auto_layout);
+        self
+    }
+
+    /// Whether the viewer adds views based on the data in the recording.
+    ///
+    /// Defaults to false, unless the blueprint has no root container.
+    #[inline]
+    pub fn with_auto_views(mut self, auto_views: bool) -> Self {
+        self.auto_views = Some(auto_views);
+        self
+    }
+
+    /// Collapses all panels that aren't set explicitly.
+    ///
+    /// This fully hides the blueprint and selection panels, and shows the simplified time panel.
+    #[inline]
+    pub fn with_collapse_panels(mut self, collapse_panels: bool) -> Self {
+        self.collapse_panels = collapse_panels;
+        self
+    }
+
+    /// The state of the top panel.
+    #[inline]
+    pub fn with_top_panel(mut self, state: PanelState) -> Self {
+        self.top_panel = Some(state);
+        self
+    }
+
+    /// The state of the blueprint panel.
+    #[inline]
+    pub fn with_blueprint_panel(mut self, state: PanelState) -> Self {
+        self.blueprint_panel = Some(state);
+        self
+    }
+
+    /// The state of the selection panel.
+    #[inline]
+    pub fn with_selection_panel(mut self, state: PanelState) -> Self {
+        self.selection_panel = Some(state);
+        self
+    }
+
+    /// The state of the time panel, e.g. its timeline and playback speed.
+    #[inline]
+    pub fn with_time_panel(mut self, time_panel: TimePanelBlueprint) -> Self {
+        self.time_panel = Some(time_panel);
+        self
+    }
+
+    /// Sends the blueprint through the given recording stream, making it both the active and
+    /// the default blueprint of the stream's application.
+    ///
+    /// See [`Self::send_opts`] for more control.
+    pub fn send(&self, rec: &RecordingStream) -> RecordingStreamResult<()> {
+        self.send_opts(rec, true, true)
+    }
+
+    /// Sends the blueprint through the given recording stream.
+    ///
+    /// If `make_active` is set, the blueprint is activated immediately.
+    /// If `make_default` is set, it becomes the blueprint the viewer resets to.
+    pub fn send_opts(
+        &self,
+        rec: &RecordingStream,
+        make_active: bool,
+        make_default: bool,
+    ) -> RecordingStreamResult<()> {
+        let Some(store_info) = rec.store_info() else {
+            re_log::warn_once!("Recording disabled - call to Blueprint::send() ignored");
+            return Ok(());
+        };
+
+        let Some((blueprint_id, msgs)) = self.to_log_msgs(store_info.application_id().clone())?
+        else {
+            return Ok(());
+        };
+
+        rec.send_blueprint(
+            msgs,
+            BlueprintActivationCommand {
+                blueprint_id,
+                make_active,
+                make_default,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Saves the blueprint to a file. Rerun recommends the `.rbl` suffix.
+    ///
+    /// The `application_id` has to match the one of the recordings the blueprint is used with.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save(
+        &self,
+        application_id: impl Into<ApplicationId>,
+        path: impl Into<std::path::PathBuf>,
+    ) -> RecordingStreamResult<()> {
+        let Some((blueprint_id, msgs)) = self.to_log_msgs(application_id.into())? else {
+            return Ok(());
+        };
+
+        let sink = crate::sink::FileSink::new(path)?;
+        for msg in msgs {
+            sink.send(msg);
+        }
+        sink.send(BlueprintActivationCommand::make_active(blueprint_id).into());
+
+        // Dropping the sink flushes it.
+        drop(sink);
+
+        Ok(())
+    }
+
+    /// Logs the blueprint to a new blueprint stream and returns its id and messages.
+    ///
+    /// Returns `None` if logging is disabled.
+    fn to_log_msgs(
+        &self,
+        application_id: ApplicationId,
+    ) -> RecordingStreamResult<Option<(StoreId, Vec<LogMsg>)>> {
+        let (stream, storage) = RecordingStreamBuilder::new(application_id)
+            // We don't support additive blueprints, so every blueprint gets a new, unique id.
+            .recording_id(RecordingId::random())
+            .blueprint()
+            // Recording properties don't make sense for blueprints.
+            .send_properties(false)
+            .memory()?;
+        let Some(blueprint_id) = storage.store_id() else {
+            return Ok(None);
+        };
+
+        stream.set_time_sequence("blueprint", 0);
+        self.log_to_stream(&stream)?;
+
+        Ok(Some((blueprint_id, storage.take())))
+    }
+
+    fn log_to_stream(&self, stream: &RecordingStream) -> RecordingStreamResult<()> {
+        let Self {
+            root_container,
+            auto_layout,
+            auto_views,
+            collapse_panels,
+            top_panel,
+            blueprint_panel,
+            selection_panel,
+            time_panel,
+        } = self;
+
+        let mut viewport = ViewportBlueprint::new();
+        if let Some(root_container) = root_container {
+            root_container.log_to_stream(stream)?;
+            viewport = viewport.with_root_container(root_container.id);
+        }
+
+        // Without any content, the viewer has to come up with the views and their layout.
+        let is_empty = root_container.is_none();
+        if let Some(auto_layout) = auto_layout.or(is_empty.then_some(true)) {
+            viewport = viewport.with_auto_layout(auto_layout);
+        }
+        if let Some(auto_views) = auto_views.or(is_empty.then_some(true)) {
+            viewport = viewport.with_auto_views(auto_views);
+        }
+        stream.log("viewport", &viewport)?;
+
+        let collapsed = collapse_panels.then_some(PanelState::Collapsed);
+
+        if let Some(state) = top_panel {
+            stream.log("top_panel", &PanelBlueprint::new().with_state(*state))?;
+        }
+        if let Some(state) = (*blueprint_panel).or(collapsed) {
+            stream.log("blueprint_panel", &PanelBlueprint::new().with_state(state))?;
+        }
+        if let Some(state) = (*selection_panel).or(collapsed) {
+            stream.log("selection_panel", &PanelBlueprint::new().with_state(state))?;
+        }
+
+        if let Some(time_panel) = time_panel {
+            let mut time_panel = time_panel.clone();
+
+            // The play state is static, so that it isn't reset when the blueprint timeline moves.
+            if let Some(play_state) = time_panel.play_state.take() {
+                stream.log_static(
+                    "time_panel",
+                    &TimePanelBlueprint {
+                        play_state: Some(play_state),
+                        ..TimePanelBlueprint::update_fields()
+                    },
+                )?;
+            }
+
+            stream.log("time_panel", &time_panel)?;
+        } else if let Some(state) = collapsed {
+            stream.log("time_panel", &TimePanelBlueprint::new().with_state(state))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<View> for Blueprint {
+    #[inline]
+    fn from(view: View) -> Self {
+        Self::new(view)
+    }
+}
+
+impl From<Container> for Blueprint {
+    #[inline]
+    fn from(container: Container) -> Self {
+        Self::new(container)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use re_chunk::Chunk;
+
+    use super::*;
+
+    fn logged_entity_paths(blueprint: &Blueprint) -> Vec<String> {
+        let (_blueprint_id, msgs) = blueprint
+            .to_log_msgs("rerun_example_blueprint".into())
+            .unwrap()
+            .unwrap();
+
+        let mut entity_paths: Vec<String> = msgs
+            .iter()
+            .filter_map(|msg| match msg {
+                LogMsg::ArrowMsg(_, msg) => Some(Chunk::from_arrow_msg(msg).unwrap()),
+                _ => None,
+            })
+            .map(|chunk| chunk.entity_path().to_string())
+            .collect();
+        entity_paths.sort();
+        entity_paths.dedup();
+        entity_paths
+    }
+
+    #[test]
+    fn test_view_paths() {
+        let view = View::new::<views::Spatial3DView>()
+            .with_origin("/world")
+            .with_property(archetypes::LineGrid3D::update_fields().with_visible(false))
+            .with_overrides(
+                "/world/points",
+                &re_types::archetypes::Points3D::update_fields().with_radii([0.1]),
+            );
+        let view_path = view.blueprint_path().to_string();
+        let container = Container::horizontal([view]);
+        let container_path = container.blueprint_path().to_string();
+
+        let entity_paths = logged_entity_paths(&Blueprint::new(container));
+
+        let mut expected = vec![
+            container_path,
+            view_path.clone(),
+            format!("{view_path}/LineGrid3D"),
+            format!("{view_path}/ViewContents"),
+            format!("{view_path}/ViewContents/overrides/world/points"),
+            "/viewport".to_owned(),
+        ];
+        expected.sort();
+        similar_asserts::assert_eq!(expected, entity_paths);
+    }
+
+    #[test]
+    fn test_collapse_panels() {
+        let entity_paths = logged_entity_paths(&Blueprint::auto().with_collapse_panels(true));
+
+        similar_asserts::assert_eq!(
+            vec![
+                "/blueprint_panel".to_owned(),
+                "/selection_panel".to_owned(),
+                "/time_panel".to_owned(),
+                "/viewport".to_owned(),
+            ],
+            entity_paths
+        );
+    }
+}
@@ -0,0 +1,100 @@
+//! Helpers for the code generated by `#[derive(Component)]` and `#[derive(AsComponents)]`.
+//!
+//! Not part of the public API.
+
+use std::sync::Arc;
+
+use arrow::array::{
+    ArrayRef, BooleanArray, Float32Array, Float64Array, Int8Array, Int16Array, Int32Array,
+    Int64Array, StringArray, StructArray, UInt8Array, UInt16Array, UInt32Array, UInt64Array,
+};
+use arrow::buffer::NullBuffer;
+use arrow::datatypes::{DataType, Field, Fields};
+
+pub use arrow;
+pub use re_byte_size::SizeBytes;
+pub use re_types::{
+    AsComponents, Component, ComponentBatch, ComponentDescriptor, ComponentType, DisplayHints,
+    Loggable, SerializationResult, SerializedComponentBatch,
+};
+
+/// Plain Rust types that can be used as fields of a `#[derive(Component)]` struct.
+///
+/// Any other field type must implement [`Loggable`].
+pub trait PrimitiveField: Clone {
+    fn arrow_datatype() -> DataType;
+
+    fn to_arrow(values: Vec<Option<Self>>) -> ArrayRef;
+}
+
+macro_rules! impl_primitive_field {
+    ($ty:ty, $datatype:expr, $array:ty) => {
+        impl PrimitiveField for $ty {
+            #[inline]
+            fn arrow_datatype() -> DataType {
+                $datatype
+            }
+
+            #[inline]
+            fn to_arrow(values: Vec<Option<Self>>) -> ArrayRef {
+                Arc::new(<$array>::from(values))
+            }
+        }
+    };
+}
+
+impl_primitive_field!(bool, DataType::Boolean, BooleanArray);
+impl_primitive_field!(f32, DataType::Float32, Float32Array);
+impl_primitive_field!(f64, DataType::Float64, Float64Array);
+impl_primitive_field!(i8, DataType::Int8, Int8Array);
+impl_primitive_field!(i16, DataType::Int16, Int16Array);
+impl_primitive_field!(i32, DataType::Int32, Int32Array);
+impl_primitive_field!(i64, DataType::Int64, Int64Array);
+impl_primitive_field!(u8, DataType::UInt8, UInt8Array);
+impl_primitive_field!(u16, DataType::UInt16, UInt16Array);
+impl_primitive_field!(u32, DataType::UInt32, UInt32Array);
+impl_primitive_field!(u64, DataType::UInt64, UInt64Array);
+impl_primitive_field!(String, DataType::Utf8, StringArray);
+
+#[inline]
+pub fn primitive_datatype<T: PrimitiveField>() -> DataType {
+    T::arrow_datatype()
+}
+
+#[inline]
+pub fn primitive_to_arrow<T: PrimitiveField>(
+    values: Vec<Option<T>>,
+) -> SerializationResult<ArrayRef> {
+    Ok(T::to_arrow(values))
+}
+
+#[inline]
+pub fn loggable_datatype<T: Loggable>() -> DataType {
+    T::arrow_datatype()
+}
+
+#[inline]
+pub fn loggable_to_arrow<T: Loggable>(values: Vec<Option<T>>) -> SerializationResult<ArrayRef> {
+    T::to_arrow_opt(values)
+}
+
+/// A nullable struct field with the given display hints.
+pub fn field(name: &str, datatype: DataType, hints: DisplayHints) -> Field {
+    hints.apply(Field::new(name, datatype, true))
+}
+
+/// Combines the given field columns into a struct array.
+pub fn struct_to_arrow(
+    fields: Vec<Field>,
+    validity: Vec<bool>,
+    columns: Vec<ArrayRef>,
+) -> SerializationResult<ArrayRef> {
+    let validity = NullBuffer::from(validity);
+    let validity = (validity.null_count() > 0).then_some(validity);
+
+    Ok(Arc::new(StructArray::try_new(
+        Fields::from(fields),
+        columns,
+        validity,
+    )?))
+}
@@ -1,11 +1,12 @@
 //! Keeps track of global and thread-local [`RecordingStream`]s and handles fallback logic between
 //! them.
 
-use std::{cell::RefCell, sync::OnceLock};
+use std::{cell::RefCell, marker::PhantomData, sync::OnceLock, time::Duration};
 
 use parking_lot::RwLock;
+use re_log_types::StoreInfo;
 
-use crate::{RecordingStream, StoreKind};
+use crate::{RecordingStream, StoreKind, sink::SinkState};
 
 // ---
 
@@ -207,6 +208,79 @@ impl RecordingStream {
         Self::forget_any(RecordingScope::ThreadLocal, kind);
     }
 
+    // --- Scoped ---
+
+    /// Makes this the active recording of its kind in the thread-local scope, until the returned
+    /// guard is dropped.
+    ///
+    /// The previous thread-local recording, if any, is restored afterwards.
+    /// A disabled stream is installed as a [`StoreKind::Recording`].
+    ///
+    /// ```ignore
+    /// let _scope = rec.scoped();
+    /// do_some_logging(); // Logs to `rec` via `RecordingStream::get`.
+    /// ```
+    pub fn scoped(&self) -> ScopedRecording {
+        let kind = self
+            .store_info()
+            .map_or(StoreKind::Recording, |info| info.store_id.kind());
+        let previous = Self::set_thread_local(kind, Some(self.clone()));
+
+        ScopedRecording {
+            kind,
+            previous,
+            _not_send: PhantomData,
+        }
+    }
+
+    // --- Audit ---
+
+    /// All recording streams that are currently alive in this process, in order of creation.
+    ///
+    /// Use this to make sure that no data is silently going to a stream you forgot about,
+    /// e.g. a global recording that is still using a [`crate::sink::BufferedSink`].
+    ///
+    /// This queries the sink of every stream, and may block for up to a second per stream
+    /// if a sink is busy (see [`SinkState::Unresponsive`]).
+    /// Streams inherited from a parent process via `fork` are not included.
+    pub fn live_streams() -> Vec<LiveRecordingStream> {
+        re_tracing::profile_function!();
+
+        /// How long we wait for a sink to report its state.
+        const SINK_STATE_TIMEOUT: Duration = Duration::from_secs(1);
+
+        Self::all_live()
+            .into_iter()
+            .filter(|stream| !stream.is_forked_child())
+            .filter_map(|stream| {
+                let store_info = stream.store_info()?;
+                let kind = store_info.store_id.kind();
+
+                let is_installed = |installed: Option<Self>| {
+                    installed.is_some_and(|installed| installed.is_same_stream(&stream))
+                };
+                let is_global = is_installed(Self::global(kind));
+                let is_thread_local = is_installed(Self::thread_local(kind));
+
+                let (tx, rx) = crossbeam::channel::bounded(1);
+                stream.inspect_sink(move |sink| {
+                    tx.send(SinkState::of(sink)).ok();
+                });
+                let sink = rx
+                    .recv_timeout(SINK_STATE_TIMEOUT)
+                    .unwrap_or(SinkState::Unresponsive);
+
+                Some(LiveRecordingStream {
+                    stream,
+                    store_info,
+                    is_global,
+                    is_thread_local,
+                    sink,
+                })
+            })
+            .collect()
+    }
+
     // --- Internal helpers ---
 
     fn get_any(scope: RecordingScope, kind: StoreKind) -> Option<Self> {
@@ -284,12 +358,106 @@ impl RecordingStream {
 
 // ---
 
+/// Restores the previous thread-local recording when dropped, see [`RecordingStream::scoped`].
+#[must_use = "The recording is only active in the thread-local scope until this guard is dropped"]
+pub struct ScopedRecording {
+    kind: StoreKind,
+    previous: Option<RecordingStream>,
+
+    /// The guard must be dropped on the thread it was created on.
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for ScopedRecording {
+    fn drop(&mut self) {
+        RecordingStream::set_thread_local(self.kind, self.previous.take());
+    }
+}
+
+/// A recording stream that is currently alive, see [`RecordingStream::live_streams`].
+#[derive(Debug)]
+pub struct LiveRecordingStream {
+    /// A weak handle to the stream, so that it is not kept alive by the audit.
+    pub stream: RecordingStream,
+
+    /// The [`StoreInfo`] of the stream.
+    pub store_info: StoreInfo,
+
+    /// Whether this is the active recording of its kind in the global scope.
+    pub is_global: bool,
+
+    /// Whether this is the active recording of its kind in the thread-local scope of the
+    /// calling thread.
+    pub is_thread_local: bool,
+
+    /// What the stream's sink was doing at the time of the audit.
+    pub sink: SinkState,
+}
+
+// ---
+
 #[cfg(test)]
 mod tests {
     use crate::RecordingStreamBuilder;
 
     use super::*;
 
+    #[test]
+    fn scoped_and_live_streams() {
+        let outer = RecordingStreamBuilder::new("rerun_example_scoped_outer")
+            .buffered()
+            .unwrap();
+        let (inner, _storage) = RecordingStreamBuilder::new("rerun_example_scoped_inner")
+            .memory()
+            .unwrap();
+
+        let find = |rec: &RecordingStream| {
+            RecordingStream::live_streams()
+                .into_iter()
+                .find(|live| live.stream.is_same_stream(rec))
+                .unwrap()
+        };
+
+        {
+            let _outer_scope = outer.scoped();
+            {
+                let _inner_scope = inner.scoped();
+                assert!(
+                    RecordingStream::thread_local(StoreKind::Recording)
+                        .unwrap()
+                        .is_same_stream(&inner)
+                );
+
+                let live = find(&inner);
+                assert!(live.is_thread_local);
+                assert!(!live.is_global);
+                assert!(matches!(live.sink, SinkState::Memory { .. }));
+
+                assert!(!find(&outer).is_thread_local);
+            }
+
+            assert!(
+                RecordingStream::thread_local(StoreKind::Recording)
+                    .unwrap()
+                    .is_same_stream(&outer)
+            );
+
+            let live = find(&outer);
+            assert!(live.is_thread_local);
+            assert!(matches!(live.sink, SinkState::Buffered { .. }));
+        }
+
+        assert!(RecordingStream::thread_local(StoreKind::Recording).is_none());
+
+        let outer_store_id = outer.store_info().unwrap().store_id;
+        drop(outer);
+        assert!(
+            RecordingStream::live_streams()
+                .iter()
+                .all(|live| live.store_info.store_id != outer_store_id)
+        );
+    }
+
     #[test]
     fn fallbacks() {
         fn check_store_id(expected: &RecordingStream, got: Option<RecordingStream>) {
@@ -25,6 +25,9 @@ pub struct GrpcServerSink {
 
 impl GrpcServerSink {
     /// A `bind_ip` of `"0.0.0.0"` is a good default.
+    ///
+    /// Unless `server_options` already has one, the auth token is taken from the environment,
+    /// see [`re_grpc_server::ServerOptions::apply_env`].
     pub fn new(
         bind_ip: &str,
         grpc_port: u16,
@@ -33,9 +36,10 @@ impl GrpcServerSink {
         let (server_shutdown_signal, shutdown) = re_grpc_server::shutdown::shutdown();
 
         let grpc_server_addr = format!("{bind_ip}:{grpc_port}").parse()?;
+        let server_options = server_options.apply_env();
 
         let uri = re_uri::ProxyUri::new(re_uri::Origin::from_scheme_and_socket_addr(
-            re_uri::Scheme::RerunHttp,
+            server_options.scheme(),
             grpc_server_addr,
         ));
         let (channel_tx, channel_rx) = re_smart_channel::smart_channel::<re_log_types::LogMsg>(
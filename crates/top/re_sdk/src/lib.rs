@@ -20,11 +20,13 @@ mod global;
 mod log_sink;
 mod recording_stream;
 mod spawn;
+mod validation;
 
 // -------------
 // Public items:
 
 pub use spawn::{SpawnError, SpawnOptions, spawn};
+pub use validation::{ValidationIssue, ValidationStrictness};
 
 pub use self::recording_stream::{
     RecordingStream, RecordingStreamBuilder, RecordingStreamError, RecordingStreamResult,
@@ -49,9 +51,10 @@ pub fn default_server_addr() -> std::net::SocketAddr {
 pub use re_log_types::{
     ApplicationId, EntityPath, EntityPathPart, Instance, StoreId, StoreKind, entity_path,
 };
+pub use re_types::ClockOffset;
 pub use re_types::archetypes::RecordingInfo;
 
-pub use global::cleanup_if_forked_child;
+pub use global::{LiveRecordingStream, ScopedRecording, cleanup_if_forked_child};
 
 #[cfg(not(target_arch = "wasm32"))]
 impl crate::sink::LogSink for re_log_encoding::FileSink {
@@ -85,10 +88,12 @@ pub mod sink {
     pub use crate::binary_stream_sink::{BinaryStreamSink, BinaryStreamStorage};
     pub use crate::log_sink::{
         BufferedSink, CallbackSink, IntoMultiSink, LogSink, MemorySink, MemorySinkStorage,
-        MultiSink, SinkFlushError,
+        MultiSink, SinkFlushError, SinkState,
     };
 
-    pub use crate::log_sink::{GrpcSink, GrpcSinkConnectionFailure, GrpcSinkConnectionState};
+    pub use crate::log_sink::{
+        GrpcSink, GrpcSinkConnectionFailure, GrpcSinkConnectionState, GrpcSinkOptions,
+    };
 
     #[cfg(not(target_arch = "wasm32"))]
     pub use re_log_encoding::{FileSink, FileSinkError};
@@ -105,17 +110,31 @@ pub mod log {
 
 /// Time-related types.
 pub mod time {
-    pub use re_log_types::{Duration, TimeCell, TimeInt, TimePoint, TimeType, Timeline, Timestamp};
+    pub use re_log_types::{
+        Duration, TimeCell, TimeInt, TimePoint, TimeType, TimeUnit, Timeline, TimelineCategories,
+        Timestamp,
+    };
 }
-pub use time::{TimeCell, TimePoint, Timeline};
+pub use time::{TimeCell, TimePoint, Timeline, TimelineCategories};
 
 pub use re_types::{
     Archetype, ArchetypeName, AsComponents, Component, ComponentBatch, ComponentDescriptor,
     ComponentIdentifier, ComponentType, DatatypeName, DeserializationError, DeserializationResult,
-    Loggable, SerializationError, SerializationResult, SerializedComponentBatch,
+    DisplayHints, Loggable, SerializationError, SerializationResult, SerializedComponentBatch,
     SerializedComponentColumn,
 };
 
+/// Derive macros for logging custom Rust types, see [`macro@Component`] and [`macro@AsComponents`].
+pub use re_sdk_derive::{AsComponents, Component};
+
+#[doc(hidden)]
+pub mod derive_support;
+
+/// Programmatic construction of viewer blueprints: the viewport layout, views and overrides.
+///
+/// Mirrors the `rerun.blueprint` API of the Python SDK.
+pub mod blueprint;
+
 /// Transformation and reinterpretation of components.
 ///
 /// # Experimental
@@ -137,7 +156,7 @@ pub mod web_viewer;
 pub mod grpc_server;
 
 #[cfg(feature = "server")]
-pub use re_grpc_server::{MemoryLimit, PlaybackBehavior, ServerOptions};
+pub use re_grpc_server::{MemoryLimit, MergeOptions, PlaybackBehavior, ServerOptions, TlsOptions};
 
 /// Re-exports of other crates.
 pub mod external {
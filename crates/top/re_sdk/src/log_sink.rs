@@ -34,6 +34,79 @@ impl SinkFlushError {
     }
 }
 
+/// What the sink of a [`RecordingStream`] is currently doing.
+///
+/// See [`RecordingStream::live_streams`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SinkState {
+    /// A [`BufferedSink`]: nothing is sent anywhere until another sink is set.
+    Buffered {
+        /// How many messages are waiting for another sink.
+        num_pending_msgs: usize,
+    },
+
+    /// A [`MemorySink`].
+    Memory {
+        /// How many messages are currently stored.
+        num_msgs: usize,
+    },
+
+    /// A [`GrpcSink`].
+    Grpc(GrpcSinkConnectionState),
+
+    /// A [`crate::sink::FileSink`].
+    File,
+
+    /// A [`crate::sink::BinaryStreamSink`].
+    BinaryStream,
+
+    /// A [`CallbackSink`].
+    Callback,
+
+    /// A [`MultiSink`], with the state of each of its sinks.
+    Multi(Vec<Self>),
+
+    /// A custom [`LogSink`] implementation.
+    Other,
+
+    /// The sink did not respond in time, e.g. because it is blocked on a flush.
+    Unresponsive,
+}
+
+impl SinkState {
+    /// The state of the given sink.
+    pub fn of(sink: &dyn LogSink) -> Self {
+        let sink = sink.as_any();
+
+        if let Some(sink) = sink.downcast_ref::<BufferedSink>() {
+            Self::Buffered {
+                num_pending_msgs: sink.0.lock().len(),
+            }
+        } else if let Some(sink) = sink.downcast_ref::<MemorySink>() {
+            // NOTE: `MemorySinkStorage::num_msgs` would flush the recording stream, which
+            // deadlocks when called from its own sink.
+            Self::Memory {
+                num_msgs: sink.0.inner.lock().msgs.len(),
+            }
+        } else if let Some(sink) = sink.downcast_ref::<GrpcSink>() {
+            Self::Grpc(sink.status())
+        } else if sink.is::<crate::sink::BinaryStreamSink>() {
+            Self::BinaryStream
+        } else if sink.is::<CallbackSink>() {
+            Self::Callback
+        } else if let Some(sink) = sink.downcast_ref::<MultiSink>() {
+            Self::Multi(sink.0.lock().iter().map(|sink| Self::of(&**sink)).collect())
+        } else {
+            #[cfg(not(target_arch = "wasm32"))]
+            if sink.is::<crate::sink::FileSink>() {
+                return Self::File;
+            }
+
+            Self::Other
+        }
+    }
+}
+
 /// Where the SDK sends its log messages.
 pub trait LogSink: Send + Sync + 'static {
     /// Send this log message.
@@ -533,6 +606,9 @@ pub type GrpcSinkConnectionState = re_grpc_client::write::ClientConnectionState;
 /// The reason why a [`GrpcSink`] was disconnected.
 pub type GrpcSinkConnectionFailure = re_grpc_client::write::ClientConnectionFailure;
 
+/// Options of a [`GrpcSink`], such as its auth token.
+pub type GrpcSinkOptions = re_grpc_client::write::Options;
+
 impl GrpcSink {
     /// Connect to the in-memory storage node over HTTP.
     ///
@@ -540,6 +616,9 @@ impl GrpcSink {
     /// before potentially dropping data. Note: Passing `None` here can cause a
     /// call to `flush` to block indefinitely if a connection cannot be established.
     ///
+    /// The auth token and TLS root certificate are taken from the environment,
+    /// see [`GrpcSinkOptions::from_env`].
+    ///
     /// ### Example
     ///
     /// ```ignore
@@ -547,8 +626,14 @@ impl GrpcSink {
     /// ```
     #[inline]
     pub fn new(uri: re_uri::ProxyUri) -> Self {
+        Self::new_with_options(uri, Options::from_env())
+    }
+
+    /// Like [`Self::new`], but with explicit options.
+    #[inline]
+    pub fn new_with_options(uri: re_uri::ProxyUri, options: GrpcSinkOptions) -> Self {
         Self {
-            client: MessageProxyClient::new(uri, Options::default()),
+            client: MessageProxyClient::new(uri, options),
         }
     }
 
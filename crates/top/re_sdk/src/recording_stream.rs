@@ -5,8 +5,9 @@ use std::sync::{Arc, atomic::AtomicI64};
 use std::time::Duration;
 
 use ahash::HashMap;
+use crossbeam::atomic::AtomicCell;
 use crossbeam::channel::{Receiver, RecvTimeoutError, Sender};
-use itertools::Either;
+use itertools::{Either, Itertools as _};
 use nohash_hasher::IntMap;
 use parking_lot::Mutex;
 
@@ -17,16 +18,18 @@ use re_chunk::{
 use re_log_types::{
     ApplicationId, ArrowRecordBatchReleaseCallback, BlueprintActivationCommand, EntityPath, LogMsg,
     RecordingId, StoreId, StoreInfo, StoreKind, StoreSource, TimeCell, TimeInt, TimePoint,
-    Timeline, TimelineName,
+    TimeType, Timeline, TimelineCategories, TimelineName,
 };
+use re_types::ClockOffset;
 use re_types::archetypes::RecordingInfo;
 use re_types::components::Timestamp;
-use re_types::{AsComponents, SerializationError, SerializedComponentColumn};
+use re_types::{AsComponents, ComponentDescriptor, SerializationError, SerializedComponentColumn};
 
 #[cfg(feature = "web_viewer")]
 use re_web_viewer_server::WebViewerServerPort;
 
 use crate::sink::{LogSink, MemorySinkStorage};
+use crate::validation::{ValidationIssue, ValidationStrictness};
 use crate::{binary_stream_sink::BinaryStreamStorage, sink::SinkFlushError};
 
 // ---
@@ -101,6 +104,30 @@ pub enum RecordingStreamError {
     /// Invalid bind IP.
     #[error(transparent)]
     InvalidAddress(#[from] std::net::AddrParseError),
+
+    /// Temporal data was logged without any timeline.
+    #[error("no timelines were given, use `log_static` to log static data")]
+    MissingTimelines,
+
+    /// A column passed to [`RecordingStream::send_record_batch`] can't be logged.
+    #[error("Column {column:?}: {reason}")]
+    InvalidColumn {
+        /// Name of the column.
+        column: String,
+
+        /// Why it can't be logged.
+        reason: String,
+    },
+
+    /// Logged data failed validation, see [`ValidationStrictness`].
+    #[error("Invalid data logged to {entity_path}: {}", issues.iter().join(", "))]
+    InvalidData {
+        /// Where the data was logged to.
+        entity_path: EntityPath,
+
+        /// Everything that is wrong with the data.
+        issues: Vec<ValidationIssue>,
+    },
 }
 
 /// Results that can occur when creating/manipulating a [`RecordingStream`].
@@ -851,6 +878,32 @@ impl RecordingStream {
     }
 }
 
+impl RecordingStream {
+    /// Weak handles to all streams that are currently alive, in order of creation.
+    pub(crate) fn all_live() -> Vec<Self> {
+        LIVE_STREAMS
+            .lock()
+            .iter()
+            .filter(|stream| stream.strong_count() > 0)
+            .map(|stream| Self {
+                inner: Either::Right(Weak::clone(stream)),
+            })
+            .collect()
+    }
+
+    /// Whether both handles refer to the same underlying stream.
+    pub(crate) fn is_same_stream(&self, other: &Self) -> bool {
+        fn as_ptr(stream: &RecordingStream) -> *const RecordingStreamInner {
+            match &stream.inner {
+                Either::Left(strong) => Arc::as_ptr(strong),
+                Either::Right(weak) => weak.as_ptr(),
+            }
+        }
+
+        std::ptr::eq(as_ptr(self), as_ptr(other))
+    }
+}
+
 // TODO(#5335): shutdown flushing behavior is too brittle.
 impl Drop for RecordingStream {
     #[inline]
@@ -872,6 +925,11 @@ impl Drop for RecordingStream {
     }
 }
 
+/// Every [`RecordingStreamInner`] that has been created, see [`RecordingStream::live_streams`].
+///
+/// Dead entries are pruned whenever a new stream is created.
+static LIVE_STREAMS: Mutex<Vec<Weak<RecordingStreamInner>>> = Mutex::new(Vec::new());
+
 struct RecordingStreamInner {
     store_info: StoreInfo,
     recording_info: Option<RecordingInfo>,
@@ -888,6 +946,9 @@ struct RecordingStreamInner {
     /// It true, any new sink will update the batcher's configuration (as far as possible).
     sink_dependent_batcher_config: bool,
 
+    /// See [`RecordingStream::set_validation_strictness`].
+    validation_strictness: AtomicCell<ValidationStrictness>,
+
     /// Keeps track of the top-level threads that were spawned in order to execute the `DataLoader`
     /// machinery in the context of this `RecordingStream`.
     ///
@@ -1012,6 +1073,7 @@ impl RecordingStreamInner {
             batcher,
             batcher_to_sink_handle: Some(batcher_to_sink_handle),
             sink_dependent_batcher_config,
+            validation_strictness: AtomicCell::new(ValidationStrictness::from_env()),
             dataloader_handles: Mutex::new(Vec::new()),
             pid_at_creation: std::process::id(),
         })
@@ -1104,8 +1166,16 @@ impl RecordingStream {
             batcher_hooks,
             sink,
         )
-        .map(|inner| Self {
-            inner: Either::Left(Arc::new(inner)),
+        .map(|inner| {
+            let inner = Arc::new(inner);
+
+            let mut live_streams = LIVE_STREAMS.lock();
+            live_streams.retain(|stream| stream.strong_count() > 0);
+            live_streams.push(Arc::downgrade(&inner));
+
+            Self {
+                inner: Either::Left(inner),
+            }
         })?;
 
         Ok(stream)
@@ -1200,6 +1270,77 @@ impl RecordingStream {
         Ok(())
     }
 
+    /// Lower-level logging API to log data that is already in Arrow, e.g. produced by `DataFusion`
+    /// or `polars`, without re-serializing it.
+    ///
+    /// The columns named in `index_columns` become timelines of the same name, and must be
+    /// non-nullable `Int64` (sequence), `Timestamp(ns)` or `Duration(ns)` columns.
+    /// Every other column becomes a component column, see
+    /// [`SerializedComponentColumn::from_arrow_array`]: list columns hold multiple instances
+    /// per row, and any other column a single instance per row.
+    ///
+    /// The component descriptor of each column is read from the `rerun:archetype`,
+    /// `rerun:component` and `rerun:component_type` field metadata, falling back to the column
+    /// name as the component.
+    ///
+    /// Like [`Self::send_columns`], this ignores the stateful time of the stream, and does not
+    /// add the default `log_tick` and `log_time` timelines.
+    pub fn send_record_batch(
+        &self,
+        ent_path: impl Into<EntityPath>,
+        index_columns: &[&str],
+        batch: &arrow::array::RecordBatch,
+    ) -> RecordingStreamResult<()> {
+        let schema = batch.schema();
+
+        if let Some(missing) = index_columns
+            .iter()
+            .find(|column| schema.field_with_name(column).is_err())
+        {
+            return Err(RecordingStreamError::InvalidColumn {
+                column: (*missing).to_owned(),
+                reason: "no such column".to_owned(),
+            });
+        }
+
+        let mut indexes = Vec::new();
+        let mut columns = Vec::new();
+
+        for (field, array) in schema.fields().iter().zip(batch.columns()) {
+            let column = field.name();
+
+            if index_columns.contains(&column.as_str()) {
+                let invalid_column = |reason: String| RecordingStreamError::InvalidColumn {
+                    column: column.clone(),
+                    reason,
+                };
+
+                let time_type =
+                    TimeType::from_arrow_datatype(array.data_type()).ok_or_else(|| {
+                        invalid_column(format!(
+                            "index columns must be Int64, Timestamp(ns) or Duration(ns), got {}",
+                            array.data_type()
+                        ))
+                    })?;
+                let times = TimeColumn::read_array(array.as_ref())
+                    .map_err(|err| invalid_column(err.to_string()))?;
+
+                indexes.push(TimeColumn::new(
+                    None,
+                    Timeline::new(column.as_str(), time_type),
+                    times,
+                ));
+            } else {
+                columns.push(SerializedComponentColumn::from_arrow_array(
+                    array.clone(),
+                    ComponentDescriptor::from(field.as_ref().clone()),
+                )?);
+            }
+        }
+
+        self.send_columns(ent_path, indexes, columns)
+    }
+
     /// Log data to Rerun.
     ///
     /// It can be used to log anything
@@ -1230,6 +1371,55 @@ impl RecordingStream {
         self.log_with_static(ent_path, true, as_components)
     }
 
+    /// Log data to Rerun at an explicit point in time.
+    ///
+    /// Unlike [`Self::log`], this does not use the [`RecordingStream`]'s internal clock at all:
+    /// the data is logged on exactly the given timelines, which are all attached atomically.
+    /// Neither the thread-local times set via [`Self::set_time`] & co, nor the default `log_tick`
+    /// and `log_time` timelines are added.
+    ///
+    /// This makes it safe to log from many threads without having to keep their clocks in sync.
+    ///
+    /// Returns [`RecordingStreamError::MissingTimelines`] if `timepoint` is empty, since that
+    /// would make the data static. Use [`Self::log_static`] for that instead.
+    ///
+    /// # Example:
+    /// ```ignore
+    /// # use rerun;
+    /// # let (rec, storage) = rerun::RecordingStreamBuilder::new("rerun_example_log_at").memory()?;
+    /// rec.log_at(
+    ///     [
+    ///         ("frame", rerun::TimeCell::from_sequence(42)),
+    ///         ("sim_time", rerun::TimeCell::from_duration_nanos(1_230_000_000)),
+    ///     ],
+    ///     "my/points",
+    ///     &rerun::Points3D::new([(0.0, 0.0, 0.0), (1.0, 1.0, 1.0)]),
+    /// )?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn log_at<AS: ?Sized + AsComponents>(
+        &self,
+        timepoint: impl Into<TimePoint>,
+        ent_path: impl Into<EntityPath>,
+        as_components: &AS,
+    ) -> RecordingStreamResult<()> {
+        let row_id = RowId::new(); // Create row-id as early as possible. It has a timestamp and is used to estimate e2e latency.
+
+        let timepoint = timepoint.into();
+        if timepoint.is_static() {
+            return Err(RecordingStreamError::MissingTimelines);
+        }
+
+        self.log_serialized_batches_at_impl(
+            row_id,
+            ent_path,
+            timepoint,
+            false,
+            as_components.as_serialized_batches(),
+        )
+    }
+
     /// Logs the contents of a [component bundle] into Rerun.
     ///
     /// If `static_` is set to `true`, all timestamp data associated with this message will be
@@ -1331,15 +1521,68 @@ impl RecordingStream {
         self.log_static(EntityPath::properties(), &update)
     }
 
-    // NOTE: For bw and fw compatibility reasons, we need our logging APIs to be fallible, even
-    // though they really aren't at the moment.
-    #[expect(clippy::unnecessary_wraps)]
+    /// Sends how far the clock of a data source is off from the reference clock of the
+    /// recording, e.g. as measured by PTP or NTP.
+    ///
+    /// `source` is the entity subtree the data source logs to, e.g. `/robot_a`.
+    /// The viewer can use this to re-base the timestamp timelines of each source.
+    #[inline]
+    pub fn send_clock_offset(
+        &self,
+        source: impl Into<EntityPath>,
+        clock_offset: &ClockOffset,
+    ) -> RecordingStreamResult<()> {
+        self.log_static(ClockOffset::property_path(&source.into()), clock_offset)
+    }
+
+    /// Sends the categories of a categorical timeline, e.g. the phases of an experiment.
+    ///
+    /// The viewer then labels the values of that sequence timeline with the names of the
+    /// categories. Use [`TimelineCategories::time_cell`] to enter a category:
+    /// ```no_run
+    /// # let rec = re_sdk::RecordingStream::disabled();
+    /// let phases = re_sdk::TimelineCategories::new(["calib", "run", "teardown"]);
+    /// rec.send_timeline_categories("phase", &phases).ok();
+    /// rec.set_time("phase", phases.time_cell("run").unwrap());
+    /// ```
+    #[inline]
+    pub fn send_timeline_categories(
+        &self,
+        timeline: impl Into<TimelineName>,
+        categories: &TimelineCategories,
+    ) -> RecordingStreamResult<()> {
+        let values = re_types::AnyValues::default().with_component::<re_types::components::Text>(
+            TimelineCategories::CATEGORIES,
+            categories.categories().iter().map(String::as_str),
+        );
+        self.log_static(TimelineCategories::property_path(&timeline.into()), &values)
+    }
+
     fn log_serialized_batches_impl(
         &self,
         row_id: RowId,
         entity_path: impl Into<EntityPath>,
         static_: bool,
         comp_batches: impl IntoIterator<Item = re_types::SerializedComponentBatch>,
+    ) -> RecordingStreamResult<()> {
+        // NOTE: The timepoint is irrelevant for temporal data, the `RecordingStream` will overwrite
+        // it using its internal clock.
+        self.log_serialized_batches_at_impl(
+            row_id,
+            entity_path,
+            TimePoint::default(),
+            !static_,
+            comp_batches,
+        )
+    }
+
+    fn log_serialized_batches_at_impl(
+        &self,
+        row_id: RowId,
+        entity_path: impl Into<EntityPath>,
+        timepoint: TimePoint,
+        inject_time: bool,
+        comp_batches: impl IntoIterator<Item = re_types::SerializedComponentBatch>,
     ) -> RecordingStreamResult<()> {
         if !self.is_enabled() {
             return Ok(()); // silently drop the message
@@ -1352,9 +1595,34 @@ impl RecordingStream {
             .map(|comp_batch| (comp_batch.descriptor.component, comp_batch))
             .collect();
 
-        // NOTE: The timepoint is irrelevant, the `RecordingStream` will overwrite it using its
-        // internal clock.
-        let timepoint = TimePoint::default();
+        let strictness = self.validation_strictness();
+        if strictness != ValidationStrictness::Off {
+            let issues = crate::validation::validate(&components);
+            if !issues.is_empty() {
+                match strictness {
+                    ValidationStrictness::Off => {}
+                    ValidationStrictness::Warn => {
+                        re_log::warn_once!(
+                            "Invalid data logged to {entity_path}: {}",
+                            issues.iter().join(", ")
+                        );
+                    }
+                    ValidationStrictness::Error => {
+                        return Err(RecordingStreamError::InvalidData {
+                            entity_path,
+                            issues,
+                        });
+                    }
+                    ValidationStrictness::Drop => {
+                        re_log::warn_once!(
+                            "Dropped data logged to {entity_path} because it is invalid: {}",
+                            issues.iter().join(", ")
+                        );
+                        return Ok(());
+                    }
+                }
+            }
+        }
 
         if !components.is_empty() {
             let row = PendingRow {
@@ -1362,7 +1630,7 @@ impl RecordingStream {
                 timepoint,
                 components,
             };
-            self.record_row(entity_path, row, !static_);
+            self.record_row(entity_path, row, inject_time);
         }
 
         Ok(())
@@ -1668,6 +1936,23 @@ impl RecordingStream {
     pub fn is_forked_child(&self) -> bool {
         self.with(|inner| inner.is_forked_child()).unwrap_or(false)
     }
+
+    /// How logged data is validated, see [`ValidationStrictness`].
+    #[inline]
+    pub fn validation_strictness(&self) -> ValidationStrictness {
+        self.with(|inner| inner.validation_strictness.load())
+            .unwrap_or_default()
+    }
+
+    /// Sets how logged data is validated, see [`ValidationStrictness`].
+    ///
+    /// Defaults to the value of the `RERUN_VALIDATION` environment variable, or
+    /// [`ValidationStrictness::Off`].
+    /// This applies to [`Self::log`] and its variants, not to [`Self::send_columns`].
+    #[inline]
+    pub fn set_validation_strictness(&self, strictness: ValidationStrictness) {
+        self.with(|inner| inner.validation_strictness.store(strictness));
+    }
 }
 
 impl RecordingStream {
@@ -2334,6 +2619,7 @@ impl fmt::Debug for RecordingStream {
                 batcher: _,
                 batcher_to_sink_handle: _,
                 sink_dependent_batcher_config,
+                validation_strictness,
                 dataloader_handles,
                 pid_at_creation,
             } = inner;
@@ -2346,6 +2632,7 @@ impl fmt::Debug for RecordingStream {
                     "sink_dependent_batcher_config",
                     &sink_dependent_batcher_config,
                 )
+                .field("validation_strictness", &validation_strictness.load())
                 .field("pending_dataloaders", &dataloader_handles.lock().len())
                 .field("pid_at_creation", &pid_at_creation)
                 .finish_non_exhaustive()
@@ -2725,7 +3012,7 @@ impl RecordingStream {
 mod tests {
     use insta::assert_debug_snapshot;
     use itertools::Itertools as _;
-    use re_log_types::example_components::{MyLabel, MyPoints};
+    use re_log_types::example_components::{MyLabel, MyPoint, MyPoints};
     use re_types::SerializedComponentBatch;
 
     use super::*;
@@ -3017,6 +3304,161 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn log_at_ignores_thread_local_time() {
+        let (rec, storage) = RecordingStreamBuilder::new("rerun_example_log_at")
+            .batcher_config(ChunkBatcherConfig::NEVER)
+            .memory()
+            .unwrap();
+
+        rec.set_time_sequence("frame", 1);
+        rec.set_time_sequence("other", 2);
+
+        let points = MyPoints::new([MyPoint::new(1.0, 2.0)]);
+        rec.log_at(
+            [
+                ("frame", TimeCell::from_sequence(42)),
+                ("sim_time", TimeCell::from_duration_nanos(1_230_000_000)),
+            ],
+            "points",
+            &points,
+        )
+        .unwrap();
+        assert!(matches!(
+            rec.log_at(TimePoint::default(), "points", &points),
+            Err(RecordingStreamError::MissingTimelines)
+        ));
+
+        rec.flush_blocking().unwrap();
+
+        let chunk = storage
+            .take()
+            .into_iter()
+            .filter_map(|msg| match msg {
+                LogMsg::ArrowMsg(_, msg) => Some(Chunk::from_arrow_msg(&msg).unwrap()),
+                _ => None,
+            })
+            .find(|chunk| chunk.entity_path() == &EntityPath::from("points"))
+            .unwrap();
+
+        assert_eq!(
+            chunk
+                .timelines()
+                .keys()
+                .map(|timeline| timeline.as_str())
+                .sorted()
+                .collect_vec(),
+            ["frame", "sim_time"]
+        );
+        assert_eq!(
+            chunk.timelines()[&TimelineName::new("frame")].times_raw(),
+            [42]
+        );
+    }
+
+    #[test]
+    fn send_record_batch() {
+        use arrow::array::{Float64Array, Int64Array, ListArray, RecordBatch};
+        use arrow::datatypes::{DataType, Field, Int32Type, Schema};
+
+        let (rec, storage) = RecordingStreamBuilder::new("rerun_example_send_record_batch")
+            .batcher_config(ChunkBatcherConfig::NEVER)
+            .memory()
+            .unwrap();
+
+        let class_ids = Field::new_list(
+            "class_ids",
+            Field::new_list_field(DataType::Int32, true),
+            true,
+        )
+        .with_metadata(
+            [(
+                re_types::FIELD_METADATA_KEY_COMPONENT.to_owned(),
+                "Points3D:class_ids".to_owned(),
+            )]
+            .into(),
+        );
+
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("frame", DataType::Int64, false),
+                Field::new("temperature", DataType::Float64, true),
+                class_ids,
+            ])),
+            vec![
+                Arc::new(Int64Array::from(vec![1, 2])),
+                Arc::new(Float64Array::from(vec![Some(20.5), None])),
+                Arc::new(ListArray::from_iter_primitive::<Int32Type, _, _>([
+                    Some(vec![Some(1), Some(2)]),
+                    Some(vec![]),
+                ])),
+            ],
+        )
+        .unwrap();
+
+        rec.send_record_batch("sensor", &["frame"], &batch).unwrap();
+        assert!(matches!(
+            rec.send_record_batch("sensor", &["time"], &batch),
+            Err(RecordingStreamError::InvalidColumn { .. })
+        ));
+        assert!(matches!(
+            rec.send_record_batch("sensor", &["temperature"], &batch),
+            Err(RecordingStreamError::InvalidColumn { .. })
+        ));
+
+        rec.flush_blocking().unwrap();
+
+        let chunk = storage
+            .take()
+            .into_iter()
+            .filter_map(|msg| match msg {
+                LogMsg::ArrowMsg(_, msg) => Some(Chunk::from_arrow_msg(&msg).unwrap()),
+                _ => None,
+            })
+            .find(|chunk| chunk.entity_path() == &EntityPath::from("sensor"))
+            .unwrap();
+
+        assert_eq!(chunk.num_rows(), 2);
+        assert_eq!(
+            chunk.timelines()[&TimelineName::new("frame")].times_raw(),
+            [1, 2]
+        );
+        assert_eq!(
+            chunk
+                .component_descriptors()
+                .map(|descr| descr.component.as_str())
+                .sorted()
+                .collect_vec(),
+            ["Points3D:class_ids", "temperature"]
+        );
+    }
+
+    #[test]
+    fn validation_strictness() {
+        let (rec, storage) = RecordingStreamBuilder::new("rerun_example_validation")
+            .batcher_config(ChunkBatcherConfig::NEVER)
+            .memory()
+            .unwrap();
+
+        let invalid = re_types::archetypes::Points3D::new([(0.0, f32::NAN, 0.0)]);
+        let num_msgs = || storage.num_msgs();
+        let num_msgs_before = num_msgs();
+
+        rec.set_validation_strictness(ValidationStrictness::Error);
+        assert!(matches!(
+            rec.log("points", &invalid),
+            Err(RecordingStreamError::InvalidData { .. })
+        ));
+
+        rec.set_validation_strictness(ValidationStrictness::Drop);
+        rec.log("points", &invalid).unwrap();
+        assert_eq!(num_msgs(), num_msgs_before);
+
+        rec.set_validation_strictness(ValidationStrictness::Warn);
+        rec.log("points", &invalid).unwrap();
+        assert_eq!(num_msgs(), num_msgs_before + 1);
+    }
+
     fn example_rows(static_: bool) -> Vec<PendingRow> {
         use re_log_types::example_components::{MyColor, MyLabel, MyPoint};
         use re_types::Loggable;
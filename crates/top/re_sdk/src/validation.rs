@@ -0,0 +1,331 @@
+//! Opt-in validation of logged data at the SDK boundary.
+//!
+//! See [`ValidationStrictness`].
+
+use std::collections::BTreeMap;
+
+use nohash_hasher::IntMap;
+
+use re_types::components::{
+    ClassId, Color, KeypointId, Position2D, Position3D, Radius, RotationQuat, Text, Translation3D,
+    TriangleIndices,
+};
+use re_types::{
+    ArchetypeName, Component as _, ComponentIdentifier, Loggable, SerializedComponentBatch,
+};
+
+/// What to do with logged data that fails validation.
+///
+/// Validation is off by default, since it has to inspect every logged row.
+/// The default can be changed with the `RERUN_VALIDATION` environment variable, see
+/// [`Self::from_env`] and [`crate::RecordingStream::set_validation_strictness`].
+///
+/// The following is checked:
+/// - positions and translations must be finite,
+/// - rotation quaternions must be finite and non-zero,
+/// - per-instance components (colors, radii, labels, class and keypoint ids) must have either a
+///   single instance or one per position of the same archetype,
+/// - triangle indices must refer to existing vertex positions of the same archetype.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ValidationStrictness {
+    /// Don't validate anything.
+    #[default]
+    Off,
+
+    /// Log a warning, but still log the data.
+    Warn,
+
+    /// Don't log the data, and return [`crate::RecordingStreamError::InvalidData`].
+    Error,
+
+    /// Don't log the data, and log a warning.
+    Drop,
+}
+
+impl ValidationStrictness {
+    /// Environment variable to set the default strictness: `off`, `warn`, `error` or `drop`.
+    pub const ENV: &'static str = "RERUN_VALIDATION";
+
+    /// The strictness set by the `RERUN_VALIDATION` environment variable, if any.
+    pub fn from_env() -> Self {
+        let Ok(value) = std::env::var(Self::ENV) else {
+            return Self::default();
+        };
+
+        value.parse().unwrap_or_else(|err| {
+            re_log::warn!("Ignoring {}={value:?}: {err}", Self::ENV);
+            Self::default()
+        })
+    }
+}
+
+impl std::str::FromStr for ValidationStrictness {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "off" => Ok(Self::Off),
+            "warn" => Ok(Self::Warn),
+            "error" => Ok(Self::Error),
+            "drop" => Ok(Self::Drop),
+            _ => Err(format!(
+                "expected one of `off`, `warn`, `error` or `drop`, got {s:?}"
+            )),
+        }
+    }
+}
+
+/// Why logged data failed validation, see [`ValidationStrictness`].
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+pub enum ValidationIssue {
+    /// A position or translation contains NaN or infinity.
+    #[error("{component}: instance #{index} is not finite: {value:?}")]
+    NonFinitePosition {
+        /// The offending component.
+        component: ComponentIdentifier,
+
+        /// Index of the first non-finite instance.
+        index: usize,
+
+        /// Coordinates of that instance.
+        value: Vec<f32>,
+    },
+
+    /// A per-instance component doesn't have as many instances as there are positions.
+    #[error("{component}: has {len} instances, but there are {num_positions} positions")]
+    MismatchedLength {
+        /// The offending component.
+        component: ComponentIdentifier,
+
+        /// Number of instances of the component.
+        len: usize,
+
+        /// Number of positions of the same archetype.
+        num_positions: usize,
+    },
+
+    /// A quaternion that is zero or not finite, and thus not a rotation.
+    #[error("{component}: instance #{index} is not a valid rotation: {xyzw:?}")]
+    InvalidQuaternion {
+        /// The offending component.
+        component: ComponentIdentifier,
+
+        /// Index of the first invalid quaternion.
+        index: usize,
+
+        /// That quaternion.
+        xyzw: [f32; 4],
+    },
+
+    /// A triangle refers to a vertex that doesn't exist.
+    #[error(
+        "{component}: triangle #{index} refers to vertex {vertex}, but there are only {num_vertices} vertices"
+    )]
+    IndexOutOfRange {
+        /// The offending component.
+        component: ComponentIdentifier,
+
+        /// Index of the first offending triangle.
+        index: usize,
+
+        /// The largest vertex index of that triangle.
+        vertex: u32,
+
+        /// Number of vertex positions of the same archetype.
+        num_vertices: usize,
+    },
+}
+
+/// Checks the components of a single row, see [`ValidationStrictness`].
+///
+/// Reports at most one issue per component.
+/// Data that can't be deserialized is not validated.
+pub(crate) fn validate(
+    components: &IntMap<ComponentIdentifier, SerializedComponentBatch>,
+) -> Vec<ValidationIssue> {
+    re_tracing::profile_function!();
+
+    let mut issues = Vec::new();
+
+    // Number of positions per archetype, for the per-instance checks below.
+    let mut num_positions: BTreeMap<Option<ArchetypeName>, usize> = Default::default();
+
+    for batch in components.values() {
+        let component = batch.descriptor.component;
+        let Some(component_type) = batch.descriptor.component_type else {
+            continue;
+        };
+
+        let issue = if component_type == Position2D::name() {
+            num_positions.insert(batch.descriptor.archetype, batch.array.len());
+            non_finite(component, &deserialize::<Position2D>(batch), |pos| pos.0.0)
+        } else if component_type == Position3D::name() {
+            num_positions.insert(batch.descriptor.archetype, batch.array.len());
+            non_finite(component, &deserialize::<Position3D>(batch), |pos| pos.0.0)
+        } else if component_type == Translation3D::name() {
+            non_finite(component, &deserialize::<Translation3D>(batch), |pos| {
+                pos.0.0
+            })
+        } else if component_type == RotationQuat::name() {
+            deserialize::<RotationQuat>(batch)
+                .iter()
+                .enumerate()
+                .find(|(_, quat)| {
+                    let xyzw = quat.0.0;
+                    !xyzw.iter().all(|v| v.is_finite()) || xyzw.iter().all(|v| *v == 0.0)
+                })
+                .map(|(index, quat)| ValidationIssue::InvalidQuaternion {
+                    component,
+                    index,
+                    xyzw: quat.0.0,
+                })
+        } else {
+            None
+        };
+
+        issues.extend(issue);
+    }
+
+    if num_positions.is_empty() {
+        return issues;
+    }
+
+    let per_instance_types = [
+        Color::name(),
+        Radius::name(),
+        Text::name(),
+        ClassId::name(),
+        KeypointId::name(),
+    ];
+
+    for batch in components.values() {
+        let component = batch.descriptor.component;
+        let (Some(component_type), Some(&num_positions)) = (
+            batch.descriptor.component_type,
+            num_positions.get(&batch.descriptor.archetype),
+        ) else {
+            continue;
+        };
+
+        if per_instance_types.contains(&component_type) {
+            let len = batch.array.len();
+            if 1 < len && len != num_positions {
+                issues.push(ValidationIssue::MismatchedLength {
+                    component,
+                    len,
+                    num_positions,
+                });
+            }
+        } else if component_type == TriangleIndices::name() {
+            let out_of_range = deserialize::<TriangleIndices>(batch)
+                .iter()
+                .enumerate()
+                .find_map(|(index, triangle)| {
+                    let vertex = triangle.0.0.into_iter().max()?;
+                    (num_positions <= vertex as usize).then_some((index, vertex))
+                });
+
+            if let Some((index, vertex)) = out_of_range {
+                issues.push(ValidationIssue::IndexOutOfRange {
+                    component,
+                    index,
+                    vertex,
+                    num_vertices: num_positions,
+                });
+            }
+        }
+    }
+
+    issues
+}
+
+fn deserialize<C: Loggable>(batch: &SerializedComponentBatch) -> Vec<C> {
+    C::from_arrow(&*batch.array).unwrap_or_default()
+}
+
+fn non_finite<C, const N: usize>(
+    component: ComponentIdentifier,
+    values: &[C],
+    coordinates: impl Fn(&C) -> [f32; N],
+) -> Option<ValidationIssue> {
+    values.iter().enumerate().find_map(|(index, value)| {
+        let value = coordinates(value);
+        (!value.iter().all(|v| v.is_finite())).then(|| ValidationIssue::NonFinitePosition {
+            component,
+            index,
+            value: value.to_vec(),
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use re_types::AsComponents as _;
+    use re_types::archetypes::{Mesh3D, Points3D, Transform3D};
+    use re_types::components::RotationQuat;
+    use re_types::datatypes::Quaternion;
+
+    use super::*;
+
+    fn issues(archetype: &dyn re_types::AsComponents) -> Vec<ValidationIssue> {
+        let components = archetype
+            .as_serialized_batches()
+            .into_iter()
+            .map(|batch| (batch.descriptor.component, batch))
+            .collect();
+        validate(&components)
+    }
+
+    #[test]
+    fn valid_data() {
+        assert!(
+            issues(&Points3D::new([(0.0, 1.0, 2.0), (3.0, 4.0, 5.0)]).with_radii([1.0])).is_empty()
+        );
+        assert!(
+            issues(
+                &Mesh3D::new([(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (0.0, 1.0, 0.0)])
+                    .with_triangle_indices([(0, 1, 2)])
+            )
+            .is_empty()
+        );
+    }
+
+    #[test]
+    fn invalid_data() {
+        assert!(matches!(
+            issues(&Points3D::new([(0.0, 1.0, 2.0), (3.0, f32::NAN, 5.0)]))[..],
+            [ValidationIssue::NonFinitePosition { index: 1, .. }]
+        ));
+
+        assert!(matches!(
+            issues(&Points3D::new([(0.0, 1.0, 2.0), (3.0, 4.0, 5.0)]).with_radii([1.0, 2.0, 3.0]))
+                [..],
+            [ValidationIssue::MismatchedLength {
+                len: 3,
+                num_positions: 2,
+                ..
+            }]
+        ));
+
+        assert!(matches!(
+            issues(
+                &Transform3D::default()
+                    .with_quaternion(RotationQuat(Quaternion::from_xyzw([0.0; 4])))
+            )[..],
+            [ValidationIssue::InvalidQuaternion { index: 0, .. }]
+        ));
+
+        assert!(matches!(
+            issues(
+                &Mesh3D::new([(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (0.0, 1.0, 0.0)])
+                    .with_triangle_indices([(0, 1, 3)])
+            )[..],
+            [ValidationIssue::IndexOutOfRange {
+                index: 0,
+                vertex: 3,
+                num_vertices: 3,
+                ..
+            }]
+        ));
+    }
+}
@@ -48,6 +48,11 @@ impl WebViewerSink {
     ) -> Result<Self, WebViewerSinkError> {
         let (server_shutdown_signal, shutdown) = re_grpc_server::shutdown::shutdown();
 
+        if server_options.tls.is_some() {
+            re_log::warn!("The hosted web viewer can't connect to a gRPC server that requires TLS");
+        }
+        let proxy_token = server_options.auth_token.clone();
+
         let grpc_server_addr = format!("{bind_ip}:{grpc_port}").parse()?;
         let uri = re_uri::ProxyUri::new(re_uri::Origin::from_scheme_and_socket_addr(
             re_uri::Scheme::RerunHttp,
@@ -76,12 +81,19 @@ impl WebViewerSink {
 
         let http_web_viewer_url = webviewer_server.server_url();
 
-        let viewer_url =
+        let mut viewer_url =
             if grpc_server_addr.ip().is_unspecified() || grpc_server_addr.ip().is_loopback() {
                 format!("{http_web_viewer_url}?url=rerun%2Bhttp://localhost:{grpc_port}/proxy")
             } else {
                 format!("{http_web_viewer_url}?url=rerun%2Bhttp://{grpc_server_addr}/proxy")
             };
+        if let Some(proxy_token) = proxy_token {
+            let proxy_token = percent_encoding::utf8_percent_encode(
+                &proxy_token,
+                percent_encoding::NON_ALPHANUMERIC,
+            );
+            viewer_url = format!("{viewer_url}&proxy_token={proxy_token}");
+        }
 
         re_log::info!("Hosting a web-viewer at {viewer_url}");
         if open_browser {
@@ -172,6 +184,11 @@ pub struct WebViewerConfig {
     /// Has no effect if [`Self::open_browser`] is false.
     pub video_decoder: Option<String>,
 
+    /// If set, the webviewer authenticates against message proxy servers with this token.
+    ///
+    /// It is passed on through the browser url, so only use this with trusted browsers.
+    pub proxy_token: Option<String>,
+
     /// If set to `true`, opens the default browser after hosting the webviewer.
     ///
     /// Defaults to `true`.
@@ -187,6 +204,7 @@ impl Default for WebViewerConfig {
             connect_to: Vec::new(),
             force_wgpu_backend: None,
             video_decoder: None,
+            proxy_token: None,
             open_browser: true,
         }
     }
@@ -208,6 +226,7 @@ impl WebViewerConfig {
             web_port,
             force_wgpu_backend,
             video_decoder,
+            proxy_token,
             open_browser,
         } = self;
 
@@ -241,6 +260,13 @@ impl WebViewerConfig {
         if let Some(video_decoder) = video_decoder {
             append_argument(format!("video_decoder={video_decoder}"));
         }
+        if let Some(proxy_token) = proxy_token {
+            let proxy_token = percent_encoding::utf8_percent_encode(
+                &proxy_token,
+                percent_encoding::NON_ALPHANUMERIC,
+            );
+            append_argument(format!("proxy_token={proxy_token}"));
+        }
 
         re_log::info!("Hosting a web-viewer at {viewer_url}");
         if open_browser {
@@ -0,0 +1,120 @@
+use arrow::array::{Array as _, AsArray as _};
+use arrow::datatypes::{DataType, Float32Type};
+use re_sdk::{AsComponents as _, Component as _, ComponentBatch as _, Loggable as _};
+
+#[derive(Clone, Debug, re_sdk::Component)]
+#[component(crate = "re_sdk")]
+struct Confidence(f32);
+
+#[derive(Clone, Debug, re_sdk::Component)]
+#[component(crate = "re_sdk", name = "user.Detection")]
+struct Detection {
+    label: String,
+
+    #[component(colormap = "viridis", min = 0, max = 1)]
+    confidence: Confidence,
+
+    #[component(unit = "m")]
+    distance: f32,
+}
+
+#[derive(re_sdk::AsComponents)]
+#[as_components(crate = "re_sdk")]
+struct DetectedPoints {
+    #[as_components(flatten)]
+    points: re_types::archetypes::Points3D,
+    detections: Vec<Detection>,
+    confidence: Option<Confidence>,
+
+    #[as_components(skip)]
+    #[expect(dead_code)]
+    internal: u32,
+}
+
+#[test]
+fn derive_component() {
+    assert_eq!(Confidence::name(), "user.Confidence".into());
+    assert_eq!(Confidence::arrow_datatype(), DataType::Float32);
+
+    let array = vec![Confidence(0.5), Confidence(1.0)].to_arrow().unwrap();
+    assert_eq!(
+        array.as_primitive::<Float32Type>().values().as_ref(),
+        [0.5, 1.0]
+    );
+
+    assert_eq!(Detection::name(), "user.Detection".into());
+    let array = [Detection {
+        label: "cat".to_owned(),
+        confidence: Confidence(0.9),
+        distance: 2.5,
+    }]
+    .to_arrow()
+    .unwrap();
+    let array = array.as_struct();
+    assert_eq!(array.len(), 1);
+    assert_eq!(array.column_names(), ["label", "confidence", "distance"]);
+    assert_eq!(array.column(0).as_string::<i32>().value(0), "cat");
+
+    let DataType::Struct(fields) = Detection::arrow_datatype() else {
+        panic!("expected a struct datatype");
+    };
+    assert!(re_sdk::DisplayHints::from_field(&fields[0]).is_empty());
+    assert_eq!(
+        re_sdk::DisplayHints::from_field(&fields[1]),
+        re_sdk::DisplayHints::default().with_colormap("viridis", [0.0, 1.0])
+    );
+    assert_eq!(
+        re_sdk::DisplayHints::from_field(&fields[2]),
+        re_sdk::DisplayHints::default().with_unit("m")
+    );
+}
+
+#[test]
+fn derive_as_components() {
+    let mut points = DetectedPoints {
+        points: re_types::archetypes::Points3D::new([(0.0, 0.0, 0.0), (1.0, 1.0, 1.0)]),
+        detections: vec![
+            Detection {
+                label: "cat".to_owned(),
+                confidence: Confidence(0.9),
+                distance: 2.5,
+            },
+            Detection {
+                label: "dog".to_owned(),
+                confidence: Confidence(0.8),
+                distance: 4.0,
+            },
+        ],
+        confidence: None,
+        internal: 42,
+    };
+
+    let components = |points: &DetectedPoints| {
+        points
+            .as_serialized_batches()
+            .into_iter()
+            .map(|batch| batch.descriptor.component.to_string())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        components(&points),
+        ["Points3D:positions", "user.DetectedPoints:detections"]
+    );
+
+    points.confidence = Some(Confidence(0.7));
+    let batches = points.as_serialized_batches();
+    let confidence = batches.last().unwrap();
+    assert_eq!(
+        confidence.descriptor.component,
+        "user.DetectedPoints:confidence".into()
+    );
+    assert_eq!(
+        confidence.descriptor.archetype,
+        Some("user.DetectedPoints".into())
+    );
+    assert_eq!(
+        confidence.descriptor.component_type,
+        Some(Confidence::name())
+    );
+}
@@ -0,0 +1,489 @@
+//! Derive macros for logging custom Rust types with the Rerun SDK.
+//!
+//! These are re-exported by `re_sdk` and `rerun`, see [`macro@Component`] and
+//! [`macro@AsComponents`].
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::spanned::Spanned as _;
+use syn::{Data, DeriveInput, Fields, GenericArgument, LitStr, PathArguments, Type};
+
+/// Implements `Loggable` and `Component` for a struct, so that it can be logged as a custom
+/// component.
+///
+/// - A newtype (`struct Confidence(f32);`) is serialized like its inner type.
+/// - A struct with named fields is serialized as an Arrow struct with one nullable field each.
+///
+/// Fields must either be `bool`, `String`, a numeric primitive, or implement `Loggable`.
+/// The struct must also implement `Clone`.
+///
+/// The component type defaults to `user.<StructName>`, and can be set with
+/// `#[component(name = "my.Component")]`.
+/// Use `#[component(crate = "re_sdk")]` if you depend on `re_sdk` rather than `rerun`.
+///
+/// Named fields can carry display hints, which the viewer uses when showing the component in
+/// the selection panel:
+/// - `#[component(unit = "m")]` shows a unit after each value.
+/// - `#[component(colormap = "viridis", min = 0.0, max = 1.0)]` shows a color from the given
+///   colormap next to each value.
+///
+/// ```ignore
+/// #[derive(Clone, rerun::Component)]
+/// #[component(name = "user.Detection")]
+/// struct Detection {
+///     label: String,
+///
+///     #[component(colormap = "viridis", min = 0.0, max = 1.0)]
+///     score: f32,
+///
+///     #[component(unit = "m")]
+///     distance: f32,
+/// }
+/// ```
+#[proc_macro_derive(Component, attributes(component))]
+pub fn derive_component(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    derive_component_impl(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Implements `AsComponents` for a struct, so that it can be logged as a custom archetype.
+///
+/// Each field is logged as a component with a generated descriptor: the archetype defaults to
+/// `user.<StructName>` and the component to `<archetype>:<field_name>`.
+///
+/// Fields must be a component `C`, a `Vec<C>` or a `[C; N]`, optionally wrapped in an `Option`,
+/// in which case nothing is logged for `None`.
+///
+/// Attributes:
+/// - `#[as_components(archetype = "my.Archetype")]` on the struct sets the archetype name.
+/// - `#[as_components(crate = "re_sdk")]` on the struct, if you depend on `re_sdk` rather than `rerun`.
+/// - `#[as_components(flatten)]` on a field logs all components of a field that implements
+///   `AsComponents` itself, e.g. a builtin archetype.
+/// - `#[as_components(skip)]` on a field ignores it.
+///
+/// ```ignore
+/// #[derive(rerun::AsComponents)]
+/// struct CustomPoints3D {
+///     #[as_components(flatten)]
+///     points: rerun::Points3D,
+///     confidences: Option<Vec<Confidence>>,
+/// }
+/// ```
+#[proc_macro_derive(AsComponents, attributes(as_components))]
+pub fn derive_as_components(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    derive_as_components_impl(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+// ---
+
+/// Attributes on the struct itself.
+struct ContainerAttributes {
+    /// The path of the crate the generated code refers to.
+    krate: syn::Path,
+
+    /// Component or archetype name.
+    name: String,
+}
+
+impl ContainerAttributes {
+    fn parse(input: &DeriveInput, attribute: &str, name_key: &str) -> syn::Result<Self> {
+        let mut krate: syn::Path = syn::parse_quote!(::rerun);
+        let mut name = format!("user.{}", input.ident);
+
+        for attr in input
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident(attribute))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("crate") {
+                    krate = meta.value()?.parse::<LitStr>()?.parse()?;
+                    Ok(())
+                } else if meta.path.is_ident(name_key) {
+                    name = meta.value()?.parse::<LitStr>()?.value();
+                    Ok(())
+                } else {
+                    Err(meta.error(format!("expected `crate` or `{name_key}`")))
+                }
+            })?;
+        }
+
+        Ok(Self { krate, name })
+    }
+}
+
+/// Display hints on a field of a `Component` struct, see `DisplayHints`.
+#[derive(Default)]
+struct DisplayHintAttributes {
+    unit: Option<String>,
+    colormap: Option<String>,
+    min: Option<syn::Expr>,
+    max: Option<syn::Expr>,
+}
+
+impl DisplayHintAttributes {
+    fn parse(field: &syn::Field) -> syn::Result<Self> {
+        let mut hints = Self::default();
+
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("component"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("unit") {
+                    hints.unit = Some(meta.value()?.parse::<LitStr>()?.value());
+                    Ok(())
+                } else if meta.path.is_ident("colormap") {
+                    hints.colormap = Some(meta.value()?.parse::<LitStr>()?.value());
+                    Ok(())
+                } else if meta.path.is_ident("min") {
+                    hints.min = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("max") {
+                    hints.max = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `unit`, `colormap`, `min` or `max`"))
+                }
+            })?;
+        }
+
+        if hints.colormap.is_some() != (hints.min.is_some() && hints.max.is_some())
+            || hints.min.is_some() != hints.max.is_some()
+        {
+            return Err(syn::Error::new(
+                field.span(),
+                "`colormap`, `min` and `max` must be used together",
+            ));
+        }
+
+        Ok(hints)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.unit.is_none() && self.colormap.is_none()
+    }
+
+    /// Expression that constructs the `DisplayHints`.
+    fn to_tokens(&self, support: &TokenStream) -> TokenStream {
+        let Self {
+            unit,
+            colormap,
+            min,
+            max,
+        } = self;
+
+        let unit = match unit {
+            Some(unit) => quote!(Some(#unit.to_owned())),
+            None => quote!(None),
+        };
+        let colormap = match colormap {
+            Some(colormap) => quote!(Some(#colormap.to_owned())),
+            None => quote!(None),
+        };
+        let range = match (min, max) {
+            (Some(min), Some(max)) => quote!(Some([(#min) as f64, (#max) as f64])),
+            _ => quote!(None),
+        };
+
+        quote! {
+            #support::DisplayHints {
+                unit: #unit,
+                colormap: #colormap,
+                range: #range,
+            }
+        }
+    }
+}
+
+const PRIMITIVE_TYPES: &[&str] = &[
+    "bool", "f32", "f64", "i8", "i16", "i32", "i64", "u8", "u16", "u32", "u64", "String",
+];
+
+/// The `derive_support` functions that convert values of this type to Arrow.
+fn field_serializer(krate: &syn::Path, ty: &Type) -> (TokenStream, TokenStream) {
+    let is_primitive = matches!(ty, Type::Path(path) if path.qself.is_none()
+        && path.path.get_ident().is_some_and(|ident| PRIMITIVE_TYPES.contains(&ident.to_string().as_str())));
+
+    if is_primitive {
+        (
+            quote!(#krate::derive_support::primitive_datatype::<#ty>),
+            quote!(#krate::derive_support::primitive_to_arrow::<#ty>),
+        )
+    } else {
+        (
+            quote!(#krate::derive_support::loggable_datatype::<#ty>),
+            quote!(#krate::derive_support::loggable_to_arrow::<#ty>),
+        )
+    }
+}
+
+fn derive_component_impl(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let ContainerAttributes { krate, name } =
+        ContainerAttributes::parse(input, "component", "name")?;
+    let support = quote!(#krate::derive_support);
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.span(),
+            "`Component` can only be derived for structs",
+        ));
+    };
+
+    let (datatype, to_arrow, heap_size_bytes) = match &data.fields {
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+            let field = &fields.unnamed[0];
+            if !DisplayHintAttributes::parse(field)?.is_empty() {
+                return Err(syn::Error::new(
+                    field.span(),
+                    "display hints are only supported on named fields",
+                ));
+            }
+
+            let ty = &field.ty;
+            let (datatype_fn, to_arrow_fn) = field_serializer(&krate, ty);
+            (
+                quote!(#datatype_fn()),
+                quote!(#to_arrow_fn(data.into_iter().map(|value| value.map(|value| value.0)).collect())),
+                quote!(#support::SizeBytes::heap_size_bytes(&self.0)),
+            )
+        }
+
+        Fields::Named(fields) if !fields.named.is_empty() => {
+            let names = fields
+                .named
+                .iter()
+                .map(|field| field.ident.clone().expect("named field"))
+                .collect::<Vec<_>>();
+            let name_strs = names.iter().map(ToString::to_string).collect::<Vec<_>>();
+            let serializers = fields
+                .named
+                .iter()
+                .map(|field| field_serializer(&krate, &field.ty))
+                .collect::<Vec<_>>();
+            let hints = fields
+                .named
+                .iter()
+                .map(|field| Ok(DisplayHintAttributes::parse(field)?.to_tokens(&support)))
+                .collect::<syn::Result<Vec<_>>>()?;
+            let datatype_fns = serializers.iter().map(|(datatype_fn, _)| datatype_fn);
+            let to_arrow_fns = serializers.iter().map(|(_, to_arrow_fn)| to_arrow_fn);
+
+            let fields_expr = quote! {
+                vec![#(#support::field(#name_strs, #datatype_fns(), #hints)),*]
+            };
+
+            (
+                quote!(#support::arrow::datatypes::DataType::Struct(#fields_expr.into())),
+                quote! {
+                    #support::struct_to_arrow(
+                        #fields_expr,
+                        data.iter().map(Option::is_some).collect(),
+                        vec![#(
+                            #to_arrow_fns(
+                                data.iter()
+                                    .map(|value| value.as_ref().map(|value| value.#names.clone()))
+                                    .collect(),
+                            )?
+                        ),*],
+                    )
+                },
+                quote!(#(#support::SizeBytes::heap_size_bytes(&self.#names))+*),
+            )
+        }
+
+        _ => {
+            return Err(syn::Error::new(
+                input.span(),
+                "`Component` can only be derived for newtypes and structs with named fields",
+            ));
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics #support::SizeBytes for #ident #ty_generics #where_clause {
+            #[inline]
+            fn heap_size_bytes(&self) -> u64 {
+                #heap_size_bytes
+            }
+        }
+
+        impl #impl_generics #support::Loggable for #ident #ty_generics #where_clause {
+            #[inline]
+            fn arrow_datatype() -> #support::arrow::datatypes::DataType {
+                #datatype
+            }
+
+            fn to_arrow_opt<'a>(
+                data: impl IntoIterator<Item = Option<impl Into<::std::borrow::Cow<'a, Self>>>>,
+            ) -> #support::SerializationResult<#support::arrow::array::ArrayRef>
+            where
+                Self: 'a,
+            {
+                let data: Vec<Option<Self>> = data
+                    .into_iter()
+                    .map(|value| value.map(|value| value.into().into_owned()))
+                    .collect();
+                #to_arrow
+            }
+        }
+
+        impl #impl_generics #support::Component for #ident #ty_generics #where_clause {
+            #[inline]
+            fn name() -> #support::ComponentType {
+                #name.into()
+            }
+        }
+    })
+}
+
+// ---
+
+/// How a field of an `AsComponents` struct is logged.
+enum FieldKind {
+    Component,
+    Flatten,
+    Skip,
+}
+
+fn field_kind(field: &syn::Field) -> syn::Result<FieldKind> {
+    let mut kind = FieldKind::Component;
+
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("as_components"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("flatten") {
+                kind = FieldKind::Flatten;
+                Ok(())
+            } else if meta.path.is_ident("skip") {
+                kind = FieldKind::Skip;
+                Ok(())
+            } else {
+                Err(meta.error("expected `flatten` or `skip`"))
+            }
+        })?;
+    }
+
+    Ok(kind)
+}
+
+/// If `ty` is `Wrapper<T>`, returns `T`.
+fn generic_argument<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != wrapper {
+        return None;
+    }
+
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    match arguments.args.first()? {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    }
+}
+
+/// The component type of a field, i.e. `C` for `C`, `Vec<C>` or `[C; N]`.
+fn component_type(ty: &Type) -> &Type {
+    if let Some(ty) = generic_argument(ty, "Vec") {
+        ty
+    } else if let Type::Array(array) = ty {
+        &array.elem
+    } else {
+        ty
+    }
+}
+
+fn derive_as_components_impl(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let ContainerAttributes {
+        krate,
+        name: archetype,
+    } = ContainerAttributes::parse(input, "as_components", "archetype")?;
+    let support = quote!(#krate::derive_support);
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new(
+                    input.span(),
+                    "`AsComponents` can only be derived for structs with named fields",
+                ));
+            }
+        },
+        _ => {
+            return Err(syn::Error::new(
+                input.span(),
+                "`AsComponents` can only be derived for structs",
+            ));
+        }
+    };
+
+    let mut statements = Vec::new();
+    for field in fields {
+        let field_ident = field.ident.clone().expect("named field");
+
+        match field_kind(field)? {
+            FieldKind::Skip => {}
+
+            FieldKind::Flatten => statements.push(quote! {
+                batches.extend(#support::AsComponents::as_serialized_batches(&self.#field_ident));
+            }),
+
+            FieldKind::Component => {
+                let component = format!("{archetype}:{field_ident}");
+                let (batch_ty, is_optional) = match generic_argument(&field.ty, "Option") {
+                    Some(ty) => (ty, true),
+                    None => (&field.ty, false),
+                };
+                let component_ty = component_type(batch_ty);
+
+                let descriptor = quote! {
+                    #support::ComponentDescriptor {
+                        archetype: Some(#archetype.into()),
+                        component: #component.into(),
+                        component_type: Some(<#component_ty as #support::Component>::name()),
+                    }
+                };
+
+                statements.push(if is_optional {
+                    quote! {
+                        if let Some(batch) = &self.#field_ident {
+                            batches.extend(#support::ComponentBatch::serialized(batch, #descriptor));
+                        }
+                    }
+                } else {
+                    quote! {
+                        batches.extend(#support::ComponentBatch::serialized(&self.#field_ident, #descriptor));
+                    }
+                });
+            }
+        }
+    }
+
+    Ok(quote! {
+        impl #impl_generics #support::AsComponents for #ident #ty_generics #where_clause {
+            fn as_serialized_batches(&self) -> Vec<#support::SerializedComponentBatch> {
+                let mut batches = Vec::new();
+                #(#statements)*
+                batches
+            }
+        }
+    })
+}
@@ -143,6 +143,8 @@ impl RerunArgs {
 
                     memory_limit: re_sdk::MemoryLimit::parse(&self.server_memory_limit)
                         .map_err(|err| anyhow::format_err!("Bad --server-memory-limit: {err}"))?,
+
+                    ..Default::default()
                 };
 
                 let rec = RecordingStreamBuilder::new("rerun_example_minimal_serve")
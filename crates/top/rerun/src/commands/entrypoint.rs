@@ -121,6 +121,45 @@ Default is `0B`, or `25%` if any of the `--serve-*` flags are set."
     #[clap(long)]
     newest_first: bool,
 
+    /// Require clients of the gRPC server to authenticate with this token.
+    ///
+    /// Defaults to the value of the `RERUN_GRPC_TOKEN` environment variable, which is also
+    /// where the SDKs look for the token to send.
+    #[clap(long)]
+    server_token: Option<String>,
+
+    /// Only accept TLS connections to the gRPC server, using this PEM-encoded certificate chain.
+    ///
+    /// Requires `--tls-key`.
+    #[clap(long, requires = "tls_key", conflicts_with = "tls_self_signed")]
+    tls_cert: Option<std::path::PathBuf>,
+
+    /// The PEM-encoded private key matching `--tls-cert`.
+    #[clap(long, requires = "tls_cert")]
+    tls_key: Option<std::path::PathBuf>,
+
+    /// Only accept TLS connections to the gRPC server, using a freshly generated self-signed
+    /// certificate, which is saved to the given path.
+    ///
+    /// Clients must be told to trust that certificate, e.g. by pointing the
+    /// `RERUN_GRPC_CA_CERT` environment variable at it.
+    #[clap(long)]
+    tls_self_signed: Option<std::path::PathBuf>,
+
+    /// Log the entities of each client of the gRPC server under `/<client name>/…`.
+    ///
+    /// Useful when several clients, e.g. one per robot, log into the same recording.
+    /// Clients name themselves with the `RERUN_GRPC_CLIENT_NAME` environment variable.
+    #[clap(long)]
+    prefix_client_entities: bool,
+
+    /// Estimate the clock offset of each client of the gRPC server, and shift their timestamp
+    /// timelines to compensate.
+    ///
+    /// Useful when several clients with unsynchronized clocks log into the same recording.
+    #[clap(long)]
+    align_client_clocks: bool,
+
     #[clap(
         long,
         default_value_t = true,
@@ -141,6 +180,14 @@ When persisted, the state will be stored at the following locations:
     #[clap(long)]
     profile: bool,
 
+    /// Replay the files passed on the command line at the wall-clock speed they were recorded at,
+    /// times this factor, instead of loading them as fast as possible.
+    ///
+    /// E.g. `1` replays in real time, and `2` twice as fast.
+    /// Useful to rehearse how live data behaves, e.g. in a dashboard, from an .rrd file.
+    #[clap(long, value_name = "FACTOR", conflicts_with_all = ["save", "test_receive"])]
+    replay_speed: Option<f64>,
+
     /// Stream incoming log events to an .rrd file at the given path.
     #[clap(long)]
     save: Option<String>,
@@ -181,6 +228,9 @@ When persisted, the state will be stored at the following locations:
     /// and the pathname must be `/proxy`.
     ///
     /// The default is `rerun+http://127.0.0.1:9876/proxy`.
+    ///
+    /// Servers requiring a token are authenticated against with the `RERUN_GRPC_TOKEN`
+    /// environment variable, and `RERUN_GRPC_CA_CERT` can point at a certificate to trust.
     #[clap(long)]
     #[expect(clippy::option_option)] // Tri-state: none, --connect, --connect <url>.
     connect: Option<Option<String>>,
@@ -244,6 +294,29 @@ If no arguments are given, a server will be hosted which a Rerun SDK can connect
     #[clap(long)]
     detach_process: bool,
 
+    /// Keep the viewer running in the background when its window is closed.
+    ///
+    /// Data from connected SDKs keeps being ingested, and the window pops back up when an
+    /// error-level `TextLog` arrives.
+    /// If Rerun was compiled with the `system_tray` feature, the viewer is hidden to the
+    /// system tray, from where it can be shown again or quit. Otherwise it is minimized.
+    #[clap(long)]
+    background: bool,
+
+    /// Report frames of the viewer that take longer than this many milliseconds.
+    ///
+    /// For each stalled frame, a puffin profile and the most recent store events are written to
+    /// a temporary directory. Useful for attaching to "the viewer froze" bug reports.
+    #[clap(long, value_name = "MS")]
+    frame_watchdog: Option<u64>,
+
+    /// Write a summary of the viewer session to this path on exit.
+    ///
+    /// The summary contains ingest totals, peak memory use, frame time percentiles per
+    /// recording, and query cache hit rates. Useful for attaching to performance bug reports.
+    #[clap(long, value_name = "PATH")]
+    session_report: Option<std::path::PathBuf>,
+
     /// Set the screen resolution (in logical points), e.g. "1920x1080".
     /// Useful together with `--screenshot-to`.
     #[clap(long)]
@@ -754,7 +827,29 @@ fn run_impl(
             re_memory::MemoryLimit::parse(value)
                 .map_err(|err| anyhow::format_err!("Bad --server-memory-limit: {err}"))?
         },
-    };
+
+        auth_token: args.server_token.clone(),
+
+        tls: if let (Some(cert_path), Some(key_path)) = (&args.tls_cert, &args.tls_key) {
+            Some(re_sdk::TlsOptions::Files {
+                cert_path: cert_path.clone(),
+                key_path: key_path.clone(),
+            })
+        } else {
+            args.tls_self_signed
+                .clone()
+                .map(|path| re_sdk::TlsOptions::SelfSigned {
+                    subject_alt_names: Vec::new(),
+                    save_cert_to: Some(path),
+                })
+        },
+
+        merge: re_sdk::MergeOptions {
+            prefix_entities: args.prefix_client_entities,
+            align_clocks: args.align_client_clocks,
+        },
+    }
+    .apply_env();
 
     // All URLs that we want to process.
     #[allow(clippy::allow_attributes, unused_mut)]
@@ -790,6 +885,7 @@ fn run_impl(
                     &connection_registry,
                     server_addr,
                     server_options,
+                    args.replay_speed,
                 )
             } else {
                 Err(anyhow::anyhow!(
@@ -822,11 +918,17 @@ fn run_impl(
                     server_addr,
                     server_options,
                     open_browser,
+                    args.replay_speed,
                 )
             }
         }
     } else if args.connect.is_none() && is_another_server_already_running(server_addr) {
-        connect_to_existing_server(url_or_paths, &connection_registry, server_addr)
+        connect_to_existing_server(
+            url_or_paths,
+            &connection_registry,
+            server_addr,
+            args.replay_speed,
+        )
     } else {
         cfg_if::cfg_if! {
             if #[cfg(feature = "native_viewer")] {
@@ -879,7 +981,7 @@ fn start_native_viewer(
         urls_to_pass_on_to_viewer,
     } = ReceiversFromUrlParams::new(
         url_or_paths,
-        &UrlParamProcessingConfig::native_viewer(),
+        &UrlParamProcessingConfig::native_viewer().with_replay_speed(args.replay_speed),
         &connection_registry,
     )?;
     #[allow(clippy::allow_attributes, unused_mut)]
@@ -982,6 +1084,9 @@ fn native_startup_options_from_args(args: &Args) -> anyhow::Result<re_viewer::St
         },
         force_wgpu_backend: args.renderer.clone(),
         video_decoder_hw_acceleration,
+        run_in_background: args.background,
+        frame_watchdog_threshold: args.frame_watchdog.map(std::time::Duration::from_millis),
+        session_report_path: args.session_report.clone(),
 
         ..Default::default()
     })
@@ -991,6 +1096,7 @@ fn connect_to_existing_server(
     url_or_paths: Vec<String>,
     connection_registry: &re_redap_client::ConnectionRegistryHandle,
     server_addr: std::net::SocketAddr,
+    replay_speed: Option<f64>,
 ) -> anyhow::Result<()> {
     use re_sdk::sink::LogSink as _;
 
@@ -999,7 +1105,8 @@ fn connect_to_existing_server(
     let sink = re_sdk::sink::GrpcSink::new(uri);
     let receivers = ReceiversFromUrlParams::new(
         url_or_paths,
-        &UrlParamProcessingConfig::convert_everything_to_data_sources(),
+        &UrlParamProcessingConfig::convert_everything_to_data_sources()
+            .with_replay_speed(replay_speed),
         connection_registry,
     )?;
     if !receivers.urls_to_pass_on_to_viewer.is_empty() {
@@ -1042,18 +1149,20 @@ fn serve_web(
     server_addr: std::net::SocketAddr,
     server_options: re_sdk::ServerOptions,
     open_browser: bool,
+    replay_speed: Option<f64>,
 ) -> anyhow::Result<()> {
     let ReceiversFromUrlParams {
         log_receivers,
         mut urls_to_pass_on_to_viewer,
     } = ReceiversFromUrlParams::new(
         url_or_paths,
-        &UrlParamProcessingConfig::grpc_server_and_web_viewer(),
+        &UrlParamProcessingConfig::grpc_server_and_web_viewer().with_replay_speed(replay_speed),
         connection_registry,
     )?;
 
     // Don't spawn a server if there's only a bunch of URIs that we want to view directly.
     let spawn_server = !log_receivers.is_empty() || urls_to_pass_on_to_viewer.is_empty();
+    let mut proxy_token = None;
     if spawn_server {
         if server_addr.port() == web_viewer_port {
             anyhow::bail!(
@@ -1063,6 +1172,9 @@ fn serve_web(
             );
         }
 
+        let scheme = server_options.scheme();
+        proxy_token.clone_from(&server_options.auth_token);
+
         // Spawn a server which the Web Viewer can connect to.
         // All `rxs` are consumed by the server.
         re_grpc_server::spawn_from_rx_set(
@@ -1074,9 +1186,9 @@ fn serve_web(
 
         // Add the proxy URL to the url parameters.
         let proxy_url = if server_addr.ip().is_unspecified() || server_addr.ip().is_loopback() {
-            format!("rerun+http://localhost:{}/proxy", server_addr.port())
+            format!("{scheme}://localhost:{}/proxy", server_addr.port())
         } else {
-            format!("rerun+http://{server_addr}/proxy")
+            format!("{scheme}://{server_addr}/proxy")
         };
 
         debug_assert!(
@@ -1094,6 +1206,7 @@ fn serve_web(
         connect_to: urls_to_pass_on_to_viewer,
         force_wgpu_backend,
         video_decoder,
+        proxy_token,
         open_browser,
     }
     .host_web_viewer()?
@@ -1109,6 +1222,7 @@ fn serve_grpc(
     connection_registry: &re_redap_client::ConnectionRegistryHandle,
     server_addr: std::net::SocketAddr,
     server_options: re_sdk::ServerOptions,
+    replay_speed: Option<f64>,
 ) -> anyhow::Result<()> {
     if !cfg!(feature = "server") {
         anyhow::bail!("Can't host server - rerun was not compiled with the 'server' feature");
@@ -1116,7 +1230,8 @@ fn serve_grpc(
 
     let receivers = ReceiversFromUrlParams::new(
         url_or_paths,
-        &UrlParamProcessingConfig::convert_everything_to_data_sources(),
+        &UrlParamProcessingConfig::convert_everything_to_data_sources()
+            .with_replay_speed(replay_speed),
         connection_registry,
     )?;
     receivers.error_on_unhandled_urls("--serve-grpc")?;
@@ -1383,6 +1498,9 @@ struct UrlParamProcessingConfig {
     data_sources_from_http_urls: bool,
     data_sources_from_redap_datasets: bool,
     data_source_from_filepaths: bool,
+
+    /// Replay files at this multiple of the speed they were recorded at, see `--replay-speed`.
+    replay_speed: Option<f64>,
 }
 
 impl UrlParamProcessingConfig {
@@ -1395,6 +1513,7 @@ impl UrlParamProcessingConfig {
             data_sources_from_http_urls: true,
             data_sources_from_redap_datasets: true,
             data_source_from_filepaths: true,
+            replay_speed: None,
         }
     }
 
@@ -1405,6 +1524,7 @@ impl UrlParamProcessingConfig {
             data_sources_from_http_urls: false,
             data_sources_from_redap_datasets: false,
             data_source_from_filepaths: true,
+            replay_speed: None,
         }
     }
 
@@ -1415,8 +1535,19 @@ impl UrlParamProcessingConfig {
             data_sources_from_http_urls: false,
             data_sources_from_redap_datasets: false,
             data_source_from_filepaths: false,
+            replay_speed: None,
         }
     }
+
+    /// Replay the files at the given multiple of the speed they were recorded at.
+    fn with_replay_speed(mut self, replay_speed: Option<f64>) -> Self {
+        if replay_speed.is_some() {
+            // Files handed on to the viewer are loaded as fast as possible.
+            self.data_source_from_filepaths = true;
+        }
+        self.replay_speed = replay_speed;
+        self
+    }
 }
 
 /// Log receivers created from URLs or path parameters that were passed in on the CLI.
@@ -1468,7 +1599,9 @@ impl ReceiversFromUrlParams {
                         }
                     }
 
-                    LogDataSource::FileContents(..) | LogDataSource::Stdin => {
+                    LogDataSource::FileContents(..)
+                    | LogDataSource::Stdin
+                    | LogDataSource::FoxgloveWebSocket(..) => {
                         data_sources.push(data_source);
                     }
                 }
@@ -1481,8 +1614,13 @@ impl ReceiversFromUrlParams {
         let log_receivers = data_sources
             .into_iter()
             .map(|data_source| {
+                let is_file = matches!(data_source, LogDataSource::FilePath(..));
                 let on_msg = None;
-                data_source.stream(connection_registry, on_msg)
+                let rx = data_source.stream(connection_registry, on_msg)?;
+                match config.replay_speed {
+                    Some(speed) if is_file => re_data_source::replay_at_recorded_speed(rx, speed),
+                    _ => Ok(rx),
+                }
             })
             .collect::<anyhow::Result<Vec<_>>>()?;
 
@@ -36,7 +36,8 @@ mod analytics;
 pub use self::entrypoint::run;
 pub use self::rrd::RrdCommands;
 pub use self::stdio::{
-    read_raw_rrd_streams_from_file_or_stdin, read_rrd_streams_from_file_or_stdin,
+    read_indexed_rrd_file, read_raw_rrd_streams_from_file_or_stdin,
+    read_rrd_streams_from_file_or_stdin,
 };
 
 #[cfg(feature = "data_loaders")]
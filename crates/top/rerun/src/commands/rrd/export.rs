@@ -0,0 +1,363 @@
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::Context as _;
+use arrow::{
+    array::{ArrayRef, RecordBatch as ArrowRecordBatch, RecordBatchOptions},
+    datatypes::{Field as ArrowField, Schema as ArrowSchema},
+};
+
+use re_log_types::{EntityPath, LogMsg, ResolvedEntityPathFilter};
+use re_sorbet::ColumnKind;
+
+use super::filter::entity_filter_from_rules;
+use super::sensitive::{SensitiveEntities, SensitiveMode};
+use crate::commands::read_rrd_streams_from_file_or_stdin;
+
+// ---
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum ExportFormat {
+    Parquet,
+    Arrow,
+}
+
+impl ExportFormat {
+    fn file_extension(self) -> &'static str {
+        match self {
+            Self::Parquet => "parquet",
+            Self::Arrow => "arrow",
+        }
+    }
+}
+
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ExportCommand {
+    /// Paths to read from. Reads from standard input if none are specified.
+    path_to_input_rrds: Vec<String>,
+
+    /// Directory to write the exported files to. It is created if needed.
+    #[arg(short = 'o', long = "output", value_name = "DIR")]
+    path_to_output_dir: PathBuf,
+
+    /// The file format to export to.
+    ///
+    /// `arrow` writes Arrow IPC files (also known as Feather v2).
+    #[clap(long = "format", value_enum, default_value_t = ExportFormat::Parquet)]
+    format: ExportFormat,
+
+    /// Entity path filter rules: only the matching entities are exported, e.g.
+    /// `--entity '+ /world/**' --entity '- /world/secret/**'`.
+    ///
+    /// If all rules are exclusions, everything else is exported.
+    #[clap(long = "entity", value_name = "RULE")]
+    entity_rules: Vec<String>,
+
+    /// Only export these components, e.g. `Points3D:positions`. Exports all components if unspecified.
+    #[clap(long = "component")]
+    components: Vec<String>,
+
+    /// What to do with entities tagged as `Sensitive`, and everything below them.
+    ///
+    /// The input is read twice for this, so it can't be standard input.
+    #[clap(long = "sensitive", value_enum)]
+    sensitive: Option<SensitiveMode>,
+
+    /// Only treat entities tagged with one of these tags as sensitive, e.g. `faces`.
+    ///
+    /// All tags count if unspecified.
+    #[clap(long = "sensitive-tag", value_name = "TAG", requires = "sensitive")]
+    sensitive_tags: Vec<String>,
+
+    /// If set, will try to proceed even in the face of IO and/or decoding errors in the input data.
+    #[clap(long = "continue-on-error", default_value_t = false)]
+    continue_on_error: bool,
+}
+
+impl ExportCommand {
+    pub fn run(&self) -> anyhow::Result<()> {
+        let Self {
+            path_to_input_rrds,
+            path_to_output_dir,
+            format,
+            entity_rules,
+            components,
+            sensitive,
+            sensitive_tags,
+            continue_on_error,
+        } = self;
+
+        let now = std::time::Instant::now();
+        re_log::info!(srcs = ?path_to_input_rrds, dst = ?path_to_output_dir, "export started");
+
+        let selection = Selection {
+            entity_filter: entity_filter_from_rules(entity_rules)?,
+            components: components.iter().cloned().collect(),
+        };
+        let sensitive_entities = sensitive
+            .map(|mode| SensitiveEntities::find(path_to_input_rrds, mode, sensitive_tags))
+            .transpose()?;
+
+        // Each entity ends up in a single file, so everything has to be gathered first.
+        let mut batches_per_recording: BTreeMap<
+            String,
+            BTreeMap<EntityPath, Vec<ArrowRecordBatch>>,
+        > = BTreeMap::new();
+
+        let (rx_decoder, _rx_size_bytes) = read_rrd_streams_from_file_or_stdin(path_to_input_rrds);
+
+        for (_source, res) in rx_decoder {
+            let mut is_success = true;
+
+            match res {
+                Ok(LogMsg::ArrowMsg(store_id, msg)) if store_id.is_recording() => {
+                    let batch = re_sorbet::ChunkBatch::try_from(&msg.batch)
+                        .map_err(anyhow::Error::from)
+                        .and_then(|batch| match &sensitive_entities {
+                            Some(sensitive_entities) => sensitive_entities.apply(&store_id, &batch),
+                            None => Ok(Some(batch)),
+                        });
+
+                    match batch {
+                        Ok(None) => {}
+                        Ok(Some(batch)) => {
+                            if let Some(exported) = selection.select(&batch) {
+                                batches_per_recording
+                                    .entry(store_id.recording_id().as_str().to_owned())
+                                    .or_default()
+                                    .entry(batch.entity_path().clone())
+                                    .or_default()
+                                    .push(exported);
+                            }
+                        }
+                        Err(err) => {
+                            re_log::warn_once!("Failed to read chunk: {err}");
+                        }
+                    }
+                }
+
+                Ok(_) => {}
+
+                Err(err) => {
+                    re_log::error!(err = re_error::format(err));
+                    is_success = false;
+                }
+            }
+
+            if !*continue_on_error && !is_success {
+                anyhow::bail!(
+                    "one or more IO and/or decoding failures in the input stream (check logs)"
+                )
+            }
+        }
+
+        // Only nest the files by recording when there is more than one of them.
+        let nest_by_recording = batches_per_recording.len() > 1;
+
+        let mut num_files = 0;
+        for (recording_id, batches_per_entity) in batches_per_recording {
+            let recording_dir = if nest_by_recording {
+                path_to_output_dir.join(sanitize_file_name(&recording_id))
+            } else {
+                path_to_output_dir.clone()
+            };
+
+            for (entity_path, batches) in batches_per_entity {
+                let path = entity_file_path(&recording_dir, &entity_path, *format);
+                write_entity(&path, *format, &batches)
+                    .with_context(|| format!("couldn't export {entity_path} to {path:?}"))?;
+                num_files += 1;
+            }
+        }
+
+        re_log::info!(
+            num_files,
+            time = ?now.elapsed(),
+            dst = ?path_to_output_dir,
+            "export finished"
+        );
+
+        Ok(())
+    }
+}
+
+// ---
+
+struct Selection {
+    entity_filter: Option<ResolvedEntityPathFilter>,
+    components: HashSet<String>,
+}
+
+impl Selection {
+    /// The selected columns of the chunk, renamed to plain names and stripped of all Rerun metadata.
+    ///
+    /// Returns `None` if no component of the chunk is selected.
+    fn select(&self, batch: &re_sorbet::ChunkBatch) -> Option<ArrowRecordBatch> {
+        let entity_path = batch.entity_path();
+        if let Some(entity_filter) = &self.entity_filter
+            && !entity_filter.matches(entity_path)
+        {
+            return None;
+        }
+
+        let mut num_components = 0;
+        let (fields, columns): (Vec<_>, Vec<_>) =
+            itertools::izip!(&batch.schema().fields, batch.columns())
+                .filter_map(|(field, column)| {
+                    let name = match ColumnKind::try_from(field.as_ref()) {
+                        Ok(ColumnKind::RowId) => field.name().clone(),
+
+                        Ok(ColumnKind::Index) => {
+                            re_sorbet::IndexColumnDescriptor::try_from(field.as_ref())
+                                .ok()?
+                                .column_name()
+                                .to_owned()
+                        }
+
+                        Ok(ColumnKind::Component) => {
+                            let component = re_sorbet::ComponentColumnDescriptor::from_arrow_field(
+                                Some(entity_path),
+                                field,
+                            )
+                            .component;
+                            if !self.components.is_empty()
+                                && !self.components.contains(component.as_str())
+                            {
+                                return None;
+                            }
+                            num_components += 1;
+                            component.as_str().to_owned()
+                        }
+
+                        Err(_) => return None,
+                    };
+
+                    let field = ArrowField::new(name, field.data_type().clone(), true);
+                    Some((Arc::new(field), column.clone()))
+                })
+                .unzip();
+
+        if num_components == 0 {
+            return None;
+        }
+
+        ArrowRecordBatch::try_new_with_options(
+            ArrowSchema::new(fields).into(),
+            columns,
+            &RecordBatchOptions::default().with_row_count(Some(batch.num_rows())),
+        )
+        .ok()
+    }
+}
+
+/// E.g. `/world/points` is exported to `<dir>/world/points.parquet`.
+fn entity_file_path(dir: &Path, entity_path: &EntityPath, format: ExportFormat) -> PathBuf {
+    let mut path = dir.to_path_buf();
+    if entity_path.is_root() {
+        path.push("__root");
+    } else {
+        for part in entity_path.iter() {
+            path.push(sanitize_file_name(part.unescaped_str()));
+        }
+    }
+    path.set_extension(format.file_extension());
+    path
+}
+
+fn sanitize_file_name(file_name: &str) -> String {
+    file_name.replace(
+        |c: char| !(c.is_alphanumeric() || matches!(c, '-' | '_' | '.')),
+        "_",
+    )
+}
+
+/// Writes all the chunks of an entity to a single file.
+///
+/// The chunks of an entity don't necessarily share the same columns: the file contains the union
+/// of all of them, filled with nulls where a chunk doesn't have a column.
+fn write_entity(
+    path: &Path,
+    format: ExportFormat,
+    batches: &[ArrowRecordBatch],
+) -> anyhow::Result<()> {
+    let schema = union_schema(batches)?;
+    let batches = batches
+        .iter()
+        .map(|batch| conform_to_schema(batch, &schema))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let file = std::io::BufWriter::new(std::fs::File::create(path)?);
+
+    match format {
+        ExportFormat::Parquet => {
+            let props = parquet::file::properties::WriterProperties::builder()
+                .set_compression(parquet::basic::Compression::SNAPPY)
+                .build();
+            let mut writer = parquet::arrow::ArrowWriter::try_new(file, schema, Some(props))?;
+            for batch in &batches {
+                writer.write(batch)?;
+            }
+            writer.close()?;
+        }
+
+        ExportFormat::Arrow => {
+            let mut writer = arrow::ipc::writer::FileWriter::try_new(file, &schema)?;
+            for batch in &batches {
+                writer.write(batch)?;
+            }
+            writer.finish()?;
+        }
+    }
+
+    Ok(())
+}
+
+/// All the columns of all the batches, in order of first appearance.
+fn union_schema(batches: &[ArrowRecordBatch]) -> anyhow::Result<Arc<ArrowSchema>> {
+    let mut fields: Vec<Arc<ArrowField>> = Vec::new();
+
+    for batch in batches {
+        for field in batch.schema().fields() {
+            match fields.iter().find(|f| f.name() == field.name()) {
+                Some(existing) => anyhow::ensure!(
+                    existing.data_type() == field.data_type(),
+                    "column {:?} has conflicting datatypes: {} vs. {}",
+                    field.name(),
+                    existing.data_type(),
+                    field.data_type(),
+                ),
+                None => fields.push(Arc::clone(field)),
+            }
+        }
+    }
+
+    Ok(Arc::new(ArrowSchema::new(fields)))
+}
+
+fn conform_to_schema(
+    batch: &ArrowRecordBatch,
+    schema: &Arc<ArrowSchema>,
+) -> anyhow::Result<ArrowRecordBatch> {
+    let columns: Vec<ArrayRef> = schema
+        .fields()
+        .iter()
+        .map(|field| {
+            batch
+                .column_by_name(field.name())
+                .cloned()
+                .unwrap_or_else(|| {
+                    arrow::array::new_null_array(field.data_type(), batch.num_rows())
+                })
+        })
+        .collect();
+
+    Ok(ArrowRecordBatch::try_new_with_options(
+        Arc::clone(schema),
+        columns,
+        &RecordBatchOptions::default().with_row_count(Some(batch.num_rows())),
+    )?)
+}
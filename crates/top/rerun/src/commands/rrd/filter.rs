@@ -8,11 +8,20 @@ use arrow::{
 };
 use itertools::Either;
 
+use re_arrow_util::ArrowArrayDowncastRef as _;
 use re_build_info::CrateVersion;
 use re_chunk::external::crossbeam;
+use re_log_encoding::ChunkIndexEntry;
+use re_log_types::{
+    AbsoluteTimeRange, EntityPathFilter, ResolvedEntityPathFilter, StoreId, TimeInt, TimeType,
+    TimelineName,
+};
 use re_sdk::{EntityPath, external::arrow};
+use re_sorbet::ColumnKind;
 
-use crate::commands::read_rrd_streams_from_file_or_stdin;
+use super::sensitive::{SensitiveEntities, SensitiveMode};
+use super::time_arg::TimeArg;
+use crate::commands::{read_indexed_rrd_file, read_rrd_streams_from_file_or_stdin};
 
 // ---
 
@@ -33,6 +42,44 @@ pub struct FilterCommand {
     #[clap(long = "drop-entity")]
     dropped_entity_paths: Vec<String>,
 
+    /// Entity path filter rules: only the matching entities are kept, e.g.
+    /// `--entity '+ /world/**' --entity '- /world/secret/**'`.
+    ///
+    /// If all rules are exclusions, everything else is kept.
+    /// Reserved entities (e.g. recording properties) are always kept, unless dropped explicitly.
+    #[clap(long = "entity", value_name = "RULE")]
+    entity_rules: Vec<String>,
+
+    /// Components to be filtered out, on all entities, e.g. `Points3D:colors`.
+    #[clap(long = "drop-component")]
+    dropped_components: Vec<String>,
+
+    /// Name of the timeline to trim the data on, see `--range`.
+    #[clap(long = "timeline", requires = "time_range")]
+    timeline: Option<String>,
+
+    /// Only keep the rows within this inclusive range of the `--timeline`, e.g. `100..200`,
+    /// `..1.5s` or `250ms..`.
+    ///
+    /// Values without a unit are in the native unit of the timeline (steps or nanoseconds).
+    /// Static data, as well as data that isn't on that timeline, is kept as is.
+    ///
+    /// If the input is a single indexed .rrd file, only the chunks that overlap the range are read.
+    #[clap(long = "range", value_name = "MIN..MAX", requires = "timeline")]
+    time_range: Option<TimeRangeArg>,
+
+    /// What to do with entities tagged as `Sensitive`, and everything below them.
+    ///
+    /// The input is read twice for this, so it can't be standard input.
+    #[clap(long = "sensitive", value_enum)]
+    sensitive: Option<SensitiveMode>,
+
+    /// Only treat entities tagged with one of these tags as sensitive, e.g. `faces`.
+    ///
+    /// All tags count if unspecified.
+    #[clap(long = "sensitive-tag", value_name = "TAG", requires = "sensitive")]
+    sensitive_tags: Vec<String>,
+
     /// If set, will try to proceed even in the face of IO and/or decoding errors in the input data.
     #[clap(long = "continue-on-error", default_value_t = false)]
     continue_on_error: bool,
@@ -45,6 +92,12 @@ impl FilterCommand {
             path_to_output_rrd,
             dropped_timelines,
             dropped_entity_paths,
+            entity_rules,
+            dropped_components,
+            timeline,
+            time_range,
+            sensitive,
+            sensitive_tags,
             continue_on_error,
         } = self;
 
@@ -59,13 +112,34 @@ impl FilterCommand {
         let now = std::time::Instant::now();
         re_log::info!(srcs = ?path_to_input_rrds, ?dropped_timelines, "filter started");
 
-        let dropped_timelines: HashSet<_> = dropped_timelines.iter().cloned().collect();
-        let dropped_entity_paths: HashSet<EntityPath> = dropped_entity_paths
-            .iter()
-            .map(|s| EntityPath::parse_forgiving(s))
-            .collect();
+        let entity_filter = entity_filter_from_rules(entity_rules)?;
+        let sensitive_entities = sensitive
+            .map(|mode| SensitiveEntities::find(path_to_input_rrds, mode, sensitive_tags))
+            .transpose()?;
+
+        let filters = Filters {
+            dropped_timelines: dropped_timelines.iter().cloned().collect(),
+            dropped_entity_paths: dropped_entity_paths
+                .iter()
+                .map(|s| EntityPath::parse_forgiving(s))
+                .collect(),
+            entity_filter,
+            dropped_components: dropped_components.iter().cloned().collect(),
+            time_range: timeline
+                .as_ref()
+                .zip(*time_range)
+                .map(|(timeline, range)| (timeline.clone(), range)),
+            sensitive_entities,
+        };
 
-        let (rx_decoder, rx_size_bytes) = read_rrd_streams_from_file_or_stdin(path_to_input_rrds);
+        // Indexed files let us skip over everything that would be filtered out anyway.
+        let indexed_input = filters.filters_by_index().then(|| {
+            let filters = filters.clone();
+            read_indexed_rrd_file(path_to_input_rrds, move |entry| filters.might_keep(entry))
+        });
+        let (rx_decoder, rx_size_bytes) = indexed_input
+            .flatten()
+            .unwrap_or_else(|| read_rrd_streams_from_file_or_stdin(path_to_input_rrds));
 
         // TODO(cmc): might want to make this configurable at some point.
         let (tx_encoder, rx_encoder) = crossbeam::channel::bounded(100);
@@ -110,38 +184,18 @@ impl FilterCommand {
                     let msg = match msg {
                         re_log_types::LogMsg::ArrowMsg(store_id, mut msg) => {
                             match re_sorbet::ChunkBatch::try_from(&msg.batch) {
-                                Ok(batch) => {
-                                    if !dropped_entity_paths.contains(batch.entity_path()) {
+                                Ok(batch) => match filters.filter(&store_id, &batch) {
+                                    Ok(Some(new_batch)) => {
+                                        msg.batch = new_batch;
+                                        Some(re_log_types::LogMsg::ArrowMsg(store_id, msg))
+                                    }
+                                    Ok(None) => None,
+                                    Err(err) => {
+                                        re_log::error!(%err, "couldn't filter chunk");
+                                        is_success = false;
                                         None
-                                    } else {
-                                        let (fields, columns): (Vec<_>, Vec<_>) = itertools::izip!(
-                                            &batch.schema().fields,
-                                            batch.columns()
-                                        )
-                                        .filter(|(field, _col)| {
-                                            !is_field_timeline_of(field, &dropped_timelines)
-                                        })
-                                        .map(|(field, col)| (field.clone(), col.clone()))
-                                        .unzip();
-
-                                        if let Ok(new_batch) =
-                                            ArrowRecordBatch::try_new_with_options(
-                                                ArrowSchema::new_with_metadata(
-                                                    fields,
-                                                    batch.schema().metadata().clone(),
-                                                )
-                                                .into(),
-                                                columns,
-                                                &RecordBatchOptions::default(),
-                                            )
-                                        {
-                                            msg.batch = new_batch;
-                                            Some(re_log_types::LogMsg::ArrowMsg(store_id, msg))
-                                        } else {
-                                            None // Probably failed because we filtered out everything
-                                        }
                                     }
-                                }
+                                },
                                 Err(err) => {
                                     re_log::warn_once!("Failed to parse chunk schema: {err}");
                                     None
@@ -209,9 +263,230 @@ impl FilterCommand {
 
 // ---
 
-// Does the given field represent a timeline that is in the given set?
-fn is_field_timeline_of(field: &ArrowField, dropped_timelines: &HashSet<String>) -> bool {
-    re_sorbet::IndexColumnDescriptor::try_from(field)
-        .ok()
-        .is_some_and(|schema| dropped_timelines.contains(schema.column_name()))
+/// Parses `--entity` rules, see [`FilterCommand::entity_rules`].
+///
+/// Returns `None` if there are no rules, i.e. everything should be kept.
+pub(super) fn entity_filter_from_rules(
+    entity_rules: &[String],
+) -> anyhow::Result<Option<ResolvedEntityPathFilter>> {
+    if entity_rules.is_empty() {
+        return Ok(None);
+    }
+
+    let mut rules = entity_rules.join("\n");
+    if entity_rules
+        .iter()
+        .all(|rule| rule.trim_start().starts_with('-'))
+    {
+        rules = format!("+ /**\n{rules}");
+    }
+
+    Ok(Some(
+        EntityPathFilter::parse_strict(&rules)
+            .context("invalid --entity rules")?
+            .resolve_without_substitutions(),
+    ))
+}
+
+/// `MIN..MAX`, either of which can be omitted, see [`FilterCommand::time_range`].
+#[derive(Debug, Clone, Copy)]
+struct TimeRangeArg {
+    min: Option<TimeArg>,
+    max: Option<TimeArg>,
+}
+
+impl TimeRangeArg {
+    /// Like [`Self::to_native`], for when the type of the timeline isn't known.
+    ///
+    /// Both temporal types share the same native unit, so this is exact as long as the range is
+    /// valid for the timeline at all. Otherwise, everything is included.
+    fn to_native_any(self) -> AbsoluteTimeRange {
+        self.to_native(TimeType::Sequence)
+            .or_else(|_err| self.to_native(TimeType::DurationNs))
+            .unwrap_or(AbsoluteTimeRange::EVERYTHING)
+    }
+
+    fn to_native(self, typ: TimeType) -> anyhow::Result<AbsoluteTimeRange> {
+        let min = self
+            .min
+            .map(|min| min.to_native(typ))
+            .transpose()?
+            .map_or(TimeInt::MIN, TimeInt::saturated_temporal_i64);
+        let max = self
+            .max
+            .map(|max| max.to_native(typ))
+            .transpose()?
+            .map_or(TimeInt::MAX, TimeInt::saturated_temporal_i64);
+        Ok(AbsoluteTimeRange::new(min, max))
+    }
+}
+
+impl std::str::FromStr for TimeRangeArg {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (min, max) = s
+            .split_once("..")
+            .ok_or_else(|| format!("expected MIN..MAX, got {s:?}"))?;
+        let parse_bound = |bound: &str| {
+            let bound = bound.trim();
+            (!bound.is_empty()).then(|| bound.parse()).transpose()
+        };
+        Ok(Self {
+            min: parse_bound(min)?,
+            max: parse_bound(max)?,
+        })
+    }
+}
+
+#[derive(Clone)]
+struct Filters {
+    dropped_timelines: HashSet<String>,
+    dropped_entity_paths: HashSet<EntityPath>,
+    entity_filter: Option<ResolvedEntityPathFilter>,
+    dropped_components: HashSet<String>,
+    time_range: Option<(String, TimeRangeArg)>,
+    sensitive_entities: Option<SensitiveEntities>,
+}
+
+impl Filters {
+    /// Can [`Self::might_keep`] rule out anything?
+    fn filters_by_index(&self) -> bool {
+        self.time_range.is_some()
+            || self.entity_filter.is_some()
+            || !self.dropped_entity_paths.is_empty()
+    }
+
+    /// Might anything be left of the message, judging only by its index entry?
+    fn might_keep(&self, entry: &ChunkIndexEntry) -> bool {
+        if let Some(entity_path) = &entry.entity_path
+            && !self.keeps_entity(entity_path)
+        {
+            return false;
+        }
+
+        self.time_range.as_ref().is_none_or(|(timeline, range)| {
+            entry.intersects(&TimelineName::new(timeline), range.to_native_any())
+        })
+    }
+
+    /// Returns `None` if nothing is left of the chunk.
+    fn filter(
+        &self,
+        store_id: &StoreId,
+        batch: &re_sorbet::ChunkBatch,
+    ) -> anyhow::Result<Option<ArrowRecordBatch>> {
+        let entity_path = batch.entity_path();
+        if !self.keeps_entity(entity_path) {
+            return Ok(None);
+        }
+
+        let redacted_batch;
+        let batch = match &self.sensitive_entities {
+            Some(sensitive_entities) => match sensitive_entities.apply(store_id, batch)? {
+                Some(batch) => {
+                    redacted_batch = batch;
+                    &redacted_batch
+                }
+                None => return Ok(None),
+            },
+            None => batch,
+        };
+
+        let rows_to_keep = self.rows_to_keep(batch)?;
+        if rows_to_keep
+            .as_ref()
+            .is_some_and(|rows| rows.true_count() == 0)
+        {
+            return Ok(None);
+        }
+
+        let mut num_components = 0;
+        let (fields, columns): (Vec<_>, Vec<_>) =
+            itertools::izip!(&batch.schema().fields, batch.columns())
+                .filter(|(field, _col)| match ColumnKind::try_from(field.as_ref()) {
+                    Ok(ColumnKind::Index) => !self.is_dropped_timeline(field),
+                    Ok(ColumnKind::Component) => {
+                        let is_dropped = self.is_dropped_component(entity_path, field);
+                        num_components += (!is_dropped) as usize;
+                        !is_dropped
+                    }
+                    Ok(ColumnKind::RowId) | Err(_) => true,
+                })
+                .map(|(field, col)| (field.clone(), col.clone()))
+                .unzip();
+
+        if num_components == 0 {
+            return Ok(None);
+        }
+
+        let new_batch = ArrowRecordBatch::try_new_with_options(
+            ArrowSchema::new_with_metadata(fields, batch.schema().metadata().clone()).into(),
+            columns,
+            &RecordBatchOptions::default(),
+        )?;
+
+        Ok(Some(match rows_to_keep {
+            Some(rows) => arrow::compute::filter_record_batch(&new_batch, &rows)?,
+            None => new_batch,
+        }))
+    }
+
+    /// The rows within [`Self::time_range`], or `None` if they should all be kept.
+    fn rows_to_keep(
+        &self,
+        batch: &re_sorbet::ChunkBatch,
+    ) -> anyhow::Result<Option<arrow::array::BooleanArray>> {
+        let Some((timeline, time_range)) = &self.time_range else {
+            return Ok(None);
+        };
+
+        let Some((descr, times)) = batch
+            .index_columns()
+            .find(|(descr, _)| descr.column_name() == timeline.as_str())
+        else {
+            return Ok(None);
+        };
+
+        let time_range = time_range
+            .to_native(descr.timeline().typ())
+            .with_context(|| format!("--range on timeline {timeline:?}"))?;
+
+        let times = arrow::compute::cast(times, &arrow::datatypes::DataType::Int64)?;
+        let times = times
+            .downcast_array_ref::<arrow::array::Int64Array>()
+            .context("unexpected index datatype")?;
+
+        let rows: arrow::array::BooleanArray = times
+            .iter()
+            .map(|time| time.map(|time| time_range.contains(TimeInt::saturated_temporal_i64(time))))
+            .collect();
+
+        Ok((rows.true_count() < rows.len()).then_some(rows))
+    }
+
+    fn keeps_entity(&self, entity_path: &EntityPath) -> bool {
+        if self.dropped_entity_paths.contains(entity_path) {
+            return false;
+        }
+
+        self.entity_filter.as_ref().is_none_or(|entity_filter| {
+            entity_path.is_reserved() || entity_filter.matches(entity_path)
+        })
+    }
+
+    fn is_dropped_timeline(&self, field: &ArrowField) -> bool {
+        re_sorbet::IndexColumnDescriptor::try_from(field)
+            .ok()
+            .is_some_and(|descr| self.dropped_timelines.contains(descr.column_name()))
+    }
+
+    fn is_dropped_component(&self, entity_path: &EntityPath, field: &ArrowField) -> bool {
+        if self.dropped_components.is_empty() {
+            return false;
+        }
+        let descr =
+            re_sorbet::ComponentColumnDescriptor::from_arrow_field(Some(entity_path), field);
+        self.dropped_components.contains(descr.component.as_str())
+    }
 }
@@ -1,14 +1,22 @@
+use std::collections::{BTreeMap, BTreeSet};
 use std::io::{IsTerminal as _, Write as _};
+use std::path::PathBuf;
+use std::sync::Arc;
 
 use anyhow::Context as _;
-use itertools::Either;
+use itertools::{Either, Itertools as _};
 
+use re_chunk::{Chunk, ComponentIdentifier};
 use re_chunk_store::{ChunkStore, ChunkStoreConfig, ChunkStoreError};
 use re_entity_db::EntityDb;
-use re_log_types::StoreId;
+use re_log_types::{
+    AbsoluteTimeRange, ApplicationId, EntityPath, LogMsg, RecordingId, StoreId, TimeType, TimeUnit,
+    TimelineName,
+};
 use re_sdk::StoreKind;
 
-use crate::commands::read_rrd_streams_from_file_or_stdin;
+use super::time_arg::TimeArg;
+use crate::commands::{read_rrd_streams_from_file_or_stdin, stdio::InputSource};
 
 // ---
 
@@ -24,6 +32,45 @@ pub struct MergeCommand {
     /// If set, will try to proceed even in the face of IO and/or decoding errors in the input data.
     #[clap(long = "continue-on-error", default_value_t = false)]
     continue_on_error: bool,
+
+    /// If set, all recordings and blueprints are moved to this application id.
+    #[clap(long = "application-id")]
+    application_id: Option<String>,
+
+    /// If set, all recordings are merged into a single one, with this recording id.
+    ///
+    /// The store info (e.g. start time) of the first recording is kept.
+    #[clap(long = "recording-id")]
+    recording_id: Option<String>,
+
+    /// Moves all the entities of an input file under the given prefix, e.g.
+    /// `--entity-prefix left.rrd=/cameras/left`.
+    ///
+    /// Can be specified multiple times.
+    #[clap(long = "entity-prefix", value_name = "INPUT=PREFIX")]
+    entity_prefixes: Vec<EntityPrefix>,
+
+    /// Moves all the entities of each input file under a prefix named after the file, e.g.
+    /// `/left` for `left.rrd`.
+    ///
+    /// Ignored for files that have an explicit `--entity-prefix`.
+    #[clap(long = "prefix-with-file-name", default_value_t = false)]
+    prefix_with_file_name: bool,
+
+    /// Shifts all the times of a timeline in an input file, e.g. `--time-offset left.rrd:frame=10`
+    /// or `--time-offset right.rrd:log_time=-1.5s`.
+    ///
+    /// Offsets without a unit are in the native unit of the timeline (steps or nanoseconds).
+    /// Supported units are `ns`, `us`, `ms` and `s`.
+    ///
+    /// Can be specified multiple times.
+    #[clap(long = "time-offset", value_name = "INPUT:TIMELINE=OFFSET")]
+    time_offsets: Vec<TimeOffset>,
+
+    /// If set, fails if different inputs end up writing to the same entities, instead of just
+    /// reporting it.
+    #[clap(long = "fail-on-conflict", default_value_t = false)]
+    fail_on_conflict: bool,
 }
 
 impl MergeCommand {
@@ -32,6 +79,12 @@ impl MergeCommand {
             path_to_input_rrds,
             path_to_output_rrd,
             continue_on_error,
+            application_id,
+            recording_id,
+            entity_prefixes,
+            prefix_with_file_name,
+            time_offsets,
+            fail_on_conflict,
         } = self;
 
         if path_to_output_rrd.is_none() {
@@ -47,14 +100,234 @@ impl MergeCommand {
         // (e.g. by recompacting it differently), so make sure to disable all these features.
         let store_config = ChunkStoreConfig::ALL_DISABLED;
 
+        let rewrites = MergeRewrites {
+            application_id: application_id.clone().map(ApplicationId::from),
+            recording_id: recording_id.clone().map(RecordingId::from),
+            entity_prefixes: entity_prefixes.clone(),
+            prefix_with_file_name: *prefix_with_file_name,
+            time_offsets: time_offsets.clone(),
+        };
+
         let num_passes = 0;
-        merge_and_compact(
+        let conflicts = merge_and_compact(
             num_passes,
             *continue_on_error,
             &store_config,
+            &rewrites,
             path_to_input_rrds,
             path_to_output_rrd.as_ref(),
-        )
+        )?;
+
+        for conflict in &conflicts {
+            re_log::warn!("{conflict}");
+        }
+        anyhow::ensure!(
+            !*fail_on_conflict || conflicts.is_empty(),
+            "{} conflict(s) between the inputs (see above)",
+            conflicts.len()
+        );
+
+        Ok(())
+    }
+}
+
+// ---
+
+/// `INPUT=PREFIX`, see [`MergeCommand::entity_prefixes`].
+#[derive(Debug, Clone)]
+struct EntityPrefix {
+    input: PathBuf,
+    prefix: EntityPath,
+}
+
+impl std::str::FromStr for EntityPrefix {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (input, prefix) = s
+            .rsplit_once('=')
+            .ok_or_else(|| format!("expected INPUT=PREFIX, got {s:?}"))?;
+        Ok(Self {
+            input: PathBuf::from(input),
+            prefix: EntityPath::parse_forgiving(prefix),
+        })
+    }
+}
+
+/// `INPUT:TIMELINE=OFFSET`, see [`MergeCommand::time_offsets`].
+#[derive(Debug, Clone)]
+struct TimeOffset {
+    input: PathBuf,
+    timeline: TimelineName,
+    offset: TimeArg,
+}
+
+impl std::str::FromStr for TimeOffset {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || format!("expected INPUT:TIMELINE=OFFSET, got {s:?}");
+
+        let (input_and_timeline, offset) = s.rsplit_once('=').ok_or_else(err)?;
+        let (input, timeline) = input_and_timeline.rsplit_once(':').ok_or_else(err)?;
+
+        Ok(Self {
+            input: PathBuf::from(input),
+            timeline: TimelineName::new(timeline),
+            offset: offset.parse()?,
+        })
+    }
+}
+
+/// How to rewrite the data of each input while merging.
+#[derive(Debug, Clone, Default)]
+struct MergeRewrites {
+    application_id: Option<ApplicationId>,
+    recording_id: Option<RecordingId>,
+    entity_prefixes: Vec<EntityPrefix>,
+    prefix_with_file_name: bool,
+    time_offsets: Vec<TimeOffset>,
+}
+
+impl MergeRewrites {
+    fn store_id(&self, store_id: &StoreId) -> StoreId {
+        let mut store_id = store_id.clone();
+        if let Some(application_id) = &self.application_id {
+            store_id = store_id.with_application_id(application_id.clone());
+        }
+        if store_id.is_recording()
+            && let Some(recording_id) = &self.recording_id
+        {
+            store_id = store_id.with_recording_id(recording_id.clone());
+        }
+        store_id
+    }
+
+    fn msg(&self, msg: LogMsg) -> LogMsg {
+        if self.application_id.is_none() && self.recording_id.is_none() {
+            return msg;
+        }
+
+        match msg {
+            LogMsg::SetStoreInfo(mut msg) => {
+                msg.info.store_id = self.store_id(&msg.info.store_id);
+                LogMsg::SetStoreInfo(msg)
+            }
+            LogMsg::ArrowMsg(store_id, arrow_msg) => {
+                LogMsg::ArrowMsg(self.store_id(&store_id), arrow_msg)
+            }
+            LogMsg::BlueprintActivationCommand(mut cmd) => {
+                cmd.blueprint_id = self.store_id(&cmd.blueprint_id);
+                LogMsg::BlueprintActivationCommand(cmd)
+            }
+        }
+    }
+
+    fn entity_prefix(&self, source: &InputSource) -> Option<EntityPath> {
+        let InputSource::File(path) = source else {
+            return None;
+        };
+
+        if let Some(prefix) = self.entity_prefixes.iter().find(|p| p.input == *path) {
+            return Some(prefix.prefix.clone());
+        }
+
+        if self.prefix_with_file_name {
+            let file_stem = path.file_stem()?.to_string_lossy();
+            return Some(EntityPath::from_single_string(file_stem.as_ref()));
+        }
+
+        None
+    }
+
+    fn chunk(&self, source: &InputSource, mut chunk: Chunk) -> anyhow::Result<Chunk> {
+        // Reserved entities (e.g. recording properties) have a fixed location.
+        if let Some(prefix) = self.entity_prefix(source)
+            && !chunk.entity_path().is_reserved()
+        {
+            let entity_path = prefix.join(chunk.entity_path());
+            chunk = chunk.with_entity_path(entity_path);
+        }
+
+        let InputSource::File(path) = source else {
+            return Ok(chunk);
+        };
+        for time_offset in self.time_offsets.iter().filter(|o| o.input == *path) {
+            let Some(typ) = chunk
+                .timelines()
+                .get(&time_offset.timeline)
+                .map(|time_column| time_column.timeline().typ())
+            else {
+                continue;
+            };
+            let offset = time_offset.offset.to_native(typ).with_context(|| {
+                format!(
+                    "time offset for timeline {:?}",
+                    time_offset.timeline.as_str()
+                )
+            })?;
+            chunk = chunk.time_shifted(&time_offset.timeline, offset);
+        }
+
+        Ok(chunk)
+    }
+}
+
+/// Keeps track of which input wrote to which entity, to report data that got mixed up while merging.
+#[derive(Default)]
+struct ConflictTracker {
+    static_sources: BTreeMap<(StoreId, EntityPath, ComponentIdentifier), BTreeSet<String>>,
+    time_ranges: BTreeMap<(StoreId, EntityPath, TimelineName), BTreeMap<String, AbsoluteTimeRange>>,
+}
+
+impl ConflictTracker {
+    fn on_chunk(&mut self, store_id: &StoreId, source: &InputSource, chunk: &Chunk) {
+        let source = source.to_string();
+
+        if chunk.is_static() {
+            for component in chunk.components().keys() {
+                self.static_sources
+                    .entry((store_id.clone(), chunk.entity_path().clone(), *component))
+                    .or_default()
+                    .insert(source.clone());
+            }
+            return;
+        }
+
+        for (timeline, time_column) in chunk.timelines() {
+            self.time_ranges
+                .entry((store_id.clone(), chunk.entity_path().clone(), *timeline))
+                .or_default()
+                .entry(source.clone())
+                .and_modify(|range| *range = range.union(time_column.time_range()))
+                .or_insert_with(|| time_column.time_range());
+        }
+    }
+
+    fn into_conflicts(self) -> Vec<String> {
+        let mut conflicts = Vec::new();
+
+        for ((store_id, entity_path, component), sources) in self.static_sources {
+            if sources.len() > 1 {
+                conflicts.push(format!(
+                    "{store_id:?}: static data for {entity_path}:{component} comes from {}, only the most recent one is kept",
+                    sources.iter().join(", "),
+                ));
+            }
+        }
+
+        for ((store_id, entity_path, timeline), ranges) in self.time_ranges {
+            for ((source_a, range_a), (source_b, range_b)) in ranges.iter().tuple_combinations() {
+                if range_a.intersects(*range_b) {
+                    conflicts.push(format!(
+                        "{store_id:?}: {entity_path} has interleaved data from {source_a} and {source_b} on timeline {:?}",
+                        timeline.as_str(),
+                    ));
+                }
+            }
+        }
+
+        conflicts
     }
 }
 
@@ -150,19 +423,24 @@ impl CompactCommand {
             *num_extra_passes,
             *continue_on_error,
             &store_config,
+            &MergeRewrites::default(),
             path_to_input_rrds,
             path_to_output_rrd.as_ref(),
-        )
+        )?;
+
+        Ok(())
     }
 }
 
+/// Returns a description of every conflict between the inputs.
 fn merge_and_compact(
     num_passes: u32,
     continue_on_error: bool,
     store_config: &ChunkStoreConfig,
+    rewrites: &MergeRewrites,
     path_to_input_rrds: &[String],
     path_to_output_rrd: Option<&String>,
-) -> anyhow::Result<()> {
+) -> anyhow::Result<Vec<String>> {
     let file_size_to_string = |size: Option<u64>| {
         size.map_or_else(
             || "<unknown>".to_owned(),
@@ -182,26 +460,47 @@ fn merge_and_compact(
     let (rx, rx_size_bytes) = read_rrd_streams_from_file_or_stdin(path_to_input_rrds);
 
     let mut entity_dbs: std::collections::HashMap<StoreId, EntityDb> = Default::default();
+    let mut conflicts = ConflictTracker::default();
 
     re_log::info!("processing input…");
     let mut num_chunks_before = 0u64;
     let mut last_checkpoint = std::time::Instant::now();
-    for (msg_nr, (_source, res)) in rx.iter().enumerate() {
+    for (msg_nr, (source, res)) in rx.iter().enumerate() {
         let mut is_success = true;
 
         match res {
             Ok(msg) => {
-                num_chunks_before += matches!(msg, re_log_types::LogMsg::ArrowMsg(_, _)) as u64;
-                if let Err(err) = entity_dbs
-                    .entry(msg.store_id().clone())
-                    .or_insert_with(|| {
-                        re_entity_db::EntityDb::with_store_config(
-                            msg.store_id().clone(),
-                            store_config.clone(),
-                        )
-                    })
-                    .add(&msg)
-                {
+                num_chunks_before += matches!(msg, LogMsg::ArrowMsg(_, _)) as u64;
+
+                let msg = rewrites.msg(msg);
+                let entity_db = entity_dbs.entry(msg.store_id().clone()).or_insert_with(|| {
+                    re_entity_db::EntityDb::with_store_config(
+                        msg.store_id().clone(),
+                        store_config.clone(),
+                    )
+                });
+
+                let res = match &msg {
+                    LogMsg::ArrowMsg(store_id, arrow_msg) => Chunk::from_arrow_msg(arrow_msg)
+                        .map_err(anyhow::Error::from)
+                        .and_then(|mut chunk| {
+                            chunk.sort_if_unsorted();
+                            let chunk = rewrites.chunk(&source, chunk)?;
+                            conflicts.on_chunk(store_id, &source, &chunk);
+                            Ok(entity_db.add_chunk(&Arc::new(chunk))?)
+                        }),
+
+                    // When merging several recordings into one, the first store info wins.
+                    LogMsg::SetStoreInfo(_)
+                        if rewrites.recording_id.is_some() && entity_db.store_info().is_some() =>
+                    {
+                        Ok(vec![])
+                    }
+
+                    _ => entity_db.add(&msg).map_err(anyhow::Error::from),
+                };
+
+                if let Err(err) = res {
                     re_log::error!(%err, "couldn't index corrupt chunk");
                     is_success = false;
                 }
@@ -309,15 +608,25 @@ fn merge_and_compact(
         .unwrap_or(re_build_info::CrateVersion::LOCAL);
 
     re_log::info!("encoding…");
-    let rrd_out_size = re_log_encoding::Encoder::encode_into(
-        version,
-        encoding_options,
+    let rrd_out_size = {
+        // Indexed, so that the output can be streamed progressively when hosted on the web.
+        let mut encoder =
+            re_log_encoding::Encoder::new_eager(version, encoding_options, &mut rrd_out)
+                .context("couldn't encode messages")?
+                .with_chunk_index();
+
         // NOTE: We want to make sure all blueprints come first, so that the viewer can immediately
         // set up the viewport correctly.
-        messages_rbl.chain(messages_rrd),
-        &mut rrd_out,
-    )
-    .context("couldn't encode messages")?;
+        let mut rrd_out_size = 0;
+        for message in messages_rbl.chain(messages_rrd) {
+            rrd_out_size += encoder
+                .append(&message?)
+                .context("couldn't encode messages")?;
+        }
+        encoder.finish().context("couldn't encode messages")?;
+
+        rrd_out_size
+    };
 
     rrd_out.flush().context("couldn't flush output")?;
 
@@ -347,5 +656,5 @@ fn merge_and_compact(
         "merge/compaction finished"
     );
 
-    Ok(())
+    Ok(conflicts.into_conflicts())
 }
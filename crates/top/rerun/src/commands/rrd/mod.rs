@@ -1,14 +1,18 @@
 mod compare;
+mod export;
 mod filter;
 mod merge_compact;
 mod migrate;
 mod print;
 mod route;
+mod sensitive;
 mod stats;
+mod time_arg;
 mod verify;
 
 use self::{
     compare::CompareCommand,
+    export::ExportCommand,
     filter::FilterCommand,
     merge_compact::{CompactCommand, MergeCommand},
     migrate::MigrateCommand,
@@ -52,13 +56,38 @@ pub enum RrdCommands {
     /// This ignores the `log_time` timeline.
     Compare(CompareCommand),
 
+    /// Exports the contents of .rrd/.rbl files/streams as Parquet or Arrow IPC files, one per entity.
+    ///
+    /// Reads from standard input if no paths are specified.
+    ///
+    /// Each file holds all the rows of an entity: the row IDs, one column per timeline, and one
+    /// list column per component. This makes it possible to load recordings into e.g. pandas or
+    /// polars without the Rerun SDK.
+    ///
+    /// `/world/points` ends up in `<DIR>/world/points.parquet`. If the input contains several
+    /// recordings, each of them gets its own subdirectory, named after its recording ID.
+    ///
+    /// Examples:
+    ///
+    /// * `rerun rrd export /my/recordings/*.rrd -o exported/`
+    ///
+    /// * `rerun rrd export --format arrow --entity '+ /world/**' --component Points3D:positions my.rrd -o exported/`
+    Export(ExportCommand),
+
     /// Filters out data from .rrd/.rbl files/streams, and writes the result to standard output.
     ///
     /// Reads from standard input if no paths are specified.
     ///
+    /// Entities can be selected with entity path filter rules, components dropped, and the data
+    /// trimmed to a time range, e.g. to share a minimal bug repro or strip sensitive channels.
+    ///
     /// This will not affect the chunking of the data in any way.
     ///
-    /// Example: `rerun rrd filter --drop-timeline log_tick /my/recordings/*.rrd > output.rrd`
+    /// Examples:
+    ///
+    /// * `rerun rrd filter --drop-timeline log_tick /my/recordings/*.rrd > output.rrd`
+    ///
+    /// * `rerun rrd filter --entity '+ /world/**' --drop-component Image:buffer --timeline frame --range 100..200 my.rrd -o repro.rrd`
     Filter(FilterCommand),
 
     /// Merges the contents of multiple .rrd/.rbl files/streams, and writes the result to standard output.
@@ -67,7 +96,15 @@ pub enum RrdCommands {
     ///
     /// ⚠️ This will automatically migrate the data to the latest version of the RRD protocol, if needed. ⚠️
     ///
-    /// Example: `rerun rrd merge /my/recordings/*.rrd > output.rrd`
+    /// Entities can be moved under a per-input prefix, and timelines shifted per input, which
+    /// together with `--recording-id` makes it possible to assemble a single recording out of
+    /// several ones. Inputs that end up writing to the same entities are reported.
+    ///
+    /// Examples:
+    ///
+    /// * `rerun rrd merge /my/recordings/*.rrd > output.rrd`
+    ///
+    /// * `rerun rrd merge --recording-id rig --prefix-with-file-name --time-offset right.rrd:log_time=-0.25s left.rrd right.rrd -o rig.rrd`
     Merge(MergeCommand),
 
     /// Migrate one or more .rrd files to the newest Rerun version.
@@ -95,12 +132,20 @@ pub enum RrdCommands {
     ///
     /// Reads from standard input if no paths are specified.
     ///
+    /// Includes a per-entity and per-component breakdown of row counts, sizes, time extents and
+    /// chunk fragmentation, largest entities first, to find out what makes a recording so big.
+    ///
     /// Example: `rerun rrd stats /my/recordings/*.rrd`
+    /// Example: `rerun rrd stats --format json /my/recordings/*.rrd > stats.json`
     Stats(StatsCommand),
 
     /// Verify the that the .rrd file can be loaded and correctly interpreted.
     ///
     /// Can be used to ensure that the current Rerun version can load the data.
+    ///
+    /// Also checks that the stream isn't truncated or corrupted, that every chunk is well-formed,
+    /// that columns keep the same datatype across chunks, that chunks are in order, and that
+    /// every coordinate frame is connected to something by a transform.
     Verify(VerifyCommand),
 }
 
@@ -113,6 +158,7 @@ impl RrdCommands {
                     .with_context(|| format!("current directory {:?}", std::env::current_dir()))
             }
             Self::Compact(cmd) => cmd.run(),
+            Self::Export(cmd) => cmd.run(),
             Self::Filter(cmd) => cmd.run(),
             Self::Merge(cmd) => cmd.run(),
             Self::Migrate(cmd) => cmd.run(),
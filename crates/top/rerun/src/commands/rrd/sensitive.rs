@@ -0,0 +1,163 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use anyhow::Context as _;
+use arrow::{
+    array::{RecordBatch as ArrowRecordBatch, RecordBatchOptions},
+    datatypes::{Field as ArrowField, Schema as ArrowSchema},
+};
+
+use re_log_types::{EntityPath, LogMsg, StoreId};
+use re_sorbet::ColumnKind;
+use re_types::archetypes::Sensitive;
+
+use crate::commands::read_rrd_streams_from_file_or_stdin;
+
+// ---
+
+/// What to do with the data of entities tagged with [`Sensitive`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub(super) enum SensitiveMode {
+    /// Keep the entities and the times they were logged at, but clear all of their data.
+    Redact,
+
+    /// Leave the entities out entirely.
+    Drop,
+}
+
+/// The entities tagged with [`Sensitive`] in some recordings, and what to do with them.
+///
+/// A tag covers the tagged entity and everything below it.
+#[derive(Debug, Clone)]
+pub(super) struct SensitiveEntities {
+    mode: SensitiveMode,
+    tagged_entities: HashMap<StoreId, Vec<EntityPath>>,
+}
+
+impl SensitiveEntities {
+    /// Reads all of the input once to find the tagged entities, so that data logged before the
+    /// tag is covered too.
+    ///
+    /// Only tags within `tags` count, or all of them if `tags` is empty.
+    pub fn find(
+        path_to_input_rrds: &[String],
+        mode: SensitiveMode,
+        tags: &[String],
+    ) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            !path_to_input_rrds.is_empty(),
+            "--sensitive can't read from standard input: the input is read twice, once to find the tagged entities"
+        );
+
+        let tags: HashSet<&str> = tags.iter().map(String::as_str).collect();
+        let component = Sensitive::descriptor_tags().component;
+
+        let mut tagged_entities: HashMap<StoreId, Vec<EntityPath>> = HashMap::default();
+
+        let (rx_decoder, _rx_size_bytes) = read_rrd_streams_from_file_or_stdin(path_to_input_rrds);
+        for (_source, res) in rx_decoder {
+            // Better to fail than to miss a tag and leak what it protects.
+            let msg = res.context("couldn't read the input to find sensitive entities")?;
+
+            let LogMsg::ArrowMsg(store_id, msg) = msg else {
+                continue;
+            };
+
+            let chunk = re_chunk::Chunk::from_arrow_msg(&msg)
+                .context("couldn't decode the input to find sensitive entities")?;
+            if !chunk.components().contains_component(component) {
+                continue;
+            }
+
+            let is_tagged = chunk.iter_slices::<String>(component).any(|chunk_tags| {
+                chunk_tags
+                    .iter()
+                    .any(|tag| tags.is_empty() || tags.contains(tag.as_str()))
+            });
+            if is_tagged {
+                let entities = tagged_entities.entry(store_id).or_default();
+                if !entities.contains(chunk.entity_path()) {
+                    entities.push(chunk.entity_path().clone());
+                }
+            }
+        }
+
+        for (store_id, entities) in &tagged_entities {
+            re_log::info!(
+                recording = %store_id.recording_id(),
+                ?entities,
+                ?mode,
+                "found sensitive entities"
+            );
+        }
+
+        Ok(Self {
+            mode,
+            tagged_entities,
+        })
+    }
+
+    pub fn is_sensitive(&self, store_id: &StoreId, entity_path: &EntityPath) -> bool {
+        self.tagged_entities.get(store_id).is_some_and(|entities| {
+            entities
+                .iter()
+                .any(|tagged| entity_path.starts_with(tagged))
+        })
+    }
+
+    /// Returns `None` if the chunk is left out.
+    ///
+    /// Redacting replaces all component columns with nulls, except for the tags themselves.
+    pub fn apply(
+        &self,
+        store_id: &StoreId,
+        batch: &re_sorbet::ChunkBatch,
+    ) -> anyhow::Result<Option<re_sorbet::ChunkBatch>> {
+        let entity_path = batch.entity_path();
+        if !self.is_sensitive(store_id, entity_path) {
+            return Ok(Some(batch.clone()));
+        }
+
+        match self.mode {
+            SensitiveMode::Drop => Ok(None),
+
+            SensitiveMode::Redact => {
+                let tags_component = Sensitive::descriptor_tags().component;
+                let is_redacted = |field: &ArrowField| {
+                    ColumnKind::try_from(field).is_ok_and(|kind| kind == ColumnKind::Component)
+                        && re_sorbet::ComponentColumnDescriptor::from_arrow_field(
+                            Some(entity_path),
+                            field,
+                        )
+                        .component
+                            != tags_component
+                };
+
+                let (fields, columns): (Vec<_>, Vec<_>) =
+                    itertools::izip!(&batch.schema().fields, batch.columns())
+                        .map(|(field, column)| {
+                            if is_redacted(field.as_ref()) {
+                                let null_column =
+                                    arrow::array::new_null_array(field.data_type(), column.len());
+                                (
+                                    Arc::new(field.as_ref().clone().with_nullable(true)),
+                                    null_column,
+                                )
+                            } else {
+                                (field.clone(), column.clone())
+                            }
+                        })
+                        .unzip();
+
+                let redacted = ArrowRecordBatch::try_new_with_options(
+                    ArrowSchema::new_with_metadata(fields, batch.schema().metadata().clone())
+                        .into(),
+                    columns,
+                    &RecordBatchOptions::default().with_row_count(Some(batch.num_rows())),
+                )?;
+
+                Ok(Some(re_sorbet::ChunkBatch::try_from(&redacted)?))
+            }
+        }
+    }
+}
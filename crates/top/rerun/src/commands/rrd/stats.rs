@@ -1,7 +1,12 @@
+use std::collections::BTreeMap;
+
 use ahash::{HashMap, HashMapExt as _};
+use arrow::array::{Array as _, Int64Array};
 use itertools::Itertools as _;
 
+use re_arrow_util::ArrowArrayDowncastRef as _;
 use re_log_encoding::ToApplication as _;
+use re_log_types::{TimeInt, TimeType, TimestampFormat};
 use re_protos::log_msg::v1alpha1::log_msg::Msg;
 
 use crate::commands::read_raw_rrd_streams_from_file_or_stdin;
@@ -23,6 +28,19 @@ pub struct StatsCommand {
     /// If set, will try to proceed even in the face of IO and/or decoding errors in the input data.
     #[clap(long = "continue-on-error", default_value_t = true)]
     continue_on_error: bool,
+
+    /// How to print the statistics.
+    ///
+    /// `json` only contains the totals and the per-entity breakdown, and is meant to be consumed
+    /// by other tools. Times in there are raw integers (nanoseconds for temporal timelines).
+    #[clap(long = "format", value_enum, default_value_t = StatsFormat::Table)]
+    format: StatsFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum StatsFormat {
+    Table,
+    Json,
 }
 
 impl StatsCommand {
@@ -31,10 +49,11 @@ impl StatsCommand {
             no_decode,
             path_to_input_rrds,
             continue_on_error,
+            format,
         } = self;
 
         let mut num_chunks = 0u64;
-        let mut num_chunks_per_entity: HashMap<String, u64> = HashMap::new();
+        let mut stats_per_entity: BTreeMap<String, EntityStats> = BTreeMap::new();
         let mut num_chunks_per_index: HashMap<String, u64> = HashMap::new();
         let mut num_chunks_per_component: HashMap<String, u64> = HashMap::new();
         let mut num_rows = Vec::with_capacity(num_chunks as _);
@@ -102,18 +121,23 @@ impl StatsCommand {
                         Ok(Some(stats)) => {
                             num_chunks += 1;
 
-                            if let Some(stats) = stats.app {
-                                *num_chunks_per_entity.entry(stats.entity_path).or_default() += 1;
-                                for index in stats.indexes {
-                                    *num_chunks_per_index.entry(index).or_default() += 1;
+                            if let Some(app) = stats.app {
+                                stats_per_entity
+                                    .entry(app.entity_path.clone())
+                                    .or_default()
+                                    .add(&app, &stats.transport);
+
+                                for index in app.indexes {
+                                    *num_chunks_per_index.entry(index.name).or_default() += 1;
                                 }
-                                for component in stats.components {
-                                    *num_chunks_per_component.entry(component).or_default() += 1;
+                                for component in app.components {
+                                    *num_chunks_per_component.entry(component.name).or_default() +=
+                                        1;
                                 }
-                                num_rows.push(stats.num_rows);
-                                num_static += (stats.num_indexes == 0) as u64;
-                                num_indexes.push(stats.num_indexes);
-                                num_components.push(stats.num_components);
+                                num_rows.push(app.num_rows);
+                                num_static += (app.num_indexes == 0) as u64;
+                                num_indexes.push(app.num_indexes);
+                                num_components.push(app.num_components);
                             }
 
                             ipc_size_bytes_compressed
@@ -166,6 +190,19 @@ impl StatsCommand {
 
         re_log::info!("computing stats…");
 
+        if *format == StatsFormat::Json {
+            let report = StatsReport {
+                num_chunks,
+                num_static_chunks: num_static,
+                num_rows: num_rows.iter().copied().sum(),
+                size_bytes_compressed: ipc_size_bytes_compressed.iter().copied().sum(),
+                size_bytes_uncompressed: ipc_size_bytes_uncompressed.iter().copied().sum(),
+                entities: stats_per_entity,
+            };
+            println!("{}", serde_json::to_string_pretty(&report)?);
+            return Ok(());
+        }
+
         println!("Overview");
         println!("----------");
 
@@ -174,7 +211,7 @@ impl StatsCommand {
         if !*no_decode {
             println!(
                 "num_entity_paths = {}",
-                re_format::format_uint(num_chunks_per_entity.len())
+                re_format::format_uint(stats_per_entity.len())
             );
 
             let num_chunks_without_components = num_components.iter().filter(|v| **v == 0).count();
@@ -235,9 +272,12 @@ impl StatsCommand {
             };
 
             println!();
-            println!("Num chunks per entity");
-            println!("---------------------");
-            print_details(num_chunks_per_entity);
+            println!("Per entity (largest first)");
+            println!("--------------------------");
+            print_entity_stats(
+                stats_per_entity,
+                ipc_size_bytes_compressed.iter().sum::<u64>(),
+            );
 
             println!();
             println!("Num chunks per index");
@@ -340,14 +380,165 @@ struct ChunkStatsApplication {
     // TODO(#6572): the fact that the Entity Path is only present at the app layer is a serious problem.
     entity_path: String,
 
-    indexes: Vec<String>,
-    components: Vec<String>,
+    indexes: Vec<IndexStats>,
+    components: Vec<ComponentStats>,
 
     num_rows: u64,
     num_indexes: u64,
     num_components: u64,
 }
 
+#[derive(Clone, Debug)]
+struct IndexStats {
+    name: String,
+
+    /// `None` if the datatype isn't one of the known index datatypes.
+    typ: Option<TimeType>,
+
+    /// Smallest and largest time in the chunk, if it has any rows.
+    range: Option<(i64, i64)>,
+}
+
+#[derive(Clone, Debug)]
+struct ComponentStats {
+    name: String,
+
+    /// Number of non-null rows.
+    num_rows: u64,
+    size_bytes: u64,
+}
+
+// ---
+
+/// The output of `rrd stats --format json`.
+#[derive(Debug, serde::Serialize)]
+struct StatsReport {
+    num_chunks: u64,
+    num_static_chunks: u64,
+    num_rows: u64,
+    size_bytes_compressed: u64,
+    size_bytes_uncompressed: u64,
+    entities: BTreeMap<String, EntityStats>,
+}
+
+/// Everything we know about a single entity, accumulated over all of its chunks.
+#[derive(Debug, Default, serde::Serialize)]
+struct EntityStats {
+    num_chunks: u64,
+    num_static_chunks: u64,
+    num_rows: u64,
+
+    /// How fragmented the entity is: the lower, the more overhead per row.
+    avg_rows_per_chunk: f64,
+
+    size_bytes_compressed: u64,
+    size_bytes_uncompressed: u64,
+
+    time_ranges: BTreeMap<String, EntityTimeRange>,
+    components: BTreeMap<String, EntityComponentStats>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct EntityTimeRange {
+    #[serde(rename = "type", serialize_with = "serialize_time_type")]
+    typ: Option<TimeType>,
+    min: i64,
+    max: i64,
+}
+
+#[derive(Debug, Default, serde::Serialize)]
+struct EntityComponentStats {
+    num_chunks: u64,
+
+    /// Number of non-null rows.
+    num_rows: u64,
+    size_bytes: u64,
+}
+
+fn serialize_time_type<S: serde::Serializer>(
+    typ: &Option<TimeType>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serde::Serialize::serialize(&typ.map(|typ| typ.to_string()), serializer)
+}
+
+impl EntityStats {
+    fn add(&mut self, app: &ChunkStatsApplication, transport: &ChunkStatsTransport) {
+        self.num_chunks += 1;
+        self.num_static_chunks += (app.num_indexes == 0) as u64;
+        self.num_rows += app.num_rows;
+        self.avg_rows_per_chunk = self.num_rows as f64 / self.num_chunks as f64;
+
+        self.size_bytes_compressed += transport.ipc_size_bytes_compressed;
+        self.size_bytes_uncompressed += transport.ipc_size_bytes_uncompressed;
+
+        for index in &app.indexes {
+            let Some((min, max)) = index.range else {
+                continue;
+            };
+            self.time_ranges
+                .entry(index.name.clone())
+                .and_modify(|range| {
+                    range.min = range.min.min(min);
+                    range.max = range.max.max(max);
+                })
+                .or_insert_with(|| EntityTimeRange {
+                    typ: index.typ,
+                    min,
+                    max,
+                });
+        }
+
+        for component in &app.components {
+            let stats = self.components.entry(component.name.clone()).or_default();
+            stats.num_chunks += 1;
+            stats.num_rows += component.num_rows;
+            stats.size_bytes += component.size_bytes;
+        }
+    }
+}
+
+fn print_entity_stats(stats_per_entity: BTreeMap<String, EntityStats>, size_bytes_total: u64) {
+    let mut stats_per_entity = stats_per_entity.into_iter().collect_vec();
+    stats_per_entity
+        .sort_by(|(_, l), (_, r)| r.size_bytes_compressed.cmp(&l.size_bytes_compressed));
+
+    for (entity_path, stats) in stats_per_entity {
+        println!(
+            "{entity_path}: chunks = {} (static = {}), rows = {}, rows_per_chunk = {:.3}, size = {} ({:.3}%, {} uncompressed)",
+            re_format::format_uint(stats.num_chunks),
+            re_format::format_uint(stats.num_static_chunks),
+            re_format::format_uint(stats.num_rows),
+            stats.avg_rows_per_chunk,
+            re_format::format_bytes(stats.size_bytes_compressed as _),
+            stats.size_bytes_compressed as f64 / size_bytes_total as f64 * 100.0,
+            re_format::format_bytes(stats.size_bytes_uncompressed as _),
+        );
+
+        for (timeline, range) in &stats.time_ranges {
+            let typ = range.typ.unwrap_or(TimeType::Sequence);
+            let format =
+                |time: i64| typ.format(TimeInt::new_temporal(time), TimestampFormat::utc());
+            println!(
+                "    {timeline}: {}..={}",
+                format(range.min),
+                format(range.max)
+            );
+        }
+
+        let mut components = stats.components.iter().collect_vec();
+        components.sort_by(|(_, l), (_, r)| r.size_bytes.cmp(&l.size_bytes));
+        for (component, stats) in components {
+            println!(
+                "    {component}: chunks = {}, rows = {}, size = {}",
+                re_format::format_uint(stats.num_chunks),
+                re_format::format_uint(stats.num_rows),
+                re_format::format_bytes(stats.size_bytes as _),
+            );
+        }
+    }
+}
+
 fn compute_stats(app: bool, msg: &Msg) -> anyhow::Result<Option<ChunkStats>> {
     if let Msg::ArrowMsg(arrow_msg) = msg {
         let re_protos::log_msg::v1alpha1::ArrowMsg {
@@ -403,21 +594,11 @@ fn compute_stats(app: bool, msg: &Msg) -> anyhow::Result<Option<ChunkStats>> {
                 entity_path.map(ToOwned::to_owned).unwrap_or_default()
             };
 
-            // TODO(cmc): shortest and longest range covered per timeline would be welcome addition,
-            // something like the following, but generic:
-            if false && let Some(log_tick) = decoded.batch.column_by_name("log_tick") {
-                let log_tick = log_tick
-                    .as_any()
-                    .downcast_ref::<arrow::array::Int64Array>()
-                    .ok_or_else(|| anyhow::anyhow!("`log_tick` is not a Int64Array, somehow"))?;
-                let _min = log_tick.values().iter().copied().min().unwrap_or_default();
-                let _max = log_tick.values().iter().copied().max().unwrap_or_default();
-            }
-
             let indexes = schema
                 .fields
                 .iter()
-                .filter(|&field| {
+                .zip(decoded.batch.columns())
+                .filter(|(field, _)| {
                     field
                         .metadata()
                         .get(re_sorbet::metadata::RERUN_KIND)
@@ -425,14 +606,29 @@ fn compute_stats(app: bool, msg: &Msg) -> anyhow::Result<Option<ChunkStats>> {
                         == Some("index")
                         || field.metadata().get("rerun.kind").map(|s| s.as_str()) == Some("index")
                 })
-                .map(|field| field.name().to_owned())
+                .map(|(field, column)| {
+                    let typ = TimeType::from_arrow_datatype(field.data_type());
+                    let range = arrow::compute::cast(column, &arrow::datatypes::DataType::Int64)
+                        .ok()
+                        .and_then(|times| {
+                            let times = times.downcast_array_ref::<Int64Array>()?;
+                            Some((arrow::compute::min(times)?, arrow::compute::max(times)?))
+                        });
+
+                    IndexStats {
+                        name: field.name().to_owned(),
+                        typ,
+                        range,
+                    }
+                })
                 .collect_vec();
             let num_indexes = indexes.len() as _;
 
             let components = schema
                 .fields
                 .iter()
-                .filter(|&field| {
+                .zip(decoded.batch.columns())
+                .filter(|(field, _)| {
                     field
                         .metadata()
                         .get(re_sorbet::metadata::RERUN_KIND)
@@ -440,7 +636,13 @@ fn compute_stats(app: bool, msg: &Msg) -> anyhow::Result<Option<ChunkStats>> {
                         == Some("data")
                         || field.metadata().get("rerun.kind").map(|s| s.as_str()) == Some("data")
                 })
-                .map(|field| field.name().to_owned())
+                .map(|(field, column)| ComponentStats {
+                    name: field.name().to_owned(),
+                    num_rows: (column.len() - column.null_count()) as _,
+                    // NOTE: Decoded columns are slices of the same IPC buffer, so only count the
+                    // bytes that are actually referenced.
+                    size_bytes: column.to_data().get_slice_memory_size().unwrap_or_default() as _,
+                })
                 .collect_vec();
             let num_components = components.len() as _;
 
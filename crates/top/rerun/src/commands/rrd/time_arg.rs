@@ -0,0 +1,50 @@
+use re_log_types::{TimeType, TimeUnit};
+
+/// A time value passed on the command line, e.g. `42`, `-1.5s` or `250ms`.
+///
+/// Values without a unit are in the native unit of the timeline (steps or nanoseconds).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimeArg {
+    value: f64,
+    unit: Option<TimeUnit>,
+}
+
+impl TimeArg {
+    /// The value in the native unit of a timeline of the given type.
+    pub fn to_native(self, typ: TimeType) -> anyhow::Result<i64> {
+        let unit = self.unit.unwrap_or_else(|| TimeUnit::native(typ));
+        anyhow::ensure!(
+            unit.is_compatible_with(typ),
+            "{self} doesn't apply to {typ:?} timelines"
+        );
+        unit.checked_to_native_f64(self.value)
+            .ok_or_else(|| anyhow::anyhow!("{self} is out of range"))
+    }
+}
+
+impl std::fmt::Display for TimeArg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)?;
+        if let Some(unit) = self.unit {
+            f.write_str(unit.suffix())?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for TimeArg {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let value = s.trim_end_matches(char::is_alphabetic);
+        let unit = &s[value.len()..];
+
+        Ok(Self {
+            value: value
+                .parse()
+                .map_err(|err| format!("invalid time {s:?}: {err}"))?,
+            unit: (!unit.is_empty()).then(|| unit.parse()).transpose()?,
+        })
+    }
+}
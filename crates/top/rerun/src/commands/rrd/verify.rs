@@ -1,9 +1,11 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
 use arrow::array::AsArray as _;
+use arrow::datatypes::DataType as ArrowDataType;
 
 use itertools::Itertools as _;
-use re_log_types::LogMsg;
+use re_chunk::{ChunkId, ComponentIdentifier};
+use re_log_types::{EntityPath, LogMsg, StoreId};
 use re_types::reflection::{ComponentDescriptorExt as _, Reflection};
 
 use crate::commands::read_rrd_streams_from_file_or_stdin;
@@ -14,23 +16,56 @@ use crate::commands::read_rrd_streams_from_file_or_stdin;
 pub struct VerifyCommand {
     /// Paths to read from. Reads from standard input if none are specified.
     path_to_input_rrds: Vec<String>,
+
+    /// Treat chunks that are out of order within a recording as errors rather than warnings.
+    ///
+    /// Chunk IDs are expected to increase monotonically within a recording, except for recordings
+    /// that were merged from several sources.
+    #[clap(long = "strict-ordering", default_value_t = false)]
+    strict_ordering: bool,
 }
 
 impl VerifyCommand {
     pub fn run(&self) -> anyhow::Result<()> {
-        let mut verifier = Verifier::new()?;
+        let Self {
+            path_to_input_rrds,
+            strict_ordering,
+        } = self;
 
-        let Self { path_to_input_rrds } = self;
+        let mut verifier = Verifier::new(*strict_ordering)?;
 
         let (rx, _) = read_rrd_streams_from_file_or_stdin(path_to_input_rrds);
 
         let mut seen_files = std::collections::HashSet::new();
+        let mut num_msgs_per_file: HashMap<String, u64> = HashMap::new();
 
         for (source, res) in rx {
-            verifier.verify_log_msg(&source.to_string(), res?);
+            let source_name = source.to_string();
+            let num_msgs = num_msgs_per_file.entry(source_name.clone()).or_default();
+
+            match res {
+                Ok(msg) => {
+                    *num_msgs += 1;
+                    verifier.verify_log_msg(&source_name, msg);
+                }
+                Err(err) => {
+                    verifier.errors.insert(format!(
+                        "{source_name}: Failed to decode the stream after {num_msgs} valid messages: {}. \
+                         The file is likely truncated or corrupted; everything before that point can still be loaded.",
+                        re_error::format(err),
+                    ));
+                }
+            }
+
             seen_files.insert(source);
         }
 
+        verifier.verify_transform_frames();
+
+        for warning in &verifier.warnings {
+            eprintln!("warning: {warning}");
+        }
+
         if verifier.errors.is_empty() {
             if seen_files.len() == 1 {
                 eprintln!("1 file verified without error.");
@@ -52,30 +87,75 @@ impl VerifyCommand {
 
 struct Verifier {
     reflection: Reflection,
-    errors: HashSet<String>,
+    strict_ordering: bool,
+
+    // Sorted, so that the report is stable.
+    errors: BTreeSet<String>,
+    warnings: BTreeSet<String>,
+
+    stores: HashMap<StoreId, StoreState>,
+}
+
+/// What we've learned about a store so far, to check it for consistency.
+#[derive(Default)]
+struct StoreState {
+    has_store_info: bool,
+    last_chunk_id: Option<ChunkId>,
+    chunk_ids: HashSet<ChunkId>,
+
+    /// The datatype of the first chunk of each column.
+    component_datatypes: HashMap<(EntityPath, ComponentIdentifier), ArrowDataType>,
+    index_datatypes: HashMap<String, ArrowDataType>,
+
+    /// Frames mentioned by `Transform3D:child_frame` or `Transform3D:parent_frame`.
+    transform_frames: HashSet<String>,
+
+    /// Frames mentioned by `CoordinateFrame:frame_id`, and the first entity that mentions each.
+    coordinate_frames: BTreeMap<String, EntityPath>,
 }
 
 impl Verifier {
-    fn new() -> anyhow::Result<Self> {
+    fn new(strict_ordering: bool) -> anyhow::Result<Self> {
         Ok(Self {
             reflection: re_types::reflection::generate_reflection()?,
-            errors: HashSet::new(),
+            strict_ordering,
+            errors: BTreeSet::new(),
+            warnings: BTreeSet::new(),
+            stores: HashMap::new(),
         })
     }
 
     fn verify_log_msg(&mut self, source: &str, msg: LogMsg) {
         match msg {
-            LogMsg::SetStoreInfo { .. } | LogMsg::BlueprintActivationCommand { .. } => {}
+            LogMsg::SetStoreInfo(store_info) => {
+                self.stores
+                    .entry(store_info.info.store_id.clone())
+                    .or_default()
+                    .has_store_info = true;
+            }
 
-            LogMsg::ArrowMsg(_store_id, arrow_msg) => {
-                self.verify_record_batch(source, &arrow_msg.batch);
+            LogMsg::BlueprintActivationCommand { .. } => {}
+
+            LogMsg::ArrowMsg(store_id, arrow_msg) => {
+                self.verify_record_batch(source, &store_id, &arrow_msg.batch);
             }
         }
     }
 
-    fn verify_record_batch(&mut self, source: &str, batch: &arrow::array::RecordBatch) {
+    fn verify_record_batch(
+        &mut self,
+        source: &str,
+        store_id: &StoreId,
+        batch: &arrow::array::RecordBatch,
+    ) {
         match re_sorbet::ChunkBatch::try_from(batch) {
-            Ok(chunk_batch) => self.verify_chunk_batch(source, &chunk_batch),
+            Ok(chunk_batch) => {
+                self.verify_chunk_order(source, store_id, chunk_batch.chunk_id());
+                self.verify_chunk_integrity(source, &chunk_batch);
+                self.verify_chunk_schema(source, store_id, &chunk_batch);
+                self.collect_transform_frames(store_id, &chunk_batch);
+                self.verify_chunk_batch(source, &chunk_batch);
+            }
             Err(err) => {
                 self.errors
                     .insert(format!("{source}: Failed to parse batch: {err}"));
@@ -83,6 +163,135 @@ impl Verifier {
         }
     }
 
+    fn verify_chunk_order(&mut self, source: &str, store_id: &StoreId, chunk_id: ChunkId) {
+        let store = self.stores.entry(store_id.clone()).or_default();
+
+        if !store.has_store_info {
+            self.errors.insert(format!(
+                "{source}: Data for {store_id:?} arrives before its store info. \
+                 Was the beginning of the stream cut off?"
+            ));
+        }
+
+        if !store.chunk_ids.insert(chunk_id) {
+            self.warnings.insert(format!(
+                "{source}: Chunk {chunk_id} of {store_id:?} appears more than once."
+            ));
+        }
+
+        if let Some(last_chunk_id) = store.last_chunk_id
+            && chunk_id < last_chunk_id
+        {
+            let report = format!(
+                "{source}: Chunks of {store_id:?} are out of order: chunk {chunk_id} comes after chunk {last_chunk_id}."
+            );
+            if self.strict_ordering {
+                self.errors.insert(report);
+            } else if self.warnings.len() < MAX_WARNINGS {
+                self.warnings.insert(report);
+            }
+        }
+        store.last_chunk_id = Some(chunk_id);
+    }
+
+    fn verify_chunk_integrity(&mut self, source: &str, chunk_batch: &re_sorbet::ChunkBatch) {
+        let res =
+            re_chunk::Chunk::from_chunk_batch(chunk_batch).and_then(|chunk| chunk.sanity_check());
+        if let Err(err) = res {
+            self.errors.insert(format!(
+                "{source}: Chunk {} of {} is malformed: {err}",
+                chunk_batch.chunk_id(),
+                chunk_batch.entity_path(),
+            ));
+        }
+    }
+
+    /// All chunks must agree on the datatype of a given column, otherwise they can't be queried
+    /// together.
+    fn verify_chunk_schema(
+        &mut self,
+        source: &str,
+        store_id: &StoreId,
+        chunk_batch: &re_sorbet::ChunkBatch,
+    ) {
+        let store = self.stores.entry(store_id.clone()).or_default();
+        let entity_path = chunk_batch.entity_path();
+
+        for (descr, column) in chunk_batch.index_columns() {
+            let expected = store
+                .index_datatypes
+                .entry(descr.column_name().to_owned())
+                .or_insert_with(|| column.data_type().clone());
+            if expected != column.data_type() {
+                self.errors.insert(format!(
+                    "{source}: Timeline {:?} of {store_id:?} is of type {expected} in some chunks, but of type {} in chunk {} of {entity_path}.",
+                    descr.column_name(),
+                    column.data_type(),
+                    chunk_batch.chunk_id(),
+                ));
+            }
+        }
+
+        for (descr, column) in chunk_batch.component_columns() {
+            let expected = store
+                .component_datatypes
+                .entry((entity_path.clone(), descr.component))
+                .or_insert_with(|| column.data_type().clone());
+            if expected != column.data_type() {
+                self.errors.insert(format!(
+                    "{source}: Component {entity_path}:{} of {store_id:?} is of type {expected} in some chunks, but of type {} in chunk {}.",
+                    descr.component,
+                    column.data_type(),
+                    chunk_batch.chunk_id(),
+                ));
+            }
+        }
+    }
+
+    fn collect_transform_frames(
+        &mut self,
+        store_id: &StoreId,
+        chunk_batch: &re_sorbet::ChunkBatch,
+    ) {
+        let child_frame = re_types::archetypes::Transform3D::descriptor_child_frame().component;
+        let parent_frame = re_types::archetypes::Transform3D::descriptor_parent_frame().component;
+        let frame_id = re_types::archetypes::CoordinateFrame::descriptor_frame_id().component;
+
+        let store = self.stores.entry(store_id.clone()).or_default();
+        for (descr, column) in chunk_batch.component_columns() {
+            if descr.component == child_frame || descr.component == parent_frame {
+                store.transform_frames.extend(frame_ids(column));
+            } else if descr.component == frame_id {
+                for frame in frame_ids(column) {
+                    store
+                        .coordinate_frames
+                        .entry(frame)
+                        .or_insert_with(|| chunk_batch.entity_path().clone());
+                }
+            }
+        }
+    }
+
+    /// Every frame an entity claims to be in must be connected to something by a transform.
+    ///
+    /// Implicit frames (`tf#/some/entity`) always are.
+    fn verify_transform_frames(&mut self) {
+        for (store_id, store) in &self.stores {
+            for (frame, entity_path) in &store.coordinate_frames {
+                if frame
+                    .starts_with(re_types::components::TransformFrameId::ENTITY_HIERARCHY_PREFIX)
+                    || store.transform_frames.contains(frame)
+                {
+                    continue;
+                }
+                self.errors.insert(format!(
+                    "{store_id:?}: {entity_path} is in the coordinate frame {frame:?}, but no transform ever mentions that frame. \
+                     Is the `child_frame` of its Transform3D missing or misspelled?"
+                ));
+            }
+        }
+    }
+
     fn verify_chunk_batch(&mut self, source: &str, chunk_batch: &re_sorbet::ChunkBatch) {
         for (component_descriptor, column) in chunk_batch.component_columns() {
             if let Err(err) = self.verify_component_column(component_descriptor, column) {
@@ -192,3 +401,17 @@ impl Verifier {
         Ok(())
     }
 }
+
+/// Out-of-order chunks are reported individually, but it's no use flooding the terminal with them.
+const MAX_WARNINGS: usize = 100;
+
+/// The values of a `TransformFrameId` column.
+fn frame_ids(column: &dyn arrow::array::Array) -> Vec<String> {
+    column
+        .as_list_opt::<i32>()
+        .and_then(|list| {
+            let frames = list.values().as_string_opt::<i32>()?;
+            Some(frames.iter().flatten().map(ToOwned::to_owned).collect())
+        })
+        .unwrap_or_default()
+}
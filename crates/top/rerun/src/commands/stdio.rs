@@ -80,6 +80,60 @@ pub fn read_raw_rrd_streams_from_file_or_stdin(
     read_any_rrd_streams_from_file_or_stdin::<re_protos::log_msg::v1alpha1::log_msg::Msg>(paths)
 }
 
+/// Like [`read_rrd_streams_from_file_or_stdin`], but only decodes the messages that `filter`
+/// selects, by seeking directly to them using the [`re_log_encoding::ChunkIndex`] of the file.
+///
+/// Returns `None` if `paths` isn't a single indexed RRD file, in which case the caller should
+/// fall back to scanning the input.
+pub fn read_indexed_rrd_file(
+    paths: &[String],
+    filter: impl Fn(&re_log_encoding::ChunkIndexEntry) -> bool + Send + 'static,
+) -> Option<(
+    channel::Receiver<(InputSource, anyhow::Result<re_log_types::LogMsg>)>,
+    channel::Receiver<u64>,
+)> {
+    let [rrd_path] = paths else {
+        return None;
+    };
+    let rrd_path = PathBuf::from(rrd_path);
+
+    let mut rrd_file = std::io::BufReader::new(std::fs::File::open(&rrd_path).ok()?);
+    let index = match re_log_encoding::ChunkIndex::read_from(&mut rrd_file) {
+        Ok(index) => index?,
+        Err(err) => {
+            re_log::warn!(%err, "couldn't read chunk index of {rrd_path:?} -- scanning instead");
+            return None;
+        }
+    };
+
+    re_log::debug!(
+        "Seeking through the {} indexed messages of {rrd_path:?}…",
+        index.entries.len()
+    );
+
+    // TODO(cmc): might want to make this configurable at some point.
+    let (tx, rx) = crossbeam::channel::bounded(100);
+    let (tx_size_bytes, rx_size_bytes) = crossbeam::channel::bounded(1);
+
+    _ = std::thread::Builder::new()
+        .name("rerun-rrd-in".to_owned())
+        .spawn(move || {
+            let mut messages =
+                re_log_encoding::DecoderApp::decode_indexed(rrd_file, &index, filter);
+
+            for res in &mut messages {
+                let res = res
+                    .context("decode rrd message")
+                    .with_context(|| format!("couldn't decode message {rrd_path:?} -- skipping"));
+                tx.send((InputSource::File(rrd_path.clone()), res)).ok();
+            }
+
+            tx_size_bytes.send(messages.num_bytes_processed()).ok();
+        });
+
+    Some((rx, rx_size_bytes))
+}
+
 fn read_any_rrd_streams_from_file_or_stdin<
     T: re_log_encoding::DecoderEntrypoint + Send + 'static,
 >(
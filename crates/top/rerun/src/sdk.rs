@@ -11,6 +11,13 @@ pub mod coordinates {
     pub use re_types::view_coordinates::{Axis3, Handedness, Sign, SignedAxis3};
 }
 
+/// Camera capture helpers, for use with [`archetypes::Image::from_raw_parts`].
+pub mod camera {
+    pub use re_types::image::{
+        CameraPixelFormat, RawImageConversion, RawImageError, RawImageLayout,
+    };
+}
+
 pub use re_types::{archetypes, components, datatypes};
 
 mod prelude {
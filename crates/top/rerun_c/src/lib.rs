@@ -817,6 +817,8 @@ fn rr_recording_stream_serve_grpc_impl(
             .as_maybe_empty_str("server_memory_limit")?
             .parse::<re_sdk::MemoryLimit>()
             .map_err(|err| CError::new(CErrorCode::InvalidMemoryLimit, &err))?,
+
+        ..Default::default()
     };
 
     stream
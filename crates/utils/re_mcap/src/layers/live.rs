@@ -0,0 +1,149 @@
+use std::sync::Arc;
+
+use re_chunk::{Chunk, EntityPath, external::nohash_hasher::IntMap};
+
+use super::{LayerRegistry, McapChunkDecoder, MessageLayerRunner};
+use crate::{
+    Error,
+    parsers::{ChannelId, ParserContext},
+};
+
+/// Decodes MCAP messages one at a time, as they arrive from a live source.
+///
+/// In contrast to [`super::ExecutionPlan`], this does not require the entire MCAP file up front.
+/// Instead, channels are announced incrementally (e.g. by a foxglove-bridge style WebSocket server),
+/// and each time the set of channels changes, the channels are re-assigned to the message layers.
+///
+/// File-scoped layers are not run, since there is no file to process.
+pub struct LiveDecoder {
+    registry: LayerRegistry,
+    summary: mcap::Summary,
+    runners: Vec<MessageLayerRunner>,
+}
+
+impl LiveDecoder {
+    pub fn new(registry: LayerRegistry) -> Self {
+        Self {
+            registry,
+            summary: mcap::Summary::default(),
+            runners: Vec::new(),
+        }
+    }
+
+    /// Announces a new channel, or replaces an existing channel with the same id.
+    pub fn add_channel(&mut self, channel: Arc<mcap::Channel<'static>>) -> Result<(), Error> {
+        if let Some(schema) = &channel.schema {
+            self.summary.schemas.insert(schema.id, schema.clone());
+        }
+        let channel_id = channel.id;
+        let previous = self.summary.channels.insert(channel_id, channel);
+
+        if let Err(err) = self.replan() {
+            // Don't let a single bad channel prevent all other channels from being decoded.
+            if let Some(previous) = previous {
+                self.summary.channels.insert(channel_id, previous);
+            } else {
+                self.summary.channels.remove(&channel_id);
+            }
+            return Err(err);
+        }
+
+        Ok(())
+    }
+
+    /// Removes a channel, messages on that channel will be ignored from here on.
+    pub fn remove_channel(&mut self, channel_id: u16) -> Result<(), Error> {
+        if self.summary.channels.remove(&channel_id).is_some() {
+            self.replan()?;
+        }
+        Ok(())
+    }
+
+    /// Returns the channel that was announced for `channel_id`, if any.
+    pub fn channel(&self, channel_id: u16) -> Option<&Arc<mcap::Channel<'static>>> {
+        self.summary.channels.get(&channel_id)
+    }
+
+    /// Converts a single message into Rerun chunks.
+    ///
+    /// Returns an empty list if no layer handles the channel of this message.
+    pub fn decode(&self, msg: &mcap::Message<'_>) -> Result<Vec<Chunk>, Error> {
+        re_tracing::profile_function!();
+
+        let channel_id = ChannelId::from(msg.channel.id);
+
+        let Some(runner) = self
+            .runners
+            .iter()
+            .find(|runner| runner.allowed.contains(&channel_id))
+        else {
+            return Ok(Vec::new());
+        };
+
+        let Some(parser) = runner.inner.message_parser(&msg.channel, 1) else {
+            return Ok(Vec::new());
+        };
+
+        let ctx = ParserContext::new(EntityPath::from(msg.channel.topic.as_str()));
+        let mut decoder = McapChunkDecoder::new(
+            std::iter::once((channel_id, (ctx, parser))).collect::<IntMap<_, _>>(),
+        );
+        decoder.decode_next(msg)?;
+        decoder.finish().collect()
+    }
+
+    fn replan(&mut self) -> Result<(), Error> {
+        let mut runners = self.registry.plan(&self.summary)?.runners;
+        for runner in &mut runners {
+            runner.inner.init(&self.summary)?;
+        }
+        self.runners = runners;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layers::McapRawLayer;
+
+    fn channel(id: u16, topic: &str) -> Arc<mcap::Channel<'static>> {
+        Arc::new(mcap::Channel {
+            id,
+            topic: topic.to_owned(),
+            schema: None,
+            message_encoding: "json".to_owned(),
+            metadata: Default::default(),
+        })
+    }
+
+    fn message(channel: &Arc<mcap::Channel<'static>>) -> mcap::Message<'static> {
+        mcap::Message {
+            channel: channel.clone(),
+            sequence: 0,
+            log_time: 1,
+            publish_time: 1,
+            data: std::borrow::Cow::Owned(b"{}".to_vec()),
+        }
+    }
+
+    #[test]
+    fn test_live_decoder_channels() {
+        let registry = LayerRegistry::empty().register_message_layer::<McapRawLayer>();
+        let mut decoder = LiveDecoder::new(registry);
+
+        let foo = channel(1, "/foo");
+        let msg = message(&foo);
+
+        // Messages on channels that were never announced are ignored.
+        assert!(decoder.decode(&msg).unwrap().is_empty());
+
+        decoder.add_channel(foo.clone()).unwrap();
+        let chunks = decoder.decode(&msg).unwrap();
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].entity_path(), &EntityPath::from("/foo"));
+
+        decoder.remove_channel(foo.id).unwrap();
+        assert!(decoder.decode(&msg).unwrap().is_empty());
+    }
+}
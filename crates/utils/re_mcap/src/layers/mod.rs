@@ -1,3 +1,4 @@
+mod live;
 mod protobuf;
 mod raw;
 mod recording_info;
@@ -10,9 +11,9 @@ use re_chunk::{Chunk, EntityPath, external::nohash_hasher::IntMap};
 use std::collections::{BTreeMap, BTreeSet};
 
 pub use self::{
-    protobuf::McapProtobufLayer, raw::McapRawLayer, recording_info::McapRecordingInfoLayer,
-    ros2::McapRos2Layer, ros2_reflection::McapRos2ReflectionLayer, schema::McapSchemaLayer,
-    stats::McapStatisticLayer,
+    live::LiveDecoder, protobuf::McapProtobufLayer, raw::McapRawLayer,
+    recording_info::McapRecordingInfoLayer, ros2::McapRos2Layer,
+    ros2_reflection::McapRos2ReflectionLayer, schema::McapSchemaLayer, stats::McapStatisticLayer,
 };
 
 use crate::{
@@ -120,12 +121,7 @@ impl McapChunkDecoder {
 
         let channel = msg.channel.as_ref();
         let channel_id = ChannelId(channel.id);
-        let log_time_cell = crate::util::TimestampCell::guess_from_nanos(msg.log_time);
-        let publish_time_cell = crate::util::TimestampCell::guess_from_nanos(msg.publish_time);
-        let timepoint = re_chunk::TimePoint::from([
-            ("log_time", log_time_cell.into_time_cell()),
-            ("publish_time", publish_time_cell.into_time_cell()),
-        ]);
+        let timepoint = crate::util::message_timepoint(msg);
 
         if let Some((ctx, parser)) = self.parsers.get_mut(&channel_id) {
             // If the parser fails, we should _not_ append the timepoint
@@ -5,15 +5,18 @@ use crate::parsers::{
     MessageParser,
     ros2msg::{
         Ros2MessageParser,
+        nav_msgs::OdometryMessageParser,
         rcl_interfaces::LogMessageParser,
         sensor_msgs::{
             BatteryStateMessageParser, CameraInfoMessageParser, CompressedImageMessageParser,
             FluidPressureMessageParser, IlluminanceMessageParser, ImageMessageParser,
-            ImuMessageParser, JointStateMessageParser, MagneticFieldMessageParser,
-            NavSatFixMessageParser, PointCloud2MessageParser, RangeMessageParser,
-            RelativeHumidityMessageParser, TemperatureMessageParser,
+            ImuMessageParser, JointStateMessageParser, LaserScanMessageParser,
+            MagneticFieldMessageParser, NavSatFixMessageParser, PointCloud2MessageParser,
+            RangeMessageParser, RelativeHumidityMessageParser, TemperatureMessageParser,
         },
         std_msgs::StringMessageParser,
+        tf2_msgs::TFMessageParser,
+        visualization_msgs::MarkerMessageParser,
     },
 };
 
@@ -36,6 +39,8 @@ impl McapRos2Layer {
     /// Creates a new [`McapRos2Layer`] with all supported message types pre-registered
     pub fn new() -> Self {
         Self::empty()
+            // nav_msgs
+            .register_parser::<OdometryMessageParser>("nav_msgs/msg/Odometry")
             // rcl_interfaces
             .register_parser::<LogMessageParser>("rcl_interfaces/msg/Log")
             // sensor_msgs
@@ -47,6 +52,7 @@ impl McapRos2Layer {
             .register_parser::<ImageMessageParser>("sensor_msgs/msg/Image")
             .register_parser::<ImuMessageParser>("sensor_msgs/msg/Imu")
             .register_parser::<JointStateMessageParser>("sensor_msgs/msg/JointState")
+            .register_parser::<LaserScanMessageParser>("sensor_msgs/msg/LaserScan")
             .register_parser::<MagneticFieldMessageParser>("sensor_msgs/msg/MagneticField")
             .register_parser::<NavSatFixMessageParser>("sensor_msgs/msg/NavSatFix")
             .register_parser::<PointCloud2MessageParser>("sensor_msgs/msg/PointCloud2")
@@ -55,6 +61,10 @@ impl McapRos2Layer {
             .register_parser::<TemperatureMessageParser>("sensor_msgs/msg/Temperature")
             // std_msgs
             .register_parser::<StringMessageParser>("std_msgs/msg/String")
+            // tf2_msgs
+            .register_parser::<TFMessageParser>("tf2_msgs/msg/TFMessage")
+            // visualization_msgs
+            .register_parser::<MarkerMessageParser>("visualization_msgs/msg/Marker")
     }
 
     /// Registers a new message parser for the given schema name
@@ -7,7 +7,9 @@ pub(crate) mod parsers;
 pub(crate) mod util;
 
 pub use error::Error;
-pub use layers::{Layer, LayerIdentifier, LayerRegistry, MessageLayer, SelectedLayers};
+pub use layers::{
+    Layer, LayerIdentifier, LayerRegistry, LiveDecoder, MessageLayer, SelectedLayers,
+};
 pub use parsers::{MessageParser, ParserContext, cdr};
 
 // TODO(grtlr): We should expose an `Mcap` object that internally holds the summary + a reference to the bytes.
@@ -62,6 +62,19 @@ pub fn try_decode_message<'d, T: Deserialize<'d>>(msg: &'d [u8]) -> Result<T, Cd
     }
 }
 
+/// Encode a `T` as a little-endian CDR message, including the 4 byte header.
+///
+/// The inverse of [`try_decode_message`], used to construct messages in tests.
+#[cfg(test)]
+pub(crate) fn encode_message<T: serde::Serialize>(value: &T) -> Vec<u8> {
+    let mut msg = vec![0x00, 0x01, 0x00, 0x00];
+    msg.extend(
+        cdr_encoding::to_vec::<T, byteorder::LittleEndian>(value)
+            .expect("Failed to encode CDR message"),
+    );
+    msg
+}
+
 /// Errors from CDR decoding.
 #[derive(Error, Debug)]
 pub enum CdrError {
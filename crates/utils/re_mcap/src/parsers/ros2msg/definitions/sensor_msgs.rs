@@ -787,3 +787,48 @@ pub struct MagneticField {
     /// 0 is interpreted as variance unknown.
     pub magnetic_field_covariance: [f64; 9],
 }
+
+/// Single scan from a planar laser range-finder.
+///
+/// If you have another ranging device with different behavior (e.g. a sonar
+/// array), please find or create a different message, since applications
+/// will make fairly laser-specific assumptions about this data.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LaserScan {
+    /// Timestamp in the header is the acquisition time of the first ray in the scan.
+    ///
+    /// In `frame_id`, angles are measured around the positive Z axis (counterclockwise, if Z is up)
+    /// with zero angle being forward along the x axis.
+    pub header: Header,
+
+    /// Start angle of the scan in radians.
+    pub angle_min: f32,
+
+    /// End angle of the scan in radians.
+    pub angle_max: f32,
+
+    /// Angular distance between measurements in radians.
+    pub angle_increment: f32,
+
+    /// Time between measurements in seconds.
+    pub time_increment: f32,
+
+    /// Time between scans in seconds.
+    pub scan_time: f32,
+
+    /// Minimum range value in meters.
+    pub range_min: f32,
+
+    /// Maximum range value in meters.
+    pub range_max: f32,
+
+    /// Range data in meters.
+    ///
+    /// Values < `range_min` or > `range_max` should be discarded.
+    pub ranges: Vec<f32>,
+
+    /// Intensity data in device-specific units.
+    ///
+    /// If your device does not provide intensities, please leave the array empty.
+    pub intensities: Vec<f32>,
+}
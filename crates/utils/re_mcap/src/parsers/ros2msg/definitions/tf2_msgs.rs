@@ -0,0 +1,13 @@
+//! Definitions for the ROS2 `tf2_msgs` package.
+//!
+//! Based on definitions taken from <https://github.com/ros2/geometry2/tree/rolling/tf2_msgs>
+
+use serde::{Deserialize, Serialize};
+
+use super::geometry_msgs::TransformStamped;
+
+/// A set of transforms, as published on the `/tf` and `/tf_static` topics.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TFMessage {
+    pub transforms: Vec<TransformStamped>,
+}
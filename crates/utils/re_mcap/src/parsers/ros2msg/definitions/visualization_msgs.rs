@@ -0,0 +1,76 @@
+//! Definitions for the ROS2 `visualization_msgs` package.
+//!
+//! Based on definitions taken from <https://github.com/ros2/common_interfaces/tree/rolling/visualization_msgs>
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    builtin_interfaces::Duration,
+    geometry_msgs::{Point, Pose, Vector3},
+    std_msgs::{ColorRGBA, Header},
+};
+
+/// A marker that describes a shape to be displayed in a visualization tool such as `RViz`.
+///
+/// Only the fields up to and including `colors` are decoded: the layout of the remaining
+/// fields (text, textures and meshes) differs between ROS2 distributions.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Marker {
+    /// Metadata including timestamp and coordinate frame.
+    pub header: Header,
+
+    /// Namespace to place this object in, used in conjunction with `id` to create a unique name for the object.
+    pub ns: String,
+
+    /// Object ID used in conjunction with the namespace for manipulating and deleting the object later.
+    pub id: i32,
+
+    /// Type of object, see the `Marker::*` type constants.
+    pub marker_type: i32,
+
+    /// Action to take, see the `Marker::*` action constants.
+    pub action: i32,
+
+    /// Pose of the object with respect to the frame / time specified in the header.
+    pub pose: Pose,
+
+    /// Scale of the object, 1,1,1 means default (usually 1 meter square).
+    pub scale: Vector3,
+
+    /// Color of the object, each channel in the range [0, 1].
+    pub color: ColorRGBA,
+
+    /// How long the object should last before being automatically deleted. 0 indicates forever.
+    pub lifetime: Duration,
+
+    /// If this marker should be frame-locked, i.e. retransformed into its frame every timestep.
+    pub frame_locked: bool,
+
+    /// Only used if the type specified has some use for them (eg. `POINTS`, `LINE_STRIP`, etc.).
+    pub points: Vec<Point>,
+
+    /// Only used if the type specified has some use for them (eg. `POINTS`, `LINE_STRIP`, etc.).
+    ///
+    /// The number of colors provided must either be 0 or equal to the number of points provided.
+    pub colors: Vec<ColorRGBA>,
+}
+
+impl Marker {
+    pub const ARROW: i32 = 0;
+    pub const CUBE: i32 = 1;
+    pub const SPHERE: i32 = 2;
+    pub const CYLINDER: i32 = 3;
+    pub const LINE_STRIP: i32 = 4;
+    pub const LINE_LIST: i32 = 5;
+    pub const CUBE_LIST: i32 = 6;
+    pub const SPHERE_LIST: i32 = 7;
+    pub const POINTS: i32 = 8;
+    pub const TEXT_VIEW_FACING: i32 = 9;
+    pub const MESH_RESOURCE: i32 = 10;
+    pub const TRIANGLE_LIST: i32 = 11;
+
+    pub const ADD: i32 = 0;
+    pub const MODIFY: i32 = 0;
+    pub const DELETE: i32 = 2;
+    pub const DELETEALL: i32 = 3;
+}
@@ -2,13 +2,49 @@ use crate::parsers::MessageParser;
 
 mod definitions;
 
+pub mod nav_msgs;
 pub mod rcl_interfaces;
 pub mod scalar_parser;
 pub mod sensor_msgs;
 pub mod std_msgs;
+pub mod tf2_msgs;
+pub mod visualization_msgs;
 
 /// Trait for ROS2 message parsers that can be constructed with just a row count.
 pub trait Ros2MessageParser: MessageParser {
     /// Create a new parser instance.
     fn new(num_rows: usize) -> Self;
 }
+
+/// Runs a parser over CDR-encoded messages of a single topic, the same way the MCAP loader does.
+#[cfg(test)]
+pub(crate) fn parse_messages<P: Ros2MessageParser + 'static>(
+    topic: &str,
+    messages: &[Vec<u8>],
+) -> anyhow::Result<Vec<re_chunk::Chunk>> {
+    use crate::parsers::ParserContext;
+
+    let channel = std::sync::Arc::new(mcap::Channel {
+        id: 0,
+        topic: topic.to_owned(),
+        schema: None,
+        message_encoding: "cdr".to_owned(),
+        metadata: Default::default(),
+    });
+
+    let mut ctx = ParserContext::new(re_chunk::EntityPath::from(topic));
+    let mut parser = Box::new(P::new(messages.len()));
+    for (sequence, data) in (0..).zip(messages) {
+        let msg = mcap::Message {
+            channel: channel.clone(),
+            sequence,
+            log_time: u64::from(sequence),
+            publish_time: u64::from(sequence),
+            data: std::borrow::Cow::Borrowed(data),
+        };
+        parser.append(&mut ctx, &msg)?;
+        ctx.add_timepoint(crate::util::message_timepoint(&msg));
+    }
+
+    parser.finalize(ctx)
+}
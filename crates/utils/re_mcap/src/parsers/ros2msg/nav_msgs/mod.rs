@@ -0,0 +1,3 @@
+mod odometry;
+
+pub use odometry::*;
@@ -0,0 +1,231 @@
+use anyhow::Context as _;
+use arrow::array::{FixedSizeListBuilder, Float64Builder};
+use re_chunk::{Chunk, ChunkId};
+use re_types::{
+    ComponentDescriptor, SerializedComponentColumn, archetypes::Transform3D,
+    reflection::ComponentDescriptorExt as _,
+};
+
+use super::super::definitions::nav_msgs;
+use crate::parsers::{
+    cdr,
+    decode::{MessageParser, ParserContext},
+    ros2msg::Ros2MessageParser,
+    util::fixed_size_list_builder,
+};
+
+/// Plugin that parses `nav_msgs/msg/Odometry` messages.
+#[derive(Default)]
+pub struct OdometrySchemaPlugin;
+
+/// Logs the estimated pose as a [`Transform3D`] and keeps the twist as additional components.
+pub struct OdometryMessageParser {
+    translations: Vec<[f32; 3]>,
+    quaternions: Vec<[f32; 4]>,
+    linear_velocity: FixedSizeListBuilder<Float64Builder>,
+    angular_velocity: FixedSizeListBuilder<Float64Builder>,
+}
+
+impl OdometryMessageParser {
+    const ARCHETYPE_NAME: &str = "nav_msgs.msg.Odometry";
+
+    fn create_metadata_column(
+        name: &str,
+        mut builder: FixedSizeListBuilder<Float64Builder>,
+    ) -> SerializedComponentColumn {
+        SerializedComponentColumn {
+            list_array: builder.finish().into(),
+            descriptor: ComponentDescriptor::partial(name)
+                .with_builtin_archetype(Self::ARCHETYPE_NAME),
+        }
+    }
+}
+
+impl Ros2MessageParser for OdometryMessageParser {
+    fn new(num_rows: usize) -> Self {
+        Self {
+            translations: Vec::with_capacity(num_rows),
+            quaternions: Vec::with_capacity(num_rows),
+            linear_velocity: fixed_size_list_builder(3, num_rows),
+            angular_velocity: fixed_size_list_builder(3, num_rows),
+        }
+    }
+}
+
+impl MessageParser for OdometryMessageParser {
+    fn append(&mut self, ctx: &mut ParserContext, msg: &mcap::Message<'_>) -> anyhow::Result<()> {
+        re_tracing::profile_function!();
+        let nav_msgs::Odometry {
+            header,
+            pose,
+            twist,
+            ..
+        } = cdr::try_decode_message::<nav_msgs::Odometry>(&msg.data)
+            .context("Failed to decode nav_msgs::Odometry message from CDR data")?;
+
+        // add the sensor timestamp to the context, `log_time` and `publish_time` are added automatically
+        ctx.add_timestamp_cell(crate::util::TimestampCell::guess_from_nanos_ros2(
+            header.stamp.as_nanos() as u64,
+        ));
+
+        let position = pose.pose.position;
+        let orientation = pose.pose.orientation;
+        self.translations
+            .push([position.x as f32, position.y as f32, position.z as f32]);
+        self.quaternions.push([
+            orientation.x as f32,
+            orientation.y as f32,
+            orientation.z as f32,
+            orientation.w as f32,
+        ]);
+
+        let linear = twist.twist.linear;
+        self.linear_velocity
+            .values()
+            .append_slice(&[linear.x, linear.y, linear.z]);
+        self.linear_velocity.append(true);
+
+        let angular = twist.twist.angular;
+        self.angular_velocity
+            .values()
+            .append_slice(&[angular.x, angular.y, angular.z]);
+        self.angular_velocity.append(true);
+
+        Ok(())
+    }
+
+    fn finalize(self: Box<Self>, ctx: ParserContext) -> anyhow::Result<Vec<Chunk>> {
+        re_tracing::profile_function!();
+        let Self {
+            translations,
+            quaternions,
+            linear_velocity,
+            angular_velocity,
+        } = *self;
+
+        let entity_path = ctx.entity_path().clone();
+        let timelines = ctx.build_timelines();
+
+        let mut chunk_components: Vec<_> = Transform3D::update_fields()
+            .with_many_translation(translations)
+            .with_many_quaternion(quaternions)
+            .columns_of_unit_batches()?
+            .collect();
+
+        chunk_components.extend([
+            Self::create_metadata_column("linear_velocity", linear_velocity),
+            Self::create_metadata_column("angular_velocity", angular_velocity),
+        ]);
+
+        Ok(vec![Chunk::from_auto_row_ids(
+            ChunkId::new(),
+            entity_path,
+            timelines,
+            chunk_components.into_iter().collect(),
+        )?])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::definitions::{
+        builtin_interfaces::Time,
+        geometry_msgs::{
+            Point, Pose, PoseWithCovariance, Quaternion, Twist, TwistWithCovariance, Vector3,
+        },
+        std_msgs::Header,
+    };
+    use super::*;
+    use crate::parsers::ros2msg::parse_messages;
+
+    /// A covariance matrix where every entry holds its row-major index, plus an offset.
+    fn covariance(offset: f64) -> [[f64; 6]; 6] {
+        std::array::from_fn(|row| std::array::from_fn(|col| offset + (row * 6 + col) as f64))
+    }
+
+    fn odometry() -> nav_msgs::Odometry {
+        nav_msgs::Odometry {
+            header: Header {
+                stamp: Time { sec: 1, nanosec: 0 },
+                frame_id: "odom".to_owned(),
+            },
+            child_frame_id: "base_link".to_owned(),
+            pose: PoseWithCovariance {
+                pose: Pose {
+                    position: Point {
+                        x: 1.0,
+                        y: 2.0,
+                        z: 3.0,
+                    },
+                    orientation: Quaternion {
+                        x: 0.0,
+                        y: 0.0,
+                        z: 0.0,
+                        w: 1.0,
+                    },
+                },
+                covariance: covariance(0.0),
+            },
+            twist: TwistWithCovariance {
+                twist: Twist {
+                    linear: Vector3 {
+                        x: 4.0,
+                        y: 5.0,
+                        z: 6.0,
+                    },
+                    angular: Vector3 {
+                        x: 7.0,
+                        y: 8.0,
+                        z: 9.0,
+                    },
+                },
+                covariance: covariance(100.0),
+            },
+        }
+    }
+
+    #[test]
+    fn test_odometry_covariance_layout() {
+        // Both covariances are 36 row-major doubles without a length prefix, aligned to 8 bytes
+        // (relative to the end of the 4 byte header).
+        const POSE_COVARIANCE: usize = 4 + 96;
+        const TWIST_COVARIANCE: usize = 4 + 432;
+
+        let data = cdr::encode_message(&odometry());
+        assert_eq!(data.len(), 4 + 720);
+
+        let read_f64 = |offset: usize| {
+            f64::from_le_bytes(data[offset..offset + 8].try_into().expect("8 bytes"))
+        };
+        for index in 0..36 {
+            assert_eq!(read_f64(POSE_COVARIANCE + 8 * index), index as f64);
+            assert_eq!(read_f64(TWIST_COVARIANCE + 8 * index), 100.0 + index as f64);
+        }
+
+        let decoded = cdr::try_decode_message::<nav_msgs::Odometry>(&data).unwrap();
+        assert_eq!(decoded.pose.covariance, covariance(0.0));
+        assert_eq!(decoded.twist.covariance, covariance(100.0));
+
+        // The twist comes after the pose covariance, so it only decodes correctly if the layout does.
+        let chunks = parse_messages::<OdometryMessageParser>("/odom", &[data]).unwrap();
+        assert_eq!(chunks.len(), 1);
+        let chunk = &chunks[0];
+
+        let translations = chunk
+            .iter_slices::<[f32; 3]>(Transform3D::descriptor_translation().component)
+            .collect::<Vec<_>>();
+        assert_eq!(translations, vec![&[[1.0, 2.0, 3.0]][..]]);
+
+        let velocity = |name: &str| {
+            chunk
+                .iter_slices::<f64>(
+                    ComponentDescriptor::partial(name)
+                        .with_builtin_archetype(OdometryMessageParser::ARCHETYPE_NAME)
+                        .component,
+                )
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(velocity("linear_velocity"), vec![&[4.0, 5.0, 6.0][..]]);
+        assert_eq!(velocity("angular_velocity"), vec![&[7.0, 8.0, 9.0][..]]);
+    }
+}
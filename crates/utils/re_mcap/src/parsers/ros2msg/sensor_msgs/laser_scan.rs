@@ -0,0 +1,173 @@
+use anyhow::Context as _;
+use arrow::array::{Float32Builder, ListBuilder};
+use re_chunk::{Chunk, ChunkId};
+use re_types::{
+    ComponentDescriptor, SerializedComponentColumn, archetypes::Points3D, datatypes::Vec3D,
+    reflection::ComponentDescriptorExt as _,
+};
+
+use super::super::definitions::sensor_msgs;
+use crate::parsers::{
+    cdr,
+    decode::{MessageParser, ParserContext},
+    ros2msg::Ros2MessageParser,
+};
+
+/// Plugin that parses `sensor_msgs/msg/LaserScan` messages.
+#[derive(Default)]
+pub struct LaserScanSchemaPlugin;
+
+/// Converts each scan into a [`Points3D`] in the XY-plane of the sensor frame.
+pub struct LaserScanMessageParser {
+    positions: Vec<Vec3D>,
+    num_points: Vec<usize>,
+    intensities: ListBuilder<Float32Builder>,
+}
+
+impl LaserScanMessageParser {
+    const ARCHETYPE_NAME: &str = "sensor_msgs.msg.LaserScan";
+}
+
+impl Ros2MessageParser for LaserScanMessageParser {
+    fn new(num_rows: usize) -> Self {
+        Self {
+            positions: Vec::new(),
+            num_points: Vec::with_capacity(num_rows),
+            intensities: ListBuilder::with_capacity(Float32Builder::new(), num_rows),
+        }
+    }
+}
+
+impl MessageParser for LaserScanMessageParser {
+    fn append(&mut self, ctx: &mut ParserContext, msg: &mcap::Message<'_>) -> anyhow::Result<()> {
+        re_tracing::profile_function!();
+        let sensor_msgs::LaserScan {
+            header,
+            angle_min,
+            angle_increment,
+            range_min,
+            range_max,
+            ranges,
+            intensities,
+            ..
+        } = cdr::try_decode_message::<sensor_msgs::LaserScan>(&msg.data)
+            .context("Failed to decode sensor_msgs::LaserScan message from CDR data")?;
+
+        // add the sensor timestamp to the context, `log_time` and `publish_time` are added automatically
+        ctx.add_timestamp_cell(crate::util::TimestampCell::guess_from_nanos_ros2(
+            header.stamp.as_nanos() as u64,
+        ));
+
+        let num_positions_before = self.positions.len();
+        let has_intensities = intensities.len() == ranges.len();
+
+        for (i, range) in ranges.iter().copied().enumerate() {
+            // Out-of-range values (incl. `inf` and `NaN`) mark invalid measurements.
+            if !(range_min..=range_max).contains(&range) {
+                continue;
+            }
+
+            let angle = angle_min + i as f32 * angle_increment;
+            self.positions
+                .push(Vec3D::new(range * angle.cos(), range * angle.sin(), 0.0));
+
+            if has_intensities {
+                self.intensities.values().append_value(intensities[i]);
+            }
+        }
+
+        self.num_points
+            .push(self.positions.len() - num_positions_before);
+        self.intensities.append(true);
+
+        Ok(())
+    }
+
+    fn finalize(self: Box<Self>, ctx: ParserContext) -> anyhow::Result<Vec<Chunk>> {
+        re_tracing::profile_function!();
+        let Self {
+            positions,
+            num_points,
+            mut intensities,
+        } = *self;
+
+        let entity_path = ctx.entity_path().clone();
+        let timelines = ctx.build_timelines();
+
+        let mut chunk_components: Vec<_> = Points3D::new(positions).columns(num_points)?.collect();
+
+        chunk_components.push(SerializedComponentColumn {
+            list_array: intensities.finish(),
+            descriptor: ComponentDescriptor::partial("intensities")
+                .with_builtin_archetype(Self::ARCHETYPE_NAME),
+        });
+
+        Ok(vec![Chunk::from_auto_row_ids(
+            ChunkId::new(),
+            entity_path,
+            timelines,
+            chunk_components.into_iter().collect(),
+        )?])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use super::super::super::definitions::{builtin_interfaces::Time, std_msgs::Header};
+    use super::*;
+    use crate::parsers::ros2msg::parse_messages;
+
+    fn scan(ranges: Vec<f32>, intensities: Vec<f32>) -> Vec<u8> {
+        cdr::encode_message(&sensor_msgs::LaserScan {
+            header: Header {
+                stamp: Time { sec: 1, nanosec: 0 },
+                frame_id: "laser".to_owned(),
+            },
+            angle_min: 0.0,
+            angle_max: 3.0 * FRAC_PI_2,
+            angle_increment: FRAC_PI_2,
+            time_increment: 0.0,
+            scan_time: 0.1,
+            range_min: 0.1,
+            range_max: 10.0,
+            ranges,
+            intensities,
+        })
+    }
+
+    #[test]
+    fn test_laser_scan_intensity_mismatch() {
+        let chunks = parse_messages::<LaserScanMessageParser>(
+            "/scan",
+            &[
+                // Out-of-range measurements are skipped, mismatched intensities are dropped.
+                scan(vec![1.0, 0.05, f32::INFINITY, 2.0], vec![10.0, 20.0]),
+                scan(vec![1.0, 2.0], vec![5.0, 6.0]),
+            ],
+        )
+        .unwrap();
+        assert_eq!(chunks.len(), 1);
+        let chunk = &chunks[0];
+        assert_eq!(chunk.num_rows(), 2);
+
+        let positions = chunk
+            .iter_slices::<[f32; 3]>(Points3D::descriptor_positions().component)
+            .collect::<Vec<_>>();
+        assert_eq!(positions[0].len(), 2);
+        assert_eq!(positions[1].len(), 2);
+
+        let [x, y, z] = positions[0][1];
+        assert!(x.abs() < 1e-6 && (y + 2.0).abs() < 1e-6 && z == 0.0);
+
+        let intensities = chunk
+            .iter_slices::<f32>(
+                ComponentDescriptor::partial("intensities")
+                    .with_builtin_archetype(LaserScanMessageParser::ARCHETYPE_NAME)
+                    .component,
+            )
+            .collect::<Vec<_>>();
+        assert_eq!(intensities, vec![&[][..], &[5.0, 6.0][..]]);
+    }
+}
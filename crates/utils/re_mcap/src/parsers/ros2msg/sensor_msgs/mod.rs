@@ -3,6 +3,7 @@ mod compressed_image;
 mod image;
 mod imu;
 mod joint_state;
+mod laser_scan;
 mod magnetic_field;
 mod nav_sat_fix;
 mod point_cloud_2;
@@ -13,6 +14,7 @@ pub use compressed_image::*;
 pub use image::*;
 pub use imu::*;
 pub use joint_state::*;
+pub use laser_scan::*;
 pub use magnetic_field::*;
 pub use nav_sat_fix::*;
 pub use point_cloud_2::*;
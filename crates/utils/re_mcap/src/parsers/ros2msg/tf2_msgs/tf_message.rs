@@ -0,0 +1,184 @@
+use std::collections::BTreeMap;
+
+use anyhow::Context as _;
+use re_chunk::{Chunk, ChunkBuilder, ChunkId, EntityPath, RowId, TimePoint, TimelineName};
+use re_log_types::EntityPathPart;
+use re_types::archetypes::Transform3D;
+
+use super::super::definitions::tf2_msgs;
+use crate::parsers::{
+    cdr,
+    decode::{MessageParser, ParserContext},
+    ros2msg::Ros2MessageParser,
+};
+
+/// Plugin that parses `tf2_msgs/msg/TFMessage` messages.
+#[derive(Default)]
+pub struct TFMessageSchemaPlugin;
+
+/// All transforms that were published for a single child frame.
+struct FrameTransforms {
+    parent_frame_id: String,
+    rows: Vec<(TimePoint, Transform3D)>,
+}
+
+/// Maps the transform tree of a `/tf` or `/tf_static` topic onto the entity hierarchy.
+///
+/// Every frame becomes an entity below the topic, nested according to its parent frame,
+/// e.g. `/tf/map/odom/base_link`. The transforms are logged to the entity of their child frame.
+pub struct TFMessageParser {
+    frames: BTreeMap<String, FrameTransforms>,
+}
+
+impl TFMessageParser {
+    /// The entity path of `frame_id` relative to the topic, following its chain of parent frames.
+    fn frame_path(&self, frame_id: &str) -> EntityPath {
+        let mut parts = vec![EntityPathPart::new(frame_id)];
+
+        let mut current = frame_id;
+        while let Some(frame) = self.frames.get(current) {
+            current = &frame.parent_frame_id;
+            if parts.len() > self.frames.len() {
+                re_log::warn_once!("Cycle in the TF tree at frame {current:?}");
+                break;
+            }
+            parts.push(EntityPathPart::new(current));
+        }
+
+        parts.reverse();
+        EntityPath::new(parts)
+    }
+}
+
+impl Ros2MessageParser for TFMessageParser {
+    fn new(_num_rows: usize) -> Self {
+        Self {
+            frames: BTreeMap::new(),
+        }
+    }
+}
+
+impl MessageParser for TFMessageParser {
+    fn append(&mut self, _ctx: &mut ParserContext, msg: &mcap::Message<'_>) -> anyhow::Result<()> {
+        re_tracing::profile_function!();
+        let tf2_msgs::TFMessage { transforms } =
+            cdr::try_decode_message::<tf2_msgs::TFMessage>(&msg.data)
+                .context("Failed to decode tf2_msgs::TFMessage message from CDR data")?;
+
+        // A single message can contain transforms for many different frames, each with their own timestamp,
+        // so we can't rely on the timelines of the `ParserContext` here.
+        let message_timepoint = crate::util::message_timepoint(msg);
+
+        for transform_stamped in transforms {
+            let stamp = crate::util::TimestampCell::guess_from_nanos_ros2(
+                transform_stamped.header.stamp.as_nanos() as u64,
+            );
+            let timepoint = message_timepoint.clone().with_index(
+                TimelineName::from(stamp.timeline_name()),
+                stamp.into_time_cell(),
+            );
+
+            let translation = transform_stamped.transform.translation;
+            let rotation = transform_stamped.transform.rotation;
+            let transform = Transform3D::update_fields()
+                .with_translation([
+                    translation.x as f32,
+                    translation.y as f32,
+                    translation.z as f32,
+                ])
+                .with_quaternion([
+                    rotation.x as f32,
+                    rotation.y as f32,
+                    rotation.z as f32,
+                    rotation.w as f32,
+                ]);
+
+            let child_frame_id = transform_stamped.child_frame_id;
+            let parent_frame_id = transform_stamped.header.frame_id;
+            let frame = self
+                .frames
+                .entry(child_frame_id.clone())
+                .or_insert_with(|| FrameTransforms {
+                    parent_frame_id: parent_frame_id.clone(),
+                    rows: Vec::new(),
+                });
+
+            if frame.parent_frame_id != parent_frame_id {
+                re_log::warn_once!(
+                    "Frame {child_frame_id:?} changed its parent from {:?} to {parent_frame_id:?}, which is not supported",
+                    frame.parent_frame_id,
+                );
+                continue;
+            }
+
+            frame.rows.push((timepoint, transform));
+        }
+
+        Ok(())
+    }
+
+    fn finalize(self: Box<Self>, ctx: ParserContext) -> anyhow::Result<Vec<Chunk>> {
+        re_tracing::profile_function!();
+        let topic_path = ctx.entity_path().clone();
+
+        self.frames
+            .iter()
+            .map(|(frame_id, frame)| {
+                let entity_path = topic_path.join(&self.frame_path(frame_id));
+                let chunk = frame.rows.iter().fold(
+                    ChunkBuilder::new(ChunkId::new(), entity_path),
+                    |chunk, (timepoint, transform)| {
+                        chunk.with_archetype(RowId::new(), timepoint.clone(), transform)
+                    },
+                );
+                Ok(chunk.build()?)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parser(parent_of: &[(&str, &str)]) -> TFMessageParser {
+        TFMessageParser {
+            frames: parent_of
+                .iter()
+                .map(|(child, parent)| {
+                    (
+                        (*child).to_owned(),
+                        FrameTransforms {
+                            parent_frame_id: (*parent).to_owned(),
+                            rows: Vec::new(),
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_frame_path() {
+        let parser = parser(&[
+            ("base_link", "odom"),
+            ("odom", "map"),
+            ("laser", "base_link"),
+        ]);
+
+        assert_eq!(parser.frame_path("map"), EntityPath::from("map"));
+        assert_eq!(parser.frame_path("odom"), EntityPath::from("map/odom"));
+        assert_eq!(
+            parser.frame_path("laser"),
+            EntityPath::from("map/odom/base_link/laser")
+        );
+    }
+
+    #[test]
+    fn test_frame_path_with_cycle() {
+        let parser = parser(&[("a", "b"), ("b", "a")]);
+
+        // Terminates, even though there is no root frame.
+        assert_eq!(parser.frame_path("a").len(), 3);
+    }
+}
@@ -0,0 +1,335 @@
+use std::collections::BTreeMap;
+
+use anyhow::Context as _;
+use re_chunk::{Chunk, ChunkBuilder, ChunkId, EntityPath, RowId, TimePoint, TimelineName};
+use re_log_types::EntityPathPart;
+use re_types::{
+    AsComponents as _, SerializedComponentBatch,
+    archetypes::{
+        Arrows3D, Boxes3D, Clear, Cylinders3D, Ellipsoids3D, LineStrips3D, Mesh3D, Points3D,
+        Transform3D,
+    },
+    components::{Color, FillMode},
+    datatypes::Vec3D,
+};
+
+use super::super::definitions::{geometry_msgs, std_msgs, visualization_msgs::Marker};
+use crate::parsers::{
+    cdr,
+    decode::{MessageParser, ParserContext},
+    ros2msg::Ros2MessageParser,
+};
+
+/// Plugin that parses `visualization_msgs/msg/Marker` messages.
+#[derive(Default)]
+pub struct MarkerSchemaPlugin;
+
+/// Logs every marker to its own entity below the topic, identified by its namespace and id,
+/// e.g. `/markers/obstacles/3`.
+///
+/// The marker pose is logged as a [`Transform3D`] on the same entity.
+/// Text and mesh markers are not supported.
+pub struct MarkerMessageParser {
+    rows: BTreeMap<EntityPath, Vec<(TimePoint, Vec<SerializedComponentBatch>)>>,
+}
+
+fn vec3d(point: &geometry_msgs::Point) -> Vec3D {
+    Vec3D::new(point.x as f32, point.y as f32, point.z as f32)
+}
+
+fn color(color: &std_msgs::ColorRGBA) -> Color {
+    let to_u8 = |channel: f32| (channel * 255.0).round() as u8;
+    Color::from_unmultiplied_rgba(
+        to_u8(color.r),
+        to_u8(color.g),
+        to_u8(color.b),
+        to_u8(color.a),
+    )
+}
+
+/// Per-point colors if there is one for every point, otherwise the marker color.
+fn point_colors(marker: &Marker) -> Vec<Color> {
+    if !marker.colors.is_empty() && marker.colors.len() == marker.points.len() {
+        marker.colors.iter().map(color).collect()
+    } else {
+        vec![color(&marker.color)]
+    }
+}
+
+/// Converts the shape of a marker into the corresponding archetype.
+///
+/// Returns `None` for unsupported marker types.
+fn marker_shape(marker: &Marker) -> Option<Vec<SerializedComponentBatch>> {
+    let scale = Vec3D::new(
+        marker.scale.x as f32,
+        marker.scale.y as f32,
+        marker.scale.z as f32,
+    );
+    let half_size = [scale.x() / 2.0, scale.y() / 2.0, scale.z() / 2.0];
+    let points = marker.points.iter().map(vec3d);
+
+    let batches = match marker.marker_type {
+        Marker::ARROW => {
+            let arrows = if let [start, end, ..] = marker.points.as_slice() {
+                // Shaft diameter, head diameter, and head length are given by the scale.
+                let (start, end) = (vec3d(start), vec3d(end));
+                Arrows3D::from_vectors([Vec3D::new(
+                    end.x() - start.x(),
+                    end.y() - start.y(),
+                    end.z() - start.z(),
+                )])
+                .with_origins([start])
+                .with_radii([scale.x() / 2.0])
+            } else {
+                // Arrow length, width and height are given by the scale.
+                Arrows3D::from_vectors([Vec3D::new(scale.x(), 0.0, 0.0)])
+                    .with_radii([scale.y() / 2.0])
+            };
+            arrows
+                .with_colors([color(&marker.color)])
+                .as_serialized_batches()
+        }
+        Marker::CUBE => Boxes3D::from_half_sizes([half_size])
+            .with_colors([color(&marker.color)])
+            .with_fill_mode(FillMode::Solid)
+            .as_serialized_batches(),
+        Marker::SPHERE => Ellipsoids3D::from_half_sizes([half_size])
+            .with_colors([color(&marker.color)])
+            .with_fill_mode(FillMode::Solid)
+            .as_serialized_batches(),
+        Marker::CYLINDER => Cylinders3D::from_lengths_and_radii([scale.z()], [half_size[0]])
+            .with_colors([color(&marker.color)])
+            .with_fill_mode(FillMode::Solid)
+            .as_serialized_batches(),
+        Marker::LINE_STRIP => LineStrips3D::new([points.collect::<Vec<_>>()])
+            .with_radii([scale.x() / 2.0])
+            .with_colors([color(&marker.color)])
+            .as_serialized_batches(),
+        Marker::LINE_LIST => LineStrips3D::new(
+            marker
+                .points
+                .chunks_exact(2)
+                .map(|segment| [vec3d(&segment[0]), vec3d(&segment[1])]),
+        )
+        .with_radii([scale.x() / 2.0])
+        .with_colors([color(&marker.color)])
+        .as_serialized_batches(),
+        Marker::CUBE_LIST => Boxes3D::from_centers_and_half_sizes(points, [half_size])
+            .with_colors(point_colors(marker))
+            .with_fill_mode(FillMode::Solid)
+            .as_serialized_batches(),
+        Marker::SPHERE_LIST => Ellipsoids3D::from_centers_and_half_sizes(points, [half_size])
+            .with_colors(point_colors(marker))
+            .with_fill_mode(FillMode::Solid)
+            .as_serialized_batches(),
+        Marker::POINTS => Points3D::new(points)
+            .with_radii([scale.x() / 2.0])
+            .with_colors(point_colors(marker))
+            .as_serialized_batches(),
+        Marker::TRIANGLE_LIST => {
+            let mesh = Mesh3D::new(points);
+            if marker.colors.len() == marker.points.len() {
+                mesh.with_vertex_colors(marker.colors.iter().map(color))
+            } else {
+                mesh.with_albedo_factor(color(&marker.color).0)
+            }
+            .as_serialized_batches()
+        }
+        _ => {
+            re_log::warn_once!(
+                "Marker type {} is currently not supported",
+                marker.marker_type
+            );
+            return None;
+        }
+    };
+
+    Some(batches)
+}
+
+impl Ros2MessageParser for MarkerMessageParser {
+    fn new(_num_rows: usize) -> Self {
+        Self {
+            rows: BTreeMap::new(),
+        }
+    }
+}
+
+impl MessageParser for MarkerMessageParser {
+    fn append(&mut self, ctx: &mut ParserContext, msg: &mcap::Message<'_>) -> anyhow::Result<()> {
+        re_tracing::profile_function!();
+        let marker = cdr::try_decode_message::<Marker>(&msg.data)
+            .context("Failed to decode visualization_msgs::Marker message from CDR data")?;
+
+        // Every marker ends up on a different entity, so we can't rely on the timelines of the `ParserContext` here.
+        let stamp = crate::util::TimestampCell::guess_from_nanos_ros2(
+            marker.header.stamp.as_nanos() as u64,
+        );
+        let timepoint = crate::util::message_timepoint(msg).with_index(
+            TimelineName::from(stamp.timeline_name()),
+            stamp.into_time_cell(),
+        );
+
+        let topic_path = ctx.entity_path();
+        let marker_path = topic_path
+            / EntityPathPart::new(&marker.ns)
+            / EntityPathPart::new(marker.id.to_string());
+
+        let (entity_path, batches) = match marker.action {
+            Marker::DELETE => (marker_path, Clear::flat().as_serialized_batches()),
+            Marker::DELETEALL => (
+                topic_path.clone(),
+                Clear::recursive().as_serialized_batches(),
+            ),
+            _ => {
+                let Some(shape) = marker_shape(&marker) else {
+                    return Ok(());
+                };
+
+                let orientation = &marker.pose.orientation;
+                let transform = Transform3D::update_fields()
+                    .with_translation(vec3d(&marker.pose.position))
+                    .with_quaternion([
+                        orientation.x as f32,
+                        orientation.y as f32,
+                        orientation.z as f32,
+                        orientation.w as f32,
+                    ]);
+
+                (
+                    marker_path,
+                    transform
+                        .as_serialized_batches()
+                        .into_iter()
+                        .chain(shape)
+                        .collect(),
+                )
+            }
+        };
+
+        self.rows
+            .entry(entity_path)
+            .or_default()
+            .push((timepoint, batches));
+
+        Ok(())
+    }
+
+    fn finalize(self: Box<Self>, _ctx: ParserContext) -> anyhow::Result<Vec<Chunk>> {
+        re_tracing::profile_function!();
+        self.rows
+            .into_iter()
+            .map(|(entity_path, rows)| {
+                let chunk = rows.into_iter().fold(
+                    ChunkBuilder::new(ChunkId::new(), entity_path),
+                    |chunk, (timepoint, batches)| {
+                        chunk.with_serialized_batches(RowId::new(), timepoint, batches)
+                    },
+                );
+                Ok(chunk.build()?)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use re_types::components::ClearIsRecursive;
+
+    use super::super::super::definitions::{
+        builtin_interfaces::{Duration, Time},
+        geometry_msgs::{Pose, Quaternion, Vector3},
+        std_msgs::Header,
+    };
+    use super::*;
+    use crate::parsers::ros2msg::parse_messages;
+
+    fn marker(id: i32, action: i32) -> Vec<u8> {
+        cdr::encode_message(&Marker {
+            header: Header {
+                stamp: Time { sec: 1, nanosec: 0 },
+                frame_id: "map".to_owned(),
+            },
+            ns: "obstacles".to_owned(),
+            id,
+            marker_type: Marker::CUBE,
+            action,
+            pose: Pose {
+                position: geometry_msgs::Point {
+                    x: 0.0,
+                    y: 0.0,
+                    z: 0.0,
+                },
+                orientation: Quaternion {
+                    x: 0.0,
+                    y: 0.0,
+                    z: 0.0,
+                    w: 1.0,
+                },
+            },
+            scale: Vector3 {
+                x: 1.0,
+                y: 1.0,
+                z: 1.0,
+            },
+            color: std_msgs::ColorRGBA {
+                r: 1.0,
+                g: 0.0,
+                b: 0.0,
+                a: 1.0,
+            },
+            lifetime: Duration { sec: 0, nanosec: 0 },
+            frame_locked: false,
+            points: Vec::new(),
+            colors: Vec::new(),
+        })
+    }
+
+    fn is_recursive(chunk: &Chunk) -> Vec<bool> {
+        chunk
+            .iter_component::<ClearIsRecursive>(Clear::descriptor_is_recursive().component)
+            .flat_map(|batch| {
+                batch
+                    .iter()
+                    .map(|is_recursive| is_recursive.0.0)
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_marker_delete() {
+        let chunks = parse_messages::<MarkerMessageParser>(
+            "/markers",
+            &[
+                marker(3, Marker::ADD),
+                marker(4, Marker::ADD),
+                marker(3, Marker::DELETE),
+                marker(0, Marker::DELETEALL),
+            ],
+        )
+        .unwrap();
+
+        let chunk = |entity_path: &str| {
+            chunks
+                .iter()
+                .find(|chunk| chunk.entity_path() == &EntityPath::from(entity_path))
+                .unwrap_or_else(|| panic!("missing chunk for {entity_path}"))
+        };
+        assert_eq!(chunks.len(), 3);
+
+        // `DELETE` only clears the marker itself.
+        let deleted = chunk("/markers/obstacles/3");
+        assert_eq!(deleted.num_rows(), 2);
+        assert_eq!(is_recursive(deleted), vec![false]);
+
+        let kept = chunk("/markers/obstacles/4");
+        assert_eq!(kept.num_rows(), 1);
+        assert!(is_recursive(kept).is_empty());
+
+        // `DELETEALL` clears everything below the topic.
+        let topic = chunk("/markers");
+        assert_eq!(topic.num_rows(), 1);
+        assert_eq!(is_recursive(topic), vec![true]);
+    }
+}
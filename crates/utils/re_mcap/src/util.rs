@@ -6,7 +6,7 @@ use mcap::{
 };
 use saturating_cast::SaturatingCast as _;
 
-use re_log_types::TimeCell;
+use re_log_types::{TimeCell, TimePoint};
 
 /// Read out the summary of an MCAP file.
 pub fn read_summary<R: Read + Seek>(mut reader: R) -> anyhow::Result<Option<Summary>> {
@@ -89,6 +89,16 @@ impl TimestampCell {
     }
 }
 
+/// The `log_time` and `publish_time` of an MCAP message as a [`TimePoint`].
+pub fn message_timepoint(msg: &mcap::Message<'_>) -> TimePoint {
+    let log_time_cell = TimestampCell::guess_from_nanos(msg.log_time);
+    let publish_time_cell = TimestampCell::guess_from_nanos(msg.publish_time);
+    TimePoint::from([
+        ("log_time", log_time_cell.into_time_cell()),
+        ("publish_time", publish_time_cell.into_time_cell()),
+    ])
+}
+
 #[cfg(test)]
 mod tests {
     #![expect(clippy::cast_possible_wrap)] // ok in tests
@@ -0,0 +1,83 @@
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering::Relaxed};
+
+/// Transport statistics of a single network connection feeding a channel.
+///
+/// Created with [`crate::Sender::add_connection`], updated by whoever reads from the network.
+pub struct ConnectionStats {
+    peer: String,
+    transport_compression: Option<String>,
+    num_messages: AtomicU64,
+    num_payload_bytes: AtomicU64,
+    num_uncompressed_bytes: AtomicU64,
+    connected: AtomicBool,
+}
+
+impl ConnectionStats {
+    pub(crate) fn new(peer: String, transport_compression: Option<String>) -> Self {
+        Self {
+            peer,
+            transport_compression,
+            num_messages: AtomicU64::new(0),
+            num_payload_bytes: AtomicU64::new(0),
+            num_uncompressed_bytes: AtomicU64::new(0),
+            connected: AtomicBool::new(true),
+        }
+    }
+
+    /// Call for every message received.
+    ///
+    /// `payload_bytes` is the size of the message as encoded for transport, and
+    /// `uncompressed_bytes` its size once any payload compression has been undone.
+    pub fn on_message(&self, payload_bytes: u64, uncompressed_bytes: u64) {
+        self.num_messages.fetch_add(1, Relaxed);
+        self.num_payload_bytes.fetch_add(payload_bytes, Relaxed);
+        self.num_uncompressed_bytes
+            .fetch_add(uncompressed_bytes, Relaxed);
+    }
+
+    /// Call once the connection is closed.
+    pub fn on_disconnect(&self) {
+        self.connected.store(false, Relaxed);
+    }
+
+    pub fn snapshot(&self) -> ConnectionStatsSnapshot {
+        ConnectionStatsSnapshot {
+            peer: self.peer.clone(),
+            transport_compression: self.transport_compression.clone(),
+            num_messages: self.num_messages.load(Relaxed),
+            num_payload_bytes: self.num_payload_bytes.load(Relaxed),
+            num_uncompressed_bytes: self.num_uncompressed_bytes.load(Relaxed),
+            connected: self.connected.load(Relaxed),
+        }
+    }
+}
+
+/// A point-in-time copy of [`ConnectionStats`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConnectionStatsSnapshot {
+    /// Who is on the other end, e.g. `192.168.1.7:53124`.
+    pub peer: String,
+
+    /// The negotiated gRPC message compression (e.g. `zstd`), if any.
+    pub transport_compression: Option<String>,
+
+    pub num_messages: u64,
+
+    /// Total size of the received messages, as encoded for transport.
+    ///
+    /// This already accounts for payload compression (e.g. LZ4), but not for transport compression.
+    pub num_payload_bytes: u64,
+
+    /// Total size of the received messages, once decompressed.
+    pub num_uncompressed_bytes: u64,
+
+    pub connected: bool,
+}
+
+impl ConnectionStatsSnapshot {
+    /// How much smaller the payloads are thanks to payload compression, e.g. `0.25` for 4x.
+    pub fn compression_ratio(&self) -> Option<f64> {
+        (0 < self.num_uncompressed_bytes)
+            .then(|| self.num_payload_bytes as f64 / self.num_uncompressed_bytes as f64)
+    }
+}
@@ -7,10 +7,12 @@ use web_time::Instant;
 
 pub use crossbeam::channel::{RecvError, RecvTimeoutError, SendError, TryRecvError};
 
+mod connection_stats;
 mod receive_set;
 mod receiver;
 mod sender;
 
+pub use connection_stats::{ConnectionStats, ConnectionStatsSnapshot};
 pub use receive_set::ReceiveSet;
 pub use receiver::Receiver;
 pub use sender::Sender;
@@ -71,6 +73,12 @@ pub enum SmartChannelSource {
 
     /// The data is streaming in via a message proxy.
     MessageProxy(re_uri::ProxyUri),
+
+    /// The data is streaming in live from a Foxglove WebSocket server, e.g. `foxglove_bridge`.
+    McapWebSocket {
+        /// Should include `ws(s)://` prefix.
+        url: String,
+    },
 }
 
 impl std::fmt::Display for SmartChannelSource {
@@ -79,6 +87,7 @@ impl std::fmt::Display for SmartChannelSource {
             Self::File(path) => path.display().fmt(f),
             Self::RrdHttpStream { url, follow: _ } => url.fmt(f),
             Self::MessageProxy(uri) => uri.fmt(f),
+            Self::McapWebSocket { url } => url.fmt(f),
             Self::RedapGrpcStream { uri, .. } => uri.fmt(f),
             Self::RrdWebEventListener => "Web event listener".fmt(f),
             Self::JsChannel { channel_name } => write!(f, "Javascript channel: {channel_name}"),
@@ -95,7 +104,8 @@ impl SmartChannelSource {
             Self::RrdHttpStream { .. }
             | Self::JsChannel { .. }
             | Self::RedapGrpcStream { .. }
-            | Self::MessageProxy { .. } => true,
+            | Self::MessageProxy { .. }
+            | Self::McapWebSocket { .. } => true,
         }
     }
 
@@ -107,7 +117,8 @@ impl SmartChannelSource {
             | Self::Stdin
             | Self::RrdHttpStream { .. }
             | Self::JsChannel { .. }
-            | Self::MessageProxy { .. } => true,
+            | Self::MessageProxy { .. }
+            | Self::McapWebSocket { .. } => true,
 
             Self::RedapGrpcStream {
                 select_when_loaded, ..
@@ -125,7 +136,8 @@ impl SmartChannelSource {
             | Self::RrdWebEventListener
             | Self::Stdin
             | Self::RrdHttpStream { .. }
-            | Self::JsChannel { .. } => None,
+            | Self::JsChannel { .. }
+            | Self::McapWebSocket { .. } => None,
         }
     }
 
@@ -151,6 +163,7 @@ impl SmartChannelSource {
             Self::RrdWebEventListener
             | Self::JsChannel { .. }
             | Self::MessageProxy { .. }
+            | Self::McapWebSocket { .. }
             | Self::Sdk
             | Self::Stdin => {
                 // For all of these sources we're not actively loading data, but rather waiting for data to be sent.
@@ -173,6 +186,9 @@ impl SmartChannelSource {
             Self::MessageProxy(uri) => {
                 format!("Waiting for data on {uri}…")
             }
+            Self::McapWebSocket { url } => {
+                format!("Waiting for data on {url}…")
+            }
             Self::RedapGrpcStream { uri, .. } => {
                 format!(
                     "Waiting for data on {}…",
@@ -194,7 +210,8 @@ impl SmartChannelSource {
                 uri1.clone().without_fragment() == uri2.clone().without_fragment()
             }
             (Self::RrdHttpStream { url: url1, .. }, Self::RrdHttpStream { url: url2, .. }) => {
-                url1 == url2
+                let without_fragment = |url: &str| url.split_once('#').map_or(url, |(url, _)| url);
+                without_fragment(url1) == without_fragment(url2)
             }
             _ => self == other,
         }
@@ -248,6 +265,12 @@ pub enum SmartMessageSource {
 
     /// A stream of messages over message proxy gRPC interface.
     MessageProxy(re_uri::ProxyUri),
+
+    /// A live stream of messages from a Foxglove WebSocket server.
+    McapWebSocket {
+        /// Should include `ws(s)://` prefix.
+        url: String,
+    },
 }
 
 impl std::fmt::Display for SmartMessageSource {
@@ -257,6 +280,7 @@ impl std::fmt::Display for SmartMessageSource {
             Self::File(path) => format!("file://{}", path.to_string_lossy()),
             Self::RrdHttpStream { url } => url.clone(),
             Self::MessageProxy(uri) => uri.to_string(),
+            Self::McapWebSocket { url } => url.clone(),
             Self::RedapGrpcStream { uri, .. } => uri.to_string(),
             Self::RrdWebEventCallback => "web_callback".into(),
             Self::JsChannelPush => "javascript".into(),
@@ -273,6 +297,9 @@ impl std::fmt::Display for SmartMessageSource {
 pub(crate) struct SharedStats {
     /// Latest known latency from sending a message to receiving it, it nanoseconds.
     latency_nanos: AtomicU64,
+
+    /// The network connections feeding this channel, if any.
+    connections: parking_lot::Mutex<Vec<Arc<ConnectionStats>>>,
 }
 
 pub fn smart_channel<T: Send>(
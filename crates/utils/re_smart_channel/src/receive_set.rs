@@ -61,6 +61,7 @@ impl<T: Send> ReceiveSet<T> {
             // - don't point at the given `needle`
             SmartChannelSource::RrdHttpStream { url, .. } => url != needle,
             SmartChannelSource::MessageProxy(url) => url.to_string() != needle,
+            SmartChannelSource::McapWebSocket { url } => url != needle,
             SmartChannelSource::RedapGrpcStream { uri, .. } => uri.to_string() != needle,
 
             SmartChannelSource::File(_)
@@ -109,6 +110,13 @@ impl<T: Send> ReceiveSet<T> {
         latency_nanos
     }
 
+    /// Transport statistics of all network connections feeding any of the receivers.
+    pub fn connections(&self) -> Vec<crate::ConnectionStatsSnapshot> {
+        re_tracing::profile_function!();
+        let rx = self.receivers.lock();
+        rx.iter().flat_map(|r| r.connections()).collect()
+    }
+
     /// Sum queue length of all receivers.
     pub fn queue_len(&self) -> usize {
         re_tracing::profile_function!();
@@ -124,6 +124,16 @@ impl<T: Send> Receiver<T> {
         self.latency_nanos() as f32 / 1e9
     }
 
+    /// Transport statistics of the network connections feeding this channel, if any.
+    pub fn connections(&self) -> Vec<crate::ConnectionStatsSnapshot> {
+        self.stats
+            .connections
+            .lock()
+            .iter()
+            .map(|connection| connection.snapshot())
+            .collect()
+    }
+
     /// Create a new channel that use the same stats as this one.
     ///
     /// This means both channels will see the same latency numbers.
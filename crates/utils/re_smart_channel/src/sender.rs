@@ -2,7 +2,9 @@ use std::sync::{Arc, atomic::Ordering::Relaxed};
 
 use web_time::Instant;
 
-use crate::{SendError, SharedStats, SmartMessage, SmartMessagePayload, SmartMessageSource};
+use crate::{
+    ConnectionStats, SendError, SharedStats, SmartMessage, SmartMessagePayload, SmartMessageSource,
+};
 
 #[derive(Clone)]
 pub struct Sender<T: Send> {
@@ -29,6 +31,19 @@ impl<T: Send> Sender<T> {
         }
     }
 
+    /// Starts tracking the transport statistics of a new network connection feeding this channel.
+    ///
+    /// These show up in [`crate::Receiver::connections`].
+    pub fn add_connection(
+        &self,
+        peer: String,
+        transport_compression: Option<String>,
+    ) -> Arc<ConnectionStats> {
+        let connection = Arc::new(ConnectionStats::new(peer, transport_compression));
+        self.stats.connections.lock().push(connection.clone());
+        connection
+    }
+
     pub fn send(&self, msg: T) -> Result<(), SendError<T>> {
         self.send_at(
             Instant::now(),
@@ -1,3 +1,4 @@
+use base64::Engine as _;
 use re_log_types::{DataPath, TimeCell, TimelineName};
 
 /// We use the `#fragment` of the URI to point to a specific entity or time.
@@ -10,6 +11,7 @@ use re_log_types::{DataPath, TimeCell, TimelineName};
 ///  "selection=/entity/path[#42]&when=log_tick@32",
 ///  "selection=/entity/path&when=log_time@2022-01-01T00:00:03.123456789Z",
 ///  "when=log_time@2022-01-01T00:00:03.123456789Z",
+///  "when=log_tick@32&blueprint=UlJGMg",
 /// # ];
 /// # for test in tests {
 /// #     assert!(test.parse::<Fragment>().unwrap() != Fragment::default());
@@ -21,11 +23,20 @@ pub struct Fragment {
 
     /// Select this timeline and this time
     pub when: Option<(TimelineName, TimeCell)>,
+
+    /// An encoded blueprint (i.e. the contents of an `.rbl` file) to activate.
+    ///
+    /// Serialized as unpadded url-safe base64.
+    pub blueprint: Option<Vec<u8>>,
 }
 
 impl std::fmt::Display for Fragment {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let Self { selection, when } = self;
+        let Self {
+            selection,
+            when,
+            blueprint,
+        } = self;
 
         let mut did_write = false;
 
@@ -39,6 +50,15 @@ impl std::fmt::Display for Fragment {
                 write!(f, "&")?;
             }
             write!(f, "when={timeline}@{time_cell}")?;
+            did_write = true;
+        }
+
+        if let Some(blueprint) = blueprint {
+            if did_write {
+                write!(f, "&")?;
+            }
+            let blueprint = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(blueprint);
+            write!(f, "blueprint={blueprint}")?;
         }
 
         Ok(())
@@ -51,6 +71,7 @@ impl std::str::FromStr for Fragment {
     fn from_str(fragment: &str) -> Result<Self, Self::Err> {
         let mut selection = None;
         let mut when = None;
+        let mut blueprint = None;
 
         for part in split_on_unescaped_ampersand(fragment) {
             // If there isn't an equals in this part we skip it as it doesn't contain any data.
@@ -79,16 +100,30 @@ impl std::str::FromStr for Fragment {
                             }
                         }
                     }
+                    "blueprint" => {
+                        match base64::engine::general_purpose::URL_SAFE_NO_PAD.decode(value) {
+                            Ok(bytes) => {
+                                blueprint = Some(bytes);
+                            }
+                            Err(err) => {
+                                return Err(format!("Bad blueprint: {err}"));
+                            }
+                        }
+                    }
                     _ => {
                         return Err(format!(
-                            "Unknown key {key:?}. Expected either 'selection' or 'time'"
+                            "Unknown key {key:?}. Expected one of 'selection', 'when' or 'blueprint'"
                         ));
                     }
                 }
             }
         }
 
-        Ok(Self { selection, when })
+        Ok(Self {
+            selection,
+            when,
+            blueprint,
+        })
     }
 }
 
@@ -103,9 +138,13 @@ impl Fragment {
     /// True if this fragment doesn't contain any information.
     pub fn is_empty(&self) -> bool {
         // Keep this as a destruction so there is a compile error if a new field isn't handled here.
-        let Self { selection, when } = self;
+        let Self {
+            selection,
+            when,
+            blueprint,
+        } = self;
 
-        selection.is_none() && when.is_none()
+        selection.is_none() && when.is_none() && blueprint.is_none()
     }
 }
 
@@ -187,6 +226,7 @@ fn test_parse_fragment() {
             Fragment {
                 selection: Some("/entity/path".parse().unwrap()),
                 when: None,
+                blueprint: None,
             },
         ),
         (
@@ -197,6 +237,7 @@ fn test_parse_fragment() {
                     "log_time".into(),
                     "2022-01-01T00:00:03.123456789Z".parse().unwrap(),
                 )),
+                blueprint: None,
             },
         ),
         (
@@ -207,6 +248,15 @@ fn test_parse_fragment() {
                     "log_time".into(),
                     "2022-01-01T00:00:03.123456789Z".parse().unwrap(),
                 )),
+                blueprint: None,
+            },
+        ),
+        (
+            "when=log_tick@32&blueprint=UlJGMgABAg",
+            Fragment {
+                selection: None,
+                when: Some(("log_tick".into(), "32".parse().unwrap())),
+                blueprint: Some(b"RRF2\x00\x01\x02".to_vec()),
             },
         ),
     ];
@@ -216,7 +266,11 @@ fn test_parse_fragment() {
         assert_eq!(string.parse::<Fragment>().unwrap(), fragment);
     }
 
-    let fail_cases = ["focus=/entity/path", "selection=/entity/path&foo=test"];
+    let fail_cases = [
+        "focus=/entity/path",
+        "selection=/entity/path&foo=test",
+        "blueprint=not*base64",
+    ];
 
     for string in fail_cases {
         assert!(string.parse::<Fragment>().is_err());
@@ -1,11 +1,16 @@
-use arrow::{array::Array, error::ArrowError};
+use arrow::array::{Array, AsArray as _};
+use arrow::datatypes::DataType;
 
 use re_log_types::TimestampFormat;
 use re_ui::list_item::list_item_scope;
 use re_ui::{UiExt as _, UiLayout};
 
 use crate::datatype_ui::DataTypeUi;
-use crate::show_index::{ArrayUi, DisplayOptions};
+use crate::json::json_section_ui;
+use crate::show_index::{ArrayUi, ColormapFn, DisplayOptions, struct_table_ui};
+
+/// Struct arrays with more items than this are not shown in full by [`component_arrow_ui`].
+const MAX_TABLE_ROWS: usize = 1000;
 
 pub fn arrow_ui(
     ui: &mut egui::Ui,
@@ -15,10 +20,57 @@ pub fn arrow_ui(
 ) {
     re_tracing::profile_function!();
 
+    arrow_ui_impl(
+        ui,
+        ui_layout,
+        &DisplayOptions {
+            timestamp_format,
+            ..Default::default()
+        },
+        false,
+        array,
+    );
+}
+
+/// Like [`arrow_ui`], but meant for components the viewer has no dedicated UI for.
+///
+/// Struct fields with [`re_types_core::DisplayHints`] are shown with their unit, and with a
+/// color looked up with `colormap`.
+/// In the selection panel, struct arrays with more than one item are shown as a table,
+/// and all data can additionally be expanded as JSON.
+pub fn component_arrow_ui(
+    ui: &mut egui::Ui,
+    ui_layout: UiLayout,
+    timestamp_format: TimestampFormat,
+    colormap: Option<&ColormapFn>,
+    array: &dyn Array,
+) {
+    re_tracing::profile_function!();
+
+    arrow_ui_impl(
+        ui,
+        ui_layout,
+        &DisplayOptions {
+            timestamp_format,
+            colormap,
+            ..Default::default()
+        },
+        true,
+        array,
+    );
+}
+
+fn arrow_ui_impl(
+    ui: &mut egui::Ui,
+    ui_layout: UiLayout,
+    options: &DisplayOptions<'_>,
+    unknown_component: bool,
+    array: &dyn Array,
+) {
     ui.scope(|ui| {
         ui.style_mut().wrap_mode = Some(egui::TextWrapMode::Truncate);
 
-        match make_ui(timestamp_format, array) {
+        match ArrayUi::try_new(array, options) {
             Ok(array_formatter) => match ui_layout {
                 UiLayout::SelectionPanel => {
                     // Data type has a separate scope to prevent items from being aligned.
@@ -26,13 +78,31 @@ pub fn arrow_ui(
                     list_item_scope(ui, "arrow_data_type_ui", |ui| {
                         DataTypeUi::new(array.data_type()).list_item_ui(ui);
                     });
-                    list_item_scope(ui, "arrow_ui", |ui| {
-                        if array.len() == 1 {
-                            array_formatter.show_value(0, ui);
-                        } else {
-                            array_formatter.show(ui);
+
+                    if unknown_component
+                        && 1 < array.len()
+                        && matches!(array.data_type(), DataType::Struct(_))
+                    {
+                        if let Err(err) =
+                            struct_table_ui(ui, array.as_struct(), options, MAX_TABLE_ROWS)
+                        {
+                            ui.error_with_details_on_hover(err.to_string());
                         }
-                    });
+                    } else {
+                        list_item_scope(ui, "arrow_ui", |ui| {
+                            if array.len() == 1 {
+                                array_formatter.show_value(0, ui);
+                            } else {
+                                array_formatter.show(ui);
+                            }
+                        });
+                    }
+
+                    if unknown_component {
+                        list_item_scope(ui, "arrow_json_ui", |ui| {
+                            json_section_ui(ui, array);
+                        });
+                    }
                 }
                 UiLayout::Tooltip | UiLayout::List => {
                     let highlighted = if array.len() == 1 {
@@ -56,17 +126,3 @@ pub fn arrow_ui(
         }
     });
 }
-
-pub(crate) fn make_ui(
-    timestamp_format: TimestampFormat,
-    array: &dyn Array,
-) -> Result<ArrayUi<'_>, ArrowError> {
-    let array_ui = ArrayUi::try_new(
-        array,
-        &DisplayOptions {
-            timestamp_format,
-            ..Default::default()
-        },
-    )?;
-    Ok(array_ui)
-}
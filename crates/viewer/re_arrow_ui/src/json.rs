@@ -0,0 +1,322 @@
+use arrow::array::{Array, AsArray as _};
+use arrow::datatypes::DataType;
+use arrow::error::ArrowError;
+use arrow::util::display::{ArrayFormatter, FormatOptions};
+use egui::Id;
+
+use re_ui::UiExt as _;
+use re_ui::list_item::{LabelContent, ListItemContentButtonsExt as _, PropertyContent};
+use re_ui::syntax_highlighting::SyntaxHighlightedBuilder;
+
+/// A JSON value converted from arrow data.
+///
+/// Unlike most JSON libraries, this keeps the order of object keys, i.e. of struct fields.
+#[derive(Clone, Debug, PartialEq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+
+    /// A number, already formatted.
+    Number(String),
+
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    /// Formats the value as indented JSON.
+    pub fn to_pretty_string(&self) -> String {
+        let mut out = String::new();
+        self.write_pretty(&mut out, 0);
+        out
+    }
+
+    fn write_pretty(&self, out: &mut String, indentation: usize) {
+        const INDENT: &str = "  ";
+
+        match self {
+            Self::Null => out.push_str("null"),
+            Self::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+            Self::Number(n) => out.push_str(n),
+            Self::String(s) => write_json_string(out, s),
+            Self::Array(values) if values.is_empty() => out.push_str("[]"),
+            Self::Array(values) => {
+                out.push('[');
+                for (i, value) in values.iter().enumerate() {
+                    out.push_str(if i == 0 { "\n" } else { ",\n" });
+                    out.push_str(&INDENT.repeat(indentation + 1));
+                    value.write_pretty(out, indentation + 1);
+                }
+                out.push('\n');
+                out.push_str(&INDENT.repeat(indentation));
+                out.push(']');
+            }
+            Self::Object(entries) if entries.is_empty() => out.push_str("{}"),
+            Self::Object(entries) => {
+                out.push('{');
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    out.push_str(if i == 0 { "\n" } else { ",\n" });
+                    out.push_str(&INDENT.repeat(indentation + 1));
+                    write_json_string(out, key);
+                    out.push_str(": ");
+                    value.write_pretty(out, indentation + 1);
+                }
+                out.push('\n');
+                out.push_str(&INDENT.repeat(indentation));
+                out.push('}');
+            }
+        }
+    }
+
+    fn has_children(&self) -> bool {
+        match self {
+            Self::Array(values) => !values.is_empty(),
+            Self::Object(entries) => !entries.is_empty(),
+            Self::Null | Self::Bool(_) | Self::Number(_) | Self::String(_) => false,
+        }
+    }
+
+    /// The value itself, or a short summary for arrays and objects.
+    fn syntax_highlighted(&self) -> SyntaxHighlightedBuilder {
+        let mut builder = SyntaxHighlightedBuilder::new();
+        match self {
+            Self::Null => builder.append_keyword("null"),
+            Self::Bool(b) => builder.append_primitive(if *b { "true" } else { "false" }),
+            Self::Number(n) => builder.append_primitive(n),
+            Self::String(s) => builder.append_string_value(s),
+            Self::Array(values) => builder
+                .append_syntax("[…] ")
+                .append_body(&format!("{} items", re_format::format_uint(values.len()))),
+            Self::Object(entries) => builder
+                .append_syntax("{…} ")
+                .append_body(&format!("{} fields", re_format::format_uint(entries.len()))),
+        };
+        builder
+    }
+}
+
+fn write_json_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Converts arrow data to JSON.
+///
+/// An array with a single item is converted to just that item, all others to a JSON array.
+/// Binary data, timestamps and other types without a JSON counterpart are converted to strings.
+/// Non-finite floats become `null`.
+pub fn arrow_to_json(array: &dyn Array) -> Result<JsonValue, ArrowError> {
+    re_tracing::profile_function!();
+
+    if array.len() == 1 {
+        value_to_json(array, 0)
+    } else {
+        array_to_json(array).map(JsonValue::Array)
+    }
+}
+
+fn array_to_json(array: &dyn Array) -> Result<Vec<JsonValue>, ArrowError> {
+    if let Some(dictionary) = array.as_any_dictionary_opt() {
+        // Look up all keys at once instead of once per value.
+        let values = dictionary.values();
+        return dictionary
+            .normalized_keys()
+            .into_iter()
+            .enumerate()
+            .map(|(index, key)| {
+                if array.is_null(index) {
+                    Ok(JsonValue::Null)
+                } else {
+                    value_to_json(values, key)
+                }
+            })
+            .collect();
+    }
+
+    (0..array.len())
+        .map(|index| value_to_json(array, index))
+        .collect()
+}
+
+fn value_to_json(array: &dyn Array, index: usize) -> Result<JsonValue, ArrowError> {
+    if array.is_null(index) {
+        return Ok(JsonValue::Null);
+    }
+
+    let formatted = || -> Result<String, ArrowError> {
+        Ok(ArrayFormatter::try_new(array, &FormatOptions::default())?
+            .value(index)
+            .to_string())
+    };
+
+    Ok(match array.data_type() {
+        DataType::Null => JsonValue::Null,
+
+        DataType::Boolean => JsonValue::Bool(array.as_boolean().value(index)),
+
+        DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64 => JsonValue::Number(formatted()?),
+
+        DataType::Float16 | DataType::Float32 | DataType::Float64 => {
+            let number = formatted()?;
+            if number.parse::<f64>().is_ok_and(f64::is_finite) {
+                JsonValue::Number(number)
+            } else {
+                JsonValue::Null
+            }
+        }
+
+        DataType::List(_) => JsonValue::Array(array_to_json(&array.as_list::<i32>().value(index))?),
+        DataType::LargeList(_) => {
+            JsonValue::Array(array_to_json(&array.as_list::<i64>().value(index))?)
+        }
+        DataType::FixedSizeList(_, _) => {
+            JsonValue::Array(array_to_json(&array.as_fixed_size_list().value(index))?)
+        }
+        DataType::ListView(_) => {
+            JsonValue::Array(array_to_json(&array.as_list_view::<i32>().value(index))?)
+        }
+        DataType::LargeListView(_) => {
+            JsonValue::Array(array_to_json(&array.as_list_view::<i64>().value(index))?)
+        }
+
+        DataType::Struct(fields) => {
+            let array = array.as_struct();
+            JsonValue::Object(
+                fields
+                    .iter()
+                    .zip(array.columns())
+                    .map(|(field, column)| {
+                        Ok((field.name().clone(), value_to_json(column, index)?))
+                    })
+                    .collect::<Result<_, ArrowError>>()?,
+            )
+        }
+
+        DataType::Map(_, _) => {
+            let entries = array.as_map().value(index);
+            let keys = array_to_json(entries.column(0))?;
+            let values = array_to_json(entries.column(1))?;
+
+            if keys.iter().all(|key| matches!(key, JsonValue::String(_))) {
+                JsonValue::Object(
+                    keys.into_iter()
+                        .zip(values)
+                        .map(|(key, value)| match key {
+                            JsonValue::String(key) => (key, value),
+                            _ => unreachable!("checked above"),
+                        })
+                        .collect(),
+                )
+            } else {
+                // JSON only has string keys, so fall back to a list of key-value pairs.
+                JsonValue::Array(
+                    keys.into_iter()
+                        .zip(values)
+                        .map(|(key, value)| {
+                            JsonValue::Object(vec![
+                                ("key".to_owned(), key),
+                                ("value".to_owned(), value),
+                            ])
+                        })
+                        .collect(),
+                )
+            }
+        }
+
+        DataType::Dictionary(_, _) => {
+            let dictionary = array.as_any_dictionary();
+            let key = dictionary.normalized_keys()[index];
+            value_to_json(dictionary.values(), key)?
+        }
+
+        DataType::Union(_, _) => {
+            let union = array.as_union();
+            value_to_json(union.child(union.type_id(index)), union.value_offset(index))?
+        }
+
+        _ => JsonValue::String(formatted()?),
+    })
+}
+
+/// Shows arrow data as a collapsible JSON tree, with a button to copy it as text.
+pub(crate) fn json_section_ui(ui: &mut egui::Ui, array: &dyn Array) {
+    let json = match arrow_to_json(array) {
+        Ok(json) => json,
+        Err(err) => {
+            ui.error_with_details_on_hover(format!("Failed to convert to JSON: {err}"));
+            return;
+        }
+    };
+
+    let egui_ctx = ui.ctx().clone();
+    let pretty = json.to_pretty_string();
+    let content =
+        LabelContent::new("JSON").with_action_button(&re_ui::icons::COPY, "Copy JSON", move || {
+            egui_ctx.copy_text(pretty)
+        });
+
+    let id = ui.id().with("json");
+    ui.list_item()
+        .show_hierarchical_with_children(ui, id, false, content, |ui| {
+            json_children_ui(ui, id, &json);
+        });
+}
+
+fn json_children_ui(ui: &mut egui::Ui, id: Id, value: &JsonValue) {
+    match value {
+        JsonValue::Array(values) => {
+            for (index, value) in values.iter().enumerate() {
+                let label = SyntaxHighlightedBuilder::index(&re_format::format_uint(index));
+                json_node_ui(ui, id.with(index), label, value);
+            }
+        }
+        JsonValue::Object(entries) => {
+            for (key, value) in entries {
+                let label = SyntaxHighlightedBuilder::identifier(key);
+                json_node_ui(ui, id.with(key), label, value);
+            }
+        }
+        JsonValue::Null | JsonValue::Bool(_) | JsonValue::Number(_) | JsonValue::String(_) => {
+            json_node_ui(
+                ui,
+                id.with("value"),
+                SyntaxHighlightedBuilder::body("value"),
+                value,
+            );
+        }
+    }
+}
+
+fn json_node_ui(ui: &mut egui::Ui, id: Id, label: SyntaxHighlightedBuilder, value: &JsonValue) {
+    let label = label.into_widget_text(ui.style());
+    let value_text = value.syntax_highlighted().into_widget_text(ui.style());
+    let content = PropertyContent::new(label).value_text(value_text);
+
+    if value.has_children() {
+        ui.list_item()
+            .show_hierarchical_with_children(ui, id, false, content, |ui| {
+                json_children_ui(ui, id, value);
+            });
+    } else {
+        ui.list_item().show_hierarchical(ui, content);
+    }
+}
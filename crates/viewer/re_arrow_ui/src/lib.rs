@@ -2,10 +2,13 @@
 mod arrow_node;
 mod arrow_ui;
 mod datatype_ui;
+mod json;
 mod list_item_ranges;
 mod show_index;
 
-pub use arrow_ui::arrow_ui;
+pub use arrow_ui::{arrow_ui, component_arrow_ui};
+pub use json::{JsonValue, arrow_to_json};
+pub use show_index::ColormapFn;
 
 pub fn arrow_syntax_highlighted(
     data: &dyn arrow::array::Array,
@@ -26,6 +26,7 @@ use arrow::util::display::{ArrayFormatter, FormatOptions};
 use egui::{RichText, Ui};
 
 use re_log_types::TimestampFormat;
+use re_types_core::DisplayHints;
 use re_ui::list_item::{CustomContent, LabelContent};
 use re_ui::syntax_highlighting::SyntaxHighlightedBuilder;
 use re_ui::{UiExt as _, UiLayout};
@@ -33,6 +34,11 @@ use re_ui::{UiExt as _, UiLayout};
 use crate::arrow_node::ArrowNode;
 use crate::list_item_ranges::list_item_ranges;
 
+/// Maps a colormap name and a value in `[0, 1]` to a color.
+///
+/// Returns `None` for unknown colormaps.
+pub type ColormapFn = dyn Fn(&str, f32) -> Option<egui::Color32>;
+
 /// Arrow display options.
 ///
 /// Max item limits will not affect the `list_item`-based ui, that will always show all items.
@@ -57,6 +63,9 @@ pub struct DisplayOptions<'a> {
 
     /// Each nested level, by how much should the number of shown items decrease?
     pub decrease_nested_items_per_nested_level: usize,
+
+    /// Looks up colormaps by name, used for struct fields with [`DisplayHints`].
+    pub colormap: Option<&'a ColormapFn>,
 }
 
 impl Default for DisplayOptions<'_> {
@@ -71,6 +80,7 @@ impl Default for DisplayOptions<'_> {
             max_map_items: 3,
             max_struct_items: 6,
             decrease_nested_items_per_nested_level: 1,
+            colormap: None,
         }
     }
 }
@@ -93,6 +103,7 @@ impl DisplayOptions<'_> {
                 .max_struct_items
                 .saturating_sub(self.decrease_nested_items_per_nested_level),
             decrease_nested_items_per_nested_level: self.decrease_nested_items_per_nested_level,
+            colormap: self.colormap,
         }
     }
 
@@ -665,6 +676,76 @@ impl<'a> ShowIndexState<'a> for &'a FixedSizeListArray {
 /// Pairs a boxed [`ShowIndex`] with its field name
 type FieldDisplay<'a> = (&'a Field, Box<dyn ShowIndex + 'a>);
 
+fn struct_fields_ui<'a>(
+    array: &'a StructArray,
+    options: &DisplayOptions<'a>,
+) -> Result<Vec<FieldDisplay<'a>>, ArrowError> {
+    array
+        .columns()
+        .iter()
+        .zip(array.fields())
+        .map(|(a, f)| {
+            let format = make_ui(a.as_ref(), options)?;
+            let format = ShowWithHints::wrap(format, f, options.colormap);
+            Ok((&**f, format))
+        })
+        .collect()
+}
+
+/// Show a struct array as a table, with one row per item and one column per field.
+///
+/// Shows at most `max_rows` rows.
+pub(crate) fn struct_table_ui(
+    ui: &mut Ui,
+    array: &StructArray,
+    options: &DisplayOptions<'_>,
+    max_rows: usize,
+) -> EmptyArrowResult {
+    let items = struct_fields_ui(array, &options.nested())?;
+
+    egui::ScrollArea::horizontal()
+        .id_salt("struct_table")
+        .show(ui, |ui| {
+            egui::Grid::new("struct_table")
+                .striped(true)
+                .num_columns(items.len() + 1)
+                .show(ui, |ui| {
+                    ui.label("");
+                    for (field, _) in &items {
+                        ui.label(RichText::new(field.name()).strong());
+                    }
+                    ui.end_row();
+
+                    for idx in 0..array.len().min(max_rows) {
+                        let mut index = SyntaxHighlightedBuilder::new();
+                        index.append_index(&re_format::format_uint(idx));
+                        UiLayout::List.data_label(ui, index);
+
+                        for (_, display) in &items {
+                            let mut value = SyntaxHighlightedBuilder::new();
+                            if array.is_null(idx) {
+                                value.append_primitive("null");
+                            } else if let Err(err) = display.write(idx, &mut value) {
+                                ui.error_label(err.to_string());
+                                continue;
+                            }
+                            UiLayout::List.data_label(ui, value);
+                        }
+                        ui.end_row();
+                    }
+                });
+        });
+
+    if max_rows < array.len() {
+        ui.weak(format!(
+            "… {} more rows",
+            re_format::format_uint(array.len() - max_rows)
+        ));
+    }
+
+    Ok(())
+}
+
 struct FieldDisplayState<'a> {
     items: Vec<FieldDisplay<'a>>,
     max_items: usize,
@@ -674,20 +755,8 @@ impl<'a> ShowIndexState<'a> for &'a StructArray {
     type State = FieldDisplayState<'a>;
 
     fn prepare(&self, options: &DisplayOptions<'a>) -> Result<Self::State, ArrowError> {
-        let fields = self.fields();
-        let nested_options = options.nested();
-
-        let items = self
-            .columns()
-            .iter()
-            .zip(fields)
-            .map(|(a, f)| {
-                let format = make_ui(a.as_ref(), &nested_options)?;
-                Ok((&**f, format))
-            })
-            .collect::<Result<_, ArrowError>>()?;
         Ok(FieldDisplayState {
-            items,
+            items: struct_fields_ui(self, &options.nested())?,
             max_items: options.max_struct_items,
         })
     }
@@ -737,6 +806,97 @@ impl<'a> ShowIndexState<'a> for &'a StructArray {
     }
 }
 
+/// Shows the values of a struct field together with its [`DisplayHints`].
+struct ShowWithHints<'a> {
+    inner: Box<dyn ShowIndex + 'a>,
+    unit: Option<String>,
+
+    /// The colormap name, its range, and the values cast to `f64`.
+    colormap: Option<(&'a ColormapFn, String, [f64; 2], arrow::array::Float64Array)>,
+}
+
+impl<'a> ShowWithHints<'a> {
+    fn wrap(
+        inner: Box<dyn ShowIndex + 'a>,
+        field: &Field,
+        colormap_fn: Option<&'a ColormapFn>,
+    ) -> Box<dyn ShowIndex + 'a> {
+        let DisplayHints {
+            unit,
+            colormap,
+            range,
+        } = DisplayHints::from_field(field);
+
+        let colormap = match (colormap_fn, colormap, range) {
+            (Some(colormap_fn), Some(colormap), Some(range)) => {
+                arrow::compute::cast(inner.array(), &DataType::Float64)
+                    .ok()
+                    .map(|values| {
+                        (
+                            colormap_fn,
+                            colormap,
+                            range,
+                            values.as_primitive::<Float64Type>().clone(),
+                        )
+                    })
+            }
+            _ => None,
+        };
+
+        if unit.is_none() && colormap.is_none() {
+            inner
+        } else {
+            Box::new(Self {
+                inner,
+                unit,
+                colormap,
+            })
+        }
+    }
+}
+
+impl ShowIndex for ShowWithHints<'_> {
+    fn write(&self, idx: usize, f: &mut SyntaxHighlightedBuilder) -> EmptyArrowResult {
+        let is_valid = self.inner.array().is_valid(idx);
+
+        if let Some((colormap_fn, colormap, [min, max], values)) = &self.colormap
+            && values.is_valid(idx)
+        {
+            let t = ((values.value(idx) - min) / (max - min)).clamp(0.0, 1.0);
+            if let Some(color) = colormap_fn(colormap, t as f32) {
+                f.append_with_format_closure("■ ", move |style| egui::TextFormat {
+                    font_id: egui::TextStyle::Monospace.resolve(style),
+                    color,
+                    ..Default::default()
+                });
+            }
+        }
+
+        self.inner.write(idx, f)?;
+
+        if let Some(unit) = &self.unit
+            && is_valid
+        {
+            f.append_syntax(" ");
+            f.append_primitive(unit);
+        }
+
+        Ok(())
+    }
+
+    fn show(&self, idx: usize, ui: &mut Ui) {
+        self.inner.show(idx, ui);
+    }
+
+    fn is_item_nested(&self) -> bool {
+        self.inner.is_item_nested()
+    }
+
+    fn array(&self) -> &dyn Array {
+        self.inner.array()
+    }
+}
+
 struct MapArrayState<'a> {
     keys: Box<dyn ShowIndex + 'a>,
     values: Box<dyn ShowIndex + 'a>,
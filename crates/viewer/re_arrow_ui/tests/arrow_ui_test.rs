@@ -112,3 +112,91 @@ fn inline_formatting() {
         insta::assert_snapshot!(name, highlighted.text());
     }
 }
+
+#[test]
+fn display_hints() {
+    use std::sync::Arc;
+
+    use arrow::array::{Float64Array, StructArray};
+    use arrow::datatypes::{DataType, Field};
+
+    let distance = re_types::DisplayHints::default()
+        .with_unit("m")
+        .apply(Field::new("distance", DataType::Float64, true));
+    let data = StructArray::from(vec![(
+        Arc::new(distance),
+        Arc::new(Float64Array::from(vec![Some(2.5), None])) as _,
+    )]);
+
+    let highlighted = re_arrow_ui::arrow_syntax_highlighted(&data).expect("Failed to format data");
+    assert_eq!(highlighted.text(), "[{distance: 2.5 m}, {distance: null}]");
+}
+
+#[test]
+fn json() {
+    use std::sync::Arc;
+
+    use arrow::array::{BooleanArray, Float64Array, ListArray, StringArray, StructArray};
+    use arrow::datatypes::{DataType, Field, Int32Type};
+
+    let data = StructArray::from(vec![
+        (
+            Arc::new(Field::new("name", DataType::Utf8, true)),
+            Arc::new(StringArray::from(vec![Some("a \"quoted\"\nname"), None])) as _,
+        ),
+        (
+            Arc::new(Field::new("value", DataType::Float64, true)),
+            Arc::new(Float64Array::from(vec![2.5, f64::NAN])) as _,
+        ),
+        (
+            Arc::new(Field::new(
+                "ids",
+                DataType::new_list(DataType::Int32, true),
+                true,
+            )),
+            Arc::new(ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+                Some(vec![Some(1), Some(2)]),
+                Some(vec![]),
+            ])) as _,
+        ),
+        (
+            Arc::new(Field::new("visible", DataType::Boolean, true)),
+            Arc::new(BooleanArray::from(vec![Some(true), None])) as _,
+        ),
+    ]);
+
+    // Fields keep their order, non-finite floats become null.
+    let json = re_arrow_ui::arrow_to_json(&data).expect("Failed to convert to JSON");
+    assert_eq!(
+        json.to_pretty_string(),
+        r#"[
+  {
+    "name": "a \"quoted\"\nname",
+    "value": 2.5,
+    "ids": [
+      1,
+      2
+    ],
+    "visible": true
+  },
+  {
+    "name": null,
+    "value": null,
+    "ids": [],
+    "visible": null
+  }
+]"#
+    );
+
+    // A single item isn't wrapped in an array.
+    let json = re_arrow_ui::arrow_to_json(&data.slice(1, 1)).expect("Failed to convert to JSON");
+    assert_eq!(
+        json,
+        re_arrow_ui::JsonValue::Object(vec![
+            ("name".to_owned(), re_arrow_ui::JsonValue::Null),
+            ("value".to_owned(), re_arrow_ui::JsonValue::Null),
+            ("ids".to_owned(), re_arrow_ui::JsonValue::Array(vec![])),
+            ("visible".to_owned(), re_arrow_ui::JsonValue::Null),
+        ])
+    );
+}
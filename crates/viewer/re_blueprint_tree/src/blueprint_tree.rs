@@ -15,11 +15,15 @@ use re_viewer_context::{
     Item, ItemCollection, ItemContext, SystemCommand, SystemCommandSender as _, ViewId,
     ViewerContext, VisitorControlFlow, contents_name_style, icon_for_container_kind,
 };
-use re_viewport_blueprint::{ViewportBlueprint, ui::show_add_view_or_container_modal};
+use re_viewport_blueprint::{
+    ViewportBlueprint,
+    ui::{show_add_view_or_container_modal, show_blueprint_diff_modal},
+};
 
 use crate::data::{
     BlueprintTreeData, ContainerData, ContentsData, DataResultData, DataResultKind, ViewData,
 };
+use crate::data_cache::{BlueprintTreeDataCache, BlueprintTreeDataCacheStats};
 
 /// Holds the state of the blueprint tree UI.
 #[derive(Default)]
@@ -62,6 +66,9 @@ pub struct BlueprintTree {
     /// IMPORTANT: Always make sure that the item will be drawn this or next frame when setting this
     /// to `Some`, so that this flag is immediately consumed.
     scroll_to_me_item: Option<Item>,
+
+    /// The tree data of the previous frame, which is only rebuilt where something changed.
+    tree_data_cache: BlueprintTreeDataCache,
 }
 
 impl BlueprintTree {
@@ -70,6 +77,11 @@ impl BlueprintTree {
         self.filter_state.activate(query);
     }
 
+    /// How often the tree data was rebuilt so far (for e.g. test purposes).
+    pub fn tree_data_cache_stats(&self) -> BlueprintTreeDataCacheStats {
+        self.tree_data_cache.stats()
+    }
+
     /// Show the Blueprint section of the left panel based on the current [`ViewportBlueprint`]
     pub fn show(
         &mut self,
@@ -107,6 +119,8 @@ impl BlueprintTree {
                             add_new_view_or_container_menu_button(ctx, viewport_blueprint, ui);
                             set_blueprint_to_default_menu_buttons(ctx, ui);
                             set_blueprint_to_auto_menu_button(ctx, ui);
+                            generate_dashboard_menu_button(ctx, viewport_blueprint, ui);
+                            compare_blueprints_menu_button(ctx, ui);
                         },
                     ),
                 );
@@ -131,11 +145,10 @@ impl BlueprintTree {
         self.candidate_drop_parent_container_id = self.next_candidate_drop_parent_container_id;
         self.next_candidate_drop_parent_container_id = None;
 
-        let blueprint_tree_data = BlueprintTreeData::from_blueprint_and_filter(
-            ctx,
-            viewport_blueprint,
-            &self.filter_state.filter(),
-        );
+        // Taken out for the duration of the frame, since the UI code below needs `&mut self`.
+        let mut tree_data_cache = std::mem::take(&mut self.tree_data_cache);
+        let blueprint_tree_data =
+            tree_data_cache.update(ctx, viewport_blueprint, &self.filter_state);
 
         egui::ScrollArea::both()
             .id_salt("blueprint_tree_scroll_area")
@@ -152,7 +165,7 @@ impl BlueprintTree {
                             self.root_container_ui(
                                 ctx,
                                 viewport_blueprint,
-                                &blueprint_tree_data,
+                                blueprint_tree_data,
                                 ui,
                                 root_container,
                             );
@@ -181,6 +194,8 @@ impl BlueprintTree {
                     );
                 });
             });
+
+        self.tree_data_cache = tree_data_cache;
     }
 
     /// Display the root container.
@@ -1264,6 +1279,45 @@ fn set_blueprint_to_auto_menu_button(ctx: &ViewerContext<'_>, ui: &mut egui::Ui)
     }
 }
 
+fn generate_dashboard_menu_button(
+    ctx: &ViewerContext<'_>,
+    viewport_blueprint: &ViewportBlueprint,
+    ui: &mut egui::Ui,
+) {
+    if ui
+        .add(re_ui::icons::CONTAINER_GRID.as_button_with_label(ui.tokens(), "Generate dashboard"))
+        .on_hover_text(
+            "Replace all views with automatically chosen ones, grouped by namespace and view type",
+        )
+        .clicked()
+    {
+        ui.close();
+        viewport_blueprint.generate_dashboard(ctx);
+    }
+}
+
+fn compare_blueprints_menu_button(ctx: &ViewerContext<'_>, ui: &mut egui::Ui) {
+    let app_id = ctx.store_context.application_id();
+    let has_other_blueprint = ctx.storage_context.bundle.entity_dbs().any(|db| {
+        db.store_kind() == re_log_types::StoreKind::Blueprint
+            && db.application_id() == app_id
+            && db.store_id() != ctx.store_context.blueprint.store_id()
+    });
+
+    if ui
+        .add_enabled(
+            has_other_blueprint,
+            re_ui::icons::BLUEPRINT.as_button_with_label(ui.tokens(), "Compare blueprints…"),
+        )
+        .on_hover_text("Compare the active blueprint with another one, and merge selected changes")
+        .on_disabled_hover_text("There is no other blueprint for this app")
+        .clicked()
+    {
+        ui.close();
+        show_blueprint_diff_modal();
+    }
+}
+
 /// List all views that have the provided entity as data result.
 #[inline]
 fn list_views_with_entity(
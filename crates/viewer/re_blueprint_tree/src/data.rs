@@ -64,6 +64,13 @@ impl BlueprintTreeData {
             ControlFlow::Continue(())
         }
     }
+
+    /// Finds the data of a view, if the view is part of the tree.
+    pub fn view_data_mut(&mut self, view_id: &ViewId) -> Option<&mut ViewData> {
+        self.root_container
+            .as_mut()
+            .and_then(|root_container| root_container.view_data_mut(view_id))
+    }
 }
 
 // ---
@@ -172,6 +179,13 @@ impl ContainerData {
     pub fn item(&self) -> Item {
         Item::Container(self.id)
     }
+
+    fn view_data_mut(&mut self, view_id: &ViewId) -> Option<&mut ViewData> {
+        self.children.iter_mut().find_map(|child| match child {
+            ContentsData::Container(container_data) => container_data.view_data_mut(view_id),
+            ContentsData::View(view_data) => (view_data.id == *view_id).then_some(view_data),
+        })
+    }
 }
 
 // ---
@@ -202,7 +216,7 @@ pub struct ViewData {
 }
 
 impl ViewData {
-    fn from_blueprint_and_filter(
+    pub(crate) fn from_blueprint_and_filter(
         ctx: &ViewerContext<'_>,
         view_blueprint: &ViewBlueprint,
         filter_matcher: &FilterMatcher,
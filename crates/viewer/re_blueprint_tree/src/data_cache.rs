@@ -0,0 +1,202 @@
+//! Caching of [`BlueprintTreeData`] across frames.
+//!
+//! Building the blueprint tree data walks the data results of all views. For layouts that don't
+//! change, this is wasted work, so the tree is built once and then updated from the store events
+//! collected by [`BlueprintTreeStoreSubscriber`]:
+//! - the entire tree is rebuilt if the containers, the set of views, the filter, the blueprint
+//!   query, or the active stores changed,
+//! - a single view is rebuilt if only its blueprint changed,
+//! - all views are rebuilt if the recording changed in a way that may affect their data results.
+
+use std::cell::Cell;
+use std::collections::BTreeSet;
+
+use re_chunk_store::LatestAtQuery;
+use re_log_types::{EntityPath, StoreId};
+use re_ui::filter_widget::FilterState;
+use re_viewer_context::{ViewId, ViewerContext};
+use re_viewport_blueprint::ViewportBlueprint;
+
+use crate::data::{BlueprintTreeData, ViewData};
+use crate::store_subscriber::BlueprintTreeStoreSubscriber;
+
+thread_local! {
+    /// How often the blueprint tree data was rebuilt during the last frame.
+    ///
+    /// A full rebuild and every rebuilt view count once each,
+    /// so this stays at zero for static layouts.
+    /// Thread local, since the blueprint tree is only ever shown on the UI thread.
+    pub static BLUEPRINT_TREE_REBUILDS_THIS_FRAME: Cell<u64> = const { Cell::new(0) };
+}
+
+fn count_rebuild_this_frame() {
+    BLUEPRINT_TREE_REBUILDS_THIS_FRAME.set(BLUEPRINT_TREE_REBUILDS_THIS_FRAME.get() + 1);
+}
+
+/// Counts how often the blueprint tree data was (partially) rebuilt.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BlueprintTreeDataCacheStats {
+    /// Number of times the entire tree was rebuilt.
+    pub num_full_rebuilds: u64,
+
+    /// Number of times a single view was rebuilt while the rest of the tree was reused.
+    pub num_view_rebuilds: u64,
+}
+
+/// Everything the tree was built for that isn't tracked through store events.
+#[derive(PartialEq, Eq)]
+struct BuiltFor {
+    blueprint_id: StoreId,
+    blueprint_query: LatestAtQuery,
+    recording_id: StoreId,
+    filter_query: Option<String>,
+    view_ids: BTreeSet<ViewId>,
+}
+
+/// Keeps the [`BlueprintTreeData`] of the last frame around and updates it incrementally.
+#[derive(Default)]
+pub struct BlueprintTreeDataCache {
+    data: BlueprintTreeData,
+
+    /// `None` if the tree was never built.
+    built_for: Option<BuiltFor>,
+
+    /// Generation of the [`BlueprintTreeStoreSubscriber`] for the blueprint store that the tree
+    /// is up to date with.
+    blueprint_generation: u64,
+
+    /// Generation of the [`BlueprintTreeStoreSubscriber`] for the recording that the tree is up to
+    /// date with.
+    recording_generation: u64,
+
+    stats: BlueprintTreeDataCacheStats,
+}
+
+impl BlueprintTreeDataCache {
+    /// Brings the cached tree up to date and returns it.
+    pub fn update(
+        &mut self,
+        ctx: &ViewerContext<'_>,
+        viewport_blueprint: &ViewportBlueprint,
+        filter_state: &FilterState,
+    ) -> &BlueprintTreeData {
+        re_tracing::profile_function!();
+
+        BLUEPRINT_TREE_REBUILDS_THIS_FRAME.set(0);
+
+        let built_for = BuiltFor {
+            blueprint_id: ctx.store_context.blueprint.store_id().clone(),
+            blueprint_query: ctx.blueprint_query.clone(),
+            recording_id: ctx.recording().store_id().clone(),
+            filter_query: filter_state.query().map(ToOwned::to_owned),
+            view_ids: viewport_blueprint.views.keys().copied().collect(),
+        };
+
+        let (blueprint_generation, changed_blueprint_entities) =
+            BlueprintTreeStoreSubscriber::changes_since(
+                &built_for.blueprint_id,
+                self.blueprint_generation,
+            );
+        let (recording_generation, changed_recording_entities) =
+            BlueprintTreeStoreSubscriber::changes_since(
+                &built_for.recording_id,
+                self.recording_generation,
+            );
+
+        // The generations start over if a store was dropped and loaded again.
+        let mut needs_full_rebuild = self.built_for.as_ref() != Some(&built_for)
+            || blueprint_generation < self.blueprint_generation
+            || recording_generation < self.recording_generation;
+        let mut changed_views = BTreeSet::new();
+
+        if !needs_full_rebuild {
+            for entity_path in &changed_blueprint_entities {
+                match view_of_blueprint_entity(entity_path) {
+                    Some(view_id) if built_for.view_ids.contains(&view_id) => {
+                        changed_views.insert(view_id);
+                    }
+
+                    // Containers, the viewport, or anything else may change the structure.
+                    _ => {
+                        needs_full_rebuild = true;
+                        break;
+                    }
+                }
+            }
+
+            if !changed_recording_entities.is_empty() {
+                changed_views.clone_from(&built_for.view_ids);
+            }
+        }
+
+        if needs_full_rebuild
+            || !self.update_changed_views(ctx, viewport_blueprint, filter_state, &changed_views)
+        {
+            self.data = BlueprintTreeData::from_blueprint_and_filter(
+                ctx,
+                viewport_blueprint,
+                &filter_state.filter(),
+            );
+            self.stats.num_full_rebuilds += 1;
+            count_rebuild_this_frame();
+        }
+
+        self.built_for = Some(built_for);
+        self.blueprint_generation = blueprint_generation;
+        self.recording_generation = recording_generation;
+
+        &self.data
+    }
+
+    /// How often the tree was rebuilt so far.
+    pub fn stats(&self) -> BlueprintTreeDataCacheStats {
+        self.stats
+    }
+
+    /// Rebuilds the given views in place.
+    ///
+    /// Returns `false` if this isn't possible because a view appeared in or disappeared from the
+    /// tree due to filtering, which may affect its parent containers.
+    fn update_changed_views(
+        &mut self,
+        ctx: &ViewerContext<'_>,
+        viewport_blueprint: &ViewportBlueprint,
+        filter_state: &FilterState,
+        changed_views: &BTreeSet<ViewId>,
+    ) -> bool {
+        let filter_matcher = filter_state.filter();
+
+        for view_id in changed_views {
+            let Some(view_blueprint) = viewport_blueprint.view(view_id) else {
+                return false;
+            };
+            let Some(view_data) = self.data.view_data_mut(view_id) else {
+                return false;
+            };
+            let Some(new_view_data) =
+                ViewData::from_blueprint_and_filter(ctx, view_blueprint, &filter_matcher)
+            else {
+                return false;
+            };
+
+            *view_data = new_view_data;
+            self.stats.num_view_rebuilds += 1;
+            count_rebuild_this_frame();
+        }
+
+        true
+    }
+}
+
+/// The view a blueprint entity belongs to, i.e. `/view/<id>` and everything below it.
+fn view_of_blueprint_entity(entity_path: &EntityPath) -> Option<ViewId> {
+    let [registry, id, ..] = entity_path.as_slice() else {
+        return None;
+    };
+    if registry != ViewId::registry_part() {
+        return None;
+    }
+
+    let view_id = ViewId::from_entity_path(&EntityPath::new(vec![registry.clone(), id.clone()]));
+    (view_id != ViewId::invalid()).then_some(view_id)
+}
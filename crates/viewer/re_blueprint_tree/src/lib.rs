@@ -9,6 +9,9 @@ pub mod data;
 
 #[cfg(not(feature = "testing"))]
 pub(crate) mod data;
+mod data_cache;
 mod data_result_node_or_path;
+mod store_subscriber;
 
 pub use blueprint_tree::BlueprintTree;
+pub use data_cache::{BLUEPRINT_TREE_REBUILDS_THIS_FRAME, BlueprintTreeDataCacheStats};
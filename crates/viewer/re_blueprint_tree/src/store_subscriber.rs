@@ -0,0 +1,104 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::OnceLock;
+
+use re_chunk_store::{
+    ChunkStore, ChunkStoreDiffKind, ChunkStoreEvent, ChunkStoreSubscriberHandle,
+    PerStoreChunkSubscriber,
+};
+use re_log_types::{EntityPath, StoreId};
+use re_types::ComponentIdentifier;
+
+/// Keeps track of the entities of a store that changed in a way that may affect the blueprint tree.
+///
+/// For blueprints, every write counts, since any blueprint component may show up in the tree.
+/// For recordings, only new entities, new components, and removed data count: these may change
+/// the data results of a view, whereas more data for known components doesn't.
+#[derive(Default)]
+pub struct BlueprintTreeStoreSubscriber {
+    /// Incremented for every relevant change.
+    generation: u64,
+
+    /// The generation at which each entity last changed.
+    changed_at: BTreeMap<EntityPath, u64>,
+
+    /// The components seen so far for each entity of a recording.
+    known_components: BTreeMap<EntityPath, BTreeSet<ComponentIdentifier>>,
+}
+
+impl BlueprintTreeStoreSubscriber {
+    /// Accesses the global store subscriber.
+    ///
+    /// Lazily registers the subscriber if it hasn't been registered yet.
+    pub fn subscription_handle() -> ChunkStoreSubscriberHandle {
+        static SUBSCRIPTION: OnceLock<ChunkStoreSubscriberHandle> = OnceLock::new();
+        *SUBSCRIPTION.get_or_init(ChunkStore::register_per_store_subscriber::<Self>)
+    }
+
+    /// Returns the current generation of the given store, and all entities that changed after
+    /// `generation`.
+    pub fn changes_since(store_id: &StoreId, generation: u64) -> (u64, Vec<EntityPath>) {
+        ChunkStore::with_per_store_subscriber_once(
+            Self::subscription_handle(),
+            store_id,
+            |subscriber: &Self| {
+                let changed_entities = if subscriber.generation == generation {
+                    Vec::new()
+                } else {
+                    subscriber
+                        .changed_at
+                        .iter()
+                        .filter(|(_, changed_at)| **changed_at > generation)
+                        .map(|(entity_path, _)| entity_path.clone())
+                        .collect()
+                };
+
+                (subscriber.generation, changed_entities)
+            },
+        )
+        .unwrap_or_default()
+    }
+}
+
+impl PerStoreChunkSubscriber for BlueprintTreeStoreSubscriber {
+    #[inline]
+    fn name() -> String {
+        "BlueprintTreeStoreSubscriber".to_owned()
+    }
+
+    fn on_events<'a>(&mut self, events: impl Iterator<Item = &'a ChunkStoreEvent>) {
+        re_tracing::profile_function!();
+
+        for event in events {
+            let chunk = &event.diff.chunk;
+            let entity_path = chunk.entity_path();
+
+            let is_relevant = match event.diff.kind {
+                ChunkStoreDiffKind::Addition if event.store_id.is_blueprint() => true,
+
+                ChunkStoreDiffKind::Addition => {
+                    let known_components = self
+                        .known_components
+                        .entry(entity_path.clone())
+                        .or_default();
+
+                    // No short-circuiting, all components of the chunk need to be recorded.
+                    chunk
+                        .components_identifiers()
+                        .fold(false, |is_new, component| {
+                            known_components.insert(component) || is_new
+                        })
+                }
+
+                ChunkStoreDiffKind::Deletion => {
+                    self.known_components.remove(entity_path);
+                    true
+                }
+            };
+
+            if is_relevant {
+                self.generation += 1;
+                self.changed_at.insert(entity_path.clone(), self.generation);
+            }
+        }
+    }
+}
@@ -1,6 +1,8 @@
 #![cfg(feature = "testing")]
 
-use re_blueprint_tree::BlueprintTree;
+use re_blueprint_tree::{
+    BLUEPRINT_TREE_REBUILDS_THIS_FRAME, BlueprintTree, BlueprintTreeDataCacheStats,
+};
 use re_chunk_store::RowId;
 use re_chunk_store::external::re_chunk::ChunkBuilder;
 use re_log_types::build_frame_nr;
@@ -127,6 +129,92 @@ fn blueprint_panel_filter_active_above_origin_should_match_snapshot() {
     );
 }
 
+#[test]
+fn blueprint_tree_data_is_only_rebuilt_on_changes() {
+    let (test_context, mut blueprint_tree) = setup_filter_test(None);
+    assert_eq!(blueprint_tree.tree_data_cache_stats().num_full_rebuilds, 1);
+
+    let run_frame = |blueprint_tree: &mut BlueprintTree| {
+        test_context.run_in_egui_central_panel(|ctx, ui| {
+            let blueprint =
+                ViewportBlueprint::from_db(ctx.store_context.blueprint, ctx.blueprint_query);
+
+            blueprint_tree.show(ctx, &blueprint, ui);
+        });
+    };
+
+    // Nothing changes, so the tree data from the first frame is reused.
+    for _ in 0..3 {
+        run_frame(&mut blueprint_tree);
+    }
+    assert_eq!(
+        blueprint_tree.tree_data_cache_stats(),
+        BlueprintTreeDataCacheStats {
+            num_full_rebuilds: 1,
+            num_view_rebuilds: 0,
+        }
+    );
+
+    // Changing the filter requires a full rebuild.
+    blueprint_tree.activate_filter("left");
+    run_frame(&mut blueprint_tree);
+    run_frame(&mut blueprint_tree);
+    assert_eq!(blueprint_tree.tree_data_cache_stats().num_full_rebuilds, 2);
+}
+
+#[test]
+fn blueprint_tree_rebuilds_this_frame_counts_rebuilds() {
+    let (test_context, mut blueprint_tree) = setup_filter_test(None);
+
+    // Static layout, nothing to rebuild.
+    assert_eq!(run_frame_once(&test_context, &mut blueprint_tree), 0);
+    assert_eq!(run_frame_once(&test_context, &mut blueprint_tree), 0);
+
+    // Changing the filter rebuilds the tree on the very next frame, and only then.
+    blueprint_tree.activate_filter("left");
+    assert_eq!(run_frame_once(&test_context, &mut blueprint_tree), 1);
+    assert_eq!(run_frame_once(&test_context, &mut blueprint_tree), 0);
+}
+
+#[test]
+fn blueprint_tree_only_rebuilds_changed_views() {
+    let (test_context, mut blueprint_tree) = setup_filter_test(None);
+    assert_eq!(run_frame_once(&test_context, &mut blueprint_tree), 0);
+
+    let egui_ctx = test_context.run_once_in_egui_central_panel(|ctx, ui| {
+        let blueprint =
+            ViewportBlueprint::from_db(ctx.store_context.blueprint, ctx.blueprint_query);
+        let view_blueprint = blueprint.views.values().next().expect("one view");
+        view_blueprint.set_display_name(ctx, Some("renamed".to_owned()));
+
+        ui.ctx().clone()
+    });
+    test_context.handle_system_commands(&egui_ctx);
+
+    // The blueprint store event for the view only rebuilds that view.
+    assert_eq!(run_frame_once(&test_context, &mut blueprint_tree), 1);
+    assert_eq!(run_frame_once(&test_context, &mut blueprint_tree), 0);
+    assert_eq!(
+        blueprint_tree.tree_data_cache_stats(),
+        BlueprintTreeDataCacheStats {
+            num_full_rebuilds: 1,
+            num_view_rebuilds: 1,
+        }
+    );
+}
+
+/// Shows the blueprint tree for exactly one frame and returns how often it was rebuilt.
+fn run_frame_once(test_context: &TestContext, blueprint_tree: &mut BlueprintTree) -> u64 {
+    test_context.run_once_in_egui_central_panel(|ctx, ui| {
+        let blueprint =
+            ViewportBlueprint::from_db(ctx.store_context.blueprint, ctx.blueprint_query);
+
+        blueprint_tree.show(ctx, &blueprint, ui);
+    });
+
+    BLUEPRINT_TREE_REBUILDS_THIS_FRAME.get()
+}
+
 fn setup_filter_test(query: Option<&str>) -> (TestContext, BlueprintTree) {
     let mut test_context = TestContext::new_with_view_class::<re_view_spatial::SpatialView3D>();
 
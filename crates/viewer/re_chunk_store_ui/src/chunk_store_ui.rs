@@ -454,6 +454,11 @@ impl DatastoreUi {
                     list_item::PropertyContent::new("Chunk max rows (unsorted)")
                         .value_uint(chunk_store.config().chunk_max_rows_if_unsorted),
                 );
+
+                ui.list_item_flat_noninteractive(
+                    list_item::PropertyContent::new("Max static versions")
+                        .value_uint(chunk_store.config().max_static_versions),
+                );
             });
         });
 
@@ -29,4 +29,10 @@ pub fn archetype_field_fallbacks(registry: &mut FallbackProviderRegistry) {
         blueprint::archetypes::GraphBackground::descriptor_color().component,
         |ctx| components::Color::from(ctx.viewer_ctx().tokens().viewport_background),
     );
+
+    // ColorByComponent
+    registry.register_component_fallback_provider(
+        blueprint::archetypes::ColorByComponent::descriptor_colormap().component,
+        |_| components::Colormap::Viridis,
+    );
 }
@@ -19,6 +19,39 @@ pub fn edit_u64_range(
     edit_u64_raw(ui, &mut value, range, "")
 }
 
+/// Generic editor for a [`re_types::datatypes::UInt32`] values within a given range.
+pub fn edit_u32_range(
+    _ctx: &re_viewer_context::ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    value: &mut MaybeMutRef<'_, impl std::ops::DerefMut<Target = datatypes::UInt32>>,
+    range: RangeInclusive<u32>,
+) -> egui::Response {
+    let mut value: MaybeMutRef<'_, u32> = match value {
+        MaybeMutRef::Ref(value) => MaybeMutRef::Ref(value),
+        MaybeMutRef::MutRef(value) => MaybeMutRef::MutRef(&mut value.deref_mut().0),
+    };
+    edit_u32_raw(ui, &mut value, range)
+}
+
+/// Non monomorphized implementation for u32 editing.
+fn edit_u32_raw(
+    ui: &mut egui::Ui,
+    value: &mut MaybeMutRef<'_, u32>,
+    range: RangeInclusive<u32>,
+) -> egui::Response {
+    let speed = (**value as f64 * 0.01).at_least(0.001);
+    if let Some(value) = value.as_mut() {
+        ui.add(
+            egui::DragValue::new(value)
+                .clamp_existing_to_range(false)
+                .range(range)
+                .speed(speed),
+        )
+    } else {
+        UiLayout::List.data_label(ui, SyntaxHighlightedBuilder::new().with(&**value))
+    }
+}
+
 /// Non monomorphized implementation for u64 editing.
 pub fn edit_u64_raw(
     ui: &mut egui::Ui,
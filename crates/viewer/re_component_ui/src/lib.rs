@@ -30,23 +30,24 @@ mod zoom_level;
 use datatype_uis::{
     edit_bool, edit_f32_min_to_max_float, edit_f32_zero_to_max, edit_f32_zero_to_one,
     edit_f64_min_to_max_float, edit_f64_zero_to_max, edit_multiline_string, edit_or_view_vec2d,
-    edit_or_view_vec3d, edit_singleline_string, edit_u64_range, edit_ui_points, edit_view_enum,
-    edit_view_enum_with_variant_available, edit_view_range1d, view_timestamp, view_uuid,
-    view_view_id,
+    edit_or_view_vec3d, edit_singleline_string, edit_u32_range, edit_u64_range, edit_ui_points,
+    edit_view_enum, edit_view_enum_with_variant_available, edit_view_range1d, view_timestamp,
+    view_uuid, view_view_id,
 };
 
 use re_types::{
     blueprint::components::{
-        AngularSpeed, BackgroundKind, Corner2D, Enabled, Eye3DKind, ForceDistance, ForceIterations,
-        ForceStrength, GridSpacing, LinkAxis, LockRangeDuringZoom, MapProvider, NearClipPlane,
-        RootContainer, ViewFit, ViewMaximized,
+        AngularSpeed, BackgroundKind, BarChartMode, Corner2D, DepthCloudSubsampling, Enabled,
+        Eye3DKind, Eye3DProjection, FftWindowOverlap, FftWindowSize, ForceDistance,
+        ForceIterations, ForceStrength, GridSpacing, LinkAxis, LockRangeDuringZoom, MapProvider,
+        NearClipPlane, OnionSkinSteps, RootContainer, ViewFit, ViewMaximized,
     },
     components::{
-        AggregationPolicy, AlbedoFactor, AxisLength, Color, DepthMeter, DrawOrder, FillMode,
-        FillRatio, GammaCorrection, GraphType, ImagePlaneDistance, LinearSpeed,
-        MagnificationFilter, MarkerSize, Name, Opacity, Position2D, Position3D, Range1D, Scale3D,
-        SeriesVisible, ShowLabels, StrokeWidth, Text, Timestamp, TransformRelation, Translation3D,
-        ValueRange, Vector3D, VideoCodec, Visible,
+        AggregationPolicy, AlbedoFactor, AudioSampleFormat, AxisLength, Color, DepthMeter,
+        DrawOrder, FillMode, FillRatio, GammaCorrection, GraphType, ImagePlaneDistance,
+        LinearSpeed, MagnificationFilter, MarkerSize, Name, Opacity, Position2D, Position3D,
+        Range1D, Scale3D, SeriesVisible, ShowLabels, StrokeWidth, Text, Timestamp,
+        TransformRelation, Translation3D, Unit, ValueRange, Vector3D, VideoCodec, Visible,
     },
 };
 use re_viewer_context::gpu_bridge::colormap_edit_or_view_ui;
@@ -96,12 +97,22 @@ pub fn create_component_ui_registry() -> re_viewer_context::ComponentUiRegistry
     registry.add_singleline_edit_or_view::<ForceStrength>(edit_f64_min_to_max_float);
 
     // float 0-1 components:
+    registry.add_singleline_edit_or_view::<FftWindowOverlap>(edit_f32_zero_to_one);
     registry.add_singleline_edit_or_view::<Opacity>(edit_f32_zero_to_one);
 
     // integer range components:
     registry.add_singleline_edit_or_view::<ForceIterations>(|ctx, ui, value| {
         edit_u64_range(ctx, ui, value, 1..=5)
     });
+    registry.add_singleline_edit_or_view::<OnionSkinSteps>(|ctx, ui, value| {
+        edit_u64_range(ctx, ui, value, 0..=10)
+    });
+    registry.add_singleline_edit_or_view::<DepthCloudSubsampling>(|ctx, ui, value| {
+        edit_u64_range(ctx, ui, value, 1..=16)
+    });
+    registry.add_singleline_edit_or_view::<FftWindowSize>(|ctx, ui, value| {
+        edit_u32_range(ctx, ui, value, 16..=8192)
+    });
 
     // Bool components:
     registry.add_singleline_edit_or_view::<Enabled>(edit_bool);
@@ -118,13 +129,17 @@ pub fn create_component_ui_registry() -> re_viewer_context::ComponentUiRegistry
     registry.add_multiline_edit_or_view::<Text>(edit_multiline_string);
     registry.add_singleline_edit_or_view::<Name>(edit_singleline_string);
     registry.add_multiline_edit_or_view::<Name>(edit_multiline_string);
+    registry.add_singleline_edit_or_view::<Unit>(edit_singleline_string);
 
     // Enums:
     // TODO(#6974): Enums editors trivial and always the same, provide them automatically!
     registry.add_singleline_edit_or_view::<AggregationPolicy>(edit_view_enum);
+    registry.add_singleline_edit_or_view::<AudioSampleFormat>(edit_view_enum);
     registry.add_singleline_edit_or_view::<BackgroundKind>(edit_view_enum);
+    registry.add_singleline_edit_or_view::<BarChartMode>(edit_view_enum);
     registry.add_singleline_edit_or_view::<Corner2D>(edit_view_enum);
     registry.add_singleline_edit_or_view::<Eye3DKind>(edit_view_enum);
+    registry.add_singleline_edit_or_view::<Eye3DProjection>(edit_view_enum);
     registry.add_singleline_edit_or_view::<FillMode>(edit_view_enum);
     registry.add_singleline_edit_or_view::<GraphType>(edit_view_enum);
     registry.add_singleline_edit_or_view::<LinkAxis>(edit_view_enum);
@@ -0,0 +1,81 @@
+use re_log_types::EntityPath;
+use re_types::blueprint::archetypes::RigidAlignment;
+use re_viewer_context::{BlueprintContext as _, Item, ViewId};
+
+use super::track_entity::is_3d_view;
+use crate::{ContextMenuAction, ContextMenuContext};
+
+/// Moves the clicked entity onto the other selected one, by fitting a rigid transform that is only
+/// applied in the view.
+///
+/// The view fits the transform and reports the remaining error, see [`RigidAlignment`].
+pub struct AlignEntitiesAction {
+    /// Match each point with the closest point of the other entity, instead of by index.
+    pub match_closest: bool,
+}
+
+impl AlignEntitiesAction {
+    /// The view, the entity to move, and the entity to align it to.
+    fn view_source_and_reference<'a>(
+        ctx: &'a ContextMenuContext<'_>,
+    ) -> Option<(&'a ViewId, &'a EntityPath, &'a EntityPath)> {
+        let Item::DataResult(view_id, source) = ctx.clicked_item else {
+            return None;
+        };
+
+        if ctx.selection.len() != 2
+            || !ctx.selection.contains_item(ctx.clicked_item)
+            || !is_3d_view(ctx, view_id)
+        {
+            return None;
+        }
+
+        let reference = ctx.selection.iter().find_map(|(item, _)| match item {
+            Item::DataResult(other_view_id, reference)
+                if other_view_id == view_id && reference.entity_path != source.entity_path =>
+            {
+                Some(&reference.entity_path)
+            }
+            _ => None,
+        })?;
+
+        Some((view_id, &source.entity_path, reference))
+    }
+}
+
+impl ContextMenuAction for AlignEntitiesAction {
+    fn supports_selection(&self, ctx: &ContextMenuContext<'_>) -> bool {
+        Self::view_source_and_reference(ctx).is_some()
+    }
+
+    fn label(&self, ctx: &ContextMenuContext<'_>) -> String {
+        let Some((_, _, reference)) = Self::view_source_and_reference(ctx) else {
+            return String::new();
+        };
+
+        if self.match_closest {
+            format!("Align to {reference} by closest points")
+        } else {
+            format!("Align to {reference} by index")
+        }
+    }
+
+    fn process_selection(&self, ctx: &ContextMenuContext<'_>) {
+        let Some((view_id, source, reference)) = Self::view_source_and_reference(ctx) else {
+            return;
+        };
+
+        let query_result = ctx.viewer_context.lookup_query_result(*view_id);
+        let Some(data_result) = query_result.tree.lookup_result_by_path(source.hash()) else {
+            return;
+        };
+
+        // Clears any previous fit, so that the view fits the transform again.
+        ctx.viewer_context.save_blueprint_archetype(
+            data_result.override_path().clone(),
+            &RigidAlignment::clear_fields()
+                .with_reference(reference)
+                .with_match_closest(self.match_closest),
+        );
+    }
+}
@@ -0,0 +1,48 @@
+use re_log_types::ComponentPath;
+use re_viewer_context::{EventSearch, Item, TimeControlCommand};
+
+use crate::{ContextMenuAction, ContextMenuContext};
+
+/// Highlights the times at which a numeric component satisfies a predicate in the time panel.
+pub struct FindEventsAction;
+
+impl ContextMenuAction for FindEventsAction {
+    fn supports_item(&self, ctx: &ContextMenuContext<'_>, item: &Item) -> bool {
+        match item {
+            Item::AppId(_)
+            | Item::TableId(_)
+            | Item::DataSource(_)
+            | Item::StoreId(_)
+            | Item::Container(_)
+            | Item::View(_)
+            | Item::RedapEntry(_)
+            | Item::RedapServer(_)
+            | Item::DataResult(..)
+            | Item::InstancePath(_) => false,
+            Item::ComponentPath(component_path) => is_numeric(ctx, component_path),
+        }
+    }
+
+    fn label(&self, _ctx: &ContextMenuContext<'_>) -> String {
+        "Find events…".to_owned()
+    }
+
+    fn process_component_path(&self, ctx: &ContextMenuContext<'_>, component_path: &ComponentPath) {
+        ctx.viewer_context
+            .send_time_commands([TimeControlCommand::SetEventSearch(EventSearch {
+                component_path: component_path.clone(),
+                predicate: Default::default(),
+            })]);
+    }
+}
+
+fn is_numeric(ctx: &ContextMenuContext<'_>, component_path: &ComponentPath) -> bool {
+    let engine = ctx.viewer_context.recording().storage_engine();
+    let store = engine.store();
+
+    store
+        .entity_component_descriptor(component_path.entity_path(), component_path.component)
+        .and_then(|descr| descr.component_type)
+        .and_then(|component_type| store.lookup_datatype(&component_type))
+        .is_some_and(|datatype| datatype.is_numeric())
+}
@@ -1,6 +1,7 @@
 pub mod add_container;
 pub mod add_entities_to_new_view;
 pub mod add_view;
+pub mod align_entities;
 pub mod clone_view;
 pub mod collapse_expand_all;
 pub mod move_contents_to_new_container;
@@ -9,8 +10,10 @@ pub mod show_hide;
 pub mod track_entity;
 
 mod copy_entity_path;
+mod find_events;
 mod screenshot_action;
 
 pub use copy_entity_path::CopyEntityPathToClipboard;
+pub use find_events::FindEventsAction;
 pub use screenshot_action::ScreenshotAction;
 pub use track_entity::TrackEntity;
@@ -61,7 +61,7 @@ impl ContextMenuAction for TrackEntity {
     }
 }
 
-fn is_3d_view(ctx: &ContextMenuContext<'_>, view_id: &ViewId) -> bool {
+pub(super) fn is_3d_view(ctx: &ContextMenuContext<'_>, view_id: &ViewId) -> bool {
     ctx.viewport_blueprint
         .views
         .iter()
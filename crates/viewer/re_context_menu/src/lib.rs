@@ -20,10 +20,11 @@ pub mod collapse_expand;
 mod sub_menu;
 
 use actions::{
-    CopyEntityPathToClipboard, TrackEntity,
+    CopyEntityPathToClipboard, FindEventsAction, TrackEntity,
     add_container::AddContainerAction,
     add_entities_to_new_view::AddEntitiesToNewViewAction,
     add_view::AddViewAction,
+    align_entities::AlignEntitiesAction,
     clone_view::CloneViewAction,
     collapse_expand_all::CollapseExpandAllAction,
     move_contents_to_new_container::MoveContentsToNewContainerAction,
@@ -170,6 +171,7 @@ fn action_list(
                 Box::new(RemoveAction),
                 Box::new(CopyEntityPathToClipboard),
                 Box::new(TrackEntity),
+                Box::new(FindEventsAction),
             ],
             vec![
                 Box::new(actions::ScreenshotAction::CopyScreenshot),
@@ -179,6 +181,14 @@ fn action_list(
                 Box::new(CollapseExpandAllAction::ExpandAll),
                 Box::new(CollapseExpandAllAction::CollapseAll),
             ],
+            vec![
+                Box::new(AlignEntitiesAction {
+                    match_closest: false,
+                }),
+                Box::new(AlignEntitiesAction {
+                    match_closest: true,
+                }),
+            ],
             vec![Box::new(CloneViewAction)],
             vec![
                 Box::new(SubMenu {
@@ -0,0 +1,113 @@
+use re_log_types::EntityPath;
+use re_types::{archetypes, components::AnnotationContext};
+use re_ui::UiExt as _;
+use re_viewer_context::{
+    AnnotationClassOverride, AnnotationClassOverrides, AnnotationContextStoreSubscriber,
+    ViewerContext, auto_color_egui,
+};
+
+/// Lists the classes of all annotation contexts of the active recording,
+/// with controls to hide classes or override their color in all views.
+pub fn annotation_legend_ui(ctx: &ViewerContext<'_>, ui: &mut egui::Ui) {
+    re_tracing::profile_function!();
+
+    let store_id = ctx.recording().store_id();
+    let query = ctx.current_query();
+
+    let mut context_entities: Vec<EntityPath> =
+        AnnotationContextStoreSubscriber::access(store_id, |entities| {
+            entities.iter().cloned().collect()
+        })
+        .unwrap_or_default();
+    context_entities.sort();
+
+    let contexts = context_entities
+        .into_iter()
+        .filter_map(|entity_path| {
+            let (_, annotation_context) =
+                ctx.recording().latest_at_component::<AnnotationContext>(
+                    &entity_path,
+                    &query,
+                    archetypes::AnnotationContext::descriptor_context().component,
+                )?;
+            Some((entity_path, annotation_context))
+        })
+        .collect::<Vec<_>>();
+
+    if contexts.is_empty() {
+        ui.weak("No annotation context");
+        return;
+    }
+
+    let mut overrides = AnnotationClassOverrides::load(ui.ctx(), store_id);
+    let mut changed = false;
+
+    for (entity_path, annotation_context) in &contexts {
+        ui.horizontal(|ui| {
+            crate::item_ui::entity_path_button(ctx, &query, ctx.recording(), ui, None, entity_path);
+
+            let has_overrides = annotation_context.0.iter().any(|elem| {
+                overrides.get(entity_path, elem.class_id) != AnnotationClassOverride::default()
+            });
+            if has_overrides
+                && ui
+                    .small_icon_button(&re_ui::icons::RESET, "Reset")
+                    .on_hover_text("Show all classes with their logged colors")
+                    .clicked()
+            {
+                overrides.clear(entity_path);
+                changed = true;
+            }
+        });
+
+        ui.indent(("annotation_legend", entity_path), |ui| {
+            for elem in annotation_context.0.iter() {
+                let info = &elem.class_description.info;
+                let mut class_override = overrides.get(entity_path, elem.class_id);
+
+                ui.horizontal(|ui| {
+                    let mut visible = !class_override.is_hidden;
+                    if ui
+                        .visibility_toggle_button(&mut visible)
+                        .on_hover_text("Show or hide this class in all views")
+                        .changed()
+                    {
+                        class_override.is_hidden = !visible;
+                    }
+
+                    let logged_color = info
+                        .color
+                        .map_or_else(|| auto_color_egui(info.id), |color| color.into());
+                    let mut color = class_override.color.unwrap_or(logged_color);
+                    if ui
+                        .color_edit_button_srgba(&mut color)
+                        .on_hover_text("Override the color of this class in all views")
+                        .changed()
+                    {
+                        class_override.color = (color != logged_color).then_some(color);
+                    }
+
+                    let mut text = format!("{}", info.id);
+                    if let Some(label) = &info.label {
+                        text.push(' ');
+                        text.push_str(label.as_str());
+                    }
+                    if class_override.is_hidden {
+                        ui.weak(text);
+                    } else {
+                        ui.label(text);
+                    }
+                });
+
+                if class_override != overrides.get(entity_path, elem.class_id) {
+                    overrides.set(entity_path, elem.class_id, class_override);
+                    changed = true;
+                }
+            }
+        });
+    }
+
+    if changed {
+        overrides.store(ui.ctx(), store_id);
+    }
+}
@@ -1,10 +1,10 @@
 use egui::NumExt as _;
 
-use re_chunk_store::UnitChunkShared;
+use re_chunk_store::{RowId, UnitChunkShared};
 use re_entity_db::InstancePath;
 use re_log_types::{ComponentPath, EntityPath, Instance, TimeInt, TimePoint};
 use re_ui::{SyntaxHighlighting as _, UiExt as _};
-use re_viewer_context::{UiLayout, ViewerContext};
+use re_viewer_context::{SystemCommand, SystemCommandSender as _, UiLayout, ViewerContext};
 
 use super::DataUi;
 use crate::item_ui;
@@ -85,9 +85,13 @@ impl DataUi for ComponentPathLatestAtResults<'_> {
                     ))
                     .on_hover_text(
                         "When a static component is logged multiple times, only the last value \
-                        is stored. Previously logged values are overwritten and not \
-                        recoverable.",
+                        is shown. A few of the previously logged values are kept around, and can \
+                        be pinned instead of the latest one.",
                     );
+
+                    if ui_layout.is_selection_panel() {
+                        static_version_ui(ctx, ui, db, &self.component_path);
+                    }
                 }
 
                 let temporal_message_count = engine
@@ -241,3 +245,63 @@ impl DataUi for ComponentPathLatestAtResults<'_> {
         ui.sanity_check();
     }
 }
+
+/// Lets the user pin one of the previously logged values of a static component.
+fn static_version_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    db: &re_entity_db::EntityDb,
+    component_path: &ComponentPath,
+) {
+    let versions = db.static_versions(&component_path.entity_path, component_path.component);
+    if versions.len() < 2 {
+        return;
+    }
+
+    let pinned = db.pinned_static_version(&component_path.entity_path, component_path.component);
+
+    let format_version = |version: Option<RowId>| -> String {
+        let Some(version) = version else {
+            return "Latest".to_owned();
+        };
+        let index = versions.iter().position(|v| *v == version).unwrap_or(0);
+        let logged_at = i64::try_from(version.nanos_since_epoch())
+            .map(|nanos| {
+                re_log_types::Timestamp::from_nanos_since_epoch(nanos)
+                    .format(ctx.app_options().timestamp_format)
+            })
+            .unwrap_or_default();
+        format!("Version {} ({logged_at})", index + 1)
+    };
+
+    let mut selected = pinned;
+    ui.horizontal(|ui| {
+        ui.label("Static version");
+        egui::ComboBox::from_id_salt(("static_version", component_path))
+            .selected_text(format_version(selected))
+            .show_ui(ui, |ui| {
+                ui.selectable_value(&mut selected, None, format_version(None));
+                for version in versions.iter().rev() {
+                    ui.selectable_value(
+                        &mut selected,
+                        Some(*version),
+                        format_version(Some(*version)),
+                    );
+                }
+            });
+    })
+    .response
+    .on_hover_text(
+        "Pin a specific version of this static component. It stays in use even when newer \
+        values are logged.",
+    );
+
+    if selected != pinned {
+        ctx.command_sender()
+            .send_system(SystemCommand::PinStaticVersion {
+                store_id: db.store_id().clone(),
+                component_path: component_path.clone(),
+                version: selected,
+            });
+    }
+}
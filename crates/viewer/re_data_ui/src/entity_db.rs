@@ -144,6 +144,7 @@ impl crate::DataUi for EntityDb {
                     chunk_max_bytes,
                     chunk_max_rows,
                     chunk_max_rows_if_unsorted,
+                    max_static_versions: _,
                 } = self.storage_engine().store().config();
 
                 ui.grid_left_hand_label("Compaction");
@@ -203,6 +204,13 @@ impl crate::DataUi for EntityDb {
 
         match self.store_kind() {
             StoreKind::Recording => {
+                if ui_layout.is_selection_panel() {
+                    ui.add_space(8.0);
+                    ui.collapsing("Re-base timeline", |ui| {
+                        crate::timeline_rebase::rebase_timeline_ui(ctx, ui, self);
+                    });
+                }
+
                 if false {
                     // Just confusing and unnecessary to show this.
                     if store_id == hub.active_store_id() {
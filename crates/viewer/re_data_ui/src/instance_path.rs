@@ -8,7 +8,7 @@ use re_chunk_store::UnitChunkShared;
 use re_entity_db::InstancePath;
 use re_log_types::ComponentPath;
 use re_types::{
-    ArchetypeName, Component as _, ComponentDescriptor, components,
+    ArchetypeName, Component as _, ComponentDescriptor, ComponentIdentifier, components,
     reflection::ComponentDescriptorExt as _,
 };
 use re_ui::list_item::ListItemContentButtonsExt as _;
@@ -269,6 +269,81 @@ impl DataUi for InstancePath {
     }
 }
 
+/// Shows only the given components of an instance, in the given order.
+///
+/// Components are named either by their full identifier or by their field name, e.g. `velocity`.
+/// Components the entity doesn't have are skipped.
+pub fn instance_components_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    ui_layout: UiLayout,
+    query: &re_chunk_store::LatestAtQuery,
+    db: &re_entity_db::EntityDb,
+    instance_path: &InstancePath,
+    component_names: &[impl AsRef<str>],
+) {
+    let InstancePath {
+        entity_path,
+        instance,
+    } = instance_path;
+
+    let storage_engine = db.storage_engine();
+    let store = storage_engine.store();
+    let available_components = store
+        .all_components_on_timeline(&query.timeline(), entity_path)
+        .unwrap_or_default();
+
+    let descriptors = component_names
+        .iter()
+        .filter_map(|name| {
+            available_components
+                .iter()
+                .find(|component| matches_component_name(**component, name.as_ref()))
+        })
+        .unique()
+        .filter_map(|component| store.entity_component_descriptor(entity_path, *component))
+        .collect_vec();
+
+    let mut query_results = storage_engine.cache().latest_at(
+        query,
+        entity_path,
+        descriptors.iter().map(|descr| descr.component),
+    );
+    let components = descriptors
+        .into_iter()
+        .filter_map(|descr| {
+            let unit = query_results.components.remove(&descr.component)?;
+            Some((descr, unit))
+        })
+        .collect_vec();
+
+    if components.is_empty() {
+        ui_layout.label(ui, "None of the tooltip components are logged");
+        return;
+    }
+
+    // Keep the order the components were named in, rather than grouping them by archetype.
+    component_list_ui(
+        ctx,
+        ui,
+        ui_layout,
+        query,
+        db,
+        entity_path,
+        instance,
+        &BTreeMap::from([(None, components)]),
+    );
+}
+
+/// Whether the name is either the full component identifier or its field name.
+fn matches_component_name(component: ComponentIdentifier, name: &str) -> bool {
+    let component = component.as_str();
+    component == name
+        || component
+            .rsplit_once(':')
+            .is_some_and(|(_, field)| field == name)
+}
+
 #[expect(clippy::too_many_arguments)]
 fn component_list_ui(
     ctx: &ViewerContext<'_>,
@@ -11,6 +11,7 @@ use re_ui::UiExt as _;
 use re_viewer_context::{UiLayout, ViewerContext};
 
 mod annotation_context;
+mod annotation_legend;
 mod app_id;
 mod blob;
 mod component;
@@ -22,21 +23,25 @@ mod entity_db;
 mod entity_path;
 mod image;
 mod instance_path;
+mod series_alignment;
 mod store_id;
 mod tensor;
+mod timeline_rebase;
 mod video;
 
 mod extra_data_ui;
 pub mod item_ui;
 
 pub use crate::tensor::tensor_summary_ui_grid_contents;
+pub use annotation_legend::annotation_legend_ui;
 pub use component::ComponentPathLatestAtResults;
 pub use component_ui_registry::{add_to_registry, register_component_uis};
 pub use image::image_preview_ui;
-pub use instance_path::archetype_label_list_item_ui;
+pub use instance_path::{archetype_label_list_item_ui, instance_components_ui};
 use re_chunk_store::UnitChunkShared;
 use re_types_core::reflection::Reflection;
 use re_types_core::{ArchetypeName, Component};
+pub use series_alignment::{scalar_series_of_item, series_alignment_ui};
 
 pub type ArchetypeComponentMap =
     std::collections::BTreeMap<Option<ArchetypeName>, Vec<ComponentDescriptor>>;
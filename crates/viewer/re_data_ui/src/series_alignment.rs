@@ -0,0 +1,174 @@
+//! Estimating the time offset between two scalar series, e.g. to sync data captured with
+//! unsynchronized clocks.
+
+use re_chunk_store::{ChunkStoreGeneration, RangeQuery};
+use re_entity_db::TimelineRebase;
+use re_entity_db::external::re_query::{SeriesLag, estimate_lag};
+use re_log_types::{AbsoluteTimeRange, ComponentPath, TimeUnit, TimelineName};
+use re_types::archetypes::Scalars;
+use re_ui::UiExt as _;
+use re_viewer_context::{Item, SystemCommand, SystemCommandSender as _, ViewerContext};
+
+use crate::timeline_rebase::offset_unit;
+
+/// The lag of the last estimate, kept around between frames.
+///
+/// Recomputed whenever the time range or the data changes.
+#[derive(Clone)]
+struct CachedLag {
+    timeline: TimelineName,
+    time_range: AbsoluteTimeRange,
+    generation: ChunkStoreGeneration,
+    lag: Result<SeriesLag, String>,
+}
+
+/// The scalar series the item refers to, if any.
+///
+/// Entities refer to their [`Scalars`], component paths to themselves if they're numeric.
+pub fn scalar_series_of_item(ctx: &ViewerContext<'_>, item: &Item) -> Option<ComponentPath> {
+    let engine = ctx.recording().storage_engine();
+    let store = engine.store();
+
+    match item {
+        Item::ComponentPath(component_path) => store
+            .entity_component_descriptor(component_path.entity_path(), component_path.component)
+            .and_then(|descr| descr.component_type)
+            .and_then(|component_type| store.lookup_datatype(&component_type))
+            .is_some_and(|datatype| datatype.is_numeric())
+            .then(|| component_path.clone()),
+
+        Item::InstancePath(instance_path) | Item::DataResult(_, instance_path) => {
+            let component = Scalars::descriptor_scalars().component;
+            store
+                .entity_has_component(&instance_path.entity_path, component)
+                .then(|| ComponentPath::new(instance_path.entity_path.clone(), component))
+        }
+
+        _ => None,
+    }
+}
+
+/// Cross-correlates two scalar series and lets the user create a copy of the recording where the
+/// second series is shifted to line up with the first one.
+pub fn series_alignment_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    first: &ComponentPath,
+    second: &ComponentPath,
+) {
+    let timeline = *ctx.time_ctrl.timeline();
+    let time_range = if let Some(loop_selection) = ctx.time_ctrl.loop_selection() {
+        ui.weak("Comparing the series within the loop selection.");
+        loop_selection.to_int()
+    } else {
+        ui.weak(
+            "Comparing the series over the whole timeline. Select a time range to narrow it down.",
+        );
+        AbsoluteTimeRange::EVERYTHING
+    };
+
+    let state_id = ui.id().with(("series_alignment", first, second));
+    let generation = ctx.recording().generation();
+    let cached: Option<CachedLag> = ui.data_mut(|data| data.get_temp(state_id));
+    let cached = cached
+        .filter(|cached| {
+            cached.timeline == *timeline.name()
+                && cached.time_range == time_range
+                && cached.generation == generation
+        })
+        .unwrap_or_else(|| CachedLag {
+            timeline: *timeline.name(),
+            time_range,
+            generation: generation.clone(),
+            lag: series_lag(
+                ctx,
+                first,
+                second,
+                &RangeQuery::new(*timeline.name(), time_range),
+            ),
+        });
+
+    match &cached.lag {
+        Ok(lag) => {
+            let unit = offset_unit(timeline.typ());
+            let lag_native = lag.lag.round() as i64;
+
+            egui::Grid::new("series_alignment")
+                .num_columns(2)
+                .show(ui, |ui| {
+                    ui.grid_left_hand_label("Lag");
+                    let lag_text = match unit {
+                        TimeUnit::Sequence => format!("{lag_native:+}"),
+                        _ => format!("{:+.6} {}", unit.native_to_f64(lag_native), unit.suffix()),
+                    };
+                    ui.monospace(lag_text)
+                        .on_hover_text(format!("How far {second} lags behind {first}"));
+                    ui.end_row();
+
+                    ui.grid_left_hand_label("Correlation");
+                    ui.monospace(format!("{:.3}", lag.correlation))
+                        .on_hover_text("1 means the series match perfectly once aligned");
+                    ui.end_row();
+                });
+
+            let disabled_reason = if first.entity_path == second.entity_path {
+                Some("Only series of different entities can be aligned")
+            } else if lag_native == 0 {
+                Some("The series are already aligned")
+            } else {
+                None
+            };
+            if ui
+                .add_enabled(
+                    disabled_reason.is_none(),
+                    egui::Button::new("Create aligned copy"),
+                )
+                .on_hover_text(format!(
+                    "Recordings are immutable: this creates a copy of the recording where {} is \
+                    shifted to line up with {}",
+                    second.entity_path, first.entity_path
+                ))
+                .on_disabled_hover_text(disabled_reason.unwrap_or_default())
+                .clicked()
+            {
+                ctx.command_sender()
+                    .send_system(SystemCommand::RebaseTimeline {
+                        store_id: ctx.recording().store_id().clone(),
+                        timeline: *timeline.name(),
+                        rebase: TimelineRebase {
+                            offset: 0,
+                            per_source: vec![(second.entity_path.clone(), -lag_native)],
+                        },
+                    });
+            }
+        }
+        Err(err) => {
+            ui.error_label(format!("Can't align the series: {err}"));
+        }
+    }
+
+    ui.data_mut(|data| data.insert_temp(state_id, cached));
+}
+
+fn series_lag(
+    ctx: &ViewerContext<'_>,
+    first: &ComponentPath,
+    second: &ComponentPath,
+    query: &RangeQuery,
+) -> Result<SeriesLag, String> {
+    re_tracing::profile_function!();
+
+    let engine = ctx.recording().storage_engine();
+    let read_series = |path: &ComponentPath| {
+        engine
+            .cache()
+            .scalar_series(query, &path.entity_path, path.component)
+            .map_err(|err| format!("{path} isn't a scalar series: {err}"))
+    };
+
+    let first_series = read_series(first)?;
+    let second_series = read_series(second)?;
+    estimate_lag(&first_series, &second_series).ok_or_else(|| {
+        "the series need at least two samples each, and must vary where they overlap".to_owned()
+    })
+}
@@ -0,0 +1,154 @@
+//! Re-basing the timelines of a recording, e.g. to correct for drifting clocks.
+
+use arrow::array::Int64Array;
+
+use re_arrow_util::ArrowArrayDowncastRef as _;
+use re_chunk_store::LatestAtQuery;
+use re_entity_db::{EntityDb, TimelineRebase};
+use re_log_types::{EntityPath, TimeType, TimeUnit, TimelineName};
+use re_types::ClockOffset;
+use re_ui::UiExt as _;
+use re_viewer_context::{SystemCommand, SystemCommandSender as _, ViewerContext};
+
+/// What the user has entered so far, kept around between frames.
+#[derive(Clone, Default)]
+struct RebaseState {
+    timeline: Option<TimelineName>,
+
+    /// In [`offset_unit`] of the selected timeline.
+    offset: f64,
+
+    use_clock_offsets: bool,
+}
+
+/// The per-source clock offsets logged with [`ClockOffset`], in nanoseconds.
+pub fn clock_offsets(db: &EntityDb) -> Vec<(EntityPath, i64)> {
+    let Some(subtree) = db.tree().subtree(&ClockOffset::properties_path()) else {
+        return Vec::new();
+    };
+
+    let query = LatestAtQuery::latest(TimelineName::log_tick());
+    let component = ClockOffset::OFFSET_NS.into();
+
+    let mut offsets = Vec::new();
+    subtree.visit_children_recursively(|property_path| {
+        let Some(source) = ClockOffset::source_of(property_path) else {
+            return;
+        };
+
+        let offset_ns = db
+            .latest_at(&query, property_path, [component])
+            .component_batch_raw(component)
+            .and_then(|array| {
+                let array = array.as_ref().downcast_array_ref::<Int64Array>()?;
+                array.iter().next().flatten()
+            });
+
+        if let Some(offset_ns) = offset_ns {
+            offsets.push((source, offset_ns));
+        }
+    });
+    offsets
+}
+
+/// The unit the user enters offsets in.
+pub(crate) fn offset_unit(typ: TimeType) -> TimeUnit {
+    match typ {
+        TimeType::Sequence => TimeUnit::Sequence,
+        TimeType::DurationNs | TimeType::TimestampNs => TimeUnit::Secs,
+    }
+}
+
+/// Lets the user create a copy of the recording with one of its timelines shifted.
+pub fn rebase_timeline_ui(ctx: &ViewerContext<'_>, ui: &mut egui::Ui, db: &EntityDb) {
+    let timelines = db.timelines();
+    if timelines.is_empty() {
+        return;
+    }
+
+    let state_id = ui.id().with(("rebase_timeline", db.store_id()));
+    let mut state: RebaseState = ui.data_mut(|data| data.get_temp(state_id).unwrap_or_default());
+
+    let timeline = state
+        .timeline
+        .and_then(|name| timelines.get(&name).copied())
+        .or_else(|| timelines.values().next().copied());
+    let Some(timeline) = timeline else {
+        return;
+    };
+
+    let clock_offsets = clock_offsets(db);
+
+    egui::Grid::new("rebase_timeline")
+        .num_columns(2)
+        .show(ui, |ui| {
+            ui.grid_left_hand_label("Timeline");
+            egui::ComboBox::from_id_salt("rebase_timeline_name")
+                .selected_text(timeline.name().as_str())
+                .show_ui(ui, |ui| {
+                    let mut selected = *timeline.name();
+                    for name in timelines.keys() {
+                        ui.selectable_value(&mut selected, *name, name.as_str());
+                    }
+                    state.timeline = Some(selected);
+                });
+            ui.end_row();
+
+            ui.grid_left_hand_label("Offset");
+            let unit = offset_unit(timeline.typ());
+            let (speed, suffix) = match unit {
+                TimeUnit::Sequence => (1.0, String::new()),
+                _ => (0.001, format!(" {}", unit.suffix())),
+            };
+            ui.add(
+                egui::DragValue::new(&mut state.offset)
+                    .speed(speed)
+                    .suffix(suffix),
+            )
+            .on_hover_text("Added to all times of the timeline");
+            ui.end_row();
+
+            if timeline.typ() == TimeType::TimestampNs && !clock_offsets.is_empty() {
+                ui.grid_left_hand_label("Clock offsets");
+                ui.re_checkbox(
+                    &mut state.use_clock_offsets,
+                    format!("Apply {} logged clock offsets", clock_offsets.len()),
+                )
+                .on_hover_ui(|ui| {
+                    for (source, offset_ns) in &clock_offsets {
+                        ui.label(format!(
+                            "{source}: {:+.6} s",
+                            TimeUnit::Secs.native_to_f64(*offset_ns)
+                        ));
+                    }
+                });
+                ui.end_row();
+            }
+        });
+
+    let rebase = TimelineRebase {
+        offset: offset_unit(timeline.typ())
+            .checked_to_native_f64(state.offset)
+            .unwrap_or_default(),
+        per_source: if state.use_clock_offsets && timeline.typ() == TimeType::TimestampNs {
+            clock_offsets
+        } else {
+            Vec::new()
+        },
+    };
+
+    if ui
+        .add_enabled(!rebase.is_noop(), egui::Button::new("Create re-based copy"))
+        .on_hover_text("Recordings are immutable: this creates a corrected copy of the recording")
+        .clicked()
+    {
+        ctx.command_sender()
+            .send_system(SystemCommand::RebaseTimeline {
+                store_id: db.store_id().clone(),
+                timeline: *timeline.name(),
+                rebase,
+            });
+    }
+
+    ui.data_mut(|data| data.insert_temp(state_id, state));
+}
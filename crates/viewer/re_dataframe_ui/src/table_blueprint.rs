@@ -5,7 +5,7 @@ use re_viewer_context::VariantName;
 
 use crate::filters::ColumnFilter;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum SortDirection {
     Ascending,
     Descending,
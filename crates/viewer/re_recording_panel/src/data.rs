@@ -85,7 +85,8 @@ impl<'a> RecordingPanelData<'a> {
                 | SmartChannelSource::JsChannel { .. }
                 | SmartChannelSource::Sdk
                 | SmartChannelSource::Stdin
-                | SmartChannelSource::MessageProxy(_) => {}
+                | SmartChannelSource::MessageProxy(_)
+                | SmartChannelSource::McapWebSocket { .. } => {}
             }
         }
 
@@ -9,21 +9,30 @@
 //! To the user, it will look like business as usual.
 //!
 //! See `re_renderer/src/workspace_shaders.rs` for the end result.
+//!
+//! It also parses and validates all shaders with naga, so that shader errors fail the build with
+//! a `file:line:column` diagnostic instead of only showing up at pipeline creation.
 
 // TODO(cmc): this should only run for release builds
 
 #![allow(clippy::allow_attributes, clippy::disallowed_types)] // False positives for using files on Wasm
 #![expect(clippy::unwrap_used)]
 
+use std::collections::HashSet;
+use std::fmt::Write as _;
 use std::path::{Path, PathBuf};
 
 use anyhow::{Context as _, bail, ensure};
+use rayon::prelude::*;
 use walkdir::{DirEntry, WalkDir};
 
 use re_build_tools::{
     Environment, get_and_track_env_var, rerun_if_changed, write_file_if_necessary,
 };
 
+#[path = "src/shader_preprocessor.rs"]
+mod shader_preprocessor;
+
 // ---
 
 /// A pre-parsed import clause, as in `#import <something>`.
@@ -103,6 +112,175 @@ fn check_hermeticity(root_path: impl AsRef<Path>, file_path: impl AsRef<Path>) {
 
 // ---
 
+/// A shader with all its imports interpolated, along with where each of its lines came from.
+struct InterpolatedShader {
+    contents: String,
+
+    /// The original file and 1-based line number of each line of `contents`.
+    line_origins: Vec<(PathBuf, usize)>,
+}
+
+impl InterpolatedShader {
+    /// Interpolates all imports of the given shader, following the `#pragma once` semantics of
+    /// `src/file_resolver.rs`.
+    fn load(path: &Path) -> anyhow::Result<Self> {
+        fn load_rec(
+            path: &Path,
+            shader: &mut InterpolatedShader,
+            imported: &mut HashSet<PathBuf>,
+            path_stack: &mut Vec<PathBuf>,
+        ) -> anyhow::Result<()> {
+            ensure!(
+                !path_stack.iter().any(|p| p == path),
+                "import cycle detected: {path_stack:?}"
+            );
+            if !imported.insert(path.to_owned()) {
+                return Ok(());
+            }
+
+            let contents =
+                std::fs::read_to_string(path).with_context(|| format!("couldn't read {path:?}"))?;
+
+            path_stack.push(path.to_owned());
+            for (line_idx, line) in contents.lines().enumerate() {
+                if line.trim().starts_with(ImportClause::PREFIX) {
+                    let clause = line
+                        .parse::<ImportClause>()
+                        .with_context(|| format!("{}:{}", path.display(), line_idx + 1))?;
+                    let clause_path = path.parent().unwrap().join(&clause.path);
+                    let clause_path = std::fs::canonicalize(&clause_path).with_context(|| {
+                        format!(
+                            "{}:{}: couldn't resolve import clause path at {:?}",
+                            path.display(),
+                            line_idx + 1,
+                            clause.path
+                        )
+                    })?;
+                    load_rec(&clause_path, shader, imported, path_stack)?;
+                } else {
+                    shader.contents += line;
+                    shader.contents.push('\n');
+                    shader.line_origins.push((path.to_owned(), line_idx + 1));
+                }
+            }
+            path_stack.pop();
+
+            Ok(())
+        }
+
+        let mut shader = Self {
+            contents: String::new(),
+            line_origins: Vec::new(),
+        };
+        load_rec(path, &mut shader, &mut HashSet::new(), &mut Vec::new())?;
+
+        Ok(shader)
+    }
+
+    /// Maps a 1-based line number of the interpolated shader back to its original file and line.
+    fn origin(&self, line_number: u32) -> Option<(&Path, usize)> {
+        let (path, line) = self
+            .line_origins
+            .get(usize::try_from(line_number).ok()?.checked_sub(1)?)?;
+        Some((path.as_path(), *line))
+    }
+
+    /// Formats an error at the given span as `file:line:column: message`.
+    fn diagnostic(
+        &self,
+        root_path: &Path,
+        location: Option<naga::SourceLocation>,
+        message: &str,
+    ) -> String {
+        match location.and_then(|location| {
+            self.origin(location.line_number)
+                .map(|origin| (origin, location.line_position))
+        }) {
+            Some(((path, line), column)) => {
+                format!("{}:{line}:{column}: {message}", path.display())
+            }
+            None => format!("{}: {message}", root_path.display()),
+        }
+    }
+}
+
+/// Parses and validates the given shader with naga, the same way `wgpu` does when creating a
+/// shader module, so that errors are caught at build time rather than at pipeline creation.
+///
+/// Returns one diagnostic per error, pointing at the original file & line.
+fn validate_shader(path: &Path) -> Vec<String> {
+    let shader = match InterpolatedShader::load(path) {
+        Ok(shader) => shader,
+        Err(err) => return vec![format!("{err:#}")],
+    };
+
+    // Only the default permutation of each shader can be validated here, i.e. without any defines
+    // being passed in. The preprocessor keeps line numbers intact.
+    let contents = match shader_preprocessor::preprocess_shader(&shader.contents, &[]) {
+        Ok(contents) => contents,
+        Err(err) => return vec![format!("{}: {err:#}", path.display())],
+    };
+
+    let module = match naga::front::wgsl::parse_str(&contents) {
+        Ok(module) => module,
+        Err(err) => {
+            return vec![shader.diagnostic(path, err.location(&contents), err.message())];
+        }
+    };
+
+    let mut validator = naga::valid::Validator::new(
+        naga::valid::ValidationFlags::all(),
+        naga::valid::Capabilities::all(),
+    );
+    match validator.validate(&module) {
+        Ok(_) => Vec::new(),
+        Err(err) => {
+            let mut message = err.as_inner().to_string();
+            let mut source = std::error::Error::source(err.as_inner());
+            while let Some(err) = source {
+                write!(message, ": {err}").unwrap();
+                source = err.source();
+            }
+            vec![shader.diagnostic(path, err.location(&contents), &message)]
+        }
+    }
+}
+
+/// Validates all shaders that aren't imported by any other shader, in parallel.
+///
+/// Shaders that are only ever imported are validated as part of their importers.
+/// Panics with a list of all errors if any of the shaders is invalid.
+fn validate_shaders(shader_paths: &[PathBuf]) {
+    let imported_paths = shader_paths
+        .iter()
+        .flat_map(|path| {
+            let dir_path = path.parent().unwrap();
+            std::fs::read_to_string(path)
+                .unwrap()
+                .lines()
+                .filter(|line| line.trim().starts_with(ImportClause::PREFIX))
+                .filter_map(|line| line.parse::<ImportClause>().ok())
+                .filter_map(|clause| std::fs::canonicalize(dir_path.join(clause.path)).ok())
+                .collect::<Vec<_>>()
+        })
+        .collect::<HashSet<_>>();
+
+    let diagnostics = shader_paths
+        .par_iter()
+        .filter(|path| !imported_paths.contains(*path))
+        .flat_map_iter(|path| validate_shader(path))
+        .collect::<Vec<_>>();
+
+    assert!(
+        diagnostics.is_empty(),
+        "re_renderer build.rs found {} invalid shader(s):\n{}",
+        diagnostics.len(),
+        diagnostics.join("\n")
+    );
+}
+
+// ---
+
 fn should_run(environment: Environment) -> bool {
     #![expect(clippy::match_same_arms)]
 
@@ -119,6 +297,16 @@ fn should_run(environment: Environment) -> bool {
     }
 }
 
+fn should_validate_shaders(environment: Environment) -> bool {
+    match environment {
+        Environment::DeveloperInWorkspace | Environment::RerunCI => true,
+
+        Environment::PublishingCrates | Environment::CondaBuild | Environment::UsedAsDependency => {
+            false
+        }
+    }
+}
+
 fn main() {
     let environment = Environment::detect();
     let is_release = cfg!(not(debug_assertions)); // This works
@@ -137,7 +325,7 @@ fn main() {
         println!("cargo:rustc-cfg=load_shaders_from_disk");
     }
 
-    if !should_run(environment) {
+    if !should_run(environment) && !should_validate_shaders(environment) {
         return;
     }
 
@@ -169,6 +357,34 @@ fn main() {
         is_dir || is_wgsl
     }
 
+    let walker = WalkDir::new(shader_dir).into_iter();
+    let entries = {
+        let mut entries = walker
+            .filter_entry(is_wgsl_or_dir)
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_type().is_file())
+            .collect::<Vec<_>>();
+        entries.sort_by(|a, b| a.path().cmp(b.path()));
+        entries
+    };
+
+    assert!(
+        !entries.is_empty(),
+        "re_renderer build.rs found no shaders - I think some path is wrong!"
+    );
+
+    if should_validate_shaders(environment) {
+        let shader_paths = entries
+            .iter()
+            .map(|entry| entry.path().to_owned())
+            .collect::<Vec<_>>();
+        validate_shaders(&shader_paths);
+    }
+
+    if !should_run(environment) {
+        return;
+    }
+
     // We do our best to generate code that passes rustfmt, even though we also
     // add `#[rustfmt::skip]` to the whole module.
 
@@ -189,22 +405,6 @@ pub fn init() {
 "#
     .to_owned();
 
-    let walker = WalkDir::new(shader_dir).into_iter();
-    let entries = {
-        let mut entries = walker
-            .filter_entry(is_wgsl_or_dir)
-            .filter_map(|entry| entry.ok())
-            .filter(|entry| entry.file_type().is_file())
-            .collect::<Vec<_>>();
-        entries.sort_by(|a, b| a.path().cmp(b.path()));
-        entries
-    };
-
-    assert!(
-        !entries.is_empty(),
-        "re_renderer build.rs found no shaders - I think some path is wrong!"
-    );
-
     for entry in entries {
         rerun_if_changed(entry.path());
 
@@ -0,0 +1,196 @@
+#import <../types.wgsl>
+#import <../screen_triangle_vertex.wgsl>
+
+struct UniformBuffer {
+    grid_min: vec3f,
+    cell_size: f32,
+    grid_size: vec3u,
+    num_points: u32,
+    viewpoint: vec3f,
+    _padding: f32,
+};
+
+@group(0) @binding(0)
+var<uniform> uniform_buffer: UniformBuffer;
+
+/// Point positions in the order they were passed in.
+@group(0) @binding(1)
+var positions_texture: texture_2d<f32>;
+
+/// Point positions sorted by grid cell.
+@group(0) @binding(2)
+var sorted_positions_texture: texture_2d<f32>;
+
+/// For every grid cell the (start, count) range into `sorted_positions_texture`.
+@group(0) @binding(3)
+var cells_texture: texture_2d<u32>;
+
+/// Maximum number of neighbors used for the plane fit.
+///
+/// Keep in sync with the documentation of `PointCloudNormalEstimationTask`.
+const MAX_NEIGHBORS: u32 = 16u;
+
+fn load_from_data_texture_f32(tex: texture_2d<f32>, idx: u32) -> vec4f {
+    let size = textureDimensions(tex);
+    return textureLoad(tex, vec2u(idx % size.x, idx / size.x), 0);
+}
+
+fn load_cell(cell_idx: u32) -> vec2u {
+    let size = textureDimensions(cells_texture);
+    return textureLoad(cells_texture, vec2u(cell_idx % size.x, cell_idx / size.x), 0).xy;
+}
+
+/// Eigenvector of the smallest eigenvalue of a symmetric 3x3 matrix.
+///
+/// Eigenvalues are computed analytically, the eigenvector is then the most stable cross product
+/// of two rows of `(A - λI)`.
+/// Returns the eigenvector in `xyz` and the surface variation `λ0 / (λ0 + λ1 + λ2)` in `w`.
+fn smallest_eigenvector(a00: f32, a01: f32, a02: f32, a11: f32, a12: f32, a22: f32) -> vec4f {
+    let p1 = a01 * a01 + a02 * a02 + a12 * a12;
+    let trace = a00 + a11 + a22;
+    let q = trace / 3.0;
+
+    var smallest_eigenvalue: f32;
+    if p1 < 1e-20 {
+        // Diagonal matrix.
+        smallest_eigenvalue = min(a00, min(a11, a22));
+    } else {
+        let p2 = (a00 - q) * (a00 - q) + (a11 - q) * (a11 - q) + (a22 - q) * (a22 - q) + 2.0 * p1;
+        let p = sqrt(p2 / 6.0);
+        let b00 = (a00 - q) / p;
+        let b11 = (a11 - q) / p;
+        let b22 = (a22 - q) / p;
+        let b01 = a01 / p;
+        let b02 = a02 / p;
+        let b12 = a12 / p;
+        let det_b = b00 * (b11 * b22 - b12 * b12) - b01 * (b01 * b22 - b12 * b02) + b02 * (b01 * b12 - b11 * b02);
+        let r = clamp(det_b * 0.5, -1.0, 1.0);
+        let phi = acos(r) / 3.0;
+        // Eigenvalues satisfy eig1 >= eig2 >= eig3, we're after eig3.
+        smallest_eigenvalue = q + 2.0 * p * cos(phi + (2.0 * 3.14159265 / 3.0));
+    }
+
+    let row0 = vec3f(a00 - smallest_eigenvalue, a01, a02);
+    let row1 = vec3f(a01, a11 - smallest_eigenvalue, a12);
+    let row2 = vec3f(a02, a12, a22 - smallest_eigenvalue);
+
+    let c01 = cross(row0, row1);
+    let c02 = cross(row0, row2);
+    let c12 = cross(row1, row2);
+    let d01 = dot(c01, c01);
+    let d02 = dot(c02, c02);
+    let d12 = dot(c12, c12);
+
+    var normal: vec3f;
+    var max_d = d01;
+    normal = c01;
+    if d02 > max_d {
+        max_d = d02;
+        normal = c02;
+    }
+    if d12 > max_d {
+        max_d = d12;
+        normal = c12;
+    }
+    if max_d < 1e-30 {
+        // Degenerate (e.g. all neighbors on a line or a single point), there's no well defined normal.
+        return vec4f(0.0);
+    }
+
+    let surface_variation = select(0.0, max(smallest_eigenvalue, 0.0) / trace, trace > 0.0);
+    return vec4f(normal * inverseSqrt(max_d), surface_variation);
+}
+
+@fragment
+fn fs_main(in: FragmentInput) -> @location(0) vec4f {
+    let output_size = textureDimensions(positions_texture);
+    let coords = vec2u(in.texcoord * vec2f(output_size));
+    let point_idx = coords.x + coords.y * output_size.x;
+    if point_idx >= uniform_buffer.num_points {
+        return vec4f(0.0);
+    }
+
+    let position = load_from_data_texture_f32(positions_texture, point_idx).xyz;
+
+    // NaN & Inf positions were never sorted into the grid and have no meaningful normal.
+    // (checking the exponent bits since comparisons with NaN may be optimized away)
+    let exponent_mask = vec3u(0x7f800000u);
+    if any((bitcast<vec3u>(position) & exponent_mask) == exponent_mask) {
+        return vec4f(0.0);
+    }
+
+    // Points outside of the grid can't happen for finite positions, but clamp anyways to stay clear of integer overflows.
+    let cell_float = floor((position - uniform_buffer.grid_min) / uniform_buffer.cell_size);
+    let cell = vec3i(clamp(cell_float, vec3f(-1.0), vec3f(uniform_buffer.grid_size)));
+    let grid_size = vec3i(uniform_buffer.grid_size);
+
+    // Keep the closest `MAX_NEIGHBORS` points, sorted by distance, via insertion sort.
+    var neighbor_positions: array<vec3f, MAX_NEIGHBORS>;
+    var neighbor_distances: array<f32, MAX_NEIGHBORS>;
+    var num_neighbors = 0u;
+
+    for (var z = max(cell.z - 1, 0); z <= min(cell.z + 1, grid_size.z - 1); z += 1) {
+        for (var y = max(cell.y - 1, 0); y <= min(cell.y + 1, grid_size.y - 1); y += 1) {
+            for (var x = max(cell.x - 1, 0); x <= min(cell.x + 1, grid_size.x - 1); x += 1) {
+                let cell_idx = u32(x) + u32(y) * uniform_buffer.grid_size.x + u32(z) * uniform_buffer.grid_size.x * uniform_buffer.grid_size.y;
+                let range = load_cell(cell_idx);
+
+                for (var i = range.x; i < range.x + range.y; i += 1u) {
+                    let neighbor = load_from_data_texture_f32(sorted_positions_texture, i).xyz;
+                    let to_neighbor = neighbor - position;
+                    let distance_sq = dot(to_neighbor, to_neighbor);
+
+                    if num_neighbors == MAX_NEIGHBORS && distance_sq >= neighbor_distances[MAX_NEIGHBORS - 1u] {
+                        continue;
+                    }
+
+                    var insert_at = min(num_neighbors, MAX_NEIGHBORS - 1u);
+                    while insert_at > 0u && neighbor_distances[insert_at - 1u] > distance_sq {
+                        neighbor_distances[insert_at] = neighbor_distances[insert_at - 1u];
+                        neighbor_positions[insert_at] = neighbor_positions[insert_at - 1u];
+                        insert_at -= 1u;
+                    }
+                    neighbor_distances[insert_at] = distance_sq;
+                    neighbor_positions[insert_at] = neighbor;
+                    num_neighbors = min(num_neighbors + 1u, MAX_NEIGHBORS);
+                }
+            }
+        }
+    }
+
+    if num_neighbors < 3u {
+        return vec4f(0.0);
+    }
+
+    // Plane fit: the normal is the direction of least variance of the neighborhood.
+    var centroid = vec3f(0.0);
+    for (var i = 0u; i < num_neighbors; i += 1u) {
+        centroid += neighbor_positions[i];
+    }
+    centroid /= f32(num_neighbors);
+
+    var a00 = 0.0;
+    var a01 = 0.0;
+    var a02 = 0.0;
+    var a11 = 0.0;
+    var a12 = 0.0;
+    var a22 = 0.0;
+    for (var i = 0u; i < num_neighbors; i += 1u) {
+        let d = neighbor_positions[i] - centroid;
+        a00 += d.x * d.x;
+        a01 += d.x * d.y;
+        a02 += d.x * d.z;
+        a11 += d.y * d.y;
+        a12 += d.y * d.z;
+        a22 += d.z * d.z;
+    }
+
+    var result = smallest_eigenvector(a00, a01, a02, a11, a12, a22);
+
+    // Normals have no inherent orientation, flip them towards the viewpoint.
+    if dot(result.xyz, uniform_buffer.viewpoint - position) < 0.0 {
+        result = vec4f(-result.xyz, result.w);
+    }
+
+    return result;
+}
@@ -20,6 +20,12 @@ const SAMPLE_TYPE_FLOAT = 1u;
 const SAMPLE_TYPE_SINT  = 2u;
 const SAMPLE_TYPE_UINT  = 3u;
 
+// How to apply the crop box?
+// Keep in sync with `point_cloud.wgsl`.
+const CROP_MODE_NONE: u32 = 0u;
+const CROP_MODE_KEEP_INSIDE: u32 = 1u;
+const CROP_MODE_KEEP_OUTSIDE: u32 = 2u;
+
 /// Same for all draw-phases.
 struct DepthCloudInfo {
     /// The extrinsincs of the camera used for the projection.
@@ -53,6 +59,19 @@ struct DepthCloudInfo {
 
     /// Changes between the opaque and outline draw-phases.
     radius_boost_in_ui_points: f32,
+
+    /// Only every n-th texel in each direction is turned into a point.
+    subsampling: u32,
+
+    /// Points with a world-space depth outside of this range are not drawn.
+    valid_depth_range_in_world: vec2f,
+
+    /// One of `CROP_MODE_*`.
+    crop_mode: u32,
+
+    /// The crop box in world-space.
+    crop_box_min: vec3f,
+    crop_box_max: vec3f,
 };
 
 @group(1) @binding(0)
@@ -96,20 +115,30 @@ struct PointData {
 }
 
 // Backprojects the depth texture using the intrinsics passed in the uniform buffer.
-fn compute_point_data(quad_idx: u32) -> PointData {
-    var texcoords: vec2u;
+//
+// `point_idx` enumerates the (subsampled) points, `texel_idx` is set to the index of the texel
+// the point was read from.
+fn compute_point_data(point_idx: u32, texel_idx: ptr<function, u32>) -> PointData {
+    var wh: vec2u;
+    if depth_cloud_info.sample_type == SAMPLE_TYPE_FLOAT {
+        wh = textureDimensions(texture_float);
+    } else if depth_cloud_info.sample_type == SAMPLE_TYPE_SINT {
+        wh = textureDimensions(texture_sint);
+    } else {
+        wh = textureDimensions(texture_uint);
+    }
+
+    let subsampling = depth_cloud_info.subsampling;
+    let num_columns = (wh.x + subsampling - 1u) / subsampling;
+    let texcoords = vec2u(point_idx % num_columns, point_idx / num_columns) * subsampling;
+    *texel_idx = texcoords.y * wh.x + texcoords.x;
+
     var texture_value = 0.0;
     if depth_cloud_info.sample_type == SAMPLE_TYPE_FLOAT {
-        let wh = textureDimensions(texture_float);
-        texcoords = vec2u(quad_idx % wh.x, quad_idx / wh.x);
         texture_value = textureLoad(texture_float, texcoords, 0).x;
     } else if depth_cloud_info.sample_type == SAMPLE_TYPE_SINT {
-        let wh = textureDimensions(texture_sint);
-        texcoords = vec2u(quad_idx % wh.x, quad_idx / wh.x);
         texture_value = f32(textureLoad(texture_sint, texcoords, 0).x);
     } else {
-        let wh = textureDimensions(texture_uint);
-        texcoords = vec2u(quad_idx % wh.x, quad_idx / wh.x);
         texture_value = f32(textureLoad(texture_uint, texcoords, 0).x);
     }
 
@@ -118,7 +147,9 @@ fn compute_point_data(quad_idx: u32) -> PointData {
 
     var data: PointData;
 
-    if 0.0 < world_space_depth && world_space_depth < f32max {
+    let valid_range = depth_cloud_info.valid_depth_range_in_world;
+    if 0.0 < world_space_depth && world_space_depth < f32max &&
+       valid_range.x <= world_space_depth && world_space_depth <= valid_range.y {
         // TODO(cmc): albedo textures
         let normalized_depth =
             (world_space_depth - depth_cloud_info.min_max_depth_in_world.x) /
@@ -150,19 +181,30 @@ fn compute_point_data(quad_idx: u32) -> PointData {
     return data;
 }
 
+fn is_cropped(pos_in_world: vec3f) -> bool {
+    if depth_cloud_info.crop_mode == CROP_MODE_NONE {
+        return false;
+    }
+    let is_inside = all(depth_cloud_info.crop_box_min <= pos_in_world) &&
+                    all(pos_in_world <= depth_cloud_info.crop_box_max);
+    return is_inside == (depth_cloud_info.crop_mode == CROP_MODE_KEEP_OUTSIDE);
+}
+
 @vertex
 fn vs_main(@builtin(vertex_index) vertex_idx: u32) -> VertexOut {
     let quad_idx = sphere_quad_index(vertex_idx);
 
     // Compute point data (valid for the entire quad).
-    let point_data = compute_point_data(quad_idx);
+    var texel_idx = 0u;
+    let point_data = compute_point_data(quad_idx, &texel_idx);
 
     var out: VertexOut;
     out.point_pos_in_world = point_data.pos_in_world;
     out.point_color = point_data.color;
-    out.quad_idx = u32(quad_idx);
+    // Picking reports the texel, so that hovering works the same regardless of subsampling.
+    out.quad_idx = texel_idx;
 
-    if 0.0 < point_data.unresolved_radius {
+    if 0.0 < point_data.unresolved_radius && !is_cropped(point_data.pos_in_world) {
         // Span quad
         let camera_distance = distance(frame.camera_position, point_data.pos_in_world);
         let world_scale_factor = average_scale_from_transform(depth_cloud_info.world_from_rdf); // TODO(andreas): somewhat costly, should precompute this
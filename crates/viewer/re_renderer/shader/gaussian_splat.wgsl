@@ -0,0 +1,241 @@
+#import <./global_bindings.wgsl>
+#import <./types.wgsl>
+#import <./utils/camera.wgsl>
+#import <./utils/srgb.wgsl>
+
+@group(1) @binding(0)
+var center_texture: texture_2d<f32>;
+/// Two texels per splat: (xx, xy, xz, yy) and (yz, zz, -, -) of the object space covariance.
+@group(1) @binding(1)
+var covariance_texture: texture_2d<f32>;
+/// Zeroth degree of the spherical harmonics, in gamma space with separate alpha.
+@group(1) @binding(2)
+var color_texture: texture_2d<f32>;
+@group(1) @binding(3)
+var picking_instance_id_texture: texture_2d<u32>;
+
+struct BatchUniformBuffer {
+    world_from_obj: mat4x4f,
+    outline_mask: vec2u,
+    picking_layer_object_id: vec2u,
+    obj_from_world: mat3x3f,
+    first_splat_index: u32,
+    num_sh_coefficients: u32,
+};
+@group(2) @binding(0)
+var<uniform> batch: BatchUniformBuffer;
+
+/// `batch.num_sh_coefficients` RGB coefficients per splat of the spherical harmonics above degree zero.
+@group(2) @binding(1)
+var sh_texture: texture_2d<f32>;
+
+/// Splats are cut off at this many standard deviations.
+const CUTOFF_SIGMA: f32 = 3.0;
+
+/// Low-pass filter in pixels², making sure every splat covers at least about one pixel.
+const LOW_PASS_FILTER_PX2: f32 = 0.3;
+
+/// Below this alpha fragments are discarded.
+const MIN_ALPHA: f32 = 1.0 / 255.0;
+
+struct VertexOut {
+    @builtin(position)
+    position: vec4f,
+
+    /// Position within the quad in units of standard deviations along the splat's screen space axes.
+    @location(0) @interpolate(perspective)
+    pos_in_sigma: vec2f,
+
+    @location(1) @interpolate(flat)
+    color: vec4f, // linear RGBA with unmulitplied/separate alpha
+
+    @location(2) @interpolate(flat)
+    picking_instance_id: vec2u,
+};
+
+fn load_texel_f32(tex: texture_2d<f32>, idx: u32) -> vec4f {
+    let size = textureDimensions(tex);
+    return textureLoad(tex, vec2u(idx % size.x, idx / size.x), 0);
+}
+
+fn load_covariance(splat_idx: u32) -> mat3x3f {
+    let a = load_texel_f32(covariance_texture, splat_idx * 2u);
+    let b = load_texel_f32(covariance_texture, splat_idx * 2u + 1u);
+    return mat3x3f(
+        vec3f(a.x, a.y, a.z),
+        vec3f(a.y, a.w, b.x),
+        vec3f(a.z, b.x, b.y),
+    );
+}
+
+fn load_sh_coefficient(splat_idx: u32, coefficient_idx: u32) -> vec3f {
+    let first_texel = (splat_idx - batch.first_splat_index) * batch.num_sh_coefficients;
+    return load_texel_f32(sh_texture, first_texel + coefficient_idx).rgb;
+}
+
+// Normalization constants of the real spherical harmonics, in the same order as the coefficients.
+const SH_C1: f32 = 0.4886025119029199;
+const SH_C2 = array<f32, 5>(1.0925484305920792, -1.0925484305920792, 0.31539156525252005, -1.0925484305920792, 0.5462742152960396);
+const SH_C3 = array<f32, 7>(-0.5900435899266435, 2.890611442640554, -0.4570457994644658, 0.3731763325901154, -0.4570457994644658, 1.445305721320277, -0.5900435899266435);
+
+/// Evaluates the spherical harmonics above degree zero for a normalized object space direction.
+fn view_dependent_color(splat_idx: u32, dir: vec3f) -> vec3f {
+    var color = vec3f(0.0);
+    if batch.num_sh_coefficients < 3u {
+        return color;
+    }
+    let x = dir.x;
+    let y = dir.y;
+    let z = dir.z;
+    color += SH_C1 * (-y * load_sh_coefficient(splat_idx, 0u) +
+                       z * load_sh_coefficient(splat_idx, 1u) -
+                       x * load_sh_coefficient(splat_idx, 2u));
+
+    if batch.num_sh_coefficients < 8u {
+        return color;
+    }
+    let xx = x * x;
+    let yy = y * y;
+    let zz = z * z;
+    color += SH_C2[0] * x * y * load_sh_coefficient(splat_idx, 3u) +
+             SH_C2[1] * y * z * load_sh_coefficient(splat_idx, 4u) +
+             SH_C2[2] * (2.0 * zz - xx - yy) * load_sh_coefficient(splat_idx, 5u) +
+             SH_C2[3] * x * z * load_sh_coefficient(splat_idx, 6u) +
+             SH_C2[4] * (xx - yy) * load_sh_coefficient(splat_idx, 7u);
+
+    if batch.num_sh_coefficients < 15u {
+        return color;
+    }
+    color += SH_C3[0] * y * (3.0 * xx - yy) * load_sh_coefficient(splat_idx, 8u) +
+             SH_C3[1] * x * y * z * load_sh_coefficient(splat_idx, 9u) +
+             SH_C3[2] * y * (4.0 * zz - xx - yy) * load_sh_coefficient(splat_idx, 10u) +
+             SH_C3[3] * z * (2.0 * zz - 3.0 * xx - 3.0 * yy) * load_sh_coefficient(splat_idx, 11u) +
+             SH_C3[4] * x * (4.0 * zz - xx - yy) * load_sh_coefficient(splat_idx, 12u) +
+             SH_C3[5] * z * (xx - yy) * load_sh_coefficient(splat_idx, 13u) +
+             SH_C3[6] * x * (xx - 3.0 * yy) * load_sh_coefficient(splat_idx, 14u);
+    return color;
+}
+
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_idx: u32) -> VertexOut {
+    let splat_idx = vertex_idx / 6u;
+    let local_idx = vertex_idx % 6u;
+    let top_bottom = f32(local_idx <= 1u || local_idx == 5u) * 2.0 - 1.0; // 1 for a top vertex, -1 for a bottom vertex.
+    let left_right = f32(vertex_idx % 2u) * 2.0 - 1.0; // 1 for a right vertex, -1 for a left vertex.
+
+    var out: VertexOut;
+
+    let center_obj = load_texel_f32(center_texture, splat_idx).xyz;
+    let center_world = (batch.world_from_obj * vec4f(center_obj, 1.0)).xyz;
+    let center_view = frame.view_from_world * vec4f(center_world, 1.0);
+
+    // Project the 3D covariance into pixel space (EWA splatting, Zwicker et al. 2001):
+    // Σ' = J W Σ Wᵀ Jᵀ with W being the linear part of view_from_obj and J the Jacobian of the projection.
+    let world_from_obj = mat3x3f(batch.world_from_obj[0].xyz, batch.world_from_obj[1].xyz, batch.world_from_obj[2].xyz);
+    let view_from_world = mat3x3f(frame.view_from_world[0], frame.view_from_world[1], frame.view_from_world[2]);
+    let view_from_obj = view_from_world * world_from_obj;
+    let covariance_view = view_from_obj * load_covariance(splat_idx) * transpose(view_from_obj);
+
+    var jacobian: mat3x3f;
+    if is_camera_orthographic() {
+        let px_from_view = 1.0 / frame.pixel_world_size_from_camera_distance;
+        jacobian = mat3x3f(
+            vec3f(px_from_view, 0.0, 0.0),
+            vec3f(0.0, px_from_view, 0.0),
+            vec3f(0.0, 0.0, 0.0),
+        );
+    } else {
+        // Negative z since z dir is towards viewer (by current RUB convention).
+        let depth = -center_view.z;
+        if depth <= 0.0 {
+            // Behind the camera, emit a degenerate triangle.
+            out.position = vec4f(0.0);
+            return out;
+        }
+        let px_from_view = 1.0 / (frame.pixel_world_size_from_camera_distance * depth);
+        jacobian = mat3x3f(
+            vec3f(px_from_view, 0.0, 0.0),
+            vec3f(0.0, px_from_view, 0.0),
+            vec3f(px_from_view * center_view.x / depth, px_from_view * center_view.y / depth, 0.0),
+        );
+    }
+    let covariance_3d_px = jacobian * covariance_view * transpose(jacobian);
+    let cov_xx = covariance_3d_px[0][0] + LOW_PASS_FILTER_PX2;
+    let cov_xy = covariance_3d_px[0][1];
+    let cov_yy = covariance_3d_px[1][1] + LOW_PASS_FILTER_PX2;
+
+    // Eigen decomposition of the symmetric 2x2 pixel space covariance.
+    let mid = 0.5 * (cov_xx + cov_yy);
+    let det = cov_xx * cov_yy - cov_xy * cov_xy;
+    let discriminant = sqrt(max(mid * mid - det, 0.0));
+    let eigenvalue_major = mid + discriminant;
+    let eigenvalue_minor = max(mid - discriminant, LOW_PASS_FILTER_PX2);
+    var axis_major = vec2f(1.0, 0.0);
+    if abs(cov_xy) > 1e-12 {
+        axis_major = normalize(vec2f(cov_xy, eigenvalue_major - cov_xx));
+    } else if cov_yy > cov_xx {
+        axis_major = vec2f(0.0, 1.0);
+    }
+    let axis_minor = vec2f(-axis_major.y, axis_major.x);
+
+    // Span the quad along the eigenvectors, out to `CUTOFF_SIGMA` standard deviations.
+    let pos_in_sigma = vec2f(left_right, top_bottom) * CUTOFF_SIGMA;
+    let offset_px = pos_in_sigma.x * sqrt(eigenvalue_major) * axis_major +
+                    pos_in_sigma.y * sqrt(eigenvalue_minor) * axis_minor;
+
+    let center_clip = frame.projection_from_world * vec4f(center_world, 1.0);
+    let ndc_from_px = frame.pixel_world_size_from_camera_distance *
+                      vec2f(frame.projection_from_view[0][0], frame.projection_from_view[1][1]);
+
+    out.position = center_clip + vec4f(offset_px * ndc_from_px * center_clip.w, 0.0, 0.0);
+    out.pos_in_sigma = pos_in_sigma;
+
+    // The color depends on the direction the splat is viewed from, like in the original Gaussian splatting paper.
+    var view_dir_world = frame.camera_forward;
+    if is_camera_perspective() {
+        view_dir_world = center_world - frame.camera_position;
+    }
+    let view_dir_obj = normalize(batch.obj_from_world * view_dir_world);
+    let color = load_texel_f32(color_texture, splat_idx);
+    let color_gamma = clamp(color.rgb + view_dependent_color(splat_idx, view_dir_obj), vec3f(0.0), vec3f(1.0));
+    out.color = vec4f(linear_from_srgb(color_gamma), color.a);
+    let picking_instance_id_texture_size = textureDimensions(picking_instance_id_texture);
+    out.picking_instance_id = textureLoad(picking_instance_id_texture,
+        vec2u(splat_idx % picking_instance_id_texture_size.x, splat_idx / picking_instance_id_texture_size.x), 0).xy;
+
+    return out;
+}
+
+fn gaussian_alpha(in: VertexOut) -> f32 {
+    let sigma_sq = dot(in.pos_in_sigma, in.pos_in_sigma);
+    if sigma_sq > CUTOFF_SIGMA * CUTOFF_SIGMA {
+        return 0.0;
+    }
+    return in.color.a * exp(-0.5 * sigma_sq);
+}
+
+@fragment
+fn fs_main(in: VertexOut) -> @location(0) vec4f {
+    let alpha = gaussian_alpha(in);
+    if alpha < MIN_ALPHA {
+        discard;
+    }
+    // Premultiplied alpha blending.
+    return vec4f(in.color.rgb * alpha, alpha);
+}
+
+@fragment
+fn fs_main_picking_layer(in: VertexOut) -> @location(0) vec4u {
+    if gaussian_alpha(in) <= 0.5 {
+        discard;
+    }
+    return vec4u(batch.picking_layer_object_id, in.picking_instance_id);
+}
+
+@fragment
+fn fs_main_outline_mask(in: VertexOut) -> @location(0) vec2u {
+    if gaussian_alpha(in) <= 0.5 {
+        discard;
+    }
+    return batch.outline_mask;
+}
@@ -32,6 +32,7 @@ struct BatchUniformBuffer {
     depth_offset: f32,
     triangle_cap_length_factor: f32,
     triangle_cap_width_factor: f32,
+    opacity: f32,
 };
 @group(2) @binding(0)
 var<uniform> batch: BatchUniformBuffer;
@@ -348,6 +349,9 @@ fn fs_main(in: VertexOut) -> @location(0) vec4f {
         coverage = step(0.5, coverage);
     }
 
+    // Translucency via alpha-to-coverage.
+    coverage *= batch.opacity;
+
     if coverage < 0.001 {
         discard;
     }
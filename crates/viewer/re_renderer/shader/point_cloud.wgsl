@@ -28,17 +28,32 @@ struct BatchUniformBuffer {
     world_from_obj: mat4x4f,
     flags: u32,
     depth_offset: f32,
-    _padding: vec2u,
+    opacity: f32,
+    crop_mode: u32,
     outline_mask: vec2u,
     picking_layer_object_id: vec2u,
+    crop_box_min: vec3f,
+    crop_box_max: vec3f,
+    first_point_index: u32,
 };
 @group(2) @binding(0)
 var<uniform> batch: BatchUniformBuffer;
 
+/// Object space normals of the points of the batch, only valid if `FLAG_HAS_NORMALS` is set.
+@group(2) @binding(1)
+var normals_texture: texture_2d<f32>;
+
 // Flags
 // See point_cloud.rs#PointCloudBatchFlags
 const FLAG_ENABLE_SHADING: u32 = 1u;
 const FLAG_DRAW_AS_CIRCLES: u32 = 2u;
+const FLAG_HAS_NORMALS: u32 = 4u;
+
+// Crop modes
+// See point_cloud.rs#PointCloudBatchInfo::uniform_buffer
+const CROP_MODE_NONE: u32 = 0u;
+const CROP_MODE_KEEP_INSIDE: u32 = 1u;
+const CROP_MODE_KEEP_OUTSIDE: u32 = 2u;
 
 struct VertexOut {
     @builtin(position)
@@ -64,6 +79,10 @@ struct VertexOut {
 
     @location(4) @interpolate(flat)
     picking_instance_id: vec2u,
+
+    /// World space normal, zero if there is none.
+    @location(5) @interpolate(flat)
+    normal: vec3f,
 };
 
 struct PointData {
@@ -96,6 +115,26 @@ fn read_data(idx: u32) -> PointData {
     return data;
 }
 
+fn read_normal(idx: u32) -> vec3f {
+    if !has_any_flag(batch.flags, FLAG_HAS_NORMALS) {
+        return vec3f(0.0);
+    }
+
+    let normal_idx = idx - batch.first_point_index;
+    let normals_texture_size = textureDimensions(normals_texture);
+    let normal = textureLoad(normals_texture,
+         vec2u(normal_idx % normals_texture_size.x, normal_idx / normals_texture_size.x), 0).xyz;
+    return (batch.world_from_obj * vec4f(normal, 0.0)).xyz;
+}
+
+fn is_cropped(pos: vec3f) -> bool {
+    if batch.crop_mode == CROP_MODE_NONE {
+        return false;
+    }
+    let is_inside = all(batch.crop_box_min <= pos) && all(pos <= batch.crop_box_max);
+    return is_inside == (batch.crop_mode == CROP_MODE_KEEP_OUTSIDE);
+}
+
 @vertex
 fn vs_main(@builtin(vertex_index) vertex_idx: u32) -> VertexOut {
     let quad_idx = sphere_quad_index(vertex_idx);
@@ -103,6 +142,13 @@ fn vs_main(@builtin(vertex_index) vertex_idx: u32) -> VertexOut {
     // Read point data (valid for the entire quad)
     let point_data = read_data(quad_idx);
 
+    var out: VertexOut;
+    if is_cropped(point_data.pos) {
+        // Degenerate case - early-out!
+        out.position = vec4f(0.0);
+        return out;
+    }
+
     // Span quad
     let camera_distance = distance(frame.camera_position, point_data.pos);
     let world_scale_factor = average_scale_from_transform(batch.world_from_obj); // TODO(andreas): somewhat costly, should precompute this
@@ -112,13 +158,13 @@ fn vs_main(@builtin(vertex_index) vertex_idx: u32) -> VertexOut {
                                              has_any_flag(batch.flags, FLAG_DRAW_AS_CIRCLES));
 
     // Output, transform to projection space and done.
-    var out: VertexOut;
     out.position = apply_depth_offset(frame.projection_from_world * vec4f(quad.pos_in_world, 1.0), batch.depth_offset);
     out.color = point_data.color;
     out.radius = quad.point_resolved_radius;
     out.world_position = quad.pos_in_world;
     out.point_center = point_data.pos;
     out.picking_instance_id = point_data.picking_instance_id;
+    out.normal = read_normal(quad_idx);
 
     return out;
 }
@@ -153,6 +199,9 @@ fn fs_main(in: VertexOut) -> @location(0) vec4f {
         coverage = step(0.5, coverage);
     }
 
+    // Translucency via alpha-to-coverage.
+    coverage *= batch.opacity;
+
     if coverage < 0.001 {
         discard;
     }
@@ -161,7 +210,11 @@ fn fs_main(in: VertexOut) -> @location(0) vec4f {
     // TODO(andreas): Proper shading
     // TODO(andreas): This doesn't even use the sphere's world position for shading, the world position used here is flat!
     var shading = 1.0;
-    if has_any_flag(batch.flags, FLAG_ENABLE_SHADING) {
+    if any(in.normal != vec3f(0.0)) {
+        // Light from the camera. Estimated normals may face either way, so light both sides.
+        let to_camera = -camera_ray_to_world_pos(in.point_center).direction;
+        shading = max(0.2, abs(dot(normalize(in.normal), to_camera)));
+    } else if has_any_flag(batch.flags, FLAG_ENABLE_SHADING) {
         shading = max(0.4, sqrt(1.2 - distance(in.point_center, in.world_position) / in.radius)); // quick and dirty coloring
     }
     return vec4f(in.color.rgb * shading, coverage);
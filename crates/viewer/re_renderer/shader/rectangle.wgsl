@@ -79,6 +79,9 @@ struct UniformBuffer {
 
     /// Boolean: swizzle RGBA to BGRA
     bgra_to_rgba: u32,
+
+    /// Boolean: hide the rectangle when seen from behind.
+    cull_back_face: u32,
 };
 
 @group(1) @binding(0)
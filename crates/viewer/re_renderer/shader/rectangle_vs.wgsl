@@ -1,4 +1,5 @@
 #import <./rectangle.wgsl>
+#import <./utils/camera.wgsl>
 #import <./utils/depth_offset.wgsl>
 
 @vertex
@@ -7,6 +8,24 @@ fn vs_main(@builtin(vertex_index) v_idx: u32) -> VertexOut {
     let pos = texcoord.x * rect_info.extent_u + texcoord.y * rect_info.extent_v + rect_info.top_left_corner_position;
 
     var out: VertexOut;
+
+    if rect_info.cull_back_face != 0u {
+        // The front is the side that the normal points away from.
+        let normal = cross(rect_info.extent_u, rect_info.extent_v);
+        var view_dir: vec3f;
+        if is_camera_orthographic() {
+            view_dir = frame.camera_forward;
+        } else {
+            view_dir = rect_info.top_left_corner_position - frame.camera_position;
+        }
+        if dot(normal, view_dir) < 0.0 {
+            // Collapse all vertices onto a single point, so nothing gets rasterized.
+            out.position = vec4f(0.0);
+            out.texcoord = vec2f(0.0);
+            return out;
+        }
+    }
+
     out.position = apply_depth_offset(frame.projection_from_world * vec4f(pos, 1.0), rect_info.depth_offset);
     out.texcoord = texcoord;
     if rect_info.sample_type == SAMPLE_TYPE_NV12 {
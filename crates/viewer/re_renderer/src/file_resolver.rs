@@ -78,6 +78,11 @@
 //!
 //! At the moment, our import system only provides support for `#pragma once` semantics.
 //!
+//! ### Conditional compilation
+//!
+//! Once all imports are interpolated, shader modules are run through a minimal preprocessor
+//! supporting `#define`, `#ifdef`, `#ifndef`, `#else` & `#endif`, see `shader_preprocessor.rs`.
+//!
 //! ## Hot-reloading: platform specifics
 //!
 //! This import system transparently integrates with the renderer's hot-reloading capabilities.
@@ -0,0 +1,621 @@
+use slotmap::SecondaryMap;
+
+use super::CpuModelMeshKey;
+
+/// Transform of a node relative to its parent.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NodeTransform {
+    pub translation: glam::Vec3,
+    pub rotation: glam::Quat,
+    pub scale: glam::Vec3,
+}
+
+impl NodeTransform {
+    pub const IDENTITY: Self = Self {
+        translation: glam::Vec3::ZERO,
+        rotation: glam::Quat::IDENTITY,
+        scale: glam::Vec3::ONE,
+    };
+
+    #[inline]
+    pub fn to_affine3a(&self) -> glam::Affine3A {
+        glam::Affine3A::from_scale_rotation_translation(self.scale, self.rotation, self.translation)
+    }
+}
+
+/// A node in the scene hierarchy of a [`ModelRig`].
+pub struct RigNode {
+    pub name: Option<String>,
+    pub children: Vec<usize>,
+
+    /// Transform relative to the parent node when not animated.
+    pub rest_transform: NodeTransform,
+
+    pub mesh: Option<CpuModelMeshKey>,
+
+    /// Index into [`ModelRig::skins`] that deforms the mesh of this node.
+    pub skin: Option<usize>,
+
+    /// Weights of the morph targets of the mesh when not animated.
+    pub rest_morph_weights: Vec<f32>,
+}
+
+/// Joints that deform a skinned mesh.
+pub struct Skin {
+    /// Indices into [`ModelRig::nodes`].
+    pub joints: Vec<usize>,
+
+    /// Transforms from model space into the space of each joint in the rest pose.
+    pub joint_from_model: Vec<glam::Affine3A>,
+}
+
+/// Vertex displacements of a morph target.
+///
+/// Either of the two may be empty if the target doesn't displace them.
+pub struct MorphTarget {
+    pub position_offsets: Vec<glam::Vec3>,
+    pub normal_offsets: Vec<glam::Vec3>,
+}
+
+/// Per-vertex data needed to skin and morph a [`crate::mesh::CpuMesh`].
+pub struct MeshDeformer {
+    pub rest_positions: Vec<glam::Vec3>,
+    pub rest_normals: Vec<glam::Vec3>,
+
+    /// Indices into [`Skin::joints`], four per vertex.
+    ///
+    /// Empty if the mesh isn't skinned.
+    pub joint_indices: Vec<[u16; 4]>,
+
+    /// Weights of [`Self::joint_indices`].
+    pub joint_weights: Vec<glam::Vec4>,
+
+    pub morph_targets: Vec<MorphTarget>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AnimationProperty {
+    Translation,
+    Rotation,
+    Scale,
+    MorphWeights,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Interpolation {
+    Step,
+    Linear,
+
+    /// Every keyframe has an in-tangent, a value, and an out-tangent.
+    CubicSpline,
+}
+
+/// Keyframes of a single property of a single node.
+pub struct AnimationChannel {
+    /// Index into [`ModelRig::nodes`].
+    pub node: usize,
+    pub property: AnimationProperty,
+    pub interpolation: Interpolation,
+
+    /// Increasing keyframe times in seconds.
+    pub times: Vec<f32>,
+
+    /// Flattened keyframe values, see [`Interpolation`].
+    pub values: Vec<f32>,
+}
+
+impl AnimationChannel {
+    /// Number of floats per value.
+    fn stride(&self) -> usize {
+        let values_per_key = match self.interpolation {
+            Interpolation::Step | Interpolation::Linear => 1,
+            Interpolation::CubicSpline => 3,
+        };
+        self.values.len() / (self.times.len() * values_per_key).max(1)
+    }
+
+    /// The value and, for cubic splines, the in- and out-tangent of a keyframe.
+    fn key(&self, index: usize) -> [&[f32]; 3] {
+        let stride = self.stride();
+        let element = |i: usize| &self.values[i * stride..(i + 1) * stride];
+        match self.interpolation {
+            Interpolation::Step | Interpolation::Linear => {
+                [element(index), element(index), element(index)]
+            }
+            Interpolation::CubicSpline => [
+                element(3 * index + 1),
+                element(3 * index),
+                element(3 * index + 2),
+            ],
+        }
+    }
+
+    /// Samples the channel at `time`, holding the first and last value outside of the keyframes.
+    pub fn sample(&self, time: f32) -> Vec<f32> {
+        if self.times.is_empty() || self.stride() == 0 {
+            return Vec::new();
+        }
+
+        let next = self.times.partition_point(|&t| t <= time);
+        if next == 0 {
+            return self.key(0)[0].to_vec();
+        }
+        if next == self.times.len() {
+            return self.key(next - 1)[0].to_vec();
+        }
+        let prev = next - 1;
+
+        let duration = self.times[next] - self.times[prev];
+        let t = if duration > 0.0 {
+            (time - self.times[prev]) / duration
+        } else {
+            0.0
+        };
+        let [v0, _, out_tangent] = self.key(prev);
+        let [v1, in_tangent, _] = self.key(next);
+
+        match self.interpolation {
+            Interpolation::Step => v0.to_vec(),
+
+            Interpolation::Linear if self.property == AnimationProperty::Rotation => {
+                let q0 = glam::Quat::from_slice(v0);
+                let q1 = glam::Quat::from_slice(v1);
+                q0.slerp(q1, t).to_array().to_vec()
+            }
+
+            Interpolation::Linear => v0.iter().zip(v1).map(|(a, b)| a + (b - a) * t).collect(),
+
+            // https://registry.khronos.org/glTF/specs/2.0/glTF-2.0.html#interpolation-cubic
+            Interpolation::CubicSpline => {
+                let (t2, t3) = (t * t, t * t * t);
+                let h00 = 2.0 * t3 - 3.0 * t2 + 1.0;
+                let h10 = t3 - 2.0 * t2 + t;
+                let h01 = -2.0 * t3 + 3.0 * t2;
+                let h11 = t3 - t2;
+                (0..v0.len())
+                    .map(|i| {
+                        h00 * v0[i]
+                            + h10 * duration * out_tangent[i]
+                            + h01 * v1[i]
+                            + h11 * duration * in_tangent[i]
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+pub struct Animation {
+    pub name: Option<String>,
+    pub channels: Vec<AnimationChannel>,
+}
+
+impl Animation {
+    /// Time of the last keyframe in seconds.
+    pub fn duration(&self) -> f32 {
+        self.channels
+            .iter()
+            .filter_map(|channel| channel.times.last().copied())
+            .fold(0.0, f32::max)
+    }
+}
+
+/// The node transforms and morph weights a [`ModelRig`] is drawn with.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pose {
+    /// Transform of every node relative to its parent.
+    pub local_transforms: Vec<NodeTransform>,
+
+    /// Morph target weights of every node.
+    pub morph_weights: Vec<Vec<f32>>,
+}
+
+/// Vertices of a mesh after skinning and morphing.
+pub struct DeformedVertices {
+    pub positions: Vec<glam::Vec3>,
+    pub normals: Vec<glam::Vec3>,
+    pub bbox: macaw::BoundingBox,
+}
+
+/// A mesh instance of a [`ModelRig`] in a given [`Pose`].
+pub struct PosedMeshInstance {
+    pub mesh: CpuModelMeshKey,
+    pub world_from_mesh: glam::Affine3A,
+
+    /// Replaces the vertex positions and normals of the mesh, if it's skinned or morphed.
+    pub deformed_vertices: Option<DeformedVertices>,
+}
+
+/// The scene hierarchy, skins, morph targets, and animations of a [`super::CpuModel`].
+///
+/// Skinning and morphing happens on the CPU, see [`crate::mesh::GpuMesh::with_deformed_vertices`]
+/// for uploading the result.
+#[derive(Default)]
+pub struct ModelRig {
+    pub nodes: Vec<RigNode>,
+
+    /// Indices into [`Self::nodes`] of the nodes without a parent.
+    pub roots: Vec<usize>,
+
+    pub skins: Vec<Skin>,
+    pub deformers: SecondaryMap<CpuModelMeshKey, MeshDeformer>,
+    pub animations: Vec<Animation>,
+}
+
+impl ModelRig {
+    pub fn rest_pose(&self) -> Pose {
+        Pose {
+            local_transforms: self.nodes.iter().map(|node| node.rest_transform).collect(),
+            morph_weights: self
+                .nodes
+                .iter()
+                .map(|node| node.rest_morph_weights.clone())
+                .collect(),
+        }
+    }
+
+    /// Finds a node, e.g. a joint, by name.
+    pub fn node_index(&self, name: &str) -> Option<usize> {
+        self.nodes
+            .iter()
+            .position(|node| node.name.as_deref() == Some(name))
+    }
+
+    /// Overwrites `pose` with `animation` at `time` seconds.
+    ///
+    /// The animation loops, nodes it doesn't animate are left untouched.
+    pub fn apply_animation(&self, pose: &mut Pose, animation: usize, time: f32) {
+        let Some(animation) = self.animations.get(animation) else {
+            return;
+        };
+
+        let duration = animation.duration();
+        let time = if duration > 0.0 {
+            time.rem_euclid(duration)
+        } else {
+            0.0
+        };
+
+        for channel in &animation.channels {
+            let value = channel.sample(time);
+            let Some(transform) = pose.local_transforms.get_mut(channel.node) else {
+                continue;
+            };
+
+            match channel.property {
+                AnimationProperty::Translation if value.len() == 3 => {
+                    transform.translation = glam::Vec3::from_slice(&value);
+                }
+                AnimationProperty::Rotation if value.len() == 4 => {
+                    transform.rotation = glam::Quat::from_slice(&value).normalize();
+                }
+                AnimationProperty::Scale if value.len() == 3 => {
+                    transform.scale = glam::Vec3::from_slice(&value);
+                }
+                AnimationProperty::MorphWeights => {
+                    pose.morph_weights[channel.node] = value;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Transform of every node relative to the model's origin.
+    ///
+    /// `None` for nodes that aren't reachable from any root, those aren't part of the model.
+    pub fn model_from_nodes(&self, pose: &Pose) -> Vec<Option<glam::Affine3A>> {
+        let mut model_from_nodes = vec![None; self.nodes.len()];
+
+        let mut stack = self
+            .roots
+            .iter()
+            .map(|&root| (root, glam::Affine3A::IDENTITY))
+            .collect::<Vec<_>>();
+        while let Some((node, model_from_parent)) = stack.pop() {
+            // Guard against malformed files with cycles.
+            if node >= self.nodes.len() || model_from_nodes[node].is_some() {
+                continue;
+            }
+
+            let model_from_node = model_from_parent * pose.local_transforms[node].to_affine3a();
+            model_from_nodes[node] = Some(model_from_node);
+            stack.extend(
+                self.nodes[node]
+                    .children
+                    .iter()
+                    .map(|&child| (child, model_from_node)),
+            );
+        }
+
+        model_from_nodes
+    }
+
+    /// Computes every mesh instance in the given pose, skinning and morphing meshes as needed.
+    pub fn posed_instances(&self, pose: &Pose) -> Vec<PosedMeshInstance> {
+        re_tracing::profile_function!();
+
+        let model_from_nodes = self.model_from_nodes(pose);
+
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter_map(|(index, node)| {
+                let mesh = node.mesh?;
+                let model_from_node = model_from_nodes[index]?;
+
+                let Some(deformer) = self.deformers.get(mesh) else {
+                    return Some(PosedMeshInstance {
+                        mesh,
+                        world_from_mesh: model_from_node,
+                        deformed_vertices: None,
+                    });
+                };
+
+                let skin = node
+                    .skin
+                    .and_then(|skin| self.skins.get(skin))
+                    .filter(|_| !deformer.joint_indices.is_empty());
+                let morph_weights = pose.morph_weights.get(index).map_or(&[][..], Vec::as_slice);
+
+                let mut deformed_vertices = deformer.morph(morph_weights);
+                let world_from_mesh = if let Some(skin) = skin {
+                    // Skinned meshes are placed by their joints, not by their node.
+                    let model_from_rest_model = skin
+                        .joints
+                        .iter()
+                        .zip(&skin.joint_from_model)
+                        .map(|(&joint, joint_from_model)| {
+                            model_from_nodes
+                                .get(joint)
+                                .copied()
+                                .flatten()
+                                .unwrap_or(glam::Affine3A::IDENTITY)
+                                * *joint_from_model
+                        })
+                        .collect::<Vec<_>>();
+                    let vertices = deformed_vertices.get_or_insert_with(|| DeformedVertices {
+                        positions: deformer.rest_positions.clone(),
+                        normals: deformer.rest_normals.clone(),
+                        bbox: macaw::BoundingBox::nothing(),
+                    });
+                    deformer.skin(vertices, &model_from_rest_model);
+                    glam::Affine3A::IDENTITY
+                } else {
+                    model_from_node
+                };
+
+                if let Some(vertices) = &mut deformed_vertices {
+                    vertices.bbox =
+                        macaw::BoundingBox::from_points(vertices.positions.iter().copied());
+                }
+
+                Some(PosedMeshInstance {
+                    mesh,
+                    world_from_mesh,
+                    deformed_vertices,
+                })
+            })
+            .collect()
+    }
+}
+
+impl MeshDeformer {
+    /// Applies the morph targets, returns `None` if none of them has any effect.
+    fn morph(&self, weights: &[f32]) -> Option<DeformedVertices> {
+        let active_targets = self
+            .morph_targets
+            .iter()
+            .zip(weights)
+            .filter(|(_, weight)| **weight != 0.0)
+            .collect::<Vec<_>>();
+        if active_targets.is_empty() {
+            return None;
+        }
+
+        let mut positions = self.rest_positions.clone();
+        let mut normals = self.rest_normals.clone();
+        for (target, &weight) in active_targets {
+            for (position, offset) in positions.iter_mut().zip(&target.position_offsets) {
+                *position += weight * *offset;
+            }
+            for (normal, offset) in normals.iter_mut().zip(&target.normal_offsets) {
+                *normal += weight * *offset;
+            }
+        }
+        for normal in &mut normals {
+            *normal = normal.normalize_or_zero();
+        }
+
+        Some(DeformedVertices {
+            positions,
+            normals,
+            bbox: macaw::BoundingBox::nothing(),
+        })
+    }
+
+    /// Applies linear blend skinning.
+    ///
+    /// Vertices without any weight are left in place.
+    fn skin(&self, vertices: &mut DeformedVertices, joint_transforms: &[glam::Affine3A]) {
+        re_tracing::profile_function!();
+
+        for (((position, normal), joints), weights) in vertices
+            .positions
+            .iter_mut()
+            .zip(&mut vertices.normals)
+            .zip(&self.joint_indices)
+            .zip(&self.joint_weights)
+        {
+            let mut skinned_position = glam::Vec3::ZERO;
+            let mut skinned_normal = glam::Vec3::ZERO;
+            let mut total_weight = 0.0;
+            for (&joint, weight) in joints.iter().zip(weights.to_array()) {
+                let Some(transform) = joint_transforms.get(joint as usize) else {
+                    continue;
+                };
+                if weight <= 0.0 {
+                    continue;
+                }
+                skinned_position += weight * transform.transform_point3(*position);
+                skinned_normal += weight * transform.transform_vector3(*normal);
+                total_weight += weight;
+            }
+
+            if total_weight > 0.0 {
+                *position = skinned_position / total_weight;
+                *normal = skinned_normal.normalize_or_zero();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn channel(
+        property: AnimationProperty,
+        interpolation: Interpolation,
+        times: Vec<f32>,
+        values: Vec<f32>,
+    ) -> AnimationChannel {
+        AnimationChannel {
+            node: 0,
+            property,
+            interpolation,
+            times,
+            values,
+        }
+    }
+
+    #[test]
+    fn test_sample_channel() {
+        let linear = channel(
+            AnimationProperty::Translation,
+            Interpolation::Linear,
+            vec![0.0, 2.0],
+            vec![0.0, 0.0, 0.0, 2.0, 4.0, 6.0],
+        );
+        assert_eq!(linear.sample(-1.0), [0.0, 0.0, 0.0]);
+        assert_eq!(linear.sample(1.0), [1.0, 2.0, 3.0]);
+        assert_eq!(linear.sample(3.0), [2.0, 4.0, 6.0]);
+
+        let step = channel(
+            AnimationProperty::MorphWeights,
+            Interpolation::Step,
+            vec![0.0, 1.0],
+            vec![0.0, 1.0, 1.0, 0.0],
+        );
+        assert_eq!(step.sample(0.9), [0.0, 1.0]);
+        assert_eq!(step.sample(1.0), [1.0, 0.0]);
+
+        // With zero tangents, the spline eases in and out of the keyframes.
+        let cubic = channel(
+            AnimationProperty::MorphWeights,
+            Interpolation::CubicSpline,
+            vec![0.0, 1.0],
+            vec![0.0, 0.0, 0.0, 0.0, 1.0, 0.0],
+        );
+        assert_eq!(cubic.sample(0.5), [0.5]);
+        assert!(cubic.sample(0.25)[0] < 0.25);
+    }
+
+    #[test]
+    fn test_skinning_follows_joint() {
+        let mut meshes = slotmap::SlotMap::<CpuModelMeshKey, ()>::with_key();
+        let mesh = meshes.insert(());
+
+        let mut rig = ModelRig {
+            nodes: vec![
+                RigNode {
+                    name: Some("mesh".to_owned()),
+                    children: Vec::new(),
+                    rest_transform: NodeTransform::IDENTITY,
+                    mesh: Some(mesh),
+                    skin: Some(0),
+                    rest_morph_weights: Vec::new(),
+                },
+                RigNode {
+                    name: Some("joint".to_owned()),
+                    children: Vec::new(),
+                    rest_transform: NodeTransform {
+                        translation: glam::Vec3::X,
+                        ..NodeTransform::IDENTITY
+                    },
+                    mesh: None,
+                    skin: None,
+                    rest_morph_weights: Vec::new(),
+                },
+            ],
+            roots: vec![0, 1],
+            skins: vec![Skin {
+                joints: vec![1],
+                joint_from_model: vec![glam::Affine3A::from_translation(-glam::Vec3::X)],
+            }],
+            deformers: SecondaryMap::new(),
+            animations: vec![Animation {
+                name: None,
+                channels: vec![AnimationChannel {
+                    node: 1,
+                    ..channel(
+                        AnimationProperty::Translation,
+                        Interpolation::Linear,
+                        vec![0.0, 1.0],
+                        vec![1.0, 0.0, 0.0, 1.0, 2.0, 0.0],
+                    )
+                }],
+            }],
+        };
+        rig.deformers.insert(
+            mesh,
+            MeshDeformer {
+                rest_positions: vec![glam::Vec3::new(1.0, 0.0, 0.0)],
+                rest_normals: vec![glam::Vec3::Z],
+                joint_indices: vec![[0; 4]],
+                joint_weights: vec![glam::Vec4::new(1.0, 0.0, 0.0, 0.0)],
+                morph_targets: Vec::new(),
+            },
+        );
+
+        // In the rest pose, the vertex stays where it is.
+        let rest = rig.posed_instances(&rig.rest_pose());
+        let vertices = rest[0].deformed_vertices.as_ref().unwrap();
+        assert_eq!(vertices.positions, [glam::Vec3::new(1.0, 0.0, 0.0)]);
+
+        // Halfway through the animation, the joint and the vertex moved up by one.
+        let mut pose = rig.rest_pose();
+        rig.apply_animation(&mut pose, 0, 0.5);
+        assert_eq!(rig.node_index("joint"), Some(1));
+        assert_eq!(
+            pose.local_transforms[1].translation,
+            glam::Vec3::new(1.0, 1.0, 0.0)
+        );
+
+        let posed = rig.posed_instances(&pose);
+        let vertices = posed[0].deformed_vertices.as_ref().unwrap();
+        assert_eq!(vertices.positions, [glam::Vec3::new(1.0, 1.0, 0.0)]);
+        assert_eq!(vertices.normals, [glam::Vec3::Z]);
+        assert_eq!(posed[0].world_from_mesh, glam::Affine3A::IDENTITY);
+    }
+
+    #[test]
+    fn test_morph_targets() {
+        let deformer = MeshDeformer {
+            rest_positions: vec![glam::Vec3::ZERO, glam::Vec3::X],
+            rest_normals: vec![glam::Vec3::Z; 2],
+            joint_indices: Vec::new(),
+            joint_weights: Vec::new(),
+            morph_targets: vec![MorphTarget {
+                position_offsets: vec![glam::Vec3::Y, glam::Vec3::ZERO],
+                normal_offsets: Vec::new(),
+            }],
+        };
+
+        assert!(deformer.morph(&[0.0]).is_none());
+        let vertices = deformer.morph(&[0.5]).unwrap();
+        assert_eq!(
+            vertices.positions,
+            [glam::Vec3::new(0.0, 0.5, 0.0), glam::Vec3::X]
+        );
+    }
+}
@@ -60,10 +60,7 @@ impl CpuModel {
     /// * instances with invalid mesh keys
     /// * unreferenced meshes
     pub fn into_gpu_meshes(self, ctx: &RenderContext) -> Result<Vec<GpuMeshInstance>, MeshError> {
-        let mut gpu_meshes = SecondaryMap::with_capacity(self.meshes.len());
-        for (mesh_key, mesh) in &self.meshes {
-            gpu_meshes.insert(mesh_key, Arc::new(GpuMesh::new(ctx, mesh)?));
-        }
+        let gpu_meshes = self.upload_meshes(ctx)?;
 
         Ok(self
             .instances
@@ -79,4 +76,16 @@ impl CpuModel {
             })
             .collect())
     }
+
+    /// Uploads every mesh of the model, e.g. to instantiate them in a posed [`super::ModelRig`].
+    pub fn upload_meshes(
+        &self,
+        ctx: &RenderContext,
+    ) -> Result<SecondaryMap<CpuModelMeshKey, Arc<GpuMesh>>, MeshError> {
+        let mut gpu_meshes = SecondaryMap::with_capacity(self.meshes.len());
+        for (mesh_key, mesh) in &self.meshes {
+            gpu_meshes.insert(mesh_key, Arc::new(GpuMesh::new(ctx, mesh)?));
+        }
+        Ok(gpu_meshes)
+    }
 }
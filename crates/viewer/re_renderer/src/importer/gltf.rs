@@ -5,6 +5,11 @@ use smallvec::SmallVec;
 
 use crate::{
     CpuMeshInstance, CpuModel, CpuModelMeshKey, RenderContext, Rgba32Unmul,
+    importer::{
+        Animation, AnimationChannel, AnimationProperty, Interpolation, MeshDeformer, ModelRig,
+        MorphTarget, NodeTransform, RigNode, Skin,
+        meshopt::{self, MeshoptError},
+    },
     mesh::{CpuMesh, Material, MeshError},
     resource_managers::{GpuTexture2D, ImageDataDesc, TextureManager2D},
 };
@@ -33,20 +38,83 @@ pub enum GltfImportError {
 
     #[error("Mesh {mesh_name:?} has no triangle primitives.")]
     NoTrianglePrimitives { mesh_name: String },
+
+    #[error(
+        "Draco compressed meshes (KHR_draco_mesh_compression) are not supported. Export the asset with EXT_meshopt_compression or without compression instead."
+    )]
+    DracoCompression,
+
+    #[error("Buffer {0} uses a data URI that isn't base64 encoded.")]
+    InvalidDataUri(usize),
+
+    #[error("Failed to decode buffer view {view}: {err}")]
+    MeshoptDecoding { view: usize, err: MeshoptError },
+
+    #[error("Compressed buffer view {0} lies outside of its buffers.")]
+    CompressedViewOutOfBounds(usize),
 }
 
+/// Name of the glTF extension for meshoptimizer compressed buffer views.
+const EXT_MESHOPT_COMPRESSION: &str = "EXT_meshopt_compression";
+
+/// Name of the glTF extension for Draco compressed mesh primitives.
+const KHR_DRACO_MESH_COMPRESSION: &str = "KHR_draco_mesh_compression";
+
 /// Loads both gltf and glb into the mesh & texture manager.
 pub fn load_gltf_from_buffer(
     mesh_name: &str,
     buffer: &[u8],
     ctx: &RenderContext,
 ) -> Result<CpuModel, GltfImportError> {
+    load_rigged_gltf_from_buffer(mesh_name, buffer, ctx).map(|(model, _rig)| model)
+}
+
+/// Like [`load_gltf_from_buffer`], but also loads skins, morph targets, and animations.
+///
+/// The rig is `None` for models that have none of these.
+pub fn load_rigged_gltf_from_buffer(
+    mesh_name: &str,
+    buffer: &[u8],
+    ctx: &RenderContext,
+) -> Result<(CpuModel, Option<ModelRig>), GltfImportError> {
+    load_rigged_gltf_from_parsed(mesh_name, parse_gltf(buffer)?, ctx)
+}
+
+/// A gltf or glb whose buffers and images are decoded, but whose textures and meshes are not yet created.
+///
+/// Decoding is the expensive part of loading a gltf and doesn't need the render context,
+/// so it can happen on any thread.
+pub struct ParsedGltf {
+    doc: gltf::Document,
+    buffers: Vec<gltf::buffer::Data>,
+    images: Vec<gltf::image::Data>,
+}
+
+/// Decodes a gltf or glb, see [`ParsedGltf`].
+pub fn parse_gltf(buffer: &[u8]) -> Result<ParsedGltf, GltfImportError> {
     re_tracing::profile_function!();
 
-    let (doc, buffers, images) = {
-        re_tracing::profile_scope!("gltf::import_slice");
-        gltf::import_slice(buffer)?
-    };
+    let (doc, buffers, images) = import_slice(buffer)?;
+    Ok(ParsedGltf {
+        doc,
+        buffers,
+        images,
+    })
+}
+
+/// Like [`load_rigged_gltf_from_buffer`], but for a gltf that was already decoded with [`parse_gltf`].
+pub fn load_rigged_gltf_from_parsed(
+    mesh_name: &str,
+    parsed: ParsedGltf,
+    ctx: &RenderContext,
+) -> Result<(CpuModel, Option<ModelRig>), GltfImportError> {
+    re_tracing::profile_function!();
+
+    let ParsedGltf {
+        doc,
+        buffers,
+        images,
+    } = parsed;
 
     let mut images_as_textures = Vec::with_capacity(images.len());
     for (_index, image) in images.into_iter().enumerate() {
@@ -105,13 +173,18 @@ pub fn load_gltf_from_buffer(
     }
 
     let mut re_model = CpuModel::default();
+    let mut rig = ModelRig::default();
     let mut mesh_keys = HashMap::with_capacity(doc.meshes().len());
     for ref mesh in doc.meshes() {
         re_tracing::profile_scope!("mesh");
 
-        let re_mesh = import_mesh(mesh, &buffers, &images_as_textures, &ctx.texture_manager_2d)?;
+        let (re_mesh, deformer) =
+            import_mesh(mesh, &buffers, &images_as_textures, &ctx.texture_manager_2d)?;
         let re_mesh_key = re_model.meshes.insert(re_mesh);
         mesh_keys.insert(mesh.index(), re_mesh_key);
+        if let Some(deformer) = deformer {
+            rig.deformers.insert(re_mesh_key, deformer);
+        }
     }
 
     for scene in doc.scenes() {
@@ -125,7 +198,336 @@ pub fn load_gltf_from_buffer(
         }
     }
 
-    Ok(re_model)
+    let is_rigged = doc.skins().next().is_some()
+        || doc.animations().next().is_some()
+        || !rig.deformers.is_empty();
+    let rig = is_rigged.then(|| import_rig(rig, &doc, &buffers, &mesh_keys));
+
+    Ok((re_model, rig))
+}
+
+/// Like [`gltf::import_slice`], but also decodes buffer views compressed with
+/// `EXT_meshopt_compression`.
+fn import_slice(
+    slice: &[u8],
+) -> Result<
+    (
+        gltf::Document,
+        Vec<gltf::buffer::Data>,
+        Vec<gltf::image::Data>,
+    ),
+    GltfImportError,
+> {
+    let (json, blob) = if slice.starts_with(b"glTF") {
+        let glb = gltf::Glb::from_slice(slice)?;
+        (glb.json.into_owned(), glb.bin.map(|bin| bin.into_owned()))
+    } else {
+        (slice.to_vec(), None)
+    };
+
+    let mut root = gltf::json::Root::from_slice(&json).map_err(gltf::Error::Deserialize)?;
+    if root
+        .extensions_required
+        .iter()
+        .any(|e| e == KHR_DRACO_MESH_COMPRESSION)
+    {
+        return Err(GltfImportError::DracoCompression);
+    }
+
+    // We decode the compressed views ourselves, so the extension must not fail validation.
+    let compression = if root
+        .extensions_used
+        .iter()
+        .any(|e| e == EXT_MESHOPT_COMPRESSION)
+    {
+        root.extensions_required
+            .retain(|extension| extension != EXT_MESHOPT_COMPRESSION);
+        serde_json::from_slice::<MeshoptJson>(&json).map_err(gltf::Error::Deserialize)?
+    } else {
+        MeshoptJson::default()
+    };
+
+    let doc = gltf::Document::from_json(root)?;
+    let mut buffers = import_buffers(&doc, blob, &compression)?;
+    decode_meshopt_views(&doc, &compression, &mut buffers)?;
+    let images = gltf::import_images(&doc, None, &buffers)?;
+
+    Ok((doc, buffers, images))
+}
+
+/// The parts of the glTF json that describe `EXT_meshopt_compression`, which `gltf` doesn't parse.
+#[derive(Default, serde::Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct MeshoptJson {
+    buffers: Vec<MeshoptBufferJson>,
+    buffer_views: Vec<MeshoptBufferViewJson>,
+}
+
+#[derive(Default, serde::Deserialize)]
+#[serde(default)]
+struct MeshoptBufferJson {
+    extensions: MeshoptBufferExtensions,
+}
+
+#[derive(Default, serde::Deserialize)]
+#[serde(default)]
+struct MeshoptBufferExtensions {
+    #[serde(rename = "EXT_meshopt_compression")]
+    meshopt: Option<MeshoptBuffer>,
+}
+
+#[derive(Default, serde::Deserialize)]
+#[serde(default)]
+struct MeshoptBuffer {
+    /// The buffer has no data of its own, and is only there to hold the decompressed views.
+    fallback: bool,
+}
+
+#[derive(Default, serde::Deserialize)]
+#[serde(default)]
+struct MeshoptBufferViewJson {
+    extensions: MeshoptBufferViewExtensions,
+}
+
+#[derive(Default, serde::Deserialize)]
+#[serde(default)]
+struct MeshoptBufferViewExtensions {
+    #[serde(rename = "EXT_meshopt_compression")]
+    meshopt: Option<MeshoptBufferView>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MeshoptBufferView {
+    buffer: usize,
+    #[serde(default)]
+    byte_offset: usize,
+    byte_length: usize,
+    byte_stride: usize,
+    count: usize,
+    mode: meshopt::Mode,
+    #[serde(default)]
+    filter: meshopt::Filter,
+}
+
+impl MeshoptJson {
+    fn is_fallback(&self, buffer: usize) -> bool {
+        self.buffers
+            .get(buffer)
+            .and_then(|buffer| buffer.extensions.meshopt.as_ref())
+            .is_some_and(|meshopt| meshopt.fallback)
+    }
+}
+
+/// Like [`gltf::import_buffers`] for slices, but fallback buffers of `EXT_meshopt_compression`
+/// are zero-initialized instead of failing the import.
+fn import_buffers(
+    doc: &gltf::Document,
+    mut blob: Option<Vec<u8>>,
+    compression: &MeshoptJson,
+) -> Result<Vec<gltf::buffer::Data>, GltfImportError> {
+    use base64::Engine as _;
+
+    let mut buffers = Vec::with_capacity(doc.buffers().len());
+    for buffer in doc.buffers() {
+        let mut data = if compression.is_fallback(buffer.index()) {
+            vec![0; buffer.length()]
+        } else {
+            match buffer.source() {
+                gltf::buffer::Source::Bin => blob.take().ok_or(gltf::Error::MissingBlob)?,
+                gltf::buffer::Source::Uri(uri) => {
+                    let Some(encoded) = uri
+                        .strip_prefix("data:")
+                        .and_then(|uri| uri.split_once(";base64,"))
+                        .map(|(_mime_type, encoded)| encoded)
+                    else {
+                        if uri.starts_with("data:") {
+                            return Err(GltfImportError::InvalidDataUri(buffer.index()));
+                        }
+                        return Err(gltf::Error::ExternalReferenceInSliceImport.into());
+                    };
+                    base64::engine::general_purpose::STANDARD
+                        .decode(encoded)
+                        .map_err(|_err| GltfImportError::InvalidDataUri(buffer.index()))?
+                }
+            }
+        };
+
+        if data.len() < buffer.length() {
+            return Err(gltf::Error::BufferLength {
+                buffer: buffer.index(),
+                expected: buffer.length(),
+                actual: data.len(),
+            }
+            .into());
+        }
+        // Accessors assume that buffers are padded to a multiple of four bytes.
+        data.resize(data.len().next_multiple_of(4), 0);
+
+        buffers.push(gltf::buffer::Data(data));
+    }
+
+    Ok(buffers)
+}
+
+/// Decodes all `EXT_meshopt_compression` buffer views into their fallback buffers.
+///
+/// Views that aren't backed by a fallback buffer already contain uncompressed data.
+fn decode_meshopt_views(
+    doc: &gltf::Document,
+    compression: &MeshoptJson,
+    buffers: &mut [gltf::buffer::Data],
+) -> Result<(), GltfImportError> {
+    re_tracing::profile_function!();
+
+    for (view, json) in doc.views().zip(&compression.buffer_views) {
+        let Some(compressed) = &json.extensions.meshopt else {
+            continue;
+        };
+        if !compression.is_fallback(view.buffer().index()) {
+            continue;
+        }
+
+        let out_of_bounds = || GltfImportError::CompressedViewOutOfBounds(view.index());
+        let source = buffers
+            .get(compressed.buffer)
+            .and_then(|buffer| {
+                buffer.get(compressed.byte_offset..compressed.byte_offset + compressed.byte_length)
+            })
+            .ok_or_else(out_of_bounds)?;
+        let decoded = meshopt::decode(
+            compressed.mode,
+            compressed.filter,
+            compressed.count,
+            compressed.byte_stride,
+            source,
+        )
+        .map_err(|err| GltfImportError::MeshoptDecoding {
+            view: view.index(),
+            err,
+        })?;
+
+        let length = decoded.len().min(view.length());
+        buffers[view.buffer().index()]
+            .0
+            .get_mut(view.offset()..view.offset() + length)
+            .ok_or_else(out_of_bounds)?
+            .copy_from_slice(&decoded[..length]);
+    }
+
+    Ok(())
+}
+
+/// Imports the node hierarchy, skins, and animations into a rig that already has the deformers
+/// of all meshes.
+fn import_rig(
+    mut rig: ModelRig,
+    doc: &gltf::Document,
+    buffers: &[gltf::buffer::Data],
+    meshes: &HashMap<usize, CpuModelMeshKey>,
+) -> ModelRig {
+    re_tracing::profile_function!();
+
+    rig.nodes = doc
+        .nodes()
+        .map(|node| {
+            let (translation, rotation, scale) = node.transform().decomposed();
+            RigNode {
+                name: node.name().map(ToOwned::to_owned),
+                children: node.children().map(|child| child.index()).collect(),
+                rest_transform: NodeTransform {
+                    translation: glam::Vec3::from(translation),
+                    rotation: glam::Quat::from_array(rotation),
+                    scale: glam::Vec3::from(scale),
+                },
+                mesh: node
+                    .mesh()
+                    .and_then(|mesh| meshes.get(&mesh.index()).copied()),
+                skin: node.skin().map(|skin| skin.index()),
+                rest_morph_weights: node
+                    .weights()
+                    .or_else(|| node.mesh().and_then(|mesh| mesh.weights()))
+                    .map(<[f32]>::to_vec)
+                    .unwrap_or_default(),
+            }
+        })
+        .collect();
+
+    rig.roots = doc
+        .scenes()
+        .flat_map(|scene| scene.nodes().map(|node| node.index()))
+        .unique()
+        .collect();
+
+    rig.skins = doc
+        .skins()
+        .map(|skin| {
+            let joints = skin.joints().map(|joint| joint.index()).collect_vec();
+            let reader = skin.reader(|buffer| Some(&*buffers[buffer.index()]));
+            let joint_from_model = match reader.read_inverse_bind_matrices() {
+                Some(matrices) => matrices
+                    .map(|matrix| {
+                        glam::Affine3A::from_mat4(glam::Mat4::from_cols_array_2d(&matrix))
+                    })
+                    .collect(),
+                None => vec![glam::Affine3A::IDENTITY; joints.len()],
+            };
+            Skin {
+                joints,
+                joint_from_model,
+            }
+        })
+        .collect();
+
+    rig.animations = doc
+        .animations()
+        .map(|animation| Animation {
+            name: animation.name().map(ToOwned::to_owned),
+            channels: animation
+                .channels()
+                .filter_map(|channel| import_animation_channel(&channel, buffers))
+                .collect(),
+        })
+        .collect();
+
+    rig
+}
+
+fn import_animation_channel(
+    channel: &gltf::animation::Channel<'_>,
+    buffers: &[gltf::buffer::Data],
+) -> Option<AnimationChannel> {
+    use gltf::animation::util::ReadOutputs;
+
+    let reader = channel.reader(|buffer| Some(&*buffers[buffer.index()]));
+    let times = reader.read_inputs()?.collect_vec();
+    let (property, values) = match reader.read_outputs()? {
+        ReadOutputs::Translations(translations) => (
+            AnimationProperty::Translation,
+            translations.flatten().collect_vec(),
+        ),
+        ReadOutputs::Rotations(rotations) => (
+            AnimationProperty::Rotation,
+            rotations.into_f32().flatten().collect_vec(),
+        ),
+        ReadOutputs::Scales(scales) => (AnimationProperty::Scale, scales.flatten().collect_vec()),
+        ReadOutputs::MorphTargetWeights(weights) => (
+            AnimationProperty::MorphWeights,
+            weights.into_f32().collect_vec(),
+        ),
+    };
+
+    Some(AnimationChannel {
+        node: channel.target().node().index(),
+        property,
+        interpolation: match channel.sampler().interpolation() {
+            gltf::animation::Interpolation::Step => Interpolation::Step,
+            gltf::animation::Interpolation::Linear => Interpolation::Linear,
+            gltf::animation::Interpolation::CubicSpline => Interpolation::CubicSpline,
+        },
+        times,
+        values,
+    })
 }
 
 fn map_format(format: gltf::image::Format) -> Option<wgpu::TextureFormat> {
@@ -156,7 +558,7 @@ fn import_mesh(
     buffers: &[gltf::buffer::Data],
     gpu_image_handles: &[GpuTexture2D],
     texture_manager: &TextureManager2D, //imported_materials: HashMap<usize, Material>,
-) -> Result<CpuMesh, GltfImportError> {
+) -> Result<(CpuMesh, Option<MeshDeformer>), GltfImportError> {
     re_tracing::profile_function!();
 
     let mesh_name = mesh.name().map_or("<unknown", |f| f).to_owned();
@@ -166,6 +568,9 @@ fn import_mesh(
     let mut vertex_colors = Vec::new();
     let mut vertex_normals = Vec::new();
     let mut vertex_texcoords = Vec::new();
+    let mut vertex_joints = Vec::new();
+    let mut vertex_joint_weights = Vec::new();
+    let mut morph_targets: Vec<MorphTarget> = Vec::new();
     let mut materials = SmallVec::new();
 
     // A GLTF mesh consists of several primitives, each with their own material.
@@ -192,6 +597,7 @@ fn import_mesh(
             return Err(GltfImportError::NoIndices { mesh_name });
         }
 
+        let base_vertex = vertex_positions.len();
         if let Some(primitive_positions) = reader.read_positions() {
             vertex_positions.extend(primitive_positions.map(glam::Vec3::from));
         } else {
@@ -220,6 +626,37 @@ fn import_mesh(
             vertex_texcoords.resize(vertex_positions.len(), glam::Vec2::ZERO);
         }
 
+        if let (Some(joints), Some(weights)) = (reader.read_joints(set), reader.read_weights(set)) {
+            vertex_joints.extend(joints.into_u16());
+            vertex_joint_weights.extend(weights.into_f32().map(glam::Vec4::from));
+        }
+        vertex_joints.resize(vertex_positions.len(), [0; 4]);
+        vertex_joint_weights.resize(vertex_positions.len(), glam::Vec4::ZERO);
+
+        for (target_index, (positions, normals, _tangents)) in
+            reader.read_morph_targets().enumerate()
+        {
+            if morph_targets.len() <= target_index {
+                morph_targets.push(MorphTarget {
+                    position_offsets: Vec::new(),
+                    normal_offsets: Vec::new(),
+                });
+            }
+            let target = &mut morph_targets[target_index];
+            target
+                .position_offsets
+                .resize(base_vertex, glam::Vec3::ZERO);
+            target.normal_offsets.resize(base_vertex, glam::Vec3::ZERO);
+            if let Some(positions) = positions {
+                target
+                    .position_offsets
+                    .extend(positions.map(glam::Vec3::from));
+            }
+            if let Some(normals) = normals {
+                target.normal_offsets.extend(normals.map(glam::Vec3::from));
+            }
+        }
+
         let primitive_material = primitive.material();
         let pbr_material = primitive_material.pbr_metallic_roughness();
 
@@ -282,6 +719,35 @@ fn import_mesh(
 
     let bbox = macaw::BoundingBox::from_points(vertex_positions.iter().copied());
 
+    let is_skinned = vertex_joint_weights
+        .iter()
+        .any(|weights| *weights != glam::Vec4::ZERO);
+    let deformer = (is_skinned || !morph_targets.is_empty()).then(|| {
+        for target in &mut morph_targets {
+            target
+                .position_offsets
+                .resize(vertex_positions.len(), glam::Vec3::ZERO);
+            target
+                .normal_offsets
+                .resize(vertex_positions.len(), glam::Vec3::ZERO);
+        }
+        MeshDeformer {
+            rest_positions: vertex_positions.clone(),
+            rest_normals: vertex_normals.clone(),
+            joint_indices: if is_skinned {
+                vertex_joints
+            } else {
+                Vec::new()
+            },
+            joint_weights: if is_skinned {
+                vertex_joint_weights
+            } else {
+                Vec::new()
+            },
+            morph_targets,
+        }
+    });
+
     let mesh = CpuMesh {
         label: mesh.name().into(),
         triangle_indices,
@@ -295,7 +761,7 @@ fn import_mesh(
 
     mesh.sanity_check()?;
 
-    Ok(mesh)
+    Ok((mesh, deformer))
 }
 
 fn gather_instances_recursive(
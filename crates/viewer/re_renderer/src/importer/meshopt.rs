@@ -0,0 +1,602 @@
+//! Decoders for the buffer compression of the glTF `EXT_meshopt_compression` extension.
+//!
+//! See <https://github.com/KhronosGroup/glTF/tree/main/extensions/2.0/Vendor/EXT_meshopt_compression>
+//! for the specification of the bitstreams.
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum MeshoptError {
+    #[error("Unsupported meshopt {stream} stream header {header:#04x}.")]
+    UnsupportedHeader { stream: &'static str, header: u8 },
+
+    #[error("Invalid meshopt element size {0}.")]
+    InvalidElementSize(usize),
+
+    #[error("Meshopt stream ended unexpectedly.")]
+    UnexpectedEnd,
+
+    #[error("Meshopt stream has {0} trailing bytes.")]
+    TrailingBytes(usize),
+
+    #[error("Meshopt filter {filter} doesn't support an element size of {size}.")]
+    InvalidFilterStride { filter: &'static str, size: usize },
+}
+
+/// How a compressed buffer view was encoded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Mode {
+    Attributes,
+    Triangles,
+    Indices,
+}
+
+/// Post-processing applied to decoded vertex attributes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Filter {
+    #[default]
+    None,
+    Octahedral,
+    Quaternion,
+    Exponential,
+}
+
+/// Decodes `count` elements of `size` bytes each.
+pub fn decode(
+    mode: Mode,
+    filter: Filter,
+    count: usize,
+    size: usize,
+    data: &[u8],
+) -> Result<Vec<u8>, MeshoptError> {
+    let mut decoded = match mode {
+        Mode::Attributes => decode_vertex_buffer(count, size, data)?,
+        Mode::Triangles => decode_index_buffer(count, size, data)?,
+        Mode::Indices => decode_index_sequence(count, size, data)?,
+    };
+    match filter {
+        Filter::None => {}
+        Filter::Octahedral => decode_filter_octahedral(&mut decoded, size)?,
+        Filter::Quaternion => decode_filter_quaternion(&mut decoded, size)?,
+        Filter::Exponential => decode_filter_exponential(&mut decoded, size)?,
+    }
+    Ok(decoded)
+}
+
+// ----------------------------------------------------------------------------
+// Vertex codec
+
+const VERTEX_HEADER: u8 = 0xa0;
+const VERTEX_BLOCK_SIZE_BYTES: usize = 8192;
+const VERTEX_BLOCK_MAX_SIZE: usize = 256;
+const BYTE_GROUP_SIZE: usize = 16;
+const BYTE_GROUP_DECODE_LIMIT: usize = 24;
+const TAIL_MIN_SIZE: usize = 32;
+
+/// Decodes a vertex buffer of `count` vertices with `size` bytes each.
+///
+/// Vertices are split into blocks, and every byte of the vertices in a block is delta-encoded
+/// against the same byte of the previous vertex, and then packed into groups of 16 with a
+/// per-group bit width.
+pub fn decode_vertex_buffer(
+    count: usize,
+    size: usize,
+    data: &[u8],
+) -> Result<Vec<u8>, MeshoptError> {
+    if size == 0 || size > 256 || size % 4 != 0 {
+        return Err(MeshoptError::InvalidElementSize(size));
+    }
+    if data.len() < 1 + size {
+        return Err(MeshoptError::UnexpectedEnd);
+    }
+    if data[0] != VERTEX_HEADER {
+        return Err(MeshoptError::UnsupportedHeader {
+            stream: "vertex",
+            header: data[0],
+        });
+    }
+
+    // The first vertex is stored at the very end of the stream, and is the base of all deltas.
+    let mut last_vertex = data[data.len() - size..].to_vec();
+
+    let block_size =
+        (VERTEX_BLOCK_SIZE_BYTES / size & !(BYTE_GROUP_SIZE - 1)).min(VERTEX_BLOCK_MAX_SIZE);
+    let mut decoded = vec![0; count * size];
+    let mut bytes = [0; VERTEX_BLOCK_MAX_SIZE];
+    let mut pos = 1;
+
+    for block_start in (0..count).step_by(block_size) {
+        let block_count = block_size.min(count - block_start);
+        let block_count_aligned = block_count.next_multiple_of(BYTE_GROUP_SIZE);
+        let block = &mut decoded[block_start * size..(block_start + block_count) * size];
+
+        for k in 0..size {
+            pos = decode_bytes(data, pos, &mut bytes[..block_count_aligned])?;
+
+            let mut previous = last_vertex[k];
+            for (i, &byte) in bytes[..block_count].iter().enumerate() {
+                let value = unzigzag8(byte).wrapping_add(previous);
+                block[i * size + k] = value;
+                previous = value;
+            }
+        }
+
+        last_vertex.copy_from_slice(&block[(block_count - 1) * size..]);
+    }
+
+    let tail_size = size.max(TAIL_MIN_SIZE);
+    match (data.len() - pos).cmp(&tail_size) {
+        std::cmp::Ordering::Less => Err(MeshoptError::UnexpectedEnd),
+        std::cmp::Ordering::Equal => Ok(decoded),
+        std::cmp::Ordering::Greater => {
+            Err(MeshoptError::TrailingBytes(data.len() - pos - tail_size))
+        }
+    }
+}
+
+/// Decodes `output.len()` bytes, which must be a multiple of [`BYTE_GROUP_SIZE`], starting at
+/// `pos`, and returns the position after them.
+fn decode_bytes(data: &[u8], mut pos: usize, output: &mut [u8]) -> Result<usize, MeshoptError> {
+    // Two bits per group select how many bits each of its bytes uses.
+    let header_size = (output.len() / BYTE_GROUP_SIZE).div_ceil(4);
+    let header = data
+        .get(pos..pos + header_size)
+        .ok_or(MeshoptError::UnexpectedEnd)?;
+    pos += header_size;
+
+    for (group_index, group) in output.chunks_exact_mut(BYTE_GROUP_SIZE).enumerate() {
+        if data.len() - pos < BYTE_GROUP_DECODE_LIMIT {
+            return Err(MeshoptError::UnexpectedEnd);
+        }
+
+        let bits_log2 = (header[group_index / 4] >> ((group_index % 4) * 2)) & 3;
+        pos = match bits_log2 {
+            0 => {
+                group.fill(0);
+                pos
+            }
+            1 => decode_bytes_group(data, pos, group, 2),
+            2 => decode_bytes_group(data, pos, group, 4),
+            _ => {
+                group.copy_from_slice(&data[pos..pos + BYTE_GROUP_SIZE]);
+                pos + BYTE_GROUP_SIZE
+            }
+        };
+    }
+
+    Ok(pos)
+}
+
+/// Decodes 16 packed values of `bits` bits each.
+///
+/// Values with all bits set are escapes for full bytes that follow the packed values.
+fn decode_bytes_group(data: &[u8], pos: usize, group: &mut [u8], bits: u32) -> usize {
+    let packed_size = BYTE_GROUP_SIZE * bits as usize / 8;
+    let escape = (1u8 << bits) - 1;
+    let mut escaped = pos + packed_size;
+
+    for (i, value) in group.iter_mut().enumerate() {
+        let bit_offset = i * bits as usize;
+        let shift = 8 - bits as usize - bit_offset % 8;
+        let packed = (data[pos + bit_offset / 8] >> shift) & escape;
+        *value = if packed == escape {
+            escaped += 1;
+            data[escaped - 1]
+        } else {
+            packed
+        };
+    }
+
+    escaped
+}
+
+fn unzigzag8(value: u8) -> u8 {
+    (0u8.wrapping_sub(value & 1)) ^ (value >> 1)
+}
+
+// ----------------------------------------------------------------------------
+// Index codecs
+
+const INDEX_HEADER: u8 = 0xe0;
+const SEQUENCE_HEADER: u8 = 0xd0;
+
+fn check_index_size(size: usize) -> Result<(), MeshoptError> {
+    if size == 2 || size == 4 {
+        Ok(())
+    } else {
+        Err(MeshoptError::InvalidElementSize(size))
+    }
+}
+
+fn check_header(stream: &'static str, data: &[u8], expected: u8) -> Result<u8, MeshoptError> {
+    let header = data[0];
+    let version = header & 0x0f;
+    if header & 0xf0 != expected || version > 1 {
+        return Err(MeshoptError::UnsupportedHeader { stream, header });
+    }
+    Ok(version)
+}
+
+fn write_index(output: &mut [u8], i: usize, size: usize, index: u32) {
+    if size == 2 {
+        output[i * 2..i * 2 + 2].copy_from_slice(&(index as u16).to_le_bytes());
+    } else {
+        output[i * 4..i * 4 + 4].copy_from_slice(&index.to_le_bytes());
+    }
+}
+
+/// Reads a little endian base-128 integer.
+fn decode_vbyte(data: &[u8], pos: &mut usize) -> Result<u32, MeshoptError> {
+    let mut result = 0;
+    for shift in (0..35).step_by(7) {
+        let byte = *data.get(*pos).ok_or(MeshoptError::UnexpectedEnd)?;
+        *pos += 1;
+        result |= u32::from(byte & 0x7f) << shift;
+        if byte < 0x80 {
+            break;
+        }
+    }
+    Ok(result)
+}
+
+fn unzigzag32(value: u32) -> u32 {
+    (0u32.wrapping_sub(value & 1)) ^ (value >> 1)
+}
+
+/// Decodes a triangle list of `count` indices, with `size` bytes per index.
+///
+/// Triangles are encoded relative to recently seen edges and vertices, which are kept in two
+/// small FIFOs that have to be updated in exactly the same way as when encoding.
+pub fn decode_index_buffer(
+    count: usize,
+    size: usize,
+    data: &[u8],
+) -> Result<Vec<u8>, MeshoptError> {
+    check_index_size(size)?;
+    if count % 3 != 0 {
+        return Err(MeshoptError::InvalidElementSize(count));
+    }
+    if data.len() < 1 + count / 3 + 16 {
+        return Err(MeshoptError::UnexpectedEnd);
+    }
+    let version = check_header("index", data, INDEX_HEADER)?;
+
+    let codes = &data[1..1 + count / 3];
+    let data_end = data.len() - 16;
+    let codeaux_table = &data[data_end..];
+    let data = &data[..data_end];
+    let mut pos = 1 + count / 3;
+
+    let mut edge_fifo = [[u32::MAX; 2]; 16];
+    let mut vertex_fifo = [u32::MAX; 16];
+    let mut edge_offset = 0;
+    let mut vertex_offset = 0;
+
+    let mut next = 0u32;
+    let mut last = 0u32;
+    let fec_max = if version >= 1 { 13 } else { 15 };
+
+    let mut output = vec![0; count * size];
+    for (triangle, &code) in codes.iter().enumerate() {
+        let [a, b, c] = if code < 0xf0 {
+            // The triangle shares an edge with a recent one.
+            let fe = usize::from(code >> 4);
+            let [a, b] = edge_fifo[(edge_offset + 15 - fe) & 15];
+            let fec = usize::from(code & 15);
+            let c = if fec < fec_max {
+                let is_new = fec == 0;
+                let c = if is_new {
+                    next
+                } else {
+                    vertex_fifo[(vertex_offset + 15 - fec) & 15]
+                };
+                next += u32::from(is_new);
+                push_vertex(&mut vertex_fifo, &mut vertex_offset, c, is_new);
+                c
+            } else {
+                // 13 and 14 are small deltas, 15 is a free index.
+                last = match fec {
+                    13 => last.wrapping_sub(1),
+                    14 => last.wrapping_add(1),
+                    _ => last.wrapping_add(unzigzag32(decode_vbyte(data, &mut pos)?)),
+                };
+                push_vertex(&mut vertex_fifo, &mut vertex_offset, last, true);
+                last
+            };
+            push_edge(&mut edge_fifo, &mut edge_offset, c, b);
+            push_edge(&mut edge_fifo, &mut edge_offset, a, c);
+            [a, b, c]
+        } else {
+            // The triangle doesn't share an edge, and its vertices are either new, recent, or
+            // free indices.
+            let fe = if code < 0xfe {
+                let codeaux = codeaux_table[usize::from(code & 15)];
+                [0, codeaux >> 4, codeaux & 15]
+            } else {
+                let codeaux = *data.get(pos).ok_or(MeshoptError::UnexpectedEnd)?;
+                pos += 1;
+                if codeaux == 0 {
+                    next = 0;
+                }
+                [
+                    if code == 0xfe { 0 } else { 15 },
+                    codeaux >> 4,
+                    codeaux & 15,
+                ]
+            }
+            .map(usize::from);
+
+            // `next` is advanced for all new vertices before any of the free indices is read.
+            let mut vertices = fe.map(|fe| {
+                if fe == 0 {
+                    next += 1;
+                    next - 1
+                } else {
+                    vertex_fifo[(vertex_offset + 16 - fe) & 15]
+                }
+            });
+            for (vertex, fe) in vertices.iter_mut().zip(fe) {
+                if fe == 15 {
+                    last = last.wrapping_add(unzigzag32(decode_vbyte(data, &mut pos)?));
+                    *vertex = last;
+                }
+            }
+
+            let [a, b, c] = vertices;
+            push_vertex(&mut vertex_fifo, &mut vertex_offset, a, true);
+            push_vertex(
+                &mut vertex_fifo,
+                &mut vertex_offset,
+                b,
+                fe[1] == 0 || fe[1] == 15,
+            );
+            push_vertex(
+                &mut vertex_fifo,
+                &mut vertex_offset,
+                c,
+                fe[2] == 0 || fe[2] == 15,
+            );
+            push_edge(&mut edge_fifo, &mut edge_offset, b, a);
+            push_edge(&mut edge_fifo, &mut edge_offset, c, b);
+            push_edge(&mut edge_fifo, &mut edge_offset, a, c);
+            vertices
+        };
+
+        write_index(&mut output, triangle * 3, size, a);
+        write_index(&mut output, triangle * 3 + 1, size, b);
+        write_index(&mut output, triangle * 3 + 2, size, c);
+    }
+
+    if pos != data.len() {
+        return Err(MeshoptError::TrailingBytes(data.len().saturating_sub(pos)));
+    }
+
+    Ok(output)
+}
+
+fn push_edge(fifo: &mut [[u32; 2]; 16], offset: &mut usize, a: u32, b: u32) {
+    fifo[*offset] = [a, b];
+    *offset = (*offset + 1) & 15;
+}
+
+fn push_vertex(fifo: &mut [u32; 16], offset: &mut usize, vertex: u32, advance: bool) {
+    fifo[*offset] = vertex;
+    *offset = (*offset + usize::from(advance)) & 15;
+}
+
+/// Decodes an arbitrary index sequence of `count` indices, with `size` bytes per index.
+///
+/// Every index is a delta to one of two previous indices.
+pub fn decode_index_sequence(
+    count: usize,
+    size: usize,
+    data: &[u8],
+) -> Result<Vec<u8>, MeshoptError> {
+    check_index_size(size)?;
+    if data.len() < 1 + count + 4 {
+        return Err(MeshoptError::UnexpectedEnd);
+    }
+    check_header("index sequence", data, SEQUENCE_HEADER)?;
+
+    let data = &data[..data.len() - 4];
+    let mut pos = 1;
+    let mut last = [0u32; 2];
+
+    let mut output = vec![0; count * size];
+    for i in 0..count {
+        if pos >= data.len() {
+            return Err(MeshoptError::UnexpectedEnd);
+        }
+        let value = decode_vbyte(data, &mut pos)?;
+        let baseline = (value & 1) as usize;
+        let index = last[baseline].wrapping_add(unzigzag32(value >> 1));
+        last[baseline] = index;
+        write_index(&mut output, i, size, index);
+    }
+
+    if pos != data.len() {
+        return Err(MeshoptError::TrailingBytes(data.len() - pos));
+    }
+
+    Ok(output)
+}
+
+// ----------------------------------------------------------------------------
+// Filters
+
+/// Reconstructs unit vectors from octahedral encoding, in 8 bit (`size == 4`) or 16 bit
+/// (`size == 8`) signed normalized components.
+///
+/// The fourth component is passed through untouched.
+fn decode_filter_octahedral(data: &mut [u8], size: usize) -> Result<(), MeshoptError> {
+    fn decode(x: f32, y: f32, one: f32, max: f32) -> [f32; 3] {
+        let mut x = x;
+        let mut y = y;
+        let z = one - x.abs() - y.abs();
+
+        // Fold the lower hemisphere back out.
+        let t = z.min(0.0);
+        x -= if x >= 0.0 { t } else { -t };
+        y -= if y >= 0.0 { t } else { -t };
+
+        let scale = max / (x * x + y * y + z * z).sqrt();
+        [x * scale, y * scale, z * scale].map(|v| (v + 0.5f32.copysign(v)).trunc())
+    }
+
+    match size {
+        4 => {
+            for element in data.chunks_exact_mut(4) {
+                let [x, y, one] = [0, 1, 2].map(|i| f32::from(element[i] as i8));
+                for (byte, value) in element.iter_mut().zip(decode(x, y, one, 127.0)) {
+                    *byte = value as i8 as u8;
+                }
+            }
+            Ok(())
+        }
+        8 => {
+            for element in data.chunks_exact_mut(8) {
+                let [x, y, one] = [0, 1, 2].map(|i| f32::from(read_i16(element, i)));
+                for (i, value) in decode(x, y, one, 32767.0).into_iter().enumerate() {
+                    write_i16(element, i, value as i16);
+                }
+            }
+            Ok(())
+        }
+        _ => Err(MeshoptError::InvalidFilterStride {
+            filter: "OCTAHEDRAL",
+            size,
+        }),
+    }
+}
+
+/// Reconstructs unit quaternions from their three smallest components.
+///
+/// The fourth 16 bit component holds the index of the omitted component in its two low bits,
+/// and the scale of the other components in the remaining ones.
+fn decode_filter_quaternion(data: &mut [u8], size: usize) -> Result<(), MeshoptError> {
+    if size != 8 {
+        return Err(MeshoptError::InvalidFilterStride {
+            filter: "QUATERNION",
+            size,
+        });
+    }
+
+    for element in data.chunks_exact_mut(8) {
+        let packed = read_i16(element, 3);
+        let scale = std::f32::consts::FRAC_1_SQRT_2 / f32::from(packed | 3);
+        let [x, y, z] = [0, 1, 2].map(|i| f32::from(read_i16(element, i)) * scale);
+        let w = (1.0 - x * x - y * y - z * z).max(0.0).sqrt();
+
+        let omitted = (packed & 3) as usize;
+        for (offset, value) in [w, x, y, z].into_iter().enumerate() {
+            let value = (value * 32767.0 + 0.5f32.copysign(value)).trunc();
+            write_i16(element, (omitted + offset) & 3, value as i16);
+        }
+    }
+
+    Ok(())
+}
+
+/// Reconstructs 32 bit floats from a 24 bit signed mantissa and an 8 bit signed exponent.
+fn decode_filter_exponential(data: &mut [u8], size: usize) -> Result<(), MeshoptError> {
+    if size % 4 != 0 {
+        return Err(MeshoptError::InvalidFilterStride {
+            filter: "EXPONENTIAL",
+            size,
+        });
+    }
+
+    for value in data.chunks_exact_mut(4) {
+        let bits = u32::from_le_bytes([value[0], value[1], value[2], value[3]]);
+        let mantissa = ((bits << 8) as i32) >> 8;
+        let exponent = (bits as i32) >> 24;
+        let decoded = (mantissa as f32) * 2f32.powi(exponent);
+        value.copy_from_slice(&decoded.to_le_bytes());
+    }
+
+    Ok(())
+}
+
+fn read_i16(element: &[u8], i: usize) -> i16 {
+    i16::from_le_bytes([element[i * 2], element[i * 2 + 1]])
+}
+
+fn write_i16(element: &mut [u8], i: usize, value: i16) {
+    element[i * 2..i * 2 + 2].copy_from_slice(&value.to_le_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn indices_u16(bytes: &[u8]) -> Vec<u16> {
+        bytes
+            .chunks_exact(2)
+            .map(|b| u16::from_le_bytes([b[0], b[1]]))
+            .collect()
+    }
+
+    #[test]
+    fn vertex_buffer() {
+        // Two 4 byte vertices, the second one is the first one plus 4 in every byte.
+        let mut data = vec![VERTEX_HEADER];
+        for _ in 0..4 {
+            // One group of 4 bit values: a delta of 0, then zigzag(4) = 8.
+            data.extend([0b10, 0x08, 0, 0, 0, 0, 0, 0, 0]);
+        }
+        data.extend([0; TAIL_MIN_SIZE - 4]);
+        data.extend([1, 2, 3, 4]);
+
+        assert_eq!(
+            decode_vertex_buffer(2, 4, &data),
+            Ok(vec![1, 2, 3, 4, 5, 6, 7, 8])
+        );
+        assert_eq!(
+            decode_vertex_buffer(2, 4, &data[..data.len() - 1]),
+            Err(MeshoptError::UnexpectedEnd)
+        );
+        assert!(decode_vertex_buffer(2, 3, &data).is_err());
+    }
+
+    #[test]
+    fn index_buffer() {
+        // A triangle of three new vertices, and one that shares its second edge.
+        let mut data = vec![INDEX_HEADER | 1, 0xf0, 0x10];
+        data.extend([
+            0x00, 0x76, 0x87, 0x56, 0x67, 0x78, 0xa9, 0x86, 0x65, 0x89, 0x68, 0x98, 0x01, 0x69, 0,
+            0,
+        ]);
+
+        let decoded = decode_index_buffer(6, 2, &data).unwrap();
+        assert_eq!(indices_u16(&decoded), [0, 1, 2, 2, 1, 3]);
+    }
+
+    #[test]
+    fn index_sequence() {
+        let data = [SEQUENCE_HEADER | 1, 0, 4, 4, 12, 0, 0, 0, 0];
+        let decoded = decode_index_sequence(4, 2, &data).unwrap();
+        assert_eq!(indices_u16(&decoded), [0, 1, 2, 5]);
+    }
+
+    #[test]
+    fn filters() {
+        let mut exponential = 0xff00_0003u32.to_le_bytes().to_vec();
+        decode_filter_exponential(&mut exponential, 4).unwrap();
+        assert_eq!(exponential, 1.5f32.to_le_bytes());
+
+        // Identity, with `w` omitted.
+        let mut quaternion = [0i16, 0, 0, 0x7fff]
+            .iter()
+            .flat_map(|v| v.to_le_bytes())
+            .collect::<Vec<_>>();
+        decode_filter_quaternion(&mut quaternion, 8).unwrap();
+        assert_eq!(
+            [0, 1, 2, 3].map(|i| read_i16(&quaternion, i)),
+            [0, 0, 0, 32767]
+        );
+
+        let mut octahedral = vec![127, 0, 127, 42];
+        decode_filter_octahedral(&mut octahedral, 4).unwrap();
+        assert_eq!(octahedral, [127, 0, 0, 42]);
+    }
+}
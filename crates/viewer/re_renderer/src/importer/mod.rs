@@ -1,3 +1,4 @@
+mod animation;
 mod cpu_model;
 
 #[cfg(feature = "import-obj")]
@@ -6,7 +7,14 @@ pub mod obj;
 #[cfg(feature = "import-gltf")]
 pub mod gltf;
 
+#[cfg(feature = "import-gltf")]
+mod meshopt;
+
 #[cfg(feature = "import-stl")]
 pub mod stl;
 
+pub use animation::{
+    Animation, AnimationChannel, AnimationProperty, DeformedVertices, Interpolation, MeshDeformer,
+    ModelRig, MorphTarget, NodeTransform, Pose, PosedMeshInstance, RigNode, Skin,
+};
 pub use cpu_model::{CpuMeshInstance, CpuModel, CpuModelMeshKey};
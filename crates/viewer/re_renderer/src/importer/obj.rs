@@ -3,6 +3,7 @@ use smallvec::smallvec;
 use crate::{
     CpuModel, RenderContext, Rgba32Unmul,
     mesh::{CpuMesh, Material, MeshError},
+    resource_managers::GpuTexture2D,
 };
 
 #[derive(thiserror::Error, Debug)]
@@ -19,6 +20,14 @@ pub enum ObjImportError {
 pub fn load_obj_from_buffer(
     buffer: &[u8],
     ctx: &RenderContext,
+) -> Result<CpuModel, ObjImportError> {
+    cpu_model_from_obj(buffer, ctx.texture_manager_2d.white_texture_unorm_handle())
+}
+
+/// Like [`load_obj_from_buffer`], but doesn't need the render context, so it can run on any thread.
+pub fn cpu_model_from_obj(
+    buffer: &[u8],
+    albedo: &GpuTexture2D,
 ) -> Result<CpuModel, ObjImportError> {
     re_tracing::profile_function!();
 
@@ -79,8 +88,6 @@ pub fn load_obj_from_buffer(
             .collect();
         vertex_texcoords.resize(vertex_positions.len(), glam::Vec2::ZERO);
 
-        let texture = ctx.texture_manager_2d.white_texture_unorm_handle();
-
         let mesh = CpuMesh {
             label: obj_model.name.into(),
             triangle_indices,
@@ -94,7 +101,7 @@ pub fn load_obj_from_buffer(
             materials: smallvec![Material {
                 label: "default material".into(),
                 index_range: 0..mesh.indices.len() as u32,
-                albedo: texture.clone(),
+                albedo: albedo.clone(),
                 albedo_factor: crate::Rgba::WHITE,
             }],
         };
@@ -1,7 +1,7 @@
 use itertools::Itertools as _;
 use smallvec::smallvec;
 
-use crate::{CpuModel, DebugLabel, RenderContext, mesh};
+use crate::{CpuModel, DebugLabel, RenderContext, mesh, resource_managers::GpuTexture2D};
 
 #[derive(thiserror::Error, Debug)]
 pub enum StlImportError {
@@ -16,22 +16,26 @@ pub enum StlImportError {
 pub fn load_stl_from_buffer(
     buffer: &[u8],
     ctx: &RenderContext,
+) -> Result<CpuModel, StlImportError> {
+    cpu_model_from_stl(buffer, ctx.texture_manager_2d.white_texture_unorm_handle())
+}
+
+/// Like [`load_stl_from_buffer`], but doesn't need the render context, so it can run on any thread.
+pub fn cpu_model_from_stl(
+    buffer: &[u8],
+    albedo: &GpuTexture2D,
 ) -> Result<CpuModel, StlImportError> {
     re_tracing::profile_function!();
 
     let mut cursor = std::io::Cursor::new(buffer);
     let reader = stl_io::create_stl_reader(&mut cursor).map_err(StlImportError::StlIoError)?;
 
-    // TODO(hmeyer/stl_io#26): use optional name from ascii stl files.
-    // https://github.com/hmeyer/stl_io/pull/26
-    let name = DebugLabel::from("");
-
     let (normals, triangles): (Vec<_>, Vec<_>) = reader
         .into_iter()
         .map(|triangle_res| {
             triangle_res.map(|triangle| {
                 (
-                    [triangle.normal.0, triangle.normal.0, triangle.normal.0],
+                    triangle.normal.0,
                     [
                         triangle.vertices[0].0,
                         triangle.vertices[1].0,
@@ -45,12 +49,97 @@ pub fn load_stl_from_buffer(
         .into_iter()
         .unzip();
 
+    cpu_model_from_triangles(&normals, triangles, albedo)
+}
+
+/// A coarse preview of a binary STL file, made of at most `max_triangles` evenly spread triangles.
+///
+/// Reading every n-th triangle is much faster than parsing the whole file.
+/// Returns `None` for ASCII STL files, and for files with no more than `max_triangles` triangles.
+pub fn cpu_model_preview_from_binary_stl(
+    buffer: &[u8],
+    max_triangles: usize,
+    albedo: &GpuTexture2D,
+) -> Option<Result<CpuModel, StlImportError>> {
+    re_tracing::profile_function!();
+
+    let (normals, triangles) = sample_binary_stl_triangles(buffer, max_triangles)?;
+    Some(cpu_model_from_triangles(&normals, triangles, albedo))
+}
+
+/// Size of the header of a binary STL file, followed by the number of triangles.
+const BINARY_STL_HEADER_SIZE: usize = 80;
+
+/// A normal, three vertices, and a two byte attribute.
+const BINARY_STL_TRIANGLE_SIZE: usize = 50;
+
+/// Reads every n-th triangle of a binary STL file, such that at most `max_triangles` are read.
+///
+/// Returns the normal and vertices of each triangle read.
+fn sample_binary_stl_triangles(
+    buffer: &[u8],
+    max_triangles: usize,
+) -> Option<(Vec<[f32; 3]>, Vec<[[f32; 3]; 3]>)> {
+    let num_triangles = buffer
+        .get(BINARY_STL_HEADER_SIZE..BINARY_STL_HEADER_SIZE + 4)
+        .and_then(|count| count.try_into().ok())
+        .map(u32::from_le_bytes)? as usize;
+    let triangles_start = BINARY_STL_HEADER_SIZE + 4;
+
+    // ASCII files don't have this exact size.
+    if buffer.len() != triangles_start + num_triangles * BINARY_STL_TRIANGLE_SIZE
+        || num_triangles <= max_triangles
+        || max_triangles == 0
+    {
+        return None;
+    }
+
+    let read_vec3 = |offset: usize| -> [f32; 3] {
+        std::array::from_fn(|i| {
+            let start = offset + 4 * i;
+            f32::from_le_bytes([
+                buffer[start],
+                buffer[start + 1],
+                buffer[start + 2],
+                buffer[start + 3],
+            ])
+        })
+    };
+
+    let stride = num_triangles.div_ceil(max_triangles);
+    Some(
+        (0..num_triangles)
+            .step_by(stride)
+            .map(|triangle| {
+                let offset = triangles_start + triangle * BINARY_STL_TRIANGLE_SIZE;
+                (
+                    read_vec3(offset),
+                    [
+                        read_vec3(offset + 12),
+                        read_vec3(offset + 24),
+                        read_vec3(offset + 36),
+                    ],
+                )
+            })
+            .unzip(),
+    )
+}
+
+fn cpu_model_from_triangles(
+    normals: &[[f32; 3]],
+    triangles: Vec<[[f32; 3]; 3]>,
+    albedo: &GpuTexture2D,
+) -> Result<CpuModel, StlImportError> {
+    // TODO(hmeyer/stl_io#26): use optional name from ascii stl files.
+    // https://github.com/hmeyer/stl_io/pull/26
+    let name = DebugLabel::from("");
+
     let num_vertices = triangles.len() * 3;
 
     let material = mesh::Material {
         label: name.clone(),
         index_range: 0..num_vertices as u32,
-        albedo: ctx.texture_manager_2d.white_texture_unorm_handle().clone(),
+        albedo: albedo.clone(),
         albedo_factor: crate::Rgba::WHITE,
     };
 
@@ -68,7 +157,10 @@ pub fn load_stl_from_buffer(
 
         // Normals on STL are per triangle, not per vertex.
         // Yes, this makes STL always look faceted.
-        vertex_normals: bytemuck::cast_vec(normals),
+        vertex_normals: normals
+            .iter()
+            .flat_map(|normal| [glam::Vec3::from(*normal); 3])
+            .collect(),
 
         // STL has neither colors nor texcoords.
         vertex_colors: vec![crate::Rgba32Unmul::WHITE; num_vertices],
@@ -83,3 +175,41 @@ pub fn load_stl_from_buffer(
 
     Ok(CpuModel::from_single_mesh(mesh))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A binary STL file with one triangle per index, whose first vertex is `(index, 0, 0)`.
+    fn binary_stl(num_triangles: u32) -> Vec<u8> {
+        let mut buffer = vec![0; BINARY_STL_HEADER_SIZE];
+        buffer.extend(num_triangles.to_le_bytes());
+        for triangle in 0..num_triangles {
+            let mut floats = [0.0_f32; 12];
+            floats[2] = 1.0; // normal
+            floats[3] = triangle as f32;
+            buffer.extend(floats.iter().flat_map(|f| f.to_le_bytes()));
+            buffer.extend([0, 0]); // attribute
+        }
+        buffer
+    }
+
+    #[test]
+    fn sampling() {
+        let buffer = binary_stl(10);
+
+        let (normals, triangles) = sample_binary_stl_triangles(&buffer, 4).unwrap();
+        assert_eq!(normals, vec![[0.0, 0.0, 1.0]; 4]);
+        assert_eq!(
+            triangles.iter().map(|t| t[0][0]).collect::<Vec<_>>(),
+            vec![0.0, 3.0, 6.0, 9.0]
+        );
+
+        // Nothing to gain over loading the whole file.
+        assert!(sample_binary_stl_triangles(&buffer, 10).is_none());
+
+        // Not a binary STL file.
+        assert!(sample_binary_stl_triangles(b"solid cube\nendsolid cube\n", 4).is_none());
+        assert!(sample_binary_stl_triangles(&buffer[..buffer.len() - 1], 4).is_none());
+    }
+}
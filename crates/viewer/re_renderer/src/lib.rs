@@ -55,6 +55,7 @@ mod line_drawable_builder;
 mod point_cloud_builder;
 mod queueable_draw_data;
 mod rect;
+mod shader_preprocessor;
 mod size;
 mod transform;
 mod wgpu_resources;
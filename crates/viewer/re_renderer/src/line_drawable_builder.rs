@@ -28,6 +28,7 @@ pub struct LineDrawableBuilder<'ctx> {
     pub(crate) picking_instance_ids_buffer: DataTextureSource<'ctx, PickingLayerInstanceId>,
 
     pub(crate) radius_boost_in_ui_points_for_outlines: f32,
+    pub(crate) radius_boost_in_ui_points_for_picking: f32,
 }
 
 impl<'ctx> LineDrawableBuilder<'ctx> {
@@ -39,6 +40,7 @@ impl<'ctx> LineDrawableBuilder<'ctx> {
             batches: Vec::with_capacity(16),
             picking_instance_ids_buffer: DataTextureSource::new(ctx),
             radius_boost_in_ui_points_for_outlines: 0.0,
+            radius_boost_in_ui_points_for_picking: 0.0,
         }
     }
 
@@ -64,6 +66,17 @@ impl<'ctx> LineDrawableBuilder<'ctx> {
         self.radius_boost_in_ui_points_for_outlines = radius_boost_in_ui_points_for_outlines;
     }
 
+    /// Boosts the size of the lines by the given amount of ui-points for the purpose of picking.
+    ///
+    /// Makes thin lines, arrows and box edges easier to hover, since they otherwise
+    /// may only cover a sub-pixel area on the picking layer.
+    pub fn radius_boost_in_ui_points_for_picking(
+        &mut self,
+        radius_boost_in_ui_points_for_picking: f32,
+    ) {
+        self.radius_boost_in_ui_points_for_picking = radius_boost_in_ui_points_for_picking;
+    }
+
     /// Start of a new batch.
     pub fn batch(&mut self, label: impl Into<DebugLabel>) -> LineBatchBuilder<'_, 'ctx> {
         self.batches.push(LineBatchInfo {
@@ -237,6 +250,15 @@ impl<'ctx> LineBatchBuilder<'_, 'ctx> {
         self
     }
 
+    /// Sets the opacity for the entire batch.
+    ///
+    /// See [`LineBatchInfo::opacity`].
+    #[inline]
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.batch_mut().opacity = opacity;
+        self
+    }
+
     /// Sets the length factor as multiple of a line's radius applied to all triangle caps in this batch.
     ///
     /// This controls how far the "pointy end" of the triangle/arrow-head extends.
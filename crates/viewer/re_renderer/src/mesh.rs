@@ -167,6 +167,11 @@ pub enum MeshError {
     #[error("Index {index} was out of bounds for {num_pos} vertex positions")]
     IndexOutOfBounds { num_pos: usize, index: u32 },
 
+    #[error(
+        "Number of deformed vertices {num_deformed} differed from the number of vertices {num_pos}"
+    )]
+    WrongNumberOfDeformedVertices { num_pos: usize, num_deformed: usize },
+
     #[error(transparent)]
     CpuWriteGpuReadError(#[from] crate::allocator::CpuWriteGpuReadError),
 }
@@ -414,4 +419,85 @@ impl GpuMesh {
             bbox: data.bbox,
         })
     }
+
+    /// Creates a copy of this mesh with different vertex positions and normals, e.g. for a skinned
+    /// or morphed mesh.
+    ///
+    /// Only the new positions and normals are uploaded, everything else is shared with, or copied
+    /// on the GPU from, the original mesh.
+    pub fn with_deformed_vertices(
+        &self,
+        ctx: &RenderContext,
+        vertex_positions: &[glam::Vec3],
+        vertex_normals: &[glam::Vec3],
+        bbox: macaw::BoundingBox,
+    ) -> Result<Self, MeshError> {
+        re_tracing::profile_function!();
+
+        let num_pos = (self.vertex_buffer_positions_range.end
+            - self.vertex_buffer_positions_range.start) as usize
+            / size_of::<glam::Vec3>();
+        if vertex_positions.len() != num_pos || vertex_normals.len() != num_pos {
+            return Err(MeshError::WrongNumberOfDeformedVertices {
+                num_pos,
+                num_deformed: vertex_positions.len().max(vertex_normals.len()),
+            });
+        }
+        if bbox.is_nan() || !bbox.is_finite() || bbox.is_nothing() {
+            return Err(MeshError::InvalidBbox(bbox));
+        }
+
+        let vertex_buffer_combined = ctx.gpu_resources.buffers.alloc(
+            &ctx.device,
+            &BufferDesc {
+                label: format!(
+                    "{} - deformed",
+                    self.vertex_buffer_combined.creation_desc.label
+                )
+                .into(),
+                size: self.vertex_buffer_combined.size(),
+                usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            },
+        );
+
+        for (vertices, range) in [
+            (vertex_positions, &self.vertex_buffer_positions_range),
+            (vertex_normals, &self.vertex_buffer_normals_range),
+        ] {
+            let mut staging_buffer = ctx.cpu_write_gpu_read_belt.lock().allocate::<glam::Vec3>(
+                &ctx.device,
+                &ctx.gpu_resources.buffers,
+                vertices.len(),
+            )?;
+            staging_buffer.extend_from_slice(vertices)?;
+            staging_buffer.copy_to_buffer(
+                ctx.active_frame.before_view_builder_encoder.lock().get(),
+                &vertex_buffer_combined,
+                range.start,
+            )?;
+        }
+
+        // Colors and texture coordinates don't change.
+        let mut encoder = ctx.active_frame.before_view_builder_encoder.lock();
+        for range in [
+            &self.vertex_buffer_colors_range,
+            &self.vertex_buffer_texcoord_range,
+        ] {
+            encoder.get().copy_buffer_to_buffer(
+                &self.vertex_buffer_combined,
+                range.start,
+                &vertex_buffer_combined,
+                range.start,
+                range.end - range.start,
+            );
+        }
+        drop(encoder);
+
+        Ok(Self {
+            vertex_buffer_combined,
+            bbox,
+            ..self.clone()
+        })
+    }
 }
@@ -8,9 +8,10 @@ use crate::{
     allocator::DataTextureSource,
     draw_phases::PickingLayerObjectId,
     renderer::{
-        PointCloudBatchFlags, PointCloudBatchInfo, PointCloudDrawData, PointCloudDrawDataError,
-        gpu_data::PositionRadius,
+        PointCloudBatchFlags, PointCloudBatchInfo, PointCloudCropBox, PointCloudDrawData,
+        PointCloudDrawDataError, gpu_data::PositionRadius,
     },
+    wgpu_resources::GpuTexture,
 };
 
 /// Builder for point clouds, making it easy to create [`crate::renderer::PointCloudDrawData`].
@@ -131,6 +132,33 @@ impl PointCloudBatchBuilder<'_, '_> {
         self
     }
 
+    /// Sets the opacity for the entire batch.
+    ///
+    /// See [`PointCloudBatchInfo::opacity`].
+    #[inline]
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.batch_mut().opacity = opacity;
+        self
+    }
+
+    /// Sets the box hiding the points inside or outside of it for the entire batch.
+    ///
+    /// See [`PointCloudBatchInfo::crop_box`].
+    #[inline]
+    pub fn crop_box(mut self, crop_box: Option<PointCloudCropBox>) -> Self {
+        self.batch_mut().crop_box = crop_box;
+        self
+    }
+
+    /// Sets the normals the points of this batch are lit by.
+    ///
+    /// See [`PointCloudBatchInfo::normals`].
+    #[inline]
+    pub fn normals(mut self, normals: Option<GpuTexture>) -> Self {
+        self.batch_mut().normals = normals;
+        self
+    }
+
     /// Add several 3D points
     ///
     /// Returns a `PointBuilder` which can be used to set the colors, radii, and user-data for the points.
@@ -30,14 +30,14 @@ use crate::{
     },
 };
 
-use super::{DrawData, DrawError, RenderContext, Renderer};
+use super::{DrawData, DrawError, PointCloudCropBox, RenderContext, Renderer};
 
 // ---
 
 mod gpu_data {
     use crate::{PickingLayerObjectId, wgpu_buffer_types};
 
-    use super::DepthCloudDrawDataError;
+    use super::{DepthCloudDrawDataError, PointCloudCropBox};
 
     // Keep in sync with mirror in `depth_cloud.wgsl.`
 
@@ -77,10 +77,24 @@ mod gpu_data {
         /// Changes over different draw-phases.
         pub radius_boost_in_ui_points: f32,
 
-        pub _row_padding: [f32; 1],
+        /// Only every n-th texel in each direction is turned into a point.
+        pub subsampling: u32,
 
         // ---
-        pub _end_padding: [wgpu_buffer_types::PaddingRow; 16 - 4 - 3 - 1 - 1 - 1],
+        /// Points with a world-space depth outside of this range are not drawn.
+        pub valid_depth_range_in_world: [f32; 2],
+
+        /// 0: no crop box, 1: keep inside, 2: keep outside
+        pub crop_mode: u32,
+
+        pub _row_padding: u32,
+
+        // ---
+        pub crop_box_min: wgpu_buffer_types::Vec3RowPadded,
+        pub crop_box_max: wgpu_buffer_types::Vec3RowPadded,
+
+        // ---
+        pub _end_padding: [wgpu_buffer_types::PaddingRow; 16 - 4 - 3 - 1 - 1 - 1 - 1 - 2],
     }
 
     impl DepthCloudInfoUBO {
@@ -94,11 +108,14 @@ mod gpu_data {
                 world_depth_from_texture_depth,
                 point_radius_from_world_depth,
                 min_max_depth_in_world,
+                valid_depth_range_in_world,
+                subsampling,
                 depth_dimensions: _,
                 depth_texture,
                 colormap,
                 outline_mask_id,
                 picking_object_id,
+                crop_box,
             } = depth_cloud;
 
             let texture_format = depth_texture.texture.format();
@@ -113,6 +130,9 @@ mod gpu_data {
                 }
             };
 
+            let (crop_mode, crop_box_min, crop_box_max) =
+                PointCloudCropBox::gpu_data(crop_box.as_ref());
+
             Ok(Self {
                 world_from_rdf: (*world_from_obj).into(),
                 depth_camera_intrinsics: (*depth_camera_intrinsics).into(),
@@ -123,8 +143,13 @@ mod gpu_data {
                 colormap: *colormap as u32,
                 sample_type,
                 radius_boost_in_ui_points,
+                subsampling: (*subsampling).max(1),
+                valid_depth_range_in_world: *valid_depth_range_in_world,
                 picking_layer_object_id: *picking_object_id,
+                crop_mode,
                 _row_padding: Default::default(),
+                crop_box_min: crop_box_min.into(),
+                crop_box_max: crop_box_max.into(),
                 _end_padding: Default::default(),
             })
         }
@@ -150,6 +175,16 @@ pub struct DepthCloud {
     /// The minimum and maximum depth value in world-space, for use with the colormap.
     pub min_max_depth_in_world: [f32; 2],
 
+    /// Points with a world-space depth outside of this range are not drawn.
+    ///
+    /// Use `[0.0, f32::INFINITY]` to draw all points with a positive depth.
+    pub valid_depth_range_in_world: [f32; 2],
+
+    /// Only every n-th texel in each direction is turned into a point.
+    ///
+    /// `1` draws a point for every texel, `0` is treated like `1`.
+    pub subsampling: u32,
+
     /// The dimensions of the depth texture in pixels.
     pub depth_dimensions: glam::UVec2,
 
@@ -166,9 +201,19 @@ pub struct DepthCloud {
 
     /// Picking object id that applies for the entire depth cloud.
     pub picking_object_id: PickingLayerObjectId,
+
+    /// Optional box hiding the points inside or outside of it.
+    pub crop_box: Option<PointCloudCropBox>,
 }
 
 impl DepthCloud {
+    /// The number of points drawn for this depth cloud, taking [`Self::subsampling`] into account.
+    pub fn num_points(&self) -> u32 {
+        let subsampling = self.subsampling.max(1);
+        self.depth_dimensions.x.div_ceil(subsampling)
+            * self.depth_dimensions.y.div_ceil(subsampling)
+    }
+
     /// World-space bounding-box.
     ///
     /// Assumes max extent to be the maximum depth used for colormapping
@@ -340,7 +385,7 @@ impl DepthCloudDrawData {
 
             instances.push(DepthCloudDrawInstance {
                 sorting_world_position: depth_cloud.world_from_rdf.translation,
-                num_points: depth_cloud.depth_dimensions.x * depth_cloud.depth_dimensions.y,
+                num_points: depth_cloud.num_points(),
                 bind_group_opaque,
                 bind_group_outline,
                 render_outline_mask: depth_cloud.outline_mask_id.is_some(),
@@ -0,0 +1,640 @@
+//! Renderer for 3D Gaussian splats.
+//!
+//! How it works:
+//! =================
+//! Each splat is an anisotropic 3D gaussian given by its center, its 3D covariance, a color and an opacity.
+//! In the vertex shader the covariance is projected to screen space (EWA splatting) and a quad is spanned
+//! along the axes of the resulting 2D gaussian. The fragment shader then evaluates the gaussian falloff.
+//!
+//! Splats are alpha blended and therefore have to be drawn back to front.
+//! Since there are no compute shaders on WebGL, sorting happens on the CPU when the draw data is created,
+//! with respect to a view position that has to be passed in.
+//! Like with [`super::PointCloudDrawData`], the data is uploaded as data textures and all splats of a batch
+//! are drawn with a single draw call without any vertex buffer.
+//!
+//! Colors may depend on the view direction, given by spherical harmonics up to degree 3.
+//! They are evaluated in the vertex shader, for the direction from the camera to the splat center.
+
+use std::{num::NonZeroU64, ops::Range};
+
+use enumset::{EnumSet, enum_set};
+use smallvec::smallvec;
+
+use crate::{
+    Color32, DebugLabel, DrawableCollector, OutlineMaskPreference, PickingLayerInstanceId,
+    allocator::{
+        DataTextureSource, DataTextureSourceWriteError, create_and_fill_uniform_buffer_batch,
+    },
+    draw_phases::{DrawPhase, OutlineMaskProcessor, PickingLayerObjectId, PickingLayerProcessor},
+    include_shader_module,
+    renderer::{DrawDataDrawable, DrawInstruction, DrawableCollectionViewInfo},
+    view_builder::ViewBuilder,
+    wgpu_resources::{
+        BindGroupDesc, BindGroupEntry, BindGroupLayoutDesc, GpuBindGroup, GpuBindGroupLayoutHandle,
+        GpuRenderPipelineHandle, GpuRenderPipelinePoolAccessor, PipelineLayoutDesc,
+        RenderPipelineDesc,
+    },
+};
+
+use super::{DrawData, DrawError, PointCloudCropBox, RenderContext, Renderer};
+
+pub mod gpu_data {
+    use crate::{draw_phases::PickingLayerObjectId, wgpu_buffer_types};
+
+    /// Uniform buffer that changes for every batch of splats.
+    #[repr(C)]
+    #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+    pub struct BatchUniformBuffer {
+        pub world_from_obj: wgpu_buffer_types::Mat4,
+
+        pub outline_mask_ids: wgpu_buffer_types::UVec2,
+        pub picking_object_id: PickingLayerObjectId,
+
+        /// Linear part of the inverse of `world_from_obj`, to get view directions in object space.
+        pub obj_from_world: wgpu_buffer_types::Mat3,
+
+        /// Index of the first splat of the batch, to look up its spherical harmonics.
+        pub first_splat_index: u32,
+        pub num_sh_coefficients: u32,
+        pub row_padding: [u32; 2],
+
+        pub end_padding: [wgpu_buffer_types::PaddingRow; 16 - 9],
+    }
+}
+
+/// Computes the covariance matrix of a gaussian from its orientation and its standard deviations along its local axes.
+///
+/// This is the parameterization used by most Gaussian splatting reconstructions.
+#[inline]
+pub fn gaussian_covariance(rotation: glam::Quat, scale: glam::Vec3) -> glam::Mat3 {
+    let rotation_scale = glam::Mat3::from_quat(rotation) * glam::Mat3::from_diagonal(scale);
+    rotation_scale * rotation_scale.transpose()
+}
+
+/// Data for a batch of Gaussian splats that share the same transform.
+///
+/// All slices are expected to have the same length.
+/// If they don't, the batch is truncated to the shortest slice.
+pub struct GaussianSplatBatchInfo<'a> {
+    pub label: DebugLabel,
+
+    /// Transformation applied to the splats, including their covariances.
+    pub world_from_obj: glam::Affine3A,
+
+    /// Splat centers in object space.
+    pub centers: &'a [glam::Vec3],
+
+    /// Symmetric 3x3 covariance matrices in object space.
+    ///
+    /// See also [`gaussian_covariance`].
+    pub covariances: &'a [glam::Mat3],
+
+    /// Splat colors, the alpha channel is the opacity at the center of the splat.
+    ///
+    /// This is the zeroth degree of the spherical harmonics.
+    pub colors: &'a [Color32],
+
+    /// Coefficients of the spherical harmonics above degree zero, [`Self::num_sh_coefficients`] per splat.
+    ///
+    /// They are added to the color in gamma space, like most Gaussian splatting reconstructions do.
+    pub sh_coefficients: &'a [glam::Vec3],
+
+    /// Number of spherical harmonics coefficients per splat, 3, 8 or 15 for degree 1, 2 or 3.
+    ///
+    /// If zero, or if there are too few [`Self::sh_coefficients`], the colors are view independent.
+    pub num_sh_coefficients: usize,
+
+    pub picking_instance_ids: &'a [PickingLayerInstanceId],
+
+    /// Picking object id that applies for the entire batch.
+    pub picking_object_id: PickingLayerObjectId,
+
+    /// Optional outline mask setting for the entire batch.
+    pub overall_outline_mask_ids: OutlineMaskPreference,
+
+    /// Optional box hiding the splats with a center inside or outside of it.
+    ///
+    /// Unlike for point clouds this is applied on the CPU, while sorting the splats.
+    pub crop_box: Option<PointCloudCropBox>,
+}
+
+impl GaussianSplatBatchInfo<'_> {
+    /// The number of splats after truncating all slices to the shortest one.
+    fn num_splats(&self) -> usize {
+        self.centers
+            .len()
+            .min(self.covariances.len())
+            .min(self.colors.len())
+            .min(self.picking_instance_ids.len())
+    }
+}
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum GaussianSplatDrawDataError {
+    #[error("Failed to transfer data to the GPU: {0}")]
+    FailedTransferringDataToGpu(#[from] crate::allocator::CpuWriteGpuReadError),
+
+    #[error(transparent)]
+    DataTextureSourceWriteError(#[from] DataTextureSourceWriteError),
+}
+
+/// Internal, ready to draw representation of [`GaussianSplatBatchInfo`]
+#[derive(Clone)]
+struct GaussianSplatBatch {
+    bind_group: GpuBindGroup,
+    vertex_range: Range<u32>,
+    active_phases: EnumSet<DrawPhase>,
+    world_from_obj: glam::Affine3A,
+}
+
+/// A Gaussian splat drawing operation.
+/// Expected to be recreated every frame.
+#[derive(Clone)]
+pub struct GaussianSplatDrawData {
+    bind_group_all_splats: Option<GpuBindGroup>,
+    batches: Vec<GaussianSplatBatch>,
+}
+
+impl DrawData for GaussianSplatDrawData {
+    type Renderer = GaussianSplatRenderer;
+
+    fn collect_drawables(
+        &self,
+        view_info: &DrawableCollectionViewInfo,
+        collector: &mut DrawableCollector<'_>,
+    ) {
+        for (batch_idx, batch) in self.batches.iter().enumerate() {
+            collector.add_drawable(
+                batch.active_phases,
+                DrawDataDrawable::from_affine(view_info, &batch.world_from_obj, batch_idx as _),
+            );
+        }
+    }
+}
+
+/// Number of spherical harmonics coefficients of degree 1 to 3.
+pub const MAX_SH_COEFFICIENTS: usize = 15;
+
+/// Returns the splat indices of a batch sorted back to front as seen from `view_position_in_obj`.
+fn back_to_front_order(centers: &[glam::Vec3], view_position_in_obj: glam::Vec3) -> Vec<u32> {
+    re_tracing::profile_function!();
+
+    let mut order = (0..centers.len() as u32).collect::<Vec<_>>();
+    order.sort_by_cached_key(|&i| {
+        // Sorting by the negated distance bits works since squared distances are never negative.
+        // NaN sorts as very far away which is as good as any other place.
+        std::cmp::Reverse(
+            centers[i as usize]
+                .distance_squared(view_position_in_obj)
+                .to_bits(),
+        )
+    });
+    order
+}
+
+impl GaussianSplatDrawData {
+    /// Sorts and uploads Gaussian splat data to be consumed by the gpu.
+    ///
+    /// Splats within each batch are sorted back to front as seen from `view_position_in_world`.
+    /// Since this happens on creation, the draw data should be recreated whenever the view moves.
+    pub fn new(
+        ctx: &RenderContext,
+        batches: &[GaussianSplatBatchInfo<'_>],
+        view_position_in_world: glam::Vec3,
+    ) -> Result<Self, GaussianSplatDrawDataError> {
+        re_tracing::profile_function!();
+
+        let renderer = ctx.renderer::<GaussianSplatRenderer>();
+
+        // Indices of the drawn splats of each batch, in drawing order.
+        let orders = batches
+            .iter()
+            .map(|batch| {
+                let centers = &batch.centers[..batch.num_splats()];
+                let view_position_in_obj = batch
+                    .world_from_obj
+                    .inverse()
+                    .transform_point3(view_position_in_world);
+
+                let mut order = back_to_front_order(centers, view_position_in_obj);
+                if let Some(crop_box) = &batch.crop_box {
+                    order.retain(|&i| {
+                        !crop_box.hides(batch.world_from_obj.transform_point3(centers[i as usize]))
+                    });
+                }
+                order
+            })
+            .collect::<Vec<_>>();
+        let num_splats = orders.iter().map(Vec::len).sum::<usize>();
+        if num_splats == 0 {
+            return Ok(Self {
+                bind_group_all_splats: None,
+                batches: Vec::new(),
+            });
+        }
+
+        let mut center_buffer = DataTextureSource::<glam::Vec4>::new(ctx);
+        let mut covariance_buffer = DataTextureSource::<glam::Vec4>::new(ctx);
+        let mut color_buffer = DataTextureSource::<Color32>::new(ctx);
+        let mut picking_instance_id_buffer = DataTextureSource::<PickingLayerInstanceId>::new(ctx);
+        center_buffer.reserve(num_splats)?;
+        covariance_buffer.reserve(num_splats * 2)?;
+        color_buffer.reserve(num_splats)?;
+        picking_instance_id_buffer.reserve(num_splats)?;
+
+        for (batch, order) in batches.iter().zip(&orders) {
+            center_buffer.extend_from_slice(
+                &order
+                    .iter()
+                    .map(|&i| batch.centers[i as usize].extend(1.0))
+                    .collect::<Vec<_>>(),
+            )?;
+            covariance_buffer.extend_from_slice(
+                &order
+                    .iter()
+                    .flat_map(|&i| {
+                        let c = batch.covariances[i as usize];
+                        [
+                            glam::vec4(c.x_axis.x, c.x_axis.y, c.x_axis.z, c.y_axis.y),
+                            glam::vec4(c.y_axis.z, c.z_axis.z, 0.0, 0.0),
+                        ]
+                    })
+                    .collect::<Vec<_>>(),
+            )?;
+            color_buffer.extend_from_slice(
+                &order
+                    .iter()
+                    .map(|&i| batch.colors[i as usize])
+                    .collect::<Vec<_>>(),
+            )?;
+            picking_instance_id_buffer.extend_from_slice(
+                &order
+                    .iter()
+                    .map(|&i| batch.picking_instance_ids[i as usize])
+                    .collect::<Vec<_>>(),
+            )?;
+        }
+
+        let center_texture = center_buffer.finish(
+            wgpu::TextureFormat::Rgba32Float,
+            "GaussianSplatDrawData::center_texture",
+        )?;
+        let covariance_texture = covariance_buffer.finish(
+            wgpu::TextureFormat::Rgba32Float,
+            "GaussianSplatDrawData::covariance_texture",
+        )?;
+        // Not sRGB, since spherical harmonics are added in gamma space.
+        let color_texture = color_buffer.finish(
+            wgpu::TextureFormat::Rgba8Unorm,
+            "GaussianSplatDrawData::color_texture",
+        )?;
+        let picking_instance_id_texture = picking_instance_id_buffer.finish(
+            wgpu::TextureFormat::Rg32Uint,
+            "GaussianSplatDrawData::picking_instance_id_texture",
+        )?;
+
+        let bind_group_all_splats = ctx.gpu_resources.bind_groups.alloc(
+            &ctx.device,
+            &ctx.gpu_resources,
+            &BindGroupDesc {
+                label: "GaussianSplatDrawData::bind_group_all_splats".into(),
+                entries: smallvec![
+                    BindGroupEntry::DefaultTextureView(center_texture.handle),
+                    BindGroupEntry::DefaultTextureView(covariance_texture.handle),
+                    BindGroupEntry::DefaultTextureView(color_texture.handle),
+                    BindGroupEntry::DefaultTextureView(picking_instance_id_texture.handle),
+                ],
+                layout: renderer.bind_group_layout_all_splats,
+            },
+        );
+
+        let first_splat_indices = orders
+            .iter()
+            .scan(0, |first_splat_index, order| {
+                let index = *first_splat_index;
+                *first_splat_index += order.len() as u32;
+                Some(index)
+            })
+            .collect::<Vec<_>>();
+
+        let num_sh_coefficients = batches
+            .iter()
+            .map(|batch| {
+                if batch.num_sh_coefficients <= MAX_SH_COEFFICIENTS
+                    && batch.sh_coefficients.len() >= batch.num_splats() * batch.num_sh_coefficients
+                {
+                    batch.num_sh_coefficients
+                } else {
+                    0
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let uniform_buffer_bindings = create_and_fill_uniform_buffer_batch(
+            ctx,
+            "gaussian splat batch uniform buffers".into(),
+            batches
+                .iter()
+                .zip(&first_splat_indices)
+                .zip(&num_sh_coefficients)
+                .map(|((batch_info, &first_splat_index), &num_sh_coefficients)| {
+                    gpu_data::BatchUniformBuffer {
+                        world_from_obj: batch_info.world_from_obj.into(),
+                        outline_mask_ids: batch_info
+                            .overall_outline_mask_ids
+                            .0
+                            .unwrap_or_default()
+                            .into(),
+                        picking_object_id: batch_info.picking_object_id,
+                        obj_from_world: glam::Mat3::from(
+                            batch_info.world_from_obj.matrix3.inverse(),
+                        )
+                        .into(),
+                        first_splat_index,
+                        num_sh_coefficients: num_sh_coefficients as u32,
+                        row_padding: Default::default(),
+                        end_padding: Default::default(),
+                    }
+                }),
+        );
+
+        let mut batches_internal = Vec::with_capacity(batches.len());
+        let mut start_splat_for_next_batch = 0;
+        for (((batch_info, order), &num_sh_coefficients), uniform_buffer_binding) in batches
+            .iter()
+            .zip(&orders)
+            .zip(&num_sh_coefficients)
+            .zip(uniform_buffer_bindings)
+        {
+            let num_splats = order.len();
+            let splat_range_end = start_splat_for_next_batch + num_splats as u32;
+            if num_splats > 0 {
+                let mut active_phases = enum_set![DrawPhase::Transparent | DrawPhase::PickingLayer];
+                if batch_info.overall_outline_mask_ids.is_some() {
+                    active_phases.insert(DrawPhase::OutlineMask);
+                }
+
+                let sh_texture = if num_sh_coefficients > 0 {
+                    let mut sh_buffer = DataTextureSource::<glam::Vec4>::new(ctx);
+                    sh_buffer.reserve(num_splats * num_sh_coefficients)?;
+                    sh_buffer.extend_from_slice(
+                        &order
+                            .iter()
+                            .flat_map(|&i| {
+                                let start = i as usize * num_sh_coefficients;
+                                batch_info.sh_coefficients[start..start + num_sh_coefficients]
+                                    .iter()
+                                    .map(|coefficient| coefficient.extend(0.0))
+                            })
+                            .collect::<Vec<_>>(),
+                    )?;
+                    sh_buffer
+                        .finish(
+                            wgpu::TextureFormat::Rgba32Float,
+                            "GaussianSplatDrawData::sh_texture",
+                        )?
+                        .handle
+                } else {
+                    ctx.texture_manager_2d.zeroed_texture_float().handle
+                };
+
+                batches_internal.push(GaussianSplatBatch {
+                    bind_group: ctx.gpu_resources.bind_groups.alloc(
+                        &ctx.device,
+                        &ctx.gpu_resources,
+                        &BindGroupDesc {
+                            label: batch_info.label.clone(),
+                            entries: smallvec![
+                                uniform_buffer_binding,
+                                BindGroupEntry::DefaultTextureView(sh_texture),
+                            ],
+                            layout: renderer.bind_group_layout_batch,
+                        },
+                    ),
+                    vertex_range: (start_splat_for_next_batch * 6)..(splat_range_end * 6),
+                    active_phases,
+                    world_from_obj: batch_info.world_from_obj,
+                });
+            }
+            start_splat_for_next_batch = splat_range_end;
+        }
+
+        Ok(Self {
+            bind_group_all_splats: Some(bind_group_all_splats),
+            batches: batches_internal,
+        })
+    }
+}
+
+pub struct GaussianSplatRenderer {
+    render_pipeline_color: GpuRenderPipelineHandle,
+    render_pipeline_picking_layer: GpuRenderPipelineHandle,
+    render_pipeline_outline_mask: GpuRenderPipelineHandle,
+    bind_group_layout_all_splats: GpuBindGroupLayoutHandle,
+    bind_group_layout_batch: GpuBindGroupLayoutHandle,
+}
+
+impl Renderer for GaussianSplatRenderer {
+    type RendererDrawData = GaussianSplatDrawData;
+
+    fn create_renderer(ctx: &RenderContext) -> Self {
+        re_tracing::profile_function!();
+
+        let render_pipelines = &ctx.gpu_resources.render_pipelines;
+
+        let data_texture_entry = |binding, sample_type| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::VERTEX,
+            ty: wgpu::BindingType::Texture {
+                sample_type,
+                view_dimension: wgpu::TextureViewDimension::D2,
+                multisampled: false,
+            },
+            count: None,
+        };
+        let float_sample_type = wgpu::TextureSampleType::Float { filterable: false };
+
+        let bind_group_layout_all_splats = ctx.gpu_resources.bind_group_layouts.get_or_create(
+            &ctx.device,
+            &BindGroupLayoutDesc {
+                label: "GaussianSplatRenderer::bind_group_layout_all_splats".into(),
+                entries: vec![
+                    data_texture_entry(0, float_sample_type),
+                    data_texture_entry(1, float_sample_type),
+                    data_texture_entry(2, float_sample_type),
+                    data_texture_entry(3, wgpu::TextureSampleType::Uint),
+                ],
+            },
+        );
+
+        let bind_group_layout_batch = ctx.gpu_resources.bind_group_layouts.get_or_create(
+            &ctx.device,
+            &BindGroupLayoutDesc {
+                label: "GaussianSplatRenderer::bind_group_layout_batch".into(),
+                entries: vec![
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::VERTEX | wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: NonZeroU64::new(std::mem::size_of::<
+                                gpu_data::BatchUniformBuffer,
+                            >() as _),
+                        },
+                        count: None,
+                    },
+                    // Spherical harmonics.
+                    data_texture_entry(1, float_sample_type),
+                ],
+            },
+        );
+
+        let pipeline_layout = ctx.gpu_resources.pipeline_layouts.get_or_create(
+            ctx,
+            &PipelineLayoutDesc {
+                label: "GaussianSplatRenderer::pipeline_layout".into(),
+                entries: vec![
+                    ctx.global_bindings.layout,
+                    bind_group_layout_all_splats,
+                    bind_group_layout_batch,
+                ],
+            },
+        );
+
+        let shader_module = ctx.gpu_resources.shader_modules.get_or_create(
+            ctx,
+            &include_shader_module!("../../shader/gaussian_splat.wgsl"),
+        );
+
+        let render_pipeline_desc_color = RenderPipelineDesc {
+            label: "GaussianSplatRenderer::render_pipeline_color".into(),
+            pipeline_layout,
+            vertex_entrypoint: "vs_main".into(),
+            vertex_handle: shader_module,
+            fragment_entrypoint: "fs_main".into(),
+            fragment_handle: shader_module,
+            vertex_buffers: smallvec![],
+            render_targets: smallvec![Some(wgpu::ColorTargetState {
+                format: ViewBuilder::MAIN_TARGET_COLOR_FORMAT,
+                blend: Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+                write_mask: wgpu::ColorWrites::ALL,
+            })],
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                ..Default::default()
+            },
+            depth_stencil: Some(ViewBuilder::MAIN_TARGET_DEFAULT_DEPTH_STATE_NO_WRITE),
+            multisample: ViewBuilder::main_target_default_msaa_state(ctx.render_config(), false),
+        };
+        let render_pipeline_color =
+            render_pipelines.get_or_create(ctx, &render_pipeline_desc_color);
+        let render_pipeline_picking_layer = render_pipelines.get_or_create(
+            ctx,
+            &RenderPipelineDesc {
+                label: "GaussianSplatRenderer::render_pipeline_picking_layer".into(),
+                fragment_entrypoint: "fs_main_picking_layer".into(),
+                render_targets: smallvec![Some(PickingLayerProcessor::PICKING_LAYER_FORMAT.into())],
+                depth_stencil: PickingLayerProcessor::PICKING_LAYER_DEPTH_STATE,
+                multisample: PickingLayerProcessor::PICKING_LAYER_MSAA_STATE,
+                ..render_pipeline_desc_color.clone()
+            },
+        );
+        let render_pipeline_outline_mask = render_pipelines.get_or_create(
+            ctx,
+            &RenderPipelineDesc {
+                label: "GaussianSplatRenderer::render_pipeline_outline_mask".into(),
+                fragment_entrypoint: "fs_main_outline_mask".into(),
+                render_targets: smallvec![Some(OutlineMaskProcessor::MASK_FORMAT.into())],
+                depth_stencil: OutlineMaskProcessor::MASK_DEPTH_STATE,
+                multisample: OutlineMaskProcessor::mask_default_msaa_state(ctx.device_caps().tier),
+                ..render_pipeline_desc_color
+            },
+        );
+
+        Self {
+            render_pipeline_color,
+            render_pipeline_picking_layer,
+            render_pipeline_outline_mask,
+            bind_group_layout_all_splats,
+            bind_group_layout_batch,
+        }
+    }
+
+    fn draw(
+        &self,
+        render_pipelines: &GpuRenderPipelinePoolAccessor<'_>,
+        phase: DrawPhase,
+        pass: &mut wgpu::RenderPass<'_>,
+        draw_instructions: &[DrawInstruction<'_, Self::RendererDrawData>],
+    ) -> Result<(), DrawError> {
+        let pipeline_handle = match phase {
+            DrawPhase::OutlineMask => self.render_pipeline_outline_mask,
+            DrawPhase::Transparent => self.render_pipeline_color,
+            DrawPhase::PickingLayer => self.render_pipeline_picking_layer,
+            _ => unreachable!("We were called on a phase we weren't subscribed to: {phase:?}"),
+        };
+        pass.set_pipeline(render_pipelines.get(pipeline_handle)?);
+
+        for DrawInstruction {
+            draw_data,
+            drawables,
+        } in draw_instructions
+        {
+            let Some(bind_group_all_splats) = &draw_data.bind_group_all_splats else {
+                debug_assert!(
+                    false,
+                    "Gaussian splat bind group for draw phase {phase:?} was not set despite being submitted for drawing."
+                );
+                continue;
+            };
+            pass.set_bind_group(1, bind_group_all_splats, &[]);
+
+            for drawable in *drawables {
+                let batch = &draw_data.batches[drawable.draw_data_payload as usize];
+                pass.set_bind_group(2, &batch.bind_group, &[]);
+                pass.draw(batch.vertex_range.clone(), 0..1);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn covariance_from_rotation_and_scale() {
+        let scale = glam::vec3(1.0, 2.0, 3.0);
+
+        let covariance = gaussian_covariance(glam::Quat::IDENTITY, scale);
+        assert!(covariance.abs_diff_eq(glam::Mat3::from_diagonal(scale * scale), 1e-6));
+
+        // Rotating by 90° around z swaps the x and y axes.
+        let covariance = gaussian_covariance(
+            glam::Quat::from_rotation_z(std::f32::consts::FRAC_PI_2),
+            scale,
+        );
+        assert!(covariance.abs_diff_eq(glam::Mat3::from_diagonal(glam::vec3(4.0, 1.0, 9.0)), 1e-5));
+        assert_eq!(covariance, covariance.transpose());
+    }
+
+    #[test]
+    fn splats_are_sorted_back_to_front() {
+        let centers = [
+            glam::vec3(0.0, 0.0, 1.0),
+            glam::vec3(0.0, 0.0, 3.0),
+            glam::vec3(0.0, 0.0, 2.0),
+        ];
+        assert_eq!(
+            back_to_front_order(&centers, glam::Vec3::ZERO),
+            vec![1, 2, 0]
+        );
+        assert_eq!(
+            back_to_front_order(&centers, glam::vec3(0.0, 0.0, 4.0)),
+            vec![0, 2, 1]
+        );
+    }
+}
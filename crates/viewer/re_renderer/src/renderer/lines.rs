@@ -171,7 +171,7 @@ pub mod gpu_data {
         pub depth_offset: f32,
         pub triangle_cap_length_factor: f32,
         pub triangle_cap_width_factor: f32,
-        pub _padding: f32,
+        pub opacity: f32,
 
         pub end_padding: [wgpu_buffer_types::PaddingRow; 16 - 6],
     }
@@ -191,6 +191,7 @@ struct LineStripBatch {
 pub struct LineDrawData {
     bind_group_all_lines: Option<GpuBindGroup>,
     bind_group_all_lines_outline_mask: Option<GpuBindGroup>,
+    bind_group_all_lines_picking_layer: Option<GpuBindGroup>,
     batches: Vec<LineStripBatch>,
 }
 
@@ -313,6 +314,12 @@ pub struct LineBatchInfo {
     /// This controls how wide the triangle/arrow-head is orthogonal to the line's direction.
     /// (defaults to 2.0)
     pub triangle_cap_width_factor: f32,
+
+    /// Opacity of all lines in the batch.
+    ///
+    /// Lines are drawn together with opaque geometry, so anything below 1.0 is approximated
+    /// by covering only a part of the pixel's samples (alpha-to-coverage).
+    pub opacity: f32,
 }
 
 impl Default for LineBatchInfo {
@@ -327,6 +334,7 @@ impl Default for LineBatchInfo {
             depth_offset: 0,
             triangle_cap_length_factor: 4.0,
             triangle_cap_width_factor: 2.0,
+            opacity: 1.0,
         }
     }
 }
@@ -361,6 +369,7 @@ impl LineDrawData {
             strips_buffer,
             picking_instance_ids_buffer,
             radius_boost_in_ui_points_for_outlines,
+            radius_boost_in_ui_points_for_picking,
         } = line_builder;
 
         let line_renderer = ctx.renderer::<LineRenderer>();
@@ -369,6 +378,7 @@ impl LineDrawData {
             return Ok(Self {
                 bind_group_all_lines: None,
                 bind_group_all_lines_outline_mask: None,
+                bind_group_all_lines_picking_layer: None,
                 batches: Vec::new(),
             });
         }
@@ -414,6 +424,10 @@ impl LineDrawData {
                     radius_boost_in_ui_points: radius_boost_in_ui_points_for_outlines.into(),
                     end_padding: Default::default(),
                 },
+                gpu_data::DrawDataUniformBuffer {
+                    radius_boost_in_ui_points: radius_boost_in_ui_points_for_picking.into(),
+                    end_padding: Default::default(),
+                },
             ]
             .into_iter(),
         );
@@ -445,6 +459,20 @@ impl LineDrawData {
                 layout: line_renderer.bind_group_layout_all_lines,
             },
         );
+        let bind_group_all_lines_picking_layer = ctx.gpu_resources.bind_groups.alloc(
+            &ctx.device,
+            &ctx.gpu_resources,
+            &BindGroupDesc {
+                label: "LineDrawData::bind_group_all_lines_picking_layer".into(),
+                entries: smallvec![
+                    BindGroupEntry::DefaultTextureView(position_texture.handle),
+                    BindGroupEntry::DefaultTextureView(strip_data_texture.handle),
+                    BindGroupEntry::DefaultTextureView(picking_instance_id_texture.handle),
+                    draw_data_uniform_buffer_bindings[2].clone(),
+                ],
+                layout: line_renderer.bind_group_layout_all_lines,
+            },
+        );
 
         // Process batches
         let mut batches_internal = Vec::with_capacity(batches.len());
@@ -460,7 +488,7 @@ impl LineDrawData {
                     depth_offset: batch_info.depth_offset as f32,
                     triangle_cap_length_factor: batch_info.triangle_cap_length_factor,
                     triangle_cap_width_factor: batch_info.triangle_cap_width_factor,
-                    _padding: 0.0,
+                    opacity: batch_info.opacity,
                     end_padding: Default::default(),
                 }
             }
@@ -535,6 +563,7 @@ impl LineDrawData {
         Ok(Self {
             bind_group_all_lines: Some(bind_group_all_lines),
             bind_group_all_lines_outline_mask: Some(bind_group_all_lines_outline_mask),
+            bind_group_all_lines_picking_layer: Some(bind_group_all_lines_picking_layer),
             batches: batches_internal,
         })
     }
@@ -758,7 +787,8 @@ impl Renderer for LineRenderer {
         {
             let bind_group_draw_data = match phase {
                 DrawPhase::OutlineMask => &draw_data.bind_group_all_lines_outline_mask,
-                DrawPhase::Opaque | DrawPhase::PickingLayer => &draw_data.bind_group_all_lines,
+                DrawPhase::PickingLayer => &draw_data.bind_group_all_lines_picking_layer,
+                DrawPhase::Opaque => &draw_data.bind_group_all_lines,
                 _ => unreachable!("We were called on a phase we weren't subscribed to: {phase:?}"),
             };
             let Some(bind_group_draw_data) = bind_group_draw_data else {
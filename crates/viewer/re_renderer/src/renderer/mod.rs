@@ -1,6 +1,7 @@
 mod compositor;
 mod debug_overlay;
 mod depth_cloud;
+mod gaussian_splats;
 mod generic_skybox;
 mod lines;
 mod mesh_renderer;
@@ -11,11 +12,16 @@ mod world_grid;
 
 pub use self::depth_cloud::{DepthCloud, DepthCloudDrawData, DepthCloudRenderer, DepthClouds};
 pub use debug_overlay::{DebugOverlayDrawData, DebugOverlayError, DebugOverlayRenderer};
+pub use gaussian_splats::{
+    GaussianSplatBatchInfo, GaussianSplatDrawData, GaussianSplatDrawDataError, MAX_SH_COEFFICIENTS,
+    gaussian_covariance,
+};
 pub use generic_skybox::{GenericSkyboxDrawData, GenericSkyboxType};
 pub use lines::{LineBatchInfo, LineDrawData, LineDrawDataError, LineStripFlags};
 pub use mesh_renderer::{GpuMeshInstance, MeshDrawData};
 pub use point_cloud::{
-    PointCloudBatchFlags, PointCloudBatchInfo, PointCloudDrawData, PointCloudDrawDataError,
+    PointCloudBatchFlags, PointCloudBatchInfo, PointCloudCropBox, PointCloudDrawData,
+    PointCloudDrawDataError,
 };
 pub use rectangles::{
     ColorMapper, ColormappedTexture, RectangleDrawData, RectangleOptions, ShaderDecoding,
@@ -32,7 +32,8 @@ use crate::{
     view_builder::ViewBuilder,
     wgpu_resources::{
         BindGroupDesc, BindGroupEntry, BindGroupLayoutDesc, GpuBindGroup, GpuBindGroupLayoutHandle,
-        GpuRenderPipelineHandle, PipelineLayoutDesc, RenderPipelineDesc,
+        GpuRenderPipelineHandle, GpuTexture, GpuTextureHandle, PipelineLayoutDesc,
+        RenderPipelineDesc,
     },
 };
 
@@ -50,6 +51,9 @@ bitflags! {
 
         /// If true, draw 2D camera facing circles instead of spheres.
         const FLAG_DRAW_AS_CIRCLES = 0b0010;
+
+        /// Set automatically if the batch has [`PointCloudBatchInfo::normals`].
+        const FLAG_HAS_NORMALS = 0b0100;
     }
 }
 
@@ -85,12 +89,60 @@ pub mod gpu_data {
 
         pub flags: u32, // PointCloudBatchFlags
         pub depth_offset: f32,
-        pub _row_padding: [f32; 2],
+        pub opacity: f32,
+        pub crop_mode: u32, // 0: no crop box, 1: keep inside, 2: keep outside
 
         pub outline_mask_ids: wgpu_buffer_types::UVec2,
         pub picking_object_id: PickingLayerObjectId,
 
-        pub end_padding: [wgpu_buffer_types::PaddingRow; 16 - 6],
+        pub crop_box_min: wgpu_buffer_types::Vec3RowPadded,
+        pub crop_box_max: wgpu_buffer_types::Vec3Unpadded,
+
+        /// Index of the first point of the batch, to look up its normals.
+        pub first_point_index: u32,
+
+        pub end_padding: [wgpu_buffer_types::PaddingRow; 16 - 8],
+    }
+}
+
+/// Hides the points of a batch that are on one side of an axis-aligned box.
+///
+/// Points are tested on the GPU, so this is cheap even for large point clouds.
+/// Hidden points don't show up in the picking layer either.
+///
+/// Also used by [`super::DepthCloud`] and, by splat center, by [`super::GaussianSplatBatchInfo`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PointCloudCropBox {
+    /// The box in world space.
+    pub bounds: macaw::BoundingBox,
+
+    /// If true, the points outside of the box are hidden, otherwise the points inside of it.
+    pub keep_inside: bool,
+}
+
+impl PointCloudCropBox {
+    /// Whether a point at the given world space position is hidden.
+    ///
+    /// Same test as the one done on the GPU.
+    #[inline]
+    pub fn hides(&self, position_in_world: glam::Vec3) -> bool {
+        let is_inside = self.bounds.min.cmple(position_in_world).all()
+            && position_in_world.cmple(self.bounds.max).all();
+        is_inside != self.keep_inside
+    }
+
+    /// Crop mode, min and max as passed to shaders.
+    ///
+    /// Keep the crop modes in sync with `point_cloud.wgsl` and `depth_cloud.wgsl`.
+    pub(super) fn gpu_data(crop_box: Option<&Self>) -> (u32, glam::Vec3, glam::Vec3) {
+        match crop_box {
+            None => (0, glam::Vec3::ZERO, glam::Vec3::ZERO),
+            Some(crop_box) => (
+                if crop_box.keep_inside { 1 } else { 2 },
+                crop_box.bounds.min,
+                crop_box.bounds.max,
+            ),
+        }
     }
 }
 
@@ -170,6 +222,53 @@ pub struct PointCloudBatchInfo {
 
     /// Depth offset applied after projection.
     pub depth_offset: DepthOffset,
+
+    /// Opacity of all points in the batch.
+    ///
+    /// Points are drawn together with opaque geometry, so anything below 1.0 is approximated
+    /// by covering only a part of the pixel's samples (alpha-to-coverage).
+    pub opacity: f32,
+
+    /// Optional box hiding the points inside or outside of it.
+    pub crop_box: Option<PointCloudCropBox>,
+
+    /// Optional normals in object space, which points are then lit by.
+    ///
+    /// A data texture with one texel per point of this batch, as produced by
+    /// [`crate::resource_managers::PointCloudNormalEstimationTask::estimate_normals`].
+    /// Points with a zero normal are shaded as if there were no normals.
+    pub normals: Option<GpuTexture>,
+}
+
+impl PointCloudBatchInfo {
+    fn uniform_buffer(
+        &self,
+        outline_mask_ids: OutlineMaskPreference,
+        first_point_index: u32,
+    ) -> gpu_data::BatchUniformBuffer {
+        let (crop_mode, crop_box_min, crop_box_max) =
+            PointCloudCropBox::gpu_data(self.crop_box.as_ref());
+
+        let mut flags = self.flags;
+        flags.set(
+            PointCloudBatchFlags::FLAG_HAS_NORMALS,
+            self.normals.is_some(),
+        );
+
+        gpu_data::BatchUniformBuffer {
+            world_from_obj: self.world_from_obj.into(),
+            flags: flags.bits(),
+            depth_offset: self.depth_offset as f32,
+            opacity: self.opacity,
+            crop_mode,
+            outline_mask_ids: outline_mask_ids.0.unwrap_or_default().into(),
+            picking_object_id: self.picking_object_id,
+            crop_box_min: crop_box_min.into(),
+            crop_box_max: crop_box_max.into(),
+            first_point_index,
+            end_padding: Default::default(),
+        }
+    }
 }
 
 impl Default for PointCloudBatchInfo {
@@ -184,6 +283,9 @@ impl Default for PointCloudBatchInfo {
             additional_outline_mask_ids_vertex_ranges: Vec::new(),
             picking_object_id: Default::default(),
             depth_offset: 0,
+            opacity: 1.0,
+            crop_box: None,
+            normals: None,
         }
     }
 }
@@ -235,6 +337,9 @@ impl PointCloudDrawData {
             additional_outline_mask_ids_vertex_ranges: Vec::new(),
             picking_object_id: Default::default(),
             depth_offset: 0,
+            opacity: 1.0,
+            crop_box: None,
+            normals: None,
         }];
         let batches = if batches.is_empty() {
             &fallback_batches
@@ -305,24 +410,24 @@ impl PointCloudDrawData {
         // Process batches
         let mut batches_internal = Vec::with_capacity(batches.len());
         {
+            let first_point_indices = batches
+                .iter()
+                .scan(0, |next_start, batch_info| {
+                    let start = *next_start;
+                    *next_start += batch_info.point_count;
+                    Some(start)
+                })
+                .collect_vec();
+
             let uniform_buffer_bindings = create_and_fill_uniform_buffer_batch(
                 ctx,
                 "point batch uniform buffers".into(),
                 batches
                     .iter()
-                    .map(|batch_info| gpu_data::BatchUniformBuffer {
-                        world_from_obj: batch_info.world_from_obj.into(),
-                        flags: batch_info.flags.bits(),
-                        outline_mask_ids: batch_info
-                            .overall_outline_mask_ids
-                            .0
-                            .unwrap_or_default()
-                            .into(),
-                        picking_object_id: batch_info.picking_object_id,
-                        depth_offset: batch_info.depth_offset as f32,
-
-                        _row_padding: [0.0, 0.0],
-                        end_padding: Default::default(),
+                    .zip(&first_point_indices)
+                    .map(|(batch_info, &first_point_index)| {
+                        batch_info
+                            .uniform_buffer(batch_info.overall_outline_mask_ids, first_point_index)
                     }),
             );
 
@@ -334,19 +439,13 @@ impl PointCloudDrawData {
                     "lines batch uniform buffers - mask only".into(),
                     batches
                         .iter()
-                        .flat_map(|batch_info| {
+                        .zip(&first_point_indices)
+                        .flat_map(|(batch_info, &first_point_index)| {
                             batch_info
                                 .additional_outline_mask_ids_vertex_ranges
                                 .iter()
-                                .map(|(_, mask)| gpu_data::BatchUniformBuffer {
-                                    world_from_obj: batch_info.world_from_obj.into(),
-                                    flags: batch_info.flags.bits(),
-                                    outline_mask_ids: mask.0.unwrap_or_default().into(),
-                                    picking_object_id: batch_info.picking_object_id,
-                                    depth_offset: batch_info.depth_offset as f32,
-
-                                    _row_padding: [0.0, 0.0],
-                                    end_padding: Default::default(),
+                                .map(move |(_, mask)| {
+                                    batch_info.uniform_buffer(*mask, first_point_index)
                                 })
                         })
                         .collect::<Vec<_>>()
@@ -358,6 +457,11 @@ impl PointCloudDrawData {
             for (batch_info, uniform_buffer_binding) in
                 batches.iter().zip(uniform_buffer_bindings.into_iter())
             {
+                let normals_texture = batch_info.normals.as_ref().map_or_else(
+                    || ctx.texture_manager_2d.zeroed_texture_float().handle,
+                    |normals| normals.handle,
+                );
+
                 let point_vertex_range_end = start_point_for_next_batch + batch_info.point_count;
                 let mut active_phases = enum_set![DrawPhase::Opaque | DrawPhase::PickingLayer];
                 // Does the entire batch participate in the outline mask phase?
@@ -369,6 +473,7 @@ impl PointCloudDrawData {
                     ctx,
                     batch_info.label.clone(),
                     uniform_buffer_binding,
+                    normals_texture,
                     start_point_for_next_batch..point_vertex_range_end,
                     active_phases,
                 ));
@@ -380,6 +485,7 @@ impl PointCloudDrawData {
                         ctx,
                         format!("{:?} strip-only {:?}", batch_info.label, range).into(),
                         uniform_buffer_bindings_mask_only_batches.next().unwrap(),
+                        normals_texture,
                         range.clone(),
                         enum_set![DrawPhase::OutlineMask],
                     ));
@@ -416,6 +522,7 @@ impl PointCloudRenderer {
         ctx: &RenderContext,
         label: DebugLabel,
         uniform_buffer_binding: BindGroupEntry,
+        normals_texture: GpuTextureHandle,
         vertex_range: Range<u32>,
         active_phases: EnumSet<DrawPhase>,
     ) -> PointCloudBatch {
@@ -426,7 +533,10 @@ impl PointCloudRenderer {
             &ctx.gpu_resources,
             &BindGroupDesc {
                 label,
-                entries: smallvec![uniform_buffer_binding],
+                entries: smallvec![
+                    uniform_buffer_binding,
+                    BindGroupEntry::DefaultTextureView(normals_texture),
+                ],
                 layout: self.bind_group_layout_batch,
             },
         );
@@ -502,18 +612,31 @@ impl Renderer for PointCloudRenderer {
             &ctx.device,
             &BindGroupLayoutDesc {
                 label: "PointCloudRenderer::bind_group_layout_batch".into(),
-                entries: vec![wgpu::BindGroupLayoutEntry {
-                    binding: 0,
-                    visibility: wgpu::ShaderStages::VERTEX | wgpu::ShaderStages::FRAGMENT,
-                    ty: wgpu::BindingType::Buffer {
-                        ty: wgpu::BufferBindingType::Uniform,
-                        has_dynamic_offset: false,
-                        min_binding_size: NonZeroU64::new(std::mem::size_of::<
-                            gpu_data::BatchUniformBuffer,
-                        >() as _),
+                entries: vec![
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::VERTEX | wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: NonZeroU64::new(std::mem::size_of::<
+                                gpu_data::BatchUniformBuffer,
+                            >() as _),
+                        },
+                        count: None,
                     },
-                    count: None,
-                }],
+                    // Normals.
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::VERTEX,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                ],
             },
         );
 
@@ -210,6 +210,12 @@ pub struct RectangleOptions {
 
     /// Optional outline mask.
     pub outline_mask: OutlineMaskPreference,
+
+    /// Hide the rectangle when it's seen from behind.
+    ///
+    /// The front is the side that `extent_u × extent_v` points away from, i.e. for an image
+    /// with `u` pointing right and `v` pointing down, the side the viewer is looking at.
+    pub cull_back_face: bool,
 }
 
 impl Default for RectangleOptions {
@@ -220,6 +226,7 @@ impl Default for RectangleOptions {
             multiplicative_tint: Rgba::WHITE,
             depth_offset: 0,
             outline_mask: OutlineMaskPreference::NONE,
+            cull_back_face: false,
         }
     }
 }
@@ -298,7 +305,7 @@ mod gpu_data {
         decode_srgb: u32,
         texture_alpha: u32,
         bgra_to_rgba: u32,
-        _row_padding: [u32; 1],
+        cull_back_face: u32,
 
         _end_padding: [wgpu_buffer_types::PaddingRow; 16 - 7],
     }
@@ -335,6 +342,7 @@ mod gpu_data {
                 multiplicative_tint,
                 depth_offset,
                 outline_mask,
+                cull_back_face,
             } = options;
 
             let sample_type = match texture_format.sample_type(None, None) {
@@ -396,7 +404,7 @@ mod gpu_data {
                 decode_srgb: *decode_srgb as _,
                 texture_alpha: *texture_alpha as _,
                 bgra_to_rgba: bgra_to_rgba as _,
-                _row_padding: Default::default(),
+                cull_back_face: *cull_back_face as _,
                 _end_padding: Default::default(),
             })
         }
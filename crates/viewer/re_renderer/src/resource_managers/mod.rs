@@ -7,11 +7,16 @@
 //! low level gpu resources and their efficient allocation.
 
 mod image_data_to_texture;
+mod point_cloud_normals;
 mod texture_manager;
 mod yuv_converter;
 
 pub use image_data_to_texture::{
     ImageDataDesc, ImageDataToTextureError, SourceImageDataFormat, transfer_image_data_to_texture,
 };
+pub use point_cloud_normals::{
+    PointCloudNormalEstimationError, PointCloudNormalEstimationSettings,
+    PointCloudNormalEstimationTask,
+};
 pub use texture_manager::{GpuTexture2D, TextureManager2D, TextureManager2DError};
 pub use yuv_converter::{YuvMatrixCoefficients, YuvPixelLayout, YuvRange};
@@ -0,0 +1,533 @@
+use smallvec::smallvec;
+
+use crate::{
+    DrawableCollector, RenderContext,
+    allocator::{
+        CpuWriteGpuReadError, DataTextureSource, DataTextureSourceWriteError,
+        create_and_fill_uniform_buffer,
+    },
+    include_shader_module,
+    renderer::{
+        DrawData, DrawError, DrawInstruction, DrawableCollectionViewInfo, Renderer,
+        screen_triangle_vertex_shader,
+    },
+    wgpu_resources::{
+        BindGroupDesc, BindGroupEntry, BindGroupLayoutDesc, GpuBindGroup, GpuBindGroupLayoutHandle,
+        GpuRenderPipelineHandle, GpuTexture, PipelineLayoutDesc, RenderPipelineDesc, TextureDesc,
+    },
+};
+
+/// Upper bound for the number of cells in the acceleration grid.
+///
+/// If the requested cell size would exceed this, cells are made larger.
+const MAX_NUM_GRID_CELLS: u64 = 1 << 22;
+
+/// Upper bound for the number of cells along each axis of the acceleration grid.
+///
+/// Cells are never smaller than the extent of the point cloud divided by this, so that tiny cell
+/// sizes can't overflow the number of cells.
+const MAX_GRID_SIZE_PER_AXIS: u32 = 1 << 12;
+
+/// Settings for [`PointCloudNormalEstimationTask`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PointCloudNormalEstimationSettings {
+    /// Edge length of the acceleration grid cells.
+    ///
+    /// Neighbors are searched within the 27 cells around a point, so this should be about the
+    /// radius of the neighborhood that is considered for the plane fit.
+    /// If `None`, it is derived from the average point density.
+    pub cell_size: Option<f32>,
+
+    /// Estimated normals are flipped such that they face this position.
+    pub viewpoint: glam::Vec3,
+}
+
+impl Default for PointCloudNormalEstimationSettings {
+    fn default() -> Self {
+        Self {
+            cell_size: None,
+            viewpoint: glam::Vec3::ZERO,
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum PointCloudNormalEstimationError {
+    #[error("Point cloud contains no finite positions.")]
+    NoFinitePositions,
+
+    #[error(transparent)]
+    FailedTransferringDataToGpu(#[from] CpuWriteGpuReadError),
+
+    #[error(transparent)]
+    DataTextureSourceWriteError(#[from] DataTextureSourceWriteError),
+}
+
+/// Uniform grid over a point cloud with points sorted by cell.
+///
+/// This is the CPU side preparation for the neighbor search on the GPU.
+#[derive(Debug)]
+pub(crate) struct PointGrid {
+    pub min: glam::Vec3,
+    pub cell_size: f32,
+    pub size: glam::UVec3,
+
+    /// Positions sorted by grid cell.
+    pub sorted_positions: Vec<glam::Vec3>,
+
+    /// For every cell the `[start, count]` range into [`Self::sorted_positions`].
+    pub cells: Vec<[u32; 2]>,
+}
+
+impl PointGrid {
+    /// Bins all finite positions into a uniform grid.
+    ///
+    /// Returns `None` if there are no finite positions.
+    pub fn new(positions: &[glam::Vec3], cell_size: Option<f32>) -> Option<Self> {
+        re_tracing::profile_function!();
+
+        let mut bbox = macaw::BoundingBox::nothing();
+        let mut num_finite_positions = 0;
+        for position in positions.iter().filter(|p| p.is_finite()) {
+            bbox.extend(*position);
+            num_finite_positions += 1;
+        }
+        if num_finite_positions == 0 {
+            return None;
+        }
+
+        let extent = bbox.size().max(glam::Vec3::splat(f32::EPSILON));
+
+        let mut cell_size = cell_size.unwrap_or_else(|| {
+            // Aim for a handful of points per cell, assuming points are spread out uniformly.
+            let volume = extent.x * extent.y * extent.z;
+            2.0 * (volume / num_finite_positions as f32).cbrt()
+        });
+        if !cell_size.is_finite() || cell_size <= 0.0 {
+            cell_size = extent.max_element();
+        }
+        cell_size = cell_size.max(extent.max_element() / MAX_GRID_SIZE_PER_AXIS as f32);
+        if !cell_size.is_finite() {
+            // The extent itself overflowed, put everything into a single cell.
+            cell_size = f32::MAX;
+        }
+
+        let grid_size_for = |cell_size: f32| {
+            (extent / cell_size)
+                .floor()
+                .as_uvec3()
+                .saturating_add(glam::UVec3::ONE)
+                .min(glam::UVec3::splat(MAX_GRID_SIZE_PER_AXIS))
+        };
+        let num_cells_of = |size: glam::UVec3| size.x as u64 * size.y as u64 * size.z as u64;
+        let mut size = grid_size_for(cell_size);
+        while num_cells_of(size) > MAX_NUM_GRID_CELLS {
+            cell_size *= 2.0;
+            size = grid_size_for(cell_size);
+        }
+
+        let num_cells = num_cells_of(size) as usize;
+        let cell_index = |position: glam::Vec3| {
+            let cell = ((position - bbox.min) / cell_size)
+                .floor()
+                .as_uvec3()
+                .min(size - glam::UVec3::ONE);
+            (cell.x + cell.y * size.x + cell.z * size.x * size.y) as usize
+        };
+
+        // Counting sort by cell index.
+        let mut cells = vec![[0_u32; 2]; num_cells];
+        for position in positions.iter().filter(|p| p.is_finite()) {
+            cells[cell_index(*position)][1] += 1;
+        }
+        let mut next_start = 0;
+        for cell in &mut cells {
+            cell[0] = next_start;
+            next_start += cell[1];
+        }
+
+        let mut write_offsets: Vec<u32> = cells.iter().map(|[start, _]| *start).collect();
+        let mut sorted_positions = vec![glam::Vec3::ZERO; num_finite_positions];
+        for position in positions.iter().filter(|p| p.is_finite()) {
+            let offset = &mut write_offsets[cell_index(*position)];
+            sorted_positions[*offset as usize] = *position;
+            *offset += 1;
+        }
+
+        Some(Self {
+            min: bbox.min,
+            cell_size,
+            size,
+            sorted_positions,
+            cells,
+        })
+    }
+}
+
+mod gpu_data {
+    use crate::wgpu_buffer_types;
+
+    #[repr(C)]
+    #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+    pub struct UniformBuffer {
+        pub grid_min: wgpu_buffer_types::Vec3Unpadded,
+        pub cell_size: f32,
+        pub grid_size: [u32; 3],
+        pub num_points: u32,
+        pub viewpoint: wgpu_buffer_types::Vec3RowPadded,
+
+        pub _end_padding: [wgpu_buffer_types::PaddingRow; 16 - 3],
+    }
+}
+
+/// Estimates normals for a point cloud on the GPU.
+///
+/// For every point, the closest 16 points are gathered via a uniform grid and
+/// the normal is the direction of least variance of that neighborhood.
+/// This allows lit rendering of point clouds that were logged without normals.
+///
+/// The result is written to [`Self::normals_texture`], a data texture with one texel per input point:
+/// `xyz` is the unit length normal (or zero if it couldn't be determined) and `w` the
+/// surface variation, a measure of how "non-planar" the neighborhood is.
+pub struct PointCloudNormalEstimationTask {
+    bind_group: GpuBindGroup,
+    normals_texture: GpuTexture,
+}
+
+impl DrawData for PointCloudNormalEstimationTask {
+    type Renderer = PointCloudNormalEstimator;
+
+    fn collect_drawables(
+        &self,
+        _view_info: &DrawableCollectionViewInfo,
+        _collector: &mut DrawableCollector<'_>,
+    ) {
+        // Doesn't participate in regular rendering.
+    }
+}
+
+impl PointCloudNormalEstimationTask {
+    /// Format of [`Self::normals_texture`].
+    pub const OUTPUT_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba16Float;
+
+    /// Prepares the acceleration grid and uploads all data needed for the normal estimation.
+    ///
+    /// Non-finite positions are not considered as neighbors of other points.
+    pub fn new(
+        ctx: &RenderContext,
+        label: &str,
+        positions: &[glam::Vec3],
+        settings: &PointCloudNormalEstimationSettings,
+    ) -> Result<Self, PointCloudNormalEstimationError> {
+        re_tracing::profile_function!();
+
+        let grid = PointGrid::new(positions, settings.cell_size)
+            .ok_or(PointCloudNormalEstimationError::NoFinitePositions)?;
+
+        let renderer = ctx.renderer::<PointCloudNormalEstimator>();
+
+        let mut positions_buffer = DataTextureSource::<glam::Vec4>::new(ctx);
+        positions_buffer.reserve(positions.len())?;
+        for position in positions {
+            positions_buffer.push(position.extend(1.0))?;
+        }
+        let positions_texture = positions_buffer.finish(
+            wgpu::TextureFormat::Rgba32Float,
+            format!("{label}_normal_estimation_positions"),
+        )?;
+
+        let mut sorted_positions_buffer = DataTextureSource::<glam::Vec4>::new(ctx);
+        sorted_positions_buffer.reserve(grid.sorted_positions.len())?;
+        for position in &grid.sorted_positions {
+            sorted_positions_buffer.push(position.extend(1.0))?;
+        }
+        let sorted_positions_texture = sorted_positions_buffer.finish(
+            wgpu::TextureFormat::Rgba32Float,
+            format!("{label}_normal_estimation_sorted_positions"),
+        )?;
+
+        let mut cells_buffer = DataTextureSource::<[u32; 2]>::new(ctx);
+        cells_buffer.reserve(grid.cells.len())?;
+        cells_buffer.extend_from_slice(&grid.cells)?;
+        let cells_texture = cells_buffer.finish(
+            wgpu::TextureFormat::Rg32Uint,
+            format!("{label}_normal_estimation_cells"),
+        )?;
+
+        // One output texel per input point, laid out exactly like the positions data texture.
+        let normals_texture = ctx.gpu_resources.textures.alloc(
+            &ctx.device,
+            &TextureDesc {
+                label: format!("{label}_normals").into(),
+                size: positions_texture.creation_desc.size,
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: Self::OUTPUT_FORMAT,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING
+                    | wgpu::TextureUsages::RENDER_ATTACHMENT,
+            },
+        );
+
+        let uniform_buffer = create_and_fill_uniform_buffer(
+            ctx,
+            format!("{label}_normal_estimation").into(),
+            gpu_data::UniformBuffer {
+                grid_min: grid.min.into(),
+                cell_size: grid.cell_size,
+                grid_size: grid.size.to_array(),
+                num_points: positions.len() as u32,
+                viewpoint: settings.viewpoint.into(),
+                _end_padding: Default::default(),
+            },
+        );
+
+        let bind_group = ctx.gpu_resources.bind_groups.alloc(
+            &ctx.device,
+            &ctx.gpu_resources,
+            &BindGroupDesc {
+                label: format!("{label}_normal_estimation").into(),
+                entries: smallvec![
+                    uniform_buffer,
+                    BindGroupEntry::DefaultTextureView(positions_texture.handle),
+                    BindGroupEntry::DefaultTextureView(sorted_positions_texture.handle),
+                    BindGroupEntry::DefaultTextureView(cells_texture.handle),
+                ],
+                layout: renderer.bind_group_layout,
+            },
+        );
+
+        Ok(Self {
+            bind_group,
+            normals_texture,
+        })
+    }
+
+    /// Data texture with one normal per input point.
+    ///
+    /// Only filled once [`Self::estimate_normals`] was executed on the GPU.
+    pub fn normals_texture(&self) -> &GpuTexture {
+        &self.normals_texture
+    }
+
+    /// Schedules the normal estimation, returning the texture the normals are written to.
+    pub fn estimate_normals(self, ctx: &RenderContext) -> Result<GpuTexture, DrawError> {
+        {
+            let mut encoder = ctx.active_frame.before_view_builder_encoder.lock();
+            let mut pass = encoder
+                .get()
+                .begin_render_pass(&wgpu::RenderPassDescriptor {
+                    label: self.normals_texture.creation_desc.label.get(),
+                    color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                        view: &self.normals_texture.default_view,
+                        depth_slice: None,
+                        resolve_target: None,
+                        ops: wgpu::Operations {
+                            load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                            store: wgpu::StoreOp::Store,
+                        },
+                    })],
+                    ..Default::default()
+                });
+
+            ctx.renderer::<PointCloudNormalEstimator>().draw(
+                &ctx.gpu_resources.render_pipelines.resources(),
+                crate::draw_phases::DrawPhase::Opaque, // Don't care about the phase.
+                &mut pass,
+                &[DrawInstruction {
+                    draw_data: &self,
+                    drawables: &[],
+                }],
+            )?;
+        }
+
+        Ok(self.normals_texture)
+    }
+}
+
+/// Renderer for [`PointCloudNormalEstimationTask`].
+///
+/// Implemented as a fullscreen pass over the normals data texture rather than a compute shader,
+/// so it also works on devices of the [`crate::device_caps::DeviceCapabilityTier::Limited`] tier.
+pub struct PointCloudNormalEstimator {
+    render_pipeline: GpuRenderPipelineHandle,
+    bind_group_layout: GpuBindGroupLayoutHandle,
+}
+
+impl Renderer for PointCloudNormalEstimator {
+    type RendererDrawData = PointCloudNormalEstimationTask;
+
+    fn create_renderer(ctx: &RenderContext) -> Self {
+        let vertex_handle = screen_triangle_vertex_shader(ctx);
+
+        let data_texture_entry = |binding, sample_type| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::FRAGMENT,
+            ty: wgpu::BindingType::Texture {
+                multisampled: false,
+                view_dimension: wgpu::TextureViewDimension::D2,
+                sample_type,
+            },
+            count: None,
+        };
+
+        let bind_group_layout = ctx.gpu_resources.bind_group_layouts.get_or_create(
+            &ctx.device,
+            &BindGroupLayoutDesc {
+                label: "PointCloudNormalEstimator".into(),
+                entries: vec![
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: (std::mem::size_of::<gpu_data::UniformBuffer>()
+                                as u64)
+                                .try_into()
+                                .ok(),
+                        },
+                        count: None,
+                    },
+                    // Positions.
+                    data_texture_entry(1, wgpu::TextureSampleType::Float { filterable: false }),
+                    // Positions sorted by grid cell.
+                    data_texture_entry(2, wgpu::TextureSampleType::Float { filterable: false }),
+                    // Grid cells.
+                    data_texture_entry(3, wgpu::TextureSampleType::Uint),
+                ],
+            },
+        );
+
+        let pipeline_layout = ctx.gpu_resources.pipeline_layouts.get_or_create(
+            ctx,
+            &PipelineLayoutDesc {
+                label: "PointCloudNormalEstimator".into(),
+                entries: vec![bind_group_layout],
+            },
+        );
+
+        let shader_modules = &ctx.gpu_resources.shader_modules;
+        let render_pipeline = ctx.gpu_resources.render_pipelines.get_or_create(
+            ctx,
+            &RenderPipelineDesc {
+                label: "PointCloudNormalEstimator::render_pipeline".into(),
+                pipeline_layout,
+                vertex_entrypoint: "main".into(),
+                vertex_handle,
+                fragment_entrypoint: "fs_main".into(),
+                fragment_handle: shader_modules.get_or_create(
+                    ctx,
+                    &include_shader_module!("../../shader/conversions/point_cloud_normals.wgsl"),
+                ),
+                vertex_buffers: smallvec![],
+                render_targets: smallvec![Some(
+                    PointCloudNormalEstimationTask::OUTPUT_FORMAT.into()
+                )],
+                primitive: wgpu::PrimitiveState::default(),
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState::default(),
+            },
+        );
+
+        Self {
+            render_pipeline,
+            bind_group_layout,
+        }
+    }
+
+    fn draw(
+        &self,
+        render_pipelines: &crate::wgpu_resources::GpuRenderPipelinePoolAccessor<'_>,
+        _phase: crate::draw_phases::DrawPhase,
+        pass: &mut wgpu::RenderPass<'_>,
+        draw_instructions: &[DrawInstruction<'_, Self::RendererDrawData>],
+    ) -> Result<(), DrawError> {
+        let pipeline = render_pipelines.get(self.render_pipeline)?;
+
+        pass.set_pipeline(pipeline);
+
+        for DrawInstruction { draw_data, .. } in draw_instructions {
+            pass.set_bind_group(0, &draw_data.bind_group, &[]);
+            pass.draw(0..3, 0..1);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PointGrid;
+
+    #[test]
+    fn grid_sorts_all_finite_points_into_cells() {
+        let positions = [
+            glam::vec3(0.0, 0.0, 0.0),
+            glam::vec3(1.0, 1.0, 1.0),
+            glam::vec3(f32::NAN, 0.0, 0.0),
+            glam::vec3(0.1, 0.1, 0.1),
+            glam::vec3(0.9, 0.9, 0.9),
+        ];
+        let grid = PointGrid::new(&positions, Some(0.5)).unwrap();
+
+        assert_eq!(grid.min, glam::Vec3::ZERO);
+        assert_eq!(grid.size, glam::UVec3::splat(3));
+        assert_eq!(grid.sorted_positions.len(), 4);
+        assert_eq!(
+            grid.cells.iter().map(|[_, count]| count).sum::<u32>(),
+            4,
+            "Non-finite positions should be skipped"
+        );
+
+        // Every point is within the range of the cell it belongs to.
+        for (cell_idx, [start, count]) in grid.cells.iter().enumerate() {
+            for position in &grid.sorted_positions[*start as usize..(*start + *count) as usize] {
+                let cell = ((*position - grid.min) / grid.cell_size)
+                    .floor()
+                    .as_uvec3()
+                    .min(grid.size - glam::UVec3::ONE);
+                let expected_idx =
+                    cell.x + cell.y * grid.size.x + cell.z * grid.size.x * grid.size.y;
+                assert_eq!(expected_idx as usize, cell_idx);
+            }
+        }
+    }
+
+    #[test]
+    fn grid_limits_number_of_cells() {
+        let positions = [glam::Vec3::ZERO, glam::Vec3::splat(1000.0)];
+        let grid = PointGrid::new(&positions, Some(0.001)).unwrap();
+        assert!(
+            (grid.size.x * grid.size.y * grid.size.z) as u64 <= super::MAX_NUM_GRID_CELLS,
+            "{:?}",
+            grid.size
+        );
+    }
+
+    #[test]
+    fn grid_with_degenerate_cell_size() {
+        let positions = [glam::Vec3::ZERO, glam::Vec3::splat(1.0e6)];
+        for cell_size in [f32::MIN_POSITIVE, 1.0e-30, 0.0, -1.0, f32::NAN] {
+            let grid = PointGrid::new(&positions, Some(cell_size)).unwrap();
+            assert!(grid.cell_size.is_finite() && grid.cell_size > 0.0);
+            assert!(
+                (grid.size.x * grid.size.y * grid.size.z) as u64 <= super::MAX_NUM_GRID_CELLS,
+                "{:?}",
+                grid.size
+            );
+        }
+
+        // An extent that doesn't fit into an f32.
+        let positions = [glam::Vec3::splat(-f32::MAX), glam::Vec3::splat(f32::MAX)];
+        let grid = PointGrid::new(&positions, None).unwrap();
+        assert_eq!(grid.size, glam::UVec3::ONE);
+        assert_eq!(grid.sorted_positions.len(), 2);
+    }
+
+    #[test]
+    fn grid_without_finite_points() {
+        assert!(PointGrid::new(&[], None).is_none());
+        assert!(PointGrid::new(&[glam::Vec3::INFINITY], None).is_none());
+    }
+}
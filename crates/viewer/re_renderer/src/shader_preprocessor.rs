@@ -0,0 +1,239 @@
+//! A minimal C-style preprocessor for our WGSL shaders, allowing several permutations of a
+//! shader to live in a single file.
+//!
+//! It runs after all `#import` clauses have been interpolated (see `file_resolver.rs`).
+//! This module is shared with the build script (see `build.rs`), so it must not depend on
+//! anything else in this crate.
+//!
+//! ## Directives
+//!
+//! Each directive must be on its own line and start with `#` (excl. whitespaces):
+//! - `#define NAME` or `#define NAME VALUE` defines `NAME`, optionally with a value.
+//! - `#ifdef NAME` / `#ifndef NAME` keep the following lines only if `NAME` is (not) defined.
+//! - `#else` inverts the last `#ifdef`/`#ifndef`.
+//! - `#endif` closes the last `#ifdef`/`#ifndef`.
+//!
+//! Conditionals can be nested. Note that `#import` clauses are resolved before any of this, i.e.
+//! an `#import` inside a disabled block is still interpolated.
+//!
+//! Every occurrence of a defined name as a whole identifier in an enabled line is replaced with
+//! its value, if it has one. This makes it possible to inject constants, e.g.:
+//! ```raw
+//! #ifndef NUM_SAMPLES
+//! #define NUM_SAMPLES 4
+//! #endif
+//! const num_samples = NUM_SAMPLES;
+//! ```
+//!
+//! Directives and disabled lines are replaced with empty lines, so that line numbers in shader
+//! compilation errors still match the source.
+
+use std::collections::HashMap;
+
+/// Runs the preprocessor on the given shader source.
+///
+/// `defines` are defined before the first line of the source, as if by `#define NAME VALUE`.
+/// Pass an empty value for names that should be defined without a value.
+pub fn preprocess_shader(source: &str, defines: &[(String, String)]) -> anyhow::Result<String> {
+    struct Conditional {
+        /// Line number of the `#ifdef`/`#ifndef`, for error reporting.
+        line_number: usize,
+
+        /// Whether the enclosing block is enabled.
+        parent_enabled: bool,
+
+        /// Whether the condition of the `#ifdef`/`#ifndef` holds.
+        condition: bool,
+
+        /// Whether we're past the `#else`.
+        in_else: bool,
+    }
+
+    impl Conditional {
+        fn is_enabled(&self) -> bool {
+            self.parent_enabled && (self.condition != self.in_else)
+        }
+    }
+
+    let mut defines = defines
+        .iter()
+        .map(|(name, value)| (name.clone(), value.clone()))
+        .collect::<HashMap<_, _>>();
+    let mut conditionals: Vec<Conditional> = Vec::new();
+
+    let mut output = String::with_capacity(source.len());
+
+    for (line_idx, line) in source.lines().enumerate() {
+        let line_number = line_idx + 1;
+        let enabled = conditionals.last().is_none_or(Conditional::is_enabled);
+
+        let Some(directive) = line.trim().strip_prefix('#') else {
+            if enabled {
+                substitute_defines(line, &defines, &mut output);
+            }
+            output.push('\n');
+            continue;
+        };
+
+        let mut parts = directive.split_whitespace();
+        let keyword = parts.next().unwrap_or_default();
+        let name = parts.next();
+
+        match (keyword, name) {
+            ("define", Some(name)) => {
+                if enabled {
+                    let value = parts.collect::<Vec<_>>().join(" ");
+                    defines.insert(name.to_owned(), value);
+                }
+            }
+
+            ("ifdef" | "ifndef", Some(name)) => {
+                conditionals.push(Conditional {
+                    line_number,
+                    parent_enabled: enabled,
+                    condition: defines.contains_key(name) == (keyword == "ifdef"),
+                    in_else: false,
+                });
+            }
+
+            ("else", None) => match conditionals.last_mut() {
+                Some(conditional) if !conditional.in_else => conditional.in_else = true,
+                Some(_) => anyhow::bail!("line {line_number}: duplicate #else"),
+                None => anyhow::bail!("line {line_number}: #else without #ifdef or #ifndef"),
+            },
+
+            ("endif", None) => {
+                if conditionals.pop().is_none() {
+                    anyhow::bail!("line {line_number}: #endif without #ifdef or #ifndef");
+                }
+            }
+
+            _ => anyhow::bail!("line {line_number}: malformed preprocessor directive {line:?}"),
+        }
+
+        output.push('\n');
+    }
+
+    if let Some(conditional) = conditionals.last() {
+        anyhow::bail!(
+            "line {}: #ifdef or #ifndef without #endif",
+            conditional.line_number
+        );
+    }
+
+    Ok(output)
+}
+
+/// Appends `line` to `output`, replacing all identifiers that have a defined value.
+fn substitute_defines(line: &str, defines: &HashMap<String, String>, output: &mut String) {
+    if defines.values().all(|value| value.is_empty()) {
+        output.push_str(line);
+        return;
+    }
+
+    let is_identifier_char = |c: char| c.is_ascii_alphanumeric() || c == '_';
+
+    let mut rest = line;
+    while let Some(start) = rest.find(is_identifier_char) {
+        let (before, from_start) = rest.split_at(start);
+        let end = from_start
+            .find(|c: char| !is_identifier_char(c))
+            .unwrap_or(from_start.len());
+        let (token, after) = from_start.split_at(end);
+
+        output.push_str(before);
+        match defines.get(token) {
+            Some(value) if !value.is_empty() => output.push_str(value),
+            _ => output.push_str(token),
+        }
+
+        rest = after;
+    }
+    output.push_str(rest);
+}
+
+#[cfg(test)]
+mod tests {
+    use unindent::unindent;
+
+    use super::*;
+
+    fn defines(defines: &[(&str, &str)]) -> Vec<(String, String)> {
+        defines
+            .iter()
+            .map(|(name, value)| ((*name).to_owned(), (*value).to_owned()))
+            .collect()
+    }
+
+    #[test]
+    fn test_conditionals() {
+        let source = unindent(
+            "
+            a
+            #ifdef SHADOWS
+            b
+            #ifndef SOFT
+            c
+            #else
+            d
+            #endif
+            #else
+            e
+            #endif
+            f",
+        );
+
+        let lines = |defines: &[(String, String)]| {
+            preprocess_shader(&source, defines)
+                .unwrap()
+                .lines()
+                .filter(|line| !line.is_empty())
+                .map(ToOwned::to_owned)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(lines(&[]), ["a", "e", "f"]);
+        assert_eq!(lines(&defines(&[("SHADOWS", "")])), ["a", "b", "c", "f"]);
+        assert_eq!(
+            lines(&defines(&[("SHADOWS", ""), ("SOFT", "")])),
+            ["a", "b", "d", "f"]
+        );
+
+        // Line numbers are preserved.
+        assert_eq!(
+            preprocess_shader(&source, &[]).unwrap().lines().count(),
+            source.lines().count()
+        );
+    }
+
+    #[test]
+    fn test_constants() {
+        let source = unindent(
+            "
+            #ifndef NUM_SAMPLES
+            #define NUM_SAMPLES 4
+            #endif
+            const num_samples = NUM_SAMPLES; // NUM_SAMPLES_X is left alone
+            ",
+        );
+
+        assert_eq!(
+            preprocess_shader(&source, &[]).unwrap().trim(),
+            "const num_samples = 4; // NUM_SAMPLES_X is left alone"
+        );
+        assert_eq!(
+            preprocess_shader(&source, &defines(&[("NUM_SAMPLES", "8")]))
+                .unwrap()
+                .trim(),
+            "const num_samples = 8; // NUM_SAMPLES_X is left alone"
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(preprocess_shader("#ifdef A\n", &[]).is_err());
+        assert!(preprocess_shader("#endif\n", &[]).is_err());
+        assert!(preprocess_shader("#ifdef A\n#else\n#else\n#endif\n", &[]).is_err());
+        assert!(preprocess_shader("#if A\n#endif\n", &[]).is_err());
+    }
+}
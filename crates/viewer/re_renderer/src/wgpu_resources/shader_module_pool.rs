@@ -3,7 +3,10 @@ use std::{hash::Hash, path::PathBuf};
 use ahash::HashSet;
 use anyhow::Context as _;
 
-use crate::{FileResolver, FileSystem, RenderContext, debug_label::DebugLabel};
+use crate::{
+    FileResolver, FileSystem, RenderContext, debug_label::DebugLabel,
+    shader_preprocessor::preprocess_shader,
+};
 
 use super::static_resource_pool::{StaticResourcePool, StaticResourcePoolReadLockAccessor};
 
@@ -24,6 +27,7 @@ macro_rules! include_shader_module {
             label: $crate::DebugLabel::from(stringify!($path).strip_prefix("../../shader/")),
             source: $crate::include_file!($path),
             extra_workaround_replacements: Vec::new(),
+            defines: Vec::new(),
         }
     }};
 }
@@ -39,11 +43,29 @@ pub struct ShaderModuleDesc {
 
     /// Additional text replacement workarounds that may be added on top of globally known workarounds.
     pub extra_workaround_replacements: Vec<(String, String)>,
+
+    /// Names (and optionally values) that are defined when preprocessing the shader.
+    ///
+    /// Use this to select a permutation of a shader via `#ifdef`, or to inject constants.
+    /// See `shader_preprocessor.rs` for details.
+    pub defines: Vec<(String, String)>,
+}
+
+impl ShaderModuleDesc {
+    /// Defines `name` with the given value when preprocessing the shader.
+    ///
+    /// Pass an empty value to only define the name, e.g. for use with `#ifdef`.
+    #[inline]
+    pub fn with_define(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.defines.push((name.into(), value.into()));
+        self
+    }
 }
 
 impl PartialEq for ShaderModuleDesc {
     fn eq(&self, rhs: &Self) -> bool {
-        self.source.eq(&rhs.source)
+        // Different defines yield different shader permutations of the same source.
+        self.source.eq(&rhs.source) && self.defines.eq(&rhs.defines)
     }
 }
 
@@ -53,6 +75,7 @@ impl Hash for ShaderModuleDesc {
         // code since we can have many entrypoints referring to the same file!
         self.source.hash(state);
         self.extra_workaround_replacements.hash(state);
+        self.defines.hash(state);
     }
 }
 
@@ -69,6 +92,12 @@ impl ShaderModuleDesc {
             .map_err(|err| re_log::error!(err=%re_error::format(err)))
             .unwrap_or_default();
 
+        source_interpolated.contents =
+            preprocess_shader(&source_interpolated.contents, &self.defines)
+                .with_context(|| format!("couldn't preprocess shader module {:?}", self.source))
+                .map_err(|err| re_log::error!(err=%re_error::format(err)))
+                .unwrap_or_default();
+
         for (from, to) in shader_text_workaround_replacements
             .iter()
             .chain(self.extra_workaround_replacements.iter())
@@ -25,6 +25,12 @@ pub fn init() {
         fs.create_file(virtpath, content).unwrap();
     }
 
+    {
+        let virtpath = Path::new("shader/conversions/point_cloud_normals.wgsl");
+        let content = include_str!("../shader/conversions/point_cloud_normals.wgsl").into();
+        fs.create_file(virtpath, content).unwrap();
+    }
+
     {
         let virtpath = Path::new("shader/conversions/yuv_converter.wgsl");
         let content = include_str!("../shader/conversions/yuv_converter.wgsl").into();
@@ -49,6 +55,12 @@ pub fn init() {
         fs.create_file(virtpath, content).unwrap();
     }
 
+    {
+        let virtpath = Path::new("shader/gaussian_splat.wgsl");
+        let content = include_str!("../shader/gaussian_splat.wgsl").into();
+        fs.create_file(virtpath, content).unwrap();
+    }
+
     {
         let virtpath = Path::new("shader/generic_skybox.wgsl");
         let content = include_str!("../shader/generic_skybox.wgsl").into();
@@ -179,11 +179,14 @@ impl RenderDepthClouds {
                     world_depth_from_texture_depth: 1.0,
                     point_radius_from_world_depth: *point_radius_from_world_depth,
                     min_max_depth_in_world: [0.0, 5.0],
+                    valid_depth_range_in_world: [0.0, f32::INFINITY],
+                    subsampling: 1,
                     depth_dimensions: depth.dimensions,
                     depth_texture: depth.texture.clone(),
                     colormap: re_renderer::Colormap::Turbo,
                     outline_mask_id: Default::default(),
                     picking_object_id: Default::default(),
+                    crop_box: None,
                 }],
                 radius_boost_in_ui_points_for_outlines: 2.5,
             },
@@ -8,7 +8,7 @@ use re_data_ui::{
 };
 use re_entity_db::{EntityPath, InstancePath};
 use re_log_types::{ComponentPath, EntityPathFilter, EntityPathSubs, ResolvedEntityPathFilter};
-use re_types::ComponentDescriptor;
+use re_types::{ComponentDescriptor, blueprint::archetypes::VisibilityRules};
 use re_ui::list_item::ListItemContentButtonsExt as _;
 use re_ui::{
     SyntaxHighlighting as _, UiExt as _, icons,
@@ -158,6 +158,19 @@ impl SelectionPanel {
             response.response.widget_info(|| {
                 WidgetInfo::labeled(egui::WidgetType::Panel, true, "_selection_panel")
             });
+
+            if selection.len() == 2 {
+                let series = selection
+                    .iter_items()
+                    .filter_map(|item| re_data_ui::scalar_series_of_item(ctx, item))
+                    .collect::<Vec<_>>();
+                if let [first, second] = &series[..] {
+                    ui.section_collapsing_header("Series alignment")
+                        .show(ui, |ui| {
+                            re_data_ui::series_alignment_ui(ctx, ui, first, second);
+                        });
+                }
+            }
         }
     }
 
@@ -505,6 +518,12 @@ The last rule matching `/world/house` is `+ /world/**`, so it is included.
                     if cursor == ui.cursor() {
                         ui.weak("(none)");
                     }
+
+                    // Visibility rules are supported by views of all classes.
+                    let view_ctx = view.bundle_context_with_state(ctx, view_state);
+                    list_item::list_item_scope(ui, "view_visibility_rules", |ui| {
+                        re_view::view_property_ui::<VisibilityRules>(&view_ctx, ui);
+                    });
                 });
 
             let view_ctx = view.bundle_context_with_state(ctx, view_state);
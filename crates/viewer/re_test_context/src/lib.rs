@@ -492,6 +492,8 @@ impl TestContext {
 
         let drag_and_drop_manager =
             re_viewer_context::DragAndDropManager::new(ItemCollection::default());
+        let entity_tree =
+            re_viewer_context::EntityTreeSnapshot::from_entity_db(store_context.recording);
 
         let mut context_render_state = self.egui_render_state.lock();
         let render_state = context_render_state.get_or_insert_with(create_egui_renderstate);
@@ -531,6 +533,7 @@ impl TestContext {
             maybe_visualizable_entities_per_visualizer: &maybe_visualizable_entities_per_visualizer,
             indicated_entities_per_visualizer: &indicated_entities_per_visualizer,
             query_results: &self.query_results,
+            entity_tree: &entity_tree,
             time_ctrl: &self.time_ctrl.read(),
             blueprint_time_ctrl: &Default::default(),
             selection_state: &selection_state,
@@ -630,7 +633,11 @@ impl TestContext {
     ///
     /// Does *not* switch the active recording.
     fn go_to_dataset_data(&self, store_id: StoreId, fragment: re_uri::Fragment) {
-        let re_uri::Fragment { selection, when } = fragment;
+        let re_uri::Fragment {
+            selection,
+            when,
+            blueprint: _,
+        } = fragment;
 
         if let Some(selection) = selection {
             let re_log_types::DataPath {
@@ -752,6 +759,8 @@ impl TestContext {
                 | SystemCommand::AddRedapServer { .. }
                 | SystemCommand::UndoBlueprint { .. }
                 | SystemCommand::RedoBlueprint { .. }
+                | SystemCommand::RebaseTimeline { .. }
+                | SystemCommand::PinStaticVersion { .. }
                 | SystemCommand::CloseAllEntries
                 | SystemCommand::ShowNotification { .. } => handled = false,
 
@@ -111,6 +111,7 @@ impl TestContextExt for TestContext {
                                 ctx.store_context,
                                 class_registry,
                                 ctx.blueprint_query,
+                                ctx.entity_tree,
                                 &visualizable_entities,
                             );
 
@@ -130,6 +131,13 @@ impl TestContextExt for TestContext {
                                 &mut data_query_result,
                                 self.view_states.lock().get_mut_or_create(*view_id, class),
                             );
+                            re_viewport_blueprint::apply_entity_visibility_rules(
+                                ctx.store_context.blueprint,
+                                ctx.blueprint_query,
+                                ctx.recording(),
+                                &ctx.current_query(),
+                                &mut data_query_result,
+                            );
 
                             query_results.insert(*view_id, data_query_result);
                         }
@@ -688,6 +688,58 @@ pub fn show_row_ids_tooltip(
     }
 }
 
+/// The first time after `after` at which the item has any data on the given timeline.
+///
+/// Used to jump over the gaps in the data density graph.
+pub fn next_data_time(
+    db: &re_entity_db::EntityDb,
+    item: &TimePanelItem,
+    timeline: &TimelineName,
+    after: TimeInt,
+) -> Option<TimeInt> {
+    re_tracing::profile_function!();
+
+    if after == TimeInt::MAX {
+        return None;
+    }
+
+    let engine = db.storage_engine();
+    let store = engine.store();
+    let query = RangeQuery::new(*timeline, AbsoluteTimeRange::new(after.inc(), TimeInt::MAX));
+
+    if let Some(component) = item.component {
+        store
+            .range_relevant_chunks(&query, &item.entity_path, component)
+            .iter()
+            .filter_map(|chunk| {
+                chunk
+                    .iter_component_indices(*timeline, component)
+                    .map(|(time, _row_id)| time)
+                    .filter(|time| *time > after)
+                    .min()
+            })
+            .min()
+    } else {
+        PathRecursiveChunksPerTimelineStoreSubscriber::access(&store.id(), |chunks_per_timeline| {
+            let info = chunks_per_timeline
+                .path_recursive_chunks_for_entity_and_timeline(&item.entity_path, timeline)?;
+
+            info.recursive_chunks_info
+                .values()
+                .filter(|info| after < info.resolved_time_range.max())
+                .filter_map(|info| {
+                    info.chunk
+                        .iter_indices(timeline)
+                        .map(|(time, _row_id)| time)
+                        .filter(|time| *time > after)
+                        .min()
+                })
+                .min()
+        })
+        .flatten()
+    }
+}
+
 pub struct DensityGraphBuilder<'a> {
     time_ranges_ui: &'a TimeRangesUi,
     row_rect: Rect,
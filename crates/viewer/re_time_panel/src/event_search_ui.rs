@@ -0,0 +1,237 @@
+use egui::{Color32, Rect};
+
+use re_chunk_store::{ChunkStoreGeneration, RangeQuery};
+use re_entity_db::{EntityDb, external::re_query::ScalarComparison};
+use re_log_types::{AbsoluteTimeRange, AbsoluteTimeRangeSet, TimelineName};
+use re_ui::UiExt as _;
+use re_viewer_context::{EventSearch, TimeControl, TimeControlCommand};
+
+use crate::time_ranges_ui::TimeRangesUi;
+
+/// Shows the times at which the [`EventSearch`] of the [`TimeControl`] matches, if any.
+///
+/// The search is started from the context menu of a component.
+#[derive(Default)]
+pub struct EventSearchUi {
+    /// The result of the last search.
+    ///
+    /// Recomputed whenever the search, the timeline, or the data changes.
+    cached: Option<CachedEvents>,
+}
+
+struct CachedEvents {
+    search: EventSearch,
+    timeline: TimelineName,
+    generation: ChunkStoreGeneration,
+    events: Result<AbsoluteTimeRangeSet, String>,
+}
+
+impl EventSearchUi {
+    /// The times at which the current search matches, or `None` if nothing is being searched for.
+    fn events(
+        &mut self,
+        entity_db: &EntityDb,
+        time_ctrl: &TimeControl,
+    ) -> Option<&Result<AbsoluteTimeRangeSet, String>> {
+        let Some(search) = &time_ctrl.event_search else {
+            self.cached = None;
+            return None;
+        };
+
+        let timeline = *time_ctrl.timeline().name();
+        let generation = entity_db.generation();
+
+        let is_up_to_date = self.cached.as_ref().is_some_and(|cached| {
+            &cached.search == search
+                && cached.timeline == timeline
+                && cached.generation == generation
+        });
+
+        if !is_up_to_date {
+            re_tracing::profile_scope!("find_events");
+
+            let query = RangeQuery::new(timeline, AbsoluteTimeRange::EVERYTHING);
+            let events = entity_db
+                .storage_engine()
+                .cache()
+                .find_time_ranges(
+                    &query,
+                    search.component_path.entity_path(),
+                    search.component_path.component,
+                    |value| search.predicate.holds(value),
+                )
+                .map_err(|err| err.to_string());
+
+            self.cached = Some(CachedEvents {
+                search: search.clone(),
+                timeline,
+                generation,
+                events,
+            });
+        }
+
+        self.cached.as_ref().map(|cached| &cached.events)
+    }
+
+    /// Edits the current search and steps through its events.
+    ///
+    /// Shows nothing if nothing is being searched for.
+    pub fn toolbar_ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        entity_db: &EntityDb,
+        time_ctrl: &TimeControl,
+        time_commands: &mut Vec<TimeControlCommand>,
+    ) {
+        let Some(mut search) = time_ctrl.event_search.clone() else {
+            return;
+        };
+
+        ui.scope(|ui| {
+            ui.spacing_mut().item_spacing.x = 4.0;
+
+            ui.label(search.component_path.to_string())
+                .on_hover_text("Highlighting the times at which this component satisfies:");
+
+            let mut changed = false;
+
+            egui::ComboBox::from_id_salt("event_search_comparison")
+                .selected_text(search.predicate.comparison.symbol())
+                .width(40.0)
+                .show_ui(ui, |ui| {
+                    for comparison in ScalarComparison::ALL {
+                        changed |= ui
+                            .selectable_value(
+                                &mut search.predicate.comparison,
+                                comparison,
+                                comparison.symbol(),
+                            )
+                            .changed();
+                    }
+                });
+
+            let threshold = search.predicate.threshold;
+            changed |= ui
+                .add(
+                    egui::DragValue::new(&mut search.predicate.threshold)
+                        .speed((threshold.abs() * 0.01).max(0.01)),
+                )
+                .changed();
+
+            if changed {
+                time_commands.push(TimeControlCommand::SetEventSearch(search));
+            }
+
+            match self.events(entity_db, time_ctrl) {
+                Some(Ok(events)) => {
+                    let num_events = events.num_ranges();
+                    ui.label(if num_events == 1 {
+                        "1 event".to_owned()
+                    } else {
+                        format!("{} events", re_format::format_uint(num_events))
+                    });
+
+                    let time = time_ctrl.time_int();
+                    let previous = time
+                        .and_then(|time| events.iter().filter(|range| range.min() < time).last());
+                    let next = match time {
+                        Some(time) => events.next_range_after(time),
+                        None => events.iter().next(),
+                    };
+
+                    if ui
+                        .add_enabled_ui(previous.is_some(), |ui| {
+                            ui.small_icon_button(&re_ui::icons::ARROW_LEFT, "Previous event")
+                        })
+                        .inner
+                        .on_hover_text("Jump to the start of the previous event")
+                        .clicked()
+                        && let Some(previous) = previous
+                    {
+                        time_commands.push(TimeControlCommand::SetTime(previous.min().into()));
+                    }
+
+                    if ui
+                        .add_enabled_ui(next.is_some(), |ui| {
+                            ui.small_icon_button(&re_ui::icons::ARROW_RIGHT, "Next event")
+                        })
+                        .inner
+                        .on_hover_text("Jump to the start of the next event")
+                        .clicked()
+                        && let Some(next) = next
+                    {
+                        time_commands.push(TimeControlCommand::SetTime(next.min().into()));
+                    }
+                }
+
+                Some(Err(err)) => {
+                    ui.error_label(format!("Can't search this component: {err}"));
+                }
+
+                None => {}
+            }
+
+            if ui
+                .small_icon_button(&re_ui::icons::CLOSE_SMALL, "Stop highlighting events")
+                .on_hover_text("Stop highlighting events")
+                .clicked()
+            {
+                time_commands.push(TimeControlCommand::ClearEventSearch);
+            }
+        });
+    }
+
+    /// Paints a band over every event that is in view.
+    pub fn paint_events(
+        &mut self,
+        entity_db: &EntityDb,
+        time_ctrl: &TimeControl,
+        time_ranges_ui: &TimeRangesUi,
+        painter: &egui::Painter,
+        rect: Rect,
+    ) {
+        let Some(Ok(events)) = self.events(entity_db, time_ctrl) else {
+            return;
+        };
+
+        let color = painter
+            .ctx()
+            .style()
+            .visuals
+            .selection
+            .bg_fill
+            .gamma_multiply(0.3);
+
+        for event in events.iter() {
+            paint_event(event, time_ranges_ui, painter, rect, color);
+        }
+    }
+}
+
+fn paint_event(
+    event: AbsoluteTimeRange,
+    time_ranges_ui: &TimeRangesUi,
+    painter: &egui::Painter,
+    rect: Rect,
+    color: Color32,
+) {
+    let (Some(x_from), Some(x_to)) = (
+        time_ranges_ui.x_from_time_f32(event.min().into()),
+        time_ranges_ui.x_from_time_f32(event.max().into()),
+    ) else {
+        return;
+    };
+
+    // Events that last a single row would be invisible otherwise.
+    let min_width = 2.0;
+    let center = 0.5 * (x_from + x_to);
+    let x_range = egui::Rangef::new(
+        x_from.min(center - 0.5 * min_width),
+        x_to.max(center + 0.5 * min_width),
+    );
+
+    let event_rect = Rect::from_x_y_ranges(x_range, rect.y_range()).intersect(rect);
+    if event_rect.is_positive() {
+        painter.rect_filled(event_rect, 0.0, color);
+    }
+}
@@ -0,0 +1,130 @@
+use std::collections::BTreeSet;
+
+use re_log_types::EntityPath;
+use re_types::blueprint::archetypes::TimePanelBlueprint;
+use re_viewer_context::{
+    BlueprintContext as _, CollapsedId, ViewerContext, time_panel_blueprint_entity_path,
+};
+
+/// Keeps the expanded rows of the streams tree in sync with the time panel blueprint.
+///
+/// The egui collapsing state stays the source of truth while the ui is shown, so that every way
+/// of expanding a row (clicking it, "Expand all", focusing a child, …) keeps working. The rows
+/// are only forced to match the blueprint when the blueprint itself changes, e.g. when a new one
+/// is loaded or after an undo.
+#[derive(Default)]
+pub struct ExpandedEntities {
+    /// The expanded entities as last read from the blueprint.
+    in_blueprint: Option<Vec<EntityPath>>,
+
+    /// The expanded entities, or `None` if the blueprint doesn't specify them yet.
+    expanded: Option<BTreeSet<EntityPath>>,
+
+    /// The rows that were open this frame.
+    ///
+    /// Used to seed [`Self::expanded`] the first time a row is toggled.
+    open_this_frame: BTreeSet<EntityPath>,
+
+    /// Should the rows shown this frame be opened or closed to match the blueprint?
+    apply_to_rows: bool,
+
+    /// Has a row been toggled this frame?
+    changed: bool,
+}
+
+impl ExpandedEntities {
+    /// Picks up any changes made to the blueprint since last frame.
+    pub fn begin_frame(&mut self, ctx: &ViewerContext<'_>) {
+        let in_blueprint = load_expanded_entities(ctx);
+
+        if in_blueprint != self.in_blueprint {
+            self.expanded = in_blueprint
+                .as_ref()
+                .map(|entities| entities.iter().cloned().collect());
+            self.in_blueprint = in_blueprint;
+            self.apply_to_rows = self.expanded.is_some();
+        }
+
+        self.open_this_frame.clear();
+        self.changed = false;
+    }
+
+    /// Opens or closes the row to match the blueprint, if needed.
+    ///
+    /// Call before showing the row.
+    pub fn apply(&self, egui_ctx: &egui::Context, entity_path: &EntityPath, id: &CollapsedId) {
+        if self.apply_to_rows
+            && let Some(expanded) = &self.expanded
+        {
+            id.set_open(egui_ctx, expanded.contains(entity_path));
+        }
+    }
+
+    /// Records whether the row ended up open, after it was shown.
+    pub fn record(&mut self, entity_path: &EntityPath, was_open: bool, is_open: bool) {
+        if is_open {
+            self.open_this_frame.insert(entity_path.clone());
+        }
+
+        if let Some(expanded) = &mut self.expanded {
+            if is_open != expanded.contains(entity_path) {
+                if is_open {
+                    expanded.insert(entity_path.clone());
+                } else {
+                    expanded.remove(entity_path);
+                }
+                self.changed = true;
+            }
+        } else if was_open != is_open {
+            self.changed = true;
+        }
+    }
+
+    /// Writes any toggled rows back to the blueprint.
+    pub fn end_frame(&mut self, ctx: &ViewerContext<'_>) {
+        self.apply_to_rows = false;
+
+        if !self.changed {
+            return;
+        }
+
+        let expanded = self
+            .expanded
+            .get_or_insert_with(|| std::mem::take(&mut self.open_this_frame));
+        let entities: Vec<EntityPath> = expanded.iter().cloned().collect();
+
+        save_expanded_entities(ctx, &entities);
+        self.in_blueprint = Some(entities);
+    }
+}
+
+fn load_expanded_entities(ctx: &ViewerContext<'_>) -> Option<Vec<EntityPath>> {
+    let component = TimePanelBlueprint::descriptor_expanded_entities().component;
+    let results = ctx.current_blueprint().storage_engine().cache().latest_at(
+        ctx.blueprint_query(),
+        &time_panel_blueprint_entity_path(),
+        [component],
+    );
+
+    let entities = results.component_batch_quiet::<re_types::components::EntityPath>(component)?;
+
+    Some(
+        entities
+            .iter()
+            .map(|entity_path| EntityPath::from(entity_path.as_str()))
+            .collect(),
+    )
+}
+
+fn save_expanded_entities(ctx: &ViewerContext<'_>, entities: &[EntityPath]) {
+    let entities: Vec<_> = entities
+        .iter()
+        .map(re_types::components::EntityPath::from)
+        .collect();
+
+    ctx.save_blueprint_component(
+        time_panel_blueprint_entity_path(),
+        &TimePanelBlueprint::descriptor_expanded_entities(),
+        &entities,
+    );
+}
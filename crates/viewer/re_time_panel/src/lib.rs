@@ -6,6 +6,8 @@
 #![warn(clippy::iter_over_hash_type)] //  TODO(#6198): enable everywhere
 
 mod data_density_graph;
+mod event_search_ui;
+mod expanded_entities;
 mod paint_ticks;
 mod recursive_chunks_per_timeline_subscriber;
 mod streams_tree_data;
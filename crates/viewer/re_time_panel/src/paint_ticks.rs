@@ -3,7 +3,9 @@ use std::ops::RangeInclusive;
 use egui::{Align2, Color32, Rect, Rgba, Shape, Stroke, lerp, pos2, remap_clamp};
 
 use re_format::time::next_grid_tick_magnitude_nanos;
-use re_log_types::{AbsoluteTimeRangeF, TimeReal, TimeType, TimestampFormat};
+use re_log_types::{
+    AbsoluteTimeRangeF, TimeInt, TimeReal, TimeType, TimelineCategories, TimestampFormat,
+};
 
 use super::time_ranges_ui::TimeRangesUi;
 
@@ -14,6 +16,7 @@ pub fn paint_time_ranges_and_ticks(
     line_y_range: RangeInclusive<f32>,
     time_type: TimeType,
     timestamp_format: TimestampFormat,
+    categories: Option<&TimelineCategories>,
 ) {
     let clip_rect = ui.clip_rect();
     let clip_left = clip_rect.left() as f64;
@@ -53,6 +56,7 @@ pub fn paint_time_ranges_and_ticks(
                 time_type,
                 &time_range,
                 timestamp_format,
+                categories,
             ));
     }
 }
@@ -63,6 +67,7 @@ fn paint_time_range_ticks(
     time_type: TimeType,
     time_range: &AbsoluteTimeRangeF,
     timestamp_format: TimestampFormat,
+    categories: Option<&TimelineCategories>,
 ) -> Vec<Shape> {
     let font_id = egui::TextStyle::Small.resolve(ui.style());
 
@@ -92,7 +97,10 @@ fn paint_time_range_ticks(
                 &ui.clip_rect(),
                 time_range,
                 next_power_of_10,
-                |seq| format!("#{}", re_format::format_int(seq)),
+                |seq| match categories {
+                    Some(categories) => categories.format(TimeInt::new_temporal(seq)),
+                    None => format!("#{}", re_format::format_int(seq)),
+                },
             )
         }
     }
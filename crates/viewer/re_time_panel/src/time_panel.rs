@@ -11,7 +11,7 @@ use re_data_ui::DataUi as _;
 use re_data_ui::item_ui::guess_instance_path_icon;
 use re_entity_db::{EntityDb, InstancePath};
 use re_log_types::{
-    AbsoluteTimeRange, ApplicationId, ComponentPath, EntityPath, TimeInt, TimeReal,
+    AbsoluteTimeRange, ApplicationId, ComponentPath, EntityPath, TimeInt, TimeReal, TimeType,
 };
 use re_types::ComponentIdentifier;
 use re_types::blueprint::components::PanelState;
@@ -27,7 +27,10 @@ use re_viewer_context::{
 use re_viewport_blueprint::ViewportBlueprint;
 
 use crate::{
-    MOVE_TIME_CURSOR_ICON, data_density_graph, paint_ticks,
+    MOVE_TIME_CURSOR_ICON, data_density_graph,
+    event_search_ui::EventSearchUi,
+    expanded_entities::ExpandedEntities,
+    paint_ticks,
     recursive_chunks_per_timeline_subscriber::PathRecursiveChunksPerTimelineStoreSubscriber,
     streams_tree_data::{EntityData, StreamsTreeData, components_for_entity},
     time_axis::TimelineAxis,
@@ -147,6 +150,14 @@ pub struct TimePanel {
     /// If we're hovering a specific event - what time is it?
     #[serde(skip)]
     hovered_event_time: Option<TimeInt>,
+
+    /// Highlights the results of the active event search, if any.
+    #[serde(skip)]
+    event_search_ui: EventSearchUi,
+
+    /// Keeps the expanded rows of the streams tree in sync with the blueprint.
+    #[serde(skip)]
+    expanded_entities: ExpandedEntities,
 }
 
 impl Default for TimePanel {
@@ -166,6 +177,8 @@ impl Default for TimePanel {
             scroll_to_me_item: None,
             time_edit_string: None,
             hovered_event_time: None,
+            event_search_ui: Default::default(),
+            expanded_entities: Default::default(),
         }
     }
 }
@@ -536,6 +549,14 @@ impl TimePanel {
             );
         }
 
+        self.event_search_ui.paint_events(
+            entity_db,
+            time_ctrl,
+            &self.time_ranges_ui,
+            ui.painter(),
+            time_fg_area_rect,
+        );
+
         ui.painter().hline(
             timeline_rect.left()..=ui.max_rect().right(),
             timeline_rect.bottom(),
@@ -549,6 +570,9 @@ impl TimePanel {
             timeline_rect.top()..=timeline_rect.bottom(),
             time_ctrl.time_type(),
             ctx.app_options().timestamp_format,
+            ctx.recording()
+                .timeline_categories(time_ctrl.timeline().name())
+                .as_ref(),
         );
         paint_time_ranges_gaps(
             &self.time_ranges_ui,
@@ -672,6 +696,11 @@ impl TimePanel {
                         &filter_matcher,
                     );
 
+                let syncs_expanded_entities = self.syncs_expanded_entities();
+                if syncs_expanded_entities {
+                    self.expanded_entities.begin_frame(ctx);
+                }
+
                 for child in &streams_tree_data.children {
                     self.show_entity(
                         ctx,
@@ -686,6 +715,10 @@ impl TimePanel {
                         time_commands,
                     );
                 }
+
+                if syncs_expanded_entities {
+                    self.expanded_entities.end_frame(ctx);
+                }
             });
     }
 
@@ -715,6 +748,13 @@ impl TimePanel {
             == HoverHighlight::Hovered;
 
         let collapse_scope = self.collapse_scope();
+        let collapsed_id = collapse_scope.entity(entity_path.clone());
+
+        let syncs_expanded_entities = self.syncs_expanded_entities();
+        if syncs_expanded_entities {
+            self.expanded_entities
+                .apply(ui.ctx(), entity_path, &collapsed_id);
+        }
 
         // Expand if one of the children is focused
         let focused_entity_path = ctx
@@ -725,14 +765,16 @@ impl TimePanel {
         if focused_entity_path
             .is_some_and(|focused_entity_path| focused_entity_path.is_descendant_of(entity_path))
         {
-            collapse_scope
-                .entity(entity_path.clone())
-                .set_open(ui.ctx(), true);
+            collapsed_id.set_open(ui.ctx(), true);
         }
 
+        let was_open = collapsed_id
+            .is_open(ui.ctx())
+            .unwrap_or(entity_data.default_open);
+
         // Globally unique id that is dependent on the "nature" of the tree (recording or blueprint,
         // in a filter session or not)
-        let id = collapse_scope.entity(entity_path.clone()).into();
+        let id = collapsed_id.egui_id();
 
         let list_item::ShowCollapsingResponse {
             item_response: response,
@@ -806,6 +848,14 @@ impl TimePanel {
             true,
         );
 
+        if syncs_expanded_entities {
+            let is_open = collapsed_id
+                .is_open(ui.ctx())
+                .unwrap_or(entity_data.default_open);
+            self.expanded_entities
+                .record(entity_path, was_open, is_open);
+        }
+
         let is_closed = body_response.is_none();
         let response_rect = response.rect;
         self.next_col_right = self.next_col_right.max(response_rect.right());
@@ -1154,6 +1204,20 @@ impl TimePanel {
                     );
                 });
             }
+        } else if ui.input(|i| i.pointer.primary_clicked())
+            && let Some(pointer_pos) = ui.ctx().pointer_interact_pos()
+            && ui.rect_contains_pointer(row_rect)
+            && let Some(clicked_time) = self.time_ranges_ui.time_from_x_f32(pointer_pos.x)
+            && let Some(next_time) = data_density_graph::next_data_time(
+                db,
+                item,
+                time_ctrl.timeline().name(),
+                clicked_time.floor(),
+            )
+        {
+            // Clicking a gap jumps to the next data of the stream, instead of into the void.
+            time_commands.push(TimeControlCommand::SetTime(next_time.into()));
+            time_commands.push(TimeControlCommand::Pause);
         }
     }
 
@@ -1300,6 +1364,8 @@ impl TimePanel {
                     );
 
                     self.current_time_ui(ctx, time_ctrl, ui, time_commands);
+                    self.event_search_ui
+                        .toolbar_ui(ui, entity_db, time_ctrl, time_commands);
 
                     ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                         help_button(ui);
@@ -1322,6 +1388,8 @@ impl TimePanel {
                 .playback_speed_ui(time_ctrl, ui, time_commands);
             self.time_control_ui.fps_ui(time_ctrl, ui, time_commands);
             self.current_time_ui(ctx, time_ctrl, ui, time_commands);
+            self.event_search_ui
+                .toolbar_ui(ui, entity_db, time_ctrl, time_commands);
 
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                 help_button(ui);
@@ -1329,6 +1397,11 @@ impl TimePanel {
         }
     }
 
+    /// Only the unfiltered streams tree of the recording keeps its expanded rows in the blueprint.
+    fn syncs_expanded_entities(&self) -> bool {
+        matches!(self.collapse_scope(), CollapseScope::StreamsTree)
+    }
+
     fn collapse_scope(&self) -> CollapseScope {
         match (self.source, self.filter_state.session_id()) {
             (TimePanelSource::Recording, None) => CollapseScope::StreamsTree,
@@ -1449,6 +1522,12 @@ impl TimePanel {
             && let Some(time) = time_ctrl.time()
         {
             let time_type = time_ctrl.time_type();
+            let categories = if time_type == TimeType::Sequence {
+                ctx.recording()
+                    .timeline_categories(time_ctrl.timeline().name())
+            } else {
+                None
+            };
 
             /// Pick number of decimals to show based on zoom level
             ///
@@ -1473,11 +1552,15 @@ impl TimePanel {
                 num_subsecond_decimals(1.0 / self.time_ranges_ui.points_per_time);
 
             let mut time_str = self.time_edit_string.clone().unwrap_or_else(|| {
-                time_type.format_opt(
-                    time_int,
-                    ctx.app_options().timestamp_format,
-                    subsecond_decimals,
-                )
+                if let Some(categories) = &categories {
+                    categories.format(time_int)
+                } else {
+                    time_type.format_opt(
+                        time_int,
+                        ctx.app_options().timestamp_format,
+                        subsecond_decimals,
+                    )
+                }
             });
 
             ui.style_mut().spacing.text_edit_width = 200.0;
@@ -1487,9 +1570,12 @@ impl TimePanel {
                 self.time_edit_string = Some(time_str.clone());
             }
             if response.lost_focus() {
-                if let Some(time_int) =
+                let parsed = if let Some(categories) = &categories {
+                    categories.parse(&time_str)
+                } else {
                     time_type.parse_time(&time_str, ctx.app_options().timestamp_format)
-                {
+                };
+                if let Some(time_int) = parsed {
                     time_commands.push(TimeControlCommand::SetTime(time_int.into()));
                 } else {
                     re_log::warn!("Failed to parse {time_str:?}");
@@ -1598,6 +1684,7 @@ fn help(os: egui::os::OperatingSystem) -> Help {
         )
         .control("Zoom", "Right click drag")
         .control("Reset view", "Double click")
+        .control("Jump to next data", "Click gap in stream")
         .control("Play/Pause", "Space")
 }
 
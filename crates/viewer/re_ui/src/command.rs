@@ -24,6 +24,9 @@ pub enum UICommand {
     SaveRecording,
     SaveRecordingSelection,
     SaveBlueprint,
+    ExportViewDataAsCsv,
+    #[cfg(not(target_arch = "wasm32"))]
+    ExportViewDataAsParquet,
     CloseCurrentRecording,
     CloseAllEntries,
 
@@ -51,6 +54,10 @@ pub enum UICommand {
     ToggleSelectionPanel,
     ToggleTimePanel,
     ToggleChunkStoreBrowser,
+    ToggleTransformTreePanel,
+    ToggleAnnotationLegend,
+    ToggleTrajectoryMetricsPanel,
+    ToggleEntityStatisticsOverlay,
     Settings,
 
     #[cfg(debug_assertions)]
@@ -132,6 +139,17 @@ impl UICommand {
                 "Save the current viewer setup as a Rerun blueprint file (.rbl)",
             ),
 
+            Self::ExportViewDataAsCsv => (
+                "Export view data as CSV…",
+                "Save the data the selected view shows, after overrides and visible time ranges, as CSV",
+            ),
+
+            #[cfg(not(target_arch = "wasm32"))]
+            Self::ExportViewDataAsParquet => (
+                "Export view data as Parquet…",
+                "Save the data the selected view shows, after overrides and visible time ranges, as Parquet",
+            ),
+
             Self::Open => (
                 "Open…",
                 "Open any supported files (.rrd, images, meshes, …) in a new recording",
@@ -212,6 +230,22 @@ impl UICommand {
                 "Toggle chunk store browser",
                 "Toggle the chunk store browser",
             ),
+            Self::ToggleTransformTreePanel => (
+                "Toggle transform tree panel",
+                "Show how the transforms of the current recording resolve at the current time, including cycles and missing parents",
+            ),
+            Self::ToggleAnnotationLegend => (
+                "Toggle annotation legend",
+                "Show the classes of the annotation contexts of the current recording, to hide them or change their color in all views",
+            ),
+            Self::ToggleTrajectoryMetricsPanel => (
+                "Toggle trajectory metrics panel",
+                "Compare the poses of two selected entities with transforms, e.g. an estimated trajectory and its ground truth, using the absolute and relative pose error",
+            ),
+            Self::ToggleEntityStatisticsOverlay => (
+                "Toggle entity statistics overlay",
+                "Show how many instances each visible entity has in every view, and how much data it logs, to find the streams overwhelming a view",
+            ),
             Self::Settings => ("Settings…", "Show the settings screen"),
 
             #[cfg(debug_assertions)]
@@ -357,6 +391,9 @@ impl UICommand {
             Self::SaveRecording => smallvec![cmd(Key::S)],
             Self::SaveRecordingSelection => smallvec![cmd_alt(Key::S)],
             Self::SaveBlueprint => smallvec![],
+            Self::ExportViewDataAsCsv => smallvec![],
+            #[cfg(not(target_arch = "wasm32"))]
+            Self::ExportViewDataAsParquet => smallvec![],
             Self::Open => smallvec![cmd(Key::O)],
             // Some browsers have a "paste and go" action.
             // But unfortunately there's no standard shortcut for this.
@@ -401,6 +438,10 @@ impl UICommand {
             Self::ToggleSelectionPanel => smallvec![ctrl_shift(Key::S)],
             Self::ToggleTimePanel => smallvec![ctrl_shift(Key::T)],
             Self::ToggleChunkStoreBrowser => smallvec![ctrl_shift(Key::D)],
+            Self::ToggleTransformTreePanel => smallvec![],
+            Self::ToggleAnnotationLegend => smallvec![],
+            Self::ToggleTrajectoryMetricsPanel => smallvec![],
+            Self::ToggleEntityStatisticsOverlay => smallvec![],
             Self::Settings => smallvec![cmd(Key::Comma)],
 
             #[cfg(debug_assertions)]
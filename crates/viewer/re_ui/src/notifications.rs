@@ -3,10 +3,15 @@
 //! Notifications are drawn both as a toast for some time when
 //! they're first created and in the notification panel.
 //!
+//! A notification that repeats an earlier one isn't shown again, but bumps the count of the
+//! earlier one instead, so that a problem that keeps happening doesn't flood the user.
+//! The panel sums up the warnings and errors of each source at the top.
+//!
 //! ## Special cased text
 //! - If a notifications text contains `"\nDetails:"` the section after that
 //!   will be displayed inside a collapsible details header.
 
+use std::collections::BTreeMap;
 use std::time::Duration;
 
 use egui::{NumExt as _, Widget as _};
@@ -106,13 +111,22 @@ pub struct Notification {
     details: Option<String>,
     link: Option<Link>,
 
+    /// Where the notification comes from, e.g. the crate that logged it.
+    source: Option<String>,
+
     /// If set, the notification will NEVER be shown again
     /// if the user has dismissed it.
     permanent_dismiss_id: Option<egui::Id>,
 
-    /// When this notification was added to the list.
+    /// When this notification was last added to the list.
     created_at: Timestamp,
 
+    /// When this notification was first added to the list.
+    first_created_at: Timestamp,
+
+    /// How many times this notification was added to the list.
+    count: usize,
+
     /// Time to live for toasts, the notification itself lives until dismissed.
     toast_ttl: Duration,
 
@@ -122,13 +136,17 @@ pub struct Notification {
 
 impl Notification {
     pub fn new(level: NotificationLevel, text: impl Into<String>) -> Self {
+        let now = Timestamp::now();
         Self {
             level,
             text: text.into(),
             details: None,
             link: None,
+            source: None,
             permanent_dismiss_id: None,
-            created_at: Timestamp::now(),
+            created_at: now,
+            first_created_at: now,
+            count: 1,
             toast_ttl: base_ttl(),
             is_unread: true,
         }
@@ -144,6 +162,12 @@ impl Notification {
         self
     }
 
+    /// Where the notification comes from, used to sum up warnings and errors in the panel.
+    pub fn with_source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
     // Show no toast - only show when clicking the notification panel!
     pub fn no_toast(mut self) -> Self {
         self.toast_ttl = Duration::ZERO;
@@ -164,6 +188,15 @@ impl Notification {
         }
     }
 
+    /// Is this the same notification as `other`, bar when it was created?
+    fn is_repeat_of(&self, other: &Self) -> bool {
+        self.level == other.level
+            && self.text == other.text
+            && self.details == other.details
+            && self.source == other.source
+            && self.permanent_dismiss_id == other.permanent_dismiss_id
+    }
+
     /// Did the user already dismiss this during an earlier run?
     fn is_perma_dismissed(&self, ctx: &egui::Context) -> bool {
         self.permanent_dismiss_id.is_some_and(|id| {
@@ -225,7 +258,8 @@ impl NotificationUi {
 
             let msg = split_msg.unwrap_or(&msg);
 
-            let mut notification = Notification::new(level.into(), msg);
+            let source = target.split("::").next().unwrap_or(&target);
+            let mut notification = Notification::new(level.into(), msg).with_source(source);
 
             if let Some(msg_details) = msg_details {
                 notification = notification.with_details(msg_details);
@@ -247,7 +281,21 @@ impl NotificationUi {
         if Some(notification.level) > self.unread_notification_level {
             self.unread_notification_level = Some(notification.level);
         }
-        self.notifications.push(notification);
+
+        if let Some(index) = self
+            .notifications
+            .iter()
+            .position(|existing| existing.is_repeat_of(&notification))
+        {
+            // Count the repeat instead of showing it again, and keep the list sorted by `created_at`.
+            let mut existing = self.notifications.remove(index);
+            existing.count += 1;
+            existing.created_at = notification.created_at;
+            existing.is_unread = true;
+            self.notifications.push(existing);
+        } else {
+            self.notifications.push(notification);
+        }
     }
 
     /// A little bell-like button, that shows recent notifications when clicked.
@@ -314,8 +362,9 @@ impl NotificationUi {
             .at_most(640.0);
 
         let mut to_dismiss = None;
+        let mut scroll_to = None;
 
-        let notification_list = |ui: &mut egui::Ui| {
+        let mut notification_list = |ui: &mut egui::Ui, scroll_to: Option<usize>| {
             if notifications.is_empty() {
                 ui.label(egui::RichText::new("No notifications yet.").weak());
 
@@ -323,10 +372,13 @@ impl NotificationUi {
             }
 
             for (i, notification) in notifications.iter().enumerate().rev() {
-                let reaction = show_notification(ui, notification, DisplayMode::Panel).0;
+                let (reaction, response) = show_notification(ui, notification, DisplayMode::Panel);
                 if reaction.is_some() {
                     to_dismiss = Some(i);
                 }
+                if scroll_to == Some(i) {
+                    response.scroll_to_me(Some(egui::Align::Center));
+                }
             }
         };
 
@@ -347,10 +399,12 @@ impl NotificationUi {
                 }
             });
         });
+        sources_summary_ui(ui, notifications, &mut scroll_to);
+
         egui::ScrollArea::vertical()
             .min_scrolled_height(panel_max_height / 2.0)
             .max_height(panel_max_height)
-            .show(ui, notification_list);
+            .show(ui, |ui| notification_list(ui, scroll_to));
 
         if !notifications.is_empty() {
             ui.horizontal_top(|ui| {
@@ -374,6 +428,90 @@ impl NotificationUi {
     }
 }
 
+/// Warnings and errors of a single source, summed up over all notifications.
+#[derive(Default)]
+struct SourceSummary {
+    num_warnings: usize,
+    num_errors: usize,
+
+    /// Index of the oldest warning or error of the source.
+    first_index: Option<usize>,
+}
+
+/// Lists the number of warnings and errors per source, each with a button that scrolls to
+/// the oldest of them.
+fn sources_summary_ui(
+    ui: &mut egui::Ui,
+    notifications: &[Notification],
+    scroll_to: &mut Option<usize>,
+) {
+    let mut summaries: BTreeMap<&str, SourceSummary> = BTreeMap::default();
+    for (i, notification) in notifications.iter().enumerate() {
+        let Some(source) = &notification.source else {
+            continue;
+        };
+
+        let summary = summaries.entry(source.as_str()).or_default();
+        match notification.level {
+            NotificationLevel::Warning => summary.num_warnings += notification.count,
+            NotificationLevel::Error => summary.num_errors += notification.count,
+            NotificationLevel::Tip | NotificationLevel::Info | NotificationLevel::Success => {
+                continue;
+            }
+        }
+
+        // Repeats move to the end of the list, so the list isn't sorted by when they first happened.
+        if summary.first_index.is_none_or(|first_index| {
+            notification.first_created_at < notifications[first_index].first_created_at
+        }) {
+            summary.first_index = Some(i);
+        }
+    }
+
+    for (source, summary) in summaries {
+        let Some(first_index) = summary.first_index else {
+            continue;
+        };
+
+        ui.horizontal(|ui| {
+            let level = if summary.num_errors > 0 {
+                NotificationLevel::Error
+            } else {
+                NotificationLevel::Warning
+            };
+            ui.add(level.image(ui));
+            ui.label(source);
+
+            let counts = [
+                (summary.num_errors, "error"),
+                (summary.num_warnings, "warning"),
+            ]
+            .into_iter()
+            .filter(|(count, _)| *count > 0)
+            .map(|(count, noun)| {
+                format!(
+                    "{} {noun}{}",
+                    re_format::format_uint(count),
+                    if count == 1 { "" } else { "s" }
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+            ui.label(egui::RichText::new(counts).weak());
+
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui
+                    .small_icon_button(&icons::ARROW_DOWN, "Jump to first occurrence")
+                    .on_hover_text("Jump to first occurrence")
+                    .clicked()
+                {
+                    *scroll_to = Some(first_index);
+                }
+            });
+        });
+    }
+}
+
 fn base_ttl() -> Duration {
     Duration::from_secs(4)
 }
@@ -463,8 +601,11 @@ fn show_notification(
         text,
         details,
         link,
+        source: _,
         permanent_dismiss_id,
         created_at,
+        first_created_at,
+        count,
         toast_ttl: _,
         is_unread,
     } = notification;
@@ -492,6 +633,17 @@ fn show_notification(
                         ui.set_width(270.0);
                         ui.label(text);
 
+                        if *count > 1 {
+                            ui.label(
+                                egui::RichText::new(format!(
+                                    "Happened {} times",
+                                    re_format::format_uint(*count)
+                                ))
+                                .weak(),
+                            )
+                            .on_hover_text(format!("First at {first_created_at}"));
+                        }
+
                         if let Some(details) = details {
                             ui.collapsing_header("Details", false, |ui| ui.label(details));
                         }
@@ -567,3 +719,36 @@ fn notification_age_label(ui: &mut egui::Ui, created_at: Timestamp) {
         });
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repeated_notifications_are_counted() {
+        let mut notification_ui = NotificationUi::new(egui::Context::default());
+
+        let decode_error = || {
+            Notification::new(NotificationLevel::Error, "Failed to decode")
+                .with_source("re_data_loader")
+        };
+        notification_ui.add(decode_error());
+        notification_ui.add(
+            Notification::new(NotificationLevel::Warning, "Dropped message")
+                .with_source("re_data_loader"),
+        );
+        notification_ui.add(decode_error());
+
+        // The repeat is counted, and moved to the end as the latest notification.
+        let counts = notification_ui
+            .notifications
+            .iter()
+            .map(|notification| (notification.text.as_str(), notification.count))
+            .collect::<Vec<_>>();
+        assert_eq!(counts, [("Dropped message", 1), ("Failed to decode", 2)]);
+        assert_eq!(
+            notification_ui.unread_notification_level(),
+            Some(NotificationLevel::Error)
+        );
+    }
+}
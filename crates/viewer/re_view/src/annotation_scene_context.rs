@@ -1,9 +1,12 @@
 use std::sync::Arc;
 
+use re_types::blueprint::archetypes::ClassIdRemap;
+use re_types::components::ClassId;
 use re_viewer_context::{
-    AnnotationMap, IdentifiedViewSystem, ViewContextSystem, ViewContextSystemOncePerFrameResult,
-    ViewSystemIdentifier,
+    AnnotationMap, ClassIdRemapping, IdentifiedViewSystem, ViewContextSystem,
+    ViewContextSystemOncePerFrameResult, ViewSystemIdentifier,
 };
+use re_viewport_blueprint::ViewProperty;
 
 #[derive(Default)]
 pub struct AnnotationSceneContext(pub Arc<AnnotationMap>);
@@ -29,7 +32,7 @@ impl ViewContextSystem for AnnotationSceneContext {
 
     fn execute(
         &mut self,
-        _ctx: &re_viewer_context::ViewContext<'_>,
+        ctx: &re_viewer_context::ViewContext<'_>,
         _query: &re_viewer_context::ViewQuery<'_>,
         once_per_frame_result: &ViewContextSystemOncePerFrameResult,
     ) {
@@ -39,9 +42,40 @@ impl ViewContextSystem for AnnotationSceneContext {
             .expect("Unexpected static execution result type")
             .0
             .clone();
+
+        let class_remap = class_id_remapping(ctx);
+        if !class_remap.is_empty() {
+            self.0 = Arc::new(self.0.with_class_remap(&class_remap));
+        }
     }
 
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
 }
+
+/// The [`ClassIdRemap`] view property of the view, if any.
+fn class_id_remapping(ctx: &re_viewer_context::ViewContext<'_>) -> ClassIdRemapping {
+    let property = ViewProperty::from_archetype::<ClassIdRemap>(
+        ctx.blueprint_db(),
+        ctx.blueprint_query(),
+        ctx.view_id,
+    );
+    let class_ids = |component| {
+        property
+            .component_array_or_empty::<ClassId>(component)
+            .unwrap_or_else(|err| {
+                re_log::warn_once!("Failed to read class id remap: {err}");
+                Vec::new()
+            })
+    };
+
+    let source_class_ids = class_ids(ClassIdRemap::descriptor_source_class_ids().component);
+    let target_class_ids = class_ids(ClassIdRemap::descriptor_target_class_ids().component);
+    ClassIdRemapping::from_pairs(
+        source_class_ids
+            .into_iter()
+            .zip(target_class_ids)
+            .map(|(source, target)| (source.0, target.0)),
+    )
+}
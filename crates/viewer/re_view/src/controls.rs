@@ -26,12 +26,39 @@ pub const ROLL_MOUSE_ALT: PointerButton = PointerButton::Primary;
 /// See [`ROLL_MOUSE_ALT`].
 pub const ROLL_MOUSE_MODIFIER: Modifiers = Modifiers::ALT;
 
+/// Which mouse button to drag for selecting instances in a 3D view, if a selection modifier is pressed.
+pub const SELECTION3D_BUTTON: PointerButton = PointerButton::Primary;
+
+/// Dragging with this modifier selects the instances inside a rectangle in 3D views.
+pub const SELECTION_RECT3D_MODIFIER: Modifiers = Modifiers::COMMAND;
+
+/// Dragging with this modifier selects the instances inside a lasso in 3D views.
+pub const SELECTION_LASSO3D_MODIFIER: Modifiers = Modifiers::COMMAND.plus(Modifiers::SHIFT);
+
 /// Which modifier speeds up the 3D camera movement.
 pub const SPEED_UP_3D_MODIFIER: Modifiers = Modifiers::SHIFT;
 
 /// Key to restore the camera.
 pub const TRACKED_OBJECT_RESTORE_KEY: Key = Key::Escape;
 
+/// Key to move the crop box of a 3D view to the hovered point.
+pub const CROP_BOX_CENTER_KEY: Key = Key::C;
+
+/// Key to look at a 3D scene from the front.
+pub const FRONT_VIEW3D_KEY: Key = Key::Num1;
+
+/// Key to look at a 3D scene from the left.
+pub const LEFT_VIEW3D_KEY: Key = Key::Num2;
+
+/// Key to look at a 3D scene from the top.
+pub const TOP_VIEW3D_KEY: Key = Key::Num3;
+
+/// Key to look at a 3D scene from an isometric angle.
+pub const ISOMETRIC_VIEW3D_KEY: Key = Key::Num4;
+
+/// Key to switch a 3D view between perspective and orthographic projection.
+pub const TOGGLE_PROJECTION3D_KEY: Key = Key::Num5;
+
 /// Toggle the currently selected view to be maximized or not.
 // NOTE: we use CTRL and not COMMAND, because ⌘+M minimizes the whole window on macOS.
 pub const TOGGLE_MAXIMIZE_VIEW: KeyboardShortcut = KeyboardShortcut::new(Modifiers::CTRL, Key::M);
@@ -9,6 +9,9 @@ pub const SIZE_BOOST_IN_POINTS_FOR_LINE_OUTLINES: f32 = 1.0;
 /// Gap between points and their outline.
 pub const SIZE_BOOST_IN_POINTS_FOR_POINT_OUTLINES: f32 = 2.5;
 
+/// Extra radius lines get on the picking layer, so that thin lines remain hoverable.
+pub const SIZE_BOOST_IN_POINTS_FOR_LINE_PICKING: f32 = 2.0;
+
 /// Produce an [`re_renderer::OutlineConfig`] based on the [`egui::Style`] of the provided [`egui::Context`].
 pub fn outline_config(gui_ctx: &egui::Context) -> re_renderer::OutlineConfig {
     // Use the exact same colors we have in the ui!
@@ -0,0 +1,399 @@
+use re_types::components::{AudioSampleFormat, MediaType};
+
+/// Decoded audio, with the samples of each channel converted to `f32` in the range `[-1, 1]`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecodedAudio {
+    /// Samples per second, per channel.
+    pub sample_rate: u32,
+
+    /// One buffer of samples per channel. All buffers have the same length.
+    pub channels: Vec<Vec<f32>>,
+}
+
+impl DecodedAudio {
+    /// Number of samples in each channel.
+    pub fn num_frames(&self) -> usize {
+        self.channels.first().map_or(0, |channel| channel.len())
+    }
+
+    /// Duration of the audio in nanoseconds.
+    pub fn duration_nanos(&self) -> i64 {
+        self.frame_offset_nanos(self.num_frames())
+    }
+
+    /// Offset of the given frame from the start of the audio, in nanoseconds.
+    pub fn frame_offset_nanos(&self, frame: usize) -> i64 {
+        (frame as f64 * 1e9 / self.sample_rate as f64).round() as i64
+    }
+
+    /// Total memory used by the decoded samples.
+    pub fn size_bytes(&self) -> u64 {
+        self.channels
+            .iter()
+            .map(|channel| (channel.len() * std::mem::size_of::<f32>()) as u64)
+            .sum()
+    }
+}
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum AudioDecodeError {
+    #[error(
+        "Unsupported audio media type {0:?}. Only raw PCM samples and uncompressed WAV are supported"
+    )]
+    UnsupportedMediaType(String),
+
+    #[error("Raw PCM samples need a sample rate")]
+    MissingSampleRate,
+
+    #[error("Invalid sample rate of {0} Hz")]
+    InvalidSampleRate(u32),
+
+    #[error("Audio needs at least one channel")]
+    NoChannels,
+
+    #[error(
+        "Buffer of {num_bytes} bytes is not a whole number of frames of {frame_size} bytes each"
+    )]
+    PartialFrame { num_bytes: usize, frame_size: usize },
+
+    #[error("Invalid WAV file: {0}")]
+    InvalidWav(&'static str),
+
+    #[error(
+        "Unsupported WAV encoding with format tag {format_tag} and {bits_per_sample} bits per sample"
+    )]
+    UnsupportedWavEncoding {
+        format_tag: u16,
+        bits_per_sample: u16,
+    },
+}
+
+/// Media types that are treated as WAV files.
+///
+/// The official one is `audio/wav`, but the others are commonly found in the wild.
+const WAV_MEDIA_TYPES: &[&str] = &["audio/wav", "audio/x-wav", "audio/wave", "audio/vnd.wave"];
+
+/// Decodes an [`re_types::archetypes::Audio`] blob.
+///
+/// Without a media type, the blob is interpreted as raw PCM samples described by
+/// `sample_rate`, `sample_format`, and `channel_count`, unless it starts with a WAV header.
+/// For WAV files, all of these are read from the header instead.
+pub fn decode_audio(
+    blob: &[u8],
+    media_type: Option<&MediaType>,
+    sample_rate: Option<u32>,
+    sample_format: AudioSampleFormat,
+    channel_count: u32,
+) -> Result<DecodedAudio, AudioDecodeError> {
+    re_tracing::profile_function!();
+
+    match media_type {
+        Some(media_type) if WAV_MEDIA_TYPES.contains(&media_type.as_str()) => decode_wav(blob),
+        Some(media_type) => Err(AudioDecodeError::UnsupportedMediaType(
+            media_type.as_str().to_owned(),
+        )),
+        None if is_wav(blob) => decode_wav(blob),
+        None => decode_pcm(
+            blob,
+            sample_rate.ok_or(AudioDecodeError::MissingSampleRate)?,
+            sample_format,
+            channel_count,
+        ),
+    }
+}
+
+/// Splits interleaved little-endian PCM samples into one `f32` buffer per channel.
+fn decode_pcm(
+    bytes: &[u8],
+    sample_rate: u32,
+    sample_format: AudioSampleFormat,
+    channel_count: u32,
+) -> Result<DecodedAudio, AudioDecodeError> {
+    if sample_rate == 0 {
+        return Err(AudioDecodeError::InvalidSampleRate(sample_rate));
+    }
+    if channel_count == 0 {
+        return Err(AudioDecodeError::NoChannels);
+    }
+
+    let num_channels = channel_count as usize;
+    let bytes_per_sample = sample_format.bytes_per_sample();
+    let frame_size = bytes_per_sample * num_channels;
+    if bytes.len() % frame_size != 0 {
+        return Err(AudioDecodeError::PartialFrame {
+            num_bytes: bytes.len(),
+            frame_size,
+        });
+    }
+
+    let num_frames = bytes.len() / frame_size;
+    let mut channels = vec![Vec::with_capacity(num_frames); num_channels];
+    for (i, sample) in bytes.chunks_exact(bytes_per_sample).enumerate() {
+        let value = match sample_format {
+            AudioSampleFormat::F32 => {
+                f32::from_le_bytes([sample[0], sample[1], sample[2], sample[3]])
+            }
+            AudioSampleFormat::I16 => {
+                i16::from_le_bytes([sample[0], sample[1]]) as f32 / -(i16::MIN as f32)
+            }
+            AudioSampleFormat::I32 => {
+                i32::from_le_bytes([sample[0], sample[1], sample[2], sample[3]]) as f32
+                    / -(i32::MIN as f32)
+            }
+        };
+        channels[i % num_channels].push(value);
+    }
+
+    Ok(DecodedAudio {
+        sample_rate,
+        channels,
+    })
+}
+
+fn is_wav(bytes: &[u8]) -> bool {
+    bytes.len() >= 12 && &bytes[0..4] == b"RIFF" && &bytes[8..12] == b"WAVE"
+}
+
+/// Parses an uncompressed WAV file, i.e. integer or float PCM samples in a RIFF container.
+fn decode_wav(bytes: &[u8]) -> Result<DecodedAudio, AudioDecodeError> {
+    const WAVE_FORMAT_PCM: u16 = 0x0001;
+    const WAVE_FORMAT_IEEE_FLOAT: u16 = 0x0003;
+    const WAVE_FORMAT_EXTENSIBLE: u16 = 0xFFFE;
+
+    if !is_wav(bytes) {
+        return Err(AudioDecodeError::InvalidWav("missing RIFF/WAVE header"));
+    }
+
+    let read_u16 = |b: &[u8], offset: usize| u16::from_le_bytes([b[offset], b[offset + 1]]);
+    let read_u32 = |b: &[u8], offset: usize| {
+        u32::from_le_bytes([b[offset], b[offset + 1], b[offset + 2], b[offset + 3]])
+    };
+
+    // (format tag, channel count, sample rate, bits per sample)
+    let mut format = None;
+    let mut data = None;
+
+    let mut rest = &bytes[12..];
+    while rest.len() >= 8 {
+        let chunk_id = &rest[0..4];
+        let chunk_size = read_u32(rest, 4) as usize;
+        let body = &rest[8..];
+
+        // Streaming writers may leave the size of the last chunk unset.
+        let chunk = &body[..chunk_size.min(body.len())];
+
+        match chunk_id {
+            b"fmt " => {
+                if chunk.len() < 16 {
+                    return Err(AudioDecodeError::InvalidWav("`fmt ` chunk is too short"));
+                }
+                let mut format_tag = read_u16(chunk, 0);
+                if format_tag == WAVE_FORMAT_EXTENSIBLE && chunk.len() >= 26 {
+                    // The actual format is stored in the first two bytes of the sub-format GUID.
+                    format_tag = read_u16(chunk, 24);
+                }
+                format = Some((
+                    format_tag,
+                    read_u16(chunk, 2),
+                    read_u32(chunk, 4),
+                    read_u16(chunk, 14),
+                ));
+            }
+            b"data" => {
+                data = Some(chunk);
+            }
+            _ => {}
+        }
+
+        // Chunks are padded to an even number of bytes.
+        let padded_size = chunk_size.saturating_add(chunk_size % 2);
+        rest = body.get(padded_size..).unwrap_or_default();
+    }
+
+    let Some((format_tag, channel_count, sample_rate, bits_per_sample)) = format else {
+        return Err(AudioDecodeError::InvalidWav("missing `fmt ` chunk"));
+    };
+    let Some(data) = data else {
+        return Err(AudioDecodeError::InvalidWav("missing `data` chunk"));
+    };
+
+    let sample_format = match (format_tag, bits_per_sample) {
+        (WAVE_FORMAT_PCM, 16) => AudioSampleFormat::I16,
+        (WAVE_FORMAT_PCM, 32) => AudioSampleFormat::I32,
+        (WAVE_FORMAT_IEEE_FLOAT, 32) => AudioSampleFormat::F32,
+        _ => {
+            return Err(AudioDecodeError::UnsupportedWavEncoding {
+                format_tag,
+                bits_per_sample,
+            });
+        }
+    };
+
+    // Ignore a trailing partial frame of a truncated file rather than rejecting the whole file.
+    let frame_size = sample_format.bytes_per_sample() * channel_count.max(1) as usize;
+    let data = &data[..data.len() - data.len() % frame_size];
+
+    decode_pcm(data, sample_rate, sample_format, channel_count as u32)
+}
+
+/// Reduces the samples in `frames` to at most `num_buckets` pairs of minimum and maximum.
+///
+/// Used to draw long waveforms without submitting every single sample to the plot.
+pub fn min_max_envelope(
+    samples: &[f32],
+    frames: std::ops::Range<usize>,
+    num_buckets: usize,
+) -> impl Iterator<Item = (usize, f32, f32)> + '_ {
+    let frames = frames.start.min(samples.len())..frames.end.min(samples.len());
+    let bucket_size = frames.len().div_ceil(num_buckets.max(1)).max(1);
+
+    samples[frames.clone()]
+        .chunks(bucket_size)
+        .enumerate()
+        .map(move |(i, bucket)| {
+            let (min, max) = bucket
+                .iter()
+                .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), &s| {
+                    (min.min(s), max.max(s))
+                });
+            (frames.start + i * bucket_size, min, max)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wav_file(format_tag: u16, channels: u16, bits_per_sample: u16, data: &[u8]) -> Vec<u8> {
+        let sample_rate = 8000_u32;
+        let block_align = channels * bits_per_sample / 8;
+
+        let mut wav = Vec::new();
+        wav.extend_from_slice(b"RIFF");
+        wav.extend_from_slice(&(36 + data.len() as u32).to_le_bytes());
+        wav.extend_from_slice(b"WAVE");
+        wav.extend_from_slice(b"fmt ");
+        wav.extend_from_slice(&16_u32.to_le_bytes());
+        wav.extend_from_slice(&format_tag.to_le_bytes());
+        wav.extend_from_slice(&channels.to_le_bytes());
+        wav.extend_from_slice(&sample_rate.to_le_bytes());
+        wav.extend_from_slice(&(sample_rate * block_align as u32).to_le_bytes());
+        wav.extend_from_slice(&block_align.to_le_bytes());
+        wav.extend_from_slice(&bits_per_sample.to_le_bytes());
+        wav.extend_from_slice(b"data");
+        wav.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        wav.extend_from_slice(data);
+        wav
+    }
+
+    #[test]
+    fn decode_raw_pcm() {
+        let samples = [0.5_f32, -0.5, 0.25, -0.25, 1.0, -1.0];
+        let bytes: Vec<u8> = samples.iter().flat_map(|s| s.to_le_bytes()).collect();
+
+        let audio = decode_audio(&bytes, None, Some(48_000), AudioSampleFormat::F32, 2).unwrap();
+        assert_eq!(audio.sample_rate, 48_000);
+        assert_eq!(
+            audio.channels,
+            vec![vec![0.5, 0.25, 1.0], vec![-0.5, -0.25, -1.0]]
+        );
+        assert_eq!(audio.num_frames(), 3);
+
+        let bytes: Vec<u8> = [i16::MIN, 0, 16384]
+            .iter()
+            .flat_map(|s| s.to_le_bytes())
+            .collect();
+        let audio = decode_audio(&bytes, None, Some(100), AudioSampleFormat::I16, 1).unwrap();
+        assert_eq!(audio.channels, vec![vec![-1.0, 0.0, 0.5]]);
+        assert_eq!(audio.duration_nanos(), 30_000_000);
+    }
+
+    #[test]
+    fn decode_raw_pcm_errors() {
+        let bytes = [0_u8; 6];
+        assert_eq!(
+            decode_audio(&bytes, None, None, AudioSampleFormat::I16, 1),
+            Err(AudioDecodeError::MissingSampleRate)
+        );
+        assert_eq!(
+            decode_audio(&bytes, None, Some(100), AudioSampleFormat::I16, 0),
+            Err(AudioDecodeError::NoChannels)
+        );
+        assert_eq!(
+            decode_audio(&bytes, None, Some(100), AudioSampleFormat::F32, 1),
+            Err(AudioDecodeError::PartialFrame {
+                num_bytes: 6,
+                frame_size: 4
+            })
+        );
+        assert!(matches!(
+            decode_audio(
+                &bytes,
+                Some(&MediaType::from("audio/mpeg")),
+                Some(100),
+                AudioSampleFormat::I16,
+                1
+            ),
+            Err(AudioDecodeError::UnsupportedMediaType(_))
+        ));
+    }
+
+    #[test]
+    fn decode_wav_file() {
+        let data: Vec<u8> = [i16::MIN, i16::MAX, 0, 0]
+            .iter()
+            .flat_map(|s| s.to_le_bytes())
+            .collect();
+        let wav = wav_file(1, 2, 16, &data);
+
+        // The media type is optional for WAV files, and the header wins over the logged parameters.
+        for media_type in [None, Some(MediaType::wav()), Some("audio/x-wav".into())] {
+            let audio = decode_audio(
+                &wav,
+                media_type.as_ref(),
+                Some(1),
+                AudioSampleFormat::F32,
+                5,
+            )
+            .unwrap();
+            assert_eq!(audio.sample_rate, 8000);
+            assert_eq!(audio.channels.len(), 2);
+            assert_eq!(audio.channels[0], vec![-1.0, 0.0]);
+            assert!((audio.channels[1][0] - 1.0).abs() < 1e-4);
+        }
+
+        let data: Vec<u8> = [0.25_f32, -0.75]
+            .iter()
+            .flat_map(|s| s.to_le_bytes())
+            .collect();
+        let audio = decode_wav(&wav_file(3, 1, 32, &data)).unwrap();
+        assert_eq!(audio.channels, vec![vec![0.25, -0.75]]);
+
+        assert_eq!(
+            decode_wav(&wav_file(1, 1, 24, &[0; 6])),
+            Err(AudioDecodeError::UnsupportedWavEncoding {
+                format_tag: 1,
+                bits_per_sample: 24
+            })
+        );
+        assert_eq!(
+            decode_wav(b"RIFF\0\0\0\0WAVE"),
+            Err(AudioDecodeError::InvalidWav("missing `fmt ` chunk"))
+        );
+    }
+
+    #[test]
+    fn envelope() {
+        let samples = [0.0, 1.0, -1.0, 0.5, 0.25, -0.25, 0.0];
+
+        let buckets: Vec<_> = min_max_envelope(&samples, 0..samples.len(), 3).collect();
+        assert_eq!(
+            buckets,
+            vec![(0, -1.0, 1.0), (3, -0.25, 0.5), (6, 0.0, 0.0)]
+        );
+
+        // Out-of-range frames are clamped.
+        let buckets: Vec<_> = min_max_envelope(&samples, 5..100, 100).collect();
+        assert_eq!(buckets, vec![(5, -0.25, -0.25), (6, 0.0, 0.0)]);
+    }
+}
@@ -0,0 +1,134 @@
+use std::sync::Arc;
+
+use ahash::{HashMap, HashSet};
+
+use itertools::Either;
+use re_chunk_store::{ChunkStoreEvent, RowId};
+use re_entity_db::EntityDb;
+use re_log_types::hash::Hash64;
+use re_types::{
+    archetypes::Audio,
+    components::{AudioSampleFormat, MediaType},
+};
+use re_viewer_context::{Cache, CacheMemoryReport, CacheMemoryReportItem};
+
+use crate::audio::{AudioDecodeError, DecodedAudio, decode_audio};
+
+struct AudioEntry {
+    result: Result<Arc<DecodedAudio>, AudioDecodeError>,
+    last_used_generation: u64,
+}
+
+impl AudioEntry {
+    fn memory_used(&self) -> u64 {
+        self.result.as_ref().map_or(0, |audio| audio.size_bytes())
+    }
+}
+
+/// Caches the decoded samples of [`Audio`] blobs.
+///
+/// Entries are keyed by the row id of the blob and the parameters it was decoded with,
+/// since those may be changed with blueprint overrides.
+#[derive(Default)]
+pub struct AudioCache {
+    cache: HashMap<RowId, HashMap<Hash64, AudioEntry>>,
+    generation: u64,
+}
+
+impl AudioCache {
+    /// Decode an audio blob and cache the result.
+    pub fn entry(
+        &mut self,
+        blob_row_id: RowId,
+        blob: &[u8],
+        media_type: Option<&MediaType>,
+        sample_rate: Option<u32>,
+        sample_format: AudioSampleFormat,
+        channel_count: u32,
+    ) -> Result<Arc<DecodedAudio>, AudioDecodeError> {
+        let inner_key = Hash64::hash((media_type, sample_rate, sample_format, channel_count));
+
+        let entry = self
+            .cache
+            .entry(blob_row_id)
+            .or_default()
+            .entry(inner_key)
+            .or_insert_with(|| AudioEntry {
+                result: decode_audio(blob, media_type, sample_rate, sample_format, channel_count)
+                    .map(Arc::new),
+                last_used_generation: 0,
+            });
+        entry.last_used_generation = self.generation;
+
+        entry.result.clone()
+    }
+}
+
+impl Cache for AudioCache {
+    fn begin_frame(&mut self) {
+        // Decoding is cheap compared to keeping long recordings around twice,
+        // so drop everything that wasn't used in the last frame.
+        self.cache.retain(|_, entries| {
+            entries.retain(|_, entry| entry.last_used_generation == self.generation);
+
+            !entries.is_empty()
+        });
+        self.generation += 1;
+    }
+
+    fn purge_memory(&mut self) {
+        self.cache.clear();
+    }
+
+    fn memory_report(&self) -> CacheMemoryReport {
+        let mut items: Vec<_> = self
+            .cache
+            .iter()
+            .map(|(row_id, entries)| CacheMemoryReportItem {
+                item_name: row_id.short_string(),
+                bytes_cpu: entries.values().map(AudioEntry::memory_used).sum(),
+                bytes_gpu: None,
+            })
+            .collect();
+        items.sort_by(|a, b| a.item_name.cmp(&b.item_name));
+        CacheMemoryReport {
+            bytes_cpu: items.iter().map(|item| item.bytes_cpu).sum(),
+            bytes_gpu: None,
+            per_cache_item_info: items,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "Audio"
+    }
+
+    fn on_store_events(&mut self, events: &[&ChunkStoreEvent], _entity_db: &EntityDb) {
+        re_tracing::profile_function!();
+
+        let row_ids_removed: HashSet<RowId> = events
+            .iter()
+            .flat_map(|event| {
+                let is_deletion = event.kind == re_chunk_store::ChunkStoreDiffKind::Deletion;
+                let contains_audio = || {
+                    event
+                        .chunk
+                        .components()
+                        .contains_component(Audio::descriptor_blob().component)
+                };
+
+                if is_deletion && contains_audio() {
+                    Either::Left(event.chunk.row_ids())
+                } else {
+                    Either::Right(std::iter::empty())
+                }
+            })
+            .collect();
+
+        self.cache
+            .retain(|row_id, _entries| !row_ids_removed.contains(row_id));
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
@@ -0,0 +1,12 @@
+//! Rerun Audio View
+//!
+//! A View that shows the waveforms of audio on the timeline.
+
+mod audio;
+mod audio_cache;
+#[cfg(all(feature = "playback", not(target_arch = "wasm32")))]
+mod playback;
+mod view_class;
+mod visualizer_system;
+
+pub use view_class::AudioView;
@@ -0,0 +1,256 @@
+//! Plays the audio of a view back, in sync with the time cursor.
+
+use std::sync::Arc;
+
+use cpal::traits::{DeviceTrait as _, HostTrait as _, StreamTrait as _};
+use parking_lot::Mutex;
+
+use crate::audio::DecodedAudio;
+
+/// How far the audio may drift from the time cursor before it jumps to it, in nanoseconds.
+///
+/// Time advances once per frame, while audio is consumed in buffers of its own, so the two never
+/// line up exactly. Jumping on every frame would be audible, so small differences are tolerated.
+const MAX_DRIFT_NANOS: f64 = 100e6;
+
+/// Audio to play, starting at `start` on the timeline.
+#[derive(Clone)]
+pub struct PlaybackClip {
+    /// In nanoseconds.
+    pub start: i64,
+    pub audio: Arc<DecodedAudio>,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum PlaybackError {
+    #[error("No audio output device found")]
+    NoOutputDevice,
+
+    #[error("Unsupported output sample format {0}")]
+    UnsupportedSampleFormat(String),
+
+    #[error("Audio output device error: {0}")]
+    Device(String),
+}
+
+fn device_error(err: impl std::fmt::Display) -> PlaybackError {
+    PlaybackError::Device(err.to_string())
+}
+
+/// What the output device plays, shared between the UI and the audio thread.
+#[derive(Default)]
+struct Playback {
+    clips: Vec<PlaybackClip>,
+
+    /// Where on the timeline the next output sample is, in nanoseconds.
+    position: f64,
+
+    /// Timeline nanoseconds per second of output, zero while paused.
+    nanos_per_second: f64,
+
+    /// Set when the player is dropped.
+    stop: bool,
+}
+
+impl Playback {
+    /// Mixes all clips into the interleaved `out` buffer, and advances the position past it.
+    ///
+    /// Mono clips play on all output channels, other clips on the matching ones.
+    fn fill(&mut self, out: &mut [f32], num_channels: usize, sample_rate: u32) {
+        out.fill(0.0);
+        if self.nanos_per_second == 0.0 || num_channels == 0 {
+            return;
+        }
+
+        let nanos_per_frame = self.nanos_per_second / sample_rate as f64;
+        let num_frames = out.len() / num_channels;
+        let buffer_end = self.position + num_frames as f64 * nanos_per_frame;
+
+        let clips = self.clips.iter().filter(|clip| {
+            let start = clip.start as f64;
+            start < buffer_end && self.position < start + clip.audio.duration_nanos() as f64
+        });
+
+        for clip in clips {
+            let audio = &clip.audio;
+
+            for (frame_index, frame) in out.chunks_exact_mut(num_channels).enumerate() {
+                let offset =
+                    self.position + frame_index as f64 * nanos_per_frame - clip.start as f64;
+                if offset < 0.0 {
+                    continue;
+                }
+                let source_frame = (offset * audio.sample_rate as f64 / 1e9) as usize;
+                if source_frame >= audio.num_frames() {
+                    break;
+                }
+
+                for (channel, sample) in frame.iter_mut().enumerate() {
+                    *sample += audio.channels[channel % audio.channels.len()][source_frame];
+                }
+            }
+        }
+
+        for sample in out.iter_mut() {
+            *sample = sample.clamp(-1.0, 1.0);
+        }
+
+        self.position = buffer_end;
+    }
+}
+
+/// Plays audio on the default output device.
+///
+/// The output stream lives on a thread of its own, since it can't be moved between threads on all
+/// platforms.
+pub struct AudioPlayer {
+    playback: Arc<Mutex<Playback>>,
+    thread: std::thread::Thread,
+}
+
+impl AudioPlayer {
+    pub fn new() -> Result<Self, PlaybackError> {
+        re_tracing::profile_function!();
+
+        let playback = Arc::new(Mutex::new(Playback::default()));
+
+        let (init_tx, init_rx) = std::sync::mpsc::sync_channel(1);
+        let thread = std::thread::Builder::new()
+            .name("audio_playback".to_owned())
+            .spawn({
+                let playback = playback.clone();
+                move || {
+                    let stream = match open_stream(playback.clone()) {
+                        Ok(stream) => stream,
+                        Err(err) => {
+                            init_tx.send(Err(err)).ok();
+                            return;
+                        }
+                    };
+                    init_tx.send(Ok(())).ok();
+
+                    while !playback.lock().stop {
+                        std::thread::park();
+                    }
+                    drop(stream);
+                }
+            })
+            .map_err(device_error)?;
+
+        init_rx.recv().map_err(device_error)??;
+
+        Ok(Self {
+            playback,
+            thread: thread.thread().clone(),
+        })
+    }
+
+    /// Updates what is played, call once per frame.
+    ///
+    /// `time` is the time cursor in nanoseconds, and `speed` the playback speed of the timeline,
+    /// or `None` while paused.
+    pub fn update(&self, clips: Vec<PlaybackClip>, time: i64, speed: Option<f32>) {
+        let mut playback = self.playback.lock();
+        playback.clips = clips;
+
+        let was_paused = playback.nanos_per_second == 0.0;
+        playback.nanos_per_second = speed.map_or(0.0, |speed| speed as f64 * 1e9);
+
+        let time = time as f64;
+        if was_paused || (playback.position - time).abs() > MAX_DRIFT_NANOS {
+            playback.position = time;
+        }
+    }
+}
+
+impl Drop for AudioPlayer {
+    fn drop(&mut self) {
+        self.playback.lock().stop = true;
+        self.thread.unpark();
+    }
+}
+
+fn open_stream(playback: Arc<Mutex<Playback>>) -> Result<cpal::Stream, PlaybackError> {
+    let device = cpal::default_host()
+        .default_output_device()
+        .ok_or(PlaybackError::NoOutputDevice)?;
+    let config = device.default_output_config().map_err(device_error)?;
+
+    let stream = match config.sample_format() {
+        cpal::SampleFormat::F32 => build_stream::<f32>(&device, &config.config(), playback),
+        cpal::SampleFormat::I16 => build_stream::<i16>(&device, &config.config(), playback),
+        cpal::SampleFormat::U16 => build_stream::<u16>(&device, &config.config(), playback),
+        sample_format => {
+            return Err(PlaybackError::UnsupportedSampleFormat(
+                sample_format.to_string(),
+            ));
+        }
+    }?;
+    stream.play().map_err(device_error)?;
+
+    Ok(stream)
+}
+
+fn build_stream<T: cpal::SizedSample + cpal::FromSample<f32>>(
+    device: &cpal::Device,
+    config: &cpal::StreamConfig,
+    playback: Arc<Mutex<Playback>>,
+) -> Result<cpal::Stream, PlaybackError> {
+    let num_channels = config.channels as usize;
+    let sample_rate = config.sample_rate.0;
+
+    let mut mixed = Vec::new();
+    device
+        .build_output_stream(
+            config,
+            move |out: &mut [T], _| {
+                mixed.resize(out.len(), 0.0);
+                playback.lock().fill(&mut mixed, num_channels, sample_rate);
+                for (out, &sample) in out.iter_mut().zip(&mixed) {
+                    *out = T::from_sample(sample);
+                }
+            },
+            |err| re_log::warn_once!("Audio playback failed: {err}"),
+            None,
+        )
+        .map_err(device_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fill() {
+        let clip = PlaybackClip {
+            start: 1_000_000,
+            audio: Arc::new(DecodedAudio {
+                sample_rate: 1000,
+                channels: vec![vec![0.1, 0.2, 0.3, 0.4]],
+            }),
+        };
+        let mut playback = Playback {
+            clips: vec![clip],
+            ..Default::default()
+        };
+
+        // Paused.
+        let mut out = [1.0; 4];
+        playback.fill(&mut out, 2, 1000);
+        assert_eq!(out, [0.0; 4]);
+
+        // Starts a frame before the clip, and plays it on both channels.
+        playback.nanos_per_second = 1e9;
+        let mut out = [1.0; 6];
+        playback.fill(&mut out, 2, 1000);
+        assert_eq!(out, [0.0, 0.0, 0.1, 0.1, 0.2, 0.2]);
+        assert_eq!(playback.position, 3e6);
+
+        // Twice as fast, past the end of the clip.
+        playback.nanos_per_second = 2e9;
+        let mut out = [1.0; 3];
+        playback.fill(&mut out, 1, 1000);
+        assert_eq!(out, [0.3, 0.0, 0.0]);
+        assert_eq!(playback.position, 9e6);
+    }
+}
@@ -0,0 +1,370 @@
+use egui::ahash::HashSet;
+use egui_plot::{Line, Plot, PlotPoint};
+
+use re_chunk_store::TimeType;
+use re_log_types::{EntityPath, TimeInt};
+use re_types::{View as _, ViewClassIdentifier, datatypes::TimeRange};
+use re_ui::{Help, MouseButtonText, UiExt as _, icons};
+use re_view::controls::MOVE_TIME_CURSOR_BUTTON;
+use re_viewer_context::{
+    Item, QueryRange, TimeControlCommand, ViewClass, ViewClassRegistryError, ViewId, ViewQuery,
+    ViewSpawnHeuristics, ViewState, ViewStateExt as _, ViewSystemExecutionError, ViewerContext,
+    auto_color_for_entity_path, suggest_view_for_each_entity,
+};
+
+use crate::{
+    audio::{DecodedAudio, min_max_envelope},
+    visualizer_system::{AudioClip, AudioSystem},
+};
+
+/// Vertical distance between the centers of two channels, which each span `[-1, 1]`.
+const CHANNEL_SPACING: f64 = 2.5;
+
+#[derive(Default)]
+pub struct AudioViewState {
+    /// Don't play this view's audio back.
+    muted: bool,
+
+    /// Only opened once time is played, so that views don't grab the output device for nothing.
+    #[cfg(all(feature = "playback", not(target_arch = "wasm32")))]
+    player: Option<crate::playback::AudioPlayer>,
+
+    /// Set once opening the output device failed, so that we don't retry every frame.
+    #[cfg(all(feature = "playback", not(target_arch = "wasm32")))]
+    player_failed: bool,
+}
+
+impl AudioViewState {
+    /// Plays the audio of the view back while time is playing.
+    #[cfg(all(feature = "playback", not(target_arch = "wasm32")))]
+    fn update_playback(&mut self, ctx: &ViewerContext<'_>, clips: &[AudioClip]) {
+        use crate::playback::{AudioPlayer, PlaybackClip};
+
+        let is_playing =
+            ctx.time_ctrl.play_state() != re_types::blueprint::components::PlayState::Paused;
+        if self.muted {
+            self.player = None;
+            return;
+        }
+        if self.player.is_none() && (!is_playing || self.player_failed) {
+            return;
+        }
+        let Some(time) = ctx.time_ctrl.time_i64() else {
+            return;
+        };
+
+        let player = match &mut self.player {
+            Some(player) => player,
+            None => match AudioPlayer::new() {
+                Ok(player) => self.player.insert(player),
+                Err(err) => {
+                    re_log::warn!("Audio playback is unavailable: {err}");
+                    self.player_failed = true;
+                    return;
+                }
+            },
+        };
+
+        let clips = clips
+            .iter()
+            .filter_map(|clip| {
+                Some(PlaybackClip {
+                    start: clip.start.as_i64(),
+                    audio: clip.audio.as_ref().ok()?.clone(),
+                })
+            })
+            .collect();
+        player.update(clips, time, is_playing.then(|| ctx.time_ctrl.speed()));
+    }
+}
+
+impl ViewState for AudioViewState {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Default)]
+pub struct AudioView;
+
+type ViewType = re_types::blueprint::views::AudioView;
+
+impl ViewClass for AudioView {
+    fn identifier() -> ViewClassIdentifier {
+        ViewType::identifier()
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Audio"
+    }
+
+    fn icon(&self) -> &'static re_ui::Icon {
+        &icons::VIEW_TIMESERIES
+    }
+
+    fn help(&self, _os: egui::os::OperatingSystem) -> Help {
+        Help::new("Audio view")
+            .docs_link("https://rerun.io/docs/reference/types/views/audio_view")
+            .markdown(
+                "Shows the waveform of each audio channel on the timeline.\n\n\
+                Supports raw PCM samples and uncompressed WAV files.\n\n\
+                While time is playing, the native viewer plays the audio back if it was built \
+                with audio playback support.",
+            )
+            .control("Pan", (icons::LEFT_MOUSE_CLICK, "+", "drag"))
+            .control("Zoom", icons::SCROLL)
+            .control("Move time cursor", MouseButtonText(MOVE_TIME_CURSOR_BUTTON))
+    }
+
+    fn on_register(
+        &self,
+        system_registry: &mut re_viewer_context::ViewSystemRegistrator<'_>,
+    ) -> Result<(), ViewClassRegistryError> {
+        system_registry.register_visualizer::<AudioSystem>()
+    }
+
+    fn new_state(&self) -> Box<dyn ViewState> {
+        Box::<AudioViewState>::default()
+    }
+
+    fn default_query_range(&self, _view_state: &dyn ViewState) -> QueryRange {
+        // Audio is logged in short chunks, so we want to see all of them, not just the latest one.
+        QueryRange::TimeRange(TimeRange::EVERYTHING)
+    }
+
+    fn supports_visible_time_range(&self) -> bool {
+        true
+    }
+
+    fn layout_priority(&self) -> re_viewer_context::ViewClassLayoutPriority {
+        re_viewer_context::ViewClassLayoutPriority::Low
+    }
+
+    fn spawn_heuristics(
+        &self,
+        ctx: &ViewerContext<'_>,
+        include_entity: &dyn Fn(&EntityPath) -> bool,
+    ) -> ViewSpawnHeuristics {
+        re_tracing::profile_function!();
+        // By default spawn a view for every audio stream.
+        suggest_view_for_each_entity::<AudioSystem>(ctx, self, include_entity)
+    }
+
+    fn selection_ui(
+        &self,
+        _ctx: &ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        state: &mut dyn ViewState,
+        _space_origin: &EntityPath,
+        _view_id: ViewId,
+    ) -> Result<(), ViewSystemExecutionError> {
+        let state = state.downcast_mut::<AudioViewState>()?;
+
+        ui.selection_grid("audio_settings_ui").show(ui, |ui| {
+            ui.grid_left_hand_label("Playback");
+            ui.re_checkbox(&mut state.muted, "Mute")
+                .on_hover_text("Don't play this view's audio back while time is playing");
+            ui.end_row();
+        });
+
+        Ok(())
+    }
+
+    fn ui(
+        &self,
+        ctx: &ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        state: &mut dyn ViewState,
+        query: &ViewQuery<'_>,
+        system_output: re_viewer_context::SystemExecutionOutput,
+    ) -> Result<(), ViewSystemExecutionError> {
+        re_tracing::profile_function!();
+
+        let state = state.downcast_mut::<AudioViewState>()?;
+        let audio = system_output.view_systems.get::<AudioSystem>()?;
+
+        let time_type = ctx.time_ctrl.time_type();
+        if time_type == TimeType::Sequence {
+            ui.error_label(
+                "Audio can only be shown on temporal timelines. \
+                Select a timeline with timestamps or durations.",
+            );
+            return Ok(());
+        }
+
+        #[cfg(all(feature = "playback", not(target_arch = "wasm32")))]
+        state.update_playback(ctx, &audio.clips);
+        #[cfg(not(all(feature = "playback", not(target_arch = "wasm32"))))]
+        _ = state;
+
+        // Decoding errors are reported once per entity rather than once per chunk.
+        let mut entities_with_errors = HashSet::default();
+        for clip in &audio.clips {
+            if let Err(err) = &clip.audio
+                && entities_with_errors.insert(clip.entity_path.clone())
+            {
+                ui.error_label(format!("{}: {err}", clip.entity_path));
+            }
+        }
+
+        let Some(min_time) = audio.clips.iter().map(|clip| clip.start).min() else {
+            // We get here if we scroll back time to before the first audio was logged.
+            ui.weak("(empty)");
+            return Ok(());
+        };
+
+        // Offset all times to avoid precision issues with large times (nanos since epoch don't fit into a f64).
+        let time_offset = min_time.as_i64();
+        let timestamp_format = ctx.app_options().timestamp_format;
+        let timeline_name = ctx.time_ctrl.timeline().name().to_string();
+
+        let num_lanes = audio
+            .clips
+            .iter()
+            .filter_map(|clip| clip.audio.as_ref().ok())
+            .map(|audio| audio.channels.len())
+            .max()
+            .unwrap_or(1);
+
+        let egui_plot::PlotResponse {
+            response,
+            transform,
+            ..
+        } = Plot::new(("audio_plot", &timeline_name))
+            .id(egui::Id::new(query.view_id))
+            .show_y(false)
+            .show_axes([true, false])
+            .allow_scroll([true, false])
+            .allow_zoom([true, false])
+            .allow_drag([true, false])
+            .default_y_bounds(
+                -(num_lanes as f64 - 0.5) * CHANNEL_SPACING,
+                0.5 * CHANNEL_SPACING,
+            )
+            .link_cursor(timeline_name.as_str(), [true, false])
+            .custom_x_axes(vec![egui_plot::AxisHints::new_x().formatter(
+                move |time, _| {
+                    re_log_types::TimeCell::new(
+                        time_type,
+                        (time.value as i64).saturating_add(time_offset),
+                    )
+                    .format_compact(timestamp_format)
+                },
+            )])
+            .label_formatter(move |_name, value| {
+                time_type.format(
+                    TimeInt::new_temporal((value.x as i64).saturating_add(time_offset)),
+                    timestamp_format,
+                )
+            })
+            .show(ui, |plot_ui| {
+                if plot_ui.response().clicked_by(MOVE_TIME_CURSOR_BUTTON)
+                    && let Some(pointer) = plot_ui.pointer_coordinate()
+                {
+                    let time = re_log_types::TimeReal::from(pointer.x as i64 + time_offset);
+                    ctx.send_time_commands([
+                        TimeControlCommand::SetTime(time),
+                        TimeControlCommand::Pause,
+                    ]);
+                }
+
+                let bounds = plot_ui.plot_bounds();
+                let visible_range = bounds.min()[0]..bounds.max()[0];
+                let num_buckets = plot_ui.response().rect.width().max(1.0) as usize;
+
+                for clip in &audio.clips {
+                    if let Ok(decoded) = &clip.audio {
+                        add_clip_to_plot(
+                            plot_ui,
+                            clip,
+                            decoded,
+                            time_offset,
+                            visible_range.clone(),
+                            num_buckets,
+                        );
+                    }
+                }
+            });
+
+        ctx.handle_select_hover_drag_interactions(&response, Item::View(query.view_id), false);
+
+        if let Some(current_time) = ctx.time_ctrl.time_i64() {
+            let x = current_time.saturating_sub(time_offset) as f64;
+            if transform.bounds().min()[0] <= x && x <= transform.bounds().max()[0] {
+                let x = transform.position_from_point(&PlotPoint::new(x, 0.0)).x;
+                ui.paint_time_cursor(ui.painter(), None, x, response.rect.y_range());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Adds the waveform of every channel of the clip, restricted to the visible time range.
+///
+/// Long stretches of audio are reduced to their min/max envelope, one bucket per pixel.
+fn add_clip_to_plot(
+    plot_ui: &mut egui_plot::PlotUi<'_>,
+    clip: &AudioClip,
+    audio: &DecodedAudio,
+    time_offset: i64,
+    visible_range: std::ops::Range<f64>,
+    num_buckets: usize,
+) {
+    let clip_start = clip.start.as_i64().saturating_sub(time_offset) as f64;
+    let nanos_per_frame = 1e9 / audio.sample_rate as f64;
+    let clip_end = clip_start + audio.duration_nanos() as f64;
+    if clip_end < visible_range.start || visible_range.end < clip_start {
+        return;
+    }
+
+    let first_frame = ((visible_range.start - clip_start) / nanos_per_frame)
+        .floor()
+        .max(0.0) as usize;
+    let end_frame = ((visible_range.end - clip_start) / nanos_per_frame).ceil() as usize + 1;
+
+    // Only the part of the visible range covered by this clip is split into buckets.
+    let covered = (clip_end.min(visible_range.end) - clip_start.max(visible_range.start))
+        / (visible_range.end - visible_range.start);
+    let num_buckets = ((num_buckets as f64 * covered).ceil() as usize).max(1);
+
+    let color = auto_color_for_entity_path(&clip.entity_path);
+    let name = clip.entity_path.to_string();
+
+    for (channel_index, samples) in audio.channels.iter().enumerate() {
+        let lane = -(channel_index as f64) * CHANNEL_SPACING;
+
+        let points: Vec<[f64; 2]> = if end_frame.saturating_sub(first_frame) <= 2 * num_buckets {
+            samples
+                .iter()
+                .enumerate()
+                .take(end_frame)
+                .skip(first_frame)
+                .map(|(frame, &sample)| {
+                    [
+                        clip_start + frame as f64 * nanos_per_frame,
+                        lane + sample as f64,
+                    ]
+                })
+                .collect()
+        } else {
+            // Zig-zag between the minimum and maximum of each bucket to fill the envelope.
+            min_max_envelope(samples, first_frame..end_frame, num_buckets)
+                .flat_map(|(frame, min, max)| {
+                    let x = clip_start + frame as f64 * nanos_per_frame;
+                    [[x, lane + min as f64], [x, lane + max as f64]]
+                })
+                .collect()
+        };
+
+        plot_ui.line(
+            Line::new(&name, points)
+                .color(egui::Color32::from(color))
+                .id(egui::Id::new((clip.row_id, channel_index))),
+        );
+    }
+}
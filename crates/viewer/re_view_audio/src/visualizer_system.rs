@@ -0,0 +1,148 @@
+use std::sync::Arc;
+
+use re_chunk_store::{RowId, TimeInt};
+use re_log_types::EntityPath;
+use re_types::{
+    archetypes::Audio,
+    components::{AudioChannelCount, AudioSampleFormat, MediaType},
+};
+use re_view::{DataResultQuery as _, RangeResultsExt as _};
+use re_viewer_context::{
+    IdentifiedViewSystem, ViewContext, ViewContextCollection, ViewQuery, ViewSystemExecutionError,
+    VisualizerQueryInfo, VisualizerSystem, typed_fallback_for,
+};
+
+use crate::{
+    audio::{AudioDecodeError, DecodedAudio},
+    audio_cache::AudioCache,
+};
+
+/// A single logged chunk of audio.
+pub struct AudioClip {
+    pub entity_path: EntityPath,
+    pub row_id: RowId,
+
+    /// The time on the current timeline at which the first sample plays.
+    pub start: TimeInt,
+
+    pub audio: Result<Arc<DecodedAudio>, AudioDecodeError>,
+}
+
+/// All the audio clips of the view, with everything needed to render them.
+#[derive(Default)]
+pub struct AudioSystem {
+    pub clips: Vec<AudioClip>,
+}
+
+impl IdentifiedViewSystem for AudioSystem {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "Audio".into()
+    }
+}
+
+impl VisualizerSystem for AudioSystem {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<Audio>()
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        view_query: &ViewQuery<'_>,
+        _context_systems: &ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
+        re_tracing::profile_function!();
+
+        let timeline = view_query.timeline;
+        let latest_at_query = view_query.latest_at_query();
+
+        for data_result in view_query.iter_visible_data_results(Self::identifier()) {
+            let results = data_result.query_archetype_with_history::<Audio>(ctx, view_query);
+
+            let Some(all_blob_chunks) =
+                results.get_required_chunks(Audio::descriptor_blob().component)
+            else {
+                continue;
+            };
+
+            let query_context = ctx.query_context(data_result, &latest_at_query);
+            let fallback_sample_format: AudioSampleFormat =
+                typed_fallback_for(&query_context, Audio::descriptor_sample_format().component);
+            let fallback_channel_count: AudioChannelCount =
+                typed_fallback_for(&query_context, Audio::descriptor_channel_count().component);
+
+            let all_blobs_indexed = all_blob_chunks.iter().flat_map(|chunk| {
+                itertools::izip!(
+                    chunk.iter_component_indices(timeline),
+                    chunk.iter_slices::<&[u8]>()
+                )
+            });
+            let all_sample_rates =
+                results.iter_as(timeline, Audio::descriptor_sample_rate().component);
+            let all_media_types =
+                results.iter_as(timeline, Audio::descriptor_media_type().component);
+            let all_sample_formats =
+                results.iter_as(timeline, Audio::descriptor_sample_format().component);
+            let all_channel_counts =
+                results.iter_as(timeline, Audio::descriptor_channel_count().component);
+
+            for (
+                (start, row_id),
+                blobs,
+                sample_rates,
+                media_types,
+                sample_formats,
+                channel_counts,
+            ) in re_query::range_zip_1x4(
+                all_blobs_indexed,
+                all_sample_rates.slice::<u32>(),
+                all_media_types.slice::<String>(),
+                // Legit call to `component_slow`, there is only a single sample format per clip.
+                all_sample_formats.component_slow::<AudioSampleFormat>(),
+                all_channel_counts.slice::<u32>(),
+            ) {
+                let Some(blob) = blobs.first() else {
+                    continue;
+                };
+                let media_type = media_types
+                    .and_then(|media_types| media_types.first().cloned())
+                    .map(|media_type| MediaType(media_type.into()));
+                let sample_rate =
+                    sample_rates.and_then(|sample_rates| sample_rates.first().copied());
+                let sample_format = sample_formats
+                    .and_then(|sample_formats| sample_formats.first().copied())
+                    .unwrap_or(fallback_sample_format);
+                let channel_count = channel_counts
+                    .and_then(|channel_counts| channel_counts.first().copied())
+                    .unwrap_or(fallback_channel_count.0.0);
+
+                let audio = query_context
+                    .store_ctx()
+                    .caches
+                    .entry(|c: &mut AudioCache| {
+                        c.entry(
+                            row_id,
+                            blob,
+                            media_type.as_ref(),
+                            sample_rate,
+                            sample_format,
+                            channel_count,
+                        )
+                    });
+
+                self.clips.push(AudioClip {
+                    entity_path: data_result.entity_path.clone(),
+                    row_id,
+                    start,
+                    audio,
+                });
+            }
+        }
+
+        Ok(Vec::new())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
@@ -1,6 +1,6 @@
 //! Rerun bar chart View.
 //!
-//! A View that shows a single bar chart.
+//! A View that shows bar charts, overlapping, grouped or stacked across entities.
 
 mod view_class;
 mod visualizer_system;
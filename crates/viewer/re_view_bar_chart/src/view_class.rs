@@ -4,8 +4,8 @@ use re_log_types::EntityPath;
 use re_types::{
     View as _, ViewClassIdentifier,
     blueprint::{
-        archetypes::{PlotBackground, PlotLegend},
-        components::{Corner2D, Enabled},
+        archetypes::{BarChartLayout, PlotBackground, PlotLegend},
+        components::{BarChartMode, Corner2D, Enabled},
     },
     components::{Color, Visible},
     datatypes::TensorBuffer,
@@ -149,6 +149,7 @@ impl ViewClass for BarChartView {
     ) -> Result<(), ViewSystemExecutionError> {
         list_item::list_item_scope(ui, "bar_char_selection_ui", |ui| {
             let ctx = self.view_context(ctx, view_id, state);
+            view_property_ui::<BarChartLayout>(&ctx, ui);
             view_property_ui::<PlotBackground>(&ctx, ui);
             view_property_ui::<PlotLegend>(&ctx, ui);
         });
@@ -200,7 +201,16 @@ impl ViewClass for BarChartView {
         let legend_corner: Corner2D =
             plot_legend.component_or_fallback(&ctx, PlotLegend::descriptor_corner().component)?;
 
+        let layout = ViewProperty::from_archetype::<BarChartLayout>(
+            blueprint_db,
+            ctx.blueprint_query(),
+            view_id,
+        );
+        let mode: BarChartMode =
+            layout.component_or_fallback(&ctx, BarChartLayout::descriptor_mode().component)?;
+
         ui.scope(|ui| {
+            let egui_ctx = ui.ctx().clone();
             let background_color = background_color.into();
             ui.style_mut().visuals.extreme_bg_color = background_color;
             let mut plot = Plot::new("bar_chart_plot")
@@ -222,13 +232,21 @@ impl ViewClass for BarChartView {
                 hovered_plot_item,
                 ..
             } = plot.show(ui, |plot_ui| {
-                fn create_bar_chart<N: Into<f64>>(
-                    ent_path: &EntityPath,
-                    indexes: impl Iterator<Item = f64>,
-                    values: impl Iterator<Item = N>,
-                    color: &re_types::components::Color,
-                    background_color: egui::Color32,
-                ) -> BarChart {
+                // Running totals of the stacked bars at each position, separately for positive and negative values.
+                let mut stack_tops: HashMap<u64, (f64, f64)> = HashMap::default();
+
+                for (
+                    chart_index,
+                    (
+                        ent_path,
+                        BarChartData {
+                            abscissa,
+                            values,
+                            color,
+                        },
+                    ),
+                ) in charts.iter().enumerate()
+                {
                     let color: egui::Color32 = color.0.into();
                     let fill = if background_color.intensity() < 0.5 {
                         color.gamma_multiply(0.75).additive() // make sure overlapping bars are obvious for darker background colors.
@@ -236,132 +254,57 @@ impl ViewClass for BarChartView {
                         color.gamma_multiply(0.75)
                     };
                     let stroke_color = fill.linear_multiply(0.5);
-                    BarChart::new(
-                        "bar_chart",
-                        values
-                            .zip(indexes)
-                            .enumerate()
-                            .map(|(i, (value, index))| {
-                                Bar::new(index + 0.5, value.into())
-                                    .width(1.0) // No gaps
-                                    .name(format!("{ent_path} #{i}"))
-                                    .fill(fill)
-                                    .stroke((1.0, stroke_color))
-                            })
-                            .collect(),
-                    )
-                    .name(ent_path.to_string())
-                    .color(color)
-                }
 
-                for (
-                    ent_path,
-                    BarChartData {
-                        abscissa,
-                        values: tensor,
-                        color,
-                    },
-                ) in charts
-                {
-                    let arg: ::arrow::buffer::ScalarBuffer<f64> = match &abscissa.buffer {
-                        TensorBuffer::U8(data) => data.iter().map(|v| *v as f64).collect(),
-                        TensorBuffer::U16(data) => data.iter().map(|v| *v as f64).collect(),
-                        TensorBuffer::U32(data) => data.iter().map(|v| *v as f64).collect(),
-                        TensorBuffer::U64(data) => data.iter().map(|v| *v as f64).collect(),
-                        TensorBuffer::I8(data) => data.iter().map(|v| *v as f64).collect(),
-                        TensorBuffer::I16(data) => data.iter().map(|v| *v as f64).collect(),
-                        TensorBuffer::I32(data) => data.iter().map(|v| *v as f64).collect(),
-                        TensorBuffer::I64(data) => data.iter().map(|v| *v as f64).collect(),
-                        TensorBuffer::F16(data) => data.iter().map(|v| f64::from(*v)).collect(),
-                        TensorBuffer::F32(data) => data.iter().map(|v| *v as f64).collect(),
-                        TensorBuffer::F64(data) => data.iter().copied().collect(),
+                    // Bars span a unit interval starting at their abscissa, split between all entities when grouped.
+                    let (width, offset) = match mode {
+                        BarChartMode::Overlapping | BarChartMode::Stacked => (1.0, 0.5),
+                        BarChartMode::Grouped => {
+                            let width = 1.0 / charts.len() as f64;
+                            (width, (chart_index as f64 + 0.5) * width)
+                        }
                     };
 
-                    let chart = match &tensor.buffer {
-                        TensorBuffer::U8(data) => create_bar_chart(
-                            ent_path,
-                            arg.iter().copied(),
-                            data.iter().copied(),
-                            color,
-                            background_color,
-                        ),
-                        TensorBuffer::U16(data) => create_bar_chart(
-                            ent_path,
-                            arg.iter().copied(),
-                            data.iter().copied(),
-                            color,
-                            background_color,
-                        ),
-                        TensorBuffer::U32(data) => create_bar_chart(
-                            ent_path,
-                            arg.iter().copied(),
-                            data.iter().copied(),
-                            color,
-                            background_color,
-                        ),
-                        TensorBuffer::U64(data) => create_bar_chart(
-                            ent_path,
-                            arg.iter().copied(),
-                            data.iter().copied().map(|v| v as f64),
-                            color,
-                            background_color,
-                        ),
-                        TensorBuffer::I8(data) => create_bar_chart(
-                            ent_path,
-                            arg.iter().copied(),
-                            data.iter().copied(),
-                            color,
-                            background_color,
-                        ),
-                        TensorBuffer::I16(data) => create_bar_chart(
-                            ent_path,
-                            arg.iter().copied(),
-                            data.iter().copied(),
-                            color,
-                            background_color,
-                        ),
-                        TensorBuffer::I32(data) => create_bar_chart(
-                            ent_path,
-                            arg.iter().copied(),
-                            data.iter().copied(),
-                            color,
-                            background_color,
-                        ),
-                        TensorBuffer::I64(data) => create_bar_chart(
-                            ent_path,
-                            arg.iter().copied(),
-                            data.iter().copied().map(|v| v as f64),
-                            color,
-                            background_color,
-                        ),
-                        TensorBuffer::F16(data) => create_bar_chart(
-                            ent_path,
-                            arg.iter().copied(),
-                            data.iter().map(|f| f.to_f32()),
-                            color,
-                            background_color,
-                        ),
-                        TensorBuffer::F32(data) => create_bar_chart(
-                            ent_path,
-                            arg.iter().copied(),
-                            data.iter().copied(),
-                            color,
-                            background_color,
-                        ),
-                        TensorBuffer::F64(data) => create_bar_chart(
-                            ent_path,
-                            arg.iter().copied(),
-                            data.iter().copied(),
-                            color,
-                            background_color,
-                        ),
-                    };
+                    let bars = tensor_buffer_to_f64(&values.buffer)
+                        .iter()
+                        .zip(tensor_buffer_to_f64(&abscissa.buffer).iter())
+                        .enumerate()
+                        .map(|(i, (&value, &index))| {
+                            let value = animated_value(
+                                &egui_ctx,
+                                egui::Id::new((view_id, ent_path, i)),
+                                value,
+                            );
+                            let mut bar = Bar::new(index + offset, value)
+                                .width(width)
+                                .name(format!("{ent_path} #{i}"))
+                                .fill(fill)
+                                .stroke((1.0, stroke_color));
+
+                            if mode == BarChartMode::Stacked {
+                                let (positive_top, negative_top) =
+                                    stack_tops.entry(index.to_bits()).or_default();
+                                let top = if value < 0.0 {
+                                    negative_top
+                                } else {
+                                    positive_top
+                                };
+                                bar = bar.base_offset(*top);
+                                *top += value;
+                            }
+
+                            bar
+                        })
+                        .collect();
 
                     let id = egui::Id::new(ent_path.hash());
                     plot_item_id_to_entity_path.insert(id, ent_path.clone());
-                    let chart = chart.id(id);
 
-                    plot_ui.bar_chart(chart);
+                    plot_ui.bar_chart(
+                        BarChart::new("bar_chart", bars)
+                            .name(ent_path.to_string())
+                            .color(color)
+                            .id(id),
+                    );
                 }
             });
 
@@ -388,6 +331,36 @@ impl ViewClass for BarChartView {
     }
 }
 
+/// Converts the elements of a tensor buffer to `f64`.
+fn tensor_buffer_to_f64(buffer: &TensorBuffer) -> ::arrow::buffer::ScalarBuffer<f64> {
+    match buffer {
+        TensorBuffer::U8(data) => data.iter().map(|v| *v as f64).collect(),
+        TensorBuffer::U16(data) => data.iter().map(|v| *v as f64).collect(),
+        TensorBuffer::U32(data) => data.iter().map(|v| *v as f64).collect(),
+        TensorBuffer::U64(data) => data.iter().map(|v| *v as f64).collect(),
+        TensorBuffer::I8(data) => data.iter().map(|v| *v as f64).collect(),
+        TensorBuffer::I16(data) => data.iter().map(|v| *v as f64).collect(),
+        TensorBuffer::I32(data) => data.iter().map(|v| *v as f64).collect(),
+        TensorBuffer::I64(data) => data.iter().map(|v| *v as f64).collect(),
+        TensorBuffer::F16(data) => data.iter().map(|v| f64::from(*v)).collect(),
+        TensorBuffer::F32(data) => data.iter().map(|v| *v as f64).collect(),
+        TensorBuffer::F64(data) => data.iter().copied().collect(),
+    }
+}
+
+/// Eases a bar towards its latest value, so that changes are easy to follow while the time cursor moves.
+fn animated_value(egui_ctx: &egui::Context, id: egui::Id, value: f64) -> f64 {
+    let animation_time = egui_ctx.style().animation_time;
+    let animated = egui_ctx.animate_value_with_time(id, value as f32, animation_time);
+
+    // Don't lose precision once the animation is done.
+    if animated == value as f32 {
+        value
+    } else {
+        animated as f64
+    }
+}
+
 #[test]
 fn test_help_view() {
     re_test_context::TestContext::test_help_view(|ctx| BarChartView.help(ctx));
@@ -0,0 +1,173 @@
+use std::borrow::Cow;
+
+use arrow::array::{Array as _, ArrayRef, AsArray as _};
+use arrow::error::ArrowError;
+use arrow::util::display::{ArrayFormatter, FormatOptions};
+
+use re_chunk_store::ColumnDescriptor;
+use re_dataframe_ui::default_display_name_for_column;
+use re_types_core::{Loggable as _, RowId};
+
+/// Formats the content of a single cell as text.
+///
+/// Component values are lists of instances: single instances are formatted on their own, and
+/// multiple instances as a bracketed list.
+pub(crate) fn cell_text(
+    column: &ColumnDescriptor,
+    data: &ArrayRef,
+    row: usize,
+) -> Result<String, ArrowError> {
+    if data.is_null(row) {
+        return Ok(String::new());
+    }
+
+    if matches!(column, ColumnDescriptor::RowId(_)) {
+        let row_ids =
+            RowId::from_arrow(data).map_err(|err| ArrowError::CastError(err.to_string()))?;
+        return Ok(row_ids
+            .get(row)
+            .map(ToString::to_string)
+            .unwrap_or_default());
+    }
+
+    let options = FormatOptions::default();
+    if let Some(list) = data.as_list_opt::<i32>() {
+        let instances = list.value(row);
+        let formatter = ArrayFormatter::try_new(instances.as_ref(), &options)?;
+        if instances.len() == 1 {
+            Ok(formatter.value(0).try_to_string()?)
+        } else {
+            let instances = (0..instances.len())
+                .map(|instance| formatter.value(instance).try_to_string())
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(format!("[{}]", instances.join(", ")))
+        }
+    } else {
+        ArrayFormatter::try_new(data.as_ref(), &options)?
+            .value(row)
+            .try_to_string()
+    }
+}
+
+/// Writes the rows of a table as CSV, with a header line containing the column names.
+///
+/// Each row contains one array per column, as returned by the query.
+pub fn rows_to_csv<'a>(
+    columns: &[ColumnDescriptor],
+    rows: impl IntoIterator<Item = &'a Vec<ArrayRef>>,
+) -> Result<String, ArrowError> {
+    re_tracing::profile_function!();
+
+    let mut csv = String::new();
+
+    let header = columns
+        .iter()
+        .map(|column| default_display_name_for_column(&column.into()))
+        .collect::<Vec<_>>();
+    write_csv_line(&mut csv, header.iter().map(String::as_str));
+
+    for row in rows {
+        for row_index in 0..row.first().map_or(0, |data| data.len()) {
+            let fields = columns
+                .iter()
+                .zip(row)
+                .map(|(column, data)| cell_text(column, data, row_index))
+                .collect::<Result<Vec<_>, _>>()?;
+            write_csv_line(&mut csv, fields.iter().map(String::as_str));
+        }
+    }
+
+    Ok(csv)
+}
+
+fn write_csv_line<'a>(csv: &mut String, fields: impl Iterator<Item = &'a str>) {
+    for (index, field) in fields.enumerate() {
+        if index > 0 {
+            csv.push(',');
+        }
+        csv.push_str(&escape_csv_field(field));
+    }
+    csv.push('\n');
+}
+
+/// Quotes fields containing separators, quotes or line breaks, as per RFC 4180.
+fn escape_csv_field(field: &str) -> Cow<'_, str> {
+    if field.contains([',', '"', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::array::{Float64Array, ListArray};
+    use arrow::buffer::OffsetBuffer;
+    use arrow::datatypes::{DataType, Field};
+
+    use re_log_types::EntityPath;
+    use re_sorbet::ComponentColumnDescriptor;
+
+    use super::*;
+
+    fn component_column(component: &str) -> ColumnDescriptor {
+        ColumnDescriptor::Component(ComponentColumnDescriptor {
+            store_datatype: DataType::Float64,
+            component_type: None,
+            entity_path: EntityPath::from("points"),
+            archetype: None,
+            component: component.into(),
+            is_static: false,
+            is_tombstone: false,
+            is_semantically_empty: false,
+        })
+    }
+
+    /// A list array with a single row containing the given instances, or null if `None`.
+    fn list(instances: Option<&[f64]>) -> ArrayRef {
+        let field = Arc::new(Field::new("item", DataType::Float64, true));
+        let values = instances.unwrap_or_default();
+        Arc::new(ListArray::new(
+            field,
+            OffsetBuffer::from_lengths([values.len()]),
+            Arc::new(Float64Array::from(values.to_vec())),
+            instances.is_none().then(|| vec![false].into()),
+        ))
+    }
+
+    #[test]
+    fn escaping() {
+        assert_eq!(escape_csv_field("plain"), "plain");
+        assert_eq!(escape_csv_field("a,b"), "\"a,b\"");
+        assert_eq!(escape_csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(escape_csv_field("two\nlines"), "\"two\nlines\"");
+    }
+
+    #[test]
+    fn cells() {
+        let column = component_column("value");
+        assert_eq!(cell_text(&column, &list(Some(&[1.5])), 0).unwrap(), "1.5");
+        assert_eq!(
+            cell_text(&column, &list(Some(&[1.0, 2.5])), 0).unwrap(),
+            "[1.0, 2.5]"
+        );
+        assert_eq!(cell_text(&column, &list(Some(&[])), 0).unwrap(), "[]");
+        assert_eq!(cell_text(&column, &list(None), 0).unwrap(), "");
+    }
+
+    #[test]
+    fn csv() {
+        let columns = vec![component_column("x"), component_column("y")];
+        let rows = vec![
+            vec![list(Some(&[1.0])), list(Some(&[1.0, 2.0]))],
+            vec![list(None), list(Some(&[3.0]))],
+        ];
+
+        assert_eq!(
+            rows_to_csv(&columns, &rows).unwrap(),
+            "/points:x,/points:y\n1.0,\"[1.0, 2.0]\"\n,3.0\n"
+        );
+    }
+}
@@ -2,23 +2,30 @@ use std::collections::BTreeMap;
 use std::ops::Range;
 
 use anyhow::Context as _;
-use arrow::array::ArrayRef;
+use arrow::array::{Array as _, ArrayRef};
 use egui::{NumExt as _, RichText};
 use itertools::Itertools as _;
 
-use re_chunk_store::{ColumnDescriptor, LatestAtQuery};
+use re_chunk_store::{ColumnDescriptor, ComponentColumnDescriptor, LatestAtQuery};
 use re_dataframe::QueryHandle;
 use re_dataframe::external::re_query::StorageEngineArcReadGuard;
 use re_dataframe_ui::table_utils::{apply_table_style_fixes, cell_ui, header_ui};
-use re_dataframe_ui::{ColumnBlueprint, DisplayRecordBatch, DisplayRecordBatchError};
+use re_dataframe_ui::{
+    ColumnBlueprint, DisplayRecordBatch, DisplayRecordBatchError, SortDirection,
+};
 use re_log_types::{EntityPath, TimeInt, TimelineName};
 use re_types::ComponentDescriptor;
+use re_types::archetypes::Scalars;
+use re_types::components::Unit;
 use re_types::reflection::ComponentDescriptorExt as _;
 use re_ui::UiExt as _;
 use re_viewer_context::TimeControlCommand;
 use re_viewer_context::{ViewId, ViewerContext};
 
+use crate::csv_export::{cell_text, rows_to_csv};
 use crate::expanded_rows::{ExpandedRows, ExpandedRowsCache};
+use crate::sorted_rows::fetch_rows;
+use crate::table_settings::TableSettings;
 
 /// Ui actions triggered by the dataframe UI to be handled by the calling code.
 pub(crate) enum HideColumnAction {
@@ -34,25 +41,45 @@ pub(crate) enum HideColumnAction {
     },
 }
 
+/// Output of [`dataframe_ui`], to be handled by the calling code.
+#[derive(Default)]
+pub(crate) struct DataframeUiOutput {
+    pub hide_column_actions: Vec<HideColumnAction>,
+
+    /// The user asked to export the entire table as CSV.
+    pub export_csv: bool,
+}
+
 /// Display a dataframe table for the provided query.
+///
+/// The rows are displayed in the order of `sorted_rows` if provided, and the columns are
+/// reordered according to the pinned columns of `table_settings`.
 pub(crate) fn dataframe_ui(
     ctx: &ViewerContext<'_>,
     ui: &mut egui::Ui,
     query_handle: &re_dataframe::QueryHandle<StorageEngineArcReadGuard>,
     expanded_rows_cache: &mut ExpandedRowsCache,
+    table_settings: &mut TableSettings,
+    sorted_rows: Option<&[u64]>,
     view_id: &ViewId,
-) -> Vec<HideColumnAction> {
+) -> DataframeUiOutput {
     re_tracing::profile_function!();
 
     let tokens = ui.tokens();
     let table_style = re_ui::TableStyle::Dense;
 
-    let selected_columns = query_handle
+    let query_columns = query_handle
         .selected_contents()
         .iter()
         .map(|(_, desc)| desc.clone())
         .collect::<Vec<_>>();
 
+    let (column_order, num_sticky_cols) = table_settings.display_order(&query_columns);
+    let selected_columns = column_order
+        .iter()
+        .map(|&index| query_columns[index].clone())
+        .collect::<Vec<_>>();
+
     // The table id mainly drives column widths, along with the id of each column. Empirically, the
     // user experience is better if we have stable column width even when the query changes (which
     // can, in turn, change the column's content).
@@ -64,7 +91,8 @@ pub(crate) fn dataframe_ui(
     let row_expansion_id_salt = egui::Id::new("__dataframe_row_exp__")
         .with(view_id)
         .with(&selected_columns)
-        .with(query_handle.query());
+        .with(query_handle.query())
+        .with(sorted_rows.is_some().then_some(&table_settings.sort_by));
 
     let (header_groups, header_entity_paths) = column_groups_for_entity(&selected_columns);
 
@@ -75,6 +103,9 @@ pub(crate) fn dataframe_ui(
         table_style,
         query_handle,
         selected_columns: &selected_columns,
+        column_order: &column_order,
+        sorted_rows,
+        table_settings,
         header_entity_paths,
         num_rows,
         display_data: Err(anyhow::anyhow!(
@@ -86,14 +117,10 @@ pub(crate) fn dataframe_ui(
             expanded_rows_cache,
             tokens.table_row_height(table_style),
         ),
-        hide_column_actions: vec![],
+        output: DataframeUiOutput::default(),
+        copy_visible_rows: false,
     };
 
-    let num_sticky_cols = selected_columns
-        .iter()
-        .take_while(|cd| matches!(cd, ColumnDescriptor::RowId(_) | ColumnDescriptor::Time(_)))
-        .count();
-
     ui.scope(|ui| {
         apply_table_style_fixes(ui.style_mut());
         egui_table::Table::new()
@@ -121,7 +148,11 @@ pub(crate) fn dataframe_ui(
             .show(ui, &mut table_delegate);
     });
 
-    table_delegate.hide_column_actions
+    if table_delegate.copy_visible_rows {
+        table_delegate.copy_visible_rows_as_csv(ui);
+    }
+
+    table_delegate.output
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -140,6 +171,9 @@ struct BatchRef {
 /// display record batch and the index inside it.
 #[derive(Debug)]
 struct RowsDisplayData {
+    /// The raw data of the rows, as returned by the query but with the columns in display order.
+    row_data: Vec<Vec<ArrayRef>>,
+
     /// The [`DisplayRecordBatch`]s to display.
     display_record_batches: Vec<DisplayRecordBatch>,
 
@@ -158,13 +192,13 @@ impl RowsDisplayData {
         query_timeline: &TimelineName,
     ) -> Result<Self, DisplayRecordBatchError> {
         let display_record_batches = row_data
-            .into_iter()
+            .iter()
             .map(|data| {
                 DisplayRecordBatch::try_new(
                     selected_columns
                         .iter()
                         .map(|desc| desc.into())
-                        .zip(data)
+                        .zip(data.iter().cloned())
                         .map(|(desc, data)| (desc, ColumnBlueprint::default_ref(), data)),
                 )
             })
@@ -193,6 +227,7 @@ impl RowsDisplayData {
             .map(|(pos, _)| pos);
 
         Ok(Self {
+            row_data,
             display_record_batches,
             batch_ref_from_row,
             query_time_column_index,
@@ -205,18 +240,113 @@ struct DataframeTableDelegate<'a> {
     ctx: &'a ViewerContext<'a>,
     table_style: re_ui::TableStyle,
     query_handle: &'a QueryHandle<StorageEngineArcReadGuard>,
+
+    /// The selected columns, in display order.
     selected_columns: &'a [ColumnDescriptor],
+
+    /// For each displayed column, its index in the query result.
+    column_order: &'a [usize],
+
+    /// For each displayed row, its index in the query result, if the table is sorted.
+    sorted_rows: Option<&'a [u64]>,
+
+    table_settings: &'a mut TableSettings,
     header_entity_paths: Vec<Option<EntityPath>>,
     display_data: anyhow::Result<RowsDisplayData>,
 
     expanded_rows: ExpandedRows<'a>,
 
     num_rows: u64,
-    hide_column_actions: Vec<HideColumnAction>,
+    output: DataframeUiOutput,
+    copy_visible_rows: bool,
 }
 
 impl DataframeTableDelegate<'_> {
     const LEFT_RIGHT_MARGIN: i8 = 4;
+
+    fn copy_visible_rows_as_csv(&self, ui: &egui::Ui) {
+        let Ok(display_data) = &self.display_data else {
+            return;
+        };
+
+        match rows_to_csv(self.selected_columns, &display_data.row_data) {
+            Ok(csv) => ui.ctx().copy_text(csv),
+            Err(err) => re_log::error!("Failed to copy rows as CSV: {err}"),
+        }
+    }
+
+    /// Sorting, pinning and export options of a column.
+    fn column_context_menu_ui(&mut self, ui: &mut egui::Ui, column: &ColumnDescriptor) {
+        let current_sort = self.table_settings.sort_of(column);
+        let is_only_sort = current_sort.is_some() && self.table_settings.sort_by.len() == 1;
+
+        for direction in SortDirection::iter() {
+            let already_sorted =
+                is_only_sort && current_sort.map(|(_, dir)| dir) == Some(direction);
+            if ui
+                .add_enabled_ui(!already_sorted, |ui| direction.menu_item_ui(ui))
+                .inner
+                .clicked()
+            {
+                self.table_settings.sort_by(column, direction);
+                ui.close();
+            }
+        }
+
+        if !self.table_settings.sort_by.is_empty() && !is_only_sort {
+            for direction in SortDirection::iter() {
+                let label = if direction.is_ascending() {
+                    "Then ascending"
+                } else {
+                    "Then descending"
+                };
+                if ui
+                    .icon_and_text_menu_item(direction.icon(), label)
+                    .clicked()
+                {
+                    self.table_settings.then_sort_by(column, direction);
+                    ui.close();
+                }
+            }
+        }
+
+        if current_sort.is_some() && ui.button("Remove from sort").clicked() {
+            self.table_settings.remove_sort(column);
+            ui.close();
+        }
+
+        if matches!(column, ColumnDescriptor::Component(_)) {
+            ui.separator();
+
+            let label = if self.table_settings.is_pinned(column) {
+                "Unpin column"
+            } else {
+                "Pin column"
+            };
+            if ui.button(label).clicked() {
+                self.table_settings.toggle_pinned(column);
+                ui.close();
+            }
+        }
+
+        ui.separator();
+
+        if ui
+            .icon_and_text_menu_item(&re_ui::icons::COPY, "Copy visible rows as CSV")
+            .clicked()
+        {
+            self.copy_visible_rows = true;
+            ui.close();
+        }
+
+        if ui
+            .icon_and_text_menu_item(&re_ui::icons::DOWNLOAD, "Export as CSV…")
+            .clicked()
+        {
+            self.output.export_csv = true;
+            ui.close();
+        }
+    }
 }
 
 impl egui_table::TableDelegate for DataframeTableDelegate<'_> {
@@ -230,11 +360,19 @@ impl egui_table::TableDelegate for DataframeTableDelegate<'_> {
             .filtered_index
             .unwrap_or_else(|| TimelineName::new(""));
 
-        self.query_handle
-            .seek_to_row(info.visible_rows.start as usize);
-        let data = std::iter::from_fn(|| self.query_handle.next_row())
-            .take((info.visible_rows.end - info.visible_rows.start) as usize)
-            .collect();
+        let data = fetch_rows(
+            self.query_handle,
+            self.sorted_rows,
+            info.visible_rows.clone(),
+        )
+        .into_iter()
+        .map(|row| {
+            self.column_order
+                .iter()
+                .map(|&index| row[index].clone())
+                .collect()
+        })
+        .collect();
 
         let data = RowsDisplayData::try_new(
             &info.visible_rows,
@@ -290,9 +428,10 @@ impl egui_table::TableDelegate for DataframeTableDelegate<'_> {
                 }
             });
         } else if cell.row_nr == 1 {
-            let column = &self.selected_columns[cell.col_range.start];
+            let selected_columns = self.selected_columns;
+            let column = &selected_columns[cell.col_range.start];
 
-            let next_column = self.selected_columns.get(cell.col_range.end);
+            let next_column = selected_columns.get(cell.col_range.end);
             let connected_to_next_cell = next_column.is_some_and(|next_column| {
                 next_column.entity_path() == column.entity_path()
                     && next_column.archetype_name() == column.archetype_name()
@@ -322,14 +461,21 @@ impl egui_table::TableDelegate for DataframeTableDelegate<'_> {
                 }),
             };
 
+            let sort = self.table_settings.sort_of(column);
+            let is_multi_sort = self.table_settings.sort_by.len() > 1;
+
             header_ui(ui, table_style, connected_to_next_cell, |ui| {
-                let header_content = |ui: &mut egui::Ui| {
+                let mut header_content = |ui: &mut egui::Ui| {
                     let mut text = egui::RichText::new(
                         if let ColumnDescriptor::Component(component) = column {
-                            component
+                            let field_name = component
                                 .component_descriptor()
                                 .archetype_field_name()
-                                .to_owned()
+                                .to_owned();
+                            match scalars_unit(self.ctx, component) {
+                                Some(unit) => format!("{field_name} [{unit}]"),
+                                None => field_name,
+                            }
                         } else {
                             column.display_name()
                         },
@@ -365,7 +511,21 @@ impl egui_table::TableDelegate for DataframeTableDelegate<'_> {
                                     .size(10.0)
                                     .color(ui.tokens().text_subdued),
                             );
-                            ui.selectable_label(is_selected, text)
+                            if let Some((priority, direction)) = sort {
+                                ui.horizontal(|ui| {
+                                    let response = ui.selectable_label(is_selected, text);
+                                    let tint = ui.tokens().table_sort_icon_color;
+                                    ui.add_space(-5.0);
+                                    ui.small_icon(direction.icon(), Some(tint));
+                                    if is_multi_sort {
+                                        ui.weak((priority + 1).to_string());
+                                    }
+                                    response
+                                })
+                                .inner
+                            } else {
+                                ui.selectable_label(is_selected, text)
+                            }
                         })
                         .inner;
 
@@ -388,6 +548,8 @@ impl egui_table::TableDelegate for DataframeTableDelegate<'_> {
                             );
                         }
                     }
+
+                    response.context_menu(|ui| self.column_context_menu_ui(ui, column));
                 };
 
                 if let Some(hide_action) = hide_action {
@@ -399,7 +561,7 @@ impl egui_table::TableDelegate for DataframeTableDelegate<'_> {
                     );
 
                     if hide_clicked {
-                        self.hide_column_actions.push(hide_action);
+                        self.output.hide_column_actions.push(hide_action);
                     }
                 } else {
                     header_content(ui);
@@ -464,6 +626,13 @@ impl egui_table::TableDelegate for DataframeTableDelegate<'_> {
             .unwrap_or_else(|| TimelineName::new(""));
         let latest_at_query = LatestAtQuery::new(filtered_index, timestamp);
 
+        // Sensed before drawing the cell content, so that the widgets within the cell keep their
+        // own interactions.
+        let cell_id = ui
+            .id()
+            .with(("cell_context_menu", cell.row_nr, cell.col_nr));
+        let cell_response = ui.interact(ui.max_rect(), cell_id, egui::Sense::click());
+
         ui.set_truncate_style();
 
         let instance_count = column.instance_count(batch_row_idx);
@@ -525,6 +694,37 @@ impl egui_table::TableDelegate for DataframeTableDelegate<'_> {
                 row_content,
             );
         }
+
+        cell_response.context_menu(|ui| {
+            let row_data = &display_data.row_data[batch_idx];
+
+            if ui
+                .icon_and_text_menu_item(&re_ui::icons::COPY, "Copy value")
+                .clicked()
+            {
+                let column = &self.selected_columns[cell.col_nr];
+                match cell_text(column, &row_data[cell.col_nr], batch_row_idx) {
+                    Ok(text) => ui.ctx().copy_text(text),
+                    Err(err) => re_log::error!("Failed to copy value: {err}"),
+                }
+                ui.close();
+            }
+
+            if ui
+                .icon_and_text_menu_item(&re_ui::icons::COPY, "Copy row as CSV")
+                .clicked()
+            {
+                let row = row_data
+                    .iter()
+                    .map(|data| data.slice(batch_row_idx, 1))
+                    .collect::<Vec<_>>();
+                match rows_to_csv(self.selected_columns, [&row]) {
+                    Ok(csv) => ui.ctx().copy_text(csv),
+                    Err(err) => re_log::error!("Failed to copy row as CSV: {err}"),
+                }
+                ui.close();
+            }
+        });
     }
 
     fn row_top_offset(&self, _ctx: &egui::Context, _table_id: egui::Id, row_nr: u64) -> f32 {
@@ -830,3 +1030,19 @@ fn split_ui_vertically<Item, Ctx>(
         });
     }
 }
+
+/// The unit of the scalars in this column, if it's a column of [`Scalars`] with a unit logged.
+fn scalars_unit(ctx: &ViewerContext<'_>, column: &ComponentColumnDescriptor) -> Option<Unit> {
+    if column.component != Scalars::descriptor_scalars().component {
+        return None;
+    }
+
+    ctx.recording()
+        .latest_at_component::<Unit>(
+            &column.entity_path,
+            &ctx.current_query(),
+            Scalars::descriptor_unit().component,
+        )
+        .map(|(_, unit)| unit)
+        .filter(|unit| !unit.as_str().is_empty())
+}
@@ -2,12 +2,17 @@
 //!
 //! A View that shows the data contained in entities in a table.
 
+mod csv_export;
 mod dataframe_ui;
 
 mod expanded_rows;
+mod sorted_rows;
+mod sql_ui;
+mod table_settings;
 mod view_class;
 mod view_query;
 mod visualizer_system;
 
+pub use csv_export::rows_to_csv;
 pub use view_class::DataframeView;
 pub use view_query::Query;
@@ -0,0 +1,140 @@
+use std::ops::Range;
+
+use arrow::array::ArrayRef;
+use arrow::compute::{SortColumn, SortOptions};
+
+use re_chunk_store::ChunkStoreGeneration;
+use re_dataframe::QueryHandle;
+use re_dataframe::external::re_query::StorageEngineArcReadGuard;
+use re_log_types::hash::Hash64;
+
+use crate::table_settings::ColumnSort;
+
+/// Caches the order of the rows of a sorted table.
+///
+/// Sorting requires reading the entire query result, so this is only recomputed when the query,
+/// the sort order or the content of the store changes.
+#[derive(Default)]
+pub(crate) struct SortedRowsCache {
+    key: Option<Hash64>,
+
+    /// For each displayed row, the index of the row in the query result.
+    ///
+    /// `None` if the table isn't sorted.
+    row_indices: Option<Vec<u64>>,
+}
+
+impl SortedRowsCache {
+    /// The query result row indices in sorted order, or `None` if the rows aren't sorted.
+    pub fn row_indices(
+        &mut self,
+        query_handle: &QueryHandle<StorageEngineArcReadGuard>,
+        sort_by: &[ColumnSort],
+        generation: &ChunkStoreGeneration,
+    ) -> Option<&[u64]> {
+        let key = Hash64::hash((query_handle.query(), sort_by, generation));
+        if self.key != Some(key) {
+            self.key = Some(key);
+            self.row_indices = sort_rows(query_handle, sort_by);
+        }
+
+        self.row_indices.as_deref()
+    }
+}
+
+fn sort_rows(
+    query_handle: &QueryHandle<StorageEngineArcReadGuard>,
+    sort_by: &[ColumnSort],
+) -> Option<Vec<u64>> {
+    re_tracing::profile_function!();
+
+    // Columns which have been hidden since are ignored.
+    let sort_columns = sort_by
+        .iter()
+        .filter_map(|sort| {
+            let index = query_handle
+                .selected_contents()
+                .iter()
+                .position(|(_, column)| column == &sort.column)?;
+            Some((index, sort.direction))
+        })
+        .collect::<Vec<_>>();
+    if sort_columns.is_empty() {
+        return None;
+    }
+
+    let mut column_data: Vec<Vec<ArrayRef>> = vec![Vec::new(); sort_columns.len()];
+    query_handle.seek_to_row(0);
+    while let Some(row) = query_handle.next_row() {
+        for (data, (index, _)) in column_data.iter_mut().zip(&sort_columns) {
+            data.push(row[*index].clone());
+        }
+    }
+
+    let sort_columns = column_data
+        .iter()
+        .zip(&sort_columns)
+        .map(|(data, (_, direction))| {
+            let data = data.iter().map(|array| array.as_ref()).collect::<Vec<_>>();
+            Ok(SortColumn {
+                values: arrow::compute::concat(&data)?,
+                options: Some(SortOptions {
+                    descending: !direction.is_ascending(),
+                    nulls_first: false,
+                }),
+            })
+        })
+        .collect::<Result<Vec<_>, arrow::error::ArrowError>>();
+
+    match sort_columns.and_then(|columns| arrow::compute::lexsort_to_indices(&columns, None)) {
+        Ok(indices) => Some(indices.values().iter().map(|&index| index as u64).collect()),
+        Err(err) => {
+            re_log::warn_once!("Failed to sort the dataframe: {err}");
+            None
+        }
+    }
+}
+
+/// Fetches the rows displayed in the given range, in sorted order if `sorted_rows` is provided.
+pub(crate) fn fetch_rows(
+    query_handle: &QueryHandle<StorageEngineArcReadGuard>,
+    sorted_rows: Option<&[u64]>,
+    rows: Range<u64>,
+) -> Vec<Vec<ArrayRef>> {
+    if let Some(sorted_rows) = sorted_rows {
+        let rows = rows.start.min(sorted_rows.len() as u64) as usize
+            ..rows.end.min(sorted_rows.len() as u64) as usize;
+        sorted_rows[rows]
+            .iter()
+            .filter_map(|&row| {
+                query_handle.seek_to_row(row as usize);
+                query_handle.next_row()
+            })
+            .collect()
+    } else {
+        query_handle.seek_to_row(rows.start as usize);
+        std::iter::from_fn(|| query_handle.next_row())
+            .take((rows.end - rows.start) as usize)
+            .collect()
+    }
+}
+
+/// Fetches all rows, in sorted order if `sorted_rows` is provided.
+pub(crate) fn fetch_all_rows(
+    query_handle: &QueryHandle<StorageEngineArcReadGuard>,
+    sorted_rows: Option<&[u64]>,
+) -> Vec<Vec<ArrayRef>> {
+    query_handle.seek_to_row(0);
+    let rows = std::iter::from_fn(|| query_handle.next_row()).collect::<Vec<_>>();
+
+    if let Some(sorted_rows) = sorted_rows {
+        // Seeking to each row is much slower than reading everything in order.
+        let mut rows = rows.into_iter().map(Some).collect::<Vec<_>>();
+        sorted_rows
+            .iter()
+            .filter_map(|&row| rows.get_mut(row as usize).and_then(Option::take))
+            .collect()
+    } else {
+        rows
+    }
+}
@@ -0,0 +1,362 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use datafusion::error::DataFusionError;
+use datafusion::prelude::SessionContext;
+
+use re_dataframe::QueryHandle;
+use re_dataframe::external::re_query::StorageEngineArcReadGuard;
+use re_dataframe_ui::{DataFusionTableWidget, RequestedObject};
+use re_ui::UiExt as _;
+use re_viewer_context::{AsyncRuntimeHandle, ViewId, ViewerContext};
+
+/// The table containing the result of the view's query.
+const RECORDING_TABLE: &str = "recording";
+
+/// The table containing the result of the SQL query.
+const RESULT_TABLE: &str = "result";
+
+const MAX_SUGGESTIONS: usize = 8;
+
+const KEYWORDS: &[&str] = &[
+    "SELECT",
+    "FROM",
+    "WHERE",
+    "GROUP BY",
+    "ORDER BY",
+    "HAVING",
+    "LIMIT",
+    "OFFSET",
+    "DISTINCT",
+    "AS",
+    "AND",
+    "OR",
+    "NOT",
+    "IN",
+    "IS NULL",
+    "IS NOT NULL",
+    "LIKE",
+    "BETWEEN",
+    "CASE",
+    "WHEN",
+    "THEN",
+    "ELSE",
+    "END",
+    "ASC",
+    "DESC",
+    "COUNT",
+    "SUM",
+    "AVG",
+    "MIN",
+    "MAX",
+    "UNNEST",
+];
+
+/// The SQL editor of a dataframe view, and the last query run from it.
+pub(crate) struct SqlState {
+    sql: String,
+
+    /// Byte offset of the cursor in the editor, kept while the editor isn't focused.
+    cursor: Option<usize>,
+
+    run: Option<SqlRun>,
+}
+
+impl Default for SqlState {
+    fn default() -> Self {
+        Self {
+            sql: format!("SELECT * FROM {RECORDING_TABLE} LIMIT 100"),
+            cursor: None,
+            run: None,
+        }
+    }
+}
+
+struct SqlRun {
+    /// Contains the snapshot of the view's query and, once planned, the result of the SQL query.
+    session_ctx: Arc<SessionContext>,
+
+    /// Completes once the SQL query is planned and its result registered.
+    registration: RequestedObject<Result<(), DataFusionError>>,
+}
+
+impl SqlState {
+    /// Runs the SQL query against a snapshot of the current result of the view's query.
+    fn run(
+        &mut self,
+        ctx: &ViewerContext<'_>,
+        runtime: &AsyncRuntimeHandle,
+        query_handle: &QueryHandle<StorageEngineArcReadGuard>,
+    ) {
+        re_tracing::profile_function!();
+
+        if let Some(previous_run) = self.run.take() {
+            DataFusionTableWidget::refresh(ctx.egui_ctx(), &previous_run.session_ctx, RESULT_TABLE);
+        }
+
+        let session_ctx = Arc::new(SessionContext::new());
+        let recording_table = re_datafusion::local_query_table(query_handle).and_then(|table| {
+            session_ctx.register_table(RECORDING_TABLE, Arc::new(table))?;
+            Ok(())
+        });
+
+        let registration = match recording_table {
+            Ok(()) => {
+                let session_ctx = session_ctx.clone();
+                let sql = self.sql.clone();
+                RequestedObject::new_with_repaint(runtime, ctx.egui_ctx().clone(), async move {
+                    let result = session_ctx.sql(&sql).await?.into_view();
+                    session_ctx.register_table(RESULT_TABLE, result)?;
+                    Ok(())
+                })
+            }
+            Err(err) => RequestedObject::Completed(Err(err)),
+        };
+
+        self.run = Some(SqlRun {
+            session_ctx,
+            registration,
+        });
+    }
+}
+
+/// Shows the SQL editor, and the result of the last query below it.
+pub(crate) fn sql_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    state: &mut SqlState,
+    query_handle: &QueryHandle<StorageEngineArcReadGuard>,
+    view_id: ViewId,
+) {
+    re_tracing::profile_function!();
+
+    let runtime = match AsyncRuntimeHandle::from_current_tokio_runtime_or_wasmbindgen() {
+        Ok(runtime) => runtime,
+        Err(err) => {
+            ui.error_label(format!("SQL queries are not available: {err}"));
+            return;
+        }
+    };
+
+    let columns = query_handle
+        .schema()
+        .fields()
+        .iter()
+        .map(|field| field.name().clone())
+        .collect::<Vec<_>>();
+
+    let editor_id = egui::Id::new("dataframe_sql_editor").with(view_id);
+    let (token_start, suggestions) = state.cursor.map_or((0, Vec::new()), |cursor| {
+        let (token_start, token) = token_before_cursor(&state.sql, cursor);
+        (token_start, suggestions(token, &columns))
+    });
+
+    // These must be consumed before the editor uses them to insert text.
+    let mut accepted = None;
+    let mut run = false;
+    if ui.memory(|mem| mem.has_focus(editor_id)) {
+        ui.input_mut(|input| {
+            run = input.consume_key(egui::Modifiers::COMMAND, egui::Key::Enter);
+            if !suggestions.is_empty() && input.consume_key(egui::Modifiers::NONE, egui::Key::Tab) {
+                accepted = suggestions.first().cloned();
+            }
+        });
+    }
+
+    let tokens = ui.tokens();
+    egui::Frame::new()
+        .inner_margin(tokens.view_padding())
+        .show(ui, |ui| {
+            ui.horizontal(|ui| {
+                run |= ui
+                    .button("Run")
+                    .on_hover_text(format!(
+                        "Run the query ({})",
+                        ui.ctx().format_shortcut(&egui::KeyboardShortcut::new(
+                            egui::Modifiers::COMMAND,
+                            egui::Key::Enter
+                        ))
+                    ))
+                    .clicked();
+                ui.weak(format!(
+                    "The rows of this view are available as the `{RECORDING_TABLE}` table."
+                ));
+            });
+
+            let output = egui::TextEdit::multiline(&mut state.sql)
+                .id(editor_id)
+                .code_editor()
+                .desired_rows(4)
+                .desired_width(f32::INFINITY)
+                .show(ui);
+            if let Some(cursor_range) = output.cursor_range {
+                state.cursor = Some(byte_offset(&state.sql, cursor_range.primary.index));
+            }
+
+            if !suggestions.is_empty() {
+                ui.horizontal_wrapped(|ui| {
+                    for suggestion in &suggestions {
+                        if ui.small_button(suggestion).clicked() {
+                            accepted = Some(suggestion.clone());
+                        }
+                    }
+                    ui.weak("Tab to complete");
+                });
+            }
+        });
+
+    if let (Some(suggestion), Some(cursor)) = (accepted, state.cursor) {
+        state.sql.replace_range(token_start..cursor, &suggestion);
+        let cursor = token_start + suggestion.len();
+        state.cursor = Some(cursor);
+
+        let mut editor_state = egui::TextEdit::load_state(ui.ctx(), editor_id).unwrap_or_default();
+        let char_cursor = egui::text::CCursor::new(state.sql[..cursor].chars().count());
+        editor_state
+            .cursor
+            .set_char_range(Some(egui::text::CCursorRange::one(char_cursor)));
+        editor_state.store(ui.ctx(), editor_id);
+        ui.memory_mut(|mem| mem.request_focus(editor_id));
+    }
+
+    if run {
+        state.run(ctx, &runtime, query_handle);
+    }
+
+    let Some(sql_run) = &mut state.run else {
+        return;
+    };
+
+    sql_run.registration.on_frame_start();
+    if let Some(Err(err)) = sql_run.registration.try_as_ref() {
+        ui.error_label(err.to_string());
+    } else {
+        DataFusionTableWidget::new(sql_run.session_ctx.clone(), RESULT_TABLE)
+            .show(ctx, &runtime, ui);
+    }
+}
+
+fn byte_offset(text: &str, char_index: usize) -> usize {
+    text.char_indices()
+        .nth(char_index)
+        .map_or(text.len(), |(offset, _)| offset)
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '/' | ':' | '.' | '"')
+}
+
+/// The partially typed identifier or keyword right before the cursor, and its byte offset.
+fn token_before_cursor(sql: &str, cursor: usize) -> (usize, &str) {
+    let Some(before_cursor) = sql.get(..cursor) else {
+        return (cursor, "");
+    };
+
+    let start = before_cursor
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| is_identifier_char(*c))
+        .last()
+        .map_or(cursor, |(start, _)| start);
+
+    (start, &before_cursor[start..])
+}
+
+/// Completions for a partially typed token: the tables and columns containing it, and the
+/// keywords starting with it.
+fn suggestions(token: &str, columns: &[String]) -> Vec<String> {
+    let needle = token.trim_start_matches('"').to_lowercase();
+    if needle.is_empty() {
+        return Vec::new();
+    }
+
+    let identifiers = std::iter::once(RECORDING_TABLE)
+        .chain(columns.iter().map(String::as_str))
+        .filter(|name| name.to_lowercase().contains(&needle))
+        .map(|name| quote_identifier(name).into_owned());
+
+    let keywords = KEYWORDS
+        .iter()
+        .filter(|keyword| keyword.to_lowercase().starts_with(&needle))
+        .map(|keyword| (*keyword).to_owned());
+
+    let mut suggestions = Vec::new();
+    for suggestion in identifiers.chain(keywords) {
+        if !suggestion.eq_ignore_ascii_case(token) && !suggestions.contains(&suggestion) {
+            suggestions.push(suggestion);
+        }
+        if suggestions.len() == MAX_SUGGESTIONS {
+            break;
+        }
+    }
+    suggestions
+}
+
+/// Quotes identifiers which SQL would otherwise lowercase or fail to parse.
+fn quote_identifier(name: &str) -> Cow<'_, str> {
+    let is_plain = name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_lowercase() || c == '_')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+
+    if is_plain {
+        Cow::Borrowed(name)
+    } else {
+        Cow::Owned(format!("\"{}\"", name.replace('"', "\"\"")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token() {
+        let sql = "SELECT \"/points:Po FROM";
+        assert_eq!(token_before_cursor(sql, 18), (7, "\"/points:Po"));
+        assert_eq!(token_before_cursor(sql, 7), (7, ""));
+        assert_eq!(token_before_cursor(sql, 3), (0, "SEL"));
+        assert_eq!(token_before_cursor(sql, 100), (100, ""));
+    }
+
+    #[test]
+    fn quoting() {
+        assert_eq!(quote_identifier("recording"), "recording");
+        assert_eq!(quote_identifier("log_time"), "log_time");
+        assert_eq!(quote_identifier("Points"), "\"Points\"");
+        assert_eq!(quote_identifier("/points:x"), "\"/points:x\"");
+        assert_eq!(quote_identifier("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(quote_identifier("1st"), "\"1st\"");
+    }
+
+    #[test]
+    fn completions() {
+        let columns = vec![
+            "log_time".to_owned(),
+            "/points:Points3D:positions".to_owned(),
+            "/points:Points3D:colors".to_owned(),
+        ];
+
+        assert_eq!(
+            suggestions("point", &columns),
+            vec![
+                "\"/points:Points3D:positions\"",
+                "\"/points:Points3D:colors\""
+            ]
+        );
+        assert_eq!(
+            suggestions("\"/points:Points3D:col", &columns),
+            vec!["\"/points:Points3D:colors\""]
+        );
+        assert_eq!(suggestions("sel", &columns), vec!["SELECT"]);
+        assert_eq!(suggestions("rec", &columns), vec!["recording"]);
+        assert_eq!(suggestions("is", &columns), vec!["IS NULL", "IS NOT NULL"]);
+
+        // Tokens which are already complete have nothing to suggest.
+        assert!(suggestions("SELECT", &columns).is_empty());
+        assert!(suggestions("", &columns).is_empty());
+    }
+}
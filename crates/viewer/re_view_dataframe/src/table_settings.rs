@@ -0,0 +1,170 @@
+use re_chunk_store::ColumnDescriptor;
+use re_dataframe_ui::SortDirection;
+
+/// Sort order for a single column.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct ColumnSort {
+    pub column: ColumnDescriptor,
+    pub direction: SortDirection,
+}
+
+/// How the table is presented, on top of what the query returns.
+///
+/// Unlike the query, these settings are not stored in the blueprint.
+#[derive(Debug, Default)]
+pub(crate) struct TableSettings {
+    /// Component columns shown right after the index columns, which stay in view when scrolling
+    /// horizontally.
+    pub pinned_columns: Vec<ColumnDescriptor>,
+
+    /// The columns to sort the rows by, the first one having the highest priority.
+    pub sort_by: Vec<ColumnSort>,
+}
+
+impl TableSettings {
+    pub fn is_pinned(&self, column: &ColumnDescriptor) -> bool {
+        self.pinned_columns.contains(column)
+    }
+
+    pub fn toggle_pinned(&mut self, column: &ColumnDescriptor) {
+        if self.is_pinned(column) {
+            self.pinned_columns.retain(|pinned| pinned != column);
+        } else {
+            self.pinned_columns.push(column.clone());
+        }
+    }
+
+    /// The priority and direction of the sort on this column, if any.
+    pub fn sort_of(&self, column: &ColumnDescriptor) -> Option<(usize, SortDirection)> {
+        self.sort_by
+            .iter()
+            .position(|sort| &sort.column == column)
+            .map(|priority| (priority, self.sort_by[priority].direction))
+    }
+
+    /// Sort by this column only.
+    pub fn sort_by(&mut self, column: &ColumnDescriptor, direction: SortDirection) {
+        self.sort_by = vec![ColumnSort {
+            column: column.clone(),
+            direction,
+        }];
+    }
+
+    /// Sort by this column after all the columns already sorted by.
+    ///
+    /// If the column is already sorted by, only its direction changes.
+    pub fn then_sort_by(&mut self, column: &ColumnDescriptor, direction: SortDirection) {
+        if let Some(sort) = self.sort_by.iter_mut().find(|sort| &sort.column == column) {
+            sort.direction = direction;
+        } else {
+            self.sort_by.push(ColumnSort {
+                column: column.clone(),
+                direction,
+            });
+        }
+    }
+
+    pub fn remove_sort(&mut self, column: &ColumnDescriptor) {
+        self.sort_by.retain(|sort| &sort.column != column);
+    }
+
+    /// The order in which the selected columns are displayed, and how many of them are sticky.
+    ///
+    /// The index columns come first, followed by the pinned columns in the order they were pinned,
+    /// and then the remaining columns. The leading index columns and the pinned columns are sticky.
+    pub fn display_order(&self, columns: &[ColumnDescriptor]) -> (Vec<usize>, usize) {
+        let num_index_columns = columns
+            .iter()
+            .take_while(|cd| matches!(cd, ColumnDescriptor::RowId(_) | ColumnDescriptor::Time(_)))
+            .count();
+
+        let pinned = self
+            .pinned_columns
+            .iter()
+            .filter_map(|pinned| {
+                columns
+                    .iter()
+                    .skip(num_index_columns)
+                    .position(|column| column == pinned)
+                    .map(|index| index + num_index_columns)
+            })
+            .collect::<Vec<_>>();
+
+        let order = (0..num_index_columns)
+            .chain(pinned.iter().copied())
+            .chain((num_index_columns..columns.len()).filter(|index| !pinned.contains(index)))
+            .collect();
+
+        (order, num_index_columns + pinned.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use re_log_types::{EntityPath, Timeline};
+    use re_sorbet::{ComponentColumnDescriptor, IndexColumnDescriptor};
+
+    use super::*;
+
+    fn time_column() -> ColumnDescriptor {
+        ColumnDescriptor::Time(IndexColumnDescriptor::from(Timeline::new_sequence("frame")))
+    }
+
+    fn component_column(entity_path: &str) -> ColumnDescriptor {
+        ColumnDescriptor::Component(ComponentColumnDescriptor {
+            store_datatype: arrow::datatypes::DataType::Float64,
+            component_type: None,
+            entity_path: EntityPath::from(entity_path),
+            archetype: None,
+            component: "value".into(),
+            is_static: false,
+            is_tombstone: false,
+            is_semantically_empty: false,
+        })
+    }
+
+    #[test]
+    fn pinned_columns_follow_index_columns() {
+        let columns = vec![
+            time_column(),
+            component_column("a"),
+            component_column("b"),
+            component_column("c"),
+        ];
+
+        let mut settings = TableSettings::default();
+        assert_eq!(settings.display_order(&columns), (vec![0, 1, 2, 3], 1));
+
+        settings.toggle_pinned(&columns[3]);
+        settings.toggle_pinned(&columns[2]);
+        assert_eq!(settings.display_order(&columns), (vec![0, 3, 2, 1], 3));
+
+        settings.toggle_pinned(&columns[3]);
+        assert_eq!(settings.display_order(&columns), (vec![0, 2, 1, 3], 2));
+
+        // Pinned columns which are not selected are ignored.
+        settings.toggle_pinned(&component_column("hidden"));
+        assert_eq!(settings.display_order(&columns), (vec![0, 2, 1, 3], 2));
+    }
+
+    #[test]
+    fn sort_priority() {
+        let (a, b) = (component_column("a"), component_column("b"));
+
+        let mut settings = TableSettings::default();
+        settings.sort_by(&a, SortDirection::Ascending);
+        settings.then_sort_by(&b, SortDirection::Descending);
+        assert_eq!(settings.sort_of(&a), Some((0, SortDirection::Ascending)));
+        assert_eq!(settings.sort_of(&b), Some((1, SortDirection::Descending)));
+
+        settings.then_sort_by(&a, SortDirection::Descending);
+        assert_eq!(settings.sort_of(&a), Some((0, SortDirection::Descending)));
+
+        settings.remove_sort(&a);
+        assert_eq!(settings.sort_of(&a), None);
+        assert_eq!(settings.sort_of(&b), Some((0, SortDirection::Descending)));
+
+        settings.sort_by(&a, SortDirection::Ascending);
+        assert_eq!(settings.sort_of(&b), None);
+    }
+}
@@ -1,7 +1,8 @@
 use std::any::Any;
 
 use re_chunk_store::{ColumnDescriptor, SparseFillStrategy};
-use re_dataframe::QueryEngine;
+use re_dataframe::external::re_query::StorageEngineArcReadGuard;
+use re_dataframe::{QueryEngine, QueryHandle};
 use re_log_types::EntityPath;
 use re_types_core::ViewClassIdentifier;
 use re_ui::{Help, UiExt as _};
@@ -12,7 +13,13 @@ use re_viewer_context::{
 };
 
 use crate::{
-    dataframe_ui::dataframe_ui, expanded_rows::ExpandedRowsCache, view_query,
+    csv_export::rows_to_csv,
+    dataframe_ui::dataframe_ui,
+    expanded_rows::ExpandedRowsCache,
+    sorted_rows::{SortedRowsCache, fetch_all_rows},
+    sql_ui::{SqlState, sql_ui},
+    table_settings::TableSettings,
+    view_query,
     visualizer_system::EmptySystem,
 };
 
@@ -23,6 +30,20 @@ struct DataframeViewState {
 
     /// List of view columns for the current query, cached here for the column visibility UI.
     view_columns: Option<Vec<ColumnDescriptor>>,
+
+    /// Pinned columns and sort order.
+    table_settings: TableSettings,
+
+    /// Cache for the order of the rows when sorting.
+    sorted_rows_cache: SortedRowsCache,
+
+    /// Set from the selection panel, handled the next time the table is shown.
+    pending_csv_export: bool,
+
+    /// Whether the SQL editor is shown instead of the table.
+    show_sql: bool,
+
+    sql_state: SqlState,
 }
 
 impl ViewState for DataframeViewState {
@@ -102,7 +123,34 @@ Configure in the selection panel:
     ) -> Result<(), ViewSystemExecutionError> {
         let state = state.downcast_mut::<DataframeViewState>()?;
         let view_query = view_query::Query::from_blueprint(ctx, view_id);
-        view_query.selection_panel_ui(ctx, ui, view_id, state.view_columns.as_deref())
+        view_query.selection_panel_ui(ctx, ui, view_id, state.view_columns.as_deref())?;
+
+        if state.view_columns.is_some() {
+            ui.separator();
+            table_settings_ui(ui, state);
+        }
+
+        Ok(())
+    }
+
+    fn extra_title_bar_ui(
+        &self,
+        _ctx: &ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        state: &mut dyn ViewState,
+        _space_origin: &EntityPath,
+        _view_id: ViewId,
+    ) -> Result<(), ViewSystemExecutionError> {
+        let state = state.downcast_mut::<DataframeViewState>()?;
+        if ui
+            .selectable_label(state.show_sql, "SQL")
+            .on_hover_text("Query the content of this view with SQL")
+            .clicked()
+        {
+            state.show_sql = !state.show_sql;
+        }
+
+        Ok(())
     }
 
     fn ui(
@@ -136,7 +184,12 @@ Configure in the selection panel:
             .collect();
 
         let sparse_fill_strategy = if view_query.latest_at_enabled()? {
-            SparseFillStrategy::LatestAtGlobal
+            match view_query.latest_at_tolerance()? {
+                Some(tolerance) => SparseFillStrategy::LatestAtGlobalWithin {
+                    tolerance: u64::try_from(tolerance.as_i64()).unwrap_or_default(),
+                },
+                None => SparseFillStrategy::LatestAtGlobal,
+            }
         } else {
             SparseFillStrategy::None
         };
@@ -165,21 +218,142 @@ Configure in the selection panel:
 
         let query_handle = query_engine.query(dataframe_query);
 
-        let hide_column_actions = dataframe_ui(
+        if state.show_sql {
+            sql_ui(ctx, ui, &mut state.sql_state, &query_handle, query.view_id);
+            state.view_columns = Some(view_columns);
+            return Ok(());
+        }
+
+        let DataframeViewState {
+            expanded_rows_cache,
+            table_settings,
+            sorted_rows_cache,
+            pending_csv_export,
+            ..
+        } = state;
+
+        let sorted_rows = sorted_rows_cache.row_indices(
+            &query_handle,
+            &table_settings.sort_by,
+            &ctx.recording().generation(),
+        );
+
+        let output = dataframe_ui(
             ctx,
             ui,
             &query_handle,
-            &mut state.expanded_rows_cache,
+            expanded_rows_cache,
+            table_settings,
+            sorted_rows,
             &query.view_id,
         );
 
-        view_query.handle_hide_column_actions(ctx, &view_columns, hide_column_actions)?;
+        if output.export_csv || std::mem::take(pending_csv_export) {
+            export_csv(ctx, &query_handle, table_settings, sorted_rows);
+        }
+
+        view_query.handle_hide_column_actions(ctx, &view_columns, output.hide_column_actions)?;
 
         state.view_columns = Some(view_columns);
         Ok(())
     }
 }
 
+/// Lists the sort order and pinned columns of the table, and offers to export it.
+fn table_settings_ui(ui: &mut egui::Ui, state: &mut DataframeViewState) {
+    let settings = &mut state.table_settings;
+
+    if settings.sort_by.is_empty() {
+        ui.label("Sort: none");
+    } else {
+        ui.label("Sort by:");
+        let mut removed_sort = None;
+        for sort in &settings.sort_by {
+            ui.horizontal(|ui| {
+                ui.small_icon(sort.direction.icon(), None);
+                ui.label(sort.column.display_name());
+                if ui
+                    .small_icon_button(&re_ui::icons::CLOSE_SMALL, "Remove from sort")
+                    .clicked()
+                {
+                    removed_sort = Some(sort.column.clone());
+                }
+            });
+        }
+        if let Some(column) = removed_sort {
+            settings.remove_sort(&column);
+        }
+    }
+
+    if !settings.pinned_columns.is_empty() {
+        ui.label("Pinned columns:");
+        let mut unpinned = None;
+        for column in &settings.pinned_columns {
+            ui.horizontal(|ui| {
+                ui.label(column.display_name());
+                if ui
+                    .small_icon_button(&re_ui::icons::CLOSE_SMALL, "Unpin")
+                    .clicked()
+                {
+                    unpinned = Some(column.clone());
+                }
+            });
+        }
+        if let Some(column) = unpinned {
+            settings.toggle_pinned(&column);
+        }
+    }
+
+    if ui
+        .button("Export as CSV…")
+        .on_hover_text("Save all rows of the table, in the displayed order")
+        .clicked()
+    {
+        state.pending_csv_export = true;
+    }
+}
+
+/// Asks the user where to save the entire table as CSV, with the columns and rows as displayed.
+fn export_csv(
+    ctx: &ViewerContext<'_>,
+    query_handle: &QueryHandle<StorageEngineArcReadGuard>,
+    table_settings: &TableSettings,
+    sorted_rows: Option<&[u64]>,
+) {
+    re_tracing::profile_function!();
+
+    let query_columns = query_handle
+        .selected_contents()
+        .iter()
+        .map(|(_, desc)| desc.clone())
+        .collect::<Vec<_>>();
+    let (column_order, _) = table_settings.display_order(&query_columns);
+    let columns = column_order
+        .iter()
+        .map(|&index| query_columns[index].clone())
+        .collect::<Vec<_>>();
+
+    let rows = fetch_all_rows(query_handle, sorted_rows)
+        .into_iter()
+        .map(|row| {
+            column_order
+                .iter()
+                .map(|&index| row[index].clone())
+                .collect()
+        })
+        .collect::<Vec<_>>();
+
+    match rows_to_csv(&columns, &rows) {
+        Ok(csv) => ctx.command_sender().save_file_dialog(
+            re_capabilities::MainThreadToken::i_promise_i_am_on_the_main_thread(),
+            "dataframe.csv",
+            "Export as CSV".to_owned(),
+            csv.into_bytes(),
+        ),
+        Err(err) => re_log::error!("Failed to export the table as CSV: {err}"),
+    }
+}
+
 fn timeline_not_found_ui(ctx: &ViewerContext<'_>, ui: &mut egui::Ui, view_id: ViewId) {
     let full_view_rect = ui.available_rect_before_wrap();
     let tokens = ui.tokens();
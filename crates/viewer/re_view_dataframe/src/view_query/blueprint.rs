@@ -1,7 +1,7 @@
 use std::collections::HashSet;
 
 use re_chunk_store::ColumnDescriptor;
-use re_log_types::{AbsoluteTimeRange, Timeline, TimelineName};
+use re_log_types::{AbsoluteTimeRange, TimeInt, Timeline, TimelineName};
 use re_sorbet::{ColumnSelector, ComponentColumnSelector};
 use re_types::blueprint::archetypes::DataframeQuery;
 use re_types::blueprint::{components, datatypes};
@@ -137,6 +137,29 @@ impl Query {
         );
     }
 
+    /// Get the maximum age of the values used to fill empty cells, if any.
+    pub fn latest_at_tolerance(&self) -> Result<Option<TimeInt>, ViewSystemExecutionError> {
+        Ok(self
+            .query_property
+            .component_or_empty::<components::TimeInt>(
+                DataframeQuery::descriptor_latest_at_tolerance().component,
+            )?
+            .map(|tolerance| TimeInt::from(tolerance.0)))
+    }
+
+    pub fn save_latest_at_tolerance(&self, ctx: &ViewerContext<'_>, tolerance: Option<TimeInt>) {
+        if let Some(tolerance) = tolerance {
+            self.query_property.save_blueprint_component(
+                ctx,
+                &DataframeQuery::descriptor_latest_at_tolerance(),
+                &components::TimeInt(tolerance.into()),
+            );
+        } else {
+            self.query_property
+                .clear_blueprint_component(ctx, DataframeQuery::descriptor_latest_at_tolerance());
+        }
+    }
+
     pub fn save_selected_columns(
         &self,
         ctx: &ViewerContext<'_>,
@@ -301,6 +324,7 @@ impl Query {
 #[cfg(test)]
 mod test {
     use super::Query;
+    use re_log_types::TimeInt;
     use re_test_context::TestContext;
     use re_viewer_context::ViewId;
 
@@ -325,4 +349,29 @@ mod test {
             assert!(query.latest_at_enabled().unwrap());
         });
     }
+
+    #[test]
+    fn test_latest_at_tolerance() {
+        let test_context = TestContext::new();
+
+        let view_id = ViewId::random();
+
+        test_context.run_in_egui_central_panel(|ctx, _| {
+            let query = Query::from_blueprint(ctx, view_id);
+            assert_eq!(query.latest_at_tolerance().unwrap(), None);
+            query.save_latest_at_tolerance(ctx, Some(TimeInt::new_temporal(42)));
+        });
+
+        egui::__run_test_ctx(|egui_ctx| {
+            test_context.handle_system_commands(egui_ctx);
+        });
+
+        test_context.run_in_egui_central_panel(|ctx, _| {
+            let query = Query::from_blueprint(ctx, view_id);
+            assert_eq!(
+                query.latest_at_tolerance().unwrap(),
+                Some(TimeInt::new_temporal(42))
+            );
+        });
+    }
 }
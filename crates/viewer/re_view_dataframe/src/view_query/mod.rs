@@ -49,7 +49,7 @@ impl Query {
         ui.separator();
         self.column_visibility_ui(ctx, ui, timeline.as_ref(), view_columns)?;
         ui.separator();
-        self.latest_at_ui(ctx, ui)?;
+        self.latest_at_ui(ctx, ui, timeline.as_ref())?;
 
         Ok(())
     }
@@ -505,6 +505,7 @@ impl Query {
         &self,
         ctx: &ViewerContext<'_>,
         ui: &mut egui::Ui,
+        timeline: Option<&Timeline>,
     ) -> Result<(), ViewSystemExecutionError> {
         ui.label("Empty cells:");
 
@@ -521,6 +522,59 @@ impl Query {
             self.save_latest_at_enabled(ctx, latest_at);
         }
 
+        if latest_at && let Some(timeline) = timeline {
+            self.latest_at_tolerance_ui(ctx, ui, timeline)?;
+        }
+
+        Ok(())
+    }
+
+    fn latest_at_tolerance_ui(
+        &self,
+        ctx: &ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        timeline: &Timeline,
+    ) -> Result<(), ViewSystemExecutionError> {
+        let tolerance = self.latest_at_tolerance()?;
+        let mut limited = tolerance.is_some();
+        let mut tolerance = tolerance.unwrap_or(TimeInt::ZERO);
+
+        ui.horizontal(|ui| {
+            let mut changed = ui
+                .re_checkbox(&mut limited, "Only with values at most")
+                .on_hover_text(
+                    "Leave cells empty rather than filling them with values logged more than this \
+                    long before their row.\n\nTogether with filtering rows by a column, this \
+                    aligns a fast sensor with a slower one without carrying stale values over.",
+                )
+                .changed();
+
+            if limited {
+                let time_drag_value =
+                    if let Some(times) = ctx.recording().time_histogram(timeline.name()) {
+                        TimeDragValue::from_time_histogram(times)
+                    } else {
+                        TimeDragValue::from_time_range(0..=0)
+                    };
+
+                changed |= time_drag_value
+                    .drag_value_ui(
+                        ui,
+                        timeline.typ(),
+                        &mut tolerance,
+                        false,
+                        Some(TimeInt::ZERO),
+                        ctx.app_options().timestamp_format,
+                    )
+                    .changed();
+                ui.label("old");
+            }
+
+            if changed {
+                self.save_latest_at_tolerance(ctx, limited.then_some(tolerance));
+            }
+        });
+
         Ok(())
     }
 }
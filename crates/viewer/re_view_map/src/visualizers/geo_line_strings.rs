@@ -143,6 +143,7 @@ impl GeoLineStringsVisualizer {
         lines.radius_boost_in_ui_points_for_outlines(
             re_view::SIZE_BOOST_IN_POINTS_FOR_LINE_OUTLINES,
         );
+        lines.radius_boost_in_ui_points_for_picking(re_view::SIZE_BOOST_IN_POINTS_FOR_LINE_PICKING);
 
         for (entity_path, batch) in &self.batches {
             let outline = highlight.entity_outline_mask(entity_path.hash());
@@ -14,7 +14,10 @@ use re_types::{
 };
 use re_viewer_context::{Cache, CacheMemoryReport, CacheMemoryReportItem};
 
-use crate::mesh_loader::{LoadedMesh, NativeAsset3D, NativeMesh3D};
+use crate::mesh_loader::{
+    AssetLoadProgress, BackgroundAssetLoad, LoadedMesh, NativeAsset3D, NativeMesh3D,
+    NativeTensorGrid,
+};
 
 // ----------------------------------------------------------------------------
 
@@ -45,13 +48,39 @@ impl re_byte_size::SizeBytes for MeshCacheKey {
 }
 
 struct MeshEntry {
+    /// While loading in the background, this is a coarse preview, if any.
     mesh: Option<Arc<LoadedMesh>>,
+
+    loading: Option<BackgroundAssetLoad>,
     last_used_generation: u64,
 }
 
+impl MeshEntry {
+    fn name(&self) -> Option<&str> {
+        self.mesh
+            .as_ref()
+            .map(|mesh| mesh.name())
+            .or_else(|| self.loading.as_ref().map(|loading| loading.name()))
+    }
+
+    fn bytes_gpu(&self) -> u64 {
+        self.mesh.as_ref().map_or(0, |mesh| {
+            mesh.mesh_instances
+                .iter()
+                .map(|s| s.gpu_mesh.gpu_byte_size())
+                .sum::<u64>()
+        })
+    }
+}
+
 impl re_byte_size::SizeBytes for MeshEntry {
     fn heap_size_bytes(&self) -> u64 {
-        self.mesh.heap_size_bytes()
+        let Self {
+            mesh,
+            loading,
+            last_used_generation: _,
+        } = self;
+        mesh.heap_size_bytes() + loading.as_ref().map_or(0, |loading| loading.num_bytes())
     }
 }
 
@@ -62,11 +91,15 @@ pub struct MeshCache {
     generation: u64,
 }
 
-/// Either a [`re_types::archetypes::Asset3D`] or [`re_types::archetypes::Mesh3D`] to be cached.
+/// Either a [`re_types::archetypes::Asset3D`], [`re_types::archetypes::Mesh3D`]
+/// or a [`re_types::archetypes::Tensor`] shown as a grid to be cached.
 #[derive(Debug, Clone)]
 pub enum AnyMesh<'a> {
     Asset {
         asset: NativeAsset3D<'a>,
+
+        /// Repainted whenever a large asset loading in the background has something new to show.
+        egui_ctx: &'a egui::Context,
     },
     Mesh {
         mesh: NativeMesh3D<'a>,
@@ -75,6 +108,9 @@ pub enum AnyMesh<'a> {
         /// hash for texture manager lookup.
         texture_key: u64,
     },
+    TensorGrid {
+        grid: NativeTensorGrid<'a>,
+    },
 }
 
 impl MeshCache {
@@ -91,6 +127,18 @@ impl MeshCache {
             .or_default()
             .entry(key)
             .or_insert_with(|| {
+                if let AnyMesh::Asset { asset, egui_ctx } = &mesh
+                    && let Some(loading) =
+                        BackgroundAssetLoad::start(name, asset, egui_ctx, render_ctx)
+                {
+                    re_log::debug!("Loading mesh {name:?} in the background…");
+                    return MeshEntry {
+                        mesh: None,
+                        loading: Some(loading),
+                        last_used_generation: 0,
+                    };
+                }
+
                 re_log::trace!("Loading CPU mesh {name:?}…");
 
                 let result = LoadedMesh::load(name.to_owned(), mesh, render_ctx);
@@ -98,17 +146,41 @@ impl MeshCache {
                 match result {
                     Ok(cpu_mesh) => MeshEntry {
                         mesh: Some(Arc::new(cpu_mesh)),
+                        loading: None,
                         last_used_generation: 0,
                     },
                     Err(err) => {
                         re_log::warn!("Failed to load mesh {name:?}: {}", re_error::format(&err));
                         MeshEntry {
                             mesh: None,
+                            loading: None,
                             last_used_generation: 0,
                         }
                     }
                 }
             });
+
+        if let Some(loading) = &entry.loading {
+            match loading.poll(render_ctx) {
+                Some(AssetLoadProgress::Preview(preview)) => {
+                    entry.mesh = Some(Arc::new(preview));
+                }
+                Some(AssetLoadProgress::Done(result)) => {
+                    entry.mesh = match result {
+                        Ok(mesh) => Some(Arc::new(mesh)),
+                        Err(err) => {
+                            re_log::warn!(
+                                "Failed to load mesh {name:?}: {}",
+                                re_error::format(&err)
+                            );
+                            None
+                        }
+                    };
+                    entry.loading = None;
+                }
+                None => {}
+            }
+        }
         entry.last_used_generation = self.generation;
 
         entry.mesh.clone()
@@ -121,8 +193,17 @@ impl Cache for MeshCache {
         // `query_result_hash` in `MeshCacheKey` includes overrides in the hash. And
         // we currently have no way of knowing which hash should be removed because
         // of overrides changing.
+        //
+        // Large assets still being parsed in the background are kept though,
+        // so that their parsing doesn't start over once they are shown again.
         self.cache.retain(|_, meshes| {
-            meshes.retain(|_, mesh| mesh.last_used_generation == self.generation);
+            meshes.retain(|_, mesh| {
+                mesh.last_used_generation == self.generation
+                    || mesh
+                        .loading
+                        .as_ref()
+                        .is_some_and(|loading| !loading.is_parsed())
+            });
 
             !meshes.is_empty()
         });
@@ -138,21 +219,23 @@ impl Cache for MeshCache {
         let mut items: Vec<_> = self
             .cache
             .iter()
-            .map(|(row_id, meshes)| {
-                let bytes_gpu = meshes
-                    .values()
-                    .filter_map(|entry| entry.mesh.as_ref())
-                    .map(|mesh| {
-                        mesh.mesh_instances
-                            .iter()
-                            .map(|s| s.gpu_mesh.gpu_byte_size())
-                            .sum::<u64>()
-                    })
-                    .sum();
+            .flat_map(|(row_id, meshes)| {
+                meshes.values().map(move |entry| {
+                    let name = entry.name().unwrap_or_default();
+                    let item_name = if entry.loading.is_some() {
+                        format!("{name} ({}, loading)", row_id.short_string())
+                    } else {
+                        format!("{name} ({})", row_id.short_string())
+                    };
+                    (item_name, entry)
+                })
+            })
+            .map(|(item_name, entry)| {
+                let bytes_gpu = entry.bytes_gpu();
                 full_bytes_gpu += bytes_gpu;
                 CacheMemoryReportItem {
-                    item_name: row_id.short_string(),
-                    bytes_cpu: meshes.total_size_bytes(),
+                    item_name,
+                    bytes_cpu: entry.total_size_bytes(),
                     bytes_gpu: Some(bytes_gpu),
                 }
             })
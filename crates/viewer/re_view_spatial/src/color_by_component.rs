@@ -0,0 +1,96 @@
+use re_entity_db::EntityDb;
+use re_log_types::EntityPath;
+use re_types::{
+    blueprint::archetypes::ColorByComponent,
+    components::{Colormap, Name, ValueRange},
+};
+use re_viewer_context::{DataResult, ViewerContext, gpu_bridge};
+
+use crate::instance_filter::numeric_instance_values;
+
+/// Colors the instances of an entity by the values of a numeric component.
+///
+/// Read from a [`ColorByComponent`] override of the entity.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColorByComponentSettings {
+    /// Either the full identifier of the component or only its field name, e.g. `intensity`.
+    pub component: String,
+
+    pub colormap: Colormap,
+
+    /// The values mapped to the start and end of the colormap.
+    ///
+    /// `None` to use the range of the values.
+    pub range: Option<ValueRange>,
+}
+
+impl ColorByComponentSettings {
+    /// Returns `None` if the entity has no override naming a component to color by.
+    pub fn from_overrides(ctx: &ViewerContext<'_>, data_result: &DataResult) -> Option<Self> {
+        let component = ColorByComponent::descriptor_component().component;
+        let colormap = ColorByComponent::descriptor_colormap().component;
+        let range = ColorByComponent::descriptor_range().component;
+
+        let results = ctx.blueprint_db().latest_at(
+            ctx.blueprint_query,
+            data_result.override_path(),
+            [component, colormap, range],
+        );
+
+        let component = results.component_mono::<Name>(component)?;
+        let component = component.as_str().trim();
+        if component.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            component: component.to_owned(),
+            colormap: results
+                .component_mono::<Colormap>(colormap)
+                .unwrap_or(Colormap::Viridis),
+            range: results.component_mono::<ValueRange>(range),
+        })
+    }
+
+    /// For each instance of the entity, the color of its latest value of the component.
+    ///
+    /// Instances without a value are `None`.
+    /// Returns `None` if the entity doesn't have the component, or if its values aren't numeric.
+    pub fn instance_colors(
+        &self,
+        entity_db: &EntityDb,
+        query: &re_chunk_store::LatestAtQuery,
+        entity_path: &EntityPath,
+    ) -> Option<Vec<Option<egui::Color32>>> {
+        let values = numeric_instance_values(entity_db, query, entity_path, &self.component)?;
+
+        let (start, end) = match self.range {
+            Some(range) => (range.start(), range.end()),
+            None => values
+                .iter()
+                .flatten()
+                .filter(|value| value.is_finite())
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+                    (min.min(*value), max.max(*value))
+                }),
+        };
+
+        let colormap = gpu_bridge::colormap_to_re_renderer(self.colormap);
+        Some(
+            values
+                .iter()
+                .map(|value| {
+                    let value = (*value)?;
+                    // Maps everything to the middle of the colormap if all values are the same.
+                    let t = if start < end {
+                        ((value - start) / (end - start)).clamp(0.0, 1.0)
+                    } else {
+                        0.5
+                    };
+                    let [r, g, b, a] = re_renderer::colormap_srgb(colormap, t as f32);
+                    Some(egui::Color32::from_rgba_unmultiplied(r, g, b, a))
+                })
+                .collect(),
+        )
+    }
+}
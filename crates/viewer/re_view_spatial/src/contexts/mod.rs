@@ -11,7 +11,7 @@ pub use transform_tree_context::TransformTreeContext;
 // -----------------------------------------------------------------------------
 
 use re_renderer::DepthOffset;
-use re_viewer_context::{Annotations, ViewClassRegistryError};
+use re_viewer_context::{Annotations, ResolvedAnnotationInfos, ViewClassRegistryError};
 
 /// Context objects for a single entity in a spatial scene.
 pub struct SpatialSceneEntityContext<'a> {
@@ -21,6 +21,59 @@ pub struct SpatialSceneEntityContext<'a> {
 
     pub highlight: &'a re_viewer_context::ViewOutlineMasks, // Not part of the context, but convenient to have here.
     pub view_class_identifier: ViewClassIdentifier,
+
+    /// Set if the entity is shown on the image plane of a pinhole camera in a 3D view.
+    pub image_plane: Option<crate::image_planes::ImagePlaneSettings>,
+
+    /// For each instance, whether it is hidden by the view's instance filter,
+    /// or was hidden after selecting it.
+    ///
+    /// `None` if the view doesn't filter this entity.
+    pub filtered_instances: Option<Vec<bool>>,
+
+    /// For each instance, the color set by a [`re_types::blueprint::archetypes::ColorByComponent`] override.
+    ///
+    /// `None` if the entity isn't colored by a component.
+    pub instance_colors: Option<Vec<Option<egui::Color32>>>,
+
+    /// Whether this is an earlier state of the entity shown by the view's onion skin.
+    ///
+    /// Labels aren't shown for earlier states.
+    pub is_earlier_state: bool,
+
+    /// Opacity the entity is drawn with, below 1.0 only for earlier states.
+    pub opacity: f32,
+}
+
+impl SpatialSceneEntityContext<'_> {
+    /// Whether 2D annotations (points, boxes, lines, arrows) of this entity should be shown.
+    ///
+    /// They can be hidden from the image planes of a 3D view.
+    #[inline]
+    pub fn shows_2d_annotations(&self) -> bool {
+        self.image_plane
+            .is_none_or(|image_plane| image_plane.project_annotations)
+    }
+
+    /// Marks the instances hidden by the view's instance filter as hidden.
+    #[inline]
+    pub fn hide_filtered_instances(&self, annotation_infos: &mut ResolvedAnnotationInfos) {
+        if let Some(filtered_instances) = &self.filtered_instances {
+            annotation_infos.hide_instances(filtered_instances);
+        }
+    }
+
+    /// Replaces the colors of the instances that are colored by a component.
+    #[inline]
+    pub fn apply_instance_colors(&self, colors: &mut [egui::Color32]) {
+        if let Some(instance_colors) = &self.instance_colors {
+            for (color, instance_color) in colors.iter_mut().zip(instance_colors) {
+                if let Some(instance_color) = instance_color {
+                    *color = *instance_color;
+                }
+            }
+        }
+    }
 }
 
 pub fn register_spatial_contexts(
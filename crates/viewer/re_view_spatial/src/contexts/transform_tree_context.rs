@@ -7,12 +7,15 @@ use re_tf::{TransformFrameId, TransformFrameIdHash};
 use re_types::{archetypes, components::ImagePlaneDistance};
 use re_view::{DataResultQuery as _, latest_at_with_blueprint_resolved_data};
 use re_viewer_context::{
-    DataResult, IdentifiedViewSystem, ViewContext, ViewContextSystem,
+    DataResult, IdentifiedViewSystem, ViewClass as _, ViewContext, ViewContextSystem,
     ViewContextSystemOncePerFrameResult, typed_fallback_for,
 };
 use vec1::smallvec_v1::SmallVec1;
 
-use crate::caches::TransformDatabaseStoreCache;
+use crate::{
+    SpatialView3D, caches::TransformDatabaseStoreCache,
+    coordinate_conversion::CoordinateConversionSettings, image_planes::ImagePlaneSettings,
+};
 
 /// Provides a transform tree for the view & time it operates on.
 ///
@@ -25,6 +28,12 @@ pub struct TransformTreeContext {
         IntMap<EntityPathHash, Result<re_tf::TransformInfo, re_tf::TransformFromToError>>,
     target_frame: TransformFrameIdHash,
     entity_transform_id_mapping: EntityTransformIdMapping,
+
+    /// Only set for 3D views.
+    image_plane_settings: Option<ImagePlaneSettings>,
+
+    /// Only set for 3D views.
+    coordinate_conversion: Option<CoordinateConversionSettings>,
 }
 
 impl Default for TransformTreeContext {
@@ -34,6 +43,8 @@ impl Default for TransformTreeContext {
             transform_infos: IntMap::default(),
             target_frame: TransformFrameIdHash::entity_path_hierarchy_root(),
             entity_transform_id_mapping: EntityTransformIdMapping::default(),
+            image_plane_settings: None,
+            coordinate_conversion: None,
         }
     }
 }
@@ -97,6 +108,12 @@ impl ViewContextSystem for TransformTreeContext {
         // Target frame is the coordinate frame of the space origin entity.
         self.target_frame = self.transform_frame_id_for(query.space_origin.hash());
 
+        let is_3d_view = ctx.view_class_identifier == SpatialView3D::identifier();
+        self.image_plane_settings = is_3d_view.then(|| ImagePlaneSettings::from_view(ctx));
+        self.coordinate_conversion =
+            is_3d_view.then(|| CoordinateConversionSettings::from_view(ctx, query.space_origin));
+        let image_plane_distance_override = self.image_plane_distance_override();
+
         let latest_at_query = query.latest_at_query();
 
         let transform_infos_per_frame = self
@@ -108,6 +125,10 @@ impl ViewContextSystem for TransformTreeContext {
                     .keys()
                     .copied(),
                 &|transform_frame_id_hash| {
+                    if let Some(image_plane_distance) = image_plane_distance_override {
+                        return image_plane_distance as f64;
+                    }
+
                     self.entity_transform_id_mapping
                         .transform_frame_id_to_entity_path
                         .get(&transform_frame_id_hash)
@@ -123,11 +144,23 @@ impl ViewContextSystem for TransformTreeContext {
             )
             .collect::<Vec<_>>();
 
+        let view_from_origin = self
+            .coordinate_conversion
+            .and_then(|conversion| conversion.view_from_origin());
+
         self.transform_infos = {
             re_tracing::profile_scope!("transform info lookup");
 
             transform_infos_per_frame
                 .into_iter()
+                .map(|(transform_frame_id_hash, transform_info)| {
+                    let transform_info = match view_from_origin {
+                        Some(view_from_origin) => transform_info
+                            .map(|transform_info| transform_info.left_multiply(view_from_origin)),
+                        None => transform_info,
+                    };
+                    (transform_frame_id_hash, transform_info)
+                })
                 .filter_map(|(transform_frame_id_hash, transform_info)| {
                     self.entity_transform_id_mapping
                         .transform_frame_id_to_entity_path
@@ -141,6 +174,10 @@ impl ViewContextSystem for TransformTreeContext {
                 .flatten()
                 .collect()
         };
+
+        if is_3d_view {
+            self.apply_rigid_alignments(ctx, query);
+        }
     }
 
     fn as_any(&self) -> &dyn std::any::Any {
@@ -149,6 +186,34 @@ impl ViewContextSystem for TransformTreeContext {
 }
 
 impl TransformTreeContext {
+    /// Moves entities by the transforms fitted for their [`RigidAlignment`] overrides.
+    ///
+    /// [`RigidAlignment`]: re_types::blueprint::archetypes::RigidAlignment
+    ///
+    /// Only affects the entities themselves, not their children.
+    fn apply_rigid_alignments(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        query: &re_viewer_context::ViewQuery<'_>,
+    ) {
+        re_tracing::profile_function!();
+
+        for data_result in query.iter_all_data_results() {
+            let Some(entity_from_aligned_entity) =
+                crate::rigid_alignment::fitted_alignment(ctx.viewer_ctx, data_result)
+            else {
+                continue;
+            };
+
+            if let Some(Ok(transform_info)) = self
+                .transform_infos
+                .get_mut(&data_result.entity_path.hash())
+            {
+                *transform_info = transform_info.with_moved_source(entity_from_aligned_entity);
+            }
+        }
+    }
+
     /// Transform info to get from the entity's transform to the origin transform frame.
     #[inline]
     pub fn transform_info_for_entity(
@@ -167,6 +232,33 @@ impl TransformTreeContext {
         self.transform_forest.pinhole_tree_root_info(frame)
     }
 
+    /// The image plane distance that overrides the one of all pinhole cameras, if any.
+    #[inline]
+    pub fn image_plane_distance_override(&self) -> Option<f32> {
+        self.image_plane_settings?.image_plane_distance
+    }
+
+    /// The image plane settings that apply to the given entity, if it is shown on the image plane
+    /// of a pinhole camera in a 3D view.
+    #[inline]
+    pub fn image_plane_settings_for(
+        &self,
+        transform_info: &re_tf::TransformInfo,
+    ) -> Option<ImagePlaneSettings> {
+        let settings = self.image_plane_settings?;
+        self.pinhole_tree_root_info(transform_info.tree_root())
+            .is_some()
+            .then_some(settings)
+    }
+
+    /// How the data is converted from the coordinate convention it was logged in.
+    ///
+    /// Already applied to all transform infos. Only set for 3D views.
+    #[inline]
+    pub fn coordinate_conversion(&self) -> Option<&CoordinateConversionSettings> {
+        self.coordinate_conversion.as_ref()
+    }
+
     /// Returns the target frame, also known as the space origin.
     #[inline]
     pub fn target_frame(&self) -> TransformFrameIdHash {
@@ -0,0 +1,233 @@
+use re_log_types::EntityPath;
+use re_types::{
+    blueprint::{archetypes::CoordinateConversion, components::Enabled},
+    components::{LatLon, ViewCoordinates},
+};
+use re_viewer_context::ViewContext;
+use re_viewport_blueprint::ViewProperty;
+
+/// Semi-major axis of the WGS84 ellipsoid, in meters.
+const WGS84_A: f64 = 6_378_137.0;
+
+/// First eccentricity squared of the WGS84 ellipsoid.
+const WGS84_E2: f64 = 6.694_379_990_14e-3;
+
+/// How the data of a 3D view is converted from the coordinate convention it was logged in.
+///
+/// Read from the [`CoordinateConversion`] view property.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CoordinateConversionSettings {
+    /// The coordinate convention the data is logged in, if known.
+    pub source: Option<ViewCoordinates>,
+
+    /// The coordinate convention the data is shown in, if it differs from the source.
+    pub target: Option<ViewCoordinates>,
+
+    /// Whether the data is logged in Earth-centered, Earth-fixed coordinates.
+    pub earth_centered: bool,
+
+    /// Origin of the east-north-up tangent plane.
+    pub geo_origin: LatLon,
+}
+
+impl CoordinateConversionSettings {
+    pub fn from_view(ctx: &ViewContext<'_>, space_origin: &EntityPath) -> Self {
+        let property = ViewProperty::from_archetype::<CoordinateConversion>(
+            ctx.blueprint_db(),
+            ctx.blueprint_query(),
+            ctx.view_id,
+        );
+
+        let source = property
+            .component_or_empty::<ViewCoordinates>(
+                CoordinateConversion::descriptor_source_coordinates().component,
+            )
+            .ok()
+            .flatten()
+            .or_else(|| {
+                re_tf::query_view_coordinates_at_closest_ancestor(
+                    space_origin,
+                    ctx.recording(),
+                    &ctx.current_query(),
+                )
+            });
+        let target = property
+            .component_or_empty::<ViewCoordinates>(
+                CoordinateConversion::descriptor_target_coordinates().component,
+            )
+            .ok()
+            .flatten();
+        let earth_centered = property
+            .component_or_empty::<Enabled>(
+                CoordinateConversion::descriptor_earth_centered().component,
+            )
+            .ok()
+            .flatten()
+            .is_some_and(bool::from);
+        let geo_origin = property
+            .component_or_empty::<LatLon>(CoordinateConversion::descriptor_geo_origin().component)
+            .ok()
+            .flatten()
+            .unwrap_or_default();
+
+        Self {
+            source,
+            target,
+            earth_centered,
+            geo_origin,
+        }
+    }
+
+    /// The coordinate convention the data is shown in.
+    ///
+    /// This is what decides which way is up in the view.
+    pub fn view_coordinates(&self) -> Option<ViewCoordinates> {
+        if self.earth_centered {
+            Some(self.target.unwrap_or(ViewCoordinates::RFU))
+        } else {
+            self.target.or(self.source)
+        }
+    }
+
+    /// Transform from the coordinate frame of the space origin, as logged, to the one shown in the view.
+    ///
+    /// `None` if there's nothing to convert.
+    pub fn view_from_origin(&self) -> Option<glam::DAffine3> {
+        if self.earth_centered {
+            let view_from_enu = self
+                .target
+                .unwrap_or(ViewCoordinates::RFU)
+                .from_other(&ViewCoordinates::RFU)
+                .as_dmat3();
+            return Some(glam::DAffine3::from_mat3(view_from_enu) * enu_from_ecef(self.geo_origin));
+        }
+
+        let target = self.target?;
+        let source = self.source.unwrap_or_default();
+        (target != source).then(|| glam::DAffine3::from_mat3(target.from_other(&source).as_dmat3()))
+    }
+
+    /// Position of a point on the WGS84 ellipsoid in the coordinate frame of the space origin, as logged.
+    ///
+    /// Unless the data is Earth-centered, the point is placed in the east-north-up tangent plane at
+    /// [`Self::geo_origin`], expressed in the [`Self::source`] coordinate convention.
+    pub fn origin_from_lat_lon(&self, lat_lon: LatLon) -> glam::DVec3 {
+        let ecef = ecef_from_lat_lon(lat_lon);
+        if self.earth_centered {
+            return ecef;
+        }
+
+        let origin_from_enu = self
+            .source
+            .unwrap_or_default()
+            .from_other(&ViewCoordinates::RFU)
+            .as_dmat3();
+        origin_from_enu * enu_from_ecef(self.geo_origin).transform_point3(ecef)
+    }
+}
+
+/// Earth-centered, Earth-fixed position of a point on the WGS84 ellipsoid.
+fn ecef_from_lat_lon(lat_lon: LatLon) -> glam::DVec3 {
+    let (sin_lat, cos_lat) = lat_lon.latitude().to_radians().sin_cos();
+    let (sin_lon, cos_lon) = lat_lon.longitude().to_radians().sin_cos();
+
+    // Prime vertical radius of curvature.
+    let n = WGS84_A / (1.0 - WGS84_E2 * sin_lat * sin_lat).sqrt();
+
+    glam::DVec3::new(
+        n * cos_lat * cos_lon,
+        n * cos_lat * sin_lon,
+        n * (1.0 - WGS84_E2) * sin_lat,
+    )
+}
+
+/// Transform from Earth-centered, Earth-fixed coordinates to the east-north-up tangent plane at `origin`.
+fn enu_from_ecef(origin: LatLon) -> glam::DAffine3 {
+    let (sin_lat, cos_lat) = origin.latitude().to_radians().sin_cos();
+    let (sin_lon, cos_lon) = origin.longitude().to_radians().sin_cos();
+
+    let east = glam::DVec3::new(-sin_lon, cos_lon, 0.0);
+    let north = glam::DVec3::new(-sin_lat * cos_lon, -sin_lat * sin_lon, cos_lat);
+    let up = glam::DVec3::new(cos_lat * cos_lon, cos_lat * sin_lon, sin_lat);
+
+    let enu_from_ecef_rotation = glam::DMat3::from_cols(east, north, up).transpose();
+    glam::DAffine3::from_mat3_translation(
+        enu_from_ecef_rotation,
+        -(enu_from_ecef_rotation * ecef_from_lat_lon(origin)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ned_to_enu() {
+        let settings = CoordinateConversionSettings {
+            source: Some(ViewCoordinates::FRD),
+            target: Some(ViewCoordinates::RFU),
+            ..Default::default()
+        };
+        let view_from_origin = settings.view_from_origin().unwrap();
+
+        // North, east & down.
+        assert!(
+            view_from_origin
+                .transform_point3(glam::DVec3::X)
+                .abs_diff_eq(glam::DVec3::Y, 1e-9)
+        );
+        assert!(
+            view_from_origin
+                .transform_point3(glam::DVec3::Y)
+                .abs_diff_eq(glam::DVec3::X, 1e-9)
+        );
+        assert!(
+            view_from_origin
+                .transform_point3(glam::DVec3::Z)
+                .abs_diff_eq(glam::DVec3::NEG_Z, 1e-9)
+        );
+
+        let no_conversion = CoordinateConversionSettings {
+            source: Some(ViewCoordinates::FRD),
+            target: Some(ViewCoordinates::FRD),
+            ..Default::default()
+        };
+        assert_eq!(no_conversion.view_from_origin(), None);
+    }
+
+    #[test]
+    fn earth_centered() {
+        let geo_origin = LatLon::new(47.37, 8.54);
+        let settings = CoordinateConversionSettings {
+            earth_centered: true,
+            geo_origin,
+            ..Default::default()
+        };
+        let view_from_origin = settings.view_from_origin().unwrap();
+
+        // The origin ends up at the origin of the view.
+        let origin = view_from_origin.transform_point3(ecef_from_lat_lon(geo_origin));
+        assert!(origin.length() < 1e-6, "{origin:?}");
+
+        // A point a bit further north ends up ~111m north of it, on the ground.
+        let north = view_from_origin
+            .transform_point3(settings.origin_from_lat_lon(LatLon::new(47.371, 8.54)));
+        assert!(north.x.abs() < 1e-6, "{north:?}");
+        assert!((north.y - 111.2).abs() < 0.5, "{north:?}");
+        assert!(north.z.abs() < 0.01, "{north:?}");
+    }
+
+    #[test]
+    fn geo_points_in_local_frame() {
+        let settings = CoordinateConversionSettings {
+            source: Some(ViewCoordinates::FRD),
+            geo_origin: LatLon::new(0.0, 0.0),
+            ..Default::default()
+        };
+
+        // Due east of the origin is along +Y in NED.
+        let east = settings.origin_from_lat_lon(LatLon::new(0.0, 0.001));
+        assert!(east.x.abs() < 1e-6, "{east:?}");
+        assert!((east.y - 111.3).abs() < 0.5, "{east:?}");
+    }
+}
@@ -0,0 +1,187 @@
+use glam::Vec3;
+
+use re_renderer::{LineDrawableBuilder, Size, renderer::PointCloudCropBox};
+use re_types::{
+    blueprint::{archetypes::CropBox, components::Enabled},
+    components::{HalfSize3D, Translation3D},
+};
+use re_viewer_context::ViewContext;
+use re_viewport_blueprint::ViewProperty;
+
+use crate::eye::Eye;
+
+/// Radius of the handles on the faces of the crop box, in ui points.
+const HANDLE_RADIUS: f32 = 5.0;
+
+/// Hides the points of point clouds, depth clouds and Gaussian splats inside or outside of an axis-aligned box.
+///
+/// Read from the [`CropBox`] view property.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CropBoxSettings {
+    /// Center of the box in the view's world space.
+    pub center: Vec3,
+
+    /// Half the size of the box along each axis. Never negative.
+    pub half_size: Vec3,
+
+    /// If true, the points inside the box are hidden, otherwise those outside of it.
+    pub keep_outside: bool,
+}
+
+impl CropBoxSettings {
+    /// Returns `None` if the crop box is disabled.
+    pub fn from_view(ctx: &ViewContext<'_>) -> Option<Self> {
+        let property = property(ctx);
+
+        let enabled = property
+            .component_or_fallback::<Enabled>(ctx, CropBox::descriptor_enabled().component)
+            .is_ok_and(bool::from);
+        if !enabled {
+            return None;
+        }
+
+        let center = property
+            .component_or_fallback::<Translation3D>(ctx, CropBox::descriptor_center().component)
+            .map_or(Vec3::ZERO, |center| center.0.into());
+        let half_size = property
+            .component_or_fallback::<HalfSize3D>(ctx, CropBox::descriptor_half_size().component)
+            .map_or(Vec3::ONE, Vec3::from)
+            .abs();
+        let keep_outside = property
+            .component_or_fallback::<Enabled>(ctx, CropBox::descriptor_keep_outside().component)
+            .is_ok_and(bool::from);
+
+        Some(Self {
+            center,
+            half_size,
+            keep_outside,
+        })
+    }
+
+    pub fn bounds(&self) -> macaw::BoundingBox {
+        macaw::BoundingBox::from_min_max(self.center - self.half_size, self.center + self.half_size)
+    }
+
+    pub fn point_cloud_crop_box(&self) -> PointCloudCropBox {
+        PointCloudCropBox {
+            bounds: self.bounds(),
+            keep_inside: !self.keep_outside,
+        }
+    }
+
+    fn save(&self, ctx: &ViewContext<'_>) {
+        let property = property(ctx);
+        property.save_blueprint_component(
+            ctx.viewer_ctx,
+            &CropBox::descriptor_center(),
+            &Translation3D::from(self.center),
+        );
+        property.save_blueprint_component(
+            ctx.viewer_ctx,
+            &CropBox::descriptor_half_size(),
+            &HalfSize3D::from(self.half_size),
+        );
+    }
+}
+
+fn property(ctx: &ViewContext<'_>) -> ViewProperty {
+    ViewProperty::from_archetype::<CropBox>(ctx.blueprint_db(), ctx.blueprint_query(), ctx.view_id)
+}
+
+/// Draws the outline of the crop box.
+pub fn add_crop_box_outline(
+    line_builder: &mut LineDrawableBuilder<'_>,
+    crop_box: &CropBoxSettings,
+    radius: Size,
+    color: egui::Color32,
+) {
+    line_builder
+        .batch("crop_box")
+        .add_box_outline(&crop_box.bounds())
+        .map(|lines| lines.radius(radius).color(color));
+}
+
+/// Shows a handle on each face of the crop box, which moves that face along its axis when dragged.
+///
+/// Pressing [`re_view::controls::CROP_BOX_CENTER_KEY`] while hovering the view moves the box
+/// to the hovered point.
+pub fn crop_box_ui(
+    ctx: &ViewContext<'_>,
+    ui: &egui::Ui,
+    crop_box: &CropBoxSettings,
+    eye: &Eye,
+    ui_rect: egui::Rect,
+    view_response: &egui::Response,
+    hovered_position: Option<Vec3>,
+) {
+    let mut new_crop_box = *crop_box;
+
+    if view_response.hovered()
+        && ui.input(|i| i.key_pressed(re_view::controls::CROP_BOX_CENTER_KEY))
+        && let Some(hovered_position) = hovered_position
+    {
+        new_crop_box.center = hovered_position;
+    }
+
+    let ui_from_world = eye.ui_from_world(ui_rect);
+    let project = |pos: Vec3| {
+        let pos = ui_from_world * pos.extend(1.0);
+        (pos.w > 0.0).then(|| egui::pos2(pos.x / pos.w, pos.y / pos.w))
+    };
+
+    let painter = ui.painter().with_clip_rect(ui_rect);
+
+    for axis in 0..3 {
+        for sign in [-1.0, 1.0] {
+            let axis_dir = Vec3::AXES[axis] * sign;
+            let face_center = crop_box.center + axis_dir * crop_box.half_size[axis];
+
+            // A step along the axis, to find out how the axis looks on screen.
+            let step = crop_box.half_size[axis].max(0.1);
+            let (Some(handle_pos), Some(step_pos)) =
+                (project(face_center), project(face_center + axis_dir * step))
+            else {
+                continue;
+            };
+            if !ui_rect.contains(handle_pos) {
+                continue;
+            }
+
+            let response = ui.interact(
+                egui::Rect::from_center_size(handle_pos, egui::Vec2::splat(2.0 * HANDLE_RADIUS)),
+                ui.id().with(("crop_box_handle", axis, sign > 0.0)),
+                egui::Sense::drag(),
+            );
+
+            let screen_dir = step_pos - handle_pos;
+            if response.dragged() && screen_dir.length_sq() > 0.0 {
+                let movement =
+                    response.drag_delta().dot(screen_dir) / screen_dir.length_sq() * step;
+                let mut min = new_crop_box.center - new_crop_box.half_size;
+                let mut max = new_crop_box.center + new_crop_box.half_size;
+                if sign > 0.0 {
+                    max[axis] = (max[axis] + movement).max(min[axis]);
+                } else {
+                    min[axis] = (min[axis] - movement).min(max[axis]);
+                }
+                new_crop_box.center = (min + max) * 0.5;
+                new_crop_box.half_size = (max - min) * 0.5;
+            }
+
+            let visuals = ui.style().interact(&response);
+            painter.circle(
+                handle_pos,
+                HANDLE_RADIUS,
+                visuals.bg_fill,
+                visuals.fg_stroke,
+            );
+            if response.hovered() || response.dragged() {
+                ui.ctx().set_cursor_icon(egui::CursorIcon::Grab);
+            }
+        }
+    }
+
+    if new_crop_box != *crop_box {
+        new_crop_box.save(ctx);
+    }
+}
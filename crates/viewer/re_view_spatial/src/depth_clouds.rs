@@ -0,0 +1,84 @@
+use re_renderer::renderer::PointCloudCropBox;
+use re_types::{
+    blueprint::{
+        archetypes::DepthClouds3D,
+        components::{DepthCloudSubsampling, Enabled},
+    },
+    components::ValueRange,
+};
+use re_viewer_context::ViewContext;
+use re_viewport_blueprint::ViewProperty;
+
+use crate::crop_box::CropBoxSettings;
+
+/// How depth images are backprojected into point clouds in a 3D view.
+///
+/// Read from the [`DepthClouds3D`] view property.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DepthCloudSettings {
+    /// Whether depth images under a pinhole are shown as point clouds at all.
+    pub enabled: bool,
+
+    /// Only every n-th pixel in each direction is turned into a point. Always at least 1.
+    pub subsampling: u32,
+
+    /// Points with a depth in meters outside of this range are not shown.
+    pub depth_range: [f32; 2],
+
+    /// Hides the points inside or outside of a box, like for point clouds.
+    ///
+    /// Read from the [`re_types::blueprint::archetypes::CropBox`] view property.
+    pub crop_box: Option<PointCloudCropBox>,
+}
+
+impl Default for DepthCloudSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            subsampling: 1,
+            depth_range: [0.0, f32::INFINITY],
+            crop_box: None,
+        }
+    }
+}
+
+impl DepthCloudSettings {
+    pub fn from_view(ctx: &ViewContext<'_>) -> Self {
+        let property = ViewProperty::from_archetype::<DepthClouds3D>(
+            ctx.blueprint_db(),
+            ctx.blueprint_query(),
+            ctx.view_id,
+        );
+        let defaults = Self::default();
+
+        let enabled = property
+            .component_or_fallback::<Enabled>(ctx, DepthClouds3D::descriptor_enabled().component)
+            .map_or(defaults.enabled, bool::from);
+        let subsampling = property
+            .component_or_fallback::<DepthCloudSubsampling>(
+                ctx,
+                DepthClouds3D::descriptor_subsampling().component,
+            )
+            .map_or(defaults.subsampling, |subsampling| {
+                subsampling.0.0.clamp(1, u32::MAX as u64) as u32
+            });
+        let depth_range = property
+            .component_or_empty::<ValueRange>(DepthClouds3D::descriptor_depth_range().component)
+            .ok()
+            .flatten()
+            .filter(|range| range.start() <= range.end())
+            .map_or(defaults.depth_range, |range| {
+                [range.start() as f32, range.end() as f32]
+            });
+
+        let crop_box =
+            CropBoxSettings::from_view(ctx).map(|crop_box| crop_box.point_cloud_crop_box());
+
+        Self {
+            enabled,
+            subsampling,
+            depth_range,
+            crop_box,
+        }
+    }
+}
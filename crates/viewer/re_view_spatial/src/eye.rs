@@ -4,17 +4,20 @@ use glam::{Mat4, Quat, Vec3, vec3};
 use macaw::IsoTransform;
 
 use re_log_types::EntityPath;
+use re_renderer::view_builder::{OrthographicCameraMode, Projection};
 use re_types::{
     blueprint::{
         archetypes::EyeControls3D,
-        components::{AngularSpeed, Eye3DKind},
+        components::{AngularSpeed, Eye3DKind, Eye3DProjection},
     },
-    components::{LinearSpeed, Position3D, Vector3D},
+    components::{LinearSpeed, Position3D, Vector3D, ViewCoordinates},
+    view_coordinates::SignedAxis3,
 };
 use re_ui::ContextExt as _;
 use re_view::controls::{
-    DRAG_PAN3D_BUTTON, ROLL_MOUSE, ROLL_MOUSE_ALT, ROLL_MOUSE_MODIFIER, ROTATE3D_BUTTON,
-    RuntimeModifiers, SPEED_UP_3D_MODIFIER,
+    DRAG_PAN3D_BUTTON, FRONT_VIEW3D_KEY, ISOMETRIC_VIEW3D_KEY, LEFT_VIEW3D_KEY, ROLL_MOUSE,
+    ROLL_MOUSE_ALT, ROLL_MOUSE_MODIFIER, ROTATE3D_BUTTON, RuntimeModifiers, SPEED_UP_3D_MODIFIER,
+    TOP_VIEW3D_KEY,
 };
 use re_viewer_context::{ViewContext, ViewerContext};
 use re_viewport_blueprint::{ViewProperty, ViewPropertyQueryError};
@@ -32,6 +35,12 @@ pub struct Eye {
 
     /// If no angle is present, this is an orthographic camera.
     pub fov_y: Option<f32>,
+
+    /// Height of the view volume of an orthographic camera, in world units.
+    ///
+    /// If not set, an orthographic camera maps the rect it's shown in to its view plane, as in 2D views.
+    #[serde(default)]
+    pub vertical_world_size: Option<f32>,
 }
 
 impl Eye {
@@ -46,6 +55,7 @@ impl Eye {
         Some(Self {
             world_from_rub_view: space_cameras.world_from_rub_view()?,
             fov_y: Some(fov_y),
+            vertical_world_size: None,
         })
     }
 
@@ -53,15 +63,19 @@ impl Eye {
         if self.is_perspective() {
             0.01 // TODO(emilk)
         } else {
-            -1000.0 // TODO(andreas)
+            -self.far()
         }
     }
 
     pub fn far(&self) -> f32 {
         if self.is_perspective() {
             f32::INFINITY
+        } else if let Some(vertical_world_size) = self.vertical_world_size {
+            // Orthographic eyes see the same no matter how far back they are,
+            // so we let them see what's behind them as well.
+            (1000.0 * vertical_world_size).at_least(1000.0)
         } else {
-            1000.0
+            1000.0 // TODO(andreas)
         }
     }
 
@@ -70,6 +84,17 @@ impl Eye {
 
         let projection = if let Some(fov_y) = self.fov_y {
             Mat4::perspective_infinite_rh(fov_y, aspect_ratio, self.near())
+        } else if let Some(vertical_world_size) = self.vertical_world_size {
+            let half_height = 0.5 * vertical_world_size;
+            let half_width = half_height * aspect_ratio;
+            Mat4::orthographic_rh(
+                -half_width,
+                half_width,
+                -half_height,
+                half_height,
+                self.near(),
+                self.far(),
+            )
         } else {
             Mat4::orthographic_rh(
                 space2d_rect.left(),
@@ -95,6 +120,34 @@ impl Eye {
     //     self.fov_y.is_none()
     // }
 
+    /// The view transform and projection to render the eye with.
+    pub fn view_from_world_and_projection(&self, aspect_ratio: f32) -> (IsoTransform, Projection) {
+        if let (None, Some(vertical_world_size)) = (self.fov_y, self.vertical_world_size) {
+            // The renderer puts the near plane at the eye, so move the eye to our near plane.
+            let world_from_near_plane = IsoTransform::from_rotation_translation(
+                self.world_from_rub_view.rotation(),
+                self.viewpoint_in_world(),
+            );
+            (
+                world_from_near_plane.inverse(),
+                Projection::Orthographic {
+                    camera_mode: OrthographicCameraMode::NearPlaneCenter,
+                    vertical_world_size,
+                    far_plane_distance: self.far() - self.near(),
+                },
+            )
+        } else {
+            (
+                self.world_from_rub_view.inverse(),
+                Projection::Perspective {
+                    vertical_fov: self.fov_y.unwrap_or(Self::DEFAULT_FOV_Y),
+                    near_plane_distance: self.near(),
+                    aspect_ratio,
+                },
+            )
+        }
+    }
+
     /// Picking ray for a given pointer in the parent space
     /// (i.e. prior to camera transform, "world" space)
     pub fn picking_ray(&self, screen_rect: Rect, pointer: glam::Vec2) -> macaw::Ray3 {
@@ -108,6 +161,19 @@ impl Eye {
                 .world_from_rub_view
                 .transform_vector3(glam::vec3(px, py, -1.0));
             macaw::Ray3::from_origin_dir(self.pos_in_world(), ray_dir.normalize_or_zero())
+        } else if let Some(vertical_world_size) = self.vertical_world_size {
+            // Parallel rays, starting on the near plane.
+            let (w, h) = (screen_rect.width(), screen_rect.height());
+            let aspect_ratio = w / h;
+            let px = (2.0 * (pointer.x - screen_rect.left()) / w - 1.0)
+                * 0.5
+                * vertical_world_size
+                * aspect_ratio;
+            let py = (1.0 - 2.0 * (pointer.y - screen_rect.top()) / h) * 0.5 * vertical_world_size;
+            let origin =
+                self.world_from_rub_view
+                    .transform_point3(glam::vec3(px, py, -self.near()));
+            macaw::Ray3::from_origin_dir(origin, self.forward_in_world())
         } else {
             // The ray originates on the camera plane, not from the camera position
             let ray_dir = self.world_from_rub_view.rotation().mul_vec3(glam::Vec3::Z);
@@ -124,6 +190,18 @@ impl Eye {
         self.world_from_rub_view.translation()
     }
 
+    /// Where the scene is seen from, e.g. to sort transparent geometry by distance.
+    ///
+    /// Orthographic eyes in 3D views also see what's behind them, so this is on their near plane.
+    pub fn viewpoint_in_world(&self) -> glam::Vec3 {
+        if self.fov_y.is_none() && self.vertical_world_size.is_some() {
+            self.world_from_rub_view
+                .transform_point3(Vec3::Z * -self.near())
+        } else {
+            self.pos_in_world()
+        }
+    }
+
     pub fn forward_in_world(&self) -> glam::Vec3 {
         self.world_from_rub_view.rotation() * -Vec3::Z // because we use RUB
     }
@@ -152,9 +230,79 @@ impl Eye {
             ))
         };
 
+        let vertical_world_size = match (self.vertical_world_size, other.vertical_world_size) {
+            (Some(start), Some(stop)) => Some(egui::lerp(start..=stop, t)),
+            (start, stop) => {
+                if t < 0.5 {
+                    start
+                } else {
+                    stop
+                }
+            }
+        };
+
         Self {
             world_from_rub_view: IsoTransform::from_rotation_translation(rotation, translation),
             fov_y,
+            vertical_world_size,
+        }
+    }
+}
+
+/// Canonical directions to look at a 3D scene from, as used for inspecting CAD models.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EyePreset {
+    Front,
+    Left,
+    Top,
+    Isometric,
+}
+
+impl EyePreset {
+    pub const ALL: [Self; 4] = [Self::Front, Self::Left, Self::Top, Self::Isometric];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Front => "Front",
+            Self::Left => "Left",
+            Self::Top => "Top",
+            Self::Isometric => "Isometric",
+        }
+    }
+
+    pub fn key(self) -> egui::Key {
+        match self {
+            Self::Front => FRONT_VIEW3D_KEY,
+            Self::Left => LEFT_VIEW3D_KEY,
+            Self::Top => TOP_VIEW3D_KEY,
+            Self::Isometric => ISOMETRIC_VIEW3D_KEY,
+        }
+    }
+
+    /// The direction the eye looks in and its up-axis, in world space.
+    fn fwd_and_up(self, scene_view_coordinates: Option<ViewCoordinates>) -> (Vec3, Vec3) {
+        let scene_view_coordinates = scene_view_coordinates.unwrap_or_default();
+        let right = Vec3::from(
+            scene_view_coordinates
+                .right()
+                .unwrap_or(SignedAxis3::POSITIVE_X),
+        );
+        let forward = Vec3::from(
+            scene_view_coordinates
+                .forward()
+                .unwrap_or(SignedAxis3::POSITIVE_Y),
+        );
+        let up = Vec3::from(
+            scene_view_coordinates
+                .up()
+                .unwrap_or(SignedAxis3::POSITIVE_Z),
+        );
+
+        match self {
+            Self::Front => (forward, up),
+            Self::Left => (right, up),
+            Self::Top => (-up, forward),
+            Self::Isometric => ((forward + right - up).normalize(), up),
         }
     }
 }
@@ -224,6 +372,7 @@ struct ControlEye {
     pos: Vec3,
     look_target: Vec3,
     kind: Eye3DKind,
+    projection: Eye3DProjection,
     speed: f64,
     eye_up: Vec3,
     fov_y: Option<f32>,
@@ -236,6 +385,17 @@ impl ControlEye {
     const MAX_PITCH: f32 = 0.99 * 0.25 * std::f32::consts::TAU;
 
     fn get_eye(&self) -> Eye {
+        let fov_y = self.fov_y.unwrap_or(Eye::DEFAULT_FOV_Y);
+        let (fov_y, vertical_world_size) = match self.projection {
+            Eye3DProjection::Perspective => (Some(fov_y), None),
+            Eye3DProjection::Orthographic => {
+                // Show as much of the scene at the look target as the perspective projection would,
+                // so that zooming works the same and switching projections keeps the scale.
+                let vertical_world_size = 2.0 * self.radius() * (0.5 * fov_y).tan();
+                (None, Some(vertical_world_size.at_least(f32::MIN_POSITIVE)))
+            }
+        };
+
         Eye {
             world_from_rub_view: IsoTransform::look_at_rh(
                 self.pos,
@@ -248,7 +408,8 @@ impl ControlEye {
             )
             .unwrap_or_else(|| IsoTransform::from_translation(self.pos))
             .inverse(),
-            fov_y: Some(self.fov_y.unwrap_or(Eye::DEFAULT_FOV_Y)),
+            fov_y,
+            vertical_world_size,
         }
     }
 
@@ -260,6 +421,11 @@ impl ControlEye {
         let kind = eye_property
             .component_or_fallback::<Eye3DKind>(ctx, EyeControls3D::descriptor_kind().component)?;
 
+        let projection = eye_property.component_or_fallback::<Eye3DProjection>(
+            ctx,
+            EyeControls3D::descriptor_projection().component,
+        )?;
+
         let speed = **eye_property.component_or_fallback::<LinearSpeed>(
             ctx,
             EyeControls3D::descriptor_speed().component,
@@ -289,6 +455,7 @@ impl ControlEye {
             pos,
             look_target,
             kind,
+            projection,
             speed,
             eye_up,
             did_interact: false,
@@ -447,6 +614,11 @@ impl ControlEye {
     }
 
     fn handle_drag(&mut self, response: &egui::Response, drag_threshold: f32) {
+        // Dragging a selection around instances doesn't move the camera.
+        if crate::instance_selection::SelectionShape::from_response(response).is_some() {
+            return;
+        }
+
         if response.drag_delta().length() > drag_threshold {
             let roll = response.dragged_by(ROLL_MOUSE)
                 || (response.dragged_by(ROLL_MOUSE_ALT)
@@ -607,6 +779,30 @@ pub fn find_camera(space_cameras: &[SpaceCamera3D], needle: &EntityPath) -> Opti
     found_camera.and_then(Eye::from_camera)
 }
 
+/// Switches between perspective and orthographic projection.
+pub fn toggle_projection(ctx: &ViewContext<'_>) -> Result<(), ViewPropertyQueryError> {
+    let eye_property = ViewProperty::from_archetype::<EyeControls3D>(
+        ctx.blueprint_db(),
+        ctx.blueprint_query(),
+        ctx.view_id,
+    );
+
+    let projection = match eye_property.component_or_fallback::<Eye3DProjection>(
+        ctx,
+        EyeControls3D::descriptor_projection().component,
+    )? {
+        Eye3DProjection::Perspective => Eye3DProjection::Orthographic,
+        Eye3DProjection::Orthographic => Eye3DProjection::Perspective,
+    };
+    eye_property.save_blueprint_component(
+        ctx.viewer_ctx,
+        &EyeControls3D::descriptor_projection(),
+        &projection,
+    );
+
+    Ok(())
+}
+
 fn ease_out(t: f32) -> f32 {
     1. - (1. - t) * (1. - t)
 }
@@ -860,6 +1056,57 @@ impl EyeState {
         Ok(())
     }
 
+    /// Looks at the scene from the direction of the preset, keeping the distance to the look target.
+    pub fn apply_preset(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        preset: EyePreset,
+        scene_view_coordinates: Option<ViewCoordinates>,
+    ) -> Result<(), ViewPropertyQueryError> {
+        let eye_property = ViewProperty::from_archetype::<EyeControls3D>(
+            ctx.blueprint_db(),
+            ctx.blueprint_query(),
+            ctx.view_id,
+        );
+
+        let mut eye = ControlEye::from_blueprint(ctx, &eye_property, self.fov_y)?;
+        eye.did_interact = true;
+        let ControlEye {
+            pos: old_pos,
+            look_target: old_look_target,
+            eye_up: old_eye_up,
+            ..
+        } = eye;
+
+        let (fwd, up) = preset.fwd_and_up(scene_view_coordinates);
+        let radius = eye.radius();
+        let radius = if radius > 0.0 { radius } else { 1.0 };
+        match eye.kind {
+            Eye3DKind::FirstPerson => {
+                eye.look_target = eye.pos + fwd * radius;
+            }
+            Eye3DKind::Orbital => {
+                eye.pos = eye.look_target - fwd * radius;
+            }
+        }
+        eye.eye_up = up;
+
+        eye.save_to_blueprint(
+            ctx.viewer_ctx,
+            &eye_property,
+            old_pos,
+            old_look_target,
+            old_eye_up,
+        );
+
+        eye_property
+            .clear_blueprint_component(ctx.viewer_ctx, EyeControls3D::descriptor_tracking_entity());
+
+        self.start_interpolation();
+
+        Ok(())
+    }
+
     pub fn focus_entity(
         &self,
         ctx: &ViewContext<'_>,
@@ -0,0 +1,40 @@
+use re_types::components::DepthMeter;
+use re_viewer_context::ImageInfo;
+
+/// Correlates positions in a 2D space under a pinhole camera with points in 3D.
+///
+/// This is what allows a 3D view to show the point a pixel hovered in a 2D view corresponds to.
+/// Visualizers register providers via [`crate::visualizers::SpatialViewVisualizerData::add_hover_correlation`].
+pub trait HoverCorrelationProvider: Send + Sync {
+    /// The depth at the given position of the 2D space, in scene units along the camera's view direction.
+    ///
+    /// Returns `None` if the provider doesn't know the depth at this position.
+    fn depth_at(&self, pos_in_space: glam::Vec2) -> Option<f32>;
+}
+
+/// Looks up the depth at a position in a depth image.
+pub struct DepthImageHoverCorrelation {
+    pub image: ImageInfo,
+    pub depth_meter: DepthMeter,
+
+    /// Transforms positions in the 2D space to pixel coordinates of the image.
+    pub image_from_space: glam::Affine3A,
+}
+
+impl HoverCorrelationProvider for DepthImageHoverCorrelation {
+    fn depth_at(&self, pos_in_space: glam::Vec2) -> Option<f32> {
+        let pixel = self
+            .image_from_space
+            .transform_point3(pos_in_space.extend(0.0))
+            .truncate();
+        if pixel.x < 0.0 || pixel.y < 0.0 {
+            return None;
+        }
+
+        let raw_value = self.image.get_xyc(pixel.x as u32, pixel.y as u32, 0)?;
+        let depth = raw_value.as_f64() / *self.depth_meter.0 as f64;
+
+        // Zero means "no depth" in most depth images.
+        (depth > 0.0 && depth.is_finite()).then_some(depth as f32)
+    }
+}
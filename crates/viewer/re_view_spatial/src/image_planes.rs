@@ -0,0 +1,83 @@
+use re_types::{
+    blueprint::{archetypes::ImagePlanes3D, components::Enabled},
+    components::{ImagePlaneDistance, Opacity},
+};
+use re_viewer_context::ViewContext;
+use re_viewport_blueprint::ViewProperty;
+
+/// How the contents of pinhole cameras are shown on their image plane in a 3D view.
+///
+/// Read from the [`ImagePlanes3D`] view property.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ImagePlaneSettings {
+    /// Overrides the image plane distance of all cameras if set.
+    pub image_plane_distance: Option<f32>,
+
+    /// Multiplied with the opacity of every image shown on an image plane.
+    pub opacity: f32,
+
+    /// Whether images on image planes are visible from behind.
+    pub show_back_faces: bool,
+
+    /// Whether 2D annotations (points, boxes, lines, arrows) are shown on image planes.
+    pub project_annotations: bool,
+}
+
+impl Default for ImagePlaneSettings {
+    fn default() -> Self {
+        Self {
+            image_plane_distance: None,
+            opacity: 1.0,
+            show_back_faces: true,
+            project_annotations: true,
+        }
+    }
+}
+
+impl ImagePlaneSettings {
+    pub fn from_view(ctx: &ViewContext<'_>) -> Self {
+        let property = ViewProperty::from_archetype::<ImagePlanes3D>(
+            ctx.blueprint_db(),
+            ctx.blueprint_query(),
+            ctx.view_id,
+        );
+        let defaults = Self::default();
+
+        let image_plane_distance = property
+            .component_or_empty::<ImagePlaneDistance>(
+                ImagePlanes3D::descriptor_image_plane_distance().component,
+            )
+            .ok()
+            .flatten()
+            .map(f32::from)
+            .filter(|distance| distance.is_finite() && *distance > 0.0);
+        let opacity = property
+            .component_or_fallback::<Opacity>(ctx, ImagePlanes3D::descriptor_opacity().component)
+            .map_or(defaults.opacity, |opacity| opacity.0.0.clamp(0.0, 1.0));
+        let show_back_faces = property
+            .component_or_fallback::<Enabled>(
+                ctx,
+                ImagePlanes3D::descriptor_show_back_faces().component,
+            )
+            .map_or(defaults.show_back_faces, bool::from);
+        let project_annotations = property
+            .component_or_fallback::<Enabled>(
+                ctx,
+                ImagePlanes3D::descriptor_project_annotations().component,
+            )
+            .map_or(defaults.project_annotations, bool::from);
+
+        Self {
+            image_plane_distance,
+            opacity,
+            show_back_faces,
+            project_annotations,
+        }
+    }
+
+    /// Applies the opacity and back face settings to the options of an image on an image plane.
+    pub fn apply_to_rectangle(&self, options: &mut re_renderer::renderer::RectangleOptions) {
+        options.multiplicative_tint = options.multiplicative_tint.multiply(self.opacity);
+        options.cull_back_face = !self.show_back_faces;
+    }
+}
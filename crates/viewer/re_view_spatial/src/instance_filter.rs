@@ -0,0 +1,183 @@
+use arrow::array::{Array as _, Float64Array};
+use re_entity_db::EntityDb;
+use re_log_types::EntityPath;
+use re_types::{
+    Component as _, ComponentIdentifier,
+    blueprint::archetypes::InstanceFilter,
+    components::{Name, Scalar},
+};
+use re_ui::{UiExt as _, list_item};
+use re_viewer_context::ViewContext;
+use re_viewport_blueprint::ViewProperty;
+
+/// Threshold used if none was set.
+pub const DEFAULT_THRESHOLD: f64 = 0.5;
+
+/// Hides instances whose value of a scalar component is below a threshold.
+///
+/// Read from the [`InstanceFilter`] view property.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InstanceFilterSettings {
+    /// Either the full identifier of the component or only its field name, e.g. `confidence`.
+    pub component: String,
+
+    /// Instances with a value below this are hidden.
+    pub threshold: f64,
+}
+
+impl InstanceFilterSettings {
+    /// Returns `None` if no component to filter by was chosen.
+    pub fn from_view(ctx: &ViewContext<'_>) -> Option<Self> {
+        let property = ViewProperty::from_archetype::<InstanceFilter>(
+            ctx.blueprint_db(),
+            ctx.blueprint_query(),
+            ctx.view_id,
+        );
+
+        let component = property
+            .component_or_empty::<Name>(InstanceFilter::descriptor_component().component)
+            .ok()
+            .flatten()?;
+        let component = component.as_str().trim();
+        if component.is_empty() {
+            return None;
+        }
+
+        let threshold = property
+            .component_or_fallback::<Scalar>(ctx, InstanceFilter::descriptor_threshold().component)
+            .map_or(DEFAULT_THRESHOLD, |threshold| threshold.0.0);
+
+        Some(Self {
+            component: component.to_owned(),
+            threshold,
+        })
+    }
+
+    /// For each instance of the entity, whether its latest value of the component is below the threshold.
+    ///
+    /// Returns `None` if the entity doesn't have the component, or if its values aren't numeric.
+    pub fn hidden_instances(
+        &self,
+        entity_db: &EntityDb,
+        query: &re_chunk_store::LatestAtQuery,
+        entity_path: &EntityPath,
+    ) -> Option<Vec<bool>> {
+        let values = numeric_instance_values(entity_db, query, entity_path, &self.component)?;
+
+        // Instances without a value are kept.
+        Some(
+            values
+                .iter()
+                .map(|value| value.is_some_and(|value| value < self.threshold))
+                .collect(),
+        )
+    }
+}
+
+/// The latest value of a numeric component for each instance of the entity.
+///
+/// The component is given either by its full identifier or only by its field name, e.g. `confidence`.
+/// Returns `None` if the entity doesn't have the component, or if its values aren't numeric.
+pub fn numeric_instance_values(
+    entity_db: &EntityDb,
+    query: &re_chunk_store::LatestAtQuery,
+    entity_path: &EntityPath,
+    component_name: &str,
+) -> Option<Vec<Option<f64>>> {
+    let component = entity_db
+        .storage_engine()
+        .store()
+        .all_components_for_entity(entity_path)?
+        .into_iter()
+        .find(|component| component_matches(*component, component_name))?;
+
+    let values = entity_db
+        .latest_at(query, entity_path, [component])
+        .component_batch_raw(component)?;
+    let values = arrow::compute::cast(&values, &arrow::datatypes::DataType::Float64).ok()?;
+    let values = values.as_any().downcast_ref::<Float64Array>()?;
+    Some(values.iter().collect())
+}
+
+fn component_matches(component: ComponentIdentifier, component_name: &str) -> bool {
+    let component = component.as_str();
+    component == component_name
+        || component
+            .rsplit_once(':')
+            .is_some_and(|(_, field)| field == component_name)
+}
+
+// The generic ui (via `view_property_ui::<InstanceFilter>`) would show a drag value for the threshold,
+// but a slider makes it a lot easier to find a good threshold while looking at the view.
+pub fn view_property_ui_instance_filter(ctx: &ViewContext<'_>, ui: &mut egui::Ui) {
+    let property = ViewProperty::from_archetype::<InstanceFilter>(
+        ctx.blueprint_db(),
+        ctx.blueprint_query(),
+        ctx.view_id,
+    );
+    let reflection = ctx.viewer_ctx.reflection();
+    let Some(reflection) = reflection.archetypes.get(&property.archetype_name) else {
+        ui.error_label(format!(
+            "Missing reflection data for archetype {:?}.",
+            property.archetype_name
+        ));
+        return;
+    };
+
+    let query_ctx = property.query_context(ctx);
+    let sub_prop_ui = |ui: &mut egui::Ui| {
+        for field in &reflection.fields {
+            if field.component_type == Scalar::name() {
+                re_view::view_property_component_ui_custom(
+                    &query_ctx,
+                    ui,
+                    &property,
+                    field.display_name,
+                    field,
+                    &|ui| {
+                        let Ok(threshold) = property.component_or_fallback::<Scalar>(
+                            ctx,
+                            InstanceFilter::descriptor_threshold().component,
+                        ) else {
+                            ui.error_label("Failed to query threshold component");
+                            return;
+                        };
+                        let mut edit_threshold = threshold.0.0;
+                        if ui
+                            .add(
+                                egui::Slider::new(&mut edit_threshold, 0.0..=1.0)
+                                    .clamping(egui::SliderClamping::Never),
+                            )
+                            .changed()
+                        {
+                            property.save_blueprint_component(
+                                ctx.viewer_ctx,
+                                &InstanceFilter::descriptor_threshold(),
+                                &Scalar::from(edit_threshold),
+                            );
+                        }
+                    },
+                    None, // No multiline editor.
+                );
+            } else {
+                re_view::view_property_component_ui(
+                    &query_ctx,
+                    ui,
+                    &property,
+                    field.display_name,
+                    field,
+                );
+            }
+        }
+    };
+
+    ui.list_item()
+        .interactive(false)
+        .show_hierarchical_with_children(
+            ui,
+            ui.make_persistent_id(property.archetype_name.full_name()),
+            true,
+            list_item::LabelContent::new(reflection.display_name),
+            sub_prop_ui,
+        );
+}
@@ -0,0 +1,605 @@
+//! Selecting many instances of a 3D view at once, by dragging a rectangle or a lasso around them.
+//!
+//! The selected instances can then be hidden, exported, or looked at in a table.
+
+use egui::{Modifiers, Pos2};
+use nohash_hasher::IntSet;
+
+use re_entity_db::InstancePath;
+use re_log_types::{EntityPath, Instance};
+use re_types::{
+    Archetype as _,
+    archetypes::{Boxes3D, Points3D},
+    components::{HalfSize3D, PoseTranslation3D, Position3D},
+};
+use re_ui::UiExt as _;
+use re_view::controls::{
+    ROLL_MOUSE_MODIFIER, SELECTION_LASSO3D_MODIFIER, SELECTION_RECT3D_MODIFIER, SELECTION3D_BUTTON,
+};
+use re_viewer_context::{
+    IdentifiedViewSystem as _, Item, ItemCollection, SystemCommand, SystemCommandSender as _,
+    ViewId, ViewQuery, ViewerContext,
+};
+
+use crate::{
+    contexts::TransformTreeContext,
+    eye::Eye,
+    scene_export::{ExportedPoints, ExportedScene, SceneExportFormat},
+    visualizers::{Boxes3DVisualizer, Points3DVisualizer},
+};
+
+/// Lasso points closer than this to the previous one are skipped.
+const MIN_LASSO_SEGMENT_LENGTH: f32 = 2.0;
+
+/// Maximum number of rows shown in the table of selected instances.
+const MAX_TABLE_ROWS: usize = 1000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SelectionShape {
+    Rect,
+    Lasso,
+}
+
+impl SelectionShape {
+    /// The shape drawn when dragging with these modifiers, if any.
+    pub fn from_modifiers(modifiers: Modifiers) -> Option<Self> {
+        if modifiers.contains(ROLL_MOUSE_MODIFIER) {
+            None
+        } else if modifiers.contains(SELECTION_LASSO3D_MODIFIER) {
+            Some(Self::Lasso)
+        } else if modifiers.contains(SELECTION_RECT3D_MODIFIER) {
+            Some(Self::Rect)
+        } else {
+            None
+        }
+    }
+
+    /// The shape drawn by the current drag, if it selects instances rather than moving the camera.
+    pub fn from_response(response: &egui::Response) -> Option<Self> {
+        if response.dragged_by(SELECTION3D_BUTTON) {
+            Self::from_modifiers(response.ctx.input(|i| i.modifiers))
+        } else {
+            None
+        }
+    }
+}
+
+/// A rectangle or lasso being dragged in a 3D view.
+#[derive(Clone, Debug)]
+pub struct SelectionDrag {
+    shape: SelectionShape,
+
+    /// Corners of the rectangle, or the points of the lasso, in ui coordinates.
+    points: Vec<Pos2>,
+}
+
+impl SelectionDrag {
+    pub fn new(shape: SelectionShape, start: Pos2) -> Self {
+        Self {
+            shape,
+            points: vec![start],
+        }
+    }
+
+    pub fn extend(&mut self, pos: Pos2) {
+        match self.shape {
+            SelectionShape::Rect => {
+                self.points.truncate(1);
+                self.points.push(pos);
+            }
+            SelectionShape::Lasso => {
+                if self
+                    .points
+                    .last()
+                    .is_none_or(|last| last.distance(pos) >= MIN_LASSO_SEGMENT_LENGTH)
+                {
+                    self.points.push(pos);
+                }
+            }
+        }
+    }
+
+    pub fn contains(&self, pos: Pos2) -> bool {
+        match self.shape {
+            SelectionShape::Rect => match self.points.as_slice() {
+                [start, end] => egui::Rect::from_two_pos(*start, *end).contains(pos),
+                _ => false,
+            },
+            SelectionShape::Lasso => polygon_contains(&self.points, pos),
+        }
+    }
+
+    pub fn shape(&self, stroke: egui::Stroke) -> egui::Shape {
+        match (self.shape, self.points.as_slice()) {
+            (SelectionShape::Rect, [start, end]) => egui::Shape::rect_stroke(
+                egui::Rect::from_two_pos(*start, *end),
+                0.0,
+                stroke,
+                egui::StrokeKind::Middle,
+            ),
+            (SelectionShape::Rect, _) => egui::Shape::Noop,
+            (SelectionShape::Lasso, points) => egui::Shape::closed_line(points.to_vec(), stroke),
+        }
+    }
+}
+
+/// Whether a point is inside a polygon, using the even-odd rule.
+fn polygon_contains(polygon: &[Pos2], pos: Pos2) -> bool {
+    if polygon.len() < 3 {
+        return false;
+    }
+
+    let mut inside = false;
+    let mut previous = polygon[polygon.len() - 1];
+    for &current in polygon {
+        if (current.y > pos.y) != (previous.y > pos.y) {
+            let crossing_x = current.x
+                + (pos.y - current.y) / (previous.y - current.y) * (previous.x - current.x);
+            if pos.x < crossing_x {
+                inside = !inside;
+            }
+        }
+        previous = current;
+    }
+    inside
+}
+
+/// Instances hidden from a 3D view after selecting them.
+///
+/// Unlike hiding an entity, this is not stored in the blueprint.
+#[derive(Clone, Debug, Default)]
+pub struct HiddenInstances(ahash::HashMap<EntityPath, IntSet<u64>>);
+
+impl HiddenInstances {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.values().map(|instances| instances.len()).sum()
+    }
+
+    pub fn hide(&mut self, instance_path: &InstancePath) {
+        if instance_path.instance.is_specific() {
+            self.0
+                .entry(instance_path.entity_path.clone())
+                .or_default()
+                .insert(instance_path.instance.get());
+        }
+    }
+
+    pub fn is_hidden(&self, entity_path: &EntityPath, instance: u64) -> bool {
+        self.0
+            .get(entity_path)
+            .is_some_and(|instances| instances.contains(&instance))
+    }
+
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    /// Adds the hidden instances of an entity to the instances hidden by the view's instance filter.
+    ///
+    /// See [`crate::contexts::SpatialSceneEntityContext::filtered_instances`].
+    pub fn add_to_filtered(
+        &self,
+        entity_path: &EntityPath,
+        filtered_instances: Option<Vec<bool>>,
+    ) -> Option<Vec<bool>> {
+        let Some(hidden) = self.0.get(entity_path) else {
+            return filtered_instances;
+        };
+
+        let mut filtered_instances = filtered_instances.unwrap_or_default();
+        for &instance in hidden {
+            let instance = instance as usize;
+            if filtered_instances.len() <= instance {
+                filtered_instances.resize(instance + 1, false);
+            }
+            filtered_instances[instance] = true;
+        }
+        Some(filtered_instances)
+    }
+}
+
+/// A point cloud or box instance which can be selected by dragging around it.
+struct SelectableInstance {
+    instance_path: InstancePath,
+
+    /// Position of the point, or center of the box, in the space of the view.
+    position: glam::Vec3,
+}
+
+/// Collects the visible points and boxes of a view at the current time.
+///
+/// Instances drawn once for each pose of their entity are returned once per pose.
+fn selectable_instances(
+    ctx: &ViewerContext<'_>,
+    query: &ViewQuery<'_>,
+    transforms: &TransformTreeContext,
+    hidden_instances: &HiddenInstances,
+) -> Vec<SelectableInstance> {
+    re_tracing::profile_function!();
+
+    let latest_at = ctx.current_query();
+    let engine = ctx.recording_engine();
+
+    let mut instances = Vec::new();
+    let mut add_instances = |entity_path: &EntityPath, positions: Vec<glam::Vec3>| {
+        let Some(transform_info) = transforms.transform_info_for_entity(entity_path.hash()) else {
+            return;
+        };
+        for world_from_instance in transform_info.target_from_instances() {
+            let world_from_instance = world_from_instance.as_affine3a();
+            for (instance, position) in positions.iter().enumerate() {
+                if hidden_instances.is_hidden(entity_path, instance as u64) {
+                    continue;
+                }
+                instances.push(SelectableInstance {
+                    instance_path: InstancePath::instance(
+                        entity_path.clone(),
+                        Instance::from(instance as u64),
+                    ),
+                    position: world_from_instance.transform_point3(*position),
+                });
+            }
+        }
+    };
+
+    for data_result in query.iter_visible_data_results(Points3DVisualizer::identifier()) {
+        let entity_path = &data_result.entity_path;
+        let results = engine.cache().latest_at(
+            &latest_at,
+            entity_path,
+            Points3D::all_component_identifiers(),
+        );
+        let positions = results
+            .component_batch::<Position3D>(Points3D::descriptor_positions().component)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|position| glam::Vec3::from(position.0))
+            .collect();
+        add_instances(entity_path, positions);
+    }
+
+    for data_result in query.iter_visible_data_results(Boxes3DVisualizer::identifier()) {
+        let entity_path = &data_result.entity_path;
+        let results = engine.cache().latest_at(
+            &latest_at,
+            entity_path,
+            Boxes3D::all_component_identifiers(),
+        );
+        let num_boxes = results
+            .component_batch::<HalfSize3D>(Boxes3D::descriptor_half_sizes().component)
+            .map_or(0, |half_sizes| half_sizes.len());
+        let centers = results
+            .component_batch::<PoseTranslation3D>(Boxes3D::descriptor_centers().component)
+            .unwrap_or_default();
+        let positions = (0..num_boxes.max(centers.len()))
+            .map(|i| {
+                centers
+                    .get(i)
+                    .map_or(glam::Vec3::ZERO, |c| glam::Vec3::from(c.0))
+            })
+            .collect();
+        add_instances(entity_path, positions);
+    }
+
+    instances
+}
+
+/// Selects all points and boxes whose position is inside the dragged shape.
+pub fn select_instances(
+    ctx: &ViewerContext<'_>,
+    query: &ViewQuery<'_>,
+    transforms: &TransformTreeContext,
+    hidden_instances: &HiddenInstances,
+    eye: &Eye,
+    ui_rect: egui::Rect,
+    drag: &SelectionDrag,
+) {
+    re_tracing::profile_function!();
+
+    let ui_from_world = eye.ui_from_world(ui_rect);
+    let selected = selectable_instances(ctx, query, transforms, hidden_instances)
+        .into_iter()
+        .filter(|instance| {
+            let pos_in_ui = ui_from_world * instance.position.extend(1.0);
+            if pos_in_ui.w <= 0.0 {
+                return false; // behind camera
+            }
+            let pos_in_ui = egui::pos2(pos_in_ui.x / pos_in_ui.w, pos_in_ui.y / pos_in_ui.w);
+            ui_rect.contains(pos_in_ui) && drag.contains(pos_in_ui)
+        })
+        .map(|instance| {
+            (
+                Item::DataResult(query.view_id, instance.instance_path),
+                None,
+            )
+        });
+
+    ctx.command_sender()
+        .send_system(SystemCommand::set_selection(
+            ItemCollection::from_items_and_context(selected),
+        ));
+}
+
+/// The selected instances of this view, in the order they were selected.
+fn selected_instances(ctx: &ViewerContext<'_>, view_id: ViewId) -> Vec<InstancePath> {
+    ctx.selection()
+        .iter_items()
+        .filter_map(|item| match item {
+            Item::DataResult(item_view_id, instance_path)
+                if *item_view_id == view_id && instance_path.instance.is_specific() =>
+            {
+                Some(instance_path.clone())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Shows what can be done with the selected instances in a corner of the view.
+///
+/// Nothing is shown if neither instances of this view are selected, nor instances were hidden.
+pub fn selected_instances_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    query: &ViewQuery<'_>,
+    transforms: &TransformTreeContext,
+    hidden_instances: &mut HiddenInstances,
+    show_table: &mut bool,
+    ui_rect: egui::Rect,
+) {
+    let selected = selected_instances(ctx, query.view_id);
+    if selected.is_empty() && hidden_instances.is_empty() {
+        return;
+    }
+
+    // Positions of the selected instances, for the first pose of their entity.
+    let selected_positions = if selected.is_empty() {
+        Vec::new()
+    } else {
+        let selectable = selectable_instances(ctx, query, transforms, hidden_instances);
+        selected
+            .iter()
+            .filter_map(|instance_path| {
+                selectable
+                    .iter()
+                    .find(|selectable| &selectable.instance_path == instance_path)
+                    .map(|selectable| (instance_path, selectable.position))
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let tokens = ui.tokens();
+    let overlay_rect = ui_rect.shrink(f32::from(tokens.view_padding()));
+    ui.scope_builder(egui::UiBuilder::new().max_rect(overlay_rect), |ui| {
+        egui::Frame::popup(ui.style()).show(ui, |ui| {
+            if !selected_positions.is_empty() {
+                ui.horizontal(|ui| {
+                    ui.label(format!(
+                        "{} selected instance{}",
+                        re_format::format_uint(selected_positions.len()),
+                        if selected_positions.len() == 1 {
+                            ""
+                        } else {
+                            "s"
+                        }
+                    ));
+
+                    if ui.small_button("Hide").clicked() {
+                        for (instance_path, _) in &selected_positions {
+                            hidden_instances.hide(instance_path);
+                        }
+                        ctx.command_sender()
+                            .send_system(SystemCommand::set_selection(ItemCollection::default()));
+                    }
+
+                    for (format, label) in [
+                        (ExportFormat::Ply, "Export PLY…"),
+                        (ExportFormat::Csv, "Export CSV…"),
+                    ] {
+                        if ui.small_button(label).clicked() {
+                            export_instances(ctx, query, &selected_positions, format);
+                        }
+                    }
+
+                    ui.re_checkbox(show_table, "Table");
+                });
+            }
+
+            if !hidden_instances.is_empty()
+                && ui
+                    .small_button(format!(
+                        "Show {} hidden instance{}",
+                        re_format::format_uint(hidden_instances.len()),
+                        if hidden_instances.len() == 1 { "" } else { "s" }
+                    ))
+                    .clicked()
+            {
+                hidden_instances.clear();
+            }
+
+            if *show_table && !selected_positions.is_empty() {
+                instance_table_ui(ui, &selected_positions);
+            }
+        });
+    });
+}
+
+fn instance_table_ui(ui: &mut egui::Ui, instances: &[(&InstancePath, glam::Vec3)]) {
+    egui::ScrollArea::vertical()
+        .max_height(200.0)
+        .show(ui, |ui| {
+            egui::Grid::new("selected_instances_table")
+                .num_columns(5)
+                .striped(true)
+                .show(ui, |ui| {
+                    for header in ["Entity", "Instance", "X", "Y", "Z"] {
+                        ui.strong(header);
+                    }
+                    ui.end_row();
+
+                    for (instance_path, position) in instances.iter().take(MAX_TABLE_ROWS) {
+                        ui.label(instance_path.entity_path.to_string());
+                        ui.label(instance_path.instance.to_string());
+                        for coordinate in position.to_array() {
+                            ui.monospace(re_format::format_f32(coordinate));
+                        }
+                        ui.end_row();
+                    }
+                });
+
+            if instances.len() > MAX_TABLE_ROWS {
+                ui.weak(format!(
+                    "…and {} more",
+                    re_format::format_uint(instances.len() - MAX_TABLE_ROWS)
+                ));
+            }
+        });
+}
+
+#[derive(Clone, Copy)]
+enum ExportFormat {
+    Ply,
+    Csv,
+}
+
+/// Asks the user where to save the positions of the selected instances.
+fn export_instances(
+    ctx: &ViewerContext<'_>,
+    query: &ViewQuery<'_>,
+    instances: &[(&InstancePath, glam::Vec3)],
+    format: ExportFormat,
+) {
+    let name = query
+        .space_origin
+        .last()
+        .map_or("selection", |name| name.unescaped_str());
+
+    let (extension, bytes) = match format {
+        ExportFormat::Ply => {
+            let scene = ExportedScene {
+                meshes: Vec::new(),
+                point_clouds: vec![ExportedPoints {
+                    name: name.to_owned(),
+                    positions: instances.iter().map(|(_, position)| *position).collect(),
+                    colors: Vec::new(),
+                }],
+            };
+            ("ply", scene.to_bytes(SceneExportFormat::Ply))
+        }
+        ExportFormat::Csv => ("csv", instances_to_csv(instances).into_bytes()),
+    };
+
+    ctx.command_sender().save_file_dialog(
+        re_capabilities::MainThreadToken::i_promise_i_am_on_the_main_thread(),
+        &format!("{name}_selection.{extension}"),
+        format!("Export selected instances as {}", extension.to_uppercase()),
+        bytes,
+    );
+}
+
+fn instances_to_csv(instances: &[(&InstancePath, glam::Vec3)]) -> String {
+    let mut csv = "entity_path,instance,x,y,z\n".to_owned();
+    for (instance_path, position) in instances {
+        csv += &format!(
+            "\"{}\",{},{},{},{}\n",
+            instance_path.entity_path.to_string().replace('"', "\"\""),
+            instance_path.instance.get(),
+            position.x,
+            position.y,
+            position.z
+        );
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn modifiers() {
+        assert_eq!(SelectionShape::from_modifiers(Modifiers::NONE), None);
+        assert_eq!(
+            SelectionShape::from_modifiers(Modifiers::COMMAND),
+            Some(SelectionShape::Rect)
+        );
+        assert_eq!(
+            SelectionShape::from_modifiers(Modifiers::COMMAND | Modifiers::SHIFT),
+            Some(SelectionShape::Lasso)
+        );
+        assert_eq!(
+            SelectionShape::from_modifiers(Modifiers::COMMAND | Modifiers::ALT),
+            None
+        );
+    }
+
+    #[test]
+    fn rect() {
+        let mut drag = SelectionDrag::new(SelectionShape::Rect, egui::pos2(10.0, 10.0));
+        drag.extend(egui::pos2(50.0, 50.0));
+        drag.extend(egui::pos2(0.0, 30.0));
+
+        assert!(drag.contains(egui::pos2(5.0, 20.0)));
+        assert!(!drag.contains(egui::pos2(30.0, 20.0)));
+    }
+
+    #[test]
+    fn lasso() {
+        // An L-shaped lasso.
+        let mut drag = SelectionDrag::new(SelectionShape::Lasso, egui::pos2(0.0, 0.0));
+        for pos in [
+            (10.0, 0.0),
+            (10.0, 10.0),
+            (20.0, 10.0),
+            (20.0, 20.0),
+            (0.0, 20.0),
+        ] {
+            drag.extend(egui::pos2(pos.0, pos.1));
+        }
+        // Too close to the previous point to be added.
+        drag.extend(egui::pos2(0.5, 20.0));
+
+        assert_eq!(drag.points.len(), 6);
+        assert!(drag.contains(egui::pos2(5.0, 5.0)));
+        assert!(drag.contains(egui::pos2(15.0, 15.0)));
+        assert!(!drag.contains(egui::pos2(15.0, 5.0)));
+        assert!(!drag.contains(egui::pos2(25.0, 15.0)));
+    }
+
+    #[test]
+    fn hidden_instances() {
+        let points = EntityPath::from("points");
+        let mut hidden = HiddenInstances::default();
+        assert_eq!(hidden.add_to_filtered(&points, None), None);
+
+        hidden.hide(&InstancePath::instance(points.clone(), Instance::from(3)));
+        hidden.hide(&InstancePath::entity_all(points.clone()));
+        assert_eq!(hidden.len(), 1);
+
+        assert_eq!(
+            hidden.add_to_filtered(&points, None),
+            Some(vec![false, false, false, true])
+        );
+        assert_eq!(
+            hidden.add_to_filtered(&points, Some(vec![true, false, false, false, true])),
+            Some(vec![true, false, false, true, true])
+        );
+        assert_eq!(
+            hidden.add_to_filtered(&EntityPath::from("other"), Some(vec![true])),
+            Some(vec![true])
+        );
+    }
+
+    #[test]
+    fn csv() {
+        let instance_path = InstancePath::instance(EntityPath::from("points"), Instance::from(2));
+        assert_eq!(
+            instances_to_csv(&[(&instance_path, glam::vec3(1.0, 2.5, -3.0))]),
+            "entity_path,instance,x,y,z\n\"/points\",2,1,2.5,-3\n"
+        );
+    }
+}
@@ -3,21 +3,35 @@
 //! Views that show entities in a 2D or 3D spatial relationship.
 
 mod caches;
+mod color_by_component;
 mod contexts;
+mod coordinate_conversion;
+mod crop_box;
+mod depth_clouds;
 mod eye;
 mod heuristics;
+mod hover_correlation;
+mod image_planes;
+mod instance_filter;
+mod instance_selection;
 mod max_image_dimension_subscriber;
 mod mesh_loader;
+mod onion_skin;
+mod outlines;
 mod pickable_textured_rect;
 mod picking;
 mod picking_ui;
 mod picking_ui_pixel;
 mod pinhole;
 mod proc_mesh;
+mod rigid_alignment;
 mod scene_bounding_boxes;
+mod scene_export;
 mod shared_fallbacks;
 mod space_camera_3d;
 mod spatial_topology;
+mod trajectory_metrics;
+mod transform_tree_panel;
 mod ui;
 mod ui_2d;
 mod ui_3d;
@@ -25,6 +39,8 @@ mod view_2d;
 mod view_3d;
 mod visualizers;
 
+pub use trajectory_metrics::{ErrorStatistics, TrajectoryErrors, trajectory_errors};
+pub use transform_tree_panel::transform_tree_panel_ui;
 pub use ui::SpatialViewState;
 pub use view_2d::SpatialView2D;
 pub use view_3d::SpatialView3D;
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use itertools::Itertools as _;
 
 use re_renderer::{RenderContext, mesh::GpuMesh};
@@ -10,7 +12,7 @@ use crate::{caches::AnyMesh, visualizers::entity_iterator::clamped_vec_or};
 
 #[derive(Debug, Clone)]
 pub struct NativeAsset3D<'a> {
-    pub bytes: &'a [u8],
+    pub blob: &'a datatypes::Blob,
     pub media_type: Option<MediaType>,
     pub albedo_factor: Option<re_renderer::Rgba>,
 }
@@ -30,6 +32,21 @@ pub struct NativeMesh3D<'a> {
     pub albedo_texture_format: Option<datatypes::ImageFormat>,
 }
 
+/// A 2D scalar tensor shown as a grid of colored cells in 3D.
+///
+/// Every cell is one unit large, starting at the origin,
+/// so the cell size and placement of the grid are given by the entity's transform.
+#[derive(Debug, Clone)]
+pub struct NativeTensorGrid<'a> {
+    pub tensor: &'a datatypes::TensorData,
+
+    /// Values in this range are mapped to the colormap, values outside of it are clamped.
+    pub value_range: [f64; 2],
+
+    /// If true, the value of each cell is used as its height, otherwise the grid is flat.
+    pub height_map: bool,
+}
+
 pub struct LoadedMesh {
     name: String,
 
@@ -37,9 +54,64 @@ pub struct LoadedMesh {
     // Can't do that right now because it's too hard to pass the render context through.
     pub mesh_instances: Vec<re_renderer::renderer::GpuMeshInstance>,
 
+    /// Skins, morph targets, and animations, if the asset has any.
+    pub rig: Option<LoadedRig>,
+
     bbox: macaw::BoundingBox,
 }
 
+/// A [`re_renderer::importer::ModelRig`] whose meshes are already on the GPU.
+pub struct LoadedRig {
+    pub rig: re_renderer::importer::ModelRig,
+    gpu_meshes: ahash::HashMap<re_renderer::CpuModelMeshKey, Arc<GpuMesh>>,
+}
+
+impl LoadedRig {
+    /// Instantiates the meshes in the given pose, uploading the vertices of skinned and morphed
+    /// meshes.
+    ///
+    /// Also returns the bounding box of the posed model.
+    pub fn mesh_instances(
+        &self,
+        pose: &re_renderer::importer::Pose,
+        render_ctx: &RenderContext,
+    ) -> (
+        Vec<re_renderer::renderer::GpuMeshInstance>,
+        macaw::BoundingBox,
+    ) {
+        re_tracing::profile_function!();
+
+        let mut bbox = macaw::BoundingBox::nothing();
+        let instances = self
+            .rig
+            .posed_instances(pose)
+            .into_iter()
+            .filter_map(|instance| {
+                let mut gpu_mesh = self.gpu_meshes.get(&instance.mesh)?.clone();
+                if let Some(vertices) = &instance.deformed_vertices {
+                    match gpu_mesh.with_deformed_vertices(
+                        render_ctx,
+                        &vertices.positions,
+                        &vertices.normals,
+                        vertices.bbox,
+                    ) {
+                        Ok(deformed_mesh) => gpu_mesh = Arc::new(deformed_mesh),
+                        Err(err) => re_log::warn_once!("Failed to deform mesh: {err}"),
+                    }
+                }
+
+                bbox = bbox.union(gpu_mesh.bbox.transform_affine3(&instance.world_from_mesh));
+
+                let mut mesh_instance = re_renderer::renderer::GpuMeshInstance::new(gpu_mesh);
+                mesh_instance.world_from_mesh = instance.world_from_mesh;
+                Some(mesh_instance)
+            })
+            .collect();
+
+        (instances, bbox)
+    }
+}
+
 impl re_byte_size::SizeBytes for LoadedMesh {
     fn heap_size_bytes(&self) -> u64 {
         0 // Mostly VRAM, not counted here.
@@ -54,10 +126,11 @@ impl LoadedMesh {
     ) -> anyhow::Result<Self> {
         // TODO(emilk): load CpuMesh in background thread.
         match mesh {
-            AnyMesh::Asset { asset } => Ok(Self::load_asset3d(name, asset, render_ctx)?),
+            AnyMesh::Asset { asset, .. } => Ok(Self::load_asset3d(name, asset, render_ctx)?),
             AnyMesh::Mesh { mesh, texture_key } => {
                 Ok(Self::load_mesh3d(name, mesh, texture_key, render_ctx)?)
             }
+            AnyMesh::TensorGrid { grid } => Ok(Self::load_tensor_grid(name, grid, render_ctx)?),
         }
     }
 
@@ -69,23 +142,40 @@ impl LoadedMesh {
         re_tracing::profile_function!();
 
         let NativeAsset3D {
-            bytes,
+            blob,
             media_type,
             albedo_factor,
         } = asset;
 
+        let bytes: &[u8] = blob;
         let media_type = MediaType::or_guess_from_data(media_type, bytes)
             .ok_or_else(|| anyhow::anyhow!("couldn't guess media type"))?;
 
-        let mut cpu_model = match media_type.as_str() {
+        let (cpu_model, rig) = match media_type.as_str() {
             MediaType::GLTF | MediaType::GLB => {
-                re_renderer::importer::gltf::load_gltf_from_buffer(&name, bytes, render_ctx)?
+                re_renderer::importer::gltf::load_rigged_gltf_from_buffer(&name, bytes, render_ctx)?
             }
-            MediaType::OBJ => re_renderer::importer::obj::load_obj_from_buffer(bytes, render_ctx)?,
-            MediaType::STL => re_renderer::importer::stl::load_stl_from_buffer(bytes, render_ctx)?,
+            MediaType::OBJ => (
+                re_renderer::importer::obj::load_obj_from_buffer(bytes, render_ctx)?,
+                None,
+            ),
+            MediaType::STL => (
+                re_renderer::importer::stl::load_stl_from_buffer(bytes, render_ctx)?,
+                None,
+            ),
             _ => anyhow::bail!("{media_type} files are not supported"),
         };
 
+        Self::from_cpu_model(name, cpu_model, rig, albedo_factor, render_ctx)
+    }
+
+    fn from_cpu_model(
+        name: String,
+        mut cpu_model: re_renderer::CpuModel,
+        rig: Option<re_renderer::importer::ModelRig>,
+        albedo_factor: Option<re_renderer::Rgba>,
+        render_ctx: &RenderContext,
+    ) -> anyhow::Result<Self> {
         // Overwriting albedo_factor of CpuMesh if specified in the Asset3D
         if let Some(albedo_factor) = albedo_factor {
             for instance in &cpu_model.instances {
@@ -96,12 +186,29 @@ impl LoadedMesh {
         }
 
         let bbox = cpu_model.bbox;
-        let mesh_instances = cpu_model.into_gpu_meshes(render_ctx)?;
+
+        let Some(rig) = rig else {
+            let mesh_instances = cpu_model.into_gpu_meshes(render_ctx)?;
+            return Ok(Self {
+                name,
+                bbox,
+                mesh_instances,
+                rig: None,
+            });
+        };
+
+        // Rigged meshes are instantiated anew for every pose.
+        let rig = LoadedRig {
+            gpu_meshes: cpu_model.upload_meshes(render_ctx)?.into_iter().collect(),
+            rig,
+        };
+        let (mesh_instances, bbox) = rig.mesh_instances(&rig.rig.rest_pose(), render_ctx);
 
         Ok(Self {
             name,
             bbox,
             mesh_instances,
+            rig: Some(rig),
         })
     }
 
@@ -209,10 +316,153 @@ impl LoadedMesh {
             name,
             bbox,
             mesh_instances,
+            rig: None,
+        })
+    }
+
+    fn load_tensor_grid(
+        name: String,
+        grid: NativeTensorGrid<'_>,
+        render_ctx: &RenderContext,
+    ) -> anyhow::Result<Self> {
+        re_tracing::profile_function!();
+
+        let NativeTensorGrid {
+            tensor,
+            value_range: [min, max],
+            height_map,
+        } = grid;
+
+        let &[height, width] = tensor.shape_short() else {
+            anyhow::bail!("expected a 2D tensor, got shape {:?}", tensor.shape());
+        };
+        let (height, width) = (height as usize, width as usize);
+        let values = tensor_values_f64(&tensor.buffer);
+        anyhow::ensure!(values.len() == width * height);
+
+        let color = |value: f64| {
+            let t = if min < max {
+                ((value - min) / (max - min)).clamp(0.0, 1.0)
+            } else {
+                0.5
+            };
+            let [r, g, b, a] = re_renderer::colormap_srgb(re_renderer::Colormap::Viridis, t as f32);
+            re_renderer::Rgba32Unmul::from_rgba_unmul_array([r, g, b, a])
+        };
+
+        let mut vertex_positions = Vec::new();
+        let mut vertex_colors = Vec::new();
+        let mut vertex_normals = Vec::new();
+        let mut triangle_indices = Vec::new();
+
+        if height_map {
+            re_tracing::profile_scope!("height_map");
+
+            // One vertex at the center of every cell. Cells without a finite value leave a hole.
+            let height_at = |row: usize, col: usize| {
+                let value = values[row * width + col];
+                if value.is_finite() { value as f32 } else { 0.0 }
+            };
+            for row in 0..height {
+                for col in 0..width {
+                    vertex_positions.push(glam::vec3(
+                        col as f32 + 0.5,
+                        row as f32 + 0.5,
+                        height_at(row, col),
+                    ));
+                    vertex_colors.push(color(values[row * width + col]));
+
+                    // Central differences, one-sided at the border.
+                    let (left, right) = (col.saturating_sub(1), (col + 1).min(width - 1));
+                    let (top, bottom) = (row.saturating_sub(1), (row + 1).min(height - 1));
+                    let dz_dx = (height_at(row, right) - height_at(row, left))
+                        / (right - left).max(1) as f32;
+                    let dz_dy = (height_at(bottom, col) - height_at(top, col))
+                        / (bottom - top).max(1) as f32;
+                    vertex_normals.push(glam::vec3(-dz_dx, -dz_dy, 1.0).normalize());
+                }
+            }
+
+            for row in 0..height.saturating_sub(1) {
+                for col in 0..width.saturating_sub(1) {
+                    let top_left = row * width + col;
+                    let corners = [
+                        top_left,
+                        top_left + 1,
+                        top_left + width,
+                        top_left + width + 1,
+                    ];
+                    if corners.iter().all(|&i| values[i].is_finite()) {
+                        let [a, b, c, d] = corners.map(|i| i as u32);
+                        triangle_indices.push(glam::uvec3(a, b, c));
+                        triangle_indices.push(glam::uvec3(b, d, c));
+                    }
+                }
+            }
+        } else {
+            re_tracing::profile_scope!("flat_grid");
+
+            // Four vertices per cell, so that every cell has a single color.
+            for (i, &value) in values.iter().enumerate() {
+                if !value.is_finite() {
+                    continue;
+                }
+                let (x, y) = ((i % width) as f32, (i / width) as f32);
+                let first = vertex_positions.len() as u32;
+                vertex_positions.extend([
+                    glam::vec3(x, y, 0.0),
+                    glam::vec3(x + 1.0, y, 0.0),
+                    glam::vec3(x, y + 1.0, 0.0),
+                    glam::vec3(x + 1.0, y + 1.0, 0.0),
+                ]);
+                vertex_colors.extend([color(value); 4]);
+                // Unshaded, so that the colors are exactly those of the colormap.
+                vertex_normals.extend([glam::Vec3::ZERO; 4]);
+                triangle_indices.push(glam::uvec3(first, first + 1, first + 2));
+                triangle_indices.push(glam::uvec3(first + 1, first + 3, first + 2));
+            }
+        }
+
+        anyhow::ensure!(
+            !triangle_indices.is_empty(),
+            "the tensor doesn't have enough finite values"
+        );
+
+        let num_positions = vertex_positions.len();
+        let num_indices = triangle_indices.len() * 3;
+        let bbox = macaw::BoundingBox::from_points(vertex_positions.iter().copied());
+
+        let mesh = re_renderer::mesh::CpuMesh {
+            label: name.clone().into(),
+            triangle_indices,
+            vertex_positions,
+            vertex_colors,
+            vertex_normals,
+            vertex_texcoords: vec![glam::Vec2::ZERO; num_positions],
+            materials: smallvec::smallvec![re_renderer::mesh::Material {
+                label: name.clone().into(),
+                index_range: 0..num_indices as _,
+                albedo: render_ctx
+                    .texture_manager_2d
+                    .white_texture_unorm_handle()
+                    .clone(),
+                albedo_factor: re_renderer::Rgba::WHITE,
+            }],
+            bbox,
+        };
+
+        let mesh_instances = vec![re_renderer::renderer::GpuMeshInstance::new(
+            std::sync::Arc::new(GpuMesh::new(render_ctx, &mesh)?),
+        )];
+
+        Ok(Self {
+            name,
+            bbox,
+            mesh_instances,
+            rig: None,
         })
     }
 
-    #[expect(dead_code)]
     pub fn name(&self) -> &str {
         &self.name
     }
@@ -222,6 +472,206 @@ impl LoadedMesh {
     }
 }
 
+/// Assets at least this large are parsed on a background thread, so they don't block the viewer.
+const BACKGROUND_LOAD_MIN_BYTES: usize = 32 * 1024 * 1024;
+
+/// Binary STL assets loaded in the background show this many triangles until they are fully parsed.
+const PREVIEW_MAX_TRIANGLES: usize = 250_000;
+
+/// What the background thread has parsed so far.
+#[derive(Default)]
+struct ParsedAsset {
+    preview: Option<re_renderer::CpuModel>,
+    full: Option<anyhow::Result<ParsedModel>>,
+}
+
+/// A fully parsed asset, waiting to be uploaded.
+enum ParsedModel {
+    Cpu(re_renderer::CpuModel),
+
+    /// The textures and meshes of a gltf are only created on upload, since that needs the render context.
+    Gltf(re_renderer::importer::gltf::ParsedGltf),
+}
+
+/// See [`BackgroundAssetLoad::poll`].
+pub enum AssetLoadProgress {
+    /// A coarse version of the mesh to show until it is fully loaded.
+    Preview(LoadedMesh),
+
+    /// The mesh is fully loaded, or failed to load.
+    Done(anyhow::Result<LoadedMesh>),
+}
+
+/// A large asset being parsed on a background thread.
+///
+/// For glTF and GLB assets, only the decoding happens in the background:
+/// their textures and meshes are created when uploading, which needs the render context.
+/// Not available on the web, where we have no threads.
+pub struct BackgroundAssetLoad {
+    name: String,
+    albedo_factor: Option<re_renderer::Rgba>,
+
+    /// Size of the asset being parsed.
+    num_bytes: u64,
+
+    parsed: Arc<parking_lot::Mutex<ParsedAsset>>,
+}
+
+impl BackgroundAssetLoad {
+    /// Starts parsing the asset on a background thread, if it is large enough to be worth it.
+    ///
+    /// Returns `None` if the asset should be loaded right away instead.
+    /// The egui context is repainted whenever there is something new to show.
+    pub fn start(
+        name: &str,
+        asset: &NativeAsset3D<'_>,
+        egui_ctx: &egui::Context,
+        render_ctx: &RenderContext,
+    ) -> Option<Self> {
+        if cfg!(target_arch = "wasm32") || asset.blob.len() < BACKGROUND_LOAD_MIN_BYTES {
+            return None;
+        }
+
+        let media_type = MediaType::or_guess_from_data(asset.media_type.clone(), asset.blob)?;
+        if ![
+            MediaType::obj(),
+            MediaType::stl(),
+            MediaType::gltf(),
+            MediaType::glb(),
+        ]
+        .contains(&media_type)
+        {
+            return None;
+        }
+
+        let parsed = Arc::new(parking_lot::Mutex::new(ParsedAsset::default()));
+        let blob = asset.blob.clone();
+        let albedo = render_ctx
+            .texture_manager_2d
+            .white_texture_unorm_handle()
+            .clone();
+        let egui_ctx = egui_ctx.clone();
+
+        let spawn_result = std::thread::Builder::new()
+            .name(format!("load_asset({name})"))
+            .spawn({
+                let parsed = parsed.clone();
+                move || {
+                    if media_type == MediaType::stl()
+                        && let Some(Ok(preview)) =
+                            re_renderer::importer::stl::cpu_model_preview_from_binary_stl(
+                                &blob,
+                                PREVIEW_MAX_TRIANGLES,
+                                &albedo,
+                            )
+                    {
+                        parsed.lock().preview = Some(preview);
+                        egui_ctx.request_repaint();
+                    }
+
+                    let full = if media_type == MediaType::stl() {
+                        re_renderer::importer::stl::cpu_model_from_stl(&blob, &albedo)
+                            .map(ParsedModel::Cpu)
+                            .map_err(Into::into)
+                    } else if media_type == MediaType::obj() {
+                        re_renderer::importer::obj::cpu_model_from_obj(&blob, &albedo)
+                            .map(ParsedModel::Cpu)
+                            .map_err(Into::into)
+                    } else {
+                        re_renderer::importer::gltf::parse_gltf(&blob)
+                            .map(ParsedModel::Gltf)
+                            .map_err(Into::into)
+                    };
+                    parsed.lock().full = Some(full);
+                    egui_ctx.request_repaint();
+                }
+            });
+
+        if let Err(err) = spawn_result {
+            re_log::warn_once!("Failed to spawn thread for loading {name:?}: {err}");
+            return None;
+        }
+
+        Some(Self {
+            name: name.to_owned(),
+            albedo_factor: asset.albedo_factor,
+            num_bytes: asset.blob.len() as u64,
+            parsed,
+        })
+    }
+
+    /// Uploads whatever was parsed since the last call, if anything.
+    pub fn poll(&self, render_ctx: &RenderContext) -> Option<AssetLoadProgress> {
+        let mut parsed = self.parsed.lock();
+        if let Some(full) = parsed.full.take() {
+            parsed.preview = None;
+            drop(parsed);
+
+            re_log::trace!("Uploading mesh {:?}…", self.name);
+            return Some(AssetLoadProgress::Done(
+                full.and_then(|parsed| self.upload_parsed(parsed, render_ctx)),
+            ));
+        }
+
+        let preview = parsed.preview.take()?;
+        drop(parsed);
+
+        re_log::trace!("Uploading preview of mesh {:?}…", self.name);
+        match self.upload(preview, None, render_ctx) {
+            Ok(mesh) => Some(AssetLoadProgress::Preview(mesh)),
+            Err(err) => {
+                re_log::debug!("Failed to upload preview of mesh {:?}: {err}", self.name);
+                None
+            }
+        }
+    }
+
+    fn upload_parsed(
+        &self,
+        parsed: ParsedModel,
+        render_ctx: &RenderContext,
+    ) -> anyhow::Result<LoadedMesh> {
+        match parsed {
+            ParsedModel::Cpu(cpu_model) => self.upload(cpu_model, None, render_ctx),
+            ParsedModel::Gltf(gltf) => {
+                let (cpu_model, rig) = re_renderer::importer::gltf::load_rigged_gltf_from_parsed(
+                    &self.name, gltf, render_ctx,
+                )?;
+                self.upload(cpu_model, rig, render_ctx)
+            }
+        }
+    }
+
+    fn upload(
+        &self,
+        cpu_model: re_renderer::CpuModel,
+        rig: Option<re_renderer::importer::ModelRig>,
+        render_ctx: &RenderContext,
+    ) -> anyhow::Result<LoadedMesh> {
+        LoadedMesh::from_cpu_model(
+            self.name.clone(),
+            cpu_model,
+            rig,
+            self.albedo_factor,
+            render_ctx,
+        )
+    }
+
+    /// True once the asset is fully parsed, and only waiting for [`Self::poll`] to upload it.
+    pub fn is_parsed(&self) -> bool {
+        self.parsed.lock().full.is_some()
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Size of the asset, which the background thread holds on to while parsing.
+    pub fn num_bytes(&self) -> u64 {
+        self.num_bytes
+    }
+}
+
 fn try_get_or_create_albedo_texture(
     albedo_texture_buffer: &Option<datatypes::Blob>,
     albedo_texture_format: &Option<datatypes::ImageFormat>,
@@ -274,3 +724,26 @@ fn try_get_or_create_albedo_texture(
         }
     }
 }
+
+/// All values of a tensor buffer in memory order.
+fn tensor_values_f64(buffer: &datatypes::TensorBuffer) -> Vec<f64> {
+    use datatypes::TensorBuffer;
+    use re_types::tensor_data::TensorElement;
+
+    match buffer {
+        TensorBuffer::U8(buf) => buf.iter().map(|&v| f64::from(v)).collect(),
+        TensorBuffer::U16(buf) => buf.iter().map(|&v| f64::from(v)).collect(),
+        TensorBuffer::U32(buf) => buf.iter().map(|&v| f64::from(v)).collect(),
+        TensorBuffer::U64(buf) => buf.iter().map(|&v| v as f64).collect(),
+        TensorBuffer::I8(buf) => buf.iter().map(|&v| f64::from(v)).collect(),
+        TensorBuffer::I16(buf) => buf.iter().map(|&v| f64::from(v)).collect(),
+        TensorBuffer::I32(buf) => buf.iter().map(|&v| f64::from(v)).collect(),
+        TensorBuffer::I64(buf) => buf.iter().map(|&v| v as f64).collect(),
+        TensorBuffer::F16(buf) => buf
+            .iter()
+            .map(|&v| TensorElement::F16(v).as_f64())
+            .collect(),
+        TensorBuffer::F32(buf) => buf.iter().map(|&v| f64::from(v)).collect(),
+        TensorBuffer::F64(buf) => buf.to_vec(),
+    }
+}
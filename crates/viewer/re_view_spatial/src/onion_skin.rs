@@ -0,0 +1,173 @@
+use re_chunk_store::LatestAtQuery;
+use re_entity_db::EntityDb;
+use re_log_types::{EntityPath, TimeInt};
+use re_tf::{TransformFrameIdHash, TransformsForChildFrame};
+use re_types::{
+    ComponentIdentifier,
+    blueprint::{archetypes::OnionSkin, components::OnionSkinSteps},
+    components::Opacity,
+};
+use re_viewer_context::ViewContext;
+use re_viewport_blueprint::ViewProperty;
+
+use crate::caches::TransformDatabaseStoreCache;
+
+/// Opacity of the most recent earlier state if none was set.
+pub const DEFAULT_OPACITY: f32 = 0.5;
+
+/// An earlier state of an entity shown by the onion skin.
+#[derive(Clone, Copy, Debug)]
+pub struct EarlierState {
+    /// Time at which the state started.
+    pub time: TimeInt,
+
+    /// Opacity the state is drawn with.
+    pub opacity: f32,
+
+    /// Moves the entity from where it is now to where it was at [`Self::time`].
+    ///
+    /// Identity if the entity's own transform didn't change.
+    pub entity_from_earlier_entity: glam::DAffine3,
+}
+
+/// Shows earlier states of entities as translucent ghosts.
+///
+/// Read from the [`OnionSkin`] view property.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OnionSkinSettings {
+    /// How many earlier states are shown per entity.
+    pub steps: usize,
+
+    /// Opacity of the most recent earlier state.
+    pub opacity: f32,
+}
+
+impl OnionSkinSettings {
+    /// Returns `None` if the onion skin is disabled, i.e. shows no earlier states.
+    pub fn from_view(ctx: &ViewContext<'_>) -> Option<Self> {
+        let property = ViewProperty::from_archetype::<OnionSkin>(
+            ctx.blueprint_db(),
+            ctx.blueprint_query(),
+            ctx.view_id,
+        );
+
+        let steps = property
+            .component_or_fallback::<OnionSkinSteps>(ctx, OnionSkin::descriptor_steps().component)
+            .map_or(0, |steps| steps.0.0 as usize);
+        if steps == 0 {
+            return None;
+        }
+
+        let opacity = property
+            .component_or_fallback::<Opacity>(ctx, OnionSkin::descriptor_opacity().component)
+            .map_or(DEFAULT_OPACITY, |opacity| opacity.0.0.clamp(0.0, 1.0));
+
+        Some(Self { steps, opacity })
+    }
+
+    /// Opacity of the `step`-th earlier state, starting at 0 for the most recent one.
+    ///
+    /// Older states fade out linearly.
+    #[inline]
+    pub fn opacity_for_step(&self, step: usize) -> f32 {
+        self.opacity * (1.0 - step as f32 / self.steps as f32)
+    }
+
+    /// Returns the earlier states of an entity, most recent first.
+    ///
+    /// A new state starts whenever any of the given components or the entity's own transform changed.
+    pub fn earlier_states(
+        &self,
+        ctx: &ViewContext<'_>,
+        query: &LatestAtQuery,
+        entity_path: &EntityPath,
+        components: &[ComponentIdentifier],
+    ) -> Vec<EarlierState> {
+        let entity_db = ctx.recording();
+        let transform_cache = ctx
+            .viewer_ctx
+            .store_context
+            .caches
+            .entry(|c: &mut TransformDatabaseStoreCache| c.lock_transform_cache(entity_db));
+        let frame_transforms = transform_cache
+            .transforms_for_timeline(query.timeline())
+            .frame_transforms(TransformFrameIdHash::from_entity_path(entity_path));
+        let parent_from_entity = |query: &LatestAtQuery| {
+            frame_transforms
+                .and_then(|frame_transforms| frame_transforms.latest_at_transform(entity_db, query))
+                .map(|transform| transform.transform)
+        };
+        let parent_from_entity_now = parent_from_entity(query);
+
+        self.earlier_state_times(entity_db, query, entity_path, components, frame_transforms)
+            .into_iter()
+            .enumerate()
+            .map(|(step, time)| {
+                let parent_from_earlier_entity =
+                    parent_from_entity(&LatestAtQuery::new(query.timeline(), time));
+                let entity_from_earlier_entity =
+                    match (parent_from_entity_now, parent_from_earlier_entity) {
+                        (Some(now), Some(earlier)) => now.inverse() * earlier,
+                        _ => glam::DAffine3::IDENTITY,
+                    };
+
+                EarlierState {
+                    time,
+                    opacity: self.opacity_for_step(step),
+                    entity_from_earlier_entity,
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the times at which the earlier states of an entity start, most recent first.
+    ///
+    /// Static data doesn't change over time and is therefore never shown as an earlier state.
+    fn earlier_state_times(
+        &self,
+        entity_db: &EntityDb,
+        query: &LatestAtQuery,
+        entity_path: &EntityPath,
+        components: &[ComponentIdentifier],
+        frame_transforms: Option<&TransformsForChildFrame>,
+    ) -> Vec<TimeInt> {
+        re_tracing::profile_function!();
+
+        let state_start = |time: TimeInt| {
+            let data_time = entity_db
+                .latest_at(
+                    &LatestAtQuery::new(query.timeline(), time),
+                    entity_path,
+                    components.iter().copied(),
+                )
+                .index()
+                .0;
+            let transform_time = frame_transforms
+                .and_then(|frame_transforms| frame_transforms.latest_frame_transform_time_at(time));
+
+            std::iter::once(data_time)
+                .chain(transform_time)
+                .filter(|time| !time.is_static())
+                .max()
+        };
+
+        let mut times = Vec::with_capacity(self.steps);
+        let mut time = query.at();
+        while times.len() < self.steps {
+            let Some(start) = state_start(time) else {
+                break;
+            };
+            let Some(earlier_start) = state_start(start.dec()) else {
+                break;
+            };
+            if start <= earlier_start {
+                // Reached the beginning of the timeline.
+                break;
+            }
+            times.push(earlier_start);
+            time = earlier_start;
+        }
+
+        times
+    }
+}
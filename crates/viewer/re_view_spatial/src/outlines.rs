@@ -0,0 +1,152 @@
+use egui::NumExt as _;
+use re_types::{
+    Component as _,
+    blueprint::{archetypes::Outlines, components::QueryExpression},
+    components::{Color, StrokeWidth},
+};
+use re_ui::{UiExt as _, list_item};
+use re_viewer_context::{ViewContext, ViewHighlights};
+use re_viewport_blueprint::ViewProperty;
+
+/// Produce the [`re_renderer::OutlineConfig`] of a spatial view, read from its [`Outlines`] view property.
+///
+/// Returns `None` if nothing in the view has an outline.
+///
+/// Channel A is used for hovered objects, unless the view has entities that are always outlined,
+/// see [`ViewHighlights::any_persistent_highlights`].
+pub fn outline_config(
+    ctx: &ViewContext<'_>,
+    highlights: &ViewHighlights,
+) -> Option<re_renderer::OutlineConfig> {
+    if !highlights.any_outlines() {
+        return None;
+    }
+
+    let property = ViewProperty::from_archetype::<Outlines>(
+        ctx.blueprint_db(),
+        ctx.blueprint_query(),
+        ctx.view_id,
+    );
+    let mut config = re_view::outline_config(ctx.egui_ctx());
+
+    if let Ok(stroke_width) = property
+        .component_or_fallback::<StrokeWidth>(ctx, Outlines::descriptor_stroke_width().component)
+    {
+        // See also: SIZE_BOOST_IN_POINTS_FOR_LINE_OUTLINES
+        config.outline_radius_pixel =
+            (ctx.egui_ctx().pixels_per_point() * 0.5 * stroke_width.0.0).at_least(0.5);
+    }
+
+    let layer_a_color = if highlights.any_persistent_highlights {
+        Outlines::descriptor_highlight_color()
+    } else {
+        Outlines::descriptor_hover_color()
+    };
+    if let Ok(color) = property.component_or_fallback::<Color>(ctx, layer_a_color.component) {
+        config.color_layer_a = color.into();
+    }
+    if let Ok(color) = property
+        .component_or_fallback::<Color>(ctx, Outlines::descriptor_selection_color().component)
+    {
+        config.color_layer_b = color.into();
+    }
+
+    Some(config)
+}
+
+/// Like the generic property ui, but with a text editor for the query expressions of highlighted entities.
+pub fn view_property_ui_outlines(ctx: &ViewContext<'_>, ui: &mut egui::Ui) {
+    let property = ViewProperty::from_archetype::<Outlines>(
+        ctx.blueprint_db(),
+        ctx.blueprint_query(),
+        ctx.view_id,
+    );
+    let reflection = ctx.viewer_ctx.reflection();
+    let Some(reflection) = reflection.archetypes.get(&property.archetype_name) else {
+        ui.error_label(format!(
+            "Missing reflection data for archetype {:?}.",
+            property.archetype_name
+        ));
+        return;
+    };
+
+    let query_ctx = property.query_context(ctx);
+    let sub_prop_ui = |ui: &mut egui::Ui| {
+        for field in &reflection.fields {
+            if field.component_type == QueryExpression::name() {
+                re_view::view_property_component_ui_custom(
+                    &query_ctx,
+                    ui,
+                    &property,
+                    field.display_name,
+                    field,
+                    &|ui| highlighted_expressions_ui(ctx, ui, &property),
+                    None, // No multiline editor.
+                );
+            } else {
+                re_view::view_property_component_ui(
+                    &query_ctx,
+                    ui,
+                    &property,
+                    field.display_name,
+                    field,
+                );
+            }
+        }
+    };
+
+    ui.list_item()
+        .interactive(false)
+        .show_hierarchical_with_children(
+            ui,
+            ui.make_persistent_id(property.archetype_name.full_name()),
+            true,
+            list_item::LabelContent::new(reflection.display_name),
+            sub_prop_ui,
+        );
+}
+
+/// One query expression per line, saved once the text field loses focus.
+fn highlighted_expressions_ui(ctx: &ViewContext<'_>, ui: &mut egui::Ui, property: &ViewProperty) {
+    let expressions = property
+        .component_array_or_empty::<QueryExpression>(Outlines::descriptor_highlighted().component)
+        .unwrap_or_default();
+
+    // Keep the text while it's edited, so that empty lines aren't dropped right away.
+    let edit_id = ui.id().with("outline_highlighted_expressions");
+    let mut text = ui
+        .data_mut(|data| data.get_temp::<String>(edit_id))
+        .unwrap_or_else(|| {
+            expressions
+                .iter()
+                .map(|expression| expression.0.as_str())
+                .collect::<Vec<_>>()
+                .join("\n")
+        });
+
+    let response = ui.add(
+        egui::TextEdit::multiline(&mut text)
+            .desired_rows(1)
+            .hint_text("+ /world/ground_truth/**"),
+    );
+
+    if response.lost_focus() {
+        ui.data_mut(|data| data.remove::<String>(edit_id));
+
+        let new_expressions = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(QueryExpression::from)
+            .collect::<Vec<_>>();
+        if new_expressions != expressions {
+            property.save_blueprint_component(
+                ctx.viewer_ctx,
+                &Outlines::descriptor_highlighted(),
+                &new_expressions,
+            );
+        }
+    } else if response.has_focus() {
+        ui.data_mut(|data| data.insert_temp(edit_id, text));
+    }
+}
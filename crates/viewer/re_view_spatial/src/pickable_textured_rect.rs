@@ -26,14 +26,24 @@ pub struct PickableTexturedRect {
     /// Textured rectangle used by the renderer.
     pub textured_rect: TexturedRect,
 
+    /// If not empty, these are drawn instead of [`Self::textured_rect`].
+    ///
+    /// Used for the visible tiles of images too large to upload as a whole.
+    pub tiles: Vec<TexturedRect>,
+
     /// Associated data.
     pub source_data: PickableRectSourceData,
 }
 
 impl PickableTexturedRect {
-    /// Resolution of the underlying texture.
+    /// Resolution of the underlying image, or texture if this isn't an image.
     pub fn resolution(&self) -> [u32; 2] {
-        self.textured_rect.colormapped_texture.width_height()
+        match &self.source_data {
+            PickableRectSourceData::Image { image, .. } => image.width_height(),
+            PickableRectSourceData::Video | PickableRectSourceData::Placeholder => {
+                self.textured_rect.colormapped_texture.width_height()
+            }
+        }
     }
 
     pub fn to_draw_data(
@@ -43,7 +53,14 @@ impl PickableTexturedRect {
         // TODO(wumpf): Can we avoid this copy, maybe let DrawData take an iterator?
         let rectangles = rects
             .iter()
-            .map(|image| image.textured_rect.clone())
+            .flat_map(|image| {
+                if image.tiles.is_empty() {
+                    std::slice::from_ref(&image.textured_rect)
+                } else {
+                    image.tiles.as_slice()
+                }
+            })
+            .cloned()
             .collect::<Vec<_>>();
         match re_renderer::renderer::RectangleDrawData::new(render_ctx, &rectangles) {
             Ok(draw_data) => Ok(draw_data.into()),
@@ -291,7 +291,7 @@ fn picking_textured_rects<'a>(
         let v = dir_from_rect_top_left.dot(rect.extent_v) / rect.extent_v.length_squared();
 
         if (0.0..=1.0).contains(&u) && (0.0..=1.0).contains(&v) {
-            let [width, height] = rect.colormapped_texture.width_height();
+            let [width, height] = image.resolution();
 
             // Ignore the image if we hit the same entity already as an image.
             // This happens if the same entity has multiple textured rects.
@@ -3,13 +3,14 @@ use egui::NumExt as _;
 use re_data_ui::{DataUi as _, item_ui};
 use re_log_types::Instance;
 use re_renderer::ViewPickingConfiguration;
+use re_types::{blueprint::archetypes::HoverTooltip, components::Name};
 use re_ui::{
     UiExt as _,
     list_item::{PropertyContent, list_item_scope},
 };
 use re_view::AnnotationSceneContext;
 use re_viewer_context::{
-    Item, ItemCollection, ItemContext, UiLayout, ViewQuery, ViewSystemExecutionError,
+    DataResult, Item, ItemCollection, ItemContext, UiLayout, ViewQuery, ViewSystemExecutionError,
     ViewerContext, VisualizerCollection,
 };
 
@@ -74,10 +75,6 @@ pub fn picking(
     let mut hovered_image_items = Vec::new();
     let mut hovered_non_image_items = Vec::new();
 
-    // Depth at pointer used for projecting rays from a hovered 2D view to corresponding 3D view(s).
-    // TODO(#1818): Depth at pointer only works for depth images so far.
-    let mut depth_at_pointer = None;
-
     // We iterate front-to-back, putting foreground hits on top, like layers in Photoshop:
     for (hit_idx, hit) in picking_result.hits.iter().enumerate() {
         let Some(mut instance_path) = hit.instance_path_hash.resolve(ctx.recording()) else {
@@ -104,19 +101,6 @@ pub fn picking(
         }
 
         response = if let Some(picked_pixel) = get_pixel_picking_info(system_output, hit) {
-            if let PickableRectSourceData::Image {
-                depth_meter: Some(meter),
-                image,
-            } = &picked_pixel.source_data
-            {
-                let [x, y] = picked_pixel.pixel_coordinates;
-                if let Some(raw_value) = image.get_xyc(x, y, 0) {
-                    let raw_value = raw_value.as_f64();
-                    let depth_in_meters = raw_value / *meter.0 as f64;
-                    depth_at_pointer = Some(depth_in_meters as f32);
-                }
-            }
-
             response
                 .on_hover_cursor(egui::CursorIcon::Crosshair)
                 .on_hover_ui_at_pointer(|ui| {
@@ -137,6 +121,7 @@ pub fn picking(
                 })
         } else {
             // Hover ui for everything else
+            let tooltip_components = hover_tooltip_components(ctx, data_result);
             response.on_hover_ui_at_pointer(|ui| {
                 list_item_scope(ui, "spatial_hover", |ui| {
                     hit_ui(ui, hit);
@@ -148,7 +133,19 @@ pub fn picking(
                         Some(query.view_id),
                         &instance_path,
                     );
-                    instance_path.data_ui_recording(ctx, ui, UiLayout::Tooltip);
+                    if let Some(tooltip_components) = &tooltip_components {
+                        re_data_ui::instance_components_ui(
+                            ctx,
+                            ui,
+                            UiLayout::Tooltip,
+                            &ctx.current_query(),
+                            ctx.recording(),
+                            &instance_path,
+                            tooltip_components,
+                        );
+                    } else {
+                        instance_path.data_ui_recording(ctx, ui, UiLayout::Tooltip);
+                    }
                 });
             })
         };
@@ -192,9 +189,13 @@ pub fn picking(
         *context = Some(match spatial_kind {
             SpatialViewKind::TwoD => ItemContext::TwoD {
                 space_2d: query.space_origin.clone(),
-                pos: picking_context
-                    .pointer_in_camera_plane
-                    .extend(depth_at_pointer.unwrap_or(f32::INFINITY)),
+                pos: picking_context.pointer_in_camera_plane.extend(
+                    depth_at(
+                        &system_output.view_systems,
+                        picking_context.pointer_in_camera_plane,
+                    )
+                    .unwrap_or(f32::INFINITY),
+                ),
             },
             SpatialViewKind::ThreeD => {
                 let hovered_point = picking_result.space_position();
@@ -225,6 +226,22 @@ pub fn picking(
     Ok((response, Some(picking_config)))
 }
 
+/// The components to show when hovering the entity, if set with a [`HoverTooltip`] override.
+fn hover_tooltip_components(
+    ctx: &ViewerContext<'_>,
+    data_result: &DataResult,
+) -> Option<Vec<Name>> {
+    let component = HoverTooltip::descriptor_shown_components().component;
+    ctx.blueprint_db()
+        .latest_at(
+            ctx.blueprint_query,
+            data_result.override_path(),
+            [component],
+        )
+        .component_batch::<Name>(component)
+        .filter(|names| !names.is_empty())
+}
+
 fn iter_pickable_rects(
     visualizers: &VisualizerCollection,
 ) -> impl Iterator<Item = &PickableTexturedRect> {
@@ -233,6 +250,17 @@ fn iter_pickable_rects(
         .flat_map(|data| data.pickable_rects.iter())
 }
 
+/// Depth at a position in a 2D view, used for projecting rays from a hovered 2D view to
+/// corresponding 3D view(s).
+///
+/// Asks all [`crate::hover_correlation::HoverCorrelationProvider`]s of the view's visualizers.
+fn depth_at(visualizers: &VisualizerCollection, pos_in_space: glam::Vec2) -> Option<f32> {
+    visualizers
+        .iter_visualizer_data::<SpatialViewVisualizerData>()
+        .flat_map(|data| data.hover_correlations.iter())
+        .find_map(|provider| provider.depth_at(pos_in_space))
+}
+
 /// If available, finds pixel info for a picking hit.
 ///
 /// Returns `None` for error placeholder since we generally don't want to zoom into those.
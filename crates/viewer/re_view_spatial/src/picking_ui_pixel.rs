@@ -200,7 +200,11 @@ fn try_show_zoomed_image_region(
     interaction_id: &TextureInteractionId<'_>,
     center_texel: [i64; 2],
 ) -> anyhow::Result<()> {
-    let [width, height] = colormapped_texture.width_height();
+    // The texture of a very large image may only be a low resolution overview of it.
+    let [width, height] = image.map_or_else(
+        || colormapped_texture.width_height(),
+        ImageInfo::width_height,
+    );
 
     const POINTS_PER_TEXEL: f32 = 5.0;
     let size = egui::Vec2::splat(((ZOOMED_IMAGE_TEXEL_RADIUS * 2 + 1) as f32) * POINTS_PER_TEXEL);
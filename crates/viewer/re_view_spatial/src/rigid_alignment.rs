@@ -0,0 +1,513 @@
+//! Moves entities onto others with rigid transforms that are only applied in a view.
+//!
+//! This is meant for comparing e.g. a reconstructed point cloud or trajectory with its ground
+//! truth: the transform is fitted once by the viewer and stored in a [`RigidAlignment`] override.
+
+use glam::{DAffine3, DQuat, DVec3};
+
+use re_log_types::EntityPath;
+use re_types::{
+    Archetype as _,
+    archetypes::{LineStrips3D, Points3D},
+    blueprint::{archetypes::RigidAlignment, components::Enabled},
+    components::{Length, LineStrip3D, Position3D, RotationQuat, Translation3D},
+};
+use re_viewer_context::{BlueprintContext as _, DataResult, ViewQuery, ViewerContext};
+
+use crate::contexts::TransformTreeContext;
+
+/// Points beyond this are subsampled before matching closest points.
+const MAX_SOURCE_POINTS: usize = 1_000;
+
+/// Reference points beyond this are subsampled before matching closest points.
+const MAX_REFERENCE_POINTS: usize = 10_000;
+
+const MAX_ICP_ITERATIONS: usize = 50;
+
+/// Matching closest points stops once the error improves by less than this fraction.
+const ICP_CONVERGENCE: f64 = 1e-6;
+
+/// A rigid transform fitted between two sets of points.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RigidFit {
+    /// Maps the source points onto the reference points.
+    pub reference_from_source: DAffine3,
+
+    /// The root mean square distance between the matched points after the fit.
+    pub rmse: f64,
+}
+
+/// Fits the rotation and translation that move each source point closest to the reference point
+/// with the same index, in the least squares sense.
+///
+/// This is Umeyama's method without scaling. The rotation is found as the eigenvector of Horn's
+/// symmetric 4x4 matrix with the largest eigenvalue, which avoids a singular value decomposition
+/// and never results in a reflection.
+///
+/// Returns `None` if there are no points, or if their counts differ.
+pub fn fit_by_index(source: &[DVec3], reference: &[DVec3]) -> Option<RigidFit> {
+    if source.is_empty() || source.len() != reference.len() {
+        return None;
+    }
+
+    let source_centroid = centroid(source);
+    let reference_centroid = centroid(reference);
+
+    // Cross-covariance of the centered points.
+    let mut s = [[0.0; 3]; 3];
+    for (source, reference) in source.iter().zip(reference) {
+        let a = (*source - source_centroid).to_array();
+        let b = (*reference - reference_centroid).to_array();
+        for (row, a) in s.iter_mut().zip(a) {
+            for (value, b) in row.iter_mut().zip(b) {
+                *value += a * b;
+            }
+        }
+    }
+    let [[sxx, sxy, sxz], [syx, syy, syz], [szx, szy, szz]] = s;
+
+    let horn = [
+        [sxx + syy + szz, syz - szy, szx - sxz, sxy - syx],
+        [syz - szy, sxx - syy - szz, sxy + syx, szx + sxz],
+        [szx - sxz, sxy + syx, -sxx + syy - szz, syz + szy],
+        [sxy - syx, szx + sxz, syz + szy, -sxx - syy + szz],
+    ];
+    let [w, x, y, z] = largest_eigenvector(horn);
+    let rotation = DQuat::from_xyzw(x, y, z, w).normalize();
+
+    let translation = reference_centroid - rotation * source_centroid;
+    let reference_from_source = DAffine3::from_rotation_translation(rotation, translation);
+
+    let squared_error = source
+        .iter()
+        .zip(reference)
+        .map(|(source, reference)| {
+            reference_from_source
+                .transform_point3(*source)
+                .distance_squared(*reference)
+        })
+        .sum::<f64>();
+
+    Some(RigidFit {
+        reference_from_source,
+        rmse: (squared_error / source.len() as f64).sqrt(),
+    })
+}
+
+/// Fits the rigid transform that moves the source points closest to the reference points,
+/// without knowing which points correspond to each other.
+///
+/// This is the iterative closest point algorithm: starting from aligned centroids, every source
+/// point is matched with its closest reference point and the transform is refitted with
+/// [`fit_by_index`], until the error stops improving.
+/// Large point clouds are subsampled to keep this interactive, the error is measured on the
+/// subsampled points.
+///
+/// Returns `None` if there are no points.
+pub fn fit_closest(source: &[DVec3], reference: &[DVec3]) -> Option<RigidFit> {
+    if source.is_empty() || reference.is_empty() {
+        return None;
+    }
+
+    let source = subsampled(source, MAX_SOURCE_POINTS);
+    let reference = subsampled(reference, MAX_REFERENCE_POINTS);
+
+    let mut reference_from_source =
+        DAffine3::from_translation(centroid(&reference) - centroid(&source));
+    let (mut matched, mut rmse) = closest_points(&source, &reference, &reference_from_source);
+
+    for _ in 0..MAX_ICP_ITERATIONS {
+        let refitted = fit_by_index(&source, &matched)?.reference_from_source;
+        let (new_matched, new_rmse) = closest_points(&source, &reference, &refitted);
+        if new_rmse > rmse {
+            break;
+        }
+
+        let converged = rmse - new_rmse <= ICP_CONVERGENCE * rmse;
+        reference_from_source = refitted;
+        matched = new_matched;
+        rmse = new_rmse;
+        if converged {
+            break;
+        }
+    }
+
+    Some(RigidFit {
+        reference_from_source,
+        rmse,
+    })
+}
+
+fn centroid(points: &[DVec3]) -> DVec3 {
+    points.iter().sum::<DVec3>() / points.len() as f64
+}
+
+fn subsampled(points: &[DVec3], max_points: usize) -> Vec<DVec3> {
+    let step = points.len().div_ceil(max_points).max(1);
+    points.iter().step_by(step).copied().collect()
+}
+
+/// For each moved source point the closest reference point, and the root mean square distance to them.
+fn closest_points(
+    source: &[DVec3],
+    reference: &[DVec3],
+    reference_from_source: &DAffine3,
+) -> (Vec<DVec3>, f64) {
+    let mut squared_error = 0.0;
+    let matched = source
+        .iter()
+        .map(|source| {
+            let moved = reference_from_source.transform_point3(*source);
+            let (closest, distance_squared) = reference
+                .iter()
+                .map(|reference| (*reference, reference.distance_squared(moved)))
+                .min_by(|(_, a), (_, b)| a.total_cmp(b))
+                .unwrap_or((moved, 0.0));
+            squared_error += distance_squared;
+            closest
+        })
+        .collect();
+
+    (matched, (squared_error / source.len() as f64).sqrt())
+}
+
+/// The eigenvector of a symmetric matrix with the largest eigenvalue, using Jacobi rotations.
+fn largest_eigenvector(mut a: [[f64; 4]; 4]) -> [f64; 4] {
+    let mut v = [
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ];
+
+    for _sweep in 0..50 {
+        let off_diagonal = (0..4)
+            .flat_map(|p| (p + 1..4).map(move |q| (p, q)))
+            .map(|(p, q)| a[p][q] * a[p][q])
+            .sum::<f64>();
+        if off_diagonal <= f64::EPSILON * f64::EPSILON {
+            break;
+        }
+
+        for p in 0..4 {
+            for q in p + 1..4 {
+                if a[p][q] == 0.0 {
+                    continue;
+                }
+
+                // Rotate in the (p, q) plane such that `a[p][q]` becomes zero.
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                let t = theta.signum() / (theta.abs() + theta.hypot(1.0));
+                let c = 1.0 / t.hypot(1.0);
+                let s = t * c;
+
+                for row in &mut a {
+                    let (ap, aq) = (row[p], row[q]);
+                    row[p] = c * ap - s * aq;
+                    row[q] = s * ap + c * aq;
+                }
+                for k in 0..4 {
+                    let (ap, aq) = (a[p][k], a[q][k]);
+                    a[p][k] = c * ap - s * aq;
+                    a[q][k] = s * ap + c * aq;
+                }
+                for row in &mut v {
+                    let (vp, vq) = (row[p], row[q]);
+                    row[p] = c * vp - s * vq;
+                    row[q] = s * vp + c * vq;
+                }
+            }
+        }
+    }
+
+    let largest = (0..4)
+        .max_by(|&i, &j| a[i][i].total_cmp(&a[j][j]))
+        .unwrap_or(0);
+    [v[0][largest], v[1][largest], v[2][largest], v[3][largest]]
+}
+
+// ---
+
+/// A [`RigidAlignment`] override of an entity.
+struct RigidAlignmentOverride {
+    reference: Option<EntityPath>,
+    match_closest: bool,
+
+    /// `None` until the viewer fitted the transform.
+    entity_from_aligned_entity: Option<DAffine3>,
+}
+
+impl RigidAlignmentOverride {
+    /// Returns `None` if the entity has no [`RigidAlignment`] override.
+    fn from_overrides(ctx: &ViewerContext<'_>, data_result: &DataResult) -> Option<Self> {
+        let reference = RigidAlignment::descriptor_reference().component;
+        let match_closest = RigidAlignment::descriptor_match_closest().component;
+        let translation = RigidAlignment::descriptor_translation().component;
+        let quaternion = RigidAlignment::descriptor_quaternion().component;
+
+        let results = ctx.blueprint_db().latest_at(
+            ctx.blueprint_query,
+            data_result.override_path(),
+            [reference, match_closest, translation, quaternion],
+        );
+
+        let reference = results
+            .component_mono::<re_types::components::EntityPath>(reference)
+            .map(|reference| EntityPath::from(reference.as_str()));
+        let translation = results.component_mono::<Translation3D>(translation);
+        let quaternion = results.component_mono::<RotationQuat>(quaternion);
+
+        if reference.is_none() && translation.is_none() && quaternion.is_none() {
+            return None;
+        }
+
+        let entity_from_aligned_entity =
+            (translation.is_some() || quaternion.is_some()).then(|| {
+                let rotation = quaternion
+                    .and_then(|quaternion| re_tf::convert::quaternion_to_dquat(quaternion.0).ok())
+                    .unwrap_or(DQuat::IDENTITY);
+                let translation =
+                    translation.map_or(DVec3::ZERO, |t| re_tf::convert::vec3d_to_dvec3(t.0));
+                DAffine3::from_rotation_translation(rotation, translation)
+            });
+
+        Some(Self {
+            reference,
+            match_closest: results
+                .component_mono::<Enabled>(match_closest)
+                .is_some_and(bool::from),
+            entity_from_aligned_entity,
+        })
+    }
+}
+
+/// The transform that moves the entity onto its reference, if it has been fitted.
+///
+/// Applied between the entity and its own transform, i.e. in the space of the entity.
+pub fn fitted_alignment(ctx: &ViewerContext<'_>, data_result: &DataResult) -> Option<DAffine3> {
+    RigidAlignmentOverride::from_overrides(ctx, data_result)?.entity_from_aligned_entity
+}
+
+/// Fits the transforms of all [`RigidAlignment`] overrides in the view that don't have one yet.
+///
+/// The fitted transforms are written back to the overrides, and thus take effect next frame.
+pub fn fit_pending_alignments(
+    ctx: &ViewerContext<'_>,
+    query: &ViewQuery<'_>,
+    transforms: &TransformTreeContext,
+) {
+    for data_result in query.iter_all_data_results() {
+        let Some(alignment) = RigidAlignmentOverride::from_overrides(ctx, data_result) else {
+            continue;
+        };
+        if alignment.entity_from_aligned_entity.is_some() {
+            continue;
+        }
+        let Some(reference) = &alignment.reference else {
+            continue;
+        };
+
+        re_tracing::profile_scope!("fit_alignment", data_result.entity_path.to_string());
+
+        let entity_path = &data_result.entity_path;
+        match fit_alignment(
+            ctx,
+            transforms,
+            entity_path,
+            reference,
+            alignment.match_closest,
+        ) {
+            Ok((entity_from_aligned_entity, rmse)) => {
+                re_log::info!(
+                    "Aligned {entity_path} to {reference} with an RMSE of {}",
+                    re_format::format_f64(rmse)
+                );
+                save_fit(ctx, data_result, &entity_from_aligned_entity, rmse);
+            }
+            Err(err) => {
+                re_log::warn_once!("Can't align {entity_path} to {reference}: {err}");
+            }
+        }
+    }
+}
+
+fn fit_alignment(
+    ctx: &ViewerContext<'_>,
+    transforms: &TransformTreeContext,
+    entity_path: &EntityPath,
+    reference: &EntityPath,
+    match_closest: bool,
+) -> Result<(DAffine3, f64), String> {
+    let (view_from_entity, source) = positions_in_view(ctx, transforms, entity_path)
+        .ok_or_else(|| format!("{entity_path} isn't shown in this view"))?;
+    let (_, reference_positions) = positions_in_view(ctx, transforms, reference)
+        .ok_or_else(|| format!("{reference} isn't shown in this view"))?;
+
+    if source.is_empty() || reference_positions.is_empty() {
+        return Err("both entities need to have 3D points or line strips".to_owned());
+    }
+
+    let fit = if match_closest {
+        fit_closest(&source, &reference_positions)
+    } else if source.len() != reference_positions.len() {
+        return Err(format!(
+            "matching points by index requires the same number of points, but there are {} and {}",
+            re_format::format_uint(source.len()),
+            re_format::format_uint(reference_positions.len())
+        ));
+    } else {
+        fit_by_index(&source, &reference_positions)
+    }
+    .ok_or_else(|| "there are no points to match".to_owned())?;
+
+    // The fit moves the entity in the space of the view, but is stored in the space of the
+    // entity so that it stays put relative to the entity when the view's origin changes.
+    let entity_from_aligned_entity =
+        view_from_entity.inverse() * fit.reference_from_source * view_from_entity;
+
+    Ok((entity_from_aligned_entity, fit.rmse))
+}
+
+/// The transform from the entity into the view, and the positions of the entity's points and
+/// line strip vertices at the current time in the space of the view.
+///
+/// Returns `None` if the entity isn't part of the view.
+fn positions_in_view(
+    ctx: &ViewerContext<'_>,
+    transforms: &TransformTreeContext,
+    entity_path: &EntityPath,
+) -> Option<(DAffine3, Vec<DVec3>)> {
+    let view_from_entity = transforms
+        .transform_info_for_entity(entity_path.hash())?
+        .single_transform_required_for_entity(entity_path, RigidAlignment::name());
+
+    let positions = Points3D::descriptor_positions().component;
+    let strips = LineStrips3D::descriptor_strips().component;
+    let results = ctx.recording_engine().cache().latest_at(
+        &ctx.current_query(),
+        entity_path,
+        [positions, strips],
+    );
+
+    let positions = results
+        .component_batch::<Position3D>(positions)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|position| glam::Vec3::from(position).as_dvec3());
+    let strip_vertices = results
+        .component_batch::<LineStrip3D>(strips)
+        .unwrap_or_default()
+        .into_iter()
+        .flat_map(|strip| strip.0)
+        .map(|vertex| glam::Vec3::from(vertex).as_dvec3());
+
+    Some((
+        view_from_entity,
+        positions
+            .chain(strip_vertices)
+            .map(|position| view_from_entity.transform_point3(position))
+            .collect(),
+    ))
+}
+
+fn save_fit(
+    ctx: &ViewerContext<'_>,
+    data_result: &DataResult,
+    entity_from_aligned_entity: &DAffine3,
+    rmse: f64,
+) {
+    let override_path = data_result.override_path();
+    let rotation = DQuat::from_mat3(&entity_from_aligned_entity.matrix3).normalize();
+
+    ctx.save_blueprint_component(
+        override_path.clone(),
+        &RigidAlignment::descriptor_translation(),
+        &Translation3D::from(entity_from_aligned_entity.translation.as_vec3()),
+    );
+    ctx.save_blueprint_component(
+        override_path.clone(),
+        &RigidAlignment::descriptor_quaternion(),
+        &RotationQuat::from(rotation.as_quat()),
+    );
+    ctx.save_blueprint_component(
+        override_path.clone(),
+        &RigidAlignment::descriptor_rmse(),
+        &Length::from(rmse as f32),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_points() -> Vec<DVec3> {
+        (0..50)
+            .map(|i| {
+                let t = i as f64 * 0.37;
+                DVec3::new(t.sin() * 2.0, t.cos() * 0.5 + t * 0.1, (t * 1.3).sin())
+            })
+            .collect()
+    }
+
+    fn assert_transforms_eq(a: &DAffine3, b: &DAffine3) {
+        assert!(a.abs_diff_eq(*b, 1e-6), "transforms differ:\n{a:?}\n{b:?}");
+    }
+
+    #[test]
+    fn fit_by_index_recovers_transform() {
+        let reference_from_source = DAffine3::from_rotation_translation(
+            DQuat::from_euler(glam::EulerRot::XYZ, 0.3, -1.2, 2.5),
+            DVec3::new(1.0, -2.0, 0.5),
+        );
+        let source = test_points();
+        let reference = source
+            .iter()
+            .map(|p| reference_from_source.transform_point3(*p))
+            .collect::<Vec<_>>();
+
+        let fit = fit_by_index(&source, &reference).unwrap();
+        assert_transforms_eq(&fit.reference_from_source, &reference_from_source);
+        assert!(fit.rmse < 1e-9);
+
+        assert_eq!(fit_by_index(&source, &reference[1..]), None);
+        assert_eq!(fit_by_index(&[], &[]), None);
+    }
+
+    #[test]
+    fn fit_by_index_reports_rmse() {
+        let source = [
+            DVec3::X,
+            DVec3::NEG_X,
+            DVec3::Y,
+            DVec3::NEG_Y,
+            DVec3::Z,
+            DVec3::NEG_Z,
+        ];
+        let reference = source.map(|p| 2.0 * p);
+
+        // The best fit is the identity, leaving every point 1 away from its reference.
+        let fit = fit_by_index(&source, &reference).unwrap();
+        assert_transforms_eq(&fit.reference_from_source, &DAffine3::IDENTITY);
+        assert!((fit.rmse - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn fit_closest_recovers_small_motion() {
+        let reference_from_source = DAffine3::from_rotation_translation(
+            DQuat::from_rotation_z(0.1),
+            DVec3::new(0.05, -0.02, 0.03),
+        );
+        let source = test_points();
+
+        // Shuffle the reference points, so that indices don't correspond.
+        let mut reference = source
+            .iter()
+            .map(|p| reference_from_source.transform_point3(*p))
+            .collect::<Vec<_>>();
+        reference.reverse();
+
+        let fit = fit_closest(&source, &reference).unwrap();
+        assert_transforms_eq(&fit.reference_from_source, &reference_from_source);
+        assert!(fit.rmse < 1e-6);
+    }
+}
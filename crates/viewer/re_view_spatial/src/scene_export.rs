@@ -0,0 +1,857 @@
+//! Exports the meshes and point clouds of a 3D view to a file, with all transforms baked in.
+//!
+//! This is meant for handing scenes over to DCC tools, so only geometry and colors are kept:
+//! textures, line primitives, and the radii of points are dropped.
+
+use re_renderer::importer::CpuModel;
+use re_types::{
+    Archetype as _,
+    archetypes::{Asset3D, Mesh3D, Points3D},
+    components::{AlbedoFactor, Blob, Color, MediaType, Position3D, TriangleIndices, Vector3D},
+};
+use re_viewer_context::{
+    IdentifiedViewSystem as _, SystemExecutionOutput, ViewQuery, ViewSystemExecutionError,
+    ViewerContext,
+};
+
+use crate::{
+    contexts::TransformTreeContext,
+    visualizers::{Asset3DVisualizer, Mesh3DVisualizer, Points3DVisualizer},
+};
+
+/// The file formats a scene can be exported to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SceneExportFormat {
+    /// Binary glTF (`.glb`).
+    Gltf,
+
+    /// Wavefront OBJ, with vertex colors.
+    Obj,
+
+    /// Binary PLY, with all meshes and point clouds merged.
+    Ply,
+}
+
+impl SceneExportFormat {
+    pub const ALL: [Self; 3] = [Self::Gltf, Self::Obj, Self::Ply];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Gltf => "glTF",
+            Self::Obj => "OBJ",
+            Self::Ply => "PLY",
+        }
+    }
+
+    pub fn file_extension(self) -> &'static str {
+        match self {
+            Self::Gltf => "glb",
+            Self::Obj => "obj",
+            Self::Ply => "ply",
+        }
+    }
+}
+
+/// Collects the visible geometry of a view, and asks the user where to save it.
+pub fn export_view(
+    ctx: &ViewerContext<'_>,
+    query: &ViewQuery<'_>,
+    system_output: &SystemExecutionOutput,
+    format: SceneExportFormat,
+) -> Result<(), ViewSystemExecutionError> {
+    let transforms = system_output
+        .context_systems
+        .get::<TransformTreeContext>()?;
+    let scene = ExportedScene::collect(ctx, query, transforms);
+    if scene.is_empty() {
+        re_log::warn!("There are no visible meshes or point clouds to export");
+        return Ok(());
+    }
+
+    let name = query
+        .space_origin
+        .last()
+        .map_or("scene", |name| name.unescaped_str());
+    ctx.command_sender().save_file_dialog(
+        re_capabilities::MainThreadToken::i_promise_i_am_on_the_main_thread(),
+        &format!("{name}.{}", format.file_extension()),
+        format!("Export {}", format.label()),
+        scene.to_bytes(format),
+    );
+
+    Ok(())
+}
+
+/// A triangle mesh in the space of the view.
+#[derive(Clone, Debug, Default)]
+pub struct ExportedMesh {
+    pub name: String,
+    pub positions: Vec<glam::Vec3>,
+
+    /// Either empty, or one per position.
+    pub normals: Vec<glam::Vec3>,
+
+    /// Unmultiplied sRGBA, either empty or one per position.
+    pub colors: Vec<[u8; 4]>,
+
+    pub triangles: Vec<glam::UVec3>,
+
+    /// Unmultiplied sRGBA color of the whole mesh, which the vertex colors are multiplied with.
+    pub albedo: [u8; 4],
+}
+
+/// A point cloud in the space of the view.
+#[derive(Clone, Debug, Default)]
+pub struct ExportedPoints {
+    pub name: String,
+    pub positions: Vec<glam::Vec3>,
+
+    /// Unmultiplied sRGBA, either empty or one per position.
+    pub colors: Vec<[u8; 4]>,
+}
+
+/// The visible geometry of a 3D view.
+#[derive(Clone, Debug, Default)]
+pub struct ExportedScene {
+    pub meshes: Vec<ExportedMesh>,
+    pub point_clouds: Vec<ExportedPoints>,
+}
+
+impl ExportedScene {
+    /// Collects all visible meshes, assets, and point clouds of a view at the current time.
+    pub fn collect(
+        ctx: &ViewerContext<'_>,
+        query: &ViewQuery<'_>,
+        transforms: &TransformTreeContext,
+    ) -> Self {
+        re_tracing::profile_function!();
+
+        let mut scene = Self::default();
+        let latest_at = ctx.current_query();
+        let engine = ctx.recording_engine();
+
+        // Meshes and points are drawn once for every instance pose of their entity.
+        let world_from_instances = |entity_path: &re_log_types::EntityPath| {
+            transforms
+                .transform_info_for_entity(entity_path.hash())
+                .map(|info| info.target_from_instances().to_vec())
+                .unwrap_or_default()
+        };
+
+        for data_result in query.iter_visible_data_results(Mesh3DVisualizer::identifier()) {
+            let entity_path = &data_result.entity_path;
+            let results = engine.cache().latest_at(
+                &latest_at,
+                entity_path,
+                Mesh3D::all_component_identifiers(),
+            );
+            let Some(positions) = results
+                .component_batch::<Position3D>(Mesh3D::descriptor_vertex_positions().component)
+            else {
+                continue;
+            };
+            let positions = positions
+                .into_iter()
+                .map(|position| glam::Vec3::from(position.0))
+                .collect::<Vec<_>>();
+            let triangles = results
+                .component_batch::<TriangleIndices>(Mesh3D::descriptor_triangle_indices().component)
+                .map(|indices| {
+                    indices
+                        .into_iter()
+                        .map(|indices| glam::UVec3::from(indices.0.0))
+                        .collect()
+                })
+                .unwrap_or_else(|| {
+                    (0..positions.len() as u32 / 3)
+                        .map(|i| glam::uvec3(3 * i, 3 * i + 1, 3 * i + 2))
+                        .collect()
+                });
+            let normals = results
+                .component_batch::<Vector3D>(Mesh3D::descriptor_vertex_normals().component)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|normal| glam::Vec3::from(normal.0))
+                .collect();
+            let colors = results
+                .component_batch::<Color>(Mesh3D::descriptor_vertex_colors().component)
+                .unwrap_or_default()
+                .into_iter()
+                .map(Color::to_array)
+                .collect();
+            let albedo = results
+                .component_mono::<AlbedoFactor>(Mesh3D::descriptor_albedo_factor().component)
+                .map_or([255; 4], |albedo| albedo.0.to_array());
+
+            let mesh = ExportedMesh {
+                name: entity_path.to_string(),
+                positions,
+                normals,
+                colors,
+                triangles,
+                albedo,
+            };
+            if let Some(mesh) = mesh.validated() {
+                for world_from_instance in world_from_instances(entity_path) {
+                    scene
+                        .meshes
+                        .push(mesh.transformed(&world_from_instance.as_affine3a()));
+                }
+            }
+        }
+
+        for data_result in query.iter_visible_data_results(Asset3DVisualizer::identifier()) {
+            let entity_path = &data_result.entity_path;
+            let results = engine.cache().latest_at(
+                &latest_at,
+                entity_path,
+                Asset3D::all_component_identifiers(),
+            );
+            let Some(blob) = results.component_mono::<Blob>(Asset3D::descriptor_blob().component)
+            else {
+                continue;
+            };
+            let media_type =
+                results.component_mono::<MediaType>(Asset3D::descriptor_media_type().component);
+            let albedo_factor = results
+                .component_mono::<AlbedoFactor>(Asset3D::descriptor_albedo_factor().component);
+
+            let name = entity_path.to_string();
+            let model = match load_asset(&name, &blob, media_type, ctx.render_ctx()) {
+                Ok(model) => model,
+                Err(err) => {
+                    re_log::warn!("Skipping {entity_path} in the export: {err}");
+                    continue;
+                }
+            };
+
+            for world_from_instance in world_from_instances(entity_path) {
+                let world_from_instance = world_from_instance.as_affine3a();
+                for instance in &model.instances {
+                    let Some(mesh) = model.meshes.get(instance.mesh) else {
+                        continue;
+                    };
+                    let world_from_mesh = world_from_instance * instance.world_from_mesh;
+
+                    // Every material covers a range of the triangles.
+                    for material in &mesh.materials {
+                        let triangles = mesh
+                            .triangle_indices
+                            .get(material.index_range.start as usize / 3..)
+                            .unwrap_or_default()
+                            .iter()
+                            .take(material.index_range.len() / 3)
+                            .copied()
+                            .collect();
+                        let albedo = albedo_factor.map_or_else(
+                            || egui::Color32::from(material.albedo_factor).to_srgba_unmultiplied(),
+                            |albedo| albedo.0.to_array(),
+                        );
+                        let exported = ExportedMesh {
+                            name: name.clone(),
+                            positions: mesh.vertex_positions.clone(),
+                            normals: mesh.vertex_normals.clone(),
+                            colors: mesh.vertex_colors.iter().map(|color| color.0).collect(),
+                            triangles,
+                            albedo,
+                        };
+                        if let Some(exported) = exported.validated() {
+                            scene.meshes.push(exported.transformed(&world_from_mesh));
+                        }
+                    }
+                }
+            }
+        }
+
+        for data_result in query.iter_visible_data_results(Points3DVisualizer::identifier()) {
+            let entity_path = &data_result.entity_path;
+            let results = engine.cache().latest_at(
+                &latest_at,
+                entity_path,
+                Points3D::all_component_identifiers(),
+            );
+            let Some(positions) =
+                results.component_batch::<Position3D>(Points3D::descriptor_positions().component)
+            else {
+                continue;
+            };
+            let positions = positions
+                .into_iter()
+                .map(|position| glam::Vec3::from(position.0))
+                .collect::<Vec<_>>();
+            let mut colors = results
+                .component_batch::<Color>(Points3D::descriptor_colors().component)
+                .unwrap_or_default()
+                .into_iter()
+                .map(Color::to_array)
+                .collect::<Vec<_>>();
+            // Like when drawing, the last color is repeated for the remaining points.
+            if let Some(last) = colors.last().copied() {
+                colors.resize(positions.len(), last);
+            }
+
+            for world_from_instance in world_from_instances(entity_path) {
+                let world_from_instance = world_from_instance.as_affine3a();
+                scene.point_clouds.push(ExportedPoints {
+                    name: entity_path.to_string(),
+                    positions: positions
+                        .iter()
+                        .map(|position| world_from_instance.transform_point3(*position))
+                        .collect(),
+                    colors: colors.clone(),
+                });
+            }
+        }
+
+        scene
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.meshes.is_empty() && self.point_clouds.is_empty()
+    }
+
+    pub fn to_bytes(&self, format: SceneExportFormat) -> Vec<u8> {
+        re_tracing::profile_function!();
+
+        match format {
+            SceneExportFormat::Gltf => self.to_glb(),
+            SceneExportFormat::Obj => self.to_obj().into_bytes(),
+            SceneExportFormat::Ply => self.to_ply(),
+        }
+    }
+
+    /// Writes a binary glTF file, with one node per mesh and point cloud.
+    fn to_glb(&self) -> Vec<u8> {
+        use serde_json::json;
+
+        let mut builder = GlbBuilder::default();
+        let mut nodes = Vec::new();
+        let mut meshes = Vec::new();
+        let mut materials = Vec::new();
+
+        for mesh in &self.meshes {
+            let mut attributes = serde_json::Map::new();
+            attributes.insert("POSITION".to_owned(), builder.positions(&mesh.positions));
+            if !mesh.normals.is_empty() {
+                attributes.insert(
+                    "NORMAL".to_owned(),
+                    builder.accessor(
+                        bytemuck::cast_slice(&mesh.normals),
+                        json!({
+                            "componentType": GlbBuilder::FLOAT,
+                            "count": mesh.normals.len(),
+                            "type": "VEC3",
+                        }),
+                        GlbBuilder::ARRAY_BUFFER,
+                    ),
+                );
+            }
+            if !mesh.colors.is_empty() {
+                attributes.insert("COLOR_0".to_owned(), builder.colors(&mesh.colors));
+            }
+            let indices = builder.accessor(
+                bytemuck::cast_slice(&mesh.triangles),
+                json!({
+                    "componentType": GlbBuilder::UNSIGNED_INT,
+                    "count": mesh.triangles.len() * 3,
+                    "type": "SCALAR",
+                }),
+                GlbBuilder::ELEMENT_ARRAY_BUFFER,
+            );
+
+            let [r, g, b, a] = mesh.albedo;
+            let base_color_factor = [
+                egui::ecolor::linear_f32_from_gamma_u8(r),
+                egui::ecolor::linear_f32_from_gamma_u8(g),
+                egui::ecolor::linear_f32_from_gamma_u8(b),
+                egui::ecolor::linear_f32_from_linear_u8(a),
+            ];
+            let alpha_mode = if a < 255 { "BLEND" } else { "OPAQUE" };
+            materials.push(json!({
+                "pbrMetallicRoughness": {
+                    "baseColorFactor": base_color_factor,
+                    "metallicFactor": 0.0,
+                    "roughnessFactor": 1.0,
+                },
+                "alphaMode": alpha_mode,
+                "doubleSided": true,
+            }));
+
+            meshes.push(json!({
+                "name": mesh.name,
+                "primitives": [{
+                    "attributes": attributes,
+                    "indices": indices,
+                    "material": materials.len() - 1,
+                }],
+            }));
+            nodes.push(json!({ "name": mesh.name, "mesh": meshes.len() - 1 }));
+        }
+
+        for points in &self.point_clouds {
+            if points.positions.is_empty() {
+                continue;
+            }
+            let mut attributes = serde_json::Map::new();
+            attributes.insert("POSITION".to_owned(), builder.positions(&points.positions));
+            if !points.colors.is_empty() {
+                attributes.insert("COLOR_0".to_owned(), builder.colors(&points.colors));
+            }
+
+            const POINTS: u32 = 0;
+            meshes.push(json!({
+                "name": points.name,
+                "primitives": [{ "attributes": attributes, "mode": POINTS }],
+            }));
+            nodes.push(json!({ "name": points.name, "mesh": meshes.len() - 1 }));
+        }
+
+        let GlbBuilder {
+            mut buffer,
+            buffer_views,
+            accessors,
+        } = builder;
+
+        let scene_nodes = (0..nodes.len()).collect::<Vec<_>>();
+        let mut document = json!({
+            "asset": { "version": "2.0", "generator": "Rerun" },
+            "scene": 0,
+            "scenes": [{ "nodes": scene_nodes }],
+            "nodes": nodes,
+            "meshes": meshes,
+            "materials": materials,
+            "accessors": accessors,
+            "bufferViews": buffer_views,
+        });
+        if !buffer.is_empty() {
+            document["buffers"] = json!([{ "byteLength": buffer.len() }]);
+        }
+
+        // Both chunks are padded to 4 bytes, the JSON one with spaces.
+        let mut json = serde_json::to_vec(&document).unwrap_or_default();
+        while json.len() % 4 != 0 {
+            json.push(b' ');
+        }
+        while buffer.len() % 4 != 0 {
+            buffer.push(0);
+        }
+
+        let has_buffer = !buffer.is_empty();
+        let chunks_size = 8 + json.len() + if has_buffer { 8 + buffer.len() } else { 0 };
+        let mut glb = Vec::with_capacity(12 + chunks_size);
+        glb.extend_from_slice(b"glTF");
+        glb.extend_from_slice(&2u32.to_le_bytes());
+        glb.extend_from_slice(&((12 + chunks_size) as u32).to_le_bytes());
+        glb.extend_from_slice(&(json.len() as u32).to_le_bytes());
+        glb.extend_from_slice(b"JSON");
+        glb.extend_from_slice(&json);
+        if has_buffer {
+            glb.extend_from_slice(&(buffer.len() as u32).to_le_bytes());
+            glb.extend_from_slice(b"BIN\0");
+            glb.extend_from_slice(&buffer);
+        }
+        glb
+    }
+
+    /// Writes a Wavefront OBJ file, with one object per mesh and point cloud.
+    ///
+    /// Colors are written as the widely supported `v x y z r g b` extension.
+    fn to_obj(&self) -> String {
+        use std::fmt::Write as _;
+
+        let mut obj = String::from("# Exported from Rerun\n");
+        let mut num_vertices = 0;
+
+        for mesh in &self.meshes {
+            writeln!(obj, "o {}", obj_name(&mesh.name)).ok();
+            let colors = mesh.baked_colors();
+            for (i, position) in mesh.positions.iter().enumerate() {
+                write_obj_vertex(&mut obj, *position, colors.as_ref().map(|colors| colors[i]));
+            }
+            for normal in &mesh.normals {
+                writeln!(obj, "vn {} {} {}", normal.x, normal.y, normal.z).ok();
+            }
+            for triangle in &mesh.triangles {
+                // OBJ indices are 1-based, and global to the file.
+                let [a, b, c] = (*triangle + glam::UVec3::splat(num_vertices + 1)).to_array();
+                if mesh.normals.is_empty() {
+                    writeln!(obj, "f {a} {b} {c}").ok();
+                } else {
+                    writeln!(obj, "f {a}//{a} {b}//{b} {c}//{c}").ok();
+                }
+            }
+            num_vertices += mesh.positions.len() as u32;
+        }
+
+        for points in &self.point_clouds {
+            writeln!(obj, "o {}", obj_name(&points.name)).ok();
+            for (i, position) in points.positions.iter().enumerate() {
+                write_obj_vertex(&mut obj, *position, points.colors.get(i).copied());
+            }
+            if !points.positions.is_empty() {
+                obj.push('p');
+                for i in 0..points.positions.len() as u32 {
+                    write!(obj, " {}", num_vertices + i + 1).ok();
+                }
+                obj.push('\n');
+            }
+            num_vertices += points.positions.len() as u32;
+        }
+
+        obj
+    }
+
+    /// Writes a single binary PLY file with all vertices, and the faces of all meshes.
+    fn to_ply(&self) -> Vec<u8> {
+        let num_vertices = self
+            .meshes
+            .iter()
+            .map(|mesh| mesh.positions.len())
+            .chain(
+                self.point_clouds
+                    .iter()
+                    .map(|points| points.positions.len()),
+            )
+            .sum::<usize>();
+        let num_faces = self
+            .meshes
+            .iter()
+            .map(|mesh| mesh.triangles.len())
+            .sum::<usize>();
+
+        let mut ply = format!(
+            "ply\n\
+             format binary_little_endian 1.0\n\
+             comment Exported from Rerun\n\
+             element vertex {num_vertices}\n\
+             property float x\n\
+             property float y\n\
+             property float z\n\
+             property float nx\n\
+             property float ny\n\
+             property float nz\n\
+             property uchar red\n\
+             property uchar green\n\
+             property uchar blue\n\
+             property uchar alpha\n\
+             element face {num_faces}\n\
+             property list uchar uint vertex_indices\n\
+             end_header\n"
+        )
+        .into_bytes();
+
+        let mut write_vertex = |position: glam::Vec3, normal: glam::Vec3, color: [u8; 4]| {
+            for value in position.to_array().into_iter().chain(normal.to_array()) {
+                ply.extend_from_slice(&value.to_le_bytes());
+            }
+            ply.extend_from_slice(&color);
+        };
+        for mesh in &self.meshes {
+            let colors = mesh.baked_colors();
+            for (i, position) in mesh.positions.iter().enumerate() {
+                let normal = mesh.normals.get(i).copied().unwrap_or(glam::Vec3::ZERO);
+                let color = colors.as_ref().map_or([255; 4], |colors| colors[i]);
+                write_vertex(*position, normal, color);
+            }
+        }
+        for points in &self.point_clouds {
+            for (i, position) in points.positions.iter().enumerate() {
+                let color = points.colors.get(i).copied().unwrap_or([255; 4]);
+                write_vertex(*position, glam::Vec3::ZERO, color);
+            }
+        }
+
+        let mut offset = 0;
+        for mesh in &self.meshes {
+            for triangle in &mesh.triangles {
+                ply.push(3);
+                for index in triangle.to_array() {
+                    ply.extend_from_slice(&(index + offset).to_le_bytes());
+                }
+            }
+            offset += mesh.positions.len() as u32;
+        }
+
+        ply
+    }
+}
+
+impl ExportedMesh {
+    /// Drops meshes without triangles, and attributes or triangles that don't match the positions.
+    fn validated(mut self) -> Option<Self> {
+        let num_positions = self.positions.len();
+        // Zero normals mark unshaded meshes, which none of the formats can express.
+        if self.normals.len() != num_positions
+            || self
+                .normals
+                .iter()
+                .any(|normal| *normal == glam::Vec3::ZERO)
+        {
+            self.normals.clear();
+        }
+        if self.colors.len() != num_positions {
+            self.colors.clear();
+        }
+        self.triangles
+            .retain(|triangle| triangle.max_element() < num_positions as u32);
+        (!self.triangles.is_empty()).then_some(self)
+    }
+
+    fn transformed(&self, world_from_mesh: &glam::Affine3A) -> Self {
+        let normal_from_mesh = world_from_mesh.matrix3.inverse().transpose();
+        Self {
+            name: self.name.clone(),
+            positions: self
+                .positions
+                .iter()
+                .map(|position| world_from_mesh.transform_point3(*position))
+                .collect(),
+            normals: self
+                .normals
+                .iter()
+                .map(|normal| (normal_from_mesh * *normal).normalize_or_zero())
+                .collect(),
+            colors: self.colors.clone(),
+            triangles: self.triangles.clone(),
+            albedo: self.albedo,
+        }
+    }
+
+    /// The vertex colors multiplied with the albedo, for formats without materials.
+    ///
+    /// `None` if all vertices are white.
+    fn baked_colors(&self) -> Option<Vec<[u8; 4]>> {
+        if self.colors.is_empty() && self.albedo == [255; 4] {
+            return None;
+        }
+        let multiply = |a: u8, b: u8| ((u16::from(a) * u16::from(b) + 127) / 255) as u8;
+        Some(
+            (0..self.positions.len())
+                .map(|i| {
+                    let color = self.colors.get(i).copied().unwrap_or([255; 4]);
+                    std::array::from_fn(|c| multiply(color[c], self.albedo[c]))
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Accumulates the binary buffer, buffer views, and accessors of a glTF file.
+#[derive(Default)]
+struct GlbBuilder {
+    buffer: Vec<u8>,
+    buffer_views: Vec<serde_json::Value>,
+    accessors: Vec<serde_json::Value>,
+}
+
+impl GlbBuilder {
+    const ARRAY_BUFFER: u32 = 34962;
+    const ELEMENT_ARRAY_BUFFER: u32 = 34963;
+
+    const UNSIGNED_BYTE: u32 = 5121;
+    const UNSIGNED_INT: u32 = 5125;
+    const FLOAT: u32 = 5126;
+
+    /// Adds an accessor with its own buffer view, returning its index.
+    fn accessor(
+        &mut self,
+        bytes: &[u8],
+        mut accessor: serde_json::Value,
+        target: u32,
+    ) -> serde_json::Value {
+        while self.buffer.len() % 4 != 0 {
+            self.buffer.push(0);
+        }
+        self.buffer_views.push(serde_json::json!({
+            "buffer": 0,
+            "byteOffset": self.buffer.len(),
+            "byteLength": bytes.len(),
+            "target": target,
+        }));
+        self.buffer.extend_from_slice(bytes);
+
+        accessor["bufferView"] = (self.buffer_views.len() - 1).into();
+        self.accessors.push(accessor);
+        (self.accessors.len() - 1).into()
+    }
+
+    /// Positions need their bounds.
+    fn positions(&mut self, positions: &[glam::Vec3]) -> serde_json::Value {
+        let bbox = macaw::BoundingBox::from_points(positions.iter().copied());
+        self.accessor(
+            bytemuck::cast_slice(positions),
+            serde_json::json!({
+                "componentType": Self::FLOAT,
+                "count": positions.len(),
+                "type": "VEC3",
+                "min": bbox.min.to_array(),
+                "max": bbox.max.to_array(),
+            }),
+            Self::ARRAY_BUFFER,
+        )
+    }
+
+    /// Vertex colors are linear in glTF.
+    fn colors(&mut self, colors: &[[u8; 4]]) -> serde_json::Value {
+        let linear = colors
+            .iter()
+            .flat_map(|&[r, g, b, a]| {
+                let [r, g, b] = [r, g, b].map(|c| {
+                    egui::ecolor::linear_u8_from_linear_f32(egui::ecolor::linear_f32_from_gamma_u8(
+                        c,
+                    ))
+                });
+                [r, g, b, a]
+            })
+            .collect::<Vec<_>>();
+        self.accessor(
+            &linear,
+            serde_json::json!({
+                "componentType": Self::UNSIGNED_BYTE,
+                "normalized": true,
+                "count": colors.len(),
+                "type": "VEC4",
+            }),
+            Self::ARRAY_BUFFER,
+        )
+    }
+}
+
+fn load_asset(
+    name: &str,
+    blob: &Blob,
+    media_type: Option<MediaType>,
+    render_ctx: &re_renderer::RenderContext,
+) -> anyhow::Result<CpuModel> {
+    let bytes: &[u8] = &blob.0;
+    let media_type = MediaType::or_guess_from_data(media_type, bytes)
+        .ok_or_else(|| anyhow::anyhow!("couldn't guess media type"))?;
+
+    Ok(match media_type.as_str() {
+        MediaType::GLTF | MediaType::GLB => {
+            re_renderer::importer::gltf::load_gltf_from_buffer(name, bytes, render_ctx)?
+        }
+        MediaType::OBJ => re_renderer::importer::obj::load_obj_from_buffer(bytes, render_ctx)?,
+        MediaType::STL => re_renderer::importer::stl::load_stl_from_buffer(bytes, render_ctx)?,
+        _ => anyhow::bail!("{media_type} files are not supported"),
+    })
+}
+
+fn write_obj_vertex(obj: &mut String, position: glam::Vec3, color: Option<[u8; 4]>) {
+    use std::fmt::Write as _;
+
+    let glam::Vec3 { x, y, z } = position;
+    match color {
+        Some([r, g, b, _]) => writeln!(
+            obj,
+            "v {x} {y} {z} {} {} {}",
+            f32::from(r) / 255.0,
+            f32::from(g) / 255.0,
+            f32::from(b) / 255.0
+        ),
+        None => writeln!(obj, "v {x} {y} {z}"),
+    }
+    .ok();
+}
+
+/// Object names can't contain whitespace.
+fn obj_name(name: &str) -> String {
+    name.trim_start_matches('/')
+        .chars()
+        .map(|c| if c.is_whitespace() { '_' } else { c })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scene() -> ExportedScene {
+        ExportedScene {
+            meshes: vec![ExportedMesh {
+                name: "/triangle".to_owned(),
+                positions: vec![glam::Vec3::ZERO, glam::Vec3::X, glam::Vec3::Y],
+                normals: vec![glam::Vec3::Z; 3],
+                colors: Vec::new(),
+                triangles: vec![glam::uvec3(0, 1, 2)],
+                albedo: [255, 0, 0, 255],
+            }],
+            point_clouds: vec![ExportedPoints {
+                name: "/points".to_owned(),
+                positions: vec![glam::Vec3::ONE],
+                colors: vec![[0, 255, 0, 255]],
+            }],
+        }
+    }
+
+    #[test]
+    fn transformed_mesh() {
+        let mesh = scene().meshes.remove(0);
+        let world_from_mesh = glam::Affine3A::from_scale_rotation_translation(
+            glam::vec3(2.0, 1.0, 1.0),
+            glam::Quat::from_rotation_x(std::f32::consts::FRAC_PI_2),
+            glam::Vec3::Z,
+        );
+        let transformed = mesh.transformed(&world_from_mesh);
+
+        assert!(transformed.positions[1].abs_diff_eq(glam::vec3(2.0, 0.0, 1.0), 1e-6));
+        assert!(transformed.positions[2].abs_diff_eq(glam::vec3(0.0, 0.0, 2.0), 1e-6));
+        assert!(transformed.normals[0].abs_diff_eq(-glam::Vec3::Y, 1e-6));
+    }
+
+    #[test]
+    fn obj_export() {
+        let obj = scene().to_obj();
+        assert_eq!(
+            obj,
+            "# Exported from Rerun\n\
+             o triangle\n\
+             v 0 0 0 1 0 0\n\
+             v 1 0 0 1 0 0\n\
+             v 0 1 0 1 0 0\n\
+             vn 0 0 1\n\
+             vn 0 0 1\n\
+             vn 0 0 1\n\
+             f 1//1 2//2 3//3\n\
+             o points\n\
+             v 1 1 1 0 1 0\n\
+             p 4\n"
+        );
+    }
+
+    #[test]
+    fn glb_export() {
+        let glb = scene().to_glb();
+        assert_eq!(&glb[..4], b"glTF");
+        assert_eq!(
+            u32::from_le_bytes(glb[8..12].try_into().unwrap()) as usize,
+            glb.len()
+        );
+
+        let json_len = u32::from_le_bytes(glb[12..16].try_into().unwrap()) as usize;
+        let document: serde_json::Value = serde_json::from_slice(&glb[20..20 + json_len]).unwrap();
+        assert_eq!(document["nodes"].as_array().unwrap().len(), 2);
+        assert_eq!(document["meshes"][1]["primitives"][0]["mode"], 0);
+        assert_eq!(
+            document["materials"][0]["pbrMetallicRoughness"]["baseColorFactor"][0],
+            1.0
+        );
+    }
+
+    #[test]
+    fn ply_export() {
+        let ply = scene().to_ply();
+        let header_end = b"end_header\n";
+        let header_len = ply
+            .windows(header_end.len())
+            .position(|window| window == header_end)
+            .unwrap()
+            + header_end.len();
+        let header = std::str::from_utf8(&ply[..header_len]).unwrap();
+        assert!(header.contains("element vertex 4\n"));
+        assert!(header.contains("element face 1\n"));
+
+        // 4 vertices of 6 floats and 4 colors, and a face of a count and 3 indices.
+        assert_eq!(ply.len() - header_len, 4 * 28 + 13);
+    }
+}
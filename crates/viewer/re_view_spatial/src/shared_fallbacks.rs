@@ -1,4 +1,5 @@
-use re_types::{archetypes, components, image::ImageKind};
+use re_types::{archetypes, blueprint, components, image::ImageKind};
+use re_ui::ContextExt as _;
 use re_view::DataResultQuery as _;
 use re_viewer_context::{IdentifiedViewSystem as _, QueryContext, ViewStateExt as _};
 
@@ -114,4 +115,28 @@ pub fn register_fallbacks(system_registry: &mut re_viewer_context::ViewSystemReg
             components::AxisLength::from(0.3)
         },
     );
+
+    // Outlines: by default the same as hover & selection everywhere else in the viewer.
+    system_registry.register_fallback_provider(
+        blueprint::archetypes::Outlines::descriptor_hover_color().component,
+        |ctx| components::Color::new(ctx.egui_ctx().hover_stroke().color),
+    );
+    system_registry.register_fallback_provider(
+        blueprint::archetypes::Outlines::descriptor_selection_color().component,
+        |ctx| components::Color::new(ctx.egui_ctx().selection_stroke().color),
+    );
+    system_registry.register_fallback_provider(
+        blueprint::archetypes::Outlines::descriptor_stroke_width().component,
+        |ctx| {
+            let egui_ctx = ctx.egui_ctx();
+            components::StrokeWidth::from(f32::max(
+                egui_ctx.hover_stroke().width,
+                egui_ctx.selection_stroke().width,
+            ))
+        },
+    );
+    system_registry.register_fallback_provider(
+        blueprint::archetypes::Outlines::descriptor_highlight_color().component,
+        |_| components::Color::from_rgb(64, 220, 96),
+    );
 }
@@ -0,0 +1,298 @@
+//! Standard metrics for comparing an estimated trajectory with its ground truth,
+//! as used to evaluate SLAM and odometry.
+
+use glam::{DAffine3, DQuat};
+
+use re_chunk_store::LatestAtQuery;
+use re_entity_db::EntityDb;
+use re_log_types::{AbsoluteTimeRange, EntityPath, TimeInt, TimelineName};
+use re_tf::{CachedTransformsForTimeline, TransformFrameIdHash};
+use re_viewer_context::ViewerContext;
+
+use crate::caches::TransformDatabaseStoreCache;
+
+/// Guards against cycles when walking up the transform hierarchy.
+const MAX_TRANSFORM_DEPTH: usize = 1_000;
+
+/// The errors of an estimated trajectory with respect to its ground truth, for every estimated pose.
+///
+/// Poses are compared in the root frame of their transform hierarchy, so both trajectories should
+/// be logged relative to the same world frame.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TrajectoryErrors {
+    /// The times of the estimated poses that have a ground truth pose.
+    pub times: Vec<TimeInt>,
+
+    /// Absolute pose error (APE): the distance between each estimated position and the ground truth.
+    pub absolute_translation: Vec<f64>,
+
+    /// Absolute pose error (APE): the angle in radians between each estimated orientation and the ground truth.
+    pub absolute_rotation: Vec<f64>,
+
+    /// Relative pose error (RPE): how far the motion since the previous pose moved away from the
+    /// ground truth motion.
+    ///
+    /// One shorter than [`Self::times`], since the first pose has no previous one.
+    pub relative_translation: Vec<f64>,
+
+    /// Relative pose error (RPE): the angle in radians by which the rotation since the previous
+    /// pose differs from the ground truth rotation.
+    ///
+    /// One shorter than [`Self::times`], since the first pose has no previous one.
+    pub relative_rotation: Vec<f64>,
+}
+
+impl TrajectoryErrors {
+    /// Compares pairs of poses that were taken at the same times.
+    pub fn from_poses(times: Vec<TimeInt>, poses: &[(DAffine3, DAffine3)]) -> Self {
+        debug_assert_eq!(times.len(), poses.len());
+
+        let (absolute_translation, absolute_rotation) = poses
+            .iter()
+            .map(|(estimate, ground_truth)| pose_error(ground_truth, estimate))
+            .unzip();
+
+        let (relative_translation, relative_rotation) = poses
+            .iter()
+            .zip(poses.iter().skip(1))
+            .map(
+                |((previous_estimate, previous_ground_truth), (estimate, ground_truth))| {
+                    pose_error(
+                        &(previous_ground_truth.inverse() * *ground_truth),
+                        &(previous_estimate.inverse() * *estimate),
+                    )
+                },
+            )
+            .unzip();
+
+        Self {
+            times,
+            absolute_translation,
+            absolute_rotation,
+            relative_translation,
+            relative_rotation,
+        }
+    }
+
+    /// The times belonging to [`Self::relative_translation`] and [`Self::relative_rotation`].
+    pub fn relative_times(&self) -> &[TimeInt] {
+        self.times.get(1..).unwrap_or_default()
+    }
+}
+
+/// The translation and rotation (in radians) of the transform from `expected` to `actual`.
+fn pose_error(expected: &DAffine3, actual: &DAffine3) -> (f64, f64) {
+    let error = expected.inverse() * *actual;
+    let rotation = DQuat::from_mat3(&error.matrix3).normalize();
+    (
+        error.translation.length(),
+        rotation.angle_between(DQuat::IDENTITY),
+    )
+}
+
+/// Summary statistics of a series of errors.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ErrorStatistics {
+    pub rmse: f64,
+    pub mean: f64,
+    pub median: f64,
+    pub std_dev: f64,
+    pub min: f64,
+    pub max: f64,
+}
+
+impl ErrorStatistics {
+    /// Returns `None` if there are no errors.
+    pub fn new(errors: &[f64]) -> Option<Self> {
+        if errors.is_empty() {
+            return None;
+        }
+
+        let n = errors.len() as f64;
+        let mean = errors.iter().sum::<f64>() / n;
+        let mean_square = errors.iter().map(|e| e * e).sum::<f64>() / n;
+
+        let mut sorted = errors.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        let middle = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            0.5 * (sorted[middle - 1] + sorted[middle])
+        } else {
+            sorted[middle]
+        };
+
+        Some(Self {
+            rmse: mean_square.sqrt(),
+            mean,
+            median,
+            std_dev: (mean_square - mean * mean).max(0.0).sqrt(),
+            min: sorted[0],
+            max: sorted[sorted.len() - 1],
+        })
+    }
+}
+
+/// Compares the poses of two entities with transforms over a range of the given timeline.
+///
+/// Every time the estimated pose changes within the range, it is compared with the latest ground
+/// truth pose at that time.
+pub fn trajectory_errors(
+    ctx: &ViewerContext<'_>,
+    estimate: &EntityPath,
+    ground_truth: &EntityPath,
+    timeline: TimelineName,
+    time_range: AbsoluteTimeRange,
+) -> Result<TrajectoryErrors, String> {
+    re_tracing::profile_function!();
+
+    let recording = ctx.recording();
+    let transform_cache = ctx
+        .store_context
+        .caches
+        .entry(|c: &mut TransformDatabaseStoreCache| c.lock_transform_cache(recording));
+    let transforms = transform_cache.transforms_for_timeline(timeline);
+
+    let estimate_frame = TransformFrameIdHash::from_entity_path(estimate);
+    let ground_truth_frame = TransformFrameIdHash::from_entity_path(ground_truth);
+    let Some(estimate_transforms) = transforms.frame_transforms(estimate_frame) else {
+        return Err(format!("{estimate} has no transform on this timeline"));
+    };
+    if transforms.frame_transforms(ground_truth_frame).is_none() {
+        return Err(format!("{ground_truth} has no transform on this timeline"));
+    }
+
+    let mut times = Vec::new();
+    let mut poses = Vec::new();
+    for time in
+        estimate_transforms.frame_transform_times_in_range(time_range.min()..=time_range.max())
+    {
+        let query = LatestAtQuery::new(timeline, time);
+        let (Some(estimate_pose), Some(ground_truth_pose)) = (
+            root_from_frame(transforms, recording, &query, estimate_frame),
+            root_from_frame(transforms, recording, &query, ground_truth_frame),
+        ) else {
+            continue;
+        };
+        times.push(time);
+        poses.push((estimate_pose, ground_truth_pose));
+    }
+
+    if poses.is_empty() {
+        return Err(format!(
+            "{estimate} and {ground_truth} have no poses at the same times in this range"
+        ));
+    }
+
+    Ok(TrajectoryErrors::from_poses(times, &poses))
+}
+
+/// The transform from the frame into the root of its hierarchy, or `None` if the frame has no
+/// transform at that time.
+fn root_from_frame(
+    transforms: &CachedTransformsForTimeline,
+    recording: &EntityDb,
+    query: &LatestAtQuery,
+    frame: TransformFrameIdHash,
+) -> Option<DAffine3> {
+    let mut root_from_frame = transforms
+        .frame_transforms(frame)?
+        .latest_at_transform(recording, query)?;
+
+    for _ in 0..MAX_TRANSFORM_DEPTH {
+        let Some(parent_from_child) = transforms
+            .frame_transforms(root_from_frame.parent)
+            .and_then(|parent| parent.latest_at_transform(recording, query))
+        else {
+            return Some(root_from_frame.transform);
+        };
+        root_from_frame.transform = parent_from_child.transform * root_from_frame.transform;
+        root_from_frame.parent = parent_from_child.parent;
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::DVec3;
+
+    use super::*;
+
+    fn pose(yaw: f64, x: f64) -> DAffine3 {
+        DAffine3::from_rotation_translation(DQuat::from_rotation_z(yaw), DVec3::new(x, 0.0, 0.0))
+    }
+
+    #[test]
+    fn identical_trajectories_have_no_error() {
+        let poses = (0..5)
+            .map(|i| {
+                (
+                    pose(i as f64 * 0.1, i as f64),
+                    pose(i as f64 * 0.1, i as f64),
+                )
+            })
+            .collect::<Vec<_>>();
+        let times = (0..5).map(TimeInt::new_temporal).collect();
+
+        let errors = TrajectoryErrors::from_poses(times, &poses);
+        assert_eq!(errors.absolute_translation.len(), 5);
+        assert_eq!(errors.relative_translation.len(), 4);
+        assert_eq!(errors.relative_times().len(), 4);
+        for error in errors
+            .absolute_translation
+            .iter()
+            .chain(&errors.absolute_rotation)
+            .chain(&errors.relative_translation)
+            .chain(&errors.relative_rotation)
+        {
+            assert!(error.abs() < 1e-6, "unexpected error {error}");
+        }
+    }
+
+    #[test]
+    fn constant_offset_only_shows_in_absolute_error() {
+        // The estimate is always 0.5 ahead, but moves exactly like the ground truth.
+        let poses = (0..4)
+            .map(|i| (pose(0.0, i as f64 + 0.5), pose(0.0, i as f64)))
+            .collect::<Vec<_>>();
+        let times = (0..4).map(TimeInt::new_temporal).collect();
+
+        let errors = TrajectoryErrors::from_poses(times, &poses);
+        for error in &errors.absolute_translation {
+            assert!((error - 0.5).abs() < 1e-9);
+        }
+        for error in &errors.relative_translation {
+            assert!(error.abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn drift_shows_in_relative_error() {
+        // The estimate turns by an extra 0.1 radians every step.
+        let poses = (0..4)
+            .map(|i| (pose(i as f64 * 0.1, 0.0), pose(0.0, 0.0)))
+            .collect::<Vec<_>>();
+        let times = (0..4).map(TimeInt::new_temporal).collect();
+
+        let errors = TrajectoryErrors::from_poses(times, &poses);
+        for (i, error) in errors.absolute_rotation.iter().enumerate() {
+            assert!((error - i as f64 * 0.1).abs() < 1e-6);
+        }
+        for error in &errors.relative_rotation {
+            assert!((error - 0.1).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn error_statistics() {
+        assert_eq!(ErrorStatistics::new(&[]), None);
+
+        let statistics = ErrorStatistics::new(&[3.0, 1.0, 4.0, 0.0]).unwrap();
+        assert_eq!(statistics.mean, 2.0);
+        assert_eq!(statistics.median, 2.0);
+        assert_eq!(statistics.min, 0.0);
+        assert_eq!(statistics.max, 4.0);
+        assert!((statistics.rmse - 6.5_f64.sqrt()).abs() < 1e-12);
+        assert!((statistics.std_dev - 2.5_f64.sqrt()).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,168 @@
+use re_tf::{TransformFrameIdHash, TransformFrameNode, TransformHierarchy};
+use re_ui::UiExt as _;
+use re_viewer_context::ViewerContext;
+
+use crate::caches::TransformDatabaseStoreCache;
+
+/// Shows the transform hierarchy of the active recording at the current time,
+/// with the frames that have problems highlighted.
+///
+/// Meant for debugging how transforms were logged.
+pub fn transform_tree_panel_ui(ctx: &ViewerContext<'_>, ui: &mut egui::Ui) {
+    re_tracing::profile_function!();
+
+    let query = ctx.current_query();
+
+    let hierarchy = {
+        let transform_cache = ctx
+            .store_context
+            .caches
+            .entry(|c: &mut TransformDatabaseStoreCache| c.lock_transform_cache(ctx.recording()));
+        TransformHierarchy::new(ctx.recording(), &transform_cache, &query)
+    };
+
+    let problems = hierarchy.problems();
+    if !problems.is_empty() {
+        ui.strong("Problems");
+        for node in problems {
+            ui.horizontal(|ui| {
+                frame_label_ui(ui, node);
+                if let Some(problem) = node.problem {
+                    ui.label(problem.description());
+                }
+            });
+            entities_ui(ctx, &query, ui, node);
+        }
+        ui.separator();
+    }
+
+    ui.strong("Hierarchy");
+    if hierarchy.roots().is_empty() {
+        ui.weak("No transforms");
+    }
+    for &root in hierarchy.roots() {
+        frame_tree_ui(ctx, &query, ui, &hierarchy, root);
+    }
+}
+
+fn frame_tree_ui(
+    ctx: &ViewerContext<'_>,
+    query: &re_chunk_store::LatestAtQuery,
+    ui: &mut egui::Ui,
+    hierarchy: &TransformHierarchy,
+    frame: TransformFrameIdHash,
+) {
+    let Some(node) = hierarchy.frame(frame) else {
+        return;
+    };
+
+    let children = hierarchy.children(frame);
+    if children.is_empty() && node.entities.is_empty() {
+        ui.horizontal(|ui| frame_label_ui(ui, node));
+        return;
+    }
+
+    egui::collapsing_header::CollapsingState::load_with_default_open(
+        ui.ctx(),
+        ui.make_persistent_id(("transform_tree_frame", frame)),
+        true,
+    )
+    .show_header(ui, |ui| frame_label_ui(ui, node))
+    .body(|ui| {
+        entities_ui(ctx, query, ui, node);
+        for &child in children {
+            frame_tree_ui(ctx, query, ui, hierarchy, child);
+        }
+    });
+}
+
+fn frame_label_ui(ui: &mut egui::Ui, node: &TransformFrameNode) {
+    let text = egui::RichText::new(node.name()).monospace();
+    let text = match node.problem {
+        Some(re_tf::TransformFrameProblem::Cycle) => text.color(ui.visuals().error_fg_color),
+        Some(
+            re_tf::TransformFrameProblem::AncestorInCycle
+            | re_tf::TransformFrameProblem::MissingParent,
+        ) => text.color(ui.visuals().warn_fg_color),
+        None => text,
+    };
+    let response = ui.label(text);
+
+    if node.is_pinhole {
+        ui.weak("pinhole");
+    }
+    if node.is_implicit_parent {
+        ui.weak("(implicit)");
+    }
+
+    response.on_hover_ui(|ui| {
+        match node.problem {
+            Some(problem @ re_tf::TransformFrameProblem::Cycle) => {
+                ui.error_label(problem.description());
+            }
+            Some(problem) => {
+                ui.warning_label(problem.description());
+            }
+            None => {}
+        }
+        ui.label(format!(
+            "Parent from frame:\n{}",
+            format_transform(&node.parent_from_frame)
+        ));
+    });
+}
+
+/// The entities in the frame, together with their transform to the root of the tree.
+fn entities_ui(
+    ctx: &ViewerContext<'_>,
+    query: &re_chunk_store::LatestAtQuery,
+    ui: &mut egui::Ui,
+    node: &TransformFrameNode,
+) {
+    for entity_path in &node.entities {
+        ui.horizontal(|ui| {
+            re_data_ui::item_ui::entity_path_button(
+                ctx,
+                query,
+                ctx.recording(),
+                ui,
+                None,
+                entity_path,
+            );
+            match &node.root_from_frame {
+                Some(root_from_frame) => {
+                    ui.weak(format_transform(root_from_frame));
+                }
+                None => {
+                    ui.weak("no root");
+                }
+            }
+        });
+    }
+}
+
+fn format_transform(transform: &glam::DAffine3) -> String {
+    let (scale, rotation, translation) = transform.to_scale_rotation_translation();
+    let (axis, angle) = rotation.to_axis_angle();
+
+    let mut parts = vec![format!(
+        "translation [{:.3}, {:.3}, {:.3}]",
+        translation.x, translation.y, translation.z
+    )];
+    if angle != 0.0 {
+        parts.push(format!(
+            "rotation {:.1}° around [{:.3}, {:.3}, {:.3}]",
+            angle.to_degrees(),
+            axis.x,
+            axis.y,
+            axis.z
+        ));
+    }
+    if scale != glam::DVec3::ONE {
+        parts.push(format!(
+            "scale [{:.3}, {:.3}, {:.3}]",
+            scale.x, scale.y, scale.z
+        ));
+    }
+    parts.join(", ")
+}
@@ -65,6 +65,18 @@ pub struct SpatialViewState {
     pub pinhole_at_origin: Option<Pinhole>,
 
     pub visual_bounds_2d: Option<VisualBounds2D>,
+
+    /// What a 2D view showed last frame.
+    pub visible_scene_rect_2d: Option<VisibleSceneRect2D>,
+}
+
+/// The part of a 2D scene a view shows.
+#[derive(Clone, Copy, Debug)]
+pub struct VisibleSceneRect2D {
+    pub scene_rect: egui::Rect,
+
+    /// How many physical pixels one scene unit covers on screen.
+    pub pixels_per_unit: f32,
 }
 
 impl ViewState for SpatialViewState {
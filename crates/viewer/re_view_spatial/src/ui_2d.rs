@@ -21,7 +21,9 @@ use re_viewport_blueprint::ViewProperty;
 
 use super::{eye::Eye, ui::create_labels};
 use crate::{
-    Pinhole, SpatialView2D, ui::SpatialViewState, view_kind::SpatialViewKind,
+    Pinhole, SpatialView2D,
+    ui::{SpatialViewState, VisibleSceneRect2D},
+    view_kind::SpatialViewKind,
     visualizers::collect_ui_labels,
 };
 
@@ -188,6 +190,10 @@ impl SpatialView2D {
             ui_from_scene
         };
         let scene_from_ui = ui_from_scene.inverse();
+        state.visible_scene_rect_2d = Some(VisibleSceneRect2D {
+            scene_rect: scene_from_ui.transform_rect(response.rect),
+            pixels_per_unit: ui_from_scene.scale().x * ui.ctx().pixels_per_point(),
+        });
 
         let view_ctx = self.view_context(ctx, query.view_id, state);
         let near_clip_plane: blueprint_components::NearClipPlane = clip_property
@@ -202,6 +208,7 @@ impl SpatialView2D {
         let eye = Eye {
             world_from_rub_view: IsoTransform::IDENTITY,
             fov_y: None,
+            vertical_world_size: None,
         };
 
         // Don't let clipping plane become zero
@@ -242,13 +249,17 @@ impl SpatialView2D {
         };
 
         let scene_bounds = *scene_from_ui.to();
+        let outline_config = crate::outlines::outline_config(
+            &self.view_context(ctx, query.view_id, state),
+            &query.highlights,
+        );
         let Ok(target_config) = setup_target_config(
             ctx.render_mode(),
             &painter,
             scene_bounds,
             near_clip_plane,
             &query.space_origin.to_string(),
-            query.highlights.any_outlines(),
+            outline_config,
             &state.pinhole_at_origin,
             picking_config,
         ) else {
@@ -320,7 +331,7 @@ fn setup_target_config(
     scene_bounds: Rect,
     near_clip_plane: f32,
     space_name: &str,
-    any_outlines: bool,
+    outline_config: Option<re_renderer::OutlineConfig>,
     scene_pinhole: &Option<Pinhole>,
     picking_config: Option<ViewPickingConfiguration>,
 ) -> anyhow::Result<TargetConfiguration> {
@@ -415,7 +426,7 @@ fn setup_target_config(
             projection_from_view,
             viewport_transformation,
             pixels_per_point,
-            outline_config: any_outlines.then(|| re_view::outline_config(egui_painter.ctx())),
+            outline_config,
             blend_with_background: false,
             picking_config,
         }
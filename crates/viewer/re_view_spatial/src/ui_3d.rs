@@ -4,7 +4,7 @@ use glam::Vec3;
 use macaw::BoundingBox;
 use re_renderer::{
     LineDrawableBuilder, Size,
-    view_builder::{Projection, TargetConfiguration, ViewBuilder},
+    view_builder::{TargetConfiguration, ViewBuilder},
 };
 use re_tf::{image_view_coordinates, query_view_coordinates_at_closest_ancestor};
 use re_types::{
@@ -17,7 +17,7 @@ use re_types::{
 use re_ui::{ContextExt as _, Help, IconText, MouseButtonText, UiExt as _, icons};
 use re_view::controls::{
     DRAG_PAN3D_BUTTON, ROLL_MOUSE_ALT, ROLL_MOUSE_MODIFIER, ROTATE3D_BUTTON, RuntimeModifiers,
-    SPEED_UP_3D_MODIFIER, TRACKED_OBJECT_RESTORE_KEY,
+    SPEED_UP_3D_MODIFIER, TOGGLE_PROJECTION3D_KEY, TRACKED_OBJECT_RESTORE_KEY,
 };
 use re_viewer_context::{
     Item, ItemContext, ViewClassExt as _, ViewContext, ViewQuery, ViewSystemExecutionError,
@@ -27,14 +27,17 @@ use re_viewport_blueprint::ViewProperty;
 
 use crate::{
     SpatialView3D,
+    contexts::TransformTreeContext,
+    crop_box::{CropBoxSettings, add_crop_box_outline, crop_box_ui},
     eye::find_camera,
+    instance_selection::{HiddenInstances, SelectionDrag, SelectionShape},
     space_camera_3d::SpaceCamera3D,
     ui::{SpatialViewState, create_labels},
     view_kind::SpatialViewKind,
-    visualizers::{CamerasVisualizer, collect_ui_labels},
+    visualizers::{CamerasVisualizer, GaussianSplats3DVisualizer, collect_ui_labels},
 };
 
-use super::eye::{Eye, EyeState};
+use super::eye::{Eye, EyePreset, EyeState};
 
 // ---
 
@@ -50,6 +53,21 @@ pub struct View3DState {
     eye_interact_fade_change_time: f64,
 
     pub show_smoothed_bbox: bool,
+
+    /// Export requested from the selection panel, performed in the next frame of the view.
+    pub(crate) pending_export: Option<crate::scene_export::SceneExportFormat>,
+
+    /// Eye preset requested from the selection panel, applied in the next frame of the view.
+    pub(crate) pending_eye_preset: Option<EyePreset>,
+
+    /// The rectangle or lasso currently dragged to select instances, if any.
+    pub(crate) selection_drag: Option<SelectionDrag>,
+
+    /// Instances hidden after selecting them.
+    pub(crate) hidden_instances: HiddenInstances,
+
+    /// Whether the selected instances are listed in a table.
+    show_selection_table: bool,
 }
 
 impl Default for View3DState {
@@ -60,6 +78,11 @@ impl Default for View3DState {
             eye_interact_fade_in: false,
             eye_interact_fade_change_time: f64::NEG_INFINITY,
             show_smoothed_bbox: false,
+            pending_export: None,
+            pending_eye_preset: None,
+            selection_drag: None,
+            hidden_instances: HiddenInstances::default(),
+            show_selection_table: false,
         }
     }
 }
@@ -138,10 +161,19 @@ impl SpatialView3D {
             .view_systems
             .get::<CamerasVisualizer>()?
             .space_cameras;
-        let scene_view_coordinates = query_view_coordinates_at_closest_ancestor(
-            query.space_origin,
-            ctx.recording(),
-            &ctx.current_query(),
+        let transforms = system_output
+            .context_systems
+            .get::<TransformTreeContext>()?;
+        let coordinate_conversion = transforms.coordinate_conversion();
+        let scene_view_coordinates = coordinate_conversion.map_or_else(
+            || {
+                query_view_coordinates_at_closest_ancestor(
+                    query.space_origin,
+                    ctx.recording(),
+                    &ctx.current_query(),
+                )
+            },
+            |conversion| conversion.view_coordinates(),
         );
 
         let (ui_rect, response) =
@@ -169,8 +201,50 @@ impl SpatialView3D {
             &view_context,
             SpatialInformation::descriptor_show_bounding_box().component,
         )?;
+        let crop_box = CropBoxSettings::from_view(&view_context);
         state_3d.update(scene_view_coordinates);
 
+        // A selection drag ends when the button is released, or is cancelled when the modifiers are.
+        let mut finished_selection_drag = None;
+        if response.drag_stopped() {
+            finished_selection_drag = state_3d.selection_drag.take();
+        } else if let (Some(shape), Some(pointer_pos)) = (
+            SelectionShape::from_response(&response),
+            response.interact_pointer_pos(),
+        ) {
+            state_3d
+                .selection_drag
+                .get_or_insert_with(|| SelectionDrag::new(shape, pointer_pos))
+                .extend(pointer_pos);
+        } else {
+            state_3d.selection_drag = None;
+        }
+
+        // Canonical views and the projection can be switched with the keyboard while hovering the view.
+        if response.hovered() && !ui.ctx().wants_keyboard_input() {
+            let (preset, toggle_projection) = ui.input(|i| {
+                (
+                    EyePreset::ALL
+                        .into_iter()
+                        .find(|preset| i.key_pressed(preset.key())),
+                    i.key_pressed(TOGGLE_PROJECTION3D_KEY),
+                )
+            });
+            if preset.is_some() {
+                state_3d.pending_eye_preset = preset;
+            }
+            if toggle_projection {
+                crate::eye::toggle_projection(&view_context)?;
+            }
+        }
+        if let Some(preset) = state_3d.pending_eye_preset.take() {
+            state_3d.eye_state.apply_preset(
+                &view_context,
+                preset,
+                state_3d.scene_view_coordinates,
+            )?;
+        }
+
         let eye = state_3d.eye_state.update(
             &view_context,
             &response,
@@ -178,6 +252,18 @@ impl SpatialView3D {
             &state.bounding_boxes,
         )?;
 
+        if let Some(selection_drag) = &finished_selection_drag {
+            crate::instance_selection::select_instances(
+                ctx,
+                query,
+                transforms,
+                &state_3d.hidden_instances,
+                &eye,
+                ui_rect,
+                selection_drag,
+            );
+        }
+
         state.state_3d = state_3d;
 
         // Determine view port resolution and position.
@@ -201,10 +287,18 @@ impl SpatialView3D {
         //              As of #2522 state is now longer accessible there, move the property to a context?
         if show_axes {
             let axis_length = 1.0; // The axes are also a measuring stick
+
+            // Show the axes of the space origin as logged, unless that's the center of the Earth.
+            let world_from_origin_axes = coordinate_conversion
+                .filter(|conversion| !conversion.earth_centered)
+                .and_then(|conversion| conversion.view_from_origin())
+                .map_or(glam::Affine3A::IDENTITY, |view_from_origin| {
+                    view_from_origin.as_affine3a()
+                });
             crate::visualizers::add_axis_arrows(
                 ctx.tokens(),
                 &mut line_builder,
-                glam::Affine3A::IDENTITY,
+                world_from_origin_axes,
                 None,
                 axis_length,
                 re_renderer::OutlineMaskPreference::NONE,
@@ -250,26 +344,25 @@ impl SpatialView3D {
             (response, None)
         };
 
+        let (view_from_world, projection_from_view) = eye.view_from_world_and_projection(
+            resolution_in_pixel[0] as f32 / resolution_in_pixel[1] as f32,
+        );
         let target_config = TargetConfiguration {
             name: query.space_origin.to_string().into(),
             render_mode: ctx.render_mode(),
 
             resolution_in_pixel,
 
-            view_from_world: eye.world_from_rub_view.inverse(),
-            projection_from_view: Projection::Perspective {
-                vertical_fov: eye.fov_y.unwrap_or(Eye::DEFAULT_FOV_Y),
-                near_plane_distance: eye.near(),
-                aspect_ratio: resolution_in_pixel[0] as f32 / resolution_in_pixel[1] as f32,
-            },
+            view_from_world,
+            projection_from_view,
             viewport_transformation: re_renderer::RectTransform::IDENTITY,
 
             pixels_per_point: ui.ctx().pixels_per_point(),
 
-            outline_config: query
-                .highlights
-                .any_outlines()
-                .then(|| re_view::outline_config(ui.ctx())),
+            outline_config: crate::outlines::outline_config(
+                &self.view_context(ctx, query.view_id, state),
+                &query.highlights,
+            ),
             blend_with_background: false,
             picking_config,
         };
@@ -347,23 +440,31 @@ impl SpatialView3D {
                 .clear_blueprint_component(ctx, EyeControls3D::descriptor_tracking_entity());
         }
 
+        // Points in 3D that correspond to positions with known depth hovered/selected in 2D views.
+        let mut back_projected_points = Vec::new();
         for selected_context in ctx.selection_state().selection_item_contexts() {
-            show_projections_from_2d_space(
+            let color = ui.ctx().selection_stroke().color;
+            if let Some(point) = show_projections_from_2d_space(
                 &mut line_builder,
                 space_cameras,
                 state,
                 selected_context,
-                ui.ctx().selection_stroke().color,
-            );
+                color,
+            ) {
+                back_projected_points.push((point, color));
+            }
         }
         if let Some(hovered_context) = ctx.selection_state().hovered_item_context() {
-            show_projections_from_2d_space(
+            let color = ui.ctx().hover_stroke().color;
+            if let Some(point) = show_projections_from_2d_space(
                 &mut line_builder,
                 space_cameras,
                 state,
                 hovered_context,
-                ui.ctx().hover_stroke().color,
-            );
+                color,
+            ) {
+                back_projected_points.push((point, color));
+            }
         }
 
         // TODO(andreas): Make configurable. Could pick up default radius for this view?
@@ -379,6 +480,14 @@ impl SpatialView3D {
                         .color(ui.tokens().frustum_color)
                 });
         }
+        if let Some(crop_box) = &crop_box {
+            add_crop_box_outline(
+                &mut line_builder,
+                crop_box,
+                box_line_radius,
+                ui.tokens().frustum_color,
+            );
+        }
         if state.state_3d.show_smoothed_bbox {
             line_builder
                 .batch("scene_bbox_smoothed")
@@ -401,6 +510,15 @@ impl SpatialView3D {
             view_builder.queue_draw(ctx.render_ctx(), draw_data);
         }
 
+        // Gaussian splats are sorted back to front, which requires the eye of this frame.
+        if let Some(draw_data) = system_output
+            .view_systems
+            .get::<GaussianSplats3DVisualizer>()?
+            .draw_data(ctx.render_ctx(), eye.viewpoint_in_world())?
+        {
+            view_builder.queue_draw(ctx.render_ctx(), draw_data);
+        }
+
         let view_ctx = self.view_context(ctx, query.view_id, state);
 
         // Optional 3D line grid.
@@ -444,6 +562,42 @@ impl SpatialView3D {
         // Add egui-rendered labels on top of everything else:
         let painter = ui.painter().with_clip_rect(ui.max_rect());
         painter.extend(label_shapes);
+        painter.extend(back_projected_point_shapes(
+            ui,
+            &eye,
+            ui_rect,
+            &back_projected_points,
+        ));
+
+        if let Some(selection_drag) = &state.state_3d.selection_drag {
+            painter.add(selection_drag.shape(ui.ctx().selection_stroke()));
+        }
+
+        if let Some(crop_box) = &crop_box {
+            crop_box_ui(
+                &view_ctx,
+                ui,
+                crop_box,
+                &eye,
+                ui_rect,
+                &response,
+                state
+                    .previous_picking_result
+                    .as_ref()
+                    .and_then(|result| result.space_position()),
+            );
+        }
+
+        let state_3d = &mut state.state_3d;
+        crate::instance_selection::selected_instances_ui(
+            ctx,
+            ui,
+            query,
+            transforms,
+            &mut state_3d.hidden_instances,
+            &mut state_3d.show_selection_table,
+            ui_rect,
+        );
 
         Ok(())
     }
@@ -589,13 +743,16 @@ fn show_orbit_eye_center(
     }
 }
 
+/// Shows rays for positions in 2D views under cameras.
+///
+/// Returns the point in 3D the position corresponds to, if its depth is known.
 fn show_projections_from_2d_space(
     line_builder: &mut re_renderer::LineDrawableBuilder<'_>,
     space_cameras: &[SpaceCamera3D],
     state: &SpatialViewState,
     item_context: &ItemContext,
     ray_color: egui::Color32,
-) {
+) -> Option<glam::Vec3> {
     match item_context {
         ItemContext::TwoD { space_2d, pos } => {
             if let Some(cam) = space_cameras.iter().find(|cam| &cam.ent_path == space_2d)
@@ -603,7 +760,8 @@ fn show_projections_from_2d_space(
             {
                 // Render a thick line to the actual z value if any and a weaker one as an extension
                 // If we don't have a z value, we only render the thick one.
-                let depth = if 0.0 < pos.z && pos.z.is_finite() {
+                let has_depth = 0.0 < pos.z && pos.z.is_finite();
+                let depth = if has_depth {
                     pos.z
                 } else {
                     cam.picture_plane_distance
@@ -631,6 +789,8 @@ fn show_projections_from_2d_space(
                         ray_color,
                     );
                 }
+
+                return has_depth.then_some(stop_in_world);
             }
         }
         ItemContext::ThreeD {
@@ -660,6 +820,36 @@ fn show_projections_from_2d_space(
         | ItemContext::StreamsTree { .. }
         | ItemContext::BlueprintTree { .. } => {}
     }
+
+    None
+}
+
+/// Marks points back-projected from 2D views, see [`show_projections_from_2d_space`].
+fn back_projected_point_shapes(
+    ui: &egui::Ui,
+    eye: &Eye,
+    ui_rect: egui::Rect,
+    points: &[(glam::Vec3, egui::Color32)],
+) -> Vec<egui::Shape> {
+    let ui_from_world = eye.ui_from_world(ui_rect);
+
+    let mut shapes = Vec::new();
+    for &(point, color) in points {
+        let pos_in_ui = ui_from_world * point.extend(1.0);
+        if pos_in_ui.w <= 0.0 {
+            continue; // behind camera
+        }
+        let pos_in_ui = egui::pos2(pos_in_ui.x / pos_in_ui.w, pos_in_ui.y / pos_in_ui.w);
+
+        let radius = 4.0;
+        shapes.push(egui::Shape::circle_filled(
+            pos_in_ui,
+            radius + 2.0,
+            ui.visuals().extreme_bg_color,
+        ));
+        shapes.push(egui::Shape::circle_filled(pos_in_ui, radius, color));
+    }
+    shapes
 }
 
 fn add_picking_ray(
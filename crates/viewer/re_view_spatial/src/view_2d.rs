@@ -4,7 +4,9 @@ use re_entity_db::{EntityDb, EntityTree};
 use re_log_types::EntityPath;
 use re_types::{
     View as _, ViewClassIdentifier,
-    blueprint::archetypes::{Background, NearClipPlane, VisualBounds2D},
+    blueprint::archetypes::{
+        Background, ClassIdRemap, InstanceFilter, NearClipPlane, OnionSkin, VisualBounds2D,
+    },
 };
 use re_ui::{Help, UiExt as _};
 use re_view::view_property_ui;
@@ -68,6 +70,16 @@ impl ViewClass for SpatialView2D {
             re_types::blueprint::components::BackgroundKind::SolidColor
         });
 
+        system_registry
+            .register_fallback_provider(InstanceFilter::descriptor_threshold().component, |_| {
+                re_types::components::Scalar::from(crate::instance_filter::DEFAULT_THRESHOLD)
+            });
+
+        system_registry
+            .register_fallback_provider(OnionSkin::descriptor_opacity().component, |_| {
+                re_types::components::Opacity::from(crate::onion_skin::DEFAULT_OPACITY)
+            });
+
         fn valid_bound(rect: &egui::Rect) -> bool {
             rect.is_finite() && rect.is_positive()
         }
@@ -299,6 +311,10 @@ impl ViewClass for SpatialView2D {
             view_property_ui::<VisualBounds2D>(&view_ctx, ui);
             view_property_ui::<NearClipPlane>(&view_ctx, ui);
             view_property_ui::<Background>(&view_ctx, ui);
+            crate::instance_filter::view_property_ui_instance_filter(&view_ctx, ui);
+            view_property_ui::<OnionSkin>(&view_ctx, ui);
+            view_property_ui::<ClassIdRemap>(&view_ctx, ui);
+            crate::outlines::view_property_ui_outlines(&view_ctx, ui);
         });
 
         Ok(())
@@ -9,7 +9,11 @@ use re_tf::query_view_coordinates;
 use re_types::{
     Component as _, View as _, ViewClassIdentifier, archetypes,
     blueprint::{
-        archetypes::{Background, EyeControls3D, LineGrid3D, SpatialInformation},
+        archetypes::{
+            Background, ClassIdRemap, CoordinateConversion, CropBox, DepthClouds3D, EyeControls3D,
+            ImagePlanes3D, InstanceFilter, LineGrid3D, OnionSkin, PointShading, SpatialInformation,
+            TrajectoryTrails,
+        },
         components::Eye3DKind,
     },
     components::{LinearSpeed, Plane3D, Position3D, Vector3D},
@@ -17,7 +21,7 @@ use re_types::{
     view_coordinates::SignedAxis3,
 };
 use re_ui::{Help, UiExt as _, list_item};
-use re_view::view_property_ui;
+use re_view::{controls::TOGGLE_PROJECTION3D_KEY, view_property_ui};
 use re_viewer_context::{
     IdentifiedViewSystem as _, IndicatedEntities, MaybeVisualizableEntities, PerVisualizer,
     QueryContext, RecommendedView, SmallVisualizerSet, ViewClass, ViewClassExt as _,
@@ -28,8 +32,10 @@ use re_viewer_context::{
 use re_viewport_blueprint::ViewProperty;
 
 use crate::{
-    contexts::register_spatial_contexts,
+    contexts::{TransformTreeContext, register_spatial_contexts},
+    eye::EyePreset,
     heuristics::default_visualized_entities_for_visualizer_kind,
+    scene_export::SceneExportFormat,
     spatial_topology::{HeuristicHints, SpatialTopology, SubSpaceConnectionFlags},
     ui::SpatialViewState,
     view_kind::SpatialViewKind,
@@ -37,7 +43,10 @@ use crate::{
 };
 use crate::{
     shared_fallbacks,
-    visualizers::{AxisLengthDetector, CamerasVisualizer, Transform3DArrowsVisualizer},
+    visualizers::{
+        AxisLengthDetector, CamerasVisualizer, GeoPoints3DVisualizer, TensorGridVisualizer,
+        TensorHeightMapVisualizer, TrajectoryVisualizer, Transform3DArrowsVisualizer,
+    },
 };
 
 #[derive(Default)]
@@ -110,6 +119,51 @@ impl ViewClass for SpatialView3D {
                 re_types::components::StrokeWidth::from(1.0)
             });
 
+        system_registry
+            .register_fallback_provider(ImagePlanes3D::descriptor_opacity().component, |_| {
+                re_types::components::Opacity::from(1.0)
+            });
+
+        system_registry.register_fallback_provider(
+            ImagePlanes3D::descriptor_show_back_faces().component,
+            |_| re_types::blueprint::components::Enabled::from(true),
+        );
+
+        system_registry.register_fallback_provider(
+            ImagePlanes3D::descriptor_project_annotations().component,
+            |_| re_types::blueprint::components::Enabled::from(true),
+        );
+
+        system_registry
+            .register_fallback_provider(DepthClouds3D::descriptor_enabled().component, |_| {
+                re_types::blueprint::components::Enabled::from(true)
+            });
+
+        system_registry
+            .register_fallback_provider(DepthClouds3D::descriptor_depth_range().component, |_| {
+                re_types::components::ValueRange::new(0.0, f64::MAX)
+            });
+
+        system_registry
+            .register_fallback_provider(InstanceFilter::descriptor_threshold().component, |_| {
+                re_types::components::Scalar::from(crate::instance_filter::DEFAULT_THRESHOLD)
+            });
+
+        system_registry.register_fallback_provider(
+            TrajectoryTrails::descriptor_time_range().component,
+            crate::visualizers::default_trail_time_range,
+        );
+
+        system_registry
+            .register_fallback_provider(TrajectoryTrails::descriptor_fade().component, |_| {
+                re_types::blueprint::components::Enabled::from(true)
+            });
+
+        system_registry
+            .register_fallback_provider(OnionSkin::descriptor_opacity().component, |_| {
+                re_types::components::Opacity::from(crate::onion_skin::DEFAULT_OPACITY)
+            });
+
         system_registry.register_fallback_provider(
             Background::descriptor_kind().component,
             |ctx| match ctx.egui_ctx().theme() {
@@ -396,6 +450,10 @@ impl ViewClass for SpatialView3D {
         let arrows_viz = Transform3DArrowsVisualizer::identifier();
         let axis_detector = AxisLengthDetector::identifier();
         let camera_viz = CamerasVisualizer::identifier();
+        let trajectory_viz = TrajectoryVisualizer::identifier();
+        let geo_points_viz = GeoPoints3DVisualizer::identifier();
+        let tensor_grid_viz = TensorGridVisualizer::identifier();
+        let tensor_height_map_viz = TensorHeightMapVisualizer::identifier();
 
         let maybe_visualizable: HashSet<&ViewSystemIdentifier> =
             maybe_visualizable_entities_per_visualizer
@@ -423,10 +481,17 @@ impl ViewClass for SpatialView3D {
         // We never want to consider `Transform3DArrows` as directly indicated since it uses the
         // the Transform3D archetype. This is often used to transform other 3D primitives, where
         // it might be annoying to always have the arrows show up.
+        // The same goes for `Trajectory`, `GeoPoints3D`, `TensorGrid` and `TensorHeightMap`, which have to be enabled explicitly.
         let indicated: HashSet<&ViewSystemIdentifier> = indicated_entities_per_visualizer
             .iter()
             .filter_map(|(visualizer, ents)| {
-                if visualizer != &arrows_viz && ents.contains(entity_path) {
+                if visualizer != &arrows_viz
+                    && visualizer != &trajectory_viz
+                    && visualizer != &geo_points_viz
+                    && visualizer != &tensor_grid_viz
+                    && visualizer != &tensor_height_map_viz
+                    && ents.contains(entity_path)
+                {
                     Some(visualizer)
                 } else {
                     None
@@ -479,17 +544,17 @@ impl ViewClass for SpatialView3D {
         // Is there a nicer way for this or do we want a visualizer for view coordinates anyways?
         // There's also a strong argument to be made that ViewCoordinates implies a 3D space, thus changing the SpacialTopology accordingly!
         let engine = ctx.recording_engine();
-        ctx.recording().tree().visit_children_recursively(|path| {
-            if let Some(components) = engine.store().all_components_for_entity(path)
+        for entity in ctx.entity_tree.entities() {
+            if let Some(components) = engine.store().all_components_for_entity(&entity.path)
                 && components.into_iter().any(|component| {
                     // TODO(#2663): Note that the view coordinates component may be logged by different archetypes.
                     component == archetypes::Pinhole::descriptor_camera_xyz().component
                         || component == archetypes::ViewCoordinates::descriptor_xyz().component
                 })
             {
-                indicated_entities.insert(path.clone());
+                indicated_entities.insert(entity.path.clone());
             }
-        });
+        }
 
         // Spawn a view at each subspace that has any potential 3D content.
         // Note that visualizability filtering is all about being in the right subspace,
@@ -580,6 +645,27 @@ impl ViewClass for SpatialView3D {
             });
             ui.end_row();
 
+            ui.grid_left_hand_label("View").on_hover_text(format!(
+                "Look at the scene from a canonical direction.\n\
+                Press {} while hovering the view to switch between perspective and orthographic projection.",
+                TOGGLE_PROJECTION3D_KEY.name()
+            ));
+            ui.horizontal(|ui| {
+                for preset in EyePreset::ALL {
+                    if ui
+                        .button(preset.label())
+                        .on_hover_text(format!(
+                            "Press {} while hovering the view",
+                            preset.key().name()
+                        ))
+                        .clicked()
+                    {
+                        state.state_3d.pending_eye_preset = Some(preset);
+                    }
+                }
+            });
+            ui.end_row();
+
             ui.grid_left_hand_label("Coordinates")
                 .on_hover_text("The world coordinate system used for this view");
             ui.vertical(|ui| {
@@ -597,6 +683,18 @@ impl ViewClass for SpatialView3D {
 
             state.bounding_box_ui(ui, SpatialViewKind::ThreeD);
 
+            ui.grid_left_hand_label("Export").on_hover_text(
+                "Save the visible meshes and point clouds, with their transforms baked in",
+            );
+            ui.horizontal(|ui| {
+                for format in SceneExportFormat::ALL {
+                    if ui.button(format!("{}…", format.label())).clicked() {
+                        state.state_3d.pending_export = Some(format);
+                    }
+                }
+            });
+            ui.end_row();
+
             #[cfg(debug_assertions)]
             ui.re_checkbox(&mut state.state_3d.show_smoothed_bbox, "Smoothed bbox");
         });
@@ -606,6 +704,16 @@ impl ViewClass for SpatialView3D {
             view_property_ui::<EyeControls3D>(&view_ctx, ui);
             view_property_ui::<Background>(&view_ctx, ui);
             view_property_ui_grid3d(&view_ctx, ui);
+            view_property_ui::<ImagePlanes3D>(&view_ctx, ui);
+            view_property_ui::<DepthClouds3D>(&view_ctx, ui);
+            view_property_ui::<ClassIdRemap>(&view_ctx, ui);
+            view_property_ui::<CoordinateConversion>(&view_ctx, ui);
+            view_property_ui::<CropBox>(&view_ctx, ui);
+            view_property_ui::<PointShading>(&view_ctx, ui);
+            crate::outlines::view_property_ui_outlines(&view_ctx, ui);
+            crate::instance_filter::view_property_ui_instance_filter(&view_ctx, ui);
+            view_property_ui::<TrajectoryTrails>(&view_ctx, ui);
+            view_property_ui::<OnionSkin>(&view_ctx, ui);
             view_property_ui::<SpatialInformation>(&view_ctx, ui);
         });
 
@@ -626,6 +734,15 @@ impl ViewClass for SpatialView3D {
         let state = state.downcast_mut::<SpatialViewState>()?;
         state.update_frame_statistics(ui, &system_output, SpatialViewKind::ThreeD);
 
+        if let Some(format) = state.state_3d.pending_export.take() {
+            crate::scene_export::export_view(ctx, query, &system_output, format)?;
+        }
+
+        let transforms = system_output
+            .context_systems
+            .get::<TransformTreeContext>()?;
+        crate::rigid_alignment::fit_pending_alignments(ctx, query, transforms);
+
         self.view_3d(ctx, ui, state, query, system_output)
     }
 }
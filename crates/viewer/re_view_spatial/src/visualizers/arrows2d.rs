@@ -57,7 +57,7 @@ impl Arrows2DVisualizer {
                 continue;
             }
 
-            let (annotation_infos, _) = process_annotation_and_keypoint_slices(
+            let (mut annotation_infos, _) = process_annotation_and_keypoint_slices(
                 query.latest_at,
                 num_instances,
                 data.vectors.iter().map(|_| glam::Vec3::ZERO),
@@ -65,6 +65,7 @@ impl Arrows2DVisualizer {
                 data.class_ids,
                 &ent_context.annotations,
             );
+            ent_context.hide_filtered_instances(&mut annotation_infos);
 
             // Has not custom fallback for radius, so we use the default.
             // TODO(andreas): It would be nice to have this handle this fallback as part of the query.
@@ -86,6 +87,7 @@ impl Arrows2DVisualizer {
             let mut line_batch = line_builder
                 .batch(entity_path.to_string())
                 .depth_offset(ent_context.depth_offset)
+                .opacity(ent_context.opacity)
                 .world_from_obj(world_from_obj)
                 .outline_mask_ids(ent_context.highlight.overall)
                 .picking_object_id(re_renderer::PickingLayerObjectId(entity_path.hash64()));
@@ -94,9 +96,19 @@ impl Arrows2DVisualizer {
 
             let origins = clamped_or(data.origins, &Position2D::ZERO);
 
-            for (i, (vector, origin, radius, &color)) in
-                itertools::izip!(data.vectors, origins, radii, &colors).enumerate()
+            for (i, (vector, origin, radius, &color, annotation_info)) in itertools::izip!(
+                data.vectors,
+                origins,
+                radii,
+                &colors,
+                annotation_infos.iter()
+            )
+            .enumerate()
             {
+                if annotation_info.is_hidden {
+                    continue;
+                }
+
                 let vector: glam::Vec2 = vector.0.into();
                 let origin: glam::Vec2 = origin.0.into();
                 let end = origin + vector;
@@ -127,28 +139,30 @@ impl Arrows2DVisualizer {
             self.data
                 .add_bounding_box(entity_path.hash(), obj_space_bounding_box, world_from_obj);
 
-            self.data.ui_labels.extend(process_labels_2d(
-                LabeledBatch {
-                    entity_path,
-                    num_instances,
-                    overall_position: obj_space_bounding_box.center().truncate(),
-                    instance_positions: {
-                        // Take middle point of every arrow.
-                        let origins = clamped_or(data.origins, &Position2D::ZERO);
-                        itertools::izip!(data.vectors, origins).map(|(vector, origin)| {
-                            // `0.45` rather than `0.5` to account for cap and such
-                            glam::Vec2::from(origin.0) + glam::Vec2::from(vector.0) * 0.45
-                        })
+            if !ent_context.is_earlier_state {
+                self.data.ui_labels.extend(process_labels_2d(
+                    LabeledBatch {
+                        entity_path,
+                        num_instances,
+                        overall_position: obj_space_bounding_box.center().truncate(),
+                        instance_positions: {
+                            // Take middle point of every arrow.
+                            let origins = clamped_or(data.origins, &Position2D::ZERO);
+                            itertools::izip!(data.vectors, origins).map(|(vector, origin)| {
+                                // `0.45` rather than `0.5` to account for cap and such
+                                glam::Vec2::from(origin.0) + glam::Vec2::from(vector.0) * 0.45
+                            })
+                        },
+                        labels: &data.labels,
+                        colors: &colors,
+                        show_labels: data.show_labels.unwrap_or_else(|| {
+                            typed_fallback_for(ctx, Arrows2D::descriptor_show_labels().component)
+                        }),
+                        annotation_infos: &annotation_infos,
                     },
-                    labels: &data.labels,
-                    colors: &colors,
-                    show_labels: data.show_labels.unwrap_or_else(|| {
-                        typed_fallback_for(ctx, Arrows2D::descriptor_show_labels().component)
-                    }),
-                    annotation_infos: &annotation_infos,
-                },
-                world_from_obj,
-            ));
+                    world_from_obj,
+                ));
+            }
         }
     }
 }
@@ -200,15 +214,21 @@ impl VisualizerSystem for Arrows2DVisualizer {
         line_builder.radius_boost_in_ui_points_for_outlines(
             re_view::SIZE_BOOST_IN_POINTS_FOR_LINE_OUTLINES,
         );
+        line_builder
+            .radius_boost_in_ui_points_for_picking(re_view::SIZE_BOOST_IN_POINTS_FOR_LINE_PICKING);
 
-        use super::entity_iterator::{iter_slices, process_archetype};
-        process_archetype::<Self, Arrows2D, _>(
+        use super::entity_iterator::{iter_slices, process_archetype_with_onion_skin};
+        process_archetype_with_onion_skin::<Self, Arrows2D, _>(
             ctx,
             view_query,
             context_systems,
             |ctx, spatial_ctx, results| {
                 use re_view::RangeResultsExt as _;
 
+                if !spatial_ctx.shows_2d_annotations() {
+                    return Ok(());
+                }
+
                 let Some(all_vector_chunks) =
                     results.get_required_chunks(Arrows2D::descriptor_vectors().component)
                 else {
@@ -55,12 +55,13 @@ impl Arrows3DVisualizer {
                 continue;
             }
 
-            let annotation_infos = process_annotation_slices(
+            let mut annotation_infos = process_annotation_slices(
                 query.latest_at,
                 num_instances,
                 data.class_ids,
                 &ent_context.annotations,
             );
+            ent_context.hide_filtered_instances(&mut annotation_infos);
 
             // Has not custom fallback for radius, so we use the default.
             // TODO(andreas): It would be nice to have this handle this fallback as part of the query.
@@ -81,6 +82,7 @@ impl Arrows3DVisualizer {
 
             let mut line_batch = line_builder
                 .batch(entity_path.to_string())
+                .opacity(ent_context.opacity)
                 .world_from_obj(world_from_obj)
                 .outline_mask_ids(ent_context.highlight.overall)
                 .picking_object_id(re_renderer::PickingLayerObjectId(entity_path.hash64()));
@@ -89,9 +91,19 @@ impl Arrows3DVisualizer {
 
             let origins = clamped_or(data.origins, &Position3D::ZERO);
 
-            for (i, (vector, origin, radius, &color)) in
-                itertools::izip!(data.vectors, origins, radii, &colors).enumerate()
+            for (i, (vector, origin, radius, &color, annotation_info)) in itertools::izip!(
+                data.vectors,
+                origins,
+                radii,
+                &colors,
+                annotation_infos.iter()
+            )
+            .enumerate()
             {
+                if annotation_info.is_hidden {
+                    continue;
+                }
+
                 let vector: glam::Vec3 = vector.0.into();
                 let origin: glam::Vec3 = origin.0.into();
                 let end = origin + vector;
@@ -134,21 +146,26 @@ impl Arrows3DVisualizer {
                     })
                 };
 
-                self.data.ui_labels.extend(process_labels_3d(
-                    LabeledBatch {
-                        entity_path,
-                        num_instances,
-                        overall_position: obj_space_bounding_box.center(),
-                        instance_positions,
-                        labels: &data.labels,
-                        colors: &colors,
-                        show_labels: data.show_labels.unwrap_or_else(|| {
-                            typed_fallback_for(ctx, Arrows3D::descriptor_show_labels().component)
-                        }),
-                        annotation_infos: &annotation_infos,
-                    },
-                    world_from_obj,
-                ));
+                if !ent_context.is_earlier_state {
+                    self.data.ui_labels.extend(process_labels_3d(
+                        LabeledBatch {
+                            entity_path,
+                            num_instances,
+                            overall_position: obj_space_bounding_box.center(),
+                            instance_positions,
+                            labels: &data.labels,
+                            colors: &colors,
+                            show_labels: data.show_labels.unwrap_or_else(|| {
+                                typed_fallback_for(
+                                    ctx,
+                                    Arrows3D::descriptor_show_labels().component,
+                                )
+                            }),
+                            annotation_infos: &annotation_infos,
+                        },
+                        world_from_obj,
+                    ));
+                }
             }
         }
     }
@@ -201,9 +218,11 @@ impl VisualizerSystem for Arrows3DVisualizer {
         line_builder.radius_boost_in_ui_points_for_outlines(
             re_view::SIZE_BOOST_IN_POINTS_FOR_LINE_OUTLINES,
         );
+        line_builder
+            .radius_boost_in_ui_points_for_picking(re_view::SIZE_BOOST_IN_POINTS_FOR_LINE_PICKING);
 
-        use super::entity_iterator::{iter_slices, process_archetype};
-        process_archetype::<Self, Arrows3D, _>(
+        use super::entity_iterator::{iter_slices, process_archetype_with_onion_skin};
+        process_archetype_with_onion_skin::<Self, Arrows3D, _>(
             ctx,
             view_query,
             context_systems,
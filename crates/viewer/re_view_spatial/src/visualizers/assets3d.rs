@@ -1,7 +1,14 @@
 use re_chunk_store::RowId;
-use re_log_types::{Instance, TimeInt, hash::Hash64};
-use re_renderer::renderer::GpuMeshInstance;
-use re_types::{ArrowString, archetypes::Asset3D, components::AlbedoFactor};
+use re_log_types::{Instance, TimeInt, TimeType, hash::Hash64};
+use re_renderer::{
+    importer::{ModelRig, Pose},
+    renderer::GpuMeshInstance,
+};
+use re_types::{
+    ArrowString,
+    archetypes::{Asset3D, Skeleton},
+    components::{AlbedoFactor, PoseRotationQuat, PoseTranslation3D, Text},
+};
 use re_viewer_context::{
     IdentifiedViewSystem, MaybeVisualizableEntities, QueryContext, ViewContext,
     ViewContextCollection, ViewQuery, ViewSystemExecutionError, VisualizableEntities,
@@ -66,10 +73,11 @@ impl Asset3DVisualizer {
                     key.clone(),
                     AnyMesh::Asset {
                         asset: crate::mesh_loader::NativeAsset3D {
-                            bytes: &data.blob,
+                            blob: &data.blob,
                             media_type: data.media_type.clone().map(Into::into),
                             albedo_factor: data.albedo_factor.map(|a| a.0.into()),
                         },
+                        egui_ctx: ctx.egui_ctx(),
                     },
                     ctx.render_ctx(),
                 )
@@ -78,11 +86,21 @@ impl Asset3DVisualizer {
             if let Some(mesh) = mesh {
                 re_tracing::profile_scope!("mesh instances");
 
+                // Rigged assets are posed anew for every query.
+                let posed = mesh.rig.as_ref().map(|rig| {
+                    let pose = asset_pose(ctx, &rig.rig, data.index.0);
+                    rig.mesh_instances(&pose, ctx.render_ctx())
+                });
+                let (mesh_instances, bbox) = match &posed {
+                    Some((mesh_instances, bbox)) => (mesh_instances.as_slice(), *bbox),
+                    None => (mesh.mesh_instances.as_slice(), mesh.bbox()),
+                };
+
                 // Let's draw the mesh once for every instance transform.
                 // TODO(#7026): This a rare form of hybrid joining.
                 for &world_from_pose in ent_context.transform_info.target_from_instances() {
                     let world_from_pose = world_from_pose.as_affine3a();
-                    instances.extend(mesh.mesh_instances.iter().map(move |mesh_instance| {
+                    instances.extend(mesh_instances.iter().map(move |mesh_instance| {
                         let pose_from_mesh = mesh_instance.world_from_mesh;
                         let world_from_mesh = world_from_pose * pose_from_mesh;
 
@@ -98,13 +116,82 @@ impl Asset3DVisualizer {
                     }));
 
                     self.0
-                        .add_bounding_box(entity_path.hash(), mesh.bbox(), world_from_pose);
+                        .add_bounding_box(entity_path.hash(), bbox, world_from_pose);
                 }
             }
         }
     }
 }
 
+/// Poses a rigged asset for the given query.
+///
+/// On timelines that measure time, the first animation of the asset plays in a loop, starting at
+/// the time the asset was logged.
+/// A [`Skeleton`] logged to the same entity overrides the translation and rotation of every joint
+/// that one of its labels names.
+fn asset_pose(ctx: &QueryContext<'_>, rig: &ModelRig, asset_time: TimeInt) -> Pose {
+    let mut pose = rig.rest_pose();
+
+    if let Some(animation) = rig.animations.first()
+        && ctx.viewer_ctx().time_ctrl.time_type() != TimeType::Sequence
+    {
+        let start = if asset_time.is_static() {
+            0
+        } else {
+            asset_time.as_i64()
+        };
+        let seconds = ctx.query.at().as_i64().saturating_sub(start) as f64 * 1e-9;
+
+        // Loop in double precision, timestamps since the epoch don't fit into a `f32`.
+        let duration = f64::from(animation.duration());
+        let seconds = if duration > 0.0 {
+            seconds.rem_euclid(duration)
+        } else {
+            0.0
+        };
+        rig.apply_animation(&mut pose, 0, seconds as f32);
+    }
+
+    let results = ctx.recording().latest_at(
+        ctx.query,
+        ctx.target_entity_path,
+        [
+            Skeleton::descriptor_labels().component,
+            Skeleton::descriptor_translations().component,
+            Skeleton::descriptor_quaternions().component,
+        ],
+    );
+    let Some(labels) =
+        results.component_batch_quiet::<Text>(Skeleton::descriptor_labels().component)
+    else {
+        return pose;
+    };
+    let translations = results
+        .component_batch_quiet::<PoseTranslation3D>(Skeleton::descriptor_translations().component)
+        .unwrap_or_default();
+    let quaternions = results
+        .component_batch_quiet::<PoseRotationQuat>(Skeleton::descriptor_quaternions().component)
+        .unwrap_or_default();
+
+    for (joint, label) in labels.iter().enumerate() {
+        let Some(node) = rig.node_index(label.as_str()) else {
+            continue;
+        };
+        let transform = &mut pose.local_transforms[node];
+        if let Some(translation) = translations.get(joint) {
+            transform.translation = translation.0.into();
+        }
+        if let Some(rotation) = quaternions
+            .get(joint)
+            .and_then(|quat| glam::Quat::try_from(quat.0).ok())
+        {
+            transform.rotation = rotation;
+        }
+    }
+
+    pose
+}
+
 impl IdentifiedViewSystem for Asset3DVisualizer {
     fn identifier() -> re_viewer_context::ViewSystemIdentifier {
         "Asset3D".into()
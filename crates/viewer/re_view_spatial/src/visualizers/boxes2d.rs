@@ -56,12 +56,13 @@ impl Boxes2DVisualizer {
                 continue;
             }
 
-            let annotation_infos = process_annotation_slices(
+            let mut annotation_infos = process_annotation_slices(
                 view_query.latest_at,
                 num_instances,
                 data.class_ids,
                 &ent_context.annotations,
             );
+            ent_context.hide_filtered_instances(&mut annotation_infos);
 
             // Has not custom fallback for radius, so we use the default.
             // TODO(andreas): It would be nice to have this handle this fallback as part of the query.
@@ -83,6 +84,7 @@ impl Boxes2DVisualizer {
             let mut line_batch = line_builder
                 .batch("boxes2d")
                 .depth_offset(ent_context.depth_offset)
+                .opacity(ent_context.opacity)
                 .world_from_obj(world_from_obj)
                 .outline_mask_ids(ent_context.highlight.overall)
                 .picking_object_id(re_renderer::PickingLayerObjectId(entity_path.hash64()));
@@ -91,9 +93,19 @@ impl Boxes2DVisualizer {
 
             let centers = clamped_or(data.centers, &Position2D::ZERO);
 
-            for (i, (half_size, center, radius, &color)) in
-                itertools::izip!(data.half_sizes, centers, radii, &colors).enumerate()
+            for (i, (half_size, center, radius, &color, annotation_info)) in itertools::izip!(
+                data.half_sizes,
+                centers,
+                radii,
+                &colors,
+                annotation_infos.iter()
+            )
+            .enumerate()
             {
+                if annotation_info.is_hidden {
+                    continue;
+                }
+
                 let min = half_size.box_min(*center);
                 let max = half_size.box_max(*center);
                 obj_space_bounding_box.extend(min.extend(0.0));
@@ -120,35 +132,37 @@ impl Boxes2DVisualizer {
             self.data
                 .add_bounding_box(entity_path.hash(), obj_space_bounding_box, world_from_obj);
 
-            self.data.ui_labels.extend(process_labels(
-                LabeledBatch {
-                    entity_path,
-                    num_instances,
-                    overall_position: UiLabelTarget::Point2D(
-                        <[f32; 2]>::from(obj_space_bounding_box.center().truncate()).into(),
-                    ),
-                    instance_positions: data
-                        .half_sizes
-                        .iter()
-                        .copied()
-                        .zip(clamped_or(data.centers, &Position2D::ZERO).copied())
-                        .map(|(half_size, center)| {
-                            let min = half_size.box_min(center);
-                            let max = half_size.box_max(center);
-                            UiLabelTarget::Rect(egui::Rect::from_min_max(
-                                egui::pos2(min.x, min.y),
-                                egui::pos2(max.x, max.y),
-                            ))
+            if !ent_context.is_earlier_state {
+                self.data.ui_labels.extend(process_labels(
+                    LabeledBatch {
+                        entity_path,
+                        num_instances,
+                        overall_position: UiLabelTarget::Point2D(
+                            <[f32; 2]>::from(obj_space_bounding_box.center().truncate()).into(),
+                        ),
+                        instance_positions: data
+                            .half_sizes
+                            .iter()
+                            .copied()
+                            .zip(clamped_or(data.centers, &Position2D::ZERO).copied())
+                            .map(|(half_size, center)| {
+                                let min = half_size.box_min(center);
+                                let max = half_size.box_max(center);
+                                UiLabelTarget::Rect(egui::Rect::from_min_max(
+                                    egui::pos2(min.x, min.y),
+                                    egui::pos2(max.x, max.y),
+                                ))
+                            }),
+                        labels: &data.labels,
+                        colors: &colors,
+                        show_labels: data.show_labels.unwrap_or_else(|| {
+                            typed_fallback_for(ctx, Boxes2D::descriptor_show_labels().component)
                         }),
-                    labels: &data.labels,
-                    colors: &colors,
-                    show_labels: data.show_labels.unwrap_or_else(|| {
-                        typed_fallback_for(ctx, Boxes2D::descriptor_show_labels().component)
-                    }),
-                    annotation_infos: &annotation_infos,
-                },
-                std::convert::identity,
-            ));
+                        annotation_infos: &annotation_infos,
+                    },
+                    std::convert::identity,
+                ));
+            }
         }
     }
 }
@@ -200,15 +214,21 @@ impl VisualizerSystem for Boxes2DVisualizer {
         line_builder.radius_boost_in_ui_points_for_outlines(
             re_view::SIZE_BOOST_IN_POINTS_FOR_LINE_OUTLINES,
         );
+        line_builder
+            .radius_boost_in_ui_points_for_picking(re_view::SIZE_BOOST_IN_POINTS_FOR_LINE_PICKING);
 
-        use super::entity_iterator::{iter_slices, process_archetype};
-        process_archetype::<Self, Boxes2D, _>(
+        use super::entity_iterator::{iter_slices, process_archetype_with_onion_skin};
+        process_archetype_with_onion_skin::<Self, Boxes2D, _>(
             ctx,
             view_query,
             context_systems,
             |ctx, spatial_ctx, results| {
                 use re_view::RangeResultsExt as _;
 
+                if !spatial_ctx.shows_2d_annotations() {
+                    return Ok(());
+                }
+
                 let Some(all_half_size_chunks) =
                     results.get_required_chunks(Boxes2D::descriptor_half_sizes().component)
                 else {
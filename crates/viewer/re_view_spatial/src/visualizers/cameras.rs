@@ -229,6 +229,8 @@ impl VisualizerSystem for CamerasVisualizer {
         line_builder.radius_boost_in_ui_points_for_outlines(
             re_view::SIZE_BOOST_IN_POINTS_FOR_LINE_OUTLINES,
         );
+        line_builder
+            .radius_boost_in_ui_points_for_picking(re_view::SIZE_BOOST_IN_POINTS_FOR_LINE_PICKING);
 
         for data_result in query.iter_visible_data_results(Self::identifier()) {
             let time_query = re_chunk_store::LatestAtQuery::new(query.timeline, query.latest_at);
@@ -276,7 +278,9 @@ impl VisualizerSystem for CamerasVisualizer {
                     line_width,
                 },
                 camera_xyz,
-                image_plane_distance: image_plane_distance.into(),
+                image_plane_distance: transforms
+                    .image_plane_distance_override()
+                    .unwrap_or_else(|| image_plane_distance.into()),
             };
 
             let entity_highlight = query
@@ -19,6 +19,8 @@ use re_viewer_context::{
 use crate::{
     PickableRectSourceData, PickableTexturedRect, SpatialView3D,
     contexts::{SpatialSceneEntityContext, TransformTreeContext},
+    depth_clouds::DepthCloudSettings,
+    hover_correlation::DepthImageHoverCorrelation,
     view_kind::SpatialViewKind,
     visualizers::filter_visualizable_2d_entities,
 };
@@ -56,6 +58,7 @@ impl DepthImageVisualizer {
         depth_clouds: &mut Vec<DepthCloud>,
         ent_context: &SpatialSceneEntityContext<'_>,
         transforms: &TransformTreeContext,
+        depth_cloud_settings: Option<DepthCloudSettings>,
         images: impl Iterator<Item = DepthImageComponentData>,
     ) {
         let is_3d_view = ent_context.view_class_identifier == SpatialView3D::identifier();
@@ -110,7 +113,8 @@ impl DepthImageVisualizer {
                 return;
             };
 
-            if is_3d_view
+            if let Some(depth_cloud_settings) =
+                depth_cloud_settings.filter(|settings| settings.enabled)
                 && let Some(pinhole_tree_root_info) =
                     transforms.pinhole_tree_root_info(ent_context.transform_info.tree_root())
             {
@@ -126,6 +130,7 @@ impl DepthImageVisualizer {
                     depth_meter,
                     fill_ratio,
                     &textured_rect.colormapped_texture,
+                    &depth_cloud_settings,
                 );
                 self.data.add_bounding_box(
                     entity_path.hash(),
@@ -142,10 +147,23 @@ impl DepthImageVisualizer {
                 return;
             }
 
+            if !is_3d_view {
+                let space_from_image = ent_context
+                    .transform_info
+                    .single_transform_required_for_entity(entity_path, DepthImage::name())
+                    .as_affine3a();
+                self.data.add_hover_correlation(DepthImageHoverCorrelation {
+                    image: image.clone(),
+                    depth_meter,
+                    image_from_space: space_from_image.inverse(),
+                });
+            }
+
             self.data.add_pickable_rect(
                 PickableTexturedRect {
                     ent_path: entity_path.clone(),
                     textured_rect,
+                    tiles: Vec::new(),
                     source_data: PickableRectSourceData::Image {
                         image,
                         depth_meter: Some(depth_meter),
@@ -163,6 +181,7 @@ impl DepthImageVisualizer {
         depth_meter: DepthMeter,
         radius_scale: FillRatio,
         depth_texture: &ColormappedTexture,
+        settings: &DepthCloudSettings,
     ) -> DepthCloud {
         re_tracing::profile_function!();
 
@@ -184,7 +203,9 @@ impl DepthImageVisualizer {
             .image_from_camera
             .fov_y(pinhole.resolution.unwrap_or([1.0, 1.0].into()));
         let pixel_width_from_depth = (0.5 * fov_y).tan() / (0.5 * dimensions.y as f32);
-        let point_radius_from_world_depth = *radius_scale.0 * pixel_width_from_depth;
+        // Subsampled points cover several pixels each.
+        let point_radius_from_world_depth =
+            *radius_scale.0 * pixel_width_from_depth * settings.subsampling as f32;
 
         let min_max_depth_in_world = [
             world_depth_from_texture_depth * depth_texture.range[0],
@@ -197,6 +218,8 @@ impl DepthImageVisualizer {
             world_depth_from_texture_depth,
             point_radius_from_world_depth,
             min_max_depth_in_world,
+            valid_depth_range_in_world: settings.depth_range,
+            subsampling: settings.subsampling,
             depth_dimensions: dimensions,
             depth_texture: depth_texture.texture.clone(),
             colormap: match depth_texture.color_mapper {
@@ -205,6 +228,7 @@ impl DepthImageVisualizer {
             },
             outline_mask_id: ent_context.highlight.overall,
             picking_object_id: re_renderer::PickingLayerObjectId(ent_path.hash64()),
+            crop_box: settings.crop_box,
         }
     }
 }
@@ -238,6 +262,8 @@ impl VisualizerSystem for DepthImageVisualizer {
         let mut depth_clouds = Vec::new();
 
         let transforms = context_systems.get::<TransformTreeContext>()?;
+        let depth_cloud_settings = (ctx.view_class_identifier == SpatialView3D::identifier())
+            .then(|| DepthCloudSettings::from_view(ctx));
 
         use super::entity_iterator::{iter_component, iter_slices, process_archetype};
         process_archetype::<Self, DepthImage, _>(
@@ -314,6 +340,7 @@ impl VisualizerSystem for DepthImageVisualizer {
                     &mut depth_clouds,
                     spatial_ctx,
                     transforms,
+                    depth_cloud_settings,
                     &mut data,
                 );
 
@@ -0,0 +1,391 @@
+use itertools::Itertools as _;
+
+use re_renderer::{
+    PickingLayerInstanceId, RenderContext,
+    renderer::{
+        GaussianSplatBatchInfo, GaussianSplatDrawData, GaussianSplatDrawDataError,
+        MAX_SH_COEFFICIENTS, PointCloudCropBox, gaussian_covariance,
+    },
+};
+use re_types::{
+    archetypes::GaussianSplats3D,
+    components::{ClassId, Color, Opacity, PoseRotationQuat, PoseScale3D, Position3D},
+};
+use re_view::{clamped_or_nothing, process_annotation_slices, process_color_slice};
+use re_viewer_context::{
+    IdentifiedViewSystem, MaybeVisualizableEntities, QueryContext, ViewContext,
+    ViewContextCollection, ViewQuery, ViewSystemExecutionError, VisualizableEntities,
+    VisualizableFilterContext, VisualizerQueryInfo, VisualizerSystem,
+};
+
+use crate::{
+    contexts::SpatialSceneEntityContext, crop_box::CropBoxSettings, view_kind::SpatialViewKind,
+};
+
+use super::{SpatialViewVisualizerData, filter_visualizable_3d_entities};
+
+// ---
+
+pub struct GaussianSplats3DVisualizer {
+    pub data: SpatialViewVisualizerData,
+
+    /// Splats are sorted on the CPU, which requires the eye of the current frame.
+    /// Since it is only known after all visualizers ran, the draw data is created later on by [`Self::draw_data`].
+    splat_batches: Vec<SplatBatch>,
+}
+
+impl Default for GaussianSplats3DVisualizer {
+    fn default() -> Self {
+        Self {
+            data: SpatialViewVisualizerData::new(Some(SpatialViewKind::ThreeD)),
+            splat_batches: Vec::new(),
+        }
+    }
+}
+
+struct GaussianSplats3DComponentData<'a> {
+    // Point of views
+    centers: &'a [Position3D],
+
+    // Clamped to edge
+    quaternions: &'a [PoseRotationQuat],
+    scales: &'a [PoseScale3D],
+    colors: &'a [Color],
+    opacities: &'a [Opacity],
+    spherical_harmonics: Vec<&'a [[f32; 3]]>,
+    class_ids: &'a [ClassId],
+}
+
+/// Splats of a single entity & instance pose, ready to be handed to the renderer.
+struct SplatBatch {
+    label: String,
+    world_from_obj: glam::Affine3A,
+    centers: Vec<glam::Vec3>,
+    covariances: Vec<glam::Mat3>,
+    colors: Vec<re_renderer::Color32>,
+    sh_coefficients: Vec<glam::Vec3>,
+    num_sh_coefficients: usize,
+    picking_instance_ids: Vec<PickingLayerInstanceId>,
+    picking_object_id: re_renderer::PickingLayerObjectId,
+    overall_outline_mask_ids: re_renderer::OutlineMaskPreference,
+    crop_box: Option<PointCloudCropBox>,
+}
+
+/// Relative size of splats without scale, compared to the size of the bounding box of all centers.
+const FALLBACK_SCALE_FROM_BOUNDING_BOX_SIZE: f32 = 0.01;
+
+// NOTE: Do not put profile scopes in these methods. They are called for all entities and all
+// timestamps within a time range -- it's _a lot_.
+impl GaussianSplats3DVisualizer {
+    fn process_data<'a>(
+        &mut self,
+        ctx: &QueryContext<'_>,
+        query: &ViewQuery<'_>,
+        ent_context: &SpatialSceneEntityContext<'_>,
+        crop_box: Option<PointCloudCropBox>,
+        data: impl Iterator<Item = GaussianSplats3DComponentData<'a>>,
+    ) {
+        let entity_path = ctx.target_entity_path;
+
+        for data in data {
+            let num_instances = data.centers.len();
+            if num_instances == 0 {
+                continue;
+            }
+
+            let annotation_infos = process_annotation_slices(
+                query.latest_at,
+                num_instances,
+                data.class_ids,
+                &ent_context.annotations,
+            );
+
+            let centers: &[glam::Vec3] = bytemuck::cast_slice(data.centers);
+            let obj_space_bounding_box = macaw::BoundingBox::from_points(centers.iter().copied());
+
+            let fallback_scale = if obj_space_bounding_box.is_finite() {
+                obj_space_bounding_box.size().length() * FALLBACK_SCALE_FROM_BOUNDING_BOX_SIZE
+            } else {
+                FALLBACK_SCALE_FROM_BOUNDING_BOX_SIZE
+            };
+            let scales = clamped_or_nothing(data.scales, num_instances)
+                .map(|scale| glam::Vec3::from(scale.0))
+                .chain(std::iter::repeat(glam::Vec3::splat(fallback_scale)));
+            let rotations = clamped_or_nothing(data.quaternions, num_instances)
+                .map(|quat| {
+                    // Treat rotations that can't be normalized as invalid and collapse the splat.
+                    glam::Vec4::from(quat.0.0)
+                        .try_normalize()
+                        .map(glam::Quat::from_vec4)
+                })
+                .chain(std::iter::repeat(Some(glam::Quat::IDENTITY)));
+            let covariances = rotations
+                .zip(scales)
+                .take(num_instances)
+                .map(|(rotation, scale)| {
+                    rotation.map_or(glam::Mat3::ZERO, |rotation| {
+                        gaussian_covariance(rotation, scale)
+                    })
+                })
+                .collect_vec();
+
+            let colors = process_color_slice(
+                ctx,
+                GaussianSplats3D::descriptor_colors().component,
+                num_instances,
+                &annotation_infos,
+                data.colors,
+            );
+            let opacities = clamped_or_nothing(data.opacities, num_instances)
+                .map(|opacity| opacity.0.0)
+                .chain(std::iter::repeat(1.0));
+            let colors = colors
+                .iter()
+                .zip(opacities)
+                .map(|(color, opacity)| {
+                    // The splat renderer expects separate alpha.
+                    let [r, g, b, a] = color.to_srgba_unmultiplied();
+                    let a = (a as f32 * opacity.clamp(0.0, 1.0)).round() as u8;
+                    #[expect(clippy::disallowed_methods)] // This is not a hard-coded color.
+                    re_renderer::Color32::from_rgba_premultiplied(r, g, b, a)
+                })
+                .collect_vec();
+
+            let (sh_coefficients, num_sh_coefficients) =
+                sh_coefficients(&data.spherical_harmonics, num_instances);
+
+            let picking_instance_ids = (0..num_instances)
+                .map(|i| PickingLayerInstanceId(i as _))
+                .collect_vec();
+
+            for world_from_obj in ent_context
+                .transform_info
+                .target_from_instances()
+                .iter()
+                .map(|transform| transform.as_affine3a())
+            {
+                self.data.add_bounding_box(
+                    entity_path.hash(),
+                    obj_space_bounding_box,
+                    world_from_obj,
+                );
+
+                self.splat_batches.push(SplatBatch {
+                    label: entity_path.to_string(),
+                    world_from_obj,
+                    centers: centers.to_vec(),
+                    covariances: covariances.clone(),
+                    colors: colors.clone(),
+                    sh_coefficients: sh_coefficients.clone(),
+                    num_sh_coefficients,
+                    picking_instance_ids: picking_instance_ids.clone(),
+                    picking_object_id: re_renderer::PickingLayerObjectId(entity_path.hash64()),
+                    overall_outline_mask_ids: ent_context.highlight.overall,
+                    crop_box,
+                });
+            }
+        }
+    }
+
+    /// Creates the draw data for all splats, sorted back to front as seen from `view_position_in_world`.
+    ///
+    /// Returns `None` if there are no splats.
+    pub fn draw_data(
+        &self,
+        render_ctx: &RenderContext,
+        view_position_in_world: glam::Vec3,
+    ) -> Result<Option<GaussianSplatDrawData>, GaussianSplatDrawDataError> {
+        if self.splat_batches.is_empty() {
+            return Ok(None);
+        }
+
+        let batch_infos = self
+            .splat_batches
+            .iter()
+            .map(|batch| GaussianSplatBatchInfo {
+                label: batch.label.clone().into(),
+                world_from_obj: batch.world_from_obj,
+                centers: &batch.centers,
+                covariances: &batch.covariances,
+                colors: &batch.colors,
+                sh_coefficients: &batch.sh_coefficients,
+                num_sh_coefficients: batch.num_sh_coefficients,
+                picking_instance_ids: &batch.picking_instance_ids,
+                picking_object_id: batch.picking_object_id,
+                overall_outline_mask_ids: batch.overall_outline_mask_ids,
+                crop_box: batch.crop_box,
+            })
+            .collect_vec();
+
+        GaussianSplatDrawData::new(render_ctx, &batch_infos, view_position_in_world).map(Some)
+    }
+}
+
+/// Flattens the spherical harmonics of all splats, returning them along with the number of coefficients per splat.
+///
+/// The degree is given by the splat with the most coefficients, missing coefficients of other splats are zero.
+fn sh_coefficients(
+    spherical_harmonics: &[&[[f32; 3]]],
+    num_instances: usize,
+) -> (Vec<glam::Vec3>, usize) {
+    let max_len = spherical_harmonics
+        .iter()
+        .map(|coefficients| coefficients.len())
+        .max()
+        .unwrap_or(0);
+    // Degree 1, 2 or 3, see `re_types::components::SphericalHarmonics`.
+    let Some(num_sh_coefficients) = [MAX_SH_COEFFICIENTS, 8, 3]
+        .into_iter()
+        .find(|&num| num <= max_len)
+    else {
+        return (Vec::new(), 0);
+    };
+
+    let sh_coefficients = clamped_or_nothing(spherical_harmonics, num_instances)
+        .flat_map(|coefficients| {
+            coefficients
+                .iter()
+                .map(|&coefficient| glam::Vec3::from(coefficient))
+                .chain(std::iter::repeat(glam::Vec3::ZERO))
+                .take(num_sh_coefficients)
+        })
+        .collect_vec();
+
+    (sh_coefficients, num_sh_coefficients)
+}
+
+impl IdentifiedViewSystem for GaussianSplats3DVisualizer {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "GaussianSplats3D".into()
+    }
+}
+
+impl VisualizerSystem for GaussianSplats3DVisualizer {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<GaussianSplats3D>()
+    }
+
+    fn filter_visualizable_entities(
+        &self,
+        entities: MaybeVisualizableEntities,
+        context: &dyn VisualizableFilterContext,
+    ) -> VisualizableEntities {
+        re_tracing::profile_function!();
+        filter_visualizable_3d_entities(entities, context)
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        view_query: &ViewQuery<'_>,
+        context_systems: &ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
+        let crop_box =
+            CropBoxSettings::from_view(ctx).map(|crop_box| crop_box.point_cloud_crop_box());
+
+        use super::entity_iterator::{iter_slices, process_archetype};
+        process_archetype::<Self, GaussianSplats3D, _>(
+            ctx,
+            view_query,
+            context_systems,
+            |ctx, spatial_ctx, results| {
+                use re_view::RangeResultsExt as _;
+
+                let Some(all_center_chunks) =
+                    results.get_required_chunks(GaussianSplats3D::descriptor_centers().component)
+                else {
+                    return Ok(());
+                };
+
+                let timeline = ctx.query.timeline();
+                let all_centers_indexed = iter_slices::<[f32; 3]>(&all_center_chunks, timeline);
+                let all_quaternions = results.iter_as(
+                    timeline,
+                    GaussianSplats3D::descriptor_quaternions().component,
+                );
+                let all_scales =
+                    results.iter_as(timeline, GaussianSplats3D::descriptor_scales().component);
+                let all_colors =
+                    results.iter_as(timeline, GaussianSplats3D::descriptor_colors().component);
+                let all_opacities =
+                    results.iter_as(timeline, GaussianSplats3D::descriptor_opacities().component);
+                let all_spherical_harmonics = results.iter_as(
+                    timeline,
+                    GaussianSplats3D::descriptor_spherical_harmonics().component,
+                );
+                let all_class_ids =
+                    results.iter_as(timeline, GaussianSplats3D::descriptor_class_ids().component);
+
+                let data = re_query::range_zip_1x6(
+                    all_centers_indexed,
+                    all_quaternions.slice::<[f32; 4]>(),
+                    all_scales.slice::<[f32; 3]>(),
+                    all_colors.slice::<u32>(),
+                    all_opacities.slice::<f32>(),
+                    all_spherical_harmonics.slice::<&[[f32; 3]]>(),
+                    all_class_ids.slice::<u16>(),
+                )
+                .map(
+                    |(
+                        _index,
+                        centers,
+                        quaternions,
+                        scales,
+                        colors,
+                        opacities,
+                        spherical_harmonics,
+                        class_ids,
+                    )| {
+                        GaussianSplats3DComponentData {
+                            centers: bytemuck::cast_slice(centers),
+                            quaternions: quaternions.map_or(&[], bytemuck::cast_slice),
+                            scales: scales.map_or(&[], bytemuck::cast_slice),
+                            colors: colors.map_or(&[], bytemuck::cast_slice),
+                            opacities: opacities.map_or(&[], bytemuck::cast_slice),
+                            spherical_harmonics: spherical_harmonics.unwrap_or_default(),
+                            class_ids: class_ids.map_or(&[], bytemuck::cast_slice),
+                        }
+                    },
+                );
+
+                self.process_data(ctx, view_query, spatial_ctx, crop_box, data);
+
+                Ok(())
+            },
+        )?;
+
+        // The draw data is created by the view once the eye of this frame is known, see `Self::draw_data`.
+        Ok(Vec::new())
+    }
+
+    fn data(&self) -> Option<&dyn std::any::Any> {
+        Some(self.data.as_any())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sh_coefficients_are_padded_to_the_highest_degree() {
+        let degree_1 = [[1.0, 1.0, 1.0]; 3];
+        let degree_2 = [[2.0, 2.0, 2.0]; 8];
+
+        let (coefficients, num_coefficients) = sh_coefficients(&[&degree_1[..], &degree_2[..]], 3);
+        assert_eq!(num_coefficients, 8);
+        assert_eq!(coefficients.len(), 3 * 8);
+        assert_eq!(coefficients[2], glam::Vec3::ONE);
+        assert_eq!(coefficients[3], glam::Vec3::ZERO);
+        // The last splat repeats the coefficients of the second one.
+        assert_eq!(coefficients[16..], coefficients[8..16]);
+
+        // Too few coefficients for degree 1.
+        assert_eq!(sh_coefficients(&[&[[1.0; 3]; 2][..]], 1), (Vec::new(), 0));
+        assert_eq!(sh_coefficients(&[], 1), (Vec::new(), 0));
+    }
+}
@@ -0,0 +1,185 @@
+use re_renderer::PickingLayerInstanceId;
+use re_types::{
+    archetypes::GeoPoints,
+    components::{Color, LatLon, Radius},
+};
+use re_view::{
+    AnnotationSceneContext, DataResultQuery as _, RangeResultsExt as _, process_annotation_slices,
+    process_color_slice,
+};
+use re_viewer_context::{
+    IdentifiedViewSystem, MaybeVisualizableEntities, ViewContext, ViewContextCollection, ViewQuery,
+    ViewSystemExecutionError, VisualizableEntities, VisualizableFilterContext, VisualizerQueryInfo,
+    VisualizerSystem,
+};
+
+use crate::contexts::TransformTreeContext;
+
+use super::{SpatialViewVisualizerData, filter_visualizable_3d_entities, process_radius_slice};
+
+/// Shows [`GeoPoints`] in a 3D view.
+///
+/// The points are placed relative to the space origin, regardless of the transforms of their entity:
+/// in the east-north-up tangent plane at the geo origin of the
+/// [`re_types::blueprint::archetypes::CoordinateConversion`] view property, or in Earth-centered,
+/// Earth-fixed coordinates if the view is configured that way.
+pub struct GeoPoints3DVisualizer(SpatialViewVisualizerData);
+
+impl Default for GeoPoints3DVisualizer {
+    fn default() -> Self {
+        // Geo points are shown in a map view by default, they shouldn't spawn 3D views.
+        Self(SpatialViewVisualizerData::new(None))
+    }
+}
+
+impl IdentifiedViewSystem for GeoPoints3DVisualizer {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "GeoPoints3D".into()
+    }
+}
+
+impl VisualizerSystem for GeoPoints3DVisualizer {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<GeoPoints>()
+    }
+
+    fn filter_visualizable_entities(
+        &self,
+        entities: MaybeVisualizableEntities,
+        context: &dyn VisualizableFilterContext,
+    ) -> VisualizableEntities {
+        filter_visualizable_3d_entities(entities, context)
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        view_query: &ViewQuery<'_>,
+        context_systems: &ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
+        let mut data_results = view_query
+            .iter_visible_data_results(Self::identifier())
+            .peekable();
+        if data_results.peek().is_none() {
+            return Ok(Vec::new());
+        }
+
+        let annotation_scene_context = context_systems.get::<AnnotationSceneContext>()?;
+        let Some(coordinate_conversion) = context_systems
+            .get::<TransformTreeContext>()?
+            .coordinate_conversion()
+            .copied()
+        else {
+            return Ok(Vec::new());
+        };
+        // Earth-centered positions are way beyond single precision, so everything is converted to
+        // view space up front.
+        let world_from_origin = coordinate_conversion
+            .view_from_origin()
+            .unwrap_or(glam::DAffine3::IDENTITY);
+        let latest_at_query = view_query.latest_at_query();
+
+        let mut point_builder = re_renderer::PointCloudBuilder::new(ctx.viewer_ctx.render_ctx());
+        point_builder.radius_boost_in_ui_points_for_outlines(
+            re_view::SIZE_BOOST_IN_POINTS_FOR_POINT_OUTLINES,
+        );
+
+        for data_result in data_results {
+            let entity_path = &data_result.entity_path;
+            let results = data_result.query_archetype_with_history::<GeoPoints>(ctx, view_query);
+            let annotation_context = annotation_scene_context.0.find(entity_path);
+            let query_context = ctx.query_context(data_result, &latest_at_query);
+            let highlight = view_query
+                .highlights
+                .entity_outline_mask(entity_path.hash());
+
+            let timeline = view_query.timeline;
+            let all_positions =
+                results.iter_as(timeline, GeoPoints::descriptor_positions().component);
+            let all_colors = results.iter_as(timeline, GeoPoints::descriptor_colors().component);
+            let all_radii = results.iter_as(timeline, GeoPoints::descriptor_radii().component);
+            let all_class_ids =
+                results.iter_as(timeline, GeoPoints::descriptor_class_ids().component);
+
+            for (_index, positions, colors, radii, class_ids) in re_query::range_zip_1x3(
+                all_positions.slice::<[f64; 2]>(),
+                all_colors.slice::<u32>(),
+                all_radii.slice::<f32>(),
+                all_class_ids.slice::<u16>(),
+            ) {
+                let num_instances = positions.len();
+                if num_instances == 0 {
+                    continue;
+                }
+
+                let annotation_infos = process_annotation_slices(
+                    view_query.latest_at,
+                    num_instances,
+                    class_ids.map_or(&[], |class_ids| bytemuck::cast_slice(class_ids)),
+                    &annotation_context,
+                );
+                let colors = process_color_slice(
+                    &query_context,
+                    GeoPoints::descriptor_colors().component,
+                    num_instances,
+                    &annotation_infos,
+                    colors.map_or(&[], |colors| bytemuck::cast_slice::<u32, Color>(colors)),
+                );
+                // Same default as in the map view.
+                let radii = process_radius_slice(
+                    entity_path,
+                    num_instances,
+                    radii.map_or(&[], |radii| bytemuck::cast_slice::<f32, Radius>(radii)),
+                    Radius::new_ui_points(5.0),
+                );
+
+                let positions = positions
+                    .iter()
+                    .map(|[lat, lon]| {
+                        world_from_origin
+                            .transform_point3(
+                                coordinate_conversion.origin_from_lat_lon(LatLon::new(*lat, *lon)),
+                            )
+                            .as_vec3()
+                    })
+                    .collect::<Vec<_>>();
+                let picking_ids = (0..num_instances)
+                    .map(|i| PickingLayerInstanceId(i as _))
+                    .collect::<Vec<_>>();
+
+                let mut point_range_builder = point_builder
+                    .batch(entity_path.to_string())
+                    .outline_mask_ids(highlight.overall)
+                    .picking_object_id(re_renderer::PickingLayerObjectId(entity_path.hash64()))
+                    .add_points(&positions, &radii, &colors, &picking_ids);
+
+                for (highlighted_key, instance_mask_ids) in &highlight.instances {
+                    let highlighted_point_index = highlighted_key.get() as usize;
+                    if highlighted_point_index < num_instances {
+                        point_range_builder = point_range_builder
+                            .push_additional_outline_mask_ids_for_range(
+                                highlighted_point_index as u32..highlighted_point_index as u32 + 1,
+                                *instance_mask_ids,
+                            );
+                    }
+                }
+
+                self.0.add_bounding_box(
+                    entity_path.hash(),
+                    macaw::BoundingBox::from_points(positions.iter().copied()),
+                    glam::Affine3A::IDENTITY,
+                );
+            }
+        }
+
+        Ok(vec![point_builder.into_draw_data()?.into()])
+    }
+
+    fn data(&self) -> Option<&dyn std::any::Any> {
+        Some(self.0.as_any())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
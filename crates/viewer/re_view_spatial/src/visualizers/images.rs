@@ -15,7 +15,7 @@ use crate::{
     PickableRectSourceData, PickableTexturedRect,
     contexts::SpatialSceneEntityContext,
     view_kind::SpatialViewKind,
-    visualizers::{filter_visualizable_2d_entities, textured_rect_from_image},
+    visualizers::{filter_visualizable_2d_entities, textured_rect_from_image, visible_image_tiles},
 };
 
 use super::{SpatialViewVisualizerData, entity_iterator::process_archetype};
@@ -170,6 +170,7 @@ impl ImageVisualizer {
                 self.data.add_pickable_rect(
                     PickableTexturedRect {
                         ent_path: entity_path.clone(),
+                        tiles: visible_image_tiles(ctx, &image, &textured_rect),
                         textured_rect,
                         source_data: PickableRectSourceData::Image {
                             image,
@@ -52,12 +52,13 @@ impl Lines2DVisualizer {
                 continue;
             }
 
-            let annotation_infos = process_annotation_slices(
+            let mut annotation_infos = process_annotation_slices(
                 query.latest_at,
                 num_instances,
                 data.class_ids,
                 &ent_context.annotations,
             );
+            ent_context.hide_filtered_instances(&mut annotation_infos);
 
             // Has not custom fallback for radius, so we use the default.
             // TODO(andreas): It would be nice to have this handle this fallback as part of the query.
@@ -79,14 +80,20 @@ impl Lines2DVisualizer {
             let mut line_batch = line_builder
                 .batch(entity_path.to_string())
                 .depth_offset(ent_context.depth_offset)
+                .opacity(ent_context.opacity)
                 .world_from_obj(world_from_obj)
                 .outline_mask_ids(ent_context.highlight.overall)
                 .picking_object_id(re_renderer::PickingLayerObjectId(entity_path.hash64()));
 
             let mut obj_space_bounding_box = macaw::BoundingBox::nothing();
-            for (i, (strip, radius, &color)) in
-                itertools::izip!(data.strips.iter(), radii, &colors).enumerate()
+            for (i, (strip, radius, &color, annotation_info)) in
+                itertools::izip!(data.strips.iter(), radii, &colors, annotation_infos.iter())
+                    .enumerate()
             {
+                if annotation_info.is_hidden {
+                    continue;
+                }
+
                 let lines = line_batch
                     .add_strip_2d(strip.iter().copied().map(Into::into))
                     .color(color)
@@ -111,28 +118,33 @@ impl Lines2DVisualizer {
             self.data
                 .add_bounding_box(entity_path.hash(), obj_space_bounding_box, world_from_obj);
 
-            self.data.ui_labels.extend(process_labels_2d(
-                LabeledBatch {
-                    entity_path,
-                    num_instances,
-                    overall_position: obj_space_bounding_box.center().truncate(),
-                    instance_positions: data.strips.iter().map(|strip| {
-                        strip
-                            .iter()
-                            .copied()
-                            .map(glam::Vec2::from)
-                            .sum::<glam::Vec2>()
-                            / (strip.len() as f32)
-                    }),
-                    labels: &data.labels,
-                    colors: &colors,
-                    show_labels: data.show_labels.unwrap_or_else(|| {
-                        typed_fallback_for(ctx, LineStrips2D::descriptor_show_labels().component)
-                    }),
-                    annotation_infos: &annotation_infos,
-                },
-                world_from_obj,
-            ));
+            if !ent_context.is_earlier_state {
+                self.data.ui_labels.extend(process_labels_2d(
+                    LabeledBatch {
+                        entity_path,
+                        num_instances,
+                        overall_position: obj_space_bounding_box.center().truncate(),
+                        instance_positions: data.strips.iter().map(|strip| {
+                            strip
+                                .iter()
+                                .copied()
+                                .map(glam::Vec2::from)
+                                .sum::<glam::Vec2>()
+                                / (strip.len() as f32)
+                        }),
+                        labels: &data.labels,
+                        colors: &colors,
+                        show_labels: data.show_labels.unwrap_or_else(|| {
+                            typed_fallback_for(
+                                ctx,
+                                LineStrips2D::descriptor_show_labels().component,
+                            )
+                        }),
+                        annotation_infos: &annotation_infos,
+                    },
+                    world_from_obj,
+                ));
+            }
         }
     }
 }
@@ -183,15 +195,21 @@ impl VisualizerSystem for Lines2DVisualizer {
         line_builder.radius_boost_in_ui_points_for_outlines(
             re_view::SIZE_BOOST_IN_POINTS_FOR_LINE_OUTLINES,
         );
+        line_builder
+            .radius_boost_in_ui_points_for_picking(re_view::SIZE_BOOST_IN_POINTS_FOR_LINE_PICKING);
 
-        use super::entity_iterator::{iter_slices, process_archetype};
-        process_archetype::<Self, LineStrips2D, _>(
+        use super::entity_iterator::{iter_slices, process_archetype_with_onion_skin};
+        process_archetype_with_onion_skin::<Self, LineStrips2D, _>(
             ctx,
             view_query,
             context_systems,
             |ctx, spatial_ctx, results| {
                 use re_view::RangeResultsExt as _;
 
+                if !spatial_ctx.shows_2d_annotations() {
+                    return Ok(());
+                }
+
                 let Some(all_strip_chunks) =
                     results.get_required_chunks(LineStrips2D::descriptor_strips().component)
                 else {
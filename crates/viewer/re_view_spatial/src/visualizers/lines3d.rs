@@ -53,12 +53,13 @@ impl Lines3DVisualizer {
                 continue;
             }
 
-            let annotation_infos = process_annotation_slices(
+            let mut annotation_infos = process_annotation_slices(
                 query.latest_at,
                 num_instances,
                 data.class_ids,
                 &ent_context.annotations,
             );
+            ent_context.hide_filtered_instances(&mut annotation_infos);
 
             // Has not custom fallback for radius, so we use the default.
             // TODO(andreas): It would be nice to have this handle this fallback as part of the query.
@@ -80,6 +81,7 @@ impl Lines3DVisualizer {
             let mut line_batch = line_builder
                 .batch(entity_path.to_string())
                 .depth_offset(ent_context.depth_offset)
+                .opacity(ent_context.opacity)
                 .world_from_obj(world_from_obj)
                 .outline_mask_ids(ent_context.highlight.overall)
                 .picking_object_id(re_renderer::PickingLayerObjectId(entity_path.hash64()));
@@ -87,9 +89,15 @@ impl Lines3DVisualizer {
             let mut obj_space_bounding_box = macaw::BoundingBox::nothing();
 
             let mut num_rendered_strips = 0usize;
-            for (i, (strip, radius, &color)) in
-                itertools::izip!(data.strips.iter(), radii, &colors).enumerate()
+            for (i, (strip, radius, &color, annotation_info)) in
+                itertools::izip!(data.strips.iter(), radii, &colors, annotation_infos.iter())
+                    .enumerate()
             {
+                if annotation_info.is_hidden {
+                    num_rendered_strips += 1; // Hidden on purpose, not lost in post-processing.
+                    continue;
+                }
+
                 let lines = line_batch
                     .add_strip(strip.iter().copied().map(Into::into))
                     // Looped lines should be connected with rounded corners, so we always add outward extending caps.
@@ -122,28 +130,33 @@ impl Lines3DVisualizer {
             self.data
                 .add_bounding_box(entity_path.hash(), obj_space_bounding_box, world_from_obj);
 
-            self.data.ui_labels.extend(process_labels_3d(
-                LabeledBatch {
-                    entity_path,
-                    num_instances,
-                    overall_position: obj_space_bounding_box.center(),
-                    instance_positions: data.strips.iter().map(|strip| {
-                        strip
-                            .iter()
-                            .copied()
-                            .map(glam::Vec3::from)
-                            .sum::<glam::Vec3>()
-                            / (strip.len() as f32)
-                    }),
-                    labels: &data.labels,
-                    colors: &colors,
-                    show_labels: data.show_labels.unwrap_or_else(|| {
-                        typed_fallback_for(ctx, LineStrips3D::descriptor_show_labels().component)
-                    }),
-                    annotation_infos: &annotation_infos,
-                },
-                world_from_obj,
-            ));
+            if !ent_context.is_earlier_state {
+                self.data.ui_labels.extend(process_labels_3d(
+                    LabeledBatch {
+                        entity_path,
+                        num_instances,
+                        overall_position: obj_space_bounding_box.center(),
+                        instance_positions: data.strips.iter().map(|strip| {
+                            strip
+                                .iter()
+                                .copied()
+                                .map(glam::Vec3::from)
+                                .sum::<glam::Vec3>()
+                                / (strip.len() as f32)
+                        }),
+                        labels: &data.labels,
+                        colors: &colors,
+                        show_labels: data.show_labels.unwrap_or_else(|| {
+                            typed_fallback_for(
+                                ctx,
+                                LineStrips3D::descriptor_show_labels().component,
+                            )
+                        }),
+                        annotation_infos: &annotation_infos,
+                    },
+                    world_from_obj,
+                ));
+            }
         }
     }
 }
@@ -194,9 +207,11 @@ impl VisualizerSystem for Lines3DVisualizer {
         line_builder.radius_boost_in_ui_points_for_outlines(
             re_view::SIZE_BOOST_IN_POINTS_FOR_LINE_OUTLINES,
         );
+        line_builder
+            .radius_boost_in_ui_points_for_picking(re_view::SIZE_BOOST_IN_POINTS_FOR_LINE_PICKING);
 
-        use super::entity_iterator::{iter_slices, process_archetype};
-        process_archetype::<Self, LineStrips3D, _>(
+        use super::entity_iterator::{iter_slices, process_archetype_with_onion_skin};
+        process_archetype_with_onion_skin::<Self, LineStrips3D, _>(
             ctx,
             view_query,
             context_systems,
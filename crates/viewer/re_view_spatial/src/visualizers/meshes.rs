@@ -81,8 +81,22 @@ impl Mesh3DVisualizer {
             if let Some(mesh) = mesh {
                 // Let's draw the mesh once for every instance transform.
                 // TODO(#7026): We should formalize this kind of hybrid joining better.
-                for &world_from_instance in ent_context.transform_info.target_from_instances() {
+                for (instance_index, &world_from_instance) in ent_context
+                    .transform_info
+                    .target_from_instances()
+                    .iter()
+                    .enumerate()
+                {
                     let world_from_instance = world_from_instance.as_affine3a();
+
+                    // A single value of the component tints all instances.
+                    let additive_tint = ent_context
+                        .instance_colors
+                        .as_ref()
+                        .and_then(|colors| colors.get(instance_index).or(colors.last()).copied())
+                        .flatten()
+                        .unwrap_or(re_renderer::Color32::BLACK);
+
                     instances.extend(mesh.mesh_instances.iter().map(move |mesh_instance| {
                         let entity_from_mesh = mesh_instance.world_from_mesh;
                         let world_from_mesh = world_from_instance * entity_from_mesh;
@@ -94,7 +108,7 @@ impl Mesh3DVisualizer {
                             picking_layer_id: re_view::picking_layer_id_from_instance_path_hash(
                                 picking_instance_hash,
                             ),
-                            additive_tint: re_renderer::Color32::BLACK,
+                            additive_tint,
                         }
                     }));
 
@@ -11,6 +11,8 @@ mod cylinders3d;
 mod depth_images;
 mod ellipsoids;
 mod encoded_image;
+mod gaussian_splats3d;
+mod geo_points3d;
 mod images;
 mod lines2d;
 mod lines3d;
@@ -18,17 +20,28 @@ mod meshes;
 mod points2d;
 mod points3d;
 mod segmentation_images;
+mod skeleton;
+mod tensor_grids;
+mod trajectories;
 mod transform3d_arrows;
 pub mod utilities;
 mod video;
 
+pub use assets3d::Asset3DVisualizer;
+pub use boxes3d::Boxes3DVisualizer;
 pub use cameras::CamerasVisualizer;
 pub use depth_images::DepthImageVisualizer;
+pub use gaussian_splats3d::GaussianSplats3DVisualizer;
+pub use geo_points3d::GeoPoints3DVisualizer;
+pub use meshes::Mesh3DVisualizer;
+pub use points3d::Points3DVisualizer;
 use re_types::{ComponentDescriptor, archetypes};
+pub use tensor_grids::{TensorGridVisualizer, TensorHeightMapVisualizer};
+pub use trajectories::{TrajectoryVisualizer, default_trail_time_range};
 pub use transform3d_arrows::{AxisLengthDetector, Transform3DArrowsVisualizer, add_axis_arrows};
 pub use utilities::{
     SpatialViewVisualizerData, UiLabel, UiLabelStyle, UiLabelTarget, entity_iterator,
-    process_labels_3d, textured_rect_from_image,
+    process_labels_3d, textured_rect_from_image, visible_image_tiles,
 };
 
 /// Shows a loading animation in a spatial view.
@@ -106,6 +119,8 @@ pub fn register_3d_spatial_visualizers(
     system_registry.register_visualizer::<depth_images::DepthImageVisualizer>()?;
     system_registry.register_visualizer::<ellipsoids::Ellipsoids3DVisualizer>()?;
     system_registry.register_visualizer::<encoded_image::EncodedImageVisualizer>()?;
+    system_registry.register_visualizer::<gaussian_splats3d::GaussianSplats3DVisualizer>()?;
+    system_registry.register_visualizer::<geo_points3d::GeoPoints3DVisualizer>()?;
     system_registry.register_visualizer::<images::ImageVisualizer>()?;
     system_registry.register_visualizer::<lines2d::Lines2DVisualizer>()?;
     system_registry.register_visualizer::<lines3d::Lines3DVisualizer>()?;
@@ -113,6 +128,10 @@ pub fn register_3d_spatial_visualizers(
     system_registry.register_visualizer::<points2d::Points2DVisualizer>()?;
     system_registry.register_visualizer::<points3d::Points3DVisualizer>()?;
     system_registry.register_visualizer::<segmentation_images::SegmentationImageVisualizer>()?;
+    system_registry.register_visualizer::<skeleton::SkeletonVisualizer>()?;
+    system_registry.register_visualizer::<tensor_grids::TensorGridVisualizer>()?;
+    system_registry.register_visualizer::<tensor_grids::TensorHeightMapVisualizer>()?;
+    system_registry.register_visualizer::<trajectories::TrajectoryVisualizer>()?;
     system_registry.register_visualizer::<transform3d_arrows::AxisLengthDetector>()?;
     system_registry.register_visualizer::<transform3d_arrows::Transform3DArrowsVisualizer>()?;
     system_registry.register_visualizer::<video::VideoFrameReferenceVisualizer>()?;
@@ -224,6 +243,7 @@ pub fn load_keypoint_connections(
     line_builder: &mut re_renderer::LineDrawableBuilder<'_>,
     annotations: &Annotations,
     world_from_obj: glam::Affine3A,
+    opacity: f32,
     ent_path: &re_entity_db::EntityPath,
     keypoints: &Keypoints,
 ) -> Result<(), ViewSystemExecutionError> {
@@ -253,6 +273,7 @@ pub fn load_keypoint_connections(
     let mut line_batch = line_builder
         .batch("keypoint connections")
         .world_from_obj(world_from_obj)
+        .opacity(opacity)
         .picking_object_id(re_renderer::PickingLayerObjectId(ent_path.hash64()));
 
     // TODO(andreas): Make configurable. Should we pick up the point's radius and make this proportional?
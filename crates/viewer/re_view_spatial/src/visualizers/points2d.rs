@@ -21,7 +21,7 @@ use crate::{
 
 use super::{
     SpatialViewVisualizerData, filter_visualizable_2d_entities,
-    utilities::{LabeledBatch, process_labels_2d},
+    utilities::{LabeledBatch, VisiblePoints, process_labels_2d},
 };
 
 // ---
@@ -65,7 +65,7 @@ impl Points2DVisualizer {
                 .map(|i| PickingLayerInstanceId(i as _))
                 .collect_vec();
 
-            let (annotation_infos, keypoints) = process_annotation_and_keypoint_slices(
+            let (mut annotation_infos, keypoints) = process_annotation_and_keypoint_slices(
                 query.latest_at,
                 num_instances,
                 positions.iter().copied(),
@@ -73,6 +73,7 @@ impl Points2DVisualizer {
                 data.class_ids,
                 &ent_context.annotations,
             );
+            ent_context.hide_filtered_instances(&mut annotation_infos);
 
             // Has not custom fallback for radius, so we use the default.
             // TODO(andreas): It would be nice to have this handle this fallback as part of the query.
@@ -95,6 +96,7 @@ impl Points2DVisualizer {
                 let point_batch = point_builder
                     .batch(entity_path.to_string())
                     .depth_offset(ent_context.depth_offset)
+                    .opacity(ent_context.opacity)
                     .flags(
                         re_renderer::renderer::PointCloudBatchFlags::FLAG_DRAW_AS_CIRCLES
                             | re_renderer::renderer::PointCloudBatchFlags::FLAG_ENABLE_SHADING,
@@ -103,16 +105,26 @@ impl Points2DVisualizer {
                     .outline_mask_ids(ent_context.highlight.overall)
                     .picking_object_id(re_renderer::PickingLayerObjectId(entity_path.hash64()));
 
-                let mut point_range_builder =
-                    point_batch.add_points_2d(&positions, &radii, &colors, &picking_ids);
+                let visible_points = VisiblePoints::new(
+                    &annotation_infos,
+                    &positions,
+                    &radii,
+                    &colors,
+                    &picking_ids,
+                );
+                let mut point_range_builder = point_batch.add_points_2d(
+                    &visible_points.positions,
+                    &visible_points.radii,
+                    &visible_points.colors,
+                    &visible_points.picking_ids,
+                );
 
                 // Determine if there's any sub-ranges that need extra highlighting.
                 {
                     re_tracing::profile_scope!("marking additional highlight points");
                     for (highlighted_key, instance_mask_ids) in &ent_context.highlight.instances {
-                        let highlighted_point_index = (highlighted_key.get()
-                            < num_instances as u64)
-                            .then_some(highlighted_key.get());
+                        let highlighted_point_index =
+                            visible_points.index_of(highlighted_key.get());
                         if let Some(highlighted_point_index) = highlighted_point_index {
                             point_range_builder = point_range_builder
                                 .push_additional_outline_mask_ids_for_range(
@@ -133,25 +145,28 @@ impl Points2DVisualizer {
                 line_builder,
                 &ent_context.annotations,
                 world_from_obj,
+                ent_context.opacity,
                 entity_path,
                 &keypoints,
             )?;
 
-            self.data.ui_labels.extend(process_labels_2d(
-                LabeledBatch {
-                    entity_path,
-                    num_instances,
-                    overall_position: obj_space_bounding_box.center().truncate(),
-                    instance_positions: data.positions.iter().map(|p| glam::vec2(p.x(), p.y())),
-                    labels: &data.labels,
-                    colors: &colors,
-                    show_labels: data.show_labels.unwrap_or_else(|| {
-                        typed_fallback_for(ctx, Points2D::descriptor_show_labels().component)
-                    }),
-                    annotation_infos: &annotation_infos,
-                },
-                world_from_obj,
-            ));
+            if !ent_context.is_earlier_state {
+                self.data.ui_labels.extend(process_labels_2d(
+                    LabeledBatch {
+                        entity_path,
+                        num_instances,
+                        overall_position: obj_space_bounding_box.center().truncate(),
+                        instance_positions: data.positions.iter().map(|p| glam::vec2(p.x(), p.y())),
+                        labels: &data.labels,
+                        colors: &colors,
+                        show_labels: data.show_labels.unwrap_or_else(|| {
+                            typed_fallback_for(ctx, Points2D::descriptor_show_labels().component)
+                        }),
+                        annotation_infos: &annotation_infos,
+                    },
+                    world_from_obj,
+                ));
+            }
         }
 
         Ok(())
@@ -214,14 +229,18 @@ impl VisualizerSystem for Points2DVisualizer {
             re_view::SIZE_BOOST_IN_POINTS_FOR_POINT_OUTLINES,
         );
 
-        use super::entity_iterator::{iter_slices, process_archetype};
-        process_archetype::<Self, Points2D, _>(
+        use super::entity_iterator::{iter_slices, process_archetype_with_onion_skin};
+        process_archetype_with_onion_skin::<Self, Points2D, _>(
             ctx,
             view_query,
             context_systems,
             |ctx, spatial_ctx, results| {
                 use re_view::RangeResultsExt as _;
 
+                if !spatial_ctx.shows_2d_annotations() {
+                    return Ok(());
+                }
+
                 let Some(all_position_chunks) =
                     results.get_required_chunks(Points2D::descriptor_positions().component)
                 else {
@@ -1,9 +1,14 @@
 use itertools::Itertools as _;
 
-use re_renderer::{LineDrawableBuilder, PickingLayerInstanceId, PointCloudBuilder};
+use re_renderer::{
+    LineDrawableBuilder, PickingLayerInstanceId, PointCloudBuilder,
+    renderer::PointCloudCropBox,
+    resource_managers::{PointCloudNormalEstimationSettings, PointCloudNormalEstimationTask},
+};
 use re_types::{
     ArrowString,
     archetypes::Points3D,
+    blueprint::{archetypes::PointShading, components::Enabled},
     components::{ClassId, Color, KeypointId, Position3D, Radius, ShowLabels},
 };
 use re_view::{process_annotation_and_keypoint_slices, process_color_slice};
@@ -12,16 +17,18 @@ use re_viewer_context::{
     ViewContextCollection, ViewQuery, ViewSystemExecutionError, VisualizableEntities,
     VisualizableFilterContext, VisualizerQueryInfo, VisualizerSystem, typed_fallback_for,
 };
+use re_viewport_blueprint::ViewProperty;
 
 use crate::{
     contexts::SpatialSceneEntityContext,
+    crop_box::CropBoxSettings,
     view_kind::SpatialViewKind,
     visualizers::{load_keypoint_connections, process_radius_slice},
 };
 
 use super::{
     SpatialViewVisualizerData, filter_visualizable_3d_entities, process_labels_3d,
-    utilities::LabeledBatch,
+    utilities::{LabeledBatch, VisiblePoints},
 };
 
 // ---
@@ -56,6 +63,7 @@ struct Points3DComponentData<'a> {
 // NOTE: Do not put profile scopes in these methods. They are called for all entities and all
 // timestamps within a time range -- it's _a lot_.
 impl Points3DVisualizer {
+    #[expect(clippy::too_many_arguments)]
     fn process_data<'a>(
         &mut self,
         ctx: &QueryContext<'_>,
@@ -63,6 +71,8 @@ impl Points3DVisualizer {
         line_builder: &mut LineDrawableBuilder<'_>,
         query: &ViewQuery<'_>,
         ent_context: &SpatialSceneEntityContext<'_>,
+        crop_box: Option<PointCloudCropBox>,
+        estimate_normals: bool,
         data: impl Iterator<Item = Points3DComponentData<'a>>,
     ) -> Result<(), ViewSystemExecutionError> {
         let entity_path = ctx.target_entity_path;
@@ -77,7 +87,7 @@ impl Points3DVisualizer {
                 .map(|i| PickingLayerInstanceId(i as _))
                 .collect_vec();
 
-            let (annotation_infos, keypoints) = process_annotation_and_keypoint_slices(
+            let (mut annotation_infos, keypoints) = process_annotation_and_keypoint_slices(
                 query.latest_at,
                 num_instances,
                 data.positions.iter().map(|p| p.0.into()),
@@ -85,6 +95,7 @@ impl Points3DVisualizer {
                 data.class_ids,
                 &ent_context.annotations,
             );
+            ent_context.hide_filtered_instances(&mut annotation_infos);
 
             let positions = bytemuck::cast_slice(data.positions);
 
@@ -94,13 +105,39 @@ impl Points3DVisualizer {
             // TODO(andreas): It would be nice to have this handle this fallback as part of the query.
             let radii =
                 process_radius_slice(entity_path, num_instances, data.radii, Radius::default());
-            let colors = process_color_slice(
+            let mut colors = process_color_slice(
                 ctx,
                 Points3D::descriptor_colors().component,
                 num_instances,
                 &annotation_infos,
                 data.colors,
             );
+            ent_context.apply_instance_colors(&mut colors);
+
+            let visible_points =
+                VisiblePoints::new(&annotation_infos, positions, &radii, &colors, &picking_ids);
+
+            // Normals are in object space, so they can be shared by all instances.
+            let normals = if estimate_normals {
+                let render_ctx = ctx.render_ctx();
+                PointCloudNormalEstimationTask::new(
+                    render_ctx,
+                    &entity_path.to_string(),
+                    &visible_points.positions,
+                    &PointCloudNormalEstimationSettings::default(),
+                )
+                .map_err(|err| err.to_string())
+                .and_then(|task| {
+                    task.estimate_normals(render_ctx)
+                        .map_err(|err| err.to_string())
+                })
+                .map_err(|err| {
+                    re_log::warn_once!("Failed to estimate normals for {entity_path}: {err}");
+                })
+                .ok()
+            } else {
+                None
+            };
 
             // TODO(grtlr): The following is a quick fix to get multiple instance poses to work
             // with point clouds: We sent the same point cloud multiple times to the GPU (bad
@@ -114,19 +151,25 @@ impl Points3DVisualizer {
             {
                 let point_batch = point_builder
                     .batch(entity_path.to_string())
+                    .opacity(ent_context.opacity)
                     .world_from_obj(world_from_obj)
                     .outline_mask_ids(ent_context.highlight.overall)
-                    .picking_object_id(re_renderer::PickingLayerObjectId(entity_path.hash64()));
-
-                let mut point_range_builder =
-                    point_batch.add_points(positions, &radii, &colors, &picking_ids);
+                    .picking_object_id(re_renderer::PickingLayerObjectId(entity_path.hash64()))
+                    .crop_box(crop_box)
+                    .normals(normals.clone());
+
+                let mut point_range_builder = point_batch.add_points(
+                    &visible_points.positions,
+                    &visible_points.radii,
+                    &visible_points.colors,
+                    &visible_points.picking_ids,
+                );
 
                 // Determine if there's any sub-ranges that need extra highlighting.
                 {
                     for (highlighted_key, instance_mask_ids) in &ent_context.highlight.instances {
-                        let highlighted_point_index = (highlighted_key.get()
-                            < num_instances as u64)
-                            .then_some(highlighted_key.get());
+                        let highlighted_point_index =
+                            visible_points.index_of(highlighted_key.get());
                         if let Some(highlighted_point_index) = highlighted_point_index {
                             point_range_builder = point_range_builder
                                 .push_additional_outline_mask_ids_for_range(
@@ -148,25 +191,31 @@ impl Points3DVisualizer {
                     line_builder,
                     &ent_context.annotations,
                     world_from_obj,
+                    ent_context.opacity,
                     entity_path,
                     &keypoints,
                 )?;
 
-                self.data.ui_labels.extend(process_labels_3d(
-                    LabeledBatch {
-                        entity_path,
-                        num_instances,
-                        overall_position: obj_space_bounding_box.center(),
-                        instance_positions: positions.iter().copied(),
-                        labels: &data.labels,
-                        colors: &colors,
-                        show_labels: data.show_labels.unwrap_or_else(|| {
-                            typed_fallback_for(ctx, Points3D::descriptor_show_labels().component)
-                        }),
-                        annotation_infos: &annotation_infos,
-                    },
-                    world_from_obj,
-                ));
+                if !ent_context.is_earlier_state {
+                    self.data.ui_labels.extend(process_labels_3d(
+                        LabeledBatch {
+                            entity_path,
+                            num_instances,
+                            overall_position: obj_space_bounding_box.center(),
+                            instance_positions: positions.iter().copied(),
+                            labels: &data.labels,
+                            colors: &colors,
+                            show_labels: data.show_labels.unwrap_or_else(|| {
+                                typed_fallback_for(
+                                    ctx,
+                                    Points3D::descriptor_show_labels().component,
+                                )
+                            }),
+                            annotation_infos: &annotation_infos,
+                        },
+                        world_from_obj,
+                    ));
+                }
             }
         }
 
@@ -212,8 +261,21 @@ impl VisualizerSystem for Points3DVisualizer {
             re_view::SIZE_BOOST_IN_POINTS_FOR_POINT_OUTLINES,
         );
 
-        use super::entity_iterator::{iter_slices, process_archetype};
-        process_archetype::<Self, Points3D, _>(
+        let crop_box =
+            CropBoxSettings::from_view(ctx).map(|crop_box| crop_box.point_cloud_crop_box());
+        let estimate_normals = ViewProperty::from_archetype::<PointShading>(
+            ctx.blueprint_db(),
+            ctx.blueprint_query(),
+            ctx.view_id,
+        )
+        .component_or_fallback::<Enabled>(
+            ctx,
+            PointShading::descriptor_estimate_normals().component,
+        )
+        .is_ok_and(bool::from);
+
+        use super::entity_iterator::{iter_slices, process_archetype_with_onion_skin};
+        process_archetype_with_onion_skin::<Self, Points3D, _>(
             ctx,
             view_query,
             context_systems,
@@ -292,6 +354,8 @@ impl VisualizerSystem for Points3DVisualizer {
                     &mut line_builder,
                     view_query,
                     spatial_ctx,
+                    crop_box,
+                    estimate_normals,
                     data,
                 )
             },
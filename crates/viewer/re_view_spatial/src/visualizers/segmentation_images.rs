@@ -132,6 +132,7 @@ impl VisualizerSystem for SegmentationImageVisualizer {
                             PickableTexturedRect {
                                 ent_path: entity_path.clone(),
                                 textured_rect,
+                                tiles: Vec::new(),
                                 source_data: PickableRectSourceData::Image {
                                     image,
                                     depth_meter: None,
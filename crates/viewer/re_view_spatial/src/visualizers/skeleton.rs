@@ -0,0 +1,466 @@
+use itertools::Itertools as _;
+
+use re_log_types::Instance;
+use re_renderer::{
+    LineDrawableBuilder, PickingLayerInstanceId, PointCloudBuilder, renderer::LineStripFlags,
+};
+use re_types::{
+    ArrowString,
+    archetypes::Skeleton,
+    components::{
+        AxisLength, ClassId, Color, PoseRotationQuat, PoseTranslation3D, Radius, ShowLabels,
+    },
+};
+use re_view::{process_annotation_slices, process_color_slice};
+use re_viewer_context::{
+    IdentifiedViewSystem, MaybeVisualizableEntities, QueryContext, ViewContext,
+    ViewContextCollection, ViewQuery, ViewSystemExecutionError, VisualizableEntities,
+    VisualizableFilterContext, VisualizerQueryInfo, VisualizerSystem, typed_fallback_for,
+};
+
+use crate::{
+    contexts::SpatialSceneEntityContext, view_kind::SpatialViewKind,
+    visualizers::process_radius_slice,
+};
+
+use super::{
+    SpatialViewVisualizerData, add_axis_arrows, filter_visualizable_3d_entities, process_labels_3d,
+    utilities::LabeledBatch,
+};
+
+// ---
+
+pub struct SkeletonVisualizer {
+    pub data: SpatialViewVisualizerData,
+}
+
+impl Default for SkeletonVisualizer {
+    fn default() -> Self {
+        Self {
+            data: SpatialViewVisualizerData::new(Some(SpatialViewKind::ThreeD)),
+        }
+    }
+}
+
+struct SkeletonComponentData<'a> {
+    // Point of views
+    translations: &'a [PoseTranslation3D],
+
+    // Joints without a parent are roots, joints without a rotation aren't rotated.
+    parents: &'a [u32],
+    quaternions: &'a [PoseRotationQuat],
+
+    // Clamped to edge
+    colors: &'a [Color],
+    radii: &'a [Radius],
+    labels: Vec<ArrowString>,
+    class_ids: &'a [ClassId],
+
+    // Non-repeated
+    show_labels: Option<ShowLabels>,
+    axis_length: Option<AxisLength>,
+}
+
+/// Computes the pose of every joint relative to the entity's origin from the poses relative to their parents.
+///
+/// Joints that reference themselves, reference a joint that doesn't exist, or are part of a cycle are treated as roots.
+fn joint_poses_from_local_poses(
+    parents: &[u32],
+    local_poses: &[glam::Affine3A],
+) -> Vec<glam::Affine3A> {
+    let num_joints = local_poses.len();
+    let parent_of = |joint: usize| {
+        parents
+            .get(joint)
+            .map(|&parent| parent as usize)
+            .filter(|&parent| parent != joint && parent < num_joints)
+    };
+
+    let mut poses: Vec<Option<glam::Affine3A>> = vec![None; num_joints];
+    let mut on_chain = vec![false; num_joints];
+    let mut chain = Vec::new();
+
+    for joint in 0..num_joints {
+        // Walk up the hierarchy until we hit a joint whose pose is already known or a root.
+        let mut current = Some(joint);
+        let mut pose = glam::Affine3A::IDENTITY;
+        while let Some(j) = current {
+            if let Some(known_pose) = poses[j] {
+                pose = known_pose;
+                break;
+            }
+            if on_chain[j] {
+                // Cycle, the joint we started the cycle with becomes a root.
+                break;
+            }
+            on_chain[j] = true;
+            chain.push(j);
+            current = parent_of(j);
+        }
+
+        for j in chain.drain(..).rev() {
+            pose *= local_poses[j];
+            poses[j] = Some(pose);
+            on_chain[j] = false;
+        }
+    }
+
+    poses.into_iter().flatten().collect()
+}
+
+// NOTE: Do not put profile scopes in these methods. They are called for all entities and all
+// timestamps within a time range -- it's _a lot_.
+impl SkeletonVisualizer {
+    fn process_data<'a>(
+        &mut self,
+        ctx: &QueryContext<'_>,
+        point_builder: &mut PointCloudBuilder<'_>,
+        line_builder: &mut LineDrawableBuilder<'_>,
+        query: &ViewQuery<'_>,
+        ent_context: &SpatialSceneEntityContext<'_>,
+        data: impl Iterator<Item = SkeletonComponentData<'a>>,
+    ) {
+        let entity_path = ctx.target_entity_path;
+
+        for data in data {
+            let num_instances = data.translations.len();
+            if num_instances == 0 {
+                continue;
+            }
+
+            let local_poses = data
+                .translations
+                .iter()
+                .enumerate()
+                .map(|(i, translation)| {
+                    let rotation = data
+                        .quaternions
+                        .get(i)
+                        .and_then(|quat| glam::Quat::try_from(quat.0).ok())
+                        .unwrap_or(glam::Quat::IDENTITY);
+                    glam::Affine3A::from_rotation_translation(rotation, translation.0.into())
+                })
+                .collect_vec();
+            let joint_poses = joint_poses_from_local_poses(data.parents, &local_poses);
+            let positions = joint_poses
+                .iter()
+                .map(|pose| glam::Vec3::from(pose.translation))
+                .collect_vec();
+
+            let picking_ids = (0..num_instances)
+                .map(|i| PickingLayerInstanceId(i as _))
+                .collect_vec();
+
+            let annotation_infos = process_annotation_slices(
+                query.latest_at,
+                num_instances,
+                data.class_ids,
+                &ent_context.annotations,
+            );
+
+            let obj_space_bounding_box = macaw::BoundingBox::from_points(positions.iter().copied());
+
+            // Has not custom fallback for radius, so we use the default.
+            let radii =
+                process_radius_slice(entity_path, num_instances, data.radii, Radius::default());
+            let colors = process_color_slice(
+                ctx,
+                Skeleton::descriptor_colors().component,
+                num_instances,
+                &annotation_infos,
+                data.colors,
+            );
+            let axis_length = data.axis_length.map_or(0.0, |axis_length| axis_length.0.0);
+
+            for world_from_obj in ent_context
+                .transform_info
+                .target_from_instances()
+                .iter()
+                .map(|transform| transform.as_affine3a())
+            {
+                let picking_object_id = re_renderer::PickingLayerObjectId(entity_path.hash64());
+
+                // Joints
+                {
+                    let point_batch = point_builder
+                        .batch(entity_path.to_string())
+                        .world_from_obj(world_from_obj)
+                        .outline_mask_ids(ent_context.highlight.overall)
+                        .picking_object_id(picking_object_id);
+
+                    let mut point_range_builder =
+                        point_batch.add_points(&positions, &radii, &colors, &picking_ids);
+
+                    for (highlighted_key, instance_mask_ids) in &ent_context.highlight.instances {
+                        let highlighted_joint_index = highlighted_key.get();
+                        if highlighted_joint_index < num_instances as u64 {
+                            point_range_builder = point_range_builder
+                                .push_additional_outline_mask_ids_for_range(
+                                    highlighted_joint_index as u32
+                                        ..highlighted_joint_index as u32 + 1,
+                                    *instance_mask_ids,
+                                );
+                        }
+                    }
+                }
+
+                // Bones
+                {
+                    let mut line_batch = line_builder
+                        .batch(entity_path.to_string())
+                        .depth_offset(ent_context.depth_offset)
+                        .world_from_obj(world_from_obj)
+                        .outline_mask_ids(ent_context.highlight.overall)
+                        .picking_object_id(picking_object_id);
+
+                    for (joint, parent) in data.parents.iter().enumerate().take(num_instances) {
+                        let parent = *parent as usize;
+                        if parent == joint || parent >= num_instances {
+                            continue;
+                        }
+
+                        let bone = line_batch
+                            .add_segment(positions[parent], positions[joint])
+                            .flags(LineStripFlags::FLAGS_OUTWARD_EXTENDING_ROUND_CAPS)
+                            .color(colors[joint])
+                            .radius(radii[joint] * 0.5)
+                            .picking_instance_id(picking_ids[joint]);
+
+                        if let Some(outline_mask_ids) = ent_context
+                            .highlight
+                            .instances
+                            .get(&Instance::from(joint as u64))
+                        {
+                            bone.outline_mask_ids(*outline_mask_ids);
+                        }
+                    }
+                }
+
+                // Axes
+                if axis_length > 0.0 {
+                    for joint_pose in &joint_poses {
+                        add_axis_arrows(
+                            ctx.viewer_ctx().tokens(),
+                            line_builder,
+                            world_from_obj * *joint_pose,
+                            Some(entity_path),
+                            axis_length,
+                            ent_context.highlight.overall,
+                        );
+                    }
+                }
+
+                self.data.add_bounding_box(
+                    entity_path.hash(),
+                    obj_space_bounding_box,
+                    world_from_obj,
+                );
+
+                self.data.ui_labels.extend(process_labels_3d(
+                    LabeledBatch {
+                        entity_path,
+                        num_instances,
+                        overall_position: obj_space_bounding_box.center(),
+                        instance_positions: positions.iter().copied(),
+                        labels: &data.labels,
+                        colors: &colors,
+                        show_labels: data.show_labels.unwrap_or_else(|| {
+                            typed_fallback_for(ctx, Skeleton::descriptor_show_labels().component)
+                        }),
+                        annotation_infos: &annotation_infos,
+                    },
+                    world_from_obj,
+                ));
+            }
+        }
+    }
+}
+
+impl IdentifiedViewSystem for SkeletonVisualizer {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "Skeleton".into()
+    }
+}
+
+impl VisualizerSystem for SkeletonVisualizer {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<Skeleton>()
+    }
+
+    fn filter_visualizable_entities(
+        &self,
+        entities: MaybeVisualizableEntities,
+        context: &dyn VisualizableFilterContext,
+    ) -> VisualizableEntities {
+        re_tracing::profile_function!();
+        filter_visualizable_3d_entities(entities, context)
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        view_query: &ViewQuery<'_>,
+        context_systems: &ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
+        let mut point_builder = PointCloudBuilder::new(ctx.viewer_ctx.render_ctx());
+        point_builder.radius_boost_in_ui_points_for_outlines(
+            re_view::SIZE_BOOST_IN_POINTS_FOR_POINT_OUTLINES,
+        );
+
+        // The number of bones and axes is harder to predict, so we'll go with the dynamic allocation approach.
+        let mut line_builder = LineDrawableBuilder::new(ctx.viewer_ctx.render_ctx());
+        line_builder.radius_boost_in_ui_points_for_outlines(
+            re_view::SIZE_BOOST_IN_POINTS_FOR_LINE_OUTLINES,
+        );
+        line_builder
+            .radius_boost_in_ui_points_for_picking(re_view::SIZE_BOOST_IN_POINTS_FOR_LINE_PICKING);
+
+        use super::entity_iterator::{iter_slices, process_archetype};
+        process_archetype::<Self, Skeleton, _>(
+            ctx,
+            view_query,
+            context_systems,
+            |ctx, spatial_ctx, results| {
+                use re_view::RangeResultsExt as _;
+
+                let Some(all_translation_chunks) =
+                    results.get_required_chunks(Skeleton::descriptor_translations().component)
+                else {
+                    return Ok(());
+                };
+
+                let num_joints = all_translation_chunks
+                    .iter()
+                    .flat_map(|chunk| chunk.iter_slices::<[f32; 3]>())
+                    .map(|translations| translations.len())
+                    .sum();
+
+                if num_joints == 0 {
+                    return Ok(());
+                }
+
+                point_builder.reserve(num_joints)?;
+
+                let timeline = ctx.query.timeline();
+                let all_translations_indexed =
+                    iter_slices::<[f32; 3]>(&all_translation_chunks, timeline);
+                let all_parents =
+                    results.iter_as(timeline, Skeleton::descriptor_parents().component);
+                let all_quaternions =
+                    results.iter_as(timeline, Skeleton::descriptor_quaternions().component);
+                let all_colors = results.iter_as(timeline, Skeleton::descriptor_colors().component);
+                let all_radii = results.iter_as(timeline, Skeleton::descriptor_radii().component);
+                let all_labels = results.iter_as(timeline, Skeleton::descriptor_labels().component);
+                let all_show_labels =
+                    results.iter_as(timeline, Skeleton::descriptor_show_labels().component);
+                let all_axis_lengths =
+                    results.iter_as(timeline, Skeleton::descriptor_axis_length().component);
+                let all_class_ids =
+                    results.iter_as(timeline, Skeleton::descriptor_class_ids().component);
+
+                let data = re_query::range_zip_1x8(
+                    all_translations_indexed,
+                    all_parents.slice::<u32>(),
+                    all_quaternions.slice::<[f32; 4]>(),
+                    all_colors.slice::<u32>(),
+                    all_radii.slice::<f32>(),
+                    all_labels.slice::<String>(),
+                    all_show_labels.slice::<bool>(),
+                    all_axis_lengths.slice::<f32>(),
+                    all_class_ids.slice::<u16>(),
+                )
+                .map(
+                    |(
+                        _index,
+                        translations,
+                        parents,
+                        quaternions,
+                        colors,
+                        radii,
+                        labels,
+                        show_labels,
+                        axis_lengths,
+                        class_ids,
+                    )| {
+                        SkeletonComponentData {
+                            translations: bytemuck::cast_slice(translations),
+                            parents: parents.unwrap_or_default(),
+                            quaternions: quaternions.map_or(&[], bytemuck::cast_slice),
+                            colors: colors.map_or(&[], bytemuck::cast_slice),
+                            radii: radii.map_or(&[], bytemuck::cast_slice),
+                            labels: labels.unwrap_or_default(),
+                            class_ids: class_ids.map_or(&[], bytemuck::cast_slice),
+                            show_labels: show_labels
+                                .map(|b| !b.is_empty() && b.value(0))
+                                .map(Into::into),
+                            axis_length: axis_lengths
+                                .and_then(|lengths| lengths.first().copied())
+                                .map(Into::into),
+                        }
+                    },
+                );
+
+                self.process_data(
+                    ctx,
+                    &mut point_builder,
+                    &mut line_builder,
+                    view_query,
+                    spatial_ctx,
+                    data,
+                );
+
+                Ok(())
+            },
+        )?;
+
+        Ok(vec![
+            point_builder.into_draw_data()?.into(),
+            line_builder.into_draw_data()?.into(),
+        ])
+    }
+
+    fn data(&self) -> Option<&dyn std::any::Any> {
+        Some(self.data.as_any())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn translation(z: f32) -> glam::Affine3A {
+        glam::Affine3A::from_translation(glam::vec3(0.0, 0.0, z))
+    }
+
+    #[test]
+    fn joint_poses_follow_hierarchy() {
+        // Parents may come after their children.
+        let parents = [2, 0, 2];
+        let local_poses = [translation(1.0), translation(2.0), translation(4.0)];
+
+        let poses = joint_poses_from_local_poses(&parents, &local_poses);
+        let z = poses.iter().map(|pose| pose.translation.z).collect_vec();
+        assert_eq!(z, vec![5.0, 7.0, 4.0]);
+    }
+
+    #[test]
+    fn invalid_parents_are_roots() {
+        // Joint 0 references a joint that doesn't exist, joints 1 & 2 form a cycle,
+        // joint 3 has no parent at all.
+        let parents = [7, 2, 1];
+        let local_poses = [
+            translation(1.0),
+            translation(2.0),
+            translation(4.0),
+            translation(8.0),
+        ];
+
+        let poses = joint_poses_from_local_poses(&parents, &local_poses);
+        let z = poses.iter().map(|pose| pose.translation.z).collect_vec();
+        assert_eq!(z, vec![1.0, 6.0, 4.0, 8.0]);
+    }
+}
@@ -0,0 +1,253 @@
+use re_log_types::{Instance, hash::Hash64};
+use re_renderer::renderer::GpuMeshInstance;
+use re_types::{
+    archetypes::Tensor,
+    components::{TensorData, ValueRange},
+};
+use re_viewer_context::{
+    IdentifiedViewSystem, MaybeVisualizableEntities, QueryContext, ViewContext,
+    ViewContextCollection, ViewQuery, ViewSystemExecutionError, VisualizableEntities,
+    VisualizableFilterContext, VisualizerQueryInfo, VisualizerSystem, typed_fallback_for,
+};
+
+use super::{SpatialViewVisualizerData, filter_visualizable_3d_entities};
+
+use crate::{
+    caches::{AnyMesh, MeshCache, MeshCacheKey},
+    contexts::SpatialSceneEntityContext,
+    mesh_loader::NativeTensorGrid,
+};
+
+// ---
+
+/// Shows 2D tensors as a flat grid of colored cells, e.g. an occupancy grid.
+///
+/// Every cell is one unit large and the grid starts at the origin,
+/// so cell size and placement are controlled with the entity's transform.
+pub struct TensorGridVisualizer(SpatialViewVisualizerData);
+
+/// Shows 2D tensors as a height map, using the value of each cell as its height.
+///
+/// Like [`TensorGridVisualizer`], every cell is one unit large.
+pub struct TensorHeightMapVisualizer(SpatialViewVisualizerData);
+
+impl Default for TensorGridVisualizer {
+    fn default() -> Self {
+        // Tensors are shown in the tensor view by default, not in new 3D views.
+        Self(SpatialViewVisualizerData::new(None))
+    }
+}
+
+impl Default for TensorHeightMapVisualizer {
+    fn default() -> Self {
+        // Tensors are shown in the tensor view by default, not in new 3D views.
+        Self(SpatialViewVisualizerData::new(None))
+    }
+}
+
+impl IdentifiedViewSystem for TensorGridVisualizer {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "TensorGrid".into()
+    }
+}
+
+impl IdentifiedViewSystem for TensorHeightMapVisualizer {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "TensorHeightMap".into()
+    }
+}
+
+impl VisualizerSystem for TensorGridVisualizer {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<Tensor>()
+    }
+
+    fn filter_visualizable_entities(
+        &self,
+        entities: MaybeVisualizableEntities,
+        context: &dyn VisualizableFilterContext,
+    ) -> VisualizableEntities {
+        re_tracing::profile_function!();
+        filter_visualizable_3d_entities(entities, context)
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        view_query: &ViewQuery<'_>,
+        context_systems: &ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
+        let height_map = false;
+        execute_tensor_grids::<Self>(&mut self.0, ctx, view_query, context_systems, height_map)
+    }
+
+    fn data(&self) -> Option<&dyn std::any::Any> {
+        Some(self.0.as_any())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl VisualizerSystem for TensorHeightMapVisualizer {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<Tensor>()
+    }
+
+    fn filter_visualizable_entities(
+        &self,
+        entities: MaybeVisualizableEntities,
+        context: &dyn VisualizableFilterContext,
+    ) -> VisualizableEntities {
+        re_tracing::profile_function!();
+        filter_visualizable_3d_entities(entities, context)
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        view_query: &ViewQuery<'_>,
+        context_systems: &ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
+        let height_map = true;
+        execute_tensor_grids::<Self>(&mut self.0, ctx, view_query, context_systems, height_map)
+    }
+
+    fn data(&self) -> Option<&dyn std::any::Any> {
+        Some(self.0.as_any())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+fn execute_tensor_grids<System: IdentifiedViewSystem>(
+    data: &mut SpatialViewVisualizerData,
+    ctx: &ViewContext<'_>,
+    view_query: &ViewQuery<'_>,
+    context_systems: &ViewContextCollection,
+    height_map: bool,
+) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
+    let mut instances = Vec::new();
+
+    use super::entity_iterator::{iter_component, process_archetype};
+    process_archetype::<System, Tensor, _>(
+        ctx,
+        view_query,
+        context_systems,
+        |ctx, spatial_ctx, results| {
+            use re_view::RangeResultsExt as _;
+
+            let Some(all_tensor_chunks) =
+                results.get_required_chunks(Tensor::descriptor_data().component)
+            else {
+                return Ok(());
+            };
+
+            let timeline = ctx.query.timeline();
+            let all_tensors_indexed = iter_component::<TensorData>(&all_tensor_chunks, timeline);
+            let all_value_ranges =
+                results.iter_as(timeline, Tensor::descriptor_value_range().component);
+            let query_result_hash = results.query_result_hash();
+
+            for ((_time, row_id), tensors, value_ranges) in
+                re_query::range_zip_1x1(all_tensors_indexed, all_value_ranges.slice::<[f64; 2]>())
+            {
+                let Some(tensor) = tensors.first() else {
+                    continue;
+                };
+                if tensor.shape_short().len() != 2 {
+                    // Only 2D tensors can be shown as a grid.
+                    continue;
+                }
+
+                let value_range = value_ranges
+                    .and_then(|ranges| ranges.first().copied())
+                    .unwrap_or_else(|| {
+                        typed_fallback_for::<ValueRange>(
+                            ctx,
+                            Tensor::descriptor_value_range().component,
+                        )
+                        .0
+                        .0
+                    });
+
+                add_tensor_grid(
+                    data,
+                    ctx,
+                    spatial_ctx,
+                    &mut instances,
+                    row_id,
+                    // The same tensor may be shown both as a grid and as a height map.
+                    Hash64::hash((query_result_hash, height_map)),
+                    NativeTensorGrid {
+                        tensor: &tensor.0,
+                        value_range,
+                        height_map,
+                    },
+                );
+            }
+
+            Ok(())
+        },
+    )?;
+
+    match re_renderer::renderer::MeshDrawData::new(ctx.viewer_ctx.render_ctx(), &instances) {
+        Ok(draw_data) => Ok(vec![draw_data.into()]),
+        Err(err) => {
+            re_log::error_once!("Failed to create mesh draw data from tensor grids: {err}");
+            Ok(Vec::new())
+        }
+    }
+}
+
+fn add_tensor_grid(
+    data: &mut SpatialViewVisualizerData,
+    ctx: &QueryContext<'_>,
+    spatial_ctx: &SpatialSceneEntityContext<'_>,
+    instances: &mut Vec<GpuMeshInstance>,
+    row_id: re_chunk_store::RowId,
+    query_result_hash: Hash64,
+    grid: NativeTensorGrid<'_>,
+) {
+    let entity_path = ctx.target_entity_path;
+    let picking_instance_hash = re_entity_db::InstancePathHash::entity_all(entity_path);
+    let outline_mask_ids = spatial_ctx.highlight.index_outline_mask(Instance::ALL);
+
+    let mesh = ctx.store_ctx().caches.entry(|c: &mut MeshCache| {
+        c.entry(
+            &entity_path.to_string(),
+            MeshCacheKey {
+                versioned_instance_path_hash: picking_instance_hash.versioned(row_id),
+                query_result_hash,
+                media_type: None,
+            },
+            AnyMesh::TensorGrid { grid },
+            ctx.render_ctx(),
+        )
+    });
+    let Some(mesh) = mesh else {
+        return;
+    };
+
+    for &world_from_instance in spatial_ctx.transform_info.target_from_instances() {
+        let world_from_instance = world_from_instance.as_affine3a();
+        instances.extend(
+            mesh.mesh_instances
+                .iter()
+                .map(|mesh_instance| GpuMeshInstance {
+                    gpu_mesh: mesh_instance.gpu_mesh.clone(),
+                    world_from_mesh: world_from_instance * mesh_instance.world_from_mesh,
+                    outline_mask_ids,
+                    picking_layer_id: re_view::picking_layer_id_from_instance_path_hash(
+                        picking_instance_hash,
+                    ),
+                    additive_tint: re_renderer::Color32::BLACK,
+                }),
+        );
+
+        data.add_bounding_box(entity_path.hash(), mesh.bbox(), world_from_instance);
+    }
+}
@@ -0,0 +1,257 @@
+use re_log_types::{AbsoluteTimeRange, Instance, TimeInt, TimeType};
+use re_renderer::{Color32, renderer::LineStripFlags};
+use re_types::{
+    Archetype as _,
+    archetypes::Transform3D,
+    blueprint::{
+        archetypes::TrajectoryTrails,
+        components::{Enabled, TimeRange},
+    },
+    datatypes::{TimeInt as DatatypesTimeInt, TimeRangeBoundary},
+};
+use re_viewer_context::{
+    IdentifiedViewSystem, MaybeVisualizableEntities, QueryContext, ViewContext,
+    ViewContextCollection, ViewQuery, ViewSystemExecutionError, VisualizableEntities,
+    VisualizableFilterContext, VisualizerQueryInfo, VisualizerSystem, auto_color_for_entity_path,
+};
+use re_viewport_blueprint::ViewProperty;
+
+use crate::{
+    caches::TransformDatabaseStoreCache, contexts::TransformTreeContext, view_kind::SpatialViewKind,
+};
+
+use super::{
+    SpatialViewVisualizerData, filter_visualizable_3d_entities,
+    transform3d_arrows::Transform3DVisualizabilityFilter,
+};
+
+/// Radius of the most recent part of a trail.
+const TRAIL_RADIUS_UI_POINTS: f32 = 1.5;
+
+/// Relative radius of the oldest part of a trail if fading is enabled.
+const FADED_RADIUS_FACTOR: f32 = 0.2;
+
+/// Draws the past positions of entities with a [`Transform3D`] as a trail.
+///
+/// The trails are configured for the whole view via the [`TrajectoryTrails`] view property.
+pub struct TrajectoryVisualizer(SpatialViewVisualizerData);
+
+impl Default for TrajectoryVisualizer {
+    fn default() -> Self {
+        Self(SpatialViewVisualizerData::new(Some(
+            SpatialViewKind::ThreeD,
+        )))
+    }
+}
+
+impl IdentifiedViewSystem for TrajectoryVisualizer {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "Trajectory".into()
+    }
+}
+
+impl VisualizerSystem for TrajectoryVisualizer {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<Transform3D>()
+    }
+
+    fn data_based_visualizability_filter(
+        &self,
+    ) -> Option<Box<dyn re_viewer_context::DataBasedVisualizabilityFilter>> {
+        Some(Box::new(Transform3DVisualizabilityFilter {
+            visualizability_trigger_components: Transform3D::all_components()
+                .iter()
+                .filter_map(|descr| descr.component_type)
+                .collect(),
+        }))
+    }
+
+    fn filter_visualizable_entities(
+        &self,
+        entities: MaybeVisualizableEntities,
+        context: &dyn VisualizableFilterContext,
+    ) -> VisualizableEntities {
+        filter_visualizable_3d_entities(entities, context)
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        query: &ViewQuery<'_>,
+        context_systems: &ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
+        let mut data_results = query
+            .iter_visible_data_results(Self::identifier())
+            .peekable();
+        if data_results.peek().is_none() {
+            return Ok(Vec::new());
+        }
+
+        let transforms = context_systems.get::<TransformTreeContext>()?;
+
+        let property = ViewProperty::from_archetype::<TrajectoryTrails>(
+            ctx.blueprint_db(),
+            ctx.blueprint_query(),
+            ctx.view_id,
+        );
+        let time_range = property.component_or_fallback::<TimeRange>(
+            ctx,
+            TrajectoryTrails::descriptor_time_range().component,
+        )?;
+        let fade: bool = property
+            .component_or_fallback::<Enabled>(ctx, TrajectoryTrails::descriptor_fade().component)?
+            .into();
+        let color_by_time: bool = property
+            .component_or_fallback::<Enabled>(
+                ctx,
+                TrajectoryTrails::descriptor_color_by_time().component,
+            )?
+            .into();
+
+        let time_range = AbsoluteTimeRange::from_relative_time_range(&time_range, query.latest_at);
+        let time_span =
+            (time_range.max().as_i64() as f64 - time_range.min().as_i64() as f64).max(1.0);
+        let relative_time =
+            |time: TimeInt| ((time.as_i64() - time_range.min().as_i64()) as f64 / time_span) as f32;
+
+        let transform_cache = ctx
+            .viewer_ctx
+            .store_context
+            .caches
+            .entry(|c: &mut TransformDatabaseStoreCache| c.lock_transform_cache(ctx.recording()));
+        let timeline_transforms = transform_cache.transforms_for_timeline(query.timeline);
+
+        let mut line_builder = re_renderer::LineDrawableBuilder::new(ctx.viewer_ctx.render_ctx());
+        line_builder.radius_boost_in_ui_points_for_outlines(
+            re_view::SIZE_BOOST_IN_POINTS_FOR_LINE_OUTLINES,
+        );
+        line_builder
+            .radius_boost_in_ui_points_for_picking(re_view::SIZE_BOOST_IN_POINTS_FOR_LINE_PICKING);
+
+        for data_result in data_results {
+            let entity_path = &data_result.entity_path;
+
+            let Some(transform_info) = transforms.transform_info_for_entity(entity_path.hash())
+            else {
+                continue;
+            };
+            if transforms
+                .pinhole_tree_root_info(transform_info.tree_root())
+                .is_some()
+            {
+                // Positions in image space can't be traced with a 3D trail.
+                continue;
+            }
+
+            let frame = re_tf::TransformFrameIdHash::from_entity_path(entity_path);
+            let Some(frame_transforms) = timeline_transforms.frame_transforms(frame) else {
+                continue;
+            };
+
+            // The trail is drawn in the space of the entity's parent, which we reach by undoing
+            // the entity's own transform at the time cursor.
+            let latest_at_query =
+                re_chunk_store::LatestAtQuery::new(query.timeline, query.latest_at);
+            let Some(parent_from_entity) =
+                frame_transforms.latest_at_transform(ctx.recording(), &latest_at_query)
+            else {
+                continue;
+            };
+            let world_from_entity = transform_info
+                .single_transform_required_for_entity(entity_path, Transform3D::name());
+            let world_from_parent =
+                (world_from_entity * parent_from_entity.transform.inverse()).as_affine3a();
+
+            // Start the trail where the entity was at the beginning of the time range,
+            // even if it only moved later on.
+            let mut times = frame_transforms
+                .frame_transform_times_in_range(time_range.min()..=time_range.max());
+            if times.first() != Some(&time_range.min()) {
+                times.insert(0, time_range.min());
+            }
+
+            let positions = times
+                .into_iter()
+                .filter_map(|time| {
+                    let query = re_chunk_store::LatestAtQuery::new(query.timeline, time);
+                    let parent_from_entity =
+                        frame_transforms.latest_at_transform(ctx.recording(), &query)?;
+                    Some((time, parent_from_entity.transform.translation.as_vec3()))
+                })
+                .collect::<Vec<_>>();
+            if positions.len() < 2 {
+                continue;
+            }
+
+            let entity_color: Color32 = auto_color_for_entity_path(entity_path).into();
+            let outline_mask = query
+                .highlights
+                .entity_outline_mask(entity_path.hash())
+                .overall;
+
+            let mut line_batch = line_builder
+                .batch(entity_path.to_string())
+                .world_from_obj(world_from_parent)
+                .outline_mask_ids(outline_mask)
+                .picking_object_id(re_renderer::PickingLayerObjectId(entity_path.hash64()));
+            let picking_instance_id = re_renderer::PickingLayerInstanceId(Instance::ALL.get());
+
+            let mut bounding_box = macaw::BoundingBox::nothing();
+            for segment in positions.windows(2) {
+                let [(_, start), (end_time, end)] = segment else {
+                    continue;
+                };
+                bounding_box.extend(*start);
+                bounding_box.extend(*end);
+
+                let t = relative_time(*end_time).clamp(0.0, 1.0);
+                let radius = if fade {
+                    TRAIL_RADIUS_UI_POINTS * (FADED_RADIUS_FACTOR + (1.0 - FADED_RADIUS_FACTOR) * t)
+                } else {
+                    TRAIL_RADIUS_UI_POINTS
+                };
+                let color = if color_by_time {
+                    let [r, g, b, a] = re_renderer::colormap_turbo_srgb(t);
+                    Color32::from_rgba_unmultiplied(r, g, b, a)
+                } else {
+                    entity_color
+                };
+
+                line_batch
+                    .add_segment(*start, *end)
+                    .radius(re_renderer::Size::new_ui_points(radius))
+                    .color(color)
+                    .flags(
+                        LineStripFlags::FLAG_CAP_START_ROUND | LineStripFlags::FLAG_CAP_END_ROUND,
+                    )
+                    .picking_instance_id(picking_instance_id);
+            }
+
+            self.0
+                .add_bounding_box(entity_path.hash(), bounding_box, world_from_parent);
+        }
+
+        Ok(vec![line_builder.into_draw_data()?.into()])
+    }
+
+    fn data(&self) -> Option<&dyn std::any::Any> {
+        Some(self.0.as_any())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+/// The 10 seconds before the time cursor, or the 100 steps before it on sequence timelines.
+pub fn default_trail_time_range(ctx: &QueryContext<'_>) -> TimeRange {
+    let start = match ctx.viewer_ctx().time_ctrl.timeline().typ() {
+        TimeType::Sequence => -100,
+        TimeType::DurationNs | TimeType::TimestampNs => -10_000_000_000,
+    };
+
+    TimeRange(re_types::datatypes::TimeRange {
+        start: TimeRangeBoundary::CursorRelative(DatatypesTimeInt(start)),
+        end: TimeRangeBoundary::CursorRelative(DatatypesTimeInt(0)),
+    })
+}
@@ -29,8 +29,8 @@ impl IdentifiedViewSystem for Transform3DArrowsVisualizer {
     }
 }
 
-struct Transform3DVisualizabilityFilter {
-    visualizability_trigger_components: IntSet<ComponentType>,
+pub(super) struct Transform3DVisualizabilityFilter {
+    pub(super) visualizability_trigger_components: IntSet<ComponentType>,
 }
 
 impl re_viewer_context::DataBasedVisualizabilityFilter for Transform3DVisualizabilityFilter {
@@ -92,6 +92,8 @@ impl VisualizerSystem for Transform3DArrowsVisualizer {
         line_builder.radius_boost_in_ui_points_for_outlines(
             re_view::SIZE_BOOST_IN_POINTS_FOR_LINE_OUTLINES,
         );
+        line_builder
+            .radius_boost_in_ui_points_for_picking(re_view::SIZE_BOOST_IN_POINTS_FOR_LINE_PICKING);
 
         for data_result in query.iter_visible_data_results(Self::identifier()) {
             // Use transform without potential pinhole, since we don't want to visualize image-space coordinates.
@@ -1,12 +1,19 @@
+use re_chunk_store::LatestAtQuery;
 use re_log_types::{TimeInt, TimelineName};
 use re_types::Archetype;
 use re_view::{AnnotationSceneContext, ChunksWithComponent, DataResultQuery as _, HybridResults};
 use re_viewer_context::{
-    IdentifiedViewSystem, QueryContext, ViewContext, ViewContextCollection, ViewQuery,
-    ViewSystemExecutionError,
+    IdentifiedViewSystem, QueryContext, QueryRange, ViewContext, ViewContextCollection,
+    ViewOutlineMasks, ViewQuery, ViewSystemExecutionError,
 };
 
-use crate::contexts::{EntityDepthOffsets, SpatialSceneEntityContext, TransformTreeContext};
+use crate::{
+    color_by_component::ColorByComponentSettings,
+    contexts::{EntityDepthOffsets, SpatialSceneEntityContext, TransformTreeContext},
+    instance_filter::InstanceFilterSettings,
+    onion_skin::OnionSkinSettings,
+    ui::SpatialViewState,
+};
 
 // ---
 
@@ -90,6 +97,46 @@ pub fn process_archetype<System: IdentifiedViewSystem, A, F>(
     ctx: &ViewContext<'_>,
     query: &ViewQuery<'_>,
     context_systems: &ViewContextCollection,
+    fun: F,
+) -> Result<(), ViewSystemExecutionError>
+where
+    A: Archetype,
+    F: FnMut(
+        &QueryContext<'_>,
+        &SpatialSceneEntityContext<'_>,
+        &HybridResults<'_>,
+    ) -> Result<(), ViewSystemExecutionError>,
+{
+    process_archetype_impl::<System, A, F>(ctx, query, context_systems, false, fun)
+}
+
+/// Like [`process_archetype`], but the callback is additionally called for every earlier state
+/// of an entity that is shown by the view's onion skin.
+///
+/// Earlier states are passed with a [`SpatialSceneEntityContext::opacity`] below 1.0 and never highlighted.
+/// Only entities that show their latest state get an onion skin, visible time ranges take precedence.
+pub fn process_archetype_with_onion_skin<System: IdentifiedViewSystem, A, F>(
+    ctx: &ViewContext<'_>,
+    query: &ViewQuery<'_>,
+    context_systems: &ViewContextCollection,
+    fun: F,
+) -> Result<(), ViewSystemExecutionError>
+where
+    A: Archetype,
+    F: FnMut(
+        &QueryContext<'_>,
+        &SpatialSceneEntityContext<'_>,
+        &HybridResults<'_>,
+    ) -> Result<(), ViewSystemExecutionError>,
+{
+    process_archetype_impl::<System, A, F>(ctx, query, context_systems, true, fun)
+}
+
+fn process_archetype_impl<System: IdentifiedViewSystem, A, F>(
+    ctx: &ViewContext<'_>,
+    query: &ViewQuery<'_>,
+    context_systems: &ViewContextCollection,
+    with_onion_skin: bool,
     mut fun: F,
 ) -> Result<(), ViewSystemExecutionError>
 where
@@ -105,6 +152,19 @@ where
     let annotations = context_systems.get::<AnnotationSceneContext>()?;
 
     let latest_at = query.latest_at_query();
+    let instance_filter = InstanceFilterSettings::from_view(ctx);
+    let hidden_instances = ctx
+        .view_state
+        .as_any()
+        .downcast_ref::<SpatialViewState>()
+        .map(|state| &state.state_3d.hidden_instances);
+    let onion_skin = if with_onion_skin {
+        OnionSkinSettings::from_view(ctx)
+    } else {
+        None
+    };
+    let components = A::all_component_identifiers().collect::<Vec<_>>();
+    let no_highlight = ViewOutlineMasks::default();
 
     let system_identifier = System::identifier();
 
@@ -115,6 +175,19 @@ where
             continue;
         };
 
+        let filtered_instances = instance_filter.as_ref().and_then(|instance_filter| {
+            instance_filter.hidden_instances(ctx.recording(), &latest_at, &data_result.entity_path)
+        });
+        let filtered_instances = match hidden_instances {
+            Some(hidden_instances) => {
+                hidden_instances.add_to_filtered(&data_result.entity_path, filtered_instances)
+            }
+            None => filtered_instances,
+        };
+
+        let color_by_component =
+            ColorByComponentSettings::from_overrides(ctx.viewer_ctx, data_result);
+
         let depth_offset_key = (system_identifier, data_result.entity_path.hash());
         let entity_context = SpatialSceneEntityContext {
             transform_info,
@@ -128,6 +201,17 @@ where
                 .highlights
                 .entity_outline_mask(data_result.entity_path.hash()),
             view_class_identifier: context_systems.view_class_identifier(),
+            image_plane: transforms.image_plane_settings_for(transform_info),
+            filtered_instances,
+            instance_colors: color_by_component.as_ref().and_then(|color_by_component| {
+                color_by_component.instance_colors(
+                    ctx.recording(),
+                    &latest_at,
+                    &data_result.entity_path,
+                )
+            }),
+            is_earlier_state: false,
+            opacity: 1.0,
         };
 
         let results = data_result.query_archetype_with_history::<A>(ctx, query);
@@ -139,6 +223,63 @@ where
             re_tracing::profile_scope!(format!("{}", data_result.entity_path));
             fun(&query_ctx, &entity_context, &results)?;
         }
+
+        let Some(onion_skin) = &onion_skin else {
+            continue;
+        };
+        if data_result.query_range() != &QueryRange::LatestAt {
+            continue;
+        }
+
+        for earlier_state in
+            onion_skin.earlier_states(ctx, &latest_at, &data_result.entity_path, &components)
+        {
+            let earlier_query = LatestAtQuery::new(query.timeline, earlier_state.time);
+            let earlier_transform_info =
+                transform_info.with_moved_source(earlier_state.entity_from_earlier_entity);
+            let earlier_entity_context = SpatialSceneEntityContext {
+                transform_info: &earlier_transform_info,
+                depth_offset: entity_context.depth_offset,
+                annotations: entity_context.annotations.clone(),
+                highlight: &no_highlight,
+                view_class_identifier: entity_context.view_class_identifier,
+                image_plane: entity_context.image_plane,
+                filtered_instances: instance_filter.as_ref().and_then(|instance_filter| {
+                    instance_filter.hidden_instances(
+                        ctx.recording(),
+                        &earlier_query,
+                        &data_result.entity_path,
+                    )
+                }),
+                instance_colors: color_by_component.as_ref().and_then(|color_by_component| {
+                    color_by_component.instance_colors(
+                        ctx.recording(),
+                        &earlier_query,
+                        &data_result.entity_path,
+                    )
+                }),
+                is_earlier_state: true,
+                opacity: earlier_state.opacity,
+            };
+
+            let query_shadowed_components = false;
+            let earlier_results = re_view::latest_at_with_blueprint_resolved_data(
+                ctx,
+                None,
+                &earlier_query,
+                data_result,
+                components.iter().copied(),
+                query_shadowed_components,
+            );
+            let earlier_results: HybridResults<'_> =
+                (earlier_query.clone(), earlier_results).into();
+
+            let mut query_ctx = ctx.query_context(data_result, &earlier_query);
+            query_ctx.archetype_name = Some(A::name());
+
+            re_tracing::profile_scope!("onion skin");
+            fun(&query_ctx, &earlier_entity_context, &earlier_results)?;
+        }
     }
 
     Ok(())
@@ -154,7 +154,13 @@ pub fn process_labels<'a, P: 'a>(
         annotation_infos.iter(),
         labels.iter().map(Some).chain(std::iter::repeat(None))
     )
-    .map(|(annotation_info, label)| annotation_info.label(label.map(|l| l.as_str())));
+    .map(|(annotation_info, label)| {
+        if annotation_info.is_hidden {
+            None
+        } else {
+            annotation_info.label(label.map(|l| l.as_str()))
+        }
+    });
 
     let colors = clamped_or(colors, &Color32::PLACEHOLDER);
 
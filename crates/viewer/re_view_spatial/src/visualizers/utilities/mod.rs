@@ -3,6 +3,7 @@ mod labels;
 mod proc_mesh_vis;
 mod spatial_view_visualizer;
 mod textured_rect;
+mod visible_points;
 
 pub use labels::{
     LabeledBatch, UiLabel, UiLabelStyle, UiLabelTarget, process_labels, process_labels_2d,
@@ -10,4 +11,5 @@ pub use labels::{
 };
 pub use proc_mesh_vis::{ProcMeshBatch, ProcMeshDrawableBuilder};
 pub use spatial_view_visualizer::SpatialViewVisualizerData;
-pub use textured_rect::textured_rect_from_image;
+pub use textured_rect::{textured_rect_from_image, visible_image_tiles};
+pub use visible_points::VisiblePoints;
@@ -133,6 +133,8 @@ impl<'ctx> ProcMeshDrawableBuilder<'ctx> {
         line_builder.radius_boost_in_ui_points_for_outlines(
             re_view::SIZE_BOOST_IN_POINTS_FOR_LINE_OUTLINES,
         );
+        line_builder
+            .radius_boost_in_ui_points_for_picking(re_view::SIZE_BOOST_IN_POINTS_FOR_LINE_PICKING);
 
         ProcMeshDrawableBuilder {
             data,
@@ -174,12 +176,13 @@ impl<'ctx> ProcMeshDrawableBuilder<'ctx> {
 
         let half_sizes = clamped_or_nothing(batch.half_sizes, num_instances);
 
-        let annotation_infos = process_annotation_slices(
+        let mut annotation_infos = process_annotation_slices(
             self.query.latest_at,
             num_instances,
             batch.class_ids,
             &ent_context.annotations,
         );
+        ent_context.hide_filtered_instances(&mut annotation_infos);
 
         // Has not custom fallback for radius, so we use the default.
         // TODO(andreas): It would be nice to have this handle this fallback as part of the query.
@@ -189,13 +192,14 @@ impl<'ctx> ProcMeshDrawableBuilder<'ctx> {
             batch.line_radii,
             components::Radius::default(),
         );
-        let colors = process_color_slice(
+        let mut colors = process_color_slice(
             query_context,
             color_component,
             num_instances,
             &annotation_infos,
             batch.colors,
         );
+        ent_context.apply_instance_colors(&mut colors);
 
         let mut line_batch = self
             .line_builder
@@ -216,20 +220,33 @@ impl<'ctx> ProcMeshDrawableBuilder<'ctx> {
         let mut num_instances = 0;
         for (
             instance_index,
-            (half_size, world_from_instance, radius, &color, proc_mesh_key, fill_mode),
+            (
+                half_size,
+                world_from_instance,
+                radius,
+                &color,
+                proc_mesh_key,
+                fill_mode,
+                annotation_info,
+            ),
         ) in itertools::izip!(
             half_sizes,
             world_from_instances,
             line_radii,
             colors.iter(),
             batch.meshes,
-            batch.fill_modes
+            batch.fill_modes,
+            annotation_infos.iter()
         )
         .enumerate()
         {
             let instance = Instance::from(instance_index as u64);
             num_instances = instance_index + 1;
 
+            if annotation_info.is_hidden {
+                continue;
+            }
+
             let world_from_instance = world_from_instance
                 * glam::Affine3A::from_scale(glam::Vec3::from(*half_size))
                 * constant_instance_transform;
@@ -4,7 +4,8 @@ use re_viewer_context::ViewClass as _;
 
 use super::UiLabel;
 use crate::{
-    PickableTexturedRect, SpatialView2D, view_kind::SpatialViewKind, visualizers::LoadingSpinner,
+    PickableTexturedRect, SpatialView2D, hover_correlation::HoverCorrelationProvider,
+    view_kind::SpatialViewKind, visualizers::LoadingSpinner,
 };
 
 /// Common data struct for all spatial scene elements.
@@ -23,6 +24,9 @@ pub struct SpatialViewVisualizerData {
     /// Textured rectangles that the visualizer produced which can be interacted with.
     pub pickable_rects: Vec<PickableTexturedRect>,
 
+    /// Providers that correlate hovered positions in 2D with points in 3D.
+    pub hover_correlations: Vec<Box<dyn HoverCorrelationProvider>>,
+
     /// The view kind preferred by this visualizer (used for heuristics).
     pub preferred_view_kind: Option<SpatialViewKind>,
 }
@@ -34,6 +38,7 @@ impl SpatialViewVisualizerData {
             ui_labels: Default::default(),
             bounding_boxes: Default::default(),
             pickable_rects: Default::default(),
+            hover_correlations: Default::default(),
             preferred_view_kind,
         }
     }
@@ -47,6 +52,10 @@ impl SpatialViewVisualizerData {
         self.pickable_rects.push(pickable_rect);
     }
 
+    pub fn add_hover_correlation(&mut self, provider: impl HoverCorrelationProvider + 'static) {
+        self.hover_correlations.push(Box::new(provider));
+    }
+
     pub fn add_bounding_box(
         &mut self,
         entity: EntityPathHash,
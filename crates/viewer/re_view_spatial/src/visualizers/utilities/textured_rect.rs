@@ -3,9 +3,15 @@ use glam::Vec3;
 use re_log_types::EntityPath;
 use re_renderer::renderer;
 use re_types::ArchetypeName;
-use re_viewer_context::{ColormapWithRange, ImageInfo, ImageStatsCache, ViewerContext, gpu_bridge};
+use re_viewer_context::{
+    ColormapWithRange, ImageInfo, ImageStats, ImageStatsCache, ImageTileCache, ImageTileId,
+    QueryContext, ViewerContext, gpu_bridge,
+};
 
-use crate::contexts::SpatialSceneEntityContext;
+use crate::{SpatialViewState, contexts::SpatialSceneEntityContext};
+
+/// Tiles are only streamed as long as this few of them cover the view.
+const MAX_VISIBLE_TILES: usize = 64;
 
 pub fn textured_rect_from_image(
     ctx: &ViewerContext<'_>,
@@ -22,14 +28,24 @@ pub fn textured_rect_from_image(
         .caches
         .entry(|c: &mut ImageStatsCache| c.entry(image));
 
-    match gpu_bridge::image_to_gpu(
-        ctx.render_ctx(),
-        &debug_name,
-        image,
-        &tensor_stats,
-        &ent_context.annotations,
-        colormap,
-    ) {
+    // Images too large to upload as a whole are represented by a low resolution overview,
+    // see `visible_image_tiles` for the full resolution.
+    let colormapped_texture = if ImageTileCache::should_tile(image) {
+        let overview = ImageTileId::overview(image.width_height());
+        image_tile_to_gpu(ctx, &debug_name, image, &tensor_stats, overview)
+            .ok_or_else(|| anyhow::anyhow!("failed to upload overview of the image"))
+    } else {
+        gpu_bridge::image_to_gpu(
+            ctx.render_ctx(),
+            &debug_name,
+            image,
+            &tensor_stats,
+            &ent_context.annotations,
+            colormap,
+        )
+    };
+
+    match colormapped_texture {
         Ok(colormapped_texture) => {
             // TODO(emilk): let users pick texture filtering.
             // Always use nearest for magnification: let users see crisp individual pixels when they zoom
@@ -53,7 +69,7 @@ pub fn textured_rect_from_image(
                 .single_transform_required_for_entity(ent_path, archetype_name)
                 .as_affine3a();
 
-            let textured_rect = renderer::TexturedRect {
+            let mut textured_rect = renderer::TexturedRect {
                 top_left_corner_position: world_from_entity.transform_point3(Vec3::ZERO),
                 extent_u: world_from_entity.transform_vector3(Vec3::X * image.width() as f32),
                 extent_v: world_from_entity.transform_vector3(Vec3::Y * image.height() as f32),
@@ -66,8 +82,12 @@ pub fn textured_rect_from_image(
                     multiplicative_tint,
                     depth_offset: ent_context.depth_offset,
                     outline_mask: ent_context.highlight.overall,
+                    cull_back_face: false,
                 },
             };
+            if let Some(image_plane) = &ent_context.image_plane {
+                image_plane.apply_to_rectangle(&mut textured_rect.options);
+            }
 
             Some(textured_rect)
         }
@@ -78,3 +98,117 @@ pub fn textured_rect_from_image(
         }
     }
 }
+
+/// The full resolution tiles of an image too large to upload as a whole,
+/// covering what a 2D view shows at the resolution it shows them.
+///
+/// `textured_rect` is the rectangle [`textured_rect_from_image`] created for the image.
+/// Returns nothing for other images, for images not lying flat in a 2D view,
+/// and while some of the visible tiles aren't uploaded yet.
+/// The low resolution overview is shown in those cases.
+pub fn visible_image_tiles(
+    ctx: &QueryContext<'_>,
+    image: &ImageInfo,
+    textured_rect: &renderer::TexturedRect,
+) -> Vec<renderer::TexturedRect> {
+    if !ImageTileCache::should_tile(image) {
+        return Vec::new();
+    }
+    let Some(visible) = ctx
+        .view_state()
+        .as_any()
+        .downcast_ref::<SpatialViewState>()
+        .and_then(|state| state.visible_scene_rect_2d)
+    else {
+        return Vec::new();
+    };
+
+    // Map the visible part of the scene into image pixels.
+    let image_size = image.width_height();
+    let [width, height] = image_size.map(|size| size as f32);
+    let scene_from_pixel = glam::Mat2::from_cols(
+        textured_rect.extent_u.truncate() / width,
+        textured_rect.extent_v.truncate() / height,
+    );
+    let determinant = scene_from_pixel.determinant();
+    if determinant.abs() < f32::MIN_POSITIVE {
+        return Vec::new();
+    }
+    let pixel_from_scene = scene_from_pixel.inverse();
+    let scene_origin = textured_rect.top_left_corner_position.truncate();
+    let visible_pixels = egui::Rect::from_points(
+        &[
+            visible.scene_rect.left_top(),
+            visible.scene_rect.right_top(),
+            visible.scene_rect.left_bottom(),
+            visible.scene_rect.right_bottom(),
+        ]
+        .map(|corner| {
+            let pixel = pixel_from_scene * (glam::vec2(corner.x, corner.y) - scene_origin);
+            egui::pos2(pixel.x, pixel.y)
+        }),
+    );
+
+    let image_pixels_per_screen_pixel = 1.0 / (determinant.abs().sqrt() * visible.pixels_per_unit);
+    let level = ImageTileId::level_for_scale(image_size, image_pixels_per_screen_pixel);
+    if level == ImageTileId::overview(image_size).level {
+        return Vec::new(); // The overview is all we need.
+    }
+
+    let tiles = ImageTileId::covering(image_size, visible_pixels, level);
+    if tiles.len() > MAX_VISIBLE_TILES {
+        return Vec::new();
+    }
+
+    let debug_name = ctx.target_entity_path.to_string();
+    let image_stats = ctx
+        .store_ctx()
+        .caches
+        .entry(|c: &mut ImageStatsCache| c.entry(image));
+
+    // Keep requesting all tiles, so that the missing ones keep being uploaded.
+    let rects = tiles
+        .into_iter()
+        .map(|tile| {
+            let colormapped_texture =
+                image_tile_to_gpu(ctx.viewer_ctx(), &debug_name, image, &image_stats, tile)?;
+            let pixel_rect = tile.pixel_rect(image_size);
+            Some(renderer::TexturedRect {
+                top_left_corner_position: textured_rect.top_left_corner_position
+                    + textured_rect.extent_u * (pixel_rect.min.x / width)
+                    + textured_rect.extent_v * (pixel_rect.min.y / height),
+                extent_u: textured_rect.extent_u * (pixel_rect.width() / width),
+                extent_v: textured_rect.extent_v * (pixel_rect.height() / height),
+                colormapped_texture,
+                options: textured_rect.options.clone(),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    if let Some(rects) = rects.into_iter().collect::<Option<Vec<_>>>() {
+        rects
+    } else {
+        ctx.egui_ctx().request_repaint();
+        Vec::new()
+    }
+}
+
+fn image_tile_to_gpu(
+    ctx: &ViewerContext<'_>,
+    debug_name: &str,
+    image: &ImageInfo,
+    image_stats: &ImageStats,
+    tile: ImageTileId,
+) -> Option<renderer::ColormappedTexture> {
+    let texture = ctx
+        .store_context
+        .caches
+        .entry(|c: &mut ImageTileCache| c.entry(ctx.render_ctx(), debug_name, image, tile))?;
+    Some(gpu_bridge::colormapped_color_texture(
+        ctx.render_ctx(),
+        debug_name,
+        texture,
+        image,
+        image_stats,
+    ))
+}
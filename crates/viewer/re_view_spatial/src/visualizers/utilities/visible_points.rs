@@ -0,0 +1,70 @@
+use std::borrow::Cow;
+
+use re_renderer::{Color32, PickingLayerInstanceId, Size};
+use re_viewer_context::ResolvedAnnotationInfos;
+
+/// The per-instance data of a batch of points, without the points of classes hidden by the user.
+///
+/// See [`re_viewer_context::AnnotationClassOverrides`].
+pub struct VisiblePoints<'a> {
+    pub positions: Cow<'a, [glam::Vec3]>,
+    pub radii: Cow<'a, [Size]>,
+    pub colors: Cow<'a, [Color32]>,
+
+    /// Still refer to the original instances.
+    pub picking_ids: Cow<'a, [PickingLayerInstanceId]>,
+}
+
+impl<'a> VisiblePoints<'a> {
+    /// All slices are expected to have one element per instance.
+    pub fn new(
+        annotation_infos: &ResolvedAnnotationInfos,
+        positions: &'a [glam::Vec3],
+        radii: &'a [Size],
+        colors: &'a [Color32],
+        picking_ids: &'a [PickingLayerInstanceId],
+    ) -> Self {
+        if !annotation_infos.any_hidden() {
+            // Common happy path.
+            return Self {
+                positions: Cow::Borrowed(positions),
+                radii: Cow::Borrowed(radii),
+                colors: Cow::Borrowed(colors),
+                picking_ids: Cow::Borrowed(picking_ids),
+            };
+        }
+
+        re_tracing::profile_function!();
+
+        let is_visible = annotation_infos
+            .iter()
+            .map(|info| !info.is_hidden)
+            .collect::<Vec<_>>();
+
+        Self {
+            positions: retain_visible(positions, &is_visible),
+            radii: retain_visible(radii, &is_visible),
+            colors: retain_visible(colors, &is_visible),
+            picking_ids: retain_visible(picking_ids, &is_visible),
+        }
+    }
+
+    /// Index of the given instance among the visible points, `None` if it isn't visible.
+    pub fn index_of(&self, instance: u64) -> Option<u32> {
+        self.picking_ids
+            .binary_search_by_key(&instance, |id| id.0)
+            .ok()
+            .map(|index| index as u32)
+    }
+}
+
+fn retain_visible<T: Clone>(values: &[T], is_visible: &[bool]) -> Cow<'static, [T]> {
+    Cow::Owned(
+        values
+            .iter()
+            .zip(is_visible)
+            .filter(|(_, is_visible)| **is_visible)
+            .map(|(value, _)| value.clone())
+            .collect(),
+    )
+}
@@ -9,7 +9,9 @@ use re_log_types::{EntityPath, EntityPathHash, hash::Hash64};
 use re_renderer::{renderer, resource_managers::ImageDataDesc};
 use re_viewer_context::{ViewClass as _, ViewContext, ViewId, ViewSystemIdentifier};
 
-use crate::{PickableRectSourceData, PickableTexturedRect, SpatialView2D};
+use crate::{
+    PickableRectSourceData, PickableTexturedRect, SpatialView2D, image_planes::ImagePlaneSettings,
+};
 
 use super::{LoadingSpinner, SpatialViewVisualizerData, UiLabel, UiLabelStyle, UiLabelTarget};
 
@@ -35,6 +37,7 @@ fn visualize_video_frame_texture(
     highlight: &re_viewer_context::ViewOutlineMasks,
     fallback_video_size: glam::Vec2,
     multiplicative_tint: egui::Rgba,
+    image_plane: Option<ImagePlaneSettings>,
 ) {
     let re_renderer::video::VideoFrameTexture {
         texture,
@@ -71,7 +74,7 @@ fn visualize_video_frame_texture(
     }
 
     if let Some(texture) = texture {
-        let textured_rect = renderer::TexturedRect {
+        let mut textured_rect = renderer::TexturedRect {
             top_left_corner_position,
             extent_u,
             extent_v,
@@ -82,12 +85,17 @@ fn visualize_video_frame_texture(
                 outline_mask: highlight.overall,
                 depth_offset,
                 multiplicative_tint,
+                cull_back_face: false,
             },
         };
+        if let Some(image_plane) = &image_plane {
+            image_plane.apply_to_rectangle(&mut textured_rect.options);
+        }
         visualizer_data.add_pickable_rect(
             PickableTexturedRect {
                 ent_path: entity_path.clone(),
                 textured_rect,
+                tiles: Vec::new(),
                 source_data: PickableRectSourceData::Video,
             },
             ctx.view_class_identifier,
@@ -245,6 +253,7 @@ fn show_video_playback_issue(
         PickableTexturedRect {
             ent_path: entity_path.clone(),
             textured_rect: error_rect,
+            tiles: Vec::new(),
             source_data: PickableRectSourceData::Placeholder,
         },
         ctx.view_class_identifier,
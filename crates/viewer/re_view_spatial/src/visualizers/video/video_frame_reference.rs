@@ -226,6 +226,7 @@ impl VideoFrameReferenceVisualizer {
                                 spatial_ctx.highlight,
                                 video_resolution,
                                 multiplicative_tint,
+                                spatial_ctx.image_plane,
                             );
                         }
 
@@ -194,6 +194,7 @@ impl VisualizerSystem for VideoStreamVisualizer {
                         highlight,
                         video_resolution,
                         multiplicative_tint,
+                        transforms.image_plane_settings_for(transform_info),
                     );
                 }
 
@@ -1,19 +1,25 @@
 //! Rerun time series View
 //!
-//! A View that shows plots over Rerun timelines.
+//! A View that shows plots over Rerun timelines, and one that shows their spectrograms.
 
 #![warn(clippy::iter_over_hash_type)] //  TODO(#6198): enable everywhere
 
 mod aggregation;
 mod line_visualizer_system;
 mod point_visualizer_system;
+mod scalar_aggregates_subscriber;
 mod series_query;
+mod spectrogram;
+mod spectrogram_view_class;
+mod spectrogram_visualizer_system;
+mod units;
 mod util;
 mod view_class;
 
 use re_types::components::{AggregationPolicy, MarkerShape};
 use re_viewer_context::external::re_entity_db::InstancePath;
 
+pub use spectrogram_view_class::SpectrogramView;
 pub use view_class::TimeSeriesView;
 
 /// Computes a deterministic, globally unique ID for the plot based on the ID of the view
@@ -87,6 +93,9 @@ pub struct PlotSeries {
     /// Label of the series.
     pub label: String,
 
+    /// Unit the values of the series are in, if known.
+    pub unit: Option<String>,
+
     pub color: egui::Color32,
 
     /// Radius of markers, or stroke radius for lines.
@@ -95,6 +104,11 @@ pub struct PlotSeries {
     pub kind: PlotSeriesKind,
     pub points: Vec<(i64, f64)>,
 
+    /// Minimum and maximum of the values aggregated into each of the [`PlotSeries::points`].
+    ///
+    /// Only set for series drawn as a band, see [`AggregationPolicy::MinMeanMax`].
+    pub band: Vec<(f64, f64)>,
+
     /// Earliest time an entity was recorded at on the current timeline.
     pub min_time: i64,
 
@@ -1,6 +1,6 @@
 use itertools::Itertools as _;
 
-use re_chunk_store::{LatestAtQuery, RangeQuery, RowId};
+use re_chunk_store::{AbsoluteTimeRange, LatestAtQuery, RangeQuery, RowId};
 use re_log_types::{EntityPath, TimeInt};
 use re_types::{
     Archetype as _, archetypes,
@@ -17,9 +17,11 @@ use re_viewer_context::{
 use re_viewer_context::{external::re_entity_db::InstancePath, typed_fallback_for};
 use re_viewport_blueprint::ViewPropertyQueryError;
 
+use crate::scalar_aggregates_subscriber::ScalarAggregatesStoreSubscriber;
 use crate::series_query::{
-    allocate_plot_points, collect_colors, collect_radius_ui, collect_scalars, collect_series_name,
-    collect_series_visibility, determine_num_series,
+    allocate_plot_points, collect_bootstrapped_attrs, collect_colors, collect_radius_ui,
+    collect_scalars, collect_series_name, collect_series_visibility, collect_unit_and_convert,
+    determine_num_series, determine_unit_conversion,
 };
 use crate::{PlotPoint, PlotPointAttrs, PlotSeries, PlotSeriesKind, util};
 
@@ -168,12 +170,6 @@ impl SeriesLinesSystem {
                 },
             };
 
-            let num_series = determine_num_series(&all_scalar_chunks);
-            let mut points_per_series =
-                allocate_plot_points(&query, &default_point, &all_scalar_chunks, num_series);
-
-            collect_scalars(&all_scalar_chunks, &mut points_per_series);
-
             // The plot view visualizes scalar data within a specific time range, without any kind
             // of time-alignment / bootstrapping behavior:
             // * For the scalar themselves, this is what you want: if you're trying to plot some
@@ -188,30 +184,12 @@ impl SeriesLinesSystem {
                 None,
                 &LatestAtQuery::new(query.timeline, query.range.min()),
                 data_result,
-                archetypes::SeriesLines::all_component_identifiers(),
+                archetypes::SeriesLines::all_component_identifiers().chain(std::iter::once(
+                    archetypes::Scalars::descriptor_unit().component,
+                )),
                 query_shadowed_components,
             );
 
-            collect_colors(
-                entity_path,
-                &query,
-                &bootstrapped_results,
-                &results,
-                &all_scalar_chunks,
-                &mut points_per_series,
-                &archetypes::SeriesLines::descriptor_colors(),
-            );
-            collect_radius_ui(
-                &query,
-                &bootstrapped_results,
-                &results,
-                &all_scalar_chunks,
-                &mut points_per_series,
-                &archetypes::SeriesLines::descriptor_widths(),
-                0.5,
-            );
-
-            // Now convert the `PlotPoints` into `Vec<PlotSeries>`
             let aggregation_policy_descr = archetypes::SeriesLines::descriptor_aggregation_policy();
             let aggregator = bootstrapped_results
                 .get_optional_chunks(aggregation_policy_descr.component)
@@ -231,6 +209,47 @@ impl SeriesLinesSystem {
                 //                This is fine, because we know there's no `TypedFallbackProvider`, but wrong if one were to be added.
                 .unwrap_or_default();
 
+            if aggregator == AggregationPolicy::MinMeanMax
+                && Self::load_aggregated_series(
+                    ctx,
+                    view_query,
+                    &query,
+                    time_per_pixel,
+                    data_result,
+                    &default_point,
+                    &bootstrapped_results,
+                    &results,
+                    all_series,
+                )
+            {
+                return Ok(());
+            }
+
+            let num_series = determine_num_series(&all_scalar_chunks);
+            let mut points_per_series =
+                allocate_plot_points(&query, &default_point, &all_scalar_chunks, num_series);
+
+            collect_scalars(&all_scalar_chunks, &mut points_per_series);
+
+            collect_colors(
+                entity_path,
+                &query,
+                &bootstrapped_results,
+                &results,
+                &all_scalar_chunks,
+                &mut points_per_series,
+                &archetypes::SeriesLines::descriptor_colors(),
+            );
+            collect_radius_ui(
+                &query,
+                &bootstrapped_results,
+                &results,
+                &all_scalar_chunks,
+                &mut points_per_series,
+                &archetypes::SeriesLines::descriptor_widths(),
+                0.5,
+            );
+
             // NOTE: The chunks themselves are already sorted as best as possible (hint: overlap)
             // by the query engine.
             let all_chunks_sorted_and_not_overlapped =
@@ -280,6 +299,13 @@ impl SeriesLinesSystem {
                 }
             }
 
+            let unit = collect_unit_and_convert(
+                &bootstrapped_results,
+                &results,
+                &archetypes::SeriesLines::descriptor_display_unit(),
+                &mut points_per_series,
+            );
+
             let series_visibility = collect_series_visibility(
                 &query,
                 &bootstrapped_results,
@@ -317,6 +343,7 @@ impl SeriesLinesSystem {
                     ctx.recording_engine().store(),
                     view_query,
                     label,
+                    unit.clone(),
                     aggregator,
                     all_series,
                 );
@@ -325,6 +352,146 @@ impl SeriesLinesSystem {
             Ok(())
         }
     }
+
+    /// Loads the series of an entity from the aggregates that are kept up to date as the scalars
+    /// come in, instead of from the individual scalars. See [`AggregationPolicy::MinMeanMax`].
+    ///
+    /// Returns `false` if the aggregates aren't fine enough for the current zoom level, in which
+    /// case the individual scalars have to be loaded after all.
+    ///
+    /// Series drawn this way keep the colors and widths they start out with, and aren't broken up
+    /// by clears.
+    #[expect(clippy::too_many_arguments)]
+    fn load_aggregated_series(
+        ctx: &ViewContext<'_>,
+        view_query: &ViewQuery<'_>,
+        query: &RangeQuery,
+        time_per_pixel: f64,
+        data_result: &re_viewer_context::DataResult,
+        default_point: &PlotPoint,
+        bootstrapped_results: &re_view::HybridLatestAtResults<'_>,
+        results: &re_view::HybridRangeResults<'_>,
+        all_series: &mut Vec<PlotSeries>,
+    ) -> bool {
+        re_tracing::profile_function!();
+
+        let entity_path = &data_result.entity_path;
+        let store = ctx.recording_engine().store();
+
+        // No need to go through the buckets that are off-screen, except for the ones right next
+        // to the edges that the lines lead to.
+        let plot_mem = egui_plot::PlotMemory::load(
+            ctx.viewer_ctx.egui_ctx(),
+            crate::plot_id(view_query.view_id),
+        );
+        let margin = time_per_pixel.ceil() as i64;
+        let time_range = util::determine_visible_time_range(ctx, plot_mem.as_ref())
+            .map(|visible| {
+                AbsoluteTimeRange::new(
+                    visible.min().as_i64().saturating_sub(margin),
+                    visible.max().as_i64().saturating_add(margin),
+                )
+            })
+            .and_then(|visible| visible.intersection(query.range))
+            .unwrap_or(query.range);
+
+        let Some(Some(buckets_per_series)) =
+            ScalarAggregatesStoreSubscriber::access(&store.id(), |aggregates| {
+                aggregates
+                    .series(entity_path, query.timeline())
+                    .iter()
+                    .map(|series| series.buckets(time_range, time_per_pixel))
+                    .collect::<Option<Vec<_>>>()
+            })
+        else {
+            return false;
+        };
+        if buckets_per_series.is_empty() {
+            return false;
+        }
+
+        let num_series = buckets_per_series.len();
+        let current_query = ctx.current_query();
+        let query_ctx = ctx.query_context(data_result, &current_query);
+
+        let all_attrs = collect_bootstrapped_attrs(
+            entity_path,
+            bootstrapped_results,
+            default_point,
+            num_series,
+            &archetypes::SeriesLines::descriptor_colors(),
+            &archetypes::SeriesLines::descriptor_widths(),
+            0.5,
+        );
+        let (unit, conversion) = determine_unit_conversion(
+            bootstrapped_results,
+            results,
+            &archetypes::SeriesLines::descriptor_display_unit(),
+        );
+        let convert = |value: f64| conversion.map_or(value, |conversion| conversion.apply(value));
+        let series_visibility = collect_series_visibility(
+            query,
+            bootstrapped_results,
+            results,
+            num_series,
+            archetypes::SeriesLines::descriptor_visible_series().component,
+        );
+        let series_names = collect_series_name(
+            &query_ctx,
+            bootstrapped_results,
+            results,
+            num_series,
+            &archetypes::SeriesLines::descriptor_names(),
+        );
+
+        for (instance, (buckets, attrs, label, visible)) in itertools::izip!(
+            buckets_per_series,
+            all_attrs,
+            series_names,
+            series_visibility
+        )
+        .enumerate()
+        {
+            let Some((first_time, _)) = buckets.first() else {
+                continue;
+            };
+
+            let instance_path = if num_series == 1 {
+                InstancePath::entity_all(entity_path.clone())
+            } else {
+                InstancePath::instance(entity_path.clone(), instance as u64)
+            };
+
+            let num_samples: u64 = buckets.iter().map(|(_, aggregate)| aggregate.count).sum();
+            let min_time = store
+                .entity_min_time(&view_query.timeline, entity_path)
+                .map_or(*first_time, |time| time.as_i64());
+
+            all_series.push(PlotSeries {
+                visible,
+                id: egui::Id::new(&instance_path),
+                label,
+                unit: unit.clone(),
+                color: attrs.color,
+                radius_ui: attrs.radius_ui,
+                kind: PlotSeriesKind::Continuous,
+                points: buckets
+                    .iter()
+                    .map(|(time, aggregate)| (*time, convert(aggregate.mean())))
+                    .collect(),
+                band: buckets
+                    .iter()
+                    .map(|(_, aggregate)| (convert(aggregate.min), convert(aggregate.max)))
+                    .collect(),
+                instance_path,
+                aggregator: AggregationPolicy::MinMeanMax,
+                aggregation_factor: num_samples as f64 / buckets.len() as f64,
+                min_time,
+            });
+        }
+
+        true
+    }
 }
 
 fn collect_recursive_clears(
@@ -18,7 +18,8 @@ use crate::{
     PlotPoint, PlotPointAttrs, PlotSeries, PlotSeriesKind, ScatterAttrs,
     series_query::{
         all_scalars_indices, allocate_plot_points, collect_colors, collect_radius_ui,
-        collect_scalars, collect_series_name, collect_series_visibility, determine_num_series,
+        collect_scalars, collect_series_name, collect_series_visibility, collect_unit_and_convert,
+        determine_num_series,
     },
     util,
 };
@@ -192,7 +193,9 @@ impl SeriesPointsSystem {
                 None,
                 &LatestAtQuery::new(query.timeline, query.range.min()),
                 data_result,
-                archetypes::SeriesPoints::all_component_identifiers(),
+                archetypes::SeriesPoints::all_component_identifiers().chain(std::iter::once(
+                    archetypes::Scalars::descriptor_unit().component,
+                )),
                 query_shadowed_components,
             );
 
@@ -320,6 +323,13 @@ impl SeriesPointsSystem {
                 }
             }
 
+            let unit = collect_unit_and_convert(
+                &bootstrapped_results,
+                &results,
+                &archetypes::SeriesPoints::descriptor_display_unit(),
+                &mut points_per_series,
+            );
+
             let series_visibility = collect_series_visibility(
                 &query,
                 &bootstrapped_results,
@@ -357,6 +367,7 @@ impl SeriesPointsSystem {
                     ctx.recording_engine().store(),
                     view_query,
                     label,
+                    unit.clone(),
                     // Aggregation for points is not supported.
                     re_types::components::AggregationPolicy::Off,
                     all_series,
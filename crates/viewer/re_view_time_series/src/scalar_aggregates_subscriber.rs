@@ -0,0 +1,375 @@
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+use nohash_hasher::IntMap;
+
+use re_chunk_store::{
+    AbsoluteTimeRange, Chunk, ChunkStore, ChunkStoreDiffKind, ChunkStoreEvent,
+    ChunkStoreSubscriberHandle, PerStoreChunkSubscriber,
+};
+use re_log_types::{EntityPath, StoreId, TimelineName};
+use re_types::archetypes;
+
+/// How many levels of detail each series is aggregated at.
+///
+/// Each level's buckets are four times as wide as the previous one's.
+const NUM_LEVELS: u32 = 12;
+
+/// The finest level can't be any coarser than this, or the coarsest level's buckets would be wider
+/// than what fits in an `i64`.
+const MAX_FINEST_LEVEL: u32 = 31 - NUM_LEVELS;
+
+/// How many samples of a series we look at to tell how far apart they are, before aggregating any.
+const NUM_SAMPLES_FOR_SPACING: usize = 16;
+
+#[inline]
+fn bucket_width(level: u32) -> i64 {
+    1 << (2 * level)
+}
+
+/// Minimum, maximum and sum of the scalars within one bucket of time.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Aggregate {
+    pub min: f64,
+    pub max: f64,
+    pub sum: f64,
+    pub count: u64,
+}
+
+impl Aggregate {
+    #[inline]
+    fn new(value: f64) -> Self {
+        Self {
+            min: value,
+            max: value,
+            sum: value,
+            count: 1,
+        }
+    }
+
+    #[inline]
+    fn add(&mut self, value: f64) {
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.sum += value;
+        self.count += 1;
+    }
+
+    #[inline]
+    pub fn mean(&self) -> f64 {
+        self.sum / self.count as f64
+    }
+}
+
+/// The aggregates of a single series on a single timeline, at every level of detail.
+#[derive(Clone, Debug, Default)]
+pub struct SeriesAggregates {
+    /// Samples seen before the width of the finest buckets was decided.
+    pending: Vec<(i64, f64)>,
+
+    /// Log4 of the width of the buckets on the finest level.
+    ///
+    /// Picked such that the finest buckets cover about 16 samples each, which keeps the aggregates
+    /// small compared to the scalars themselves.
+    finest_level: u32,
+
+    /// `levels[i]` holds the buckets that are `4^(finest_level + i)` time units wide, keyed by
+    /// their start time divided by their width.
+    levels: Vec<BTreeMap<i64, Aggregate>>,
+}
+
+impl SeriesAggregates {
+    pub fn add(&mut self, time: i64, value: f64) {
+        // NaNs would poison the sums; they're gaps in the plot anyway.
+        if value.is_nan() {
+            return;
+        }
+
+        if !self.levels.is_empty() {
+            self.add_to_levels(time, value);
+            return;
+        }
+
+        self.pending.push((time, value));
+        if self.pending.len() < NUM_SAMPLES_FOR_SPACING {
+            return;
+        }
+
+        let (min_time, max_time) = self
+            .pending
+            .iter()
+            .fold((i64::MAX, i64::MIN), |(min, max), (time, _)| {
+                (min.min(*time), max.max(*time))
+            });
+        let spacing = (max_time as f64 - min_time as f64) / (self.pending.len() - 1) as f64;
+
+        self.finest_level = 0;
+        while self.finest_level < MAX_FINEST_LEVEL
+            && (bucket_width(self.finest_level) as f64) < 16.0 * spacing
+        {
+            self.finest_level += 1;
+        }
+        self.levels = vec![BTreeMap::default(); NUM_LEVELS as usize];
+
+        for (time, value) in std::mem::take(&mut self.pending) {
+            self.add_to_levels(time, value);
+        }
+    }
+
+    fn add_to_levels(&mut self, time: i64, value: f64) {
+        for (level, buckets) in (self.finest_level..).zip(&mut self.levels) {
+            buckets
+                .entry(time.div_euclid(bucket_width(level)))
+                .and_modify(|aggregate| aggregate.add(value))
+                .or_insert_with(|| Aggregate::new(value));
+        }
+    }
+
+    /// Forgets about all buckets that overlap with `time_range`.
+    ///
+    /// Buckets that also hold samples outside of the range are dropped as a whole, so the plot
+    /// may lose a little more than what was removed at the edges.
+    pub fn remove(&mut self, time_range: AbsoluteTimeRange) {
+        let (min, max) = (time_range.min().as_i64(), time_range.max().as_i64());
+        self.pending.retain(|(time, _)| *time < min || max < *time);
+
+        for (level, buckets) in (self.finest_level..).zip(&mut self.levels) {
+            let width = bucket_width(level);
+            let (first, last) = (min.div_euclid(width), max.div_euclid(width));
+
+            let removed = buckets
+                .range(first..=last)
+                .map(|(index, _)| *index)
+                .collect::<Vec<_>>();
+            for index in removed {
+                buckets.remove(&index);
+            }
+        }
+    }
+
+    /// The buckets that overlap with `time_range`, keyed by the time at their center.
+    ///
+    /// Picks the coarsest level whose buckets are no wider than `max_bucket_width`.
+    /// Returns `None` if even the finest buckets are too wide, or if there aren't enough samples
+    /// yet to have any.
+    pub fn buckets(
+        &self,
+        time_range: AbsoluteTimeRange,
+        max_bucket_width: f64,
+    ) -> Option<Vec<(i64, Aggregate)>> {
+        let (level, buckets) = self
+            .levels
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(i, buckets)| (self.finest_level + i as u32, buckets))
+            .find(|(level, _)| bucket_width(*level) as f64 <= max_bucket_width)?;
+
+        let width = bucket_width(level);
+        let first = time_range.min().as_i64().div_euclid(width);
+        let last = time_range.max().as_i64().div_euclid(width);
+
+        Some(
+            buckets
+                .range(first..=last)
+                .map(|(index, aggregate)| {
+                    let center = index.saturating_mul(width).saturating_add(width / 2);
+                    (center, *aggregate)
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Aggregates of all scalars, kept up to date while the data is ingested.
+///
+/// This is what lets plots of scalars logged at high rates stay smooth: instead of going through
+/// every single sample on every frame, they can be drawn from a bucket of samples per pixel.
+#[derive(Default)]
+pub struct ScalarAggregatesStoreSubscriber {
+    /// One [`SeriesAggregates`] per instance of the scalars.
+    aggregates: IntMap<EntityPath, IntMap<TimelineName, Vec<SeriesAggregates>>>,
+}
+
+impl ScalarAggregatesStoreSubscriber {
+    /// Accesses the aggregates for a given store.
+    pub fn access<T>(store_id: &StoreId, f: impl FnOnce(&Self) -> T) -> Option<T> {
+        ChunkStore::with_per_store_subscriber_once(Self::subscription_handle(), store_id, f)
+    }
+
+    /// Accesses the global store subscriber.
+    ///
+    /// Lazily registers the subscriber if it hasn't been registered yet.
+    pub fn subscription_handle() -> ChunkStoreSubscriberHandle {
+        static SUBSCRIPTION: OnceLock<ChunkStoreSubscriberHandle> = OnceLock::new();
+        *SUBSCRIPTION.get_or_init(ChunkStore::register_per_store_subscriber::<Self>)
+    }
+
+    /// The aggregates of each instance of the scalars at `entity_path`.
+    pub fn series(&self, entity_path: &EntityPath, timeline: &TimelineName) -> &[SeriesAggregates] {
+        self.aggregates
+            .get(entity_path)
+            .and_then(|per_timeline| per_timeline.get(timeline))
+            .map_or(&[], |series| series.as_slice())
+    }
+
+    fn add_chunk(&mut self, chunk: &Chunk) {
+        let component = archetypes::Scalars::descriptor_scalars().component;
+        let per_timeline = self
+            .aggregates
+            .entry(chunk.entity_path().clone())
+            .or_default();
+
+        #[expect(clippy::iter_over_hash_type)] // Every timeline is aggregated on its own.
+        for timeline in chunk.timelines().keys() {
+            let all_series = per_timeline.entry(*timeline).or_default();
+
+            for ((time, _row_id), values) in itertools::izip!(
+                chunk.iter_component_indices(*timeline, component),
+                chunk.iter_slices::<f64>(component)
+            ) {
+                if all_series.len() < values.len() {
+                    all_series.resize_with(values.len(), Default::default);
+                }
+                for (series, value) in all_series.iter_mut().zip(values) {
+                    series.add(time.as_i64(), *value);
+                }
+            }
+        }
+    }
+
+    fn remove_chunk(&mut self, chunk: &Chunk) {
+        let Some(per_timeline) = self.aggregates.get_mut(chunk.entity_path()) else {
+            return;
+        };
+
+        #[expect(clippy::iter_over_hash_type)] // Every timeline is aggregated on its own.
+        for (timeline, time_column) in chunk.timelines() {
+            for series in per_timeline.get_mut(timeline).into_iter().flatten() {
+                series.remove(time_column.time_range());
+            }
+        }
+    }
+}
+
+impl PerStoreChunkSubscriber for ScalarAggregatesStoreSubscriber {
+    #[inline]
+    fn name() -> String {
+        "ScalarAggregatesStoreSubscriber".to_owned()
+    }
+
+    fn on_events<'a>(&mut self, events: impl Iterator<Item = &'a ChunkStoreEvent>) {
+        re_tracing::profile_function!();
+
+        let component = archetypes::Scalars::descriptor_scalars().component;
+
+        for event in events {
+            let chunk = &event.diff.chunk;
+
+            // Static scalars don't make for a plot.
+            if chunk.is_static() || !chunk.components().contains_component(component) {
+                continue;
+            }
+
+            match event.diff.kind {
+                ChunkStoreDiffKind::Addition => self.add_chunk(chunk),
+                ChunkStoreDiffKind::Deletion => self.remove_chunk(chunk),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use re_log_types::TimeInt;
+
+    use super::*;
+
+    fn series(samples: impl IntoIterator<Item = (i64, f64)>) -> SeriesAggregates {
+        let mut series = SeriesAggregates::default();
+        for (time, value) in samples {
+            series.add(time, value);
+        }
+        series
+    }
+
+    #[test]
+    fn not_enough_samples() {
+        let series = series((0..10).map(|i| (i, i as f64)));
+        assert_eq!(
+            series.buckets(AbsoluteTimeRange::EVERYTHING, f64::INFINITY),
+            None
+        );
+    }
+
+    #[test]
+    fn finest_buckets_cover_a_few_samples() {
+        // A sample every 1000 time units, e.g. 1 MHz on a nanosecond timeline.
+        let series = series((0..1000).map(|i| (i * 1000, i as f64)));
+
+        // Finest buckets are 4^7 = 16384 units wide, which is ~16 samples.
+        assert_eq!(series.finest_level, 7);
+        assert_eq!(series.buckets(AbsoluteTimeRange::EVERYTHING, 16383.0), None);
+
+        let buckets = series
+            .buckets(AbsoluteTimeRange::EVERYTHING, 16384.0)
+            .unwrap();
+        assert_eq!(buckets.len(), 999_000 / 16384 + 1);
+        assert_eq!(
+            buckets[0],
+            (
+                8192,
+                Aggregate {
+                    min: 0.0,
+                    max: 16.0,
+                    sum: 136.0,
+                    count: 17,
+                }
+            )
+        );
+        assert_eq!(
+            buckets.iter().map(|(_, a)| a.count).sum::<u64>(),
+            1000,
+            "every sample is in exactly one bucket"
+        );
+    }
+
+    #[test]
+    fn coarsest_level_that_fits() {
+        let series = series((0..1000).map(|i| (i * 1000, i as f64)));
+
+        // 4^8 = 65536 fits, 4^9 doesn't.
+        let buckets = series
+            .buckets(AbsoluteTimeRange::EVERYTHING, 100_000.0)
+            .unwrap();
+        assert_eq!(buckets.len(), 999_000 / 65536 + 1);
+
+        let last = buckets.last().unwrap().1;
+        assert_eq!(last.max, 999.0);
+        assert_eq!(last.min, 984.0); // The first sample past 15 * 65536.
+
+        // Only the buckets within the range.
+        let range = AbsoluteTimeRange::new(TimeInt::new_temporal(0), TimeInt::new_temporal(65535));
+        let buckets = series.buckets(range, 100_000.0).unwrap();
+        assert_eq!(buckets.len(), 1);
+        assert_eq!(buckets[0].1.count, 66);
+    }
+
+    #[test]
+    fn removal() {
+        let mut series = series((0..1000).map(|i| (i * 1000, i as f64)));
+
+        series.remove(AbsoluteTimeRange::new(
+            TimeInt::new_temporal(0),
+            TimeInt::new_temporal(499_000),
+        ));
+
+        for max_bucket_width in [16384.0, 100_000.0] {
+            let buckets = series
+                .buckets(AbsoluteTimeRange::EVERYTHING, max_bucket_width)
+                .unwrap();
+            assert!(buckets.iter().all(|(_, a)| a.min >= 500.0));
+            assert_eq!(buckets.last().unwrap().1.max, 999.0);
+        }
+    }
+}
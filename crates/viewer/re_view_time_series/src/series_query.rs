@@ -5,11 +5,14 @@ use itertools::Itertools as _;
 use re_chunk_store::RangeQuery;
 use re_log_types::{EntityPath, TimeInt};
 use re_types::external::arrow::datatypes::DataType as ArrowDatatype;
-use re_types::{ComponentDescriptor, ComponentIdentifier, Loggable as _, RowId, components};
+use re_types::{
+    ComponentDescriptor, ComponentIdentifier, Loggable as _, RowId, archetypes, components,
+};
 use re_view::{ChunksWithComponent, HybridRangeResults, RangeResultsExt as _, clamped_or_nothing};
 use re_viewer_context::{QueryContext, auto_color_egui, typed_fallback_for};
 
-use crate::{PlotPoint, PlotSeriesKind};
+use crate::units::UnitConversion;
+use crate::{PlotPoint, PlotPointAttrs, PlotSeriesKind};
 
 type PlotPointsPerSeries = smallvec::SmallVec<[Vec<PlotPoint>; 1]>;
 
@@ -136,12 +139,6 @@ pub fn collect_colors(
 
     debug_assert_eq!(components::Color::arrow_datatype(), ArrowDatatype::UInt32);
 
-    fn map_raw_color(raw: &u32) -> re_renderer::Color32 {
-        let [a, b, g, r] = raw.to_le_bytes();
-        #[expect(clippy::disallowed_methods)] // This is not a hard-coded color.
-        re_renderer::Color32::from_rgba_unmultiplied(r, g, b, a)
-    }
-
     let all_color_chunks = bootstrapped_results
         .get_optional_chunks(color_descriptor.component)
         .iter()
@@ -179,12 +176,7 @@ pub fn collect_colors(
             // TODO(andreas): Could they somehow be provided by the fallback provider?
             // It's tricky since the fallback provider doesn't know how many colors to produce!
             for (i, points) in points_per_series.iter_mut().skip(1).enumerate() {
-                // Normally we generate colors from entity names, but getting the display label needs extra processing,
-                // and it's nice to not care about that here.
-                let fallback_color = auto_color_egui(
-                    (re_log_types::hash::Hash64::hash((entity_path, i)).hash64() % u16::MAX as u64)
-                        as u16,
-                );
+                let fallback_color = fallback_series_color(entity_path, i);
                 for point in points {
                     point.attrs.color = fallback_color;
                 }
@@ -227,6 +219,79 @@ pub fn collect_colors(
     }
 }
 
+fn map_raw_color(raw: &u32) -> re_renderer::Color32 {
+    let [a, b, g, r] = raw.to_le_bytes();
+    #[expect(clippy::disallowed_methods)] // This is not a hard-coded color.
+    re_renderer::Color32::from_rgba_unmultiplied(r, g, b, a)
+}
+
+/// Color for the series past the first one, if there are several but no colors were logged.
+fn fallback_series_color(entity_path: &EntityPath, i: usize) -> re_renderer::Color32 {
+    // Normally we generate colors from entity names, but getting the display label needs extra processing,
+    // and it's nice to not care about that here.
+    auto_color_egui(
+        (re_log_types::hash::Hash64::hash((entity_path, i)).hash64() % u16::MAX as u64) as u16,
+    )
+}
+
+/// Collects the colors and stroke radii the series start out with at the beginning of the query.
+///
+/// This is for series that are drawn from aggregates rather than from their individual points,
+/// and therefore keep the same attributes all the way through.
+pub fn collect_bootstrapped_attrs(
+    entity_path: &EntityPath,
+    bootstrapped_results: &re_view::HybridLatestAtResults<'_>,
+    default_point: &PlotPoint,
+    num_series: usize,
+    color_descriptor: &ComponentDescriptor,
+    radius_descriptor: &ComponentDescriptor,
+    radius_multiplier: f32,
+) -> Vec<PlotPointAttrs> {
+    re_tracing::profile_function!();
+
+    let mut all_attrs = vec![default_point.attrs.clone(); num_series];
+
+    let first_chunk = |component: ComponentIdentifier| {
+        bootstrapped_results
+            .get_optional_chunks(component)
+            .iter()
+            .find(|chunk| !chunk.is_empty())
+            .cloned()
+    };
+
+    let colors = first_chunk(color_descriptor.component);
+    if let Some(colors) = colors
+        .as_ref()
+        .and_then(|chunk| chunk.iter_slices::<u32>(color_descriptor.component).next())
+    {
+        for (attrs, color) in all_attrs
+            .iter_mut()
+            .zip(clamped_or_nothing(colors, num_series))
+        {
+            attrs.color = map_raw_color(color);
+        }
+    } else {
+        for (i, attrs) in all_attrs.iter_mut().skip(1).enumerate() {
+            attrs.color = fallback_series_color(entity_path, i);
+        }
+    }
+
+    let radii = first_chunk(radius_descriptor.component);
+    if let Some(radii) = radii
+        .as_ref()
+        .and_then(|chunk| chunk.iter_slices::<f32>(radius_descriptor.component).next())
+    {
+        for (attrs, radius) in all_attrs
+            .iter_mut()
+            .zip(clamped_or_nothing(radii, num_series))
+        {
+            attrs.radius_ui = radius * radius_multiplier;
+        }
+    }
+
+    all_attrs
+}
+
 /// Collects series names for the series into pre-allocated plot points.
 pub fn collect_series_name(
     query_ctx: &QueryContext<'_>,
@@ -270,6 +335,70 @@ pub fn collect_series_name(
     series_names
 }
 
+/// Determines the unit the series are shown in, and converts the plot points to it.
+///
+/// The scalars are converted from their logged unit to the display unit if both are known units
+/// of the same quantity. Otherwise, they are left as they are and shown in the unit they were
+/// logged in, if any.
+pub fn collect_unit_and_convert(
+    bootstrapped_results: &re_view::HybridLatestAtResults<'_>,
+    results: &re_view::HybridRangeResults<'_>,
+    display_unit_descriptor: &ComponentDescriptor,
+    points_per_series: &mut PlotPointsPerSeries,
+) -> Option<String> {
+    re_tracing::profile_function!();
+
+    let (unit, conversion) =
+        determine_unit_conversion(bootstrapped_results, results, display_unit_descriptor);
+
+    if let Some(conversion) = conversion
+        && !conversion.is_identity()
+    {
+        for point in points_per_series.iter_mut().flatten() {
+            point.value = conversion.apply(point.value);
+        }
+    }
+
+    unit
+}
+
+/// Determines the unit the series are shown in, and how to convert the scalars to it.
+///
+/// See [`collect_unit_and_convert`].
+pub fn determine_unit_conversion(
+    bootstrapped_results: &re_view::HybridLatestAtResults<'_>,
+    results: &re_view::HybridRangeResults<'_>,
+    display_unit_descriptor: &ComponentDescriptor,
+) -> (Option<String>, Option<UnitConversion>) {
+    let find_unit = |component: ComponentIdentifier| {
+        bootstrapped_results
+            .get_optional_chunks(component)
+            .iter()
+            .chain(results.get_optional_chunks(component).iter())
+            .find(|chunk| !chunk.is_empty())
+            .and_then(|chunk| chunk.iter_slices::<String>(component).next())
+            .and_then(|slice| slice.first().map(|unit| unit.to_string()))
+            .filter(|unit| !unit.is_empty())
+    };
+
+    let unit = find_unit(archetypes::Scalars::descriptor_unit().component);
+    let Some(display_unit) = find_unit(display_unit_descriptor.component) else {
+        return (unit, None);
+    };
+    let Some(unit) = unit else {
+        // We don't know what the scalars were logged in, so all we can do is to trust the display unit.
+        return (Some(display_unit), None);
+    };
+
+    match UnitConversion::between(&unit, &display_unit) {
+        Some(conversion) => (Some(display_unit), Some(conversion)),
+        None => {
+            re_log::warn_once!("Can't show values logged in {unit:?} in {display_unit:?}");
+            (Some(unit), None)
+        }
+    }
+}
+
 /// Collects `radius_ui` for the series into pre-allocated plot points.
 pub fn collect_radius_ui(
     query: &RangeQuery,
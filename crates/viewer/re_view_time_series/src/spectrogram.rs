@@ -0,0 +1,311 @@
+//! Short-time Fourier transform of scalar series.
+
+use rayon::prelude::*;
+
+/// Magnitudes this far below the loudest bin of a spectrogram map to the bottom of the colormap.
+const DYNAMIC_RANGE_DB: f64 = 80.0;
+
+/// Larger windows are clamped to this size, keeping the number of bins below texture size limits.
+const MAX_WINDOW_SIZE: u32 = 8192;
+
+/// Upper limit on the number of windows of a spectrogram.
+///
+/// Long series use a larger hop between windows instead, keeping memory and texture size bounded.
+const MAX_NUM_WINDOWS: usize = 4096;
+
+/// Upper limit on the number of samples after resampling, to guard against huge gaps in the data.
+const MAX_NUM_SAMPLES: usize = 1 << 24;
+
+/// The rolling FFT of a scalar series.
+#[derive(Clone, Debug)]
+pub struct Spectrogram {
+    /// Time of the center of the first window.
+    pub start_time: f64,
+
+    /// Time between the centers of two consecutive windows.
+    pub hop_time: f64,
+
+    /// Time between two samples of the resampled series.
+    pub sample_interval: f64,
+
+    pub num_windows: usize,
+
+    /// Number of frequency bins, from zero up to the Nyquist frequency.
+    pub num_bins: usize,
+
+    /// Window-major magnitudes, normalized to `[0, 1]` over the dynamic range of the spectrogram.
+    pub magnitudes: Vec<f32>,
+}
+
+impl Spectrogram {
+    /// Computes the spectrogram of a series of `(time, value)` pairs, sorted by time.
+    ///
+    /// The series is resampled onto a uniform grid with the median interval between samples,
+    /// and each window is multiplied with a Hann window before its spectrum is computed.
+    ///
+    /// Returns `None` if there are fewer than two distinct sample times.
+    pub fn compute(points: &[(i64, f64)], window_size: u32, overlap: f32) -> Option<Self> {
+        re_tracing::profile_function!();
+
+        let sample_interval = median_sample_interval(points)?;
+        let (first_time, last_time) = (points.first()?.0, points.last()?.0);
+        let span = (last_time - first_time) as f64;
+        let sample_interval = sample_interval.max(span / MAX_NUM_SAMPLES as f64);
+        let samples = resample(points, sample_interval);
+
+        let window_size = window_size.clamp(2, MAX_WINDOW_SIZE).next_power_of_two() as usize;
+        let overlap = overlap.clamp(0.0, 0.95) as f64;
+        let mut hop = ((window_size as f64 * (1.0 - overlap)).round() as usize).max(1);
+
+        let num_full_windows = |hop: usize| samples.len().saturating_sub(window_size) / hop + 1;
+        if MAX_NUM_WINDOWS < num_full_windows(hop) {
+            hop = samples
+                .len()
+                .saturating_sub(window_size)
+                .div_ceil(MAX_NUM_WINDOWS - 1);
+        }
+        let num_windows = num_full_windows(hop);
+        let num_bins = window_size / 2 + 1;
+
+        let hann: Vec<f64> = (0..window_size)
+            .map(|i| 0.5 - 0.5 * (std::f64::consts::TAU * i as f64 / window_size as f64).cos())
+            .collect();
+        let twiddles = twiddles(window_size);
+
+        let mut decibels = vec![0.0_f64; num_windows * num_bins];
+        decibels
+            .par_chunks_mut(num_bins)
+            .enumerate()
+            .for_each(|(window_index, bins)| {
+                let start = window_index * hop;
+                let end = (start + window_size).min(samples.len());
+                let window = &samples[start..end];
+                let mean = window.iter().sum::<f64>() / window.len() as f64;
+
+                // Windows at the end of short series are zero-padded.
+                let mut re = vec![0.0; window_size];
+                let mut im = vec![0.0; window_size];
+                for ((re, sample), hann) in re.iter_mut().zip(window).zip(&hann) {
+                    *re = (sample - mean) * hann;
+                }
+                fft(&mut re, &mut im, &twiddles);
+
+                for (bin, decibel) in bins.iter_mut().enumerate() {
+                    let magnitude = re[bin].hypot(im[bin]) / window_size as f64;
+                    *decibel = 20.0 * (magnitude + 1e-12).log10();
+                }
+            });
+
+        let max_decibel = decibels.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let magnitudes = decibels
+            .iter()
+            .map(|decibel| {
+                ((decibel - max_decibel + DYNAMIC_RANGE_DB) / DYNAMIC_RANGE_DB).clamp(0.0, 1.0)
+                    as f32
+            })
+            .collect();
+
+        Some(Self {
+            start_time: first_time as f64 + 0.5 * window_size as f64 * sample_interval,
+            hop_time: hop as f64 * sample_interval,
+            sample_interval,
+            num_windows,
+            num_bins,
+            magnitudes,
+        })
+    }
+
+    /// The highest frequency of the spectrogram, in cycles per time unit.
+    pub fn nyquist_frequency(&self) -> f64 {
+        0.5 / self.sample_interval
+    }
+
+    /// Normalized magnitudes of a single window, from the lowest to the highest frequency.
+    pub fn window(&self, window_index: usize) -> &[f32] {
+        &self.magnitudes[window_index * self.num_bins..(window_index + 1) * self.num_bins]
+    }
+}
+
+/// The median of the intervals between consecutive distinct sample times.
+fn median_sample_interval(points: &[(i64, f64)]) -> Option<f64> {
+    let mut intervals: Vec<i64> = points
+        .windows(2)
+        .map(|pair| pair[1].0 - pair[0].0)
+        .filter(|&interval| 0 < interval)
+        .collect();
+    if intervals.is_empty() {
+        return None;
+    }
+
+    let middle = intervals.len() / 2;
+    let (_, median, _) = intervals.select_nth_unstable(middle);
+    Some(*median as f64)
+}
+
+/// Linearly interpolates the series at multiples of `sample_interval` from its first time on.
+fn resample(points: &[(i64, f64)], sample_interval: f64) -> Vec<f64> {
+    let Some(&(first_time, _)) = points.first() else {
+        return Vec::new();
+    };
+    let last_time = points.last().map_or(first_time, |&(time, _)| time);
+    let num_samples = ((last_time - first_time) as f64 / sample_interval).floor() as usize + 1;
+
+    let mut segment = 0;
+    (0..num_samples)
+        .map(|i| {
+            let time = first_time as f64 + i as f64 * sample_interval;
+            while segment + 2 < points.len() && (points[segment + 1].0 as f64) < time {
+                segment += 1;
+            }
+
+            let (time_a, value_a) = points[segment];
+            let Some(&(time_b, value_b)) = points.get(segment + 1) else {
+                return value_a;
+            };
+            if time_b <= time_a {
+                return value_b;
+            }
+            let t = ((time - time_a as f64) / (time_b - time_a) as f64).clamp(0.0, 1.0);
+            value_a + t * (value_b - value_a)
+        })
+        .collect()
+}
+
+/// The roots of unity `exp(-2πik/n)` for `k` in `0..n/2`.
+fn twiddles(n: usize) -> Vec<(f64, f64)> {
+    (0..n / 2)
+        .map(|k| {
+            let (sin, cos) = (-std::f64::consts::TAU * k as f64 / n as f64).sin_cos();
+            (cos, sin)
+        })
+        .collect()
+}
+
+/// In-place iterative radix-2 FFT.
+///
+/// The length of `re` and `im` must be a power of two, matching the `twiddles` it was computed for.
+fn fft(re: &mut [f64], im: &mut [f64], twiddles: &[(f64, f64)]) {
+    let n = re.len();
+    debug_assert!(n.is_power_of_two());
+    debug_assert_eq!(n, im.len());
+    debug_assert_eq!(n / 2, twiddles.len());
+
+    // Bit-reversal permutation.
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            re.swap(i, j);
+            im.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let twiddle_stride = n / len;
+        for start in (0..n).step_by(len) {
+            for k in 0..len / 2 {
+                let (cos, sin) = twiddles[k * twiddle_stride];
+                let a = start + k;
+                let b = a + len / 2;
+                let t_re = re[b] * cos - im[b] * sin;
+                let t_im = re[b] * sin + im[b] * cos;
+                re[b] = re[a] - t_re;
+                im[b] = im[a] - t_im;
+                re[a] += t_re;
+                im[a] += t_im;
+            }
+        }
+        len <<= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sine(num_samples: usize, interval: i64, cycles_per_sample: f64) -> Vec<(i64, f64)> {
+        (0..num_samples)
+            .map(|i| {
+                let phase = std::f64::consts::TAU * cycles_per_sample * i as f64;
+                (i as i64 * interval, phase.sin())
+            })
+            .collect()
+    }
+
+    fn loudest_bin(bins: &[f32]) -> usize {
+        bins.iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(bin, _)| bin)
+            .unwrap()
+    }
+
+    #[test]
+    fn fft_of_impulse_is_flat() {
+        let mut re = vec![1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0];
+        let mut im = vec![0.0; 8];
+        fft(&mut re, &mut im, &twiddles(8));
+
+        for (re, im) in re.iter().zip(&im) {
+            assert!((re - 1.0).abs() < 1e-12);
+            assert!(im.abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn sine_peaks_at_its_frequency() {
+        // 1/8 cycles per sample lands on bin 32 of a 256 sample window.
+        let spectrogram = Spectrogram::compute(&sine(4096, 10, 0.125), 256, 0.5).unwrap();
+
+        assert_eq!(spectrogram.num_bins, 129);
+        assert_eq!(spectrogram.num_windows, (4096 - 256) / 128 + 1);
+        assert_eq!(spectrogram.hop_time, 1280.0);
+        assert_eq!(spectrogram.nyquist_frequency(), 0.05);
+        for window_index in 0..spectrogram.num_windows {
+            assert_eq!(loudest_bin(spectrogram.window(window_index)), 32);
+        }
+    }
+
+    #[test]
+    fn window_size_is_rounded_up_to_power_of_two() {
+        let spectrogram = Spectrogram::compute(&sine(1024, 1, 0.25), 100, 0.0).unwrap();
+
+        assert_eq!(spectrogram.num_bins, 65);
+        assert_eq!(spectrogram.num_windows, 1024 / 128);
+    }
+
+    #[test]
+    fn irregular_samples_are_resampled() {
+        // Every fourth sample is missing, the median interval is still 10.
+        let points: Vec<_> = sine(4096, 10, 0.125)
+            .into_iter()
+            .enumerate()
+            .filter(|(i, _)| i % 4 != 3)
+            .map(|(_, point)| point)
+            .collect();
+
+        let spectrogram = Spectrogram::compute(&points, 256, 0.5).unwrap();
+
+        assert_eq!(spectrogram.sample_interval, 10.0);
+        assert_eq!(loudest_bin(spectrogram.window(0)), 32);
+    }
+
+    #[test]
+    fn number_of_windows_is_bounded() {
+        let spectrogram = Spectrogram::compute(&sine(1 << 20, 1, 0.125), 16, 0.5).unwrap();
+
+        assert!(spectrogram.num_windows <= MAX_NUM_WINDOWS);
+    }
+
+    #[test]
+    fn needs_two_distinct_times() {
+        assert!(Spectrogram::compute(&[], 256, 0.5).is_none());
+        assert!(Spectrogram::compute(&[(0, 1.0), (0, 2.0)], 256, 0.5).is_none());
+    }
+}
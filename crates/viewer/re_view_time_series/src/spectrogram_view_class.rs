@@ -0,0 +1,441 @@
+use std::hash::{Hash, Hasher};
+
+use egui::{NumExt as _, ahash::HashMap};
+use egui_plot::{Plot, PlotImage, PlotPoint};
+
+use re_chunk_store::TimeType;
+use re_log_types::{EntityPath, TimeInt, hash::Hash64};
+use re_types::{
+    View as _, ViewClassIdentifier,
+    blueprint::{
+        archetypes::{FftWindow, TensorScalarMapping},
+        components::{FftWindowOverlap, FftWindowSize},
+    },
+    components::{Colormap, GammaCorrection, MagnificationFilter},
+    datatypes::TimeRange,
+};
+use re_ui::{Help, MouseButtonText, UiExt as _, icons, list_item};
+use re_view::{controls::MOVE_TIME_CURSOR_BUTTON, view_property_ui};
+use re_viewer_context::{
+    Item, QueryRange, TimeControlCommand, ViewClass, ViewClassExt as _, ViewClassRegistryError,
+    ViewId, ViewQuery, ViewSpawnHeuristics, ViewState, ViewStateExt as _, ViewSystemExecutionError,
+    ViewerContext, external::re_entity_db::InstancePath, gpu_bridge,
+};
+use re_viewport_blueprint::ViewProperty;
+
+use crate::{
+    spectrogram::Spectrogram,
+    spectrogram_visualizer_system::{ScalarSeries, SpectrogramSystem},
+};
+
+/// How the spectrograms of a view are computed and colored.
+#[derive(Clone, Copy)]
+struct SpectrogramSettings {
+    window_size: u32,
+    overlap: f32,
+    colormap: Colormap,
+    gamma: f32,
+    mag_filter: MagnificationFilter,
+}
+
+/// A spectrogram along with the texture it is drawn with.
+struct SpectrogramTexture {
+    /// Hash of the series and settings the spectrogram was computed from.
+    key: Hash64,
+
+    spectrogram: Spectrogram,
+    texture: egui::TextureHandle,
+}
+
+#[derive(Default)]
+pub struct SpectrogramViewState {
+    /// Computing a spectrogram is expensive, so we only redo it when its inputs change.
+    textures: HashMap<InstancePath, SpectrogramTexture>,
+}
+
+impl ViewState for SpectrogramViewState {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Default)]
+pub struct SpectrogramView;
+
+type ViewType = re_types::blueprint::views::SpectrogramView;
+
+impl ViewClass for SpectrogramView {
+    fn identifier() -> ViewClassIdentifier {
+        ViewType::identifier()
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Spectrogram"
+    }
+
+    fn icon(&self) -> &'static re_ui::Icon {
+        &icons::VIEW_TIMESERIES
+    }
+
+    fn help(&self, _os: egui::os::OperatingSystem) -> Help {
+        Help::new("Spectrogram view")
+            .docs_link("https://rerun.io/docs/reference/types/views/spectrogram_view")
+            .markdown(
+                "Shows the rolling FFT of each scalar series as a time-frequency heatmap.\n\n\
+                Frequencies are in Hz on temporal timelines, and in cycles per step on sequence timelines.",
+            )
+            .control("Pan", (icons::LEFT_MOUSE_CLICK, "+", "drag"))
+            .control("Zoom", icons::SCROLL)
+            .control("Move time cursor", MouseButtonText(MOVE_TIME_CURSOR_BUTTON))
+    }
+
+    fn on_register(
+        &self,
+        system_registry: &mut re_viewer_context::ViewSystemRegistrator<'_>,
+    ) -> Result<(), ViewClassRegistryError> {
+        system_registry.register_fallback_provider(
+            TensorScalarMapping::descriptor_colormap().component,
+            |_| Colormap::Viridis,
+        );
+
+        system_registry.register_visualizer::<SpectrogramSystem>()
+    }
+
+    fn new_state(&self) -> Box<dyn ViewState> {
+        Box::<SpectrogramViewState>::default()
+    }
+
+    fn default_query_range(&self, _view_state: &dyn ViewState) -> QueryRange {
+        QueryRange::TimeRange(TimeRange::EVERYTHING)
+    }
+
+    fn supports_visible_time_range(&self) -> bool {
+        true
+    }
+
+    fn layout_priority(&self) -> re_viewer_context::ViewClassLayoutPriority {
+        re_viewer_context::ViewClassLayoutPriority::Low
+    }
+
+    fn spawn_heuristics(
+        &self,
+        _ctx: &ViewerContext<'_>,
+        _include_entity: &dyn Fn(&EntityPath) -> bool,
+    ) -> ViewSpawnHeuristics {
+        // Scalars are already shown in time series views, spectrograms have to be added explicitly.
+        ViewSpawnHeuristics::empty()
+    }
+
+    fn selection_ui(
+        &self,
+        ctx: &ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        state: &mut dyn ViewState,
+        _space_origin: &EntityPath,
+        view_id: ViewId,
+    ) -> Result<(), ViewSystemExecutionError> {
+        list_item::list_item_scope(ui, "spectrogram_selection_ui", |ui| {
+            let ctx = self.view_context(ctx, view_id, state);
+            view_property_ui::<FftWindow>(&ctx, ui);
+            view_property_ui::<TensorScalarMapping>(&ctx, ui);
+        });
+
+        Ok(())
+    }
+
+    fn ui(
+        &self,
+        ctx: &ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        state: &mut dyn ViewState,
+        query: &ViewQuery<'_>,
+        system_output: re_viewer_context::SystemExecutionOutput,
+    ) -> Result<(), ViewSystemExecutionError> {
+        re_tracing::profile_function!();
+
+        let settings = {
+            let view_ctx = self.view_context(ctx, query.view_id, state);
+            let fft_window = ViewProperty::from_archetype::<FftWindow>(
+                ctx.blueprint_db(),
+                ctx.blueprint_query,
+                query.view_id,
+            );
+            let scalar_mapping = ViewProperty::from_archetype::<TensorScalarMapping>(
+                ctx.blueprint_db(),
+                ctx.blueprint_query,
+                query.view_id,
+            );
+
+            SpectrogramSettings {
+                window_size: fft_window
+                    .component_or_fallback::<FftWindowSize>(
+                        &view_ctx,
+                        FftWindow::descriptor_size().component,
+                    )?
+                    .0
+                    .0,
+                overlap: fft_window
+                    .component_or_fallback::<FftWindowOverlap>(
+                        &view_ctx,
+                        FftWindow::descriptor_overlap().component,
+                    )?
+                    .0
+                    .0,
+                colormap: scalar_mapping.component_or_fallback(
+                    &view_ctx,
+                    TensorScalarMapping::descriptor_colormap().component,
+                )?,
+                gamma: **scalar_mapping.component_or_fallback::<GammaCorrection>(
+                    &view_ctx,
+                    TensorScalarMapping::descriptor_gamma().component,
+                )?,
+                mag_filter: scalar_mapping.component_or_fallback(
+                    &view_ctx,
+                    TensorScalarMapping::descriptor_mag_filter().component,
+                )?,
+            }
+        };
+
+        let state = state.downcast_mut::<SpectrogramViewState>()?;
+        let spectrogram_system = system_output.view_systems.get::<SpectrogramSystem>()?;
+
+        // Forget about series that are no longer part of the view.
+        state.textures.retain(|instance_path, _| {
+            spectrogram_system
+                .all_series
+                .iter()
+                .any(|series| &series.instance_path == instance_path)
+        });
+
+        let Some(min_time) = spectrogram_system
+            .all_series
+            .iter()
+            .filter_map(|series| series.points.first().map(|&(time, _)| time))
+            .min()
+        else {
+            ui.weak("(empty)");
+            return Ok(());
+        };
+
+        // Offset all times to avoid precision issues with large times (nanos since epoch don't fit into a f64).
+        let time_offset = min_time;
+
+        let num_series = spectrogram_system.all_series.len();
+        let plot_height = (ui.available_height() / num_series as f32 - ui.spacing().item_spacing.y)
+            .at_least(32.0);
+
+        for series in &spectrogram_system.all_series {
+            let texture = spectrogram_texture(ui.ctx(), state, series, settings);
+            let response = if let Some(texture) = texture {
+                spectrogram_plot_ui(
+                    ctx,
+                    ui,
+                    query.view_id,
+                    series,
+                    texture,
+                    time_offset,
+                    plot_height,
+                )
+            } else {
+                ui.weak(format!("{}: not enough samples", series.instance_path))
+            };
+
+            ctx.handle_select_hover_drag_interactions(
+                &response,
+                Item::DataResult(query.view_id, series.instance_path.clone()),
+                false,
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the up-to-date spectrogram texture of a series, recomputing it if needed.
+///
+/// Returns `None` if the series has too few samples for a spectrogram.
+fn spectrogram_texture<'a>(
+    egui_ctx: &egui::Context,
+    state: &'a mut SpectrogramViewState,
+    series: &ScalarSeries,
+    settings: SpectrogramSettings,
+) -> Option<&'a SpectrogramTexture> {
+    let key = Hash64::hash(SpectrogramKey(&series.points, settings));
+
+    if state
+        .textures
+        .get(&series.instance_path)
+        .is_none_or(|texture| texture.key != key)
+    {
+        state.textures.remove(&series.instance_path);
+
+        let spectrogram =
+            Spectrogram::compute(&series.points, settings.window_size, settings.overlap)?;
+        let texture = egui_ctx.load_texture(
+            format!("spectrogram_{}", series.instance_path),
+            colorize(&spectrogram, settings),
+            match settings.mag_filter {
+                MagnificationFilter::Nearest => egui::TextureOptions::NEAREST,
+                MagnificationFilter::Linear => egui::TextureOptions::LINEAR,
+            },
+        );
+
+        state.textures.insert(
+            series.instance_path.clone(),
+            SpectrogramTexture {
+                key,
+                spectrogram,
+                texture,
+            },
+        );
+    }
+
+    state.textures.get(&series.instance_path)
+}
+
+/// Applies gamma and colormap to the magnitudes, with the highest frequencies in the top row.
+fn colorize(spectrogram: &Spectrogram, settings: SpectrogramSettings) -> egui::ColorImage {
+    re_tracing::profile_function!();
+
+    let colormap = gpu_bridge::colormap_to_re_renderer(settings.colormap);
+    let width = spectrogram.num_windows;
+    let height = spectrogram.num_bins;
+
+    let mut pixels = vec![egui::Color32::BLACK; width * height];
+    for window_index in 0..width {
+        for (bin, &magnitude) in spectrogram.window(window_index).iter().enumerate() {
+            let [r, g, b, _] = re_renderer::colormap_srgb(colormap, magnitude.powf(settings.gamma));
+            pixels[(height - 1 - bin) * width + window_index] = egui::Color32::from_rgb(r, g, b);
+        }
+    }
+
+    egui::ColorImage::new([width, height], pixels)
+}
+
+fn spectrogram_plot_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    view_id: ViewId,
+    series: &ScalarSeries,
+    texture: &SpectrogramTexture,
+    time_offset: i64,
+    plot_height: f32,
+) -> egui::Response {
+    let time_type = ctx.time_ctrl.time_type();
+    let timestamp_format = ctx.app_options().timestamp_format;
+    let timeline_name = ctx.time_ctrl.timeline().name().to_string();
+
+    let SpectrogramTexture {
+        spectrogram,
+        texture,
+        ..
+    } = texture;
+
+    // Each window covers one hop, centered on the middle of the window.
+    let width = spectrogram.num_windows as f64 * spectrogram.hop_time;
+    let center_time = spectrogram.start_time - 0.5 * spectrogram.hop_time + 0.5 * width;
+
+    // Times are in nanoseconds on temporal timelines, so frequencies are shown in Hz.
+    let (frequency_scale, frequency_unit) = match time_type {
+        TimeType::Sequence => (1.0, ""),
+        TimeType::DurationNs | TimeType::TimestampNs => (1e9, " Hz"),
+    };
+    let max_frequency = spectrogram.nyquist_frequency() * frequency_scale;
+
+    let egui_plot::PlotResponse {
+        response,
+        transform,
+        ..
+    } = Plot::new(("spectrogram_plot", &series.instance_path))
+        .id(egui::Id::new((view_id, &series.instance_path)))
+        .height(plot_height)
+        .allow_scroll([true, false])
+        .allow_zoom([true, false])
+        .allow_drag([true, false])
+        .default_y_bounds(0.0, max_frequency)
+        .link_axis(timeline_name.clone(), [true, false])
+        .link_cursor(timeline_name.as_str(), [true, false])
+        .custom_x_axes(vec![egui_plot::AxisHints::new_x().formatter(
+            move |time, _| {
+                re_log_types::TimeCell::new(
+                    time_type,
+                    (time.value as i64).saturating_add(time_offset),
+                )
+                .format_compact(timestamp_format)
+            },
+        )])
+        .custom_y_axes(vec![egui_plot::AxisHints::new_y().formatter(
+            move |frequency, _| {
+                format!("{}{frequency_unit}", re_format::format_f64(frequency.value))
+            },
+        )])
+        .label_formatter(move |_name, value| {
+            format!(
+                "{}\n{}{frequency_unit}",
+                time_type.format(
+                    TimeInt::new_temporal((value.x as i64).saturating_add(time_offset)),
+                    timestamp_format,
+                ),
+                re_format::format_f64(value.y),
+            )
+        })
+        .show(ui, |plot_ui| {
+            if plot_ui.response().clicked_by(MOVE_TIME_CURSOR_BUTTON)
+                && let Some(pointer) = plot_ui.pointer_coordinate()
+            {
+                let time = re_log_types::TimeReal::from(pointer.x as i64 + time_offset);
+                ctx.send_time_commands([
+                    TimeControlCommand::SetTime(time),
+                    TimeControlCommand::Pause,
+                ]);
+            }
+
+            plot_ui.image(PlotImage::new(
+                series.instance_path.to_string(),
+                texture,
+                PlotPoint::new(center_time - time_offset as f64, 0.5 * max_frequency),
+                egui::vec2(width as f32, max_frequency as f32),
+            ));
+        });
+
+    if let Some(current_time) = ctx.time_ctrl.time_i64() {
+        let x = current_time.saturating_sub(time_offset) as f64;
+        if transform.bounds().min()[0] <= x && x <= transform.bounds().max()[0] {
+            let x = transform.position_from_point(&PlotPoint::new(x, 0.0)).x;
+            ui.paint_time_cursor(ui.painter(), None, x, response.rect.y_range());
+        }
+    }
+
+    response
+}
+
+/// Hashes everything a spectrogram texture depends on.
+struct SpectrogramKey<'a>(&'a [(i64, f64)], SpectrogramSettings);
+
+impl Hash for SpectrogramKey<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let Self(points, settings) = self;
+        let SpectrogramSettings {
+            window_size,
+            overlap,
+            colormap,
+            gamma,
+            mag_filter,
+        } = settings;
+
+        points.len().hash(state);
+        for (time, value) in *points {
+            time.hash(state);
+            value.to_bits().hash(state);
+        }
+        window_size.hash(state);
+        overlap.to_bits().hash(state);
+        colormap.hash(state);
+        gamma.to_bits().hash(state);
+        mag_filter.hash(state);
+    }
+}
@@ -0,0 +1,94 @@
+use re_types::archetypes::Scalars;
+use re_view::{DataResultQuery as _, RangeResultsExt as _};
+use re_viewer_context::{
+    IdentifiedViewSystem, ViewContext, ViewContextCollection, ViewQuery, ViewSystemExecutionError,
+    VisualizerQueryInfo, VisualizerSystem, external::re_entity_db::InstancePath,
+};
+
+use crate::series_query::determine_num_series;
+
+/// The raw samples of a single scalar series.
+pub struct ScalarSeries {
+    pub instance_path: InstancePath,
+
+    /// `(time, value)` pairs, sorted by time.
+    pub points: Vec<(i64, f64)>,
+}
+
+/// Collects the scalar series shown by the spectrogram view.
+#[derive(Default)]
+pub struct SpectrogramSystem {
+    pub all_series: Vec<ScalarSeries>,
+}
+
+impl IdentifiedViewSystem for SpectrogramSystem {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "Spectrogram".into()
+    }
+}
+
+impl VisualizerSystem for SpectrogramSystem {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<Scalars>()
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        view_query: &ViewQuery<'_>,
+        _context_systems: &ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
+        re_tracing::profile_function!();
+
+        let timeline = view_query.timeline;
+
+        for data_result in view_query.iter_visible_data_results(Self::identifier()) {
+            let results = data_result.query_archetype_with_history::<Scalars>(ctx, view_query);
+
+            let Some(all_scalar_chunks) =
+                results.get_required_chunks(Scalars::descriptor_scalars().component)
+            else {
+                continue;
+            };
+
+            let num_series = determine_num_series(&all_scalar_chunks);
+            let mut all_series: Vec<ScalarSeries> = (0..num_series)
+                .map(|instance| ScalarSeries {
+                    instance_path: if num_series == 1 {
+                        InstancePath::entity_all(data_result.entity_path.clone())
+                    } else {
+                        InstancePath::instance(data_result.entity_path.clone(), instance as u64)
+                    },
+                    points: Vec::new(),
+                })
+                .collect();
+
+            for chunk in all_scalar_chunks.iter() {
+                for ((time, _row_id), values) in itertools::izip!(
+                    chunk.iter_component_indices(timeline),
+                    chunk.iter_slices::<f64>()
+                ) {
+                    for (series, &value) in all_series.iter_mut().zip(values) {
+                        // Gaps are bridged by the resampling of the spectrogram.
+                        if value.is_finite() {
+                            series.points.push((time.as_i64(), value));
+                        }
+                    }
+                }
+            }
+
+            for mut series in all_series {
+                if !series.points.is_sorted_by_key(|&(time, _)| time) {
+                    series.points.sort_by_key(|&(time, _)| time);
+                }
+                self.all_series.push(series);
+            }
+        }
+
+        Ok(Vec::new())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
@@ -0,0 +1,170 @@
+//! Conversion between units of measurement of plotted scalars.
+
+/// The physical quantity a unit measures.
+///
+/// Only units of the same quantity can be converted into each other.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Quantity {
+    Angle,
+    AngularVelocity,
+    Frequency,
+    Length,
+    Mass,
+    Pressure,
+    Speed,
+    Temperature,
+    Time,
+}
+
+/// A unit the time series view knows how to convert from and to.
+struct KnownUnit {
+    symbols: &'static [&'static str],
+    quantity: Quantity,
+
+    /// Value of one of this unit in the base unit of the quantity.
+    scale: f64,
+
+    /// Added to the scaled value to get to the base unit of the quantity.
+    offset: f64,
+}
+
+impl KnownUnit {
+    const fn new(symbols: &'static [&'static str], quantity: Quantity, scale: f64) -> Self {
+        Self {
+            symbols,
+            quantity,
+            scale,
+            offset: 0.0,
+        }
+    }
+}
+
+const KNOWN_UNITS: &[KnownUnit] = &[
+    // Angle:
+    KnownUnit::new(&["rad"], Quantity::Angle, 1.0),
+    KnownUnit::new(&["deg", "°"], Quantity::Angle, std::f64::consts::PI / 180.0),
+    KnownUnit::new(&["rev"], Quantity::Angle, std::f64::consts::TAU),
+    // Angular velocity:
+    KnownUnit::new(&["rad/s"], Quantity::AngularVelocity, 1.0),
+    KnownUnit::new(
+        &["deg/s", "°/s"],
+        Quantity::AngularVelocity,
+        std::f64::consts::PI / 180.0,
+    ),
+    KnownUnit::new(
+        &["rpm"],
+        Quantity::AngularVelocity,
+        std::f64::consts::TAU / 60.0,
+    ),
+    // Frequency:
+    KnownUnit::new(&["Hz"], Quantity::Frequency, 1.0),
+    KnownUnit::new(&["kHz"], Quantity::Frequency, 1e3),
+    KnownUnit::new(&["MHz"], Quantity::Frequency, 1e6),
+    KnownUnit::new(&["GHz"], Quantity::Frequency, 1e9),
+    // Length:
+    KnownUnit::new(&["m"], Quantity::Length, 1.0),
+    KnownUnit::new(&["km"], Quantity::Length, 1e3),
+    KnownUnit::new(&["cm"], Quantity::Length, 1e-2),
+    KnownUnit::new(&["mm"], Quantity::Length, 1e-3),
+    KnownUnit::new(&["µm", "um"], Quantity::Length, 1e-6),
+    KnownUnit::new(&["in"], Quantity::Length, 0.0254),
+    KnownUnit::new(&["ft"], Quantity::Length, 0.3048),
+    KnownUnit::new(&["mi"], Quantity::Length, 1609.344),
+    // Mass:
+    KnownUnit::new(&["kg"], Quantity::Mass, 1.0),
+    KnownUnit::new(&["g"], Quantity::Mass, 1e-3),
+    KnownUnit::new(&["t"], Quantity::Mass, 1e3),
+    KnownUnit::new(&["lb"], Quantity::Mass, 0.453_592_37),
+    // Pressure:
+    KnownUnit::new(&["Pa"], Quantity::Pressure, 1.0),
+    KnownUnit::new(&["hPa"], Quantity::Pressure, 1e2),
+    KnownUnit::new(&["kPa"], Quantity::Pressure, 1e3),
+    KnownUnit::new(&["bar"], Quantity::Pressure, 1e5),
+    KnownUnit::new(&["psi"], Quantity::Pressure, 6_894.757_293_168),
+    // Speed:
+    KnownUnit::new(&["m/s"], Quantity::Speed, 1.0),
+    KnownUnit::new(&["km/h", "kph"], Quantity::Speed, 1.0 / 3.6),
+    KnownUnit::new(&["mph"], Quantity::Speed, 0.447_04),
+    KnownUnit::new(&["kn"], Quantity::Speed, 1852.0 / 3600.0),
+    // Temperature:
+    KnownUnit::new(&["K"], Quantity::Temperature, 1.0),
+    KnownUnit {
+        symbols: &["°C", "degC"],
+        quantity: Quantity::Temperature,
+        scale: 1.0,
+        offset: 273.15,
+    },
+    KnownUnit {
+        symbols: &["°F", "degF"],
+        quantity: Quantity::Temperature,
+        scale: 5.0 / 9.0,
+        offset: 273.15 - 32.0 * 5.0 / 9.0,
+    },
+    // Time:
+    KnownUnit::new(&["s"], Quantity::Time, 1.0),
+    KnownUnit::new(&["ms"], Quantity::Time, 1e-3),
+    KnownUnit::new(&["µs", "us"], Quantity::Time, 1e-6),
+    KnownUnit::new(&["ns"], Quantity::Time, 1e-9),
+    KnownUnit::new(&["min"], Quantity::Time, 60.0),
+    KnownUnit::new(&["h"], Quantity::Time, 3600.0),
+];
+
+fn find_known_unit(symbol: &str) -> Option<&'static KnownUnit> {
+    let symbol = symbol.trim();
+    KNOWN_UNITS
+        .iter()
+        .find(|unit| unit.symbols.contains(&symbol))
+}
+
+/// A linear conversion of values from one unit to another.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UnitConversion {
+    scale: f64,
+    offset: f64,
+}
+
+impl UnitConversion {
+    /// The conversion from `from` to `to`, if both are known units of the same quantity.
+    pub fn between(from: &str, to: &str) -> Option<Self> {
+        let from = find_known_unit(from)?;
+        let to = find_known_unit(to)?;
+        (from.quantity == to.quantity).then(|| Self {
+            scale: from.scale / to.scale,
+            offset: (from.offset - to.offset) / to.scale,
+        })
+    }
+
+    /// Whether this conversion leaves all values as they are.
+    pub fn is_identity(&self) -> bool {
+        self.scale == 1.0 && self.offset == 0.0
+    }
+
+    #[inline]
+    pub fn apply(&self, value: f64) -> f64 {
+        value * self.scale + self.offset
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UnitConversion;
+
+    fn convert(value: f64, from: &str, to: &str) -> f64 {
+        UnitConversion::between(from, to).unwrap().apply(value)
+    }
+
+    #[test]
+    fn conversions() {
+        assert!((convert(std::f64::consts::PI, "rad", "deg") - 180.0).abs() < 1e-9);
+        assert!((convert(10.0, "m/s", "km/h") - 36.0).abs() < 1e-9);
+        assert!((convert(100.0, "°C", "°F") - 212.0).abs() < 1e-9);
+        assert!((convert(0.0, "K", "°C") + 273.15).abs() < 1e-9);
+        assert!(UnitConversion::between("mm", "mm").unwrap().is_identity());
+    }
+
+    #[test]
+    fn incompatible_units() {
+        assert_eq!(UnitConversion::between("m", "s"), None);
+        assert_eq!(UnitConversion::between("m", "furlong"), None);
+    }
+}
@@ -4,13 +4,14 @@ use re_types::{
     components::AggregationPolicy,
 };
 use re_viewer_context::{
-    ViewContext, ViewQuery, ViewerContext, external::re_entity_db::InstancePath,
+    ViewContext, ViewQuery, ViewStateExt as _, ViewerContext, external::re_entity_db::InstancePath,
 };
 use re_viewport_blueprint::{ViewProperty, ViewPropertyQueryError};
 
 use crate::{
     PlotPoint, PlotSeries, PlotSeriesKind, ScatterAttrs,
     aggregation::{AverageAggregator, MinMaxAggregator},
+    view_class::TimeSeriesViewState,
 };
 
 /// Find the number of time units per physical pixel.
@@ -30,6 +31,24 @@ pub fn determine_time_per_pixel(
     1.0 / pixels_per_time.max(f64::EPSILON)
 }
 
+/// The range of time that was on screen in the last frame, if the plot was shown before.
+pub fn determine_visible_time_range(
+    ctx: &ViewContext<'_>,
+    plot_mem: Option<&egui_plot::PlotMemory>,
+) -> Option<AbsoluteTimeRange> {
+    let time_offset = ctx
+        .view_state
+        .downcast_ref::<TimeSeriesViewState>()
+        .ok()?
+        .time_offset;
+    let bounds = plot_mem?.transform().bounds();
+
+    Some(AbsoluteTimeRange::new(
+        (bounds.min()[0] as i64).saturating_add(time_offset),
+        (bounds.max()[0] as i64).saturating_add(time_offset),
+    ))
+}
+
 pub fn determine_time_range(
     ctx: &ViewContext<'_>,
 ) -> Result<AbsoluteTimeRange, ViewPropertyQueryError> {
@@ -88,6 +107,7 @@ pub fn points_to_series(
     store: &re_chunk_store::ChunkStore,
     query: &ViewQuery<'_>,
     series_label: String,
+    unit: Option<String>,
     aggregator: AggregationPolicy,
     all_series: &mut Vec<PlotSeries>,
 ) {
@@ -112,10 +132,12 @@ pub fn points_to_series(
             visible,
             id: egui::Id::new(&instance_path),
             label: series_label,
+            unit,
             color: points[0].attrs.color,
             radius_ui: points[0].attrs.radius_ui,
             kind,
             points: vec![(points[0].time, points[0].value)],
+            band: Vec::new(),
             instance_path,
             aggregator,
             aggregation_factor,
@@ -125,6 +147,7 @@ pub fn points_to_series(
         add_series_runs(
             visible,
             series_label,
+            unit,
             points,
             instance_path,
             aggregator,
@@ -180,6 +203,10 @@ pub fn apply_aggregation(
             AggregationPolicy::MinMaxAverage => {
                 MinMaxAggregator::MinMaxAverage.aggregate(aggregation_duration, &points)
             }
+            // Zoomed in further than the streamed aggregates go, so there's no band to draw.
+            AggregationPolicy::MinMeanMax => {
+                AverageAggregator::aggregate(aggregation_duration, &points)
+            }
         }
     } else {
         points
@@ -206,6 +233,7 @@ pub fn apply_aggregation(
 fn add_series_runs(
     visible: bool,
     series_label: String,
+    unit: Option<String>,
     points: Vec<PlotPoint>,
     instance_path: InstancePath,
     aggregator: AggregationPolicy,
@@ -223,9 +251,11 @@ fn add_series_runs(
         visible,
         id,
         label: series_label.clone(),
+        unit: unit.clone(),
         color: attrs.color,
         radius_ui: attrs.radius_ui,
         points: Vec::with_capacity(num_points),
+        band: Vec::new(),
         kind: attrs.kind,
         instance_path: instance_path.clone(),
         aggregator,
@@ -249,10 +279,12 @@ fn add_series_runs(
                     visible,
                     id,
                     label: series_label.clone(),
+                    unit: unit.clone(),
                     color: attrs.color,
                     radius_ui: attrs.radius_ui,
                     kind: attrs.kind,
                     points: Vec::with_capacity(num_points - i),
+                    band: Vec::new(),
                     instance_path: instance_path.clone(),
                     aggregator,
                     aggregation_factor,
@@ -3,6 +3,7 @@ use egui::{
     ahash::{HashMap, HashSet},
 };
 use egui_plot::{ColorConflictHandling, Legend, Line, Plot, PlotPoint, Points};
+use itertools::Itertools as _;
 use nohash_hasher::IntSet;
 use re_viewer_context::{
     BlueprintContext as _, IdentifiedViewSystem as _, IndicatedEntities, MaybeVisualizableEntities,
@@ -234,6 +235,9 @@ impl ViewClass for TimeSeriesView {
             },
         );
 
+        // Ensure the scalars are aggregated as they come in.
+        crate::scalar_aggregates_subscriber::ScalarAggregatesStoreSubscriber::subscription_handle();
+
         system_registry.register_visualizer::<SeriesLinesSystem>()?;
         system_registry.register_visualizer::<SeriesPointsSystem>()?;
         Ok(())
@@ -621,6 +625,19 @@ impl ViewClass for TimeSeriesView {
             .map(|line| line.aggregator)
             .unwrap_or_default();
 
+        // The scalar axis is only labeled if all visible series share the same unit.
+        let y_axis_unit = all_plot_series
+            .iter()
+            .filter(|series| series.visible)
+            .map(|series| series.unit.clone())
+            .all_equal_value()
+            .ok()
+            .flatten();
+        let unit_per_series_label: HashMap<String, String> = all_plot_series
+            .iter()
+            .filter_map(|series| Some((series.label.clone(), series.unit.clone()?)))
+            .collect();
+
         // …then use that as an offset to avoid nasty precision issues with
         // large times (nanos since epoch does not fit into a f64).
         let time_offset = match timeline.typ() {
@@ -648,6 +665,13 @@ impl ViewClass for TimeSeriesView {
 
             ui.style_mut().visuals.extreme_bg_color = background_color.into();
 
+            let mut y_axis = egui_plot::AxisHints::new_y()
+                .min_thickness(min_axis_thickness)
+                .formatter(move |mark, _| format_y_axis(mark));
+            if let Some(unit) = y_axis_unit {
+                y_axis = y_axis.label(unit);
+            }
+
             let mut plot = Plot::new(plot_id_src)
                 .id(plot_id)
                 .show_grid(**show_grid)
@@ -664,11 +688,7 @@ impl ViewClass for TimeSeriesView {
                             .format_compact(timestamp_format)
                         }),
                 ])
-                .custom_y_axes(vec![
-                    egui_plot::AxisHints::new_y()
-                        .min_thickness(min_axis_thickness)
-                        .formatter(move |mark, _| format_y_axis(mark)),
-                ])
+                .custom_y_axes(vec![y_axis])
                 .label_formatter(move |name, value| {
                     let name = if name.is_empty() { "y" } else { name };
                     let label = time_type.format(
@@ -676,7 +696,10 @@ impl ViewClass for TimeSeriesView {
                         timestamp_format,
                     );
 
-                    let y_value = re_format::format_f64(value.y);
+                    let mut y_value = re_format::format_f64(value.y);
+                    if let Some(unit) = unit_per_series_label.get(name) {
+                        y_value = format!("{y_value} {unit}");
+                    }
 
                     if aggregator == AggregationPolicy::Off || aggregation_factor <= 1.0 {
                         format!("{timeline_name}: {label}\n{name}: {y_value}")
@@ -1019,6 +1042,10 @@ fn add_series_to_plot(
     *scalar_range.end_mut() = f64::NEG_INFINITY;
 
     for series in all_plot_series {
+        if series.visible {
+            add_band_to_plot(plot_ui, series, time_offset, scalar_range);
+        }
+
         let points = if series.visible {
             series
                 .points
@@ -1074,6 +1101,46 @@ fn add_series_to_plot(
     }
 }
 
+/// Fills the area between the minimum and maximum of each step of a series drawn as a band.
+fn add_band_to_plot(
+    plot_ui: &mut egui_plot::PlotUi<'_>,
+    series: &crate::PlotSeries,
+    time_offset: i64,
+    scalar_range: &mut Range1D,
+) {
+    if series.band.is_empty() {
+        return;
+    }
+
+    for (min, max) in &series.band {
+        if *min < scalar_range.start() {
+            *scalar_range.start_mut() = *min;
+        }
+        if *max > scalar_range.end() {
+            *scalar_range.end_mut() = *max;
+        }
+    }
+
+    let fill_color = series.color.gamma_multiply(0.3);
+
+    // Polygons are only filled properly if they're convex, so the band is drawn as one quad per
+    // step. They have no name, so they don't show up in the legend.
+    for ((t0, _), (t1, _), (min0, max0), (min1, max1)) in itertools::izip!(
+        &series.points,
+        series.points.iter().skip(1),
+        &series.band,
+        series.band.iter().skip(1)
+    ) {
+        let (t0, t1) = ((t0 - time_offset) as f64, (t1 - time_offset) as f64);
+        plot_ui.polygon(
+            egui_plot::Polygon::new("", vec![[t0, *min0], [t1, *min1], [t1, *max1], [t0, *max0]])
+                .fill_color(fill_color)
+                .stroke(egui::Stroke::NONE)
+                .allow_hover(false),
+        );
+    }
+}
+
 fn format_y_axis(mark: egui_plot::GridMark) -> String {
     // Example: If the step to the next tick is `0.01`, we should use 2 decimals of precision:
     let num_decimals = -mark.step_size.log10().round() as usize;
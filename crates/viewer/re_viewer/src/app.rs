@@ -73,6 +73,18 @@ pub struct App {
     pub(crate) egui_ctx: egui::Context,
     screenshotter: crate::screenshotter::Screenshotter,
 
+    /// Set if the viewer should keep running in the background when its window is closed.
+    #[cfg(not(target_arch = "wasm32"))]
+    background_mode: Option<crate::background_mode::BackgroundMode>,
+
+    /// Set if stalled frames should be reported.
+    #[cfg(not(target_arch = "wasm32"))]
+    frame_watchdog: Option<crate::frame_watchdog::FrameWatchdog>,
+
+    /// Set if a session report should be written on exit.
+    #[cfg(not(target_arch = "wasm32"))]
+    session_report: Option<parking_lot::Mutex<crate::session_report::SessionReport>>,
+
     #[cfg(target_arch = "wasm32")]
     pub(crate) popstate_listener: Option<crate::history::PopstateListener>,
 
@@ -267,6 +279,26 @@ impl App {
             screenshotter.screenshot_to_path_then_quit(&creation_context.egui_ctx, screenshot_path);
         }
 
+        #[cfg(not(target_arch = "wasm32"))]
+        let background_mode = startup_options
+            .run_in_background
+            .then(|| crate::background_mode::BackgroundMode::new(&creation_context.egui_ctx));
+
+        #[cfg(not(target_arch = "wasm32"))]
+        let frame_watchdog = startup_options
+            .frame_watchdog_threshold
+            .and_then(|threshold| {
+                crate::frame_watchdog::FrameWatchdog::start(threshold)
+                    .inspect_err(|err| re_log::warn!("Failed to start frame watchdog: {err}"))
+                    .ok()
+            });
+
+        #[cfg(not(target_arch = "wasm32"))]
+        let session_report = startup_options
+            .session_report_path
+            .clone()
+            .map(|path| parking_lot::Mutex::new(crate::session_report::SessionReport::new(path)));
+
         let (command_sender, command_receiver) = command_channel;
 
         let mut component_ui_registry = re_component_ui::create_component_ui_registry();
@@ -383,6 +415,13 @@ impl App {
             egui_ctx: creation_context.egui_ctx.clone(),
             screenshotter,
 
+            #[cfg(not(target_arch = "wasm32"))]
+            background_mode,
+            #[cfg(not(target_arch = "wasm32"))]
+            frame_watchdog,
+            #[cfg(not(target_arch = "wasm32"))]
+            session_report,
+
             #[cfg(target_arch = "wasm32")]
             popstate_listener: None,
 
@@ -859,6 +898,9 @@ impl App {
                         // TODO(#10967): We still stream in some data after that.
                         SmartChannelSource::RedapGrpcStream { .. } => true,
 
+                        // A WebSocket connection only ever feeds a single recording.
+                        SmartChannelSource::McapWebSocket { .. } => true,
+
                         // Don't close generic connections (like to an SDK) that may feed in different recordings over time.
                         SmartChannelSource::RrdWebEventListener
                         | SmartChannelSource::JsChannel { .. }
@@ -885,7 +927,9 @@ impl App {
                 // That's the case of `SmartChannelSource::RrdHttpStream`.
                 // TODO(emilk): exactly what things get kept and what gets cleared?
                 self.rx_log.retain(|r| match r.source() {
-                    SmartChannelSource::File(_) | SmartChannelSource::RrdHttpStream { .. } => false,
+                    SmartChannelSource::File(_)
+                    | SmartChannelSource::RrdHttpStream { .. }
+                    | SmartChannelSource::McapWebSocket { .. } => false,
 
                     SmartChannelSource::JsChannel { .. }
                     | SmartChannelSource::RrdWebEventListener
@@ -1086,6 +1130,56 @@ impl App {
                 blueprint_db.drop_entity_path_recursive(&entity_path);
             }
 
+            SystemCommand::RebaseTimeline {
+                store_id,
+                timeline,
+                rebase,
+            } => {
+                let Some(entity_db) = store_hub.entity_db(&store_id) else {
+                    re_log::warn!("Can't re-base unknown recording {store_id:?}");
+                    return;
+                };
+
+                let new_id =
+                    StoreId::random(StoreKind::Recording, store_id.application_id().clone());
+                match entity_db.rebased(new_id.clone(), &timeline, &rebase) {
+                    Ok(new_db) => {
+                        re_log::info!("Re-based timeline {timeline:?} into a new recording");
+                        store_hub.insert_entity_db(new_db);
+                        store_hub.set_active_recording_id(new_id.clone());
+                        self.state
+                            .navigation
+                            .replace(RecordingOrTable::from(new_id).display_mode());
+                    }
+                    Err(err) => {
+                        re_log::error!("Failed to re-base timeline {timeline:?}: {err}");
+                    }
+                }
+            }
+
+            SystemCommand::PinStaticVersion {
+                store_id,
+                component_path,
+                version,
+            } => {
+                let entity_db = store_hub.entity_db_mut(&store_id);
+                let Some(store_events) = entity_db.pin_static_version(
+                    &component_path.entity_path,
+                    component_path.component,
+                    version,
+                ) else {
+                    re_log::warn!("Unknown static version of {component_path}");
+                    return;
+                };
+
+                if store_hub.active_store_id() == Some(&store_id)
+                    && let Some(caches) = store_hub.active_caches()
+                    && let Some(entity_db) = store_hub.entity_db(&store_id)
+                {
+                    caches.on_store_events(&store_events, entity_db);
+                }
+            }
+
             #[cfg(debug_assertions)]
             SystemCommand::EnableInspectBlueprintTimeline(show) => {
                 self.app_options_mut().inspect_blueprint_timeline = show;
@@ -1199,6 +1293,13 @@ impl App {
                         debug_assert!(store_id.is_recording()); // `find_recording_store_by_source` should have filtered for recordings rather than blueprints.
                         drop(all_sources);
                         self.make_store_active_and_highlight(store_hub, egui_ctx, &store_id);
+
+                        if let Some(fragment) = url.fragment() {
+                            self.go_to_dataset_data(
+                                store_id,
+                                re_uri::Fragment::parse_forgiving(fragment),
+                            );
+                        }
                     }
                     return;
                 }
@@ -1263,6 +1364,18 @@ impl App {
                     return;
                 }
             }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            LogDataSource::FoxgloveWebSocket(url) => {
+                let new_source = SmartChannelSource::McapWebSocket {
+                    url: url.to_string(),
+                };
+                if all_sources.any(|source| source.is_same_ignoring_uri_fragments(&new_source)) {
+                    drop(all_sources);
+                    self.try_make_recording_from_source_active(egui_ctx, store_hub, &new_source);
+                    return;
+                }
+            }
         }
         // On native, `add_receiver` spawns a thread that wakes up the ui thread
         // on any new message. On web we cannot spawn threads, so instead we need
@@ -1291,7 +1404,23 @@ impl App {
     ///
     /// Does *not* switch the active recording.
     fn go_to_dataset_data(&self, store_id: StoreId, fragment: re_uri::Fragment) {
-        let re_uri::Fragment { selection, when } = fragment;
+        let re_uri::Fragment {
+            selection,
+            when,
+            blueprint,
+        } = fragment;
+
+        if let Some(blueprint) = blueprint {
+            // Loaded like any other `.rbl` file, which will activate it once it arrives.
+            self.command_sender
+                .send_system(SystemCommand::LoadDataSource(LogDataSource::FileContents(
+                    FileSource::Uri,
+                    FileContents {
+                        name: "shared_blueprint.rbl".to_owned(),
+                        bytes: blueprint.into(),
+                    },
+                )));
+        }
 
         if let Some(selection) = selection {
             let re_log_types::DataPath {
@@ -1420,6 +1549,18 @@ impl App {
                 }
             }
 
+            UICommand::ExportViewDataAsCsv => {
+                self.state
+                    .export_selected_view_data(crate::view_data_export::ViewDataExportFormat::Csv);
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            UICommand::ExportViewDataAsParquet => {
+                self.state.export_selected_view_data(
+                    crate::view_data_export::ViewDataExportFormat::Parquet,
+                );
+            }
+
             UICommand::SaveBlueprint => {
                 if let Err(err) = save_blueprint(self, store_context) {
                     re_log::error!("Failed to save blueprint: {err}");
@@ -1519,7 +1660,11 @@ impl App {
 
             #[cfg(not(target_arch = "wasm32"))]
             UICommand::Quit => {
-                egui_ctx.send_viewport_cmd(egui::ViewportCommand::Close);
+                if let Some(background_mode) = &mut self.background_mode {
+                    background_mode.quit(egui_ctx);
+                } else {
+                    egui_ctx.send_viewport_cmd(egui::ViewportCommand::Close);
+                }
             }
 
             UICommand::OpenWebHelp => {
@@ -1593,6 +1738,19 @@ impl App {
                 }
             },
 
+            UICommand::ToggleTransformTreePanel => {
+                self.state.transform_tree_panel_open ^= true;
+            }
+            UICommand::ToggleAnnotationLegend => {
+                self.state.annotation_legend_open ^= true;
+            }
+            UICommand::ToggleTrajectoryMetricsPanel => {
+                self.state.trajectory_metrics_panel_open ^= true;
+            }
+            UICommand::ToggleEntityStatisticsOverlay => {
+                self.app_options_mut().show_entity_statistics_overlay ^= true;
+            }
+
             #[cfg(debug_assertions)]
             UICommand::ToggleBlueprintInspectionPanel => {
                 self.app_options_mut().inspect_blueprint_timeline ^= true;
@@ -2119,6 +2277,9 @@ impl App {
 
         let start = web_time::Instant::now();
 
+        // Recordings that received data this frame, for `ViewerEventKind::IngestProgress`.
+        let mut ingesting_recordings = ahash::HashSet::default();
+
         while let Some((channel_source, msg)) = self.rx_log.try_recv() {
             re_log::trace!("Received a message from {channel_source:?}"); // Used by `test_ui_wakeup` test app!
 
@@ -2136,12 +2297,26 @@ impl App {
                     } else {
                         re_log::debug!("Data source {} has finished", msg.source);
                     }
+
+                    if let Some(event_dispatcher) = self.event_dispatcher.as_ref() {
+                        for entity_db in store_hub
+                            .store_bundle()
+                            .recordings()
+                            .filter(|db| db.data_source.as_ref() == Some(channel_source.as_ref()))
+                        {
+                            ingesting_recordings.remove(entity_db.store_id());
+                            event_dispatcher.on_ingest_progress(entity_db, true);
+                        }
+                    }
                     continue;
                 }
             };
 
             match msg {
                 DataSourceMessage::LogMsg(msg) => {
+                    if self.event_dispatcher.is_some() && msg.store_id().is_recording() {
+                        ingesting_recordings.insert(msg.store_id().clone());
+                    }
                     self.receive_log_msg(&msg, store_hub, egui_ctx, &channel_source);
                 }
 
@@ -2156,6 +2331,14 @@ impl App {
             }
         }
 
+        if let Some(event_dispatcher) = self.event_dispatcher.as_ref() {
+            for store_id in &ingesting_recordings {
+                if let Some(entity_db) = store_hub.entity_db(store_id) {
+                    event_dispatcher.on_ingest_progress(entity_db, false);
+                }
+            }
+        }
+
         // Run pending system commands in case any of the messages resulted in additional commands.
         // This avoid further frame delays on these commands.
         self.run_pending_system_commands(store_hub, egui_ctx);
@@ -2186,7 +2369,18 @@ impl App {
         }
 
         let was_empty = entity_db.is_empty();
-        let entity_db_add_result = entity_db.add(msg);
+        let mut unmigrated_columns = Vec::new();
+        let entity_db_add_result = entity_db.add_and_migrate(msg, |chunk| {
+            unmigrated_columns = crate::schema_migration::migrate_component_datatypes(
+                &self.reflection.components,
+                chunk,
+            );
+        });
+
+        for column in &unmigrated_columns {
+            self.command_sender
+                .send_system(SystemCommand::ShowNotification(column.notification()));
+        }
 
         // Downgrade to read-only, so we can access caches.
         let entity_db = store_hub
@@ -2200,6 +2394,30 @@ impl App {
                 }
 
                 self.validate_loaded_events(&store_events);
+
+                if store_id.is_recording() {
+                    self.follow_clock_controls(store_id, &store_events);
+                }
+
+                #[cfg(not(target_arch = "wasm32"))]
+                if let Some(background_mode) = &self.background_mode
+                    && background_mode.is_in_background()
+                    && store_events
+                        .iter()
+                        .any(|event| crate::background_mode::has_error_text_log(&event.diff.chunk))
+                {
+                    background_mode.bring_to_front(egui_ctx);
+                }
+
+                #[cfg(not(target_arch = "wasm32"))]
+                if let Some(frame_watchdog) = &self.frame_watchdog {
+                    frame_watchdog.on_store_events(&store_events);
+                }
+
+                #[cfg(not(target_arch = "wasm32"))]
+                if let Some(session_report) = &self.session_report {
+                    session_report.lock().on_store_events(&store_events);
+                }
             }
 
             Err(err) => {
@@ -2210,8 +2428,21 @@ impl App {
         if was_empty && !entity_db.is_empty() {
             // Hack: we cannot go to a specific timeline or entity until we know about it.
             // Now we _hopefully_ do.
-            if let SmartChannelSource::RedapGrpcStream { uri, .. } = channel_source {
-                self.go_to_dataset_data(uri.store_id(), uri.fragment.clone());
+            match channel_source {
+                SmartChannelSource::RedapGrpcStream { uri, .. } => {
+                    self.go_to_dataset_data(uri.store_id(), uri.fragment.clone());
+                }
+
+                SmartChannelSource::RrdHttpStream { url, .. } if store_id.is_recording() => {
+                    if let Some((_, fragment)) = url.split_once('#') {
+                        self.go_to_dataset_data(
+                            store_id.clone(),
+                            re_uri::Fragment::parse_forgiving(fragment),
+                        );
+                    }
+                }
+
+                _ => {}
             }
         }
 
@@ -2418,6 +2649,44 @@ impl App {
         ));
     }
 
+    /// Moves the time cursor to where the latest incoming [`re_types::archetypes::ClockControl`] wants it.
+    fn follow_clock_controls(
+        &self,
+        store_id: &StoreId,
+        store_events: &[re_chunk_store::ChunkStoreEvent],
+    ) {
+        let Some((timeline, time)) = store_events
+            .iter()
+            .filter(|event| event.kind == re_chunk_store::ChunkStoreDiffKind::Addition)
+            .filter_map(|event| crate::clock_control::latest_clock_control(&event.chunk))
+            .last()
+        else {
+            return;
+        };
+
+        let mut time_commands = Vec::new();
+
+        // Avoid writing the same timeline to the blueprint over and over again.
+        if self
+            .state
+            .time_control(store_id)
+            .is_none_or(|time_ctrl| *time_ctrl.timeline().name() != timeline)
+        {
+            time_commands.push(TimeControlCommand::SetActiveTimeline(timeline));
+        }
+
+        time_commands.extend([
+            TimeControlCommand::SetPlayState(PlayState::Paused),
+            TimeControlCommand::SetTime(time.into()),
+        ]);
+
+        self.command_sender
+            .send_system(SystemCommand::TimeControlCommands {
+                store_id: store_id.clone(),
+                time_commands,
+            });
+    }
+
     /// After loading some data; check if the loaded data makes sense.
     fn validate_loaded_events(&self, store_events: &[re_chunk_store::ChunkStoreEvent]) {
         re_tracing::profile_function!();
@@ -2624,6 +2893,7 @@ impl App {
                 | SmartChannelSource::Stdin
                 | SmartChannelSource::RrdWebEventListener
                 | SmartChannelSource::Sdk
+                | SmartChannelSource::McapWebSocket { .. }
                 | SmartChannelSource::JsChannel { .. } => {
                     return true; // We expect data soon, so fade-in
                 }
@@ -2851,6 +3121,15 @@ fn blueprint_loader() -> BlueprintPersistence {
     }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+impl Drop for App {
+    fn drop(&mut self) {
+        if let Some(session_report) = &self.session_report {
+            session_report.lock().write(self.store_hub.as_ref());
+        }
+    }
+}
+
 impl eframe::App for App {
     fn clear_color(&self, visuals: &egui::Visuals) -> [f32; 4] {
         if re_ui::CUSTOM_WINDOW_DECORATIONS {
@@ -2898,9 +3177,25 @@ impl eframe::App for App {
         #[cfg(all(not(target_arch = "wasm32"), feature = "perf_telemetry"))]
         re_perf_telemetry::external::tracing_tracy::client::frame_mark();
 
+        #[cfg(not(target_arch = "wasm32"))]
+        let _frame_watchdog_guard = self
+            .frame_watchdog
+            .as_ref()
+            .map(crate::frame_watchdog::FrameWatchdog::begin_frame);
+
         if let Some(seconds) = frame.info().cpu_usage {
             self.frame_time_history
                 .add(egui_ctx.input(|i| i.time), seconds);
+
+            #[cfg(not(target_arch = "wasm32"))]
+            if let Some(session_report) = &self.session_report {
+                session_report.lock().on_frame(
+                    self.store_hub
+                        .as_ref()
+                        .and_then(|store_hub| store_hub.active_store_id()),
+                    seconds,
+                );
+            }
         }
 
         #[cfg(target_arch = "wasm32")]
@@ -2942,6 +3237,11 @@ impl eframe::App for App {
             return;
         }
 
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(background_mode) = &mut self.background_mode {
+            background_mode.update(egui_ctx);
+        }
+
         if self.startup_options.memory_limit.is_unlimited() {
             // we only warn about high memory usage if the user hasn't specified a limit
             self.ram_limit_warner.update();
@@ -15,19 +15,21 @@ use re_ui::{ContextExt as _, UiExt as _};
 use re_viewer_context::{
     AppOptions, ApplicationSelectionState, AsyncRuntimeHandle, BlueprintContext,
     BlueprintUndoState, CommandSender, ComponentUiRegistry, DataQueryResult, DisplayMode,
-    DragAndDropManager, FallbackProviderRegistry, GlobalContext, IndicatedEntities, Item,
-    MaybeVisualizableEntities, PerVisualizer, SelectionChange, StorageContext, StoreContext,
-    StoreHub, SystemCommand, SystemCommandSender as _, TableStore, TimeControl, TimeControlCommand,
-    ViewClassRegistry, ViewId, ViewStates, ViewerContext, blueprint_timeline,
+    DragAndDropManager, EntityTreeSnapshot, FallbackProviderRegistry, GlobalContext,
+    IndicatedEntities, Item, MaybeVisualizableEntities, PerVisualizer, SelectionChange,
+    StorageContext, StoreContext, StoreHub, SystemCommand, SystemCommandSender as _, TableStore,
+    TimeControl, TimeControlCommand, ViewClassRegistry, ViewId, ViewStates, ViewerContext,
+    blueprint_timeline,
     open_url::{self, ViewerOpenUrl},
 };
 use re_viewport::ViewportUi;
 use re_viewport_blueprint::ViewportBlueprint;
-use re_viewport_blueprint::ui::add_view_or_container_modal_ui;
+use re_viewport_blueprint::ui::{add_view_or_container_modal_ui, blueprint_diff_modal_ui};
 
 use crate::{
     StartupOptions, app_blueprint::AppBlueprint, app_blueprint_ctx::AppBlueprintCtx,
     navigation::Navigation, open_url_description::ViewerOpenUrlDescription, ui::settings_screen_ui,
+    view_data_export::ViewDataExportFormat,
 };
 
 const WATERMARK: bool = false; // Nice for recording media material
@@ -89,6 +91,10 @@ pub struct AppState {
     #[serde(skip)]
     view_states: ViewStates,
 
+    /// The entity tree of the active recording, walked at most once per frame.
+    #[serde(skip)]
+    entity_tree: EntityTreeSnapshot,
+
     /// Selection & hovering state.
     ///
     /// Not serialized since on startup we have to typically discard it anyways since
@@ -105,6 +111,25 @@ pub struct AppState {
     /// that last several frames.
     #[serde(skip)]
     pub(crate) focused_item: Option<Item>,
+
+    /// Whether the transform tree debugging panel is shown.
+    #[serde(skip)]
+    pub(crate) transform_tree_panel_open: bool,
+
+    /// Whether the annotation class legend is shown.
+    #[serde(skip)]
+    pub(crate) annotation_legend_open: bool,
+
+    /// Whether the trajectory metrics panel is shown.
+    #[serde(skip)]
+    pub(crate) trajectory_metrics_panel_open: bool,
+
+    #[serde(skip)]
+    trajectory_metrics_panel: crate::ui::TrajectoryMetricsPanel,
+
+    /// The view whose data to export on the next frame, once its query results are available.
+    #[serde(skip)]
+    pending_view_data_export: Option<(ViewId, ViewDataExportFormat)>,
 }
 
 impl Default for AppState {
@@ -125,8 +150,14 @@ impl Default for AppState {
             share_modal: Default::default(),
             navigation: Default::default(),
             view_states: Default::default(),
+            entity_tree: Default::default(),
             selection_state: Default::default(),
             focused_item: Default::default(),
+            transform_tree_panel_open: false,
+            annotation_legend_open: false,
+            trajectory_metrics_panel_open: false,
+            trajectory_metrics_panel: Default::default(),
+            pending_view_data_export: None,
 
             #[cfg(feature = "testing")]
             test_hook: None,
@@ -155,6 +186,26 @@ impl AppState {
         &mut self.app_options
     }
 
+    /// Exports the data of the selected view on the next frame.
+    ///
+    /// If an entity is selected in a view rather than the view itself, that view is exported.
+    pub fn export_selected_view_data(&mut self, format: ViewDataExportFormat) {
+        let view_id =
+            self.selection_state
+                .selected_items()
+                .iter_items()
+                .find_map(|item| match item {
+                    Item::View(view_id) | Item::DataResult(view_id, _) => Some(*view_id),
+                    _ => None,
+                });
+
+        if let Some(view_id) = view_id {
+            self.pending_view_data_export = Some((view_id, format));
+        } else {
+            re_log::warn!("Select a view to export its data");
+        }
+    }
+
     /// Currently selected section of time, if any.
     pub fn loop_selection(
         &self,
@@ -230,8 +281,14 @@ impl AppState {
                     welcome_screen,
                     redap_servers,
                     view_states,
+                    entity_tree,
                     selection_state,
                     focused_item,
+                    transform_tree_panel_open,
+                    annotation_legend_open,
+                    trajectory_metrics_panel_open,
+                    trajectory_metrics_panel,
+                    pending_view_data_export,
                     ..
                 } = self;
 
@@ -286,6 +343,8 @@ impl AppState {
                     DragAndDropManager::new(Item::Container(viewport_ui.blueprint.root_container));
 
                 let recording = store_context.recording;
+                entity_tree.update(recording);
+                let entity_tree = &*entity_tree;
 
                 let maybe_visualizable_entities_per_visualizer = view_class_registry
                     .maybe_visualizable_entities_for_visualizer_systems(recording.store_id());
@@ -319,6 +378,7 @@ impl AppState {
                                     store_context,
                                     view_class_registry,
                                     &blueprint_query,
+                                    entity_tree,
                                     &visualizable_entities,
                                 ),
                             )
@@ -334,6 +394,20 @@ impl AppState {
                 };
                 let time_ctrl =
                     create_time_control_for(time_controls, recording, &app_blueprint_ctx);
+
+                // Let progressive HTTP loaders know what to fetch first.
+                if let Some(re_smart_channel::SmartChannelSource::RrdHttpStream {
+                    url,
+                    follow: false,
+                }) = &recording.data_source
+                    && let Some(time) = time_ctrl.time_int()
+                {
+                    re_log_encoding::rrd::stream_from_http::set_time_cursor(
+                        url,
+                        *time_ctrl.timeline().name(),
+                        time,
+                    );
+                }
                 let blueprint_query = app_blueprint_ctx.blueprint_query;
 
                 let egui_ctx = ui.ctx().clone();
@@ -362,6 +436,7 @@ impl AppState {
                         &maybe_visualizable_entities_per_visualizer,
                     indicated_entities_per_visualizer: &indicated_entities_per_visualizer,
                     query_results: &query_results,
+                    entity_tree,
                     time_ctrl,
                     blueprint_time_ctrl: blueprint_time_control,
                     selection_state,
@@ -387,6 +462,7 @@ impl AppState {
                     &viewport_ui.blueprint,
                     &blueprint_query,
                     time_ctrl.timeline(),
+                    &time_ctrl.current_query(),
                     &maybe_visualizable_entities_per_visualizer,
                     &indicated_entities_per_visualizer,
                     view_states,
@@ -418,6 +494,7 @@ impl AppState {
                         &maybe_visualizable_entities_per_visualizer,
                     indicated_entities_per_visualizer: &indicated_entities_per_visualizer,
                     query_results: &query_results,
+                    entity_tree,
                     time_ctrl,
                     blueprint_time_ctrl: blueprint_time_control,
                     selection_state,
@@ -426,6 +503,15 @@ impl AppState {
                     drag_and_drop_manager: &drag_and_drop_manager,
                 };
 
+                if let Some((view_id, format)) = pending_view_data_export.take() {
+                    crate::view_data_export::export_view_data(
+                        &ctx,
+                        &viewport_ui.blueprint,
+                        view_id,
+                        format,
+                    );
+                }
+
                 //
                 // Blueprint time panel
                 //
@@ -518,6 +604,63 @@ impl AppState {
                     );
                 }
 
+                //
+                // Transform tree panel
+                //
+
+                egui::SidePanel::right("transform_tree_panel")
+                    .min_width(120.0)
+                    .default_width(300.0)
+                    .resizable(true)
+                    .frame(egui::Frame {
+                        fill: ui.visuals().panel_fill,
+                        inner_margin: egui::Margin::same(8),
+                        ..Default::default()
+                    })
+                    .show_animated_inside(ui, *transform_tree_panel_open, |ui| {
+                        egui::ScrollArea::both().auto_shrink(false).show(ui, |ui| {
+                            re_view_spatial::transform_tree_panel_ui(&ctx, ui);
+                        });
+                    });
+
+                //
+                // Annotation legend
+                //
+
+                egui::SidePanel::right("annotation_legend")
+                    .min_width(120.0)
+                    .default_width(250.0)
+                    .resizable(true)
+                    .frame(egui::Frame {
+                        fill: ui.visuals().panel_fill,
+                        inner_margin: egui::Margin::same(8),
+                        ..Default::default()
+                    })
+                    .show_animated_inside(ui, *annotation_legend_open, |ui| {
+                        egui::ScrollArea::both().auto_shrink(false).show(ui, |ui| {
+                            re_data_ui::annotation_legend_ui(&ctx, ui);
+                        });
+                    });
+
+                //
+                // Trajectory metrics panel
+                //
+
+                egui::SidePanel::right("trajectory_metrics_panel")
+                    .min_width(120.0)
+                    .default_width(400.0)
+                    .resizable(true)
+                    .frame(egui::Frame {
+                        fill: ui.visuals().panel_fill,
+                        inner_margin: egui::Margin::same(8),
+                        ..Default::default()
+                    })
+                    .show_animated_inside(ui, *trajectory_metrics_panel_open, |ui| {
+                        egui::ScrollArea::both().auto_shrink(false).show(ui, |ui| {
+                            trajectory_metrics_panel.ui(&ctx, ui);
+                        });
+                    });
+
                 //
                 // Left panel (recordings and blueprint)
                 //
@@ -669,6 +812,7 @@ impl AppState {
                     });
 
                 add_view_or_container_modal_ui(&ctx, &viewport_ui.blueprint, ui);
+                blueprint_diff_modal_ui(&ctx, &viewport_ui.blueprint, ui);
                 drag_and_drop_manager.payload_cursor_ui(ctx.egui_ctx());
 
                 // Process deferred layout operations and apply updates back to blueprint:
@@ -804,6 +948,7 @@ fn update_overrides(
     viewport_blueprint: &ViewportBlueprint,
     blueprint_query: &LatestAtQuery,
     active_timeline: &Timeline,
+    time_query: &LatestAtQuery,
     maybe_visualizable_entities_per_visualizer: &PerVisualizer<MaybeVisualizableEntities>,
     indicated_entities_per_visualizer: &PerVisualizer<IndicatedEntities>,
     view_states: &mut ViewStates,
@@ -873,6 +1018,13 @@ fn update_overrides(
                     &mut query_result,
                     view_state,
                 );
+                re_viewport_blueprint::apply_entity_visibility_rules(
+                    store_context.blueprint,
+                    blueprint_query,
+                    store_context.recording,
+                    time_query,
+                    &mut query_result,
+                );
 
                 (view.id, query_result)
             },
@@ -914,6 +1066,7 @@ pub(crate) fn create_time_control_for<'cfgs>(
                 re_smart_channel::SmartChannelSource::RrdHttpStream { follow: true, .. }
                 | re_smart_channel::SmartChannelSource::Sdk
                 | re_smart_channel::SmartChannelSource::MessageProxy { .. }
+                | re_smart_channel::SmartChannelSource::McapWebSocket { .. }
                 | re_smart_channel::SmartChannelSource::Stdin
                 | re_smart_channel::SmartChannelSource::JsChannel { .. } => PlayState::Following,
             }
@@ -0,0 +1,297 @@
+//! Keeps the viewer running in the background when its window is closed.
+//!
+//! The window is hidden to the system tray if the viewer was built with the `system_tray`
+//! feature, and minimized otherwise. Data keeps being ingested in the meantime, and the window
+//! pops back up as soon as an error is logged.
+
+use std::cell::Cell;
+
+use re_types::{archetypes::TextLog, components::TextLogLevel};
+
+pub struct BackgroundMode {
+    /// Is the window currently hidden or minimized by us?
+    in_background: Cell<bool>,
+
+    /// Set once the user has asked to actually quit, e.g. from the tray menu.
+    quitting: bool,
+
+    #[cfg(feature = "system_tray")]
+    tray: Option<system_tray::SystemTray>,
+}
+
+impl BackgroundMode {
+    pub fn new(egui_ctx: &egui::Context) -> Self {
+        #[cfg(not(feature = "system_tray"))]
+        _ = egui_ctx;
+
+        Self {
+            in_background: Cell::new(false),
+            quitting: false,
+
+            #[cfg(feature = "system_tray")]
+            tray: system_tray::SystemTray::new(egui_ctx)
+                .inspect_err(|err| {
+                    re_log::warn!(
+                        "Failed to create system tray icon, will minimize instead: {err}"
+                    );
+                })
+                .ok(),
+        }
+    }
+
+    fn has_tray(&self) -> bool {
+        #[cfg(feature = "system_tray")]
+        {
+            self.tray.is_some()
+        }
+
+        #[cfg(not(feature = "system_tray"))]
+        {
+            false
+        }
+    }
+
+    pub fn is_in_background(&self) -> bool {
+        self.in_background.get()
+    }
+
+    /// Call once per frame, before anything else has a chance to look at close requests.
+    pub fn update(&mut self, egui_ctx: &egui::Context) {
+        #[cfg(feature = "system_tray")]
+        while let Some(command) = self.tray.as_ref().and_then(|tray| tray.try_recv()) {
+            match command {
+                system_tray::TrayCommand::Show => self.bring_to_front(egui_ctx),
+                system_tray::TrayCommand::Quit => self.quit(egui_ctx),
+            }
+        }
+
+        if egui_ctx.input(|i| i.viewport().close_requested()) && !self.quitting {
+            egui_ctx.send_viewport_cmd(egui::ViewportCommand::CancelClose);
+            self.send_to_background(egui_ctx);
+        }
+
+        // Without a tray icon, the user brings the window back themselves.
+        if !self.has_tray() && egui_ctx.input(|i| i.viewport().minimized) == Some(false) {
+            self.in_background.set(false);
+        }
+
+        if self.in_background.get() {
+            // Keep draining the incoming data while nobody is looking.
+            egui_ctx.request_repaint_after(std::time::Duration::from_millis(250));
+        }
+    }
+
+    fn send_to_background(&self, egui_ctx: &egui::Context) {
+        if self.has_tray() {
+            egui_ctx.send_viewport_cmd(egui::ViewportCommand::Visible(false));
+            re_log::info_once!(
+                "The viewer keeps running in the background. Use the tray icon to show it again, or to quit."
+            );
+        } else {
+            egui_ctx.send_viewport_cmd(egui::ViewportCommand::Minimized(true));
+            re_log::info_once!("The viewer keeps running in the background.");
+        }
+        self.in_background.set(true);
+    }
+
+    /// Actually close the viewer, rather than sending it to the background.
+    pub fn quit(&mut self, egui_ctx: &egui::Context) {
+        self.quitting = true;
+        egui_ctx.send_viewport_cmd(egui::ViewportCommand::Close);
+    }
+
+    /// Show the window again, if it was sent to the background.
+    pub fn bring_to_front(&self, egui_ctx: &egui::Context) {
+        if !self.in_background.replace(false) {
+            return;
+        }
+
+        egui_ctx.send_viewport_cmd(egui::ViewportCommand::Visible(true));
+        egui_ctx.send_viewport_cmd(egui::ViewportCommand::Minimized(false));
+        egui_ctx.send_viewport_cmd(egui::ViewportCommand::Focus);
+        egui_ctx.send_viewport_cmd(egui::ViewportCommand::RequestUserAttention(
+            egui::UserAttentionType::Critical,
+        ));
+    }
+}
+
+/// Does this chunk contain a [`TextLog`] at error level or above?
+pub fn has_error_text_log(chunk: &re_chunk::Chunk) -> bool {
+    chunk
+        .iter_slices::<String>(TextLog::descriptor_level().component)
+        .flatten()
+        .any(|level| matches!(level.as_str(), TextLogLevel::ERROR | TextLogLevel::CRITICAL))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use egui::{ViewportCommand, ViewportEvent, ViewportId};
+
+    use super::*;
+
+    fn run_frame(
+        egui_ctx: &egui::Context,
+        background_mode: &mut BackgroundMode,
+        events: Vec<ViewportEvent>,
+    ) -> egui::ViewportOutput {
+        let mut raw_input = egui::RawInput::default();
+        raw_input
+            .viewports
+            .entry(ViewportId::ROOT)
+            .or_default()
+            .events = events;
+
+        let mut output = egui_ctx.run(raw_input, |egui_ctx| background_mode.update(egui_ctx));
+        output.viewport_output.remove(&ViewportId::ROOT).unwrap()
+    }
+
+    #[test]
+    fn keeps_ingesting_in_background() {
+        let egui_ctx = egui::Context::default();
+        let mut background_mode = BackgroundMode::new(&egui_ctx);
+
+        let output = run_frame(&egui_ctx, &mut background_mode, vec![ViewportEvent::Close]);
+        assert!(background_mode.is_in_background());
+        assert!(output.commands.contains(&ViewportCommand::CancelClose));
+        assert!(
+            output.commands.contains(&ViewportCommand::Minimized(true))
+                || output.commands.contains(&ViewportCommand::Visible(false))
+        );
+
+        // Frames, and therefore ingestion, keep going while nobody is looking.
+        for _ in 0..3 {
+            let output = run_frame(&egui_ctx, &mut background_mode, Vec::new());
+            assert!(background_mode.is_in_background());
+            assert!(output.repaint_delay <= Duration::from_millis(250));
+        }
+
+        background_mode.bring_to_front(&egui_ctx);
+        assert!(!background_mode.is_in_background());
+
+        background_mode.quit(&egui_ctx);
+        let output = run_frame(&egui_ctx, &mut background_mode, vec![ViewportEvent::Close]);
+        assert!(!output.commands.contains(&ViewportCommand::CancelClose));
+    }
+}
+
+#[cfg(feature = "system_tray")]
+mod system_tray {
+    use std::sync::Arc;
+
+    use tray_icon::{
+        MouseButton, MouseButtonState, TrayIcon, TrayIconBuilder, TrayIconEvent,
+        menu::{Menu, MenuEvent, MenuItem},
+    };
+
+    /// What the user asked for through the tray icon.
+    pub enum TrayCommand {
+        Show,
+        Quit,
+    }
+
+    pub struct SystemTray {
+        rx: crossbeam::channel::Receiver<TrayCommand>,
+
+        /// Removes the icon when dropped.
+        ///
+        /// On Linux, the icon lives on the GTK thread instead.
+        #[cfg(not(target_os = "linux"))]
+        _tray_icon: TrayIcon,
+    }
+
+    impl SystemTray {
+        pub fn new(egui_ctx: &egui::Context) -> anyhow::Result<Self> {
+            let (tx, rx) = crossbeam::channel::unbounded();
+            let send = {
+                let egui_ctx = egui_ctx.clone();
+                Arc::new(move |command: TrayCommand| {
+                    tx.send(command).ok();
+                    egui_ctx.request_repaint();
+                })
+            };
+
+            #[cfg(target_os = "linux")]
+            {
+                // On Linux, the tray icon must be created on a thread running the GTK event loop.
+                let (init_tx, init_rx) = crossbeam::channel::bounded(1);
+                std::thread::Builder::new()
+                    .name("system_tray".to_owned())
+                    .spawn(move || {
+                        let tray_icon = gtk::init()
+                            .map_err(anyhow::Error::from)
+                            .and_then(|()| create_tray_icon(send));
+                        match tray_icon {
+                            Ok(_tray_icon) => {
+                                init_tx.send(Ok(())).ok();
+                                gtk::main();
+                            }
+                            Err(err) => {
+                                init_tx.send(Err(err)).ok();
+                            }
+                        }
+                    })?;
+                init_rx.recv()??;
+
+                Ok(Self { rx })
+            }
+
+            #[cfg(not(target_os = "linux"))]
+            {
+                Ok(Self {
+                    rx,
+                    _tray_icon: create_tray_icon(send)?,
+                })
+            }
+        }
+
+        pub fn try_recv(&self) -> Option<TrayCommand> {
+            self.rx.try_recv().ok()
+        }
+    }
+
+    fn create_tray_icon(send: Arc<dyn Fn(TrayCommand) + Send + Sync>) -> anyhow::Result<TrayIcon> {
+        let show = MenuItem::new("Show Rerun Viewer", true, None);
+        let quit = MenuItem::new("Quit", true, None);
+        let menu = Menu::new();
+        menu.append_items(&[&show, &quit])?;
+
+        let egui::IconData {
+            rgba,
+            width,
+            height,
+        } = crate::native::icon_data();
+
+        let tray_icon = TrayIconBuilder::new()
+            .with_menu(Box::new(menu))
+            .with_tooltip("Rerun Viewer")
+            .with_icon(tray_icon::Icon::from_rgba(rgba, width, height)?)
+            .build()?;
+
+        MenuEvent::set_event_handler(Some({
+            let send = send.clone();
+            let (show, quit) = (show.id().clone(), quit.id().clone());
+            move |event: MenuEvent| {
+                if event.id == show {
+                    send(TrayCommand::Show);
+                } else if event.id == quit {
+                    send(TrayCommand::Quit);
+                }
+            }
+        }));
+
+        TrayIconEvent::set_event_handler(Some(move |event: TrayIconEvent| {
+            if let TrayIconEvent::Click {
+                button: MouseButton::Left,
+                button_state: MouseButtonState::Up,
+                ..
+            } = event
+            {
+                send(TrayCommand::Show);
+            }
+        }));
+
+        Ok(tray_icon)
+    }
+}
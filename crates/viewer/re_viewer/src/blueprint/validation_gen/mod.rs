@@ -11,12 +11,17 @@ pub use re_types::blueprint::components::ApplyLatestAt;
 pub use re_types::blueprint::components::AutoLayout;
 pub use re_types::blueprint::components::AutoViews;
 pub use re_types::blueprint::components::BackgroundKind;
+pub use re_types::blueprint::components::BarChartMode;
 pub use re_types::blueprint::components::ColumnShare;
 pub use re_types::blueprint::components::ComponentColumnSelector;
 pub use re_types::blueprint::components::ContainerKind;
 pub use re_types::blueprint::components::Corner2D;
+pub use re_types::blueprint::components::DepthCloudSubsampling;
 pub use re_types::blueprint::components::Enabled;
 pub use re_types::blueprint::components::Eye3DKind;
+pub use re_types::blueprint::components::Eye3DProjection;
+pub use re_types::blueprint::components::FftWindowOverlap;
+pub use re_types::blueprint::components::FftWindowSize;
 pub use re_types::blueprint::components::FilterByRange;
 pub use re_types::blueprint::components::FilterIsNotNull;
 pub use re_types::blueprint::components::ForceDistance;
@@ -31,6 +36,7 @@ pub use re_types::blueprint::components::LockRangeDuringZoom;
 pub use re_types::blueprint::components::LoopMode;
 pub use re_types::blueprint::components::MapProvider;
 pub use re_types::blueprint::components::NearClipPlane;
+pub use re_types::blueprint::components::OnionSkinSteps;
 pub use re_types::blueprint::components::PanelState;
 pub use re_types::blueprint::components::PlayState;
 pub use re_types::blueprint::components::PlaybackSpeed;
@@ -63,12 +69,17 @@ pub fn is_valid_blueprint(blueprint: &EntityDb) -> bool {
         && validate_component::<AutoLayout>(blueprint)
         && validate_component::<AutoViews>(blueprint)
         && validate_component::<BackgroundKind>(blueprint)
+        && validate_component::<BarChartMode>(blueprint)
         && validate_component::<ColumnShare>(blueprint)
         && validate_component::<ComponentColumnSelector>(blueprint)
         && validate_component::<ContainerKind>(blueprint)
         && validate_component::<Corner2D>(blueprint)
+        && validate_component::<DepthCloudSubsampling>(blueprint)
         && validate_component::<Enabled>(blueprint)
         && validate_component::<Eye3DKind>(blueprint)
+        && validate_component::<Eye3DProjection>(blueprint)
+        && validate_component::<FftWindowOverlap>(blueprint)
+        && validate_component::<FftWindowSize>(blueprint)
         && validate_component::<FilterByRange>(blueprint)
         && validate_component::<FilterIsNotNull>(blueprint)
         && validate_component::<ForceDistance>(blueprint)
@@ -83,6 +94,7 @@ pub fn is_valid_blueprint(blueprint: &EntityDb) -> bool {
         && validate_component::<LoopMode>(blueprint)
         && validate_component::<MapProvider>(blueprint)
         && validate_component::<NearClipPlane>(blueprint)
+        && validate_component::<OnionSkinSteps>(blueprint)
         && validate_component::<PanelState>(blueprint)
         && validate_component::<PlayState>(blueprint)
         && validate_component::<PlaybackSpeed>(blueprint)
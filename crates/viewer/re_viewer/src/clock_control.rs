@@ -0,0 +1,72 @@
+use re_chunk::{Chunk, TimeInt, TimelineName};
+use re_types::archetypes::ClockControl;
+
+/// Where the latest [`ClockControl`] in the chunk wants the time cursor to be, if there is one.
+///
+/// That's the time the [`ClockControl`] was logged at on the timeline it names.
+/// [`ClockControl`]s naming a timeline they weren't logged on are ignored.
+pub fn latest_clock_control(chunk: &Chunk) -> Option<(TimelineName, TimeInt)> {
+    let component = ClockControl::descriptor_timeline().component;
+
+    chunk
+        .timelines()
+        .keys()
+        .flat_map(|timeline| {
+            chunk
+                .iter_slices::<String>(component)
+                .zip(chunk.iter_component_indices(*timeline, component))
+                .filter(|(names, _)| {
+                    names
+                        .last()
+                        .is_some_and(|name| name.as_str() == timeline.as_str())
+                })
+                .map(|(_, (time, row_id))| (row_id, *timeline, time))
+        })
+        .max_by_key(|(row_id, _, _)| *row_id)
+        .map(|(_, timeline, time)| (timeline, time))
+}
+
+#[cfg(test)]
+mod tests {
+    use re_chunk::{RowId, TimePoint, Timeline};
+    use re_types::archetypes::Points3D;
+
+    use super::*;
+
+    #[test]
+    fn test_latest_clock_control() {
+        let sim_time = Timeline::new_sequence("sim_time");
+        let log_tick = Timeline::new_sequence("log_tick");
+        let at = |sim: i64, tick: i64| TimePoint::from([(sim_time, sim), (log_tick, tick)]);
+
+        // Without any `ClockControl`, there is nothing to follow.
+        let chunk = Chunk::builder("points")
+            .with_archetype(RowId::new(), at(1, 1), &Points3D::new([(0.0, 0.0, 0.0)]))
+            .build()
+            .unwrap();
+        assert_eq!(latest_clock_control(&chunk), None);
+
+        // The last `ClockControl` wins, even if it jumps back in time.
+        let chunk = Chunk::builder("sim/clock")
+            .with_archetype(RowId::new(), at(10, 1), &ClockControl::new("sim_time"))
+            .with_archetype(RowId::new(), at(20, 2), &ClockControl::new("sim_time"))
+            .with_archetype(RowId::new(), at(5, 3), &ClockControl::new("sim_time"))
+            .build()
+            .unwrap();
+        assert_eq!(
+            latest_clock_control(&chunk),
+            Some((*sim_time.name(), TimeInt::new_temporal(5)))
+        );
+
+        // Naming a timeline the `ClockControl` wasn't logged on does nothing.
+        let chunk = Chunk::builder("sim/clock")
+            .with_archetype(RowId::new(), at(10, 1), &ClockControl::new("sim_time"))
+            .with_archetype(RowId::new(), at(20, 2), &ClockControl::new("wall_time"))
+            .build()
+            .unwrap();
+        assert_eq!(
+            latest_clock_control(&chunk),
+            Some((*sim_time.name(), TimeInt::new_temporal(10)))
+        );
+    }
+}
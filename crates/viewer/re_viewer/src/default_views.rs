@@ -15,6 +15,7 @@ fn populate_view_class_registry_with_builtin(
     fallback_registry: &mut FallbackProviderRegistry,
 ) -> Result<(), ViewClassRegistryError> {
     re_tracing::profile_function!();
+    view_class_registry.add_class::<re_view_audio::AudioView>(fallback_registry)?;
     view_class_registry.add_class::<re_view_bar_chart::BarChartView>(fallback_registry)?;
     view_class_registry.add_class::<re_view_dataframe::DataframeView>(fallback_registry)?;
     view_class_registry.add_class::<re_view_graph::GraphView>(fallback_registry)?;
@@ -25,6 +26,7 @@ fn populate_view_class_registry_with_builtin(
     view_class_registry.add_class::<re_view_tensor::TensorView>(fallback_registry)?;
     view_class_registry.add_class::<re_view_text_document::TextDocumentView>(fallback_registry)?;
     view_class_registry.add_class::<re_view_text_log::TextView>(fallback_registry)?;
+    view_class_registry.add_class::<re_view_time_series::SpectrogramView>(fallback_registry)?;
     view_class_registry.add_class::<re_view_time_series::TimeSeriesView>(fallback_registry)?;
 
     Ok(())
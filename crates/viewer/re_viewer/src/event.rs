@@ -108,6 +108,18 @@ pub enum ViewerEventKind {
         /// Uses semver format.
         version: Option<String>,
     },
+
+    /// Fired while data is being received for a recording.
+    ///
+    /// Fires at most once per frame per recording, and once more with `finished` set
+    /// when the source the recording is loaded from has no more data to send.
+    IngestProgress {
+        /// Total number of rows received so far.
+        num_rows: u64,
+
+        /// Whether the data source has finished sending data.
+        finished: bool,
+    },
 }
 
 /// A single item in a selection.
@@ -316,6 +328,17 @@ impl ViewerEventDispatcher {
         ));
     }
 
+    #[inline]
+    pub fn on_ingest_progress(&self, db: &EntityDb, finished: bool) {
+        self.dispatch(ViewerEvent::from_db_and_kind(
+            db,
+            ViewerEventKind::IngestProgress {
+                num_rows: db.num_rows(),
+                finished,
+            },
+        ));
+    }
+
     #[inline]
     fn dispatch(&self, event: ViewerEvent) {
         (self.f)(event);
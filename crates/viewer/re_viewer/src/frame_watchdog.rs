@@ -0,0 +1,221 @@
+//! Detects stalled frames and dumps diagnostics to disk, so that "the viewer froze" reports
+//! come with something actionable attached.
+//!
+//! A background thread checks how long the current frame has been running. If it exceeds the
+//! threshold, it writes the puffin profile of the last frames plus the most recent store events
+//! into a new directory. Once the stalled frame completes, its own profile is written there too.
+
+use std::collections::VecDeque;
+use std::io::Write as _;
+use std::path::PathBuf;
+use std::sync::{Arc, Weak};
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+use re_chunk_store::ChunkStoreEvent;
+use re_tracing::reexports::puffin;
+
+/// How many store events are kept around for the dump.
+const MAX_RECENT_STORE_EVENTS: usize = 1000;
+
+struct FrameState {
+    /// Index of the most recently started frame.
+    frame_index: u64,
+
+    /// Set while a frame is running.
+    started_at: Option<Instant>,
+}
+
+struct Shared {
+    threshold: Duration,
+    output_dir: PathBuf,
+    start_time: Instant,
+
+    frame: Mutex<FrameState>,
+
+    /// Human readable summaries of the most recent store events, oldest first.
+    recent_store_events: Mutex<VecDeque<String>>,
+
+    /// Keeps the last frames around so that we can dump them.
+    frame_view: puffin::GlobalFrameView,
+}
+
+/// Watches for frames that take longer than a threshold, see module docs.
+pub struct FrameWatchdog {
+    shared: Arc<Shared>,
+}
+
+/// Marks the end of a frame when dropped, see [`FrameWatchdog::begin_frame`].
+pub struct FrameWatchdogGuard {
+    shared: Arc<Shared>,
+}
+
+impl Drop for FrameWatchdogGuard {
+    fn drop(&mut self) {
+        self.shared.frame.lock().started_at = None;
+    }
+}
+
+impl FrameWatchdog {
+    /// Starts the watchdog thread.
+    ///
+    /// This enables puffin scopes, since there wouldn't be anything to dump otherwise.
+    pub fn start(threshold: Duration) -> std::io::Result<Self> {
+        puffin::set_scopes_on(true);
+
+        let shared = Arc::new(Shared {
+            threshold,
+            output_dir: std::env::temp_dir().join("rerun_frame_watchdog"),
+            start_time: Instant::now(),
+            frame: Mutex::new(FrameState {
+                frame_index: 0,
+                started_at: None,
+            }),
+            recent_store_events: Mutex::new(VecDeque::with_capacity(MAX_RECENT_STORE_EVENTS)),
+            frame_view: puffin::GlobalFrameView::default(),
+        });
+
+        let weak_shared = Arc::downgrade(&shared);
+        std::thread::Builder::new()
+            .name("frame_watchdog".to_owned())
+            .spawn(move || watchdog_thread(&weak_shared))?;
+
+        re_log::debug!(
+            "Frame watchdog started: frames taking longer than {threshold:?} are reported to {:?}",
+            shared.output_dir
+        );
+
+        Ok(Self { shared })
+    }
+
+    /// Call at the start of each frame, and keep the guard alive until the end of the frame.
+    pub fn begin_frame(&self) -> FrameWatchdogGuard {
+        {
+            let mut frame = self.shared.frame.lock();
+            frame.frame_index += 1;
+            frame.started_at = Some(Instant::now());
+        }
+
+        FrameWatchdogGuard {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+
+    /// Remembers the given events, so that they can be included in the next dump.
+    pub fn on_store_events(&self, events: &[ChunkStoreEvent]) {
+        let elapsed = self.shared.start_time.elapsed();
+
+        let mut recent_store_events = self.shared.recent_store_events.lock();
+        for event in events {
+            if recent_store_events.len() == MAX_RECENT_STORE_EVENTS {
+                recent_store_events.pop_front();
+            }
+
+            let chunk = &event.diff.chunk;
+            recent_store_events.push_back(format!(
+                "{:>10.3}s {:?} #{} {:?} {} ({} rows, {} components)",
+                elapsed.as_secs_f64(),
+                event.store_id,
+                event.event_id,
+                event.diff.kind,
+                chunk.entity_path(),
+                chunk.num_rows(),
+                chunk.num_components(),
+            ));
+        }
+    }
+}
+
+fn watchdog_thread(shared: &Weak<Shared>) {
+    // The stalled frame we've reported, and where its diagnostics were written to.
+    let mut reported_stall: Option<(u64, Option<PathBuf>)> = None;
+
+    loop {
+        let Some(shared) = shared.upgrade() else {
+            return; // The viewer shut down.
+        };
+
+        let poll_interval = (shared.threshold / 4).max(Duration::from_millis(10));
+
+        let (frame_index, stalled_for) = {
+            let frame = shared.frame.lock();
+            (
+                frame.frame_index,
+                frame.started_at.map(|started_at| started_at.elapsed()),
+            )
+        };
+
+        match &reported_stall {
+            Some((stalled_frame_index, dir)) => {
+                // Puffin only gets the stalled frame once it was completed, which is guaranteed
+                // once the next one started.
+                if frame_index != *stalled_frame_index {
+                    if let Some(dir) = dir {
+                        match write_profile(&shared, &dir.join("profile_with_stall.puffin")) {
+                            Ok(()) => re_log::info!(
+                                "Stalled frame completed, its profile was written to {dir:?}"
+                            ),
+                            Err(err) => {
+                                re_log::warn!("Frame watchdog failed to write profile: {err}");
+                            }
+                        }
+                    }
+                    reported_stall = None;
+                }
+            }
+
+            None => {
+                if let Some(stalled_for) = stalled_for
+                    && shared.threshold < stalled_for
+                {
+                    match dump_stall(&shared, frame_index, stalled_for) {
+                        Ok(dir) => {
+                            re_log::warn!(
+                                "Frame {frame_index} has been running for {stalled_for:.1?}. Diagnostics were written to {dir:?}"
+                            );
+                            reported_stall = Some((frame_index, Some(dir)));
+                        }
+                        Err(err) => {
+                            re_log::warn!("Frame watchdog failed to write diagnostics: {err}");
+                            reported_stall = Some((frame_index, None));
+                        }
+                    }
+                }
+            }
+        }
+
+        drop(shared);
+        std::thread::sleep(poll_interval);
+    }
+}
+
+/// Writes the profile of the last frames and the recent store events into a new directory.
+fn dump_stall(shared: &Shared, frame_index: u64, stalled_for: Duration) -> anyhow::Result<PathBuf> {
+    let timestamp = jiff::Timestamp::now().strftime("%Y%m%d_%H%M%S");
+    let dir = shared
+        .output_dir
+        .join(format!("stall_{timestamp}_frame_{frame_index}"));
+    std::fs::create_dir_all(&dir)?;
+
+    write_profile(shared, &dir.join("profile_before_stall.puffin"))?;
+
+    let mut file = std::fs::File::create(dir.join("store_events.txt"))?;
+    writeln!(
+        file,
+        "Frame {frame_index} stalled for at least {stalled_for:?} (threshold: {:?}).",
+        shared.threshold
+    )?;
+    writeln!(file, "Most recent store events, oldest first:")?;
+    for event in shared.recent_store_events.lock().iter() {
+        writeln!(file, "{event}")?;
+    }
+
+    Ok(dir)
+}
+
+fn write_profile(shared: &Shared, path: &std::path::Path) -> anyhow::Result<()> {
+    let mut file = std::io::BufWriter::new(std::fs::File::create(path)?);
+    shared.frame_view.lock().write(&mut file)?;
+    file.flush()?;
+    Ok(())
+}
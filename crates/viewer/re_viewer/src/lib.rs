@@ -10,6 +10,7 @@ mod app_blueprint;
 mod app_blueprint_ctx;
 mod app_state;
 mod background_tasks;
+mod clock_control;
 mod default_views;
 mod docker_detection;
 pub mod env_vars;
@@ -17,9 +18,11 @@ pub mod event;
 mod navigation;
 mod open_url_description;
 mod saving;
+mod schema_migration;
 mod screenshotter;
 mod startup_options;
 mod ui;
+mod view_data_export;
 
 #[cfg(feature = "analytics")]
 mod viewer_analytics;
@@ -28,6 +31,15 @@ mod viewer_analytics;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod viewer_test_utils;
 
+#[cfg(not(target_arch = "wasm32"))]
+mod background_mode;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod frame_watchdog;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod session_report;
+
 #[cfg(not(target_arch = "wasm32"))]
 mod loading;
 
@@ -58,7 +58,7 @@ pub fn eframe_options(force_wgpu_backend: Option<&str>) -> eframe::NativeOptions
     }
 }
 
-fn icon_data() -> egui::IconData {
+pub(crate) fn icon_data() -> egui::IconData {
     re_tracing::profile_function!();
 
     cfg_if::cfg_if! {
@@ -30,7 +30,7 @@ impl ViewerOpenUrlDescription {
                 target_short: item.entity_path().map(|p| p.to_string()),
             },
 
-            ViewerOpenUrl::RrdHttpUrl(url) => {
+            ViewerOpenUrl::RrdHttpUrl { url, fragment: _ } => {
                 let path = url.path();
                 let rrd_file_name = path.split('/').next_back().map(|s| s.to_owned());
 
@@ -56,6 +56,12 @@ impl ViewerOpenUrlDescription {
                 target_short: None,
             },
 
+            #[cfg(not(target_arch = "wasm32"))]
+            ViewerOpenUrl::FoxgloveWebSocket(url) => Self {
+                category: "WebSocket",
+                target_short: url.host_str().map(|host| host.to_owned()),
+            },
+
             ViewerOpenUrl::RedapCatalog(uri) => Self {
                 category: "Catalog",
                 target_short: Some(uri.origin.host.to_string()),
@@ -67,8 +67,13 @@ pub fn encode_to_file(
     let mut file = std::fs::File::create(path)
         .with_context(|| format!("Failed to create file at {path:?}"))?;
 
+    // Indexed, so that the file can be streamed progressively when hosted on the web.
     let encoding_options = re_log_encoding::rrd::EncodingOptions::PROTOBUF_COMPRESSED;
-    re_log_encoding::Encoder::encode_into(version, encoding_options, messages, &mut file)
-        .map(|_| ())
-        .context("Message encode")
+    let mut encoder = re_log_encoding::Encoder::new_eager(version, encoding_options, &mut file)
+        .context("Message encode")?
+        .with_chunk_index();
+    for message in messages {
+        encoder.append(&message?).context("Message encode")?;
+    }
+    encoder.finish().context("Message encode")
 }
@@ -0,0 +1,223 @@
+use arrow::{
+    array::{Array as _, AsArray as _},
+    compute::{CastOptions, cast_with_options},
+    datatypes::DataType,
+};
+use re_chunk::{Chunk, EntityPath};
+use re_types_core::{
+    ComponentDescriptor, SerializedComponentColumn, reflection::ComponentReflectionMap,
+};
+
+/// A component column of a builtin component that the viewer can't make sense of.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnmigratedColumn {
+    pub entity_path: EntityPath,
+    pub descriptor: ComponentDescriptor,
+
+    /// Why the column couldn't be upgraded.
+    pub reason: String,
+}
+
+impl UnmigratedColumn {
+    /// Shows up in the notification panel, where repeats of it are counted.
+    pub fn notification(&self) -> re_ui::notifications::Notification {
+        re_ui::notifications::Notification::new(
+            re_ui::notifications::NotificationLevel::Warning,
+            format!(
+                "Can't show {} of {}, it was likely logged by an incompatible version of Rerun",
+                self.descriptor.display_name(),
+                self.entity_path
+            ),
+        )
+        .with_details(self.reason.clone())
+        .with_source("schema migration")
+    }
+}
+
+/// Upgrades the columns of `chunk` holding builtin `rerun.` components that were logged with
+/// another datatype than this version of Rerun uses, e.g. by an older SDK.
+///
+/// Such columns are cast to the datatype in `components`, and only kept if the result passes the
+/// component's own validation.
+/// Returns the columns that are left as they were, because they couldn't be upgraded or hold
+/// components this version of Rerun doesn't know about.
+pub fn migrate_component_datatypes(
+    components: &ComponentReflectionMap,
+    chunk: &mut Chunk,
+) -> Vec<UnmigratedColumn> {
+    re_tracing::profile_function!();
+
+    let mut upgraded_columns = Vec::new();
+    let mut unmigrated_columns = Vec::new();
+
+    for column in chunk.components().values() {
+        let Some(component_type) = column.descriptor.component_type else {
+            continue;
+        };
+        if !component_type.starts_with("rerun.") {
+            continue;
+        }
+
+        let Some(reflection) = components.get(&component_type) else {
+            unmigrated_columns.push(UnmigratedColumn {
+                entity_path: chunk.entity_path().clone(),
+                descriptor: column.descriptor.clone(),
+                reason: format!("{component_type} is not a known component"),
+            });
+            continue;
+        };
+
+        let found = column.list_array.value_type();
+        if found == reflection.datatype {
+            continue;
+        }
+
+        let DataType::List(field) = column.list_array.data_type() else {
+            continue;
+        };
+        let target = DataType::List(
+            field
+                .as_ref()
+                .clone()
+                .with_data_type(reflection.datatype.clone())
+                .into(),
+        );
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+
+        let upgraded = cast_with_options(&column.list_array, &target, &options)
+            .map_err(|err| err.to_string())
+            .and_then(|array| {
+                let list_array = array.as_list::<i32>().clone();
+                (reflection.verify_arrow_array)(list_array.values().as_ref())
+                    .map_err(|err| err.to_string())?;
+                Ok(list_array)
+            });
+
+        match upgraded {
+            Ok(list_array) => {
+                re_log::debug_once!(
+                    "Upgraded {component_type} from {found} to {}",
+                    reflection.datatype
+                );
+                upgraded_columns.push(SerializedComponentColumn::new(
+                    list_array,
+                    column.descriptor.clone(),
+                ));
+            }
+
+            Err(err) => {
+                unmigrated_columns.push(UnmigratedColumn {
+                    entity_path: chunk.entity_path().clone(),
+                    descriptor: column.descriptor.clone(),
+                    reason: format!(
+                        "{component_type} was logged as {found}, but is now {}: {err}",
+                        reflection.datatype
+                    ),
+                });
+            }
+        }
+    }
+
+    for column in upgraded_columns {
+        let descriptor = column.descriptor.clone();
+        if let Err(err) = chunk.add_component(column) {
+            unmigrated_columns.push(UnmigratedColumn {
+                entity_path: chunk.entity_path().clone(),
+                descriptor,
+                reason: err.to_string(),
+            });
+        }
+    }
+
+    unmigrated_columns
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::array::{ArrayRef, Float64Array, StringArray};
+    use re_chunk::{RowId, TimePoint};
+    use re_types::archetypes::Points3D;
+
+    use super::*;
+
+    #[test]
+    fn test_migrate_component_datatypes() {
+        let components = re_types::reflection::generate_reflection()
+            .unwrap()
+            .components;
+
+        let unknown = ComponentDescriptor {
+            archetype: None,
+            component: "old_component".into(),
+            component_type: Some("rerun.components.NoLongerAround".into()),
+        };
+        let custom = ComponentDescriptor {
+            archetype: None,
+            component: "custom".into(),
+            component_type: Some("my.Component".into()),
+        };
+
+        let mut chunk = Chunk::builder("points")
+            .with_row(
+                RowId::new(),
+                TimePoint::default(),
+                [
+                    // Radii used to be logged as doubles, say.
+                    (
+                        Points3D::descriptor_radii(),
+                        Arc::new(Float64Array::from(vec![1.0, 2.0])) as ArrayRef,
+                    ),
+                    // Positions never were strings.
+                    (
+                        Points3D::descriptor_positions(),
+                        Arc::new(StringArray::from(vec!["up", "down"])) as ArrayRef,
+                    ),
+                    (
+                        unknown.clone(),
+                        Arc::new(Float64Array::from(vec![3.0])) as ArrayRef,
+                    ),
+                    (
+                        custom.clone(),
+                        Arc::new(StringArray::from(vec!["anything"])) as ArrayRef,
+                    ),
+                ],
+            )
+            .build()
+            .unwrap();
+
+        let mut unmigrated = migrate_component_datatypes(&components, &mut chunk)
+            .into_iter()
+            .map(|column| column.descriptor)
+            .collect::<Vec<_>>();
+        unmigrated.sort_by_key(|descriptor| descriptor.component);
+        assert_eq!(unmigrated, vec![Points3D::descriptor_positions(), unknown]);
+
+        let radii = chunk
+            .components()
+            .get_array(Points3D::descriptor_radii().component)
+            .unwrap();
+        assert_eq!(radii.value_type(), DataType::Float32);
+        assert_eq!(
+            chunk
+                .iter_slices::<f32>(Points3D::descriptor_radii().component)
+                .next()
+                .map(|radii| radii.to_vec()),
+            Some(vec![1.0, 2.0])
+        );
+
+        // Columns that aren't builtin components are none of our business.
+        assert_eq!(
+            chunk
+                .components()
+                .get_array(custom.component)
+                .unwrap()
+                .value_type(),
+            DataType::Utf8
+        );
+    }
+}
@@ -0,0 +1,235 @@
+//! An opt-in summary of the viewer session, written to disk on exit.
+//!
+//! Meant to be attached to performance bug reports. Nothing is ever sent anywhere.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::PathBuf;
+
+use re_chunk_store::{ChunkStoreDiffKind, ChunkStoreEvent};
+use re_log_types::StoreId;
+use re_viewer_context::StoreHub;
+
+/// Frame times kept per recording before we start dropping every other sample.
+const MAX_FRAME_TIME_SAMPLES: usize = 100_000;
+
+/// Uniformly downsampled frame times.
+#[derive(Default)]
+struct FrameTimes {
+    /// In seconds.
+    samples: Vec<f32>,
+
+    /// Only every `stride`-th frame time is kept.
+    stride: usize,
+
+    num_frames: usize,
+}
+
+impl FrameTimes {
+    fn add(&mut self, seconds: f32) {
+        self.stride = self.stride.max(1);
+
+        if self.num_frames % self.stride == 0 {
+            if self.samples.len() == MAX_FRAME_TIME_SAMPLES {
+                self.samples = self.samples.iter().copied().step_by(2).collect();
+                self.stride *= 2;
+            }
+            self.samples.push(seconds);
+        }
+
+        self.num_frames += 1;
+    }
+
+    /// The given percentiles, in seconds.
+    fn percentiles<const N: usize>(&self, percentiles: [f32; N]) -> Option<[f32; N]> {
+        if self.samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = self.samples.clone();
+        sorted.sort_by(f32::total_cmp);
+
+        let last_index = (sorted.len() - 1) as f32;
+        Some(
+            percentiles
+                .map(|percentile| sorted[((percentile / 100.0) * last_index).round() as usize]),
+        )
+    }
+}
+
+#[derive(Default)]
+struct RecordingReport {
+    num_chunks_added: u64,
+    num_rows_added: u64,
+    num_chunks_removed: u64,
+
+    /// Frame times while this recording was the active one.
+    frame_times: FrameTimes,
+}
+
+/// Collects statistics during the session, see module docs.
+pub struct SessionReport {
+    path: PathBuf,
+    start_time: web_time::Instant,
+
+    /// Peak memory use, according to [`re_memory::MemoryUse::used`].
+    peak_memory_bytes: Option<i64>,
+
+    per_recording: BTreeMap<StoreId, RecordingReport>,
+}
+
+impl SessionReport {
+    /// The report will be written to the given path when calling [`Self::write`].
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            start_time: web_time::Instant::now(),
+            peak_memory_bytes: None,
+            per_recording: Default::default(),
+        }
+    }
+
+    /// Call once per frame.
+    pub fn on_frame(&mut self, active_recording: Option<&StoreId>, frame_time_seconds: f32) {
+        if let Some(used) = re_memory::MemoryUse::capture().used() {
+            self.peak_memory_bytes =
+                Some(self.peak_memory_bytes.map_or(used, |peak| peak.max(used)));
+        }
+
+        if let Some(store_id) = active_recording {
+            self.per_recording
+                .entry(store_id.clone())
+                .or_default()
+                .frame_times
+                .add(frame_time_seconds);
+        }
+    }
+
+    pub fn on_store_events(&mut self, events: &[ChunkStoreEvent]) {
+        for event in events {
+            if !event.store_id.is_recording() {
+                continue;
+            }
+
+            let report = self
+                .per_recording
+                .entry(event.store_id.clone())
+                .or_default();
+            match event.diff.kind {
+                ChunkStoreDiffKind::Addition => {
+                    report.num_chunks_added += 1;
+                    report.num_rows_added += event.diff.chunk.num_rows() as u64;
+                }
+                ChunkStoreDiffKind::Deletion => report.num_chunks_removed += 1,
+            }
+        }
+    }
+
+    /// Writes the report, including stats of the recordings that are still loaded.
+    pub fn write(&self, store_hub: Option<&StoreHub>) {
+        let report = self.to_text(store_hub);
+        match std::fs::write(&self.path, report) {
+            Ok(()) => re_log::info!("Session report written to {:?}", self.path),
+            Err(err) => re_log::warn!("Failed to write session report to {:?}: {err}", self.path),
+        }
+    }
+
+    fn to_text(&self, store_hub: Option<&StoreHub>) -> String {
+        let Self {
+            path: _,
+            start_time,
+            peak_memory_bytes,
+            per_recording,
+        } = self;
+
+        let build_info = re_build_info::build_info!();
+
+        let mut text = String::new();
+        writeln!(text, "# Rerun Viewer session report").ok();
+        writeln!(text).ok();
+        writeln!(text, "Version: {build_info}").ok();
+        writeln!(
+            text,
+            "Session duration: {:.1}s",
+            start_time.elapsed().as_secs_f32()
+        )
+        .ok();
+        writeln!(
+            text,
+            "Peak memory use: {}",
+            peak_memory_bytes.map_or_else(
+                || "unknown".to_owned(),
+                |bytes| re_format::format_bytes(bytes as _)
+            )
+        )
+        .ok();
+
+        for (store_id, report) in per_recording {
+            writeln!(text).ok();
+            writeln!(
+                text,
+                "## Recording {} ({})",
+                store_id.recording_id(),
+                store_id.application_id()
+            )
+            .ok();
+            writeln!(text).ok();
+            writeln!(
+                text,
+                "Ingested: {} chunks, {} rows ({} chunks removed, e.g. by garbage collection)",
+                re_format::format_uint(report.num_chunks_added),
+                re_format::format_uint(report.num_rows_added),
+                re_format::format_uint(report.num_chunks_removed),
+            )
+            .ok();
+
+            match report.frame_times.percentiles([50.0, 90.0, 99.0, 100.0]) {
+                Some([p50, p90, p99, max]) => {
+                    writeln!(
+                        text,
+                        "Frame times over {} frames: p50 {:.1}ms, p90 {:.1}ms, p99 {:.1}ms, max {:.1}ms",
+                        re_format::format_uint(report.frame_times.num_frames),
+                        p50 * 1e3,
+                        p90 * 1e3,
+                        p99 * 1e3,
+                        max * 1e3,
+                    )
+                    .ok();
+                }
+                None => {
+                    writeln!(text, "Never shown").ok();
+                }
+            }
+
+            let Some(entity_db) = store_hub.and_then(|hub| hub.store_bundle().get(store_id)) else {
+                writeln!(text, "No longer loaded at exit").ok();
+                continue;
+            };
+
+            let engine = entity_db.storage_engine();
+            let store_stats = engine.store().stats().total();
+            writeln!(
+                text,
+                "Loaded at exit: {} chunks, {} rows, {}",
+                re_format::format_uint(store_stats.num_chunks),
+                re_format::format_uint(store_stats.num_rows),
+                re_format::format_bytes(store_stats.total_size_bytes as _),
+            )
+            .ok();
+
+            let (num_hits, num_misses) = engine.cache().stats().total_hits_and_misses();
+            let num_lookups = num_hits + num_misses;
+            if 0 < num_lookups {
+                writeln!(
+                    text,
+                    "Query cache: {:.1}% hit rate over {} lookups",
+                    100.0 * num_hits as f64 / num_lookups as f64,
+                    re_format::format_uint(num_lookups),
+                )
+                .ok();
+            }
+        }
+
+        text
+    }
+}
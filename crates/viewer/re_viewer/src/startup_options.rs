@@ -32,6 +32,25 @@ pub struct StartupOptions {
     #[cfg(not(target_arch = "wasm32"))]
     pub resolution_in_points: Option<[f32; 2]>,
 
+    /// Keep running in the background when the window is closed, and keep ingesting data.
+    ///
+    /// The window is hidden to the system tray if the viewer was built with the `system_tray`
+    /// feature, and minimized otherwise. It pops back up when an error is logged.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub run_in_background: bool,
+
+    /// If set, frames taking longer than this are reported, and diagnostics (a puffin profile and
+    /// the most recent store events) are written to a temporary directory.
+    ///
+    /// Note that this turns on puffin profiling scopes, which has a small overhead.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub frame_watchdog_threshold: Option<std::time::Duration>,
+
+    /// If set, a summary of the session (ingest totals, peak memory, frame times, cache hit
+    /// rates) is written to this path when the viewer exits.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub session_report_path: Option<std::path::PathBuf>,
+
     /// This is a hint that we expect a recording to stream in very soon.
     ///
     /// This is set by the `spawn()` method in our logging SDK.
@@ -152,6 +171,15 @@ impl Default for StartupOptions {
             #[cfg(not(target_arch = "wasm32"))]
             resolution_in_points: None,
 
+            #[cfg(not(target_arch = "wasm32"))]
+            run_in_background: false,
+
+            #[cfg(not(target_arch = "wasm32"))]
+            frame_watchdog_threshold: None,
+
+            #[cfg(not(target_arch = "wasm32"))]
+            session_report_path: None,
+
             expect_data_soon: None,
             force_wgpu_backend: None,
             video_decoder_hw_acceleration: None,
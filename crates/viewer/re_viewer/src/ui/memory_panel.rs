@@ -279,6 +279,7 @@ impl MemoryPanel {
                 let ChunkStoreStats {
                     static_chunks,
                     temporal_chunks,
+                    archived_static_chunks,
                 } = *store_stats;
 
                 ui.label(egui::RichText::new("Stats").italics());
@@ -311,8 +312,13 @@ impl MemoryPanel {
                 label_chunk_stats(ui, temporal_chunks);
                 ui.end_row();
 
+                ui.label("Archived static:")
+                    .on_hover_text("Previous versions of overwritten static data");
+                label_chunk_stats(ui, archived_static_chunks);
+                ui.end_row();
+
                 ui.label("Total:");
-                label_chunk_stats(ui, static_chunks + temporal_chunks);
+                label_chunk_stats(ui, store_stats.total());
                 ui.end_row();
             });
     }
@@ -345,6 +351,8 @@ impl MemoryPanel {
                                     total_chunks,
                                     total_effective_size_bytes,
                                     total_actual_size_bytes,
+                                    num_hits: _,
+                                    num_misses: _,
                                 } = stats;
 
                                 ui.label(cache_key.entity_path.to_string());
@@ -383,6 +391,8 @@ impl MemoryPanel {
                                     total_chunks,
                                     total_effective_size_bytes,
                                     total_actual_size_bytes,
+                                    num_hits: _,
+                                    num_misses: _,
                                 } = stats;
 
                                 ui.label(cache_key.entity_path.to_string());
@@ -4,6 +4,7 @@ mod open_url_modal;
 mod rerun_menu;
 mod share_modal;
 mod top_panel;
+mod trajectory_metrics_panel;
 mod welcome_screen;
 
 pub(crate) mod memory_panel;
@@ -15,4 +16,5 @@ pub(crate) use {
     self::mobile_warning_ui::mobile_warning_ui, self::top_panel::top_panel,
     self::welcome_screen::WelcomeScreen, open_url_modal::OpenUrlModal,
     settings_screen::settings_screen_ui, share_modal::ShareModal,
+    trajectory_metrics_panel::TrajectoryMetricsPanel,
 };
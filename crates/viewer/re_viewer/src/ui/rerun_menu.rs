@@ -111,6 +111,10 @@ impl App {
 
             UICommand::ToggleMemoryPanel.menu_button_ui(ui, &self.command_sender);
             UICommand::ToggleChunkStoreBrowser.menu_button_ui(ui, &self.command_sender);
+            UICommand::ToggleTransformTreePanel.menu_button_ui(ui, &self.command_sender);
+            UICommand::ToggleAnnotationLegend.menu_button_ui(ui, &self.command_sender);
+            UICommand::ToggleTrajectoryMetricsPanel.menu_button_ui(ui, &self.command_sender);
+            UICommand::ToggleEntityStatisticsOverlay.menu_button_ui(ui, &self.command_sender);
 
             #[cfg(debug_assertions)]
             UICommand::ToggleEguiDebugPanel.menu_button_ui(ui, &self.command_sender);
@@ -1,6 +1,7 @@
 use egui::{AtomExt as _, IntoAtoms, NumExt as _};
 
-use re_log_types::AbsoluteTimeRange;
+use re_entity_db::EntityDb;
+use re_log_types::{AbsoluteTimeRange, BlueprintActivationCommand, LogMsg, RecordingId};
 use re_redap_browser::EXAMPLES_ORIGIN;
 use re_ui::{
     UiExt as _, icons,
@@ -261,6 +262,9 @@ fn url_settings_ui(
 
         let timestamp_format = ctx.app_options().timestamp_format;
         time_cursor_ui(ui, fragments, timestamp_format, ctx.time_ctrl);
+
+        ui.add_space(8.0);
+        blueprint_ui(ui, &mut fragments.blueprint, ctx.blueprint_db());
     }
 }
 
@@ -318,6 +322,7 @@ fn time_cursor_ui(
     let Fragment {
         selection: _, // We just always include the selection, not exposing it directly in the editor.
         when,
+        blueprint: _, // Handled by `blueprint_ui`.
     } = fragments;
 
     let current_time_cursor = {
@@ -359,6 +364,68 @@ fn time_cursor_ui(
     }
 }
 
+fn blueprint_ui(ui: &mut egui::Ui, url_blueprint: &mut Option<Vec<u8>>, blueprint: &EntityDb) {
+    let mut include_blueprint = url_blueprint.is_some();
+    ui.list_item_flat_noninteractive(PropertyContent::new("Blueprint").value_fn(|ui, _| {
+        ui.selectable_toggle(|ui| {
+            selectable_value_with_min_width(
+                ui,
+                MIN_TOGGLE_WIDTH_RH,
+                &mut include_blueprint,
+                false,
+                "Default",
+            )
+            .on_hover_text("Link opens with the recording's default blueprint.");
+            selectable_value_with_available_width(ui, &mut include_blueprint, true, "Current")
+                .on_hover_text("Link embeds the current layout. This can make it a lot longer.");
+        });
+    }));
+
+    // Only encode when toggled, not every frame.
+    if include_blueprint != url_blueprint.is_some() {
+        *url_blueprint = if include_blueprint {
+            encode_blueprint(blueprint)
+                .inspect_err(|err| re_log::error!("Failed to encode blueprint: {err}"))
+                .ok()
+        } else {
+            None
+        };
+    }
+}
+
+/// Encodes a blueprint the same way as a saved `.rbl` file, so that it can be embedded in a link.
+///
+/// The copy gets a new id so that it doesn't merge with the blueprint of whoever opens the link,
+/// and it is only made active, not default.
+fn encode_blueprint(blueprint: &EntityDb) -> anyhow::Result<Vec<u8>> {
+    re_tracing::profile_function!();
+
+    let store_id = blueprint
+        .store_id()
+        .clone()
+        .with_recording_id(RecordingId::random());
+
+    let messages = blueprint
+        .to_messages(None)
+        .filter_map(|msg| match msg {
+            Ok(LogMsg::BlueprintActivationCommand(_)) => None,
+            Ok(mut msg) => {
+                msg.set_store_id(store_id.clone());
+                Some(Ok(msg))
+            }
+            Err(err) => Some(Err(err)),
+        })
+        .chain(std::iter::once(Ok(LogMsg::BlueprintActivationCommand(
+            BlueprintActivationCommand {
+                blueprint_id: store_id.clone(),
+                make_active: true,
+                make_default: false,
+            },
+        ))));
+
+    Ok(re_log_encoding::Encoder::encode(messages)?)
+}
+
 #[cfg(test)]
 mod tests {
     use std::{str::FromStr as _, sync::Arc};
@@ -456,6 +523,7 @@ mod tests {
                 fragment: re_uri::Fragment {
                     selection: selection.to_data_path(),
                     when: Some((*timeline.name(), TimeCell::new(timeline.typ(), 234))),
+                    blueprint: None,
                 },
             },
         ));
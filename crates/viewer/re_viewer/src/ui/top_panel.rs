@@ -287,15 +287,18 @@ fn connection_status_ui(ui: &mut egui::Ui, rx: &ReceiveSet<re_log_types::DataSou
                 SmartChannelSource::RrdWebEventListener
                 | SmartChannelSource::Sdk
                 | SmartChannelSource::MessageProxy { .. }
+                | SmartChannelSource::McapWebSocket { .. }
                 | SmartChannelSource::JsChannel { .. } => true,
             }
         })
         .collect_vec();
 
+    let connections = rx.connections();
+
     match sources.len() {
         0 => return,
         1 => {
-            source_label(ui, sources[0].as_ref());
+            source_label(ui, sources[0].as_ref(), &connections);
         }
         n => {
             // In practice we never get here
@@ -303,38 +306,91 @@ fn connection_status_ui(ui: &mut egui::Ui, rx: &ReceiveSet<re_log_types::DataSou
                 .on_hover_ui(|ui| {
                     ui.vertical(|ui| {
                         for source in &sources {
-                            source_label(ui, source.as_ref());
+                            source_label(ui, source.as_ref(), &connections);
                         }
                     });
                 });
         }
     }
 
-    fn source_label(ui: &mut egui::Ui, source: &SmartChannelSource) -> egui::Response {
+    fn source_label(
+        ui: &mut egui::Ui,
+        source: &SmartChannelSource,
+        connections: &[re_smart_channel::ConnectionStatsSnapshot],
+    ) -> egui::Response {
         let response = ui.label(source.status_string());
 
-        let tooltip = match source {
+        match source {
             SmartChannelSource::File(_)
             | SmartChannelSource::Stdin
             | SmartChannelSource::RrdHttpStream { .. }
             | SmartChannelSource::RedapGrpcStream { .. }
             | SmartChannelSource::RrdWebEventListener
             | SmartChannelSource::JsChannel { .. }
-            | SmartChannelSource::Sdk => None,
+            | SmartChannelSource::McapWebSocket { .. }
+            | SmartChannelSource::Sdk => response,
 
             SmartChannelSource::MessageProxy { .. } => {
-                Some("Waiting for an SDK to connect".to_owned())
+                if connections.is_empty() {
+                    response.on_hover_text("Waiting for an SDK to connect")
+                } else {
+                    response.on_hover_ui(|ui| connection_stats_ui(ui, connections))
+                }
             }
-        };
-
-        if let Some(tooltip) = tooltip {
-            response.on_hover_text(tooltip)
-        } else {
-            response
         }
     }
 }
 
+fn connection_stats_ui(
+    ui: &mut egui::Ui,
+    connections: &[re_smart_channel::ConnectionStatsSnapshot],
+) {
+    // The user is interested in the stats, so keep them updated.
+    ui.ctx().request_repaint();
+
+    egui::Grid::new("connection_stats")
+        .num_columns(5)
+        .striped(false)
+        .show(ui, |ui| {
+            ui.strong("Connection");
+            ui.strong("Messages");
+            ui.strong("Received");
+            ui.strong("Uncompressed");
+            ui.strong("Transport compression");
+            ui.end_row();
+
+            for connection in connections {
+                let re_smart_channel::ConnectionStatsSnapshot {
+                    peer,
+                    transport_compression,
+                    num_messages,
+                    num_payload_bytes,
+                    num_uncompressed_bytes,
+                    connected,
+                } = connection;
+
+                if *connected {
+                    ui.label(peer);
+                } else {
+                    ui.weak(format!("{peer} (disconnected)"));
+                }
+                ui.label(re_format::format_uint(*num_messages));
+                ui.label(re_format::format_bytes(*num_payload_bytes as _));
+                if let Some(ratio) = connection.compression_ratio() {
+                    ui.label(format!(
+                        "{} ({:.0}%)",
+                        re_format::format_bytes(*num_uncompressed_bytes as _),
+                        100.0 * ratio
+                    ));
+                } else {
+                    ui.label(re_format::format_bytes(*num_uncompressed_bytes as _));
+                }
+                ui.label(transport_compression.as_deref().unwrap_or("none"));
+                ui.end_row();
+            }
+        });
+}
+
 /// Lay out the panel button right-to-left
 fn panel_buttons_r2l(
     app: &mut App,
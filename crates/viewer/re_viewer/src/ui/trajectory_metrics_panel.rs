@@ -0,0 +1,231 @@
+use itertools::Itertools as _;
+
+use re_chunk_store::ChunkStoreGeneration;
+use re_log_types::{AbsoluteTimeRange, EntityPath, TimeInt, TimelineName};
+use re_ui::UiExt as _;
+use re_view_spatial::{ErrorStatistics, TrajectoryErrors};
+use re_viewer_context::ViewerContext;
+
+/// Compares the poses of two selected entities with transforms, e.g. the trajectory estimated by
+/// SLAM and its ground truth, using the absolute and relative pose error (APE/RPE).
+#[derive(Default)]
+pub struct TrajectoryMetricsPanel {
+    /// Treat the first selected entity as the ground truth, rather than the second one.
+    swapped: bool,
+
+    /// The errors of the last comparison.
+    ///
+    /// Recomputed whenever the entities, the time range, or the data changes.
+    cached: Option<CachedErrors>,
+}
+
+struct CachedErrors {
+    estimate: EntityPath,
+    ground_truth: EntityPath,
+    timeline: TimelineName,
+    time_range: AbsoluteTimeRange,
+    generation: ChunkStoreGeneration,
+    errors: Result<TrajectoryErrors, String>,
+}
+
+impl TrajectoryMetricsPanel {
+    pub fn ui(&mut self, ctx: &ViewerContext<'_>, ui: &mut egui::Ui) {
+        re_tracing::profile_function!();
+
+        let selected_entities = ctx
+            .selection()
+            .iter()
+            .filter_map(|(item, _)| item.entity_path())
+            .unique()
+            .collect_vec();
+        let [first, second] = selected_entities[..] else {
+            ui.weak(
+                "Select two entities with transforms: the estimated trajectory first, then its ground truth.",
+            );
+            return;
+        };
+        let (estimate, ground_truth) = if self.swapped {
+            (second, first)
+        } else {
+            (first, second)
+        };
+
+        let query = ctx.current_query();
+        egui::Grid::new("trajectory_metrics_entities")
+            .num_columns(2)
+            .show(ui, |ui| {
+                ui.label("Estimate");
+                re_data_ui::item_ui::entity_path_button(
+                    ctx,
+                    &query,
+                    ctx.recording(),
+                    ui,
+                    None,
+                    estimate,
+                );
+                ui.end_row();
+
+                ui.label("Ground truth");
+                re_data_ui::item_ui::entity_path_button(
+                    ctx,
+                    &query,
+                    ctx.recording(),
+                    ui,
+                    None,
+                    ground_truth,
+                );
+                ui.end_row();
+            });
+        if ui
+            .button("Swap")
+            .on_hover_text("Use the other entity as the ground truth")
+            .clicked()
+        {
+            self.swapped ^= true;
+        }
+
+        let timeline = *ctx.time_ctrl.timeline().name();
+        let time_range = if let Some(loop_selection) = ctx.time_ctrl.loop_selection() {
+            ui.weak("Comparing the poses within the loop selection.");
+            loop_selection.to_int()
+        } else {
+            ui.weak("Comparing the poses over the whole timeline. Select a time range to narrow it down.");
+            AbsoluteTimeRange::EVERYTHING
+        };
+
+        let errors = match self.errors(ctx, estimate, ground_truth, timeline, time_range) {
+            Ok(errors) => errors,
+            Err(err) => {
+                ui.error_label(format!("Can't compare the trajectories: {err}"));
+                return;
+            }
+        };
+
+        ui.separator();
+        ui.strong(format!(
+            "Statistics over {} poses",
+            re_format::format_uint(errors.times.len())
+        ));
+        statistics_ui(ui, errors);
+
+        ui.separator();
+        ui.strong("Translation error over time");
+        error_plot_ui(ctx, ui, errors);
+    }
+
+    fn errors(
+        &mut self,
+        ctx: &ViewerContext<'_>,
+        estimate: &EntityPath,
+        ground_truth: &EntityPath,
+        timeline: TimelineName,
+        time_range: AbsoluteTimeRange,
+    ) -> &Result<TrajectoryErrors, String> {
+        let generation = ctx.recording().generation();
+
+        let is_up_to_date = self.cached.as_ref().is_some_and(|cached| {
+            &cached.estimate == estimate
+                && &cached.ground_truth == ground_truth
+                && cached.timeline == timeline
+                && cached.time_range == time_range
+                && cached.generation == generation
+        });
+
+        if !is_up_to_date {
+            self.cached = None;
+        }
+
+        let cached = self.cached.get_or_insert_with(|| {
+            re_tracing::profile_scope!("trajectory_errors");
+
+            CachedErrors {
+                estimate: estimate.clone(),
+                ground_truth: ground_truth.clone(),
+                timeline,
+                time_range,
+                generation,
+                errors: re_view_spatial::trajectory_errors(
+                    ctx,
+                    estimate,
+                    ground_truth,
+                    timeline,
+                    time_range,
+                ),
+            }
+        });
+
+        &cached.errors
+    }
+}
+
+fn statistics_ui(ui: &mut egui::Ui, errors: &TrajectoryErrors) {
+    let degrees = |errors: &[f64]| errors.iter().map(|e| e.to_degrees()).collect_vec();
+
+    egui::Grid::new("trajectory_metrics_statistics")
+        .num_columns(7)
+        .striped(true)
+        .show(ui, |ui| {
+            for header in ["", "RMSE", "Mean", "Median", "Std dev", "Min", "Max"] {
+                ui.strong(header);
+            }
+            ui.end_row();
+
+            statistics_row_ui(ui, "APE translation", &errors.absolute_translation);
+            statistics_row_ui(ui, "APE rotation (°)", &degrees(&errors.absolute_rotation));
+            statistics_row_ui(ui, "RPE translation", &errors.relative_translation);
+            statistics_row_ui(ui, "RPE rotation (°)", &degrees(&errors.relative_rotation));
+        });
+}
+
+fn statistics_row_ui(ui: &mut egui::Ui, label: &str, errors: &[f64]) {
+    ui.label(label);
+
+    if let Some(statistics) = ErrorStatistics::new(errors) {
+        let ErrorStatistics {
+            rmse,
+            mean,
+            median,
+            std_dev,
+            min,
+            max,
+        } = statistics;
+        for value in [rmse, mean, median, std_dev, min, max] {
+            ui.monospace(format!("{value:.4}"));
+        }
+    } else {
+        ui.weak("–");
+    }
+
+    ui.end_row();
+}
+
+fn error_plot_ui(ctx: &ViewerContext<'_>, ui: &mut egui::Ui, errors: &TrajectoryErrors) {
+    let time_type = ctx.time_ctrl.timeline().typ();
+    let timestamp_format = ctx.app_options().timestamp_format;
+
+    let points = |times: &[TimeInt], values: &[f64]| -> egui_plot::PlotPoints<'static> {
+        times
+            .iter()
+            .zip(values)
+            .map(|(time, value)| [time.as_i64() as f64, *value])
+            .collect()
+    };
+
+    egui_plot::Plot::new("trajectory_metrics_plot")
+        .height(200.0)
+        .legend(egui_plot::Legend::default().position(egui_plot::Corner::LeftTop))
+        .include_y(0.0)
+        .x_axis_formatter(move |mark, _| {
+            time_type.format(TimeInt::new_temporal(mark.value as i64), timestamp_format)
+        })
+        .show(ui, |plot_ui| {
+            plot_ui.line(egui_plot::Line::new(
+                "APE",
+                points(&errors.times, &errors.absolute_translation),
+            ));
+            plot_ui.line(egui_plot::Line::new(
+                "RPE",
+                points(errors.relative_times(), &errors.relative_translation),
+            ));
+        });
+}
@@ -0,0 +1,362 @@
+//! Exporting the data a view shows, so that analysis in other tools matches what was on screen.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, ListArray, new_empty_array};
+use arrow::buffer::{NullBuffer, OffsetBuffer};
+use arrow::datatypes::{DataType, Field};
+use arrow::error::ArrowError;
+
+use re_chunk::{RowId, Timeline};
+use re_chunk_store::{
+    ColumnDescriptor, ComponentColumnDescriptor, IndexColumnDescriptor, LatestAtQuery, RangeQuery,
+};
+use re_log_types::{AbsoluteTimeRange, StoreKind, TimeInt};
+use re_types_core::ComponentDescriptor;
+use re_viewer_context::{DataResult, QueryRange, ViewId, ViewerContext, VisualizerCollection};
+use re_viewport_blueprint::{ViewBlueprint, ViewportBlueprint};
+
+/// The file format of a view data export.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ViewDataExportFormat {
+    /// Comma-separated values, with one column per component.
+    Csv,
+
+    /// Apache Parquet, keeping the Arrow datatypes of the components.
+    #[cfg(not(target_arch = "wasm32"))]
+    Parquet,
+}
+
+impl ViewDataExportFormat {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Csv => "CSV",
+            #[cfg(not(target_arch = "wasm32"))]
+            Self::Parquet => "Parquet",
+        }
+    }
+
+    fn file_extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            #[cfg(not(target_arch = "wasm32"))]
+            Self::Parquet => "parquet",
+        }
+    }
+}
+
+/// Asks the user where to save the data the view currently shows.
+///
+/// That is the data of its visible entities over their visible time ranges, restricted to the
+/// components their visualizers query, with blueprint overrides in place of the logged data.
+pub fn export_view_data(
+    ctx: &ViewerContext<'_>,
+    viewport: &ViewportBlueprint,
+    view_id: ViewId,
+    format: ViewDataExportFormat,
+) {
+    re_tracing::profile_function!();
+
+    let Some(view) = viewport.view(&view_id) else {
+        re_log::warn!("The view to export no longer exists");
+        return;
+    };
+    let name = view.display_name_or_default().as_ref().to_owned();
+
+    let table = ViewDataTable::collect(ctx, view);
+    if table.rows.is_empty() {
+        re_log::warn!("{name} shows no data to export");
+        return;
+    }
+
+    let bytes = match format {
+        ViewDataExportFormat::Csv => table.to_csv().map(String::into_bytes),
+        #[cfg(not(target_arch = "wasm32"))]
+        ViewDataExportFormat::Parquet => table.to_parquet(),
+    };
+
+    match bytes {
+        Ok(bytes) => ctx.command_sender().save_file_dialog(
+            re_capabilities::MainThreadToken::i_promise_i_am_on_the_main_thread(),
+            &format!("{name}.{}", format.file_extension()),
+            format!("Export view data as {}", format.label()),
+            bytes,
+        ),
+        Err(err) => re_log::error!("Failed to export the data of {name}: {err}"),
+    }
+}
+
+/// A row of [`ViewDataTable`], holding the components of a single entity.
+struct Row {
+    time: TimeInt,
+    row_id: RowId,
+
+    /// Index of the column of the first component of the entity.
+    first_column: usize,
+
+    /// One entry per component of the entity.
+    cells: Vec<Option<ArrayRef>>,
+}
+
+/// The data of a view as a single table, with one row per logged row of each entity and one
+/// column per component of each entity.
+struct ViewDataTable {
+    timeline: Timeline,
+    columns: Vec<ComponentColumnDescriptor>,
+
+    /// Sorted by time.
+    rows: Vec<Row>,
+}
+
+impl ViewDataTable {
+    fn collect(ctx: &ViewerContext<'_>, view: &ViewBlueprint) -> Self {
+        re_tracing::profile_function!();
+
+        let visualizers = ctx
+            .view_class_registry()
+            .new_visualizer_collection(view.class_identifier());
+
+        let mut table = Self {
+            timeline: *ctx.time_ctrl.timeline(),
+            columns: Vec::new(),
+            rows: Vec::new(),
+        };
+
+        ctx.lookup_query_result(view.id).tree.visit(&mut |node| {
+            let data_result = &node.data_result;
+            if !data_result.tree_prefix_only && data_result.is_visible() {
+                table.add_entity(ctx, &visualizers, data_result);
+            }
+            true
+        });
+
+        table
+            .rows
+            .sort_by_key(|row| (row.time, row.row_id, row.first_column));
+
+        table
+    }
+
+    fn add_entity(
+        &mut self,
+        ctx: &ViewerContext<'_>,
+        visualizers: &VisualizerCollection,
+        data_result: &DataResult,
+    ) {
+        let mut descriptors: Vec<ComponentDescriptor> = Vec::new();
+        for visualizer in &data_result.visualizers {
+            let Ok(visualizer) = visualizers.get_by_identifier(*visualizer) else {
+                continue;
+            };
+            for descriptor in visualizer.visualizer_query_info().queried.iter() {
+                if !descriptors.contains(descriptor) {
+                    descriptors.push(descriptor.clone());
+                }
+            }
+        }
+        let components = descriptors.iter().map(|descr| descr.component);
+
+        // The cells of every logged row, by index.
+        let mut rows: BTreeMap<(TimeInt, RowId), Vec<Option<ArrayRef>>> = BTreeMap::new();
+        let mut set_cell = |index: (TimeInt, RowId), column: usize, array: ArrayRef| {
+            rows.entry(index)
+                .or_insert_with(|| vec![None; descriptors.len()])[column] = Some(array);
+        };
+        let column_of = |component| {
+            descriptors
+                .iter()
+                .position(|descr| descr.component == component)
+        };
+
+        let timeline = *self.timeline.name();
+        let cursor = ctx.time_ctrl.time_int().unwrap_or(TimeInt::MAX);
+        let engine = ctx.recording().storage_engine();
+        match data_result.query_range() {
+            QueryRange::TimeRange(time_range) => {
+                let query = RangeQuery::new(
+                    timeline,
+                    AbsoluteTimeRange::from_relative_time_range(time_range, cursor),
+                );
+                let results = engine
+                    .cache()
+                    .range(&query, &data_result.entity_path, components);
+                for (component, chunks) in &results.components {
+                    let Some(column) = column_of(*component) else {
+                        continue;
+                    };
+                    for chunk in chunks {
+                        for (row, index) in chunk.iter_indices(&timeline).enumerate() {
+                            if let Some(Ok(array)) = chunk.component_batch_raw(*component, row) {
+                                set_cell(index, column, array);
+                            }
+                        }
+                    }
+                }
+            }
+
+            QueryRange::LatestAt => {
+                let query = LatestAtQuery::new(timeline, cursor);
+                let results =
+                    engine
+                        .cache()
+                        .latest_at(&query, &data_result.entity_path, components);
+                for (component, unit) in &results.components {
+                    let (Some(column), Some(index), Some(array)) = (
+                        column_of(*component),
+                        unit.index(&timeline),
+                        unit.component_batch_raw(*component),
+                    ) else {
+                        continue;
+                    };
+                    set_cell(index, column, array);
+                }
+            }
+        }
+        drop(engine); // Overrides may be looked up in the recording as well.
+
+        // Overrides apply at all times.
+        for (component, override_path) in &data_result.property_overrides.component_overrides {
+            let Some(column) = column_of(*component) else {
+                continue;
+            };
+            let (db, query) = match override_path.store_kind {
+                StoreKind::Blueprint => (ctx.blueprint_db(), ctx.blueprint_query.clone()),
+                StoreKind::Recording => (ctx.recording(), ctx.current_query()),
+            };
+            let Some(array) = db
+                .latest_at(&query, &override_path.path, [*component])
+                .component_batch_raw(*component)
+            else {
+                continue;
+            };
+            for cells in rows.values_mut() {
+                cells[column] = Some(array.clone());
+            }
+        }
+
+        // Skip components without data, so that every column has a datatype.
+        let used = (0..descriptors.len())
+            .filter(|&column| rows.values().any(|cells| cells[column].is_some()))
+            .collect::<Vec<_>>();
+
+        let first_column = self.columns.len();
+        self.columns.extend(used.iter().map(|&column| {
+            let descr = &descriptors[column];
+            let datatype = rows
+                .values()
+                .find_map(|cells| cells[column].as_ref())
+                .map_or(DataType::Null, |array| array.data_type().clone());
+            ComponentColumnDescriptor {
+                store_datatype: DataType::new_list(datatype, true),
+                component_type: descr.component_type,
+                entity_path: data_result.entity_path.clone(),
+                archetype: descr.archetype,
+                component: descr.component,
+                is_static: false,
+                is_tombstone: false,
+                is_semantically_empty: false,
+            }
+        }));
+
+        self.rows
+            .extend(rows.into_iter().map(|((time, row_id), mut cells)| Row {
+                time,
+                row_id,
+                first_column,
+                cells: used.iter().map(|&column| cells[column].take()).collect(),
+            }));
+    }
+
+    /// The time column, followed by one list array per component column.
+    fn arrays(&self) -> Result<Vec<ArrayRef>, ArrowError> {
+        let times = self
+            .timeline
+            .typ()
+            .make_arrow_array_from_time_ints(self.rows.iter().map(|row| row.time));
+
+        let mut arrays = vec![times];
+        for (column, descr) in self.columns.iter().enumerate() {
+            let cells = self
+                .rows
+                .iter()
+                .map(|row| {
+                    let cell = column.checked_sub(row.first_column)?;
+                    row.cells.get(cell)?.as_deref()
+                })
+                .collect::<Vec<_>>();
+            arrays.push(Arc::new(list_array(&descr.store_datatype, &cells)?));
+        }
+        Ok(arrays)
+    }
+
+    fn column_descriptors(&self) -> Vec<ColumnDescriptor> {
+        let is_sorted = true;
+        std::iter::once(ColumnDescriptor::Time(
+            IndexColumnDescriptor::from_timeline(self.timeline, is_sorted),
+        ))
+        .chain(
+            self.columns
+                .iter()
+                .cloned()
+                .map(ColumnDescriptor::Component),
+        )
+        .collect()
+    }
+
+    fn to_csv(&self) -> Result<String, ArrowError> {
+        re_view_dataframe::rows_to_csv(&self.column_descriptors(), [&self.arrays()?])
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn to_parquet(&self) -> anyhow::Result<Vec<u8>> {
+        use re_data_loader::external::parquet::arrow::ArrowWriter;
+
+        let arrays = self.arrays()?;
+        let fields = self
+            .column_descriptors()
+            .iter()
+            .zip(&arrays)
+            .map(|(descr, array)| {
+                Field::new(
+                    re_dataframe_ui::default_display_name_for_column(&descr.into()),
+                    array.data_type().clone(),
+                    true,
+                )
+            })
+            .collect::<Vec<_>>();
+        let batch = arrow::array::RecordBatch::try_new(
+            Arc::new(arrow::datatypes::Schema::new(fields)),
+            arrays,
+        )?;
+
+        let mut bytes = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut bytes, batch.schema(), None)?;
+        writer.write(&batch)?;
+        writer.close()?;
+        Ok(bytes)
+    }
+}
+
+/// A list array with one entry per cell, and nulls for missing cells.
+fn list_array(datatype: &DataType, cells: &[Option<&dyn Array>]) -> Result<ListArray, ArrowError> {
+    let DataType::List(field) = datatype else {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "expected a list datatype, got {datatype}"
+        )));
+    };
+
+    let present = cells.iter().flatten().copied().collect::<Vec<_>>();
+    let values = if present.is_empty() {
+        new_empty_array(field.data_type())
+    } else {
+        arrow::compute::concat(&present)?
+    };
+
+    ListArray::try_new(
+        field.clone(),
+        OffsetBuffer::from_lengths(cells.iter().map(|cell| cell.map_or(0, |cell| cell.len()))),
+        values,
+        Some(NullBuffer::from_iter(cells.iter().map(Option::is_some))),
+    )
+}
@@ -156,6 +156,7 @@ pub fn open_recording(
         re_smart_channel::SmartChannelSource::RrdHttpStream { .. } => Some("http"),
         re_smart_channel::SmartChannelSource::RedapGrpcStream { .. } => None,
         re_smart_channel::SmartChannelSource::MessageProxy { .. } => Some("grpc"),
+        re_smart_channel::SmartChannelSource::McapWebSocket { .. } => Some("websocket"),
         // vvv spawn(), connect() vvv
         re_smart_channel::SmartChannelSource::RrdWebEventListener => Some("web_event"),
         re_smart_channel::SmartChannelSource::JsChannel { .. } => Some("javascript"), // mediated via rerun-js
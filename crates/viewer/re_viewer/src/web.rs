@@ -11,7 +11,7 @@ use serde::Deserialize;
 use wasm_bindgen::prelude::*;
 
 use re_log::ResultExt as _;
-use re_log_types::{TableId, TableMsg};
+use re_log_types::{BlueprintActivationCommand, LogMsg, TableId, TableMsg};
 use re_memory::AccountingAllocator;
 use re_types::blueprint::components::PlayState;
 use re_viewer_context::{
@@ -339,6 +339,75 @@ impl WebHandle {
         }
     }
 
+    /// Load a blueprint (i.e. the contents of an `.rbl` file) into the viewer.
+    ///
+    /// If `make_active` is set (the default), the blueprint immediately replaces the current
+    /// blueprint of the application it belongs to.
+    /// If `make_default` is set (the default), the viewer also resets to it from now on.
+    #[wasm_bindgen]
+    pub fn send_blueprint(
+        &self,
+        data: &[u8],
+        make_active: Option<bool>,
+        make_default: Option<bool>,
+    ) {
+        let Some(mut app) = self.runner.app_mut::<crate::App>() else {
+            return;
+        };
+
+        let messages = match re_log_encoding::Decoder::decode_eager(data) {
+            Ok(messages) => messages,
+            Err(err) => {
+                re_log::error!("Failed to decode blueprint: {err}");
+                return;
+            }
+        };
+
+        let (tx, rx) = re_smart_channel::smart_channel(
+            re_smart_channel::SmartMessageSource::JsChannelPush,
+            re_smart_channel::SmartChannelSource::JsChannel {
+                channel_name: "blueprint".to_owned(),
+            },
+        );
+
+        // Whatever activation the blueprint came with is replaced by the one requested here.
+        let mut blueprint_id = None;
+        for msg in messages {
+            match msg {
+                Ok(LogMsg::BlueprintActivationCommand(_)) => {}
+                Ok(msg) => {
+                    if msg.store_id().is_blueprint() {
+                        blueprint_id = Some(msg.store_id().clone());
+                    }
+                    tx.send(msg.into())
+                        .warn_on_err_once("Failed to send blueprint");
+                }
+                Err(err) => {
+                    re_log::warn_once!("Failed to decode blueprint message: {err}");
+                }
+            }
+        }
+
+        if let Some(blueprint_id) = blueprint_id {
+            tx.send(
+                LogMsg::BlueprintActivationCommand(BlueprintActivationCommand {
+                    blueprint_id,
+                    make_active: make_active.unwrap_or(true),
+                    make_default: make_default.unwrap_or(true),
+                })
+                .into(),
+            )
+            .warn_on_err_once("Failed to send blueprint");
+        } else {
+            re_log::warn!("The data sent with `send_blueprint` didn't contain a blueprint.");
+        }
+
+        tx.quit(None).warn_on_err_once("Failed to send quit marker");
+        app.add_log_receiver(rx);
+        app.egui_ctx
+            .request_repaint_after(std::time::Duration::from_millis(10));
+    }
+
     #[wasm_bindgen]
     pub fn send_table_to_channel(&self, id: &str, data: &[u8]) {
         let Some(app) = self.runner.app_mut::<crate::App>() else {
@@ -652,6 +721,7 @@ pub struct AppOptions {
     // width: Option<String>, // Width & height aren't serialized and only used to configure the canvas.
     // height: Option<String>,
     fallback_token: Option<String>,
+    proxy_token: Option<String>,
 
     // Hidden `WebViewerOptions`
     // ------------
@@ -719,6 +789,7 @@ fn create_app(
         notebook,
 
         fallback_token,
+        proxy_token,
     } = app_options;
 
     if let Some(fallback_token) = fallback_token {
@@ -730,6 +801,10 @@ fn create_app(
         }
     }
 
+    if let Some(proxy_token) = proxy_token {
+        connection_registry.set_proxy_token(proxy_token);
+    }
+
     let enable_history = enable_history.unwrap_or(false);
 
     let video_decoder_hw_acceleration = video_decoder.and_then(|s| match s.parse() {
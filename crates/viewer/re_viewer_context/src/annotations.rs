@@ -25,6 +25,12 @@ const MISSING_ROW_ID: RowId = RowId::ZERO;
 pub struct Annotations {
     row_id: RowId,
     class_map: HashMap<ClassId, CachedClassDescription>,
+
+    /// Hash of the [`AnnotationClassOverrides`] that were applied to the class map.
+    overrides_hash: u64,
+
+    /// Applied to all class ids before they are looked up in the class map.
+    class_remap: ClassIdRemapping,
 }
 
 impl Annotations {
@@ -33,6 +39,8 @@ impl Annotations {
         Self {
             row_id: MISSING_ROW_ID,
             class_map: Default::default(),
+            overrides_hash: 0,
+            class_remap: ClassIdRemapping::default(),
         }
     }
 
@@ -48,11 +56,13 @@ impl Annotations {
         &self,
         class_id: Option<re_types::components::ClassId>,
     ) -> ResolvedClassDescription<'_> {
-        let found = class_id.and_then(|class_id| self.class_map.get(&class_id.0));
+        let class_id = class_id.map(|class_id| self.class_remap.remap(class_id.0));
+        let found = class_id.and_then(|class_id| self.class_map.get(&class_id));
         ResolvedClassDescription {
-            class_id: class_id.map(|id| id.0),
+            class_id,
             class_description: found.map(|f| &f.class_description),
             keypoint_map: found.map(|f| &f.keypoint_map),
+            is_hidden: found.is_some_and(|f| f.is_hidden),
         }
     }
 
@@ -60,12 +70,54 @@ impl Annotations {
     pub fn row_id(&self) -> RowId {
         self.row_id
     }
+
+    /// Identifies the content of these annotations, including the user's
+    /// [`AnnotationClassOverrides`] and the view's [`ClassIdRemapping`],
+    /// e.g. for caching derived data.
+    #[inline]
+    pub fn cache_key(&self) -> u64 {
+        egui::util::hash((self.row_id, self.overrides_hash, &self.class_remap))
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Maps class ids to other class ids, e.g. to merge classes of a segmentation image.
+///
+/// Class ids that aren't part of the map are kept as they are.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ClassIdRemapping(BTreeMap<ClassId, ClassId>);
+
+impl ClassIdRemapping {
+    /// Creates a remap from pairs of source and target class ids.
+    ///
+    /// If a source class id is listed more than once, the first entry wins.
+    pub fn from_pairs(pairs: impl IntoIterator<Item = (ClassId, ClassId)>) -> Self {
+        let mut map = BTreeMap::new();
+        for (source, target) in pairs {
+            map.entry(source).or_insert(target);
+        }
+        Self(map)
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[inline]
+    pub fn remap(&self, class_id: ClassId) -> ClassId {
+        self.0.get(&class_id).copied().unwrap_or(class_id)
+    }
 }
 
 #[derive(Clone, Debug)]
 struct CachedClassDescription {
     class_description: ClassDescription,
     keypoint_map: HashMap<KeypointId, AnnotationInfo>,
+
+    /// Hidden by the user, see [`AnnotationClassOverrides`].
+    is_hidden: bool,
 }
 
 impl From<ClassDescription> for CachedClassDescription {
@@ -78,6 +130,7 @@ impl From<ClassDescription> for CachedClassDescription {
         Self {
             class_description: desc,
             keypoint_map,
+            is_hidden: false,
         }
     }
 }
@@ -87,6 +140,9 @@ pub struct ResolvedClassDescription<'a> {
     pub class_id: Option<ClassId>,
     pub class_description: Option<&'a ClassDescription>,
     pub keypoint_map: Option<&'a HashMap<KeypointId, AnnotationInfo>>,
+
+    /// The class was hidden by the user, see [`AnnotationClassOverrides`].
+    pub is_hidden: bool,
 }
 
 impl ResolvedClassDescription<'_> {
@@ -95,6 +151,7 @@ impl ResolvedClassDescription<'_> {
         ResolvedAnnotationInfo {
             class_id: self.class_id,
             annotation_info: self.class_description.map(|desc| desc.info.clone()),
+            is_hidden: self.is_hidden,
         }
     }
 
@@ -117,6 +174,7 @@ impl ResolvedClassDescription<'_> {
                             .or_else(|| desc.info.label.clone()),
                         color: keypoint_annotation_info.color.or(desc.info.color),
                     }),
+                    is_hidden: self.is_hidden,
                 }
             } else {
                 self.annotation_info()
@@ -125,6 +183,7 @@ impl ResolvedClassDescription<'_> {
             ResolvedAnnotationInfo {
                 class_id: self.class_id,
                 annotation_info: None,
+                is_hidden: self.is_hidden,
             }
         }
     }
@@ -136,6 +195,12 @@ impl ResolvedClassDescription<'_> {
 pub struct ResolvedAnnotationInfo {
     pub class_id: Option<ClassId>,
     pub annotation_info: Option<AnnotationInfo>,
+
+    /// The instance should not be shown.
+    ///
+    /// Either because its class was hidden, see [`AnnotationClassOverrides`],
+    /// or because it was filtered out by the view.
+    pub is_hidden: bool,
 }
 
 impl ResolvedAnnotationInfo {
@@ -217,6 +282,33 @@ impl ResolvedAnnotationInfos {
             Self::Many(infos) => infos.is_empty(),
         }
     }
+
+    /// Whether any of the instances belongs to a class hidden by the user.
+    #[inline]
+    pub fn any_hidden(&self) -> bool {
+        match self {
+            Self::Same(n, info) => *n > 0 && info.is_hidden,
+            Self::Many(infos) => infos.iter().any(|info| info.is_hidden),
+        }
+    }
+
+    /// Hides every instance for which `hidden` is `true`.
+    ///
+    /// Instances past the end of `hidden` are left untouched.
+    pub fn hide_instances(&mut self, hidden: &[bool]) {
+        if !hidden.iter().any(|hidden| *hidden) {
+            return;
+        }
+
+        if let Self::Same(n, info) = self {
+            *self = Self::Many(vec![info.clone(); *n]);
+        }
+        if let Self::Many(infos) = self {
+            for (info, hidden) in infos.iter_mut().zip(hidden) {
+                info.is_hidden |= *hidden;
+            }
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -237,6 +329,8 @@ impl AnnotationMap {
             })
             .unwrap_or_default();
 
+        let overrides = AnnotationClassOverrides::load(ctx.egui_ctx(), ctx.recording().store_id());
+
         // Load current annotations.
         // (order doesn't matter, we're feeding into another hashmap)
         #[expect(clippy::iter_over_hash_type)]
@@ -254,12 +348,17 @@ impl AnnotationMap {
                         .0
                         .into_iter()
                         .map(|elem| {
-                            (
-                                elem.class_id,
-                                CachedClassDescription::from(elem.class_description),
-                            )
+                            let mut class = CachedClassDescription::from(elem.class_description);
+                            let class_override = overrides.get(&entity, elem.class_id);
+                            if let Some(color) = class_override.color {
+                                class.class_description.info.color = Some(color.into());
+                            }
+                            class.is_hidden = class_override.is_hidden;
+                            (elem.class_id, class)
                         })
                         .collect(),
+                    overrides_hash: overrides.hash_for(&entity),
+                    class_remap: ClassIdRemapping::default(),
                 };
                 self.0.insert(entity, Arc::new(annotations));
             }
@@ -281,6 +380,113 @@ impl AnnotationMap {
         // Otherwise return the missing legend
         Annotations::missing_arc()
     }
+
+    /// Returns a copy of this map in which all class ids are remapped with `class_remap`.
+    ///
+    /// This also applies to entities without any annotation context.
+    pub fn with_class_remap(&self, class_remap: &ClassIdRemapping) -> Self {
+        if class_remap.is_empty() {
+            return self.clone();
+        }
+
+        let remapped = |annotations: &Annotations| {
+            Arc::new(Annotations {
+                class_remap: class_remap.clone(),
+                ..annotations.clone()
+            })
+        };
+
+        let mut map: BTreeMap<_, _> = self
+            .0
+            .iter()
+            .map(|(entity, annotations)| (entity.clone(), remapped(annotations)))
+            .collect();
+        map.entry(EntityPath::root())
+            .or_insert_with(|| remapped(&Annotations::missing()));
+
+        Self(map)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A user override for a class of an annotation context, see [`AnnotationClassOverrides`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AnnotationClassOverride {
+    /// Don't show instances of this class in any view.
+    pub is_hidden: bool,
+
+    /// Use this color instead of the class' own.
+    pub color: Option<egui::Color32>,
+}
+
+/// Per-class overrides of the annotation contexts of a recording, set by the user.
+///
+/// Applied by [`AnnotationMap::load`], i.e. in all views.
+/// Kept in egui's memory for the duration of the session, not in the blueprint.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AnnotationClassOverrides(
+    BTreeMap<EntityPath, BTreeMap<ClassId, AnnotationClassOverride>>,
+);
+
+impl AnnotationClassOverrides {
+    fn id(store_id: &StoreId) -> egui::Id {
+        egui::Id::new(("annotation_class_overrides", store_id))
+    }
+
+    /// The overrides for the given recording.
+    pub fn load(egui_ctx: &egui::Context, store_id: &StoreId) -> Self {
+        egui_ctx.data(|data| data.get_temp(Self::id(store_id)).unwrap_or_default())
+    }
+
+    /// Replaces the overrides for the given recording.
+    pub fn store(self, egui_ctx: &egui::Context, store_id: &StoreId) {
+        egui_ctx.data_mut(|data| data.insert_temp(Self::id(store_id), self));
+    }
+
+    /// The override for a class of the annotation context logged at `context_entity`.
+    pub fn get(&self, context_entity: &EntityPath, class_id: ClassId) -> AnnotationClassOverride {
+        self.0
+            .get(context_entity)
+            .and_then(|classes| classes.get(&class_id))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn set(
+        &mut self,
+        context_entity: &EntityPath,
+        class_id: ClassId,
+        class_override: AnnotationClassOverride,
+    ) {
+        if class_override == AnnotationClassOverride::default() {
+            if let Some(classes) = self.0.get_mut(context_entity) {
+                classes.remove(&class_id);
+                if classes.is_empty() {
+                    self.0.remove(context_entity);
+                }
+            }
+        } else {
+            self.0
+                .entry(context_entity.clone())
+                .or_default()
+                .insert(class_id, class_override);
+        }
+    }
+
+    /// Removes all overrides of the annotation context logged at `context_entity`.
+    pub fn clear(&mut self, context_entity: &EntityPath) {
+        self.0.remove(context_entity);
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn hash_for(&self, context_entity: &EntityPath) -> u64 {
+        self.0.get(context_entity).map_or(0, egui::util::hash)
+    }
 }
 
 /// Keeps track of all entities that have an annotation context.
@@ -20,6 +20,9 @@ pub struct AppOptions {
     /// Displays an overlay for debugging picking.
     pub show_picking_debug_overlay: bool,
 
+    /// Displays an overlay in every view listing the instance counts and data rates of its entities.
+    pub show_entity_statistics_overlay: bool,
+
     /// Inspect the blueprint timeline.
     pub inspect_blueprint_timeline: bool,
 
@@ -80,6 +83,8 @@ impl Default for AppOptions {
 
             show_picking_debug_overlay: false,
 
+            show_entity_statistics_overlay: false,
+
             inspect_blueprint_timeline: false,
 
             blueprint_gc: true,
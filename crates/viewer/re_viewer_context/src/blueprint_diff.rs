@@ -0,0 +1,366 @@
+//! Comparing two blueprints, and merging changes from one into the other.
+
+use std::collections::BTreeMap;
+
+use arrow::array::{Array as _, ArrayRef};
+use re_chunk::{ComponentIdentifier, LatestAtQuery};
+use re_entity_db::EntityDb;
+use re_log_types::EntityPath;
+use re_types::ComponentDescriptor;
+
+use crate::{BlueprintContext, ContainerId, ViewId, blueprint_timeline};
+
+/// How a component differs between the current blueprint and the one it is compared with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BlueprintChangeKind {
+    /// Only the other blueprint has a value.
+    Added,
+
+    /// Only the current blueprint has a value.
+    Removed,
+
+    /// Both blueprints have a value, but they differ.
+    Modified,
+}
+
+/// The part of a blueprint that a [`BlueprintChange`] belongs to.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BlueprintChangeSubject {
+    /// The viewport itself, e.g. its root container or whether it uses auto layout.
+    Viewport,
+
+    /// A container, e.g. its kind or its contents.
+    Container(ContainerId),
+
+    /// A view, e.g. its class, origin or name.
+    View(ViewId),
+
+    /// The contents query, the component defaults, or a property of a view.
+    ViewProperty {
+        view_id: ViewId,
+
+        /// Name of the property, e.g. `ViewContents`, `defaults` or `Background`.
+        name: String,
+    },
+
+    /// The overrides of an entity in a view.
+    Override {
+        view_id: ViewId,
+        entity_path: EntityPath,
+    },
+
+    /// Anything else, e.g. the state of the panels.
+    Other(EntityPath),
+}
+
+impl BlueprintChangeSubject {
+    /// Finds out what a blueprint entity path belongs to.
+    pub fn from_entity_path(entity_path: &EntityPath) -> Self {
+        let parts = entity_path.as_slice();
+        let Some((first, rest)) = parts.split_first() else {
+            return Self::Other(entity_path.clone());
+        };
+
+        match (first.unescaped_str(), rest) {
+            ("viewport", []) => Self::Viewport,
+
+            ("container", [_]) => {
+                let container_id = ContainerId::from_entity_path(entity_path);
+                if container_id == ContainerId::invalid() {
+                    Self::Other(entity_path.clone())
+                } else {
+                    Self::Container(container_id)
+                }
+            }
+
+            ("view", [_, view_rest @ ..]) => {
+                let view_id = ViewId::from_entity_path(&EntityPath::new(parts[..2].to_vec()));
+                if view_id == ViewId::invalid() {
+                    return Self::Other(entity_path.clone());
+                }
+
+                match view_rest {
+                    [] => Self::View(view_id),
+                    [contents, overrides, entity @ ..]
+                        if contents.unescaped_str() == "ViewContents"
+                            && overrides.unescaped_str() == "overrides" =>
+                    {
+                        Self::Override {
+                            view_id,
+                            entity_path: EntityPath::new(entity.to_vec()),
+                        }
+                    }
+                    [name, ..] => Self::ViewProperty {
+                        view_id,
+                        name: name.unescaped_str().to_owned(),
+                    },
+                }
+            }
+
+            _ => Self::Other(entity_path.clone()),
+        }
+    }
+}
+
+/// A single component that differs between two blueprints.
+#[derive(Clone, Debug)]
+pub struct BlueprintChange {
+    pub entity_path: EntityPath,
+    pub component_descr: ComponentDescriptor,
+    pub kind: BlueprintChangeKind,
+
+    /// The value in the current blueprint, `None` if it has none.
+    pub current_value: Option<ArrayRef>,
+
+    /// The value in the other blueprint, `None` if it has none.
+    pub other_value: Option<ArrayRef>,
+
+    /// Whether the component is static in either of the blueprints.
+    pub is_static: bool,
+}
+
+impl BlueprintChange {
+    /// What part of the blueprint this change belongs to.
+    pub fn subject(&self) -> BlueprintChangeSubject {
+        BlueprintChangeSubject::from_entity_path(&self.entity_path)
+    }
+
+    /// Writes the value of the other blueprint into the current blueprint.
+    pub fn merge(&self, ctx: &impl BlueprintContext) {
+        let entity_path = self.entity_path.clone();
+        let component_descr = self.component_descr.clone();
+
+        match (&self.other_value, self.is_static) {
+            (Some(value), false) => {
+                ctx.save_blueprint_array(entity_path, component_descr, value.clone());
+            }
+            (Some(value), true) => {
+                ctx.save_static_blueprint_array(entity_path, component_descr, value.clone());
+            }
+            (None, false) => ctx.clear_blueprint_component(entity_path, component_descr),
+            (None, true) => ctx.clear_static_blueprint_component(entity_path, component_descr),
+        }
+    }
+}
+
+/// All the differences between two blueprints, on a per-component level.
+#[derive(Clone, Debug, Default)]
+pub struct BlueprintDiff {
+    /// Sorted by entity path.
+    pub changes: Vec<BlueprintChange>,
+}
+
+impl BlueprintDiff {
+    /// Compares the current blueprint, as seen by `current_query`, with the latest state of another blueprint.
+    ///
+    /// Empty components count as unset, since that is how blueprint components are cleared.
+    pub fn new(current: &EntityDb, current_query: &LatestAtQuery, other: &EntityDb) -> Self {
+        re_tracing::profile_function!();
+
+        let other_query = LatestAtQuery::latest(blueprint_timeline());
+
+        let entity_paths = current
+            .entity_paths()
+            .into_iter()
+            .chain(other.entity_paths())
+            .filter(|entity_path| !entity_path.is_reserved())
+            .cloned()
+            .collect::<std::collections::BTreeSet<_>>();
+
+        let mut changes = Vec::new();
+
+        for entity_path in entity_paths {
+            // Note that we must not hold on to the storage engines while querying.
+            let components = current
+                .storage_engine()
+                .store()
+                .all_components_for_entity_sorted(&entity_path)
+                .into_iter()
+                .chain(
+                    other
+                        .storage_engine()
+                        .store()
+                        .all_components_for_entity_sorted(&entity_path),
+                )
+                .flatten()
+                .collect::<std::collections::BTreeSet<_>>();
+
+            for component in components {
+                let current_value = latest_value(current, current_query, &entity_path, component);
+                let other_value = latest_value(other, &other_query, &entity_path, component);
+
+                let kind = match (&current_value, &other_value) {
+                    (None, None) => continue,
+                    (None, Some(_)) => BlueprintChangeKind::Added,
+                    (Some(_), None) => BlueprintChangeKind::Removed,
+                    (Some(current_value), Some(other_value)) => {
+                        if current_value.to_data() == other_value.to_data() {
+                            continue;
+                        }
+                        BlueprintChangeKind::Modified
+                    }
+                };
+
+                let Some(component_descr) = [other, current].into_iter().find_map(|blueprint| {
+                    blueprint
+                        .storage_engine()
+                        .store()
+                        .entity_component_descriptor(&entity_path, component)
+                }) else {
+                    continue;
+                };
+
+                let is_static = [current, other].into_iter().any(|blueprint| {
+                    blueprint
+                        .storage_engine()
+                        .store()
+                        .entity_has_static_component(&entity_path, component)
+                });
+
+                changes.push(BlueprintChange {
+                    entity_path: entity_path.clone(),
+                    component_descr,
+                    kind,
+                    current_value,
+                    other_value,
+                    is_static,
+                });
+            }
+        }
+
+        Self { changes }
+    }
+
+    /// Whether the two blueprints are equivalent.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// The changes, grouped by the part of the blueprint they belong to.
+    pub fn changes_per_subject(&self) -> BTreeMap<BlueprintChangeSubject, Vec<&BlueprintChange>> {
+        let mut changes_per_subject: BTreeMap<_, Vec<_>> = BTreeMap::new();
+        for change in &self.changes {
+            changes_per_subject
+                .entry(change.subject())
+                .or_default()
+                .push(change);
+        }
+        changes_per_subject
+    }
+}
+
+fn latest_value(
+    blueprint: &EntityDb,
+    query: &LatestAtQuery,
+    entity_path: &EntityPath,
+    component: ComponentIdentifier,
+) -> Option<ArrayRef> {
+    blueprint
+        .latest_at(query, entity_path, [component])
+        .component_batch_raw(component)
+        .filter(|array| !array.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use re_chunk::{Chunk, RowId};
+    use re_log_types::{StoreId, StoreKind, TimePoint, Timeline};
+    use re_types::blueprint::archetypes::ViewBlueprint;
+
+    use super::*;
+
+    fn blueprint_with(entity_path: &EntityPath, view: Option<&ViewBlueprint>) -> EntityDb {
+        let mut blueprint = EntityDb::new(StoreId::random(StoreKind::Blueprint, "test_app"));
+        if let Some(view) = view {
+            let timepoint = TimePoint::from([(Timeline::new_sequence(blueprint_timeline()), 1)]);
+            let chunk = Chunk::builder(entity_path.clone())
+                .with_archetype(RowId::new(), timepoint, view)
+                .build()
+                .unwrap();
+            blueprint.add_chunk(&Arc::new(chunk)).unwrap();
+        }
+        blueprint
+    }
+
+    #[test]
+    fn test_subject_from_entity_path() {
+        let view_id = ViewId::random();
+        let view_path = view_id.as_entity_path();
+
+        assert_eq!(
+            BlueprintChangeSubject::from_entity_path(&view_path),
+            BlueprintChangeSubject::View(view_id)
+        );
+        assert_eq!(
+            BlueprintChangeSubject::from_entity_path(&view_path.join(&"Background".into())),
+            BlueprintChangeSubject::ViewProperty {
+                view_id,
+                name: "Background".to_owned()
+            }
+        );
+        assert_eq!(
+            BlueprintChangeSubject::from_entity_path(
+                &view_path.join(&"ViewContents/overrides/world/points".into())
+            ),
+            BlueprintChangeSubject::Override {
+                view_id,
+                entity_path: "world/points".into()
+            }
+        );
+
+        let container_id = ContainerId::random();
+        assert_eq!(
+            BlueprintChangeSubject::from_entity_path(&container_id.as_entity_path()),
+            BlueprintChangeSubject::Container(container_id)
+        );
+        assert_eq!(
+            BlueprintChangeSubject::from_entity_path(&"viewport".into()),
+            BlueprintChangeSubject::Viewport
+        );
+        assert_eq!(
+            BlueprintChangeSubject::from_entity_path(&"view/not-a-uuid".into()),
+            BlueprintChangeSubject::Other("view/not-a-uuid".into())
+        );
+    }
+
+    #[test]
+    fn test_diff() {
+        let view_path = ViewId::random().as_entity_path();
+        let view = ViewBlueprint::new("3D").with_display_name("Points");
+        let renamed_view = ViewBlueprint::new("3D").with_display_name("Renamed");
+
+        let query = LatestAtQuery::latest(blueprint_timeline());
+
+        let blueprint = blueprint_with(&view_path, Some(&view));
+        assert!(BlueprintDiff::new(&blueprint, &query, &blueprint).is_empty());
+
+        let empty = blueprint_with(&view_path, None);
+        let diff = BlueprintDiff::new(&empty, &query, &blueprint);
+        assert!(!diff.is_empty());
+        assert!(
+            diff.changes
+                .iter()
+                .all(|change| change.kind == BlueprintChangeKind::Added)
+        );
+
+        let diff = BlueprintDiff::new(&blueprint, &query, &empty);
+        assert!(
+            diff.changes
+                .iter()
+                .all(|change| change.kind == BlueprintChangeKind::Removed)
+        );
+
+        let renamed = blueprint_with(&view_path, Some(&renamed_view));
+        let diff = BlueprintDiff::new(&blueprint, &query, &renamed);
+        assert_eq!(diff.changes.len(), 1);
+        assert_eq!(diff.changes[0].kind, BlueprintChangeKind::Modified);
+        assert_eq!(
+            diff.changes[0].component_descr,
+            ViewBlueprint::descriptor_display_name()
+        );
+        assert_eq!(diff.changes_per_subject().len(), 1);
+    }
+}
@@ -0,0 +1,434 @@
+use ahash::HashMap;
+
+use re_chunk_store::ChunkStoreEvent;
+use re_entity_db::EntityDb;
+use re_renderer::{RenderContext, resource_managers::GpuTexture2D};
+use re_types::{datatypes::ImageFormat, image::ImageKind};
+
+use crate::{
+    Cache, CacheMemoryReport, CacheMemoryReportItem, ImageInfo, cache::filter_blob_removed_events,
+    gpu_bridge::texture_creation_desc_from_color_image, image_info::StoredBlobCacheKey,
+};
+
+/// Width and height of a tile in texels.
+pub const IMAGE_TILE_SIZE: u32 = 1024;
+
+/// Color images with a side longer than this are streamed in tiles.
+///
+/// This is the texture size limit of many GPUs.
+const TILED_IMAGE_MIN_SIZE: u32 = 8192;
+
+/// Uploading a tile takes a while, so we spread the uploads of newly visible tiles over several frames.
+const MAX_TILE_UPLOADS_PER_FRAME: usize = 4;
+
+/// One tile of a large image, see [`ImageTileCache`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImageTileId {
+    /// Every texel of the tile covers `2^level` pixels of the image in each direction.
+    pub level: u32,
+
+    /// Column of the tile within its level.
+    pub x: u32,
+
+    /// Row of the tile within its level.
+    pub y: u32,
+}
+
+impl ImageTileId {
+    /// The single tile showing the whole image at low resolution.
+    pub fn overview([width, height]: [u32; 2]) -> Self {
+        let num_tiles = width.max(height).div_ceil(IMAGE_TILE_SIZE).max(1);
+        Self {
+            level: num_tiles.next_power_of_two().trailing_zeros(),
+            x: 0,
+            y: 0,
+        }
+    }
+
+    /// The tiles of the given level covering the given region of the image, in image pixels.
+    pub fn covering(image_size: [u32; 2], visible_rect: egui::Rect, level: u32) -> Vec<Self> {
+        let image_rect = egui::Rect::from_min_size(
+            egui::Pos2::ZERO,
+            egui::vec2(image_size[0] as f32, image_size[1] as f32),
+        );
+        let visible_rect = visible_rect.intersect(image_rect);
+        if !visible_rect.is_positive() {
+            return Vec::new();
+        }
+
+        let tile_span = (IMAGE_TILE_SIZE << level) as f32;
+        let xs = (visible_rect.min.x / tile_span).floor() as u32
+            ..(visible_rect.max.x / tile_span).ceil() as u32;
+        let ys = (visible_rect.min.y / tile_span).floor() as u32
+            ..(visible_rect.max.y / tile_span).ceil() as u32;
+
+        ys.flat_map(|y| xs.clone().map(move |x| Self { level, x, y }))
+            .collect()
+    }
+
+    /// The finest level that doesn't have more texels than there are screen pixels.
+    pub fn level_for_scale(image_size: [u32; 2], image_pixels_per_screen_pixel: f32) -> u32 {
+        let level = image_pixels_per_screen_pixel.max(1.0).log2().floor() as u32;
+        level.min(Self::overview(image_size).level)
+    }
+
+    /// Region of the image covered by this tile, in image pixels.
+    pub fn pixel_rect(&self, [width, height]: [u32; 2]) -> egui::Rect {
+        let tile_span = IMAGE_TILE_SIZE << self.level;
+        let min = [self.x * tile_span, self.y * tile_span];
+        let max = [
+            (min[0] + tile_span).min(width),
+            (min[1] + tile_span).min(height),
+        ];
+        egui::Rect::from_min_max(
+            egui::pos2(min[0] as f32, min[1] as f32),
+            egui::pos2(max[0] as f32, max[1] as f32),
+        )
+    }
+
+    /// Copies every `2^level`-th pixel of the tile's region out of the image.
+    ///
+    /// Returns `None` for chroma subsampled images, which can't be cut up per pixel.
+    fn extract(&self, image: &ImageInfo) -> Option<ImageInfo> {
+        re_tracing::profile_function!();
+
+        let format = image.format;
+        if format.pixel_format.is_some() {
+            return None;
+        }
+        let bits_per_pixel = format.color_model().num_channels() * format.datatype().bits();
+        if bits_per_pixel % 8 != 0 || image.buffer.len() < format.num_bytes() {
+            return None;
+        }
+        let bytes_per_pixel = bits_per_pixel / 8;
+
+        let rect = self.pixel_rect(image.width_height());
+        let step = 1_usize << self.level;
+        let (min_x, min_y) = (rect.min.x as usize, rect.min.y as usize);
+        let (max_x, max_y) = (rect.max.x as usize, rect.max.y as usize);
+        let tile_width = (max_x - min_x).div_ceil(step);
+        let tile_height = (max_y - min_y).div_ceil(step);
+
+        let row_bytes = format.width as usize * bytes_per_pixel;
+        let mut buffer = Vec::with_capacity(tile_width * tile_height * bytes_per_pixel);
+        for y in (min_y..max_y).step_by(step) {
+            let row = &image.buffer[y * row_bytes..(y + 1) * row_bytes];
+            if step == 1 {
+                buffer.extend_from_slice(&row[min_x * bytes_per_pixel..max_x * bytes_per_pixel]);
+            } else {
+                for x in (min_x..max_x).step_by(step) {
+                    buffer.extend_from_slice(&row[x * bytes_per_pixel..(x + 1) * bytes_per_pixel]);
+                }
+            }
+        }
+
+        Some(ImageInfo {
+            buffer_content_hash: image.buffer_content_hash,
+            buffer: buffer.into(),
+            format: ImageFormat {
+                width: tile_width as u32,
+                height: tile_height as u32,
+                ..format
+            },
+            kind: image.kind,
+        })
+    }
+}
+
+struct CachedTile {
+    /// `None` if the tile failed to upload.
+    texture: Option<GpuTexture2D>,
+
+    /// At which [`ImageTileCache::generation`] was this tile last used?
+    last_use_generation: u64,
+}
+
+impl CachedTile {
+    fn bytes_gpu(&self) -> u64 {
+        self.texture.as_ref().map_or(0, |texture| {
+            let [width, height] = texture.width_height();
+            let bytes_per_texel = texture.format().block_copy_size(None).unwrap_or(4);
+            width as u64 * height as u64 * bytes_per_texel as u64
+        })
+    }
+}
+
+/// Streams color images that are too large to upload as a whole to the GPU, tile by tile.
+///
+/// Views only request the tiles they show, at the resolution they show them at.
+/// Tiles stay cached until the cache exceeds its GPU memory budget,
+/// at which point the ones that weren't used for the longest time are dropped.
+#[derive(Default)]
+pub struct ImageTileCache {
+    tiles: HashMap<StoredBlobCacheKey, HashMap<(ImageFormat, ImageTileId), CachedTile>>,
+    bytes_gpu: u64,
+    generation: u64,
+    uploads_this_frame: usize,
+}
+
+impl ImageTileCache {
+    /// Whether the image should be streamed in tiles rather than uploaded as a single texture.
+    pub fn should_tile(image: &ImageInfo) -> bool {
+        image.kind == ImageKind::Color
+            && image.format.pixel_format.is_none()
+            && image.width().max(image.height()) > TILED_IMAGE_MIN_SIZE
+    }
+
+    /// Returns the texture of the given tile of an image.
+    ///
+    /// Returns `None` if the tile couldn't be uploaded, or won't be uploaded until a later frame.
+    /// The overview tile is always uploaded right away, so there is always something to show.
+    pub fn entry(
+        &mut self,
+        render_ctx: &RenderContext,
+        debug_name: &str,
+        image: &ImageInfo,
+        tile: ImageTileId,
+    ) -> Option<GpuTexture2D> {
+        let tiles = self.tiles.entry(image.buffer_content_hash).or_default();
+        let key = (image.format, tile);
+
+        if let Some(cached) = tiles.get_mut(&key) {
+            cached.last_use_generation = self.generation;
+            return cached.texture.clone();
+        }
+
+        if tile != ImageTileId::overview(image.width_height())
+            && self.uploads_this_frame >= MAX_TILE_UPLOADS_PER_FRAME
+        {
+            return None;
+        }
+        self.uploads_this_frame += 1;
+
+        let texture = if let Some(tile_image) = tile.extract(image) {
+            let debug_name = format!("{debug_name} tile {}/{}/{}", tile.level, tile.x, tile.y);
+            let desc = texture_creation_desc_from_color_image(
+                render_ctx.device_caps(),
+                &tile_image,
+                &debug_name,
+            );
+            match render_ctx.texture_manager_2d.create(render_ctx, desc) {
+                Ok(texture) => Some(texture),
+                Err(err) => {
+                    re_log::error_once!("Failed to upload tile of {debug_name:?}: {err}");
+                    None
+                }
+            }
+        } else {
+            re_log::error_once!("Can't cut {debug_name:?} into tiles");
+            None
+        };
+
+        let cached = CachedTile {
+            texture,
+            last_use_generation: self.generation,
+        };
+        self.bytes_gpu += cached.bytes_gpu();
+        let texture = cached.texture.clone();
+        tiles.insert(key, cached);
+        texture
+    }
+}
+
+impl Cache for ImageTileCache {
+    fn begin_frame(&mut self) {
+        #[cfg(not(target_arch = "wasm32"))]
+        let max_bytes_gpu = 1_000_000_000;
+
+        #[cfg(target_arch = "wasm32")]
+        let max_bytes_gpu = 250_000_000;
+
+        if self.bytes_gpu > max_bytes_gpu {
+            re_tracing::profile_scope!("evict tiles");
+
+            // Drop the least recently used tiles, but never the ones used in the last frame.
+            let mut last_uses = self
+                .tiles
+                .values()
+                .flat_map(|tiles| tiles.values())
+                .map(|tile| (tile.last_use_generation, tile.bytes_gpu()))
+                .collect::<Vec<_>>();
+            last_uses.sort_unstable();
+
+            let mut bytes_gpu = self.bytes_gpu;
+            let mut oldest_kept_generation = 0;
+            for (last_use_generation, tile_bytes_gpu) in last_uses {
+                if bytes_gpu <= max_bytes_gpu || last_use_generation == self.generation {
+                    break;
+                }
+                bytes_gpu -= tile_bytes_gpu;
+                oldest_kept_generation = last_use_generation + 1;
+            }
+
+            self.tiles.retain(|_, tiles| {
+                tiles.retain(|_, tile| tile.last_use_generation >= oldest_kept_generation);
+                !tiles.is_empty()
+            });
+            self.recompute_bytes_gpu();
+        }
+
+        self.uploads_this_frame = 0;
+        self.generation += 1;
+    }
+
+    fn purge_memory(&mut self) {
+        self.tiles.clear();
+        self.bytes_gpu = 0;
+    }
+
+    fn name(&self) -> &'static str {
+        "Image Tiles"
+    }
+
+    fn memory_report(&self) -> CacheMemoryReport {
+        let mut items: Vec<_> = self
+            .tiles
+            .iter()
+            .map(|(k, tiles)| CacheMemoryReportItem {
+                item_name: format!("{:x} ({} tiles)", k.0.hash64(), tiles.len()),
+                bytes_cpu: 0,
+                bytes_gpu: Some(tiles.values().map(CachedTile::bytes_gpu).sum()),
+            })
+            .collect();
+        items.sort_by(|a, b| a.item_name.cmp(&b.item_name));
+        CacheMemoryReport {
+            bytes_cpu: 0,
+            bytes_gpu: Some(self.bytes_gpu),
+            per_cache_item_info: items,
+        }
+    }
+
+    fn on_store_events(&mut self, events: &[&ChunkStoreEvent], _entity_db: &EntityDb) {
+        re_tracing::profile_function!();
+
+        let cache_key_removed = filter_blob_removed_events(events);
+        self.tiles
+            .retain(|cache_key, _tiles| !cache_key_removed.contains(cache_key));
+        self.recompute_bytes_gpu();
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl ImageTileCache {
+    fn recompute_bytes_gpu(&mut self) {
+        self.bytes_gpu = self
+            .tiles
+            .values()
+            .flat_map(|tiles| tiles.values())
+            .map(CachedTile::bytes_gpu)
+            .sum();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use re_log_types::hash::Hash64;
+    use re_types::datatypes::{ChannelDatatype, ColorModel};
+
+    use super::*;
+
+    #[test]
+    fn levels() {
+        assert_eq!(ImageTileId::overview([1000, 500]).level, 0);
+        assert_eq!(ImageTileId::overview([1025, 500]).level, 1);
+        assert_eq!(ImageTileId::overview([100_000, 20_000]).level, 7);
+
+        let size = [100_000, 20_000];
+        assert_eq!(ImageTileId::level_for_scale(size, 0.5), 0);
+        assert_eq!(ImageTileId::level_for_scale(size, 1.0), 0);
+        assert_eq!(ImageTileId::level_for_scale(size, 3.0), 1);
+        assert_eq!(ImageTileId::level_for_scale(size, 1000.0), 7);
+    }
+
+    #[test]
+    fn covering() {
+        let size = [3000, 2000];
+        let visible =
+            egui::Rect::from_min_max(egui::pos2(-10.0, 1000.0), egui::pos2(1100.0, 1030.0));
+        let tiles = ImageTileId::covering(size, visible, 0);
+        assert_eq!(
+            tiles,
+            vec![
+                ImageTileId {
+                    level: 0,
+                    x: 0,
+                    y: 0
+                },
+                ImageTileId {
+                    level: 0,
+                    x: 1,
+                    y: 0
+                },
+                ImageTileId {
+                    level: 0,
+                    x: 0,
+                    y: 1
+                },
+                ImageTileId {
+                    level: 0,
+                    x: 1,
+                    y: 1
+                },
+            ]
+        );
+        assert_eq!(
+            ImageTileId::covering(size, visible, 2),
+            vec![ImageTileId {
+                level: 2,
+                x: 0,
+                y: 0
+            }]
+        );
+
+        let outside = egui::Rect::from_min_size(egui::pos2(3000.0, 0.0), egui::vec2(10.0, 10.0));
+        assert!(ImageTileId::covering(size, outside, 0).is_empty());
+
+        assert_eq!(
+            ImageTileId {
+                level: 0,
+                x: 2,
+                y: 1
+            }
+            .pixel_rect(size),
+            egui::Rect::from_min_max(egui::pos2(2048.0, 1024.0), egui::pos2(3000.0, 2000.0))
+        );
+    }
+
+    #[test]
+    fn extract() {
+        // A 2049x3 L8 image, where each pixel is its column modulo 256.
+        let width = 2049;
+        let pixels = (0..3)
+            .flat_map(|_| (0..width).map(|x| (x % 256) as u8))
+            .collect::<Vec<_>>();
+        let image = ImageInfo {
+            buffer_content_hash: StoredBlobCacheKey(Hash64::ZERO),
+            buffer: pixels.into(),
+            format: ImageFormat::from_color_model([width, 3], ColorModel::L, ChannelDatatype::U8),
+            kind: ImageKind::Color,
+        };
+
+        let last_column = ImageTileId {
+            level: 0,
+            x: 2,
+            y: 0,
+        }
+        .extract(&image)
+        .unwrap();
+        assert_eq!(last_column.width_height(), [1, 3]);
+        assert_eq!(&last_column.buffer[..], &[0, 0, 0]);
+
+        let overview = ImageTileId {
+            level: 2,
+            x: 0,
+            y: 0,
+        }
+        .extract(&image)
+        .unwrap();
+        assert_eq!(overview.width_height(), [513, 1]);
+        assert_eq!(&overview.buffer[..3], &[0, 4, 8]);
+    }
+}
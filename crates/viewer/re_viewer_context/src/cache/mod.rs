@@ -6,6 +6,7 @@
 mod caches;
 mod image_decode_cache;
 mod image_stats_cache;
+mod image_tile_cache;
 mod tensor_stats_cache;
 mod video_asset_cache;
 mod video_stream_cache;
@@ -18,6 +19,7 @@ pub use caches::{Cache, CacheMemoryReport, CacheMemoryReportItem, Caches};
 // Ideally, they would only depend on the ones needed.
 pub use image_decode_cache::ImageDecodeCache;
 pub use image_stats_cache::ImageStatsCache;
+pub use image_tile_cache::{IMAGE_TILE_SIZE, ImageTileCache, ImageTileId};
 pub use tensor_stats_cache::TensorStatsCache;
 pub use video_asset_cache::VideoAssetCache;
 pub use video_stream_cache::{
@@ -89,6 +89,26 @@ pub enum SystemCommand {
     /// is both modified and changed in the same frame.
     DropEntity(StoreId, EntityPath),
 
+    /// Make a copy of a recording where the times of a timeline have been shifted, and make it
+    /// the active recording.
+    ///
+    /// Used to correct recordings gathered from machines with drifting clocks.
+    RebaseTimeline {
+        store_id: StoreId,
+        timeline: re_log_types::TimelineName,
+        rebase: re_entity_db::TimelineRebase,
+    },
+
+    /// Make queries use a previous version of some re-logged static data, instead of the latest
+    /// one.
+    ///
+    /// `None` unpins, i.e. goes back to the latest version.
+    PinStaticVersion {
+        store_id: StoreId,
+        component_path: re_log_types::ComponentPath,
+        version: Option<re_chunk::RowId>,
+    },
+
     /// Show a timeline of the blueprint data.
     #[cfg(debug_assertions)]
     EnableInspectBlueprintTimeline(bool),
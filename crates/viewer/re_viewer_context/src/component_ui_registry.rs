@@ -770,5 +770,23 @@ fn fallback_ui(
     timestamp_format: re_log_types::TimestampFormat,
     component: &dyn arrow::array::Array,
 ) {
-    re_arrow_ui::arrow_ui(ui, ui_layout, timestamp_format, component);
+    re_arrow_ui::component_arrow_ui(
+        ui,
+        ui_layout,
+        timestamp_format,
+        Some(&colormap_by_name),
+        component,
+    );
+}
+
+/// Looks up one of our builtin colormaps by (case-insensitive) name, for display hints.
+fn colormap_by_name(name: &str, t: f32) -> Option<egui::Color32> {
+    use re_types::reflection::Enum as _;
+
+    let colormap = re_types::components::Colormap::variants()
+        .iter()
+        .find(|colormap| colormap.to_string().eq_ignore_ascii_case(name))?;
+    let [r, g, b, a] =
+        re_renderer::colormap_srgb(crate::gpu_bridge::colormap_to_re_renderer(*colormap), t);
+    Some(egui::Color32::from_rgba_unmultiplied(r, g, b, a))
 }
@@ -0,0 +1,251 @@
+use nohash_hasher::IntMap;
+
+use re_chunk_store::ChunkStoreGeneration;
+use re_entity_db::{EntityDb, EntityTree};
+use re_log_types::{EntityPath, EntityPathHash, StoreId};
+
+/// A single entity of an [`EntityTreeSnapshot`].
+#[derive(Debug, Clone)]
+pub struct EntityTreeSnapshotEntity {
+    pub path: EntityPath,
+
+    /// Index of the entity after the last descendant of this entity.
+    subtree_end: usize,
+}
+
+/// Handle to an interest registered with [`EntityTreeSnapshot::register_interest`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EntityInterestHandle(usize);
+
+struct EntityInterest {
+    filter: Box<dyn Fn(&EntityPath) -> bool + Send + Sync>,
+
+    /// Indices of all entities that passed the filter.
+    matches: Vec<usize>,
+}
+
+/// A flattened copy of the entity tree of the active recording.
+///
+/// Updated at most once per frame, before any views are queried, and shared with all systems via
+/// [`crate::ViewerContext::entity_tree`], so that they don't have to walk the entity tree on their own.
+/// Systems that are only interested in some entities can register a filter via
+/// [`Self::register_interest`], which is evaluated during the same walk.
+#[derive(Default)]
+pub struct EntityTreeSnapshot {
+    /// The recording and its generation this snapshot was taken of.
+    source: Option<(StoreId, ChunkStoreGeneration)>,
+
+    /// All entities in depth-first order, children sorted by their path part.
+    entities: Vec<EntityTreeSnapshotEntity>,
+
+    index_per_path: IntMap<EntityPathHash, usize>,
+
+    interests: Vec<EntityInterest>,
+}
+
+impl EntityTreeSnapshot {
+    /// Takes a snapshot of the entity tree of the given recording.
+    pub fn from_entity_db(recording: &EntityDb) -> Self {
+        let mut snapshot = Self::default();
+        snapshot.update(recording);
+        snapshot
+    }
+
+    /// Takes a new snapshot if the recording changed since the last one was taken.
+    pub fn update(&mut self, recording: &EntityDb) {
+        let source = (recording.store_id().clone(), recording.generation());
+        if self.source.as_ref() == Some(&source) {
+            return;
+        }
+
+        re_tracing::profile_function!();
+
+        self.source = Some(source);
+        self.entities.clear();
+        self.index_per_path.clear();
+        for interest in &mut self.interests {
+            interest.matches.clear();
+        }
+
+        self.add_subtree(recording.tree());
+    }
+
+    fn add_subtree(&mut self, tree: &EntityTree) {
+        let index = self.entities.len();
+
+        self.index_per_path.insert(tree.path.hash(), index);
+        for interest in &mut self.interests {
+            if (interest.filter)(&tree.path) {
+                interest.matches.push(index);
+            }
+        }
+        self.entities.push(EntityTreeSnapshotEntity {
+            path: tree.path.clone(),
+            subtree_end: index + 1,
+        });
+
+        for child in tree.children.values() {
+            self.add_subtree(child);
+        }
+
+        self.entities[index].subtree_end = self.entities.len();
+    }
+
+    /// Registers a filter for entities a system is interested in.
+    ///
+    /// The filter is evaluated for all entities whenever a new snapshot is taken.
+    /// Use [`Self::interesting_entities`] to retrieve the entities that passed it.
+    pub fn register_interest(
+        &mut self,
+        filter: impl Fn(&EntityPath) -> bool + Send + Sync + 'static,
+    ) -> EntityInterestHandle {
+        let matches = self
+            .entities
+            .iter()
+            .enumerate()
+            .filter_map(|(index, entity)| filter(&entity.path).then_some(index))
+            .collect();
+
+        self.interests.push(EntityInterest {
+            filter: Box::new(filter),
+            matches,
+        });
+
+        EntityInterestHandle(self.interests.len() - 1)
+    }
+
+    /// All entities that passed the filter of the given interest, in depth-first order.
+    pub fn interesting_entities(
+        &self,
+        handle: EntityInterestHandle,
+    ) -> impl Iterator<Item = &EntityPath> + '_ {
+        self.interests
+            .get(handle.0)
+            .map(|interest| interest.matches.as_slice())
+            .unwrap_or_default()
+            .iter()
+            .map(|index| &self.entities[*index].path)
+    }
+
+    /// All entities, in depth-first order starting with the root.
+    #[inline]
+    pub fn entities(&self) -> &[EntityTreeSnapshotEntity] {
+        &self.entities
+    }
+
+    /// Index of the given entity in [`Self::entities`], if it is part of the tree.
+    #[inline]
+    pub fn index_of(&self, entity_path: &EntityPath) -> Option<usize> {
+        self.index_per_path.get(&entity_path.hash()).copied()
+    }
+
+    /// The given entity followed by all of its descendants, in depth-first order.
+    ///
+    /// Empty if the entity isn't part of the tree.
+    pub fn subtree(&self, entity_path: &EntityPath) -> &[EntityTreeSnapshotEntity] {
+        self.index_of(entity_path).map_or(&[], |index| {
+            &self.entities[index..self.entities[index].subtree_end]
+        })
+    }
+
+    /// Indices of the direct children of the entity at the given index.
+    pub fn children(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
+        let subtree_end = self.entities[index].subtree_end;
+        std::iter::successors(Some(index + 1), move |child| {
+            Some(self.entities[*child].subtree_end)
+        })
+        .take_while(move |child| *child < subtree_end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use re_chunk::{Chunk, RowId};
+    use re_log_types::{StoreKind, TimePoint};
+    use re_types::archetypes::Points3D;
+
+    use super::*;
+
+    fn recording_with(entity_paths: &[&str]) -> EntityDb {
+        let mut recording = EntityDb::new(StoreId::random(StoreKind::Recording, "test_app"));
+        for entity_path in entity_paths {
+            let chunk = Chunk::builder(*entity_path)
+                .with_archetype(
+                    RowId::new(),
+                    TimePoint::default(),
+                    &Points3D::new([[0.0, 0.0, 0.0]]),
+                )
+                .build()
+                .unwrap();
+            recording.add_chunk(&Arc::new(chunk)).unwrap();
+        }
+        recording
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let recording = recording_with(&["a/b", "a/c/d", "e"]);
+        let snapshot = EntityTreeSnapshot::from_entity_db(&recording);
+
+        let paths = |entities: &[EntityTreeSnapshotEntity]| {
+            entities
+                .iter()
+                .map(|entity| entity.path.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            paths(snapshot.entities()),
+            ["/", "/a", "/a/b", "/a/c", "/a/c/d", "/e"]
+        );
+        assert_eq!(paths(snapshot.subtree(&"a/c".into())), ["/a/c", "/a/c/d"]);
+        assert!(snapshot.subtree(&"x".into()).is_empty());
+
+        let a = snapshot.index_of(&"a".into()).unwrap();
+        assert_eq!(
+            snapshot
+                .children(a)
+                .map(|index| snapshot.entities()[index].path.to_string())
+                .collect::<Vec<_>>(),
+            ["/a/b", "/a/c"]
+        );
+        assert_eq!(snapshot.children(0).count(), 2);
+    }
+
+    #[test]
+    fn test_interests() {
+        let mut recording = recording_with(&["a/b", "e"]);
+        let mut snapshot = EntityTreeSnapshot::from_entity_db(&recording);
+
+        let handle = snapshot.register_interest(|entity_path| entity_path.len() == 2);
+        assert_eq!(
+            snapshot
+                .interesting_entities(handle)
+                .map(|entity_path| entity_path.to_string())
+                .collect::<Vec<_>>(),
+            ["/a/b"]
+        );
+
+        // Interests are evaluated again when the recording changes.
+        let chunk = Chunk::builder("e/f")
+            .with_archetype(
+                RowId::new(),
+                TimePoint::default(),
+                &Points3D::new([[0.0, 0.0, 0.0]]),
+            )
+            .build()
+            .unwrap();
+        recording.add_chunk(&Arc::new(chunk)).unwrap();
+        snapshot.update(&recording);
+
+        assert_eq!(
+            snapshot
+                .interesting_entities(handle)
+                .map(|entity_path| entity_path.to_string())
+                .collect::<Vec<_>>(),
+            ["/a/b", "/e/f"]
+        );
+    }
+}
@@ -13,7 +13,8 @@ use re_renderer::{
     pad_rgb_to_rgba,
     renderer::{ColorMapper, ColormappedTexture, ShaderDecoding, TextureAlpha},
     resource_managers::{
-        ImageDataDesc, SourceImageDataFormat, YuvMatrixCoefficients, YuvPixelLayout, YuvRange,
+        GpuTexture2D, ImageDataDesc, SourceImageDataFormat, YuvMatrixCoefficients, YuvPixelLayout,
+        YuvRange,
     },
 };
 use re_types::components::ClassId;
@@ -90,13 +91,31 @@ fn color_image_to_gpu(
 ) -> anyhow::Result<ColormappedTexture> {
     re_tracing::profile_function!();
 
-    let image_format = image.format;
-
     let texture_handle = get_or_create_texture(render_ctx, texture_key, || {
         texture_creation_desc_from_color_image(render_ctx.device_caps(), image, debug_name)
     })
     .map_err(|err| anyhow::anyhow!("{err}"))?;
 
+    Ok(colormapped_color_texture(
+        render_ctx,
+        debug_name,
+        texture_handle,
+        image,
+        image_stats,
+    ))
+}
+
+/// Determines how to show a texture holding all or part of a color image.
+///
+/// Used directly for the tiles of large images, see [`crate::ImageTileCache`].
+pub fn colormapped_color_texture(
+    render_ctx: &RenderContext,
+    debug_name: &str,
+    texture_handle: GpuTexture2D,
+    image: &ImageInfo,
+    image_stats: &ImageStats,
+) -> ColormappedTexture {
+    let image_format = image.format;
     let texture_format = texture_handle.format();
 
     let shader_decoding = required_shader_decode(render_ctx.device_caps(), &image_format);
@@ -156,7 +175,7 @@ fn color_image_to_gpu(
         "color_tensor_to_gpu {debug_name:?}, range: {range:?}, decode_srgb: {decode_srgb:?}, texture_alpha: {texture_alpha:?}, gamma: {gamma:?}, color_mapper: {color_mapper:?}",
     );
 
-    Ok(ColormappedTexture {
+    ColormappedTexture {
         texture: texture_handle,
         range: [range.min, range.max],
         decode_srgb,
@@ -164,7 +183,7 @@ fn color_image_to_gpu(
         gamma,
         color_mapper,
         shader_decoding,
-    })
+    }
 }
 
 /// Get a valid, finite range for the gpu to use.
@@ -449,7 +468,7 @@ fn segmentation_image_to_gpu(
 
     let datatype = image.format.datatype();
 
-    let colormap_key = hash(annotations.row_id());
+    let colormap_key = hash(annotations.cache_key());
 
     let (_, mut max) = image_stats
         .range
@@ -468,11 +487,16 @@ fn segmentation_image_to_gpu(
     let colormap_texture_handle = get_or_create_texture(render_ctx, colormap_key, || {
         let data: Vec<u8> = (0..(colormap_width * colormap_height))
             .flat_map(|id| {
-                let color = annotations
+                let annotation_info = annotations
                     .resolved_class_description(Some(ClassId::from(id as u16)))
-                    .annotation_info()
-                    .color()
-                    .unwrap_or(re_renderer::Color32::TRANSPARENT);
+                    .annotation_info();
+                let color = if annotation_info.is_hidden {
+                    re_renderer::Color32::TRANSPARENT
+                } else {
+                    annotation_info
+                        .color()
+                        .unwrap_or(re_renderer::Color32::TRANSPARENT)
+                };
                 color.to_array() // premultiplied!
             })
             .collect();
@@ -6,7 +6,7 @@ mod re_renderer_callback;
 
 pub use colormap::{colormap_edit_or_view_ui, colormap_to_re_renderer};
 pub use image_to_gpu::{
-    image_data_range_heuristic, image_to_gpu, required_shader_decode,
+    colormapped_color_texture, image_data_range_heuristic, image_to_gpu, required_shader_decode,
     texture_creation_desc_from_color_image,
 };
 pub use re_renderer_callback::new_renderer_callback;
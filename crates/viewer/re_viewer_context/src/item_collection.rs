@@ -242,6 +242,9 @@ impl ItemCollection {
                     re_smart_channel::SmartChannelSource::MessageProxy(uri) => {
                         Some((ClipboardTextDesc::Url, uri.to_string()))
                     }
+                    re_smart_channel::SmartChannelSource::McapWebSocket { url } => {
+                        Some((ClipboardTextDesc::Url, url.clone()))
+                    }
                 },
 
                 Item::AppId(id) => Some((ClipboardTextDesc::AppId, id.to_string())),
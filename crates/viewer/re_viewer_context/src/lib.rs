@@ -7,6 +7,7 @@
 mod annotations;
 mod app_options;
 mod async_runtime_handle;
+mod blueprint_diff;
 mod blueprint_helpers;
 mod blueprint_id;
 mod cache;
@@ -17,6 +18,7 @@ mod component_ui_registry;
 mod contents;
 mod display_mode;
 mod drag_and_drop;
+mod entity_tree_snapshot;
 mod file_dialog;
 mod global_context;
 mod heuristics;
@@ -47,17 +49,19 @@ mod visitor_flow_control;
 
 pub use self::{
     annotations::{
-        AnnotationContextStoreSubscriber, AnnotationMap, Annotations, ResolvedAnnotationInfo,
+        AnnotationClassOverride, AnnotationClassOverrides, AnnotationContextStoreSubscriber,
+        AnnotationMap, Annotations, ClassIdRemapping, ResolvedAnnotationInfo,
         ResolvedAnnotationInfos,
     },
     app_options::AppOptions,
     async_runtime_handle::{AsyncRuntimeError, AsyncRuntimeHandle, WasmNotSend},
+    blueprint_diff::{BlueprintChange, BlueprintChangeKind, BlueprintChangeSubject, BlueprintDiff},
     blueprint_helpers::{BlueprintContext, blueprint_timeline, blueprint_timepoint_for_writes},
     blueprint_id::{BlueprintId, BlueprintIdRegistry, ContainerId, GLOBAL_VIEW_ID, ViewId},
     cache::{
-        Cache, CacheMemoryReport, CacheMemoryReportItem, Caches, ImageDecodeCache, ImageStatsCache,
-        SharablePlayableVideoStream, TensorStatsCache, VideoAssetCache, VideoStreamCache,
-        VideoStreamProcessingError,
+        Cache, CacheMemoryReport, CacheMemoryReportItem, Caches, IMAGE_TILE_SIZE, ImageDecodeCache,
+        ImageStatsCache, ImageTileCache, ImageTileId, SharablePlayableVideoStream,
+        TensorStatsCache, VideoAssetCache, VideoStreamCache, VideoStreamProcessingError,
     },
     collapsed_id::{CollapseItem, CollapseScope, CollapsedId},
     command_sender::{
@@ -68,6 +72,7 @@ pub use self::{
     contents::{Contents, ContentsName, blueprint_id_to_tile_id},
     display_mode::DisplayMode,
     drag_and_drop::{DragAndDropFeedback, DragAndDropManager, DragAndDropPayload},
+    entity_tree_snapshot::{EntityInterestHandle, EntityTreeSnapshot, EntityTreeSnapshotEntity},
     file_dialog::sanitize_file_name,
     global_context::GlobalContext,
     heuristics::suggest_view_for_each_entity,
@@ -90,8 +95,8 @@ pub use self::{
     tables::{TableStore, TableStores},
     tensor::{ImageStats, TensorStats},
     time_control::{
-        TIME_PANEL_PATH, TimeControl, TimeControlCommand, TimeControlResponse, TimeView,
-        time_panel_blueprint_entity_path,
+        EventSearch, TIME_PANEL_PATH, TimeControl, TimeControlCommand, TimeControlResponse,
+        TimeView, time_panel_blueprint_entity_path,
     },
     typed_entity_collections::{
         IndicatedEntities, MaybeVisualizableEntities, PerVisualizer, VisualizableEntities,
@@ -44,7 +44,14 @@ pub enum ViewerOpenUrl {
     ///
     /// Could be either an `.rrd` recording or a `.rbl` blueprint.
     /// See also [`LogDataSource::RrdHttpUrl`].
-    RrdHttpUrl(Url),
+    RrdHttpUrl {
+        /// The url of the file, without its `#fragment`.
+        url: Url,
+
+        /// What to select, where to put the time cursor and which blueprint to use
+        /// once the recording has loaded.
+        fragment: re_uri::Fragment,
+    },
 
     /// A path to a local file.
     ///
@@ -62,6 +69,12 @@ pub enum ViewerOpenUrl {
     /// See also [`LogDataSource::RedapProxy`].
     RedapProxy(re_uri::ProxyUri),
 
+    /// A `ws://` or `wss://` URL pointing to a Foxglove WebSocket server.
+    ///
+    /// See also [`LogDataSource::FoxgloveWebSocket`].
+    #[cfg(not(target_arch = "wasm32"))]
+    FoxgloveWebSocket(Url),
+
     /// A URL that points to a redap server.
     RedapCatalog(re_uri::CatalogUri),
 
@@ -135,7 +148,7 @@ impl std::str::FromStr for ViewerOpenUrl {
             LogDataSource::from_uri(re_log_types::FileSource::Uri, url)
         {
             match data_source {
-                LogDataSource::RrdHttpUrl { url, follow: _ } => Ok(Self::RrdHttpUrl(url)),
+                LogDataSource::RrdHttpUrl { url, follow: _ } => Ok(Self::rrd_http_url(url)),
 
                 #[cfg(not(target_arch = "wasm32"))]
                 LogDataSource::FilePath(_file_source, path_buf) => Ok(Self::FilePath(path_buf)),
@@ -153,6 +166,9 @@ impl std::str::FromStr for ViewerOpenUrl {
                 } => Ok(Self::RedapDatasetPartition(uri)),
 
                 LogDataSource::RedapProxy(proxy_uri) => Ok(Self::RedapProxy(proxy_uri)),
+
+                #[cfg(not(target_arch = "wasm32"))]
+                LogDataSource::FoxgloveWebSocket(url) => Ok(Self::FoxgloveWebSocket(url)),
             }
         }
         // Web viewer URL with `url` parameters.
@@ -184,6 +200,15 @@ fn parse_webviewer_url(url: &str) -> anyhow::Result<ViewerOpenUrl> {
     })
 }
 
+/// Puts the fragment back onto a url that was split by [`ViewerOpenUrl::rrd_http_url`].
+fn url_with_fragment(url: &Url, fragment: &re_uri::Fragment) -> Url {
+    let mut url = url.clone();
+    if !fragment.is_empty() {
+        url.set_fragment(Some(&fragment.to_string()));
+    }
+    url
+}
+
 /// URL stripped of query and fragment.
 pub fn base_url(url: &Url) -> Url {
     let mut base_url = url.clone();
@@ -202,6 +227,19 @@ pub struct OpenUrlOptions {
 }
 
 impl ViewerOpenUrl {
+    /// A link to a remote RRD file.
+    ///
+    /// The `#fragment` of the url, if any, is parsed as a [`re_uri::Fragment`].
+    /// Fragments that fail to parse are dropped.
+    pub fn rrd_http_url(mut url: Url) -> Self {
+        let fragment = url
+            .fragment()
+            .map(re_uri::Fragment::parse_forgiving)
+            .unwrap_or_default();
+        url.set_fragment(None);
+        Self::RrdHttpUrl { url, fragment }
+    }
+
     pub fn from_context(ctx: &ViewerContext<'_>) -> anyhow::Result<Self> {
         Self::from_context_expanded(
             ctx.storage_context.hub,
@@ -255,7 +293,7 @@ impl ViewerOpenUrl {
         // we just preserve as much as possible here.
         match data_source {
             SmartChannelSource::RrdHttpStream { url, follow: _ } => {
-                Ok(Self::RrdHttpUrl(url.parse::<Url>()?))
+                Ok(Self::rrd_http_url(url.parse::<Url>()?))
             }
 
             SmartChannelSource::File(path_buf) => {
@@ -292,6 +330,20 @@ impl ViewerOpenUrl {
             } => Ok(Self::RedapDatasetPartition(uri.clone())),
 
             SmartChannelSource::MessageProxy(proxy_uri) => Ok(Self::RedapProxy(proxy_uri.clone())),
+
+            SmartChannelSource::McapWebSocket { url } => {
+                #[cfg(not(target_arch = "wasm32"))]
+                {
+                    Ok(Self::FoxgloveWebSocket(url.parse::<Url>()?))
+                }
+                #[cfg(target_arch = "wasm32")]
+                {
+                    _ = url;
+                    Err(anyhow::anyhow!(
+                        "Can't share links to WebSocket streams on the web."
+                    ))
+                }
+            }
         }
     }
 
@@ -374,7 +426,9 @@ impl ViewerOpenUrl {
                 )]
             }
 
-            Self::RrdHttpUrl(url) => vec1![url.to_string()],
+            Self::RrdHttpUrl { url, fragment } => {
+                vec1![url_with_fragment(url, fragment).to_string()]
+            }
 
             #[cfg(not(target_arch = "wasm32"))]
             Self::FilePath(path_buf) => vec1![(*path_buf.to_string_lossy()).to_owned()],
@@ -387,6 +441,9 @@ impl ViewerOpenUrl {
                 vec1![proxy_uri.to_string()]
             }
 
+            #[cfg(not(target_arch = "wasm32"))]
+            Self::FoxgloveWebSocket(url) => vec1![url.to_string()],
+
             Self::RedapCatalog(catalog_uri) => {
                 // The welcome page is a fake catalog right now.
                 // If we dont'have a base url we'll just roll with it. It looks ugly but it's sharable.
@@ -441,8 +498,8 @@ impl ViewerOpenUrl {
         match &self {
             Self::RedapCatalog(_) | Self::RedapEntry(_) | Self::IntraRecordingSelection(_) => None,
 
-            Self::RrdHttpUrl(url) => Some(SmartChannelSource::RrdHttpStream {
-                url: url.to_string(),
+            Self::RrdHttpUrl { url, fragment } => Some(SmartChannelSource::RrdHttpStream {
+                url: url_with_fragment(url, fragment).to_string(),
                 follow: false,
             }),
             #[cfg(not(target_arch = "wasm32"))]
@@ -452,6 +509,10 @@ impl ViewerOpenUrl {
                 select_when_loaded: false,
             }),
             Self::RedapProxy(uri) => Some(SmartChannelSource::MessageProxy(uri.clone())),
+            #[cfg(not(target_arch = "wasm32"))]
+            Self::FoxgloveWebSocket(url) => Some(SmartChannelSource::McapWebSocket {
+                url: url.to_string(),
+            }),
             Self::WebEventListener => Some(SmartChannelSource::RrdWebEventListener),
             Self::WebViewerUrl { url_parameters, .. } => (url_parameters.len() == 1)
                 .then(|| url_parameters.first().get_data_source())
@@ -493,10 +554,10 @@ impl ViewerOpenUrl {
             Self::IntraRecordingSelection(item) => {
                 command_sender.send_system(SystemCommand::set_selection(item));
             }
-            Self::RrdHttpUrl(url) => {
+            Self::RrdHttpUrl { url, fragment } => {
                 command_sender.send_system(SystemCommand::LoadDataSource(
                     LogDataSource::RrdHttpUrl {
-                        url,
+                        url: url_with_fragment(&url, &fragment),
                         // `follow` is not encoded in the url itself right now.
                         follow: options.follow_if_http,
                     },
@@ -526,6 +587,12 @@ impl ViewerOpenUrl {
                     proxy_uri.origin,
                 )));
             }
+            #[cfg(not(target_arch = "wasm32"))]
+            Self::FoxgloveWebSocket(url) => {
+                command_sender.send_system(SystemCommand::LoadDataSource(
+                    LogDataSource::FoxgloveWebSocket(url),
+                ));
+            }
             Self::RedapCatalog(uri) => {
                 command_sender.send_system(SystemCommand::AddRedapServer(uri.origin.clone()));
                 command_sender
@@ -581,11 +648,13 @@ impl ViewerOpenUrl {
     pub fn fragment_mut(&mut self) -> Option<&mut re_uri::Fragment> {
         match self {
             Self::IntraRecordingSelection(..) => None,
-            Self::RrdHttpUrl(..) => None,
+            Self::RrdHttpUrl { url: _, fragment } => Some(fragment),
             #[cfg(not(target_arch = "wasm32"))]
             Self::FilePath(..) => None,
             Self::RedapDatasetPartition(uri) => Some(&mut uri.fragment),
             Self::RedapProxy(..) => None,
+            #[cfg(not(target_arch = "wasm32"))]
+            Self::FoxgloveWebSocket(..) => None,
             Self::RedapCatalog(..) => None,
             Self::RedapEntry(..) => None,
             Self::WebEventListener => None,
@@ -606,11 +675,13 @@ impl ViewerOpenUrl {
     pub fn time_range_mut(&mut self) -> Option<&mut Option<re_uri::TimeSelection>> {
         match self {
             Self::IntraRecordingSelection(..) => None,
-            Self::RrdHttpUrl(..) => None,
+            Self::RrdHttpUrl { .. } => None,
             #[cfg(not(target_arch = "wasm32"))]
             Self::FilePath(..) => None,
             Self::RedapDatasetPartition(uri) => Some(&mut uri.time_range),
             Self::RedapProxy(..) => None,
+            #[cfg(not(target_arch = "wasm32"))]
+            Self::FoxgloveWebSocket(..) => None,
             Self::RedapCatalog(..) => None,
             Self::RedapEntry(..) => None,
             Self::WebEventListener => None,
@@ -776,8 +847,20 @@ mod tests {
             let url = "https://example.com/data.rrd";
             assert_eq!(
                 ViewerOpenUrl::from_str(url).unwrap(),
-                ViewerOpenUrl::RrdHttpUrl(Url::parse("https://example.com/data.rrd").unwrap())
+                ViewerOpenUrl::rrd_http_url(Url::parse("https://example.com/data.rrd").unwrap())
+            );
+
+            // HTTP URL with a fragment
+            let url = "https://example.com/data.rrd#selection=/camera&when=log_tick@32";
+            let open_url = ViewerOpenUrl::from_str(url).unwrap();
+            assert_eq!(
+                open_url,
+                ViewerOpenUrl::RrdHttpUrl {
+                    url: Url::parse("https://example.com/data.rrd").unwrap(),
+                    fragment: "selection=/camera&when=log_tick@32".parse().unwrap(),
+                }
             );
+            assert_eq!(open_url.sharable_url(None).unwrap(), url);
 
             // Test file path (native only)
             #[cfg(not(target_arch = "wasm32"))]
@@ -797,7 +880,7 @@ mod tests {
             let url = "https://foo.com/test?url=https://example.com/data.rrd";
             let expected = ViewerOpenUrl::WebViewerUrl {
                 base_url: Url::parse("https://foo.com/test").unwrap(),
-                url_parameters: vec1::vec1![ViewerOpenUrl::RrdHttpUrl(
+                url_parameters: vec1::vec1![ViewerOpenUrl::rrd_http_url(
                     Url::parse("https://example.com/data.rrd").unwrap()
                 )],
             };
@@ -814,7 +897,9 @@ mod tests {
                     ViewerOpenUrl::IntraRecordingSelection(Item::InstancePath(
                         InstancePath::entity_all(EntityPath::from("camera"))
                     )),
-                    ViewerOpenUrl::RrdHttpUrl(Url::parse("https://example.com/data.rrd").unwrap())
+                    ViewerOpenUrl::rrd_http_url(
+                        Url::parse("https://example.com/data.rrd").unwrap()
+                    )
                 ],
             };
             assert_eq!(ViewerOpenUrl::from_str(url).unwrap(), expected);
@@ -923,7 +1008,7 @@ mod tests {
         assert_eq!(
             ViewerOpenUrl::from_display_mode(&store_hub, &DisplayMode::LocalRecordings(id))
                 .unwrap(),
-            ViewerOpenUrl::RrdHttpUrl("https://example.com/recording.rrd".parse().unwrap())
+            ViewerOpenUrl::rrd_http_url("https://example.com/recording.rrd".parse().unwrap())
         );
 
         // originating from SDK (not possible).
@@ -995,6 +1080,7 @@ mod tests {
                     value: re_log_types::NonMinI64::ONE,
                 },
             )),
+            blueprint: None,
         };
 
         uri.fragment = fragment.clone();
@@ -1037,7 +1123,7 @@ mod tests {
         );
 
         assert_eq!(
-            ViewerOpenUrl::RrdHttpUrl(Url::parse("https://example.com/data.rrd").unwrap())
+            ViewerOpenUrl::rrd_http_url(Url::parse("https://example.com/data.rrd").unwrap())
                 .sharable_url(None)
                 .unwrap(),
             "https://example.com/data.rrd"
@@ -1089,7 +1175,7 @@ mod tests {
         assert_eq!(
             ViewerOpenUrl::WebViewerUrl {
                 base_url: Url::parse("https://foo.com/test").unwrap(),
-                url_parameters: vec1::vec1![ViewerOpenUrl::RrdHttpUrl(
+                url_parameters: vec1::vec1![ViewerOpenUrl::rrd_http_url(
                     Url::parse("https://example.com/data.rrd").unwrap()
                 )],
             }
@@ -1101,7 +1187,7 @@ mod tests {
             ViewerOpenUrl::WebViewerUrl {
                 base_url: Url::parse("https://foo.com/test").unwrap(),
                 url_parameters: vec1::vec1![
-                    ViewerOpenUrl::RrdHttpUrl(Url::parse("https://example.com/bar.rrd").unwrap()),
+                    ViewerOpenUrl::rrd_http_url(Url::parse("https://example.com/bar.rrd").unwrap()),
                     ViewerOpenUrl::RedapProxy("rerun://localhost:51234/proxy".parse().unwrap())
                 ],
             }
@@ -1123,7 +1209,7 @@ mod tests {
         );
 
         assert_eq!(
-            ViewerOpenUrl::RrdHttpUrl("https://example.com/data.rrd".parse().unwrap())
+            ViewerOpenUrl::rrd_http_url("https://example.com/data.rrd".parse().unwrap())
                 .sharable_url(base_url_param)
                 .unwrap(),
             "https://foo.com/test?url=https%3A%2F%2Fexample.com%2Fdata.rrd"
@@ -1176,7 +1262,7 @@ mod tests {
         assert_eq!(
             ViewerOpenUrl::WebViewerUrl {
                 base_url: Url::parse("http://foo.com/doesn't-matter").unwrap(),
-                url_parameters: vec1::vec1![ViewerOpenUrl::RrdHttpUrl(
+                url_parameters: vec1::vec1![ViewerOpenUrl::rrd_http_url(
                     Url::parse("https://example.com/data.rrd").unwrap()
                 )],
             }
@@ -1188,7 +1274,7 @@ mod tests {
             ViewerOpenUrl::WebViewerUrl {
                 base_url: Url::parse("http://foo.com/doesn't-matter").unwrap(),
                 url_parameters: vec1::vec1![
-                    ViewerOpenUrl::RrdHttpUrl(Url::parse("https://example.com/bar.rrd").unwrap()),
+                    ViewerOpenUrl::rrd_http_url(Url::parse("https://example.com/bar.rrd").unwrap()),
                     ViewerOpenUrl::RedapProxy("rerun://localhost:51234/proxy".parse().unwrap())
                 ],
             }
@@ -10,9 +10,10 @@ use vec1::Vec1;
 use re_chunk::{EntityPath, TimelineName};
 use re_entity_db::{TimeCounts, TimelineStats, TimesPerTimeline};
 use re_log_types::{
-    AbsoluteTimeRange, AbsoluteTimeRangeF, Duration, TimeCell, TimeInt, TimeReal, TimeType,
-    Timeline,
+    AbsoluteTimeRange, AbsoluteTimeRangeF, ComponentPath, Duration, TimeCell, TimeInt, TimeReal,
+    TimeType, Timeline,
 };
+use re_query::ScalarPredicate;
 
 use crate::{NeedsRepaint, blueprint_helpers::BlueprintContext};
 
@@ -212,6 +213,18 @@ impl From<AbsoluteTimeRange> for TimeView {
     }
 }
 
+/// Highlights the times at which the values of a component satisfy a predicate.
+///
+/// The matching time ranges are shown as bands in the time panel, which can be stepped through.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EventSearch {
+    /// The component whose values are tested.
+    pub component_path: ComponentPath,
+
+    /// What the values are tested against, e.g. `> 2.0`.
+    pub predicate: ScalarPredicate,
+}
+
 /// A command used to mutate `TimeControl`.
 ///
 /// Can be sent using [`crate::SystemCommand::TimeControlCommands`].
@@ -220,6 +233,11 @@ pub enum TimeControlCommand {
     HighlightRange(AbsoluteTimeRange),
     ClearHighlightedRange,
 
+    /// Start highlighting the times at which a component satisfies a predicate, or change what is
+    /// being searched for.
+    SetEventSearch(EventSearch),
+    ClearEventSearch,
+
     /// Reset the active timeline to instead be automatically assigned.
     ResetActiveTimeline,
     SetActiveTimeline(TimelineName),
@@ -373,6 +391,9 @@ pub struct TimeControl {
     ///
     /// This is used during UI interactions. E.g. to show visual history range that's highlighted.
     pub highlighted_range: Option<AbsoluteTimeRange>,
+
+    /// The events highlighted in the time panel, if any.
+    pub event_search: Option<EventSearch>,
 }
 
 impl Default for TimeControl {
@@ -386,6 +407,7 @@ impl Default for TimeControl {
             speed: 1.0,
             loop_mode: LoopMode::Off,
             highlighted_range: None,
+            event_search: None,
         }
     }
 }
@@ -808,6 +830,14 @@ impl TimeControl {
                 self.highlighted_range = None;
                 NeedsRepaint::No
             }
+            TimeControlCommand::SetEventSearch(event_search) => {
+                self.event_search = Some(event_search.clone());
+                NeedsRepaint::Yes
+            }
+            TimeControlCommand::ClearEventSearch => {
+                self.event_search = None;
+                NeedsRepaint::Yes
+            }
             TimeControlCommand::ResetActiveTimeline => {
                 if let Some(blueprint_ctx) = blueprint_ctx {
                     blueprint_ctx.clear_timeline();
@@ -103,6 +103,11 @@ impl ViewOutlineMasks {
 pub struct ViewHighlights {
     pub highlighted_entity_paths: IntMap<EntityPathHash, ViewEntityHighlight>,
     pub outlines_masks: IntMap<EntityPathHash, ViewOutlineMasks>,
+
+    /// Whether outline channel A is used for entities that are always outlined.
+    ///
+    /// Hovered objects are then outlined on channel B, together with the selection.
+    pub any_persistent_highlights: bool,
 }
 
 impl ViewHighlights {
@@ -96,6 +96,12 @@ impl From<re_renderer::renderer::PointCloudDrawDataError> for ViewSystemExecutio
     }
 }
 
+impl From<re_renderer::renderer::GaussianSplatDrawDataError> for ViewSystemExecutionError {
+    fn from(val: re_renderer::renderer::GaussianSplatDrawDataError) -> Self {
+        Self::DrawDataCreationError(Box::new(val))
+    }
+}
+
 impl From<re_types::DeserializationError> for ViewSystemExecutionError {
     fn from(val: re_types::DeserializationError) -> Self {
         Self::DeserializationError(Box::new(val))
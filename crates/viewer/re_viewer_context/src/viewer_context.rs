@@ -14,8 +14,8 @@ use crate::drag_and_drop::DragAndDropPayload;
 use crate::time_control::TimeControlCommand;
 use crate::{
     AppOptions, ApplicationSelectionState, CommandSender, ComponentUiRegistry, DragAndDropManager,
-    IndicatedEntities, ItemCollection, MaybeVisualizableEntities, PerVisualizer, StoreContext,
-    SystemCommandSender as _, TimeControl, ViewClassRegistry, ViewId,
+    EntityTreeSnapshot, IndicatedEntities, ItemCollection, MaybeVisualizableEntities,
+    PerVisualizer, StoreContext, SystemCommandSender as _, TimeControl, ViewClassRegistry, ViewId,
     query_context::DataQueryResult,
 };
 use crate::{DisplayMode, GlobalContext, Item, StorageContext, StoreHub, SystemCommand};
@@ -50,6 +50,11 @@ pub struct ViewerContext<'a> {
     /// All the query results for this frame.
     pub query_results: &'a HashMap<ViewId, DataQueryResult>,
 
+    /// The entity tree of the active recording for this frame.
+    ///
+    /// Prefer this over walking [`EntityDb::tree`] in per-frame code.
+    pub entity_tree: &'a EntityTreeSnapshot,
+
     /// UI config for the current recording (found in [`EntityDb`]).
     pub time_ctrl: &'a TimeControl,
 
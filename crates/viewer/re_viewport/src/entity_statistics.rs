@@ -0,0 +1,200 @@
+//! Debug overlay showing how much data each visible entity contributes to a view.
+
+use std::sync::Arc;
+
+use re_chunk_store::{Chunk, RangeQuery};
+use re_log_types::{AbsoluteTimeRange, EntityPath, TimeInt, TimeType, TimelineName};
+use re_ui::UiExt as _;
+use re_viewer_context::{ViewQuery, ViewerContext};
+
+/// Statistics are gathered over the last second of temporal timelines…
+const TEMPORAL_WINDOW_NS: i64 = 1_000_000_000;
+
+/// …or over the last steps of sequence timelines.
+const SEQUENCE_WINDOW_STEPS: i64 = 100;
+
+/// The time range ending at the time cursor over which data rates are measured.
+struct StatisticsWindow {
+    timeline: TimelineName,
+    range: AbsoluteTimeRange,
+
+    /// Length of the window in `unit`s.
+    length: f64,
+    unit: &'static str,
+
+    description: String,
+}
+
+impl StatisticsWindow {
+    fn new(ctx: &ViewerContext<'_>, query: &ViewQuery<'_>) -> Self {
+        let timeline = query.timeline;
+        let (window, length, unit, description) = match ctx.recording().timeline_type(&timeline) {
+            TimeType::Sequence => (
+                SEQUENCE_WINDOW_STEPS,
+                SEQUENCE_WINDOW_STEPS as f64,
+                "step",
+                format!("Over the last {SEQUENCE_WINDOW_STEPS} steps of '{timeline}'"),
+            ),
+            TimeType::DurationNs | TimeType::TimestampNs => (
+                TEMPORAL_WINDOW_NS,
+                1.0,
+                "s",
+                format!("Over the last second of '{timeline}'"),
+            ),
+        };
+
+        let max = query.latest_at;
+        let min = TimeInt::saturated_temporal_i64(max.as_i64().saturating_sub(window - 1));
+        Self {
+            timeline,
+            range: AbsoluteTimeRange::new(min, max),
+            length,
+            unit,
+            description,
+        }
+    }
+}
+
+/// What a single entity contributes to a view.
+struct EntityStatistics {
+    entity_path: EntityPath,
+
+    /// The largest number of instances of any of its components at the time cursor.
+    ///
+    /// For point clouds this is the number of points, for meshes the number of vertices.
+    num_instances: u64,
+
+    /// Number of rows logged within the window.
+    num_rows: u64,
+
+    /// Estimated size of the rows logged within the window.
+    size_bytes: f64,
+}
+
+impl EntityStatistics {
+    fn new(
+        ctx: &ViewerContext<'_>,
+        query: &ViewQuery<'_>,
+        window: &StatisticsWindow,
+        entity_path: &EntityPath,
+    ) -> Self {
+        let recording = ctx.recording();
+        let engine = recording.storage_engine();
+        let store = engine.store();
+
+        let components = store
+            .all_components_for_entity(entity_path)
+            .unwrap_or_default();
+        let num_instances = recording
+            .latest_at(&query.latest_at_query(), entity_path, components)
+            .components
+            .iter()
+            .map(|(component, unit)| unit.num_instances(*component))
+            .max()
+            .unwrap_or(0);
+
+        let range_query = RangeQuery::new(window.timeline, window.range);
+        let mut num_rows = 0;
+        let mut size_bytes = 0.0;
+        for chunk in
+            store.range_relevant_chunks_for_all_components(&range_query, entity_path, false)
+        {
+            let rows_in_window = rows_in_range(&chunk, &window.timeline, window.range);
+            if rows_in_window == 0 {
+                continue;
+            }
+
+            // Chunks may extend past the window, so their size is split evenly among their rows.
+            // NOTE: Do _NOT_ use `chunk.total_size_bytes` as it is sitting behind an Arc
+            // and would count as amortized (i.e. 0 bytes).
+            let chunk_size_bytes = <Chunk as re_byte_size::SizeBytes>::total_size_bytes(&*chunk);
+            num_rows += rows_in_window;
+            size_bytes +=
+                chunk_size_bytes as f64 * rows_in_window as f64 / chunk.num_rows().max(1) as f64;
+        }
+
+        Self {
+            entity_path: entity_path.clone(),
+            num_instances,
+            num_rows,
+            size_bytes,
+        }
+    }
+}
+
+fn rows_in_range(chunk: &Arc<Chunk>, timeline: &TimelineName, range: AbsoluteTimeRange) -> u64 {
+    chunk.timelines().get(timeline).map_or(0, |time_column| {
+        time_column
+            .times_raw()
+            .iter()
+            .filter(|time| (range.min().as_i64()..=range.max().as_i64()).contains(time))
+            .count() as u64
+    })
+}
+
+/// Shows the number of instances and data rate of every visible entity in a corner of the view.
+///
+/// The entities logging the most data come first.
+pub fn entity_statistics_overlay_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    query: &ViewQuery<'_>,
+) {
+    re_tracing::profile_function!();
+
+    let window = StatisticsWindow::new(ctx, query);
+
+    let mut entity_paths = query
+        .iter_all_data_results()
+        .filter(|data_result| data_result.is_visible() && !data_result.visualizers.is_empty())
+        .map(|data_result| &data_result.entity_path)
+        .collect::<Vec<_>>();
+    entity_paths.sort();
+    entity_paths.dedup();
+
+    let mut statistics = entity_paths
+        .into_iter()
+        .map(|entity_path| EntityStatistics::new(ctx, query, &window, entity_path))
+        .collect::<Vec<_>>();
+    statistics.sort_by(|a, b| {
+        b.size_bytes
+            .total_cmp(&a.size_bytes)
+            .then_with(|| a.entity_path.cmp(&b.entity_path))
+    });
+
+    let tokens = ui.tokens();
+    let overlay_rect = ui.max_rect().shrink(f32::from(tokens.view_padding()));
+    ui.scope_builder(
+        egui::UiBuilder::new()
+            .max_rect(overlay_rect)
+            .layout(egui::Layout::top_down(egui::Align::Max)),
+        |ui| {
+            egui::Frame::popup(ui.style()).show(ui, |ui| {
+                ui.weak(&window.description);
+
+                egui::Grid::new(("entity_statistics", query.view_id))
+                    .num_columns(4)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        ui.strong("Entity");
+                        ui.strong("Instances");
+                        ui.strong(format!("Rows/{}", window.unit));
+                        ui.strong(format!("Data/{}", window.unit));
+                        ui.end_row();
+
+                        for statistics in &statistics {
+                            ui.label(statistics.entity_path.to_string());
+                            ui.monospace(re_format::format_uint(statistics.num_instances));
+                            ui.monospace(re_format::format_f64(
+                                statistics.num_rows as f64 / window.length,
+                            ));
+                            ui.monospace(re_format::format_bytes(
+                                statistics.size_bytes / window.length,
+                            ));
+                            ui.end_row();
+                        }
+                    });
+            });
+        },
+    );
+}
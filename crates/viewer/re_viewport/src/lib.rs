@@ -4,6 +4,7 @@
 
 #![warn(clippy::iter_over_hash_type)] //  TODO(#6198): enable everywhere
 
+mod entity_statistics;
 mod system_execution;
 mod view_highlights;
 mod viewport_ui;
@@ -72,7 +72,7 @@ pub fn execute_systems_for_view<'a>(
 ) -> (ViewQuery<'a>, SystemExecutionOutput) {
     re_tracing::profile_function!(view.class_identifier().as_str());
 
-    let highlights = highlights_for_view(ctx, view.id);
+    let highlights = highlights_for_view(ctx, view);
 
     let query_result = ctx.lookup_query_result(view.id);
 
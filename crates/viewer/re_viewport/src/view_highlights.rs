@@ -2,23 +2,31 @@ use egui::NumExt as _;
 use nohash_hasher::IntMap;
 use re_entity_db::InstancePath;
 
-use re_log_types::EntityPathHash;
+use re_log_types::{EntityPath, EntityPathFilter, EntityPathHash, EntityPathSubs};
 use re_renderer::OutlineMaskPreference;
+use re_types::blueprint::{archetypes::Outlines, components::QueryExpression};
 use re_viewer_context::{
-    HoverHighlight, Item, SelectionHighlight, ViewEntityHighlight, ViewHighlights, ViewId,
-    ViewOutlineMasks,
+    HoverHighlight, Item, SelectionHighlight, ViewEntityHighlight, ViewHighlights, ViewOutlineMasks,
 };
+use re_viewport_blueprint::{ViewBlueprint, ViewProperty};
 
 /// Computes which things in a view should received highlighting.
 ///
 /// This method makes decisions which entities & instances should which kind of highlighting
-/// based on the entities in a view and the current selection/hover state.
+/// based on the entities in a view, the current selection/hover state
+/// and the entities the view's [`Outlines`] property always outlines.
 pub fn highlights_for_view(
     ctx: &re_viewer_context::ViewerContext<'_>,
-    view_id: ViewId,
+    view: &ViewBlueprint,
 ) -> ViewHighlights {
     re_tracing::profile_function!();
 
+    let view_id = view.id;
+
+    // Entities that are always outlined take channel A, so hovered objects move to channel B.
+    let persistently_highlighted = persistently_highlighted_entities(ctx, view);
+    let any_persistent_highlights = !persistently_highlighted.is_empty();
+
     let mut highlighted_entity_paths = IntMap::<EntityPathHash, ViewEntityHighlight>::default();
     let mut outlines_masks = IntMap::<EntityPathHash, ViewOutlineMasks>::default();
 
@@ -80,8 +88,13 @@ pub fn highlights_for_view(
     let mut hover_mask_index: u8 = 0;
     let mut next_hover_mask = || {
         // We don't expect to overflow u8, but if we do, don't use the "background mask".
-        hover_mask_index = hover_mask_index.wrapping_add(1).at_least(1);
-        OutlineMaskPreference::some(hover_mask_index, 0)
+        if any_persistent_highlights {
+            selection_mask_index = selection_mask_index.wrapping_add(1).at_least(1);
+            OutlineMaskPreference::some(0, selection_mask_index)
+        } else {
+            hover_mask_index = hover_mask_index.wrapping_add(1).at_least(1);
+            OutlineMaskPreference::some(hover_mask_index, 0)
+        }
     };
 
     for current_hover in ctx.selection_state().hovered_items().iter_items() {
@@ -123,8 +136,50 @@ pub fn highlights_for_view(
         }
     }
 
+    let mut persistent_mask_index: u8 = 0;
+    for entity_path in persistently_highlighted {
+        persistent_mask_index = persistent_mask_index.wrapping_add(1).at_least(1);
+        outlines_masks.entry(entity_path.hash()).or_default().add(
+            &InstancePath::entity_all(entity_path),
+            OutlineMaskPreference::some(persistent_mask_index, 0),
+        );
+    }
+
     ViewHighlights {
         highlighted_entity_paths,
         outlines_masks,
+        any_persistent_highlights,
     }
 }
+
+/// Visible entities of the view that match the query expressions of its [`Outlines`] property.
+fn persistently_highlighted_entities(
+    ctx: &re_viewer_context::ViewerContext<'_>,
+    view: &ViewBlueprint,
+) -> Vec<EntityPath> {
+    let property =
+        ViewProperty::from_archetype::<Outlines>(ctx.blueprint_db(), ctx.blueprint_query, view.id);
+    let expressions = property
+        .component_array_or_empty::<QueryExpression>(Outlines::descriptor_highlighted().component)
+        .unwrap_or_default();
+    if expressions.is_empty() {
+        return Vec::new();
+    }
+
+    let filter =
+        EntityPathFilter::from_query_expressions(expressions.iter().map(|qe| qe.0.as_str()))
+            .resolve_forgiving(&EntityPathSubs::new_with_origin(&view.space_origin));
+
+    let mut entities = Vec::new();
+    ctx.lookup_query_result(view.id).tree.visit(&mut |node| {
+        let data_result = &node.data_result;
+        if !data_result.visualizers.is_empty()
+            && data_result.is_visible()
+            && filter.matches(&data_result.entity_path)
+        {
+            entities.push(data_result.entity_path.clone());
+        }
+        true
+    });
+    entities
+}
@@ -16,7 +16,8 @@ use re_viewer_context::{
     icon_for_container_kind,
 };
 use re_viewport_blueprint::{
-    ViewBlueprint, ViewportBlueprint, ViewportCommand, create_entity_add_info,
+    ViewBlueprint, ViewportBlueprint, ViewportCommand, VisibilityRuleSettings,
+    create_entity_add_info,
 };
 
 use crate::system_execution::{execute_systems_for_all_views, execute_systems_for_view};
@@ -68,6 +69,9 @@ impl ViewportUi {
             .unwrap_or_default()
             .animated_view_and_rect(ui.ctx(), ui.max_rect());
 
+        // Views whose visibility rules aren't met are only hidden for this frame.
+        let mut hidden_by_rules = Vec::new();
+
         let mut tree = if let Some(view_id) = blueprint.maximized.or(animating_view_id) {
             let mut tiles = egui_tiles::Tiles::default();
 
@@ -77,7 +81,9 @@ impl ViewportUi {
             tiles.insert(tile_id, egui_tiles::Tile::Pane(view_id));
             egui_tiles::Tree::new("viewport_tree", tile_id, tiles)
         } else {
-            blueprint.tree.clone()
+            let mut tree = blueprint.tree.clone();
+            hidden_by_rules = hide_views_by_visibility_rules(ctx, blueprint, &mut tree);
+            tree
         };
 
         let executed_systems_per_view =
@@ -224,6 +230,11 @@ impl ViewportUi {
                         });
                     }
 
+                    // Don't persist the visibility that comes from the visibility rules.
+                    for tile_id in hidden_by_rules {
+                        tree.set_visible(tile_id, true);
+                    }
+
                     self.blueprint
                         .deferred_commands
                         .lock()
@@ -313,6 +324,38 @@ impl ViewportUi {
     }
 }
 
+/// Hides the tiles of all views whose [`re_types::blueprint::archetypes::VisibilityRules`] aren't met.
+///
+/// Returns the hidden tiles.
+fn hide_views_by_visibility_rules(
+    ctx: &ViewerContext<'_>,
+    blueprint: &ViewportBlueprint,
+    tree: &mut egui_tiles::Tree<ViewId>,
+) -> Vec<egui_tiles::TileId> {
+    re_tracing::profile_function!();
+
+    let time_query = ctx.current_query();
+
+    blueprint
+        .views
+        .keys()
+        .filter_map(|view_id| {
+            let tile_id = tree.tiles.find_pane(view_id)?;
+            let is_hidden = tree.tiles.is_visible(tile_id)
+                && VisibilityRuleSettings::from_view(
+                    ctx.blueprint_db(),
+                    ctx.blueprint_query,
+                    *view_id,
+                )
+                .is_some_and(|rules| rules.is_hidden(ctx.recording(), &time_query));
+            is_hidden.then(|| {
+                tree.set_visible(tile_id, false);
+                tile_id
+            })
+        })
+        .collect()
+}
+
 // ----------------------------------------------------------------------------
 
 /// `egui_tiles` has _tiles_ which are either _containers_ or _panes_.
@@ -415,6 +458,10 @@ impl<'a> egui_tiles::Behavior<ViewId> for TilesDelegate<'a, '_> {
                     );
                 });
 
+            if self.ctx.app_options().show_entity_statistics_overlay {
+                crate::entity_statistics::entity_statistics_overlay_ui(self.ctx, ui, &query);
+            }
+
             ui.ctx().memory_mut(|mem| {
                 mem.caches
                     .cache::<re_viewer_context::ViewRectPublisher>()
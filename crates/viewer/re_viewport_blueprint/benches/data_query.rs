@@ -11,8 +11,8 @@ use re_entity_db::EntityDb;
 use re_log_types::{EntityPath, EntityPathFilter, EntityPathSubs, StoreId, TimePoint, Timeline};
 use re_types::{archetypes::Points2D, components::Position2D};
 use re_viewer_context::{
-    Caches, PerVisualizer, StoreContext, ViewClassRegistry, VisualizableEntities,
-    blueprint_timeline,
+    Caches, EntityTreeSnapshot, PerVisualizer, StoreContext, ViewClassRegistry,
+    VisualizableEntities, blueprint_timeline,
 };
 use re_viewport_blueprint::ViewContents;
 
@@ -65,6 +65,8 @@ fn query_tree_many_entities(c: &mut Criterion) {
         should_enable_heuristics: false,
     };
 
+    let entity_tree = EntityTreeSnapshot::from_entity_db(&recording);
+
     let view_class_registry = ViewClassRegistry::default();
     let blueprint_query = LatestAtQuery::latest(blueprint_timeline());
 
@@ -82,6 +84,7 @@ fn query_tree_many_entities(c: &mut Criterion) {
                     &ctx,
                     &view_class_registry,
                     &blueprint_query,
+                    &entity_tree,
                     &visualizable_entities,
                 )
             });
@@ -111,6 +114,7 @@ fn query_tree_many_entities(c: &mut Criterion) {
                     &ctx,
                     &view_class_registry,
                     &blueprint_query,
+                    &entity_tree,
                     &visualizable_entities,
                 )
             });
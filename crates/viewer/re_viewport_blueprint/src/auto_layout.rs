@@ -6,8 +6,9 @@ use std::collections::BTreeMap;
 
 use itertools::Itertools as _;
 
+use re_log_types::EntityPath;
 use re_types::ViewClassIdentifier;
-use re_viewer_context::ViewId;
+use re_viewer_context::{ContainerId, ViewId, blueprint_id_to_tile_id};
 
 use crate::ViewBlueprint;
 
@@ -97,6 +98,139 @@ pub(crate) fn tree_from_views(
     egui_tiles::Tree::new("viewport_tree", root, tiles)
 }
 
+/// Lays out the views as a dashboard.
+///
+/// Views are grouped by namespace (the first part of their space origin), with one tab per
+/// namespace. Within a namespace, views of the same class are kept together, with the classes
+/// that need the most space first.
+///
+/// Returns the tree, as well as the containers that should be named after their namespace.
+pub(crate) fn dashboard_from_views(
+    view_class_registry: &re_viewer_context::ViewClassRegistry,
+    views: &BTreeMap<ViewId, ViewBlueprint>,
+) -> (egui_tiles::Tree<ViewId>, Vec<(ContainerId, String)>) {
+    re_log::trace!("Dashboard layout of {} views", views.len());
+
+    let mut grouped_by_namespace: BTreeMap<EntityPath, Vec<SpaceMakeInfo>> = Default::default();
+    for (view_id, view) in views
+        .iter()
+        // Sort for determinism:
+        .sorted_by_key(|(view_id, view)| (&view.space_origin, &view.display_name, *view_id))
+    {
+        let namespace = EntityPath::new(view.space_origin.iter().take(1).cloned().collect());
+        grouped_by_namespace
+            .entry(namespace)
+            .or_default()
+            .push(SpaceMakeInfo {
+                id: *view_id,
+                class_identifier: view.class_identifier(),
+                layout_priority: view.class(view_class_registry).layout_priority(),
+            });
+    }
+
+    let mut tiles = egui_tiles::Tiles::default();
+    let mut named_containers = Vec::new();
+
+    let namespace_tiles = grouped_by_namespace
+        .into_iter()
+        .map(|(namespace, space_make_infos)| {
+            let mut grouped_by_class: BTreeMap<ViewClassIdentifier, Vec<SpaceMakeInfo>> =
+                Default::default();
+            for smi in space_make_infos {
+                grouped_by_class
+                    .entry(smi.class_identifier)
+                    .or_default()
+                    .push(smi);
+            }
+
+            let mut groups = grouped_by_class
+                .into_values()
+                .sorted_by_key(|group| -(group[0].layout_priority as isize))
+                .collect_vec();
+
+            let name = namespace.to_string();
+            if groups.len() == 1 {
+                let group = groups.remove(0);
+                if group.len() == 1 {
+                    tiles.insert_pane(group[0].id)
+                } else {
+                    let children = group
+                        .into_iter()
+                        .map(|smi| tiles.insert_pane(smi.id))
+                        .collect_vec();
+                    insert_named_container(
+                        &mut tiles,
+                        &mut named_containers,
+                        class_group_container(children),
+                        name,
+                    )
+                }
+            } else {
+                let children = groups
+                    .into_iter()
+                    .map(|group| {
+                        let children = group
+                            .into_iter()
+                            .map(|smi| tiles.insert_pane(smi.id))
+                            .collect_vec();
+                        if children.len() == 1 {
+                            children[0]
+                        } else {
+                            tiles.insert_new(egui_tiles::Tile::Container(class_group_container(
+                                children,
+                            )))
+                        }
+                    })
+                    .collect_vec();
+                insert_named_container(
+                    &mut tiles,
+                    &mut named_containers,
+                    egui_tiles::Container::new_grid(children),
+                    name,
+                )
+            }
+        })
+        .collect_vec();
+
+    let root = if namespace_tiles.len() <= 1 {
+        namespace_tiles.first().copied()
+    } else {
+        Some(tiles.insert_tab_tile(namespace_tiles))
+    };
+
+    let tree = match root {
+        Some(root) => egui_tiles::Tree::new("viewport_tree", root, tiles),
+        None => egui_tiles::Tree::empty("viewport_tree"),
+    };
+
+    (tree, named_containers)
+}
+
+/// A few views of the same class are shown side by side, more than that go into tabs.
+fn class_group_container(children: Vec<egui_tiles::TileId>) -> egui_tiles::Container {
+    const MAX_VIEWS_IN_GRID: usize = 4;
+
+    if children.len() <= MAX_VIEWS_IN_GRID {
+        egui_tiles::Container::new_grid(children)
+    } else {
+        egui_tiles::Container::new_tabs(children)
+    }
+}
+
+/// Inserts a container whose tile id matches a new [`ContainerId`], so that it can be named.
+fn insert_named_container(
+    tiles: &mut egui_tiles::Tiles<ViewId>,
+    named_containers: &mut Vec<(ContainerId, String)>,
+    container: egui_tiles::Container,
+    name: String,
+) -> egui_tiles::TileId {
+    let container_id = ContainerId::random();
+    let tile_id = blueprint_id_to_tile_id(&container_id);
+    tiles.insert(tile_id, egui_tiles::Tile::Container(container));
+    named_containers.push((container_id, name));
+    tile_id
+}
+
 fn arrange_three(
     mut spaces: [SpaceMakeInfo; 3],
     tiles: &mut egui_tiles::Tiles<ViewId>,
@@ -11,6 +11,7 @@ mod view_contents;
 mod view_properties;
 mod viewport_blueprint;
 mod viewport_command;
+mod visibility_rules;
 
 pub use container::ContainerBlueprint;
 pub use entity_add_info::{CanAddToView, EntityAddInfo, create_entity_add_info};
@@ -21,6 +22,7 @@ pub use view_contents::{DataQueryPropertyResolver, ViewContents};
 pub use view_properties::{ViewProperty, ViewPropertyQueryError, entity_path_for_view_property};
 pub use viewport_blueprint::{ViewportBlueprint, tree_simplification_options};
 pub use viewport_command::ViewportCommand;
+pub use visibility_rules::{ScalarRule, VisibilityRuleSettings, apply_entity_visibility_rules};
 
 /// The entity path of the viewport blueprint in the blueprint store.
 pub const VIEWPORT_PATH: &str = "viewport";
@@ -0,0 +1,304 @@
+//! Modal for comparing the active blueprint with another blueprint of the same app, and merging
+//! some of the differences into the active blueprint.
+
+use std::collections::HashSet;
+
+use re_chunk::{ComponentIdentifier, EntityPath};
+use re_log_types::{StoreId, StoreKind};
+use re_viewer_context::{
+    BlueprintChange, BlueprintChangeKind, BlueprintChangeSubject, BlueprintDiff, ViewId,
+    ViewerContext,
+};
+
+use crate::ViewportBlueprint;
+
+#[derive(Default)]
+pub struct BlueprintDiffModal {
+    /// The blueprint to compare the active blueprint with.
+    ///
+    /// Defaults to the default blueprint of the app.
+    other_blueprint: Option<StoreId>,
+
+    /// Changes the user doesn't want to merge.
+    excluded: HashSet<(EntityPath, ComponentIdentifier)>,
+
+    modal_handler: re_ui::modal::ModalHandler,
+}
+
+impl BlueprintDiffModal {
+    pub(crate) fn open(&mut self) {
+        self.other_blueprint = None;
+        self.excluded.clear();
+        self.modal_handler.open();
+    }
+
+    pub(crate) fn ui(
+        &mut self,
+        egui_ctx: &egui::Context,
+        ctx: &ViewerContext<'_>,
+        viewport: &ViewportBlueprint,
+    ) {
+        let Self {
+            other_blueprint,
+            excluded,
+            modal_handler,
+        } = self;
+
+        modal_handler.ui(
+            egui_ctx,
+            || {
+                re_ui::modal::ModalWrapper::new("Compare blueprints")
+                    .min_width(500.0)
+                    .scrollable([false, true])
+            },
+            |ui| modal_ui(ui, ctx, viewport, other_blueprint, excluded),
+        );
+    }
+}
+
+fn modal_ui(
+    ui: &mut egui::Ui,
+    ctx: &ViewerContext<'_>,
+    viewport: &ViewportBlueprint,
+    other_blueprint: &mut Option<StoreId>,
+    excluded: &mut HashSet<(EntityPath, ComponentIdentifier)>,
+) {
+    let current_blueprint = ctx.store_context.blueprint;
+    let default_blueprint_id = ctx
+        .storage_context
+        .hub
+        .default_blueprint_id_for_app(ctx.store_context.application_id());
+
+    // The default blueprint goes first, then all other blueprints of this app.
+    let candidates = default_blueprint_id
+        .into_iter()
+        .chain(
+            ctx.storage_context
+                .bundle
+                .entity_dbs()
+                .filter(|db| {
+                    db.store_kind() == StoreKind::Blueprint
+                        && db.application_id() == ctx.store_context.application_id()
+                })
+                .map(|db| db.store_id())
+                .filter(|store_id| Some(*store_id) != default_blueprint_id),
+        )
+        .filter(|store_id| *store_id != current_blueprint.store_id())
+        .cloned()
+        .collect::<Vec<_>>();
+
+    if other_blueprint
+        .as_ref()
+        .is_none_or(|store_id| !candidates.contains(store_id))
+    {
+        *other_blueprint = candidates.first().cloned();
+    }
+
+    let Some(other) = other_blueprint
+        .as_ref()
+        .and_then(|store_id| ctx.storage_context.bundle.get(store_id))
+    else {
+        ui.label("There is no other blueprint for this app to compare with.");
+        return;
+    };
+
+    let blueprint_label = |store_id: &StoreId| {
+        if Some(store_id) == default_blueprint_id {
+            "Default blueprint".to_owned()
+        } else {
+            format!("Blueprint {}", store_id.recording_id())
+        }
+    };
+
+    ui.horizontal(|ui| {
+        ui.label("Compare the active blueprint with");
+        egui::ComboBox::from_id_salt("blueprint_diff_other")
+            .selected_text(blueprint_label(other.store_id()))
+            .show_ui(ui, |ui| {
+                for store_id in &candidates {
+                    if ui
+                        .selectable_label(other.store_id() == store_id, blueprint_label(store_id))
+                        .clicked()
+                    {
+                        *other_blueprint = Some(store_id.clone());
+                        excluded.clear();
+                    }
+                }
+            });
+    });
+
+    ui.add_space(8.0);
+
+    let diff = BlueprintDiff::new(current_blueprint, ctx.blueprint_query, other);
+    if diff.is_empty() {
+        ui.label("The blueprints are identical.");
+        return;
+    }
+
+    ui.label(
+        "Checked changes are merged into the active blueprint, \
+        i.e. the active blueprint takes the value of the other blueprint.",
+    );
+
+    for (subject, changes) in diff.changes_per_subject() {
+        let key = |change: &BlueprintChange| {
+            (change.entity_path.clone(), change.component_descr.component)
+        };
+
+        let num_included = changes
+            .iter()
+            .filter(|change| !excluded.contains(&key(change)))
+            .count();
+        let mut all_included = num_included == changes.len();
+
+        ui.horizontal(|ui| {
+            let response = ui.add(
+                egui::Checkbox::new(&mut all_included, "")
+                    .indeterminate(0 < num_included && num_included < changes.len()),
+            );
+            if response.changed() {
+                for change in &changes {
+                    if all_included {
+                        excluded.remove(&key(change));
+                    } else {
+                        excluded.insert(key(change));
+                    }
+                }
+            }
+            ui.strong(subject_label(viewport, &subject));
+        });
+
+        ui.indent(egui::Id::new(&subject), |ui| {
+            for change in changes {
+                let mut included = !excluded.contains(&key(change));
+                let response = ui
+                    .checkbox(&mut included, change_label(&subject, change))
+                    .on_hover_ui(|ui| change_tooltip_ui(ui, change));
+                if response.changed() {
+                    if included {
+                        excluded.remove(&key(change));
+                    } else {
+                        excluded.insert(key(change));
+                    }
+                }
+            }
+        });
+    }
+
+    ui.add_space(8.0);
+
+    let selected_changes = diff
+        .changes
+        .iter()
+        .filter(|change| {
+            !excluded.contains(&(change.entity_path.clone(), change.component_descr.component))
+        })
+        .collect::<Vec<_>>();
+
+    ui.horizontal(|ui| {
+        if ui
+            .add_enabled(
+                !selected_changes.is_empty(),
+                egui::Button::new(format!(
+                    "Merge {} selected change{}",
+                    selected_changes.len(),
+                    if selected_changes.len() == 1 { "" } else { "s" }
+                )),
+            )
+            .clicked()
+        {
+            for change in &selected_changes {
+                change.merge(ctx);
+            }
+            viewport.mark_user_interaction(ctx);
+            excluded.clear();
+            ui.close();
+        }
+
+        if ui.button("Cancel").clicked() {
+            ui.close();
+        }
+    });
+}
+
+fn subject_label(viewport: &ViewportBlueprint, subject: &BlueprintChangeSubject) -> String {
+    let view_name = |view_id: &ViewId| {
+        viewport.view(view_id).map_or_else(
+            || view_id.to_string(),
+            |view| view.display_name_or_default().to_string(),
+        )
+    };
+
+    match subject {
+        BlueprintChangeSubject::Viewport => "Viewport".to_owned(),
+        BlueprintChangeSubject::Container(container_id) => {
+            viewport.container(container_id).map_or_else(
+                || container_id.to_string(),
+                |container| container.display_name_or_default().to_string(),
+            )
+        }
+        BlueprintChangeSubject::View(view_id) => view_name(view_id),
+        BlueprintChangeSubject::ViewProperty { view_id, name } => {
+            format!("{}: {name}", view_name(view_id))
+        }
+        BlueprintChangeSubject::Override {
+            view_id,
+            entity_path,
+        } => format!("{}: overrides of {entity_path}", view_name(view_id)),
+        BlueprintChangeSubject::Other(entity_path) => entity_path.to_string(),
+    }
+}
+
+fn change_label(subject: &BlueprintChangeSubject, change: &BlueprintChange) -> String {
+    let kind = match change.kind {
+        BlueprintChangeKind::Added => "added",
+        BlueprintChangeKind::Removed => "removed",
+        BlueprintChangeKind::Modified => "changed",
+    };
+
+    // Views and properties can have sub-entities, e.g. the `ViewContents`.
+    let entity_path = match subject {
+        BlueprintChangeSubject::ViewProperty { .. } => change
+            .entity_path
+            .last()
+            .map(|part| format!("{}: ", part.ui_string()))
+            .unwrap_or_default(),
+        _ => String::new(),
+    };
+
+    format!(
+        "{entity_path}{} ({kind})",
+        change.component_descr.display_name()
+    )
+}
+
+fn change_tooltip_ui(ui: &mut egui::Ui, change: &BlueprintChange) {
+    ui.label(change.entity_path.to_string());
+    ui.label(format!(
+        "Active: {}",
+        format_value(change.current_value.as_ref())
+    ));
+    ui.label(format!(
+        "Other: {}",
+        format_value(change.other_value.as_ref())
+    ));
+}
+
+fn format_value(value: Option<&arrow::array::ArrayRef>) -> String {
+    let Some(value) = value else {
+        return "(unset)".to_owned();
+    };
+
+    let values = (0..value.len())
+        .map(|index| {
+            arrow::util::display::array_value_to_string(value, index)
+                .unwrap_or_else(|err| format!("<{err}>"))
+        })
+        .collect::<Vec<_>>();
+
+    if values.len() == 1 {
+        values[0].clone()
+    } else {
+        format!("[{}]", values.join(", "))
+    }
+}
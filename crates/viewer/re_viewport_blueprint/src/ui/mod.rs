@@ -1,6 +1,6 @@
 //! UI utilities related to the viewport blueprint.
 //!
-//! Current this is mainly the add view or container modal.
+//! Current this is mainly the add view or container modal, and the blueprint diff modal.
 
 use parking_lot::Mutex;
 
@@ -8,8 +8,10 @@ use re_viewer_context::{ContainerId, ViewerContext};
 
 use crate::ViewportBlueprint;
 mod add_view_or_container_modal;
+mod blueprint_diff_modal;
 
 use add_view_or_container_modal::AddViewOrContainerModal;
+use blueprint_diff_modal::BlueprintDiffModal;
 
 static ADD_VIEW_OR_CONTAINER_MODAL: std::sync::LazyLock<Mutex<AddViewOrContainerModal>> =
     std::sync::LazyLock::new(|| Mutex::new(AddViewOrContainerModal::default()));
@@ -28,3 +30,21 @@ pub fn add_view_or_container_modal_ui(
 pub fn show_add_view_or_container_modal(target_container: ContainerId) {
     ADD_VIEW_OR_CONTAINER_MODAL.lock().open(target_container);
 }
+
+static BLUEPRINT_DIFF_MODAL: std::sync::LazyLock<Mutex<BlueprintDiffModal>> =
+    std::sync::LazyLock::new(|| Mutex::new(BlueprintDiffModal::default()));
+
+pub fn blueprint_diff_modal_ui(
+    ctx: &ViewerContext<'_>,
+    viewport: &ViewportBlueprint,
+    ui: &egui::Ui,
+) {
+    // give a chance to the modal to be drawn
+    BLUEPRINT_DIFF_MODAL.lock().ui(ui.ctx(), ctx, viewport);
+}
+
+/// Opens a modal comparing the active blueprint with the default blueprint of the app,
+/// or any other of its blueprints.
+pub fn show_blueprint_diff_modal() {
+    BLUEPRINT_DIFF_MODAL.lock().open();
+}
@@ -765,6 +765,7 @@ mod tests {
                 ctx.store_context,
                 &test_ctx.view_class_registry,
                 &test_ctx.blueprint_query,
+                ctx.entity_tree,
                 visualizable_entities,
             );
             let mut view_states = ViewStates::default();
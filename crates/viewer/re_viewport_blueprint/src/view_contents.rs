@@ -6,7 +6,7 @@ use parking_lot::Mutex;
 use slotmap::SlotMap;
 use smallvec::SmallVec;
 
-use re_entity_db::{EntityDb, EntityTree, external::re_chunk_store::LatestAtQuery};
+use re_entity_db::{EntityDb, external::re_chunk_store::LatestAtQuery};
 use re_log_types::{
     EntityPath, EntityPathFilter, EntityPathHash, EntityPathSubs, ResolvedEntityPathFilter,
     ResolvedEntityPathRule, Timeline, path::RuleEffect,
@@ -21,16 +21,16 @@ use re_types::{
 };
 use re_viewer_context::{
     DataQueryResult, DataResult, DataResultHandle, DataResultNode, DataResultTree,
-    IndicatedEntities, MaybeVisualizableEntities, OverridePath, PerVisualizer, PropertyOverrides,
-    QueryRange, ViewClassRegistry, ViewId, ViewState, ViewSystemIdentifier, ViewerContext,
-    VisualizableEntities,
+    EntityTreeSnapshot, IndicatedEntities, MaybeVisualizableEntities, OverridePath, PerVisualizer,
+    PropertyOverrides, QueryRange, ViewClassRegistry, ViewId, ViewState, ViewSystemIdentifier,
+    ViewerContext, VisualizableEntities,
 };
 
 use crate::{ViewBlueprint, ViewProperty};
 
 /// Data to be added to a view, built from a [`blueprint_archetypes::ViewContents`].
 ///
-/// During execution, it will walk an [`EntityTree`] and return a [`DataResultTree`]
+/// During execution, it will walk an [`EntityTreeSnapshot`] and return a [`DataResultTree`]
 /// containing any entities that match a [`ResolvedEntityPathFilter`].
 ///
 /// Note: [`ViewContents`] doesn't implement Clone because it depends on its parent's [`ViewId`]
@@ -277,6 +277,7 @@ impl ViewContents {
         ctx: &re_viewer_context::StoreContext<'_>,
         view_class_registry: &re_viewer_context::ViewClassRegistry,
         blueprint_query: &LatestAtQuery,
+        entity_tree: &EntityTreeSnapshot,
         visualizable_entities_for_visualizer_systems: &PerVisualizer<VisualizableEntities>,
     ) -> DataQueryResult {
         re_tracing::profile_function!();
@@ -294,10 +295,13 @@ impl ViewContents {
 
         let mut num_matching_entities = 0;
         let mut num_visualized_entities = 0;
-        let root_handle = {
+        let root_handle = if entity_tree.entities().is_empty() {
+            None
+        } else {
             re_tracing::profile_scope!("add_entity_tree_to_data_results_recursive");
             executor.add_entity_tree_to_data_results_recursive(
-                ctx.recording.tree(),
+                entity_tree,
+                0,
                 &mut data_results,
                 &mut num_matching_entities,
                 &mut num_visualized_entities,
@@ -371,12 +375,14 @@ struct QueryExpressionEvaluator<'a> {
 impl QueryExpressionEvaluator<'_> {
     fn add_entity_tree_to_data_results_recursive(
         &self,
-        tree: &EntityTree,
+        entity_tree: &EntityTreeSnapshot,
+        index: usize,
         data_results: &mut SlotMap<DataResultHandle, DataResultNode>,
         num_matching_entities: &mut usize,
         num_visualized_entities: &mut usize,
     ) -> Option<DataResultHandle> {
-        let filter_evaluation = self.entity_path_filter.evaluate(&tree.path);
+        let entity_path = &entity_tree.entities()[index].path;
+        let filter_evaluation = self.entity_path_filter.evaluate(entity_path);
 
         // Early-out optimization
         if !filter_evaluation.subtree_included {
@@ -385,8 +391,6 @@ impl QueryExpressionEvaluator<'_> {
 
         // TODO(jleibs): If this space is disconnected, we should terminate here
 
-        let entity_path = &tree.path;
-
         let matches_filter = filter_evaluation.matches;
         *num_matching_entities += matches_filter as usize;
 
@@ -402,12 +406,12 @@ impl QueryExpressionEvaluator<'_> {
         };
         *num_visualized_entities += !visualizers.is_empty() as usize;
 
-        let children: SmallVec<[_; 4]> = tree
-            .children
-            .values()
-            .filter_map(|subtree| {
+        let children: SmallVec<[_; 4]> = entity_tree
+            .children(index)
+            .filter_map(|child| {
                 self.add_entity_tree_to_data_results_recursive(
-                    subtree,
+                    entity_tree,
+                    child,
                     data_results,
                     num_matching_entities,
                     num_visualized_entities,
@@ -718,6 +722,7 @@ mod tests {
             caches: &Caches::new(recording.store_id().clone()),
             should_enable_heuristics: false,
         };
+        let entity_tree = EntityTreeSnapshot::from_entity_db(&recording);
 
         struct Scenario {
             filter: &'static str,
@@ -815,6 +820,7 @@ mod tests {
                 &ctx,
                 &view_class_registry,
                 &LatestAtQuery::latest(blueprint_timeline()),
+                &entity_tree,
                 &visualizable_entities_for_visualizer_systems,
             );
 
@@ -15,7 +15,7 @@ use smallvec::SmallVec;
 
 use re_chunk_store::LatestAtQuery;
 use re_entity_db::EntityPath;
-use re_log_types::{EntityPathHash, EntityPathSubs};
+use re_log_types::{EntityPathHash, EntityPathSubs, ResolvedEntityPathFilter};
 use re_types::blueprint::{
     archetypes as blueprint_archetypes,
     components::{AutoLayout, AutoViews, RootContainer, ViewMaximized},
@@ -24,8 +24,8 @@ use re_types::{
     Archetype as _, ViewClassIdentifier, blueprint::components::ViewerRecommendationHash,
 };
 use re_viewer_context::{
-    BlueprintContext as _, ContainerId, Contents, Item, ViewId, ViewerContext, VisitorControlFlow,
-    blueprint_id_to_tile_id,
+    BlueprintContext as _, ContainerId, Contents, Item, RecommendedView, ViewId, ViewerContext,
+    VisitorControlFlow, blueprint_id_to_tile_id,
 };
 
 use crate::{VIEWPORT_PATH, ViewBlueprint, ViewportCommand, container::ContainerBlueprint};
@@ -317,7 +317,7 @@ impl ViewportBlueprint {
         for entry in ctx.view_class_registry().iter_registry() {
             let class_id = entry.identifier;
 
-            let excluded_entities = re_log_types::ResolvedEntityPathFilter::properties();
+            let excluded_entities = ResolvedEntityPathFilter::properties();
             let include_entity = |ent: &EntityPath| !excluded_entities.matches(ent);
 
             let mut recommended_views = entry
@@ -393,29 +393,55 @@ impl ViewportBlueprint {
 
             // Remove all views that are redundant within the remaining recommendation.
             // This n^2 loop should only run ever for frames that add new views.
-            let final_recommendations = recommended_views
-                .iter()
-                .enumerate()
-                .filter(|(j, (candidate_query_filter, _))| {
-                    recommended_views
-                        .iter()
-                        .enumerate()
-                        .all(|(i, (other_query_filter, _))| {
-                            i == *j || !other_query_filter.is_superset_of(candidate_query_filter)
-                        })
-                })
-                .map(|(_, recommendation)| recommendation);
+            let final_recommendations = non_redundant_recommendations(&recommended_views);
 
             self.add_views(
-                final_recommendations.map(|(_, recommendation)| {
-                    ViewBlueprint::new(class_id, recommendation.clone())
-                }),
+                final_recommendations
+                    .map(|recommendation| ViewBlueprint::new(class_id, recommendation.clone())),
                 None,
                 None,
             );
         }
     }
 
+    /// Replaces all views with the ones recommended by the heuristics, laid out as a dashboard.
+    ///
+    /// Unlike [`Self::spawn_heuristic_views`], this considers every recommendation, including
+    /// the ones that were already spawned or removed before. The views are grouped by the first
+    /// part of their origin, and then by class.
+    ///
+    /// Automatic views and layout are turned off afterwards, so that the dashboard can be tweaked.
+    pub fn generate_dashboard(&self, ctx: &ViewerContext<'_>) {
+        re_tracing::profile_function!();
+
+        let excluded_entities = ResolvedEntityPathFilter::properties();
+        let include_entity = |ent: &EntityPath| !excluded_entities.matches(ent);
+
+        let mut views = Vec::new();
+        for entry in ctx.view_class_registry().iter_registry() {
+            let class_id = entry.identifier;
+
+            let recommended_views = entry
+                .class
+                .spawn_heuristics(ctx, &include_entity)
+                .into_vec()
+                .into_iter()
+                .map(|view| {
+                    let path_subs = EntityPathSubs::new_with_origin(&view.origin);
+                    let query_filter = view.query_filter.resolve_forgiving(&path_subs);
+                    (query_filter, view)
+                })
+                .collect::<Vec<_>>();
+
+            views.extend(
+                non_redundant_recommendations(&recommended_views)
+                    .map(|recommendation| ViewBlueprint::new(class_id, recommendation.clone())),
+            );
+        }
+
+        self.enqueue_command(ViewportCommand::SetDashboard(views));
+    }
+
     /// Add a set of views to the viewport.
     ///
     /// The view is added to the root container, or, if provided, to a given parent container.
@@ -987,6 +1013,43 @@ fn apply_viewport_command(
             bp.tree = new_tree;
         }
 
+        ViewportCommand::SetDashboard(views) => {
+            re_log::trace!(
+                "Replacing all views with a dashboard of {} views",
+                views.len()
+            );
+
+            for view in bp.views.values() {
+                view.clear(ctx);
+            }
+            bp.views.clear();
+
+            if bp.maximized.is_some() {
+                bp.set_maximized(None, ctx);
+            }
+
+            for view in views {
+                view.save_to_blueprint_store(ctx);
+                bp.views.insert(view.id, view);
+            }
+
+            let (tree, named_containers) =
+                super::auto_layout::dashboard_from_views(ctx.view_class_registry(), &bp.views);
+            bp.tree = tree;
+
+            // Make sure the named containers keep their ids when the tree is saved.
+            for (container_id, name) in named_containers {
+                let container = ContainerBlueprint {
+                    id: container_id,
+                    ..Default::default()
+                };
+                container.set_display_name(ctx, Some(name));
+                bp.containers.insert(container_id, container);
+            }
+
+            bp.mark_user_interaction(ctx);
+        }
+
         ViewportCommand::AddView {
             view,
             parent_container,
@@ -1191,6 +1254,24 @@ fn apply_viewport_command(
     }
 }
 
+/// Filters out the recommendations that are covered by another recommendation of the same class.
+fn non_redundant_recommendations(
+    recommended_views: &[(ResolvedEntityPathFilter, RecommendedView)],
+) -> impl Iterator<Item = &RecommendedView> {
+    recommended_views
+        .iter()
+        .enumerate()
+        .filter(|(j, (candidate_query_filter, _))| {
+            recommended_views
+                .iter()
+                .enumerate()
+                .all(|(i, (other_query_filter, _))| {
+                    i == *j || !other_query_filter.is_superset_of(candidate_query_filter)
+                })
+        })
+        .map(|(_, (_, recommendation))| recommendation)
+}
+
 fn build_tree_from_views_and_containers<'a>(
     views: impl Iterator<Item = &'a ViewBlueprint>,
     containers: impl Iterator<Item = &'a ContainerBlueprint>,
@@ -10,6 +10,9 @@ pub enum ViewportCommand {
     /// Set the whole viewport tree.
     SetTree(egui_tiles::Tree<ViewId>),
 
+    /// Replace all views with the provided ones, laid out as a dashboard.
+    SetDashboard(Vec<ViewBlueprint>),
+
     /// Add a new view to the provided container (or the root if `None`).
     AddView {
         view: ViewBlueprint,
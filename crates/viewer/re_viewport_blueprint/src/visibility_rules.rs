@@ -0,0 +1,295 @@
+use arrow::array::{Array as _, Float64Array};
+use re_chunk_store::LatestAtQuery;
+use re_entity_db::EntityDb;
+use re_log_types::EntityPath;
+use re_types::{
+    Archetype as _, ComponentIdentifier,
+    archetypes::Scalars,
+    blueprint::{
+        archetypes::VisibilityRules,
+        components::{AbsoluteTimeRange, TimelineName},
+    },
+    components::{Name, Scalar},
+};
+use re_viewer_context::{DataQueryResult, DataResultHandle, DataResultTree, ViewId};
+
+use crate::entity_path_for_view_property;
+
+/// Rules under which a view or an entity in a view is shown.
+///
+/// Read from the [`VisibilityRules`] archetype, either logged as a view property or as an entity override.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VisibilityRuleSettings {
+    /// If set, `time_range` only applies to this timeline.
+    pub timeline: Option<TimelineName>,
+
+    /// Only shown while the time cursor is within this range.
+    pub time_range: Option<AbsoluteTimeRange>,
+
+    /// Only shown while the latest value of this scalar is at least the threshold.
+    pub scalar: Option<ScalarRule>,
+}
+
+/// Only shows a view or an entity while a scalar is at least a threshold.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScalarRule {
+    /// The entity that has the scalar.
+    pub entity_path: EntityPath,
+
+    /// Either the full identifier of the component or only its field name.
+    ///
+    /// `None` for the scalars of a [`Scalars`] archetype.
+    pub component: Option<String>,
+
+    /// Shown while the value is at least this.
+    pub threshold: f64,
+}
+
+impl VisibilityRuleSettings {
+    /// Reads the rules of a view.
+    ///
+    /// Returns `None` if the view has no rules.
+    pub fn from_view(
+        blueprint: &EntityDb,
+        blueprint_query: &LatestAtQuery,
+        view_id: ViewId,
+    ) -> Option<Self> {
+        let path =
+            entity_path_for_view_property(view_id, blueprint.tree(), VisibilityRules::name());
+        Self::from_blueprint_path(blueprint, blueprint_query, &path)
+    }
+
+    /// Reads the rules logged at the given blueprint path, e.g. the override path of an entity.
+    ///
+    /// Returns `None` if there are no rules at that path.
+    pub fn from_blueprint_path(
+        blueprint: &EntityDb,
+        blueprint_query: &LatestAtQuery,
+        path: &EntityPath,
+    ) -> Option<Self> {
+        // Most paths have nothing logged to them, which is a lot cheaper to check than querying.
+        blueprint.tree().subtree(path)?;
+
+        let results = blueprint.latest_at(
+            blueprint_query,
+            path,
+            VisibilityRules::all_components()
+                .iter()
+                .map(|descr| descr.component),
+        );
+
+        let timeline = results
+            .component_mono_quiet::<TimelineName>(VisibilityRules::descriptor_timeline().component);
+        let time_range = results.component_mono_quiet::<AbsoluteTimeRange>(
+            VisibilityRules::descriptor_time_range().component,
+        );
+        let scalar = results
+            .component_mono_quiet::<re_types::components::EntityPath>(
+                VisibilityRules::descriptor_scalar_entity().component,
+            )
+            .filter(|entity_path| !entity_path.as_str().is_empty())
+            .map(|entity_path| ScalarRule {
+                entity_path: EntityPath::from(entity_path.as_str()),
+                component: results
+                    .component_mono_quiet::<Name>(
+                        VisibilityRules::descriptor_scalar_component().component,
+                    )
+                    .map(|name| name.as_str().trim().to_owned())
+                    .filter(|name| !name.is_empty()),
+                threshold: results
+                    .component_mono_quiet::<Scalar>(
+                        VisibilityRules::descriptor_threshold().component,
+                    )
+                    .map_or(0.0, |threshold| threshold.0.0),
+            });
+
+        if time_range.is_none() && scalar.is_none() {
+            return None;
+        }
+
+        Some(Self {
+            timeline,
+            time_range,
+            scalar,
+        })
+    }
+
+    /// Whether any of the rules isn't met at the given time.
+    pub fn is_hidden(&self, recording: &EntityDb, time_query: &LatestAtQuery) -> bool {
+        self.is_outside_time_range(time_query)
+            || self
+                .scalar
+                .as_ref()
+                .is_some_and(|scalar| scalar.is_below_threshold(recording, time_query))
+    }
+
+    fn is_outside_time_range(&self, time_query: &LatestAtQuery) -> bool {
+        let Some(time_range) = &self.time_range else {
+            return false;
+        };
+        if let Some(timeline) = &self.timeline
+            && timeline.as_str() != time_query.timeline().as_str()
+        {
+            // The range is about another timeline.
+            return false;
+        }
+
+        let time = time_query.at().as_i64();
+        time < time_range.0.min.0 || time_range.0.max.0 < time
+    }
+}
+
+impl ScalarRule {
+    /// Whether the latest value of the scalar is below the threshold.
+    ///
+    /// Not the case if the scalar has no value at the given time.
+    fn is_below_threshold(&self, recording: &EntityDb, time_query: &LatestAtQuery) -> bool {
+        self.latest_value(recording, time_query)
+            .is_some_and(|value| value < self.threshold)
+    }
+
+    fn latest_value(&self, recording: &EntityDb, time_query: &LatestAtQuery) -> Option<f64> {
+        let component = match &self.component {
+            Some(name) => recording
+                .storage_engine()
+                .store()
+                .all_components_for_entity(&self.entity_path)?
+                .into_iter()
+                .find(|component| component_matches(*component, name))?,
+            None => Scalars::descriptor_scalars().component,
+        };
+
+        let values = recording
+            .latest_at(time_query, &self.entity_path, [component])
+            .component_batch_raw(component)?;
+        let values = arrow::compute::cast(&values, &arrow::datatypes::DataType::Float64).ok()?;
+        let values = values.as_any().downcast_ref::<Float64Array>()?;
+
+        (!values.is_empty() && values.is_valid(0)).then(|| values.value(0))
+    }
+}
+
+fn component_matches(component: ComponentIdentifier, name: &str) -> bool {
+    let component = component.as_str();
+    component == name
+        || component
+            .rsplit_once(':')
+            .is_some_and(|(_, field)| field == name)
+}
+
+/// Hides the entities of a view whose [`VisibilityRules`] overrides aren't met at the given time,
+/// together with all their children.
+pub fn apply_entity_visibility_rules(
+    blueprint: &EntityDb,
+    blueprint_query: &LatestAtQuery,
+    recording: &EntityDb,
+    time_query: &LatestAtQuery,
+    query_result: &mut DataQueryResult,
+) {
+    re_tracing::profile_function!();
+
+    if let Some(root) = query_result.tree.root_handle() {
+        apply_entity_visibility_rules_recursive(
+            blueprint,
+            blueprint_query,
+            recording,
+            time_query,
+            &mut query_result.tree,
+            root,
+        );
+    }
+}
+
+fn apply_entity_visibility_rules_recursive(
+    blueprint: &EntityDb,
+    blueprint_query: &LatestAtQuery,
+    recording: &EntityDb,
+    time_query: &LatestAtQuery,
+    tree: &mut DataResultTree,
+    handle: DataResultHandle,
+) {
+    let Some(node) = tree.lookup_node(handle) else {
+        return;
+    };
+
+    let property_overrides = &node.data_result.property_overrides;
+    if property_overrides.visible
+        && VisibilityRuleSettings::from_blueprint_path(
+            blueprint,
+            blueprint_query,
+            &property_overrides.override_path,
+        )
+        .is_some_and(|rules| rules.is_hidden(recording, time_query))
+    {
+        hide_recursive(tree, handle);
+        return;
+    }
+
+    let children = node.children.clone(); // Borrow-checker workaround.
+    for child in children {
+        apply_entity_visibility_rules_recursive(
+            blueprint,
+            blueprint_query,
+            recording,
+            time_query,
+            tree,
+            child,
+        );
+    }
+}
+
+fn hide_recursive(tree: &mut DataResultTree, handle: DataResultHandle) {
+    let Some(node) = tree.lookup_node_mut(handle) else {
+        return;
+    };
+    node.data_result.property_overrides.visible = false;
+
+    let children = node.children.clone(); // Borrow-checker workaround.
+    for child in children {
+        hide_recursive(tree, child);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use re_types::datatypes;
+
+    use super::*;
+
+    #[test]
+    fn test_time_range_rule() {
+        let rules = VisibilityRuleSettings {
+            timeline: Some(TimelineName::from("frame")),
+            time_range: Some(AbsoluteTimeRange::from(datatypes::AbsoluteTimeRange {
+                min: datatypes::TimeInt(10),
+                max: datatypes::TimeInt(20),
+            })),
+            scalar: None,
+        };
+
+        let frame = re_log_types::TimelineName::new("frame");
+        assert!(rules.is_outside_time_range(&LatestAtQuery::new(frame, 9)));
+        assert!(!rules.is_outside_time_range(&LatestAtQuery::new(frame, 10)));
+        assert!(!rules.is_outside_time_range(&LatestAtQuery::new(frame, 20)));
+        assert!(rules.is_outside_time_range(&LatestAtQuery::new(frame, 21)));
+
+        // The range is about another timeline.
+        let other = re_log_types::TimelineName::new("other");
+        assert!(!rules.is_outside_time_range(&LatestAtQuery::new(other, 0)));
+
+        // Without a timeline, the range applies to all timelines.
+        let rules = VisibilityRuleSettings {
+            timeline: None,
+            ..rules
+        };
+        assert!(rules.is_outside_time_range(&LatestAtQuery::new(other, 0)));
+    }
+
+    #[test]
+    fn test_component_matches() {
+        let component = ComponentIdentifier::from("Scalars:scalars");
+        assert!(component_matches(component, "Scalars:scalars"));
+        assert!(component_matches(component, "scalars"));
+        assert!(!component_matches(component, "Scalars"));
+    }
+}
@@ -0,0 +1,39 @@
+// Log a sine wave in short chunks, the way a microphone would deliver it.
+
+#include <rerun.hpp>
+
+#include <cmath>
+#include <cstring>
+#include <vector>
+
+constexpr uint32_t SAMPLE_RATE = 16000;
+constexpr uint32_t CHUNK_SIZE = 1600; // 100 ms per chunk
+
+int main() {
+    const auto rec = rerun::RecordingStream("rerun_example_audio_simple");
+    rec.spawn().exit_on_failure();
+
+    for (uint32_t i = 0; i < 20; ++i) {
+        const uint32_t start = i * CHUNK_SIZE;
+        rec.set_time_duration_secs("time", static_cast<double>(start) / SAMPLE_RATE);
+
+        // A 440 Hz tone that slowly fades out.
+        std::vector<float> samples(CHUNK_SIZE);
+        for (uint32_t n = 0; n < CHUNK_SIZE; ++n) {
+            const float t = static_cast<float>(start + n) / SAMPLE_RATE;
+            samples[n] = std::sin(2.0f * static_cast<float>(M_PI) * 440.0f * t) * std::exp(-t);
+        }
+
+        // Raw PCM samples are logged as little-endian bytes.
+        std::vector<uint8_t> bytes(samples.size() * sizeof(float));
+        std::memcpy(bytes.data(), samples.data(), bytes.size());
+
+        rec.log(
+            "audio",
+            rerun::Audio(rerun::Collection<uint8_t>::take_ownership(std::move(bytes)))
+                .with_sample_rate(SAMPLE_RATE)
+                .with_sample_format(rerun::components::AudioSampleFormat::F32)
+                .with_channel_count(1)
+        );
+    }
+}
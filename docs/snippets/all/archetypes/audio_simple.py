@@ -0,0 +1,19 @@
+"""Log a sine wave in short chunks, the way a microphone would deliver it."""
+
+import numpy as np
+import rerun as rr
+
+SAMPLE_RATE = 16_000
+CHUNK_SIZE = 1_600  # 100 ms per chunk
+
+rr.init("rerun_example_audio_simple", spawn=True)
+
+for i in range(20):
+    start = i * CHUNK_SIZE
+    rr.set_time("time", duration=start / SAMPLE_RATE)
+
+    # A 440 Hz tone that slowly fades out.
+    t = np.arange(start, start + CHUNK_SIZE) / SAMPLE_RATE
+    samples = (np.sin(2.0 * np.pi * 440.0 * t) * np.exp(-t)).astype(np.float32)
+
+    rr.log("audio", rr.Audio(samples=samples, sample_rate=SAMPLE_RATE))
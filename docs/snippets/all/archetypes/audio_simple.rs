@@ -0,0 +1,28 @@
+//! Log a sine wave in short chunks, the way a microphone would deliver it.
+
+const SAMPLE_RATE: u32 = 16_000;
+const CHUNK_SIZE: u32 = 1_600; // 100 ms per chunk
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let rec = rerun::RecordingStreamBuilder::new("rerun_example_audio_simple").spawn()?;
+
+    for i in 0..20 {
+        let start = i * CHUNK_SIZE;
+        rec.set_duration_secs("time", start as f64 / SAMPLE_RATE as f64);
+
+        // A 440 Hz tone that slowly fades out.
+        let samples: Vec<f32> = (start..start + CHUNK_SIZE)
+            .map(|n| {
+                let t = n as f32 / SAMPLE_RATE as f32;
+                (std::f32::consts::TAU * 440.0 * t).sin() * (-t).exp()
+            })
+            .collect();
+
+        rec.log(
+            "audio",
+            &rerun::Audio::from_pcm_f32(&samples, SAMPLE_RATE, 1),
+        )?;
+    }
+
+    Ok(())
+}
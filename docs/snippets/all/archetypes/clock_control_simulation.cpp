@@ -0,0 +1,23 @@
+// Let a simulation drive the time cursor of the viewer.
+
+#include <rerun.hpp>
+
+int main() {
+    const auto rec = rerun::RecordingStream("rerun_example_clock_control");
+    rec.spawn().exit_on_failure();
+
+    float position = 0.0f;
+    for (int step = 0; step < 100; ++step) {
+        position += 0.01f;
+
+        rec.set_time_duration_secs("sim_time", step * 0.01);
+        rec.log("ball", rerun::Points3D({{position, 0.0f, 0.0f}}));
+
+        // Move the time cursor of the viewer to the current simulation time.
+        rec.log("sim/clock", rerun::ClockControl("sim_time"));
+    }
+
+    // Jump back to the start, e.g. after a reset of the simulation.
+    rec.set_time_duration_secs("sim_time", 0.0);
+    rec.log("sim/clock", rerun::ClockControl("sim_time"));
+}
@@ -0,0 +1,19 @@
+"""Let a simulation drive the time cursor of the viewer."""
+
+import rerun as rr
+
+rr.init("rerun_example_clock_control", spawn=True)
+
+position = 0.0
+for step in range(100):
+    position += 0.01
+
+    rr.set_time("sim_time", duration=step * 0.01)
+    rr.log("ball", rr.Points3D([[position, 0.0, 0.0]]))
+
+    # Move the time cursor of the viewer to the current simulation time.
+    rr.log("sim/clock", rr.ClockControl("sim_time"))
+
+# Jump back to the start, e.g. after a reset of the simulation.
+rr.set_time("sim_time", duration=0.0)
+rr.log("sim/clock", rr.ClockControl("sim_time"))
@@ -0,0 +1,22 @@
+//! Let a simulation drive the time cursor of the viewer.
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let rec = rerun::RecordingStreamBuilder::new("rerun_example_clock_control").spawn()?;
+
+    let mut position: f32 = 0.0;
+    for step in 0..100 {
+        position += 0.01;
+
+        rec.set_duration_secs("sim_time", step as f64 * 0.01);
+        rec.log("ball", &rerun::Points3D::new([(position, 0.0, 0.0)]))?;
+
+        // Move the time cursor of the viewer to the current simulation time.
+        rec.log("sim/clock", &rerun::ClockControl::new("sim_time"))?;
+    }
+
+    // Jump back to the start, e.g. after a reset of the simulation.
+    rec.set_duration_secs("sim_time", 0.0);
+    rec.log("sim/clock", &rerun::ClockControl::new("sim_time"))?;
+
+    Ok(())
+}
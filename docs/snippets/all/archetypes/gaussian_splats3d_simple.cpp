@@ -0,0 +1,33 @@
+// Log a ring of elongated Gaussian splats.
+
+#include <rerun.hpp>
+
+#include <cmath>
+#include <vector>
+
+int main() {
+    const auto rec = rerun::RecordingStream("rerun_example_gaussian_splats3d_simple");
+    rec.spawn().exit_on_failure();
+
+    std::vector<rerun::Position3D> centers;
+    std::vector<rerun::components::PoseRotationQuat> quaternions;
+    std::vector<rerun::Color> colors;
+    for (int i = 0; i < 32; ++i) {
+        const float angle = static_cast<float>(i) / 32.0f * 2.0f * static_cast<float>(M_PI);
+        centers.emplace_back(std::cos(angle), std::sin(angle), 0.0f);
+        // Rotate each splat around the z axis so that it is tangent to the ring.
+        quaternions.emplace_back(
+            rerun::Quaternion::from_xyzw(0.0f, 0.0f, std::sin(angle / 2.0f), std::cos(angle / 2.0f))
+        );
+        colors.emplace_back(i % 2 == 0 ? rerun::Rgba32(255, 128, 0) : rerun::Rgba32(0, 128, 255));
+    }
+
+    rec.log(
+        "splats",
+        rerun::GaussianSplats3D(centers)
+            .with_quaternions(quaternions)
+            .with_scales(rerun::components::PoseScale3D(0.02f, 0.1f, 0.02f))
+            .with_colors(colors)
+            .with_opacities(rerun::Opacity(0.8f))
+    );
+}
@@ -0,0 +1,26 @@
+"""Log a ring of elongated Gaussian splats."""
+
+import numpy as np
+import rerun as rr
+
+rr.init("rerun_example_gaussian_splats3d_simple", spawn=True)
+
+angles = np.linspace(0, 2 * np.pi, 32, endpoint=False)
+centers = np.stack([np.cos(angles), np.sin(angles), np.zeros_like(angles)], axis=1)
+
+# Rotate each splat around the z axis so that it is tangent to the ring.
+quaternions = np.stack(
+    [np.zeros_like(angles), np.zeros_like(angles), np.sin(angles / 2), np.cos(angles / 2)],
+    axis=1,
+)
+
+rr.log(
+    "splats",
+    rr.GaussianSplats3D(
+        centers=centers,
+        quaternions=quaternions,
+        scales=[0.02, 0.1, 0.02],
+        colors=[[255, 128, 0], [0, 128, 255]] * 16,
+        opacities=0.8,
+    ),
+)
@@ -0,0 +1,28 @@
+//! Log a ring of elongated Gaussian splats.
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let rec =
+        rerun::RecordingStreamBuilder::new("rerun_example_gaussian_splats3d_simple").spawn()?;
+
+    let angles = (0..32).map(|i| i as f32 / 32.0 * std::f32::consts::TAU);
+
+    rec.log(
+        "splats",
+        &rerun::GaussianSplats3D::new(angles.clone().map(|a| (a.cos(), a.sin(), 0.0)))
+            // Rotate each splat around the z axis so that it is tangent to the ring.
+            .with_quaternions(angles.map(|a| {
+                rerun::Quaternion::from_xyzw([0.0, 0.0, (a / 2.0).sin(), (a / 2.0).cos()])
+            }))
+            .with_scales([(0.02, 0.1, 0.02)])
+            .with_colors((0..32).map(|i| {
+                if i % 2 == 0 {
+                    rerun::Color::from_rgb(255, 128, 0)
+                } else {
+                    rerun::Color::from_rgb(0, 128, 255)
+                }
+            }))
+            .with_opacities([0.8]),
+    )?;
+
+    Ok(())
+}
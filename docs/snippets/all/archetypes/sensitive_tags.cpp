@@ -0,0 +1,23 @@
+// Tag camera images as sensitive, so they can be redacted before sharing the recording.
+
+#include <rerun.hpp>
+
+#include <cstdint>
+#include <vector>
+
+int main() {
+    const auto rec = rerun::RecordingStream("rerun_example_sensitive");
+    rec.spawn().exit_on_failure();
+
+    // Everything logged to `camera` and below is tagged, no matter when it's logged.
+    rec.log_static("camera", rerun::Sensitive({"faces"}));
+
+    for (int frame = 0; frame < 10; ++frame) {
+        rec.set_time_sequence("frame", frame);
+        std::vector<uint8_t> image(64 * 48 * 3, static_cast<uint8_t>(frame * 25));
+        rec.log("camera/image", rerun::Image::from_rgb24(image, {64, 48}));
+    }
+
+    // Share the recording without the faces, e.g. with:
+    // `rerun rrd filter recording.rrd --sensitive redact -o shareable.rrd`
+}
@@ -0,0 +1,17 @@
+"""Tag camera images as sensitive, so they can be redacted before sharing the recording."""
+
+import numpy as np
+import rerun as rr
+
+rr.init("rerun_example_sensitive", spawn=True)
+
+# Everything logged to `camera` and below is tagged, no matter when it's logged.
+rr.log("camera", rr.Sensitive(["faces"]), static=True)
+
+for frame in range(10):
+    rr.set_time("frame", sequence=frame)
+    image = np.full((48, 64, 3), frame * 25, dtype=np.uint8)
+    rr.log("camera/image", rr.Image(image))
+
+# Share the recording without the faces, e.g. with:
+# `rerun rrd filter recording.rrd --sensitive redact -o shareable.rrd`
@@ -0,0 +1,19 @@
+//! Tag camera images as sensitive, so they can be redacted before sharing the recording.
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let rec = rerun::RecordingStreamBuilder::new("rerun_example_sensitive").spawn()?;
+
+    // Everything logged to `camera` and below is tagged, no matter when it's logged.
+    rec.log_static("camera", &rerun::Sensitive::new(["faces"]))?;
+
+    for frame in 0..10 {
+        rec.set_time_sequence("frame", frame);
+        let image = vec![(frame * 25) as u8; 64 * 48 * 3];
+        rec.log("camera/image", &rerun::Image::from_rgb24(image, [64, 48]))?;
+    }
+
+    // Share the recording without the faces, e.g. with:
+    // `rerun rrd filter recording.rrd --sensitive redact -o shareable.rrd`
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+// Log a simple kinematic chain that bends over time.
+
+#include <rerun.hpp>
+
+#include <cmath>
+
+int main() {
+    const auto rec = rerun::RecordingStream("rerun_example_skeleton_simple");
+    rec.spawn().exit_on_failure();
+
+    // The hierarchy doesn't change, so it only needs to be logged once.
+    rec.log_static(
+        "arm",
+        rerun::Skeleton::update_fields()
+            .with_parents({0, 0, 1, 2})
+            .with_labels({"base", "shoulder", "elbow", "wrist"})
+            .with_axis_length(0.1f)
+    );
+
+    for (int t = 0; t < 100; ++t) {
+        rec.set_time_sequence("step", t);
+
+        // Every joint is rotated by the same angle around the x axis relative to its parent.
+        const float angle = std::sin(static_cast<float>(t) * 0.1f) * 0.5f;
+        const auto rotation =
+            rerun::Quaternion::from_xyzw(std::sin(angle / 2.0f), 0.0f, 0.0f, std::cos(angle / 2.0f));
+
+        rec.log(
+            "arm",
+            rerun::Skeleton({{0.0f, 0.0f, 0.0f}, {0.0f, 0.0f, 0.5f}, {0.0f, 0.0f, 0.4f}, {0.0f, 0.0f, 0.3f}})
+                .with_quaternions({rotation, rotation, rotation, rotation})
+        );
+    }
+}
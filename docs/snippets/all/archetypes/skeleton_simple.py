@@ -0,0 +1,33 @@
+"""Log a simple kinematic chain that bends over time."""
+
+import math
+
+import rerun as rr
+
+rr.init("rerun_example_skeleton_simple", spawn=True)
+
+# The hierarchy doesn't change, so it only needs to be logged once.
+rr.log(
+    "arm",
+    rr.Skeleton.from_fields(
+        parents=[0, 0, 1, 2],
+        labels=["base", "shoulder", "elbow", "wrist"],
+        axis_length=0.1,
+    ),
+    static=True,
+)
+
+for t in range(100):
+    rr.set_time("step", sequence=t)
+
+    # Every joint is rotated by the same angle around the x axis relative to its parent.
+    angle = math.sin(t * 0.1) * 0.5
+    rotation = rr.Quaternion(xyzw=[math.sin(angle / 2), 0.0, 0.0, math.cos(angle / 2)])
+
+    rr.log(
+        "arm",
+        rr.Skeleton(
+            translations=[[0.0, 0.0, 0.0], [0.0, 0.0, 0.5], [0.0, 0.0, 0.4], [0.0, 0.0, 0.3]],
+            quaternions=[rotation] * 4,
+        ),
+    )
@@ -0,0 +1,36 @@
+//! Log a simple kinematic chain that bends over time.
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let rec = rerun::RecordingStreamBuilder::new("rerun_example_skeleton_simple").spawn()?;
+
+    // The hierarchy doesn't change, so it only needs to be logged once.
+    rec.log_static(
+        "arm",
+        &rerun::Skeleton::update_fields()
+            .with_parents([0, 0, 1, 2])
+            .with_labels(["base", "shoulder", "elbow", "wrist"])
+            .with_axis_length(0.1),
+    )?;
+
+    for t in 0..100 {
+        rec.set_time_sequence("step", t);
+
+        // Every joint is rotated by the same angle around the x axis relative to its parent.
+        let angle = (t as f32 * 0.1).sin() * 0.5;
+        let rotation =
+            rerun::Quaternion::from_xyzw([(angle / 2.0).sin(), 0.0, 0.0, (angle / 2.0).cos()]);
+
+        rec.log(
+            "arm",
+            &rerun::Skeleton::new([
+                [0.0, 0.0, 0.0],
+                [0.0, 0.0, 0.5],
+                [0.0, 0.0, 0.4],
+                [0.0, 0.0, 0.3],
+            ])
+            .with_quaternions([rotation; 4]),
+        )?;
+    }
+
+    Ok(())
+}
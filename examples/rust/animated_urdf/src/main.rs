@@ -38,8 +38,6 @@ fn run(rec: &rerun::RecordingStream, _args: &Args) -> anyhow::Result<()> {
         rec.set_time_sequence("step", step);
         for (joint_index, joint) in urdf.joints().enumerate() {
             if joint.joint_type == urdf_rs::JointType::Revolute {
-                let fixed_axis = joint.axis.xyz.0;
-
                 // Usually this angle would come from a measurement - here we just fake something:
                 let dynamic_angle = emath::remap(
                     (step as f64 * (0.02 + joint_index as f64 / 100.0)).sin(),
@@ -47,17 +45,11 @@ fn run(rec: &rerun::RecordingStream, _args: &Args) -> anyhow::Result<()> {
                     joint.limit.lower..=joint.limit.upper,
                 );
 
-                // NOTE: each joint already has a fixed origin pose (logged with the URDF file),
-                // and Rerun won't allow us to override or add to that transform here.
-                // So instead we apply the dynamic rotation to the child link of the joint:
-                let child_link = urdf.get_joint_child(joint);
-                let link_path = urdf.get_link_path(child_link);
-                rec.log(
-                    link_path,
-                    &rerun::Transform3D::update_fields().with_rotation(
-                        rerun::RotationAxisAngle::new(fixed_axis, dynamic_angle as f32),
-                    ),
-                )?;
+                // Each joint already has a fixed origin pose (logged with the URDF file),
+                // so the dynamic rotation is applied to the child link of the joint:
+                if let Some((link_path, transform)) = urdf.joint_transform(joint, dynamic_angle) {
+                    rec.log(link_path, &transform)?;
+                }
             }
         }
     }
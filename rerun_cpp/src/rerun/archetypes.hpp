@@ -7,16 +7,19 @@
 #include "archetypes/arrows3d.hpp"
 #include "archetypes/asset3d.hpp"
 #include "archetypes/asset_video.hpp"
+#include "archetypes/audio.hpp"
 #include "archetypes/bar_chart.hpp"
 #include "archetypes/boxes2d.hpp"
 #include "archetypes/boxes3d.hpp"
 #include "archetypes/capsules3d.hpp"
 #include "archetypes/clear.hpp"
+#include "archetypes/clock_control.hpp"
 #include "archetypes/coordinate_frame.hpp"
 #include "archetypes/cylinders3d.hpp"
 #include "archetypes/depth_image.hpp"
 #include "archetypes/ellipsoids3d.hpp"
 #include "archetypes/encoded_image.hpp"
+#include "archetypes/gaussian_splats3d.hpp"
 #include "archetypes/geo_line_strings.hpp"
 #include "archetypes/geo_points.hpp"
 #include "archetypes/graph_edges.hpp"
@@ -36,8 +39,10 @@
 #include "archetypes/recording_info.hpp"
 #include "archetypes/scalars.hpp"
 #include "archetypes/segmentation_image.hpp"
+#include "archetypes/sensitive.hpp"
 #include "archetypes/series_lines.hpp"
 #include "archetypes/series_points.hpp"
+#include "archetypes/skeleton.hpp"
 #include "archetypes/tensor.hpp"
 #include "archetypes/text_document.hpp"
 #include "archetypes/text_log.hpp"
@@ -0,0 +1,96 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/audio.fbs".
+
+#include "audio.hpp"
+
+#include "../collection_adapter_builtins.hpp"
+
+namespace rerun::archetypes {
+    Audio Audio::clear_fields() {
+        auto archetype = Audio();
+        archetype.blob =
+            ComponentBatch::empty<rerun::components::Blob>(Descriptor_blob).value_or_throw();
+        archetype.sample_rate =
+            ComponentBatch::empty<rerun::components::SampleRate>(Descriptor_sample_rate)
+                .value_or_throw();
+        archetype.media_type =
+            ComponentBatch::empty<rerun::components::MediaType>(Descriptor_media_type)
+                .value_or_throw();
+        archetype.sample_format =
+            ComponentBatch::empty<rerun::components::AudioSampleFormat>(Descriptor_sample_format)
+                .value_or_throw();
+        archetype.channel_count =
+            ComponentBatch::empty<rerun::components::AudioChannelCount>(Descriptor_channel_count)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> Audio::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(5);
+        if (blob.has_value()) {
+            columns.push_back(blob.value().partitioned(lengths_).value_or_throw());
+        }
+        if (sample_rate.has_value()) {
+            columns.push_back(sample_rate.value().partitioned(lengths_).value_or_throw());
+        }
+        if (media_type.has_value()) {
+            columns.push_back(media_type.value().partitioned(lengths_).value_or_throw());
+        }
+        if (sample_format.has_value()) {
+            columns.push_back(sample_format.value().partitioned(lengths_).value_or_throw());
+        }
+        if (channel_count.has_value()) {
+            columns.push_back(channel_count.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> Audio::columns() {
+        if (blob.has_value()) {
+            return columns(std::vector<uint32_t>(blob.value().length(), 1));
+        }
+        if (sample_rate.has_value()) {
+            return columns(std::vector<uint32_t>(sample_rate.value().length(), 1));
+        }
+        if (media_type.has_value()) {
+            return columns(std::vector<uint32_t>(media_type.value().length(), 1));
+        }
+        if (sample_format.has_value()) {
+            return columns(std::vector<uint32_t>(sample_format.value().length(), 1));
+        }
+        if (channel_count.has_value()) {
+            return columns(std::vector<uint32_t>(channel_count.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>> AsComponents<archetypes::Audio>::as_batches(
+        const archetypes::Audio& archetype
+    ) {
+        using namespace archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(5);
+
+        if (archetype.blob.has_value()) {
+            cells.push_back(archetype.blob.value());
+        }
+        if (archetype.sample_rate.has_value()) {
+            cells.push_back(archetype.sample_rate.value());
+        }
+        if (archetype.media_type.has_value()) {
+            cells.push_back(archetype.media_type.value());
+        }
+        if (archetype.sample_format.has_value()) {
+            cells.push_back(archetype.sample_format.value());
+        }
+        if (archetype.channel_count.has_value()) {
+            cells.push_back(archetype.channel_count.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
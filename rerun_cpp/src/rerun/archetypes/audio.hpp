@@ -0,0 +1,284 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/audio.fbs".
+
+#pragma once
+
+#include "../collection.hpp"
+#include "../component_batch.hpp"
+#include "../component_column.hpp"
+#include "../components/audio_channel_count.hpp"
+#include "../components/audio_sample_format.hpp"
+#include "../components/blob.hpp"
+#include "../components/media_type.hpp"
+#include "../components/sample_rate.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::archetypes {
+    /// **Archetype**: A chunk of audio, either as raw PCM samples or as an encoded audio file.
+    ///
+    /// The audio starts at the time it is logged at.
+    /// Longer recordings can be logged as a sequence of chunks, e.g. one per buffer of a microphone.
+    ///
+    /// The audio view shows the waveform of all chunks on the timeline.
+    /// While time is playing, the native viewer also plays the audio back, if it was built with the `audio_playback` feature.
+    ///
+    /// ## Example
+    ///
+    /// ### Chunked sine wave
+    /// ```cpp
+    /// #include <rerun.hpp>
+    ///
+    /// #include <cmath>
+    /// #include <cstring>
+    /// #include <vector>
+    ///
+    /// constexpr uint32_t SAMPLE_RATE = 16000;
+    /// constexpr uint32_t CHUNK_SIZE = 1600; // 100 ms per chunk
+    ///
+    /// int main() {
+    ///     const auto rec = rerun::RecordingStream("rerun_example_audio_simple");
+    ///     rec.spawn().exit_on_failure();
+    ///
+    ///     for (uint32_t i = 0; i < 20; ++i) {
+    ///         const uint32_t start = i * CHUNK_SIZE;
+    ///         rec.set_time_duration_secs("time", static_cast<double>(start) / SAMPLE_RATE);
+    ///
+    ///         // A 440 Hz tone that slowly fades out.
+    ///         std::vector<float> samples(CHUNK_SIZE);
+    ///         for (uint32_t n = 0; n < CHUNK_SIZE; ++n) {
+    ///             const float t = static_cast<float>(start + n) / SAMPLE_RATE;
+    ///             samples[n] = std::sin(2.0f * static_cast<float>(M_PI) * 440.0f * t) * std::exp(-t);
+    ///         }
+    ///
+    ///         // Raw PCM samples are logged as little-endian bytes.
+    ///         std::vector<uint8_t> bytes(samples.size() * sizeof(float));
+    ///         std::memcpy(bytes.data(), samples.data(), bytes.size());
+    ///
+    ///         rec.log(
+    ///             "audio",
+    ///             rerun::Audio(rerun::Collection<uint8_t>::take_ownership(std::move(bytes)))
+    ///                 .with_sample_rate(SAMPLE_RATE)
+    ///                 .with_sample_format(rerun::components::AudioSampleFormat::F32)
+    ///                 .with_channel_count(1)
+    ///         );
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct Audio {
+        /// The audio data.
+        ///
+        /// Either raw PCM samples, described by `components::AudioSampleFormat` and `components::AudioChannelCount`,
+        /// or an encoded audio file, see `components::MediaType`.
+        std::optional<ComponentBatch> blob;
+
+        /// The number of samples per second and channel.
+        ///
+        /// Required for raw PCM samples, encoded audio files specify their own sample rate.
+        std::optional<ComponentBatch> sample_rate;
+
+        /// The Media Type of an encoded audio file.
+        ///
+        /// Supported values:
+        /// * `audio/wav` (uncompressed PCM only)
+        ///
+        /// If omitted, the blob holds raw PCM samples, unless the viewer recognizes an encoded audio file.
+        std::optional<ComponentBatch> media_type;
+
+        /// The data type of the raw PCM samples.
+        ///
+        /// Defaults to 32-bit floats.
+        std::optional<ComponentBatch> sample_format;
+
+        /// The number of interleaved channels of the raw PCM samples.
+        ///
+        /// Defaults to a single channel.
+        std::optional<ComponentBatch> channel_count;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.archetypes.Audio";
+
+        /// `ComponentDescriptor` for the `blob` field.
+        static constexpr auto Descriptor_blob = ComponentDescriptor(
+            ArchetypeName, "Audio:blob", Loggable<rerun::components::Blob>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `sample_rate` field.
+        static constexpr auto Descriptor_sample_rate = ComponentDescriptor(
+            ArchetypeName, "Audio:sample_rate",
+            Loggable<rerun::components::SampleRate>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `media_type` field.
+        static constexpr auto Descriptor_media_type = ComponentDescriptor(
+            ArchetypeName, "Audio:media_type", Loggable<rerun::components::MediaType>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `sample_format` field.
+        static constexpr auto Descriptor_sample_format = ComponentDescriptor(
+            ArchetypeName, "Audio:sample_format",
+            Loggable<rerun::components::AudioSampleFormat>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `channel_count` field.
+        static constexpr auto Descriptor_channel_count = ComponentDescriptor(
+            ArchetypeName, "Audio:channel_count",
+            Loggable<rerun::components::AudioChannelCount>::ComponentType
+        );
+
+      public:
+        Audio() = default;
+        Audio(Audio&& other) = default;
+        Audio(const Audio& other) = default;
+        Audio& operator=(const Audio& other) = default;
+        Audio& operator=(Audio&& other) = default;
+
+        explicit Audio(rerun::components::Blob _blob)
+            : blob(ComponentBatch::from_loggable(std::move(_blob), Descriptor_blob).value_or_throw()
+              ) {}
+
+        /// Update only some specific fields of a `Audio`.
+        static Audio update_fields() {
+            return Audio();
+        }
+
+        /// Clear all the fields of a `Audio`.
+        static Audio clear_fields();
+
+        /// The audio data.
+        ///
+        /// Either raw PCM samples, described by `components::AudioSampleFormat` and `components::AudioChannelCount`,
+        /// or an encoded audio file, see `components::MediaType`.
+        Audio with_blob(const rerun::components::Blob& _blob) && {
+            blob = ComponentBatch::from_loggable(_blob, Descriptor_blob).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `blob` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_blob` should
+        /// be used when logging a single row's worth of data.
+        Audio with_many_blob(const Collection<rerun::components::Blob>& _blob) && {
+            blob = ComponentBatch::from_loggable(_blob, Descriptor_blob).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The number of samples per second and channel.
+        ///
+        /// Required for raw PCM samples, encoded audio files specify their own sample rate.
+        Audio with_sample_rate(const rerun::components::SampleRate& _sample_rate) && {
+            sample_rate = ComponentBatch::from_loggable(_sample_rate, Descriptor_sample_rate)
+                              .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `sample_rate` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_sample_rate` should
+        /// be used when logging a single row's worth of data.
+        Audio with_many_sample_rate(const Collection<rerun::components::SampleRate>& _sample_rate
+        ) && {
+            sample_rate = ComponentBatch::from_loggable(_sample_rate, Descriptor_sample_rate)
+                              .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The Media Type of an encoded audio file.
+        ///
+        /// Supported values:
+        /// * `audio/wav` (uncompressed PCM only)
+        ///
+        /// If omitted, the blob holds raw PCM samples, unless the viewer recognizes an encoded audio file.
+        Audio with_media_type(const rerun::components::MediaType& _media_type) && {
+            media_type =
+                ComponentBatch::from_loggable(_media_type, Descriptor_media_type).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `media_type` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_media_type` should
+        /// be used when logging a single row's worth of data.
+        Audio with_many_media_type(const Collection<rerun::components::MediaType>& _media_type) && {
+            media_type =
+                ComponentBatch::from_loggable(_media_type, Descriptor_media_type).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The data type of the raw PCM samples.
+        ///
+        /// Defaults to 32-bit floats.
+        Audio with_sample_format(const rerun::components::AudioSampleFormat& _sample_format) && {
+            sample_format = ComponentBatch::from_loggable(_sample_format, Descriptor_sample_format)
+                                .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `sample_format` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_sample_format` should
+        /// be used when logging a single row's worth of data.
+        Audio with_many_sample_format(
+            const Collection<rerun::components::AudioSampleFormat>& _sample_format
+        ) && {
+            sample_format = ComponentBatch::from_loggable(_sample_format, Descriptor_sample_format)
+                                .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The number of interleaved channels of the raw PCM samples.
+        ///
+        /// Defaults to a single channel.
+        Audio with_channel_count(const rerun::components::AudioChannelCount& _channel_count) && {
+            channel_count = ComponentBatch::from_loggable(_channel_count, Descriptor_channel_count)
+                                .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `channel_count` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_channel_count` should
+        /// be used when logging a single row's worth of data.
+        Audio with_many_channel_count(
+            const Collection<rerun::components::AudioChannelCount>& _channel_count
+        ) && {
+            channel_count = ComponentBatch::from_loggable(_channel_count, Descriptor_channel_count)
+                                .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<archetypes::Audio> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(const archetypes::Audio& archetype);
+    };
+} // namespace rerun
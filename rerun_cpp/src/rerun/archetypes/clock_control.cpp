@@ -0,0 +1,48 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/clock_control.fbs".
+
+#include "clock_control.hpp"
+
+#include "../collection_adapter_builtins.hpp"
+
+namespace rerun::archetypes {
+    ClockControl ClockControl::clear_fields() {
+        auto archetype = ClockControl();
+        archetype.timeline =
+            ComponentBatch::empty<rerun::components::Name>(Descriptor_timeline).value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> ClockControl::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(1);
+        if (timeline.has_value()) {
+            columns.push_back(timeline.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> ClockControl::columns() {
+        if (timeline.has_value()) {
+            return columns(std::vector<uint32_t>(timeline.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>> AsComponents<archetypes::ClockControl>::as_batches(
+        const archetypes::ClockControl& archetype
+    ) {
+        using namespace archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(1);
+
+        if (archetype.timeline.has_value()) {
+            cells.push_back(archetype.timeline.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
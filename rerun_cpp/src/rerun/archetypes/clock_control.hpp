@@ -0,0 +1,140 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/clock_control.fbs".
+
+#pragma once
+
+#include "../collection.hpp"
+#include "../component_batch.hpp"
+#include "../component_column.hpp"
+#include "../components/name.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::archetypes {
+    /// **Archetype**: Makes the time cursor of the viewer follow the logging SDK, e.g. to let a simulator drive playback.
+    ///
+    /// Whenever a `ClockControl` comes in, the viewer pauses its own playback, switches to the given
+    /// timeline, and moves the time cursor to the time the `ClockControl` was logged at on that timeline.
+    /// The cursor follows `ClockControl`s in the order they come in rather than in time order.
+    /// A simulation can thus pause by not logging any, step by logging one at the next time, and
+    /// jump by logging one at any other time, including back in time after a reset.
+    ///
+    /// The entity path a `ClockControl` is logged to doesn't matter.
+    /// The time cursor can still be moved by hand until the next `ClockControl` comes in.
+    ///
+    /// ## Example
+    ///
+    /// ### Driving the time cursor from a simulation
+    /// ```cpp
+    /// #include <rerun.hpp>
+    ///
+    /// int main() {
+    ///     const auto rec = rerun::RecordingStream("rerun_example_clock_control");
+    ///     rec.spawn().exit_on_failure();
+    ///
+    ///     float position = 0.0f;
+    ///     for (int step = 0; step < 100; ++step) {
+    ///         position += 0.01f;
+    ///
+    ///         rec.set_time_duration_secs("sim_time", step * 0.01);
+    ///         rec.log("ball", rerun::Points3D({{position, 0.0f, 0.0f}}));
+    ///
+    ///         // Move the time cursor of the viewer to the current simulation time.
+    ///         rec.log("sim/clock", rerun::ClockControl("sim_time"));
+    ///     }
+    ///
+    ///     // Jump back to the start, e.g. after a reset of the simulation.
+    ///     rec.set_time_duration_secs("sim_time", 0.0);
+    ///     rec.log("sim/clock", rerun::ClockControl("sim_time"));
+    /// }
+    /// ```
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct ClockControl {
+        /// Name of the timeline whose time cursor is controlled.
+        std::optional<ComponentBatch> timeline;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.archetypes.ClockControl";
+
+        /// `ComponentDescriptor` for the `timeline` field.
+        static constexpr auto Descriptor_timeline = ComponentDescriptor(
+            ArchetypeName, "ClockControl:timeline",
+            Loggable<rerun::components::Name>::ComponentType
+        );
+
+      public:
+        ClockControl() = default;
+        ClockControl(ClockControl&& other) = default;
+        ClockControl(const ClockControl& other) = default;
+        ClockControl& operator=(const ClockControl& other) = default;
+        ClockControl& operator=(ClockControl&& other) = default;
+
+        explicit ClockControl(rerun::components::Name _timeline)
+            : timeline(ComponentBatch::from_loggable(std::move(_timeline), Descriptor_timeline)
+                           .value_or_throw()) {}
+
+        /// Update only some specific fields of a `ClockControl`.
+        static ClockControl update_fields() {
+            return ClockControl();
+        }
+
+        /// Clear all the fields of a `ClockControl`.
+        static ClockControl clear_fields();
+
+        /// Name of the timeline whose time cursor is controlled.
+        ClockControl with_timeline(const rerun::components::Name& _timeline) && {
+            timeline =
+                ComponentBatch::from_loggable(_timeline, Descriptor_timeline).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `timeline` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_timeline` should
+        /// be used when logging a single row's worth of data.
+        ClockControl with_many_timeline(const Collection<rerun::components::Name>& _timeline) && {
+            timeline =
+                ComponentBatch::from_loggable(_timeline, Descriptor_timeline).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<archetypes::ClockControl> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const archetypes::ClockControl& archetype
+        );
+    };
+} // namespace rerun
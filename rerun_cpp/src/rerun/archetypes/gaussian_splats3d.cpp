@@ -0,0 +1,120 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/gaussian_splats3d.fbs".
+
+#include "gaussian_splats3d.hpp"
+
+#include "../collection_adapter_builtins.hpp"
+
+namespace rerun::archetypes {
+    GaussianSplats3D GaussianSplats3D::clear_fields() {
+        auto archetype = GaussianSplats3D();
+        archetype.centers = ComponentBatch::empty<rerun::components::Position3D>(Descriptor_centers)
+                                .value_or_throw();
+        archetype.quaternions =
+            ComponentBatch::empty<rerun::components::PoseRotationQuat>(Descriptor_quaternions)
+                .value_or_throw();
+        archetype.scales = ComponentBatch::empty<rerun::components::PoseScale3D>(Descriptor_scales)
+                               .value_or_throw();
+        archetype.colors =
+            ComponentBatch::empty<rerun::components::Color>(Descriptor_colors).value_or_throw();
+        archetype.opacities =
+            ComponentBatch::empty<rerun::components::Opacity>(Descriptor_opacities)
+                .value_or_throw();
+        archetype.spherical_harmonics =
+            ComponentBatch::empty<rerun::components::SphericalHarmonics>(
+                Descriptor_spherical_harmonics
+            )
+                .value_or_throw();
+        archetype.class_ids =
+            ComponentBatch::empty<rerun::components::ClassId>(Descriptor_class_ids)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> GaussianSplats3D::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(7);
+        if (centers.has_value()) {
+            columns.push_back(centers.value().partitioned(lengths_).value_or_throw());
+        }
+        if (quaternions.has_value()) {
+            columns.push_back(quaternions.value().partitioned(lengths_).value_or_throw());
+        }
+        if (scales.has_value()) {
+            columns.push_back(scales.value().partitioned(lengths_).value_or_throw());
+        }
+        if (colors.has_value()) {
+            columns.push_back(colors.value().partitioned(lengths_).value_or_throw());
+        }
+        if (opacities.has_value()) {
+            columns.push_back(opacities.value().partitioned(lengths_).value_or_throw());
+        }
+        if (spherical_harmonics.has_value()) {
+            columns.push_back(spherical_harmonics.value().partitioned(lengths_).value_or_throw());
+        }
+        if (class_ids.has_value()) {
+            columns.push_back(class_ids.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> GaussianSplats3D::columns() {
+        if (centers.has_value()) {
+            return columns(std::vector<uint32_t>(centers.value().length(), 1));
+        }
+        if (quaternions.has_value()) {
+            return columns(std::vector<uint32_t>(quaternions.value().length(), 1));
+        }
+        if (scales.has_value()) {
+            return columns(std::vector<uint32_t>(scales.value().length(), 1));
+        }
+        if (colors.has_value()) {
+            return columns(std::vector<uint32_t>(colors.value().length(), 1));
+        }
+        if (opacities.has_value()) {
+            return columns(std::vector<uint32_t>(opacities.value().length(), 1));
+        }
+        if (spherical_harmonics.has_value()) {
+            return columns(std::vector<uint32_t>(spherical_harmonics.value().length(), 1));
+        }
+        if (class_ids.has_value()) {
+            return columns(std::vector<uint32_t>(class_ids.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>> AsComponents<archetypes::GaussianSplats3D>::as_batches(
+        const archetypes::GaussianSplats3D& archetype
+    ) {
+        using namespace archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(7);
+
+        if (archetype.centers.has_value()) {
+            cells.push_back(archetype.centers.value());
+        }
+        if (archetype.quaternions.has_value()) {
+            cells.push_back(archetype.quaternions.value());
+        }
+        if (archetype.scales.has_value()) {
+            cells.push_back(archetype.scales.value());
+        }
+        if (archetype.colors.has_value()) {
+            cells.push_back(archetype.colors.value());
+        }
+        if (archetype.opacities.has_value()) {
+            cells.push_back(archetype.opacities.value());
+        }
+        if (archetype.spherical_harmonics.has_value()) {
+            cells.push_back(archetype.spherical_harmonics.value());
+        }
+        if (archetype.class_ids.has_value()) {
+            cells.push_back(archetype.class_ids.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
@@ -0,0 +1,270 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/gaussian_splats3d.fbs".
+
+#pragma once
+
+#include "../collection.hpp"
+#include "../component_batch.hpp"
+#include "../component_column.hpp"
+#include "../components/class_id.hpp"
+#include "../components/color.hpp"
+#include "../components/opacity.hpp"
+#include "../components/pose_rotation_quat.hpp"
+#include "../components/pose_scale3d.hpp"
+#include "../components/position3d.hpp"
+#include "../components/spherical_harmonics.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::archetypes {
+    /// **Archetype**: A 3D Gaussian splatting scene.
+    ///
+    /// Each splat is an anisotropic 3D gaussian, defined by its center, its orientation and its scale,
+    /// i.e. the standard deviation along each of its local axes.
+    /// Together, orientation and scale describe the covariance of the gaussian: `Σ = R S Sᵀ Rᵀ`.
+    /// This is the same parameterization that is used by most Gaussian splatting reconstructions.
+    ///
+    /// Splats are alpha blended in back to front order.
+    /// The color of a splat is given by the zeroth degree of its spherical harmonics `f_dc` as `0.5 + 0.2820948 * f_dc`.
+    /// Higher degrees, as produced by most reconstructions, are given by `components::SphericalHarmonics`
+    /// and make the color depend on the direction the splat is viewed from.
+    ///
+    /// ## Example
+    ///
+    /// ### Simple Gaussian splats
+    /// ```cpp
+    /// #include <rerun.hpp>
+    ///
+    /// #include <cmath>
+    /// #include <vector>
+    ///
+    /// int main() {
+    ///     const auto rec = rerun::RecordingStream("rerun_example_gaussian_splats3d_simple");
+    ///     rec.spawn().exit_on_failure();
+    ///
+    ///     std::vector<rerun::Position3D> centers;
+    ///     std::vector<rerun::components::PoseRotationQuat> quaternions;
+    ///     std::vector<rerun::Color> colors;
+    ///     for (int i = 0; i <32; ++i) {
+    ///         const float angle = static_cast<float>(i) / 32.0f * 2.0f * static_cast<float>(M_PI);
+    ///         centers.emplace_back(std::cos(angle), std::sin(angle), 0.0f);
+    ///         // Rotate each splat around the z axis so that it is tangent to the ring.
+    ///         quaternions.emplace_back(
+    ///             rerun::Quaternion::from_xyzw(0.0f, 0.0f, std::sin(angle / 2.0f), std::cos(angle / 2.0f))
+    ///         );
+    ///         colors.emplace_back(i % 2 == 0 ? rerun::Rgba32(255, 128, 0) : rerun::Rgba32(0, 128, 255));
+    ///     }
+    ///
+    ///     rec.log(
+    ///         "splats",
+    ///         rerun::GaussianSplats3D(centers)
+    ///             .with_quaternions(quaternions)
+    ///             .with_scales(rerun::components::PoseScale3D(0.02f, 0.1f, 0.02f))
+    ///             .with_colors(colors)
+    ///             .with_opacities(rerun::Opacity(0.8f))
+    ///     );
+    /// }
+    /// ```
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct GaussianSplats3D {
+        /// The center of each splat.
+        std::optional<ComponentBatch> centers;
+
+        /// The orientation of each splat.
+        ///
+        /// If no orientation is specified, the axes of the splats align with the axes of the local coordinate system.
+        std::optional<ComponentBatch> quaternions;
+
+        /// The standard deviation of each splat along its three local axes.
+        ///
+        /// If not specified, splats are isotropic with a standard deviation of 1% of the size of the bounding box of all centers.
+        std::optional<ComponentBatch> scales;
+
+        /// The color of each splat.
+        ///
+        /// The alpha channel is multiplied with `components::Opacity`.
+        std::optional<ComponentBatch> colors;
+
+        /// The opacity at the center of each splat.
+        ///
+        /// Defaults to fully opaque.
+        std::optional<ComponentBatch> opacities;
+
+        /// The view dependent part of the color of each splat.
+        ///
+        /// All splats are expected to have the same number of coefficients.
+        /// If not specified, splats look the same from all directions.
+        std::optional<ComponentBatch> spherical_harmonics;
+
+        /// Optional class IDs for the splats.
+        ///
+        /// The class ID provides colors if not specified explicitly.
+        std::optional<ComponentBatch> class_ids;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.archetypes.GaussianSplats3D";
+
+        /// `ComponentDescriptor` for the `centers` field.
+        static constexpr auto Descriptor_centers = ComponentDescriptor(
+            ArchetypeName, "GaussianSplats3D:centers",
+            Loggable<rerun::components::Position3D>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `quaternions` field.
+        static constexpr auto Descriptor_quaternions = ComponentDescriptor(
+            ArchetypeName, "GaussianSplats3D:quaternions",
+            Loggable<rerun::components::PoseRotationQuat>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `scales` field.
+        static constexpr auto Descriptor_scales = ComponentDescriptor(
+            ArchetypeName, "GaussianSplats3D:scales",
+            Loggable<rerun::components::PoseScale3D>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `colors` field.
+        static constexpr auto Descriptor_colors = ComponentDescriptor(
+            ArchetypeName, "GaussianSplats3D:colors",
+            Loggable<rerun::components::Color>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `opacities` field.
+        static constexpr auto Descriptor_opacities = ComponentDescriptor(
+            ArchetypeName, "GaussianSplats3D:opacities",
+            Loggable<rerun::components::Opacity>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `spherical_harmonics` field.
+        static constexpr auto Descriptor_spherical_harmonics = ComponentDescriptor(
+            ArchetypeName, "GaussianSplats3D:spherical_harmonics",
+            Loggable<rerun::components::SphericalHarmonics>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `class_ids` field.
+        static constexpr auto Descriptor_class_ids = ComponentDescriptor(
+            ArchetypeName, "GaussianSplats3D:class_ids",
+            Loggable<rerun::components::ClassId>::ComponentType
+        );
+
+      public:
+        GaussianSplats3D() = default;
+        GaussianSplats3D(GaussianSplats3D&& other) = default;
+        GaussianSplats3D(const GaussianSplats3D& other) = default;
+        GaussianSplats3D& operator=(const GaussianSplats3D& other) = default;
+        GaussianSplats3D& operator=(GaussianSplats3D&& other) = default;
+
+        explicit GaussianSplats3D(Collection<rerun::components::Position3D> _centers)
+            : centers(ComponentBatch::from_loggable(std::move(_centers), Descriptor_centers)
+                          .value_or_throw()) {}
+
+        /// Update only some specific fields of a `GaussianSplats3D`.
+        static GaussianSplats3D update_fields() {
+            return GaussianSplats3D();
+        }
+
+        /// Clear all the fields of a `GaussianSplats3D`.
+        static GaussianSplats3D clear_fields();
+
+        /// The center of each splat.
+        GaussianSplats3D with_centers(const Collection<rerun::components::Position3D>& _centers
+        ) && {
+            centers = ComponentBatch::from_loggable(_centers, Descriptor_centers).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The orientation of each splat.
+        ///
+        /// If no orientation is specified, the axes of the splats align with the axes of the local coordinate system.
+        GaussianSplats3D with_quaternions(
+            const Collection<rerun::components::PoseRotationQuat>& _quaternions
+        ) && {
+            quaternions = ComponentBatch::from_loggable(_quaternions, Descriptor_quaternions)
+                              .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The standard deviation of each splat along its three local axes.
+        ///
+        /// If not specified, splats are isotropic with a standard deviation of 1% of the size of the bounding box of all centers.
+        GaussianSplats3D with_scales(const Collection<rerun::components::PoseScale3D>& _scales) && {
+            scales = ComponentBatch::from_loggable(_scales, Descriptor_scales).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The color of each splat.
+        ///
+        /// The alpha channel is multiplied with `components::Opacity`.
+        GaussianSplats3D with_colors(const Collection<rerun::components::Color>& _colors) && {
+            colors = ComponentBatch::from_loggable(_colors, Descriptor_colors).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The opacity at the center of each splat.
+        ///
+        /// Defaults to fully opaque.
+        GaussianSplats3D with_opacities(const Collection<rerun::components::Opacity>& _opacities
+        ) && {
+            opacities =
+                ComponentBatch::from_loggable(_opacities, Descriptor_opacities).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The view dependent part of the color of each splat.
+        ///
+        /// All splats are expected to have the same number of coefficients.
+        /// If not specified, splats look the same from all directions.
+        GaussianSplats3D with_spherical_harmonics(
+            const Collection<rerun::components::SphericalHarmonics>& _spherical_harmonics
+        ) && {
+            spherical_harmonics = ComponentBatch::from_loggable(
+                                      _spherical_harmonics,
+                                      Descriptor_spherical_harmonics
+            )
+                                      .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Optional class IDs for the splats.
+        ///
+        /// The class ID provides colors if not specified explicitly.
+        GaussianSplats3D with_class_ids(const Collection<rerun::components::ClassId>& _class_ids
+        ) && {
+            class_ids =
+                ComponentBatch::from_loggable(_class_ids, Descriptor_class_ids).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<archetypes::GaussianSplats3D> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const archetypes::GaussianSplats3D& archetype
+        );
+    };
+} // namespace rerun
@@ -10,15 +10,20 @@ namespace rerun::archetypes {
         auto archetype = Scalars();
         archetype.scalars =
             ComponentBatch::empty<rerun::components::Scalar>(Descriptor_scalars).value_or_throw();
+        archetype.unit =
+            ComponentBatch::empty<rerun::components::Unit>(Descriptor_unit).value_or_throw();
         return archetype;
     }
 
     Collection<ComponentColumn> Scalars::columns(const Collection<uint32_t>& lengths_) {
         std::vector<ComponentColumn> columns;
-        columns.reserve(1);
+        columns.reserve(2);
         if (scalars.has_value()) {
             columns.push_back(scalars.value().partitioned(lengths_).value_or_throw());
         }
+        if (unit.has_value()) {
+            columns.push_back(unit.value().partitioned(lengths_).value_or_throw());
+        }
         return columns;
     }
 
@@ -26,6 +31,9 @@ namespace rerun::archetypes {
         if (scalars.has_value()) {
             return columns(std::vector<uint32_t>(scalars.value().length(), 1));
         }
+        if (unit.has_value()) {
+            return columns(std::vector<uint32_t>(unit.value().length(), 1));
+        }
         return Collection<ComponentColumn>();
     }
 } // namespace rerun::archetypes
@@ -37,11 +45,14 @@ namespace rerun {
     ) {
         using namespace archetypes;
         std::vector<ComponentBatch> cells;
-        cells.reserve(1);
+        cells.reserve(2);
 
         if (archetype.scalars.has_value()) {
             cells.push_back(archetype.scalars.value());
         }
+        if (archetype.unit.has_value()) {
+            cells.push_back(archetype.unit.value());
+        }
 
         return rerun::take_ownership(std::move(cells));
     }
@@ -7,6 +7,7 @@
 #include "../component_batch.hpp"
 #include "../component_column.hpp"
 #include "../components/scalar.hpp"
+#include "../components/unit.hpp"
 #include "../result.hpp"
 
 #include <cstdint>
@@ -81,6 +82,11 @@ namespace rerun::archetypes {
         /// The scalar values to log.
         std::optional<ComponentBatch> scalars;
 
+        /// The unit the scalar values are logged in, e.g. `m/s` or `rad`.
+        ///
+        /// Shown on the axes and tooltips of plots. Expected to be unchanging over time.
+        std::optional<ComponentBatch> unit;
+
       public:
         /// The name of the archetype as used in `ComponentDescriptor`s.
         static constexpr const char ArchetypeName[] = "rerun.archetypes.Scalars";
@@ -89,6 +95,10 @@ namespace rerun::archetypes {
         static constexpr auto Descriptor_scalars = ComponentDescriptor(
             ArchetypeName, "Scalars:scalars", Loggable<rerun::components::Scalar>::ComponentType
         );
+        /// `ComponentDescriptor` for the `unit` field.
+        static constexpr auto Descriptor_unit = ComponentDescriptor(
+            ArchetypeName, "Scalars:unit", Loggable<rerun::components::Unit>::ComponentType
+        );
 
       public:
         Scalars() = default;
@@ -115,6 +125,23 @@ namespace rerun::archetypes {
             return std::move(*this);
         }
 
+        /// The unit the scalar values are logged in, e.g. `m/s` or `rad`.
+        ///
+        /// Shown on the axes and tooltips of plots. Expected to be unchanging over time.
+        Scalars with_unit(const rerun::components::Unit& _unit) && {
+            unit = ComponentBatch::from_loggable(_unit, Descriptor_unit).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `unit` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_unit` should
+        /// be used when logging a single row's worth of data.
+        Scalars with_many_unit(const Collection<rerun::components::Unit>& _unit) && {
+            unit = ComponentBatch::from_loggable(_unit, Descriptor_unit).value_or_throw();
+            return std::move(*this);
+        }
+
         /// Partitions the component data into multiple sub-batches.
         ///
         /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
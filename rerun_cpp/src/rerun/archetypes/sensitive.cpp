@@ -0,0 +1,48 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/sensitive.fbs".
+
+#include "sensitive.hpp"
+
+#include "../collection_adapter_builtins.hpp"
+
+namespace rerun::archetypes {
+    Sensitive Sensitive::clear_fields() {
+        auto archetype = Sensitive();
+        archetype.tags =
+            ComponentBatch::empty<rerun::components::Text>(Descriptor_tags).value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> Sensitive::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(1);
+        if (tags.has_value()) {
+            columns.push_back(tags.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> Sensitive::columns() {
+        if (tags.has_value()) {
+            return columns(std::vector<uint32_t>(tags.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>> AsComponents<archetypes::Sensitive>::as_batches(
+        const archetypes::Sensitive& archetype
+    ) {
+        using namespace archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(1);
+
+        if (archetype.tags.has_value()) {
+            cells.push_back(archetype.tags.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
@@ -0,0 +1,128 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/sensitive.fbs".
+
+#pragma once
+
+#include "../collection.hpp"
+#include "../component_batch.hpp"
+#include "../component_column.hpp"
+#include "../components/text.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::archetypes {
+    /// **Archetype**: Tags an entity, and everything below it, as holding sensitive data, e.g. camera images showing faces.
+    ///
+    /// Tagged entities can be redacted or left out when sharing data, see `rerun rrd filter --sensitive`
+    /// and `rerun rrd export --sensitive`.
+    /// The tag applies to all data of the entity, no matter when it was logged, so it's best logged as static.
+    ///
+    /// ## Example
+    ///
+    /// ### Tagging camera images as sensitive
+    /// ```cpp
+    /// #include <rerun.hpp>
+    ///
+    /// #include <cstdint>
+    /// #include <vector>
+    ///
+    /// int main() {
+    ///     const auto rec = rerun::RecordingStream("rerun_example_sensitive");
+    ///     rec.spawn().exit_on_failure();
+    ///
+    ///     // Everything logged to `camera` and below is tagged, no matter when it's logged.
+    ///     rec.log_static("camera", rerun::Sensitive({"faces"}));
+    ///
+    ///     for (int frame = 0; frame < 10; ++frame) {
+    ///         rec.set_time_sequence("frame", frame);
+    ///         std::vector<uint8_t> image(64 * 48 * 3, static_cast<uint8_t>(frame * 25));
+    ///         rec.log("camera/image", rerun::Image::from_rgb24(image, {64, 48}));
+    ///     }
+    ///
+    ///     // Share the recording without the faces, e.g. with:
+    ///     // `rerun rrd filter recording.rrd --sensitive redact -o shareable.rrd`
+    /// }
+    /// ```
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct Sensitive {
+        /// What makes the data sensitive, e.g. `faces` or `license_plates`.
+        ///
+        /// Lets exports redact some kinds of sensitive data but not others.
+        std::optional<ComponentBatch> tags;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.archetypes.Sensitive";
+
+        /// `ComponentDescriptor` for the `tags` field.
+        static constexpr auto Descriptor_tags = ComponentDescriptor(
+            ArchetypeName, "Sensitive:tags",
+            Loggable<rerun::components::Text>::ComponentType
+        );
+
+      public:
+        Sensitive() = default;
+        Sensitive(Sensitive&& other) = default;
+        Sensitive(const Sensitive& other) = default;
+        Sensitive& operator=(const Sensitive& other) = default;
+        Sensitive& operator=(Sensitive&& other) = default;
+
+        explicit Sensitive(Collection<rerun::components::Text> _tags)
+            : tags(ComponentBatch::from_loggable(std::move(_tags), Descriptor_tags)
+                       .value_or_throw()) {}
+
+        /// Update only some specific fields of a `Sensitive`.
+        static Sensitive update_fields() {
+            return Sensitive();
+        }
+
+        /// Clear all the fields of a `Sensitive`.
+        static Sensitive clear_fields();
+
+        /// What makes the data sensitive, e.g. `faces` or `license_plates`.
+        ///
+        /// Lets exports redact some kinds of sensitive data but not others.
+        Sensitive with_tags(const Collection<rerun::components::Text>& _tags) && {
+            tags = ComponentBatch::from_loggable(_tags, Descriptor_tags).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<archetypes::Sensitive> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const archetypes::Sensitive& archetype
+        );
+    };
+} // namespace rerun
@@ -21,12 +21,15 @@ namespace rerun::archetypes {
         archetype.marker_sizes =
             ComponentBatch::empty<rerun::components::MarkerSize>(Descriptor_marker_sizes)
                 .value_or_throw();
+        archetype.display_unit =
+            ComponentBatch::empty<rerun::components::Unit>(Descriptor_display_unit)
+                .value_or_throw();
         return archetype;
     }
 
     Collection<ComponentColumn> SeriesPoints::columns(const Collection<uint32_t>& lengths_) {
         std::vector<ComponentColumn> columns;
-        columns.reserve(5);
+        columns.reserve(6);
         if (colors.has_value()) {
             columns.push_back(colors.value().partitioned(lengths_).value_or_throw());
         }
@@ -42,6 +45,9 @@ namespace rerun::archetypes {
         if (marker_sizes.has_value()) {
             columns.push_back(marker_sizes.value().partitioned(lengths_).value_or_throw());
         }
+        if (display_unit.has_value()) {
+            columns.push_back(display_unit.value().partitioned(lengths_).value_or_throw());
+        }
         return columns;
     }
 
@@ -61,6 +67,9 @@ namespace rerun::archetypes {
         if (marker_sizes.has_value()) {
             return columns(std::vector<uint32_t>(marker_sizes.value().length(), 1));
         }
+        if (display_unit.has_value()) {
+            return columns(std::vector<uint32_t>(display_unit.value().length(), 1));
+        }
         return Collection<ComponentColumn>();
     }
 } // namespace rerun::archetypes
@@ -72,7 +81,7 @@ namespace rerun {
     ) {
         using namespace archetypes;
         std::vector<ComponentBatch> cells;
-        cells.reserve(5);
+        cells.reserve(6);
 
         if (archetype.colors.has_value()) {
             cells.push_back(archetype.colors.value());
@@ -89,6 +98,9 @@ namespace rerun {
         if (archetype.marker_sizes.has_value()) {
             cells.push_back(archetype.marker_sizes.value());
         }
+        if (archetype.display_unit.has_value()) {
+            cells.push_back(archetype.display_unit.value());
+        }
 
         return rerun::take_ownership(std::move(cells));
     }
@@ -11,6 +11,7 @@
 #include "../components/marker_size.hpp"
 #include "../components/name.hpp"
 #include "../components/series_visible.hpp"
+#include "../components/unit.hpp"
 #include "../result.hpp"
 
 #include <cstdint>
@@ -103,6 +104,14 @@ namespace rerun::archetypes {
         /// May change over time.
         std::optional<ComponentBatch> marker_sizes;
 
+        /// The unit the series are shown in, e.g. `deg` or `km/h`.
+        ///
+        /// Values are converted from the unit of the `archetypes::Scalars` if both are known units of the same quantity.
+        /// If not set, values are shown in the unit they were logged in.
+        ///
+        /// Expected to be unchanging over time.
+        std::optional<ComponentBatch> display_unit;
+
       public:
         /// The name of the archetype as used in `ComponentDescriptor`s.
         static constexpr const char ArchetypeName[] = "rerun.archetypes.SeriesPoints";
@@ -130,6 +139,11 @@ namespace rerun::archetypes {
             ArchetypeName, "SeriesPoints:marker_sizes",
             Loggable<rerun::components::MarkerSize>::ComponentType
         );
+        /// `ComponentDescriptor` for the `display_unit` field.
+        static constexpr auto Descriptor_display_unit = ComponentDescriptor(
+            ArchetypeName, "SeriesPoints:display_unit",
+            Loggable<rerun::components::Unit>::ComponentType
+        );
 
       public: // START of extensions from series_points_ext.cpp:
         // Overload needed to avoid confusion with passing single strings.
@@ -210,6 +224,30 @@ namespace rerun::archetypes {
             return std::move(*this);
         }
 
+        /// The unit the series are shown in, e.g. `deg` or `km/h`.
+        ///
+        /// Values are converted from the unit of the `archetypes::Scalars` if both are known units of the same quantity.
+        /// If not set, values are shown in the unit they were logged in.
+        ///
+        /// Expected to be unchanging over time.
+        SeriesPoints with_display_unit(const rerun::components::Unit& _display_unit) && {
+            display_unit = ComponentBatch::from_loggable(_display_unit, Descriptor_display_unit)
+                               .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `display_unit` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_display_unit` should
+        /// be used when logging a single row's worth of data.
+        SeriesPoints with_many_display_unit(
+            const Collection<rerun::components::Unit>& _display_unit
+        ) && {
+            display_unit = ComponentBatch::from_loggable(_display_unit, Descriptor_display_unit)
+                               .value_or_throw();
+            return std::move(*this);
+        }
+
         /// Partitions the component data into multiple sub-batches.
         ///
         /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
@@ -0,0 +1,142 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/skeleton.fbs".
+
+#include "skeleton.hpp"
+
+#include "../collection_adapter_builtins.hpp"
+
+namespace rerun::archetypes {
+    Skeleton Skeleton::clear_fields() {
+        auto archetype = Skeleton();
+        archetype.translations =
+            ComponentBatch::empty<rerun::components::PoseTranslation3D>(Descriptor_translations)
+                .value_or_throw();
+        archetype.parents =
+            ComponentBatch::empty<rerun::components::JointParent>(Descriptor_parents)
+                .value_or_throw();
+        archetype.quaternions =
+            ComponentBatch::empty<rerun::components::PoseRotationQuat>(Descriptor_quaternions)
+                .value_or_throw();
+        archetype.radii =
+            ComponentBatch::empty<rerun::components::Radius>(Descriptor_radii).value_or_throw();
+        archetype.colors =
+            ComponentBatch::empty<rerun::components::Color>(Descriptor_colors).value_or_throw();
+        archetype.labels =
+            ComponentBatch::empty<rerun::components::Text>(Descriptor_labels).value_or_throw();
+        archetype.show_labels =
+            ComponentBatch::empty<rerun::components::ShowLabels>(Descriptor_show_labels)
+                .value_or_throw();
+        archetype.axis_length =
+            ComponentBatch::empty<rerun::components::AxisLength>(Descriptor_axis_length)
+                .value_or_throw();
+        archetype.class_ids =
+            ComponentBatch::empty<rerun::components::ClassId>(Descriptor_class_ids)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> Skeleton::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(9);
+        if (translations.has_value()) {
+            columns.push_back(translations.value().partitioned(lengths_).value_or_throw());
+        }
+        if (parents.has_value()) {
+            columns.push_back(parents.value().partitioned(lengths_).value_or_throw());
+        }
+        if (quaternions.has_value()) {
+            columns.push_back(quaternions.value().partitioned(lengths_).value_or_throw());
+        }
+        if (radii.has_value()) {
+            columns.push_back(radii.value().partitioned(lengths_).value_or_throw());
+        }
+        if (colors.has_value()) {
+            columns.push_back(colors.value().partitioned(lengths_).value_or_throw());
+        }
+        if (labels.has_value()) {
+            columns.push_back(labels.value().partitioned(lengths_).value_or_throw());
+        }
+        if (show_labels.has_value()) {
+            columns.push_back(show_labels.value().partitioned(lengths_).value_or_throw());
+        }
+        if (axis_length.has_value()) {
+            columns.push_back(axis_length.value().partitioned(lengths_).value_or_throw());
+        }
+        if (class_ids.has_value()) {
+            columns.push_back(class_ids.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> Skeleton::columns() {
+        if (translations.has_value()) {
+            return columns(std::vector<uint32_t>(translations.value().length(), 1));
+        }
+        if (parents.has_value()) {
+            return columns(std::vector<uint32_t>(parents.value().length(), 1));
+        }
+        if (quaternions.has_value()) {
+            return columns(std::vector<uint32_t>(quaternions.value().length(), 1));
+        }
+        if (radii.has_value()) {
+            return columns(std::vector<uint32_t>(radii.value().length(), 1));
+        }
+        if (colors.has_value()) {
+            return columns(std::vector<uint32_t>(colors.value().length(), 1));
+        }
+        if (labels.has_value()) {
+            return columns(std::vector<uint32_t>(labels.value().length(), 1));
+        }
+        if (show_labels.has_value()) {
+            return columns(std::vector<uint32_t>(show_labels.value().length(), 1));
+        }
+        if (axis_length.has_value()) {
+            return columns(std::vector<uint32_t>(axis_length.value().length(), 1));
+        }
+        if (class_ids.has_value()) {
+            return columns(std::vector<uint32_t>(class_ids.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>> AsComponents<archetypes::Skeleton>::as_batches(
+        const archetypes::Skeleton& archetype
+    ) {
+        using namespace archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(9);
+
+        if (archetype.translations.has_value()) {
+            cells.push_back(archetype.translations.value());
+        }
+        if (archetype.parents.has_value()) {
+            cells.push_back(archetype.parents.value());
+        }
+        if (archetype.quaternions.has_value()) {
+            cells.push_back(archetype.quaternions.value());
+        }
+        if (archetype.radii.has_value()) {
+            cells.push_back(archetype.radii.value());
+        }
+        if (archetype.colors.has_value()) {
+            cells.push_back(archetype.colors.value());
+        }
+        if (archetype.labels.has_value()) {
+            cells.push_back(archetype.labels.value());
+        }
+        if (archetype.show_labels.has_value()) {
+            cells.push_back(archetype.show_labels.value());
+        }
+        if (archetype.axis_length.has_value()) {
+            cells.push_back(archetype.axis_length.value());
+        }
+        if (archetype.class_ids.has_value()) {
+            cells.push_back(archetype.class_ids.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
@@ -0,0 +1,322 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/skeleton.fbs".
+
+#pragma once
+
+#include "../collection.hpp"
+#include "../component_batch.hpp"
+#include "../component_column.hpp"
+#include "../components/axis_length.hpp"
+#include "../components/class_id.hpp"
+#include "../components/color.hpp"
+#include "../components/joint_parent.hpp"
+#include "../components/pose_rotation_quat.hpp"
+#include "../components/pose_translation3d.hpp"
+#include "../components/radius.hpp"
+#include "../components/show_labels.hpp"
+#include "../components/text.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::archetypes {
+    /// **Archetype**: A hierarchy of joints connected by bones, e.g. a human skeleton or the kinematic chain of a robot.
+    ///
+    /// Each joint is placed relative to its parent joint, or relative to the entity's origin for root joints.
+    /// The hierarchy is typically logged once as static data, whereas the joint transforms change over time.
+    ///
+    /// Joints are drawn as points and bones as lines between each joint and its parent.
+    /// If `components::AxisLength` is set, the coordinate frame of every joint is drawn as well.
+    ///
+    /// ## Example
+    ///
+    /// ### Simple kinematic chain
+    /// ```cpp
+    /// #include <rerun.hpp>
+    ///
+    /// #include <cmath>
+    ///
+    /// int main() {
+    ///     const auto rec = rerun::RecordingStream("rerun_example_skeleton_simple");
+    ///     rec.spawn().exit_on_failure();
+    ///
+    ///     // The hierarchy doesn't change, so it only needs to be logged once.
+    ///     rec.log_static(
+    ///         "arm",
+    ///         rerun::Skeleton::update_fields()
+    ///             .with_parents({0, 0, 1, 2})
+    ///             .with_labels({"base", "shoulder", "elbow", "wrist"})
+    ///             .with_axis_length(0.1f)
+    ///     );
+    ///
+    ///     for (int t = 0; t <100; ++t) {
+    ///         rec.set_time_sequence("step", t);
+    ///
+    ///         // Every joint is rotated by the same angle around the x axis relative to its parent.
+    ///         const float angle = std::sin(static_cast<float>(t) * 0.1f) * 0.5f;
+    ///         const auto rotation =
+    ///             rerun::Quaternion::from_xyzw(std::sin(angle / 2.0f), 0.0f, 0.0f, std::cos(angle / 2.0f));
+    ///
+    ///         rec.log(
+    ///             "arm",
+    ///             rerun::Skeleton({{0.0f, 0.0f, 0.0f}, {0.0f, 0.0f, 0.5f}, {0.0f, 0.0f, 0.4f}, {0.0f, 0.0f, 0.3f}})
+    ///                 .with_quaternions({rotation, rotation, rotation, rotation})
+    ///         );
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct Skeleton {
+        /// Translation of each joint relative to its parent joint.
+        std::optional<ComponentBatch> translations;
+
+        /// Index of the parent of each joint.
+        ///
+        /// Root joints reference themselves.
+        /// If not specified, all joints are treated as roots, i.e. they are placed relative to the entity's origin.
+        std::optional<ComponentBatch> parents;
+
+        /// Rotation of each joint relative to its parent joint.
+        ///
+        /// If not specified, joints aren't rotated relative to their parents.
+        std::optional<ComponentBatch> quaternions;
+
+        /// Optional radii for the joints.
+        ///
+        /// Bones are drawn with half the radius of the joint they lead to.
+        std::optional<ComponentBatch> radii;
+
+        /// Optional colors for the joints and bones.
+        ///
+        /// The bone leading to a joint uses the color of that joint.
+        std::optional<ComponentBatch> colors;
+
+        /// Optional text labels for the joints, e.g. their names.
+        ///
+        /// If there's a single label present, it will be placed at the center of the skeleton.
+        /// Otherwise, each label will be placed at its joint.
+        std::optional<ComponentBatch> labels;
+
+        /// Whether the text labels should be shown.
+        ///
+        /// If not set, labels will automatically appear when there is exactly one label for this entity
+        /// or the number of instances on this entity is under a certain threshold.
+        std::optional<ComponentBatch> show_labels;
+
+        /// Length of the coordinate axes drawn at each joint.
+        ///
+        /// If not set, no axes are drawn.
+        std::optional<ComponentBatch> axis_length;
+
+        /// Optional class IDs for the joints.
+        ///
+        /// The `components::ClassId` provides colors and labels if not specified explicitly.
+        std::optional<ComponentBatch> class_ids;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.archetypes.Skeleton";
+
+        /// `ComponentDescriptor` for the `translations` field.
+        static constexpr auto Descriptor_translations = ComponentDescriptor(
+            ArchetypeName, "Skeleton:translations",
+            Loggable<rerun::components::PoseTranslation3D>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `parents` field.
+        static constexpr auto Descriptor_parents = ComponentDescriptor(
+            ArchetypeName, "Skeleton:parents",
+            Loggable<rerun::components::JointParent>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `quaternions` field.
+        static constexpr auto Descriptor_quaternions = ComponentDescriptor(
+            ArchetypeName, "Skeleton:quaternions",
+            Loggable<rerun::components::PoseRotationQuat>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `radii` field.
+        static constexpr auto Descriptor_radii = ComponentDescriptor(
+            ArchetypeName, "Skeleton:radii", Loggable<rerun::components::Radius>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `colors` field.
+        static constexpr auto Descriptor_colors = ComponentDescriptor(
+            ArchetypeName, "Skeleton:colors", Loggable<rerun::components::Color>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `labels` field.
+        static constexpr auto Descriptor_labels = ComponentDescriptor(
+            ArchetypeName, "Skeleton:labels", Loggable<rerun::components::Text>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `show_labels` field.
+        static constexpr auto Descriptor_show_labels = ComponentDescriptor(
+            ArchetypeName, "Skeleton:show_labels",
+            Loggable<rerun::components::ShowLabels>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `axis_length` field.
+        static constexpr auto Descriptor_axis_length = ComponentDescriptor(
+            ArchetypeName, "Skeleton:axis_length",
+            Loggable<rerun::components::AxisLength>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `class_ids` field.
+        static constexpr auto Descriptor_class_ids = ComponentDescriptor(
+            ArchetypeName, "Skeleton:class_ids", Loggable<rerun::components::ClassId>::ComponentType
+        );
+
+      public:
+        Skeleton() = default;
+        Skeleton(Skeleton&& other) = default;
+        Skeleton(const Skeleton& other) = default;
+        Skeleton& operator=(const Skeleton& other) = default;
+        Skeleton& operator=(Skeleton&& other) = default;
+
+        explicit Skeleton(Collection<rerun::components::PoseTranslation3D> _translations)
+            : translations(
+                  ComponentBatch::from_loggable(std::move(_translations), Descriptor_translations)
+                      .value_or_throw()
+              ) {}
+
+        /// Update only some specific fields of a `Skeleton`.
+        static Skeleton update_fields() {
+            return Skeleton();
+        }
+
+        /// Clear all the fields of a `Skeleton`.
+        static Skeleton clear_fields();
+
+        /// Translation of each joint relative to its parent joint.
+        Skeleton with_translations(
+            const Collection<rerun::components::PoseTranslation3D>& _translations
+        ) && {
+            translations = ComponentBatch::from_loggable(_translations, Descriptor_translations)
+                               .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Index of the parent of each joint.
+        ///
+        /// Root joints reference themselves.
+        /// If not specified, all joints are treated as roots, i.e. they are placed relative to the entity's origin.
+        Skeleton with_parents(const Collection<rerun::components::JointParent>& _parents) && {
+            parents = ComponentBatch::from_loggable(_parents, Descriptor_parents).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Rotation of each joint relative to its parent joint.
+        ///
+        /// If not specified, joints aren't rotated relative to their parents.
+        Skeleton with_quaternions(
+            const Collection<rerun::components::PoseRotationQuat>& _quaternions
+        ) && {
+            quaternions = ComponentBatch::from_loggable(_quaternions, Descriptor_quaternions)
+                              .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Optional radii for the joints.
+        ///
+        /// Bones are drawn with half the radius of the joint they lead to.
+        Skeleton with_radii(const Collection<rerun::components::Radius>& _radii) && {
+            radii = ComponentBatch::from_loggable(_radii, Descriptor_radii).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Optional colors for the joints and bones.
+        ///
+        /// The bone leading to a joint uses the color of that joint.
+        Skeleton with_colors(const Collection<rerun::components::Color>& _colors) && {
+            colors = ComponentBatch::from_loggable(_colors, Descriptor_colors).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Optional text labels for the joints, e.g. their names.
+        ///
+        /// If there's a single label present, it will be placed at the center of the skeleton.
+        /// Otherwise, each label will be placed at its joint.
+        Skeleton with_labels(const Collection<rerun::components::Text>& _labels) && {
+            labels = ComponentBatch::from_loggable(_labels, Descriptor_labels).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Whether the text labels should be shown.
+        ///
+        /// If not set, labels will automatically appear when there is exactly one label for this entity
+        /// or the number of instances on this entity is under a certain threshold.
+        Skeleton with_show_labels(const rerun::components::ShowLabels& _show_labels) && {
+            show_labels = ComponentBatch::from_loggable(_show_labels, Descriptor_show_labels)
+                              .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `show_labels` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_show_labels` should
+        /// be used when logging a single row's worth of data.
+        Skeleton with_many_show_labels(const Collection<rerun::components::ShowLabels>& _show_labels
+        ) && {
+            show_labels = ComponentBatch::from_loggable(_show_labels, Descriptor_show_labels)
+                              .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Length of the coordinate axes drawn at each joint.
+        ///
+        /// If not set, no axes are drawn.
+        Skeleton with_axis_length(const rerun::components::AxisLength& _axis_length) && {
+            axis_length = ComponentBatch::from_loggable(_axis_length, Descriptor_axis_length)
+                              .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `axis_length` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_axis_length` should
+        /// be used when logging a single row's worth of data.
+        Skeleton with_many_axis_length(const Collection<rerun::components::AxisLength>& _axis_length
+        ) && {
+            axis_length = ComponentBatch::from_loggable(_axis_length, Descriptor_axis_length)
+                              .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Optional class IDs for the joints.
+        ///
+        /// The `components::ClassId` provides colors and labels if not specified explicitly.
+        Skeleton with_class_ids(const Collection<rerun::components::ClassId>& _class_ids) && {
+            class_ids =
+                ComponentBatch::from_loggable(_class_ids, Descriptor_class_ids).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<archetypes::Skeleton> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(const archetypes::Skeleton& archetype);
+    };
+} // namespace rerun
@@ -3,23 +3,37 @@
 #pragma once
 
 #include "blueprint/archetypes/background.hpp"
+#include "blueprint/archetypes/bar_chart_layout.hpp"
+#include "blueprint/archetypes/class_id_remap.hpp"
+#include "blueprint/archetypes/color_by_component.hpp"
 #include "blueprint/archetypes/container_blueprint.hpp"
+#include "blueprint/archetypes/coordinate_conversion.hpp"
+#include "blueprint/archetypes/crop_box.hpp"
 #include "blueprint/archetypes/dataframe_query.hpp"
+#include "blueprint/archetypes/depth_clouds3d.hpp"
 #include "blueprint/archetypes/entity_behavior.hpp"
 #include "blueprint/archetypes/eye_controls3d.hpp"
+#include "blueprint/archetypes/fft_window.hpp"
 #include "blueprint/archetypes/force_center.hpp"
 #include "blueprint/archetypes/force_collision_radius.hpp"
 #include "blueprint/archetypes/force_link.hpp"
 #include "blueprint/archetypes/force_many_body.hpp"
 #include "blueprint/archetypes/force_position.hpp"
 #include "blueprint/archetypes/graph_background.hpp"
+#include "blueprint/archetypes/hover_tooltip.hpp"
+#include "blueprint/archetypes/image_planes3d.hpp"
+#include "blueprint/archetypes/instance_filter.hpp"
 #include "blueprint/archetypes/line_grid3d.hpp"
 #include "blueprint/archetypes/map_background.hpp"
 #include "blueprint/archetypes/map_zoom.hpp"
 #include "blueprint/archetypes/near_clip_plane.hpp"
+#include "blueprint/archetypes/onion_skin.hpp"
+#include "blueprint/archetypes/outlines.hpp"
 #include "blueprint/archetypes/panel_blueprint.hpp"
 #include "blueprint/archetypes/plot_background.hpp"
 #include "blueprint/archetypes/plot_legend.hpp"
+#include "blueprint/archetypes/point_shading.hpp"
+#include "blueprint/archetypes/rigid_alignment.hpp"
 #include "blueprint/archetypes/scalar_axis.hpp"
 #include "blueprint/archetypes/spatial_information.hpp"
 #include "blueprint/archetypes/tensor_scalar_mapping.hpp"
@@ -27,9 +41,11 @@
 #include "blueprint/archetypes/tensor_view_fit.hpp"
 #include "blueprint/archetypes/time_axis.hpp"
 #include "blueprint/archetypes/time_panel_blueprint.hpp"
+#include "blueprint/archetypes/trajectory_trails.hpp"
 #include "blueprint/archetypes/view_blueprint.hpp"
 #include "blueprint/archetypes/view_contents.hpp"
 #include "blueprint/archetypes/viewport_blueprint.hpp"
+#include "blueprint/archetypes/visibility_rules.hpp"
 #include "blueprint/archetypes/visible_time_ranges.hpp"
 #include "blueprint/archetypes/visual_bounds2d.hpp"
 #include "blueprint/archetypes/visualizer_overrides.hpp"
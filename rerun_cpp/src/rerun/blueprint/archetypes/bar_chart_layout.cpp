@@ -0,0 +1,50 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/bar_chart_layout.fbs".
+
+#include "bar_chart_layout.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    BarChartLayout BarChartLayout::clear_fields() {
+        auto archetype = BarChartLayout();
+        archetype.mode =
+            ComponentBatch::empty<rerun::blueprint::components::BarChartMode>(Descriptor_mode)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> BarChartLayout::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(1);
+        if (mode.has_value()) {
+            columns.push_back(mode.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> BarChartLayout::columns() {
+        if (mode.has_value()) {
+            return columns(std::vector<uint32_t>(mode.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>>
+        AsComponents<blueprint::archetypes::BarChartLayout>::as_batches(
+            const blueprint::archetypes::BarChartLayout& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(1);
+
+        if (archetype.mode.has_value()) {
+            cells.push_back(archetype.mode.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
@@ -0,0 +1,89 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/bar_chart_layout.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/bar_chart_mode.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Configures how the bar charts of several entities are combined in a bar chart view.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct BarChartLayout {
+        /// How the bars of different entities at the same position are arranged.
+        std::optional<ComponentBatch> mode;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.BarChartLayout";
+
+        /// `ComponentDescriptor` for the `mode` field.
+        static constexpr auto Descriptor_mode = ComponentDescriptor(
+            ArchetypeName, "BarChartLayout:mode",
+            Loggable<rerun::blueprint::components::BarChartMode>::ComponentType
+        );
+
+      public:
+        BarChartLayout() = default;
+        BarChartLayout(BarChartLayout&& other) = default;
+        BarChartLayout(const BarChartLayout& other) = default;
+        BarChartLayout& operator=(const BarChartLayout& other) = default;
+        BarChartLayout& operator=(BarChartLayout&& other) = default;
+
+        /// Update only some specific fields of a `BarChartLayout`.
+        static BarChartLayout update_fields() {
+            return BarChartLayout();
+        }
+
+        /// Clear all the fields of a `BarChartLayout`.
+        static BarChartLayout clear_fields();
+
+        /// How the bars of different entities at the same position are arranged.
+        BarChartLayout with_mode(const rerun::blueprint::components::BarChartMode& _mode) && {
+            mode = ComponentBatch::from_loggable(_mode, Descriptor_mode).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::BarChartLayout> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::BarChartLayout& archetype
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,62 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/class_id_remap.fbs".
+
+#include "class_id_remap.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    ClassIdRemap ClassIdRemap::clear_fields() {
+        auto archetype = ClassIdRemap();
+        archetype.source_class_ids =
+            ComponentBatch::empty<rerun::components::ClassId>(Descriptor_source_class_ids)
+                .value_or_throw();
+        archetype.target_class_ids =
+            ComponentBatch::empty<rerun::components::ClassId>(Descriptor_target_class_ids)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> ClassIdRemap::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(2);
+        if (source_class_ids.has_value()) {
+            columns.push_back(source_class_ids.value().partitioned(lengths_).value_or_throw());
+        }
+        if (target_class_ids.has_value()) {
+            columns.push_back(target_class_ids.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> ClassIdRemap::columns() {
+        if (source_class_ids.has_value()) {
+            return columns(std::vector<uint32_t>(source_class_ids.value().length(), 1));
+        }
+        if (target_class_ids.has_value()) {
+            return columns(std::vector<uint32_t>(target_class_ids.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>>
+        AsComponents<blueprint::archetypes::ClassIdRemap>::as_batches(
+            const blueprint::archetypes::ClassIdRemap& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(2);
+
+        if (archetype.source_class_ids.has_value()) {
+            cells.push_back(archetype.source_class_ids.value());
+        }
+        if (archetype.target_class_ids.has_value()) {
+            cells.push_back(archetype.target_class_ids.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
@@ -0,0 +1,119 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/class_id_remap.fbs".
+
+#pragma once
+
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/class_id.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Remaps class ids of the data shown in a view, e.g. to merge the classes of a segmentation image.
+    ///
+    /// Each entry of `source_class_ids` is shown as the class at the same index in `target_class_ids`,
+    /// i.e. with its color and label from the `archetypes::AnnotationContext`.
+    /// Class ids that are not listed are shown as they are.
+    ///
+    /// This applies to everything with class ids in the view, and doesn't change the logged data.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct ClassIdRemap {
+        /// The class ids that are remapped.
+        std::optional<ComponentBatch> source_class_ids;
+
+        /// The class id that each entry of `source_class_ids` is remapped to.
+        ///
+        /// Must have the same length as `source_class_ids`, extra entries of either are ignored.
+        std::optional<ComponentBatch> target_class_ids;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.ClassIdRemap";
+
+        /// `ComponentDescriptor` for the `source_class_ids` field.
+        static constexpr auto Descriptor_source_class_ids = ComponentDescriptor(
+            ArchetypeName, "ClassIdRemap:source_class_ids",
+            Loggable<rerun::components::ClassId>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `target_class_ids` field.
+        static constexpr auto Descriptor_target_class_ids = ComponentDescriptor(
+            ArchetypeName, "ClassIdRemap:target_class_ids",
+            Loggable<rerun::components::ClassId>::ComponentType
+        );
+
+      public:
+        ClassIdRemap() = default;
+        ClassIdRemap(ClassIdRemap&& other) = default;
+        ClassIdRemap(const ClassIdRemap& other) = default;
+        ClassIdRemap& operator=(const ClassIdRemap& other) = default;
+        ClassIdRemap& operator=(ClassIdRemap&& other) = default;
+
+        /// Update only some specific fields of a `ClassIdRemap`.
+        static ClassIdRemap update_fields() {
+            return ClassIdRemap();
+        }
+
+        /// Clear all the fields of a `ClassIdRemap`.
+        static ClassIdRemap clear_fields();
+
+        /// The class ids that are remapped.
+        ClassIdRemap with_source_class_ids(
+            const Collection<rerun::components::ClassId>& _source_class_ids
+        ) && {
+            source_class_ids =
+                ComponentBatch::from_loggable(_source_class_ids, Descriptor_source_class_ids).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The class id that each entry of `source_class_ids` is remapped to.
+        ///
+        /// Must have the same length as `source_class_ids`, extra entries of either are ignored.
+        ClassIdRemap with_target_class_ids(
+            const Collection<rerun::components::ClassId>& _target_class_ids
+        ) && {
+            target_class_ids =
+                ComponentBatch::from_loggable(_target_class_ids, Descriptor_target_class_ids).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::ClassIdRemap> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::ClassIdRemap& archetype
+        );
+    };
+} // namespace rerun
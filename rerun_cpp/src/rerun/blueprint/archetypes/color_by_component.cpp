@@ -0,0 +1,72 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/color_by_component.fbs".
+
+#include "color_by_component.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    ColorByComponent ColorByComponent::clear_fields() {
+        auto archetype = ColorByComponent();
+        archetype.component =
+            ComponentBatch::empty<rerun::components::Name>(Descriptor_component).value_or_throw();
+        archetype.colormap =
+            ComponentBatch::empty<rerun::components::Colormap>(Descriptor_colormap)
+                .value_or_throw();
+        archetype.range =
+            ComponentBatch::empty<rerun::components::ValueRange>(Descriptor_range).value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> ColorByComponent::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(3);
+        if (component.has_value()) {
+            columns.push_back(component.value().partitioned(lengths_).value_or_throw());
+        }
+        if (colormap.has_value()) {
+            columns.push_back(colormap.value().partitioned(lengths_).value_or_throw());
+        }
+        if (range.has_value()) {
+            columns.push_back(range.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> ColorByComponent::columns() {
+        if (component.has_value()) {
+            return columns(std::vector<uint32_t>(component.value().length(), 1));
+        }
+        if (colormap.has_value()) {
+            return columns(std::vector<uint32_t>(colormap.value().length(), 1));
+        }
+        if (range.has_value()) {
+            return columns(std::vector<uint32_t>(range.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>>
+        AsComponents<blueprint::archetypes::ColorByComponent>::as_batches(
+            const blueprint::archetypes::ColorByComponent& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(3);
+
+        if (archetype.component.has_value()) {
+            cells.push_back(archetype.component.value());
+        }
+        if (archetype.colormap.has_value()) {
+            cells.push_back(archetype.colormap.value());
+        }
+        if (archetype.range.has_value()) {
+            cells.push_back(archetype.range.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
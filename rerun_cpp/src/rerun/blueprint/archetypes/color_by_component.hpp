@@ -0,0 +1,144 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/color_by_component.fbs".
+
+#pragma once
+
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/colormap.hpp"
+#include "../../components/name.hpp"
+#include "../../components/value_range.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Colors the instances of an entity by the value of a scalar component logged alongside them.
+    ///
+    /// Log this as an override of the entity in a 3D view, e.g. to color points by `intensity`
+    /// or boxes by `confidence`.
+    /// Applies to points, boxes, ellipsoids, capsules, cylinders, and meshes,
+    /// and takes precedence over their logged colors.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct ColorByComponent {
+        /// The component whose values are mapped to colors, e.g. `intensity`.
+        ///
+        /// Either the full component identifier or just the part after the archetype prefix.
+        /// Any numeric component works.
+        /// If unset, or if the entity doesn't have this component, the instances keep their colors.
+        std::optional<ComponentBatch> component;
+
+        /// The colormap the values are mapped through.
+        ///
+        /// Defaults to viridis.
+        std::optional<ComponentBatch> colormap;
+
+        /// The values mapped to the start and end of the colormap.
+        ///
+        /// Values outside of this range are clamped.
+        /// If unset, the range of the entity's values at the current time is used.
+        std::optional<ComponentBatch> range;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.ColorByComponent";
+
+        /// `ComponentDescriptor` for the `component` field.
+        static constexpr auto Descriptor_component = ComponentDescriptor(
+            ArchetypeName, "ColorByComponent:component",
+            Loggable<rerun::components::Name>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `colormap` field.
+        static constexpr auto Descriptor_colormap = ComponentDescriptor(
+            ArchetypeName, "ColorByComponent:colormap",
+            Loggable<rerun::components::Colormap>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `range` field.
+        static constexpr auto Descriptor_range = ComponentDescriptor(
+            ArchetypeName, "ColorByComponent:range",
+            Loggable<rerun::components::ValueRange>::ComponentType
+        );
+
+      public:
+        ColorByComponent() = default;
+        ColorByComponent(ColorByComponent&& other) = default;
+        ColorByComponent(const ColorByComponent& other) = default;
+        ColorByComponent& operator=(const ColorByComponent& other) = default;
+        ColorByComponent& operator=(ColorByComponent&& other) = default;
+
+        /// Update only some specific fields of a `ColorByComponent`.
+        static ColorByComponent update_fields() {
+            return ColorByComponent();
+        }
+
+        /// Clear all the fields of a `ColorByComponent`.
+        static ColorByComponent clear_fields();
+
+        /// The component whose values are mapped to colors, e.g. `intensity`.
+        ///
+        /// Either the full component identifier or just the part after the archetype prefix.
+        /// Any numeric component works.
+        /// If unset, or if the entity doesn't have this component, the instances keep their colors.
+        ColorByComponent with_component(const rerun::components::Name& _component) && {
+            component =
+                ComponentBatch::from_loggable(_component, Descriptor_component).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The colormap the values are mapped through.
+        ///
+        /// Defaults to viridis.
+        ColorByComponent with_colormap(const rerun::components::Colormap& _colormap) && {
+            colormap =
+                ComponentBatch::from_loggable(_colormap, Descriptor_colormap).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The values mapped to the start and end of the colormap.
+        ///
+        /// Values outside of this range are clamped.
+        /// If unset, the range of the entity's values at the current time is used.
+        ColorByComponent with_range(const rerun::components::ValueRange& _range) && {
+            range = ComponentBatch::from_loggable(_range, Descriptor_range).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::ColorByComponent> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::ColorByComponent& archetype
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,86 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/coordinate_conversion.fbs".
+
+#include "coordinate_conversion.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    CoordinateConversion CoordinateConversion::clear_fields() {
+        auto archetype = CoordinateConversion();
+        archetype.source_coordinates =
+            ComponentBatch::empty<rerun::components::ViewCoordinates>(Descriptor_source_coordinates)
+                .value_or_throw();
+        archetype.target_coordinates =
+            ComponentBatch::empty<rerun::components::ViewCoordinates>(Descriptor_target_coordinates)
+                .value_or_throw();
+        archetype.earth_centered =
+            ComponentBatch::empty<rerun::blueprint::components::Enabled>(Descriptor_earth_centered)
+                .value_or_throw();
+        archetype.geo_origin =
+            ComponentBatch::empty<rerun::components::LatLon>(Descriptor_geo_origin).value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> CoordinateConversion::columns(const Collection<uint32_t>& lengths_
+    ) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(4);
+        if (source_coordinates.has_value()) {
+            columns.push_back(source_coordinates.value().partitioned(lengths_).value_or_throw());
+        }
+        if (target_coordinates.has_value()) {
+            columns.push_back(target_coordinates.value().partitioned(lengths_).value_or_throw());
+        }
+        if (earth_centered.has_value()) {
+            columns.push_back(earth_centered.value().partitioned(lengths_).value_or_throw());
+        }
+        if (geo_origin.has_value()) {
+            columns.push_back(geo_origin.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> CoordinateConversion::columns() {
+        if (source_coordinates.has_value()) {
+            return columns(std::vector<uint32_t>(source_coordinates.value().length(), 1));
+        }
+        if (target_coordinates.has_value()) {
+            return columns(std::vector<uint32_t>(target_coordinates.value().length(), 1));
+        }
+        if (earth_centered.has_value()) {
+            return columns(std::vector<uint32_t>(earth_centered.value().length(), 1));
+        }
+        if (geo_origin.has_value()) {
+            return columns(std::vector<uint32_t>(geo_origin.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>>
+        AsComponents<blueprint::archetypes::CoordinateConversion>::as_batches(
+            const blueprint::archetypes::CoordinateConversion& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(4);
+
+        if (archetype.source_coordinates.has_value()) {
+            cells.push_back(archetype.source_coordinates.value());
+        }
+        if (archetype.target_coordinates.has_value()) {
+            cells.push_back(archetype.target_coordinates.value());
+        }
+        if (archetype.earth_centered.has_value()) {
+            cells.push_back(archetype.earth_centered.value());
+        }
+        if (archetype.geo_origin.has_value()) {
+            cells.push_back(archetype.geo_origin.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
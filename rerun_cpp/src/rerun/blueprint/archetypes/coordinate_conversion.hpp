@@ -0,0 +1,180 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/coordinate_conversion.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/enabled.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/lat_lon.hpp"
+#include "../../components/view_coordinates.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Converts the data shown in a 3D view from the coordinate convention it was logged in to another one.
+    ///
+    /// E.g. this makes it possible to show data logged in NED (`FRD`) in an ENU (`RFU`) world,
+    /// or data logged in Earth-centered, Earth-fixed coordinates in the local tangent plane at `geo_origin`.
+    ///
+    /// This only changes how the data is shown, not the logged data itself.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct CoordinateConversion {
+        /// The coordinate convention the data is logged in, e.g. `FRD` for NED.
+        ///
+        /// Defaults to the `components::ViewCoordinates` logged at the space origin, if any.
+        std::optional<ComponentBatch> source_coordinates;
+
+        /// The coordinate convention the data is shown in, e.g. `RFU` for ENU.
+        ///
+        /// Defaults to `source_coordinates`, i.e. no conversion.
+        std::optional<ComponentBatch> target_coordinates;
+
+        /// Whether the data is logged in Earth-centered, Earth-fixed (ECEF) coordinates, in meters.
+        ///
+        /// If enabled, the data is shown in the east-north-up tangent plane at `geo_origin` instead,
+        /// and `source_coordinates` is ignored.
+        ///
+        /// Defaults to false.
+        std::optional<ComponentBatch> earth_centered;
+
+        /// The origin of the local tangent plane, on the WGS84 ellipsoid.
+        ///
+        /// Used for Earth-centered data, and to place `archetypes::GeoPoints` in the view,
+        /// which the `GeoPoints3D` visualizer does in an east-north-up frame.
+        ///
+        /// Defaults to 0° latitude, 0° longitude.
+        std::optional<ComponentBatch> geo_origin;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] =
+            "rerun.blueprint.archetypes.CoordinateConversion";
+
+        /// `ComponentDescriptor` for the `source_coordinates` field.
+        static constexpr auto Descriptor_source_coordinates = ComponentDescriptor(
+            ArchetypeName, "CoordinateConversion:source_coordinates",
+            Loggable<rerun::components::ViewCoordinates>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `target_coordinates` field.
+        static constexpr auto Descriptor_target_coordinates = ComponentDescriptor(
+            ArchetypeName, "CoordinateConversion:target_coordinates",
+            Loggable<rerun::components::ViewCoordinates>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `earth_centered` field.
+        static constexpr auto Descriptor_earth_centered = ComponentDescriptor(
+            ArchetypeName, "CoordinateConversion:earth_centered",
+            Loggable<rerun::blueprint::components::Enabled>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `geo_origin` field.
+        static constexpr auto Descriptor_geo_origin = ComponentDescriptor(
+            ArchetypeName, "CoordinateConversion:geo_origin",
+            Loggable<rerun::components::LatLon>::ComponentType
+        );
+
+      public:
+        CoordinateConversion() = default;
+        CoordinateConversion(CoordinateConversion&& other) = default;
+        CoordinateConversion(const CoordinateConversion& other) = default;
+        CoordinateConversion& operator=(const CoordinateConversion& other) = default;
+        CoordinateConversion& operator=(CoordinateConversion&& other) = default;
+
+        /// Update only some specific fields of a `CoordinateConversion`.
+        static CoordinateConversion update_fields() {
+            return CoordinateConversion();
+        }
+
+        /// Clear all the fields of a `CoordinateConversion`.
+        static CoordinateConversion clear_fields();
+
+        /// The coordinate convention the data is logged in, e.g. `FRD` for NED.
+        ///
+        /// Defaults to the `components::ViewCoordinates` logged at the space origin, if any.
+        CoordinateConversion with_source_coordinates(
+            const rerun::components::ViewCoordinates& _source_coordinates
+        ) && {
+            source_coordinates =
+                ComponentBatch::from_loggable(_source_coordinates, Descriptor_source_coordinates)
+                    .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The coordinate convention the data is shown in, e.g. `RFU` for ENU.
+        ///
+        /// Defaults to `source_coordinates`, i.e. no conversion.
+        CoordinateConversion with_target_coordinates(
+            const rerun::components::ViewCoordinates& _target_coordinates
+        ) && {
+            target_coordinates =
+                ComponentBatch::from_loggable(_target_coordinates, Descriptor_target_coordinates)
+                    .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Whether the data is logged in Earth-centered, Earth-fixed (ECEF) coordinates, in meters.
+        ///
+        /// If enabled, the data is shown in the east-north-up tangent plane at `geo_origin` instead,
+        /// and `source_coordinates` is ignored.
+        ///
+        /// Defaults to false.
+        CoordinateConversion with_earth_centered(
+            const rerun::blueprint::components::Enabled& _earth_centered
+        ) && {
+            earth_centered =
+                ComponentBatch::from_loggable(_earth_centered, Descriptor_earth_centered)
+                    .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The origin of the local tangent plane, on the WGS84 ellipsoid.
+        ///
+        /// Used for Earth-centered data, and to place `archetypes::GeoPoints` in the view,
+        /// which the `GeoPoints3D` visualizer does in an east-north-up frame.
+        ///
+        /// Defaults to 0° latitude, 0° longitude.
+        CoordinateConversion with_geo_origin(const rerun::components::LatLon& _geo_origin) && {
+            geo_origin =
+                ComponentBatch::from_loggable(_geo_origin, Descriptor_geo_origin).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::CoordinateConversion> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::CoordinateConversion& archetype
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,86 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/crop_box.fbs".
+
+#include "crop_box.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    CropBox CropBox::clear_fields() {
+        auto archetype = CropBox();
+        archetype.enabled =
+            ComponentBatch::empty<rerun::blueprint::components::Enabled>(Descriptor_enabled)
+                .value_or_throw();
+        archetype.center =
+            ComponentBatch::empty<rerun::components::Translation3D>(Descriptor_center)
+                .value_or_throw();
+        archetype.half_size =
+            ComponentBatch::empty<rerun::components::HalfSize3D>(Descriptor_half_size)
+                .value_or_throw();
+        archetype.keep_outside =
+            ComponentBatch::empty<rerun::blueprint::components::Enabled>(Descriptor_keep_outside)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> CropBox::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(4);
+        if (enabled.has_value()) {
+            columns.push_back(enabled.value().partitioned(lengths_).value_or_throw());
+        }
+        if (center.has_value()) {
+            columns.push_back(center.value().partitioned(lengths_).value_or_throw());
+        }
+        if (half_size.has_value()) {
+            columns.push_back(half_size.value().partitioned(lengths_).value_or_throw());
+        }
+        if (keep_outside.has_value()) {
+            columns.push_back(keep_outside.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> CropBox::columns() {
+        if (enabled.has_value()) {
+            return columns(std::vector<uint32_t>(enabled.value().length(), 1));
+        }
+        if (center.has_value()) {
+            return columns(std::vector<uint32_t>(center.value().length(), 1));
+        }
+        if (half_size.has_value()) {
+            return columns(std::vector<uint32_t>(half_size.value().length(), 1));
+        }
+        if (keep_outside.has_value()) {
+            return columns(std::vector<uint32_t>(keep_outside.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>>
+        AsComponents<blueprint::archetypes::CropBox>::as_batches(
+            const blueprint::archetypes::CropBox& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(4);
+
+        if (archetype.enabled.has_value()) {
+            cells.push_back(archetype.enabled.value());
+        }
+        if (archetype.center.has_value()) {
+            cells.push_back(archetype.center.value());
+        }
+        if (archetype.half_size.has_value()) {
+            cells.push_back(archetype.half_size.value());
+        }
+        if (archetype.keep_outside.has_value()) {
+            cells.push_back(archetype.keep_outside.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
@@ -0,0 +1,157 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/crop_box.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/enabled.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/half_size3d.hpp"
+#include "../../components/translation3d.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Hides the points of point clouds inside or outside of an axis-aligned box in a 3D view.
+    ///
+    /// The points are filtered on the GPU, so this stays fast even for very large point clouds.
+    /// Depth images shown as point clouds are cropped the same way, Gaussian splats by their center.
+    /// Hidden points can't be hovered or selected.
+    /// The box can be moved and resized by dragging its faces in the view.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct CropBox {
+        /// Whether the crop box is applied.
+        ///
+        /// Defaults to false.
+        std::optional<ComponentBatch> enabled;
+
+        /// The center of the box, in the coordinates of the view's origin.
+        ///
+        /// Defaults to the origin.
+        std::optional<ComponentBatch> center;
+
+        /// Half the size of the box along each axis.
+        ///
+        /// Defaults to 1 along each axis.
+        std::optional<ComponentBatch> half_size;
+
+        /// If enabled, the points inside the box are hidden instead of those outside.
+        ///
+        /// Defaults to false.
+        std::optional<ComponentBatch> keep_outside;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.CropBox";
+
+        /// `ComponentDescriptor` for the `enabled` field.
+        static constexpr auto Descriptor_enabled = ComponentDescriptor(
+            ArchetypeName, "CropBox:enabled",
+            Loggable<rerun::blueprint::components::Enabled>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `center` field.
+        static constexpr auto Descriptor_center = ComponentDescriptor(
+            ArchetypeName, "CropBox:center",
+            Loggable<rerun::components::Translation3D>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `half_size` field.
+        static constexpr auto Descriptor_half_size = ComponentDescriptor(
+            ArchetypeName, "CropBox:half_size",
+            Loggable<rerun::components::HalfSize3D>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `keep_outside` field.
+        static constexpr auto Descriptor_keep_outside = ComponentDescriptor(
+            ArchetypeName, "CropBox:keep_outside",
+            Loggable<rerun::blueprint::components::Enabled>::ComponentType
+        );
+
+      public:
+        CropBox() = default;
+        CropBox(CropBox&& other) = default;
+        CropBox(const CropBox& other) = default;
+        CropBox& operator=(const CropBox& other) = default;
+        CropBox& operator=(CropBox&& other) = default;
+
+        /// Update only some specific fields of a `CropBox`.
+        static CropBox update_fields() {
+            return CropBox();
+        }
+
+        /// Clear all the fields of a `CropBox`.
+        static CropBox clear_fields();
+
+        /// Whether the crop box is applied.
+        ///
+        /// Defaults to false.
+        CropBox with_enabled(const rerun::blueprint::components::Enabled& _enabled) && {
+            enabled = ComponentBatch::from_loggable(_enabled, Descriptor_enabled).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The center of the box, in the coordinates of the view's origin.
+        ///
+        /// Defaults to the origin.
+        CropBox with_center(const rerun::components::Translation3D& _center) && {
+            center = ComponentBatch::from_loggable(_center, Descriptor_center).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Half the size of the box along each axis.
+        ///
+        /// Defaults to 1 along each axis.
+        CropBox with_half_size(const rerun::components::HalfSize3D& _half_size) && {
+            half_size =
+                ComponentBatch::from_loggable(_half_size, Descriptor_half_size).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// If enabled, the points inside the box are hidden instead of those outside.
+        ///
+        /// Defaults to false.
+        CropBox with_keep_outside(const rerun::blueprint::components::Enabled& _keep_outside) && {
+            keep_outside =
+                ComponentBatch::from_loggable(_keep_outside, Descriptor_keep_outside)
+                    .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::CropBox> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::CropBox& archetype
+        );
+    };
+} // namespace rerun
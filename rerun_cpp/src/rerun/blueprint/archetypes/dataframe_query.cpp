@@ -26,6 +26,11 @@ namespace rerun::blueprint::archetypes {
                 Descriptor_apply_latest_at
             )
                 .value_or_throw();
+        archetype.latest_at_tolerance =
+            ComponentBatch::empty<rerun::blueprint::components::TimeInt>(
+                Descriptor_latest_at_tolerance
+            )
+                .value_or_throw();
         archetype.select =
             ComponentBatch::empty<rerun::blueprint::components::SelectedColumns>(Descriptor_select)
                 .value_or_throw();
@@ -34,7 +39,7 @@ namespace rerun::blueprint::archetypes {
 
     Collection<ComponentColumn> DataframeQuery::columns(const Collection<uint32_t>& lengths_) {
         std::vector<ComponentColumn> columns;
-        columns.reserve(5);
+        columns.reserve(6);
         if (timeline.has_value()) {
             columns.push_back(timeline.value().partitioned(lengths_).value_or_throw());
         }
@@ -47,6 +52,9 @@ namespace rerun::blueprint::archetypes {
         if (apply_latest_at.has_value()) {
             columns.push_back(apply_latest_at.value().partitioned(lengths_).value_or_throw());
         }
+        if (latest_at_tolerance.has_value()) {
+            columns.push_back(latest_at_tolerance.value().partitioned(lengths_).value_or_throw());
+        }
         if (select.has_value()) {
             columns.push_back(select.value().partitioned(lengths_).value_or_throw());
         }
@@ -66,6 +74,9 @@ namespace rerun::blueprint::archetypes {
         if (apply_latest_at.has_value()) {
             return columns(std::vector<uint32_t>(apply_latest_at.value().length(), 1));
         }
+        if (latest_at_tolerance.has_value()) {
+            return columns(std::vector<uint32_t>(latest_at_tolerance.value().length(), 1));
+        }
         if (select.has_value()) {
             return columns(std::vector<uint32_t>(select.value().length(), 1));
         }
@@ -81,7 +92,7 @@ namespace rerun {
         ) {
         using namespace blueprint::archetypes;
         std::vector<ComponentBatch> cells;
-        cells.reserve(5);
+        cells.reserve(6);
 
         if (archetype.timeline.has_value()) {
             cells.push_back(archetype.timeline.value());
@@ -95,6 +106,9 @@ namespace rerun {
         if (archetype.apply_latest_at.has_value()) {
             cells.push_back(archetype.apply_latest_at.value());
         }
+        if (archetype.latest_at_tolerance.has_value()) {
+            cells.push_back(archetype.latest_at_tolerance.value());
+        }
         if (archetype.select.has_value()) {
             cells.push_back(archetype.select.value());
         }
@@ -7,6 +7,7 @@
 #include "../../blueprint/components/filter_by_range.hpp"
 #include "../../blueprint/components/filter_is_not_null.hpp"
 #include "../../blueprint/components/selected_columns.hpp"
+#include "../../blueprint/components/time_int.hpp"
 #include "../../blueprint/components/timeline_name.hpp"
 #include "../../collection.hpp"
 #include "../../component_batch.hpp"
@@ -40,6 +41,12 @@ namespace rerun::blueprint::archetypes {
         /// Should empty cells be filled with latest-at queries?
         std::optional<ComponentBatch> apply_latest_at;
 
+        /// If set, empty cells are only filled with values logged at most this long before their row.
+        ///
+        /// This is in the units of the timeline, i.e. nanoseconds or sequence steps.
+        /// It has no effect unless `apply_latest_at` is enabled.
+        std::optional<ComponentBatch> latest_at_tolerance;
+
         /// Selected columns. If unset, all columns are selected.
         std::optional<ComponentBatch> select;
 
@@ -67,6 +74,11 @@ namespace rerun::blueprint::archetypes {
             ArchetypeName, "DataframeQuery:apply_latest_at",
             Loggable<rerun::blueprint::components::ApplyLatestAt>::ComponentType
         );
+        /// `ComponentDescriptor` for the `latest_at_tolerance` field.
+        static constexpr auto Descriptor_latest_at_tolerance = ComponentDescriptor(
+            ArchetypeName, "DataframeQuery:latest_at_tolerance",
+            Loggable<rerun::blueprint::components::TimeInt>::ComponentType
+        );
         /// `ComponentDescriptor` for the `select` field.
         static constexpr auto Descriptor_select = ComponentDescriptor(
             ArchetypeName, "DataframeQuery:select",
@@ -130,6 +142,19 @@ namespace rerun::blueprint::archetypes {
             return std::move(*this);
         }
 
+        /// If set, empty cells are only filled with values logged at most this long before their row.
+        ///
+        /// This is in the units of the timeline, i.e. nanoseconds or sequence steps.
+        /// It has no effect unless `apply_latest_at` is enabled.
+        DataframeQuery with_latest_at_tolerance(
+            const rerun::blueprint::components::TimeInt& _latest_at_tolerance
+        ) && {
+            latest_at_tolerance =
+                ComponentBatch::from_loggable(_latest_at_tolerance, Descriptor_latest_at_tolerance)
+                    .value_or_throw();
+            return std::move(*this);
+        }
+
         /// Selected columns. If unset, all columns are selected.
         DataframeQuery with_select(const rerun::blueprint::components::SelectedColumns& _select
         ) && {
@@ -0,0 +1,76 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/depth_clouds3d.fbs".
+
+#include "depth_clouds3d.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    DepthClouds3D DepthClouds3D::clear_fields() {
+        auto archetype = DepthClouds3D();
+        archetype.enabled =
+            ComponentBatch::empty<rerun::blueprint::components::Enabled>(Descriptor_enabled)
+                .value_or_throw();
+        archetype.subsampling =
+            ComponentBatch::empty<rerun::blueprint::components::DepthCloudSubsampling>(
+                Descriptor_subsampling
+            )
+                .value_or_throw();
+        archetype.depth_range =
+            ComponentBatch::empty<rerun::components::ValueRange>(Descriptor_depth_range)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> DepthClouds3D::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(3);
+        if (enabled.has_value()) {
+            columns.push_back(enabled.value().partitioned(lengths_).value_or_throw());
+        }
+        if (subsampling.has_value()) {
+            columns.push_back(subsampling.value().partitioned(lengths_).value_or_throw());
+        }
+        if (depth_range.has_value()) {
+            columns.push_back(depth_range.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> DepthClouds3D::columns() {
+        if (enabled.has_value()) {
+            return columns(std::vector<uint32_t>(enabled.value().length(), 1));
+        }
+        if (subsampling.has_value()) {
+            return columns(std::vector<uint32_t>(subsampling.value().length(), 1));
+        }
+        if (depth_range.has_value()) {
+            return columns(std::vector<uint32_t>(depth_range.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>>
+        AsComponents<blueprint::archetypes::DepthClouds3D>::as_batches(
+            const blueprint::archetypes::DepthClouds3D& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(3);
+
+        if (archetype.enabled.has_value()) {
+            cells.push_back(archetype.enabled.value());
+        }
+        if (archetype.subsampling.has_value()) {
+            cells.push_back(archetype.subsampling.value());
+        }
+        if (archetype.depth_range.has_value()) {
+            cells.push_back(archetype.depth_range.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
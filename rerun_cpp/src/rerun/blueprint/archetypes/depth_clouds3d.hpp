@@ -0,0 +1,144 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/depth_clouds3d.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/depth_cloud_subsampling.hpp"
+#include "../../blueprint/components/enabled.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/value_range.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Configuration for how depth images are backprojected into point clouds in the 3D view.
+    ///
+    /// A `archetypes::DepthImage` logged under a `archetypes::Pinhole` is shown as a point cloud,
+    /// using the camera intrinsics and `components::DepthMeter` of the depth image.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct DepthClouds3D {
+        /// Whether depth images are shown as point clouds.
+        ///
+        /// If disabled, depth images are shown as images on the image plane of their camera instead.
+        /// Defaults to true.
+        std::optional<ComponentBatch> enabled;
+
+        /// Only every n-th pixel of a depth image, in both directions, is turned into a point.
+        ///
+        /// Higher values speed up rendering of large depth images.
+        /// Defaults to 1, i.e. a point for every pixel.
+        std::optional<ComponentBatch> subsampling;
+
+        /// Only depths within this range, in meters, are shown as points.
+        ///
+        /// This does not affect the range used for the colormap, see `archetypes::DepthImage`.
+        /// If unset, all valid depths are shown.
+        std::optional<ComponentBatch> depth_range;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.DepthClouds3D";
+
+        /// `ComponentDescriptor` for the `enabled` field.
+        static constexpr auto Descriptor_enabled = ComponentDescriptor(
+            ArchetypeName, "DepthClouds3D:enabled",
+            Loggable<rerun::blueprint::components::Enabled>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `subsampling` field.
+        static constexpr auto Descriptor_subsampling = ComponentDescriptor(
+            ArchetypeName, "DepthClouds3D:subsampling",
+            Loggable<rerun::blueprint::components::DepthCloudSubsampling>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `depth_range` field.
+        static constexpr auto Descriptor_depth_range = ComponentDescriptor(
+            ArchetypeName, "DepthClouds3D:depth_range",
+            Loggable<rerun::components::ValueRange>::ComponentType
+        );
+
+      public:
+        DepthClouds3D() = default;
+        DepthClouds3D(DepthClouds3D&& other) = default;
+        DepthClouds3D(const DepthClouds3D& other) = default;
+        DepthClouds3D& operator=(const DepthClouds3D& other) = default;
+        DepthClouds3D& operator=(DepthClouds3D&& other) = default;
+
+        /// Update only some specific fields of a `DepthClouds3D`.
+        static DepthClouds3D update_fields() {
+            return DepthClouds3D();
+        }
+
+        /// Clear all the fields of a `DepthClouds3D`.
+        static DepthClouds3D clear_fields();
+
+        /// Whether depth images are shown as point clouds.
+        ///
+        /// If disabled, depth images are shown as images on the image plane of their camera instead.
+        /// Defaults to true.
+        DepthClouds3D with_enabled(const rerun::blueprint::components::Enabled& _enabled) && {
+            enabled = ComponentBatch::from_loggable(_enabled, Descriptor_enabled).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Only every n-th pixel of a depth image, in both directions, is turned into a point.
+        ///
+        /// Higher values speed up rendering of large depth images.
+        /// Defaults to 1, i.e. a point for every pixel.
+        DepthClouds3D with_subsampling(
+            const rerun::blueprint::components::DepthCloudSubsampling& _subsampling
+        ) && {
+            subsampling =
+                ComponentBatch::from_loggable(_subsampling, Descriptor_subsampling).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Only depths within this range, in meters, are shown as points.
+        ///
+        /// This does not affect the range used for the colormap, see `archetypes::DepthImage`.
+        /// If unset, all valid depths are shown.
+        DepthClouds3D with_depth_range(const rerun::components::ValueRange& _depth_range) && {
+            depth_range =
+                ComponentBatch::from_loggable(_depth_range, Descriptor_depth_range).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::DepthClouds3D> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::DepthClouds3D& archetype
+        );
+    };
+} // namespace rerun
@@ -27,12 +27,17 @@ namespace rerun::blueprint::archetypes {
         archetype.spin_speed =
             ComponentBatch::empty<rerun::blueprint::components::AngularSpeed>(Descriptor_spin_speed)
                 .value_or_throw();
+        archetype.projection =
+            ComponentBatch::empty<rerun::blueprint::components::Eye3DProjection>(
+                Descriptor_projection
+            )
+                .value_or_throw();
         return archetype;
     }
 
     Collection<ComponentColumn> EyeControls3D::columns(const Collection<uint32_t>& lengths_) {
         std::vector<ComponentColumn> columns;
-        columns.reserve(7);
+        columns.reserve(8);
         if (kind.has_value()) {
             columns.push_back(kind.value().partitioned(lengths_).value_or_throw());
         }
@@ -54,6 +59,9 @@ namespace rerun::blueprint::archetypes {
         if (spin_speed.has_value()) {
             columns.push_back(spin_speed.value().partitioned(lengths_).value_or_throw());
         }
+        if (projection.has_value()) {
+            columns.push_back(projection.value().partitioned(lengths_).value_or_throw());
+        }
         return columns;
     }
 
@@ -79,6 +87,9 @@ namespace rerun::blueprint::archetypes {
         if (spin_speed.has_value()) {
             return columns(std::vector<uint32_t>(spin_speed.value().length(), 1));
         }
+        if (projection.has_value()) {
+            return columns(std::vector<uint32_t>(projection.value().length(), 1));
+        }
         return Collection<ComponentColumn>();
     }
 } // namespace rerun::blueprint::archetypes
@@ -91,7 +102,7 @@ namespace rerun {
         ) {
         using namespace blueprint::archetypes;
         std::vector<ComponentBatch> cells;
-        cells.reserve(7);
+        cells.reserve(8);
 
         if (archetype.kind.has_value()) {
             cells.push_back(archetype.kind.value());
@@ -114,6 +125,9 @@ namespace rerun {
         if (archetype.spin_speed.has_value()) {
             cells.push_back(archetype.spin_speed.value());
         }
+        if (archetype.projection.has_value()) {
+            cells.push_back(archetype.projection.value());
+        }
 
         return rerun::take_ownership(std::move(cells));
     }
@@ -5,6 +5,7 @@
 
 #include "../../blueprint/components/angular_speed.hpp"
 #include "../../blueprint/components/eye3d_kind.hpp"
+#include "../../blueprint/components/eye3d_projection.hpp"
 #include "../../collection.hpp"
 #include "../../component_batch.hpp"
 #include "../../component_column.hpp"
@@ -66,6 +67,11 @@ namespace rerun::blueprint::archetypes {
         /// Defaults to zero, meaning no spinning.
         std::optional<ComponentBatch> spin_speed;
 
+        /// The projection of the eye.
+        ///
+        /// Defaults to perspective.
+        std::optional<ComponentBatch> projection;
+
       public:
         /// The name of the archetype as used in `ComponentDescriptor`s.
         static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.EyeControls3D";
@@ -105,6 +111,11 @@ namespace rerun::blueprint::archetypes {
             ArchetypeName, "EyeControls3D:spin_speed",
             Loggable<rerun::blueprint::components::AngularSpeed>::ComponentType
         );
+        /// `ComponentDescriptor` for the `projection` field.
+        static constexpr auto Descriptor_projection = ComponentDescriptor(
+            ArchetypeName, "EyeControls3D:projection",
+            Loggable<rerun::blueprint::components::Eye3DProjection>::ComponentType
+        );
 
       public:
         EyeControls3D() = default;
@@ -188,6 +199,17 @@ namespace rerun::blueprint::archetypes {
             return std::move(*this);
         }
 
+        /// The projection of the eye.
+        ///
+        /// Defaults to perspective.
+        EyeControls3D with_projection(
+            const rerun::blueprint::components::Eye3DProjection& _projection
+        ) && {
+            projection =
+                ComponentBatch::from_loggable(_projection, Descriptor_projection).value_or_throw();
+            return std::move(*this);
+        }
+
         /// Partitions the component data into multiple sub-batches.
         ///
         /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
@@ -0,0 +1,62 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/fft_window.fbs".
+
+#include "fft_window.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    FftWindow FftWindow::clear_fields() {
+        auto archetype = FftWindow();
+        archetype.size =
+            ComponentBatch::empty<rerun::blueprint::components::FftWindowSize>(Descriptor_size)
+                .value_or_throw();
+        archetype.overlap = ComponentBatch::empty<rerun::blueprint::components::FftWindowOverlap>(
+                                Descriptor_overlap
+        )
+                                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> FftWindow::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(2);
+        if (size.has_value()) {
+            columns.push_back(size.value().partitioned(lengths_).value_or_throw());
+        }
+        if (overlap.has_value()) {
+            columns.push_back(overlap.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> FftWindow::columns() {
+        if (size.has_value()) {
+            return columns(std::vector<uint32_t>(size.value().length(), 1));
+        }
+        if (overlap.has_value()) {
+            return columns(std::vector<uint32_t>(overlap.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>> AsComponents<blueprint::archetypes::FftWindow>::as_batches(
+        const blueprint::archetypes::FftWindow& archetype
+    ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(2);
+
+        if (archetype.size.has_value()) {
+            cells.push_back(archetype.size.value());
+        }
+        if (archetype.overlap.has_value()) {
+            cells.push_back(archetype.overlap.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
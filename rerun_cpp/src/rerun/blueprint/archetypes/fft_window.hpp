@@ -0,0 +1,120 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/fft_window.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/fft_window_overlap.hpp"
+#include "../../blueprint/components/fft_window_size.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Configures the windows of the rolling FFT of a spectrogram.
+    ///
+    /// Each window is multiplied with a Hann window before its spectrum is computed.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct FftWindow {
+        /// The number of samples in each window.
+        ///
+        /// Rounded up to the next power of two.
+        /// Larger windows resolve frequencies more finely, but resolve events in time more coarsely.
+        /// Defaults to 256.
+        std::optional<ComponentBatch> size;
+
+        /// How much consecutive windows overlap, as a fraction of the window size.
+        ///
+        /// Clamped to `[0, 0.95]`.
+        /// Defaults to 0.5.
+        std::optional<ComponentBatch> overlap;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.FftWindow";
+
+        /// `ComponentDescriptor` for the `size` field.
+        static constexpr auto Descriptor_size = ComponentDescriptor(
+            ArchetypeName, "FftWindow:size",
+            Loggable<rerun::blueprint::components::FftWindowSize>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `overlap` field.
+        static constexpr auto Descriptor_overlap = ComponentDescriptor(
+            ArchetypeName, "FftWindow:overlap",
+            Loggable<rerun::blueprint::components::FftWindowOverlap>::ComponentType
+        );
+
+      public:
+        FftWindow() = default;
+        FftWindow(FftWindow&& other) = default;
+        FftWindow(const FftWindow& other) = default;
+        FftWindow& operator=(const FftWindow& other) = default;
+        FftWindow& operator=(FftWindow&& other) = default;
+
+        /// Update only some specific fields of a `FftWindow`.
+        static FftWindow update_fields() {
+            return FftWindow();
+        }
+
+        /// Clear all the fields of a `FftWindow`.
+        static FftWindow clear_fields();
+
+        /// The number of samples in each window.
+        ///
+        /// Rounded up to the next power of two.
+        /// Larger windows resolve frequencies more finely, but resolve events in time more coarsely.
+        /// Defaults to 256.
+        FftWindow with_size(const rerun::blueprint::components::FftWindowSize& _size) && {
+            size = ComponentBatch::from_loggable(_size, Descriptor_size).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// How much consecutive windows overlap, as a fraction of the window size.
+        ///
+        /// Clamped to `[0, 0.95]`.
+        /// Defaults to 0.5.
+        FftWindow with_overlap(const rerun::blueprint::components::FftWindowOverlap& _overlap) && {
+            overlap = ComponentBatch::from_loggable(_overlap, Descriptor_overlap).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::FftWindow> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::FftWindow& archetype
+        );
+    };
+} // namespace rerun
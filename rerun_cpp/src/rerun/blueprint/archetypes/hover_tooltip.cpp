@@ -0,0 +1,50 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/hover_tooltip.fbs".
+
+#include "hover_tooltip.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    HoverTooltip HoverTooltip::clear_fields() {
+        auto archetype = HoverTooltip();
+        archetype.shown_components =
+            ComponentBatch::empty<rerun::components::Name>(Descriptor_shown_components)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> HoverTooltip::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(1);
+        if (shown_components.has_value()) {
+            columns.push_back(shown_components.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> HoverTooltip::columns() {
+        if (shown_components.has_value()) {
+            return columns(std::vector<uint32_t>(shown_components.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>>
+        AsComponents<blueprint::archetypes::HoverTooltip>::as_batches(
+            const blueprint::archetypes::HoverTooltip& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(1);
+
+        if (archetype.shown_components.has_value()) {
+            cells.push_back(archetype.shown_components.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
@@ -0,0 +1,103 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/hover_tooltip.fbs".
+
+#pragma once
+
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/name.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Which components of an entity are shown when hovering it in a 2D or 3D view.
+    ///
+    /// Log this as an override of the entity in a view, e.g. to only show `velocity` and `class`
+    /// instead of the default summary of all components.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct HoverTooltip {
+        /// The components shown in the tooltip, in this order.
+        ///
+        /// Either the full component identifiers or just the part after the archetype prefix,
+        /// e.g. `velocity`. Components the entity doesn't have are skipped.
+        /// If unset, the tooltip shows a summary of all components.
+        std::optional<ComponentBatch> shown_components;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.HoverTooltip";
+
+        /// `ComponentDescriptor` for the `shown_components` field.
+        static constexpr auto Descriptor_shown_components = ComponentDescriptor(
+            ArchetypeName, "HoverTooltip:shown_components",
+            Loggable<rerun::components::Name>::ComponentType
+        );
+
+      public:
+        HoverTooltip() = default;
+        HoverTooltip(HoverTooltip&& other) = default;
+        HoverTooltip(const HoverTooltip& other) = default;
+        HoverTooltip& operator=(const HoverTooltip& other) = default;
+        HoverTooltip& operator=(HoverTooltip&& other) = default;
+
+        /// Update only some specific fields of a `HoverTooltip`.
+        static HoverTooltip update_fields() {
+            return HoverTooltip();
+        }
+
+        /// Clear all the fields of a `HoverTooltip`.
+        static HoverTooltip clear_fields();
+
+        /// The components shown in the tooltip, in this order.
+        ///
+        /// Either the full component identifiers or just the part after the archetype prefix,
+        /// e.g. `velocity`. Components the entity doesn't have are skipped.
+        /// If unset, the tooltip shows a summary of all components.
+        HoverTooltip with_shown_components(
+            const Collection<rerun::components::Name>& _shown_components
+        ) && {
+            shown_components =
+                ComponentBatch::from_loggable(_shown_components, Descriptor_shown_components).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::HoverTooltip> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::HoverTooltip& archetype
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,90 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/image_planes3d.fbs".
+
+#include "image_planes3d.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    ImagePlanes3D ImagePlanes3D::clear_fields() {
+        auto archetype = ImagePlanes3D();
+        archetype.image_plane_distance =
+            ComponentBatch::empty<rerun::components::ImagePlaneDistance>(
+                Descriptor_image_plane_distance
+            )
+                .value_or_throw();
+        archetype.opacity =
+            ComponentBatch::empty<rerun::components::Opacity>(Descriptor_opacity).value_or_throw();
+        archetype.show_back_faces = ComponentBatch::empty<rerun::blueprint::components::Enabled>(
+                                        Descriptor_show_back_faces
+        )
+                                        .value_or_throw();
+        archetype.project_annotations =
+            ComponentBatch::empty<rerun::blueprint::components::Enabled>(
+                Descriptor_project_annotations
+            )
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> ImagePlanes3D::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(4);
+        if (image_plane_distance.has_value()) {
+            columns.push_back(image_plane_distance.value().partitioned(lengths_).value_or_throw());
+        }
+        if (opacity.has_value()) {
+            columns.push_back(opacity.value().partitioned(lengths_).value_or_throw());
+        }
+        if (show_back_faces.has_value()) {
+            columns.push_back(show_back_faces.value().partitioned(lengths_).value_or_throw());
+        }
+        if (project_annotations.has_value()) {
+            columns.push_back(project_annotations.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> ImagePlanes3D::columns() {
+        if (image_plane_distance.has_value()) {
+            return columns(std::vector<uint32_t>(image_plane_distance.value().length(), 1));
+        }
+        if (opacity.has_value()) {
+            return columns(std::vector<uint32_t>(opacity.value().length(), 1));
+        }
+        if (show_back_faces.has_value()) {
+            return columns(std::vector<uint32_t>(show_back_faces.value().length(), 1));
+        }
+        if (project_annotations.has_value()) {
+            return columns(std::vector<uint32_t>(project_annotations.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>>
+        AsComponents<blueprint::archetypes::ImagePlanes3D>::as_batches(
+            const blueprint::archetypes::ImagePlanes3D& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(4);
+
+        if (archetype.image_plane_distance.has_value()) {
+            cells.push_back(archetype.image_plane_distance.value());
+        }
+        if (archetype.opacity.has_value()) {
+            cells.push_back(archetype.opacity.value());
+        }
+        if (archetype.show_back_faces.has_value()) {
+            cells.push_back(archetype.show_back_faces.value());
+        }
+        if (archetype.project_annotations.has_value()) {
+            cells.push_back(archetype.project_annotations.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
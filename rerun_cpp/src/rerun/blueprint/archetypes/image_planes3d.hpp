@@ -0,0 +1,170 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/image_planes3d.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/enabled.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/image_plane_distance.hpp"
+#include "../../components/opacity.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Configuration for the image planes of pinhole cameras in the 3D view.
+    ///
+    /// 2D content logged under a `archetypes::Pinhole`, e.g. images and 2D annotations,
+    /// is shown on the image plane at the end of the camera frustum.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct ImagePlanes3D {
+        /// Distance of the image plane from the camera, used for all cameras in the view.
+        ///
+        /// If unset, each camera uses its own `components::ImagePlaneDistance`.
+        std::optional<ComponentBatch> image_plane_distance;
+
+        /// Opacity of the images shown on the image planes.
+        ///
+        /// This is multiplied with the opacity of each image.
+        /// Defaults to 1.0.
+        std::optional<ComponentBatch> opacity;
+
+        /// Whether the images are visible when looking at the image plane from behind.
+        ///
+        /// Defaults to true.
+        std::optional<ComponentBatch> show_back_faces;
+
+        /// Whether 2D annotations, i.e. points, boxes, line strips and arrows, are shown on the image planes.
+        ///
+        /// Defaults to true.
+        std::optional<ComponentBatch> project_annotations;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.ImagePlanes3D";
+
+        /// `ComponentDescriptor` for the `image_plane_distance` field.
+        static constexpr auto Descriptor_image_plane_distance = ComponentDescriptor(
+            ArchetypeName, "ImagePlanes3D:image_plane_distance",
+            Loggable<rerun::components::ImagePlaneDistance>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `opacity` field.
+        static constexpr auto Descriptor_opacity = ComponentDescriptor(
+            ArchetypeName, "ImagePlanes3D:opacity",
+            Loggable<rerun::components::Opacity>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `show_back_faces` field.
+        static constexpr auto Descriptor_show_back_faces = ComponentDescriptor(
+            ArchetypeName, "ImagePlanes3D:show_back_faces",
+            Loggable<rerun::blueprint::components::Enabled>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `project_annotations` field.
+        static constexpr auto Descriptor_project_annotations = ComponentDescriptor(
+            ArchetypeName, "ImagePlanes3D:project_annotations",
+            Loggable<rerun::blueprint::components::Enabled>::ComponentType
+        );
+
+      public:
+        ImagePlanes3D() = default;
+        ImagePlanes3D(ImagePlanes3D&& other) = default;
+        ImagePlanes3D(const ImagePlanes3D& other) = default;
+        ImagePlanes3D& operator=(const ImagePlanes3D& other) = default;
+        ImagePlanes3D& operator=(ImagePlanes3D&& other) = default;
+
+        /// Update only some specific fields of a `ImagePlanes3D`.
+        static ImagePlanes3D update_fields() {
+            return ImagePlanes3D();
+        }
+
+        /// Clear all the fields of a `ImagePlanes3D`.
+        static ImagePlanes3D clear_fields();
+
+        /// Distance of the image plane from the camera, used for all cameras in the view.
+        ///
+        /// If unset, each camera uses its own `components::ImagePlaneDistance`.
+        ImagePlanes3D with_image_plane_distance(
+            const rerun::components::ImagePlaneDistance& _image_plane_distance
+        ) && {
+            image_plane_distance = ComponentBatch::from_loggable(
+                                       _image_plane_distance,
+                                       Descriptor_image_plane_distance
+            )
+                                       .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Opacity of the images shown on the image planes.
+        ///
+        /// This is multiplied with the opacity of each image.
+        /// Defaults to 1.0.
+        ImagePlanes3D with_opacity(const rerun::components::Opacity& _opacity) && {
+            opacity = ComponentBatch::from_loggable(_opacity, Descriptor_opacity).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Whether the images are visible when looking at the image plane from behind.
+        ///
+        /// Defaults to true.
+        ImagePlanes3D with_show_back_faces(
+            const rerun::blueprint::components::Enabled& _show_back_faces
+        ) && {
+            show_back_faces =
+                ComponentBatch::from_loggable(_show_back_faces, Descriptor_show_back_faces)
+                    .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Whether 2D annotations, i.e. points, boxes, line strips and arrows, are shown on the image planes.
+        ///
+        /// Defaults to true.
+        ImagePlanes3D with_project_annotations(
+            const rerun::blueprint::components::Enabled& _project_annotations
+        ) && {
+            project_annotations = ComponentBatch::from_loggable(
+                                      _project_annotations,
+                                      Descriptor_project_annotations
+            )
+                                      .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::ImagePlanes3D> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::ImagePlanes3D& archetype
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,60 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/instance_filter.fbs".
+
+#include "instance_filter.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    InstanceFilter InstanceFilter::clear_fields() {
+        auto archetype = InstanceFilter();
+        archetype.component =
+            ComponentBatch::empty<rerun::components::Name>(Descriptor_component).value_or_throw();
+        archetype.threshold =
+            ComponentBatch::empty<rerun::components::Scalar>(Descriptor_threshold).value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> InstanceFilter::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(2);
+        if (component.has_value()) {
+            columns.push_back(component.value().partitioned(lengths_).value_or_throw());
+        }
+        if (threshold.has_value()) {
+            columns.push_back(threshold.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> InstanceFilter::columns() {
+        if (component.has_value()) {
+            return columns(std::vector<uint32_t>(component.value().length(), 1));
+        }
+        if (threshold.has_value()) {
+            return columns(std::vector<uint32_t>(threshold.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>>
+        AsComponents<blueprint::archetypes::InstanceFilter>::as_batches(
+            const blueprint::archetypes::InstanceFilter& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(2);
+
+        if (archetype.component.has_value()) {
+            cells.push_back(archetype.component.value());
+        }
+        if (archetype.threshold.has_value()) {
+            cells.push_back(archetype.threshold.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
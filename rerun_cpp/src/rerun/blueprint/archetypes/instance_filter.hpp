@@ -0,0 +1,122 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/instance_filter.fbs".
+
+#pragma once
+
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/name.hpp"
+#include "../../components/scalar.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Hides instances based on the value of a scalar component logged alongside them.
+    ///
+    /// This is useful to threshold the output of a detector, e.g. by a `confidence` component,
+    /// without logging the data again.
+    /// The value of each instance is compared against the threshold at the current time.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct InstanceFilter {
+        /// The component whose values are compared against the threshold, e.g. `confidence`.
+        ///
+        /// Either the full component identifier or just the part after the archetype prefix.
+        /// Entities that don't have this component are not filtered.
+        /// If unset, no instances are filtered.
+        std::optional<ComponentBatch> component;
+
+        /// Instances with a value below this threshold are hidden.
+        ///
+        /// Defaults to 0.5.
+        std::optional<ComponentBatch> threshold;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.InstanceFilter";
+
+        /// `ComponentDescriptor` for the `component` field.
+        static constexpr auto Descriptor_component = ComponentDescriptor(
+            ArchetypeName, "InstanceFilter:component",
+            Loggable<rerun::components::Name>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `threshold` field.
+        static constexpr auto Descriptor_threshold = ComponentDescriptor(
+            ArchetypeName, "InstanceFilter:threshold",
+            Loggable<rerun::components::Scalar>::ComponentType
+        );
+
+      public:
+        InstanceFilter() = default;
+        InstanceFilter(InstanceFilter&& other) = default;
+        InstanceFilter(const InstanceFilter& other) = default;
+        InstanceFilter& operator=(const InstanceFilter& other) = default;
+        InstanceFilter& operator=(InstanceFilter&& other) = default;
+
+        /// Update only some specific fields of a `InstanceFilter`.
+        static InstanceFilter update_fields() {
+            return InstanceFilter();
+        }
+
+        /// Clear all the fields of a `InstanceFilter`.
+        static InstanceFilter clear_fields();
+
+        /// The component whose values are compared against the threshold, e.g. `confidence`.
+        ///
+        /// Either the full component identifier or just the part after the archetype prefix.
+        /// Entities that don't have this component are not filtered.
+        /// If unset, no instances are filtered.
+        InstanceFilter with_component(const rerun::components::Name& _component) && {
+            component =
+                ComponentBatch::from_loggable(_component, Descriptor_component).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Instances with a value below this threshold are hidden.
+        ///
+        /// Defaults to 0.5.
+        InstanceFilter with_threshold(const rerun::components::Scalar& _threshold) && {
+            threshold =
+                ComponentBatch::from_loggable(_threshold, Descriptor_threshold).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::InstanceFilter> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::InstanceFilter& archetype
+        );
+    };
+} // namespace rerun
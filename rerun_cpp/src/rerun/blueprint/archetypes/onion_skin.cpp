@@ -0,0 +1,61 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/onion_skin.fbs".
+
+#include "onion_skin.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    OnionSkin OnionSkin::clear_fields() {
+        auto archetype = OnionSkin();
+        archetype.steps =
+            ComponentBatch::empty<rerun::blueprint::components::OnionSkinSteps>(Descriptor_steps)
+                .value_or_throw();
+        archetype.opacity =
+            ComponentBatch::empty<rerun::components::Opacity>(Descriptor_opacity).value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> OnionSkin::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(2);
+        if (steps.has_value()) {
+            columns.push_back(steps.value().partitioned(lengths_).value_or_throw());
+        }
+        if (opacity.has_value()) {
+            columns.push_back(opacity.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> OnionSkin::columns() {
+        if (steps.has_value()) {
+            return columns(std::vector<uint32_t>(steps.value().length(), 1));
+        }
+        if (opacity.has_value()) {
+            return columns(std::vector<uint32_t>(opacity.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>>
+        AsComponents<blueprint::archetypes::OnionSkin>::as_batches(
+            const blueprint::archetypes::OnionSkin& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(2);
+
+        if (archetype.steps.has_value()) {
+            cells.push_back(archetype.steps.value());
+        }
+        if (archetype.opacity.has_value()) {
+            cells.push_back(archetype.opacity.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
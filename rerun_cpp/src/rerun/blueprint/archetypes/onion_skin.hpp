@@ -0,0 +1,120 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/onion_skin.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/onion_skin_steps.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/opacity.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Configuration for the onion skin of a spatial view.
+    ///
+    /// The onion skin shows the earlier states of entities as translucent ghosts,
+    /// e.g. to review the jitter of a pose estimate or how a robot moved.
+    /// It is supported for points, line strips, arrows and 2D boxes.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct OnionSkin {
+        /// How many earlier states of each entity are shown.
+        ///
+        /// Every time an entity's data or its transform changed counts as a step.
+        /// Defaults to 0, i.e. no onion skin.
+        std::optional<ComponentBatch> steps;
+
+        /// Opacity of the most recent earlier state.
+        ///
+        /// Older states fade out further.
+        /// Defaults to 0.5.
+        std::optional<ComponentBatch> opacity;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.OnionSkin";
+
+        /// `ComponentDescriptor` for the `steps` field.
+        static constexpr auto Descriptor_steps = ComponentDescriptor(
+            ArchetypeName, "OnionSkin:steps",
+            Loggable<rerun::blueprint::components::OnionSkinSteps>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `opacity` field.
+        static constexpr auto Descriptor_opacity = ComponentDescriptor(
+            ArchetypeName, "OnionSkin:opacity",
+            Loggable<rerun::components::Opacity>::ComponentType
+        );
+
+      public:
+        OnionSkin() = default;
+        OnionSkin(OnionSkin&& other) = default;
+        OnionSkin(const OnionSkin& other) = default;
+        OnionSkin& operator=(const OnionSkin& other) = default;
+        OnionSkin& operator=(OnionSkin&& other) = default;
+
+        /// Update only some specific fields of a `OnionSkin`.
+        static OnionSkin update_fields() {
+            return OnionSkin();
+        }
+
+        /// Clear all the fields of a `OnionSkin`.
+        static OnionSkin clear_fields();
+
+        /// How many earlier states of each entity are shown.
+        ///
+        /// Every time an entity's data or its transform changed counts as a step.
+        /// Defaults to 0, i.e. no onion skin.
+        OnionSkin with_steps(const rerun::blueprint::components::OnionSkinSteps& _steps) && {
+            steps = ComponentBatch::from_loggable(_steps, Descriptor_steps).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Opacity of the most recent earlier state.
+        ///
+        /// Older states fade out further.
+        /// Defaults to 0.5.
+        OnionSkin with_opacity(const rerun::components::Opacity& _opacity) && {
+            opacity = ComponentBatch::from_loggable(_opacity, Descriptor_opacity).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::OnionSkin> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::OnionSkin& archetype
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,99 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/outlines.fbs".
+
+#include "outlines.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    Outlines Outlines::clear_fields() {
+        auto archetype = Outlines();
+        archetype.hover_color =
+            ComponentBatch::empty<rerun::components::Color>(Descriptor_hover_color)
+                .value_or_throw();
+        archetype.selection_color =
+            ComponentBatch::empty<rerun::components::Color>(Descriptor_selection_color)
+                .value_or_throw();
+        archetype.stroke_width =
+            ComponentBatch::empty<rerun::components::StrokeWidth>(Descriptor_stroke_width)
+                .value_or_throw();
+        archetype.highlighted =
+            ComponentBatch::empty<rerun::blueprint::components::QueryExpression>(
+                Descriptor_highlighted
+            )
+                .value_or_throw();
+        archetype.highlight_color =
+            ComponentBatch::empty<rerun::components::Color>(Descriptor_highlight_color)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> Outlines::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(5);
+        if (hover_color.has_value()) {
+            columns.push_back(hover_color.value().partitioned(lengths_).value_or_throw());
+        }
+        if (selection_color.has_value()) {
+            columns.push_back(selection_color.value().partitioned(lengths_).value_or_throw());
+        }
+        if (stroke_width.has_value()) {
+            columns.push_back(stroke_width.value().partitioned(lengths_).value_or_throw());
+        }
+        if (highlighted.has_value()) {
+            columns.push_back(highlighted.value().partitioned(lengths_).value_or_throw());
+        }
+        if (highlight_color.has_value()) {
+            columns.push_back(highlight_color.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> Outlines::columns() {
+        if (hover_color.has_value()) {
+            return columns(std::vector<uint32_t>(hover_color.value().length(), 1));
+        }
+        if (selection_color.has_value()) {
+            return columns(std::vector<uint32_t>(selection_color.value().length(), 1));
+        }
+        if (stroke_width.has_value()) {
+            return columns(std::vector<uint32_t>(stroke_width.value().length(), 1));
+        }
+        if (highlighted.has_value()) {
+            return columns(std::vector<uint32_t>(highlighted.value().length(), 1));
+        }
+        if (highlight_color.has_value()) {
+            return columns(std::vector<uint32_t>(highlight_color.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>> AsComponents<blueprint::archetypes::Outlines>::as_batches(
+        const blueprint::archetypes::Outlines& archetype
+    ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(5);
+
+        if (archetype.hover_color.has_value()) {
+            cells.push_back(archetype.hover_color.value());
+        }
+        if (archetype.selection_color.has_value()) {
+            cells.push_back(archetype.selection_color.value());
+        }
+        if (archetype.stroke_width.has_value()) {
+            cells.push_back(archetype.stroke_width.value());
+        }
+        if (archetype.highlighted.has_value()) {
+            cells.push_back(archetype.highlighted.value());
+        }
+        if (archetype.highlight_color.has_value()) {
+            cells.push_back(archetype.highlight_color.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
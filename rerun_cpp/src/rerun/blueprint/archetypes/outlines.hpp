@@ -0,0 +1,179 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/outlines.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/query_expression.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/color.hpp"
+#include "../../components/stroke_width.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Configuration for the outlines of hovered, selected and highlighted entities in a spatial view.
+    ///
+    /// Highlighted entities are always outlined, e.g. to mark the ground truth in a scene.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct Outlines {
+        /// Color of the outline of hovered objects.
+        ///
+        /// Defaults to the hover color of the viewer.
+        std::optional<ComponentBatch> hover_color;
+
+        /// Color of the outline of selected objects.
+        ///
+        /// Defaults to the selection color of the viewer.
+        std::optional<ComponentBatch> selection_color;
+
+        /// How thick the outlines are in ui units.
+        ///
+        /// Defaults to the thickness of hover & selection strokes elsewhere in the viewer.
+        std::optional<ComponentBatch> stroke_width;
+
+        /// Query expressions of the entities that are always outlined, e.g. `+ /world/ground_truth/**`.
+        ///
+        /// Uses the same syntax as the contents of the view.
+        /// While any entity is highlighted, hovered objects are outlined with the selection color.
+        std::optional<ComponentBatch> highlighted;
+
+        /// Color of the outline of highlighted entities.
+        ///
+        /// Defaults to green.
+        std::optional<ComponentBatch> highlight_color;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.Outlines";
+
+        /// `ComponentDescriptor` for the `hover_color` field.
+        static constexpr auto Descriptor_hover_color = ComponentDescriptor(
+            ArchetypeName, "Outlines:hover_color", Loggable<rerun::components::Color>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `selection_color` field.
+        static constexpr auto Descriptor_selection_color = ComponentDescriptor(
+            ArchetypeName, "Outlines:selection_color",
+            Loggable<rerun::components::Color>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `stroke_width` field.
+        static constexpr auto Descriptor_stroke_width = ComponentDescriptor(
+            ArchetypeName, "Outlines:stroke_width",
+            Loggable<rerun::components::StrokeWidth>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `highlighted` field.
+        static constexpr auto Descriptor_highlighted = ComponentDescriptor(
+            ArchetypeName, "Outlines:highlighted",
+            Loggable<rerun::blueprint::components::QueryExpression>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `highlight_color` field.
+        static constexpr auto Descriptor_highlight_color = ComponentDescriptor(
+            ArchetypeName, "Outlines:highlight_color",
+            Loggable<rerun::components::Color>::ComponentType
+        );
+
+      public:
+        Outlines() = default;
+        Outlines(Outlines&& other) = default;
+        Outlines(const Outlines& other) = default;
+        Outlines& operator=(const Outlines& other) = default;
+        Outlines& operator=(Outlines&& other) = default;
+
+        /// Update only some specific fields of a `Outlines`.
+        static Outlines update_fields() {
+            return Outlines();
+        }
+
+        /// Clear all the fields of a `Outlines`.
+        static Outlines clear_fields();
+
+        /// Color of the outline of hovered objects.
+        ///
+        /// Defaults to the hover color of the viewer.
+        Outlines with_hover_color(const rerun::components::Color& _hover_color) && {
+            hover_color = ComponentBatch::from_loggable(_hover_color, Descriptor_hover_color)
+                              .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Color of the outline of selected objects.
+        ///
+        /// Defaults to the selection color of the viewer.
+        Outlines with_selection_color(const rerun::components::Color& _selection_color) && {
+            selection_color =
+                ComponentBatch::from_loggable(_selection_color, Descriptor_selection_color)
+                    .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// How thick the outlines are in ui units.
+        ///
+        /// Defaults to the thickness of hover & selection strokes elsewhere in the viewer.
+        Outlines with_stroke_width(const rerun::components::StrokeWidth& _stroke_width) && {
+            stroke_width = ComponentBatch::from_loggable(_stroke_width, Descriptor_stroke_width)
+                               .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Query expressions of the entities that are always outlined, e.g. `+ /world/ground_truth/**`.
+        ///
+        /// Uses the same syntax as the contents of the view.
+        /// While any entity is highlighted, hovered objects are outlined with the selection color.
+        Outlines with_highlighted(
+            const Collection<rerun::blueprint::components::QueryExpression>& _highlighted
+        ) && {
+            highlighted = ComponentBatch::from_loggable(_highlighted, Descriptor_highlighted)
+                              .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Color of the outline of highlighted entities.
+        ///
+        /// Defaults to green.
+        Outlines with_highlight_color(const rerun::components::Color& _highlight_color) && {
+            highlight_color =
+                ComponentBatch::from_loggable(_highlight_color, Descriptor_highlight_color)
+                    .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::Outlines> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::Outlines& archetype
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,52 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/point_shading.fbs".
+
+#include "point_shading.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    PointShading PointShading::clear_fields() {
+        auto archetype = PointShading();
+        archetype.estimate_normals =
+            ComponentBatch::empty<rerun::blueprint::components::Enabled>(
+                Descriptor_estimate_normals
+            )
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> PointShading::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(1);
+        if (estimate_normals.has_value()) {
+            columns.push_back(estimate_normals.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> PointShading::columns() {
+        if (estimate_normals.has_value()) {
+            return columns(std::vector<uint32_t>(estimate_normals.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>>
+        AsComponents<blueprint::archetypes::PointShading>::as_batches(
+            const blueprint::archetypes::PointShading& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(1);
+
+        if (archetype.estimate_normals.has_value()) {
+            cells.push_back(archetype.estimate_normals.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
@@ -0,0 +1,103 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/point_shading.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/enabled.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Configures how point clouds are shaded in a 3D view.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct PointShading {
+        /// Whether normals are estimated for point clouds, and used to light them.
+        ///
+        /// The normal of each point is fitted to its nearest neighbors on the GPU, which brings out
+        /// the shape of scanned surfaces without having to compute normals offline.
+        /// This is redone every frame, so it can be slow for very large point clouds.
+        /// Defaults to false.
+        std::optional<ComponentBatch> estimate_normals;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.PointShading";
+
+        /// `ComponentDescriptor` for the `estimate_normals` field.
+        static constexpr auto Descriptor_estimate_normals = ComponentDescriptor(
+            ArchetypeName, "PointShading:estimate_normals",
+            Loggable<rerun::blueprint::components::Enabled>::ComponentType
+        );
+
+      public:
+        PointShading() = default;
+        PointShading(PointShading&& other) = default;
+        PointShading(const PointShading& other) = default;
+        PointShading& operator=(const PointShading& other) = default;
+        PointShading& operator=(PointShading&& other) = default;
+
+        /// Update only some specific fields of a `PointShading`.
+        static PointShading update_fields() {
+            return PointShading();
+        }
+
+        /// Clear all the fields of a `PointShading`.
+        static PointShading clear_fields();
+
+        /// Whether normals are estimated for point clouds, and used to light them.
+        ///
+        /// The normal of each point is fitted to its nearest neighbors on the GPU, which brings out
+        /// the shape of scanned surfaces without having to compute normals offline.
+        /// This is redone every frame, so it can be slow for very large point clouds.
+        /// Defaults to false.
+        PointShading with_estimate_normals(
+            const rerun::blueprint::components::Enabled& _estimate_normals
+        ) && {
+            estimate_normals =
+                ComponentBatch::from_loggable(_estimate_normals, Descriptor_estimate_normals)
+                    .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::PointShading> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::PointShading& archetype
+        );
+    };
+} // namespace rerun
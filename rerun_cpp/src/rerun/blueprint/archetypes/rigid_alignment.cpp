@@ -0,0 +1,97 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/rigid_alignment.fbs".
+
+#include "rigid_alignment.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    RigidAlignment RigidAlignment::clear_fields() {
+        auto archetype = RigidAlignment();
+        archetype.reference =
+            ComponentBatch::empty<rerun::components::EntityPath>(Descriptor_reference)
+                .value_or_throw();
+        archetype.match_closest =
+            ComponentBatch::empty<rerun::blueprint::components::Enabled>(Descriptor_match_closest)
+                .value_or_throw();
+        archetype.translation =
+            ComponentBatch::empty<rerun::components::Translation3D>(Descriptor_translation)
+                .value_or_throw();
+        archetype.quaternion =
+            ComponentBatch::empty<rerun::components::RotationQuat>(Descriptor_quaternion)
+                .value_or_throw();
+        archetype.rmse =
+            ComponentBatch::empty<rerun::components::Length>(Descriptor_rmse).value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> RigidAlignment::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(5);
+        if (reference.has_value()) {
+            columns.push_back(reference.value().partitioned(lengths_).value_or_throw());
+        }
+        if (match_closest.has_value()) {
+            columns.push_back(match_closest.value().partitioned(lengths_).value_or_throw());
+        }
+        if (translation.has_value()) {
+            columns.push_back(translation.value().partitioned(lengths_).value_or_throw());
+        }
+        if (quaternion.has_value()) {
+            columns.push_back(quaternion.value().partitioned(lengths_).value_or_throw());
+        }
+        if (rmse.has_value()) {
+            columns.push_back(rmse.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> RigidAlignment::columns() {
+        if (reference.has_value()) {
+            return columns(std::vector<uint32_t>(reference.value().length(), 1));
+        }
+        if (match_closest.has_value()) {
+            return columns(std::vector<uint32_t>(match_closest.value().length(), 1));
+        }
+        if (translation.has_value()) {
+            return columns(std::vector<uint32_t>(translation.value().length(), 1));
+        }
+        if (quaternion.has_value()) {
+            return columns(std::vector<uint32_t>(quaternion.value().length(), 1));
+        }
+        if (rmse.has_value()) {
+            return columns(std::vector<uint32_t>(rmse.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>>
+        AsComponents<blueprint::archetypes::RigidAlignment>::as_batches(
+            const blueprint::archetypes::RigidAlignment& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(5);
+
+        if (archetype.reference.has_value()) {
+            cells.push_back(archetype.reference.value());
+        }
+        if (archetype.match_closest.has_value()) {
+            cells.push_back(archetype.match_closest.value());
+        }
+        if (archetype.translation.has_value()) {
+            cells.push_back(archetype.translation.value());
+        }
+        if (archetype.quaternion.has_value()) {
+            cells.push_back(archetype.quaternion.value());
+        }
+        if (archetype.rmse.has_value()) {
+            cells.push_back(archetype.rmse.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
@@ -0,0 +1,176 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/rigid_alignment.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/enabled.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/entity_path.hpp"
+#include "../../components/length.hpp"
+#include "../../components/rotation_quat.hpp"
+#include "../../components/translation3d.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Moves an entity onto another one with a rigid transform.
+    ///
+    /// Log this as an override of the entity in a 3D view, e.g. to compare a reconstruction with its ground truth.
+    /// The transform is only applied in that view, on top of the transforms logged for the entity.
+    /// If neither translation nor rotation is set, the viewer fits the transform that best aligns
+    /// the points or line strips of the entity with those of the reference, and fills them in.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct RigidAlignment {
+        /// The entity to align to, e.g. the ground truth.
+        ///
+        /// Has to be shown in the same view.
+        std::optional<ComponentBatch> reference;
+
+        /// Whether to match each point with the closest point of the reference (ICP).
+        ///
+        /// Otherwise points are matched by index, which requires both entities to have the same
+        /// number of points, e.g. two trajectories sampled at the same times.
+        std::optional<ComponentBatch> match_closest;
+
+        /// The translation of the fitted transform, in the space of the entity.
+        std::optional<ComponentBatch> translation;
+
+        /// The rotation of the fitted transform, in the space of the entity.
+        std::optional<ComponentBatch> quaternion;
+
+        /// The root mean square distance between the matched points after the fit.
+        ///
+        /// Set by the viewer when it fits the transform.
+        std::optional<ComponentBatch> rmse;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.RigidAlignment";
+
+        /// `ComponentDescriptor` for the `reference` field.
+        static constexpr auto Descriptor_reference = ComponentDescriptor(
+            ArchetypeName, "RigidAlignment:reference",
+            Loggable<rerun::components::EntityPath>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `match_closest` field.
+        static constexpr auto Descriptor_match_closest = ComponentDescriptor(
+            ArchetypeName, "RigidAlignment:match_closest",
+            Loggable<rerun::blueprint::components::Enabled>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `translation` field.
+        static constexpr auto Descriptor_translation = ComponentDescriptor(
+            ArchetypeName, "RigidAlignment:translation",
+            Loggable<rerun::components::Translation3D>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `quaternion` field.
+        static constexpr auto Descriptor_quaternion = ComponentDescriptor(
+            ArchetypeName, "RigidAlignment:quaternion",
+            Loggable<rerun::components::RotationQuat>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `rmse` field.
+        static constexpr auto Descriptor_rmse = ComponentDescriptor(
+            ArchetypeName, "RigidAlignment:rmse",
+            Loggable<rerun::components::Length>::ComponentType
+        );
+
+      public:
+        RigidAlignment() = default;
+        RigidAlignment(RigidAlignment&& other) = default;
+        RigidAlignment(const RigidAlignment& other) = default;
+        RigidAlignment& operator=(const RigidAlignment& other) = default;
+        RigidAlignment& operator=(RigidAlignment&& other) = default;
+
+        /// Update only some specific fields of a `RigidAlignment`.
+        static RigidAlignment update_fields() {
+            return RigidAlignment();
+        }
+
+        /// Clear all the fields of a `RigidAlignment`.
+        static RigidAlignment clear_fields();
+
+        /// The entity to align to, e.g. the ground truth.
+        ///
+        /// Has to be shown in the same view.
+        RigidAlignment with_reference(const rerun::components::EntityPath& _reference) && {
+            reference =
+                ComponentBatch::from_loggable(_reference, Descriptor_reference).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Whether to match each point with the closest point of the reference (ICP).
+        ///
+        /// Otherwise points are matched by index, which requires both entities to have the same
+        /// number of points, e.g. two trajectories sampled at the same times.
+        RigidAlignment with_match_closest(
+            const rerun::blueprint::components::Enabled& _match_closest
+        ) && {
+            match_closest =
+                ComponentBatch::from_loggable(_match_closest, Descriptor_match_closest)
+                    .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The translation of the fitted transform, in the space of the entity.
+        RigidAlignment with_translation(const rerun::components::Translation3D& _translation) && {
+            translation =
+                ComponentBatch::from_loggable(_translation, Descriptor_translation)
+                    .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The rotation of the fitted transform, in the space of the entity.
+        RigidAlignment with_quaternion(const rerun::components::RotationQuat& _quaternion) && {
+            quaternion =
+                ComponentBatch::from_loggable(_quaternion, Descriptor_quaternion).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The root mean square distance between the matched points after the fit.
+        ///
+        /// Set by the viewer when it fits the transform.
+        RigidAlignment with_rmse(const rerun::components::Length& _rmse) && {
+            rmse = ComponentBatch::from_loggable(_rmse, Descriptor_rmse).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::RigidAlignment> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::RigidAlignment& archetype
+        );
+    };
+} // namespace rerun
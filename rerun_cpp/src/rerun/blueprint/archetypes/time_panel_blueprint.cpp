@@ -32,12 +32,15 @@ namespace rerun::blueprint::archetypes {
                 Descriptor_time_selection
             )
                 .value_or_throw();
+        archetype.expanded_entities =
+            ComponentBatch::empty<rerun::components::EntityPath>(Descriptor_expanded_entities)
+                .value_or_throw();
         return archetype;
     }
 
     Collection<ComponentColumn> TimePanelBlueprint::columns(const Collection<uint32_t>& lengths_) {
         std::vector<ComponentColumn> columns;
-        columns.reserve(7);
+        columns.reserve(8);
         if (state.has_value()) {
             columns.push_back(state.value().partitioned(lengths_).value_or_throw());
         }
@@ -59,6 +62,9 @@ namespace rerun::blueprint::archetypes {
         if (time_selection.has_value()) {
             columns.push_back(time_selection.value().partitioned(lengths_).value_or_throw());
         }
+        if (expanded_entities.has_value()) {
+            columns.push_back(expanded_entities.value().partitioned(lengths_).value_or_throw());
+        }
         return columns;
     }
 
@@ -84,6 +90,9 @@ namespace rerun::blueprint::archetypes {
         if (time_selection.has_value()) {
             return columns(std::vector<uint32_t>(time_selection.value().length(), 1));
         }
+        if (expanded_entities.has_value()) {
+            return columns(std::vector<uint32_t>(expanded_entities.value().length(), 1));
+        }
         return Collection<ComponentColumn>();
     }
 } // namespace rerun::blueprint::archetypes
@@ -96,7 +105,7 @@ namespace rerun {
         ) {
         using namespace blueprint::archetypes;
         std::vector<ComponentBatch> cells;
-        cells.reserve(7);
+        cells.reserve(8);
 
         if (archetype.state.has_value()) {
             cells.push_back(archetype.state.value());
@@ -119,6 +128,9 @@ namespace rerun {
         if (archetype.time_selection.has_value()) {
             cells.push_back(archetype.time_selection.value());
         }
+        if (archetype.expanded_entities.has_value()) {
+            cells.push_back(archetype.expanded_entities.value());
+        }
 
         return rerun::take_ownership(std::move(cells));
     }
@@ -13,6 +13,7 @@
 #include "../../collection.hpp"
 #include "../../component_batch.hpp"
 #include "../../component_column.hpp"
+#include "../../components/entity_path.hpp"
 #include "../../result.hpp"
 
 #include <cstdint>
@@ -51,6 +52,12 @@ namespace rerun::blueprint::archetypes {
         /// Selects a range of time on the time panel.
         std::optional<ComponentBatch> time_selection;
 
+        /// The entities whose rows are expanded in the streams tree of the time panel.
+        ///
+        /// Collapsed rows show the combined data density of their whole subtree.
+        /// If not set, the viewer picks which rows to expand.
+        std::optional<ComponentBatch> expanded_entities;
+
       public:
         /// The name of the archetype as used in `ComponentDescriptor`s.
         static constexpr const char ArchetypeName[] =
@@ -91,6 +98,11 @@ namespace rerun::blueprint::archetypes {
             ArchetypeName, "TimePanelBlueprint:time_selection",
             Loggable<rerun::blueprint::components::AbsoluteTimeRange>::ComponentType
         );
+        /// `ComponentDescriptor` for the `expanded_entities` field.
+        static constexpr auto Descriptor_expanded_entities = ComponentDescriptor(
+            ArchetypeName, "TimePanelBlueprint:expanded_entities",
+            Loggable<rerun::components::EntityPath>::ComponentType
+        );
 
       public:
         TimePanelBlueprint() = default;
@@ -168,6 +180,19 @@ namespace rerun::blueprint::archetypes {
             return std::move(*this);
         }
 
+        /// The entities whose rows are expanded in the streams tree of the time panel.
+        ///
+        /// Collapsed rows show the combined data density of their whole subtree.
+        /// If not set, the viewer picks which rows to expand.
+        TimePanelBlueprint with_expanded_entities(
+            const Collection<rerun::components::EntityPath>& _expanded_entities
+        ) && {
+            expanded_entities =
+                ComponentBatch::from_loggable(_expanded_entities, Descriptor_expanded_entities)
+                    .value_or_throw();
+            return std::move(*this);
+        }
+
         /// Partitions the component data into multiple sub-batches.
         ///
         /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
@@ -0,0 +1,74 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/trajectory_trails.fbs".
+
+#include "trajectory_trails.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    TrajectoryTrails TrajectoryTrails::clear_fields() {
+        auto archetype = TrajectoryTrails();
+        archetype.time_range =
+            ComponentBatch::empty<rerun::blueprint::components::TimeRange>(Descriptor_time_range)
+                .value_or_throw();
+        archetype.fade =
+            ComponentBatch::empty<rerun::blueprint::components::Enabled>(Descriptor_fade)
+                .value_or_throw();
+        archetype.color_by_time =
+            ComponentBatch::empty<rerun::blueprint::components::Enabled>(Descriptor_color_by_time)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> TrajectoryTrails::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(3);
+        if (time_range.has_value()) {
+            columns.push_back(time_range.value().partitioned(lengths_).value_or_throw());
+        }
+        if (fade.has_value()) {
+            columns.push_back(fade.value().partitioned(lengths_).value_or_throw());
+        }
+        if (color_by_time.has_value()) {
+            columns.push_back(color_by_time.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> TrajectoryTrails::columns() {
+        if (time_range.has_value()) {
+            return columns(std::vector<uint32_t>(time_range.value().length(), 1));
+        }
+        if (fade.has_value()) {
+            return columns(std::vector<uint32_t>(fade.value().length(), 1));
+        }
+        if (color_by_time.has_value()) {
+            return columns(std::vector<uint32_t>(color_by_time.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>>
+        AsComponents<blueprint::archetypes::TrajectoryTrails>::as_batches(
+            const blueprint::archetypes::TrajectoryTrails& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(3);
+
+        if (archetype.time_range.has_value()) {
+            cells.push_back(archetype.time_range.value());
+        }
+        if (archetype.fade.has_value()) {
+            cells.push_back(archetype.fade.value());
+        }
+        if (archetype.color_by_time.has_value()) {
+            cells.push_back(archetype.color_by_time.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
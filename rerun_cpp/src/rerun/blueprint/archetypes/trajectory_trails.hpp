@@ -0,0 +1,140 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/trajectory_trails.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/enabled.hpp"
+#include "../../blueprint/components/time_range.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Configuration for the trajectory trails in the 3D view.
+    ///
+    /// Trails show the past positions of entities, and are enabled per entity
+    /// by adding the `Trajectory` visualizer to it.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct TrajectoryTrails {
+        /// The time range of past positions that is drawn, usually relative to the time cursor.
+        ///
+        /// Defaults to the 10 seconds before the time cursor, or to the 100 steps before it on sequence timelines.
+        std::optional<ComponentBatch> time_range;
+
+        /// Whether older parts of the trails are drawn thinner.
+        ///
+        /// Defaults to true.
+        std::optional<ComponentBatch> fade;
+
+        /// Whether the trails are colored by time instead of with the color of their entity.
+        ///
+        /// Defaults to false.
+        std::optional<ComponentBatch> color_by_time;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.TrajectoryTrails";
+
+        /// `ComponentDescriptor` for the `time_range` field.
+        static constexpr auto Descriptor_time_range = ComponentDescriptor(
+            ArchetypeName, "TrajectoryTrails:time_range",
+            Loggable<rerun::blueprint::components::TimeRange>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `fade` field.
+        static constexpr auto Descriptor_fade = ComponentDescriptor(
+            ArchetypeName, "TrajectoryTrails:fade",
+            Loggable<rerun::blueprint::components::Enabled>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `color_by_time` field.
+        static constexpr auto Descriptor_color_by_time = ComponentDescriptor(
+            ArchetypeName, "TrajectoryTrails:color_by_time",
+            Loggable<rerun::blueprint::components::Enabled>::ComponentType
+        );
+
+      public:
+        TrajectoryTrails() = default;
+        TrajectoryTrails(TrajectoryTrails&& other) = default;
+        TrajectoryTrails(const TrajectoryTrails& other) = default;
+        TrajectoryTrails& operator=(const TrajectoryTrails& other) = default;
+        TrajectoryTrails& operator=(TrajectoryTrails&& other) = default;
+
+        /// Update only some specific fields of a `TrajectoryTrails`.
+        static TrajectoryTrails update_fields() {
+            return TrajectoryTrails();
+        }
+
+        /// Clear all the fields of a `TrajectoryTrails`.
+        static TrajectoryTrails clear_fields();
+
+        /// The time range of past positions that is drawn, usually relative to the time cursor.
+        ///
+        /// Defaults to the 10 seconds before the time cursor, or to the 100 steps before it on sequence timelines.
+        TrajectoryTrails with_time_range(
+            const rerun::blueprint::components::TimeRange& _time_range
+        ) && {
+            time_range =
+                ComponentBatch::from_loggable(_time_range, Descriptor_time_range).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Whether older parts of the trails are drawn thinner.
+        ///
+        /// Defaults to true.
+        TrajectoryTrails with_fade(const rerun::blueprint::components::Enabled& _fade) && {
+            fade = ComponentBatch::from_loggable(_fade, Descriptor_fade).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Whether the trails are colored by time instead of with the color of their entity.
+        ///
+        /// Defaults to false.
+        TrajectoryTrails with_color_by_time(
+            const rerun::blueprint::components::Enabled& _color_by_time
+        ) && {
+            color_by_time =
+                ComponentBatch::from_loggable(_color_by_time, Descriptor_color_by_time)
+                    .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::TrajectoryTrails> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::TrajectoryTrails& archetype
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,99 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/visibility_rules.fbs".
+
+#include "visibility_rules.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    VisibilityRules VisibilityRules::clear_fields() {
+        auto archetype = VisibilityRules();
+        archetype.timeline =
+            ComponentBatch::empty<rerun::blueprint::components::TimelineName>(Descriptor_timeline)
+                .value_or_throw();
+        archetype.time_range =
+            ComponentBatch::empty<rerun::blueprint::components::AbsoluteTimeRange>(
+                Descriptor_time_range
+            )
+                .value_or_throw();
+        archetype.scalar_entity =
+            ComponentBatch::empty<rerun::components::EntityPath>(Descriptor_scalar_entity)
+                .value_or_throw();
+        archetype.scalar_component =
+            ComponentBatch::empty<rerun::components::Name>(Descriptor_scalar_component)
+                .value_or_throw();
+        archetype.threshold =
+            ComponentBatch::empty<rerun::components::Scalar>(Descriptor_threshold).value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> VisibilityRules::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(5);
+        if (timeline.has_value()) {
+            columns.push_back(timeline.value().partitioned(lengths_).value_or_throw());
+        }
+        if (time_range.has_value()) {
+            columns.push_back(time_range.value().partitioned(lengths_).value_or_throw());
+        }
+        if (scalar_entity.has_value()) {
+            columns.push_back(scalar_entity.value().partitioned(lengths_).value_or_throw());
+        }
+        if (scalar_component.has_value()) {
+            columns.push_back(scalar_component.value().partitioned(lengths_).value_or_throw());
+        }
+        if (threshold.has_value()) {
+            columns.push_back(threshold.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> VisibilityRules::columns() {
+        if (timeline.has_value()) {
+            return columns(std::vector<uint32_t>(timeline.value().length(), 1));
+        }
+        if (time_range.has_value()) {
+            return columns(std::vector<uint32_t>(time_range.value().length(), 1));
+        }
+        if (scalar_entity.has_value()) {
+            return columns(std::vector<uint32_t>(scalar_entity.value().length(), 1));
+        }
+        if (scalar_component.has_value()) {
+            return columns(std::vector<uint32_t>(scalar_component.value().length(), 1));
+        }
+        if (threshold.has_value()) {
+            return columns(std::vector<uint32_t>(threshold.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>>
+        AsComponents<blueprint::archetypes::VisibilityRules>::as_batches(
+            const blueprint::archetypes::VisibilityRules& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(5);
+
+        if (archetype.timeline.has_value()) {
+            cells.push_back(archetype.timeline.value());
+        }
+        if (archetype.time_range.has_value()) {
+            cells.push_back(archetype.time_range.value());
+        }
+        if (archetype.scalar_entity.has_value()) {
+            cells.push_back(archetype.scalar_entity.value());
+        }
+        if (archetype.scalar_component.has_value()) {
+            cells.push_back(archetype.scalar_component.value());
+        }
+        if (archetype.threshold.has_value()) {
+            cells.push_back(archetype.threshold.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
@@ -0,0 +1,188 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/visibility_rules.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/absolute_time_range.hpp"
+#include "../../blueprint/components/timeline_name.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/entity_path.hpp"
+#include "../../components/name.hpp"
+#include "../../components/scalar.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Rules under which a view or an entity in a view is shown, evaluated every frame.
+    ///
+    /// Logged as a property of a view, the rules show or hide the whole view.
+    /// Logged as an override of an entity in a view, they show or hide that entity and its children.
+    /// As soon as any of the rules isn't met, the view or entity is hidden.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct VisibilityRules {
+        /// The timeline that `time_range` refers to.
+        ///
+        /// If unset, `time_range` applies to whichever timeline is active.
+        std::optional<ComponentBatch> timeline;
+
+        /// Only shown while the time cursor is within this range.
+        ///
+        /// If unset, shown at all times.
+        std::optional<ComponentBatch> time_range;
+
+        /// The entity whose scalar value is compared against `threshold`.
+        ///
+        /// If unset, shown regardless of any scalar value.
+        std::optional<ComponentBatch> scalar_entity;
+
+        /// The component of `scalar_entity` that is compared against `threshold`.
+        ///
+        /// Either the full component identifier or just the part after the archetype prefix.
+        /// Defaults to the `scalars` of a `Scalars` archetype.
+        std::optional<ComponentBatch> scalar_component;
+
+        /// Only shown while the latest value of the scalar is at least this threshold.
+        ///
+        /// Shown if the scalar has no value at the current time.
+        /// Defaults to 0.0.
+        std::optional<ComponentBatch> threshold;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.VisibilityRules";
+
+        /// `ComponentDescriptor` for the `timeline` field.
+        static constexpr auto Descriptor_timeline = ComponentDescriptor(
+            ArchetypeName, "VisibilityRules:timeline",
+            Loggable<rerun::blueprint::components::TimelineName>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `time_range` field.
+        static constexpr auto Descriptor_time_range = ComponentDescriptor(
+            ArchetypeName, "VisibilityRules:time_range",
+            Loggable<rerun::blueprint::components::AbsoluteTimeRange>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `scalar_entity` field.
+        static constexpr auto Descriptor_scalar_entity = ComponentDescriptor(
+            ArchetypeName, "VisibilityRules:scalar_entity",
+            Loggable<rerun::components::EntityPath>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `scalar_component` field.
+        static constexpr auto Descriptor_scalar_component = ComponentDescriptor(
+            ArchetypeName, "VisibilityRules:scalar_component",
+            Loggable<rerun::components::Name>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `threshold` field.
+        static constexpr auto Descriptor_threshold = ComponentDescriptor(
+            ArchetypeName, "VisibilityRules:threshold",
+            Loggable<rerun::components::Scalar>::ComponentType
+        );
+
+      public:
+        VisibilityRules() = default;
+        VisibilityRules(VisibilityRules&& other) = default;
+        VisibilityRules(const VisibilityRules& other) = default;
+        VisibilityRules& operator=(const VisibilityRules& other) = default;
+        VisibilityRules& operator=(VisibilityRules&& other) = default;
+
+        /// Update only some specific fields of a `VisibilityRules`.
+        static VisibilityRules update_fields() {
+            return VisibilityRules();
+        }
+
+        /// Clear all the fields of a `VisibilityRules`.
+        static VisibilityRules clear_fields();
+
+        /// The timeline that `time_range` refers to.
+        ///
+        /// If unset, `time_range` applies to whichever timeline is active.
+        VisibilityRules with_timeline(
+            const rerun::blueprint::components::TimelineName& _timeline
+        ) && {
+            timeline =
+                ComponentBatch::from_loggable(_timeline, Descriptor_timeline).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Only shown while the time cursor is within this range.
+        ///
+        /// If unset, shown at all times.
+        VisibilityRules with_time_range(
+            const rerun::blueprint::components::AbsoluteTimeRange& _time_range
+        ) && {
+            time_range =
+                ComponentBatch::from_loggable(_time_range, Descriptor_time_range).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The entity whose scalar value is compared against `threshold`.
+        ///
+        /// If unset, shown regardless of any scalar value.
+        VisibilityRules with_scalar_entity(const rerun::components::EntityPath& _scalar_entity) && {
+            scalar_entity =
+                ComponentBatch::from_loggable(_scalar_entity, Descriptor_scalar_entity)
+                    .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The component of `scalar_entity` that is compared against `threshold`.
+        ///
+        /// Either the full component identifier or just the part after the archetype prefix.
+        /// Defaults to the `scalars` of a `Scalars` archetype.
+        VisibilityRules with_scalar_component(const rerun::components::Name& _scalar_component) && {
+            scalar_component =
+                ComponentBatch::from_loggable(_scalar_component, Descriptor_scalar_component)
+                    .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Only shown while the latest value of the scalar is at least this threshold.
+        ///
+        /// Shown if the scalar has no value at the current time.
+        /// Defaults to 0.0.
+        VisibilityRules with_threshold(const rerun::components::Scalar& _threshold) && {
+            threshold =
+                ComponentBatch::from_loggable(_threshold, Descriptor_threshold).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::VisibilityRules> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::VisibilityRules& archetype
+        );
+    };
+} // namespace rerun
@@ -9,12 +9,17 @@
 #include "blueprint/components/auto_layout.hpp"
 #include "blueprint/components/auto_views.hpp"
 #include "blueprint/components/background_kind.hpp"
+#include "blueprint/components/bar_chart_mode.hpp"
 #include "blueprint/components/column_share.hpp"
 #include "blueprint/components/component_column_selector.hpp"
 #include "blueprint/components/container_kind.hpp"
 #include "blueprint/components/corner2d.hpp"
+#include "blueprint/components/depth_cloud_subsampling.hpp"
 #include "blueprint/components/enabled.hpp"
 #include "blueprint/components/eye3d_kind.hpp"
+#include "blueprint/components/eye3d_projection.hpp"
+#include "blueprint/components/fft_window_overlap.hpp"
+#include "blueprint/components/fft_window_size.hpp"
 #include "blueprint/components/filter_by_range.hpp"
 #include "blueprint/components/filter_is_not_null.hpp"
 #include "blueprint/components/force_distance.hpp"
@@ -29,6 +34,7 @@
 #include "blueprint/components/loop_mode.hpp"
 #include "blueprint/components/map_provider.hpp"
 #include "blueprint/components/near_clip_plane.hpp"
+#include "blueprint/components/onion_skin_steps.hpp"
 #include "blueprint/components/panel_state.hpp"
 #include "blueprint/components/play_state.hpp"
 #include "blueprint/components/playback_speed.hpp"
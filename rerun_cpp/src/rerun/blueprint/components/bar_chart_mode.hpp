@@ -0,0 +1,60 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/bar_chart_mode.fbs".
+
+#pragma once
+
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace arrow {
+    /// \private
+    template <typename T>
+    class NumericBuilder;
+
+    class Array;
+    class DataType;
+    class UInt8Type;
+    using UInt8Builder = NumericBuilder<UInt8Type>;
+} // namespace arrow
+
+namespace rerun::blueprint::components {
+    /// **Component**: How the bars of several bar charts in the same view are arranged.
+    enum class BarChartMode : uint8_t {
+
+        /// The bars of all entities are drawn on top of each other.
+        Overlapping = 1,
+
+        /// The bars of different entities at the same position are drawn next to each other.
+        Grouped = 2,
+
+        /// The bars of different entities at the same position are stacked on top of each other.
+        Stacked = 3,
+    };
+} // namespace rerun::blueprint::components
+
+namespace rerun {
+    template <typename T>
+    struct Loggable;
+
+    /// \private
+    template <>
+    struct Loggable<blueprint::components::BarChartMode> {
+        static constexpr std::string_view ComponentType = "rerun.blueprint.components.BarChartMode";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype();
+
+        /// Serializes an array of `rerun::blueprint:: components::BarChartMode` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const blueprint::components::BarChartMode* instances, size_t num_instances
+        );
+
+        /// Fills an arrow array builder with an array of this type.
+        static rerun::Error fill_arrow_array_builder(
+            arrow::UInt8Builder* builder, const blueprint::components::BarChartMode* elements,
+            size_t num_elements
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,79 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/depth_cloud_subsampling.fbs".
+
+#pragma once
+
+#include "../../datatypes/uint64.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace rerun::blueprint::components {
+    /// **Component**: Only every n-th pixel of a depth image, in both directions, is turned into a point of its point cloud.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct DepthCloudSubsampling {
+        rerun::datatypes::UInt64 factor;
+
+      public:
+        DepthCloudSubsampling() = default;
+
+        DepthCloudSubsampling(rerun::datatypes::UInt64 factor_) : factor(factor_) {}
+
+        DepthCloudSubsampling& operator=(rerun::datatypes::UInt64 factor_) {
+            factor = factor_;
+            return *this;
+        }
+
+        DepthCloudSubsampling(uint64_t value_) : factor(value_) {}
+
+        DepthCloudSubsampling& operator=(uint64_t value_) {
+            factor = value_;
+            return *this;
+        }
+
+        /// Cast to the underlying UInt64 datatype
+        operator rerun::datatypes::UInt64() const {
+            return factor;
+        }
+    };
+} // namespace rerun::blueprint::components
+
+namespace rerun {
+    static_assert(
+        sizeof(rerun::datatypes::UInt64) == sizeof(blueprint::components::DepthCloudSubsampling)
+    );
+
+    /// \private
+    template <>
+    struct Loggable<blueprint::components::DepthCloudSubsampling> {
+        static constexpr std::string_view ComponentType =
+            "rerun.blueprint.components.DepthCloudSubsampling";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::UInt64>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::blueprint:: components::DepthCloudSubsampling` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const blueprint::components::DepthCloudSubsampling* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::UInt64>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::UInt64>::to_arrow(
+                    &instances->factor,
+                    num_instances
+                );
+            }
+        }
+    };
+} // namespace rerun
@@ -0,0 +1,63 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/eye3d_projection.fbs".
+
+#pragma once
+
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace arrow {
+    /// \private
+    template <typename T>
+    class NumericBuilder;
+
+    class Array;
+    class DataType;
+    class UInt8Type;
+    using UInt8Builder = NumericBuilder<UInt8Type>;
+} // namespace arrow
+
+namespace rerun::blueprint::components {
+    /// **Component**: The projection of the 3D eye to view a scene in a `views::Spatial3DView`.
+    enum class Eye3DProjection : uint8_t {
+
+        /// Perspective projection.
+        ///
+        /// Objects further away from the eye appear smaller, as with a real camera.
+        Perspective = 1,
+
+        /// Orthographic projection.
+        ///
+        /// Parallel lines stay parallel and objects keep their size regardless of their distance to the eye,
+        /// which makes it possible to compare and measure lengths on screen.
+        Orthographic = 2,
+    };
+} // namespace rerun::blueprint::components
+
+namespace rerun {
+    template <typename T>
+    struct Loggable;
+
+    /// \private
+    template <>
+    struct Loggable<blueprint::components::Eye3DProjection> {
+        static constexpr std::string_view ComponentType =
+            "rerun.blueprint.components.Eye3DProjection";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype();
+
+        /// Serializes an array of `rerun::blueprint:: components::Eye3DProjection` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const blueprint::components::Eye3DProjection* instances, size_t num_instances
+        );
+
+        /// Fills an arrow array builder with an array of this type.
+        static rerun::Error fill_arrow_array_builder(
+            arrow::UInt8Builder* builder, const blueprint::components::Eye3DProjection* elements,
+            size_t num_elements
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,80 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/fft_window_overlap.fbs".
+
+#pragma once
+
+#include "../../datatypes/float32.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace rerun::blueprint::components {
+    /// **Component**: How much consecutive windows of a rolling FFT overlap, e.g. of a `SpectrogramView`.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct FftWindowOverlap {
+        /// The overlap as a fraction of the window size, in the range `[0, 1)`.
+        rerun::datatypes::Float32 fraction;
+
+      public:
+        FftWindowOverlap() = default;
+
+        FftWindowOverlap(rerun::datatypes::Float32 fraction_) : fraction(fraction_) {}
+
+        FftWindowOverlap& operator=(rerun::datatypes::Float32 fraction_) {
+            fraction = fraction_;
+            return *this;
+        }
+
+        FftWindowOverlap(float value_) : fraction(value_) {}
+
+        FftWindowOverlap& operator=(float value_) {
+            fraction = value_;
+            return *this;
+        }
+
+        /// Cast to the underlying Float32 datatype
+        operator rerun::datatypes::Float32() const {
+            return fraction;
+        }
+    };
+} // namespace rerun::blueprint::components
+
+namespace rerun {
+    static_assert(
+        sizeof(rerun::datatypes::Float32) == sizeof(blueprint::components::FftWindowOverlap)
+    );
+
+    /// \private
+    template <>
+    struct Loggable<blueprint::components::FftWindowOverlap> {
+        static constexpr std::string_view ComponentType =
+            "rerun.blueprint.components.FftWindowOverlap";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::Float32>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::blueprint:: components::FftWindowOverlap` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const blueprint::components::FftWindowOverlap* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::Float32>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::Float32>::to_arrow(
+                    &instances->fraction,
+                    num_instances
+                );
+            }
+        }
+    };
+} // namespace rerun
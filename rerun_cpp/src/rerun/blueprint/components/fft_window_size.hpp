@@ -0,0 +1,78 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/fft_window_size.fbs".
+
+#pragma once
+
+#include "../../datatypes/uint32.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace rerun::blueprint::components {
+    /// **Component**: The number of samples in each window of a rolling FFT, e.g. of a `SpectrogramView`.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct FftWindowSize {
+        /// The number of samples per window.
+        rerun::datatypes::UInt32 samples;
+
+      public:
+        FftWindowSize() = default;
+
+        FftWindowSize(rerun::datatypes::UInt32 samples_) : samples(samples_) {}
+
+        FftWindowSize& operator=(rerun::datatypes::UInt32 samples_) {
+            samples = samples_;
+            return *this;
+        }
+
+        FftWindowSize(uint32_t value_) : samples(value_) {}
+
+        FftWindowSize& operator=(uint32_t value_) {
+            samples = value_;
+            return *this;
+        }
+
+        /// Cast to the underlying UInt32 datatype
+        operator rerun::datatypes::UInt32() const {
+            return samples;
+        }
+    };
+} // namespace rerun::blueprint::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::UInt32) == sizeof(blueprint::components::FftWindowSize));
+
+    /// \private
+    template <>
+    struct Loggable<blueprint::components::FftWindowSize> {
+        static constexpr std::string_view ComponentType =
+            "rerun.blueprint.components.FftWindowSize";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::UInt32>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::blueprint:: components::FftWindowSize` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const blueprint::components::FftWindowSize* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::UInt32>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::UInt32>::to_arrow(
+                    &instances->samples,
+                    num_instances
+                );
+            }
+        }
+    };
+} // namespace rerun
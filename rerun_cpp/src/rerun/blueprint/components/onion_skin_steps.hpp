@@ -0,0 +1,79 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/onion_skin_steps.fbs".
+
+#pragma once
+
+#include "../../datatypes/uint64.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace rerun::blueprint::components {
+    /// **Component**: How many earlier states of each entity are shown by the onion skin of a view.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct OnionSkinSteps {
+        rerun::datatypes::UInt64 steps;
+
+      public:
+        OnionSkinSteps() = default;
+
+        OnionSkinSteps(rerun::datatypes::UInt64 steps_) : steps(steps_) {}
+
+        OnionSkinSteps& operator=(rerun::datatypes::UInt64 steps_) {
+            steps = steps_;
+            return *this;
+        }
+
+        OnionSkinSteps(uint64_t value_) : steps(value_) {}
+
+        OnionSkinSteps& operator=(uint64_t value_) {
+            steps = value_;
+            return *this;
+        }
+
+        /// Cast to the underlying UInt64 datatype
+        operator rerun::datatypes::UInt64() const {
+            return steps;
+        }
+    };
+} // namespace rerun::blueprint::components
+
+namespace rerun {
+    static_assert(
+        sizeof(rerun::datatypes::UInt64) == sizeof(blueprint::components::OnionSkinSteps)
+    );
+
+    /// \private
+    template <>
+    struct Loggable<blueprint::components::OnionSkinSteps> {
+        static constexpr std::string_view ComponentType =
+            "rerun.blueprint.components.OnionSkinSteps";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::UInt64>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::blueprint:: components::OnionSkinSteps` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const blueprint::components::OnionSkinSteps* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::UInt64>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::UInt64>::to_arrow(
+                    &instances->steps,
+                    num_instances
+                );
+            }
+        }
+    };
+} // namespace rerun
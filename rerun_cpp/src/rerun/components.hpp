@@ -5,6 +5,8 @@
 #include "components/aggregation_policy.hpp"
 #include "components/albedo_factor.hpp"
 #include "components/annotation_context.hpp"
+#include "components/audio_channel_count.hpp"
+#include "components/audio_sample_format.hpp"
 #include "components/axis_length.hpp"
 #include "components/blob.hpp"
 #include "components/channel_id.hpp"
@@ -30,6 +32,7 @@
 #include "components/image_format.hpp"
 #include "components/image_plane_distance.hpp"
 #include "components/interactive.hpp"
+#include "components/joint_parent.hpp"
 #include "components/key_value_pairs.hpp"
 #include "components/keypoint_id.hpp"
 #include "components/lat_lon.hpp"
@@ -57,11 +60,13 @@
 #include "components/resolution.hpp"
 #include "components/rotation_axis_angle.hpp"
 #include "components/rotation_quat.hpp"
+#include "components/sample_rate.hpp"
 #include "components/scalar.hpp"
 #include "components/scale3d.hpp"
 #include "components/schema_id.hpp"
 #include "components/series_visible.hpp"
 #include "components/show_labels.hpp"
+#include "components/spherical_harmonics.hpp"
 #include "components/stroke_width.hpp"
 #include "components/tensor_data.hpp"
 #include "components/tensor_dimension_index_selection.hpp"
@@ -76,6 +81,7 @@
 #include "components/transform_relation.hpp"
 #include "components/translation3d.hpp"
 #include "components/triangle_indices.hpp"
+#include "components/unit.hpp"
 #include "components/value_range.hpp"
 #include "components/vector2d.hpp"
 #include "components/vector3d.hpp"
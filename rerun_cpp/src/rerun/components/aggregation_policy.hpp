@@ -46,6 +46,12 @@ namespace rerun::components {
 
         /// Find both the minimum and maximum values in the range, then use the average of those.
         MinMaxAverage = 6,
+
+        /// Draw a band from the minimum to the maximum values in the range, with a line through their average.
+        ///
+        /// The aggregates are kept up to date as the data arrives rather than computed from every point on every frame,
+        /// which keeps live plots of scalars logged at high rates smooth.
+        MinMeanMax = 7,
     };
 } // namespace rerun::components
 
@@ -0,0 +1,65 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/components/audio_sample_format.fbs".
+
+#pragma once
+
+#include "../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace arrow {
+    /// \private
+    template <typename T>
+    class NumericBuilder;
+
+    class Array;
+    class DataType;
+    class UInt8Type;
+    using UInt8Builder = NumericBuilder<UInt8Type>;
+} // namespace arrow
+
+namespace rerun::components {
+    /// **Component**: The data type of raw PCM samples of `archetypes::Audio`.
+    ///
+    /// All formats are little-endian.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    enum class AudioSampleFormat : uint8_t {
+
+        /// 32-bit floats in the range `[-1, 1]`.
+        F32 = 1,
+
+        /// Signed 16-bit integers.
+        I16 = 2,
+
+        /// Signed 32-bit integers.
+        I32 = 3,
+    };
+} // namespace rerun::components
+
+namespace rerun {
+    template <typename T>
+    struct Loggable;
+
+    /// \private
+    template <>
+    struct Loggable<components::AudioSampleFormat> {
+        static constexpr std::string_view ComponentType = "rerun.components.AudioSampleFormat";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype();
+
+        /// Serializes an array of `rerun::components::AudioSampleFormat` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const components::AudioSampleFormat* instances, size_t num_instances
+        );
+
+        /// Fills an arrow array builder with an array of this type.
+        static rerun::Error fill_arrow_array_builder(
+            arrow::UInt8Builder* builder, const components::AudioSampleFormat* elements,
+            size_t num_elements
+        );
+    };
+} // namespace rerun
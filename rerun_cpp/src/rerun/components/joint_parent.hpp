@@ -0,0 +1,78 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/components/joint_parent.fbs".
+
+#pragma once
+
+#include "../datatypes/uint32.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace rerun::components {
+    /// **Component**: The index of the parent of a joint within a `archetypes::Skeleton`.
+    ///
+    /// Root joints, i.e. joints without a parent, reference themselves.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct JointParent {
+        rerun::datatypes::UInt32 index;
+
+      public:
+        JointParent() = default;
+
+        JointParent(rerun::datatypes::UInt32 index_) : index(index_) {}
+
+        JointParent& operator=(rerun::datatypes::UInt32 index_) {
+            index = index_;
+            return *this;
+        }
+
+        JointParent(uint32_t value_) : index(value_) {}
+
+        JointParent& operator=(uint32_t value_) {
+            index = value_;
+            return *this;
+        }
+
+        /// Cast to the underlying UInt32 datatype
+        operator rerun::datatypes::UInt32() const {
+            return index;
+        }
+    };
+} // namespace rerun::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::UInt32) == sizeof(components::JointParent));
+
+    /// \private
+    template <>
+    struct Loggable<components::JointParent> {
+        static constexpr std::string_view ComponentType = "rerun.components.JointParent";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::UInt32>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::components::JointParent` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const components::JointParent* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::UInt32>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::UInt32>::to_arrow(
+                    &instances->index,
+                    num_instances
+                );
+            }
+        }
+    };
+} // namespace rerun
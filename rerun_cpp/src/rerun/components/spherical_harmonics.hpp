@@ -0,0 +1,66 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/components/spherical_harmonics.fbs".
+
+#pragma once
+
+#include "../collection.hpp"
+#include "../datatypes/vec3d.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <memory>
+#include <utility>
+
+namespace arrow {
+    class Array;
+    class DataType;
+    class ListBuilder;
+} // namespace arrow
+
+namespace rerun::components {
+    /// **Component**: The view dependent color of a Gaussian splat, as coefficients of real spherical harmonics.
+    ///
+    /// Holds the RGB coefficients of all degrees above zero, i.e. 3, 8 or 15 coefficients for degree 1, 2 or 3.
+    /// They are ordered by degree first and by order second, the same way as the `f_rest` properties of most
+    /// Gaussian splatting reconstructions, after transposing those from channel major to coefficient major order.
+    /// The zeroth degree is given by the color of the splat.
+    struct SphericalHarmonics {
+        rerun::Collection<rerun::datatypes::Vec3D> coefficients;
+
+      public:
+        SphericalHarmonics() = default;
+
+        SphericalHarmonics(rerun::Collection<rerun::datatypes::Vec3D> coefficients_)
+            : coefficients(std::move(coefficients_)) {}
+
+        SphericalHarmonics& operator=(rerun::Collection<rerun::datatypes::Vec3D> coefficients_) {
+            coefficients = std::move(coefficients_);
+            return *this;
+        }
+    };
+} // namespace rerun::components
+
+namespace rerun {
+    template <typename T>
+    struct Loggable;
+
+    /// \private
+    template <>
+    struct Loggable<components::SphericalHarmonics> {
+        static constexpr std::string_view ComponentType = "rerun.components.SphericalHarmonics";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype();
+
+        /// Serializes an array of `rerun::components::SphericalHarmonics` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const components::SphericalHarmonics* instances, size_t num_instances
+        );
+
+        /// Fills an arrow array builder with an array of this type.
+        static rerun::Error fill_arrow_array_builder(
+            arrow::ListBuilder* builder, const components::SphericalHarmonics* elements,
+            size_t num_elements
+        );
+    };
+} // namespace rerun
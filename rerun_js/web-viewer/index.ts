@@ -101,6 +101,12 @@ export interface WebViewerOptions {
    * enclosing notebook environment, it should be used to set the fallback token.
    */
   fallback_token?: string;
+
+  /** The token to authenticate against message proxy servers (`/proxy` URLs) with, if any.
+   *
+   * This behaves similarly to the `RERUN_GRPC_TOKEN` env variable.
+   */
+  proxy_token?: string;
 }
 
 // `AppOptions` and `WebViewerOptions` must be compatible
@@ -139,7 +145,8 @@ export type ViewerEvent =
   | TimeUpdateEvent
   | TimelineChangeEvent
   | SelectionChangeEvent
-  | RecordingOpenEvent;
+  | RecordingOpenEvent
+  | IngestProgressEvent;
 
 /**
  * Properties available on all {@link ViewerEvent} types.
@@ -221,6 +228,23 @@ export type RecordingOpenEvent = ViewerEventBase & {
   version?: string;
 }
 
+/**
+ * Fired while data is being received for a recording.
+ *
+ * Fires at most once per frame per recording, and once more with
+ * `finished` set when the source the recording is loaded from has
+ * no more data to send.
+ */
+export type IngestProgressEvent = ViewerEventBase & {
+  type: "ingest_progress";
+
+  /** Total number of rows received so far. */
+  num_rows: number;
+
+  /** Whether the data source has finished sending data. */
+  finished: boolean;
+}
+
 // A bit of TypeScript metaprogramming to automatically produce a
 // mapping of event names to event payloads given the above type
 // definitions.
@@ -697,6 +721,29 @@ export class WebViewer {
     return new LogChannel(on_send, on_send_table, on_close, get_state);
   }
 
+  /**
+   * Load a blueprint into the viewer.
+   *
+   * @param data the contents of an `.rbl` file.
+   * @param options.make_active whether the blueprint immediately replaces the current one. Defaults to `true`.
+   * @param options.make_default whether the viewer resets to this blueprint from now on. Defaults to `true`.
+   */
+  send_blueprint(
+    data: Uint8Array,
+    options: { make_active?: boolean; make_default?: boolean } = {},
+  ) {
+    if (!this.#handle) {
+      throw new Error(`attempted to send a blueprint to a stopped web viewer`);
+    }
+
+    try {
+      this.#handle.send_blueprint(data, options.make_active, options.make_default);
+    } catch (e) {
+      this.stop();
+      throw e;
+    }
+  }
+
   /**
    * Force a panel to a specific state.
    *
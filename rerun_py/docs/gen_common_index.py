@@ -162,8 +162,10 @@ SECTION_TABLE: Final[list[Section]] = [
         title="General",
         class_list=[
             "archetypes.Clear",
+            "archetypes.ClockControl",
             "blueprint.archetypes.EntityBehavior",
             "archetypes.RecordingInfo",
+            "archetypes.Sensitive",
         ],
         gen_page=False,
     ),
@@ -200,6 +202,11 @@ SECTION_TABLE: Final[list[Section]] = [
         ],
         gen_page=False,
     ),
+    Section(
+        title="Audio",
+        class_list=["archetypes.Audio"],
+        gen_page=False,
+    ),
     Section(
         title="Plotting",
         class_list=[
@@ -221,11 +228,13 @@ SECTION_TABLE: Final[list[Section]] = [
             "archetypes.Capsules3D",
             "archetypes.Cylinders3D",
             "archetypes.Ellipsoids3D",
+            "archetypes.GaussianSplats3D",
             "archetypes.LineStrips2D",
             "archetypes.LineStrips3D",
             "archetypes.Mesh3D",
             "archetypes.Points2D",
             "archetypes.Points3D",
+            "archetypes.Skeleton",
         ],
         gen_page=False,
     ),
@@ -326,9 +335,11 @@ SECTION_TABLE: Final[list[Section]] = [
             "Grid",
             "Tabs",
             "View",
+            "AudioView",
             "BarChartView",
             "Spatial2DView",
             "Spatial3DView",
+            "SpectrogramView",
             "TensorView",
             "TextDocumentView",
             "TextLogView",
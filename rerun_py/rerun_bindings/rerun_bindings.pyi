@@ -399,10 +399,28 @@ class RecordingView:
 
         """
 
-    def fill_latest_at(self) -> RecordingView:
+    def fill_latest_at(self, *, tolerance: int | None = None) -> RecordingView:
         """
         Populate any null values in a row with the latest valid data according to the index.
 
+        Combined with [`.filter_is_not_null()`][rerun.dataframe.RecordingView.filter_is_not_null], this
+        performs an as-of join: every row at which the filtered component was logged gets the most recent
+        value of every other column, e.g. to align a 1 kHz IMU with the frames of a 30 Hz camera:
+
+        ```python
+        view = recording.view(index="capture_time", contents=["/camera/**", "/imu/**"])
+        table = view.filter_is_not_null(camera_column).fill_latest_at(tolerance=10_000_000).select()
+        ```
+
+        Parameters
+        ----------
+        tolerance : int | None
+            If set, null values are only filled with data logged at most this long before their row.
+            Older data is left null.
+
+            This is in the units of the index, i.e. nanoseconds for timestamps and durations, and
+            steps for sequences.
+
         Returns
         -------
         RecordingView
@@ -1794,10 +1812,28 @@ class DataframeQueryView:
 
         """
 
-    def fill_latest_at(self) -> Self:
+    def fill_latest_at(self, *, tolerance: int | None = None) -> Self:
         """
         Populate any null values in a row with the latest valid data according to the index.
 
+        Combined with [`.filter_is_not_null()`][rerun.dataframe.RecordingView.filter_is_not_null], this
+        performs an as-of join: every row at which the filtered component was logged gets the most recent
+        value of every other column, e.g. to align a 1 kHz IMU with the frames of a 30 Hz camera:
+
+        ```python
+        view = recording.view(index="capture_time", contents=["/camera/**", "/imu/**"])
+        table = view.filter_is_not_null(camera_column).fill_latest_at(tolerance=10_000_000).select()
+        ```
+
+        Parameters
+        ----------
+        tolerance : int | None
+            If set, null values are only filled with data logged at most this long before their row.
+            Older data is left null.
+
+            This is in the units of the index, i.e. nanoseconds for timestamps and durations, and
+            steps for sequences.
+
         Returns
         -------
         RecordingView
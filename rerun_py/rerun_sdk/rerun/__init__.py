@@ -77,16 +77,19 @@ from .archetypes import (
     Arrows3D as Arrows3D,
     Asset3D as Asset3D,
     AssetVideo as AssetVideo,
+    Audio as Audio,
     BarChart as BarChart,
     Boxes2D as Boxes2D,
     Boxes3D as Boxes3D,
     Capsules3D as Capsules3D,
     Clear as Clear,
+    ClockControl as ClockControl,
     CoordinateFrame as CoordinateFrame,
     Cylinders3D as Cylinders3D,
     DepthImage as DepthImage,
     Ellipsoids3D as Ellipsoids3D,
     EncodedImage as EncodedImage,
+    GaussianSplats3D as GaussianSplats3D,
     GeoLineStrings as GeoLineStrings,
     GeoPoints as GeoPoints,
     GraphEdges as GraphEdges,
@@ -105,8 +108,10 @@ from .archetypes import (
     Points3D as Points3D,
     Scalars as Scalars,
     SegmentationImage as SegmentationImage,
+    Sensitive as Sensitive,
     SeriesLines as SeriesLines,
     SeriesPoints as SeriesPoints,
+    Skeleton as Skeleton,
     Tensor as Tensor,
     TextDocument as TextDocument,
     TextLog as TextLog,
@@ -120,6 +125,7 @@ from .archetypes.boxes2d_ext import (
 )
 from .components import (
     AlbedoFactor as AlbedoFactor,
+    AudioSampleFormat as AudioSampleFormat,
     GraphEdge as GraphEdge,
     GraphType as GraphType,
     MediaType as MediaType,
@@ -7,16 +7,19 @@ from .arrows2d import Arrows2D
 from .arrows3d import Arrows3D
 from .asset3d import Asset3D
 from .asset_video import AssetVideo
+from .audio import Audio
 from .bar_chart import BarChart
 from .boxes2d import Boxes2D
 from .boxes3d import Boxes3D
 from .capsules3d import Capsules3D
 from .clear import Clear
+from .clock_control import ClockControl
 from .coordinate_frame import CoordinateFrame
 from .cylinders3d import Cylinders3D
 from .depth_image import DepthImage
 from .ellipsoids3d import Ellipsoids3D
 from .encoded_image import EncodedImage
+from .gaussian_splats3d import GaussianSplats3D
 from .geo_line_strings import GeoLineStrings
 from .geo_points import GeoPoints
 from .graph_edges import GraphEdges
@@ -36,8 +39,10 @@ from .points3d import Points3D
 from .recording_info import RecordingInfo
 from .scalars import Scalars
 from .segmentation_image import SegmentationImage
+from .sensitive import Sensitive
 from .series_lines import SeriesLines
 from .series_points import SeriesPoints
+from .skeleton import Skeleton
 from .tensor import Tensor
 from .text_document import TextDocument
 from .text_log import TextLog
@@ -52,16 +57,19 @@ __all__ = [
     "Arrows3D",
     "Asset3D",
     "AssetVideo",
+    "Audio",
     "BarChart",
     "Boxes2D",
     "Boxes3D",
     "Capsules3D",
     "Clear",
+    "ClockControl",
     "CoordinateFrame",
     "Cylinders3D",
     "DepthImage",
     "Ellipsoids3D",
     "EncodedImage",
+    "GaussianSplats3D",
     "GeoLineStrings",
     "GeoPoints",
     "GraphEdges",
@@ -81,8 +89,10 @@ __all__ = [
     "RecordingInfo",
     "Scalars",
     "SegmentationImage",
+    "Sensitive",
     "SeriesLines",
     "SeriesPoints",
+    "Skeleton",
     "Tensor",
     "TextDocument",
     "TextLog",
@@ -0,0 +1,305 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/archetypes/audio.fbs".
+
+# You can extend this class by creating a "AudioExt" class in "audio_ext.py".
+
+from __future__ import annotations
+
+import numpy as np
+import pyarrow as pa
+from attrs import define, field
+
+from .. import components, datatypes
+from .._baseclasses import (
+    Archetype,
+    ComponentColumnList,
+)
+from ..error_utils import catch_and_log_exceptions
+from .audio_ext import AudioExt
+
+__all__ = ["Audio"]
+
+
+@define(str=False, repr=False, init=False)
+class Audio(AudioExt, Archetype):
+    """
+    **Archetype**: A chunk of audio, either as raw PCM samples or as an encoded audio file.
+
+    The audio starts at the time it is logged at.
+    Longer recordings can be logged as a sequence of chunks, e.g. one per buffer of a microphone.
+
+    The audio view shows the waveform of all chunks on the timeline.
+    While time is playing, the native viewer also plays the audio back, if it was built with the `audio_playback` feature.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+
+    Example
+    -------
+    ### Chunked sine wave:
+    ```python
+    import numpy as np
+    import rerun as rr
+
+    SAMPLE_RATE = 16_000
+    CHUNK_SIZE = 1_600  # 100 ms per chunk
+
+    rr.init("rerun_example_audio_simple", spawn=True)
+
+    for i in range(20):
+        start = i * CHUNK_SIZE
+        rr.set_time("time", duration=start / SAMPLE_RATE)
+
+        # A 440 Hz tone that slowly fades out.
+        t = np.arange(start, start + CHUNK_SIZE) / SAMPLE_RATE
+        samples = (np.sin(2.0 * np.pi * 440.0 * t) * np.exp(-t)).astype(np.float32)
+
+        rr.log("audio", rr.Audio(samples=samples, sample_rate=SAMPLE_RATE))
+    ```
+    """
+
+    # __init__ can be found in audio_ext.py
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            blob=None,
+            sample_rate=None,
+            media_type=None,
+            sample_format=None,
+            channel_count=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> Audio:
+        """Produce an empty Audio, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        blob: datatypes.BlobLike | None = None,
+        sample_rate: datatypes.UInt32Like | None = None,
+        media_type: datatypes.Utf8Like | None = None,
+        sample_format: components.AudioSampleFormatLike | None = None,
+        channel_count: datatypes.UInt32Like | None = None,
+    ) -> Audio:
+        """
+        Update only some specific fields of a `Audio`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        blob:
+            The audio data.
+
+            Either raw PCM samples, described by [`components.AudioSampleFormat`][rerun.components.AudioSampleFormat] and [`components.AudioChannelCount`][rerun.components.AudioChannelCount],
+            or an encoded audio file, see [`components.MediaType`][rerun.components.MediaType].
+        sample_rate:
+            The number of samples per second and channel.
+
+            Required for raw PCM samples, encoded audio files specify their own sample rate.
+        media_type:
+            The Media Type of an encoded audio file.
+
+            Supported values:
+            * `audio/wav` (uncompressed PCM only)
+
+            If omitted, the blob holds raw PCM samples, unless the viewer recognizes an encoded audio file.
+        sample_format:
+            The data type of the raw PCM samples.
+
+            Defaults to 32-bit floats.
+        channel_count:
+            The number of interleaved channels of the raw PCM samples.
+
+            Defaults to a single channel.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "blob": blob,
+                "sample_rate": sample_rate,
+                "media_type": media_type,
+                "sample_format": sample_format,
+                "channel_count": channel_count,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> Audio:
+        """Clear all the fields of a `Audio`."""
+        return cls.from_fields(clear_unset=True)
+
+    @classmethod
+    def columns(
+        cls,
+        *,
+        blob: datatypes.BlobArrayLike | None = None,
+        sample_rate: datatypes.UInt32ArrayLike | None = None,
+        media_type: datatypes.Utf8ArrayLike | None = None,
+        sample_format: components.AudioSampleFormatArrayLike | None = None,
+        channel_count: datatypes.UInt32ArrayLike | None = None,
+    ) -> ComponentColumnList:
+        """
+        Construct a new column-oriented component bundle.
+
+        This makes it possible to use `rr.send_columns` to send columnar data directly into Rerun.
+
+        The returned columns will be partitioned into unit-length sub-batches by default.
+        Use `ComponentColumnList.partition` to repartition the data as needed.
+
+        Parameters
+        ----------
+        blob:
+            The audio data.
+
+            Either raw PCM samples, described by [`components.AudioSampleFormat`][rerun.components.AudioSampleFormat] and [`components.AudioChannelCount`][rerun.components.AudioChannelCount],
+            or an encoded audio file, see [`components.MediaType`][rerun.components.MediaType].
+        sample_rate:
+            The number of samples per second and channel.
+
+            Required for raw PCM samples, encoded audio files specify their own sample rate.
+        media_type:
+            The Media Type of an encoded audio file.
+
+            Supported values:
+            * `audio/wav` (uncompressed PCM only)
+
+            If omitted, the blob holds raw PCM samples, unless the viewer recognizes an encoded audio file.
+        sample_format:
+            The data type of the raw PCM samples.
+
+            Defaults to 32-bit floats.
+        channel_count:
+            The number of interleaved channels of the raw PCM samples.
+
+            Defaults to a single channel.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            inst.__attrs_init__(
+                blob=blob,
+                sample_rate=sample_rate,
+                media_type=media_type,
+                sample_format=sample_format,
+                channel_count=channel_count,
+            )
+
+        batches = inst.as_component_batches()
+        if len(batches) == 0:
+            return ComponentColumnList([])
+
+        kwargs = {
+            "Audio:blob": blob,
+            "Audio:sample_rate": sample_rate,
+            "Audio:media_type": media_type,
+            "Audio:sample_format": sample_format,
+            "Audio:channel_count": channel_count,
+        }
+        columns = []
+
+        for batch in batches:
+            arrow_array = batch.as_arrow_array()
+
+            # For primitive arrays and fixed size list arrays, we infer partition size from the input shape.
+            if pa.types.is_primitive(arrow_array.type) or pa.types.is_fixed_size_list(arrow_array.type):
+                param = kwargs[batch.component_descriptor().component]  # type: ignore[index]
+                shape = np.shape(param)  # type: ignore[arg-type]
+                elem_flat_len = int(np.prod(shape[1:])) if len(shape) > 1 else 1  # type: ignore[redundant-expr,misc]
+
+                if pa.types.is_fixed_size_list(arrow_array.type) and arrow_array.type.list_size == elem_flat_len:
+                    # If the product of the last dimensions of the shape are equal to the size of the fixed size list array,
+                    # we have `num_rows` single element batches (each element is a fixed sized list).
+                    # (This should have been already validated by conversion to the arrow_array)
+                    batch_length = 1
+                else:
+                    batch_length = shape[1] if len(shape) > 1 else 1  # type: ignore[redundant-expr,misc]
+
+                num_rows = shape[0] if len(shape) >= 1 else 1  # type: ignore[redundant-expr,misc]
+                sizes = batch_length * np.ones(num_rows)
+            else:
+                # For non-primitive types, default to partitioning each element separately.
+                sizes = np.ones(len(arrow_array))
+
+            columns.append(batch.partition(sizes))
+
+        return ComponentColumnList(columns)
+
+    blob: components.BlobBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.BlobBatch._converter,  # type: ignore[misc]
+    )
+    # The audio data.
+    #
+    # Either raw PCM samples, described by [`components.AudioSampleFormat`][rerun.components.AudioSampleFormat] and [`components.AudioChannelCount`][rerun.components.AudioChannelCount],
+    # or an encoded audio file, see [`components.MediaType`][rerun.components.MediaType].
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    sample_rate: components.SampleRateBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.SampleRateBatch._converter,  # type: ignore[misc]
+    )
+    # The number of samples per second and channel.
+    #
+    # Required for raw PCM samples, encoded audio files specify their own sample rate.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    media_type: components.MediaTypeBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.MediaTypeBatch._converter,  # type: ignore[misc]
+    )
+    # The Media Type of an encoded audio file.
+    #
+    # Supported values:
+    # * `audio/wav` (uncompressed PCM only)
+    #
+    # If omitted, the blob holds raw PCM samples, unless the viewer recognizes an encoded audio file.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    sample_format: components.AudioSampleFormatBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.AudioSampleFormatBatch._converter,  # type: ignore[misc]
+    )
+    # The data type of the raw PCM samples.
+    #
+    # Defaults to 32-bit floats.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    channel_count: components.AudioChannelCountBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.AudioChannelCountBatch._converter,  # type: ignore[misc]
+    )
+    # The number of interleaved channels of the raw PCM samples.
+    #
+    # Defaults to a single channel.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,115 @@
+from __future__ import annotations
+
+import pathlib
+from typing import TYPE_CHECKING, Any
+
+import numpy as np
+
+from ..error_utils import catch_and_log_exceptions
+
+if TYPE_CHECKING:
+    import numpy.typing as npt
+
+    from .. import datatypes
+
+# Little-endian numpy dtypes for each supported `AudioSampleFormat`.
+_SAMPLE_FORMATS = {
+    np.dtype(np.float32): ("F32", np.dtype("<f4")),
+    np.dtype(np.float64): ("F32", np.dtype("<f4")),
+    np.dtype(np.int16): ("I16", np.dtype("<i2")),
+    np.dtype(np.int32): ("I32", np.dtype("<i4")),
+}
+
+
+class AudioExt:
+    """Extension for [Audio][rerun.archetypes.Audio]."""
+
+    def __init__(
+        self: Any,
+        *,
+        samples: npt.ArrayLike | None = None,
+        sample_rate: datatypes.UInt32Like | None = None,
+        path: str | pathlib.Path | None = None,
+        contents: datatypes.BlobLike | None = None,
+        media_type: datatypes.Utf8Like | None = None,
+    ) -> None:
+        """
+        Create a new instance of the Audio archetype.
+
+        Parameters
+        ----------
+        samples:
+            Raw PCM samples, either with shape `(num_samples,)` for a single channel,
+            or with shape `(num_samples, num_channels)`.
+
+            `float32` samples are expected to be in the range `[-1, 1]`, `float64` samples are converted to `float32`.
+            `int16` and `int32` samples are logged as is.
+            Mutually exclusive with `path` and `contents`.
+
+        sample_rate:
+            The number of samples per second and channel.
+            Required for raw PCM `samples`, encoded audio files specify their own sample rate.
+
+        path:
+            A path to an encoded audio file stored on the local filesystem.
+            Mutually exclusive with `samples` and `contents`.
+
+        contents:
+            The contents of an encoded audio file. Can be a BufferedReader, BytesIO, or bytes.
+            Mutually exclusive with `samples` and `path`.
+
+        media_type:
+            The Media Type of the encoded audio file.
+
+            For instance:
+             * `audio/wav`
+
+            If omitted, it will be guessed from the `path` (if any),
+            or the viewer will try to guess from the contents (magic header).
+
+        """
+
+        from ..components import MediaType
+
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            if sum(arg is not None for arg in (samples, path, contents)) != 1:
+                raise ValueError("Must provide exactly one of 'samples', 'path', or 'contents'")
+
+            if samples is not None:
+                samples = np.asarray(samples)
+                if samples.ndim not in (1, 2):
+                    raise ValueError(
+                        f"Expected samples of shape (num_samples,) or (num_samples, num_channels), got {samples.shape}"
+                    )
+                if samples.dtype not in _SAMPLE_FORMATS:
+                    raise ValueError(
+                        f"Unsupported sample dtype {samples.dtype}, expected float32, float64, int16, or int32"
+                    )
+                if sample_rate is None:
+                    raise ValueError("Raw PCM samples need a 'sample_rate'")
+
+                sample_format, dtype = _SAMPLE_FORMATS[samples.dtype]
+                channel_count = 1 if samples.ndim == 1 else samples.shape[1]
+
+                # Row-major order of `(num_samples, num_channels)` interleaves the channels.
+                blob = np.ascontiguousarray(samples, dtype=dtype).view(np.uint8).reshape(-1)
+
+                self.__attrs_init__(
+                    blob=blob,
+                    sample_rate=sample_rate,
+                    sample_format=sample_format,
+                    channel_count=channel_count,
+                )
+                return
+
+            if path is None:
+                blob = contents
+            else:
+                blob = pathlib.Path(path).read_bytes()
+                if media_type is None:
+                    media_type = MediaType.guess_from_path(path)
+
+            self.__attrs_init__(blob=blob, sample_rate=sample_rate, media_type=media_type)
+            return
+
+        self.__attrs_clear__()
@@ -0,0 +1,205 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/archetypes/clock_control.fbs".
+
+# You can extend this class by creating a "ClockControlExt" class in "clock_control_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+import numpy as np
+import pyarrow as pa
+from attrs import define, field
+
+from .. import components, datatypes
+from .._baseclasses import (
+    Archetype,
+    ComponentColumnList,
+)
+from ..error_utils import catch_and_log_exceptions
+
+__all__ = ["ClockControl"]
+
+
+@define(str=False, repr=False, init=False)
+class ClockControl(Archetype):
+    """
+    **Archetype**: Makes the time cursor of the viewer follow the logging SDK, e.g. to let a simulator drive playback.
+
+    Whenever a `ClockControl` comes in, the viewer pauses its own playback, switches to the given
+    timeline, and moves the time cursor to the time the `ClockControl` was logged at on that timeline.
+    The cursor follows `ClockControl`s in the order they come in rather than in time order.
+    A simulation can thus pause by not logging any, step by logging one at the next time, and
+    jump by logging one at any other time, including back in time after a reset.
+
+    The entity path a `ClockControl` is logged to doesn't matter.
+    The time cursor can still be moved by hand until the next `ClockControl` comes in.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+
+    Example
+    -------
+    ### Driving the time cursor from a simulation:
+    ```python
+    import rerun as rr
+
+    rr.init("rerun_example_clock_control", spawn=True)
+
+    position = 0.0
+    for step in range(100):
+        position += 0.01
+
+        rr.set_time("sim_time", duration=step * 0.01)
+        rr.log("ball", rr.Points3D([[position, 0.0, 0.0]]))
+
+        # Move the time cursor of the viewer to the current simulation time.
+        rr.log("sim/clock", rr.ClockControl("sim_time"))
+
+    # Jump back to the start, e.g. after a reset of the simulation.
+    rr.set_time("sim_time", duration=0.0)
+    rr.log("sim/clock", rr.ClockControl("sim_time"))
+    ```
+
+    """
+
+    def __init__(self: Any, timeline: datatypes.Utf8Like) -> None:
+        """
+        Create a new instance of the ClockControl archetype.
+
+        Parameters
+        ----------
+        timeline:
+            Name of the timeline whose time cursor is controlled.
+
+        """
+
+        # You can define your own __init__ function as a member of ClockControlExt in clock_control_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(timeline=timeline)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            timeline=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> ClockControl:
+        """Produce an empty ClockControl, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        timeline: datatypes.Utf8Like | None = None,
+    ) -> ClockControl:
+        """
+        Update only some specific fields of a `ClockControl`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        timeline:
+            Name of the timeline whose time cursor is controlled.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "timeline": timeline,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> ClockControl:
+        """Clear all the fields of a `ClockControl`."""
+        return cls.from_fields(clear_unset=True)
+
+    @classmethod
+    def columns(
+        cls,
+        *,
+        timeline: datatypes.Utf8ArrayLike | None = None,
+    ) -> ComponentColumnList:
+        """
+        Construct a new column-oriented component bundle.
+
+        This makes it possible to use `rr.send_columns` to send columnar data directly into Rerun.
+
+        The returned columns will be partitioned into unit-length sub-batches by default.
+        Use `ComponentColumnList.partition` to repartition the data as needed.
+
+        Parameters
+        ----------
+        timeline:
+            Name of the timeline whose time cursor is controlled.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            inst.__attrs_init__(
+                timeline=timeline,
+            )
+
+        batches = inst.as_component_batches()
+        if len(batches) == 0:
+            return ComponentColumnList([])
+
+        kwargs = {"ClockControl:timeline": timeline}
+        columns = []
+
+        for batch in batches:
+            arrow_array = batch.as_arrow_array()
+
+            # For primitive arrays and fixed size list arrays, we infer partition size from the input shape.
+            if pa.types.is_primitive(arrow_array.type) or pa.types.is_fixed_size_list(arrow_array.type):
+                param = kwargs[batch.component_descriptor().component]  # type: ignore[index]
+                shape = np.shape(param)  # type: ignore[arg-type]
+                elem_flat_len = int(np.prod(shape[1:])) if len(shape) > 1 else 1  # type: ignore[redundant-expr,misc]
+
+                if pa.types.is_fixed_size_list(arrow_array.type) and arrow_array.type.list_size == elem_flat_len:
+                    # If the product of the last dimensions of the shape are equal to the size of the fixed size list array,
+                    # we have `num_rows` single element batches (each element is a fixed sized list).
+                    # (This should have been already validated by conversion to the arrow_array)
+                    batch_length = 1
+                else:
+                    batch_length = shape[1] if len(shape) > 1 else 1  # type: ignore[redundant-expr,misc]
+
+                num_rows = shape[0] if len(shape) >= 1 else 1  # type: ignore[redundant-expr,misc]
+                sizes = batch_length * np.ones(num_rows)
+            else:
+                # For non-primitive types, default to partitioning each element separately.
+                sizes = np.ones(len(arrow_array))
+
+            columns.append(batch.partition(sizes))
+
+        return ComponentColumnList(columns)
+
+    timeline: components.NameBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.NameBatch._converter,  # type: ignore[misc]
+    )
+    # Name of the timeline whose time cursor is controlled.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,414 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/archetypes/gaussian_splats3d.fbs".
+
+# You can extend this class by creating a "GaussianSplats3DExt" class in "gaussian_splats3d_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+import numpy as np
+import pyarrow as pa
+from attrs import define, field
+
+from .. import components, datatypes
+from .._baseclasses import (
+    Archetype,
+    ComponentColumnList,
+)
+from ..error_utils import catch_and_log_exceptions
+
+__all__ = ["GaussianSplats3D"]
+
+
+@define(str=False, repr=False, init=False)
+class GaussianSplats3D(Archetype):
+    """
+    **Archetype**: A 3D Gaussian splatting scene.
+
+    Each splat is an anisotropic 3D gaussian, defined by its center, its orientation and its scale,
+    i.e. the standard deviation along each of its local axes.
+    Together, orientation and scale describe the covariance of the gaussian: `Σ = R S Sᵀ Rᵀ`.
+    This is the same parameterization that is used by most Gaussian splatting reconstructions.
+
+    Splats are alpha blended in back to front order.
+    The color of a splat is given by the zeroth degree of its spherical harmonics `f_dc` as `0.5 + 0.2820948 * f_dc`.
+    Higher degrees, as produced by most reconstructions, are given by [`components.SphericalHarmonics`][rerun.components.SphericalHarmonics]
+    and make the color depend on the direction the splat is viewed from.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+
+    Example
+    -------
+    ### Simple Gaussian splats:
+    ```python
+    import numpy as np
+    import rerun as rr
+
+    rr.init("rerun_example_gaussian_splats3d_simple", spawn=True)
+
+    angles = np.linspace(0, 2 * np.pi, 32, endpoint=False)
+    centers = np.stack([np.cos(angles), np.sin(angles), np.zeros_like(angles)], axis=1)
+
+    # Rotate each splat around the z axis so that it is tangent to the ring.
+    quaternions = np.stack(
+        [np.zeros_like(angles), np.zeros_like(angles), np.sin(angles / 2), np.cos(angles / 2)],
+                axis=1,
+    )
+
+    rr.log(
+        "splats",
+        rr.GaussianSplats3D(
+            centers=centers,
+            quaternions=quaternions,
+            scales=[0.02, 0.1, 0.02],
+            colors=[[255, 128, 0], [0, 128, 255]] * 16,
+            opacities=0.8,
+        ),
+    )
+    ```
+    """
+
+    def __init__(
+        self: Any,
+        centers: datatypes.Vec3DArrayLike,
+        *,
+        quaternions: datatypes.QuaternionArrayLike | None = None,
+        scales: datatypes.Vec3DArrayLike | None = None,
+        colors: datatypes.Rgba32ArrayLike | None = None,
+        opacities: datatypes.Float32ArrayLike | None = None,
+        spherical_harmonics: components.SphericalHarmonicsArrayLike | None = None,
+        class_ids: datatypes.ClassIdArrayLike | None = None,
+    ) -> None:
+        """
+        Create a new instance of the GaussianSplats3D archetype.
+
+        Parameters
+        ----------
+        centers:
+            The center of each splat.
+        quaternions:
+            The orientation of each splat.
+
+            If no orientation is specified, the axes of the splats align with the axes of the local coordinate system.
+        scales:
+            The standard deviation of each splat along its three local axes.
+
+            If not specified, splats are isotropic with a standard deviation of 1% of the size of the bounding box of all centers.
+        colors:
+            The color of each splat.
+
+            The alpha channel is multiplied with [`components.Opacity`][rerun.components.Opacity].
+        opacities:
+            The opacity at the center of each splat.
+
+            Defaults to fully opaque.
+        spherical_harmonics:
+            The view dependent part of the color of each splat.
+
+            All splats are expected to have the same number of coefficients.
+            If not specified, splats look the same from all directions.
+        class_ids:
+            Optional class IDs for the splats.
+
+            The class ID provides colors if not specified explicitly.
+
+        """
+
+        # You can define your own __init__ function as a member of GaussianSplats3DExt in gaussian_splats3d_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(
+                centers=centers,
+                quaternions=quaternions,
+                scales=scales,
+                colors=colors,
+                opacities=opacities,
+                spherical_harmonics=spherical_harmonics,
+                class_ids=class_ids,
+            )
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            centers=None,
+            quaternions=None,
+            scales=None,
+            colors=None,
+            opacities=None,
+            spherical_harmonics=None,
+            class_ids=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> GaussianSplats3D:
+        """Produce an empty GaussianSplats3D, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        centers: datatypes.Vec3DArrayLike | None = None,
+        quaternions: datatypes.QuaternionArrayLike | None = None,
+        scales: datatypes.Vec3DArrayLike | None = None,
+        colors: datatypes.Rgba32ArrayLike | None = None,
+        opacities: datatypes.Float32ArrayLike | None = None,
+        spherical_harmonics: components.SphericalHarmonicsArrayLike | None = None,
+        class_ids: datatypes.ClassIdArrayLike | None = None,
+    ) -> GaussianSplats3D:
+        """
+        Update only some specific fields of a `GaussianSplats3D`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        centers:
+            The center of each splat.
+        quaternions:
+            The orientation of each splat.
+
+            If no orientation is specified, the axes of the splats align with the axes of the local coordinate system.
+        scales:
+            The standard deviation of each splat along its three local axes.
+
+            If not specified, splats are isotropic with a standard deviation of 1% of the size of the bounding box of all centers.
+        colors:
+            The color of each splat.
+
+            The alpha channel is multiplied with [`components.Opacity`][rerun.components.Opacity].
+        opacities:
+            The opacity at the center of each splat.
+
+            Defaults to fully opaque.
+        spherical_harmonics:
+            The view dependent part of the color of each splat.
+
+            All splats are expected to have the same number of coefficients.
+            If not specified, splats look the same from all directions.
+        class_ids:
+            Optional class IDs for the splats.
+
+            The class ID provides colors if not specified explicitly.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "centers": centers,
+                "quaternions": quaternions,
+                "scales": scales,
+                "colors": colors,
+                "opacities": opacities,
+                "spherical_harmonics": spherical_harmonics,
+                "class_ids": class_ids,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> GaussianSplats3D:
+        """Clear all the fields of a `GaussianSplats3D`."""
+        return cls.from_fields(clear_unset=True)
+
+    @classmethod
+    def columns(
+        cls,
+        *,
+        centers: datatypes.Vec3DArrayLike | None = None,
+        quaternions: datatypes.QuaternionArrayLike | None = None,
+        scales: datatypes.Vec3DArrayLike | None = None,
+        colors: datatypes.Rgba32ArrayLike | None = None,
+        opacities: datatypes.Float32ArrayLike | None = None,
+        spherical_harmonics: components.SphericalHarmonicsArrayLike | None = None,
+        class_ids: datatypes.ClassIdArrayLike | None = None,
+    ) -> ComponentColumnList:
+        """
+        Construct a new column-oriented component bundle.
+
+        This makes it possible to use `rr.send_columns` to send columnar data directly into Rerun.
+
+        The returned columns will be partitioned into unit-length sub-batches by default.
+        Use `ComponentColumnList.partition` to repartition the data as needed.
+
+        Parameters
+        ----------
+        centers:
+            The center of each splat.
+        quaternions:
+            The orientation of each splat.
+
+            If no orientation is specified, the axes of the splats align with the axes of the local coordinate system.
+        scales:
+            The standard deviation of each splat along its three local axes.
+
+            If not specified, splats are isotropic with a standard deviation of 1% of the size of the bounding box of all centers.
+        colors:
+            The color of each splat.
+
+            The alpha channel is multiplied with [`components.Opacity`][rerun.components.Opacity].
+        opacities:
+            The opacity at the center of each splat.
+
+            Defaults to fully opaque.
+        spherical_harmonics:
+            The view dependent part of the color of each splat.
+
+            All splats are expected to have the same number of coefficients.
+            If not specified, splats look the same from all directions.
+        class_ids:
+            Optional class IDs for the splats.
+
+            The class ID provides colors if not specified explicitly.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            inst.__attrs_init__(
+                centers=centers,
+                quaternions=quaternions,
+                scales=scales,
+                colors=colors,
+                opacities=opacities,
+                spherical_harmonics=spherical_harmonics,
+                class_ids=class_ids,
+            )
+
+        batches = inst.as_component_batches()
+        if len(batches) == 0:
+            return ComponentColumnList([])
+
+        kwargs = {
+            "GaussianSplats3D:centers": centers,
+            "GaussianSplats3D:quaternions": quaternions,
+            "GaussianSplats3D:scales": scales,
+            "GaussianSplats3D:colors": colors,
+            "GaussianSplats3D:opacities": opacities,
+            "GaussianSplats3D:spherical_harmonics": spherical_harmonics,
+            "GaussianSplats3D:class_ids": class_ids,
+        }
+        columns = []
+
+        for batch in batches:
+            arrow_array = batch.as_arrow_array()
+
+            # For primitive arrays and fixed size list arrays, we infer partition size from the input shape.
+            if pa.types.is_primitive(arrow_array.type) or pa.types.is_fixed_size_list(arrow_array.type):
+                param = kwargs[batch.component_descriptor().component]  # type: ignore[index]
+                shape = np.shape(param)  # type: ignore[arg-type]
+                elem_flat_len = int(np.prod(shape[1:])) if len(shape) > 1 else 1  # type: ignore[redundant-expr,misc]
+
+                if pa.types.is_fixed_size_list(arrow_array.type) and arrow_array.type.list_size == elem_flat_len:
+                    # If the product of the last dimensions of the shape are equal to the size of the fixed size list array,
+                    # we have `num_rows` single element batches (each element is a fixed sized list).
+                    # (This should have been already validated by conversion to the arrow_array)
+                    batch_length = 1
+                else:
+                    batch_length = shape[1] if len(shape) > 1 else 1  # type: ignore[redundant-expr,misc]
+
+                num_rows = shape[0] if len(shape) >= 1 else 1  # type: ignore[redundant-expr,misc]
+                sizes = batch_length * np.ones(num_rows)
+            else:
+                # For non-primitive types, default to partitioning each element separately.
+                sizes = np.ones(len(arrow_array))
+
+            columns.append(batch.partition(sizes))
+
+        return ComponentColumnList(columns)
+
+    centers: components.Position3DBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.Position3DBatch._converter,  # type: ignore[misc]
+    )
+    # The center of each splat.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    quaternions: components.PoseRotationQuatBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.PoseRotationQuatBatch._converter,  # type: ignore[misc]
+    )
+    # The orientation of each splat.
+    #
+    # If no orientation is specified, the axes of the splats align with the axes of the local coordinate system.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    scales: components.PoseScale3DBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.PoseScale3DBatch._converter,  # type: ignore[misc]
+    )
+    # The standard deviation of each splat along its three local axes.
+    #
+    # If not specified, splats are isotropic with a standard deviation of 1% of the size of the bounding box of all centers.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    colors: components.ColorBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ColorBatch._converter,  # type: ignore[misc]
+    )
+    # The color of each splat.
+    #
+    # The alpha channel is multiplied with [`components.Opacity`][rerun.components.Opacity].
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    opacities: components.OpacityBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.OpacityBatch._converter,  # type: ignore[misc]
+    )
+    # The opacity at the center of each splat.
+    #
+    # Defaults to fully opaque.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    spherical_harmonics: components.SphericalHarmonicsBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.SphericalHarmonicsBatch._converter,  # type: ignore[misc]
+    )
+    # The view dependent part of the color of each splat.
+    #
+    # All splats are expected to have the same number of coefficients.
+    # If not specified, splats look the same from all directions.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    class_ids: components.ClassIdBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ClassIdBatch._converter,  # type: ignore[misc]
+    )
+    # Optional class IDs for the splats.
+    #
+    # The class ID provides colors if not specified explicitly.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
+
+
+
+
@@ -91,7 +91,7 @@ class Scalars(Archetype):
 
     """
 
-    def __init__(self: Any, scalars: datatypes.Float64ArrayLike) -> None:
+    def __init__(self: Any, scalars: datatypes.Float64ArrayLike, *, unit: datatypes.Utf8Like | None = None) -> None:
         """
         Create a new instance of the Scalars archetype.
 
@@ -99,12 +99,16 @@ class Scalars(Archetype):
         ----------
         scalars:
             The scalar values to log.
+        unit:
+            The unit the scalar values are logged in, e.g. `m/s` or `rad`.
+
+            Shown on the axes and tooltips of plots. Expected to be unchanging over time.
 
         """
 
         # You can define your own __init__ function as a member of ScalarsExt in scalars_ext.py
         with catch_and_log_exceptions(context=self.__class__.__name__):
-            self.__attrs_init__(scalars=scalars)
+            self.__attrs_init__(scalars=scalars, unit=unit)
             return
         self.__attrs_clear__()
 
@@ -112,6 +116,7 @@ class Scalars(Archetype):
         """Convenience method for calling `__attrs_init__` with all `None`s."""
         self.__attrs_init__(
             scalars=None,
+            unit=None,
         )
 
     @classmethod
@@ -127,6 +132,7 @@ class Scalars(Archetype):
         *,
         clear_unset: bool = False,
         scalars: datatypes.Float64ArrayLike | None = None,
+        unit: datatypes.Utf8Like | None = None,
     ) -> Scalars:
         """
         Update only some specific fields of a `Scalars`.
@@ -137,6 +143,10 @@ class Scalars(Archetype):
             If true, all unspecified fields will be explicitly cleared.
         scalars:
             The scalar values to log.
+        unit:
+            The unit the scalar values are logged in, e.g. `m/s` or `rad`.
+
+            Shown on the axes and tooltips of plots. Expected to be unchanging over time.
 
         """
 
@@ -144,6 +154,7 @@ class Scalars(Archetype):
         with catch_and_log_exceptions(context=cls.__name__):
             kwargs = {
                 "scalars": scalars,
+                "unit": unit,
             }
 
             if clear_unset:
@@ -165,6 +176,7 @@ class Scalars(Archetype):
         cls,
         *,
         scalars: datatypes.Float64ArrayLike | None = None,
+        unit: datatypes.Utf8ArrayLike | None = None,
     ) -> ComponentColumnList:
         """
         Construct a new column-oriented component bundle.
@@ -178,6 +190,10 @@ class Scalars(Archetype):
         ----------
         scalars:
             The scalar values to log.
+        unit:
+            The unit the scalar values are logged in, e.g. `m/s` or `rad`.
+
+            Shown on the axes and tooltips of plots. Expected to be unchanging over time.
 
         """
 
@@ -185,13 +201,14 @@ class Scalars(Archetype):
         with catch_and_log_exceptions(context=cls.__name__):
             inst.__attrs_init__(
                 scalars=scalars,
+                unit=unit,
             )
 
         batches = inst.as_component_batches()
         if len(batches) == 0:
             return ComponentColumnList([])
 
-        kwargs = {"Scalars:scalars": scalars}
+        kwargs = {"Scalars:scalars": scalars, "Scalars:unit": unit}
         columns = []
 
         for batch in batches:
@@ -230,5 +247,16 @@ class Scalars(Archetype):
     #
     # (Docstring intentionally commented out to hide this field from the docs)
 
+    unit: components.UnitBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.UnitBatch._converter,  # type: ignore[misc]
+    )
+    # The unit the scalar values are logged in, e.g. `m/s` or `rad`.
+    #
+    # Shown on the axes and tooltips of plots. Expected to be unchanging over time.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
     __str__ = Archetype.__str__
     __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,206 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/archetypes/sensitive.fbs".
+
+# You can extend this class by creating a "SensitiveExt" class in "sensitive_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+import numpy as np
+import pyarrow as pa
+from attrs import define, field
+
+from .. import components, datatypes
+from .._baseclasses import (
+    Archetype,
+    ComponentColumnList,
+)
+from ..error_utils import catch_and_log_exceptions
+
+__all__ = ["Sensitive"]
+
+
+@define(str=False, repr=False, init=False)
+class Sensitive(Archetype):
+    """
+    **Archetype**: Tags an entity, and everything below it, as holding sensitive data, e.g. camera images showing faces.
+
+    Tagged entities can be redacted or left out when sharing data, see `rerun rrd filter --sensitive`
+    and `rerun rrd export --sensitive`.
+    The tag applies to all data of the entity, no matter when it was logged, so it's best logged as static.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+
+    Example
+    -------
+    ### Tagging camera images as sensitive:
+    ```python
+    import numpy as np
+    import rerun as rr
+
+    rr.init("rerun_example_sensitive", spawn=True)
+
+    # Everything logged to `camera` and below is tagged, no matter when it's logged.
+    rr.log("camera", rr.Sensitive(["faces"]), static=True)
+
+    for frame in range(10):
+        rr.set_time("frame", sequence=frame)
+        image = np.full((48, 64, 3), frame * 25, dtype=np.uint8)
+        rr.log("camera/image", rr.Image(image))
+
+    # Share the recording without the faces, e.g. with:
+    # `rerun rrd filter recording.rrd --sensitive redact -o shareable.rrd`
+    ```
+
+    """
+
+    def __init__(self: Any, tags: datatypes.Utf8ArrayLike) -> None:
+        """
+        Create a new instance of the Sensitive archetype.
+
+        Parameters
+        ----------
+        tags:
+            What makes the data sensitive, e.g. `faces` or `license_plates`.
+
+            Lets exports redact some kinds of sensitive data but not others.
+
+        """
+
+        # You can define your own __init__ function as a member of SensitiveExt in sensitive_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(tags=tags)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            tags=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> Sensitive:
+        """Produce an empty Sensitive, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        tags: datatypes.Utf8ArrayLike | None = None,
+    ) -> Sensitive:
+        """
+        Update only some specific fields of a `Sensitive`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        tags:
+            What makes the data sensitive, e.g. `faces` or `license_plates`.
+
+            Lets exports redact some kinds of sensitive data but not others.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "tags": tags,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> Sensitive:
+        """Clear all the fields of a `Sensitive`."""
+        return cls.from_fields(clear_unset=True)
+
+    @classmethod
+    def columns(
+        cls,
+        *,
+        tags: datatypes.Utf8ArrayLike | None = None,
+    ) -> ComponentColumnList:
+        """
+        Construct a new column-oriented component bundle.
+
+        This makes it possible to use `rr.send_columns` to send columnar data directly into Rerun.
+
+        The returned columns will be partitioned into unit-length sub-batches by default.
+        Use `ComponentColumnList.partition` to repartition the data as needed.
+
+        Parameters
+        ----------
+        tags:
+            What makes the data sensitive, e.g. `faces` or `license_plates`.
+
+            Lets exports redact some kinds of sensitive data but not others.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            inst.__attrs_init__(
+                tags=tags,
+            )
+
+        batches = inst.as_component_batches()
+        if len(batches) == 0:
+            return ComponentColumnList([])
+
+        kwargs = {"Sensitive:tags": tags}
+        columns = []
+
+        for batch in batches:
+            arrow_array = batch.as_arrow_array()
+
+            # For primitive arrays and fixed size list arrays, we infer partition size from the input shape.
+            if pa.types.is_primitive(arrow_array.type) or pa.types.is_fixed_size_list(arrow_array.type):
+                param = kwargs[batch.component_descriptor().component]  # type: ignore[index]
+                shape = np.shape(param)  # type: ignore[arg-type]
+                elem_flat_len = int(np.prod(shape[1:])) if len(shape) > 1 else 1  # type: ignore[redundant-expr,misc]
+
+                if pa.types.is_fixed_size_list(arrow_array.type) and arrow_array.type.list_size == elem_flat_len:
+                    # If the product of the last dimensions of the shape are equal to the size of the fixed size list array,
+                    # we have `num_rows` single element batches (each element is a fixed sized list).
+                    # (This should have been already validated by conversion to the arrow_array)
+                    batch_length = 1
+                else:
+                    batch_length = shape[1] if len(shape) > 1 else 1  # type: ignore[redundant-expr,misc]
+
+                num_rows = shape[0] if len(shape) >= 1 else 1  # type: ignore[redundant-expr,misc]
+                sizes = batch_length * np.ones(num_rows)
+            else:
+                # For non-primitive types, default to partitioning each element separately.
+                sizes = np.ones(len(arrow_array))
+
+            columns.append(batch.partition(sizes))
+
+        return ComponentColumnList(columns)
+
+    tags: components.TextBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.TextBatch._converter,  # type: ignore[misc]
+    )
+    # What makes the data sensitive, e.g. `faces` or `license_plates`.
+    #
+    # Lets exports redact some kinds of sensitive data but not others.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
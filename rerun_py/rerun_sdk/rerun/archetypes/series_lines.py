@@ -76,6 +76,7 @@ class SeriesLines(Archetype):
         names: datatypes.Utf8ArrayLike | None = None,
         visible_series: datatypes.BoolArrayLike | None = None,
         aggregation_policy: components.AggregationPolicyLike | None = None,
+        display_unit: datatypes.Utf8Like | None = None,
     ) -> None:
         """
         Create a new instance of the SeriesLines archetype.
@@ -109,6 +110,13 @@ class SeriesLines(Archetype):
             i.e. a single pixel covers more than one tick worth of data. It can greatly improve performance
             (and readability) in such situations as it prevents overdraw.
 
+            Expected to be unchanging over time.
+        display_unit:
+            The unit the series are shown in, e.g. `deg` or `km/h`.
+
+            Values are converted from the unit of the [`archetypes.Scalars`][rerun.archetypes.Scalars] if both are known units of the same quantity.
+            If not set, values are shown in the unit they were logged in.
+
             Expected to be unchanging over time.
 
         """
@@ -121,6 +129,7 @@ class SeriesLines(Archetype):
                 names=names,
                 visible_series=visible_series,
                 aggregation_policy=aggregation_policy,
+                display_unit=display_unit,
             )
             return
         self.__attrs_clear__()
@@ -133,6 +142,7 @@ class SeriesLines(Archetype):
             names=None,
             visible_series=None,
             aggregation_policy=None,
+            display_unit=None,
         )
 
     @classmethod
@@ -152,6 +162,7 @@ class SeriesLines(Archetype):
         names: datatypes.Utf8ArrayLike | None = None,
         visible_series: datatypes.BoolArrayLike | None = None,
         aggregation_policy: components.AggregationPolicyLike | None = None,
+        display_unit: datatypes.Utf8Like | None = None,
     ) -> SeriesLines:
         """
         Update only some specific fields of a `SeriesLines`.
@@ -187,6 +198,13 @@ class SeriesLines(Archetype):
             i.e. a single pixel covers more than one tick worth of data. It can greatly improve performance
             (and readability) in such situations as it prevents overdraw.
 
+            Expected to be unchanging over time.
+        display_unit:
+            The unit the series are shown in, e.g. `deg` or `km/h`.
+
+            Values are converted from the unit of the [`archetypes.Scalars`][rerun.archetypes.Scalars] if both are known units of the same quantity.
+            If not set, values are shown in the unit they were logged in.
+
             Expected to be unchanging over time.
 
         """
@@ -199,6 +217,7 @@ class SeriesLines(Archetype):
                 "names": names,
                 "visible_series": visible_series,
                 "aggregation_policy": aggregation_policy,
+                "display_unit": display_unit,
             }
 
             if clear_unset:
@@ -224,6 +243,7 @@ class SeriesLines(Archetype):
         names: datatypes.Utf8ArrayLike | None = None,
         visible_series: datatypes.BoolArrayLike | None = None,
         aggregation_policy: components.AggregationPolicyArrayLike | None = None,
+        display_unit: datatypes.Utf8ArrayLike | None = None,
     ) -> ComponentColumnList:
         """
         Construct a new column-oriented component bundle.
@@ -262,6 +282,13 @@ class SeriesLines(Archetype):
             i.e. a single pixel covers more than one tick worth of data. It can greatly improve performance
             (and readability) in such situations as it prevents overdraw.
 
+            Expected to be unchanging over time.
+        display_unit:
+            The unit the series are shown in, e.g. `deg` or `km/h`.
+
+            Values are converted from the unit of the [`archetypes.Scalars`][rerun.archetypes.Scalars] if both are known units of the same quantity.
+            If not set, values are shown in the unit they were logged in.
+
             Expected to be unchanging over time.
 
         """
@@ -274,6 +301,7 @@ class SeriesLines(Archetype):
                 names=names,
                 visible_series=visible_series,
                 aggregation_policy=aggregation_policy,
+                display_unit=display_unit,
             )
 
         batches = inst.as_component_batches()
@@ -286,6 +314,7 @@ class SeriesLines(Archetype):
             "SeriesLines:names": names,
             "SeriesLines:visible_series": visible_series,
             "SeriesLines:aggregation_policy": aggregation_policy,
+            "SeriesLines:display_unit": display_unit,
         }
         columns = []
 
@@ -379,5 +408,19 @@ class SeriesLines(Archetype):
     #
     # (Docstring intentionally commented out to hide this field from the docs)
 
+    display_unit: components.UnitBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.UnitBatch._converter,  # type: ignore[misc]
+    )
+    # The unit the series are shown in, e.g. `deg` or `km/h`.
+    #
+    # Values are converted from the unit of the [`archetypes.Scalars`][rerun.archetypes.Scalars] if both are known units of the same quantity.
+    # If not set, values are shown in the unit they were logged in.
+    #
+    # Expected to be unchanging over time.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
     __str__ = Archetype.__str__
     __repr__ = Archetype.__repr__  # type: ignore[assignment]
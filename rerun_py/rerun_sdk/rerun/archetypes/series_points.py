@@ -96,6 +96,7 @@ class SeriesPoints(SeriesPointsExt, Archetype):
             names=None,
             visible_series=None,
             marker_sizes=None,
+            display_unit=None,
         )
 
     @classmethod
@@ -115,6 +116,7 @@ class SeriesPoints(SeriesPointsExt, Archetype):
         names: datatypes.Utf8ArrayLike | None = None,
         visible_series: datatypes.BoolArrayLike | None = None,
         marker_sizes: datatypes.Float32ArrayLike | None = None,
+        display_unit: datatypes.Utf8Like | None = None,
     ) -> SeriesPoints:
         """
         Update only some specific fields of a `SeriesPoints`.
@@ -147,6 +149,13 @@ class SeriesPoints(SeriesPointsExt, Archetype):
             Sizes of the markers.
 
             May change over time.
+        display_unit:
+            The unit the series are shown in, e.g. `deg` or `km/h`.
+
+            Values are converted from the unit of the [`archetypes.Scalars`][rerun.archetypes.Scalars] if both are known units of the same quantity.
+            If not set, values are shown in the unit they were logged in.
+
+            Expected to be unchanging over time.
 
         """
 
@@ -158,6 +167,7 @@ class SeriesPoints(SeriesPointsExt, Archetype):
                 "names": names,
                 "visible_series": visible_series,
                 "marker_sizes": marker_sizes,
+                "display_unit": display_unit,
             }
 
             if clear_unset:
@@ -183,6 +193,7 @@ class SeriesPoints(SeriesPointsExt, Archetype):
         names: datatypes.Utf8ArrayLike | None = None,
         visible_series: datatypes.BoolArrayLike | None = None,
         marker_sizes: datatypes.Float32ArrayLike | None = None,
+        display_unit: datatypes.Utf8ArrayLike | None = None,
     ) -> ComponentColumnList:
         """
         Construct a new column-oriented component bundle.
@@ -218,6 +229,13 @@ class SeriesPoints(SeriesPointsExt, Archetype):
             Sizes of the markers.
 
             May change over time.
+        display_unit:
+            The unit the series are shown in, e.g. `deg` or `km/h`.
+
+            Values are converted from the unit of the [`archetypes.Scalars`][rerun.archetypes.Scalars] if both are known units of the same quantity.
+            If not set, values are shown in the unit they were logged in.
+
+            Expected to be unchanging over time.
 
         """
 
@@ -229,6 +247,7 @@ class SeriesPoints(SeriesPointsExt, Archetype):
                 names=names,
                 visible_series=visible_series,
                 marker_sizes=marker_sizes,
+                display_unit=display_unit,
             )
 
         batches = inst.as_component_batches()
@@ -241,6 +260,7 @@ class SeriesPoints(SeriesPointsExt, Archetype):
             "SeriesPoints:names": names,
             "SeriesPoints:visible_series": visible_series,
             "SeriesPoints:marker_sizes": marker_sizes,
+            "SeriesPoints:display_unit": display_unit,
         }
         columns = []
 
@@ -330,5 +350,19 @@ class SeriesPoints(SeriesPointsExt, Archetype):
     #
     # (Docstring intentionally commented out to hide this field from the docs)
 
+    display_unit: components.UnitBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.UnitBatch._converter,  # type: ignore[misc]
+    )
+    # The unit the series are shown in, e.g. `deg` or `km/h`.
+    #
+    # Values are converted from the unit of the [`archetypes.Scalars`][rerun.archetypes.Scalars] if both are known units of the same quantity.
+    # If not set, values are shown in the unit they were logged in.
+    #
+    # Expected to be unchanging over time.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
     __str__ = Archetype.__str__
     __repr__ = Archetype.__repr__  # type: ignore[assignment]
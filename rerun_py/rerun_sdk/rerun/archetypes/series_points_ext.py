@@ -17,6 +17,7 @@ class SeriesPointsExt:
         names: datatypes.Utf8ArrayLike | None = None,
         visible_series: datatypes.BoolArrayLike | None = None,
         marker_sizes: datatypes.Float32ArrayLike | None = None,
+        display_unit: datatypes.Utf8Like | None = None,
     ) -> None:
         """
         Create a new instance of the SeriesPoints archetype.
@@ -49,12 +50,19 @@ class SeriesPointsExt:
             May change over time.
 
             If no other components are set, a default `MarkerShape.Circle` will be logged.
+        display_unit:
+            The unit the series are shown in, e.g. `deg` or `km/h`.
+
+            Values are converted from the unit of the [`archetypes.Scalars`][rerun.archetypes.Scalars] if both are known units of the same quantity.
+            If not set, values are shown in the unit they were logged in.
+
+            Expected to be unchanging over time.
 
         """
 
         # You can define your own __init__ function as a member of SeriesPointsExt in series_points_ext.py
         with catch_and_log_exceptions(context=self.__class__.__name__):
-            if all(arg is None for arg in [colors, markers, names, visible_series, marker_sizes]):
+            if all(arg is None for arg in [colors, markers, names, visible_series, marker_sizes, display_unit]):
                 # TODO(#10512): Back when we had indicators, we did'nt need to specify any additional components
                 # when logging a `SeriesPoints`. Now that we don't have indicators anymore, we need to have at
                 # least one component set in `SeriesPoints`, otherwise nothing would get logged and visualizers
@@ -62,7 +70,12 @@ class SeriesPointsExt:
                 markers = components.MarkerShape.Circle
 
             self.__attrs_init__(
-                colors=colors, markers=markers, names=names, visible_series=visible_series, marker_sizes=marker_sizes
+                colors=colors,
+                markers=markers,
+                names=names,
+                visible_series=visible_series,
+                marker_sizes=marker_sizes,
+                display_unit=display_unit,
             )
             return
         self.__attrs_clear__()
@@ -0,0 +1,483 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/archetypes/skeleton.fbs".
+
+# You can extend this class by creating a "SkeletonExt" class in "skeleton_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+import numpy as np
+import pyarrow as pa
+from attrs import define, field
+
+from .. import components, datatypes
+from .._baseclasses import (
+    Archetype,
+    ComponentColumnList,
+)
+from ..error_utils import catch_and_log_exceptions
+
+__all__ = ["Skeleton"]
+
+
+@define(str=False, repr=False, init=False)
+class Skeleton(Archetype):
+    """
+    **Archetype**: A hierarchy of joints connected by bones, e.g. a human skeleton or the kinematic chain of a robot.
+
+    Each joint is placed relative to its parent joint, or relative to the entity's origin for root joints.
+    The hierarchy is typically logged once as static data, whereas the joint transforms change over time.
+
+    Joints are drawn as points and bones as lines between each joint and its parent.
+    If [`components.AxisLength`][rerun.components.AxisLength] is set, the coordinate frame of every joint is drawn as well.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+
+    Example
+    -------
+    ### Simple kinematic chain:
+    ```python
+    import math
+
+    import rerun as rr
+
+    rr.init("rerun_example_skeleton_simple", spawn=True)
+
+    # The hierarchy doesn't change, so it only needs to be logged once.
+    rr.log(
+        "arm",
+        rr.Skeleton.from_fields(
+            parents=[0, 0, 1, 2],
+            labels=["base", "shoulder", "elbow", "wrist"],
+            axis_length=0.1,
+        ),
+        static=True,
+    )
+
+    for t in range(100):
+        rr.set_time("step", sequence=t)
+
+        # Every joint is rotated by the same angle around the x axis relative to its parent.
+        angle = math.sin(t * 0.1) * 0.5
+        rotation = rr.Quaternion(xyzw=[math.sin(angle / 2), 0.0, 0.0, math.cos(angle / 2)])
+
+        rr.log(
+            "arm",
+            rr.Skeleton(
+                translations=[[0.0, 0.0, 0.0], [0.0, 0.0, 0.5], [0.0, 0.0, 0.4], [0.0, 0.0, 0.3]],
+                quaternions=[rotation] * 4,
+            ),
+        )
+    ```
+    """
+
+    def __init__(
+        self: Any,
+        translations: datatypes.Vec3DArrayLike,
+        *,
+        parents: datatypes.UInt32ArrayLike | None = None,
+        quaternions: datatypes.QuaternionArrayLike | None = None,
+        radii: datatypes.Float32ArrayLike | None = None,
+        colors: datatypes.Rgba32ArrayLike | None = None,
+        labels: datatypes.Utf8ArrayLike | None = None,
+        show_labels: datatypes.BoolLike | None = None,
+        axis_length: datatypes.Float32Like | None = None,
+        class_ids: datatypes.ClassIdArrayLike | None = None,
+    ) -> None:
+        """
+        Create a new instance of the Skeleton archetype.
+
+        Parameters
+        ----------
+        translations:
+            Translation of each joint relative to its parent joint.
+        parents:
+            Index of the parent of each joint.
+
+            Root joints reference themselves.
+            If not specified, all joints are treated as roots, i.e. they are placed relative to the entity's origin.
+        quaternions:
+            Rotation of each joint relative to its parent joint.
+
+            If not specified, joints aren't rotated relative to their parents.
+        radii:
+            Optional radii for the joints.
+
+            Bones are drawn with half the radius of the joint they lead to.
+        colors:
+            Optional colors for the joints and bones.
+
+            The bone leading to a joint uses the color of that joint.
+        labels:
+            Optional text labels for the joints, e.g. their names.
+
+            If there's a single label present, it will be placed at the center of the skeleton.
+            Otherwise, each label will be placed at its joint.
+        show_labels:
+            Whether the text labels should be shown.
+
+            If not set, labels will automatically appear when there is exactly one label for this entity
+            or the number of instances on this entity is under a certain threshold.
+        axis_length:
+            Length of the coordinate axes drawn at each joint.
+
+            If not set, no axes are drawn.
+        class_ids:
+            Optional class IDs for the joints.
+
+            The [`components.ClassId`][rerun.components.ClassId] provides colors and labels if not specified explicitly.
+
+        """
+
+        # You can define your own __init__ function as a member of SkeletonExt in skeleton_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(
+                translations=translations,
+                parents=parents,
+                quaternions=quaternions,
+                radii=radii,
+                colors=colors,
+                labels=labels,
+                show_labels=show_labels,
+                axis_length=axis_length,
+                class_ids=class_ids,
+            )
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            translations=None,
+            parents=None,
+            quaternions=None,
+            radii=None,
+            colors=None,
+            labels=None,
+            show_labels=None,
+            axis_length=None,
+            class_ids=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> Skeleton:
+        """Produce an empty Skeleton, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        translations: datatypes.Vec3DArrayLike | None = None,
+        parents: datatypes.UInt32ArrayLike | None = None,
+        quaternions: datatypes.QuaternionArrayLike | None = None,
+        radii: datatypes.Float32ArrayLike | None = None,
+        colors: datatypes.Rgba32ArrayLike | None = None,
+        labels: datatypes.Utf8ArrayLike | None = None,
+        show_labels: datatypes.BoolLike | None = None,
+        axis_length: datatypes.Float32Like | None = None,
+        class_ids: datatypes.ClassIdArrayLike | None = None,
+    ) -> Skeleton:
+        """
+        Update only some specific fields of a `Skeleton`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        translations:
+            Translation of each joint relative to its parent joint.
+        parents:
+            Index of the parent of each joint.
+
+            Root joints reference themselves.
+            If not specified, all joints are treated as roots, i.e. they are placed relative to the entity's origin.
+        quaternions:
+            Rotation of each joint relative to its parent joint.
+
+            If not specified, joints aren't rotated relative to their parents.
+        radii:
+            Optional radii for the joints.
+
+            Bones are drawn with half the radius of the joint they lead to.
+        colors:
+            Optional colors for the joints and bones.
+
+            The bone leading to a joint uses the color of that joint.
+        labels:
+            Optional text labels for the joints, e.g. their names.
+
+            If there's a single label present, it will be placed at the center of the skeleton.
+            Otherwise, each label will be placed at its joint.
+        show_labels:
+            Whether the text labels should be shown.
+
+            If not set, labels will automatically appear when there is exactly one label for this entity
+            or the number of instances on this entity is under a certain threshold.
+        axis_length:
+            Length of the coordinate axes drawn at each joint.
+
+            If not set, no axes are drawn.
+        class_ids:
+            Optional class IDs for the joints.
+
+            The [`components.ClassId`][rerun.components.ClassId] provides colors and labels if not specified explicitly.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "translations": translations,
+                "parents": parents,
+                "quaternions": quaternions,
+                "radii": radii,
+                "colors": colors,
+                "labels": labels,
+                "show_labels": show_labels,
+                "axis_length": axis_length,
+                "class_ids": class_ids,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> Skeleton:
+        """Clear all the fields of a `Skeleton`."""
+        return cls.from_fields(clear_unset=True)
+
+    @classmethod
+    def columns(
+        cls,
+        *,
+        translations: datatypes.Vec3DArrayLike | None = None,
+        parents: datatypes.UInt32ArrayLike | None = None,
+        quaternions: datatypes.QuaternionArrayLike | None = None,
+        radii: datatypes.Float32ArrayLike | None = None,
+        colors: datatypes.Rgba32ArrayLike | None = None,
+        labels: datatypes.Utf8ArrayLike | None = None,
+        show_labels: datatypes.BoolArrayLike | None = None,
+        axis_length: datatypes.Float32ArrayLike | None = None,
+        class_ids: datatypes.ClassIdArrayLike | None = None,
+    ) -> ComponentColumnList:
+        """
+        Construct a new column-oriented component bundle.
+
+        This makes it possible to use `rr.send_columns` to send columnar data directly into Rerun.
+
+        The returned columns will be partitioned into unit-length sub-batches by default.
+        Use `ComponentColumnList.partition` to repartition the data as needed.
+
+        Parameters
+        ----------
+        translations:
+            Translation of each joint relative to its parent joint.
+        parents:
+            Index of the parent of each joint.
+
+            Root joints reference themselves.
+            If not specified, all joints are treated as roots, i.e. they are placed relative to the entity's origin.
+        quaternions:
+            Rotation of each joint relative to its parent joint.
+
+            If not specified, joints aren't rotated relative to their parents.
+        radii:
+            Optional radii for the joints.
+
+            Bones are drawn with half the radius of the joint they lead to.
+        colors:
+            Optional colors for the joints and bones.
+
+            The bone leading to a joint uses the color of that joint.
+        labels:
+            Optional text labels for the joints, e.g. their names.
+
+            If there's a single label present, it will be placed at the center of the skeleton.
+            Otherwise, each label will be placed at its joint.
+        show_labels:
+            Whether the text labels should be shown.
+
+            If not set, labels will automatically appear when there is exactly one label for this entity
+            or the number of instances on this entity is under a certain threshold.
+        axis_length:
+            Length of the coordinate axes drawn at each joint.
+
+            If not set, no axes are drawn.
+        class_ids:
+            Optional class IDs for the joints.
+
+            The [`components.ClassId`][rerun.components.ClassId] provides colors and labels if not specified explicitly.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            inst.__attrs_init__(
+                translations=translations,
+                parents=parents,
+                quaternions=quaternions,
+                radii=radii,
+                colors=colors,
+                labels=labels,
+                show_labels=show_labels,
+                axis_length=axis_length,
+                class_ids=class_ids,
+            )
+
+        batches = inst.as_component_batches()
+        if len(batches) == 0:
+            return ComponentColumnList([])
+
+        kwargs = {
+            "Skeleton:translations": translations,
+            "Skeleton:parents": parents,
+            "Skeleton:quaternions": quaternions,
+            "Skeleton:radii": radii,
+            "Skeleton:colors": colors,
+            "Skeleton:labels": labels,
+            "Skeleton:show_labels": show_labels,
+            "Skeleton:axis_length": axis_length,
+            "Skeleton:class_ids": class_ids,
+        }
+        columns = []
+
+        for batch in batches:
+            arrow_array = batch.as_arrow_array()
+
+            # For primitive arrays and fixed size list arrays, we infer partition size from the input shape.
+            if pa.types.is_primitive(arrow_array.type) or pa.types.is_fixed_size_list(arrow_array.type):
+                param = kwargs[batch.component_descriptor().component]  # type: ignore[index]
+                shape = np.shape(param)  # type: ignore[arg-type]
+                elem_flat_len = int(np.prod(shape[1:])) if len(shape) > 1 else 1  # type: ignore[redundant-expr,misc]
+
+                if pa.types.is_fixed_size_list(arrow_array.type) and arrow_array.type.list_size == elem_flat_len:
+                    # If the product of the last dimensions of the shape are equal to the size of the fixed size list array,
+                    # we have `num_rows` single element batches (each element is a fixed sized list).
+                    # (This should have been already validated by conversion to the arrow_array)
+                    batch_length = 1
+                else:
+                    batch_length = shape[1] if len(shape) > 1 else 1  # type: ignore[redundant-expr,misc]
+
+                num_rows = shape[0] if len(shape) >= 1 else 1  # type: ignore[redundant-expr,misc]
+                sizes = batch_length * np.ones(num_rows)
+            else:
+                # For non-primitive types, default to partitioning each element separately.
+                sizes = np.ones(len(arrow_array))
+
+            columns.append(batch.partition(sizes))
+
+        return ComponentColumnList(columns)
+
+    translations: components.PoseTranslation3DBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.PoseTranslation3DBatch._converter,  # type: ignore[misc]
+    )
+    # Translation of each joint relative to its parent joint.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    parents: components.JointParentBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.JointParentBatch._converter,  # type: ignore[misc]
+    )
+    # Index of the parent of each joint.
+    #
+    # Root joints reference themselves.
+    # If not specified, all joints are treated as roots, i.e. they are placed relative to the entity's origin.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    quaternions: components.PoseRotationQuatBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.PoseRotationQuatBatch._converter,  # type: ignore[misc]
+    )
+    # Rotation of each joint relative to its parent joint.
+    #
+    # If not specified, joints aren't rotated relative to their parents.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    radii: components.RadiusBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.RadiusBatch._converter,  # type: ignore[misc]
+    )
+    # Optional radii for the joints.
+    #
+    # Bones are drawn with half the radius of the joint they lead to.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    colors: components.ColorBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ColorBatch._converter,  # type: ignore[misc]
+    )
+    # Optional colors for the joints and bones.
+    #
+    # The bone leading to a joint uses the color of that joint.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    labels: components.TextBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.TextBatch._converter,  # type: ignore[misc]
+    )
+    # Optional text labels for the joints, e.g. their names.
+    #
+    # If there's a single label present, it will be placed at the center of the skeleton.
+    # Otherwise, each label will be placed at its joint.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    show_labels: components.ShowLabelsBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ShowLabelsBatch._converter,  # type: ignore[misc]
+    )
+    # Whether the text labels should be shown.
+    #
+    # If not set, labels will automatically appear when there is exactly one label for this entity
+    # or the number of instances on this entity is under a certain threshold.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    axis_length: components.AxisLengthBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.AxisLengthBatch._converter,  # type: ignore[misc]
+    )
+    # Length of the coordinate axes drawn at each joint.
+    #
+    # If not set, no axes are drawn.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    class_ids: components.ClassIdBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ClassIdBatch._converter,  # type: ignore[misc]
+    )
+    # Optional class IDs for the joints.
+    #
+    # The [`components.ClassId`][rerun.components.ClassId] provides colors and labels if not specified explicitly.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
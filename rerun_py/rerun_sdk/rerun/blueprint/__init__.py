@@ -30,22 +30,40 @@ from .api import (
 )
 from .archetypes import (
     Background as Background,
+    BarChartLayout as BarChartLayout,
+    ClassIdRemap as ClassIdRemap,
+    ColorByComponent as ColorByComponent,
+    CoordinateConversion as CoordinateConversion,
+    CropBox as CropBox,
+    DepthClouds3D as DepthClouds3D,
     EntityBehavior as EntityBehavior,
     EyeControls3D as EyeControls3D,
+    FftWindow as FftWindow,
+    HoverTooltip as HoverTooltip,
+    ImagePlanes3D as ImagePlanes3D,
+    InstanceFilter as InstanceFilter,
     LineGrid3D as LineGrid3D,
+    OnionSkin as OnionSkin,
+    Outlines as Outlines,
     PlotLegend as PlotLegend,
+    PointShading as PointShading,
+    RigidAlignment as RigidAlignment,
     ScalarAxis as ScalarAxis,
     SpatialInformation as SpatialInformation,
     TensorScalarMapping as TensorScalarMapping,
     TensorSliceSelection as TensorSliceSelection,
+    TrajectoryTrails as TrajectoryTrails,
+    VisibilityRules as VisibilityRules,
     VisibleTimeRanges as VisibleTimeRanges,
     VisualBounds2D as VisualBounds2D,
     VisualizerOverrides as VisualizerOverrides,
 )
 from .components import (
     BackgroundKind as BackgroundKind,
+    BarChartMode as BarChartMode,
     Corner2D as Corner2D,
     Eye3DKind as Eye3DKind,
+    Eye3DProjection as Eye3DProjection,
     LockRangeDuringZoom as LockRangeDuringZoom,
     MapProvider as MapProvider,
 )
@@ -56,12 +74,14 @@ from .containers import (
     Vertical as Vertical,
 )
 from .views import (
+    AudioView as AudioView,
     BarChartView as BarChartView,
     DataframeView as DataframeView,
     GraphView as GraphView,
     MapView as MapView,
     Spatial2DView as Spatial2DView,
     Spatial3DView as Spatial3DView,
+    SpectrogramView as SpectrogramView,
     TensorView as TensorView,
     TextDocumentView as TextDocumentView,
     TextLogView as TextLogView,
@@ -38,9 +38,11 @@ class View:
 
     Consider using one of the subclasses instead of this class directly:
 
+    - [rerun.blueprint.AudioView][]
     - [rerun.blueprint.BarChartView][]
     - [rerun.blueprint.Spatial2DView][]
     - [rerun.blueprint.Spatial3DView][]
+    - [rerun.blueprint.SpectrogramView][]
     - [rerun.blueprint.TensorView][]
     - [rerun.blueprint.TextDocumentView][]
     - [rerun.blueprint.TextLogView][]
@@ -450,6 +452,7 @@ class TimePanel(Panel):
         play_state: PlayStateLike | None = None,
         loop_mode: LoopModeLike | None = None,
         time_selection: AbsoluteTimeRange | None = None,
+        expanded_entities: Iterable[EntityPathLike] | None = None,
     ) -> None:
         """
         Construct a new time panel.
@@ -484,6 +487,12 @@ class TimePanel(Panel):
         time_selection:
             Selects a range of time on the time panel.
 
+        expanded_entities:
+            The entities whose rows are expanded in the streams tree.
+
+            Collapsed rows show the combined data density of their whole subtree.
+            If not set, the viewer picks which rows to expand.
+
         """
         super().__init__(blueprint_path="time_panel", expanded=expanded, state=state)
 
@@ -493,6 +502,7 @@ class TimePanel(Panel):
         self.play_state = play_state
         self.loop_mode = loop_mode
         self.time_selection = time_selection
+        self.expanded_entities = list(expanded_entities) if expanded_entities is not None else None
 
     def _log_to_stream(self, stream: RecordingStream) -> None:
         """Internal method to convert to an archetype and log to the stream."""
@@ -503,6 +513,7 @@ class TimePanel(Panel):
             fps=self.fps,
             loop_mode=self.loop_mode,
             time_selection=self.time_selection,
+            expanded_entities=self.expanded_entities,
         )
 
         stream.log(self.blueprint_path(), arch)  # type: ignore[attr-defined]
@@ -3,23 +3,37 @@
 from __future__ import annotations
 
 from .background import Background
+from .bar_chart_layout import BarChartLayout
+from .class_id_remap import ClassIdRemap
+from .color_by_component import ColorByComponent
 from .container_blueprint import ContainerBlueprint
+from .coordinate_conversion import CoordinateConversion
+from .crop_box import CropBox
 from .dataframe_query import DataframeQuery
+from .depth_clouds3d import DepthClouds3D
 from .entity_behavior import EntityBehavior
 from .eye_controls3d import EyeControls3D
+from .fft_window import FftWindow
 from .force_center import ForceCenter
 from .force_collision_radius import ForceCollisionRadius
 from .force_link import ForceLink
 from .force_many_body import ForceManyBody
 from .force_position import ForcePosition
 from .graph_background import GraphBackground
+from .hover_tooltip import HoverTooltip
+from .image_planes3d import ImagePlanes3D
+from .instance_filter import InstanceFilter
 from .line_grid3d import LineGrid3D
 from .map_background import MapBackground
 from .map_zoom import MapZoom
 from .near_clip_plane import NearClipPlane
+from .onion_skin import OnionSkin
+from .outlines import Outlines
 from .panel_blueprint import PanelBlueprint
 from .plot_background import PlotBackground
 from .plot_legend import PlotLegend
+from .point_shading import PointShading
+from .rigid_alignment import RigidAlignment
 from .scalar_axis import ScalarAxis
 from .spatial_information import SpatialInformation
 from .tensor_scalar_mapping import TensorScalarMapping
@@ -27,32 +41,48 @@ from .tensor_slice_selection import TensorSliceSelection
 from .tensor_view_fit import TensorViewFit
 from .time_axis import TimeAxis
 from .time_panel_blueprint import TimePanelBlueprint
+from .trajectory_trails import TrajectoryTrails
 from .view_blueprint import ViewBlueprint
 from .view_contents import ViewContents
 from .viewport_blueprint import ViewportBlueprint
+from .visibility_rules import VisibilityRules
 from .visible_time_ranges import VisibleTimeRanges
 from .visual_bounds2d import VisualBounds2D
 from .visualizer_overrides import VisualizerOverrides
 
 __all__ = [
     "Background",
+    "BarChartLayout",
+    "ClassIdRemap",
+    "ColorByComponent",
     "ContainerBlueprint",
+    "CoordinateConversion",
+    "CropBox",
     "DataframeQuery",
+    "DepthClouds3D",
     "EntityBehavior",
     "EyeControls3D",
+    "FftWindow",
     "ForceCenter",
     "ForceCollisionRadius",
     "ForceLink",
     "ForceManyBody",
     "ForcePosition",
     "GraphBackground",
+    "HoverTooltip",
+    "ImagePlanes3D",
+    "InstanceFilter",
     "LineGrid3D",
     "MapBackground",
     "MapZoom",
     "NearClipPlane",
+    "OnionSkin",
+    "Outlines",
     "PanelBlueprint",
     "PlotBackground",
     "PlotLegend",
+    "PointShading",
+    "RigidAlignment",
     "ScalarAxis",
     "SpatialInformation",
     "TensorScalarMapping",
@@ -60,9 +90,11 @@ __all__ = [
     "TensorViewFit",
     "TimeAxis",
     "TimePanelBlueprint",
+    "TrajectoryTrails",
     "ViewBlueprint",
     "ViewContents",
     "ViewportBlueprint",
+    "VisibilityRules",
     "VisibleTimeRanges",
     "VisualBounds2D",
     "VisualizerOverrides",
@@ -0,0 +1,92 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/bar_chart_layout.fbs".
+
+# You can extend this class by creating a "BarChartLayoutExt" class in "bar_chart_layout_ext.py".
+
+from __future__ import annotations
+
+from attrs import define, field
+
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+from .bar_chart_layout_ext import BarChartLayoutExt
+
+__all__ = ["BarChartLayout"]
+
+
+@define(str=False, repr=False, init=False)
+class BarChartLayout(BarChartLayoutExt, Archetype):
+    """
+    **Archetype**: Configures how the bar charts of several entities are combined in a bar chart view.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    # __init__ can be found in bar_chart_layout_ext.py
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            mode=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> BarChartLayout:
+        """Produce an empty BarChartLayout, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        mode: blueprint_components.BarChartModeLike | None = None,
+    ) -> BarChartLayout:
+        """
+        Update only some specific fields of a `BarChartLayout`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        mode:
+            How the bars of different entities at the same position are arranged.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "mode": mode,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> BarChartLayout:
+        """Clear all the fields of a `BarChartLayout`."""
+        return cls.from_fields(clear_unset=True)
+
+    mode: blueprint_components.BarChartModeBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.BarChartModeBatch._converter,  # type: ignore[misc]
+    )
+    # How the bars of different entities at the same position are arranged.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
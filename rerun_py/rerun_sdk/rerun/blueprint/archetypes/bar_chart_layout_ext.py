@@ -0,0 +1,28 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING, Any
+
+from ...error_utils import catch_and_log_exceptions
+
+if TYPE_CHECKING:
+    from .. import components as blueprint_components
+
+
+class BarChartLayoutExt:
+    """Extension for [BarChartLayout][rerun.blueprint.archetypes.BarChartLayout]."""
+
+    def __init__(self: Any, mode: blueprint_components.BarChartModeLike | None = None) -> None:
+        """
+        Create a new instance of the BarChartLayout archetype.
+
+        Parameters
+        ----------
+        mode:
+            How the bars of different entities at the same position are arranged.
+
+        """
+
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(mode=mode)
+            return
+        self.__attrs_clear__()
@@ -0,0 +1,144 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/class_id_remap.fbs".
+
+# You can extend this class by creating a "ClassIdRemapExt" class in "class_id_remap_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components, datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["ClassIdRemap"]
+
+
+@define(str=False, repr=False, init=False)
+class ClassIdRemap(Archetype):
+    """
+    **Archetype**: Remaps class ids of the data shown in a view, e.g. to merge the classes of a segmentation image.
+
+    Each entry of `source_class_ids` is shown as the class at the same index in `target_class_ids`,
+    i.e. with its color and label from the [`archetypes.AnnotationContext`][rerun.archetypes.AnnotationContext].
+    Class ids that are not listed are shown as they are.
+
+    This applies to everything with class ids in the view, and doesn't change the logged data.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        *,
+        source_class_ids: datatypes.ClassIdArrayLike | None = None,
+        target_class_ids: datatypes.ClassIdArrayLike | None = None,
+    ) -> None:
+        """
+        Create a new instance of the ClassIdRemap archetype.
+
+        Parameters
+        ----------
+        source_class_ids:
+            The class ids that are remapped.
+        target_class_ids:
+            The class id that each entry of `source_class_ids` is remapped to.
+
+            Must have the same length as `source_class_ids`, extra entries of either are ignored.
+
+        """
+
+        # You can define your own __init__ function as a member of ClassIdRemapExt in class_id_remap_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(
+                source_class_ids=source_class_ids,
+                target_class_ids=target_class_ids,
+            )
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            source_class_ids=None,
+            target_class_ids=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> ClassIdRemap:
+        """Produce an empty ClassIdRemap, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        source_class_ids: datatypes.ClassIdArrayLike | None = None,
+        target_class_ids: datatypes.ClassIdArrayLike | None = None,
+    ) -> ClassIdRemap:
+        """
+        Update only some specific fields of a `ClassIdRemap`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        source_class_ids:
+            The class ids that are remapped.
+        target_class_ids:
+            The class id that each entry of `source_class_ids` is remapped to.
+
+            Must have the same length as `source_class_ids`, extra entries of either are ignored.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "source_class_ids": source_class_ids,
+                "target_class_ids": target_class_ids,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> ClassIdRemap:
+        """Clear all the fields of a `ClassIdRemap`."""
+        return cls.from_fields(clear_unset=True)
+
+    source_class_ids: components.ClassIdBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ClassIdBatch._converter,  # type: ignore[misc]
+    )
+    # The class ids that are remapped.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    target_class_ids: components.ClassIdBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ClassIdBatch._converter,  # type: ignore[misc]
+    )
+    # The class id that each entry of `source_class_ids` is remapped to.
+    #
+    # Must have the same length as `source_class_ids`, extra entries of either are ignored.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
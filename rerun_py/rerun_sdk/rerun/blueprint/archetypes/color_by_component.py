@@ -0,0 +1,178 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/color_by_component.fbs".
+
+# You can extend this class by creating a "ColorByComponentExt" class in "color_by_component_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components, datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["ColorByComponent"]
+
+
+@define(str=False, repr=False, init=False)
+class ColorByComponent(Archetype):
+    """
+    **Archetype**: Colors the instances of an entity by the value of a scalar component logged alongside them.
+
+    Log this as an override of the entity in a 3D view, e.g. to color points by `intensity`
+    or boxes by `confidence`.
+    Applies to points, boxes, ellipsoids, capsules, cylinders, and meshes,
+    and takes precedence over their logged colors.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        *,
+        component: datatypes.Utf8Like | None = None,
+        colormap: components.ColormapLike | None = None,
+        range: datatypes.Range1DLike | None = None,
+    ) -> None:
+        """
+        Create a new instance of the ColorByComponent archetype.
+
+        Parameters
+        ----------
+        component:
+            The component whose values are mapped to colors, e.g. `intensity`.
+
+            Either the full component identifier or just the part after the archetype prefix.
+            Any numeric component works.
+            If unset, or if the entity doesn't have this component, the instances keep their colors.
+        colormap:
+            The colormap the values are mapped through.
+
+            Defaults to viridis.
+        range:
+            The values mapped to the start and end of the colormap.
+
+            Values outside of this range are clamped.
+            If unset, the range of the entity's values at the current time is used.
+
+        """
+
+        # You can define your own __init__ function as a member of ColorByComponentExt in color_by_component_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(component=component, colormap=colormap, range=range)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            component=None,
+            colormap=None,
+            range=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> ColorByComponent:
+        """Produce an empty ColorByComponent, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        component: datatypes.Utf8Like | None = None,
+        colormap: components.ColormapLike | None = None,
+        range: datatypes.Range1DLike | None = None,
+    ) -> ColorByComponent:
+        """
+        Update only some specific fields of a `ColorByComponent`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        component:
+            The component whose values are mapped to colors, e.g. `intensity`.
+
+            Either the full component identifier or just the part after the archetype prefix.
+            Any numeric component works.
+            If unset, or if the entity doesn't have this component, the instances keep their colors.
+        colormap:
+            The colormap the values are mapped through.
+
+            Defaults to viridis.
+        range:
+            The values mapped to the start and end of the colormap.
+
+            Values outside of this range are clamped.
+            If unset, the range of the entity's values at the current time is used.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "component": component,
+                "colormap": colormap,
+                "range": range,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> ColorByComponent:
+        """Clear all the fields of a `ColorByComponent`."""
+        return cls.from_fields(clear_unset=True)
+
+    component: components.NameBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.NameBatch._converter,  # type: ignore[misc]
+    )
+    # The component whose values are mapped to colors, e.g. `intensity`.
+    #
+    # Either the full component identifier or just the part after the archetype prefix.
+    # Any numeric component works.
+    # If unset, or if the entity doesn't have this component, the instances keep their colors.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    colormap: components.ColormapBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ColormapBatch._converter,  # type: ignore[misc]
+    )
+    # The colormap the values are mapped through.
+    #
+    # Defaults to viridis.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    range: components.ValueRangeBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ValueRangeBatch._converter,  # type: ignore[misc]
+    )
+    # The values mapped to the start and end of the colormap.
+    #
+    # Values outside of this range are clamped.
+    # If unset, the range of the entity's values at the current time is used.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,216 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/coordinate_conversion.fbs".
+
+# You can extend this class by creating a "CoordinateConversionExt" class in "coordinate_conversion_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components, datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["CoordinateConversion"]
+
+
+@define(str=False, repr=False, init=False)
+class CoordinateConversion(Archetype):
+    """
+    **Archetype**: Converts the data shown in a 3D view from the coordinate convention it was logged in to another one.
+
+    E.g. this makes it possible to show data logged in NED (`FRD`) in an ENU (`RFU`) world,
+    or data logged in Earth-centered, Earth-fixed coordinates in the local tangent plane at `geo_origin`.
+
+    This only changes how the data is shown, not the logged data itself.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        *,
+        source_coordinates: datatypes.ViewCoordinatesLike | None = None,
+        target_coordinates: datatypes.ViewCoordinatesLike | None = None,
+        earth_centered: datatypes.BoolLike | None = None,
+        geo_origin: datatypes.DVec2DLike | None = None,
+    ) -> None:
+        """
+        Create a new instance of the CoordinateConversion archetype.
+
+        Parameters
+        ----------
+        source_coordinates:
+            The coordinate convention the data is logged in, e.g. `FRD` for NED.
+
+            Defaults to the [`components.ViewCoordinates`][rerun.components.ViewCoordinates] logged at the space origin, if any.
+        target_coordinates:
+            The coordinate convention the data is shown in, e.g. `RFU` for ENU.
+
+            Defaults to `source_coordinates`, i.e. no conversion.
+        earth_centered:
+            Whether the data is logged in Earth-centered, Earth-fixed (ECEF) coordinates, in meters.
+
+            If enabled, the data is shown in the east-north-up tangent plane at `geo_origin` instead,
+            and `source_coordinates` is ignored.
+
+            Defaults to false.
+        geo_origin:
+            The origin of the local tangent plane, on the WGS84 ellipsoid.
+
+            Used for Earth-centered data, and to place [`archetypes.GeoPoints`][rerun.archetypes.GeoPoints] in the view,
+            which the `GeoPoints3D` visualizer does in an east-north-up frame.
+
+            Defaults to 0° latitude, 0° longitude.
+
+        """
+
+        # You can define your own __init__ function as a member of CoordinateConversionExt in coordinate_conversion_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(
+                source_coordinates=source_coordinates,
+                target_coordinates=target_coordinates,
+                earth_centered=earth_centered,
+                geo_origin=geo_origin,
+            )
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            source_coordinates=None,
+            target_coordinates=None,
+            earth_centered=None,
+            geo_origin=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> CoordinateConversion:
+        """Produce an empty CoordinateConversion, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        source_coordinates: datatypes.ViewCoordinatesLike | None = None,
+        target_coordinates: datatypes.ViewCoordinatesLike | None = None,
+        earth_centered: datatypes.BoolLike | None = None,
+        geo_origin: datatypes.DVec2DLike | None = None,
+    ) -> CoordinateConversion:
+        """
+        Update only some specific fields of a `CoordinateConversion`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        source_coordinates:
+            The coordinate convention the data is logged in, e.g. `FRD` for NED.
+
+            Defaults to the [`components.ViewCoordinates`][rerun.components.ViewCoordinates] logged at the space origin, if any.
+        target_coordinates:
+            The coordinate convention the data is shown in, e.g. `RFU` for ENU.
+
+            Defaults to `source_coordinates`, i.e. no conversion.
+        earth_centered:
+            Whether the data is logged in Earth-centered, Earth-fixed (ECEF) coordinates, in meters.
+
+            If enabled, the data is shown in the east-north-up tangent plane at `geo_origin` instead,
+            and `source_coordinates` is ignored.
+
+            Defaults to false.
+        geo_origin:
+            The origin of the local tangent plane, on the WGS84 ellipsoid.
+
+            Used for Earth-centered data, and to place [`archetypes.GeoPoints`][rerun.archetypes.GeoPoints] in the view,
+            which the `GeoPoints3D` visualizer does in an east-north-up frame.
+
+            Defaults to 0° latitude, 0° longitude.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "source_coordinates": source_coordinates,
+                "target_coordinates": target_coordinates,
+                "earth_centered": earth_centered,
+                "geo_origin": geo_origin,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> CoordinateConversion:
+        """Clear all the fields of a `CoordinateConversion`."""
+        return cls.from_fields(clear_unset=True)
+
+    source_coordinates: components.ViewCoordinatesBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ViewCoordinatesBatch._converter,  # type: ignore[misc]
+    )
+    # The coordinate convention the data is logged in, e.g. `FRD` for NED.
+    #
+    # Defaults to the [`components.ViewCoordinates`][rerun.components.ViewCoordinates] logged at the space origin, if any.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    target_coordinates: components.ViewCoordinatesBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ViewCoordinatesBatch._converter,  # type: ignore[misc]
+    )
+    # The coordinate convention the data is shown in, e.g. `RFU` for ENU.
+    #
+    # Defaults to `source_coordinates`, i.e. no conversion.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    earth_centered: blueprint_components.EnabledBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.EnabledBatch._converter,  # type: ignore[misc]
+    )
+    # Whether the data is logged in Earth-centered, Earth-fixed (ECEF) coordinates, in meters.
+    #
+    # If enabled, the data is shown in the east-north-up tangent plane at `geo_origin` instead,
+    # and `source_coordinates` is ignored.
+    #
+    # Defaults to false.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    geo_origin: components.LatLonBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.LatLonBatch._converter,  # type: ignore[misc]
+    )
+    # The origin of the local tangent plane, on the WGS84 ellipsoid.
+    #
+    # Used for Earth-centered data, and to place [`archetypes.GeoPoints`][rerun.archetypes.GeoPoints] in the view,
+    # which the `GeoPoints3D` visualizer does in an east-north-up frame.
+    #
+    # Defaults to 0° latitude, 0° longitude.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
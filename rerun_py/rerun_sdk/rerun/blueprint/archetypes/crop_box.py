@@ -0,0 +1,193 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/crop_box.fbs".
+
+# You can extend this class by creating a "CropBoxExt" class in "crop_box_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components, datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["CropBox"]
+
+
+@define(str=False, repr=False, init=False)
+class CropBox(Archetype):
+    """
+    **Archetype**: Hides the points of point clouds inside or outside of an axis-aligned box in a 3D view.
+
+    The points are filtered on the GPU, so this stays fast even for very large point clouds.
+    Depth images shown as point clouds are cropped the same way, Gaussian splats by their center.
+    Hidden points can't be hovered or selected.
+    The box can be moved and resized by dragging its faces in the view.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        *,
+        enabled: datatypes.BoolLike | None = None,
+        center: datatypes.Vec3DLike | None = None,
+        half_size: datatypes.Vec3DLike | None = None,
+        keep_outside: datatypes.BoolLike | None = None,
+    ) -> None:
+        """
+        Create a new instance of the CropBox archetype.
+
+        Parameters
+        ----------
+        enabled:
+            Whether the crop box is applied.
+
+            Defaults to false.
+        center:
+            The center of the box, in the coordinates of the view's origin.
+
+            Defaults to the origin.
+        half_size:
+            Half the size of the box along each axis.
+
+            Defaults to 1 along each axis.
+        keep_outside:
+            If enabled, the points inside the box are hidden instead of those outside.
+
+            Defaults to false.
+
+        """
+
+        # You can define your own __init__ function as a member of CropBoxExt in crop_box_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(enabled=enabled, center=center, half_size=half_size, keep_outside=keep_outside)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            enabled=None,
+            center=None,
+            half_size=None,
+            keep_outside=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> CropBox:
+        """Produce an empty CropBox, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        enabled: datatypes.BoolLike | None = None,
+        center: datatypes.Vec3DLike | None = None,
+        half_size: datatypes.Vec3DLike | None = None,
+        keep_outside: datatypes.BoolLike | None = None,
+    ) -> CropBox:
+        """
+        Update only some specific fields of a `CropBox`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        enabled:
+            Whether the crop box is applied.
+
+            Defaults to false.
+        center:
+            The center of the box, in the coordinates of the view's origin.
+
+            Defaults to the origin.
+        half_size:
+            Half the size of the box along each axis.
+
+            Defaults to 1 along each axis.
+        keep_outside:
+            If enabled, the points inside the box are hidden instead of those outside.
+
+            Defaults to false.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "enabled": enabled,
+                "center": center,
+                "half_size": half_size,
+                "keep_outside": keep_outside,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> CropBox:
+        """Clear all the fields of a `CropBox`."""
+        return cls.from_fields(clear_unset=True)
+
+    enabled: blueprint_components.EnabledBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.EnabledBatch._converter,  # type: ignore[misc]
+    )
+    # Whether the crop box is applied.
+    #
+    # Defaults to false.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    center: components.Translation3DBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.Translation3DBatch._converter,  # type: ignore[misc]
+    )
+    # The center of the box, in the coordinates of the view's origin.
+    #
+    # Defaults to the origin.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    half_size: components.HalfSize3DBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.HalfSize3DBatch._converter,  # type: ignore[misc]
+    )
+    # Half the size of the box along each axis.
+    #
+    # Defaults to 1 along each axis.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    keep_outside: blueprint_components.EnabledBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.EnabledBatch._converter,  # type: ignore[misc]
+    )
+    # If enabled, the points inside the box are hidden instead of those outside.
+    #
+    # Defaults to false.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -39,6 +39,7 @@ class DataframeQuery(DataframeQueryExt, Archetype):
             filter_by_range=None,
             filter_is_not_null=None,
             apply_latest_at=None,
+            latest_at_tolerance=None,
             select=None,
         )
 
@@ -58,6 +59,7 @@ class DataframeQuery(DataframeQueryExt, Archetype):
         filter_by_range: blueprint_datatypes.FilterByRangeLike | None = None,
         filter_is_not_null: blueprint_datatypes.FilterIsNotNullLike | None = None,
         apply_latest_at: datatypes.BoolLike | None = None,
+        latest_at_tolerance: datatypes.TimeIntLike | None = None,
         select: blueprint_datatypes.SelectedColumnsLike | None = None,
     ) -> DataframeQuery:
         """
@@ -79,6 +81,11 @@ class DataframeQuery(DataframeQueryExt, Archetype):
             If provided, only show rows which contains a logged event for the specified component.
         apply_latest_at:
             Should empty cells be filled with latest-at queries?
+        latest_at_tolerance:
+            If set, empty cells are only filled with values logged at most this long before their row.
+
+            This is in the units of the timeline, i.e. nanoseconds or sequence steps.
+            It has no effect unless `apply_latest_at` is enabled.
         select:
             Selected columns. If unset, all columns are selected.
 
@@ -91,6 +98,7 @@ class DataframeQuery(DataframeQueryExt, Archetype):
                 "filter_by_range": filter_by_range,
                 "filter_is_not_null": filter_is_not_null,
                 "apply_latest_at": apply_latest_at,
+                "latest_at_tolerance": latest_at_tolerance,
                 "select": select,
             }
 
@@ -148,6 +156,18 @@ class DataframeQuery(DataframeQueryExt, Archetype):
     #
     # (Docstring intentionally commented out to hide this field from the docs)
 
+    latest_at_tolerance: blueprint_components.TimeIntBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.TimeIntBatch._converter,  # type: ignore[misc]
+    )
+    # If set, empty cells are only filled with values logged at most this long before their row.
+    #
+    # This is in the units of the timeline, i.e. nanoseconds or sequence steps.
+    # It has no effect unless `apply_latest_at` is enabled.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
     select: blueprint_components.SelectedColumnsBatch | None = field(
         metadata={"component": True},
         default=None,
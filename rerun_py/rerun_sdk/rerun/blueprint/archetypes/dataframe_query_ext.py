@@ -21,6 +21,7 @@ class DataframeQueryExt:
         | None = None,
         filter_is_not_null: blueprint_datatypes.ComponentColumnSelectorLike | None = None,
         apply_latest_at: bool = False,
+        latest_at_tolerance: datatypes.TimeIntLike | None = None,
         select: list[blueprint_datatypes.ComponentColumnSelectorLike | datatypes.Utf8Like | str] | None = None,
     ) -> None:
         """
@@ -41,6 +42,11 @@ class DataframeQueryExt:
         apply_latest_at:
             Should empty cells be filled with latest-at queries?
 
+        latest_at_tolerance:
+            If set, empty cells are only filled with values logged at most this long before their row.
+
+            This has no effect unless `apply_latest_at` is enabled.
+
         select:
             Selected columns. If unset, all columns are selected.
 
@@ -66,6 +72,7 @@ class DataframeQueryExt:
                 filter_by_range=filter_by_range,
                 filter_is_not_null=new_filter_is_not_null,
                 apply_latest_at=apply_latest_at,
+                latest_at_tolerance=latest_at_tolerance,
                 select=select,
             )
             return
@@ -0,0 +1,181 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/depth_clouds3d.fbs".
+
+# You can extend this class by creating a "DepthClouds3DExt" class in "depth_clouds3d_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components, datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["DepthClouds3D"]
+
+
+@define(str=False, repr=False, init=False)
+class DepthClouds3D(Archetype):
+    """
+    **Archetype**: Configuration for how depth images are backprojected into point clouds in the 3D view.
+
+    A [`archetypes.DepthImage`][rerun.archetypes.DepthImage] logged under a [`archetypes.Pinhole`][rerun.archetypes.Pinhole] is shown as a point cloud,
+    using the camera intrinsics and [`components.DepthMeter`][rerun.components.DepthMeter] of the depth image.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        *,
+        enabled: datatypes.BoolLike | None = None,
+        subsampling: datatypes.UInt64Like | None = None,
+        depth_range: datatypes.Range1DLike | None = None,
+    ) -> None:
+        """
+        Create a new instance of the DepthClouds3D archetype.
+
+        Parameters
+        ----------
+        enabled:
+            Whether depth images are shown as point clouds.
+
+            If disabled, depth images are shown as images on the image plane of their camera instead.
+            Defaults to true.
+        subsampling:
+            Only every n-th pixel of a depth image, in both directions, is turned into a point.
+
+            Higher values speed up rendering of large depth images.
+            Defaults to 1, i.e. a point for every pixel.
+        depth_range:
+            Only depths within this range, in meters, are shown as points.
+
+            This does not affect the range used for the colormap, see [`archetypes.DepthImage`][rerun.archetypes.DepthImage].
+            If unset, all valid depths are shown.
+
+        """
+
+        # You can define your own __init__ function as a member of DepthClouds3DExt in depth_clouds3d_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(
+                enabled=enabled,
+                subsampling=subsampling,
+                depth_range=depth_range,
+            )
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            enabled=None,
+            subsampling=None,
+            depth_range=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> DepthClouds3D:
+        """Produce an empty DepthClouds3D, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        enabled: datatypes.BoolLike | None = None,
+        subsampling: datatypes.UInt64Like | None = None,
+        depth_range: datatypes.Range1DLike | None = None,
+    ) -> DepthClouds3D:
+        """
+        Update only some specific fields of a `DepthClouds3D`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        enabled:
+            Whether depth images are shown as point clouds.
+
+            If disabled, depth images are shown as images on the image plane of their camera instead.
+            Defaults to true.
+        subsampling:
+            Only every n-th pixel of a depth image, in both directions, is turned into a point.
+
+            Higher values speed up rendering of large depth images.
+            Defaults to 1, i.e. a point for every pixel.
+        depth_range:
+            Only depths within this range, in meters, are shown as points.
+
+            This does not affect the range used for the colormap, see [`archetypes.DepthImage`][rerun.archetypes.DepthImage].
+            If unset, all valid depths are shown.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "enabled": enabled,
+                "subsampling": subsampling,
+                "depth_range": depth_range,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> DepthClouds3D:
+        """Clear all the fields of a `DepthClouds3D`."""
+        return cls.from_fields(clear_unset=True)
+
+    enabled: blueprint_components.EnabledBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.EnabledBatch._converter,  # type: ignore[misc]
+    )
+    # Whether depth images are shown as point clouds.
+    #
+    # If disabled, depth images are shown as images on the image plane of their camera instead.
+    # Defaults to true.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    subsampling: blueprint_components.DepthCloudSubsamplingBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.DepthCloudSubsamplingBatch._converter,  # type: ignore[misc]
+    )
+    # Only every n-th pixel of a depth image, in both directions, is turned into a point.
+    #
+    # Higher values speed up rendering of large depth images.
+    # Defaults to 1, i.e. a point for every pixel.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    depth_range: components.ValueRangeBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ValueRangeBatch._converter,  # type: ignore[misc]
+    )
+    # Only depths within this range, in meters, are shown as points.
+    #
+    # This does not affect the range used for the colormap, see [`archetypes.DepthImage`][rerun.archetypes.DepthImage].
+    # If unset, all valid depths are shown.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -39,6 +39,7 @@ class EyeControls3D(Archetype):
         speed: datatypes.Float64Like | None = None,
         tracking_entity: datatypes.EntityPathLike | None = None,
         spin_speed: datatypes.Float64Like | None = None,
+        projection: blueprint_components.Eye3DProjectionLike | None = None,
     ) -> None:
         """
         Create a new instance of the EyeControls3D archetype.
@@ -77,6 +78,10 @@ class EyeControls3D(Archetype):
             What speed, if any, the camera should spin around the eye-up axis.
 
             Defaults to zero, meaning no spinning.
+        projection:
+            The projection of the eye.
+
+            Defaults to perspective.
 
         """
 
@@ -90,6 +95,7 @@ class EyeControls3D(Archetype):
                 speed=speed,
                 tracking_entity=tracking_entity,
                 spin_speed=spin_speed,
+                projection=projection,
             )
             return
         self.__attrs_clear__()
@@ -104,6 +110,7 @@ class EyeControls3D(Archetype):
             speed=None,
             tracking_entity=None,
             spin_speed=None,
+            projection=None,
         )
 
     @classmethod
@@ -125,6 +132,7 @@ class EyeControls3D(Archetype):
         speed: datatypes.Float64Like | None = None,
         tracking_entity: datatypes.EntityPathLike | None = None,
         spin_speed: datatypes.Float64Like | None = None,
+        projection: blueprint_components.Eye3DProjectionLike | None = None,
     ) -> EyeControls3D:
         """
         Update only some specific fields of a `EyeControls3D`.
@@ -165,6 +173,10 @@ class EyeControls3D(Archetype):
             What speed, if any, the camera should spin around the eye-up axis.
 
             Defaults to zero, meaning no spinning.
+        projection:
+            The projection of the eye.
+
+            Defaults to perspective.
 
         """
 
@@ -178,6 +190,7 @@ class EyeControls3D(Archetype):
                 "speed": speed,
                 "tracking_entity": tracking_entity,
                 "spin_speed": spin_speed,
+                "projection": projection,
             }
 
             if clear_unset:
@@ -275,5 +288,16 @@ class EyeControls3D(Archetype):
     #
     # (Docstring intentionally commented out to hide this field from the docs)
 
+    projection: blueprint_components.Eye3DProjectionBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.Eye3DProjectionBatch._converter,  # type: ignore[misc]
+    )
+    # The projection of the eye.
+    #
+    # Defaults to perspective.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
     __str__ = Archetype.__str__
     __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,153 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/fft_window.fbs".
+
+# You can extend this class by creating a "FftWindowExt" class in "fft_window_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["FftWindow"]
+
+
+@define(str=False, repr=False, init=False)
+class FftWindow(Archetype):
+    """
+    **Archetype**: Configures the windows of the rolling FFT of a spectrogram.
+
+    Each window is multiplied with a Hann window before its spectrum is computed.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        *,
+        size: datatypes.UInt32Like | None = None,
+        overlap: datatypes.Float32Like | None = None,
+    ) -> None:
+        """
+        Create a new instance of the FftWindow archetype.
+
+        Parameters
+        ----------
+        size:
+            The number of samples in each window.
+
+            Rounded up to the next power of two.
+            Larger windows resolve frequencies more finely, but resolve events in time more coarsely.
+            Defaults to 256.
+        overlap:
+            How much consecutive windows overlap, as a fraction of the window size.
+
+            Clamped to `[0, 0.95]`.
+            Defaults to 0.5.
+
+        """
+
+        # You can define your own __init__ function as a member of FftWindowExt in fft_window_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(size=size, overlap=overlap)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            size=None,
+            overlap=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> FftWindow:
+        """Produce an empty FftWindow, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        size: datatypes.UInt32Like | None = None,
+        overlap: datatypes.Float32Like | None = None,
+    ) -> FftWindow:
+        """
+        Update only some specific fields of a `FftWindow`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        size:
+            The number of samples in each window.
+
+            Rounded up to the next power of two.
+            Larger windows resolve frequencies more finely, but resolve events in time more coarsely.
+            Defaults to 256.
+        overlap:
+            How much consecutive windows overlap, as a fraction of the window size.
+
+            Clamped to `[0, 0.95]`.
+            Defaults to 0.5.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "size": size,
+                "overlap": overlap,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> FftWindow:
+        """Clear all the fields of a `FftWindow`."""
+        return cls.from_fields(clear_unset=True)
+
+    size: blueprint_components.FftWindowSizeBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.FftWindowSizeBatch._converter,  # type: ignore[misc]
+    )
+    # The number of samples in each window.
+    #
+    # Rounded up to the next power of two.
+    # Larger windows resolve frequencies more finely, but resolve events in time more coarsely.
+    # Defaults to 256.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    overlap: blueprint_components.FftWindowOverlapBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.FftWindowOverlapBatch._converter,  # type: ignore[misc]
+    )
+    # How much consecutive windows overlap, as a fraction of the window size.
+    #
+    # Clamped to `[0, 0.95]`.
+    # Defaults to 0.5.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,123 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/hover_tooltip.fbs".
+
+# You can extend this class by creating a "HoverTooltipExt" class in "hover_tooltip_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components, datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["HoverTooltip"]
+
+
+@define(str=False, repr=False, init=False)
+class HoverTooltip(Archetype):
+    """
+    **Archetype**: Which components of an entity are shown when hovering it in a 2D or 3D view.
+
+    Log this as an override of the entity in a view, e.g. to only show `velocity` and `class`
+    instead of the default summary of all components.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(self: Any, *, shown_components: datatypes.Utf8ArrayLike | None = None) -> None:
+        """
+        Create a new instance of the HoverTooltip archetype.
+
+        Parameters
+        ----------
+        shown_components:
+            The components shown in the tooltip, in this order.
+
+            Either the full component identifiers or just the part after the archetype prefix,
+            e.g. `velocity`. Components the entity doesn't have are skipped.
+            If unset, the tooltip shows a summary of all components.
+
+        """
+
+        # You can define your own __init__ function as a member of HoverTooltipExt in hover_tooltip_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(shown_components=shown_components)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            shown_components=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> HoverTooltip:
+        """Produce an empty HoverTooltip, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        shown_components: datatypes.Utf8ArrayLike | None = None,
+    ) -> HoverTooltip:
+        """
+        Update only some specific fields of a `HoverTooltip`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        shown_components:
+            The components shown in the tooltip, in this order.
+
+            Either the full component identifiers or just the part after the archetype prefix,
+            e.g. `velocity`. Components the entity doesn't have are skipped.
+            If unset, the tooltip shows a summary of all components.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "shown_components": shown_components,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> HoverTooltip:
+        """Clear all the fields of a `HoverTooltip`."""
+        return cls.from_fields(clear_unset=True)
+
+    shown_components: components.NameBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.NameBatch._converter,  # type: ignore[misc]
+    )
+    # The components shown in the tooltip, in this order.
+    #
+    # Either the full component identifiers or just the part after the archetype prefix,
+    # e.g. `velocity`. Components the entity doesn't have are skipped.
+    # If unset, the tooltip shows a summary of all components.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,199 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/image_planes3d.fbs".
+
+# You can extend this class by creating a "ImagePlanes3DExt" class in "image_planes3d_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components, datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["ImagePlanes3D"]
+
+
+@define(str=False, repr=False, init=False)
+class ImagePlanes3D(Archetype):
+    """
+    **Archetype**: Configuration for the image planes of pinhole cameras in the 3D view.
+
+    2D content logged under a [`archetypes.Pinhole`][rerun.archetypes.Pinhole], e.g. images and 2D annotations,
+    is shown on the image plane at the end of the camera frustum.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        *,
+        image_plane_distance: datatypes.Float32Like | None = None,
+        opacity: datatypes.Float32Like | None = None,
+        show_back_faces: datatypes.BoolLike | None = None,
+        project_annotations: datatypes.BoolLike | None = None,
+    ) -> None:
+        """
+        Create a new instance of the ImagePlanes3D archetype.
+
+        Parameters
+        ----------
+        image_plane_distance:
+            Distance of the image plane from the camera, used for all cameras in the view.
+
+            If unset, each camera uses its own [`components.ImagePlaneDistance`][rerun.components.ImagePlaneDistance].
+        opacity:
+            Opacity of the images shown on the image planes.
+
+            This is multiplied with the opacity of each image.
+            Defaults to 1.0.
+        show_back_faces:
+            Whether the images are visible when looking at the image plane from behind.
+
+            Defaults to true.
+        project_annotations:
+            Whether 2D annotations, i.e. points, boxes, line strips and arrows, are shown on the image planes.
+
+            Defaults to true.
+
+        """
+
+        # You can define your own __init__ function as a member of ImagePlanes3DExt in image_planes3d_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(
+                image_plane_distance=image_plane_distance,
+                opacity=opacity,
+                show_back_faces=show_back_faces,
+                project_annotations=project_annotations,
+            )
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            image_plane_distance=None,
+            opacity=None,
+            show_back_faces=None,
+            project_annotations=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> ImagePlanes3D:
+        """Produce an empty ImagePlanes3D, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        image_plane_distance: datatypes.Float32Like | None = None,
+        opacity: datatypes.Float32Like | None = None,
+        show_back_faces: datatypes.BoolLike | None = None,
+        project_annotations: datatypes.BoolLike | None = None,
+    ) -> ImagePlanes3D:
+        """
+        Update only some specific fields of a `ImagePlanes3D`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        image_plane_distance:
+            Distance of the image plane from the camera, used for all cameras in the view.
+
+            If unset, each camera uses its own [`components.ImagePlaneDistance`][rerun.components.ImagePlaneDistance].
+        opacity:
+            Opacity of the images shown on the image planes.
+
+            This is multiplied with the opacity of each image.
+            Defaults to 1.0.
+        show_back_faces:
+            Whether the images are visible when looking at the image plane from behind.
+
+            Defaults to true.
+        project_annotations:
+            Whether 2D annotations, i.e. points, boxes, line strips and arrows, are shown on the image planes.
+
+            Defaults to true.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "image_plane_distance": image_plane_distance,
+                "opacity": opacity,
+                "show_back_faces": show_back_faces,
+                "project_annotations": project_annotations,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> ImagePlanes3D:
+        """Clear all the fields of a `ImagePlanes3D`."""
+        return cls.from_fields(clear_unset=True)
+
+    image_plane_distance: components.ImagePlaneDistanceBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ImagePlaneDistanceBatch._converter,  # type: ignore[misc]
+    )
+    # Distance of the image plane from the camera, used for all cameras in the view.
+    #
+    # If unset, each camera uses its own [`components.ImagePlaneDistance`][rerun.components.ImagePlaneDistance].
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    opacity: components.OpacityBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.OpacityBatch._converter,  # type: ignore[misc]
+    )
+    # Opacity of the images shown on the image planes.
+    #
+    # This is multiplied with the opacity of each image.
+    # Defaults to 1.0.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    show_back_faces: blueprint_components.EnabledBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.EnabledBatch._converter,  # type: ignore[misc]
+    )
+    # Whether the images are visible when looking at the image plane from behind.
+    #
+    # Defaults to true.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    project_annotations: blueprint_components.EnabledBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.EnabledBatch._converter,  # type: ignore[misc]
+    )
+    # Whether 2D annotations, i.e. points, boxes, line strips and arrows, are shown on the image planes.
+    #
+    # Defaults to true.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,151 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/instance_filter.fbs".
+
+# You can extend this class by creating a "InstanceFilterExt" class in "instance_filter_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components, datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["InstanceFilter"]
+
+
+@define(str=False, repr=False, init=False)
+class InstanceFilter(Archetype):
+    """
+    **Archetype**: Hides instances based on the value of a scalar component logged alongside them.
+
+    This is useful to threshold the output of a detector, e.g. by a `confidence` component,
+    without logging the data again.
+    The value of each instance is compared against the threshold at the current time.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        *,
+        component: datatypes.Utf8Like | None = None,
+        threshold: datatypes.Float64Like | None = None,
+    ) -> None:
+        """
+        Create a new instance of the InstanceFilter archetype.
+
+        Parameters
+        ----------
+        component:
+            The component whose values are compared against the threshold, e.g. `confidence`.
+
+            Either the full component identifier or just the part after the archetype prefix.
+            Entities that don't have this component are not filtered.
+            If unset, no instances are filtered.
+        threshold:
+            Instances with a value below this threshold are hidden.
+
+            Defaults to 0.5.
+
+        """
+
+        # You can define your own __init__ function as a member of InstanceFilterExt in instance_filter_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(component=component, threshold=threshold)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            component=None,
+            threshold=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> InstanceFilter:
+        """Produce an empty InstanceFilter, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        component: datatypes.Utf8Like | None = None,
+        threshold: datatypes.Float64Like | None = None,
+    ) -> InstanceFilter:
+        """
+        Update only some specific fields of a `InstanceFilter`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        component:
+            The component whose values are compared against the threshold, e.g. `confidence`.
+
+            Either the full component identifier or just the part after the archetype prefix.
+            Entities that don't have this component are not filtered.
+            If unset, no instances are filtered.
+        threshold:
+            Instances with a value below this threshold are hidden.
+
+            Defaults to 0.5.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "component": component,
+                "threshold": threshold,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> InstanceFilter:
+        """Clear all the fields of a `InstanceFilter`."""
+        return cls.from_fields(clear_unset=True)
+
+    component: components.NameBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.NameBatch._converter,  # type: ignore[misc]
+    )
+    # The component whose values are compared against the threshold, e.g. `confidence`.
+    #
+    # Either the full component identifier or just the part after the archetype prefix.
+    # Entities that don't have this component are not filtered.
+    # If unset, no instances are filtered.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    threshold: components.ScalarBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ScalarBatch._converter,  # type: ignore[misc]
+    )
+    # Instances with a value below this threshold are hidden.
+    #
+    # Defaults to 0.5.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
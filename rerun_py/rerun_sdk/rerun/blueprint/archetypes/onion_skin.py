@@ -0,0 +1,152 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/onion_skin.fbs".
+
+# You can extend this class by creating a "OnionSkinExt" class in "onion_skin_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components, datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["OnionSkin"]
+
+
+@define(str=False, repr=False, init=False)
+class OnionSkin(Archetype):
+    """
+    **Archetype**: Configuration for the onion skin of a spatial view.
+
+    The onion skin shows the earlier states of entities as translucent ghosts,
+    e.g. to review the jitter of a pose estimate or how a robot moved.
+    It is supported for points, line strips, arrows and 2D boxes.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        *,
+        steps: datatypes.UInt64Like | None = None,
+        opacity: datatypes.Float32Like | None = None,
+    ) -> None:
+        """
+        Create a new instance of the OnionSkin archetype.
+
+        Parameters
+        ----------
+        steps:
+            How many earlier states of each entity are shown.
+
+            Every time an entity's data or its transform changed counts as a step.
+            Defaults to 0, i.e. no onion skin.
+        opacity:
+            Opacity of the most recent earlier state.
+
+            Older states fade out further.
+            Defaults to 0.5.
+
+        """
+
+        # You can define your own __init__ function as a member of OnionSkinExt in onion_skin_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(steps=steps, opacity=opacity)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            steps=None,
+            opacity=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> OnionSkin:
+        """Produce an empty OnionSkin, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        steps: datatypes.UInt64Like | None = None,
+        opacity: datatypes.Float32Like | None = None,
+    ) -> OnionSkin:
+        """
+        Update only some specific fields of a `OnionSkin`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        steps:
+            How many earlier states of each entity are shown.
+
+            Every time an entity's data or its transform changed counts as a step.
+            Defaults to 0, i.e. no onion skin.
+        opacity:
+            Opacity of the most recent earlier state.
+
+            Older states fade out further.
+            Defaults to 0.5.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "steps": steps,
+                "opacity": opacity,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> OnionSkin:
+        """Clear all the fields of a `OnionSkin`."""
+        return cls.from_fields(clear_unset=True)
+
+    steps: blueprint_components.OnionSkinStepsBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.OnionSkinStepsBatch._converter,  # type: ignore[misc]
+    )
+    # How many earlier states of each entity are shown.
+    #
+    # Every time an entity's data or its transform changed counts as a step.
+    # Defaults to 0, i.e. no onion skin.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    opacity: components.OpacityBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.OpacityBatch._converter,  # type: ignore[misc]
+    )
+    # Opacity of the most recent earlier state.
+    #
+    # Older states fade out further.
+    # Defaults to 0.5.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,222 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/outlines.fbs".
+
+# You can extend this class by creating a "OutlinesExt" class in "outlines_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components, datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["Outlines"]
+
+
+@define(str=False, repr=False, init=False)
+class Outlines(Archetype):
+    """
+    **Archetype**: Configuration for the outlines of hovered, selected and highlighted entities in a spatial view.
+
+    Highlighted entities are always outlined, e.g. to mark the ground truth in a scene.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        *,
+        hover_color: datatypes.Rgba32Like | None = None,
+        selection_color: datatypes.Rgba32Like | None = None,
+        stroke_width: datatypes.Float32Like | None = None,
+        highlighted: datatypes.Utf8ArrayLike | None = None,
+        highlight_color: datatypes.Rgba32Like | None = None,
+    ) -> None:
+        """
+        Create a new instance of the Outlines archetype.
+
+        Parameters
+        ----------
+        hover_color:
+            Color of the outline of hovered objects.
+
+            Defaults to the hover color of the viewer.
+        selection_color:
+            Color of the outline of selected objects.
+
+            Defaults to the selection color of the viewer.
+        stroke_width:
+            How thick the outlines are in ui units.
+
+            Defaults to the thickness of hover & selection strokes elsewhere in the viewer.
+        highlighted:
+            Query expressions of the entities that are always outlined, e.g. `+ /world/ground_truth/**`.
+
+            Uses the same syntax as the contents of the view.
+            While any entity is highlighted, hovered objects are outlined with the selection color.
+        highlight_color:
+            Color of the outline of highlighted entities.
+
+            Defaults to green.
+
+        """
+
+        # You can define your own __init__ function as a member of OutlinesExt in outlines_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(
+                hover_color=hover_color,
+                selection_color=selection_color,
+                stroke_width=stroke_width,
+                highlighted=highlighted,
+                highlight_color=highlight_color,
+            )
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            hover_color=None,
+            selection_color=None,
+            stroke_width=None,
+            highlighted=None,
+            highlight_color=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> Outlines:
+        """Produce an empty Outlines, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        hover_color: datatypes.Rgba32Like | None = None,
+        selection_color: datatypes.Rgba32Like | None = None,
+        stroke_width: datatypes.Float32Like | None = None,
+        highlighted: datatypes.Utf8ArrayLike | None = None,
+        highlight_color: datatypes.Rgba32Like | None = None,
+    ) -> Outlines:
+        """
+        Update only some specific fields of a `Outlines`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        hover_color:
+            Color of the outline of hovered objects.
+
+            Defaults to the hover color of the viewer.
+        selection_color:
+            Color of the outline of selected objects.
+
+            Defaults to the selection color of the viewer.
+        stroke_width:
+            How thick the outlines are in ui units.
+
+            Defaults to the thickness of hover & selection strokes elsewhere in the viewer.
+        highlighted:
+            Query expressions of the entities that are always outlined, e.g. `+ /world/ground_truth/**`.
+
+            Uses the same syntax as the contents of the view.
+            While any entity is highlighted, hovered objects are outlined with the selection color.
+        highlight_color:
+            Color of the outline of highlighted entities.
+
+            Defaults to green.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "hover_color": hover_color,
+                "selection_color": selection_color,
+                "stroke_width": stroke_width,
+                "highlighted": highlighted,
+                "highlight_color": highlight_color,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> Outlines:
+        """Clear all the fields of a `Outlines`."""
+        return cls.from_fields(clear_unset=True)
+
+    hover_color: components.ColorBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ColorBatch._converter,  # type: ignore[misc]
+    )
+    # Color of the outline of hovered objects.
+    #
+    # Defaults to the hover color of the viewer.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    selection_color: components.ColorBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ColorBatch._converter,  # type: ignore[misc]
+    )
+    # Color of the outline of selected objects.
+    #
+    # Defaults to the selection color of the viewer.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    stroke_width: components.StrokeWidthBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.StrokeWidthBatch._converter,  # type: ignore[misc]
+    )
+    # How thick the outlines are in ui units.
+    #
+    # Defaults to the thickness of hover & selection strokes elsewhere in the viewer.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    highlighted: blueprint_components.QueryExpressionBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.QueryExpressionBatch._converter,  # type: ignore[misc]
+    )
+    # Query expressions of the entities that are always outlined, e.g. `+ /world/ground_truth/**`.
+    #
+    # Uses the same syntax as the contents of the view.
+    # While any entity is highlighted, hovered objects are outlined with the selection color.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    highlight_color: components.ColorBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ColorBatch._converter,  # type: ignore[misc]
+    )
+    # Color of the outline of highlighted entities.
+    #
+    # Defaults to green.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
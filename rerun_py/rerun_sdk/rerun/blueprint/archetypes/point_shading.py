@@ -0,0 +1,124 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/point_shading.fbs".
+
+# You can extend this class by creating a "PointShadingExt" class in "point_shading_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["PointShading"]
+
+
+@define(str=False, repr=False, init=False)
+class PointShading(Archetype):
+    """
+    **Archetype**: Configures how point clouds are shaded in a 3D view.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(self: Any, *, estimate_normals: datatypes.BoolLike | None = None) -> None:
+        """
+        Create a new instance of the PointShading archetype.
+
+        Parameters
+        ----------
+        estimate_normals:
+            Whether normals are estimated for point clouds, and used to light them.
+
+            The normal of each point is fitted to its nearest neighbors on the GPU, which brings out
+            the shape of scanned surfaces without having to compute normals offline.
+            This is redone every frame, so it can be slow for very large point clouds.
+            Defaults to false.
+
+        """
+
+        # You can define your own __init__ function as a member of PointShadingExt in point_shading_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(estimate_normals=estimate_normals)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            estimate_normals=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> PointShading:
+        """Produce an empty PointShading, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        estimate_normals: datatypes.BoolLike | None = None,
+    ) -> PointShading:
+        """
+        Update only some specific fields of a `PointShading`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        estimate_normals:
+            Whether normals are estimated for point clouds, and used to light them.
+
+            The normal of each point is fitted to its nearest neighbors on the GPU, which brings out
+            the shape of scanned surfaces without having to compute normals offline.
+            This is redone every frame, so it can be slow for very large point clouds.
+            Defaults to false.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "estimate_normals": estimate_normals,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> PointShading:
+        """Clear all the fields of a `PointShading`."""
+        return cls.from_fields(clear_unset=True)
+
+    estimate_normals: blueprint_components.EnabledBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.EnabledBatch._converter,  # type: ignore[misc]
+    )
+    # Whether normals are estimated for point clouds, and used to light them.
+    #
+    # The normal of each point is fitted to its nearest neighbors on the GPU, which brings out
+    # the shape of scanned surfaces without having to compute normals offline.
+    # This is redone every frame, so it can be slow for very large point clouds.
+    # Defaults to false.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,213 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/rigid_alignment.fbs".
+
+# You can extend this class by creating a "RigidAlignmentExt" class in "rigid_alignment_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components, datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["RigidAlignment"]
+
+
+@define(str=False, repr=False, init=False)
+class RigidAlignment(Archetype):
+    """
+    **Archetype**: Moves an entity onto another one with a rigid transform.
+
+    Log this as an override of the entity in a 3D view, e.g. to compare a reconstruction with its ground truth.
+    The transform is only applied in that view, on top of the transforms logged for the entity.
+    If neither translation nor rotation is set, the viewer fits the transform that best aligns
+    the points or line strips of the entity with those of the reference, and fills them in.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        *,
+        reference: datatypes.EntityPathLike | None = None,
+        match_closest: datatypes.BoolLike | None = None,
+        translation: datatypes.Vec3DLike | None = None,
+        quaternion: datatypes.QuaternionLike | None = None,
+        rmse: datatypes.Float32Like | None = None,
+    ) -> None:
+        """
+        Create a new instance of the RigidAlignment archetype.
+
+        Parameters
+        ----------
+        reference:
+            The entity to align to, e.g. the ground truth.
+
+            Has to be shown in the same view.
+        match_closest:
+            Whether to match each point with the closest point of the reference (ICP).
+
+            Otherwise points are matched by index, which requires both entities to have the same
+            number of points, e.g. two trajectories sampled at the same times.
+        translation:
+            The translation of the fitted transform, in the space of the entity.
+        quaternion:
+            The rotation of the fitted transform, in the space of the entity.
+        rmse:
+            The root mean square distance between the matched points after the fit.
+
+            Set by the viewer when it fits the transform.
+
+        """
+
+        # You can define your own __init__ function as a member of RigidAlignmentExt in rigid_alignment_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(
+                reference=reference,
+                match_closest=match_closest,
+                translation=translation,
+                quaternion=quaternion,
+                rmse=rmse,
+            )
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            reference=None,
+            match_closest=None,
+            translation=None,
+            quaternion=None,
+            rmse=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> RigidAlignment:
+        """Produce an empty RigidAlignment, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        reference: datatypes.EntityPathLike | None = None,
+        match_closest: datatypes.BoolLike | None = None,
+        translation: datatypes.Vec3DLike | None = None,
+        quaternion: datatypes.QuaternionLike | None = None,
+        rmse: datatypes.Float32Like | None = None,
+    ) -> RigidAlignment:
+        """
+        Update only some specific fields of a `RigidAlignment`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        reference:
+            The entity to align to, e.g. the ground truth.
+
+            Has to be shown in the same view.
+        match_closest:
+            Whether to match each point with the closest point of the reference (ICP).
+
+            Otherwise points are matched by index, which requires both entities to have the same
+            number of points, e.g. two trajectories sampled at the same times.
+        translation:
+            The translation of the fitted transform, in the space of the entity.
+        quaternion:
+            The rotation of the fitted transform, in the space of the entity.
+        rmse:
+            The root mean square distance between the matched points after the fit.
+
+            Set by the viewer when it fits the transform.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "reference": reference,
+                "match_closest": match_closest,
+                "translation": translation,
+                "quaternion": quaternion,
+                "rmse": rmse,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> RigidAlignment:
+        """Clear all the fields of a `RigidAlignment`."""
+        return cls.from_fields(clear_unset=True)
+
+    reference: components.EntityPathBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.EntityPathBatch._converter,  # type: ignore[misc]
+    )
+    # The entity to align to, e.g. the ground truth.
+    #
+    # Has to be shown in the same view.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    match_closest: blueprint_components.EnabledBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.EnabledBatch._converter,  # type: ignore[misc]
+    )
+    # Whether to match each point with the closest point of the reference (ICP).
+    #
+    # Otherwise points are matched by index, which requires both entities to have the same
+    # number of points, e.g. two trajectories sampled at the same times.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    translation: components.Translation3DBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.Translation3DBatch._converter,  # type: ignore[misc]
+    )
+    # The translation of the fitted transform, in the space of the entity.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    quaternion: components.RotationQuatBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.RotationQuatBatch._converter,  # type: ignore[misc]
+    )
+    # The rotation of the fitted transform, in the space of the entity.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    rmse: components.LengthBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.LengthBatch._converter,  # type: ignore[misc]
+    )
+    # The root mean square distance between the matched points after the fit.
+    #
+    # Set by the viewer when it fits the transform.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
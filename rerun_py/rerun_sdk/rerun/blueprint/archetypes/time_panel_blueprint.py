@@ -9,6 +9,7 @@ from typing import TYPE_CHECKING, Any
 
 from attrs import define, field
 
+from ... import components
 from ..._baseclasses import (
     Archetype,
 )
@@ -39,6 +40,7 @@ class TimePanelBlueprint(Archetype):
         play_state: blueprint_components.PlayStateLike | None = None,
         loop_mode: blueprint_components.LoopModeLike | None = None,
         time_selection: datatypes.AbsoluteTimeRangeLike | None = None,
+        expanded_entities: datatypes.EntityPathArrayLike | None = None,
     ) -> None:
         """
         Create a new instance of the TimePanelBlueprint archetype.
@@ -63,6 +65,11 @@ class TimePanelBlueprint(Archetype):
             Defaults to off.
         time_selection:
             Selects a range of time on the time panel.
+        expanded_entities:
+            The entities whose rows are expanded in the streams tree of the time panel.
+
+            Collapsed rows show the combined data density of their whole subtree.
+            If not set, the viewer picks which rows to expand.
 
         """
 
@@ -76,6 +83,7 @@ class TimePanelBlueprint(Archetype):
                 play_state=play_state,
                 loop_mode=loop_mode,
                 time_selection=time_selection,
+                expanded_entities=expanded_entities,
             )
             return
         self.__attrs_clear__()
@@ -90,6 +98,7 @@ class TimePanelBlueprint(Archetype):
             play_state=None,
             loop_mode=None,
             time_selection=None,
+            expanded_entities=None,
         )
 
     @classmethod
@@ -111,6 +120,7 @@ class TimePanelBlueprint(Archetype):
         play_state: blueprint_components.PlayStateLike | None = None,
         loop_mode: blueprint_components.LoopModeLike | None = None,
         time_selection: datatypes.AbsoluteTimeRangeLike | None = None,
+        expanded_entities: datatypes.EntityPathArrayLike | None = None,
     ) -> TimePanelBlueprint:
         """
         Update only some specific fields of a `TimePanelBlueprint`.
@@ -137,6 +147,11 @@ class TimePanelBlueprint(Archetype):
             Defaults to off.
         time_selection:
             Selects a range of time on the time panel.
+        expanded_entities:
+            The entities whose rows are expanded in the streams tree of the time panel.
+
+            Collapsed rows show the combined data density of their whole subtree.
+            If not set, the viewer picks which rows to expand.
 
         """
 
@@ -150,6 +165,7 @@ class TimePanelBlueprint(Archetype):
                 "play_state": play_state,
                 "loop_mode": loop_mode,
                 "time_selection": time_selection,
+                "expanded_entities": expanded_entities,
             }
 
             if clear_unset:
@@ -233,5 +249,17 @@ class TimePanelBlueprint(Archetype):
     #
     # (Docstring intentionally commented out to hide this field from the docs)
 
+    expanded_entities: components.EntityPathBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.EntityPathBatch._converter,  # type: ignore[misc]
+    )
+    # The entities whose rows are expanded in the streams tree of the time panel.
+    #
+    # Collapsed rows show the combined data density of their whole subtree.
+    # If not set, the viewer picks which rows to expand.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
     __str__ = Archetype.__str__
     __repr__ = Archetype.__repr__  # type: ignore[assignment]
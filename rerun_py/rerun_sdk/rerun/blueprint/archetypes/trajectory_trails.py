@@ -0,0 +1,168 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/trajectory_trails.fbs".
+
+# You can extend this class by creating a "TrajectoryTrailsExt" class in "trajectory_trails_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["TrajectoryTrails"]
+
+
+@define(str=False, repr=False, init=False)
+class TrajectoryTrails(Archetype):
+    """
+    **Archetype**: Configuration for the trajectory trails in the 3D view.
+
+    Trails show the past positions of entities, and are enabled per entity
+    by adding the `Trajectory` visualizer to it.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        *,
+        time_range: datatypes.TimeRangeLike | None = None,
+        fade: datatypes.BoolLike | None = None,
+        color_by_time: datatypes.BoolLike | None = None,
+    ) -> None:
+        """
+        Create a new instance of the TrajectoryTrails archetype.
+
+        Parameters
+        ----------
+        time_range:
+            The time range of past positions that is drawn, usually relative to the time cursor.
+
+            Defaults to the 10 seconds before the time cursor, or to the 100 steps before it on sequence timelines.
+        fade:
+            Whether older parts of the trails are drawn thinner.
+
+            Defaults to true.
+        color_by_time:
+            Whether the trails are colored by time instead of with the color of their entity.
+
+            Defaults to false.
+
+        """
+
+        # You can define your own __init__ function as a member of TrajectoryTrailsExt in trajectory_trails_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(time_range=time_range, fade=fade, color_by_time=color_by_time)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            time_range=None,
+            fade=None,
+            color_by_time=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> TrajectoryTrails:
+        """Produce an empty TrajectoryTrails, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        time_range: datatypes.TimeRangeLike | None = None,
+        fade: datatypes.BoolLike | None = None,
+        color_by_time: datatypes.BoolLike | None = None,
+    ) -> TrajectoryTrails:
+        """
+        Update only some specific fields of a `TrajectoryTrails`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        time_range:
+            The time range of past positions that is drawn, usually relative to the time cursor.
+
+            Defaults to the 10 seconds before the time cursor, or to the 100 steps before it on sequence timelines.
+        fade:
+            Whether older parts of the trails are drawn thinner.
+
+            Defaults to true.
+        color_by_time:
+            Whether the trails are colored by time instead of with the color of their entity.
+
+            Defaults to false.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "time_range": time_range,
+                "fade": fade,
+                "color_by_time": color_by_time,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> TrajectoryTrails:
+        """Clear all the fields of a `TrajectoryTrails`."""
+        return cls.from_fields(clear_unset=True)
+
+    time_range: blueprint_components.TimeRangeBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.TimeRangeBatch._converter,  # type: ignore[misc]
+    )
+    # The time range of past positions that is drawn, usually relative to the time cursor.
+    #
+    # Defaults to the 10 seconds before the time cursor, or to the 100 steps before it on sequence timelines.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    fade: blueprint_components.EnabledBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.EnabledBatch._converter,  # type: ignore[misc]
+    )
+    # Whether older parts of the trails are drawn thinner.
+    #
+    # Defaults to true.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    color_by_time: blueprint_components.EnabledBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.EnabledBatch._converter,  # type: ignore[misc]
+    )
+    # Whether the trails are colored by time instead of with the color of their entity.
+    #
+    # Defaults to false.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,227 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/visibility_rules.fbs".
+
+# You can extend this class by creating a "VisibilityRulesExt" class in "visibility_rules_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components, datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["VisibilityRules"]
+
+
+@define(str=False, repr=False, init=False)
+class VisibilityRules(Archetype):
+    """
+    **Archetype**: Rules under which a view or an entity in a view is shown, evaluated every frame.
+
+    Logged as a property of a view, the rules show or hide the whole view.
+    Logged as an override of an entity in a view, they show or hide that entity and its children.
+    As soon as any of the rules isn't met, the view or entity is hidden.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        *,
+        timeline: datatypes.Utf8Like | None = None,
+        time_range: datatypes.AbsoluteTimeRangeLike | None = None,
+        scalar_entity: datatypes.EntityPathLike | None = None,
+        scalar_component: datatypes.Utf8Like | None = None,
+        threshold: datatypes.Float64Like | None = None,
+    ) -> None:
+        """
+        Create a new instance of the VisibilityRules archetype.
+
+        Parameters
+        ----------
+        timeline:
+            The timeline that `time_range` refers to.
+
+            If unset, `time_range` applies to whichever timeline is active.
+        time_range:
+            Only shown while the time cursor is within this range.
+
+            If unset, shown at all times.
+        scalar_entity:
+            The entity whose scalar value is compared against `threshold`.
+
+            If unset, shown regardless of any scalar value.
+        scalar_component:
+            The component of `scalar_entity` that is compared against `threshold`.
+
+            Either the full component identifier or just the part after the archetype prefix.
+            Defaults to the `scalars` of a `Scalars` archetype.
+        threshold:
+            Only shown while the latest value of the scalar is at least this threshold.
+
+            Shown if the scalar has no value at the current time.
+            Defaults to 0.0.
+
+        """
+
+        # You can define your own __init__ function as a member of VisibilityRulesExt in visibility_rules_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(
+                timeline=timeline,
+                time_range=time_range,
+                scalar_entity=scalar_entity,
+                scalar_component=scalar_component,
+                threshold=threshold,
+            )
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            timeline=None,
+            time_range=None,
+            scalar_entity=None,
+            scalar_component=None,
+            threshold=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> VisibilityRules:
+        """Produce an empty VisibilityRules, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        timeline: datatypes.Utf8Like | None = None,
+        time_range: datatypes.AbsoluteTimeRangeLike | None = None,
+        scalar_entity: datatypes.EntityPathLike | None = None,
+        scalar_component: datatypes.Utf8Like | None = None,
+        threshold: datatypes.Float64Like | None = None,
+    ) -> VisibilityRules:
+        """
+        Update only some specific fields of a `VisibilityRules`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        timeline:
+            The timeline that `time_range` refers to.
+
+            If unset, `time_range` applies to whichever timeline is active.
+        time_range:
+            Only shown while the time cursor is within this range.
+
+            If unset, shown at all times.
+        scalar_entity:
+            The entity whose scalar value is compared against `threshold`.
+
+            If unset, shown regardless of any scalar value.
+        scalar_component:
+            The component of `scalar_entity` that is compared against `threshold`.
+
+            Either the full component identifier or just the part after the archetype prefix.
+            Defaults to the `scalars` of a `Scalars` archetype.
+        threshold:
+            Only shown while the latest value of the scalar is at least this threshold.
+
+            Shown if the scalar has no value at the current time.
+            Defaults to 0.0.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "timeline": timeline,
+                "time_range": time_range,
+                "scalar_entity": scalar_entity,
+                "scalar_component": scalar_component,
+                "threshold": threshold,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> VisibilityRules:
+        """Clear all the fields of a `VisibilityRules`."""
+        return cls.from_fields(clear_unset=True)
+
+    timeline: blueprint_components.TimelineNameBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.TimelineNameBatch._converter,  # type: ignore[misc]
+    )
+    # The timeline that `time_range` refers to.
+    #
+    # If unset, `time_range` applies to whichever timeline is active.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    time_range: blueprint_components.AbsoluteTimeRangeBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.AbsoluteTimeRangeBatch._converter,  # type: ignore[misc]
+    )
+    # Only shown while the time cursor is within this range.
+    #
+    # If unset, shown at all times.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    scalar_entity: components.EntityPathBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.EntityPathBatch._converter,  # type: ignore[misc]
+    )
+    # The entity whose scalar value is compared against `threshold`.
+    #
+    # If unset, shown regardless of any scalar value.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    scalar_component: components.NameBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.NameBatch._converter,  # type: ignore[misc]
+    )
+    # The component of `scalar_entity` that is compared against `threshold`.
+    #
+    # Either the full component identifier or just the part after the archetype prefix.
+    # Defaults to the `scalars` of a `Scalars` archetype.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    threshold: components.ScalarBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ScalarBatch._converter,  # type: ignore[misc]
+    )
+    # Only shown while the latest value of the scalar is at least this threshold.
+    #
+    # Shown if the scalar has no value at the current time.
+    # Defaults to 0.0.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
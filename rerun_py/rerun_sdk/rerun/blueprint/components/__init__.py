@@ -9,12 +9,22 @@ from .apply_latest_at import ApplyLatestAt, ApplyLatestAtBatch
 from .auto_layout import AutoLayout, AutoLayoutBatch
 from .auto_views import AutoViews, AutoViewsBatch
 from .background_kind import BackgroundKind, BackgroundKindArrayLike, BackgroundKindBatch, BackgroundKindLike
+from .bar_chart_mode import BarChartMode, BarChartModeArrayLike, BarChartModeBatch, BarChartModeLike
 from .column_share import ColumnShare, ColumnShareBatch
 from .component_column_selector import ComponentColumnSelector, ComponentColumnSelectorBatch
 from .container_kind import ContainerKind, ContainerKindArrayLike, ContainerKindBatch, ContainerKindLike
 from .corner2d import Corner2D, Corner2DArrayLike, Corner2DBatch, Corner2DLike
+from .depth_cloud_subsampling import DepthCloudSubsampling, DepthCloudSubsamplingBatch
 from .enabled import Enabled, EnabledBatch
 from .eye3d_kind import Eye3DKind, Eye3DKindArrayLike, Eye3DKindBatch, Eye3DKindLike
+from .eye3d_projection import (
+    Eye3DProjection,
+    Eye3DProjectionArrayLike,
+    Eye3DProjectionBatch,
+    Eye3DProjectionLike,
+)
+from .fft_window_overlap import FftWindowOverlap, FftWindowOverlapBatch
+from .fft_window_size import FftWindowSize, FftWindowSizeBatch
 from .filter_by_range import FilterByRange, FilterByRangeBatch
 from .filter_is_not_null import FilterIsNotNull, FilterIsNotNullBatch
 from .force_distance import ForceDistance, ForceDistanceBatch
@@ -29,6 +39,7 @@ from .lock_range_during_zoom import LockRangeDuringZoom, LockRangeDuringZoomBatc
 from .loop_mode import LoopMode, LoopModeArrayLike, LoopModeBatch, LoopModeLike
 from .map_provider import MapProvider, MapProviderArrayLike, MapProviderBatch, MapProviderLike
 from .near_clip_plane import NearClipPlane, NearClipPlaneBatch
+from .onion_skin_steps import OnionSkinSteps, OnionSkinStepsBatch
 from .panel_state import PanelState, PanelStateArrayLike, PanelStateBatch, PanelStateLike
 from .play_state import PlayState, PlayStateArrayLike, PlayStateBatch, PlayStateLike
 from .playback_speed import PlaybackSpeed, PlaybackSpeedBatch
@@ -67,6 +78,10 @@ __all__ = [
     "BackgroundKindArrayLike",
     "BackgroundKindBatch",
     "BackgroundKindLike",
+    "BarChartMode",
+    "BarChartModeArrayLike",
+    "BarChartModeBatch",
+    "BarChartModeLike",
     "ColumnShare",
     "ColumnShareBatch",
     "ComponentColumnSelector",
@@ -79,12 +94,22 @@ __all__ = [
     "Corner2DArrayLike",
     "Corner2DBatch",
     "Corner2DLike",
+    "DepthCloudSubsampling",
+    "DepthCloudSubsamplingBatch",
     "Enabled",
     "EnabledBatch",
     "Eye3DKind",
     "Eye3DKindArrayLike",
     "Eye3DKindBatch",
     "Eye3DKindLike",
+    "Eye3DProjection",
+    "Eye3DProjectionArrayLike",
+    "Eye3DProjectionBatch",
+    "Eye3DProjectionLike",
+    "FftWindowOverlap",
+    "FftWindowOverlapBatch",
+    "FftWindowSize",
+    "FftWindowSizeBatch",
     "FilterByRange",
     "FilterByRangeBatch",
     "FilterIsNotNull",
@@ -119,6 +144,8 @@ __all__ = [
     "MapProviderLike",
     "NearClipPlane",
     "NearClipPlaneBatch",
+    "OnionSkinSteps",
+    "OnionSkinStepsBatch",
     "PanelState",
     "PanelStateArrayLike",
     "PanelStateBatch",
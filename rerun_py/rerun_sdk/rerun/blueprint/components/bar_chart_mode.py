@@ -0,0 +1,82 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/bar_chart_mode.fbs".
+
+# You can extend this class by creating a "BarChartModeExt" class in "bar_chart_mode_ext.py".
+
+from __future__ import annotations
+
+from collections.abc import Sequence
+from typing import Literal
+
+import pyarrow as pa
+
+from ..._baseclasses import (
+    BaseBatch,
+    ComponentBatchMixin,
+)
+
+__all__ = ["BarChartMode", "BarChartModeArrayLike", "BarChartModeBatch", "BarChartModeLike"]
+
+
+from enum import Enum
+
+
+class BarChartMode(Enum):
+    """**Component**: How the bars of several bar charts in the same view are arranged."""
+
+    Overlapping = 1
+    """The bars of all entities are drawn on top of each other."""
+
+    Grouped = 2
+    """The bars of different entities at the same position are drawn next to each other."""
+
+    Stacked = 3
+    """The bars of different entities at the same position are stacked on top of each other."""
+
+    @classmethod
+    def auto(cls, val: str | int | BarChartMode) -> BarChartMode:
+        """Best-effort converter, including a case-insensitive string matcher."""
+        if isinstance(val, BarChartMode):
+            return val
+        if isinstance(val, int):
+            return cls(val)
+        try:
+            return cls[val]
+        except KeyError:
+            val_lower = val.lower()
+            for variant in cls:
+                if variant.name.lower() == val_lower:
+                    return variant
+        raise ValueError(f"Cannot convert {val} to {cls.__name__}")
+
+    def __str__(self) -> str:
+        """Returns the variant name."""
+        return self.name
+
+
+BarChartModeLike = (
+    BarChartMode | Literal["Grouped", "Overlapping", "Stacked", "grouped", "overlapping", "stacked"] | int
+)
+"""A type alias for any BarChartMode-like object."""
+
+BarChartModeArrayLike = (
+    BarChartMode
+    | Literal["Grouped", "Overlapping", "Stacked", "grouped", "overlapping", "stacked"]
+    | int
+    | Sequence[BarChartModeLike]
+)
+"""A type alias for any BarChartMode-like array object."""
+
+
+class BarChartModeBatch(BaseBatch[BarChartModeArrayLike], ComponentBatchMixin):
+    _ARROW_DATATYPE = pa.uint8()
+    _COMPONENT_TYPE: str = "rerun.blueprint.components.BarChartMode"
+
+    @staticmethod
+    def _native_to_pa_array(data: BarChartModeArrayLike, data_type: pa.DataType) -> pa.Array:
+        if isinstance(data, (BarChartMode, int, str)):
+            data = [data]
+
+        pa_data = [BarChartMode.auto(v).value if v is not None else None for v in data]  # type: ignore[redundant-expr]
+
+        return pa.array(pa_data, type=data_type)
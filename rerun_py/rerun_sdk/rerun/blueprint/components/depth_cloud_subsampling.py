@@ -0,0 +1,35 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/depth_cloud_subsampling.fbs".
+
+# You can extend this class by creating a "DepthCloudSubsamplingExt" class in "depth_cloud_subsampling_ext.py".
+
+from __future__ import annotations
+
+from ... import datatypes
+from ..._baseclasses import (
+    ComponentBatchMixin,
+    ComponentMixin,
+)
+
+__all__ = ["DepthCloudSubsampling", "DepthCloudSubsamplingBatch"]
+
+
+class DepthCloudSubsampling(datatypes.UInt64, ComponentMixin):
+    """
+    **Component**: Only every n-th pixel of a depth image, in both directions, is turned into a point of its point cloud.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of DepthCloudSubsamplingExt in depth_cloud_subsampling_ext.py
+
+    # Note: there are no fields here because DepthCloudSubsampling delegates to datatypes.UInt64
+
+
+class DepthCloudSubsamplingBatch(datatypes.UInt64Batch, ComponentBatchMixin):
+    _COMPONENT_TYPE: str = "rerun.blueprint.components.DepthCloudSubsampling"
+
+
+# This is patched in late to avoid circular dependencies.
+DepthCloudSubsampling._BATCH_TYPE = DepthCloudSubsamplingBatch  # type: ignore[assignment]
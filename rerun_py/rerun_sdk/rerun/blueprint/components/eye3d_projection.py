@@ -0,0 +1,88 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/eye3d_projection.fbs".
+
+# You can extend this class by creating a "Eye3DProjectionExt" class in "eye3d_projection_ext.py".
+
+from __future__ import annotations
+
+from collections.abc import Sequence
+from typing import Literal
+
+import pyarrow as pa
+
+from ..._baseclasses import (
+    BaseBatch,
+    ComponentBatchMixin,
+)
+
+__all__ = ["Eye3DProjection", "Eye3DProjectionArrayLike", "Eye3DProjectionBatch", "Eye3DProjectionLike"]
+
+
+from enum import Enum
+
+
+class Eye3DProjection(Enum):
+    """
+    **Component**: The projection of the 3D eye to view a scene in a [`views.Spatial3DView`][rerun.blueprint.views.Spatial3DView].
+    """
+
+    Perspective = 1
+    """
+    Perspective projection.
+
+    Objects further away from the eye appear smaller, as with a real camera.
+    """
+
+    Orthographic = 2
+    """
+    Orthographic projection.
+
+    Parallel lines stay parallel and objects keep their size regardless of their distance to the eye,
+    which makes it possible to compare and measure lengths on screen.
+    """
+
+    @classmethod
+    def auto(cls, val: str | int | Eye3DProjection) -> Eye3DProjection:
+        """Best-effort converter, including a case-insensitive string matcher."""
+        if isinstance(val, Eye3DProjection):
+            return val
+        if isinstance(val, int):
+            return cls(val)
+        try:
+            return cls[val]
+        except KeyError:
+            val_lower = val.lower()
+            for variant in cls:
+                if variant.name.lower() == val_lower:
+                    return variant
+        raise ValueError(f"Cannot convert {val} to {cls.__name__}")
+
+    def __str__(self) -> str:
+        """Returns the variant name."""
+        return self.name
+
+
+Eye3DProjectionLike = Eye3DProjection | Literal["Orthographic", "Perspective", "orthographic", "perspective"] | int
+"""A type alias for any Eye3DProjection-like object."""
+
+Eye3DProjectionArrayLike = (
+    Eye3DProjection
+    | Literal["Orthographic", "Perspective", "orthographic", "perspective"]
+    | int
+    | Sequence[Eye3DProjectionLike]
+)
+"""A type alias for any Eye3DProjection-like array object."""
+
+
+class Eye3DProjectionBatch(BaseBatch[Eye3DProjectionArrayLike], ComponentBatchMixin):
+    _ARROW_DATATYPE = pa.uint8()
+    _COMPONENT_TYPE: str = "rerun.blueprint.components.Eye3DProjection"
+
+    @staticmethod
+    def _native_to_pa_array(data: Eye3DProjectionArrayLike, data_type: pa.DataType) -> pa.Array:
+        if isinstance(data, (Eye3DProjection, int, str)):
+            data = [data]
+
+        pa_data = [Eye3DProjection.auto(v).value if v is not None else None for v in data]  # type: ignore[redundant-expr]
+
+        return pa.array(pa_data, type=data_type)
@@ -0,0 +1,35 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/fft_window_overlap.fbs".
+
+# You can extend this class by creating a "FftWindowOverlapExt" class in "fft_window_overlap_ext.py".
+
+from __future__ import annotations
+
+from ... import datatypes
+from ..._baseclasses import (
+    ComponentBatchMixin,
+    ComponentMixin,
+)
+
+__all__ = ["FftWindowOverlap", "FftWindowOverlapBatch"]
+
+
+class FftWindowOverlap(datatypes.Float32, ComponentMixin):
+    """
+    **Component**: How much consecutive windows of a rolling FFT overlap, e.g. of a `SpectrogramView`.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of FftWindowOverlapExt in fft_window_overlap_ext.py
+
+    # Note: there are no fields here because FftWindowOverlap delegates to datatypes.Float32
+
+
+class FftWindowOverlapBatch(datatypes.Float32Batch, ComponentBatchMixin):
+    _COMPONENT_TYPE: str = "rerun.blueprint.components.FftWindowOverlap"
+
+
+# This is patched in late to avoid circular dependencies.
+FftWindowOverlap._BATCH_TYPE = FftWindowOverlapBatch  # type: ignore[assignment]
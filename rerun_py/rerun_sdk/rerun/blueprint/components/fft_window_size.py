@@ -0,0 +1,35 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/fft_window_size.fbs".
+
+# You can extend this class by creating a "FftWindowSizeExt" class in "fft_window_size_ext.py".
+
+from __future__ import annotations
+
+from ... import datatypes
+from ..._baseclasses import (
+    ComponentBatchMixin,
+    ComponentMixin,
+)
+
+__all__ = ["FftWindowSize", "FftWindowSizeBatch"]
+
+
+class FftWindowSize(datatypes.UInt32, ComponentMixin):
+    """
+    **Component**: The number of samples in each window of a rolling FFT, e.g. of a `SpectrogramView`.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of FftWindowSizeExt in fft_window_size_ext.py
+
+    # Note: there are no fields here because FftWindowSize delegates to datatypes.UInt32
+
+
+class FftWindowSizeBatch(datatypes.UInt32Batch, ComponentBatchMixin):
+    _COMPONENT_TYPE: str = "rerun.blueprint.components.FftWindowSize"
+
+
+# This is patched in late to avoid circular dependencies.
+FftWindowSize._BATCH_TYPE = FftWindowSizeBatch  # type: ignore[assignment]
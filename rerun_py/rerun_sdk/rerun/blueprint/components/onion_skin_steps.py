@@ -0,0 +1,35 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/onion_skin_steps.fbs".
+
+# You can extend this class by creating a "OnionSkinStepsExt" class in "onion_skin_steps_ext.py".
+
+from __future__ import annotations
+
+from ... import datatypes
+from ..._baseclasses import (
+    ComponentBatchMixin,
+    ComponentMixin,
+)
+
+__all__ = ["OnionSkinSteps", "OnionSkinStepsBatch"]
+
+
+class OnionSkinSteps(datatypes.UInt64, ComponentMixin):
+    """
+    **Component**: How many earlier states of each entity are shown by the onion skin of a view.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of OnionSkinStepsExt in onion_skin_steps_ext.py
+
+    # Note: there are no fields here because OnionSkinSteps delegates to datatypes.UInt64
+
+
+class OnionSkinStepsBatch(datatypes.UInt64Batch, ComponentBatchMixin):
+    _COMPONENT_TYPE: str = "rerun.blueprint.components.OnionSkinSteps"
+
+
+# This is patched in late to avoid circular dependencies.
+OnionSkinSteps._BATCH_TYPE = OnionSkinStepsBatch  # type: ignore[assignment]
@@ -2,24 +2,28 @@
 
 from __future__ import annotations
 
+from .audio_view import AudioView
 from .bar_chart_view import BarChartView
 from .dataframe_view import DataframeView
 from .graph_view import GraphView
 from .map_view import MapView
 from .spatial2d_view import Spatial2DView
 from .spatial3d_view import Spatial3DView
+from .spectrogram_view import SpectrogramView
 from .tensor_view import TensorView
 from .text_document_view import TextDocumentView
 from .text_log_view import TextLogView
 from .time_series_view import TimeSeriesView
 
 __all__ = [
+    "AudioView",
     "BarChartView",
     "DataframeView",
     "GraphView",
     "MapView",
     "Spatial2DView",
     "Spatial3DView",
+    "SpectrogramView",
     "TensorView",
     "TextDocumentView",
     "TextLogView",
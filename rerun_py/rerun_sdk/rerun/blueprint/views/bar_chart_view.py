@@ -75,6 +75,7 @@ class BarChartView(View):
             AsComponents | Iterable[DescribedComponentBatch | AsComponents | Iterable[DescribedComponentBatch]],
         ]
         | None = None,
+        layout: blueprint_archetypes.BarChartLayout | blueprint_components.BarChartModeLike | None = None,
         plot_legend: blueprint_archetypes.PlotLegend | blueprint_components.Corner2D | None = None,
         background: blueprint_archetypes.PlotBackground | None = None,
     ) -> None:
@@ -113,6 +114,8 @@ class BarChartView(View):
             do not yet support `$origin` relative paths or glob expressions.
             This will be addressed in <https://github.com/rerun-io/rerun/issues/6673>.
 
+        layout:
+            Configures how the bar charts of several entities are combined.
         plot_legend:
             Configures the legend of the plot.
         background:
@@ -121,6 +124,11 @@ class BarChartView(View):
         """
 
         properties: dict[str, AsComponents] = {}
+        if layout is not None:
+            if not isinstance(layout, blueprint_archetypes.BarChartLayout):
+                layout = blueprint_archetypes.BarChartLayout(layout)
+            properties["BarChartLayout"] = layout
+
         if plot_legend is not None:
             if not isinstance(plot_legend, blueprint_archetypes.PlotLegend):
                 plot_legend = blueprint_archetypes.PlotLegend(plot_legend)
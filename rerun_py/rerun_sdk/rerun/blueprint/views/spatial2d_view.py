@@ -94,6 +94,10 @@ class Spatial2DView(View):
         | blueprint_components.BackgroundKindLike
         | None = None,
         visual_bounds: blueprint_archetypes.VisualBounds2D | None = None,
+        instance_filter: blueprint_archetypes.InstanceFilter | None = None,
+        onion_skin: blueprint_archetypes.OnionSkin | None = None,
+        class_id_remap: blueprint_archetypes.ClassIdRemap | None = None,
+        outlines: blueprint_archetypes.Outlines | None = None,
         time_ranges: blueprint_archetypes.VisibleTimeRanges
         | datatypes.VisibleTimeRangeLike
         | Sequence[datatypes.VisibleTimeRangeLike]
@@ -141,6 +145,14 @@ class Spatial2DView(View):
 
             Everything within these bounds are guaranteed to be visible.
             Somethings outside of these bounds may also be visible due to letterboxing.
+        instance_filter:
+            Hides instances based on the value of a scalar component, e.g. to threshold detections.
+        onion_skin:
+            Shows the earlier states of entities as translucent ghosts.
+        class_id_remap:
+            Remaps class ids, e.g. to merge classes of segmentation images without re-logging them.
+        outlines:
+            Colors and thickness of outlines, and which entities are always outlined.
         time_ranges:
             Configures which range on each timeline is shown by this view (unless specified differently per entity).
 
@@ -160,6 +172,26 @@ class Spatial2DView(View):
                 visual_bounds = blueprint_archetypes.VisualBounds2D(visual_bounds)
             properties["VisualBounds2D"] = visual_bounds
 
+        if instance_filter is not None:
+            if not isinstance(instance_filter, blueprint_archetypes.InstanceFilter):
+                instance_filter = blueprint_archetypes.InstanceFilter(instance_filter)
+            properties["InstanceFilter"] = instance_filter
+
+        if onion_skin is not None:
+            if not isinstance(onion_skin, blueprint_archetypes.OnionSkin):
+                onion_skin = blueprint_archetypes.OnionSkin(onion_skin)
+            properties["OnionSkin"] = onion_skin
+
+        if class_id_remap is not None:
+            if not isinstance(class_id_remap, blueprint_archetypes.ClassIdRemap):
+                class_id_remap = blueprint_archetypes.ClassIdRemap(class_id_remap)
+            properties["ClassIdRemap"] = class_id_remap
+
+        if outlines is not None:
+            if not isinstance(outlines, blueprint_archetypes.Outlines):
+                outlines = blueprint_archetypes.Outlines(outlines)
+            properties["Outlines"] = outlines
+
         if time_ranges is not None:
             if not isinstance(time_ranges, blueprint_archetypes.VisibleTimeRanges):
                 time_ranges = blueprint_archetypes.VisibleTimeRanges(time_ranges)
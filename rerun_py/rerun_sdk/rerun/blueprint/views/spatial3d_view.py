@@ -114,6 +114,16 @@ class Spatial3DView(View):
         line_grid: blueprint_archetypes.LineGrid3D | datatypes.BoolLike | None = None,
         spatial_information: blueprint_archetypes.SpatialInformation | None = None,
         eye_controls: blueprint_archetypes.EyeControls3D | None = None,
+        image_planes: blueprint_archetypes.ImagePlanes3D | None = None,
+        instance_filter: blueprint_archetypes.InstanceFilter | None = None,
+        trajectory_trails: blueprint_archetypes.TrajectoryTrails | None = None,
+        onion_skin: blueprint_archetypes.OnionSkin | None = None,
+        depth_clouds: blueprint_archetypes.DepthClouds3D | None = None,
+        class_id_remap: blueprint_archetypes.ClassIdRemap | None = None,
+        coordinate_conversion: blueprint_archetypes.CoordinateConversion | None = None,
+        crop_box: blueprint_archetypes.CropBox | None = None,
+        point_shading: blueprint_archetypes.PointShading | None = None,
+        outlines: blueprint_archetypes.Outlines | None = None,
         time_ranges: blueprint_archetypes.VisibleTimeRanges
         | datatypes.VisibleTimeRangeLike
         | Sequence[datatypes.VisibleTimeRangeLike]
@@ -162,6 +172,26 @@ class Spatial3DView(View):
             Configuration of debug drawing in the 3D view.
         eye_controls:
             Configuration for the 3D eye
+        image_planes:
+            Configuration for the image planes of pinhole cameras.
+        instance_filter:
+            Hides instances based on the value of a scalar component, e.g. to threshold detections.
+        trajectory_trails:
+            Configuration for the trajectory trails of moving entities.
+        onion_skin:
+            Shows the earlier states of entities as translucent ghosts.
+        depth_clouds:
+            Configuration for the point clouds backprojected from depth images.
+        class_id_remap:
+            Remaps class ids, e.g. to merge classes of segmentation images without re-logging them.
+        coordinate_conversion:
+            Converts the data from the coordinate convention it was logged in, e.g. from NED to ENU.
+        crop_box:
+            Hides the points of point clouds inside or outside of a box.
+        point_shading:
+            Configures how point clouds are shaded.
+        outlines:
+            Colors and thickness of outlines, and which entities are always outlined.
         time_ranges:
             Configures which range on each timeline is shown by this view (unless specified differently per entity).
 
@@ -191,6 +221,56 @@ class Spatial3DView(View):
                 eye_controls = blueprint_archetypes.EyeControls3D(eye_controls)
             properties["EyeControls3D"] = eye_controls
 
+        if image_planes is not None:
+            if not isinstance(image_planes, blueprint_archetypes.ImagePlanes3D):
+                image_planes = blueprint_archetypes.ImagePlanes3D(image_planes)
+            properties["ImagePlanes3D"] = image_planes
+
+        if instance_filter is not None:
+            if not isinstance(instance_filter, blueprint_archetypes.InstanceFilter):
+                instance_filter = blueprint_archetypes.InstanceFilter(instance_filter)
+            properties["InstanceFilter"] = instance_filter
+
+        if trajectory_trails is not None:
+            if not isinstance(trajectory_trails, blueprint_archetypes.TrajectoryTrails):
+                trajectory_trails = blueprint_archetypes.TrajectoryTrails(trajectory_trails)
+            properties["TrajectoryTrails"] = trajectory_trails
+
+        if onion_skin is not None:
+            if not isinstance(onion_skin, blueprint_archetypes.OnionSkin):
+                onion_skin = blueprint_archetypes.OnionSkin(onion_skin)
+            properties["OnionSkin"] = onion_skin
+
+        if depth_clouds is not None:
+            if not isinstance(depth_clouds, blueprint_archetypes.DepthClouds3D):
+                depth_clouds = blueprint_archetypes.DepthClouds3D(depth_clouds)
+            properties["DepthClouds3D"] = depth_clouds
+
+        if class_id_remap is not None:
+            if not isinstance(class_id_remap, blueprint_archetypes.ClassIdRemap):
+                class_id_remap = blueprint_archetypes.ClassIdRemap(class_id_remap)
+            properties["ClassIdRemap"] = class_id_remap
+
+        if coordinate_conversion is not None:
+            if not isinstance(coordinate_conversion, blueprint_archetypes.CoordinateConversion):
+                coordinate_conversion = blueprint_archetypes.CoordinateConversion(coordinate_conversion)
+            properties["CoordinateConversion"] = coordinate_conversion
+
+        if crop_box is not None:
+            if not isinstance(crop_box, blueprint_archetypes.CropBox):
+                crop_box = blueprint_archetypes.CropBox(crop_box)
+            properties["CropBox"] = crop_box
+
+        if point_shading is not None:
+            if not isinstance(point_shading, blueprint_archetypes.PointShading):
+                point_shading = blueprint_archetypes.PointShading(point_shading)
+            properties["PointShading"] = point_shading
+
+        if outlines is not None:
+            if not isinstance(outlines, blueprint_archetypes.Outlines):
+                outlines = blueprint_archetypes.Outlines(outlines)
+            properties["Outlines"] = outlines
+
         if time_ranges is not None:
             if not isinstance(time_ranges, blueprint_archetypes.VisibleTimeRanges):
                 time_ranges = blueprint_archetypes.VisibleTimeRanges(time_ranges)
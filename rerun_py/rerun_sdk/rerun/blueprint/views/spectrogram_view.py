@@ -0,0 +1,127 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/views/spectrogram.fbs".
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+__all__ = ["SpectrogramView"]
+
+
+from .. import archetypes as blueprint_archetypes
+from ..api import View, ViewContentsLike
+
+if TYPE_CHECKING:
+    from collections.abc import Iterable, Mapping, Sequence
+
+    from ... import datatypes
+    from ..._baseclasses import (
+        AsComponents,
+        DescribedComponentBatch,
+    )
+    from ...datatypes import EntityPathLike, Utf8Like
+
+
+class SpectrogramView(View):
+    """
+    **View**: A spectrogram view for scalars over time, for use with [`archetypes.Scalars`][rerun.archetypes.Scalars].
+
+    Shows the rolling FFT of each scalar series as a time-frequency heatmap,
+    e.g. to analyze vibrations picked up by an IMU or audio.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self,
+        *,
+        origin: EntityPathLike = "/",
+        contents: ViewContentsLike = "$origin/**",
+        name: Utf8Like | None = None,
+        visible: datatypes.BoolLike | None = None,
+        defaults: Iterable[AsComponents | Iterable[DescribedComponentBatch]] | None = None,
+        overrides: Mapping[
+            EntityPathLike,
+            AsComponents | Iterable[DescribedComponentBatch | AsComponents | Iterable[DescribedComponentBatch]],
+        ]
+        | None = None,
+        fft_window: blueprint_archetypes.FftWindow | None = None,
+        scalar_mapping: blueprint_archetypes.TensorScalarMapping | None = None,
+        time_ranges: blueprint_archetypes.VisibleTimeRanges
+        | datatypes.VisibleTimeRangeLike
+        | Sequence[datatypes.VisibleTimeRangeLike]
+        | None = None,
+    ) -> None:
+        """
+        Construct a blueprint for a new SpectrogramView view.
+
+        Parameters
+        ----------
+        origin:
+            The `EntityPath` to use as the origin of this view.
+            All other entities will be transformed to be displayed relative to this origin.
+        contents:
+            The contents of the view specified as a query expression.
+            This is either a single expression, or a list of multiple expressions.
+            See [rerun.blueprint.archetypes.ViewContents][].
+        name:
+            The display name of the view.
+        visible:
+            Whether this view is visible.
+
+            Defaults to true if not specified.
+        defaults:
+            List of archetypes or (described) component batches to add to the view.
+            When an archetype in the view is missing a component included in this set,
+            the value of default will be used instead of the normal fallback for the visualizer.
+
+            Note that an archetype's required components typically don't have any effect.
+            It is recommended to use the archetype's `from_fields` method instead and only specify the fields that you need.
+        overrides:
+            Dictionary of overrides to apply to the view. The key is the path to the entity where the override
+            should be applied. The value is a list of archetypes or (described) component batches to apply to the entity.
+
+            It is recommended to use the archetype's `from_fields` method instead and only specify the fields that you need.
+
+            Important note: the path must be a fully qualified entity path starting at the root. The override paths
+            do not yet support `$origin` relative paths or glob expressions.
+            This will be addressed in <https://github.com/rerun-io/rerun/issues/6673>.
+
+        fft_window:
+            Configures the windows of the rolling FFT.
+        scalar_mapping:
+            Configures how magnitudes are mapped to color.
+        time_ranges:
+            Configures which range on each timeline is shown by this view (unless specified differently per entity).
+
+            If not specified, the default is to show the entire timeline.
+            If a timeline is specified more than once, the first entry will be used.
+
+        """
+
+        properties: dict[str, AsComponents] = {}
+        if fft_window is not None:
+            if not isinstance(fft_window, blueprint_archetypes.FftWindow):
+                fft_window = blueprint_archetypes.FftWindow(fft_window)
+            properties["FftWindow"] = fft_window
+
+        if scalar_mapping is not None:
+            if not isinstance(scalar_mapping, blueprint_archetypes.TensorScalarMapping):
+                scalar_mapping = blueprint_archetypes.TensorScalarMapping(scalar_mapping)
+            properties["TensorScalarMapping"] = scalar_mapping
+
+        if time_ranges is not None:
+            if not isinstance(time_ranges, blueprint_archetypes.VisibleTimeRanges):
+                time_ranges = blueprint_archetypes.VisibleTimeRanges(time_ranges)
+            properties["VisibleTimeRanges"] = time_ranges
+
+        super().__init__(
+            class_identifier="Spectrogram",
+            origin=origin,
+            contents=contents,
+            name=name,
+            visible=visible,
+            properties=properties,
+            defaults=defaults,
+            overrides=overrides,
+        )
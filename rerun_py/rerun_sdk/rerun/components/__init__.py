@@ -15,6 +15,13 @@ from .annotation_context import (
     AnnotationContextBatch,
     AnnotationContextLike,
 )
+from .audio_channel_count import AudioChannelCount, AudioChannelCountBatch
+from .audio_sample_format import (
+    AudioSampleFormat,
+    AudioSampleFormatArrayLike,
+    AudioSampleFormatBatch,
+    AudioSampleFormatLike,
+)
 from .axis_length import AxisLength, AxisLengthBatch
 from .blob import Blob, BlobBatch
 from .channel_id import ChannelId, ChannelIdBatch
@@ -45,6 +52,7 @@ from .image_buffer import ImageBuffer, ImageBufferBatch
 from .image_format import ImageFormat, ImageFormatBatch
 from .image_plane_distance import ImagePlaneDistance, ImagePlaneDistanceBatch
 from .interactive import Interactive, InteractiveBatch
+from .joint_parent import JointParent, JointParentBatch
 from .key_value_pairs import KeyValuePairs, KeyValuePairsArrayLike, KeyValuePairsBatch, KeyValuePairsLike
 from .keypoint_id import KeypointId, KeypointIdBatch
 from .lat_lon import LatLon, LatLonBatch
@@ -77,11 +85,18 @@ from .range1d import Range1D, Range1DBatch
 from .resolution import Resolution, ResolutionBatch
 from .rotation_axis_angle import RotationAxisAngle, RotationAxisAngleBatch
 from .rotation_quat import RotationQuat, RotationQuatBatch
+from .sample_rate import SampleRate, SampleRateBatch
 from .scalar import Scalar, ScalarBatch
 from .scale3d import Scale3D, Scale3DBatch
 from .schema_id import SchemaId, SchemaIdBatch
 from .series_visible import SeriesVisible, SeriesVisibleBatch
 from .show_labels import ShowLabels, ShowLabelsBatch
+from .spherical_harmonics import (
+    SphericalHarmonics,
+    SphericalHarmonicsArrayLike,
+    SphericalHarmonicsBatch,
+    SphericalHarmonicsLike,
+)
 from .stroke_width import StrokeWidth, StrokeWidthBatch
 from .tensor_data import TensorData, TensorDataBatch
 from .tensor_dimension_index_selection import TensorDimensionIndexSelection, TensorDimensionIndexSelectionBatch
@@ -101,6 +116,7 @@ from .transform_relation import (
 )
 from .translation3d import Translation3D, Translation3DBatch
 from .triangle_indices import TriangleIndices, TriangleIndicesBatch
+from .unit import Unit, UnitBatch
 from .value_range import ValueRange, ValueRangeBatch
 from .vector2d import Vector2D, Vector2DBatch
 from .vector3d import Vector3D, Vector3DBatch
@@ -121,6 +137,12 @@ __all__ = [
     "AnnotationContextArrayLike",
     "AnnotationContextBatch",
     "AnnotationContextLike",
+    "AudioChannelCount",
+    "AudioChannelCountBatch",
+    "AudioSampleFormat",
+    "AudioSampleFormatArrayLike",
+    "AudioSampleFormatBatch",
+    "AudioSampleFormatLike",
     "AxisLength",
     "AxisLengthBatch",
     "Blob",
@@ -181,6 +203,8 @@ __all__ = [
     "ImagePlaneDistanceBatch",
     "Interactive",
     "InteractiveBatch",
+    "JointParent",
+    "JointParentBatch",
     "KeyValuePairs",
     "KeyValuePairsArrayLike",
     "KeyValuePairsBatch",
@@ -245,6 +269,8 @@ __all__ = [
     "RotationAxisAngleBatch",
     "RotationQuat",
     "RotationQuatBatch",
+    "SampleRate",
+    "SampleRateBatch",
     "Scalar",
     "ScalarBatch",
     "Scale3D",
@@ -255,6 +281,10 @@ __all__ = [
     "SeriesVisibleBatch",
     "ShowLabels",
     "ShowLabelsBatch",
+    "SphericalHarmonics",
+    "SphericalHarmonicsArrayLike",
+    "SphericalHarmonicsBatch",
+    "SphericalHarmonicsLike",
     "StrokeWidth",
     "StrokeWidthBatch",
     "TensorData",
@@ -285,6 +315,8 @@ __all__ = [
     "Translation3DBatch",
     "TriangleIndices",
     "TriangleIndicesBatch",
+    "Unit",
+    "UnitBatch",
     "ValueRange",
     "ValueRangeBatch",
     "Vector2D",
@@ -52,6 +52,14 @@ class AggregationPolicy(Enum):
     MinMaxAverage = 6
     """Find both the minimum and maximum values in the range, then use the average of those."""
 
+    MinMeanMax = 7
+    """
+    Draw a band from the minimum to the maximum values in the range, with a line through their average.
+
+    The aggregates are kept up to date as the data arrives rather than computed from every point on every frame,
+    which keeps live plots of scalars logged at high rates smooth.
+    """
+
     @classmethod
     def auto(cls, val: str | int | AggregationPolicy) -> AggregationPolicy:
         """Best-effort converter, including a case-insensitive string matcher."""
@@ -81,12 +89,14 @@ AggregationPolicyLike = (
         "Min",
         "MinMax",
         "MinMaxAverage",
+        "MinMeanMax",
         "Off",
         "average",
         "max",
         "min",
         "minmax",
         "minmaxaverage",
+        "minmeanmax",
         "off",
     ]
     | int
@@ -101,12 +111,14 @@ AggregationPolicyArrayLike = (
         "Min",
         "MinMax",
         "MinMaxAverage",
+        "MinMeanMax",
         "Off",
         "average",
         "max",
         "min",
         "minmax",
         "minmaxaverage",
+        "minmeanmax",
         "off",
     ]
     | int
@@ -0,0 +1,35 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/audio_channel_count.fbs".
+
+# You can extend this class by creating a "AudioChannelCountExt" class in "audio_channel_count_ext.py".
+
+from __future__ import annotations
+
+from .. import datatypes
+from .._baseclasses import (
+    ComponentBatchMixin,
+    ComponentMixin,
+)
+
+__all__ = ["AudioChannelCount", "AudioChannelCountBatch"]
+
+
+class AudioChannelCount(datatypes.UInt32, ComponentMixin):
+    """
+    **Component**: The number of interleaved channels of [`archetypes.Audio`][rerun.archetypes.Audio], e.g. 2 for stereo.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of AudioChannelCountExt in audio_channel_count_ext.py
+
+    # Note: there are no fields here because AudioChannelCount delegates to datatypes.UInt32
+
+
+class AudioChannelCountBatch(datatypes.UInt32Batch, ComponentBatchMixin):
+    _COMPONENT_TYPE: str = "rerun.components.AudioChannelCount"
+
+
+# This is patched in late to avoid circular dependencies.
+AudioChannelCount._BATCH_TYPE = AudioChannelCountBatch  # type: ignore[assignment]
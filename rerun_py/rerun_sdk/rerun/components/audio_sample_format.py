@@ -0,0 +1,83 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/audio_sample_format.fbs".
+
+# You can extend this class by creating a "AudioSampleFormatExt" class in "audio_sample_format_ext.py".
+
+from __future__ import annotations
+
+from collections.abc import Sequence
+from typing import Literal
+
+import pyarrow as pa
+
+from .._baseclasses import (
+    BaseBatch,
+    ComponentBatchMixin,
+)
+
+__all__ = ["AudioSampleFormat", "AudioSampleFormatArrayLike", "AudioSampleFormatBatch", "AudioSampleFormatLike"]
+
+
+from enum import Enum
+
+
+class AudioSampleFormat(Enum):
+    """
+    **Component**: The data type of raw PCM samples of [`archetypes.Audio`][rerun.archetypes.Audio].
+
+    All formats are little-endian.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    F32 = 1
+    """32-bit floats in the range `[-1, 1]`."""
+
+    I16 = 2
+    """Signed 16-bit integers."""
+
+    I32 = 3
+    """Signed 32-bit integers."""
+
+    @classmethod
+    def auto(cls, val: str | int | AudioSampleFormat) -> AudioSampleFormat:
+        """Best-effort converter, including a case-insensitive string matcher."""
+        if isinstance(val, AudioSampleFormat):
+            return val
+        if isinstance(val, int):
+            return cls(val)
+        try:
+            return cls[val]
+        except KeyError:
+            val_lower = val.lower()
+            for variant in cls:
+                if variant.name.lower() == val_lower:
+                    return variant
+        raise ValueError(f"Cannot convert {val} to {cls.__name__}")
+
+    def __str__(self) -> str:
+        """Returns the variant name."""
+        return self.name
+
+
+AudioSampleFormatLike = AudioSampleFormat | Literal["F32", "I16", "I32", "f32", "i16", "i32"] | int
+"""A type alias for any AudioSampleFormat-like object."""
+
+AudioSampleFormatArrayLike = (
+    AudioSampleFormat | Literal["F32", "I16", "I32", "f32", "i16", "i32"] | int | Sequence[AudioSampleFormatLike]
+)
+"""A type alias for any AudioSampleFormat-like array object."""
+
+
+class AudioSampleFormatBatch(BaseBatch[AudioSampleFormatArrayLike], ComponentBatchMixin):
+    _ARROW_DATATYPE = pa.uint8()
+    _COMPONENT_TYPE: str = "rerun.components.AudioSampleFormat"
+
+    @staticmethod
+    def _native_to_pa_array(data: AudioSampleFormatArrayLike, data_type: pa.DataType) -> pa.Array:
+        if isinstance(data, (AudioSampleFormat, int, str)):
+            data = [data]
+
+        pa_data = [AudioSampleFormat.auto(v).value if v is not None else None for v in data]  # type: ignore[redundant-expr]
+
+        return pa.array(pa_data, type=data_type)
@@ -0,0 +1,37 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/joint_parent.fbs".
+
+# You can extend this class by creating a "JointParentExt" class in "joint_parent_ext.py".
+
+from __future__ import annotations
+
+from .. import datatypes
+from .._baseclasses import (
+    ComponentBatchMixin,
+    ComponentMixin,
+)
+
+__all__ = ["JointParent", "JointParentBatch"]
+
+
+class JointParent(datatypes.UInt32, ComponentMixin):
+    """
+    **Component**: The index of the parent of a joint within a [`archetypes.Skeleton`][rerun.archetypes.Skeleton].
+
+    Root joints, i.e. joints without a parent, reference themselves.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of JointParentExt in joint_parent_ext.py
+
+    # Note: there are no fields here because JointParent delegates to datatypes.UInt32
+
+
+class JointParentBatch(datatypes.UInt32Batch, ComponentBatchMixin):
+    _COMPONENT_TYPE: str = "rerun.components.JointParent"
+
+
+# This is patched in late to avoid circular dependencies.
+JointParent._BATCH_TYPE = JointParentBatch  # type: ignore[assignment]
@@ -78,6 +78,14 @@ class MediaTypeExt:
     <https://www.iana.org/assignments/media-types/video/mp4>
     """
 
+    # --------------------------
+    # Audio:
+
+    WAV: MediaType = None  # type: ignore[assignment]
+    """
+    [WAV audio](https://en.wikipedia.org/wiki/WAV): `audio/wav`.
+    """
+
     @staticmethod
     def deferred_patch_class(cls: Any) -> None:
         cls.TEXT = cls("text/plain")
@@ -93,6 +101,8 @@ class MediaTypeExt:
 
         cls.MP4 = cls("video/mp4")
 
+        cls.WAV = cls("audio/wav")
+
     @staticmethod
     def guess_from_path(path: str | Path) -> MediaType | None:
         from ..components import MediaType
@@ -119,4 +129,8 @@ class MediaTypeExt:
         if ext == ".mp4":
             return MediaType.MP4
 
+        # Audio
+        if ext == ".wav":
+            return MediaType.WAV
+
         return None
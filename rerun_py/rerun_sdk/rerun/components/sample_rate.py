@@ -0,0 +1,35 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/sample_rate.fbs".
+
+# You can extend this class by creating a "SampleRateExt" class in "sample_rate_ext.py".
+
+from __future__ import annotations
+
+from .. import datatypes
+from .._baseclasses import (
+    ComponentBatchMixin,
+    ComponentMixin,
+)
+
+__all__ = ["SampleRate", "SampleRateBatch"]
+
+
+class SampleRate(datatypes.UInt32, ComponentMixin):
+    """
+    **Component**: The number of samples per second of a signal, e.g. of [`archetypes.Audio`][rerun.archetypes.Audio], in Hz.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of SampleRateExt in sample_rate_ext.py
+
+    # Note: there are no fields here because SampleRate delegates to datatypes.UInt32
+
+
+class SampleRateBatch(datatypes.UInt32Batch, ComponentBatchMixin):
+    _COMPONENT_TYPE: str = "rerun.components.SampleRate"
+
+
+# This is patched in late to avoid circular dependencies.
+SampleRate._BATCH_TYPE = SampleRateBatch  # type: ignore[assignment]
@@ -0,0 +1,81 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/spherical_harmonics.fbs".
+
+# You can extend this class by creating a "SphericalHarmonicsExt" class in "spherical_harmonics_ext.py".
+
+from __future__ import annotations
+
+from collections.abc import Sequence
+from typing import TYPE_CHECKING, Any
+
+import numpy as np
+import numpy.typing as npt
+import pyarrow as pa
+from attrs import define, field
+
+from .._baseclasses import (
+    BaseBatch,
+    ComponentBatchMixin,
+    ComponentMixin,
+)
+from .spherical_harmonics_ext import SphericalHarmonicsExt
+
+__all__ = ["SphericalHarmonics", "SphericalHarmonicsArrayLike", "SphericalHarmonicsBatch", "SphericalHarmonicsLike"]
+
+
+@define(init=False)
+class SphericalHarmonics(SphericalHarmonicsExt, ComponentMixin):
+    r"""
+    **Component**: The view dependent color of a Gaussian splat, as coefficients of real spherical harmonics.
+
+    Holds the RGB coefficients of all degrees above zero, i.e. 3, 8 or 15 coefficients for degree 1, 2 or 3.
+    They are ordered by degree first and by order second, the same way as the `f_rest` properties of most
+    Gaussian splatting reconstructions, after transposing those from channel major to coefficient major order.
+    The zeroth degree is given by the color of the splat.
+    """
+
+    _BATCH_TYPE = None
+
+    def __init__(self: Any, coefficients: SphericalHarmonicsLike) -> None:
+        """Create a new instance of the SphericalHarmonics component."""
+
+        # You can define your own __init__ function as a member of SphericalHarmonicsExt in spherical_harmonics_ext.py
+        self.__attrs_init__(coefficients=coefficients)
+
+    coefficients: list[datatypes.Vec3D] = field()
+
+    def __len__(self) -> int:
+        # You can define your own __len__ function as a member of SphericalHarmonicsExt in spherical_harmonics_ext.py
+        return len(self.coefficients)
+
+
+if TYPE_CHECKING:
+    from .. import datatypes
+
+    SphericalHarmonicsLike = SphericalHarmonics | datatypes.Vec3DArrayLike | npt.NDArray[np.float32]
+    """A type alias for any SphericalHarmonics-like object."""
+else:
+    SphericalHarmonicsLike = Any
+
+SphericalHarmonicsArrayLike = SphericalHarmonics | Sequence[SphericalHarmonicsLike] | npt.NDArray[np.float32]
+"""A type alias for any SphericalHarmonics-like array object."""
+
+
+class SphericalHarmonicsBatch(BaseBatch[SphericalHarmonicsArrayLike], ComponentBatchMixin):
+    _ARROW_DATATYPE = pa.list_(
+        pa.field(
+            "item",
+            pa.list_(pa.field("item", pa.float32(), nullable=False, metadata={}), 3),
+            nullable=False,
+            metadata={},
+        )
+    )
+    _COMPONENT_TYPE: str = "rerun.components.SphericalHarmonics"
+
+    @staticmethod
+    def _native_to_pa_array(data: SphericalHarmonicsArrayLike, data_type: pa.DataType) -> pa.Array:
+        return SphericalHarmonicsExt.native_to_pa_array_override(data, data_type)
+
+
+# This is patched in late to avoid circular dependencies.
+SphericalHarmonics._BATCH_TYPE = SphericalHarmonicsBatch  # type: ignore[assignment]
@@ -0,0 +1,38 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import numpy as np
+import pyarrow as pa
+
+if TYPE_CHECKING:
+    from . import SphericalHarmonicsArrayLike
+
+
+class SphericalHarmonicsExt:
+    """Extension for [SphericalHarmonics][rerun.components.SphericalHarmonics]."""
+
+    @staticmethod
+    def native_to_pa_array_override(data: SphericalHarmonicsArrayLike, data_type: pa.DataType) -> pa.Array:
+        from ..datatypes import Vec3DBatch
+        from . import SphericalHarmonics
+
+        # pure-numpy fast path, with the shape `(num_splats, num_coefficients, 3)`
+        if isinstance(data, np.ndarray):
+            if data.ndim != 3 or data.shape[2] != 3:
+                raise ValueError(
+                    f"Expected an array of shape (num_splats, num_coefficients, 3), instead got {data.shape}.",
+                )
+            offsets = np.arange(data.shape[0] + 1, dtype=np.int32) * data.shape[1]
+            inner = Vec3DBatch(data.reshape(-1, 3)).as_arrow_array()
+            return pa.ListArray.from_arrays(pa.array(offsets, type=pa.int32()), inner, type=data_type)
+
+        if isinstance(data, SphericalHarmonics):
+            data = [data]
+
+        inners = [
+            Vec3DBatch(sh.coefficients if isinstance(sh, SphericalHarmonics) else sh).as_arrow_array() for sh in data
+        ]
+        offsets = np.cumsum([0] + [len(inner) for inner in inners], dtype=np.int32)
+        inner = pa.concat_arrays(inners) if inners else Vec3DBatch([]).as_arrow_array()
+        return pa.ListArray.from_arrays(pa.array(offsets, type=pa.int32()), inner, type=data_type)
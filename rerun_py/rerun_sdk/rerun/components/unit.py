@@ -0,0 +1,37 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/unit.fbs".
+
+# You can extend this class by creating a "UnitExt" class in "unit_ext.py".
+
+from __future__ import annotations
+
+from .. import datatypes
+from .._baseclasses import (
+    ComponentBatchMixin,
+    ComponentMixin,
+)
+
+__all__ = ["Unit", "UnitBatch"]
+
+
+class Unit(datatypes.Utf8, ComponentMixin):
+    """
+    **Component**: A unit of measurement, e.g. `m/s` or `rad`.
+
+    Units are free-form strings, but using SI symbols lets the viewer convert between them.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of UnitExt in unit_ext.py
+
+    # Note: there are no fields here because Unit delegates to datatypes.Utf8
+
+
+class UnitBatch(datatypes.Utf8Batch, ComponentBatchMixin):
+    _COMPONENT_TYPE: str = "rerun.components.Unit"
+
+
+# This is patched in late to avoid circular dependencies.
+Unit._BATCH_TYPE = UnitBatch  # type: ignore[assignment]
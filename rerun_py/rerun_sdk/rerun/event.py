@@ -106,8 +106,26 @@ class RecordingOpenEvent(ViewerEventBase):
     version: str | None
 
 
+@dataclass
+class IngestProgressEvent(ViewerEventBase):
+    @property
+    def type(self) -> Literal["ingest_progress"]:
+        return "ingest_progress"
+
+    num_rows: int
+    finished: bool
+
+
 # Union type for all possible event types
-ViewerEvent = PlayEvent | PauseEvent | TimeUpdateEvent | TimelineChangeEvent | SelectionChangeEvent | RecordingOpenEvent
+ViewerEvent = (
+    PlayEvent
+    | PauseEvent
+    | TimeUpdateEvent
+    | TimelineChangeEvent
+    | SelectionChangeEvent
+    | RecordingOpenEvent
+    | IngestProgressEvent
+)
 
 
 def _viewer_event_from_json_str(json_str: str) -> ViewerEvent:
@@ -184,5 +202,14 @@ def _viewer_event_from_json_str(json_str: str) -> ViewerEvent:
             version=data.get("version", None),
         )
 
+    elif event_type == "ingest_progress":
+        return IngestProgressEvent(
+            application_id=app_id,
+            recording_id=recording_id,
+            partition_id=partition_id,
+            num_rows=data["num_rows"],
+            finished=data["finished"],
+        )
+
     else:
         raise ValueError(f"Unknown event type: '{event_type}'")
@@ -527,15 +527,37 @@ impl PyRecordingView {
     #[expect(rustdoc::private_doc_tests)]
     /// Populate any null values in a row with the latest valid data according to the index.
     ///
+    /// Combined with [`.filter_is_not_null()`][rerun.dataframe.RecordingView.filter_is_not_null], this
+    /// performs an as-of join: every row at which the filtered component was logged gets the most recent
+    /// value of every other column, e.g. to align a 1 kHz IMU with the frames of a 30 Hz camera:
+    ///
+    /// ```python
+    /// view = recording.view(index="capture_time", contents=["/camera/**", "/imu/**"])
+    /// table = view.filter_is_not_null(camera_column).fill_latest_at(tolerance=10_000_000).select()
+    /// ```
+    ///
+    /// Parameters
+    /// ----------
+    /// tolerance : int | None
+    ///     If set, null values are only filled with data logged at most this long before their row.
+    ///     Older data is left null.
+    ///
+    ///     This is in the units of the index, i.e. nanoseconds for timestamps and durations, and
+    ///     steps for sequences.
+    ///
     /// Returns
     /// -------
     /// RecordingView
     ///     A new view with the null values filled in.
     ///
     ///     The original view will not be modified.
-    fn fill_latest_at(&self) -> Self {
+    #[pyo3(signature = (*, tolerance = None))]
+    fn fill_latest_at(&self, tolerance: Option<u64>) -> Self {
         let mut query_expression = self.query_expression.clone();
-        query_expression.sparse_fill_strategy = SparseFillStrategy::LatestAtGlobal;
+        query_expression.sparse_fill_strategy = match tolerance {
+            Some(tolerance) => SparseFillStrategy::LatestAtGlobalWithin { tolerance },
+            None => SparseFillStrategy::LatestAtGlobal,
+        };
 
         Self {
             recording: self.recording.clone(),
@@ -1480,6 +1480,8 @@ fn serve_grpc(
             memory_limit: re_memory::MemoryLimit::parse(&server_memory_limit).map_err(|err| {
                 PyRuntimeError::new_err(format!("Bad server_memory_limit: {err}:"))
             })?,
+
+            ..Default::default()
         };
 
         let sink = re_sdk::grpc_server::GrpcServerSink::new(
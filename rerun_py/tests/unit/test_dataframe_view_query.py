@@ -128,6 +128,7 @@ def test_dataframe_query_property() -> None:
         filter_by_range=(TimeInt(seq=1), TimeInt(seq=10)),
         filter_is_not_null="/entity/path:Component",
         apply_latest_at=True,
+        latest_at_tolerance=TimeInt(seq=5),
         select=[
             "t",
             "/entity/path:Component",
@@ -146,6 +147,7 @@ def test_dataframe_query_property() -> None:
     )
 
     assert query.apply_latest_at == blueprint_components.ApplyLatestAtBatch(blueprint_components.ApplyLatestAt(True))
+    assert query.latest_at_tolerance == blueprint_components.TimeIntBatch(rr.datatypes.TimeInt(seq=5))
 
     assert query.select == blueprint_components.SelectedColumnsBatch(
         blueprint_components.SelectedColumns([